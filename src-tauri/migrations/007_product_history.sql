@@ -0,0 +1,48 @@
+-- Product change history (audit trail)
+-- Every UPDATE of product_details (repository upserts, write-behind queue, sync SQL, dedup merges)
+-- records one row per tracked field whose previous non-empty value changed. Blank -> value fills
+-- are not history: nothing was lost. Values are compared trimmed, as text.
+
+CREATE TABLE IF NOT EXISTS product_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    url TEXT NOT NULL,
+    field TEXT NOT NULL,
+    old_value TEXT,
+    new_value TEXT,
+    changed_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_product_history_url ON product_history(url, changed_at DESC);
+CREATE INDEX IF NOT EXISTS idx_product_history_changed_at ON product_history(changed_at DESC);
+
+CREATE TRIGGER IF NOT EXISTS product_details_history
+    AFTER UPDATE ON product_details
+    FOR EACH ROW
+BEGIN
+    INSERT INTO product_history (url, field, old_value, new_value)
+    SELECT NEW.url, field, old_value, new_value FROM (
+        SELECT 'manufacturer' AS field, CAST(OLD.manufacturer AS TEXT) AS old_value, CAST(NEW.manufacturer AS TEXT) AS new_value
+        UNION ALL SELECT 'model', CAST(OLD.model AS TEXT), CAST(NEW.model AS TEXT)
+        UNION ALL SELECT 'device_type', CAST(OLD.device_type AS TEXT), CAST(NEW.device_type AS TEXT)
+        UNION ALL SELECT 'certificate_id', CAST(OLD.certificate_id AS TEXT), CAST(NEW.certificate_id AS TEXT)
+        UNION ALL SELECT 'certification_date', CAST(OLD.certification_date AS TEXT), CAST(NEW.certification_date AS TEXT)
+        UNION ALL SELECT 'software_version', CAST(OLD.software_version AS TEXT), CAST(NEW.software_version AS TEXT)
+        UNION ALL SELECT 'hardware_version', CAST(OLD.hardware_version AS TEXT), CAST(NEW.hardware_version AS TEXT)
+        UNION ALL SELECT 'firmware_version', CAST(OLD.firmware_version AS TEXT), CAST(NEW.firmware_version AS TEXT)
+        UNION ALL SELECT 'specification_version', CAST(OLD.specification_version AS TEXT), CAST(NEW.specification_version AS TEXT)
+        UNION ALL SELECT 'vid', CAST(OLD.vid AS TEXT), CAST(NEW.vid AS TEXT)
+        UNION ALL SELECT 'pid', CAST(OLD.pid AS TEXT), CAST(NEW.pid AS TEXT)
+        UNION ALL SELECT 'family_sku', CAST(OLD.family_sku AS TEXT), CAST(NEW.family_sku AS TEXT)
+        UNION ALL SELECT 'family_variant_sku', CAST(OLD.family_variant_sku AS TEXT), CAST(NEW.family_variant_sku AS TEXT)
+        UNION ALL SELECT 'family_id', CAST(OLD.family_id AS TEXT), CAST(NEW.family_id AS TEXT)
+        UNION ALL SELECT 'tis_trp_tested', CAST(OLD.tis_trp_tested AS TEXT), CAST(NEW.tis_trp_tested AS TEXT)
+        UNION ALL SELECT 'transport_interface', CAST(OLD.transport_interface AS TEXT), CAST(NEW.transport_interface AS TEXT)
+        UNION ALL SELECT 'primary_device_type_id', CAST(OLD.primary_device_type_id AS TEXT), CAST(NEW.primary_device_type_id AS TEXT)
+        UNION ALL SELECT 'application_categories', CAST(OLD.application_categories AS TEXT), CAST(NEW.application_categories AS TEXT)
+        UNION ALL SELECT 'description', CAST(OLD.description AS TEXT), CAST(NEW.description AS TEXT)
+        UNION ALL SELECT 'compliance_document_url', CAST(OLD.compliance_document_url AS TEXT), CAST(NEW.compliance_document_url AS TEXT)
+        UNION ALL SELECT 'program_type', CAST(OLD.program_type AS TEXT), CAST(NEW.program_type AS TEXT)
+    )
+    WHERE NULLIF(TRIM(old_value), '') IS NOT NULL
+      AND NULLIF(TRIM(old_value), '') IS NOT NULLIF(TRIM(new_value), '');
+END;
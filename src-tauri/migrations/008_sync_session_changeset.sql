@@ -0,0 +1,4 @@
+-- Field-level changeset summary per sync session (JSON: total_changes, products_changed,
+-- fields[{field, count, sample_urls}]), written when the session completes.
+
+ALTER TABLE sync_sessions ADD COLUMN changeset_json TEXT;
@@ -0,0 +1,4 @@
+-- Workspace (named database) a sync session ran against; NULL for sessions recorded before
+-- workspaces existed.
+
+ALTER TABLE sync_sessions ADD COLUMN workspace TEXT;
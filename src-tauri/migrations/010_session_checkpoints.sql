@@ -0,0 +1,10 @@
+-- Resumable crawl session checkpoints (one row per session, upserted after every batch).
+-- payload is the serialized SessionCheckpoint: plan, pending/completed pages, retry counters.
+
+CREATE TABLE IF NOT EXISTS session_checkpoints (
+    session_id TEXT PRIMARY KEY,
+    plan_hash TEXT NOT NULL,
+    status TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
@@ -0,0 +1,14 @@
+-- Cron / interval crawl schedules. spec and action are serialized ScheduleSpec / ScheduleAction;
+-- next_run_at is recomputed after every run.
+
+CREATE TABLE IF NOT EXISTS crawl_schedules (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    spec TEXT NOT NULL,
+    action TEXT NOT NULL,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    last_run_at TEXT,
+    next_run_at TEXT,
+    last_error TEXT,
+    created_at TEXT NOT NULL
+);
@@ -0,0 +1,8 @@
+-- Rolling refresh cursor: a single row holding the index of the next rolling-refresh run, so
+-- each run re-fetches the next slice of the catalog across restarts.
+
+CREATE TABLE IF NOT EXISTS rolling_refresh_state (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    run_index INTEGER NOT NULL,
+    updated_at TEXT NOT NULL
+);
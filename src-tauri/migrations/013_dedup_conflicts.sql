@@ -0,0 +1,27 @@
+-- Dedup conflict queue: duplicate rows (same dedup key) whose non-empty values disagree wait here
+-- for a per-field decision. dedup_resolutions keeps every decision so the same disagreement is
+-- settled automatically when it re-appears.
+
+CREATE TABLE IF NOT EXISTS dedup_conflicts (
+    id TEXT PRIMARY KEY,
+    table_name TEXT NOT NULL,
+    dedup_key TEXT NOT NULL,
+    field TEXT NOT NULL,
+    candidates TEXT NOT NULL,
+    status TEXT NOT NULL,
+    resolved_value TEXT,
+    strategy TEXT,
+    created_at TEXT NOT NULL,
+    resolved_at TEXT,
+    UNIQUE (table_name, dedup_key, field)
+);
+
+CREATE TABLE IF NOT EXISTS dedup_resolutions (
+    table_name TEXT NOT NULL,
+    dedup_key TEXT NOT NULL,
+    field TEXT NOT NULL,
+    value TEXT NOT NULL,
+    strategy TEXT NOT NULL,
+    decided_at TEXT NOT NULL,
+    PRIMARY KEY (table_name, dedup_key, field)
+);
@@ -0,0 +1,19 @@
+-- Product / manufacturer watchlist. annotations holds operator marks on a target (kind 'watch'
+-- for the watchlist); watchlist_snapshots keeps the last seen tracked fields per watched URL so
+-- the next check can report field-level changes.
+
+CREATE TABLE IF NOT EXISTS annotations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    target_type TEXT NOT NULL,
+    target TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    note TEXT,
+    created_at TEXT NOT NULL,
+    UNIQUE (target_type, target, kind)
+);
+
+CREATE TABLE IF NOT EXISTS watchlist_snapshots (
+    url TEXT PRIMARY KEY,
+    fields TEXT NOT NULL,
+    captured_at TEXT NOT NULL
+);
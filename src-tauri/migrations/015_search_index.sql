@@ -0,0 +1,19 @@
+-- Product search index (FTS5) and per-manufacturer rollups, both derived from product_details
+-- and kept current incrementally by services::search_index. The FTS columns after url must
+-- match SEARCH_FIELDS there.
+
+CREATE VIRTUAL TABLE IF NOT EXISTS product_search USING fts5(
+    url UNINDEXED,
+    manufacturer,
+    model,
+    device_type,
+    certificate_id,
+    description
+);
+
+CREATE TABLE IF NOT EXISTS manufacturer_rollups (
+    manufacturer TEXT PRIMARY KEY,
+    product_count INTEGER NOT NULL,
+    latest_certification_date TEXT,
+    refreshed_at TEXT NOT NULL
+);
@@ -0,0 +1,14 @@
+-- Pages whose sync transaction failed to commit, keyed by canonical page_id. The next sync
+-- re-collects them (in_pass marks the pages riding along in the current pass); pages that commit
+-- cleanly leave the queue when the pass closes.
+
+CREATE TABLE IF NOT EXISTS needs_revalidation (
+    page_id INTEGER PRIMARY KEY,
+    physical_page INTEGER NOT NULL,
+    session_id TEXT NOT NULL,
+    error TEXT,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    in_pass INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
@@ -0,0 +1,37 @@
+-- Dashboard chart cache invalidation. product_details triggers bump the version of every
+-- aggregate a write can change (insert/delete: all; update: only aggregates over the changed
+-- column); services::analytics_cache reuses a cached series while its version is unchanged.
+
+CREATE TABLE IF NOT EXISTS analytics_versions (
+    aggregate TEXT PRIMARY KEY,
+    version INTEGER NOT NULL DEFAULT 0
+);
+
+INSERT OR IGNORE INTO analytics_versions (aggregate, version)
+    VALUES ('products_per_month', 0), ('products_per_device_type', 0);
+
+CREATE TRIGGER IF NOT EXISTS analytics_product_details_insert
+    AFTER INSERT ON product_details
+BEGIN
+    UPDATE analytics_versions SET version = version + 1;
+END;
+
+CREATE TRIGGER IF NOT EXISTS analytics_product_details_delete
+    AFTER DELETE ON product_details
+BEGIN
+    UPDATE analytics_versions SET version = version + 1;
+END;
+
+CREATE TRIGGER IF NOT EXISTS analytics_product_details_month
+    AFTER UPDATE OF certification_date ON product_details
+    WHEN OLD.certification_date IS NOT NEW.certification_date
+BEGIN
+    UPDATE analytics_versions SET version = version + 1 WHERE aggregate = 'products_per_month';
+END;
+
+CREATE TRIGGER IF NOT EXISTS analytics_product_details_device_type
+    AFTER UPDATE OF device_type ON product_details
+    WHEN OLD.device_type IS NOT NEW.device_type
+BEGIN
+    UPDATE analytics_versions SET version = version + 1 WHERE aggregate = 'products_per_device_type';
+END;
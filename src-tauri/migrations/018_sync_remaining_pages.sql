@@ -0,0 +1,8 @@
+-- Pages a pausable sync session has yet to run, keyed by canonical page_id. Rows are written when
+-- the session starts and deleted as each page completes; what is left after a pause is resumed.
+
+CREATE TABLE IF NOT EXISTS sync_remaining_pages (
+    session_id TEXT NOT NULL,
+    page_id INTEGER NOT NULL,
+    PRIMARY KEY (session_id, page_id)
+);
@@ -0,0 +1,18 @@
+-- Cross-check findings between products and product_details. Each validation run replaces the
+-- open rows; repaired rows keep `resolved_at` as an audit trail.
+
+CREATE TABLE IF NOT EXISTS validation_findings (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    run_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    table_name TEXT NOT NULL,
+    url TEXT NOT NULL,
+    page_id INTEGER,
+    index_in_page INTEGER,
+    detail_page_id INTEGER,
+    detail_index_in_page INTEGER,
+    repair TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'open',
+    created_at TEXT NOT NULL,
+    resolved_at TEXT
+);
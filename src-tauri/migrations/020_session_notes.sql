@@ -0,0 +1,12 @@
+-- Free-text operator notes on crawl/sync sessions, optionally scoped to one batch. Notes are
+-- included in the session report.
+
+CREATE TABLE IF NOT EXISTS session_notes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id TEXT NOT NULL,
+    batch_id TEXT,
+    note TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_session_notes_session ON session_notes (session_id, id);
@@ -0,0 +1,13 @@
+-- Per-session (page_id, index_in_page) reservations taken by sync page workers before they write
+-- coordinates. A session may hold each slot once and each URL once.
+
+CREATE TABLE IF NOT EXISTS slot_reservations (
+    session_id TEXT NOT NULL,
+    page_id INTEGER NOT NULL,
+    index_in_page INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    reserved_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (session_id, page_id, index_in_page)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_slot_reservations_url ON slot_reservations (session_id, url);
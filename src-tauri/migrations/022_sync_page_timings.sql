@@ -0,0 +1,9 @@
+-- Single-row EWMA of per-page sync time across past sessions, used to seed the ETA of the next
+-- sync before it has timed any pages of its own.
+
+CREATE TABLE IF NOT EXISTS sync_page_timings (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    ewma_ms REAL NOT NULL,
+    sessions INTEGER NOT NULL,
+    updated_at TEXT NOT NULL
+);
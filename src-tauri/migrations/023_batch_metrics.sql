@@ -0,0 +1,15 @@
+-- Throughput of finished crawl batches (pages/min at a given batch size and memory pressure),
+-- used to suggest the next batch size.
+
+CREATE TABLE IF NOT EXISTS batch_metrics (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id TEXT NOT NULL,
+    batch_id TEXT NOT NULL,
+    batch_size INTEGER NOT NULL,
+    pages INTEGER NOT NULL,
+    products INTEGER NOT NULL,
+    duration_ms INTEGER NOT NULL,
+    pages_per_min REAL NOT NULL,
+    memory_pressure REAL,
+    recorded_at TEXT NOT NULL
+);
@@ -0,0 +1,15 @@
+-- URL set of each list page per sync session: a hash of the sorted URLs plus, for recent
+-- sessions, the gzip-compressed list itself. Kept for post-hoc analysis of list shifts.
+
+CREATE TABLE IF NOT EXISTS page_url_sets (
+    session_id TEXT NOT NULL,
+    physical_page INTEGER NOT NULL,
+    expected_count INTEGER NOT NULL,
+    url_count INTEGER NOT NULL,
+    set_hash TEXT NOT NULL,
+    urls_gz BLOB,
+    recorded_at TEXT NOT NULL,
+    PRIMARY KEY (session_id, physical_page)
+);
+
+CREATE INDEX IF NOT EXISTS idx_page_url_sets_page ON page_url_sets (physical_page, recorded_at);
@@ -0,0 +1,21 @@
+-- Dead-letter queue: list pages and product details that ran out of retries, with their attempt
+-- history and the payload needed to requeue them. One row per (stage, item_key).
+
+CREATE TABLE IF NOT EXISTS failed_items (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    stage TEXT NOT NULL,
+    item_key TEXT NOT NULL,
+    session_id TEXT NOT NULL,
+    error_class TEXT NOT NULL,
+    last_error TEXT NOT NULL,
+    attempts_json TEXT NOT NULL,
+    payload_json TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending',
+    dead_letter_count INTEGER NOT NULL DEFAULT 1,
+    first_failed_at TEXT NOT NULL,
+    last_failed_at TEXT NOT NULL,
+    requeued_at TEXT,
+    UNIQUE (stage, item_key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_failed_items_status ON failed_items (status, last_failed_at);
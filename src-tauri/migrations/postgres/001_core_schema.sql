@@ -0,0 +1,72 @@
+-- Postgres counterpart of the SQLite core schema (003 + 005/006/008/009) used by
+-- `PostgresProductRepository`. Keep it in step with the SQLite migrations: a column added to
+-- products / product_details / sync_sessions there needs a numbered file here as well.
+
+CREATE TABLE IF NOT EXISTS products (
+    url TEXT PRIMARY KEY,
+    id TEXT,                                      -- "p" + 4-digit page_id + "i" + 2-digit index
+    manufacturer TEXT,
+    model TEXT,
+    certificate_id TEXT,
+    page_id INTEGER,
+    index_in_page INTEGER,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE IF NOT EXISTS product_details (
+    url TEXT PRIMARY KEY REFERENCES products (url) ON DELETE CASCADE,
+    page_id INTEGER,
+    index_in_page INTEGER,
+    id TEXT,
+    manufacturer TEXT,
+    model TEXT,
+    device_type TEXT,
+    certificate_id TEXT,
+    certification_date TEXT,
+    software_version TEXT,
+    hardware_version TEXT,
+    firmware_version TEXT,
+    specification_version TEXT,
+    vid INTEGER,
+    pid INTEGER,
+    family_sku TEXT,
+    family_variant_sku TEXT,
+    family_id TEXT,
+    tis_trp_tested TEXT,
+    transport_interface TEXT,
+    primary_device_type_id TEXT,
+    application_categories TEXT,
+    description TEXT,
+    compliance_document_url TEXT,
+    program_type TEXT DEFAULT 'Matter',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE IF NOT EXISTS sync_sessions (
+    session_id TEXT PRIMARY KEY,
+    status TEXT NOT NULL,
+    coverage_text TEXT,
+    changeset_json TEXT,
+    workspace TEXT,
+    started_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    finished_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_products_id ON products (id);
+CREATE INDEX IF NOT EXISTS idx_products_manufacturer ON products (manufacturer);
+CREATE INDEX IF NOT EXISTS idx_products_certificate_id ON products (certificate_id);
+CREATE INDEX IF NOT EXISTS idx_products_page_id ON products (page_id);
+CREATE INDEX IF NOT EXISTS idx_product_details_manufacturer ON product_details (manufacturer);
+CREATE INDEX IF NOT EXISTS idx_product_details_device_type ON product_details (device_type);
+CREATE INDEX IF NOT EXISTS idx_product_details_certificate_id ON product_details (certificate_id);
+CREATE INDEX IF NOT EXISTS idx_product_details_vid ON product_details (vid);
+
+CREATE UNIQUE INDEX IF NOT EXISTS ux_products_slot
+ON products (page_id, index_in_page)
+WHERE page_id IS NOT NULL AND index_in_page IS NOT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS ux_product_details_slot
+ON product_details (page_id, index_in_page)
+WHERE page_id IS NOT NULL AND index_in_page IS NOT NULL;
@@ -1,9 +1,11 @@
+use crate::crawl_engine::runtime::crawl_limits::CrawlLimits;
 use serde::{Deserialize, Serialize};
 
 /// 크롤링 프로필 - 크롤링 모드와 설정을 담는 구조체
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CrawlingProfile {
-    /// 크롤링 모드: "intelligent", "manual", "verification"
+    /// 크롤링 모드: "intelligent", "manual", "verification", "rolling-refresh", "incremental",
+    /// "time-boxed"
     pub mode: String,
     /// 수동 모드에서 사용할 페이지 범위 (start_page, end_page)
     pub override_range: Option<(u32, u32)>,
@@ -13,6 +15,9 @@ pub struct CrawlingProfile {
     pub verification_pages: Option<Vec<u32>>,
     /// 크롤링 속도 조절 (밀리초 단위 딜레이)
     pub crawling_delay_ms: Option<u64>,
+    /// 세션 마감 시간 (초). time-boxed 모드에서 이 시간 안에 처리 가능한 만큼만 수행
+    #[serde(default)]
+    pub max_duration_secs: Option<u64>,
 }
 
 impl CrawlingProfile {
@@ -24,6 +29,7 @@ impl CrawlingProfile {
             override_config_limit: Some(true),
             verification_pages: None,
             crawling_delay_ms: None,
+            max_duration_secs: None,
         }
     }
 
@@ -35,6 +41,7 @@ impl CrawlingProfile {
             override_config_limit: None,
             verification_pages: None,
             crawling_delay_ms: None,
+            max_duration_secs: None,
         }
     }
 
@@ -46,11 +53,51 @@ impl CrawlingProfile {
             override_config_limit: None,
             verification_pages: Some(pages),
             crawling_delay_ms: None,
+            max_duration_secs: None,
+        }
+    }
+
+    /// 인증일 최신성 버킷 기반 재수집 프로필 생성 (`advanced.rolling_refresh` 설정 사용)
+    pub fn rolling_refresh() -> Self {
+        Self {
+            mode: "rolling-refresh".to_string(),
+            override_range: None,
+            override_config_limit: None,
+            verification_pages: None,
+            crawling_delay_ms: None,
+            max_duration_secs: None,
+        }
+    }
+
+    /// 증분 크롤링 프로필 생성 (DB head 이후 신규 제품만, `advanced.incremental_crawl` 설정 사용)
+    pub fn incremental() -> Self {
+        Self {
+            mode: "incremental".to_string(),
+            override_range: None,
+            override_config_limit: None,
+            verification_pages: None,
+            crawling_delay_ms: None,
+            max_duration_secs: None,
+        }
+    }
+
+    /// 마감 시간 기반 크롤링 프로필 생성 (오래된 페이지부터, `advanced.time_boxed_crawl` 설정 사용)
+    pub fn time_boxed(max_duration_secs: u64) -> Self {
+        Self {
+            mode: "time-boxed".to_string(),
+            override_range: None,
+            override_config_limit: None,
+            verification_pages: None,
+            crawling_delay_ms: None,
+            max_duration_secs: Some(max_duration_secs),
         }
     }
 
     /// 프로필 유효성 검증
     pub fn validate(&self) -> Result<(), String> {
+        if self.max_duration_secs == Some(0) {
+            return Err("max_duration_secs must be greater than 0".to_string());
+        }
         match self.mode.as_str() {
             "intelligent" => {
                 if self.override_range.is_some() {
@@ -81,6 +128,36 @@ impl CrawlingProfile {
                     Err("Verification mode requires verification_pages".to_string())
                 }
             }
+            "rolling-refresh" => {
+                if self.override_range.is_some() || self.verification_pages.is_some() {
+                    return Err(
+                        "Rolling-refresh mode selects pages itself; remove explicit pages"
+                            .to_string(),
+                    );
+                }
+                Ok(())
+            }
+            "incremental" => {
+                if self.override_range.is_some() || self.verification_pages.is_some() {
+                    return Err(
+                        "Incremental mode derives its window from the DB head; remove explicit pages"
+                            .to_string(),
+                    );
+                }
+                Ok(())
+            }
+            "time-boxed" => {
+                if self.max_duration_secs.is_none() {
+                    return Err("Time-boxed mode requires max_duration_secs".to_string());
+                }
+                if self.override_range.is_some() {
+                    return Err(
+                        "Time-boxed mode orders pages by staleness; remove override_range"
+                            .to_string(),
+                    );
+                }
+                Ok(())
+            }
             _ => Err(format!("Unknown crawling mode: {}", self.mode)),
         }
     }
@@ -121,6 +198,9 @@ pub struct CrawlingRequest {
     pub max_retries: Option<u32>,
     /// 타임아웃 (초 단위)
     pub timeout_seconds: Option<u64>,
+    /// 세션 한도 (max_new_products / max_pages / max_duration_secs); 도달 시 LimitReached 로 종료
+    #[serde(flatten)]
+    pub limits: CrawlLimits,
 }
 
 impl CrawlingRequest {
@@ -132,6 +212,7 @@ impl CrawlingRequest {
             priority: Some(5),          // 기본 우선순위
             max_retries: Some(3),       // 기본 재시도 횟수
             timeout_seconds: Some(300), // 기본 타임아웃 5분
+            limits: CrawlLimits::default(),
         }
     }
 
@@ -159,9 +240,16 @@ impl CrawlingRequest {
         self
     }
 
+    /// 세션 한도 설정
+    pub fn with_limits(mut self, limits: CrawlLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     /// 요청 유효성 검증
     pub fn validate(&self) -> Result<(), String> {
         self.profile.validate()?;
+        self.limits.validate()?;
 
         if let Some(priority) = self.priority {
             if priority < 1 || priority > 10 {
@@ -216,6 +304,43 @@ mod tests {
         assert!(invalid_profile.validate().is_err());
     }
 
+    #[test]
+    fn test_crawling_profile_rolling_refresh() {
+        let profile = CrawlingProfile::rolling_refresh();
+        assert_eq!(profile.mode, "rolling-refresh");
+        assert!(profile.validate().is_ok());
+        assert!(profile.get_page_range().is_none());
+
+        let mut invalid_profile = CrawlingProfile::rolling_refresh();
+        invalid_profile.override_range = Some((1, 5));
+        assert!(invalid_profile.validate().is_err());
+    }
+
+    #[test]
+    fn test_crawling_profile_incremental() {
+        let profile = CrawlingProfile::incremental();
+        assert_eq!(profile.mode, "incremental");
+        assert!(profile.validate().is_ok());
+        assert!(profile.get_page_range().is_none());
+
+        let mut invalid_profile = CrawlingProfile::incremental();
+        invalid_profile.verification_pages = Some(vec![1]);
+        assert!(invalid_profile.validate().is_err());
+    }
+
+    #[test]
+    fn test_crawling_profile_time_boxed() {
+        let profile = CrawlingProfile::time_boxed(600);
+        assert_eq!(profile.mode, "time-boxed");
+        assert_eq!(profile.max_duration_secs, Some(600));
+        assert!(profile.validate().is_ok());
+
+        assert!(CrawlingProfile::time_boxed(0).validate().is_err());
+        let mut invalid_profile = CrawlingProfile::time_boxed(600);
+        invalid_profile.max_duration_secs = None;
+        assert!(invalid_profile.validate().is_err());
+    }
+
     #[test]
     fn test_crawling_request() {
         let profile = CrawlingProfile::intelligent();
@@ -228,5 +353,13 @@ mod tests {
         assert_eq!(request.priority, Some(8));
         assert_eq!(request.max_retries, Some(5));
         assert!(request.validate().is_ok());
+
+        let limited = request.with_limits(CrawlLimits {
+            max_new_products: Some(50),
+            ..CrawlLimits::default()
+        });
+        assert!(limited.validate().is_ok());
+        let json = serde_json::to_value(&limited).unwrap();
+        assert_eq!(json["max_new_products"], 50);
     }
 }
@@ -8,13 +8,16 @@ use crate::domain::events::{
     ConcurrencyEvent, CrawlingEvent, CrawlingProgress, CrawlingTaskStatus, DatabaseSaveEvent,
     DatabaseStats, ValidationEvent,
 };
+use crate::infrastructure::config::{EventLogMirrorConfig, MirrorLogLevel};
 use futures::future::join_all;
-use std::sync::Arc;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tauri::{AppHandle, Emitter};
 use thiserror::Error;
 use tokio::sync::{RwLock, mpsc};
-use tracing::{debug, error, warn};
+use tracing::{debug, error, info, trace, warn};
 
 /// 이벤트 발신 관련 오류 타입
 #[derive(Debug, Error)]
@@ -34,6 +37,63 @@ pub enum EventEmissionError {
 /// 이벤트 발신 결과 타입
 pub type EventResult = Result<(), EventEmissionError>;
 
+/// 미러링된 payload를 로그에 남길 때의 최대 길이
+const MIRROR_PAYLOAD_MAX_CHARS: usize = 512;
+
+/// Mirrors emitted events into the tracing log with per-event-name sampling and severity
+pub struct EventLogMirror {
+    config: EventLogMirrorConfig,
+    /// Events seen per event name (sampling counters)
+    seen: Mutex<HashMap<String, u64>>,
+}
+
+impl EventLogMirror {
+    pub fn new(config: EventLogMirrorConfig) -> Self {
+        Self {
+            config,
+            seen: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Count one occurrence of `event_name`; returns the level to log at when sampled.
+    /// The first event of each name is always sampled, then 1 of every `sample_every`.
+    pub fn sample(&self, event_name: &str) -> Option<MirrorLogLevel> {
+        let rule = self.config.rule_for(event_name);
+        if rule.sample_every == 0 {
+            return None;
+        }
+        let mut seen = self.seen.lock().unwrap_or_else(|p| p.into_inner());
+        let count = seen.entry(event_name.to_string()).or_insert(0);
+        let sampled = *count % u64::from(rule.sample_every) == 0;
+        *count += 1;
+        sampled.then_some(rule.level)
+    }
+
+    /// Log `payload` for `event_name` if this occurrence is sampled
+    pub fn mirror<T: Serialize + ?Sized>(&self, event_name: &str, payload: &T) {
+        let Some(level) = self.sample(event_name) else {
+            return;
+        };
+        let mut body = serde_json::to_string(payload)
+            .unwrap_or_else(|e| format!("<unserializable payload: {e}>"));
+        if body.len() > MIRROR_PAYLOAD_MAX_CHARS {
+            let mut cut = MIRROR_PAYLOAD_MAX_CHARS;
+            while !body.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            body.truncate(cut);
+            body.push('…');
+        }
+        match level {
+            MirrorLogLevel::Trace => trace!(target: "event_mirror", event = event_name, "{}", body),
+            MirrorLogLevel::Debug => debug!(target: "event_mirror", event = event_name, "{}", body),
+            MirrorLogLevel::Info => info!(target: "event_mirror", event = event_name, "{}", body),
+            MirrorLogLevel::Warn => warn!(target: "event_mirror", event = event_name, "{}", body),
+            MirrorLogLevel::Error => error!(target: "event_mirror", event = event_name, "{}", body),
+        }
+    }
+}
+
 /// Event emitter for sending real-time updates to the frontend
 #[derive(Clone)]
 pub struct EventEmitter {
@@ -42,6 +102,8 @@ pub struct EventEmitter {
     enabled: Arc<RwLock<bool>>,
     /// Event queue for batched emissions
     event_sender: Option<mpsc::Sender<CrawlingEvent>>,
+    /// Optional event → log mirroring (sampled)
+    log_mirror: Option<Arc<EventLogMirror>>,
 }
 
 impl EventEmitter {
@@ -51,6 +113,7 @@ impl EventEmitter {
             app_handle,
             enabled: Arc::new(RwLock::new(true)),
             event_sender: None,
+            log_mirror: None,
         }
     }
 
@@ -62,6 +125,7 @@ impl EventEmitter {
             app_handle: app_handle.clone(),
             enabled: Arc::new(RwLock::new(true)),
             event_sender: Some(tx),
+            log_mirror: None,
         };
 
         // 백그라운드 태스크로 이벤트 배치 처리
@@ -105,6 +169,24 @@ impl EventEmitter {
         emitter
     }
 
+    /// Attach event → log mirroring (no-op when `config.enabled` is false)
+    pub fn with_log_mirror(mut self, config: EventLogMirrorConfig) -> Self {
+        self.log_mirror = config
+            .enabled
+            .then(|| Arc::new(EventLogMirror::new(config)));
+        self
+    }
+
+    /// Mirror an outgoing event into the log if mirroring is configured
+    /// (and to SSE subscribers in `event-stream` builds)
+    fn mirror_to_log<T: Serialize + ?Sized>(&self, event_name: &str, payload: &T) {
+        #[cfg(feature = "event-stream")]
+        crate::infrastructure::event_stream::publish(event_name, payload);
+        if let Some(mirror) = &self.log_mirror {
+            mirror.mirror(event_name, payload);
+        }
+    }
+
     /// Enable or disable event emission
     pub async fn set_enabled(&self, enabled: bool) {
         let mut enabled_guard = self.enabled.write().await;
@@ -127,6 +209,8 @@ impl EventEmitter {
             return Err(EventEmissionError::Disabled);
         }
 
+        self.mirror_to_log(event.event_name(), &event);
+
         // 배치 모드인 경우 이벤트 큐에 추가
         if let Some(sender) = &self.event_sender {
             return sender
@@ -215,6 +299,7 @@ impl EventEmitter {
         }
 
         let event_name = "detailed-crawling-event";
+        self.mirror_to_log(event_name, &detailed_event);
 
         match self.app_handle.emit(event_name, &detailed_event) {
             Ok(_) => {
@@ -245,6 +330,7 @@ impl EventEmitter {
         }
 
         let event_name = "detailed-crawling-event";
+        self.mirror_to_log(event_name, &json_payload);
 
         match self.app_handle.emit(event_name, &json_payload) {
             Ok(_) => {
@@ -276,6 +362,7 @@ impl EventEmitter {
         }
 
         let event_name = AtomicTaskEvent::event_name();
+        self.mirror_to_log(event_name, &event);
 
         match self.app_handle.emit(event_name, &event) {
             Ok(_) => {
@@ -368,6 +455,7 @@ impl EventEmitter {
             return Err(EventEmissionError::Disabled);
         }
         let event_name = event.event_name();
+        self.mirror_to_log(event_name, &event);
         match self.app_handle.emit(event_name, &event) {
             Ok(_) => Ok(()),
             Err(e) => Err(EventEmissionError::TauriError(e)),
@@ -380,6 +468,7 @@ impl EventEmitter {
             return Err(EventEmissionError::Disabled);
         }
         let event_name = event.event_name();
+        self.mirror_to_log(event_name, &event);
         match self.app_handle.emit(event_name, &event) {
             Ok(_) => Ok(()),
             Err(e) => Err(EventEmissionError::TauriError(e)),
@@ -392,6 +481,7 @@ impl EventEmitter {
             return Err(EventEmissionError::Disabled);
         }
         let event_name = event.event_name();
+        self.mirror_to_log(event_name, &event);
         match self.app_handle.emit(event_name, &event) {
             Ok(_) => Ok(()),
             Err(e) => Err(EventEmissionError::TauriError(e)),
@@ -407,6 +497,7 @@ pub struct EventEmitterBuilder {
     enable_batching: bool,
     batch_size: usize,
     batch_interval_ms: u64,
+    log_mirror: Option<EventLogMirrorConfig>,
 }
 
 impl EventEmitterBuilder {
@@ -418,6 +509,7 @@ impl EventEmitterBuilder {
             enable_batching: false,
             batch_size: 10,
             batch_interval_ms: 100,
+            log_mirror: None,
         }
     }
 
@@ -441,6 +533,12 @@ impl EventEmitterBuilder {
         self
     }
 
+    /// Mirror emitted events into the tracing log
+    pub fn with_log_mirror(mut self, config: EventLogMirrorConfig) -> Self {
+        self.log_mirror = Some(config);
+        self
+    }
+
     /// Build the event emitter
     pub async fn build(self) -> Result<EventEmitter, String> {
         let app_handle = self.app_handle.ok_or("App handle is required")?;
@@ -450,6 +548,10 @@ impl EventEmitterBuilder {
         } else {
             EventEmitter::new(app_handle)
         };
+        let emitter = match self.log_mirror {
+            Some(config) => emitter.with_log_mirror(config),
+            None => emitter,
+        };
 
         emitter.set_enabled(self.enabled).await;
 
@@ -502,4 +604,35 @@ mod tests {
         assert!(serialization_msg.contains("test error"));
         assert!(emission_msg.contains("test emission error"));
     }
+
+    #[test]
+    fn test_event_log_mirror_sampling_per_event_name() {
+        let mirror = EventLogMirror::new(EventLogMirrorConfig {
+            default_sample_every: 3,
+            ..Default::default()
+        });
+
+        // crawling-error: 기본 규칙상 매번 WARN으로 기록
+        assert_eq!(mirror.sample("crawling-error"), Some(MirrorLogLevel::Warn));
+        assert_eq!(mirror.sample("crawling-error"), Some(MirrorLogLevel::Warn));
+
+        // 규칙 없는 이벤트: 3개 중 1개만 기본 레벨로 기록
+        let sampled: Vec<_> = (0..6).map(|_| mirror.sample("batch-event")).collect();
+        assert_eq!(sampled.iter().filter(|s| s.is_some()).count(), 2);
+        assert_eq!(sampled[0], Some(MirrorLogLevel::Info));
+    }
+
+    #[test]
+    fn test_event_log_mirror_zero_rate_never_logs() {
+        let mut config = EventLogMirrorConfig::default();
+        config.rules.insert(
+            "crawling-progress".to_string(),
+            crate::infrastructure::config::EventMirrorRule {
+                sample_every: 0,
+                level: MirrorLogLevel::Info,
+            },
+        );
+        let mirror = EventLogMirror::new(config);
+        assert!((0..10).all(|_| mirror.sample("crawling-progress").is_none()));
+    }
 }
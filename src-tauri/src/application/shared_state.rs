@@ -6,6 +6,7 @@ use tokio::sync::RwLock;
 use tokio::sync::Mutex as AsyncMutex;
 
 use crate::application::validated_crawling_config::ValidatedCrawlingConfig;
+use crate::crawl_engine::runtime::sync_cancellation::SyncCancellationRegistry;
 use crate::infrastructure::config::AppConfig;
 
 /// TTL 기반 캐시 항목을 위한 트레이트
@@ -305,6 +306,8 @@ pub struct SharedStateCache {
         Arc<RwLock<Vec<(String, crate::crawl_engine::actors::types::ExecutionPlan)>>>,
     /// 사이트 상태 새로고침 단일-flight 보장용 락
     site_status_refresh_lock: Arc<AsyncMutex<()>>,
+    /// 실행 중인 싱크 세션 취소 토큰 (싱크 명령과 같은 전역 레지스트리)
+    pub sync_cancellations: SyncCancellationRegistry,
 }
 
 impl Default for SharedStateCache {
@@ -327,6 +330,7 @@ impl SharedStateCache {
             created_at: Instant::now(),
             execution_plan_cache: Arc::new(RwLock::new(Vec::with_capacity(5))),
             site_status_refresh_lock: Arc::new(AsyncMutex::new(())),
+            sync_cancellations: SyncCancellationRegistry::shared(),
         }
     }
 
@@ -345,6 +349,7 @@ impl SharedStateCache {
             created_at: Instant::now(),
             execution_plan_cache: Arc::new(RwLock::new(Vec::with_capacity(5))),
             site_status_refresh_lock: Arc::new(AsyncMutex::new(())),
+            sync_cancellations: SyncCancellationRegistry::shared(),
         }
     }
 
@@ -375,6 +380,19 @@ impl SharedStateCache {
             .map(|(_, p)| p.clone())
     }
 
+    /// plan_hash / plan_id / session_id 중 하나로 ExecutionPlan 조회
+    pub async fn find_cached_execution_plan(
+        &self,
+        id: &str,
+    ) -> Option<crate::crawl_engine::actors::types::ExecutionPlan> {
+        let guard = self.execution_plan_cache.read().await;
+        guard
+            .iter()
+            .map(|(_, p)| p)
+            .find(|p| p.plan_hash == id || p.plan_id == id || p.session_id == id)
+            .cloned()
+    }
+
     /// 현재 검증된 설정 가져오기
     pub async fn get_validated_config(&self) -> ValidatedCrawlingConfig {
         self.validated_config.read().await.clone()
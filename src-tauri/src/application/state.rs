@@ -51,6 +51,7 @@ pub struct AppState {
 impl AppState {
     /// Create a new application state
     pub fn new(config: crate::infrastructure::config::AppConfig) -> Self {
+        Self::apply_global_config(&config);
         Self {
             event_emitter: Arc::new(RwLock::new(None)),
             database_pool: Arc::new(RwLock::new(None)),
@@ -65,6 +66,31 @@ impl AppState {
         }
     }
 
+    /// Push config into the process-wide runtime settings read outside `AppState`
+    fn apply_global_config(config: &crate::infrastructure::config::AppConfig) {
+        crate::infrastructure::coordinate_guard::set_strict_mode(
+            config.advanced.strict_coordinate_mode,
+        );
+        crate::infrastructure::coordinate_guard::set_products_per_page(
+            crate::infrastructure::site_profiles::resolve_site_profile(config).products_per_page(),
+        );
+        crate::infrastructure::host_profile::set_current(config.app_managed.host_profile.clone());
+        crate::infrastructure::database_connection::set_sqlite_settings(
+            config.advanced.sqlite.clone(),
+        );
+        // The queue picks up the memory budget when configured, so the budget goes first
+        crate::infrastructure::memory_budget::configure(&config.advanced.memory_budget);
+        crate::infrastructure::persistence_queue::configure(&config.advanced.persistence_queue);
+        crate::infrastructure::circuit_breaker::configure(&config.advanced.circuit_breaker);
+        crate::infrastructure::config::utils::set_url_templates(&config.advanced.url_templates);
+        crate::infrastructure::product_identity::configure(&config.advanced.product_identity);
+        #[cfg(any(test, feature = "mock-site"))]
+        crate::infrastructure::mock_http_client::configure(
+            &config.advanced.mock_site,
+            &config.advanced.url_templates,
+        );
+    }
+
     /// Initialize the shared database connection pool (Modern Rust 2024 - Backend-Only CRUD)
     pub async fn initialize_database_pool(&self) -> Result<(), String> {
         let pool = crate::infrastructure::database_connection::get_or_init_global_pool()
@@ -300,6 +326,7 @@ impl AppState {
         &self,
         config: crate::infrastructure::config::AppConfig,
     ) -> Result<(), String> {
+        Self::apply_global_config(&config);
         let mut config_guard = self.config.write().await;
         *config_guard = config;
         debug!("Application configuration updated");
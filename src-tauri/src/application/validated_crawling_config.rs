@@ -71,6 +71,11 @@ impl ValidatedCrawlingConfig {
         self.batch_size
     }
 
+    /// Batch size chosen by `batch_tuning` for the next session
+    pub fn apply_batch_size(&mut self, batch_size: u32) {
+        self.batch_size = batch_size.max(1);
+    }
+
     pub fn max_concurrent(&self) -> u32 {
         self.max_concurrent_requests
     }
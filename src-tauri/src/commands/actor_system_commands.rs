@@ -2,16 +2,29 @@
 //!
 //! Commands to test and use the Actor system from the UI
 
+use crate::application::validated_crawling_config::ValidatedCrawlingConfig;
 use crate::application::{AppState, shared_state::SharedStateCache};
-use crate::crawl_engine::actor_event_bridge::start_actor_event_bridge;
+use crate::crawl_engine::actor_event_bridge::{
+    EventBridgeStats, event_bridge_stats, start_actor_event_bridge,
+};
 use crate::crawl_engine::actors::SessionActor;
 use crate::crawl_engine::actors::contract::ACTOR_CONTRACT_VERSION;
+use crate::crawl_engine::actors::session_actor::{
+    CheckpointStatus, SessionCheckpoint, load_session_checkpoint, save_session_checkpoint,
+};
 use crate::crawl_engine::actors::types::{
-    BatchConfig, CrawlPhase, CrawlingConfig, ExecutionPlan, PageRange, SessionSummary,
+    BatchConfig, CompletionReason, CrawlPhase, CrawlingConfig, ExecutionPlan, PageRange,
+    SessionSummary,
 };
 use crate::crawl_engine::channels::types::ActorCommand; // 올바른 ActorCommand 사용
 use crate::crawl_engine::channels::types::AppEvent;
 use crate::crawl_engine::context::{AppContext, SystemConfig};
+use crate::crawl_engine::runtime::crawl_limits::{self, CrawlLimitTracker, CrawlLimits};
+use crate::crawl_engine::runtime::task_registry::{
+    BackgroundTaskInfo, SESSION_TASK_LIFETIME, spawn_tracked, task_registry,
+};
+use crate::crawl_engine::services::session_report::spawn_session_report_task;
+use crate::crawl_engine::stage_type::{BATCH_PIPELINE, StageSelection, StageSkipFlag, StageType};
 use crate::domain::services::SiteStatus;
 use crate::domain::services::crawling_services::{
     CrawlingRangeRecommendation, SiteDataChangeStatus, SiteStatus as DomainSiteStatus,
@@ -20,6 +33,11 @@ use crate::infrastructure::config::AppConfig;
 use crate::infrastructure::html_parser::MatterDataExtractor;
 use crate::infrastructure::integrated_product_repository::IntegratedProductRepository;
 use crate::infrastructure::simple_http_client::HttpClient;
+use crate::services::batch_tuning;
+use crate::services::session_export::{
+    SessionExportRequest, close_session_export, has_session_export, open_session_export,
+    spawn_session_export_closer,
+};
 use tauri::State; // For accessing managed state
 // 실제 CrawlingPlanner에서 사용
 use crate::crawl_engine::runtime::session_registry::{
@@ -53,6 +71,32 @@ fn compute_plan_hash(
     blake3::hash(hash_string.as_bytes()).to_hex().to_string()
 }
 
+// ========== Session Checkpoint Helper ==========
+/// 배치 결과를 체크포인트에 반영하고 저장 (실패해도 크롤링은 계속)
+async fn persist_batch_checkpoint(
+    pool: Option<&sqlx::SqlitePool>,
+    checkpoint: &mut SessionCheckpoint,
+    pages: &[u32],
+    failed: bool,
+) {
+    checkpoint.record_batch(pages, failed);
+    {
+        let registry = session_registry();
+        let g = registry.read().await;
+        if let Some(entry) = g.get(&checkpoint.session_id) {
+            checkpoint.retries_per_page = entry.retries_per_page.clone();
+        }
+    }
+    if let Some(pool) = pool {
+        if let Err(e) = save_session_checkpoint(pool, checkpoint).await {
+            warn!(
+                "[Checkpoint] save failed session_id={} err={}",
+                checkpoint.session_id, e
+            );
+        }
+    }
+}
+
 // ========== Error Classification ==========
 fn classify_error_type(err: &str) -> String {
     let e = err.to_lowercase();
@@ -96,6 +140,15 @@ pub struct ActorCrawlingRequest {
     pub batch_size: Option<u32>,
     pub delay_ms: Option<u64>,
     pub mode: Option<CrawlingMode>,
+    /// Stream products saved by this session to a CSV/NDJSON file
+    #[serde(default)]
+    pub export: Option<SessionExportRequest>,
+    /// Batch stages to skip (skip_validation / skip_status_check / list_only)
+    #[serde(default)]
+    pub stage_flags: Vec<StageSkipFlag>,
+    /// Stop early after N new products / pages / seconds (`LimitReached`)
+    #[serde(flatten)]
+    pub limits: CrawlLimits,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -117,12 +170,18 @@ async fn bootstrap_and_spawn_session(
     failed_pages: Option<Vec<u32>>,
     retrying_pages: Option<Vec<u32>>,
 ) -> Result<(String, ExecutionPlan), String> {
-    let (actor_event_tx, actor_event_rx) = broadcast::channel::<AppEvent>(1000);
+    let capacities = SystemConfig::channel_capacities();
+    let (actor_event_tx, actor_event_rx) =
+        broadcast::channel::<AppEvent>(capacities.session_events);
     start_actor_event_bridge(app.clone(), actor_event_rx)
         .await
         .map_err(|e| format!("Failed to start Actor Event Bridge: {}", e))?;
     let _session_actor = SessionActor::new(execution_plan.session_id.clone());
     let session_id = execution_plan.session_id.clone();
+    spawn_session_report_task(session_id.clone(), actor_event_tx.clone());
+    if has_session_export(&session_id) {
+        spawn_session_export_closer(session_id.clone(), actor_event_tx.clone());
+    }
     let (shutdown_req_tx, shutdown_req_rx) = watch::channel(false);
     let (pause_tx, pause_rx) = watch::channel(false);
     let _ = PHASE_SHUTDOWN_TX.set(shutdown_req_tx.clone());
@@ -183,145 +242,154 @@ async fn bootstrap_and_spawn_session(
     let site_status_for_loop = site_status.clone();
     let registry_for_loop = session_registry();
     let pause_rx_for_loop = pause_rx.clone();
-    tokio::spawn(async move {
-        // Feature flag: ProductDetails phase 포함 여부
-        let details_enabled = std::env::var("BOOTSTRAP_PRODUCT_DETAILS")
-            .ok()
-            .map(|v| v != "0")
-            .unwrap_or(true);
-        let mut phases = vec![CrawlPhase::ListPages];
-        if details_enabled {
-            phases.push(CrawlPhase::ProductDetails);
-        }
-        phases.push(CrawlPhase::Finalize);
-        let total_phase_start = std::time::Instant::now();
-        for phase in phases {
-            let mut emitted_pause_event = false;
-            loop {
-                if *shutdown_req_rx.borrow() {
-                    break;
-                }
-                if *pause_rx_for_loop.borrow() {
-                    if !emitted_pause_event {
-                        info!("⏸ Session paused (phase {:?})", phase);
-                        emitted_pause_event = true;
+    spawn_tracked(
+        format!("session-driver:{}", session_id),
+        Some(SESSION_TASK_LIFETIME),
+        async move {
+            // Feature flag: ProductDetails phase 포함 여부
+            let details_enabled = std::env::var("BOOTSTRAP_PRODUCT_DETAILS")
+                .ok()
+                .map(|v| v != "0")
+                .unwrap_or(true);
+            let mut phases = vec![CrawlPhase::ListPages];
+            if details_enabled {
+                phases.push(CrawlPhase::ProductDetails);
+            }
+            phases.push(CrawlPhase::Finalize);
+            let total_phase_start = std::time::Instant::now();
+            for phase in phases {
+                let mut emitted_pause_event = false;
+                loop {
+                    if *shutdown_req_rx.borrow() {
+                        break;
                     }
-                    {
-                        let mut g = registry_for_loop.write().await;
-                        if let Some(e) = g.get_mut(&exec_clone_for_loop.session_id) {
-                            e.status = SessionStatus::Paused;
+                    if *pause_rx_for_loop.borrow() {
+                        if !emitted_pause_event {
+                            info!("⏸ Session paused (phase {:?})", phase);
+                            emitted_pause_event = true;
                         }
+                        {
+                            let mut g = registry_for_loop.write().await;
+                            if let Some(e) = g.get_mut(&exec_clone_for_loop.session_id) {
+                                e.status = SessionStatus::Paused;
+                            }
+                        }
+                        tokio::time::sleep(Duration::from_millis(250)).await;
+                        continue;
+                    } else {
+                        if emitted_pause_event {
+                            info!("▶️ Session resumed");
+                        }
+                        break;
                     }
-                    tokio::time::sleep(Duration::from_millis(250)).await;
-                    continue;
-                } else {
-                    if emitted_pause_event {
-                        info!("▶️ Session resumed");
-                    }
-                    break;
-                }
-            }
-            if *shutdown_req_rx.borrow() {
-                let _ = actor_event_tx.send(AppEvent::PhaseAborted {
-                    session_id: exec_clone_for_loop.session_id.clone(),
-                    phase: phase.clone(),
-                    reason: "shutdown_requested".into(),
-                    timestamp: Utc::now(),
-                });
-                break;
-            }
-            let phase_started_at = std::time::Instant::now();
-            let _ = actor_event_tx.send(AppEvent::PhaseStarted {
-                session_id: exec_clone_for_loop.session_id.clone(),
-                phase: phase.clone(),
-                timestamp: Utc::now(),
-            });
-            let phase_res = match phase {
-                CrawlPhase::ListPages => execute_session_actor_with_execution_plan(
-                    exec_clone_for_loop.clone(),
-                    &app_cfg_for_loop,
-                    &site_status_for_loop,
-                    actor_event_tx.clone(),
-                )
-                .await
-                .map(|_| true),
-                CrawlPhase::ProductDetails => Ok(true),
-                CrawlPhase::DataValidation => Ok(true),
-                CrawlPhase::Finalize => Ok(true),
-            };
-            let dur_ms = phase_started_at.elapsed().as_millis() as u64;
-            match phase_res {
-                Ok(ok) => {
-                    let _ = actor_event_tx.send(AppEvent::PhaseCompleted {
-                        session_id: exec_clone_for_loop.session_id.clone(),
-                        phase: phase.clone(),
-                        succeeded: ok,
-                        duration_ms: dur_ms,
-                        timestamp: Utc::now(),
-                    });
                 }
-                Err(e) => {
-                    error!("Phase {:?} failed: {}", phase, e);
+                if *shutdown_req_rx.borrow() {
                     let _ = actor_event_tx.send(AppEvent::PhaseAborted {
                         session_id: exec_clone_for_loop.session_id.clone(),
                         phase: phase.clone(),
-                        reason: format!("{}", e),
+                        reason: "shutdown_requested".into(),
                         timestamp: Utc::now(),
                     });
                     break;
                 }
+                let phase_started_at = std::time::Instant::now();
+                let _ = actor_event_tx.send(AppEvent::PhaseStarted {
+                    session_id: exec_clone_for_loop.session_id.clone(),
+                    phase: phase.clone(),
+                    timestamp: Utc::now(),
+                });
+                let phase_res = match phase {
+                    CrawlPhase::ListPages => execute_session_actor_with_execution_plan(
+                        exec_clone_for_loop.clone(),
+                        &app_cfg_for_loop,
+                        &site_status_for_loop,
+                        actor_event_tx.clone(),
+                    )
+                    .await
+                    .map(|_| true),
+                    CrawlPhase::ProductDetails => Ok(true),
+                    CrawlPhase::DataValidation => Ok(true),
+                    CrawlPhase::Finalize => Ok(true),
+                };
+                let dur_ms = phase_started_at.elapsed().as_millis() as u64;
+                match phase_res {
+                    Ok(ok) => {
+                        let _ = actor_event_tx.send(AppEvent::PhaseCompleted {
+                            session_id: exec_clone_for_loop.session_id.clone(),
+                            phase: phase.clone(),
+                            succeeded: ok,
+                            duration_ms: dur_ms,
+                            timestamp: Utc::now(),
+                        });
+                    }
+                    Err(e) => {
+                        error!("Phase {:?} failed: {}", phase, e);
+                        let _ = actor_event_tx.send(AppEvent::PhaseAborted {
+                            session_id: exec_clone_for_loop.session_id.clone(),
+                            phase: phase.clone(),
+                            reason: format!("{}", e),
+                            timestamp: Utc::now(),
+                        });
+                        break;
+                    }
+                }
             }
-        }
-        info!(
-            "🎉 Session phases finished in {} ms",
-            total_phase_start.elapsed().as_millis()
-        );
-        {
-            let mut g = registry_for_loop.write().await;
-            if let Some(entry) = g.get_mut(&exec_clone_for_loop.session_id) {
-                if entry.status != SessionStatus::Failed {
-                    entry.status = SessionStatus::Completed;
-                    entry.completed_at = Some(Utc::now());
-                    entry.resume_token = None;
+            info!(
+                "🎉 Session phases finished in {} ms",
+                total_phase_start.elapsed().as_millis()
+            );
+            {
+                let mut g = registry_for_loop.write().await;
+                if let Some(entry) = g.get_mut(&exec_clone_for_loop.session_id) {
+                    if entry.status != SessionStatus::Failed {
+                        entry.status = SessionStatus::Completed;
+                        entry.completed_at = Some(Utc::now());
+                        entry.resume_token = None;
+                    }
                 }
             }
-        }
-    });
+            let leftover =
+                crate::crawl_engine::runtime::write_coalescer::release_session_write_coalescer(
+                    &exec_clone_for_loop.session_id,
+                );
+            if leftover > 0 {
+                warn!(
+                    "⚠️ Session {} ended with {} unflushed coalesced writes (dropped)",
+                    exec_clone_for_loop.session_id, leftover
+                );
+            }
+        },
+    );
     Ok((session_id, execution_plan))
 }
 
-/// Public command: start actor system crawling (refactored to use bootstrap helper)
-#[tauri::command]
-pub async fn start_actor_system_crawling(
-    app: AppHandle,
-    request: ActorCrawlingRequest,
-) -> Result<ActorSystemResponse, String> {
-    // 1. Intelligent planner 기반 ExecutionPlan 생성
-    let (mut execution_plan, mut app_config, _domain_site_status) = create_execution_plan(&app)
-        .await
-        .map_err(|e| format!("failed to create execution plan: {}", e))?;
-
-    // 2. 사용자가 ActorCrawlingRequest 로 override 한 값 적용 (옵션)
+/// ActorCrawlingRequest 의 override (batch/concurrency/delay/page 범위/한도)를 ExecutionPlan 에 적용
+pub(crate) fn apply_request_overrides(
+    plan: &mut ExecutionPlan,
+    app_config: &mut AppConfig,
+    request: &ActorCrawlingRequest,
+) {
     //    - batch_size / concurrency / (지연은 추후 Phase 구현에서 사용)
     if let Some(override_batch) = request.batch_size {
         if override_batch > 0 {
-            execution_plan.batch_size = override_batch;
+            plan.batch_size = override_batch;
         }
     }
     if let Some(override_conc) = request.concurrency {
         if override_conc > 0 {
-            execution_plan.concurrency_limit = override_conc;
+            plan.concurrency_limit = override_conc;
         }
     }
     if let Some(delay_ms) = request.delay_ms {
         app_config.user.request_delay_ms = delay_ms;
     }
+    plan.limits = request.limits;
 
     // KPI 메타 갱신 (override 적용 후 batch_size 변경 시 반영)
-    if let Some(ref mut kpi) = execution_plan.kpi_meta {
-        kpi.batches = execution_plan.crawling_ranges.len();
+    if let Some(ref mut kpi) = plan.kpi_meta {
+        kpi.batches = plan.crawling_ranges.len();
         // total_pages 재계산
-        let total_pages: u32 = execution_plan
+        let total_pages: u32 = plan
             .crawling_ranges
             .iter()
             .map(|r| {
@@ -337,13 +405,38 @@ pub async fn start_actor_system_crawling(
 
     // (NEW) 2b. 사용자가 start_page/end_page/page_count 로 범위를 제한하려는 경우 ExecutionPlan 조정
     if request.start_page.is_some() || request.end_page.is_some() || request.page_count.is_some() {
-        if let Err(e) = adjust_execution_plan_with_page_overrides(&mut execution_plan, &request) {
+        if let Err(e) = adjust_execution_plan_with_page_overrides(plan, request) {
             warn!(
                 "⚠️ Failed to apply page overrides: {} (continuing with original plan)",
                 e
             );
         }
     }
+}
+
+/// Public command: start actor system crawling (refactored to use bootstrap helper)
+#[tauri::command]
+pub async fn start_actor_system_crawling(
+    app: AppHandle,
+    request: ActorCrawlingRequest,
+) -> Result<ActorSystemResponse, String> {
+    // 0. 스테이지 스킵 플래그를 파이프라인 정의에 비추어 검증
+    let stage_selection = StageSelection::from_flags(&request.stage_flags)
+        .map_err(|e| format!("invalid stage flags: {}", e))?;
+    request
+        .limits
+        .validate()
+        .map_err(|e| format!("invalid crawl limits: {}", e))?;
+
+    // 1. Intelligent planner 기반 ExecutionPlan 생성
+    let (mut execution_plan, mut app_config, _domain_site_status) =
+        create_execution_plan_with_flags(&app, stage_selection.flags())
+            .await
+            .map_err(|e| format!("failed to create execution plan: {}", e))?;
+    execution_plan.stage_flags = stage_selection.flags().to_vec();
+
+    // 2. 사용자가 ActorCrawlingRequest 로 override 한 값 적용 (옵션)
+    apply_request_overrides(&mut execution_plan, &mut app_config, &request);
 
     // 3. CrawlingMode 별 로깅/전략 태그 (현재는 정보성)
     if let Some(mode) = &request.mode {
@@ -359,10 +452,26 @@ pub async fn start_actor_system_crawling(
     if !details_enabled {
         info!("🔧 ProductDetails phase disabled via BOOTSTRAP_PRODUCT_DETAILS=0");
     }
+    if !stage_selection.flags().is_empty() {
+        info!(
+            "⏭️ Stage flags {:?}: skipping {:?}",
+            stage_selection.flags(),
+            BATCH_PIPELINE
+                .iter()
+                .filter(|s| !stage_selection.runs(s))
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    // 5. (옵션) 세션 내보내기 싱크: 세션 시작 전에 파일을 만들어 첫 저장부터 기록
+    if let Some(export) = &request.export {
+        open_session_export(&execution_plan.session_id, export)?;
+    }
 
-    // 5. SiteStatus 파생
+    // 6. SiteStatus 파생
     let site_status = execution_plan.input_snapshot_to_site_status();
-    let (sid, exec_clone) = bootstrap_and_spawn_session(
+    let (sid, exec_clone) = match bootstrap_and_spawn_session(
         &app,
         execution_plan.clone(),
         app_config.clone(),
@@ -372,12 +481,19 @@ pub async fn start_actor_system_crawling(
         None,
         None,
     )
-    .await?;
+    .await
+    {
+        Ok(started) => started,
+        Err(e) => {
+            close_session_export(&execution_plan.session_id);
+            return Err(e);
+        }
+    };
     Ok(ActorSystemResponse {
         success: true,
         message: format!(
             "Actor system crawling started (details_phase={})",
-            details_enabled
+            details_enabled && stage_selection.runs(&StageType::ProductDetailCrawling)
         ),
         session_id: Some(sid),
         data: Some(serde_json::to_value(&exec_clone).map_err(|e| e.to_string())?),
@@ -467,6 +583,18 @@ pub async fn resume_session(
     }
 }
 
+/// Actor 이벤트 브릿지 대기열/드롭 통계 조회
+#[tauri::command]
+pub async fn get_event_bridge_stats() -> Result<EventBridgeStats, String> {
+    Ok(event_bridge_stats())
+}
+
+/// 추적 중인 백그라운드 태스크 (실행 중 → 최근 종료 순, 수명 초과 여부 포함)
+#[tauri::command]
+pub async fn list_background_tasks() -> Result<Vec<BackgroundTaskInfo>, String> {
+    Ok(task_registry().list())
+}
+
 /// 현재 레지스트리에 존재하는 세션 ID 목록 (신규 -> 오래된 순 정렬)
 #[tauri::command]
 pub async fn list_actor_sessions(_app: AppHandle) -> Result<ActorSystemResponse, String> {
@@ -913,17 +1041,22 @@ pub async fn resume_from_token(
         },
         plan_hash: plan_hash.clone(),
         skip_duplicate_urls: false,
+        stage_flags: Vec::new(),
+        limits: CrawlLimits::default(),
         kpi_meta: None,
         contract_version: ACTOR_CONTRACT_VERSION,
         page_slots,
     };
     // 3. 기존 start_actor_system_crawling 과 동일한 실행 경로 재사용 위해 내부 함수 추출이 이상적이나 현재는 임시 direct 실행
     // 재사용을 위해 start_actor_system_crawling 의 주요 블록을 축약하여 삽입 (중복: Phase3 리팩토링 항목)
-    let (actor_event_tx, actor_event_rx) = broadcast::channel::<AppEvent>(1000);
+    let capacities = SystemConfig::channel_capacities();
+    let (actor_event_tx, actor_event_rx) =
+        broadcast::channel::<AppEvent>(capacities.session_events);
     let _bridge_handle = start_actor_event_bridge(app.clone(), actor_event_rx)
         .await
         .map_err(|e| format!("Failed to start Actor Event Bridge: {}", e))?;
     let _session_actor = SessionActor::new(new_session_id.clone());
+    spawn_session_report_task(new_session_id.clone(), actor_event_tx.clone());
     let (shutdown_req_tx, _shutdown_req_rx) = watch::channel(false);
     let (pause_tx, _pause_rx) = watch::channel(false);
     let _ = PHASE_SHUTDOWN_TX.set(shutdown_req_tx.clone());
@@ -1000,51 +1133,55 @@ pub async fn resume_from_token(
     }
     // 실행 태스크 spawn
     let exec_clone = execution_plan.clone();
-    tokio::spawn(async move {
-        // plan_hash 무결성 재검증 (v1 간단: page_slots + crawling_ranges 직렬화 후 해시 비교)
-        let integrity_serialized = serde_json::json!({
-            "ranges": exec_clone.crawling_ranges,
-            "slots": exec_clone.page_slots,
-            "batch_size": exec_clone.batch_size,
-            "concurrency": exec_clone.concurrency_limit,
-        })
-        .to_string();
-        let recomputed = blake3::hash(integrity_serialized.as_bytes())
-            .to_hex()
+    spawn_tracked(
+        format!("session-driver:{}", new_session_id),
+        Some(SESSION_TASK_LIFETIME),
+        async move {
+            // plan_hash 무결성 재검증 (v1 간단: page_slots + crawling_ranges 직렬화 후 해시 비교)
+            let integrity_serialized = serde_json::json!({
+                "ranges": exec_clone.crawling_ranges,
+                "slots": exec_clone.page_slots,
+                "batch_size": exec_clone.batch_size,
+                "concurrency": exec_clone.concurrency_limit,
+            })
             .to_string();
-        if recomputed != exec_clone.plan_hash {
-            error!(
-                "resume plan hash mismatch: token={} recomputed={}",
-                exec_clone.plan_hash, recomputed
-            );
-            let _ = actor_event_tx.send(AppEvent::SessionFailed {
-                session_id: exec_clone.session_id.clone(),
-                error: "plan_hash_mismatch".into(),
-                final_failure: true,
-                timestamp: Utc::now(),
-            });
-            // 레지스트리 상태 Failed 반영
-            {
-                let registry = session_registry();
-                let mut g = registry.write().await;
-                if let Some(entry) = g.get_mut(&exec_clone.session_id) {
-                    entry.status = SessionStatus::Failed;
-                    entry.last_error = Some("plan_hash_mismatch".into());
+            let recomputed = blake3::hash(integrity_serialized.as_bytes())
+                .to_hex()
+                .to_string();
+            if recomputed != exec_clone.plan_hash {
+                error!(
+                    "resume plan hash mismatch: token={} recomputed={}",
+                    exec_clone.plan_hash, recomputed
+                );
+                let _ = actor_event_tx.send(AppEvent::SessionFailed {
+                    session_id: exec_clone.session_id.clone(),
+                    error: "plan_hash_mismatch".into(),
+                    final_failure: true,
+                    timestamp: Utc::now(),
+                });
+                // 레지스트리 상태 Failed 반영
+                {
+                    let registry = session_registry();
+                    let mut g = registry.write().await;
+                    if let Some(entry) = g.get_mut(&exec_clone.session_id) {
+                        entry.status = SessionStatus::Failed;
+                        entry.last_error = Some("plan_hash_mismatch".into());
+                    }
                 }
+                return;
             }
-            return;
-        }
-        if let Err(e) = execute_session_actor_with_execution_plan(
-            exec_clone,
-            &app_config,
-            &site_status,
-            actor_event_tx.clone(),
-        )
-        .await
-        {
-            error!("resume session execution failed: {}", e);
-        }
-    });
+            if let Err(e) = execute_session_actor_with_execution_plan(
+                exec_clone,
+                &app_config,
+                &site_status,
+                actor_event_tx.clone(),
+            )
+            .await
+            {
+                error!("resume session execution failed: {}", e);
+            }
+        },
+    );
     Ok(ActorSystemResponse {
         success: true,
         message: "resume session started".into(),
@@ -1053,6 +1190,94 @@ pub async fn resume_from_token(
     })
 }
 
+/// 체크포인트 기반 세션 재개: 마지막 체크포인트의 남은 페이지 범위로 동일 session_id 를 이어서 실행
+#[tauri::command]
+pub async fn resume_crawling_session(
+    app: AppHandle,
+    session_id: String,
+) -> Result<ActorSystemResponse, String> {
+    {
+        let registry = session_registry();
+        let g = registry.read().await;
+        if let Some(entry) = g.get(&session_id) {
+            if matches!(
+                entry.status,
+                SessionStatus::Running | SessionStatus::Paused | SessionStatus::ShuttingDown
+            ) {
+                return Err(format!("session {} is still active", session_id));
+            }
+        }
+    }
+    let pool = crate::infrastructure::database_connection::get_or_init_global_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {}", e))?;
+    let mut checkpoint = load_session_checkpoint(&pool, &session_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("no checkpoint for session {}", session_id))?;
+    if checkpoint.status == CheckpointStatus::Completed {
+        return Err(format!("session {} already completed", session_id));
+    }
+    let pending_ranges = checkpoint.pending_ranges();
+    if pending_ranges.is_empty() {
+        return Err("no remaining pages to resume".into());
+    }
+
+    // 원본 계획에서 남은 범위/슬롯만 유지한 ExecutionPlan 재구성 (해시 재계산)
+    let mut execution_plan = checkpoint.plan.clone();
+    execution_plan.crawling_ranges = pending_ranges;
+    execution_plan
+        .page_slots
+        .retain(|s| checkpoint.pending_pages.contains(&s.physical_page));
+    execution_plan.plan_hash = compute_plan_hash(
+        &execution_plan.input_snapshot,
+        &execution_plan.crawling_ranges,
+        &execution_plan.original_strategy,
+    );
+    checkpoint.resume_count += 1;
+    checkpoint.updated_at = Utc::now();
+    save_session_checkpoint(&pool, &checkpoint)
+        .await
+        .map_err(|e| e.to_string())?;
+    info!(
+        "♻️ Resuming session {} from checkpoint: completed_batches={} pending_pages={} ranges={} resume_count={}",
+        session_id,
+        checkpoint.completed_batches,
+        checkpoint.pending_pages.len(),
+        execution_plan.crawling_ranges.len(),
+        checkpoint.resume_count
+    );
+
+    let cfg_manager =
+        ConfigManager::new().map_err(|e| format!("config manager init failed: {}", e))?;
+    let app_config = cfg_manager
+        .load_config()
+        .await
+        .map_err(|e| format!("config load failed: {}", e))?;
+    update_global_failure_policy_from_config(&app_config);
+    let site_status = execution_plan.input_snapshot_to_site_status();
+    let (sid, exec_clone) = bootstrap_and_spawn_session(
+        &app,
+        execution_plan,
+        app_config,
+        site_status,
+        None,
+        Some(checkpoint.retries_per_page.clone()),
+        Some(checkpoint.failed_pages.clone()),
+        None,
+    )
+    .await?;
+    Ok(ActorSystemResponse {
+        success: true,
+        message: format!(
+            "session resumed from checkpoint ({} pages remaining)",
+            checkpoint.pending_pages.len()
+        ),
+        session_id: Some(sid),
+        data: Some(serde_json::to_value(&exec_clone).map_err(|e| e.to_string())?),
+    })
+}
+
 // (Duplicate placeholder block removed)
 
 // (Removed deprecated ServiceBasedBatchCrawlingEngine command block)
@@ -1339,13 +1564,15 @@ async fn calculate_intelligent_crawling_range(
 }
 
 /// 실제 BatchActor 실행
+/// Run one batch to completion; returns the number of newly inserted products
 async fn execute_real_batch_actor(
     batch_id: &str,
     pages: &[u32],
     context: &AppContext,
     app_config: &AppConfig,
     site_status: &SiteStatus,
-) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    stage_flags: &[StageSkipFlag],
+) -> Result<u32, Box<dyn std::error::Error + Send + Sync>> {
     use crate::crawl_engine::actors::traits::Actor;
     use crate::crawl_engine::actors::{ActorCommand, BatchActor};
     use tokio::sync::mpsc;
@@ -1418,6 +1645,14 @@ async fn execute_real_batch_actor(
         "[DedupCfg] Applied skip_duplicate_urls=false to BatchActor (batch_id={})",
         batch_id
     );
+    // 플래그는 명령 진입 시 검증됨; 재개된 계획에서 실패하면 전체 파이프라인으로 실행
+    match StageSelection::from_flags(stage_flags) {
+        Ok(selection) => batch_actor.set_stage_selection(selection),
+        Err(e) => warn!("⚠️ Ignoring invalid stage flags {:?}: {}", stage_flags, e),
+    }
+    // (inserted, updated) — 세션 한도(max_new_products) 집계용
+    let saved_metrics = Arc::new(std::sync::Mutex::new((0u32, 0u32)));
+    batch_actor.shared_metrics = Some(saved_metrics.clone());
 
     // 수동 실행에서는 중복 URL도 위치 정보(page_id, index_in_page, id)를 강제 업데이트
     // StageDeps로 전달되도록 Session/Batch 경로에서 설정한다.
@@ -1427,7 +1662,8 @@ async fn execute_real_batch_actor(
 
     // BatchActor 실행을 위한 채널 생성
     info!("🔧 Creating communication channels...");
-    let (command_tx, command_rx) = mpsc::channel::<ActorCommand>(100);
+    let capacities = SystemConfig::channel_capacities();
+    let (command_tx, command_rx) = mpsc::channel::<ActorCommand>(capacities.actor_commands);
     info!("✅ Channels created successfully");
 
     // ProcessBatch 명령 생성
@@ -1459,12 +1695,16 @@ async fn execute_real_batch_actor(
     // BatchActor 실행 태스크 시작
     info!("🚀 Starting BatchActor task...");
     let context_clone = context.clone();
-    let batch_task = tokio::spawn(async move {
-        info!("📡 BatchActor.run() starting...");
-        let result = batch_actor.run(context_clone, command_rx).await;
-        info!("📡 BatchActor.run() completed with result: {:?}", result);
-        result
-    });
+    let batch_task = spawn_tracked(
+        format!("batch-actor:{}", batch_id),
+        Some(SESSION_TASK_LIFETIME),
+        async move {
+            info!("📡 BatchActor.run() starting...");
+            let result = batch_actor.run(context_clone, command_rx).await;
+            info!("📡 BatchActor.run() completed with result: {:?}", result);
+            result
+        },
+    );
     info!("✅ BatchActor task spawned");
 
     // ProcessBatch 명령 전송
@@ -1488,7 +1728,8 @@ async fn execute_real_batch_actor(
         pages.len()
     );
     // TODO: phase/plan 실행 컨트롤러에서 남은 배치/phase 진행 후 최종 Shutdown 발송
-    Ok(())
+    let inserted = saved_metrics.lock().map(|g| g.0).unwrap_or(0);
+    Ok(inserted)
 }
 
 // (run_single_batch_real removed)
@@ -1497,11 +1738,25 @@ async fn execute_real_batch_actor(
 ///
 /// 시스템 상태를 종합 분석하여 최적의 실행 계획을 생성합니다.
 /// 이 함수가 호출된 후에는 더 이상 분석/계획 단계가 없습니다.
-async fn create_execution_plan(
+pub(crate) async fn create_execution_plan(
     app: &AppHandle,
 ) -> Result<(ExecutionPlan, AppConfig, DomainSiteStatus), Box<dyn std::error::Error + Send + Sync>>
+{
+    create_execution_plan_with_flags(app, &[]).await
+}
+
+/// Cache age accepted for site meta under `skip_status_check` (minutes)
+const SKIP_STATUS_CHECK_SITE_CACHE_TTL_MINUTES: u64 = 7 * 24 * 60;
+
+/// `create_execution_plan`; with `skip_status_check` the cached site meta is reused at any age up
+/// to a week and a missing cache is an error instead of a fresh status check.
+pub(crate) async fn create_execution_plan_with_flags(
+    app: &AppHandle,
+    stage_flags: &[StageSkipFlag],
+) -> Result<(ExecutionPlan, AppConfig, DomainSiteStatus), Box<dyn std::error::Error + Send + Sync>>
 {
     info!("🧠 Creating ExecutionPlan with CrawlingPlanner (cache-aware)...");
+    let reuse_site_meta = stage_flags.contains(&StageSkipFlag::SkipStatusCheck);
 
     // 1. 설정 로드
     let config_manager = ConfigManager::new()?;
@@ -1576,8 +1831,16 @@ async fn create_execution_plan(
     let cached_site_status: Option<DomainSiteStatus> = if let Some(cache_state) =
         shared_cache.as_ref()
     {
-        // TTL 5분 기본
-        match cache_state.get_valid_site_analysis_async(Some(5)).await {
+        // TTL 5분 기본 (skip_status_check: 최대 1주)
+        let ttl_minutes = if reuse_site_meta {
+            SKIP_STATUS_CHECK_SITE_CACHE_TTL_MINUTES
+        } else {
+            5
+        };
+        match cache_state
+            .get_valid_site_analysis_async(Some(ttl_minutes))
+            .await
+        {
             Some(cached) => {
                 info!(
                     "♻️ Reusing cached SiteStatus: total_pages={}, last_page_products={} (age<=TTL)",
@@ -1607,6 +1870,11 @@ async fn create_execution_plan(
         info!("📭 SharedStateCache not available in Tauri state – proceeding without cache");
         None
     };
+    if reuse_site_meta && cached_site_status.is_none() {
+        return Err(
+            "skip_status_check: no cached site meta available; run a status check first".into(),
+        );
+    }
 
     // ──────────────────────────────────────────────
     // (1) 사전 데이터베이스 상태로 전략 결정 힌트 계산
@@ -1633,13 +1901,22 @@ async fn create_execution_plan(
         info!("🧭 Choosing NewestFirst strategy (empty DB)");
     }
 
+    // 배치 크기: 과거 배치 처리량 기반 튜닝 (advanced.batch_tuning, apply 모드에서만 교체)
+    let mut validated_config = ValidatedCrawlingConfig::from_app_config(&app_config);
+    batch_tuning::tune(
+        &db_pool,
+        &mut validated_config,
+        &app_config.advanced.batch_tuning,
+    )
+    .await;
+
     // (2) CrawlingConfig 생성 (start_page/end_page는 '개수' 표현: start_page - end_page + 1 = 요청 수)
     let crawling_config = CrawlingConfig {
         site_url: "https://csa-iot.org/csa-iot_products/".to_string(),
         start_page: app_config.user.crawling.page_range_limit.max(1), // 요청 개수 표현
         end_page: 1,
         concurrency_limit: app_config.user.max_concurrent_requests,
-        batch_size: app_config.user.batch.batch_size,
+        batch_size: validated_config.batch_size(),
         request_delay_ms: 1000,
         timeout_secs: 300,
         max_retries: app_config.user.crawling.workers.max_retries,
@@ -2011,6 +2288,8 @@ async fn create_execution_plan(
         input_snapshot: snapshot,
         plan_hash,
         skip_duplicate_urls: true,
+        stage_flags: Vec::new(),
+        limits: CrawlLimits::default(),
         kpi_meta: Some(crate::crawl_engine::actors::types::ExecutionPlanKpi {
             total_ranges: ranges_len,
             total_pages,
@@ -2332,6 +2611,8 @@ async fn build_execution_plan_from_explicit_pages(
     // Manual runs should update existing records' fields (page_id/index_in_page),
     // so do not skip duplicates in this mode.
     skip_duplicate_urls: false,
+    stage_flags: Vec::new(),
+    limits: CrawlLimits::default(),
         kpi_meta: Some(crate::crawl_engine::actors::types::ExecutionPlanKpi {
             total_ranges: 0,
             total_pages: total_pages_planned,
@@ -2483,7 +2764,12 @@ async fn execute_session_actor_with_execution_plan(
                         executed_list_batches: 0,
                         failed_pages_count: 0,
                         failed_page_ids: vec![],
+                        latency: crate::crawl_engine::runtime::latency::finish_session(
+                            &self.session_id,
+                        ),
                         final_state: "AbortedNoCompletion".into(),
+                        completion_reason: CompletionReason::Aborted,
+                        limit_reached: None,
                         products_inserted: 0,
                         products_updated: 0,
                         timestamp: now,
@@ -2495,6 +2781,16 @@ async fn execute_session_actor_with_execution_plan(
                     "⚠️ SessionFinalizer emitted fallback SessionCompleted (aborted) session_id={}",
                     self.session_id
                 );
+                crawl_limits::clear_new_product_allowance(&self.session_id);
+                // Failed/cancelled sessions drop their spilled payloads too
+                if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+                    let session_id = self.session_id.clone();
+                    runtime.spawn(async move {
+                        crate::infrastructure::session_spill::shared_spill_store()
+                            .end_session(&session_id)
+                            .await;
+                    });
+                }
             }
         }
     }
@@ -2567,6 +2863,27 @@ async fn execute_session_actor_with_execution_plan(
         execution_plan.session_id
     );
 
+    // 세션 체크포인트: 재개된 세션이면 기존 체크포인트를 이어서 갱신
+    let checkpoint_pool = crate::infrastructure::database_connection::get_or_init_global_pool()
+        .await
+        .ok();
+    let mut checkpoint = match &checkpoint_pool {
+        Some(pool) => match load_session_checkpoint(pool, &execution_plan.session_id).await {
+            Ok(Some(cp)) if cp.status == CheckpointStatus::Active => cp,
+            Ok(_) => SessionCheckpoint::new(&execution_plan),
+            Err(e) => {
+                warn!("[Checkpoint] load failed, starting fresh: {}", e);
+                SessionCheckpoint::new(&execution_plan)
+            }
+        },
+        None => SessionCheckpoint::new(&execution_plan),
+    };
+    if let Some(pool) = &checkpoint_pool {
+        if let Err(e) = save_session_checkpoint(pool, &checkpoint).await {
+            warn!("[Checkpoint] initial save failed: {}", e);
+        }
+    }
+
     // 시작 이벤트 방출 (설정 파일 기반 값 사용)
     // 전략 추론: 첫 배치가 마지막 페이지보다 작은 페이지를 포함하면 ContinueFromDb였을 가능성 높음
     let inferred_strategy = if execution_plan.crawling_ranges.len() > 1 {
@@ -2634,6 +2951,16 @@ async fn execute_session_actor_with_execution_plan(
             .collect::<Vec<_>>()
     );
 
+    // 세션 한도: 배치 사이마다 확인, 신규 제품 한도는 저장 경로에서도 남은 만큼만 허용
+    let mut limit_tracker = CrawlLimitTracker::new(execution_plan.limits);
+    let mut limit_hit = None;
+    if !execution_plan.limits.is_unbounded() {
+        info!("🚧 Crawl limits: {:?}", execution_plan.limits);
+    }
+    if let Some(max) = execution_plan.limits.max_new_products {
+        crawl_limits::set_new_product_allowance(&execution_plan.session_id, max);
+    }
+
     // 각 범위별로 순차 실행
     // Track how many ranges we actually execute to detect premature loop termination
     let mut ranges_executed: usize = 0;
@@ -2663,6 +2990,9 @@ async fn execute_session_actor_with_execution_plan(
         }
     }
     for (range_idx, page_range) in execution_plan.crawling_ranges.iter().enumerate() {
+        if limit_hit.is_some() {
+            break;
+        }
         let mut _range_guard = RangeExecutionGuard {
             idx: range_idx,
             post_batch_logged: false,
@@ -2730,10 +3060,45 @@ async fn execute_session_actor_with_execution_plan(
         } else {
             (page_range.start_page..=page_range.end_page).collect()
         };
+        let mut range_pages_run: usize = 0;
+        let mut range_batches_run: usize = 0;
         for (batch_index, page_chunk) in pages_vec
             .chunks(execution_plan.batch_size as usize)
             .enumerate()
         {
+            if let Some(kind) = limit_tracker.reached() {
+                info!(
+                    "🚧 Crawl limit {:?} reached (pages={}, new_products={}); stopping before batch {}",
+                    kind,
+                    limit_tracker.pages(),
+                    limit_tracker.new_products(),
+                    batch_index
+                );
+                let _ = actor_event_tx.send(AppEvent::Progress {
+                    session_id: execution_plan.session_id.clone(),
+                    current_step: range_idx as u32 + 1,
+                    total_steps: execution_plan.crawling_ranges.len() as u32,
+                    message: format!(
+                        "Crawl limit {:?} reached: {} pages, {} new products",
+                        kind,
+                        limit_tracker.pages(),
+                        limit_tracker.new_products()
+                    ),
+                    percentage: ((completed_pages + range_pages_run) as f64
+                        / (expected_pages as f64).max(1.0))
+                        * 100.0,
+                    timestamp: Utc::now(),
+                });
+                limit_hit = Some(kind);
+                break;
+            }
+            // max_pages 남은 만큼만 이번 배치에 포함
+            let page_chunk = match limit_tracker.remaining_pages() {
+                Some(left) if (left as usize) < page_chunk.len() => &page_chunk[..left as usize],
+                _ => page_chunk,
+            };
+            range_pages_run += page_chunk.len();
+            range_batches_run += 1;
             info!(
                 "[RangeLoop] BATCH ENTER range_idx={} batch_index={} pages={:?}",
                 range_idx, batch_index, page_chunk
@@ -2746,8 +3111,9 @@ async fn execute_session_actor_with_execution_plan(
                 "{}_range{}_batch{}",
                 execution_plan.session_id, range_idx, batch_index
             );
-            let system_config = Arc::new(SystemConfig::default());
-            let (control_tx, _control_rx) = mpsc::channel::<ActorCommand>(100);
+            let system_config = SystemConfig::current();
+            let actor_commands = system_config.channels.stage_capacities.actor_commands;
+            let (control_tx, _control_rx) = mpsc::channel::<ActorCommand>(actor_commands);
             let (_cancel_tx, cancel_rx) = watch::channel(false);
             let context = Arc::new(AppContext::new(
                 execution_plan.session_id.clone(),
@@ -2766,10 +3132,20 @@ async fn execute_session_actor_with_execution_plan(
                     timestamp: Utc::now(),
                 });
             }
-            if let Err(e) =
-                execute_real_batch_actor(&batch_id, page_chunk, &context, app_config, site_status)
-                    .await
-            {
+            let batch_result = execute_real_batch_actor(
+                &batch_id,
+                page_chunk,
+                &context,
+                app_config,
+                site_status,
+                &execution_plan.stage_flags,
+            )
+            .await;
+            limit_tracker.record_batch(
+                page_chunk.len() as u32,
+                batch_result.as_ref().map_or(0, |inserted| *inserted),
+            );
+            if let Err(e) = batch_result {
                 error!(
                     "❌ Batch {} failed: {} (policy=ContinueWithoutRetry)",
                     batch_id, e
@@ -2817,6 +3193,13 @@ async fn execute_session_actor_with_execution_plan(
                     final_failure: true,
                     timestamp: Utc::now(),
                 });
+                persist_batch_checkpoint(
+                    checkpoint_pool.as_ref(),
+                    &mut checkpoint,
+                    page_chunk,
+                    true,
+                )
+                .await;
                 info!("➡️ Continuing to next batch after failure (policy b)");
                 info!(
                     "[RangeLoopTrace] AFTER execute_real_batch_actor (FAILED) range_idx={} batch_index={}",
@@ -2860,6 +3243,13 @@ async fn execute_session_actor_with_execution_plan(
                         }
                     }
                 }
+                persist_batch_checkpoint(
+                    checkpoint_pool.as_ref(),
+                    &mut checkpoint,
+                    page_chunk,
+                    false,
+                )
+                .await;
                 info!(
                     "[RangeLoopTrace] AFTER execute_real_batch_actor (OK) range_idx={} batch_index={} pages={:?}",
                     range_idx, batch_index, page_chunk
@@ -2885,12 +3275,20 @@ async fn execute_session_actor_with_execution_plan(
         // Always ok in unified path (errors already handled per batch)
         {
             // Approximate increments (recompute similar to helper)
-            let added_pages = if page_range.reverse_order {
-                page_range.start_page - page_range.end_page + 1
+            let (added_pages, added_batches) = if limit_hit.is_some() {
+                // 한도로 중단된 범위는 실제 실행한 만큼만
+                (range_pages_run, range_batches_run)
             } else {
-                page_range.end_page - page_range.start_page + 1
-            } as usize;
-            let added_batches = (added_pages + batch_unit as usize - 1) / batch_unit as usize;
+                let added_pages = if page_range.reverse_order {
+                    page_range.start_page - page_range.end_page + 1
+                } else {
+                    page_range.end_page - page_range.start_page + 1
+                } as usize;
+                (
+                    added_pages,
+                    (added_pages + batch_unit as usize - 1) / batch_unit as usize,
+                )
+            };
             completed_pages += added_pages;
             completed_batches += added_batches;
             // Registry 업데이트
@@ -2901,18 +3299,10 @@ async fn execute_session_actor_with_execution_plan(
                     entry.processed_pages = completed_pages as u64;
                     entry.completed_batches = completed_batches as u64;
                     // remaining_page_slots 업데이트: 현재 range 내 완료된 물리 페이지 제거
+                    // (한도로 중단되면 실행하지 않은 페이지는 남겨 둔다)
                     if let Some(ref mut remaining) = entry.remaining_page_slots {
-                        let (from, to, rev) = (
-                            page_range.start_page,
-                            page_range.end_page,
-                            page_range.reverse_order,
-                        );
-                        let pages_range: Vec<u32> = if rev {
-                            (to..=from).rev().collect()
-                        } else {
-                            (from..=to).collect()
-                        };
-                        remaining.retain(|p: &u32| !pages_range.contains(p));
+                        let pages_run = &pages_vec[..range_pages_run];
+                        remaining.retain(|p: &u32| !pages_run.contains(p));
                     }
                 }
             }
@@ -2976,14 +3366,14 @@ async fn execute_session_actor_with_execution_plan(
     }
 
     // 완료 이벤트 방출
-    // Integrity logging
-    if completed_batches != expected_batches {
+    // Integrity logging (한도로 중단된 세션은 계획보다 적게 실행하는 것이 정상)
+    if limit_hit.is_none() && completed_batches != expected_batches {
         warn!(
             "⚠️ Batch count mismatch: expected={} actual={}",
             expected_batches, completed_batches
         );
     }
-    if completed_pages != expected_pages {
+    if limit_hit.is_none() && completed_pages != expected_pages {
         warn!(
             "⚠️ Page count mismatch: expected={} actual={}",
             expected_pages, completed_pages
@@ -3025,7 +3415,9 @@ async fn execute_session_actor_with_execution_plan(
             )
         }
     };
-    let final_state = if !failed_pages_vec.is_empty() {
+    let final_state = if limit_hit.is_some() {
+        "LimitReached"
+    } else if !failed_pages_vec.is_empty() {
         if completed_batches == expected_batches {
             "CompletedWithFailures"
         } else {
@@ -3066,11 +3458,14 @@ async fn execute_session_actor_with_execution_plan(
             failed_pages_vec.len()
         )
     };
-    info!(target: "kpi.session", "{{\"event\":\"session_summary\",\"session_id\":\"{}\",\"plan_id\":\"{}\",\"final_state\":\"{}\",\"planned_ranges\":{},\"executed_ranges\":{},\"completed_batches\":{},\"expected_batches\":{},\"completed_pages\":{},\"expected_pages\":{},\"failed_count\":{},\"mismatch_flags\":{},\"failures_meta\":{}}}",
+    let latency_stats =
+        crate::crawl_engine::runtime::latency::finish_session(&execution_plan.session_id);
+    info!(target: "kpi.session", "{{\"event\":\"session_summary\",\"session_id\":\"{}\",\"plan_id\":\"{}\",\"final_state\":\"{}\",\"planned_ranges\":{},\"executed_ranges\":{},\"completed_batches\":{},\"expected_batches\":{},\"completed_pages\":{},\"expected_pages\":{},\"failed_count\":{},\"mismatch_flags\":{},\"failures_meta\":{},\"latency\":{}}}",
           execution_plan.session_id,
           execution_plan.plan_id,
           final_state,
-          execution_plan.crawling_ranges.len(), ranges_executed, completed_batches, expected_batches, completed_pages, expected_pages, failed_pages_vec.len(), mismatch_json, failures_meta);
+          execution_plan.crawling_ranges.len(), ranges_executed, completed_batches, expected_batches, completed_pages, expected_pages, failed_pages_vec.len(), mismatch_json, failures_meta,
+          crate::crawl_engine::runtime::latency::kpi_json(&latency_stats));
     let completion_event = AppEvent::SessionCompleted {
         session_id: execution_plan.session_id.clone(),
         summary: SessionSummary {
@@ -3137,6 +3532,7 @@ async fn execute_session_actor_with_execution_plan(
             },
             failed_pages_count: failed_pages_vec.len() as u32,
             failed_page_ids: failed_pages_vec.clone(),
+            latency: latency_stats,
             retry_histogram: {
                 let registry = session_registry();
                 let g = registry.read().await;
@@ -3166,7 +3562,13 @@ async fn execute_session_actor_with_execution_plan(
             planned_list_batches: expected_batches as u32,
             executed_list_batches: completed_batches as u32,
             final_state: final_state.to_string(),
-            products_inserted: 0,
+            completion_reason: if limit_hit.is_some() {
+                CompletionReason::LimitReached
+            } else {
+                CompletionReason::Finished
+            },
+            limit_reached: limit_hit,
+            products_inserted: limit_tracker.new_products(),
             products_updated: 0,
             timestamp: Utc::now(),
         },
@@ -3182,6 +3584,28 @@ async fn execute_session_actor_with_execution_plan(
         );
     }
     completed_normally.store(true, Ordering::SeqCst);
+    // Pages from failed batches with attempts left keep the checkpoint resumable
+    checkpoint.status = if checkpoint.pending_pages.is_empty() {
+        CheckpointStatus::Completed
+    } else {
+        CheckpointStatus::Active
+    };
+    checkpoint.updated_at = Utc::now();
+    if let Some(pool) = &checkpoint_pool {
+        if let Err(e) = save_session_checkpoint(pool, &checkpoint).await {
+            warn!("[Checkpoint] completion save failed: {}", e);
+        }
+    }
+    crate::infrastructure::session_spill::shared_spill_store()
+        .end_session(&execution_plan.session_id)
+        .await;
+    crate::crawl_engine::runtime::site_hints::clear(&execution_plan.session_id);
+    crawl_limits::clear_new_product_allowance(&execution_plan.session_id);
+    crate::crawl_engine::actor_system::supervisor::take_escalation(&execution_plan.session_id);
+    crate::crawl_engine::channels::priority::clear_boosts(&execution_plan.session_id);
+    crate::crawl_engine::services::performance_optimizer::clear_concurrency_targets(
+        &execution_plan.session_id,
+    );
 
     info!("🎉 ExecutionPlan fully executed!");
     // Update registry for completed (if not already failed) and schedule grace removal
@@ -3217,18 +3641,22 @@ async fn execute_session_actor_with_execution_plan(
         }
     }
     let cleanup_id = execution_plan.session_id.clone();
-    tokio::spawn(async move {
-        tokio::time::sleep(Duration::from_secs(removal_grace_secs() as u64 + 1)).await;
-        let registry = session_registry();
-        let mut g = registry.write().await;
-        if let Some(entry) = g.get(&cleanup_id) {
-            if let Some(deadline) = entry.removal_deadline {
-                if Utc::now() >= deadline {
-                    g.remove(&cleanup_id);
+    spawn_tracked(
+        format!("session-registry-cleanup:{}", cleanup_id),
+        Some(Duration::from_secs(removal_grace_secs() as u64 + 60)),
+        async move {
+            tokio::time::sleep(Duration::from_secs(removal_grace_secs() as u64 + 1)).await;
+            let registry = session_registry();
+            let mut g = registry.write().await;
+            if let Some(entry) = g.get(&cleanup_id) {
+                if let Some(deadline) = entry.removal_deadline {
+                    if Utc::now() >= deadline {
+                        g.remove(&cleanup_id);
+                    }
                 }
             }
-        }
-    });
+        },
+    );
     Ok(())
 }
 
@@ -99,6 +99,9 @@ async fn execute_crawling_with_state(app_handle: &tauri::AppHandle) -> Result<()
             batch_size: None,
             delay_ms: None,
             mode: None,
+            export: None,
+            stage_flags: Vec::new(),
+            limits: crate::crawl_engine::runtime::crawl_limits::CrawlLimits::default(),
         },
     )
     .await
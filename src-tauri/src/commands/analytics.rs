@@ -0,0 +1,32 @@
+//! 대시보드 차트 시리즈 조회 명령어 (집계 캐시 경유)
+
+use crate::application::AppState;
+use crate::domain::command_error::CommandError;
+use crate::infrastructure::query_control::{QueryOptions, run_query};
+use crate::services::analytics_cache::{AnalyticsCache, ChartId, ChartParams, ChartSeries};
+use tauri::State;
+
+/// Chart series for `chart_id` (`products_per_month`, `products_per_device_type`); served from
+/// the cache while fresh, otherwise stale with `stale: true` while a refresh runs.
+/// `options` sets a timeout / cancellable query id.
+#[tauri::command(async)]
+pub async fn get_chart_series(
+    app_state: State<'_, AppState>,
+    chart_id: String,
+    params: Option<ChartParams>,
+    options: Option<QueryOptions>,
+) -> Result<ChartSeries, CommandError> {
+    run_query("get_chart_series", options, |_| async move {
+        let chart =
+            ChartId::parse(&chart_id).ok_or_else(|| format!("Unknown chart '{chart_id}'"))?;
+        let pool = app_state
+            .get_database_pool()
+            .await
+            .map_err(|e| format!("DB pool unavailable: {e}"))?;
+        AnalyticsCache::shared()
+            .get_chart_series(&pool, chart, params.unwrap_or_default())
+            .await
+            .map_err(|e| format!("Chart query failed: {e:#}"))
+    })
+    .await
+}
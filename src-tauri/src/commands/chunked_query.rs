@@ -0,0 +1,89 @@
+//! 대용량 조회 결과를 청크 단위로 가져오는 커맨드
+//!
+//! `start_query` → `query_id` 를 받은 뒤 `fetch_query_chunk(query_id, chunk)` 로 구간별로 읽고,
+//! 다 읽었으면 `close_query` 로 커서를 정리한다. 커서 관리는 `services::chunked_query` 참고.
+
+use crate::application::AppState;
+use crate::domain::product::ProductWithDetails;
+use crate::infrastructure::IntegratedProductRepository;
+use crate::services::chunked_query::{
+    ChunkedQueryHandle, ChunkedQuerySource, QueryChunk, chunked_queries,
+};
+use sqlx::SqlitePool;
+use tauri::State;
+use tracing::info;
+
+/// Snapshot the keys of `source` and return a handle for chunked reads
+#[tauri::command(async)]
+pub async fn start_query(
+    app_state: State<'_, AppState>,
+    source: ChunkedQuerySource,
+    chunk_size: Option<usize>,
+) -> Result<ChunkedQueryHandle, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let keys = snapshot_keys(&pool, &source)
+        .await
+        .map_err(|e| format!("Failed to start query: {e:#}"))?;
+    let handle = chunked_queries().open(keys, chunk_size);
+    info!(
+        "📑 Chunked query {} opened: {:?} rows={} chunks={}",
+        handle.query_id, source, handle.total_rows, handle.total_chunks
+    );
+    Ok(handle)
+}
+
+/// Rows of chunk `chunk` (0-based) of an open query
+#[tauri::command(async)]
+pub async fn fetch_query_chunk(
+    app_state: State<'_, AppState>,
+    query_id: String,
+    chunk: usize,
+) -> Result<QueryChunk<ProductWithDetails>, String> {
+    let slice = chunked_queries().chunk_keys(&query_id, chunk)?;
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let rows = IntegratedProductRepository::new(pool)
+        .fetch_export_rows_by_keys(&slice.keys)
+        .await
+        .map_err(|e| format!("Failed to fetch chunk {chunk}: {e:#}"))?;
+    Ok(QueryChunk {
+        query_id,
+        chunk: slice.chunk,
+        total_chunks: slice.total_chunks,
+        total_rows: slice.total_rows,
+        rows,
+        has_more: slice.chunk + 1 < slice.total_chunks,
+    })
+}
+
+/// Release an open query's cursor; false when it had already expired
+#[tauri::command(async)]
+pub async fn close_query(query_id: String) -> Result<bool, String> {
+    Ok(chunked_queries().close(&query_id))
+}
+
+#[tauri::command(async)]
+pub async fn list_open_queries() -> Result<Vec<ChunkedQueryHandle>, String> {
+    Ok(chunked_queries().open_queries())
+}
+
+async fn snapshot_keys(pool: &SqlitePool, source: &ChunkedQuerySource) -> anyhow::Result<Vec<i64>> {
+    match source {
+        ChunkedQuerySource::Products { filter } => {
+            IntegratedProductRepository::new(pool.clone())
+                .export_row_keys(filter)
+                .await
+        }
+        ChunkedQuerySource::ProductsMissingDetails => Ok(sqlx::query_scalar(
+            "SELECT p.rowid FROM products p LEFT JOIN product_details pd ON p.url = pd.url \
+             WHERE pd.url IS NULL ORDER BY p.page_id DESC, p.index_in_page ASC",
+        )
+        .fetch_all(pool)
+        .await?),
+    }
+}
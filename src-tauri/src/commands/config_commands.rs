@@ -436,6 +436,38 @@ pub async fn update_crawling_settings(
     Ok(())
 }
 
+/// Probe host capabilities (CPU/memory/disk/network RTT) and persist the resulting host profile.
+/// The profile's recommendations cap the CrawlingPlanner's batch size and concurrency.
+#[tauri::command]
+pub async fn probe_host_capabilities(
+    state: State<'_, AppState>,
+) -> Result<crate::infrastructure::host_profile::HostProfile, String> {
+    info!("Frontend requested host capability probe");
+
+    let scratch_dir =
+        ConfigManager::get_app_data_dir().map_err(|e| format!("Failed to get data dir: {}", e))?;
+    let profile = crate::infrastructure::host_profile::probe_host_capabilities(&scratch_dir).await;
+
+    let config_manager =
+        ConfigManager::new().map_err(|e| format!("Failed to create config manager: {}", e))?;
+    let saved = profile.clone();
+    config_manager
+        .update_app_managed(|app_managed| {
+            app_managed.host_profile = Some(saved);
+        })
+        .await
+        .map_err(|e| format!("Failed to save host profile: {}", e))?;
+
+    // Update the app state with new configuration (also publishes the profile to the planner)
+    let updated_config = config_manager
+        .load_config()
+        .await
+        .map_err(|e| format!("Failed to reload config: {}", e))?;
+    let _ = state.update_config(updated_config).await;
+
+    Ok(profile)
+}
+
 /// Build a URL for a specific page number using the site configuration
 #[tauri::command]
 pub fn build_page_url(page: u32) -> Result<String, String> {
@@ -1195,6 +1227,178 @@ pub async fn load_window_state(
     Ok(None)
 }
 
+/// Key prefix for per-table grid preferences in `user.ui_preferences`
+const GRID_PREFERENCES_PREFIX: &str = "grid.";
+
+/// Row density of a data grid
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ts_rs::TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum GridDensity {
+    Compact,
+    #[default]
+    Standard,
+    Comfortable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ts_rs::TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ts_rs::TS)]
+#[ts(export)]
+pub struct GridSort {
+    pub column: String,
+    pub direction: SortDirection,
+}
+
+/// Column visibility, sort and density of one table
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ts_rs::TS)]
+#[ts(export)]
+pub struct GridPreferences {
+    /// Visible columns in display order
+    #[serde(default)]
+    pub visible_columns: Vec<String>,
+    #[serde(default)]
+    pub sort: Option<GridSort>,
+    #[serde(default)]
+    pub density: GridDensity,
+}
+
+/// Payload of `ui-preferences-changed`, so other windows can reload the key
+#[derive(Debug, Clone, Serialize)]
+pub struct UiPreferenceChanged {
+    pub key: String,
+    pub value: Option<serde_json::Value>,
+}
+
+fn grid_preferences_key(table_id: &str) -> Result<String, String> {
+    let table_id = table_id.trim();
+    if table_id.is_empty() {
+        return Err("Table id is required".into());
+    }
+    Ok(format!("{GRID_PREFERENCES_PREFIX}{table_id}"))
+}
+
+/// Set (or remove, with `None`) one preference, persist it and tell every window
+async fn write_ui_preference(
+    app: &tauri::AppHandle,
+    app_state: &AppState,
+    key: String,
+    value: Option<serde_json::Value>,
+) -> Result<(), String> {
+    use tauri::Emitter;
+
+    let config_manager =
+        ConfigManager::new().map_err(|e| format!("Failed to create config manager: {}", e))?;
+    let mut config = config_manager
+        .load_config()
+        .await
+        .map_err(|e| format!("Failed to load config: {}", e))?;
+    match &value {
+        Some(value) => {
+            config
+                .user
+                .ui_preferences
+                .insert(key.clone(), value.clone());
+        }
+        None => {
+            config.user.ui_preferences.remove(&key);
+        }
+    }
+    config_manager
+        .save_config(&config)
+        .await
+        .map_err(|e| format!("Failed to save config: {}", e))?;
+    app_state
+        .update_config(config)
+        .await
+        .map_err(|e| format!("Failed to update app state: {}", e))?;
+    debug!("💾 UI preference saved: {}", key);
+
+    let _ = app.emit("ui-preferences-changed", UiPreferenceChanged { key, value });
+    Ok(())
+}
+
+/// All stored UI preferences by key
+#[tauri::command]
+pub async fn list_ui_preferences(
+    app_state: State<'_, AppState>,
+) -> Result<std::collections::BTreeMap<String, serde_json::Value>, String> {
+    Ok(app_state.get_config().await.user.ui_preferences)
+}
+
+#[tauri::command]
+pub async fn get_ui_preference(
+    key: String,
+    app_state: State<'_, AppState>,
+) -> Result<Option<serde_json::Value>, String> {
+    let config = app_state.get_config().await;
+    Ok(config.user.ui_preferences.get(&key).cloned())
+}
+
+/// Store a preference; `null` removes it
+#[tauri::command]
+pub async fn set_ui_preference(
+    key: String,
+    value: Option<serde_json::Value>,
+    app: tauri::AppHandle,
+    app_state: State<'_, AppState>,
+) -> Result<(), String> {
+    if key.trim().is_empty() {
+        return Err("Preference key is required".into());
+    }
+    write_ui_preference(&app, &app_state, key, value.filter(|v| !v.is_null())).await
+}
+
+/// Grid preferences of `table_id`, if any were saved (unreadable entries count as unset)
+#[tauri::command]
+pub async fn get_grid_preferences(
+    table_id: String,
+    app_state: State<'_, AppState>,
+) -> Result<Option<GridPreferences>, String> {
+    let key = grid_preferences_key(&table_id)?;
+    let config = app_state.get_config().await;
+    let Some(value) = config.user.ui_preferences.get(&key) else {
+        return Ok(None);
+    };
+    match serde_json::from_value(value.clone()) {
+        Ok(preferences) => Ok(Some(preferences)),
+        Err(e) => {
+            tracing::warn!("⚠️ Ignoring unreadable grid preferences for {}: {}", key, e);
+            Ok(None)
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn save_grid_preferences(
+    table_id: String,
+    preferences: GridPreferences,
+    app: tauri::AppHandle,
+    app_state: State<'_, AppState>,
+) -> Result<(), String> {
+    let key = grid_preferences_key(&table_id)?;
+    let value = serde_json::to_value(&preferences)
+        .map_err(|e| format!("Failed to serialize grid preferences: {}", e))?;
+    write_ui_preference(&app, &app_state, key, Some(value)).await
+}
+
+/// Forget the grid preferences of `table_id` (back to the table's defaults)
+#[tauri::command]
+pub async fn reset_grid_preferences(
+    table_id: String,
+    app: tauri::AppHandle,
+    app_state: State<'_, AppState>,
+) -> Result<(), String> {
+    let key = grid_preferences_key(&table_id)?;
+    write_ui_preference(&app, &app_state, key, None).await
+}
+
 /// Set window position (Tauri command)
 #[tauri::command]
 pub fn set_window_position(window: tauri::Window, x: i32, y: i32) -> Result<(), String> {
@@ -1231,6 +1435,58 @@ pub fn show_window(window: tauri::Window) -> Result<(), String> {
     Ok(())
 }
 
+/// Toggle slim IPC payloads for the calling window (Tauri command)
+///
+/// Slim windows receive heavy actor events (stage results with ProductDetail arrays)
+/// as references and fetch details lazily via `get_product_details_by_urls`.
+#[tauri::command]
+pub fn set_slim_ipc_mode(window: tauri::Window, enabled: bool) -> Result<bool, String> {
+    crate::crawl_engine::actor_event_bridge::set_slim_ipc_window(window.label(), enabled);
+    info!(
+        "Slim IPC payload mode {} for window '{}'",
+        if enabled { "enabled" } else { "disabled" },
+        window.label()
+    );
+    Ok(enabled)
+}
+
+/// Whether the calling window receives slim IPC payloads (Tauri command)
+#[tauri::command]
+pub fn get_slim_ipc_mode(window: tauri::Window) -> bool {
+    crate::crawl_engine::actor_event_bridge::is_slim_ipc_window(window.label())
+}
+
+/// robots.txt cache state per origin and recently skipped/overridden requests (Tauri command)
+#[tauri::command]
+pub async fn get_robots_status(
+    state: State<'_, AppState>,
+) -> Result<crate::infrastructure::robots::RobotsStatus, String> {
+    let config = state.get_config().await;
+    Ok(crate::infrastructure::robots::shared_robots_cache()
+        .status(config.user.crawling.workers.respect_robots_txt))
+}
+
+/// HTML cache hit/miss counters and disk usage (Tauri command)
+#[tauri::command]
+pub async fn get_html_cache_stats()
+-> Result<crate::infrastructure::html_cache::HtmlCacheStats, String> {
+    Ok(crate::infrastructure::html_cache::shared_html_cache().stats())
+}
+
+/// Delete every cached HTML page; the next fetch of each URL is unconditional (Tauri command)
+#[tauri::command]
+pub async fn clear_html_cache()
+-> Result<crate::infrastructure::html_cache::HtmlCacheCleared, String> {
+    let cleared = crate::infrastructure::html_cache::shared_html_cache()
+        .clear()
+        .map_err(|e| e.to_string())?;
+    info!(
+        "🗑️ HTML cache cleared: {} entries, {} bytes",
+        cleared.removed_entries, cleared.freed_bytes
+    );
+    Ok(cleared)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1282,6 +1538,31 @@ mod tests {
         assert_eq!(site_config.matter_filters.program_type, "1049");
     }
 
+    #[test]
+    fn test_grid_preferences_fill_missing_fields_and_keys() {
+        let parsed: GridPreferences =
+            serde_json::from_value(serde_json::json!({ "visible_columns": ["vid", "pid"] }))
+                .unwrap();
+        assert_eq!(parsed.visible_columns, vec!["vid", "pid"]);
+        assert_eq!(parsed.sort, None);
+        assert_eq!(parsed.density, GridDensity::Standard);
+
+        let sorted = GridPreferences {
+            sort: Some(GridSort {
+                column: "certification_date".into(),
+                direction: SortDirection::Desc,
+            }),
+            density: GridDensity::Compact,
+            ..parsed
+        };
+        let value = serde_json::to_value(&sorted).unwrap();
+        assert_eq!(value["sort"]["direction"], "desc");
+        assert_eq!(value["density"], "compact");
+
+        assert_eq!(grid_preferences_key(" products ").unwrap(), "grid.products");
+        assert!(grid_preferences_key("  ").is_err());
+    }
+
     #[tokio::test]
     async fn test_frontend_log_writing() {
         let _entry = LogEntry {
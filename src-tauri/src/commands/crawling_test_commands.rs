@@ -150,7 +150,7 @@ pub async fn quick_crawling_test(
     info!(pages = ?pages, "📋 리스트 수집 테스트 시작");
     let list_result = executor
         .execute_stage(
-            StageType::ListCollection,
+            StageType::ListPageCrawling,
             list_items,
             concurrency,
             cancellation_token.clone(),
@@ -201,7 +201,7 @@ pub async fn quick_crawling_test(
         // 실제로는 수집된 URL을 사용해야 하지만, 테스트에서는 단순화
         let detail_result = executor
             .execute_stage(
-                StageType::DetailCollection,
+                StageType::ProductDetailCrawling,
                 Vec::new(),
                 concurrency,
                 cancellation_token.clone(),
@@ -0,0 +1,106 @@
+//! 메인 대시보드 개요 명령어 (진행 작업 / 최근 세션 / KPI 추세 / 이상 항목 / 예약 / 상태)
+//!
+//! 대시보드가 여러 명령을 따로 부르던 것을 한 번의 호출로 대신한다. 모으는 규칙은
+//! `services::dashboard_overview` 참고.
+
+use crate::application::AppState;
+use crate::crawl_engine::runtime::sync_cancellation::SyncCancellationRegistry;
+use crate::crawl_engine::runtime::task_registry::task_registry;
+use crate::crawl_engine::services::session_report;
+use crate::infrastructure::{circuit_breaker, persistence_queue};
+use crate::services::crawl_scheduler::CrawlSchedulerService;
+use crate::services::dashboard_overview::{
+    self, ActiveOperation, DashboardOverview, HealthFlags, NEXT_RUNS, RECENT_SESSIONS,
+    SessionDigest, TREND_WINDOW,
+};
+use crate::services::{startup_integrity, sync_resume};
+use chrono::Utc;
+use tauri::State;
+
+/// Everything the main dashboard shows, in one round-trip
+#[tauri::command(async)]
+pub async fn get_dashboard_overview(
+    app_state: State<'_, AppState>,
+    scheduler: State<'_, CrawlSchedulerService>,
+) -> Result<DashboardOverview, String> {
+    let mut warnings = Vec::new();
+    let pool = match app_state.get_database_pool().await {
+        Ok(pool) => Some(pool),
+        Err(e) => {
+            warnings.push(format!("DB pool unavailable: {e}"));
+            None
+        }
+    };
+
+    let mut active_operations = dashboard_overview::crawl_operations().await;
+    active_operations.extend(
+        SyncCancellationRegistry::shared()
+            .active_sessions()
+            .into_iter()
+            .map(ActiveOperation::running_sync),
+    );
+    if let Some(pool) = &pool {
+        match sync_resume::paused_sessions(pool).await {
+            Ok(paused) => {
+                active_operations.extend(paused.into_iter().map(ActiveOperation::paused_sync))
+            }
+            Err(e) => warnings.push(format!("Paused syncs unavailable: {e:#}")),
+        }
+    }
+
+    let reports = match session_report::reports_dir() {
+        Ok(dir) => dashboard_overview::load_recent_reports(&dir, TREND_WINDOW).await,
+        Err(e) => Err(e),
+    }
+    .unwrap_or_else(|e| {
+        warnings.push(format!("Session reports unavailable: {e:#}"));
+        Vec::new()
+    });
+    let recent_sessions: Vec<SessionDigest> = reports
+        .iter()
+        .take(RECENT_SESSIONS)
+        .map(SessionDigest::from)
+        .collect();
+    let kpi_trends = dashboard_overview::kpi_trends(&reports);
+
+    let mut anomalies = Default::default();
+    let mut next_runs = Vec::new();
+    let mut persistence = (0, 0);
+    if let Some(pool) = &pool {
+        match dashboard_overview::pending_anomalies(pool).await {
+            Ok(pending) => anomalies = pending,
+            Err(e) => warnings.push(format!("Anomalies unavailable: {e:#}")),
+        }
+        match scheduler.list_schedules(pool).await {
+            Ok(schedules) => next_runs = dashboard_overview::next_runs(schedules, NEXT_RUNS),
+            Err(e) => warnings.push(format!("Schedules unavailable: {e:#}")),
+        }
+        let metrics = persistence_queue::shared_queue(pool).metrics();
+        persistence = (metrics.queue_depth, metrics.failed_flushes);
+    }
+
+    let open_circuits = circuit_breaker::shared_circuit_breaker()
+        .open_circuits()
+        .into_iter()
+        .map(|c| c.host)
+        .collect();
+    let overdue_tasks = task_registry().list().iter().filter(|t| t.overdue).count() as u32;
+    let health = HealthFlags::new(
+        pool.is_some(),
+        startup_integrity::cached_report().as_ref(),
+        open_circuits,
+        persistence,
+        overdue_tasks,
+    );
+
+    Ok(DashboardOverview {
+        generated_at: Utc::now(),
+        active_operations,
+        recent_sessions,
+        kpi_trends,
+        anomalies,
+        next_runs,
+        health,
+        warnings,
+    })
+}
@@ -0,0 +1,926 @@
+//! 수집 데이터 내보내기 (products + product_details)
+//!
+//! `IntegratedProductRepository::stream_products_for_export`로 행을 하나씩 받아 파일에 바로
+//! 기록하므로, 대량 내보내기에서도 전체 결과를 메모리에 올리지 않는다.
+//! JSON은 배열 하나로, NDJSON은 행마다 한 줄로 기록한다. CSV는 `columns`로 지정한 열만
+//! RFC 4180 규칙으로 기록하며, 열 이름에 `products.` / `product_details.` 접두어를 붙여
+//! 두 테이블 중 어느 쪽 값을 쓸지 고를 수 있다.
+//! XLSX는 같은 열 규칙으로 단일 시트에 기록한다. `column_spec`을 주면 열 순서·헤더 이름·날짜
+//! 형식까지 프런트엔드에서 정할 수 있고, 내보내기 전에 ProductDetail 스키마 기준으로 검증한다.
+//! 공유용 파일은 `anonymize` 프로필로 필드를 빼거나 해시하고 로컬 경로를 치환한다
+//! (`services::anonymization`).
+//! 레코드 형식은 `src-tauri/schemas/export/`에 JSON Schema로 공개하고 바이너리에도 내장한다.
+//! 모든 파일 머리에 스키마 버전을 적는다: JSON은 `{"export_header":..,"records":[..]}`,
+//! NDJSON은 첫 줄, CSV는 `# ` 주석 첫 줄, XLSX는 사용자 지정 문서 속성.
+//! 레코드 형식이 바뀌면 새 버전 파일을 추가하고 `EXPORT_SCHEMA_VERSION`을 올린다.
+
+use crate::application::AppState;
+use crate::domain::product::{ProductExportFilter, ProductWithDetails};
+use crate::infrastructure::IntegratedProductRepository;
+use crate::infrastructure::config::ConfigManager;
+use crate::infrastructure::xlsx_writer::{XlsxCell, XlsxWriter};
+use crate::services::anonymization::{AnonymizationProfile, Anonymizer};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tauri::State;
+use tracing::info;
+
+/// Version of the product record schema written into new export files
+pub const EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Published record schemas by version (`src-tauri/schemas/export/`)
+const EXPORT_SCHEMAS: &[(u32, &str)] = &[(
+    1,
+    include_str!("../../schemas/export/product-record.v1.schema.json"),
+)];
+
+/// `$id` of a record schema version
+pub fn export_schema_id(version: u32) -> String {
+    format!("urn:rmattercertis:schema:product-record:{version}")
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Json,
+    Ndjson,
+    Csv,
+    Xlsx,
+}
+
+impl ExportFormat {
+    pub(crate) fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Ndjson => "ndjson",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Xlsx => "xlsx",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ProductExportRequest {
+    #[serde(default)]
+    pub format: ExportFormat,
+    /// Target file; defaults to `<app data>/exports/products_<timestamp>.<ext>`
+    #[serde(default)]
+    pub output_path: Option<String>,
+    #[serde(default)]
+    pub filter: ProductExportFilter,
+    /// CSV/XLSX column selection (ignored for JSON/NDJSON); defaults to `DEFAULT_CSV_COLUMNS`
+    #[serde(default)]
+    pub columns: Option<Vec<String>>,
+    /// Full CSV/XLSX layout (order, headers, date formats); takes precedence over `columns`
+    #[serde(default)]
+    pub column_spec: Option<Vec<ExportColumnSpec>>,
+    /// Fields to drop or hash (and local paths to redact) for files that will be shared
+    #[serde(default)]
+    pub anonymize: Option<AnonymizationProfile>,
+}
+
+/// One column of a frontend-defined export layout
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportColumnSpec {
+    /// Field name, optionally prefixed with `products.` / `product_details.`
+    pub field: String,
+    /// Header label; defaults to `field`
+    #[serde(default)]
+    pub header: Option<String>,
+    /// chrono strftime pattern (e.g. `%Y-%m-%d`); only valid on date fields
+    #[serde(default)]
+    pub date_format: Option<String>,
+}
+
+impl ExportColumnSpec {
+    pub fn named(name: impl Into<String>) -> Self {
+        Self {
+            field: name.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Columns written when a CSV export does not specify its own selection
+pub const DEFAULT_CSV_COLUMNS: &[&str] = &[
+    "url",
+    "page_id",
+    "index_in_page",
+    "manufacturer",
+    "model",
+    "certificate_id",
+    "device_type",
+    "vid",
+    "pid",
+    "certification_date",
+];
+
+/// Columns stored only in the `products` table
+const PRODUCT_COLUMNS: &[&str] = &[
+    "id",
+    "url",
+    "manufacturer",
+    "model",
+    "certificate_id",
+    "page_id",
+    "index_in_page",
+    "created_at",
+    "updated_at",
+];
+
+/// Columns available from the `product_details` table
+const DETAIL_COLUMNS: &[&str] = &[
+    "id",
+    "url",
+    "page_id",
+    "index_in_page",
+    "manufacturer",
+    "model",
+    "device_type",
+    "certificate_id",
+    "certification_date",
+    "software_version",
+    "hardware_version",
+    "vid",
+    "pid",
+    "family_sku",
+    "family_variant_sku",
+    "firmware_version",
+    "family_id",
+    "tis_trp_tested",
+    "specification_version",
+    "transport_interface",
+    "primary_device_type_id",
+    "application_categories",
+    "description",
+    "compliance_document_url",
+    "program_type",
+    "created_at",
+    "updated_at",
+];
+
+/// Columns that accept a `date_format`
+const DATE_COLUMNS: &[&str] = &["certification_date", "created_at", "updated_at"];
+
+/// Columns written as numeric cells in XLSX
+const NUMERIC_COLUMNS: &[&str] = &["page_id", "index_in_page", "vid", "pid"];
+
+/// Source layouts tried when reformatting `certification_date` text
+const DATE_INPUT_FORMATS: &[&str] = &["%Y-%m-%d", "%Y/%m/%d", "%m/%d/%Y", "%d.%m.%Y", "%B %d, %Y"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportTable {
+    Products,
+    ProductDetails,
+}
+
+/// A validated CSV/XLSX column: header text plus the table/field it reads from
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ExportColumn {
+    header: String,
+    table: ExportTable,
+    field: &'static str,
+    date_format: Option<String>,
+}
+
+/// Header at the top of every export file (the schema's `exportHeader`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportHeader {
+    pub schema_id: String,
+    pub schema_version: u32,
+    pub format: ExportFormat,
+    pub exported_at: DateTime<Utc>,
+    pub anonymized: bool,
+}
+
+impl ExportHeader {
+    fn new(format: ExportFormat, anonymized: bool) -> Self {
+        Self {
+            schema_id: export_schema_id(EXPORT_SCHEMA_VERSION),
+            schema_version: EXPORT_SCHEMA_VERSION,
+            format,
+            exported_at: Utc::now(),
+            anonymized,
+        }
+    }
+
+    /// Name/value pairs for the CSV comment line and XLSX document properties
+    fn fields(&self) -> [(&'static str, String); 4] {
+        [
+            ("schema_id", self.schema_id.clone()),
+            ("schema_version", self.schema_version.to_string()),
+            ("exported_at", self.exported_at.to_rfc3339()),
+            ("anonymized", self.anonymized.to_string()),
+        ]
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProductExportSummary {
+    pub output_path: String,
+    pub format: ExportFormat,
+    pub rows_written: u64,
+    pub elapsed_ms: u64,
+}
+
+/// Export products joined with product_details as JSON, NDJSON, CSV or XLSX, filtered by
+/// page_id range, manufacturer, device_type and certification date range.
+#[tauri::command(async)]
+pub async fn export_products(
+    app_state: State<'_, AppState>,
+    request: ProductExportRequest,
+) -> Result<ProductExportSummary, String> {
+    let started = Instant::now();
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let repo = IntegratedProductRepository::new(pool);
+
+    let output_path = resolve_output_path(&request)?;
+    info!(target: "data_export", "export_products: start format={:?} path={:?} filter={:?}", request.format, output_path, request.filter);
+
+    let format = request.format;
+    let anonymizer = request
+        .anonymize
+        .as_ref()
+        .map(AnonymizationProfile::anonymizer)
+        .transpose()?;
+    let mut writer = match request.column_spec.as_deref() {
+        Some(spec) => ExportWriter::create_with_spec(&output_path, format, spec, anonymizer)?,
+        None => ExportWriter::create(&output_path, format, request.columns.as_deref(), anonymizer)?,
+    };
+    let rows_written = repo
+        .stream_products_for_export(&request.filter, |row| writer.write_row(&row))
+        .await
+        .map_err(|e| format!("Export failed: {e}"))?;
+    writer.finish().map_err(|e| e.to_string())?;
+
+    let elapsed_ms = started.elapsed().as_millis() as u64;
+    info!(target: "data_export", "export_products: wrote {} rows to {:?} in {}ms", rows_written, output_path, elapsed_ms);
+
+    Ok(ProductExportSummary {
+        output_path: output_path.to_string_lossy().to_string(),
+        format,
+        rows_written,
+        elapsed_ms,
+    })
+}
+
+/// Published JSON Schema of exported product records; `version` defaults to the current one
+#[tauri::command(async)]
+pub async fn get_export_schema(version: Option<u32>) -> Result<serde_json::Value, String> {
+    let version = version.unwrap_or(EXPORT_SCHEMA_VERSION);
+    let (_, schema) = EXPORT_SCHEMAS
+        .iter()
+        .find(|(v, _)| *v == version)
+        .ok_or_else(|| {
+            let known: Vec<String> = EXPORT_SCHEMAS.iter().map(|(v, _)| v.to_string()).collect();
+            format!(
+                "Unknown export schema version {version}; available: {}",
+                known.join(", ")
+            )
+        })?;
+    serde_json::from_str(schema).map_err(|e| format!("Embedded export schema is invalid: {e}"))
+}
+
+/// Row-at-a-time export file writer shared by `export_products` and session export sinks.
+/// CSV/NDJSON files are valid after every row; JSON and XLSX need `finish` to close the file.
+pub struct ExportWriter {
+    target: ExportTarget,
+    format: ExportFormat,
+    columns: Vec<ExportColumn>,
+    anonymizer: Option<Anonymizer>,
+    rows: u64,
+}
+
+enum ExportTarget {
+    Text(BufWriter<File>),
+    Xlsx(XlsxWriter),
+}
+
+impl ExportWriter {
+    /// Create (truncate) `path`, creating parent directories, and write the header
+    pub fn create(
+        path: &Path,
+        format: ExportFormat,
+        columns: Option<&[String]>,
+        anonymizer: Option<Anonymizer>,
+    ) -> Result<Self, String> {
+        let columns = if uses_columns(format) {
+            resolve_csv_columns(columns)?
+        } else {
+            Vec::new()
+        };
+        Self::open(path, format, columns, anonymizer)
+    }
+
+    /// `create` with a frontend-defined column layout (CSV/XLSX)
+    pub fn create_with_spec(
+        path: &Path,
+        format: ExportFormat,
+        spec: &[ExportColumnSpec],
+        anonymizer: Option<Anonymizer>,
+    ) -> Result<Self, String> {
+        let columns = if uses_columns(format) {
+            resolve_columns(spec)?
+        } else {
+            Vec::new()
+        };
+        Self::open(path, format, columns, anonymizer)
+    }
+
+    fn open(
+        path: &Path,
+        format: ExportFormat,
+        mut columns: Vec<ExportColumn>,
+        anonymizer: Option<Anonymizer>,
+    ) -> Result<Self, String> {
+        if let Some(anonymizer) = &anonymizer {
+            let requested = columns.len();
+            columns.retain(|c| !anonymizer.excludes(c.field));
+            if requested > 0 && columns.is_empty() {
+                return Err("Anonymization profile excludes every export column".into());
+            }
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create export directory {parent:?}: {e}"))?;
+        }
+        let header = ExportHeader::new(format, anonymizer.is_some());
+        let headers: Vec<&str> = columns.iter().map(|c| c.header.as_str()).collect();
+        let target = if format == ExportFormat::Xlsx {
+            let fields = header.fields();
+            let properties: Vec<(&str, &str)> =
+                fields.iter().map(|(k, v)| (*k, v.as_str())).collect();
+            let mut xlsx = XlsxWriter::create_with_properties(path, "Products", &properties)
+                .map_err(|e| format!("Failed to create export file {path:?}: {e}"))?;
+            let cells: Vec<XlsxCell> = headers
+                .iter()
+                .map(|h| XlsxCell::Text(h.to_string()))
+                .collect();
+            xlsx.write_row(&cells).map_err(|e| e.to_string())?;
+            ExportTarget::Xlsx(xlsx)
+        } else {
+            let file = File::create(path)
+                .map_err(|e| format!("Failed to create export file {path:?}: {e}"))?;
+            let mut writer = BufWriter::new(file);
+            write_text_header(&mut writer, &header, &headers).map_err(|e| e.to_string())?;
+            ExportTarget::Text(writer)
+        };
+        Ok(Self {
+            target,
+            format,
+            columns,
+            anonymizer,
+            rows: 0,
+        })
+    }
+
+    pub fn write_row(&mut self, row: &ProductWithDetails) -> anyhow::Result<()> {
+        let anonymizer = self.anonymizer.as_ref();
+        match &mut self.target {
+            ExportTarget::Xlsx(xlsx) => {
+                let cells: Vec<XlsxCell> = self
+                    .columns
+                    .iter()
+                    .map(|c| xlsx_cell(row, c, anonymizer))
+                    .collect();
+                xlsx.write_row(&cells)?;
+            }
+            ExportTarget::Text(writer) => match self.format {
+                ExportFormat::Json => {
+                    let separator: &[u8] = if self.rows == 0 { b"\n" } else { b",\n" };
+                    writer.write_all(separator)?;
+                    write_json_row(&mut *writer, row, anonymizer)?;
+                }
+                ExportFormat::Ndjson => {
+                    write_json_row(&mut *writer, row, anonymizer)?;
+                    writer.write_all(b"\n")?;
+                }
+                ExportFormat::Csv | ExportFormat::Xlsx => {
+                    let values: Vec<String> = self
+                        .columns
+                        .iter()
+                        .map(|c| column_value(row, c, anonymizer).unwrap_or_default())
+                        .collect();
+                    write_csv_record(writer, &values)?;
+                }
+            },
+        }
+        self.rows += 1;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        match &mut self.target {
+            ExportTarget::Text(writer) => writer.flush(),
+            ExportTarget::Xlsx(xlsx) => xlsx.flush(),
+        }
+    }
+
+    pub fn rows_written(&self) -> u64 {
+        self.rows
+    }
+
+    /// Close the JSON array / XLSX archive (if any) and flush; returns the number of rows written
+    pub fn finish(self) -> std::io::Result<u64> {
+        match self.target {
+            ExportTarget::Text(mut writer) => {
+                if self.format == ExportFormat::Json {
+                    writer.write_all(b"\n]}\n")?;
+                }
+                writer.flush()?;
+            }
+            ExportTarget::Xlsx(xlsx) => {
+                xlsx.finish()?;
+            }
+        }
+        Ok(self.rows)
+    }
+}
+
+/// Schema header (plus the CSV column row); JSON opens the `records` array
+fn write_text_header<W: Write>(
+    writer: &mut W,
+    header: &ExportHeader,
+    columns: &[&str],
+) -> std::io::Result<()> {
+    match header.format {
+        ExportFormat::Json => {
+            writer.write_all(b"{\"export_header\":")?;
+            serde_json::to_writer(&mut *writer, header)?;
+            writer.write_all(b",\"records\":[")
+        }
+        ExportFormat::Ndjson => {
+            serde_json::to_writer(
+                &mut *writer,
+                &serde_json::json!({ "export_header": header }),
+            )?;
+            writer.write_all(b"\n")
+        }
+        ExportFormat::Csv => {
+            let fields: Vec<String> = header
+                .fields()
+                .iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect();
+            write!(writer, "# {}\r\n", fields.join(" "))?;
+            write_csv_record(writer, columns)
+        }
+        ExportFormat::Xlsx => Ok(()),
+    }
+}
+
+fn uses_columns(format: ExportFormat) -> bool {
+    matches!(format, ExportFormat::Csv | ExportFormat::Xlsx)
+}
+
+fn resolve_output_path(request: &ProductExportRequest) -> Result<PathBuf, String> {
+    if let Some(path) = request
+        .output_path
+        .as_deref()
+        .filter(|p| !p.trim().is_empty())
+    {
+        return Ok(PathBuf::from(path));
+    }
+    let exports_dir = ConfigManager::get_app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("exports");
+    let stamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+    Ok(exports_dir.join(format!("products_{stamp}.{}", request.format.extension())))
+}
+
+/// Validate the requested CSV columns by name (headers are the names as given)
+fn resolve_csv_columns(requested: Option<&[String]>) -> Result<Vec<ExportColumn>, String> {
+    let spec: Vec<ExportColumnSpec> = match requested {
+        Some(cols) if !cols.is_empty() => cols.iter().map(ExportColumnSpec::named).collect(),
+        _ => DEFAULT_CSV_COLUMNS
+            .iter()
+            .map(|c| ExportColumnSpec::named(*c))
+            .collect(),
+    };
+    resolve_columns(&spec)
+}
+
+/// Validate a column layout against the ProductDetail schema. Unprefixed names read from
+/// `products` when the column exists there and fall back to `product_details`;
+/// `products.` / `product_details.` prefixes pick the table explicitly. `date_format` must be
+/// a valid strftime pattern on one of `DATE_COLUMNS`.
+fn resolve_columns(spec: &[ExportColumnSpec]) -> Result<Vec<ExportColumn>, String> {
+    if spec.is_empty() {
+        return Err("Column spec must contain at least one column".into());
+    }
+    let mut columns = Vec::with_capacity(spec.len());
+    let mut unknown = Vec::new();
+    let mut invalid = Vec::new();
+    for entry in spec {
+        let name = entry.field.trim();
+        let (table, field_name) = match name.split_once('.') {
+            Some(("products", field)) => (Some(ExportTable::Products), field),
+            Some(("product_details", field)) => (Some(ExportTable::ProductDetails), field),
+            Some(_) => {
+                unknown.push(name.to_string());
+                continue;
+            }
+            None => (None, name),
+        };
+        let in_products = PRODUCT_COLUMNS.iter().find(|c| **c == field_name);
+        let in_details = DETAIL_COLUMNS.iter().find(|c| **c == field_name);
+        let resolved = match table {
+            Some(ExportTable::Products) => in_products.map(|f| (ExportTable::Products, *f)),
+            Some(ExportTable::ProductDetails) => {
+                in_details.map(|f| (ExportTable::ProductDetails, *f))
+            }
+            None => in_products
+                .map(|f| (ExportTable::Products, *f))
+                .or_else(|| in_details.map(|f| (ExportTable::ProductDetails, *f))),
+        };
+        let Some((table, field)) = resolved else {
+            unknown.push(name.to_string());
+            continue;
+        };
+        let date_format = entry
+            .date_format
+            .as_deref()
+            .map(str::trim)
+            .filter(|f| !f.is_empty());
+        if let Some(pattern) = date_format {
+            if !DATE_COLUMNS.contains(&field) {
+                invalid.push(format!(
+                    "{name}: date_format is only allowed on date fields"
+                ));
+                continue;
+            }
+            if StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error)) {
+                invalid.push(format!("{name}: invalid date_format '{pattern}'"));
+                continue;
+            }
+        }
+        let header = entry
+            .header
+            .as_deref()
+            .map(str::trim)
+            .filter(|h| !h.is_empty())
+            .unwrap_or(name);
+        columns.push(ExportColumn {
+            header: header.to_string(),
+            table,
+            field,
+            date_format: date_format.map(str::to_string),
+        });
+    }
+
+    if !unknown.is_empty() {
+        invalid.insert(
+            0,
+            format!(
+                "Unknown column(s): {}. Available: {}",
+                unknown.join(", "),
+                DETAIL_COLUMNS.join(", ")
+            ),
+        );
+    }
+    if !invalid.is_empty() {
+        return Err(invalid.join("; "));
+    }
+    Ok(columns)
+}
+
+/// Column value with its `date_format` and anonymization applied
+fn column_value(
+    row: &ProductWithDetails,
+    column: &ExportColumn,
+    anonymizer: Option<&Anonymizer>,
+) -> Option<String> {
+    let value = raw_field(row, column)?;
+    let value = match column.date_format.as_deref() {
+        Some(pattern) => format_date(&value, pattern).unwrap_or(value),
+        None => value,
+    };
+    Some(match anonymizer {
+        Some(anonymizer) => anonymizer.apply_text(column.field, value),
+        None => value,
+    })
+}
+
+/// JSON/NDJSON row; anonymized rows go through a `serde_json::Value` first
+fn write_json_row<W: Write>(
+    writer: W,
+    row: &ProductWithDetails,
+    anonymizer: Option<&Anonymizer>,
+) -> serde_json::Result<()> {
+    match anonymizer {
+        Some(anonymizer) => {
+            let mut value = serde_json::to_value(row)?;
+            anonymizer.apply_json(&mut value);
+            serde_json::to_writer(writer, &value)
+        }
+        None => serde_json::to_writer(writer, row),
+    }
+}
+
+/// XLSX cell for a column: numeric fields become number cells, the rest inline text
+fn xlsx_cell(
+    row: &ProductWithDetails,
+    column: &ExportColumn,
+    anonymizer: Option<&Anonymizer>,
+) -> XlsxCell {
+    let Some(value) = column_value(row, column, anonymizer) else {
+        return XlsxCell::Empty;
+    };
+    if NUMERIC_COLUMNS.contains(&column.field) {
+        if let Ok(n) = value.parse::<f64>() {
+            return XlsxCell::Number(n);
+        }
+    }
+    XlsxCell::Text(value)
+}
+
+/// Reformat an RFC 3339 timestamp or a date in one of `DATE_INPUT_FORMATS`;
+/// `None` leaves unparseable text as stored
+fn format_date(raw: &str, pattern: &str) -> Option<String> {
+    let raw = raw.trim();
+    let datetime = match DateTime::parse_from_rfc3339(raw) {
+        Ok(dt) => dt.naive_utc(),
+        Err(_) => DATE_INPUT_FORMATS
+            .iter()
+            .find_map(|f| NaiveDate::parse_from_str(raw, f).ok())?
+            .and_hms_opt(0, 0, 0)?,
+    };
+    let mut out = String::new();
+    // Offset specifiers (%z) cannot render on a naive value; keep the raw text then
+    write!(out, "{}", datetime.format(pattern)).ok()?;
+    Some(out)
+}
+
+/// Extract one cell from a joined row; `None` when the value (or the details row) is missing
+fn raw_field(row: &ProductWithDetails, column: &ExportColumn) -> Option<String> {
+    match column.table {
+        ExportTable::Products => {
+            let p = &row.product;
+            match column.field {
+                "id" => p.id.clone(),
+                "url" => Some(p.url.clone()),
+                "manufacturer" => p.manufacturer.clone(),
+                "model" => p.model.clone(),
+                "certificate_id" => p.certificate_id.clone(),
+                "page_id" => p.page_id.map(|v| v.to_string()),
+                "index_in_page" => p.index_in_page.map(|v| v.to_string()),
+                "created_at" => Some(p.created_at.to_rfc3339()),
+                "updated_at" => Some(p.updated_at.to_rfc3339()),
+                _ => None,
+            }
+        }
+        ExportTable::ProductDetails => {
+            let d = row.details.as_ref()?;
+            match column.field {
+                "id" => d.id.clone(),
+                "url" => Some(d.url.clone()),
+                "page_id" => d.page_id.map(|v| v.to_string()),
+                "index_in_page" => d.index_in_page.map(|v| v.to_string()),
+                "manufacturer" => d.manufacturer.clone(),
+                "model" => d.model.clone(),
+                "device_type" => d.device_type.clone(),
+                "certificate_id" => d.certificate_id.clone(),
+                "certification_date" => d.certification_date.clone(),
+                "software_version" => d.software_version.clone(),
+                "hardware_version" => d.hardware_version.clone(),
+                "vid" => d.vid.map(|v| v.to_string()),
+                "pid" => d.pid.map(|v| v.to_string()),
+                "family_sku" => d.family_sku.clone(),
+                "family_variant_sku" => d.family_variant_sku.clone(),
+                "firmware_version" => d.firmware_version.clone(),
+                "family_id" => d.family_id.clone(),
+                "tis_trp_tested" => d.tis_trp_tested.clone(),
+                "specification_version" => d.specification_version.clone(),
+                "transport_interface" => d.transport_interface.clone(),
+                "primary_device_type_id" => d.primary_device_type_id.clone(),
+                "application_categories" => d
+                    .application_categories
+                    .as_deref()
+                    .map(flatten_multi_valued),
+                "description" => d.description.clone(),
+                "compliance_document_url" => d.compliance_document_url.clone(),
+                "program_type" => d.program_type.clone(),
+                "created_at" => Some(d.created_at.to_rfc3339()),
+                "updated_at" => Some(d.updated_at.to_rfc3339()),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Multi-valued fields are stored as JSON arrays (e.g. `["Light Bulb","Sensor"]`);
+/// flatten them to `a; b` so each value stays a single CSV cell. Other text passes through.
+fn flatten_multi_valued(raw: &str) -> String {
+    match serde_json::from_str::<Vec<serde_json::Value>>(raw) {
+        Ok(values) => values
+            .iter()
+            .map(|v| match v {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("; "),
+        Err(_) => raw.to_string(),
+    }
+}
+
+/// Quote a CSV cell when it contains a delimiter, quote or line break (RFC 4180)
+fn csv_escape(value: &str) -> Cow<'_, str> {
+    if value.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+fn write_csv_record<W: Write, S: AsRef<str>>(writer: &mut W, values: &[S]) -> std::io::Result<()> {
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+        writer.write_all(csv_escape(value.as_ref()).as_bytes())?;
+    }
+    writer.write_all(b"\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_escape_quotes_only_when_needed() {
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape("a,b"), "\"a,b\"");
+        assert_eq!(csv_escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_escape("line\nbreak"), "\"line\nbreak\"");
+    }
+
+    #[test]
+    fn multi_valued_fields_flatten_to_single_cell() {
+        assert_eq!(
+            flatten_multi_valued(r#"["Light Bulb","Sensor"]"#),
+            "Light Bulb; Sensor"
+        );
+        assert_eq!(flatten_multi_valued("Thermostat"), "Thermostat");
+    }
+
+    #[test]
+    fn csv_columns_resolve_table_and_reject_unknown() {
+        let cols = resolve_csv_columns(Some(&[
+            "manufacturer".to_string(),
+            "product_details.manufacturer".to_string(),
+            "vid".to_string(),
+        ]))
+        .unwrap();
+        assert_eq!(cols[0].table, ExportTable::Products);
+        assert_eq!(cols[1].table, ExportTable::ProductDetails);
+        assert_eq!(cols[2].table, ExportTable::ProductDetails);
+
+        let err = resolve_csv_columns(Some(&["products.vid".to_string()])).unwrap_err();
+        assert!(err.contains("products.vid"));
+        assert_eq!(
+            resolve_csv_columns(None).unwrap().len(),
+            DEFAULT_CSV_COLUMNS.len()
+        );
+    }
+
+    #[test]
+    fn column_spec_sets_headers_and_validates_date_formats() {
+        let spec = vec![
+            ExportColumnSpec {
+                field: "product_details.certification_date".into(),
+                header: Some("Certified".into()),
+                date_format: Some("%d/%m/%Y".into()),
+            },
+            ExportColumnSpec::named("vid"),
+        ];
+        let cols = resolve_columns(&spec).unwrap();
+        assert_eq!(cols[0].header, "Certified");
+        assert_eq!(cols[0].date_format.as_deref(), Some("%d/%m/%Y"));
+        assert_eq!((cols[1].header.as_str(), cols[1].field), ("vid", "vid"));
+
+        let err = resolve_columns(&[
+            ExportColumnSpec {
+                field: "model".into(),
+                header: None,
+                date_format: Some("%Y".into()),
+            },
+            ExportColumnSpec {
+                field: "created_at".into(),
+                header: None,
+                date_format: Some("%Q".into()),
+            },
+            ExportColumnSpec::named("color"),
+        ])
+        .unwrap_err();
+        assert!(err.contains("Unknown column(s): color"));
+        assert!(err.contains("model: date_format is only allowed on date fields"));
+        assert!(err.contains("created_at: invalid date_format '%Q'"));
+        assert!(resolve_columns(&[]).is_err());
+    }
+
+    fn sample_row() -> ProductWithDetails {
+        serde_json::from_value(serde_json::json!({
+            "product": {
+                "url": "https://x.test/a",
+                "pageId": 3,
+                "createdAt": "2024-03-05T10:20:00Z",
+                "updatedAt": "2024-03-05T10:20:00Z"
+            },
+            "details": {
+                "url": "https://x.test/a",
+                "vid": 4660,
+                "created_at": "2024-03-05T10:20:00Z",
+                "updated_at": "2024-03-05T10:20:00Z"
+            }
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn embedded_schema_covers_every_serialized_field() {
+        let schema = get_export_schema(None).await.unwrap();
+        assert_eq!(schema["$id"], export_schema_id(EXPORT_SCHEMA_VERSION));
+        assert!(
+            get_export_schema(Some(EXPORT_SCHEMA_VERSION + 1))
+                .await
+                .is_err()
+        );
+
+        let row = serde_json::to_value(sample_row()).unwrap();
+        for (part, def) in [("product", "product"), ("details", "productDetail")] {
+            let properties = &schema["$defs"][def]["properties"];
+            for key in row[part].as_object().unwrap().keys() {
+                assert!(
+                    properties.get(key).is_some(),
+                    "{def}.{key} missing from schema"
+                );
+            }
+            assert_eq!(
+                properties.as_object().unwrap().len(),
+                row[part].as_object().unwrap().len(),
+                "{def} schema lists fields the record does not serialize"
+            );
+        }
+    }
+
+    #[test]
+    fn every_format_starts_with_the_schema_header() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |format: ExportFormat| {
+            let path = dir.path().join(format!("out.{}", format.extension()));
+            let mut writer = ExportWriter::create(&path, format, None, None).unwrap();
+            writer.write_row(&sample_row()).unwrap();
+            writer.finish().unwrap();
+            std::fs::read_to_string(&path).unwrap()
+        };
+
+        let json: serde_json::Value = serde_json::from_str(&write(ExportFormat::Json)).unwrap();
+        let header: ExportHeader = serde_json::from_value(json["export_header"].clone()).unwrap();
+        assert_eq!(header.schema_version, EXPORT_SCHEMA_VERSION);
+        assert_eq!(header.format, ExportFormat::Json);
+        assert_eq!(json["records"][0]["details"]["vid"], 4660);
+
+        let ndjson = write(ExportFormat::Ndjson);
+        let mut lines = ndjson.lines();
+        let first: serde_json::Value = serde_json::from_str(lines.next().unwrap()).unwrap();
+        assert_eq!(first["export_header"]["schema_id"], header.schema_id);
+        assert_eq!(lines.count(), 1);
+
+        let csv = write(ExportFormat::Csv);
+        let first = csv.lines().next().unwrap();
+        assert!(first.starts_with(&format!(
+            "# schema_id={} schema_version=1 ",
+            header.schema_id
+        )));
+        assert!(first.ends_with("anonymized=false"));
+    }
+
+    #[test]
+    fn dates_reformat_and_unparseable_text_is_kept() {
+        assert_eq!(
+            format_date("2024-03-05", "%d/%m/%Y").as_deref(),
+            Some("05/03/2024")
+        );
+        assert_eq!(
+            format_date("2024-03-05T10:20:00+00:00", "%Y.%m.%d %H:%M").as_deref(),
+            Some("2024.03.05 10:20")
+        );
+        assert_eq!(
+            format_date("March 5, 2024", "%Y-%m-%d").as_deref(),
+            Some("2024-03-05")
+        );
+        assert_eq!(format_date("pending", "%Y"), None);
+    }
+}
@@ -1,12 +1,21 @@
 use chrono::DateTime;
 use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
 use tauri::State;
 use tracing::{error, info};
 use ts_rs::TS;
 
 use crate::application::AppState;
-use crate::domain::product::Product;
+use crate::domain::command_error::CommandError;
+use crate::domain::product::{Product, ProductDetail};
 use crate::infrastructure::integrated_product_repository::IntegratedProductRepository; // 올바른 Product 타입 사용
+use crate::infrastructure::query_control::{QueryCancellation, QueryOptions, run_query};
+use crate::infrastructure::read_snapshot;
+use crate::infrastructure::session_spill::{TempStorageUsage, shared_spill_store};
+use crate::services::search_index::{self, ProductSearchHit};
+
+/// `search_products` 페이지 크기 상한
+const MAX_SEARCH_PAGE_SIZE: u32 = 100;
 
 /// 제품 페이지 응답
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -17,6 +26,20 @@ pub struct ProductPage {
     pub page: u32,
     pub size: u32,
     pub has_next: bool,
+    /// Snapshot mode: when the read snapshot was taken (RFC3339); `None` for live reads
+    #[serde(default)]
+    pub snapshot_at: Option<String>,
+}
+
+/// 제품 검색 결과 페이지 (bm25 순위)
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ProductSearchPage {
+    pub hits: Vec<ProductSearchHit>,
+    pub total_count: u32,
+    pub page: u32,
+    pub size: u32,
+    pub has_next: bool,
 }
 
 /// 크롤링 상태 정보
@@ -38,16 +61,45 @@ pub struct SystemStatus {
     pub total_products: u32,
     pub last_crawl_time: Option<DateTime<chrono::Utc>>,
     pub config_loaded: bool,
+    /// Per-session temp storage used by payload spilling
+    #[serde(default)]
+    pub temp_storage: TempStorageUsage,
 }
 
 /// 제품 데이터 페이지별 조회 (Backend-Only CRUD)
+///
+/// `snapshot: true` reads through the shared read snapshot (`infrastructure::read_snapshot`), so
+/// a grid browsed during a crawl only ever shows fully written pages and a matching total.
+/// `options` sets a timeout / cancellable query id (`infrastructure::query_control`).
 #[tauri::command]
 pub async fn get_products_page(
     state: State<'_, AppState>,
     page: u32,
     size: u32,
+    snapshot: Option<bool>,
+    options: Option<QueryOptions>,
+) -> Result<ProductPage, CommandError> {
+    run_query("get_products_page", options, |_| {
+        products_page(state, page, size, snapshot)
+    })
+    .await
+}
+
+async fn products_page(
+    state: State<'_, AppState>,
+    page: u32,
+    size: u32,
+    snapshot: Option<bool>,
 ) -> Result<ProductPage, String> {
     let pool = state.get_database_pool().await?;
+    if snapshot.unwrap_or(false) {
+        return products_page_from_snapshot(&pool, page, size)
+            .await
+            .map_err(|e| {
+                error!("Failed to get products page from snapshot: {:#}", e);
+                format!("Failed to retrieve products: {}", e)
+            });
+    }
     let repo = IntegratedProductRepository::new(pool);
 
     match repo.get_products_paginated(page as i32, size as i32).await {
@@ -76,6 +128,7 @@ pub async fn get_products_page(
                 page,
                 size,
                 has_next,
+                snapshot_at: None,
             })
         }
         Err(e) => {
@@ -85,13 +138,55 @@ pub async fn get_products_page(
     }
 }
 
-/// 최근 업데이트된 제품 조회 (Backend-Only CRUD)
+async fn products_page_from_snapshot(
+    pool: &SqlitePool,
+    page: u32,
+    size: u32,
+) -> anyhow::Result<ProductPage> {
+    let reader = read_snapshot::shared_snapshot_reader(pool);
+    let mut snap = reader.acquire().await?;
+    let products =
+        IntegratedProductRepository::products_paginated_on(snap.conn(), page as i32, size as i32)
+            .await?;
+    let total_count = IntegratedProductRepository::count_products_on(snap.conn()).await? as u32;
+    Ok(ProductPage {
+        has_next: (page + 1) * size < total_count,
+        products,
+        total_count,
+        page,
+        size,
+        snapshot_at: Some(snap.taken_at().to_rfc3339()),
+    })
+}
+
+/// 최근 업데이트된 제품 조회 (Backend-Only CRUD). `snapshot: true` reads the shared read snapshot.
+/// `options` 로 타임아웃 / 취소용 query id 지정.
 #[tauri::command]
 pub async fn get_latest_products(
     state: State<'_, AppState>,
     limit: u32,
+    snapshot: Option<bool>,
+    options: Option<QueryOptions>,
+) -> Result<Vec<Product>, CommandError> {
+    run_query("get_latest_products", options, |_| {
+        latest_products(state, limit, snapshot)
+    })
+    .await
+}
+
+async fn latest_products(
+    state: State<'_, AppState>,
+    limit: u32,
+    snapshot: Option<bool>,
 ) -> Result<Vec<Product>, String> {
     let pool = state.get_database_pool().await?;
+    if snapshot.unwrap_or(false) {
+        let reader = read_snapshot::shared_snapshot_reader(&pool);
+        let mut snap = reader.acquire().await.map_err(|e| e.to_string())?;
+        return IntegratedProductRepository::latest_updated_products_on(snap.conn(), limit)
+            .await
+            .map_err(|e| format!("Failed to retrieve latest products: {}", e));
+    }
     let repo = IntegratedProductRepository::new(pool);
 
     match repo.get_latest_updated_products(limit).await {
@@ -129,9 +224,16 @@ pub async fn get_crawling_status_v2(
     Ok(status)
 }
 
-/// 시스템 전체 상태 조회 (Backend-Only CRUD)
+/// 시스템 전체 상태 조회 (Backend-Only CRUD). `options` 로 타임아웃 / 취소용 query id 지정.
 #[tauri::command]
-pub async fn get_system_status(state: State<'_, AppState>) -> Result<SystemStatus, String> {
+pub async fn get_system_status(
+    state: State<'_, AppState>,
+    options: Option<QueryOptions>,
+) -> Result<SystemStatus, CommandError> {
+    run_query("get_system_status", options, |_| system_status(state)).await
+}
+
+async fn system_status(state: State<'_, AppState>) -> Result<SystemStatus, String> {
     // 데이터베이스 연결 확인
     let database_connected = state.get_database_pool().await.is_ok();
 
@@ -164,12 +266,117 @@ pub async fn get_system_status(state: State<'_, AppState>) -> Result<SystemStatu
         total_products,
         last_crawl_time,
         config_loaded,
+        temp_storage: shared_spill_store().usage(),
     };
 
     info!(
-        "✅ System status: db_connected={}, total_products={}, config_loaded={}",
-        status.database_connected, status.total_products, status.config_loaded
+        "✅ System status: db_connected={}, total_products={}, config_loaded={}, temp_bytes={}",
+        status.database_connected,
+        status.total_products,
+        status.config_loaded,
+        status.temp_storage.total_bytes
     );
 
     Ok(status)
 }
+
+/// URL 목록으로 제품 상세 조회 (slim IPC 모드에서 참조만 받은 경우 지연 로딩용)
+#[tauri::command]
+pub async fn get_product_details_by_urls(
+    state: State<'_, AppState>,
+    urls: Vec<String>,
+    options: Option<QueryOptions>,
+) -> Result<Vec<ProductDetail>, CommandError> {
+    run_query("get_product_details_by_urls", options, |cancel| {
+        product_details_by_urls(state, urls, cancel)
+    })
+    .await
+}
+
+async fn product_details_by_urls(
+    state: State<'_, AppState>,
+    urls: Vec<String>,
+    cancel: QueryCancellation,
+) -> Result<Vec<ProductDetail>, String> {
+    let pool = state.get_database_pool().await?;
+    let repo = IntegratedProductRepository::new(pool);
+
+    let mut details = Vec::with_capacity(urls.len());
+    for url in &urls {
+        cancel.check()?;
+        match repo.get_product_detail_by_url(url).await {
+            Ok(Some(detail)) => details.push(detail),
+            Ok(None) => {}
+            Err(e) => {
+                error!("Failed to load product detail for {}: {}", url, e);
+                return Err(format!("Failed to load product detail: {}", e));
+            }
+        }
+    }
+
+    info!(
+        "✅ Retrieved {} of {} requested product details",
+        details.len(),
+        urls.len()
+    );
+    Ok(details)
+}
+
+/// 제조사/모델/설명 전문 검색 (FTS5, bm25 순위). `prefix`(기본 true)면 각 단어를 접두어로 매칭한다.
+/// `page`는 0부터 시작. `options` 로 타임아웃 / 취소용 query id 지정.
+#[tauri::command]
+pub async fn search_products(
+    state: State<'_, AppState>,
+    query: String,
+    page: Option<u32>,
+    size: Option<u32>,
+    prefix: Option<bool>,
+    options: Option<QueryOptions>,
+) -> Result<ProductSearchPage, CommandError> {
+    run_query("search_products", options, |_| {
+        search_products_page(state, query, page, size, prefix)
+    })
+    .await
+}
+
+async fn search_products_page(
+    state: State<'_, AppState>,
+    query: String,
+    page: Option<u32>,
+    size: Option<u32>,
+    prefix: Option<bool>,
+) -> Result<ProductSearchPage, String> {
+    let page = page.unwrap_or(0);
+    let size = size.unwrap_or(20).clamp(1, MAX_SEARCH_PAGE_SIZE);
+    let Some(match_query) = search_index::build_match_query(&query, prefix.unwrap_or(true)) else {
+        return Ok(ProductSearchPage {
+            hits: Vec::new(),
+            total_count: 0,
+            page,
+            size,
+            has_next: false,
+        });
+    };
+    let pool = state.get_database_pool().await?;
+    let (hits, total_count) = search_index::search(&pool, &match_query, size, page * size)
+        .await
+        .map_err(|e| {
+            error!("Product search failed for {:?}: {:#}", query, e);
+            format!("Product search failed: {}", e)
+        })?;
+
+    info!(
+        "✅ Search {:?}: {} hits on page {} ({} total)",
+        query,
+        hits.len(),
+        page,
+        total_count
+    );
+    Ok(ProductSearchPage {
+        has_next: (page + 1) * size < total_count,
+        hits,
+        total_count,
+        page,
+        size,
+    })
+}
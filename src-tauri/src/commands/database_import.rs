@@ -0,0 +1,66 @@
+//! 외부 DB 스냅샷 병합 명령어 (두 장비의 수집 데이터 합치기)
+
+use crate::application::AppState;
+use crate::crawl_engine::runtime::command_dedup::CommandDedup;
+use crate::crawl_engine::runtime::sync_cancellation::SyncCancellationRegistry;
+use crate::infrastructure::database_paths;
+use crate::services::database_import::{self, DatabaseImportReport, ImportConflictRule};
+use std::path::PathBuf;
+use tauri::State;
+
+/// Merge `products`/`product_details` from the SQLite file at `source_path` by URL.
+/// `rule` defaults to prefer_newer; `dry_run` reports the counts without writing.
+/// A real import backs the database up first when `backup_before_destructive` is set.
+#[tauri::command(async)]
+pub async fn import_database(
+    app_state: State<'_, AppState>,
+    source_path: String,
+    rule: Option<ImportConflictRule>,
+    dry_run: Option<bool>,
+) -> Result<DatabaseImportReport, String> {
+    let rule = rule.unwrap_or_default();
+    let dry_run = dry_run.unwrap_or(false);
+    let args = (source_path.clone(), rule, dry_run);
+    CommandDedup::shared()
+        .run("import_database", &args, || {
+            run_import(app_state, source_path, rule, dry_run)
+        })
+        .await
+}
+
+async fn run_import(
+    app_state: State<'_, AppState>,
+    source_path: String,
+    rule: ImportConflictRule,
+    dry_run: bool,
+) -> Result<DatabaseImportReport, String> {
+    let running = SyncCancellationRegistry::shared().active_sessions();
+    if !running.is_empty() {
+        return Err(format!(
+            "Database import unavailable while sync is running: {}",
+            running.join(", ")
+        ));
+    }
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+
+    let maintenance_cfg = app_state.get_config().await.advanced.db_maintenance;
+    let backup_file = if !dry_run && maintenance_cfg.backup_before_destructive {
+        let backup =
+            database_paths::backup_database(&pool, "import_database", maintenance_cfg.max_backups)
+                .await
+                .map_err(|e| format!("Backup before import failed: {e:#}"))?;
+        Some(backup.file_name)
+    } else {
+        None
+    };
+
+    let mut report =
+        database_import::import_database(&pool, &PathBuf::from(source_path), rule, dry_run)
+            .await
+            .map_err(|e| format!("{e:#}"))?;
+    report.backup_file = backup_file;
+    Ok(report)
+}
@@ -0,0 +1,41 @@
+//! DB 백업 목록 / 복원 예약 명령어
+//!
+//! 백업은 파괴적 명령(`reset_product_storage`, `cleanup_duplicate_urls`, `import_database`)이 실행 전에 자동으로 만든다.
+
+use crate::application::AppState;
+use crate::infrastructure::database_paths::{self, DbBackup, DbRestorePlan};
+use tauri::State;
+
+/// Database backups, newest first
+#[tauri::command(async)]
+pub async fn list_db_backups(app_state: State<'_, AppState>) -> Result<Vec<DbBackup>, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    database_paths::list_db_backups(&pool)
+        .await
+        .map_err(|e| format!("Failed to list DB backups: {e:#}"))
+}
+
+/// Stage `file_name` to replace the database at the next app start; the current database is
+/// backed up first
+#[tauri::command(async)]
+pub async fn restore_db_backup(
+    app_state: State<'_, AppState>,
+    file_name: String,
+) -> Result<DbRestorePlan, String> {
+    let max_backups = app_state
+        .get_config()
+        .await
+        .advanced
+        .db_maintenance
+        .max_backups;
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    database_paths::stage_db_restore(&pool, &file_name, max_backups)
+        .await
+        .map_err(|e| format!("Failed to stage DB restore: {e:#}"))
+}
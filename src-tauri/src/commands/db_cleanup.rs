@@ -1,4 +1,12 @@
 use crate::application::AppState;
+use crate::crawl_engine::runtime::sync_cancellation::SyncCancellationRegistry;
+use crate::domain::command_error::CommandError;
+use crate::infrastructure::database_paths;
+use crate::infrastructure::db_maintenance::{self, VacuumReport};
+use crate::infrastructure::query_control::{QueryOptions, run_query};
+use crate::services::dedup_conflicts::{self, DedupScanReport};
+use crate::services::identity_dedup::{self, IdentityDedupReport, IdentityMergeReport};
+use crate::services::url_normalization::{self, UrlNormalizationReport};
 use serde::Serialize;
 use sqlx::sqlite::SqliteQueryResult;
 use tauri::{AppHandle, State};
@@ -15,10 +23,19 @@ pub struct UrlDedupCleanupReport {
     pub slot_product_details_removed: u64,
     pub remaining_slot_duplicates_products: u64,
     pub remaining_slot_duplicates_product_details: u64,
+    // Near-duplicate merge pass; rows with pending field conflicts are left for review
+    pub conflicts: DedupScanReport,
+    // Space reclamation after deletions (None when the storage snapshot failed)
+    pub vacuum: Option<VacuumReport>,
 }
 
-async fn delete_dupes_in_table(pool: &sqlx::SqlitePool, table: &str) -> Result<u64, String> {
+async fn delete_dupes_in_table(
+    pool: &sqlx::SqlitePool,
+    table: &str,
+    held_urls: &str,
+) -> Result<u64, String> {
     // Delete rows whose url duplicates exist, keeping the lowest rowid per url
+    // (urls awaiting a conflict decision are skipped)
     let sql = format!(
         r#"
         WITH dupes AS (
@@ -30,12 +47,14 @@ async fn delete_dupes_in_table(pool: &sqlx::SqlitePool, table: &str) -> Result<u
         )
         DELETE FROM {table}
         WHERE url IN (SELECT url FROM dupes)
-          AND rowid NOT IN (SELECT keep_rowid FROM dupes);
+          AND rowid NOT IN (SELECT keep_rowid FROM dupes)
+          AND url NOT IN (SELECT value FROM json_each(?));
         "#,
         table = table
     );
 
     let res: SqliteQueryResult = sqlx::query(&sql)
+        .bind(held_urls)
         .execute(pool)
         .await
         .map_err(|e| e.to_string())?;
@@ -64,7 +83,11 @@ async fn count_remaining_dupes(pool: &sqlx::SqlitePool, table: &str) -> Result<u
     Ok(remain as u64)
 }
 
-async fn delete_slot_dupes_in_table(pool: &sqlx::SqlitePool, table: &str) -> Result<u64, String> {
+async fn delete_slot_dupes_in_table(
+    pool: &sqlx::SqlitePool,
+    table: &str,
+    held_urls: &str,
+) -> Result<u64, String> {
     // Delete rows that collide on (page_id, index_in_page), keep the lowest rowid per slot
     let sql = format!(
         r#"
@@ -76,12 +99,14 @@ async fn delete_slot_dupes_in_table(pool: &sqlx::SqlitePool, table: &str) -> Res
         )
         DELETE FROM {table}
         WHERE page_id IS NOT NULL AND index_in_page IS NOT NULL
-          AND rowid NOT IN (SELECT keep_rowid FROM kept);
+          AND rowid NOT IN (SELECT keep_rowid FROM kept)
+          AND url NOT IN (SELECT value FROM json_each(?));
         "#,
         table = table
     );
 
     let res: SqliteQueryResult = sqlx::query(&sql)
+        .bind(held_urls)
         .execute(pool)
         .await
         .map_err(|e| e.to_string())?;
@@ -111,7 +136,9 @@ async fn count_remaining_slot_dupes(pool: &sqlx::SqlitePool, table: &str) -> Res
 }
 
 /// Remove duplicate rows by exact URL for both products and product_details.
-/// Keeps the first inserted row (by rowid) and deletes the rest.
+/// Near-duplicates are first merged through the dedup conflict queue; rows whose field
+/// values still conflict are kept until resolved. Otherwise keeps the first inserted row
+/// (by rowid) and deletes the rest.
 #[tauri::command(async)]
 pub async fn cleanup_duplicate_urls(
     _app: AppHandle,
@@ -122,13 +149,36 @@ pub async fn cleanup_duplicate_urls(
         .await
         .map_err(|e| format!("DB pool unavailable: {e}"))?;
 
+    let maintenance_cfg = app_state.get_config().await.advanced.db_maintenance;
+    if maintenance_cfg.backup_before_destructive {
+        database_paths::backup_database(
+            &pool,
+            "cleanup_duplicate_urls",
+            maintenance_cfg.max_backups,
+        )
+        .await
+        .map_err(|e| format!("Backup before cleanup failed: {e:#}"))?;
+    }
+    let storage_before = db_maintenance::storage_snapshot(&pool).await.ok();
+
+    // Pass 0: near-duplicate merge; conflicting groups are queued and held back
+    let conflicts = dedup_conflicts::scan_conflicts(&pool)
+        .await
+        .map_err(|e| format!("Dedup conflict scan failed: {e:#}"))?;
+    let held_urls = dedup_conflicts::pending_conflict_urls(&pool)
+        .await
+        .map_err(|e| format!("Failed to load pending conflict urls: {e:#}"))?;
+    let held_urls = serde_json::to_string(&held_urls).map_err(|e| e.to_string())?;
+
     // Pass 1: URL-based dedup
-    let products_removed = delete_dupes_in_table(&pool, "products").await?;
-    let product_details_removed = delete_dupes_in_table(&pool, "product_details").await?;
+    let products_removed = delete_dupes_in_table(&pool, "products", &held_urls).await?;
+    let product_details_removed =
+        delete_dupes_in_table(&pool, "product_details", &held_urls).await?;
 
     // Pass 2: Slot-based dedup (page_id, index_in_page)
-    let slot_products_removed = delete_slot_dupes_in_table(&pool, "products").await?;
-    let slot_product_details_removed = delete_slot_dupes_in_table(&pool, "product_details").await?;
+    let slot_products_removed = delete_slot_dupes_in_table(&pool, "products", &held_urls).await?;
+    let slot_product_details_removed =
+        delete_slot_dupes_in_table(&pool, "product_details", &held_urls).await?;
 
     // Remaining duplicate counts (post-commit)
     let remaining_duplicates_products = count_remaining_dupes(&pool, "products").await?;
@@ -139,6 +189,25 @@ pub async fn cleanup_duplicate_urls(
     let remaining_slot_duplicates_product_details =
         count_remaining_slot_dupes(&pool, "product_details").await?;
 
+    let rows_deleted = conflicts.rows_removed
+        + products_removed
+        + product_details_removed
+        + slot_products_removed
+        + slot_product_details_removed;
+    let vacuum = match storage_before {
+        Some(before) if rows_deleted > 0 => Some(
+            db_maintenance::reclaim_after_deletion(
+                &pool,
+                &maintenance_cfg,
+                "cleanup_duplicate_urls",
+                rows_deleted,
+                &before,
+            )
+            .await,
+        ),
+        _ => None,
+    };
+
     Ok(UrlDedupCleanupReport {
         products_removed,
         product_details_removed,
@@ -148,5 +217,120 @@ pub async fn cleanup_duplicate_urls(
         slot_product_details_removed,
         remaining_slot_duplicates_products,
         remaining_slot_duplicates_product_details,
+        conflicts,
+        vacuum,
     })
 }
+
+/// Candidate duplicate groups by product identity (certificate_id or vid/pid/model) rather
+/// than exact URL, e.g. the same product stored under a trailing-slash or query variant
+#[tauri::command(async)]
+pub async fn analyze_identity_duplicates(
+    app_state: State<'_, AppState>,
+    options: Option<QueryOptions>,
+) -> Result<IdentityDedupReport, CommandError> {
+    run_query("analyze_identity_duplicates", options, |_| async move {
+        let pool = app_state
+            .get_database_pool()
+            .await
+            .map_err(|e| format!("DB pool unavailable: {e}"))?;
+        identity_dedup::analyze(&pool)
+            .await
+            .map_err(|e| format!("Identity duplicate analysis failed: {e:#}"))
+    })
+    .await
+}
+
+/// Merge the identity duplicate groups led by `keepers` (each group's first URL in the
+/// analysis report) into that URL. Groups with differing certificate_ids or pending dedup
+/// conflicts are skipped; `dry_run` reports without writing.
+#[tauri::command(async)]
+pub async fn merge_identity_duplicates(
+    app_state: State<'_, AppState>,
+    keepers: Vec<String>,
+    dry_run: Option<bool>,
+) -> Result<IdentityMergeReport, String> {
+    if keepers.is_empty() {
+        return Err("Select at least one duplicate group to merge".into());
+    }
+    let dry_run = dry_run.unwrap_or(false);
+    let running = SyncCancellationRegistry::shared().active_sessions();
+    if !running.is_empty() {
+        return Err(format!(
+            "Duplicates cannot be merged while sync is running: {}",
+            running.join(", ")
+        ));
+    }
+    if app_state.is_crawling_active().await {
+        return Err("Duplicates cannot be merged while a crawl is running".into());
+    }
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+
+    let maintenance_cfg = app_state.get_config().await.advanced.db_maintenance;
+    let backup_file = if !dry_run && maintenance_cfg.backup_before_destructive {
+        let backup = database_paths::backup_database(
+            &pool,
+            "merge_identity_duplicates",
+            maintenance_cfg.max_backups,
+        )
+        .await
+        .map_err(|e| format!("Backup before merge failed: {e:#}"))?;
+        Some(backup.file_name)
+    } else {
+        None
+    };
+
+    let mut report = identity_dedup::merge(&pool, &keepers, dry_run)
+        .await
+        .map_err(|e| format!("Identity duplicate merge failed: {e:#}"))?;
+    report.backup_file = backup_file;
+    Ok(report)
+}
+
+/// Rewrite stored product URLs to their canonical form (one-time migration for rows written
+/// before URL normalization). Variants of an already stored URL are merged into it;
+/// `dry_run` reports without writing.
+#[tauri::command(async)]
+pub async fn normalize_product_urls(
+    app_state: State<'_, AppState>,
+    dry_run: Option<bool>,
+) -> Result<UrlNormalizationReport, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    let running = SyncCancellationRegistry::shared().active_sessions();
+    if !running.is_empty() {
+        return Err(format!(
+            "URLs cannot be normalized while sync is running: {}",
+            running.join(", ")
+        ));
+    }
+    if app_state.is_crawling_active().await {
+        return Err("URLs cannot be normalized while a crawl is running".into());
+    }
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+
+    let maintenance_cfg = app_state.get_config().await.advanced.db_maintenance;
+    let backup_file = if !dry_run && maintenance_cfg.backup_before_destructive {
+        let backup = database_paths::backup_database(
+            &pool,
+            "normalize_product_urls",
+            maintenance_cfg.max_backups,
+        )
+        .await
+        .map_err(|e| format!("Backup before URL normalization failed: {e:#}"))?;
+        Some(backup.file_name)
+    } else {
+        None
+    };
+
+    let mut report = url_normalization::normalize_stored_urls(&pool, dry_run)
+        .await
+        .map_err(|e| format!("URL normalization failed: {e:#}"))?;
+    report.backup_file = backup_file;
+    Ok(report)
+}
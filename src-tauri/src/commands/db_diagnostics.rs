@@ -1,5 +1,7 @@
 use crate::application::AppState;
 use crate::application::shared_state::SharedStateCache;
+use crate::domain::command_error::CommandError;
+use crate::infrastructure::query_control::{QueryCancellation, QueryOptions, run_query};
 // (no additional infrastructure imports needed)
 use serde::Serialize;
 use sqlx::Row;
@@ -57,10 +59,28 @@ pub struct PrepassSummary {
 /// - For non-terminal groups (page_id < max_page_id_db): count must be 12 and indices must be 0..11 (no holes/dupes)
 /// - For terminal group (page_id == max_page_id_db): indices must be contiguous starting from 0 (0..count-1)
 /// - index_in_page must be within [0, 11]
+///
+/// `options` sets a timeout / cancellable query id; the group pass yields every
+/// `SCAN_CHECKPOINT_GROUPS` groups so a timeout or cancel stops it mid-scan.
 #[tauri::command(async)]
 pub async fn scan_db_pagination_mismatches(
     _app: AppHandle,
     app_state: State<'_, AppState>,
+    options: Option<QueryOptions>,
+) -> Result<DbPaginationMismatchReport, CommandError> {
+    run_query("scan_db_pagination_mismatches", options, |cancel| {
+        pagination_mismatch_scan(_app, app_state, cancel)
+    })
+    .await
+}
+
+/// Page groups evaluated between cancellation checkpoints
+const SCAN_CHECKPOINT_GROUPS: usize = 256;
+
+async fn pagination_mismatch_scan(
+    _app: AppHandle,
+    app_state: State<'_, AppState>,
+    cancel: QueryCancellation,
 ) -> Result<DbPaginationMismatchReport, String> {
     info!(target: "db_diagnostics", "scan_db_pagination_mismatches: start");
     let pool = app_state
@@ -237,7 +257,10 @@ pub async fn scan_db_pagination_mismatches(
     let mut group_summaries: Vec<GroupSummary> = Vec::new();
     let mut duplicate_positions: Vec<DuplicatePosition> = Vec::new();
 
-    for (pid, items) in by_pid.iter() {
+    for (group_no, (pid, items)) in by_pid.iter().enumerate() {
+        if group_no % SCAN_CHECKPOINT_GROUPS == 0 {
+            cancel.checkpoint().await?;
+        }
         let count = items.len() as u32;
         let terminal = *pid == max_page_id_db;
         let expected_count = if terminal { count } else { 12 };
@@ -338,3 +361,100 @@ pub async fn scan_db_pagination_mismatches(
     info!(target: "db_diagnostics", total_products = report.total_products, groups = report.group_summaries.len(), dup_positions = report.duplicate_positions.len(), "scan_db_pagination_mismatches: done");
     Ok(report)
 }
+
+/// Writes rejected by strict coordinate mode (most recent last).
+/// Empty unless `advanced.strict_coordinate_mode` is enabled.
+#[tauri::command(async)]
+pub async fn get_coordinate_violations()
+-> Result<Vec<crate::infrastructure::coordinate_guard::CoordinateViolation>, String> {
+    Ok(crate::infrastructure::coordinate_guard::recent_violations())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DbRuntimeSettings {
+    /// Pragmas reported by a live pool connection
+    pub journal_mode: String,
+    pub busy_timeout_ms: i64,
+    pub synchronous: String,
+    pub foreign_keys: bool,
+    /// `advanced.sqlite` as currently configured (applies to pools created after it changed)
+    pub configured: crate::infrastructure::config::SqliteRuntimeConfig,
+    pub pool_size: u32,
+    pub pool_idle: usize,
+}
+
+/// Active SQLite pragmas on the shared pool, alongside the configured values
+#[tauri::command(async)]
+pub async fn get_db_runtime_settings(
+    app_state: State<'_, AppState>,
+) -> Result<DbRuntimeSettings, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    // Pragmas are per-connection: read them all on the same one
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| format!("Failed to acquire connection: {e}"))?;
+    let journal_mode: String = sqlx::query_scalar("PRAGMA journal_mode")
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| format!("PRAGMA journal_mode failed: {e}"))?;
+    let busy_timeout_ms: i64 = sqlx::query_scalar("PRAGMA busy_timeout")
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| format!("PRAGMA busy_timeout failed: {e}"))?;
+    let synchronous: i64 = sqlx::query_scalar("PRAGMA synchronous")
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| format!("PRAGMA synchronous failed: {e}"))?;
+    let foreign_keys: i64 = sqlx::query_scalar("PRAGMA foreign_keys")
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| format!("PRAGMA foreign_keys failed: {e}"))?;
+    drop(conn);
+
+    Ok(DbRuntimeSettings {
+        journal_mode: journal_mode.to_uppercase(),
+        busy_timeout_ms,
+        synchronous: match synchronous {
+            0 => "OFF".to_string(),
+            1 => "NORMAL".to_string(),
+            2 => "FULL".to_string(),
+            3 => "EXTRA".to_string(),
+            other => other.to_string(),
+        },
+        foreign_keys: foreign_keys != 0,
+        configured: crate::infrastructure::database_connection::current_sqlite_settings(),
+        pool_size: pool.size(),
+        pool_idle: pool.num_idle(),
+    })
+}
+
+/// Queue depth and flush latency of the shared ProductDetail write-behind queue
+#[tauri::command(async)]
+pub async fn get_persistence_queue_metrics(
+    app_state: State<'_, AppState>,
+) -> Result<crate::infrastructure::persistence_queue::PersistenceQueueMetrics, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    Ok(crate::infrastructure::persistence_queue::shared_queue(&pool).metrics())
+}
+
+/// Commit everything currently queued (e.g. before reading counts or shutting down)
+#[tauri::command(async)]
+pub async fn flush_persistence_queue(
+    app_state: State<'_, AppState>,
+) -> Result<crate::infrastructure::persistence_queue::FlushReport, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    crate::infrastructure::persistence_queue::shared_queue(&pool)
+        .flush()
+        .await
+        .map_err(|e| format!("Persistence queue flush failed: {e:#}"))
+}
@@ -0,0 +1,80 @@
+//! 중복 병합 충돌 큐 명령어 (scan / list / per-field resolve / bulk strategy)
+
+use crate::application::AppState;
+use crate::services::dedup_conflicts::{
+    self, ConflictStatus, DedupConflict, DedupResolveReport, DedupScanReport, ResolutionStrategy,
+};
+use tauri::State;
+
+/// Upper bound for one `get_dedup_conflicts` page
+const MAX_CONFLICTS_PER_CALL: u32 = 500;
+
+/// Queue field conflicts between near-duplicate rows and merge groups that need no decision
+#[tauri::command(async)]
+pub async fn scan_dedup_conflicts(
+    app_state: State<'_, AppState>,
+) -> Result<DedupScanReport, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    dedup_conflicts::scan_conflicts(&pool)
+        .await
+        .map_err(|e| format!("Dedup conflict scan failed: {e:#}"))
+}
+
+/// List queued conflicts (default: pending only), optionally for one dedup key
+#[tauri::command(async)]
+pub async fn get_dedup_conflicts(
+    app_state: State<'_, AppState>,
+    status: Option<ConflictStatus>,
+    dedup_key: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<DedupConflict>, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    dedup_conflicts::list_conflicts(
+        &pool,
+        Some(status.unwrap_or(ConflictStatus::Pending)),
+        dedup_key.as_deref(),
+        limit
+            .unwrap_or(MAX_CONFLICTS_PER_CALL)
+            .min(MAX_CONFLICTS_PER_CALL),
+    )
+    .await
+    .map_err(|e| format!("Failed to list dedup conflicts: {e:#}"))
+}
+
+/// Resolve one field conflict with a value chosen from its candidates
+#[tauri::command(async)]
+pub async fn resolve_dedup_conflict(
+    app_state: State<'_, AppState>,
+    conflict_id: String,
+    value: String,
+) -> Result<DedupResolveReport, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    dedup_conflicts::resolve_conflict(&pool, &conflict_id, &value)
+        .await
+        .map_err(|e| format!("Failed to resolve dedup conflict: {e:#}"))
+}
+
+/// Resolve all pending conflicts (or one dedup key's) with most_recent_wins / most_complete_wins
+#[tauri::command(async)]
+pub async fn apply_dedup_strategy(
+    app_state: State<'_, AppState>,
+    strategy: ResolutionStrategy,
+    dedup_key: Option<String>,
+) -> Result<DedupResolveReport, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    dedup_conflicts::apply_strategy(&pool, strategy, dedup_key.as_deref())
+        .await
+        .map_err(|e| format!("Failed to apply dedup strategy: {e:#}"))
+}
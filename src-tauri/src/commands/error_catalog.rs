@@ -0,0 +1,41 @@
+//! 에러 코드 카탈로그 조회 커맨드
+//!
+//! 실패 이벤트의 `error_code` 를 사람이 읽을 수 있는 설명과 조치 안내로, `SyncWarning.code` 를
+//! 심각도와 설명으로 풀어 준다.
+
+use crate::domain::error_catalog::{ErrorCode, ErrorDescription};
+use crate::domain::warning_catalog::{WarningCode, WarningDescription};
+
+/// Title, category and remediation hints for an error code (`E_RATE_LIMITED` or `RATE_LIMITED`)
+#[tauri::command(async)]
+pub async fn describe_error(code: String) -> Result<ErrorDescription, String> {
+    ErrorCode::from_code(&code)
+        .map(ErrorCode::describe)
+        .ok_or_else(|| format!("Unknown error code: {code}"))
+}
+
+/// Full catalog, e.g. for building a legend in the UI
+#[tauri::command(async)]
+pub async fn list_error_codes() -> Result<Vec<ErrorDescription>, String> {
+    Ok(ErrorCode::ALL
+        .into_iter()
+        .map(ErrorCode::describe)
+        .collect())
+}
+
+/// Severity, title and documentation for a sync warning code (`count_mismatch`)
+#[tauri::command(async)]
+pub async fn describe_warning(code: String) -> Result<WarningDescription, String> {
+    WarningCode::from_code(&code)
+        .map(WarningCode::describe)
+        .ok_or_else(|| format!("Unknown warning code: {code}"))
+}
+
+/// Every sync warning code with its metadata
+#[tauri::command(async)]
+pub async fn list_warning_codes() -> Result<Vec<WarningDescription>, String> {
+    Ok(WarningCode::ALL
+        .iter()
+        .map(|code| code.describe())
+        .collect())
+}
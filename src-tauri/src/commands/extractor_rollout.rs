@@ -0,0 +1,56 @@
+//! 추출 규칙 shadow 비교 조회 / 승격 / 롤백 명령어 (get_extractor_rollout /
+//! promote_extractor_rules / rollback_extractor_rules)
+
+use crate::application::AppState;
+use crate::infrastructure::config::ConfigManager;
+use crate::infrastructure::extractor_rollout::{self, RolloutComparison, RolloutSnapshot};
+use tauri::State;
+use tracing::info;
+
+/// Promoted rules and the latest rollout with its field-level comparison
+#[tauri::command(async)]
+pub async fn get_extractor_rollout() -> Result<RolloutSnapshot, String> {
+    Ok(extractor_rollout::snapshot())
+}
+
+/// Make the shadowing rules the promoted ones; extractors built from now on use them
+#[tauri::command(async)]
+pub async fn promote_extractor_rules() -> Result<RolloutComparison, String> {
+    let rollout = extractor_rollout::promote().map_err(|e| e.to_string())?;
+    info!(
+        "🧪 Promoted extraction rules '{}' after {} compared pages",
+        rollout.candidate.site_profile, rollout.pages_compared
+    );
+    Ok(rollout)
+}
+
+/// Drop the shadowing rules and point `advanced.site_profile` back at the promoted profile
+#[tauri::command(async)]
+pub async fn rollback_extractor_rules(
+    app_state: State<'_, AppState>,
+) -> Result<RolloutComparison, String> {
+    let Some(rollout) = extractor_rollout::snapshot()
+        .rollout
+        .filter(RolloutComparison::is_open)
+    else {
+        return Err("No extractor rule rollout in progress".into());
+    };
+    let manager =
+        ConfigManager::new().map_err(|e| format!("Failed to init config manager: {e}"))?;
+    let mut config = manager
+        .load_config()
+        .await
+        .map_err(|e| format!("Failed to load config: {e}"))?;
+    config.advanced.site_profile = rollout.baseline.site_profile.clone();
+    manager
+        .save_config(&config)
+        .await
+        .map_err(|e| format!("Failed to save config: {e}"))?;
+    app_state.update_config(config).await?;
+    let rollout = extractor_rollout::rollback().map_err(|e| e.to_string())?;
+    info!(
+        "🧪 Rolled back extraction rules '{}' to '{}'",
+        rollout.candidate.site_profile, rollout.baseline.site_profile
+    );
+    Ok(rollout)
+}
@@ -0,0 +1,238 @@
+//! 영구 실패 항목 dead-letter 큐 명령어 (list_failed_items / requeue_failed_items)
+
+use crate::application::AppState;
+use crate::domain::command_error::CommandError;
+use crate::domain::product_url::ProductUrl;
+use crate::infrastructure::crawling_service_impls::{
+    BoundedDetailFetch, CollectorConfig, DetailFetchError, ProductDetailCollectorImpl,
+};
+use crate::infrastructure::persistence_queue;
+use crate::infrastructure::query_control::{QueryOptions, run_query};
+use crate::infrastructure::{
+    html_parser::MatterDataExtractor, site_profiles::resolve_site_profile,
+};
+use crate::services::failed_items::{
+    self, DeadLetter, FailedAttempt, FailedItem, FailedItemFilter, FailedItemStage,
+    FailedItemStatus,
+};
+use crate::services::page_revalidation;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tauri::State;
+use tracing::{info, warn};
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RequeueReport {
+    pub selected: u32,
+    /// List pages queued for the next sync's revalidation pass
+    pub pages_queued: u32,
+    /// Detail URLs re-fetched and saved
+    pub resolved: u32,
+    /// Detail URLs that failed again (back to pending with the new attempts)
+    pub failed_again: u32,
+    /// Already resolved, or a payload without the coordinates a replay needs
+    pub skipped: u32,
+}
+
+/// Dead-lettered items, newest failures first. `options` sets a timeout / cancellable query id.
+#[tauri::command(async)]
+pub async fn list_failed_items(
+    app_state: State<'_, AppState>,
+    filter: Option<FailedItemFilter>,
+    options: Option<QueryOptions>,
+) -> Result<Vec<FailedItem>, CommandError> {
+    run_query("list_failed_items", options, |_| async move {
+        let pool = app_state
+            .get_database_pool()
+            .await
+            .map_err(|e| format!("DB pool unavailable: {e}"))?;
+        failed_items::list(&pool, &filter.unwrap_or_default())
+            .await
+            .map_err(|e| format!("Failed to list failed items: {e}"))
+    })
+    .await
+}
+
+/// Replay dead-lettered items: `ids` picks them individually, otherwise every item matching
+/// `filter` (pending only unless a status is given). List pages are handed to the next sync;
+/// detail URLs are fetched again right away.
+#[tauri::command(async)]
+pub async fn requeue_failed_items(
+    app_state: State<'_, AppState>,
+    ids: Option<Vec<i64>>,
+    filter: Option<FailedItemFilter>,
+) -> Result<RequeueReport, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let items = match ids {
+        Some(ids) => failed_items::load(&pool, &ids).await,
+        None => {
+            let mut filter = filter.unwrap_or_default();
+            filter.status.get_or_insert(FailedItemStatus::Pending);
+            failed_items::list(&pool, &filter).await
+        }
+    }
+    .map_err(|e| format!("Failed to load failed items: {e}"))?;
+
+    let mut report = RequeueReport {
+        selected: items.len() as u32,
+        ..RequeueReport::default()
+    };
+    let mut details: Vec<(ProductUrl, FailedItem)> = Vec::new();
+    for item in items {
+        if item.status == FailedItemStatus::Resolved {
+            report.skipped += 1;
+            continue;
+        }
+        let page_id = item.payload["page_id"].as_i64();
+        match item.stage {
+            FailedItemStage::ListPage => {
+                let physical_page = item.payload["physical_page"].as_u64();
+                let (Some(page_id), Some(physical_page)) = (page_id, physical_page) else {
+                    report.skipped += 1;
+                    continue;
+                };
+                page_revalidation::mark(
+                    &pool,
+                    &item.session_id,
+                    physical_page as u32,
+                    page_id,
+                    &item.last_error,
+                )
+                .await
+                .map_err(|e| format!("Failed to queue page {page_id}: {e}"))?;
+                failed_items::set_status(&pool, item.id, FailedItemStatus::Requeued)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                report.pages_queued += 1;
+            }
+            FailedItemStage::ProductDetail => {
+                let index_in_page = item.payload["index_in_page"].as_i64();
+                let (Some(page_id), Some(index_in_page)) = (page_id, index_in_page) else {
+                    report.skipped += 1;
+                    continue;
+                };
+                let url = item.payload["url"]
+                    .as_str()
+                    .unwrap_or(&item.item_key)
+                    .to_string();
+                let product_url = ProductUrl {
+                    url,
+                    page_id: page_id as i32,
+                    index_in_page: index_in_page as i32,
+                };
+                details.push((product_url, item));
+            }
+        }
+    }
+    if !details.is_empty() {
+        replay_details(&app_state, &pool, details, &mut report).await?;
+    }
+    info!(
+        "🪦 requeue_failed_items: selected={} pages_queued={} resolved={} failed_again={} skipped={}",
+        report.selected, report.pages_queued, report.resolved, report.failed_again, report.skipped
+    );
+    Ok(report)
+}
+
+/// Fetch dead-lettered detail URLs with the detail retry policy; saved ones are resolved,
+/// the rest are recorded again with this replay's attempts
+async fn replay_details(
+    app_state: &AppState,
+    pool: &sqlx::SqlitePool,
+    details: Vec<(ProductUrl, FailedItem)>,
+    report: &mut RequeueReport,
+) -> Result<(), String> {
+    let http = app_state.get_http_client().await?;
+    let app_config = app_state.get_config().await;
+    let extractor = MatterDataExtractor::with_profile(resolve_site_profile(&app_config))
+        .map_err(|e| e.to_string())?;
+    let detail_policy = app_config.advanced.retry_policies.detail().clone();
+    let retry_count =
+        detail_policy.max_retries_or(app_config.user.crawling.product_detail_retry_count.max(1));
+    let max_concurrent = app_config
+        .user
+        .crawling
+        .workers
+        .product_detail_max_concurrent
+        .max(1) as u32;
+    let referer = extractor.site_profile().listing_base_url().to_string();
+    let collector = ProductDetailCollectorImpl::new(
+        Arc::new(http),
+        Arc::new(extractor),
+        CollectorConfig {
+            max_concurrent,
+            concurrency: max_concurrent,
+            retry_attempts: retry_count,
+            retry_max: retry_count,
+            ..CollectorConfig::default()
+        },
+    );
+
+    let attempts: Mutex<HashMap<String, Vec<FailedAttempt>>> = Mutex::default();
+    let on_failure =
+        |product_url: &ProductUrl, attempt: u32, max_attempts: u32, e: &DetailFetchError| {
+            attempts
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .entry(product_url.url.clone())
+                .or_default()
+                .push(FailedAttempt::now(attempt, e.to_string()));
+            (attempt < max_attempts).then(|| detail_policy.delay(attempt))
+        };
+    let fetch = BoundedDetailFetch {
+        user_agent_override: app_config.user.crawling.workers.user_agent_sync.clone(),
+        referer: Some(referer),
+        on_failure: &on_failure,
+    };
+    let targets: Vec<ProductUrl> = details.iter().map(|(url, _)| url.clone()).collect();
+    let outcomes = collector.collect_details_bounded(&targets, &fetch).await;
+
+    let queue = persistence_queue::shared_queue(pool);
+    let mut fetched = Vec::new();
+    for (outcome, (_, item)) in outcomes.into_iter().zip(details) {
+        match outcome.result {
+            Ok(detail) => {
+                queue
+                    .enqueue([detail])
+                    .await
+                    .map_err(|e| format!("Failed to queue detail: {e}"))?;
+                fetched.push(item.id);
+            }
+            Err(e) => {
+                let history = attempts
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .remove(&outcome.product_url.url)
+                    .unwrap_or_default();
+                let letter = DeadLetter {
+                    stage: FailedItemStage::ProductDetail,
+                    item_key: item.item_key,
+                    session_id: item.session_id,
+                    error_class: e.error_code(),
+                    attempts: history,
+                    payload: item.payload,
+                };
+                if let Err(e) = failed_items::record(pool, &letter).await {
+                    warn!("🪦 Failed to re-record {}: {}", letter.item_key, e);
+                }
+                report.failed_again += 1;
+            }
+        }
+    }
+    // Only resolve once the rows are actually written
+    queue
+        .flush()
+        .await
+        .map_err(|e| format!("Failed to save re-fetched details: {e}"))?;
+    for id in fetched {
+        failed_items::set_status(pool, id, FailedItemStatus::Resolved)
+            .await
+            .map_err(|e| e.to_string())?;
+        report.resolved += 1;
+    }
+    Ok(())
+}
@@ -0,0 +1,105 @@
+//! 선택 필드 재수집 명령어 (refresh_fields)
+
+use crate::application::AppState;
+use crate::crawl_engine::runtime::task_registry::{PAGE_WORKER_LIFETIME, spawn_tracked};
+use crate::infrastructure::{
+    html_parser::MatterDataExtractor, simple_http_client::RequestOptions,
+    site_profiles::resolve_site_profile,
+};
+use crate::services::field_refresh::{self, FieldRefreshFilter, FieldRefreshReport, RefreshField};
+use scraper::Html;
+use std::sync::Arc;
+use tauri::State;
+use tokio::sync::Semaphore;
+use tracing::{info, warn};
+
+/// Re-fetch the detail pages matching `filter` and rewrite only `fields`; other columns are
+/// left untouched. `dry_run` fetches and counts but rolls every write back.
+#[tauri::command(async)]
+pub async fn refresh_fields(
+    app_state: State<'_, AppState>,
+    fields: Vec<RefreshField>,
+    filter: FieldRefreshFilter,
+    dry_run: Option<bool>,
+) -> Result<FieldRefreshReport, String> {
+    let fields = field_refresh::normalize_fields(&fields);
+    filter.validate(&fields).map_err(|e| e.to_string())?;
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let http = app_state.get_http_client().await?;
+    let app_config = app_state.get_config().await;
+    let extractor = MatterDataExtractor::with_profile(resolve_site_profile(&app_config))
+        .map_err(|e| e.to_string())?;
+    let sync_ua = app_config.user.crawling.workers.user_agent_sync.clone();
+
+    let targets = field_refresh::select_targets(&pool, &fields, &filter)
+        .await
+        .map_err(|e| format!("Failed to select refresh targets: {e}"))?;
+    let mut report =
+        FieldRefreshReport::new(&fields, targets.len() as u32, dry_run.unwrap_or(false));
+    info!(
+        "🪄 refresh_fields: {} rows, fields={:?}, dry_run={}",
+        targets.len(),
+        fields,
+        report.dry_run
+    );
+
+    let max_concurrent = app_config
+        .user
+        .crawling
+        .workers
+        .product_detail_max_concurrent
+        .max(1);
+    let semaphore = Arc::new(Semaphore::new(max_concurrent));
+    let referer = extractor.site_profile().listing_base_url().to_string();
+    let mut handles = Vec::with_capacity(targets.len());
+    for url in targets {
+        let permit = semaphore.clone().acquire_owned();
+        let http_c = http.clone();
+        let extractor_c = extractor.clone();
+        let options = RequestOptions {
+            user_agent_override: sync_ua.clone(),
+            referer: Some(referer.clone()),
+            skip_robots_check: false,
+            attempt: None,
+            max_attempts: None,
+        };
+        let task_name = format!("field-refresh:{}", url);
+        let handle = spawn_tracked(task_name, Some(PAGE_WORKER_LIFETIME), async move {
+            let _p = permit.await.ok()?;
+            let body = http_c
+                .fetch_response_with_options(&url, &options)
+                .await
+                .ok()?
+                .text()
+                .await
+                .ok()?;
+            let doc = Html::parse_document(&body);
+            let detail = extractor_c.extract_product_detail(&doc, url.clone()).ok()?;
+            Some((url, detail))
+        });
+        handles.push(handle);
+    }
+
+    // Writes stay on this task so the per-field counts need no locking
+    for handle in handles {
+        let Ok(Some((url, detail))) = handle.await else {
+            report.failed += 1;
+            continue;
+        };
+        match field_refresh::apply_fields(&pool, &url, &detail, &mut report).await {
+            Ok(()) => report.fetched += 1,
+            Err(e) => {
+                warn!("🪄 refresh_fields write failed for {}: {}", url, e);
+                report.failed += 1;
+            }
+        }
+    }
+    info!(
+        "🪄 refresh_fields done: fetched={} failed={}",
+        report.fetched, report.failed
+    );
+    Ok(report)
+}
@@ -0,0 +1,328 @@
+//! incremental 크롤 프로필 (마지막 실행 이후 신규 제품만 수집)
+//!
+//! 사이트 전체 제품 수(페이지 수 × 12 + 마지막 페이지 제품 수)와 로컬 DB head(max page_id /
+//! index_in_page)를 비교해 신규 제품이 들어 있을 최소 물리 페이지 창을 추정한다. 이후 최신
+//! 페이지부터 목록만 조회하며 이미 DB에 있는 URL이 `known_url_stop_threshold` 개 연속으로
+//! 나오면 조기 종료하고, 신규 URL이 발견된 페이지까지만 partial sync로 위임한다.
+
+use crate::application::AppState;
+use crate::domain::site_profile::SiteProfile;
+use crate::infrastructure::config::IncrementalCrawlConfig;
+use crate::infrastructure::html_parser::MatterDataExtractor;
+use crate::infrastructure::simple_http_client::{HttpClient, RequestOptions};
+use crate::infrastructure::site_profiles::resolve_site_profile;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::collections::HashSet;
+use tauri::{AppHandle, State};
+use tracing::info;
+
+use super::sync_commands::{SyncSummary, start_partial_sync};
+
+const PRODUCTS_PER_PAGE: i64 = 12;
+
+/// Local DB head: newest stored product coordinates
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DbHead {
+    pub page_id: i64,
+    pub index_in_page: i64,
+}
+
+impl DbHead {
+    /// Products counted from the oldest one (page_id 0, index 0)
+    fn product_count(&self) -> i64 {
+        self.page_id * PRODUCTS_PER_PAGE + self.index_in_page + 1
+    }
+}
+
+/// Window an incremental run covers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncrementalCrawlPlan {
+    /// None when the DB is empty (every probed page is new)
+    pub db_head: Option<DbHead>,
+    pub site_total_pages: u32,
+    pub site_total_products: i64,
+    /// Products the site has beyond the DB head (negative after site-side removals)
+    pub estimated_new_products: i64,
+    /// Pages the DB-head comparison expects to hold new products
+    pub estimated_pages: u32,
+    /// Newest physical pages actually fetched while probing
+    pub probed_pages: u32,
+    /// Probe ended on `known_url_stop_threshold` consecutive known URLs
+    pub stopped_early: bool,
+    pub new_urls: u32,
+    /// Physical pages 1..=window are synced (0 = nothing new)
+    pub window_pages: u32,
+    /// Range expression handed to `start_partial_sync` (empty when nothing is new)
+    pub ranges: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncrementalCrawlReport {
+    pub plan: IncrementalCrawlPlan,
+    pub sync: Option<SyncSummary>,
+}
+
+/// Probe the newest pages and report the window without syncing
+#[tauri::command(async)]
+pub async fn preview_incremental_crawl(
+    app_state: State<'_, AppState>,
+) -> Result<IncrementalCrawlPlan, String> {
+    build_plan(&app_state).await
+}
+
+/// Sync only the pages holding products newer than the DB head
+#[tauri::command(async)]
+pub async fn run_incremental_crawl(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+) -> Result<IncrementalCrawlReport, String> {
+    let plan = build_plan(&app_state).await?;
+    info!(
+        "🆕 Incremental crawl: {} new URLs in {} pages (probed {}, early stop={}) ranges=\"{}\"",
+        plan.new_urls, plan.window_pages, plan.probed_pages, plan.stopped_early, plan.ranges
+    );
+    let sync = if plan.ranges.is_empty() {
+        None
+    } else {
+        Some(start_partial_sync(app, app_state, plan.ranges.clone(), Some(false), None).await?)
+    };
+    Ok(IncrementalCrawlReport { plan, sync })
+}
+
+async fn build_plan(app_state: &AppState) -> Result<IncrementalCrawlPlan, String> {
+    let app_config = app_state.get_config().await;
+    let cfg = app_config.advanced.incremental_crawl.clone();
+    let sync_ua = app_config.user.crawling.workers.user_agent_sync.clone();
+    let http = app_state.get_http_client().await?;
+    let site = resolve_site_profile(&app_config);
+    let extractor = MatterDataExtractor::with_profile(site.clone()).map_err(|e| e.to_string())?;
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+
+    let db_head = load_db_head(&pool).await.map_err(|e| e.to_string())?;
+
+    let newest_html = fetch_list_page(&http, &sync_ua, site.as_ref(), 1).await?;
+    let site_total_pages = extractor
+        .extract_total_pages(&newest_html)
+        .unwrap_or(1)
+        .max(1);
+    let oldest_html = if site_total_pages == 1 {
+        newest_html.clone()
+    } else {
+        fetch_list_page(&http, &sync_ua, site.as_ref(), site_total_pages).await?
+    };
+    let last_page_items = extractor
+        .extract_product_urls_from_content(&oldest_html)
+        .map_err(|e| e.to_string())?
+        .len() as i64;
+    let site_total_products = (site_total_pages as i64 - 1) * PRODUCTS_PER_PAGE + last_page_items;
+    let estimated_new_products =
+        site_total_products - db_head.map_or(0, |head| head.product_count());
+    let estimated_pages = estimate_pages(estimated_new_products, site_total_pages, &cfg);
+    let probe_limit = estimated_pages
+        .saturating_add(cfg.overscan_pages)
+        .min(cfg.max_pages.max(1))
+        .min(site_total_pages);
+
+    let mut tracker = KnownRunTracker::new(cfg.known_url_stop_threshold);
+    let mut probed_pages = 0;
+    for page in 1..=probe_limit {
+        let html = if page == 1 {
+            newest_html.clone()
+        } else {
+            fetch_list_page(&http, &sync_ua, site.as_ref(), page).await?
+        };
+        let urls = extractor
+            .extract_product_urls_from_content(&html)
+            .map_err(|e| e.to_string())?;
+        let known = known_urls(&pool, &urls).await.map_err(|e| e.to_string())?;
+        probed_pages = page;
+        if tracker.observe_page(page, &urls, |u| known.contains(u)) {
+            break;
+        }
+    }
+
+    let window_pages = tracker.window_pages();
+    Ok(IncrementalCrawlPlan {
+        db_head,
+        site_total_pages,
+        site_total_products,
+        estimated_new_products,
+        estimated_pages,
+        probed_pages,
+        stopped_early: tracker.stopped,
+        new_urls: tracker.new_urls,
+        window_pages,
+        ranges: if window_pages == 0 {
+            String::new()
+        } else if window_pages == 1 {
+            "1".to_string()
+        } else {
+            format!("{}-1", window_pages)
+        },
+    })
+}
+
+/// Pages that can hold `new_products` newest-first; always at least the newest page
+/// so site-side reordering is still noticed
+fn estimate_pages(new_products: i64, site_total_pages: u32, cfg: &IncrementalCrawlConfig) -> u32 {
+    let pages = if new_products <= 0 {
+        1
+    } else {
+        // +1: new items shift the older ones across a page boundary
+        (new_products + PRODUCTS_PER_PAGE - 1) / PRODUCTS_PER_PAGE + 1
+    };
+    (pages.min(site_total_pages as i64) as u32).clamp(1, cfg.max_pages.max(1))
+}
+
+/// Consecutive known-URL counter over list pages visited newest first
+#[derive(Debug)]
+struct KnownRunTracker {
+    threshold: u32,
+    consecutive_known: u32,
+    new_urls: u32,
+    last_page_with_new: Option<u32>,
+    stopped: bool,
+}
+
+impl KnownRunTracker {
+    fn new(threshold: u32) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            consecutive_known: 0,
+            new_urls: 0,
+            last_page_with_new: None,
+            stopped: false,
+        }
+    }
+
+    /// Returns true once `threshold` consecutive known URLs have been seen
+    fn observe_page(
+        &mut self,
+        page: u32,
+        urls: &[String],
+        is_known: impl Fn(&str) -> bool,
+    ) -> bool {
+        for url in urls {
+            if is_known(url) {
+                self.consecutive_known += 1;
+                if self.consecutive_known >= self.threshold {
+                    self.stopped = true;
+                    return true;
+                }
+            } else {
+                self.consecutive_known = 0;
+                self.new_urls += 1;
+                self.last_page_with_new = Some(page);
+            }
+        }
+        false
+    }
+
+    fn window_pages(&self) -> u32 {
+        self.last_page_with_new.unwrap_or(0)
+    }
+}
+
+async fn load_db_head(pool: &SqlitePool) -> Result<Option<DbHead>, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT page_id, index_in_page FROM products \
+         WHERE page_id IS NOT NULL AND index_in_page IS NOT NULL \
+         ORDER BY page_id DESC, index_in_page DESC LIMIT 1",
+    )
+    .fetch_optional(pool)
+    .await?;
+    Ok(row.map(|r| DbHead {
+        page_id: r.get::<i64, _>("page_id"),
+        index_in_page: r.get::<i64, _>("index_in_page"),
+    }))
+}
+
+async fn known_urls(pool: &SqlitePool, urls: &[String]) -> Result<HashSet<String>, sqlx::Error> {
+    if urls.is_empty() {
+        return Ok(HashSet::new());
+    }
+    let json = serde_json::to_string(urls).unwrap_or_else(|_| "[]".to_string());
+    let rows: Vec<String> = sqlx::query_scalar(
+        "SELECT url FROM products WHERE url IN (SELECT value FROM json_each(?))",
+    )
+    .bind(json)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().collect())
+}
+
+pub(crate) async fn fetch_list_page(
+    http: &HttpClient,
+    user_agent: &Option<String>,
+    site: &dyn SiteProfile,
+    page: u32,
+) -> Result<String, String> {
+    let url = site.list_page_url(page);
+    let resp = http
+        .fetch_response_with_options(
+            &url,
+            &RequestOptions {
+                user_agent_override: user_agent.clone(),
+                referer: Some(site.listing_base_url().to_string()),
+                skip_robots_check: false,
+                attempt: None,
+                max_attempts: None,
+            },
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    resp.text().await.map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn db_head_counts_from_oldest_product() {
+        let head = DbHead {
+            page_id: 2,
+            index_in_page: 3,
+        };
+        assert_eq!(head.product_count(), 28);
+    }
+
+    #[test]
+    fn estimate_covers_new_products_plus_boundary_page() {
+        let cfg = IncrementalCrawlConfig::default();
+        assert_eq!(estimate_pages(0, 400, &cfg), 1);
+        assert_eq!(estimate_pages(-5, 400, &cfg), 1);
+        assert_eq!(estimate_pages(1, 400, &cfg), 2);
+        assert_eq!(estimate_pages(24, 400, &cfg), 3);
+        assert_eq!(estimate_pages(10_000, 400, &cfg), cfg.max_pages);
+        assert_eq!(estimate_pages(100, 2, &cfg), 2);
+    }
+
+    #[test]
+    fn stops_after_consecutive_known_urls_and_keeps_last_new_page() {
+        let known: HashSet<&str> = ["k1", "k2", "k3", "k4"].into();
+        let mut tracker = KnownRunTracker::new(3);
+        assert!(!tracker.observe_page(1, &urls(&["n1", "n2", "k1"]), |u| known.contains(u)));
+        // a known URL followed by a new one resets the run
+        assert!(!tracker.observe_page(2, &urls(&["k2", "n3"]), |u| known.contains(u)));
+        assert!(tracker.observe_page(3, &urls(&["k3", "k4", "k1", "n4"]), |u| known.contains(u)));
+        assert!(tracker.stopped);
+        assert_eq!(tracker.new_urls, 3);
+        assert_eq!(tracker.window_pages(), 2);
+    }
+
+    #[test]
+    fn nothing_new_yields_empty_window() {
+        let mut tracker = KnownRunTracker::new(2);
+        assert!(tracker.observe_page(1, &urls(&["a", "b"]), |_| true));
+        assert_eq!(tracker.window_pages(), 0);
+    }
+}
@@ -0,0 +1,75 @@
+//! 인터랙티브 우선순위 부스트 명령어
+//!
+//! UI 에서 요청한 페이지/제품 URL 을 활성 세션의 백로그보다 먼저 크롤링하게 한다. 큐 동작은
+//! `crawl_engine::channels::priority` 참고. 이미 디스패치된 아이템에는 영향이 없다.
+
+use crate::crawl_engine::channels::priority::{self, ItemPriority};
+use crate::crawl_engine::runtime::session_registry::{SessionStatus, session_registry};
+use serde::Serialize;
+use tracing::info;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PrioritizeItemsResult {
+    pub session_id: String,
+    pub pages: Vec<u32>,
+    pub urls: Vec<String>,
+    pub priority: ItemPriority,
+}
+
+/// Session to boost: `requested` if it is still running, else the most recently started one
+async fn active_session(requested: Option<String>) -> Result<String, String> {
+    let registry = session_registry();
+    let sessions = registry.read().await;
+    let is_active =
+        |status: &SessionStatus| matches!(status, SessionStatus::Running | SessionStatus::Paused);
+    match requested {
+        Some(id) => match sessions.get(&id) {
+            Some(entry) if is_active(&entry.status) => Ok(id),
+            Some(_) => Err(format!("Session {id} is no longer running")),
+            None => Err(format!("Unknown session {id}")),
+        },
+        None => sessions
+            .iter()
+            .filter(|(_, entry)| is_active(&entry.status))
+            .max_by_key(|(_, entry)| entry.started_at)
+            .map(|(id, _)| id.clone())
+            .ok_or_else(|| "No active crawl session".to_string()),
+    }
+}
+
+/// Crawl the given list pages / product URLs ahead of the session backlog
+/// (`priority` defaults to `interactive`)
+#[tauri::command(async)]
+pub async fn prioritize_items(
+    session_id: Option<String>,
+    pages: Option<Vec<u32>>,
+    urls: Option<Vec<String>>,
+    priority: Option<ItemPriority>,
+) -> Result<PrioritizeItemsResult, String> {
+    let pages = pages.unwrap_or_default();
+    let urls: Vec<String> = urls
+        .unwrap_or_default()
+        .into_iter()
+        .map(|u| u.trim().to_string())
+        .filter(|u| !u.is_empty())
+        .collect();
+    if pages.is_empty() && urls.is_empty() {
+        return Err("Nothing to prioritize: pass pages and/or urls".into());
+    }
+    let priority = priority.unwrap_or(ItemPriority::Interactive);
+    let session_id = active_session(session_id).await?;
+    priority::boost_items(&session_id, &pages, &urls, priority);
+    info!(
+        "⏫ Session {} boosted {} pages / {} urls to {:?}",
+        session_id,
+        pages.len(),
+        urls.len(),
+        priority
+    );
+    Ok(PrioritizeItemsResult {
+        session_id,
+        pages,
+        urls,
+        priority,
+    })
+}
@@ -0,0 +1,182 @@
+//! 일일 경량 동기화(light-sync) 스케줄러
+//!
+//! `advanced.light_sync.enabled`가 켜져 있으면 주기적으로 최신 N개 물리 페이지만 partial sync로
+//! 갱신하고, 이후 DB의 page_id 그룹 이상치(cnt != 12)를 집계한다. 이상치가 임계치를 넘으면
+//! 큰 크롤을 조용히 돌리는 대신 `LightSyncReport { repair_recommended: true }`를 발행하여
+//! 사용자에게 repair sync 실행을 권고한다.
+
+use crate::application::AppState;
+use crate::crawl_engine::actors::types::{AppEvent, SyncAnomalyEntry};
+use crate::infrastructure::config::LightSyncSchedulerConfig;
+use chrono::Utc;
+use sqlx::{Row, SqlitePool};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use tracing::{info, warn};
+
+use super::sync_commands::{SyncSummary, start_partial_sync};
+use super::validation_commands::emit_actor_event;
+
+/// 비활성 상태에서 설정 변경을 다시 확인하는 주기
+const DISABLED_POLL_INTERVAL: Duration = Duration::from_secs(600);
+
+/// Start the background light-sync loop (no-op ticks while disabled in config)
+pub fn start_light_sync_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        info!("🕒 Light-sync scheduler started");
+        loop {
+            let cfg = app
+                .state::<AppState>()
+                .get_config()
+                .await
+                .advanced
+                .light_sync;
+            if !cfg.enabled {
+                tokio::time::sleep(DISABLED_POLL_INTERVAL).await;
+                continue;
+            }
+            // 앱 시작 직후 대량 요청을 피하기 위해 첫 실행도 한 주기 뒤에 수행
+            tokio::time::sleep(Duration::from_secs(cfg.interval_hours.max(1) * 3600)).await;
+            let state = app.state::<AppState>();
+            if state.is_crawling_active().await {
+                info!("🕒 Light-sync skipped: crawling session is active");
+                continue;
+            }
+            if let Err(e) = run_light_sync(app.clone(), state).await {
+                warn!("🕒 Light-sync run failed: {}", e);
+            }
+        }
+    });
+}
+
+/// Run the light-sync job immediately (same path as the scheduled run)
+#[tauri::command(async)]
+pub async fn run_light_sync_now(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+) -> Result<SyncSummary, String> {
+    run_light_sync(app, app_state).await
+}
+
+async fn run_light_sync(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+) -> Result<SyncSummary, String> {
+    let cfg: LightSyncSchedulerConfig = app_state.get_config().await.advanced.light_sync;
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let expr = light_sync_range_expr(cfg.newest_pages);
+    info!(
+        "🕒 Light-sync starting: newest_pages={} ranges={}",
+        cfg.newest_pages, expr
+    );
+
+    let result = start_partial_sync(app.clone(), app_state, expr, Some(false), None)
+        .await
+        .map_err(String::from);
+    let anomalies = collect_anomalies(&pool).await;
+    let anomaly_count = anomalies.len() as u32;
+    let repair_recommended = should_recommend_repair(anomaly_count, cfg.anomaly_threshold);
+    let (pages_processed, inserted, updated, failed) = match &result {
+        Ok(s) => (s.pages_processed, s.inserted, s.updated, s.failed),
+        Err(_) => (0, 0, 0, 0),
+    };
+    if repair_recommended {
+        warn!(
+            "🕒 Light-sync anomaly gate tripped: anomalies={} > threshold={}; deeper repair recommended",
+            anomaly_count, cfg.anomaly_threshold
+        );
+    }
+    emit_actor_event(
+        &app,
+        AppEvent::LightSyncReport {
+            newest_pages: cfg.newest_pages,
+            pages_processed,
+            inserted,
+            updated,
+            failed,
+            anomaly_count,
+            anomaly_threshold: cfg.anomaly_threshold,
+            repair_recommended,
+            anomalies: if anomalies.is_empty() {
+                None
+            } else {
+                Some(anomalies)
+            },
+            error: result.as_ref().err().cloned(),
+            timestamp: Utc::now(),
+        },
+    );
+    result
+}
+
+/// Physical range expression covering the newest `n` pages ("n-1", oldest-first)
+fn light_sync_range_expr(newest_pages: u32) -> String {
+    let n = newest_pages.max(1);
+    if n == 1 {
+        "1".to_string()
+    } else {
+        format!("{}-1", n)
+    }
+}
+
+/// Anomaly gate: recommend repair only when the count strictly exceeds the threshold
+fn should_recommend_repair(anomaly_count: u32, threshold: u32) -> bool {
+    anomaly_count > threshold
+}
+
+/// page_id groups with cnt != 12, excluding the newest (legitimately partial) group
+async fn collect_anomalies(pool: &SqlitePool) -> Vec<SyncAnomalyEntry> {
+    let mut anomalies = Vec::new();
+    let rows = match sqlx::query(
+        "WITH c AS (SELECT page_id, COUNT(*) AS cnt FROM products WHERE page_id IS NOT NULL GROUP BY page_id) \
+         SELECT page_id, cnt, (SELECT MAX(page_id) FROM c) AS max_pid FROM c WHERE cnt != 12 ORDER BY page_id",
+    )
+    .fetch_all(pool)
+    .await
+    {
+        Ok(rows) => rows,
+        Err(e) => {
+            warn!("🕒 Light-sync anomaly scan failed: {}", e);
+            return anomalies;
+        }
+    };
+    for r in rows {
+        let pid: Option<i64> = r.try_get("page_id").ok();
+        let cnt: Option<i64> = r.try_get("cnt").ok();
+        let max_pid: Option<i64> = r.try_get("max_pid").ok();
+        if let (Some(page_id), Some(count), Some(max_pid)) = (pid, cnt, max_pid) {
+            if page_id == max_pid {
+                continue;
+            }
+            // total_pages ≈ max_pid + 1 → current physical page = total_pages - page_id
+            anomalies.push(SyncAnomalyEntry {
+                page_id: page_id as i32,
+                count,
+                current_page_number: (max_pid + 1 - page_id).max(1) as u32,
+            });
+        }
+    }
+    anomalies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_expr_covers_newest_pages() {
+        assert_eq!(light_sync_range_expr(5), "5-1");
+        assert_eq!(light_sync_range_expr(1), "1");
+        assert_eq!(light_sync_range_expr(0), "1");
+    }
+
+    #[test]
+    fn anomaly_gate_trips_only_above_threshold() {
+        assert!(!should_recommend_repair(3, 3));
+        assert!(should_recommend_repair(4, 3));
+        assert!(!should_recommend_repair(0, 0));
+    }
+}
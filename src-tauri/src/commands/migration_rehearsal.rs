@@ -0,0 +1,20 @@
+//! 스키마 마이그레이션 리허설 명령어 (DB 사본에 적용 → 무결성 / 행 수 비교 보고)
+
+use crate::application::AppState;
+use crate::services::migration_rehearsal::{self, MigrationRehearsalReport};
+use tauri::State;
+
+/// Apply pending migrations to a temporary copy of the database and report how it went;
+/// the real database is not modified
+#[tauri::command(async)]
+pub async fn rehearse_migrations(
+    app_state: State<'_, AppState>,
+) -> Result<MigrationRehearsalReport, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    migration_rehearsal::rehearse(&pool)
+        .await
+        .map_err(|e| format!("Migration rehearsal failed: {e:#}"))
+}
@@ -0,0 +1,53 @@
+//! 세션별 목록 페이지 URL 집합 조회 명령어 (get_page_url_set / get_page_url_history /
+//! prune_page_url_sets)
+
+use crate::application::AppState;
+use crate::services::page_url_sets::{self, PageUrlSet, PruneReport};
+use tauri::State;
+
+const DEFAULT_HISTORY_LIMIT: u32 = 20;
+
+/// URL set one session extracted from one physical page (None = not recorded)
+#[tauri::command(async)]
+pub async fn get_page_url_set(
+    app_state: State<'_, AppState>,
+    session_id: String,
+    physical_page: u32,
+) -> Result<Option<PageUrlSet>, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    page_url_sets::load_page(&pool, session_id.trim(), physical_page)
+        .await
+        .map_err(|e| format!("Failed to load page URL set: {e}"))
+}
+
+/// Recorded URL sets of a physical page across sessions, newest first
+#[tauri::command(async)]
+pub async fn get_page_url_history(
+    app_state: State<'_, AppState>,
+    physical_page: u32,
+    limit: Option<u32>,
+) -> Result<Vec<PageUrlSet>, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    page_url_sets::page_history(&pool, physical_page, limit.unwrap_or(DEFAULT_HISTORY_LIMIT))
+        .await
+        .map_err(|e| format!("Failed to load page URL history: {e}"))
+}
+
+/// Apply `advanced.page_url_sets` retention now (also runs after every sync session)
+#[tauri::command(async)]
+pub async fn prune_page_url_sets(app_state: State<'_, AppState>) -> Result<PruneReport, String> {
+    let config = app_state.get_config().await.advanced.page_url_sets;
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    page_url_sets::prune(&pool, &config)
+        .await
+        .map_err(|e| format!("Failed to prune page URL sets: {e}"))
+}
@@ -6,10 +6,15 @@ use tauri::{AppHandle, Manager, State};
 use tokio::sync::RwLock;
 use tracing::info;
 
+use crate::application::AppState;
 use crate::crawl_engine::config::SystemConfig;
 use crate::crawl_engine::services::performance_optimizer::{
-    CrawlingPerformanceMetrics, CrawlingPerformanceOptimizer, OptimizationRecommendation,
+    self, ConcurrencyTarget, CrawlingPerformanceMetrics, CrawlingPerformanceOptimizer,
+    OptimizationRecommendation,
 };
+use crate::infrastructure::config::ConfigManager;
+use crate::infrastructure::memory_budget::{self, MemoryStats};
+use crate::services::batch_tuning::{self, BatchTuningStatus};
 
 /// 성능 최적화 상태 관리
 pub struct PerformanceOptimizerState {
@@ -134,6 +139,43 @@ pub async fn end_performance_session(
     }
 }
 
+/// 🎚️ 적응형 동시성 현재 목표 (세션-스테이지별)
+#[tauri::command]
+pub async fn get_concurrency_targets() -> Result<Vec<ConcurrencyTarget>, String> {
+    Ok(performance_optimizer::concurrency_targets())
+}
+
+/// 📐 배치 크기 튜닝 현황 (크기별 처리량 / 메모리 압박 + 다음 세션 제안)
+#[tauri::command(async)]
+pub async fn get_batch_size_suggestion(app: AppHandle) -> Result<BatchTuningStatus, String> {
+    let app_config = ConfigManager::new()
+        .map_err(|e| format!("config manager init failed: {}", e))?
+        .load_config()
+        .await
+        .map_err(|e| format!("config load failed: {}", e))?;
+    let pool = {
+        let app_state = app.state::<AppState>();
+        let guard = app_state.database_pool.read().await;
+        guard
+            .as_ref()
+            .ok_or("Database pool not initialized")?
+            .clone()
+    };
+    batch_tuning::status(
+        &pool,
+        app_config.user.batch.batch_size,
+        &app_config.advanced.batch_tuning,
+    )
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// 🧠 메모리 지표 (프로세스 RSS / 시스템 압박 / 핸드오프·미커밋 행 크기 / 조기 flush)
+#[tauri::command(async)]
+pub async fn get_memory_stats() -> Result<MemoryStats, String> {
+    Ok(memory_budget::stats())
+}
+
 /// 📊 성능 메트릭 기록 (내부용)
 pub async fn record_performance_metrics(
     app: &AppHandle,
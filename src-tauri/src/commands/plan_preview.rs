@@ -0,0 +1,647 @@
+//! Crawl dry-run: CrawlingPlanner 결과를 실행 없이 미리보기
+//!
+//! `start_actor_system_crawling` 과 동일한 경로로 ExecutionPlan 을 만들고 요청 override 를 적용한 뒤,
+//! 세션을 띄우지 않고 페이지 범위 / 배치 경계 / 예상 요청 수 / 예상 소요 시간만 계산해 반환합니다.
+//! `export_execution_plan` 은 같은 미리보기(또는 체크포인트 / 캐시에 저장된 계획)를 Mermaid / Graphviz
+//! 다이어그램으로 그려 문서나 이슈에 붙여 넣을 수 있게 합니다.
+
+use crate::application::AppState;
+use crate::application::shared_state::SharedStateCache;
+use crate::commands::actor_system_commands::{
+    ActorCrawlingRequest, apply_request_overrides, create_execution_plan,
+};
+use crate::crawl_engine::actors::session_actor::load_session_checkpoint;
+use crate::crawl_engine::actors::types::{ExecutionPlan, PageRange};
+use crate::crawl_engine::stage_type::{BATCH_PIPELINE, StageSelection, StageType};
+use crate::infrastructure::config::AppConfig;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use tauri::{AppHandle, Manager};
+use tracing::info;
+
+/// Response time assumed for stored plans (their input snapshot does not record one)
+const STORED_PLAN_RESPONSE_TIME_MS: u64 = 500;
+/// Batches drawn one node each; the remainder collapses into a single summary node
+const MAX_DIAGRAM_BATCHES: usize = 40;
+
+/// One list-page batch exactly as the session loop will chunk it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanPreviewBatch {
+    pub range_index: usize,
+    pub batch_index: usize,
+    pub pages: Vec<u32>,
+    /// Estimated wall-clock time of this batch alone
+    #[serde(default)]
+    pub estimated_duration_ms: u64,
+}
+
+/// Dry-run view of the plan the engine would execute
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawlingPlanPreview {
+    pub plan_hash: String,
+    pub strategy: String,
+    pub page_ranges: Vec<PageRange>,
+    pub batches: Vec<PlanPreviewBatch>,
+    /// Stages every batch runs, in order (after the plan's skip flags)
+    #[serde(default)]
+    pub stages: Vec<StageType>,
+    pub total_pages: u32,
+    pub batch_size: u32,
+    pub concurrency_limit: u32,
+    pub request_delay_ms: u64,
+    pub batch_delay_ms: u64,
+    pub max_requests_per_second: u32,
+    pub site_response_time_ms: u64,
+    pub estimated_list_requests: u64,
+    pub estimated_detail_requests: u64,
+    pub estimated_request_count: u64,
+    pub estimated_duration_secs: u64,
+    /// Planner's own (coarser) estimate, kept for comparison
+    pub planner_estimated_duration_secs: u64,
+}
+
+/// Split ranges into batches the same way the session range loop does
+/// (newest→oldest when `start_page > end_page`, chunks of `batch_size`).
+fn preview_batches(ranges: &[PageRange], batch_size: u32) -> Vec<PlanPreviewBatch> {
+    let chunk = batch_size.max(1) as usize;
+    let mut batches = Vec::new();
+    for (range_index, range) in ranges.iter().enumerate() {
+        let pages: Vec<u32> = if range.start_page > range.end_page {
+            (range.end_page..=range.start_page).rev().collect()
+        } else {
+            (range.start_page..=range.end_page).collect()
+        };
+        for (batch_index, page_chunk) in pages.chunks(chunk).enumerate() {
+            batches.push(PlanPreviewBatch {
+                range_index,
+                batch_index,
+                pages: page_chunk.to_vec(),
+                estimated_duration_ms: 0,
+            });
+        }
+    }
+    batches
+}
+
+/// Wall-clock estimate: requests run in waves of `concurrency`, each costing delay + response
+/// time, but never faster than the shared per-host RPS limit; batch pauses are added on top.
+fn estimate_duration_ms(
+    request_count: u64,
+    concurrency: u32,
+    request_delay_ms: u64,
+    response_time_ms: u64,
+    max_requests_per_second: u32,
+    batch_count: usize,
+    batch_delay_ms: u64,
+) -> u64 {
+    let waves = request_count.div_ceil(concurrency.max(1) as u64);
+    let concurrency_bound = waves * (request_delay_ms + response_time_ms);
+    let rate_bound = if max_requests_per_second > 0 {
+        request_count * 1000 / max_requests_per_second as u64
+    } else {
+        0
+    };
+    let batch_pauses = (batch_count.saturating_sub(1) as u64) * batch_delay_ms;
+    concurrency_bound.max(rate_bound) + batch_pauses
+}
+
+/// Batches, per-batch estimates and totals for `plan` under the given pacing
+fn build_preview(
+    plan: ExecutionPlan,
+    app_config: &AppConfig,
+    response_time_ms: u64,
+) -> CrawlingPlanPreview {
+    // Stored plans were validated when created; fall back to the full pipeline regardless
+    let selection = StageSelection::from_flags(&plan.stage_flags).unwrap_or_default();
+    let stages: Vec<StageType> = BATCH_PIPELINE
+        .iter()
+        .filter(|stage| selection.runs(stage))
+        .cloned()
+        .collect();
+    let crawls_details = selection.runs(&StageType::ProductDetailCrawling);
+    let request_delay_ms = app_config.user.request_delay_ms;
+    let batch_delay_ms = app_config.user.batch.batch_delay_ms;
+    let max_requests_per_second = app_config.user.crawling.workers.max_requests_per_second;
+
+    let mut batches = preview_batches(&plan.crawling_ranges, plan.batch_size);
+    for batch in &mut batches {
+        let range = &plan.crawling_ranges[batch.range_index];
+        let range_pages = range.start_page.abs_diff(range.end_page) as u64 + 1;
+        let pages = batch.pages.len() as u64;
+        let details = if crawls_details {
+            (range.estimated_products as u64 * pages).div_ceil(range_pages)
+        } else {
+            0
+        };
+        batch.estimated_duration_ms = estimate_duration_ms(
+            pages + details,
+            plan.concurrency_limit,
+            request_delay_ms,
+            response_time_ms,
+            max_requests_per_second,
+            1,
+            0,
+        );
+    }
+
+    let total_pages: u32 = batches.iter().map(|b| b.pages.len() as u32).sum();
+    let estimated_list_requests = total_pages as u64;
+    let estimated_detail_requests: u64 = if crawls_details {
+        plan.crawling_ranges
+            .iter()
+            .map(|r| r.estimated_products as u64)
+            .sum()
+    } else {
+        0
+    };
+    let estimated_request_count = estimated_list_requests + estimated_detail_requests;
+    let duration_ms = estimate_duration_ms(
+        estimated_request_count,
+        plan.concurrency_limit,
+        request_delay_ms,
+        response_time_ms,
+        max_requests_per_second,
+        batches.len(),
+        batch_delay_ms,
+    );
+
+    CrawlingPlanPreview {
+        plan_hash: plan.plan_hash,
+        strategy: plan.original_strategy,
+        page_ranges: plan.crawling_ranges,
+        batches,
+        stages,
+        total_pages,
+        batch_size: plan.batch_size,
+        concurrency_limit: plan.concurrency_limit,
+        request_delay_ms,
+        batch_delay_ms,
+        max_requests_per_second,
+        site_response_time_ms: response_time_ms,
+        estimated_list_requests,
+        estimated_detail_requests,
+        estimated_request_count,
+        estimated_duration_secs: duration_ms.div_ceil(1000),
+        planner_estimated_duration_secs: plan.estimated_duration_secs,
+    }
+}
+
+/// Run the planner and report what a crawl would do, without executing anything
+#[tauri::command]
+pub async fn preview_crawling_plan(
+    app: AppHandle,
+    request: Option<ActorCrawlingRequest>,
+) -> Result<CrawlingPlanPreview, String> {
+    let (mut plan, mut app_config, site_status) = create_execution_plan(&app)
+        .await
+        .map_err(|e| format!("failed to create execution plan: {}", e))?;
+    if let Some(request) = &request {
+        apply_request_overrides(&mut plan, &mut app_config, request);
+    }
+
+    let preview = build_preview(plan, &app_config, site_status.response_time_ms);
+    info!(
+        "🔍 Plan preview: hash={} pages={} batches={} requests={} est={}s",
+        preview.plan_hash,
+        preview.total_pages,
+        preview.batches.len(),
+        preview.estimated_request_count,
+        preview.estimated_duration_secs
+    );
+    Ok(preview)
+}
+
+/// Diagram syntax for `export_execution_plan`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanDiagramFormat {
+    #[default]
+    Mermaid,
+    #[serde(alias = "dot")]
+    Graphviz,
+}
+
+/// Rendered plan diagram
+#[derive(Debug, Clone, Serialize)]
+pub struct PlanDiagram {
+    pub format: PlanDiagramFormat,
+    /// Where the plan came from: `checkpoint`, `cache` or `preview` (freshly planned)
+    pub source: String,
+    pub plan_hash: String,
+    pub diagram: String,
+}
+
+struct DiagramNode {
+    id: String,
+    lines: Vec<String>,
+}
+
+struct DiagramCluster {
+    id: String,
+    label: String,
+    dashed: bool,
+    nodes: Vec<DiagramNode>,
+}
+
+struct DiagramEdge {
+    from: String,
+    to: String,
+    label: Option<String>,
+    dashed: bool,
+}
+
+/// Syntax-neutral graph both renderers draw from
+struct DiagramGraph {
+    nodes: Vec<DiagramNode>,
+    clusters: Vec<DiagramCluster>,
+    edges: Vec<DiagramEdge>,
+}
+
+fn edge(from: &str, to: &str, label: Option<String>, dashed: bool) -> DiagramEdge {
+    DiagramEdge {
+        from: from.to_string(),
+        to: to.to_string(),
+        label,
+        dashed,
+    }
+}
+
+fn format_duration(ms: u64) -> String {
+    let secs = ms.div_ceil(1000);
+    if secs < 60 {
+        format!("{secs}s")
+    } else if secs < 3600 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h {:02}m", secs / 3600, secs % 3600 / 60)
+    }
+}
+
+/// Plan → ranges of sequential batches → done, plus the stage pipeline each batch runs
+fn plan_graph(preview: &CrawlingPlanPreview) -> DiagramGraph {
+    let hash: String = preview.plan_hash.chars().take(12).collect();
+    let mut nodes = vec![DiagramNode {
+        id: "plan".into(),
+        lines: vec![
+            format!("Plan {hash}"),
+            format!(
+                "{} · {} pages · {} batches",
+                preview.strategy,
+                preview.total_pages,
+                preview.batches.len()
+            ),
+            format!(
+                "concurrency {} · est. {}",
+                preview.concurrency_limit,
+                format_duration(preview.estimated_duration_secs * 1000)
+            ),
+        ],
+    }];
+    let mut clusters: Vec<DiagramCluster> = Vec::new();
+    let mut edges = Vec::new();
+    let pause = (preview.batch_delay_ms > 0)
+        .then(|| format!("pause {}", format_duration(preview.batch_delay_ms)));
+
+    let mut previous = "plan".to_string();
+    let shown = preview.batches.len().min(MAX_DIAGRAM_BATCHES);
+    for batch in &preview.batches[..shown] {
+        if clusters.len() <= batch.range_index {
+            let range = &preview.page_ranges[batch.range_index];
+            clusters.push(DiagramCluster {
+                id: format!("range_{}", batch.range_index),
+                label: format!(
+                    "Range {}: pages {} → {} (~{} products)",
+                    batch.range_index + 1,
+                    range.start_page,
+                    range.end_page,
+                    range.estimated_products
+                ),
+                dashed: false,
+                nodes: Vec::new(),
+            });
+        }
+        let id = format!("b{}_{}", batch.range_index, batch.batch_index);
+        let first = batch.pages.first().copied().unwrap_or_default();
+        let last = batch.pages.last().copied().unwrap_or_default();
+        clusters[batch.range_index].nodes.push(DiagramNode {
+            id: id.clone(),
+            lines: vec![
+                format!("Batch {}: p{first}–{last}", batch.batch_index + 1),
+                format!(
+                    "{} pages · est. {}",
+                    batch.pages.len(),
+                    format_duration(batch.estimated_duration_ms)
+                ),
+            ],
+        });
+        let label = if previous == "plan" {
+            None
+        } else {
+            pause.clone()
+        };
+        edges.push(edge(&previous, &id, label, false));
+        previous = id;
+    }
+    let hidden = &preview.batches[shown..];
+    if !hidden.is_empty() {
+        let hidden_ms: u64 = hidden.iter().map(|b| b.estimated_duration_ms).sum();
+        nodes.push(DiagramNode {
+            id: "more".into(),
+            lines: vec![
+                format!("… {} more batches", hidden.len()),
+                format!("est. {}", format_duration(hidden_ms)),
+            ],
+        });
+        edges.push(edge(&previous, "more", pause.clone(), false));
+        previous = "more".into();
+    }
+    nodes.push(DiagramNode {
+        id: "done".into(),
+        lines: vec!["Done".into()],
+    });
+    edges.push(edge(&previous, "done", None, false));
+
+    if !preview.stages.is_empty() {
+        let stage_ids: Vec<String> = (0..preview.stages.len()).map(|i| format!("s{i}")).collect();
+        clusters.push(DiagramCluster {
+            id: "stages".into(),
+            label: "Stages per batch".into(),
+            dashed: true,
+            nodes: preview
+                .stages
+                .iter()
+                .zip(&stage_ids)
+                .map(|(stage, id)| DiagramNode {
+                    id: id.clone(),
+                    lines: vec![stage.as_str().to_string()],
+                })
+                .collect(),
+        });
+        for pair in stage_ids.windows(2) {
+            edges.push(edge(&pair[0], &pair[1], None, false));
+        }
+        if let Some(first_batch) = preview.batches.first() {
+            let id = format!("b{}_{}", first_batch.range_index, first_batch.batch_index);
+            edges.push(edge(&id, &stage_ids[0], Some("each batch".into()), true));
+        }
+    }
+
+    DiagramGraph {
+        nodes,
+        clusters,
+        edges,
+    }
+}
+
+fn mermaid_text(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+fn graphviz_text(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn render_mermaid(graph: &DiagramGraph) -> String {
+    let node = |out: &mut String, indent: &str, n: &DiagramNode| {
+        let lines: Vec<String> = n.lines.iter().map(|l| mermaid_text(l)).collect();
+        let _ = writeln!(out, "{indent}{}[\"{}\"]", n.id, lines.join("<br/>"));
+    };
+    let mut out = String::from("flowchart TD\n");
+    for n in &graph.nodes {
+        node(&mut out, "    ", n);
+    }
+    for cluster in &graph.clusters {
+        let _ = writeln!(
+            out,
+            "    subgraph {}[\"{}\"]",
+            cluster.id,
+            mermaid_text(&cluster.label)
+        );
+        for n in &cluster.nodes {
+            node(&mut out, "        ", n);
+        }
+        out.push_str("    end\n");
+        if cluster.dashed {
+            let _ = writeln!(out, "    style {} stroke-dasharray: 5 5", cluster.id);
+        }
+    }
+    for e in &graph.edges {
+        let arrow = if e.dashed { "-.->" } else { "-->" };
+        match &e.label {
+            Some(label) => {
+                let _ = writeln!(
+                    out,
+                    "    {} {arrow}|\"{}\"| {}",
+                    e.from,
+                    mermaid_text(label),
+                    e.to
+                );
+            }
+            None => {
+                let _ = writeln!(out, "    {} {arrow} {}", e.from, e.to);
+            }
+        }
+    }
+    out
+}
+
+fn render_graphviz(graph: &DiagramGraph) -> String {
+    let node = |out: &mut String, indent: &str, n: &DiagramNode| {
+        let lines: Vec<String> = n.lines.iter().map(|l| graphviz_text(l)).collect();
+        let _ = writeln!(out, "{indent}{} [label=\"{}\"];", n.id, lines.join("\\n"));
+    };
+    let mut out = String::from("digraph execution_plan {\n    rankdir=TB;\n");
+    out.push_str("    node [shape=box, fontname=\"Helvetica\"];\n");
+    for n in &graph.nodes {
+        node(&mut out, "    ", n);
+    }
+    for cluster in &graph.clusters {
+        let _ = writeln!(out, "    subgraph cluster_{} {{", cluster.id);
+        let _ = writeln!(out, "        label=\"{}\";", graphviz_text(&cluster.label));
+        if cluster.dashed {
+            out.push_str("        style=dashed;\n");
+        }
+        for n in &cluster.nodes {
+            node(&mut out, "        ", n);
+        }
+        out.push_str("    }\n");
+    }
+    for e in &graph.edges {
+        let mut attrs = Vec::new();
+        if let Some(label) = &e.label {
+            attrs.push(format!("label=\"{}\"", graphviz_text(label)));
+        }
+        if e.dashed {
+            attrs.push("style=dashed".to_string());
+        }
+        if attrs.is_empty() {
+            let _ = writeln!(out, "    {} -> {};", e.from, e.to);
+        } else {
+            let _ = writeln!(out, "    {} -> {} [{}];", e.from, e.to, attrs.join(", "));
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Draw the preview's batches, stage pipeline and estimates in the requested syntax
+fn render_plan_diagram(preview: &CrawlingPlanPreview, format: PlanDiagramFormat) -> String {
+    let graph = plan_graph(preview);
+    match format {
+        PlanDiagramFormat::Mermaid => render_mermaid(&graph),
+        PlanDiagramFormat::Graphviz => render_graphviz(&graph),
+    }
+}
+
+/// Stored plan by session id (checkpoint) or by plan hash / plan id / session id (plan cache)
+async fn load_stored_plan(
+    app: &AppHandle,
+    id: &str,
+) -> Result<(ExecutionPlan, &'static str), String> {
+    let pool = app
+        .state::<AppState>()
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let checkpoint = load_session_checkpoint(&pool, id)
+        .await
+        .map_err(|e| format!("failed to load session checkpoint: {e}"))?;
+    if let Some(checkpoint) = checkpoint {
+        return Ok((checkpoint.plan, "checkpoint"));
+    }
+    let cached = match app.try_state::<SharedStateCache>() {
+        Some(cache) => cache.find_cached_execution_plan(id).await,
+        None => None,
+    };
+    cached
+        .map(|plan| (plan, "cache"))
+        .ok_or_else(|| format!("no stored execution plan for {id}"))
+}
+
+/// Export a plan as a Mermaid / Graphviz diagram for docs and issues.
+///
+/// With `session_or_plan_id` the stored plan (session checkpoint, then plan cache) is drawn
+/// with the current pacing settings; without it a fresh plan is made like
+/// `preview_crawling_plan` (`request` overrides apply only there).
+#[tauri::command]
+pub async fn export_execution_plan(
+    app: AppHandle,
+    session_or_plan_id: Option<String>,
+    format: Option<PlanDiagramFormat>,
+    request: Option<ActorCrawlingRequest>,
+) -> Result<PlanDiagram, String> {
+    let format = format.unwrap_or_default();
+    let id = session_or_plan_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty());
+    let (preview, source) = match id {
+        Some(id) => {
+            let (plan, source) = load_stored_plan(&app, id).await?;
+            let app_config = app.state::<AppState>().get_config().await;
+            (
+                build_preview(plan, &app_config, STORED_PLAN_RESPONSE_TIME_MS),
+                source,
+            )
+        }
+        None => (preview_crawling_plan(app, request).await?, "preview"),
+    };
+
+    let diagram = render_plan_diagram(&preview, format);
+    info!(
+        "🗺️ Exported plan {} ({}) as {:?}: {} batches",
+        preview.plan_hash,
+        source,
+        format,
+        preview.batches.len()
+    );
+    Ok(PlanDiagram {
+        format,
+        source: source.to_string(),
+        plan_hash: preview.plan_hash,
+        diagram,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start_page: u32, end_page: u32) -> PageRange {
+        PageRange {
+            start_page,
+            end_page,
+            estimated_products: (start_page.abs_diff(end_page) + 1) * 12,
+            reverse_order: start_page > end_page,
+        }
+    }
+
+    #[test]
+    fn batches_follow_session_chunking() {
+        let batches = preview_batches(&[range(10, 6), range(3, 3)], 2);
+        let pages: Vec<Vec<u32>> = batches.iter().map(|b| b.pages.clone()).collect();
+        assert_eq!(pages, vec![vec![10, 9], vec![8, 7], vec![6], vec![3]]);
+        assert_eq!(batches[3].range_index, 1);
+        assert_eq!(batches[3].batch_index, 0);
+    }
+
+    #[test]
+    fn zero_batch_size_does_not_panic() {
+        assert_eq!(preview_batches(&[range(2, 1)], 0).len(), 2);
+    }
+
+    #[test]
+    fn duration_respects_rate_limit_and_batch_pauses() {
+        // 100 requests, 10 wide, 100ms each => 1s; 5 RPS cap => 20s dominates
+        assert_eq!(estimate_duration_ms(100, 10, 50, 50, 5, 1, 500), 20_000);
+        // Unlimited RPS: concurrency bound + 2 batch pauses
+        assert_eq!(estimate_duration_ms(100, 10, 50, 50, 0, 3, 500), 2_000);
+    }
+
+    #[test]
+    fn diagrams_chain_batches_and_list_stages() {
+        let page_ranges = vec![range(10, 8), range(3, 3)];
+        let mut batches = preview_batches(&page_ranges, 2);
+        for batch in &mut batches {
+            batch.estimated_duration_ms = 1_500;
+        }
+        let preview = CrawlingPlanPreview {
+            plan_hash: "abc123".into(),
+            strategy: "partial".into(),
+            page_ranges,
+            batches,
+            stages: vec![StageType::ListPageCrawling, StageType::DataSaving],
+            total_pages: 4,
+            batch_size: 2,
+            concurrency_limit: 4,
+            request_delay_ms: 100,
+            batch_delay_ms: 2_000,
+            max_requests_per_second: 0,
+            site_response_time_ms: 500,
+            estimated_list_requests: 4,
+            estimated_detail_requests: 0,
+            estimated_request_count: 4,
+            estimated_duration_secs: 65,
+            planner_estimated_duration_secs: 60,
+        };
+
+        let mermaid = render_plan_diagram(&preview, PlanDiagramFormat::Mermaid);
+        assert!(mermaid.starts_with("flowchart TD\n"));
+        assert!(mermaid.contains("subgraph range_1[\"Range 2: pages 3 → 3 (~12 products)\"]"));
+        assert!(mermaid.contains("b0_0[\"Batch 1: p10–9<br/>2 pages · est. 2s\"]"));
+        assert!(mermaid.contains("plan --> b0_0\n"));
+        assert!(mermaid.contains("b0_0 -->|\"pause 2s\"| b0_1"));
+        assert!(mermaid.contains("b0_1 -->|\"pause 2s\"| b1_0"));
+        assert!(mermaid.contains("b1_0 --> done"));
+        assert!(mermaid.contains("s0 --> s1"));
+        assert!(mermaid.contains("b0_0 -.->|\"each batch\"| s0"));
+        assert!(mermaid.contains("est. 1m 05s"));
+
+        let dot = render_plan_diagram(&preview, PlanDiagramFormat::Graphviz);
+        assert!(dot.starts_with("digraph execution_plan {"));
+        assert!(dot.contains("subgraph cluster_stages {"));
+        assert!(dot.contains("b0_0 -> b0_1 [label=\"pause 2s\"];"));
+        assert!(dot.contains("b0_0 -> s0 [label=\"each batch\", style=dashed];"));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+}
@@ -0,0 +1,17 @@
+//! Postgres 연결 확인
+//!
+//! `advanced.database.postgres_url` 서버에 연결해 보고 연결 여부와 제품 수를 돌려준다. 이때 밀린
+//! 마이그레이션도 적용된다. 비밀번호는 가린 URL 만 내보낸다. 앱의 저장소는 계속 SQLite 다.
+
+use crate::application::AppState;
+use crate::infrastructure::postgres_check::{self, PostgresCheckStatus};
+use tauri::State;
+
+/// Whether a Postgres server is configured, its masked URL and product count (or the error)
+#[tauri::command(async)]
+pub async fn check_postgres_connection(
+    app_state: State<'_, AppState>,
+) -> Result<PostgresCheckStatus, String> {
+    let config = app_state.get_config().await.advanced.database;
+    Ok(postgres_check::check_postgres(&config).await)
+}
@@ -0,0 +1,70 @@
+//! 제품 변경 이력 조회 커맨드
+//!
+//! 이력 기록 규칙은 `services::product_history`, 행 단위 설명 조합은 `services::row_explanation` 참고.
+//! 세 커맨드 모두 `options` 로 타임아웃 / 취소용 query id 를 받는다 (`infrastructure::query_control`).
+
+use crate::application::AppState;
+use crate::domain::command_error::CommandError;
+use crate::infrastructure::query_control::{QueryOptions, run_query};
+use crate::services::product_history::{self, ProductChange};
+use crate::services::row_explanation::{self, RowExplanation};
+use tauri::State;
+
+/// Field changes recorded for one product URL, newest first
+#[tauri::command(async)]
+pub async fn get_product_history(
+    app_state: State<'_, AppState>,
+    url: String,
+    limit: Option<u32>,
+    options: Option<QueryOptions>,
+) -> Result<Vec<ProductChange>, CommandError> {
+    run_query("get_product_history", options, |_| async move {
+        let pool = app_state
+            .get_database_pool()
+            .await
+            .map_err(|e| format!("DB pool unavailable: {e}"))?;
+        product_history::history_for_url(&pool, &url, limit)
+            .await
+            .map_err(|e| format!("Failed to load product history: {e:#}"))
+    })
+    .await
+}
+
+/// Latest field changes across all products (default 100, max 1000)
+#[tauri::command(async)]
+pub async fn get_recent_changes(
+    app_state: State<'_, AppState>,
+    limit: Option<u32>,
+    options: Option<QueryOptions>,
+) -> Result<Vec<ProductChange>, CommandError> {
+    run_query("get_recent_changes", options, |_| async move {
+        let pool = app_state
+            .get_database_pool()
+            .await
+            .map_err(|e| format!("DB pool unavailable: {e}"))?;
+        product_history::recent_changes(&pool, limit)
+            .await
+            .map_err(|e| format!("Failed to load recent changes: {e:#}"))
+    })
+    .await
+}
+
+/// Full change story for one product: observing sync sessions with the fields each changed,
+/// coordinate history and related warnings
+#[tauri::command(async)]
+pub async fn explain_row(
+    app_state: State<'_, AppState>,
+    url: String,
+    options: Option<QueryOptions>,
+) -> Result<RowExplanation, CommandError> {
+    run_query("explain_row", options, |_| async move {
+        let pool = app_state
+            .get_database_pool()
+            .await
+            .map_err(|e| format!("DB pool unavailable: {e}"))?;
+        row_explanation::explain_row(&pool, &url)
+            .await
+            .map_err(|e| format!("Failed to explain row: {e:#}"))
+    })
+    .await
+}
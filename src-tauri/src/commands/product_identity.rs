@@ -0,0 +1,84 @@
+//! 제품 식별 전략 전환 명령어 (기존 DB 정리 + 설정 저장)
+
+use crate::application::AppState;
+use crate::crawl_engine::runtime::sync_cancellation::SyncCancellationRegistry;
+use crate::infrastructure::config::{
+    ConfigManager, IdentityCollisionPolicy, IdentityStrategy, ProductIdentityConfig,
+};
+use crate::infrastructure::database_paths;
+use crate::services::identity_migration::{self, IdentityMigrationReport};
+use tauri::State;
+
+/// Switch the repository's product identity strategy. Duplicate certificate_id groups are
+/// reported, and merged first when switching to `certificate_id` with the `merge` policy.
+/// `on_secondary_collision` defaults to the current policy; `dry_run` reports without writing
+/// and leaves the configuration unchanged.
+#[tauri::command(async)]
+pub async fn switch_product_identity_strategy(
+    app_state: State<'_, AppState>,
+    strategy: IdentityStrategy,
+    on_secondary_collision: Option<IdentityCollisionPolicy>,
+    dry_run: Option<bool>,
+) -> Result<IdentityMigrationReport, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    let running = SyncCancellationRegistry::shared().active_sessions();
+    if !running.is_empty() {
+        return Err(format!(
+            "Identity strategy cannot change while sync is running: {}",
+            running.join(", ")
+        ));
+    }
+    if app_state.is_crawling_active().await {
+        return Err("Identity strategy cannot change while a crawl is running".into());
+    }
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+
+    let config = app_state.get_config().await;
+    let from = config.advanced.product_identity;
+    let to = ProductIdentityConfig {
+        strategy,
+        on_secondary_collision: on_secondary_collision.unwrap_or(from.on_secondary_collision),
+    };
+
+    let maintenance_cfg = &config.advanced.db_maintenance;
+    let backup_file = if !dry_run
+        && maintenance_cfg.backup_before_destructive
+        && identity_migration::merges_duplicates(&to)
+    {
+        let backup = database_paths::backup_database(
+            &pool,
+            "switch_product_identity_strategy",
+            maintenance_cfg.max_backups,
+        )
+        .await
+        .map_err(|e| format!("Backup before identity switch failed: {e:#}"))?;
+        Some(backup.file_name)
+    } else {
+        None
+    };
+
+    let mut report = identity_migration::migrate(&pool, from, to, dry_run)
+        .await
+        .map_err(|e| format!("Identity strategy migration failed: {e:#}"))?;
+    report.backup_file = backup_file;
+    if dry_run {
+        return Ok(report);
+    }
+
+    let config_manager =
+        ConfigManager::new().map_err(|e| format!("Failed to create config manager: {e}"))?;
+    let mut saved = config_manager
+        .load_config()
+        .await
+        .map_err(|e| format!("Failed to load config: {e}"))?;
+    saved.advanced.product_identity = to;
+    config_manager
+        .save_config(&saved)
+        .await
+        .map_err(|e| format!("Failed to save config: {e}"))?;
+    app_state.update_config(saved).await?;
+    Ok(report)
+}
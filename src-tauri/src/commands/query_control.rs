@@ -0,0 +1,18 @@
+//! 조회 커맨드 취소 / 실행 중 조회 목록
+//!
+//! 타임아웃·취소 처리 자체는 `infrastructure::query_control::run_query` 가 담당한다. 여기서는
+//! `query_id` 로 등록된 조회를 UI 에서 취소하거나 나열한다.
+
+use crate::infrastructure::query_control::{self, RunningQuery};
+
+/// Cancel a running query started with `options.query_id`; false when no such query runs
+#[tauri::command(async)]
+pub async fn cancel_query(query_id: String) -> Result<bool, String> {
+    Ok(query_control::cancel_query(&query_id))
+}
+
+/// Queries started with a `query_id`, oldest first
+#[tauri::command(async)]
+pub async fn list_running_queries() -> Result<Vec<RunningQuery>, String> {
+    Ok(query_control::running_queries())
+}
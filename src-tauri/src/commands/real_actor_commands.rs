@@ -67,7 +67,9 @@ pub async fn start_legacy_service_based_crawling(
     let data_extractor = Arc::new(MatterDataExtractor::new().map_err(|e| e.to_string())?);
 
     // 📡 Frontend 이벤트 채널 생성
-    let (event_tx, _event_rx) = broadcast::channel::<FrontendEvent>(500);
+    let (event_tx, _event_rx) = broadcast::channel::<FrontendEvent>(
+        crate::crawl_engine::SystemConfig::channel_capacities().frontend_events,
+    );
 
     // 📊 실제 크롤링 범위 계산
     info!("🔧 Creating intelligent crawling plan using CrawlingPlanner");
@@ -182,7 +182,7 @@ pub async fn execute_real_crawling(
     let list_items: Vec<StageItem> = pages.iter().map(|&p| StageItem::Page(p)).collect();
     let list_result = executor
         .execute_stage(
-            StageType::ListCollection,
+            StageType::ListPageCrawling,
             list_items,
             request.concurrency_limit.unwrap_or(5),
             cancellation_token.clone(),
@@ -256,7 +256,7 @@ pub async fn execute_real_crawling(
 
     let detail_result = executor
         .execute_stage(
-            StageType::DetailCollection,
+            StageType::ProductDetailCrawling,
             Vec::new(), // TODO: 실제 URL 리스트 전달
             request.concurrency_limit.unwrap_or(3),
             cancellation_token.clone(),
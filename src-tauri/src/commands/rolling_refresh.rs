@@ -0,0 +1,347 @@
+//! rolling-refresh 크롤 프로필 (인증일 최신성 버킷 기반 재수집)
+//!
+//! 최근 인증된 제품일수록 펌웨어/문서 수정이 잦으므로, page_id 그룹을 가장 최근 인증일 기준
+//! 버킷(`advanced.rolling_refresh.buckets`)으로 나눈 뒤 버킷마다 `cycle_runs` 회에 걸쳐 나누어
+//! 갱신한다. 최신 버킷(cycle_runs = 1)은 매 실행마다, 오래된 버킷은 `page_id % cycle_runs`
+//! 슬라이스만 갱신한다. watchlist 관심 제품이 있는 페이지는 버킷과 상한에 관계없이 매 실행
+//! 맨 앞에 포함된다. 선택된 페이지는 물리 페이지 범위식으로 변환되어 partial sync로 위임된다.
+
+use crate::application::AppState;
+use crate::infrastructure::config::{RefreshBucketConfig, RollingRefreshConfig};
+use crate::services::watchlist;
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::collections::{BTreeMap, BTreeSet};
+use tauri::{AppHandle, State};
+use tracing::{info, warn};
+
+use super::sync_commands::{SyncSummary, start_partial_sync};
+use super::watchlist::notify_watchlist_changes;
+
+/// Per-bucket selection counts for one run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshBucketSummary {
+    pub max_age_days: Option<u32>,
+    pub cycle_runs: u32,
+    pub total_pages: u32,
+    pub selected_pages: u32,
+}
+
+/// Pages a rolling-refresh run covers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollingRefreshPlan {
+    pub run_index: u64,
+    pub buckets: Vec<RefreshBucketSummary>,
+    /// Pages holding watched products (refreshed every run, ahead of the buckets)
+    #[serde(default)]
+    pub watched_pages: u32,
+    /// Physical pages (1 = newest) in refresh priority order
+    pub physical_pages: Vec<u32>,
+    /// Range expression handed to `start_partial_sync` (empty when nothing is due)
+    pub ranges: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollingRefreshReport {
+    pub plan: RollingRefreshPlan,
+    pub sync: Option<SyncSummary>,
+}
+
+/// Compute the next rolling-refresh slice without crawling
+#[tauri::command(async)]
+pub async fn preview_rolling_refresh(
+    app_state: State<'_, AppState>,
+) -> Result<RollingRefreshPlan, String> {
+    let cfg = app_state.get_config().await.advanced.rolling_refresh;
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let watched = watchlist::watched_page_ids(&pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    build_plan(&pool, &cfg, &watched)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Refresh the current slice via partial sync and advance the run counter on success
+#[tauri::command(async)]
+pub async fn run_rolling_refresh(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+) -> Result<RollingRefreshReport, String> {
+    let cfg = app_state.get_config().await.advanced.rolling_refresh;
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let watched = watchlist::watched_page_ids(&pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    let plan = build_plan(&pool, &cfg, &watched)
+        .await
+        .map_err(|e| e.to_string())?;
+    info!(
+        "♻️ Rolling refresh run #{}: {} pages ranges=\"{}\"",
+        plan.run_index,
+        plan.physical_pages.len(),
+        plan.ranges
+    );
+    let sync = if plan.ranges.is_empty() {
+        None
+    } else {
+        Some(start_partial_sync(app.clone(), app_state, plan.ranges.clone(), Some(false), None).await?)
+    };
+    if plan.watched_pages > 0 && sync.is_some() {
+        if let Err(e) = notify_watchlist_changes(&app, &pool).await {
+            warn!("♻️ Watchlist change check failed: {}", e);
+        }
+    }
+    if let Err(e) = advance_run_index(&pool, plan.run_index + 1).await {
+        warn!("♻️ Failed to persist rolling refresh run index: {}", e);
+    }
+    Ok(RollingRefreshReport { plan, sync })
+}
+
+async fn build_plan(
+    pool: &SqlitePool,
+    cfg: &RollingRefreshConfig,
+    watched: &BTreeSet<i64>,
+) -> Result<RollingRefreshPlan, sqlx::Error> {
+    let run_index = load_run_index(pool).await?;
+    let rows = sqlx::query(
+        "SELECT p.page_id AS page_id, pd.certification_date AS certification_date \
+         FROM products p LEFT JOIN product_details pd ON pd.url = p.url \
+         WHERE p.page_id IS NOT NULL",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let today = Utc::now().date_naive();
+    // page_id → freshest certification age in days (None = no parseable date)
+    let mut page_ages: BTreeMap<i64, Option<i64>> = BTreeMap::new();
+    for row in rows {
+        let Ok(page_id) = row.try_get::<i64, _>("page_id") else {
+            continue;
+        };
+        let age = row
+            .try_get::<Option<String>, _>("certification_date")
+            .ok()
+            .flatten()
+            .and_then(|s| parse_certification_date(&s))
+            .map(|d| (today - d).num_days().max(0));
+        let entry = page_ages.entry(page_id).or_insert(None);
+        *entry = match (*entry, age) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+    }
+
+    let Some(&max_page_id) = page_ages.keys().next_back() else {
+        return Ok(RollingRefreshPlan {
+            run_index,
+            buckets: Vec::new(),
+            watched_pages: 0,
+            physical_pages: Vec::new(),
+            ranges: String::new(),
+        });
+    };
+    let page_buckets: BTreeMap<i64, usize> = page_ages
+        .into_iter()
+        .map(|(page_id, age)| (page_id, bucket_for_age(&cfg.buckets, age)))
+        .collect();
+    let selected: Vec<(usize, i64)> = select_pages(&page_buckets, cfg, run_index)
+        .into_iter()
+        .filter(|(_, page_id)| !watched.contains(page_id))
+        .collect();
+
+    let buckets = cfg
+        .buckets
+        .iter()
+        .enumerate()
+        .map(|(idx, b)| RefreshBucketSummary {
+            max_age_days: b.max_age_days,
+            cycle_runs: b.cycle_runs,
+            total_pages: page_buckets.values().filter(|&&v| v == idx).count() as u32,
+            selected_pages: selected.iter().filter(|(bucket, _)| *bucket == idx).count() as u32,
+        })
+        .collect();
+    // total_pages ≈ max_pid + 1 → current physical page = total_pages - page_id
+    let watched_ids: Vec<i64> = watched
+        .iter()
+        .rev()
+        .copied()
+        .filter(|page_id| page_buckets.contains_key(page_id))
+        .collect();
+    let physical_pages: Vec<u32> = watched_ids
+        .iter()
+        .chain(selected.iter().map(|(_, page_id)| page_id))
+        .map(|page_id| (max_page_id + 1 - page_id).max(1) as u32)
+        .collect();
+    let ranges = range_expr(&physical_pages);
+    Ok(RollingRefreshPlan {
+        run_index,
+        buckets,
+        watched_pages: watched_ids.len() as u32,
+        physical_pages,
+        ranges,
+    })
+}
+
+/// Certification dates are stored as scraped; accept the formats the site has used
+fn parse_certification_date(raw: &str) -> Option<NaiveDate> {
+    let s = raw.trim();
+    let head = s.get(..10).unwrap_or(s);
+    NaiveDate::parse_from_str(head, "%Y-%m-%d")
+        .ok()
+        .or_else(|| NaiveDate::parse_from_str(s, "%m/%d/%Y").ok())
+        .or_else(|| NaiveDate::parse_from_str(s, "%B %d, %Y").ok())
+        .or_else(|| NaiveDate::parse_from_str(s, "%b %d, %Y").ok())
+        .or_else(|| NaiveDate::parse_from_str(s, "%d %B %Y").ok())
+}
+
+/// First bucket whose `max_age_days` covers `age`; undated pages fall into the catch-all
+fn bucket_for_age(buckets: &[RefreshBucketConfig], age_days: Option<i64>) -> usize {
+    let catch_all = buckets
+        .iter()
+        .position(|b| b.max_age_days.is_none())
+        .unwrap_or(buckets.len().saturating_sub(1));
+    match age_days {
+        Some(age) => buckets
+            .iter()
+            .position(|b| b.max_age_days.is_some_and(|max| age <= max as i64))
+            .unwrap_or(catch_all),
+        None => catch_all,
+    }
+}
+
+/// (bucket index, page_id) due in `run_index`, most recent bucket first and newest page first
+/// within a bucket, capped at `max_pages_per_run`
+fn select_pages(
+    page_buckets: &BTreeMap<i64, usize>,
+    cfg: &RollingRefreshConfig,
+    run_index: u64,
+) -> Vec<(usize, i64)> {
+    let mut selected: Vec<(usize, i64)> = page_buckets
+        .iter()
+        .rev()
+        .filter(|&(&page_id, &bucket)| {
+            let cycle = cfg.buckets.get(bucket).map_or(1, |b| b.cycle_runs.max(1)) as u64;
+            page_id.unsigned_abs() % cycle == run_index % cycle
+        })
+        .map(|(&page_id, &bucket)| (bucket, page_id))
+        .collect();
+    selected.sort_by_key(|&(bucket, page_id)| (bucket, std::cmp::Reverse(page_id)));
+    if cfg.max_pages_per_run > 0 {
+        selected.truncate(cfg.max_pages_per_run as usize);
+    }
+    selected
+}
+
+/// Physical pages → descending range expression ("12-10,7")
+pub(crate) fn range_expr(pages: &[u32]) -> String {
+    let mut sorted: Vec<u32> = pages.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    sorted.dedup();
+    let mut parts: Vec<String> = Vec::new();
+    let mut iter = sorted.into_iter().peekable();
+    while let Some(start) = iter.next() {
+        let mut end = start;
+        while iter.peek() == Some(&(end.saturating_sub(1))) && end > 1 {
+            end = iter.next().unwrap_or(end);
+        }
+        parts.push(if start == end {
+            start.to_string()
+        } else {
+            format!("{}-{}", start, end)
+        });
+    }
+    parts.join(",")
+}
+
+async fn load_run_index(pool: &SqlitePool) -> Result<u64, sqlx::Error> {
+    let index: Option<i64> =
+        sqlx::query_scalar("SELECT run_index FROM rolling_refresh_state WHERE id = 1")
+            .fetch_optional(pool)
+            .await?;
+    Ok(index.unwrap_or(0).max(0) as u64)
+}
+
+async fn advance_run_index(pool: &SqlitePool, next: u64) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO rolling_refresh_state (id, run_index, updated_at) VALUES (1, ?, ?) \
+         ON CONFLICT(id) DO UPDATE SET run_index = excluded.run_index, updated_at = excluded.updated_at",
+    )
+    .bind(next as i64)
+    .bind(Utc::now().to_rfc3339())
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_scraped_date_formats() {
+        let expected = NaiveDate::from_ymd_opt(2024, 1, 15);
+        assert_eq!(parse_certification_date("2024-01-15"), expected);
+        assert_eq!(parse_certification_date("2024-01-15T00:00:00Z"), expected);
+        assert_eq!(parse_certification_date("01/15/2024"), expected);
+        assert_eq!(parse_certification_date("January 15, 2024"), expected);
+        assert_eq!(parse_certification_date("n/a"), None);
+    }
+
+    #[test]
+    fn ages_map_to_first_covering_bucket() {
+        let cfg = RollingRefreshConfig::default();
+        assert_eq!(bucket_for_age(&cfg.buckets, Some(10)), 0);
+        assert_eq!(bucket_for_age(&cfg.buckets, Some(90)), 0);
+        assert_eq!(bucket_for_age(&cfg.buckets, Some(200)), 1);
+        assert_eq!(bucket_for_age(&cfg.buckets, Some(2000)), 2);
+        assert_eq!(bucket_for_age(&cfg.buckets, None), 2);
+    }
+
+    #[test]
+    fn recent_bucket_every_run_older_buckets_rotate() {
+        let cfg = RollingRefreshConfig {
+            max_pages_per_run: 0,
+            ..Default::default()
+        };
+        // page 9..=8 recent, 7..=4 within a year, 3..=0 old
+        let bucket_of = |p: i64| match p {
+            8.. => 0,
+            4..=7 => 1,
+            _ => 2,
+        };
+        let page_buckets: BTreeMap<i64, usize> = (0..10).map(|p| (p, bucket_of(p))).collect();
+        let mut covered = std::collections::HashSet::new();
+        for run in 0..12 {
+            let sel = select_pages(&page_buckets, &cfg, run);
+            assert!(sel.starts_with(&[(0, 9), (0, 8)]));
+            covered.extend(sel.into_iter().map(|(_, p)| p));
+        }
+        assert_eq!(covered.len(), 10);
+        let run1 = select_pages(&page_buckets, &cfg, 1);
+        assert_eq!(run1, vec![(0, 9), (0, 8), (1, 5), (2, 1)]);
+    }
+
+    #[test]
+    fn cap_keeps_recent_pages() {
+        let cfg = RollingRefreshConfig {
+            max_pages_per_run: 2,
+            ..Default::default()
+        };
+        let page_buckets: BTreeMap<i64, usize> = [(1, 1), (5, 0), (6, 0), (8, 0)].into();
+        assert_eq!(select_pages(&page_buckets, &cfg, 0), vec![(0, 8), (0, 6)]);
+    }
+
+    #[test]
+    fn range_expr_merges_consecutive_pages() {
+        assert_eq!(range_expr(&[10, 12, 11, 7, 1, 2]), "12-10,7,2-1");
+        assert_eq!(range_expr(&[]), "");
+    }
+}
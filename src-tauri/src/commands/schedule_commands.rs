@@ -0,0 +1,57 @@
+//! 예약 크롤링 스케줄 관리 명령어 (create/list/delete)
+
+use crate::application::AppState;
+use crate::services::crawl_scheduler::{
+    CrawlSchedule, CrawlSchedulerService, CreateScheduleRequest,
+};
+use tauri::State;
+
+/// Create a recurring crawl schedule (cron expression or fixed interval)
+#[tauri::command(async)]
+pub async fn create_crawl_schedule(
+    app_state: State<'_, AppState>,
+    scheduler: State<'_, CrawlSchedulerService>,
+    request: CreateScheduleRequest,
+) -> Result<CrawlSchedule, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    scheduler
+        .create_schedule(&pool, request)
+        .await
+        .map_err(|e| format!("Failed to create schedule: {e:#}"))
+}
+
+/// List all crawl schedules with their last/next run times
+#[tauri::command(async)]
+pub async fn list_crawl_schedules(
+    app_state: State<'_, AppState>,
+    scheduler: State<'_, CrawlSchedulerService>,
+) -> Result<Vec<CrawlSchedule>, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    scheduler
+        .list_schedules(&pool)
+        .await
+        .map_err(|e| format!("Failed to list schedules: {e:#}"))
+}
+
+/// Delete a crawl schedule; returns false when no schedule had that id
+#[tauri::command(async)]
+pub async fn delete_crawl_schedule(
+    app_state: State<'_, AppState>,
+    scheduler: State<'_, CrawlSchedulerService>,
+    schedule_id: String,
+) -> Result<bool, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    scheduler
+        .delete_schedule(&pool, &schedule_id)
+        .await
+        .map_err(|e| format!("Failed to delete schedule: {e:#}"))
+}
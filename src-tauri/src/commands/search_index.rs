@@ -0,0 +1,28 @@
+//! 검색 인덱스(FTS) / 제조사 rollup 드리프트 점검 명령어
+
+use crate::application::AppState;
+use crate::domain::command_error::CommandError;
+use crate::infrastructure::query_control::{QueryOptions, run_query};
+use crate::services::search_index::{self, SearchIndexDriftReport};
+use tauri::State;
+
+/// Compare the search index and rollups with `product_details`; `repair` re-applies only the
+/// drifted rows (also backfills an empty index). `options` sets a timeout / cancellable query id;
+/// a repair stops between chunks once cancelled.
+#[tauri::command(async)]
+pub async fn check_search_index(
+    app_state: State<'_, AppState>,
+    repair: Option<bool>,
+    options: Option<QueryOptions>,
+) -> Result<SearchIndexDriftReport, CommandError> {
+    run_query("check_search_index", options, |cancel| async move {
+        let pool = app_state
+            .get_database_pool()
+            .await
+            .map_err(|e| format!("DB pool unavailable: {e}"))?;
+        search_index::check_consistency_cancellable(&pool, repair.unwrap_or(false), &cancel)
+            .await
+            .map_err(|e| format!("Search index check failed: {e:#}"))
+    })
+    .await
+}
@@ -0,0 +1,44 @@
+//! 세션 로그 조회 명령어
+//!
+//! 로그 수집은 `infrastructure::session_log` 레이어가 담당하고, 여기서는 UI 로그 뷰어용 페이지 조회만 한다.
+
+use crate::infrastructure::session_log::{self, SessionLogPage};
+use std::str::FromStr;
+use tracing::Level;
+
+const DEFAULT_LIMIT: u32 = 200;
+const MAX_LIMIT: u32 = 2000;
+
+/// Captured log entries of one session, oldest first. `level` keeps entries at that level or
+/// more severe (e.g. "warn" → WARN + ERROR).
+#[tauri::command(async)]
+pub async fn get_session_logs(
+    session_id: String,
+    level: Option<String>,
+    offset: Option<u32>,
+    limit: Option<u32>,
+) -> Result<SessionLogPage, String> {
+    if session_id.trim().is_empty() {
+        return Err("session_id is required".into());
+    }
+    let min_level = level
+        .as_deref()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|l| Level::from_str(l).map_err(|_| format!("Unknown log level: {l}")))
+        .transpose()?;
+    let offset = offset.unwrap_or(0) as usize;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT) as usize;
+    tokio::task::spawn_blocking(move || {
+        session_log::read_session_logs(
+            &session_log::session_log_directory(),
+            &session_id,
+            min_level,
+            offset,
+            limit,
+        )
+    })
+    .await
+    .map_err(|e| format!("Session log read task failed: {e}"))?
+    .map_err(|e| format!("Failed to read session logs: {e}"))
+}
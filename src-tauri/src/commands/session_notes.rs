@@ -0,0 +1,71 @@
+//! 세션/배치 운영자 메모 명령어 (annotate_session / get_session_notes)
+
+use crate::application::AppState;
+use crate::crawl_engine::runtime::session_registry::session_registry;
+use crate::crawl_engine::services::session_report;
+use crate::services::session_notes::{self, SessionNote};
+use tauri::State;
+use tracing::warn;
+
+/// Attach a free-text note to a running or finished session (optionally one of its batches).
+/// An already written session report is rewritten to include it.
+#[tauri::command(async)]
+pub async fn annotate_session(
+    app_state: State<'_, AppState>,
+    session_id: String,
+    note: String,
+    batch_id: Option<String>,
+) -> Result<SessionNote, String> {
+    let session_id = session_id.trim().to_string();
+    if session_id.is_empty() {
+        return Err("session_id is required".into());
+    }
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let reports_dir = session_report::reports_dir().ok();
+    let registered = session_registry().read().await.contains_key(&session_id);
+    let known = registered
+        || session_notes::is_sync_session(&pool, &session_id)
+            .await
+            .unwrap_or(false)
+        || reports_dir
+            .as_ref()
+            .is_some_and(|dir| dir.join(format!("session_{session_id}.json")).exists());
+    if !known {
+        return Err(format!("Unknown session: {session_id}"));
+    }
+
+    let saved = session_notes::add_note(&pool, &session_id, batch_id.as_deref(), &note)
+        .await
+        .map_err(|e| format!("Failed to save note: {e}"))?;
+    if let Some(dir) = reports_dir {
+        let refreshed = match session_notes::list_notes(&pool, &session_id).await {
+            Ok(notes) => session_report::refresh_report_notes(&dir, &session_id, notes).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = refreshed {
+            warn!(
+                "📝 Session report for {} not updated with note: {}",
+                session_id, e
+            );
+        }
+    }
+    Ok(saved)
+}
+
+/// Operator notes of a session, oldest first
+#[tauri::command(async)]
+pub async fn get_session_notes(
+    app_state: State<'_, AppState>,
+    session_id: String,
+) -> Result<Vec<SessionNote>, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    session_notes::list_notes(&pool, session_id.trim())
+        .await
+        .map_err(|e| format!("Failed to load session notes: {e}"))
+}
@@ -0,0 +1,246 @@
+//! 설정 번들 내보내기/가져오기 + 설정 프리셋 커맨드
+//!
+//! 번들 형식과 검증 규칙은 `services::settings_bundle` 참고.
+
+use crate::application::AppState;
+use crate::infrastructure::config::ConfigManager;
+use crate::infrastructure::features::feature_flag_snapshot;
+use crate::services::anonymization::AnonymizationProfile;
+use crate::services::crawl_scheduler::{CrawlSchedulerService, CreateScheduleRequest};
+use crate::services::settings_bundle::{
+    BundledSchedule, PortableConfig, SettingsBundle, SettingsPreset, load_presets, merge_presets,
+    read_bundle, save_presets, write_bundle,
+};
+use serde::Serialize;
+use std::path::PathBuf;
+use tauri::State;
+use tracing::info;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SettingsExportSummary {
+    pub path: String,
+    pub version: u32,
+    pub presets: usize,
+    pub schedules: usize,
+    pub feature_flags: usize,
+    /// Written with an anonymization profile (not importable)
+    pub anonymized: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SettingsImportSummary {
+    pub version: u32,
+    /// True when nothing was written (`dry_run`)
+    pub dry_run: bool,
+    pub config_applied: bool,
+    pub presets_imported: usize,
+    pub schedules_created: usize,
+    /// Schedules skipped because one with the same name already exists
+    pub schedules_skipped: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Write config, presets, schedules and feature flags to a single bundle file.
+/// `anonymize` produces a shareable diagnostics copy that cannot be imported back.
+#[tauri::command(async)]
+pub async fn export_settings(
+    app_state: State<'_, AppState>,
+    scheduler: State<'_, CrawlSchedulerService>,
+    path: String,
+    anonymize: Option<AnonymizationProfile>,
+) -> Result<SettingsExportSummary, String> {
+    let anonymizer = anonymize
+        .as_ref()
+        .map(AnonymizationProfile::anonymizer)
+        .transpose()?;
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let config = app_state.get_config().await;
+    let schedules: Vec<BundledSchedule> = scheduler
+        .list_schedules(&pool)
+        .await
+        .map_err(|e| format!("Failed to list schedules: {e:#}"))?
+        .iter()
+        .map(BundledSchedule::from)
+        .collect();
+    let presets = load_presets()
+        .await
+        .map_err(|e| format!("Failed to load presets: {e:#}"))?;
+    let bundle = SettingsBundle::new(
+        PortableConfig {
+            user: config.user,
+            advanced: config.advanced,
+        },
+        presets,
+        schedules,
+        feature_flag_snapshot(),
+    );
+    let path = PathBuf::from(path);
+    write_bundle(&path, &bundle, anonymizer.as_ref())
+        .await
+        .map_err(|e| format!("Failed to export settings: {e:#}"))?;
+    info!(
+        "📦 Settings exported to {} (presets={}, schedules={}, anonymized={})",
+        path.display(),
+        bundle.presets.len(),
+        bundle.schedules.len(),
+        anonymizer.is_some()
+    );
+    Ok(SettingsExportSummary {
+        path: path.display().to_string(),
+        version: bundle.version,
+        presets: bundle.presets.len(),
+        schedules: bundle.schedules.len(),
+        feature_flags: bundle.feature_flags.len(),
+        anonymized: anonymizer.is_some(),
+    })
+}
+
+/// Validate a bundle and apply it; any validation error aborts before anything is written.
+/// Schedules are merged by name unless `replace_schedules` is set.
+#[tauri::command(async)]
+pub async fn import_settings(
+    app_state: State<'_, AppState>,
+    scheduler: State<'_, CrawlSchedulerService>,
+    path: String,
+    dry_run: Option<bool>,
+    replace_schedules: Option<bool>,
+) -> Result<SettingsImportSummary, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    let bundle = read_bundle(&PathBuf::from(&path))
+        .await
+        .map_err(|e| format!("Failed to read settings bundle: {e:#}"))?;
+    let errors = bundle.validate();
+    if !errors.is_empty() {
+        return Err(format!(
+            "Settings bundle rejected ({} problems): {}",
+            errors.len(),
+            errors.join("; ")
+        ));
+    }
+    let warnings = bundle.feature_flag_differences(&feature_flag_snapshot());
+
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let existing = scheduler
+        .list_schedules(&pool)
+        .await
+        .map_err(|e| format!("Failed to list schedules: {e:#}"))?;
+    let replace_schedules = replace_schedules.unwrap_or(false);
+    let (to_create, schedules_skipped): (Vec<&BundledSchedule>, Vec<&BundledSchedule>) = bundle
+        .schedules
+        .iter()
+        .partition(|s| replace_schedules || !existing.iter().any(|e| e.name == s.name.trim()));
+    let mut summary = SettingsImportSummary {
+        version: bundle.version,
+        dry_run,
+        config_applied: false,
+        presets_imported: bundle.presets.len(),
+        schedules_created: to_create.len(),
+        schedules_skipped: schedules_skipped.iter().map(|s| s.name.clone()).collect(),
+        warnings,
+    };
+    if dry_run {
+        return Ok(summary);
+    }
+
+    // Config: keep this machine's app-managed state
+    let manager =
+        ConfigManager::new().map_err(|e| format!("Failed to init config manager: {e}"))?;
+    let mut config = app_state.get_config().await;
+    config.user = bundle.config.user.clone();
+    config.advanced = bundle.config.advanced.clone();
+    manager
+        .save_config(&config)
+        .await
+        .map_err(|e| format!("Failed to save config: {e:#}"))?;
+    app_state.update_config(config).await?;
+    summary.config_applied = true;
+
+    let mut presets = load_presets()
+        .await
+        .map_err(|e| format!("Failed to load presets: {e:#}"))?;
+    merge_presets(&mut presets, &bundle.presets);
+    save_presets(&presets)
+        .await
+        .map_err(|e| format!("Failed to save presets: {e:#}"))?;
+
+    if replace_schedules {
+        for schedule in &existing {
+            scheduler
+                .delete_schedule(&pool, &schedule.id)
+                .await
+                .map_err(|e| format!("Failed to delete schedule '{}': {e:#}", schedule.name))?;
+        }
+    }
+    for schedule in to_create {
+        scheduler
+            .create_schedule(
+                &pool,
+                CreateScheduleRequest {
+                    name: schedule.name.clone(),
+                    spec: schedule.spec.clone(),
+                    action: schedule.action.clone(),
+                    enabled: schedule.enabled,
+                },
+            )
+            .await
+            .map_err(|e| format!("Failed to create schedule '{}': {e:#}", schedule.name))?;
+    }
+
+    info!(
+        "📦 Settings imported from {} (presets={}, schedules_created={}, skipped={})",
+        path,
+        summary.presets_imported,
+        summary.schedules_created,
+        summary.schedules_skipped.len()
+    );
+    Ok(summary)
+}
+
+#[tauri::command(async)]
+pub async fn list_settings_presets() -> Result<Vec<SettingsPreset>, String> {
+    load_presets()
+        .await
+        .map_err(|e| format!("Failed to load presets: {e:#}"))
+}
+
+/// Create or overwrite a named preset (partial `AppConfig` JSON object)
+#[tauri::command(async)]
+pub async fn save_settings_preset(preset: SettingsPreset) -> Result<Vec<SettingsPreset>, String> {
+    if preset.name.trim().is_empty() {
+        return Err("Preset name is required".into());
+    }
+    if !preset.config.is_object() {
+        return Err("Preset config must be a JSON object".into());
+    }
+    let mut presets = load_presets()
+        .await
+        .map_err(|e| format!("Failed to load presets: {e:#}"))?;
+    merge_presets(&mut presets, std::slice::from_ref(&preset));
+    save_presets(&presets)
+        .await
+        .map_err(|e| format!("Failed to save presets: {e:#}"))?;
+    Ok(presets)
+}
+
+/// Delete a preset; false when no preset had that name
+#[tauri::command(async)]
+pub async fn delete_settings_preset(name: String) -> Result<bool, String> {
+    let mut presets = load_presets()
+        .await
+        .map_err(|e| format!("Failed to load presets: {e:#}"))?;
+    let before = presets.len();
+    presets.retain(|p| p.name != name);
+    if presets.len() == before {
+        return Ok(false);
+    }
+    save_presets(&presets)
+        .await
+        .map_err(|e| format!("Failed to save presets: {e:#}"))?;
+    Ok(true)
+}
@@ -0,0 +1,129 @@
+//! 시뮬레이션 크롤 (UI 데모 / 오프라인 점검)
+//!
+//! `mock-site` feature 빌드에서 세션 하나 동안 모든 HTTP 요청을 고정 HTML 사이트로 돌린 채 통합 크롤링을
+//! 그대로 실행한다. 결과는 실제 DB 에 저장되며 제품 URL 이 `https://mock-site.invalid/` 아래라서 실제
+//! 데이터와 구분된다. 세션이 끝나면(완료/실패/정리) 시뮬레이션을 해제한다.
+
+use crate::application::AppState;
+use crate::commands::unified_crawling::StartCrawlingResponse;
+use crate::domain::command_error::CommandError;
+use serde::Deserialize;
+use tauri::{AppHandle, State};
+
+/// Simulated site shape; unset fields fall back to `advanced.mock_site`
+#[derive(Debug, Default, Deserialize)]
+pub struct SimulatedCrawlRequest {
+    pub total_pages: Option<u32>,
+    pub products_on_last_page: Option<u32>,
+    pub latency_ms: Option<u64>,
+}
+
+/// Run `start_unified_crawling` against the built-in mock site
+///
+/// # Errors
+/// `request` category when the build lacks the `mock-site` feature or another crawl or sync
+/// is running; otherwise whatever `start_unified_crawling` reports.
+#[tauri::command(async)]
+pub async fn run_simulated_crawl(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+    request: Option<SimulatedCrawlRequest>,
+) -> Result<StartCrawlingResponse, CommandError> {
+    #[cfg(feature = "mock-site")]
+    {
+        simulate(app, &app_state, request.unwrap_or_default()).await
+    }
+    #[cfg(not(feature = "mock-site"))]
+    {
+        let _ = (app, app_state, request);
+        Err(CommandError::invalid_request(
+            "run_simulated_crawl requires a build with the `mock-site` feature",
+        ))
+    }
+}
+
+#[cfg(feature = "mock-site")]
+async fn simulate(
+    app: AppHandle,
+    app_state: &AppState,
+    request: SimulatedCrawlRequest,
+) -> Result<StartCrawlingResponse, CommandError> {
+    use crate::commands::unified_crawling::{StartCrawlingRequest, start_unified_crawling};
+    use crate::crawl_engine::runtime::crawl_limits::CrawlLimits;
+    use crate::crawl_engine::runtime::session_registry::{SessionStatus, session_registry};
+    use crate::crawl_engine::runtime::sync_cancellation::SyncCancellationRegistry;
+    use crate::crawl_engine::runtime::task_registry::spawn_tracked;
+    use crate::infrastructure::mock_http_client;
+    use std::time::Duration;
+    use tracing::info;
+
+    let running = SyncCancellationRegistry::shared().active_sessions();
+    if !running.is_empty() {
+        return Err(CommandError::invalid_request(format!(
+            "Simulated crawl cannot start while sync is running: {}",
+            running.join(", ")
+        )));
+    }
+    if app_state.is_crawling_active().await {
+        return Err(CommandError::invalid_request(
+            "Simulated crawl cannot start while a crawl is running",
+        ));
+    }
+
+    let config = app_state.get_config().await;
+    let mut mock_cfg = config.advanced.mock_site.clone();
+    mock_cfg.total_pages = request.total_pages.unwrap_or(mock_cfg.total_pages);
+    mock_cfg.products_on_last_page = request
+        .products_on_last_page
+        .unwrap_or(mock_cfg.products_on_last_page);
+    mock_cfg.latency_ms = request.latency_ms.unwrap_or(mock_cfg.latency_ms);
+    let mock = mock_http_client::begin_simulation(&mock_cfg, &config.advanced.url_templates);
+    info!(
+        "🧪 [SimulatedCrawl] {} pages / {} products",
+        mock.site().total_pages,
+        mock.site().total_products()
+    );
+
+    let response = match start_unified_crawling(
+        app,
+        StartCrawlingRequest {
+            mode: None,
+            override_batch_size: None,
+            override_concurrency: None,
+            delay_ms: None,
+            export: None,
+            stage_flags: Vec::new(),
+            limits: CrawlLimits::default(),
+        },
+    )
+    .await
+    {
+        Ok(response) => response,
+        Err(e) => {
+            mock_http_client::end_simulation();
+            return Err(e);
+        }
+    };
+    let Some(session_id) = response.session_id.clone() else {
+        mock_http_client::end_simulation();
+        return Ok(response);
+    };
+
+    spawn_tracked(format!("simulated-crawl:{session_id}"), None, async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            let finished = match session_registry().read().await.get(&session_id) {
+                Some(entry) => matches!(
+                    entry.status,
+                    SessionStatus::Completed | SessionStatus::Failed
+                ),
+                None => true,
+            };
+            if finished {
+                break;
+            }
+        }
+        mock_http_client::end_simulation();
+    });
+    Ok(response)
+}
@@ -0,0 +1,28 @@
+//! 시작 시 데이터 무결성 점검 결과 조회
+//!
+//! 점검 자체는 `services::startup_integrity` 가 앱 시작 직후 한 번 돌리고 `DataIntegritySnapshot`
+//! 이벤트로 알린다. 배너가 이벤트를 놓쳤을 때(창이 늦게 뜬 경우) 이 커맨드로 캐시된 결과를 읽는다.
+
+use crate::application::AppState;
+use crate::services::startup_integrity::{self, DataIntegritySnapshot};
+use tauri::State;
+
+/// Cached startup integrity report; `refresh: true` runs the check again and updates the cache.
+/// `None` while the startup check has not finished yet.
+#[tauri::command(async)]
+pub async fn get_startup_integrity_report(
+    app_state: State<'_, AppState>,
+    refresh: Option<bool>,
+) -> Result<Option<DataIntegritySnapshot>, String> {
+    if !refresh.unwrap_or(false) {
+        return Ok(startup_integrity::cached_report());
+    }
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    startup_integrity::run_and_cache(&pool)
+        .await
+        .map(Some)
+        .map_err(|e| format!("Integrity check failed: {e}"))
+}
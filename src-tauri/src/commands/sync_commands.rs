@@ -1,19 +1,46 @@
-use crate::application::AppState;
-use crate::crawl_engine::actors::types::{AppEvent, SyncAnomalyEntry};
+use crate::application::{AppState, SharedStateCache};
+use crate::crawl_engine::actors::types::{
+    AppEvent, SyncAnomalyEntry, SyncChangeset, SyncPageRetry,
+};
+use crate::crawl_engine::config::retry_policy::RetryBudget;
+use crate::crawl_engine::runtime::command_dedup::CommandDedup;
+use crate::crawl_engine::runtime::latency::{self, LatencyKey, LatencyOp, LatencyTimer};
+use crate::crawl_engine::runtime::sync_cancellation::SyncCancellationRegistry;
+use crate::crawl_engine::runtime::task_registry::{PAGE_WORKER_LIFETIME, spawn_tracked};
+use crate::domain::command_error::CommandError;
+use crate::domain::error_catalog::ErrorCode;
 use crate::domain::pagination::CanonicalPageIdCalculator;
+use crate::domain::product::ProductDetail;
+use crate::domain::product_url::{ProductUrl, canonical_product_url};
+use crate::domain::warning_catalog::WarningCode;
+use crate::infrastructure::crawling_service_impls::{
+    BoundedDetailFetch, CollectorConfig, DetailFetchError, DetailFetchOutcome,
+    ProductDetailCollectorImpl,
+};
+use crate::infrastructure::database_paths;
+use crate::infrastructure::db_maintenance;
+use crate::infrastructure::event_sink::EventSink;
+use crate::infrastructure::persistence_queue::{self, PersistenceQueue};
+use crate::infrastructure::read_snapshot;
+use crate::infrastructure::session_spill;
 use crate::infrastructure::{
-    config::csa_iot,
     html_parser::MatterDataExtractor,
     simple_http_client::RequestOptions,
+    site_profiles::resolve_site_profile,
     BatchCrawlingConfig,
     BatchCrawlingEngine,
     IntegratedProductRepository,
 };
+use crate::services::page_url_sets::{self, PageUrlSetConfig};
+use crate::services::failed_items::{self, DeadLetter, FailedAttempt, FailedItemStage};
+use crate::services::{page_revalidation, product_history, sync_eta, sync_resume};
+use crate::services::session_export::{self, SessionExportRequest};
+use crate::services::slot_reservation::{self, SlotClaim};
 use chrono::Utc;
 use sqlx::Row;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::atomic::{AtomicU32, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, State};
 use tokio::sync::Semaphore;
 use tracing::{debug, error, info, trace};
@@ -22,6 +49,528 @@ use scraper::Html;
 // Reuse helper to emit events
 use super::validation_commands::emit_actor_event;
  
+/// Listing page URL (1 = newest) of the site the extractor is bound to
+fn list_page_url(extractor: &MatterDataExtractor, page: u32) -> String {
+    extractor.site_profile().list_page_url(page)
+}
+
+/// Referer sent with list/detail requests for the extractor's site
+fn listing_referer(extractor: &MatterDataExtractor) -> String {
+    extractor.site_profile().listing_base_url().to_string()
+}
+
+/// Spend one retry from the session budget (`advanced.retry_policies.session_retry_budget`)
+fn retry_allowed(budget: &RetryBudget, session_id: &str, scope: &str) -> bool {
+    if budget.try_acquire() {
+        return true;
+    }
+    debug!(
+        "Retry budget of {} spent for {}; giving up on {}",
+        budget.used(),
+        session_id,
+        scope
+    );
+    false
+}
+
+/// Queue a page whose commit failed; the next sync session re-verifies it
+async fn queue_revalidation(
+    pool: &sqlx::SqlitePool,
+    session_id: &str,
+    physical_page: u32,
+    page_id: i32,
+    error: &sqlx::Error,
+) {
+    if let Err(e) = page_revalidation::mark(
+        pool,
+        session_id,
+        physical_page,
+        page_id as i64,
+        &error.to_string(),
+    )
+    .await
+    {
+        error!(
+            "Failed to queue page {} for revalidation: {}",
+            physical_page, e
+        );
+    }
+}
+
+/// Append one failed attempt to an item's in-session history
+fn note_attempt<K: std::hash::Hash + Eq>(
+    history: &Mutex<HashMap<K, Vec<FailedAttempt>>>,
+    key: K,
+    error: String,
+) {
+    let mut history = history.lock().unwrap_or_else(|e| e.into_inner());
+    let attempts = history.entry(key).or_default();
+    attempts.push(FailedAttempt::now(attempts.len() as u32 + 1, error));
+}
+
+/// One failed list-page attempt, tagged with the code its retry-loop error maps to
+/// (`fetch_failed:` / `read_body_failed:` / `parse_failed:` / `count_mismatch:`)
+fn list_attempt_error(last_err_msg: Option<&str>, expected_count: u32) -> String {
+    let Some(msg) = last_err_msg else {
+        return ErrorCode::EmptyListPage
+            .tag(format!("expected {} items, got none", expected_count));
+    };
+    let code = if let Some(cause) = msg
+        .strip_prefix("fetch_failed:")
+        .or_else(|| msg.strip_prefix("read_body_failed:"))
+    {
+        match ErrorCode::classify_message(cause) {
+            ErrorCode::Internal => ErrorCode::NetworkFailure,
+            code => code,
+        }
+    } else if msg.starts_with("parse_failed:") {
+        ErrorCode::ParseFailed
+    } else if msg.starts_with("count_mismatch:") && msg.ends_with(" got 0") {
+        ErrorCode::EmptyListPage
+    } else if msg.starts_with("count_mismatch:") {
+        ErrorCode::ListCollectFailed
+    } else {
+        ErrorCode::classify_message(msg)
+    };
+    code.tag(msg)
+}
+
+/// Detail URL that gave up for good; recorded with the session's other dead letters
+fn dead_letter_detail(
+    history: &Mutex<HashMap<String, Vec<FailedAttempt>>>,
+    session_id: &str,
+    physical_page: u32,
+    product_url: &ProductUrl,
+    error: &DetailFetchError,
+) -> DeadLetter {
+    let attempts = history
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(&product_url.url)
+        .unwrap_or_default();
+    DeadLetter {
+        stage: FailedItemStage::ProductDetail,
+        item_key: product_url.url.clone(),
+        session_id: session_id.to_string(),
+        error_class: error.error_code(),
+        attempts,
+        payload: serde_json::json!({
+            "url": product_url.url,
+            "page_id": product_url.page_id,
+            "index_in_page": product_url.index_in_page,
+            "physical_page": physical_page,
+        }),
+    }
+}
+
+/// Pages still failing after every retry pass, keyed by canonical page_id
+fn dead_letter_pages(
+    session_id: &str,
+    calculator: &CanonicalPageIdCalculator,
+    pages: &[u32],
+    mut history: HashMap<u32, Vec<FailedAttempt>>,
+) -> Vec<DeadLetter> {
+    pages
+        .iter()
+        .map(|&physical_page| {
+            let attempts = history.remove(&physical_page).unwrap_or_default();
+            let last_error = attempts.last().map_or("", |a| a.error.as_str());
+            let page_id = calculator.calculate(physical_page, 0).page_id;
+            DeadLetter {
+                stage: FailedItemStage::ListPage,
+                item_key: page_id.to_string(),
+                session_id: session_id.to_string(),
+                error_class: ErrorCode::classify_message(last_error),
+                attempts,
+                payload: serde_json::json!({
+                    "page_id": page_id,
+                    "physical_page": physical_page,
+                }),
+            }
+        })
+        .collect()
+}
+
+async fn record_dead_letters(pool: &sqlx::SqlitePool, session_id: &str, letters: Vec<DeadLetter>) {
+    if letters.is_empty() {
+        return;
+    }
+    info!(
+        "Dead-lettering {} item(s) that exhausted retries: session_id={}",
+        letters.len(),
+        session_id
+    );
+    for letter in &letters {
+        if let Err(e) = failed_items::record(pool, letter).await {
+            error!(
+                "Failed to record dead letter {} {}: {}",
+                letter.stage.as_str(),
+                letter.item_key,
+                e
+            );
+        }
+    }
+}
+
+/// Page latency percentiles of a finished sync session (kpi.sync)
+fn log_sync_latency(session_id: &str) {
+    let stats = latency::finish_session(session_id);
+    if !stats.is_empty() {
+        info!(target: "kpi.sync", "{{\"event\":\"sync_latency\",\"session_id\":\"{}\",\"latency\":{}}}", session_id, latency::kpi_json(&stats));
+    }
+}
+
+async fn history_watermark(pool: &sqlx::SqlitePool) -> Option<i64> {
+    match product_history::history_watermark(pool).await {
+        Ok(id) => Some(id),
+        Err(e) => {
+            error!("product_history watermark unavailable; no sync changeset: {}", e);
+            None
+        }
+    }
+}
+
+/// Field-level changes since `since` (None when the watermark or the query failed)
+async fn session_changeset(
+    pool: &sqlx::SqlitePool,
+    since: Option<i64>,
+    session_id: Option<&str>,
+) -> Option<SyncChangeset> {
+    match product_history::sync_changeset(pool, since?, session_id).await {
+        Ok(changeset) => Some(changeset),
+        Err(e) => {
+            error!("Failed to summarize sync changeset: {}", e);
+            None
+        }
+    }
+}
+
+/// Finish a cancelled sync: no sweep or revalidation close (the page set is incomplete),
+/// mark the session aborted and emit `SyncAborted`. Returns the command error.
+async fn abort_sync_session<S: EventSink + ?Sized>(
+    sink: &S,
+    pool: &sqlx::SqlitePool,
+    session_id: &str,
+    started: std::time::Instant,
+    counters: [&AtomicU32; 5],
+) -> String {
+    let [pages_processed, inserted, updated, skipped, failed] =
+        counters.map(|c| c.load(Ordering::SeqCst));
+    let duration_ms = started.elapsed().as_millis() as u64;
+    if let Err(e) = sqlx::query(
+        "UPDATE sync_sessions SET status='aborted', finished_at=CURRENT_TIMESTAMP WHERE session_id = ?",
+    )
+    .bind(session_id)
+    .execute(pool)
+    .await
+    {
+        error!("Failed to mark sync session aborted: {}", e);
+    }
+    // Aborted sessions are not resumable
+    if let Err(e) = sync_resume::clear(pool, session_id).await {
+        error!("Failed to clear remaining sync pages: {}", e);
+    }
+    if let Err(e) = slot_reservation::release_session(pool, session_id).await {
+        error!("Failed to release slot reservations: {}", e);
+    }
+    session_spill::shared_spill_store()
+        .end_session(session_id)
+        .await;
+    emit_actor_event(
+        sink,
+        AppEvent::SyncAborted {
+            session_id: session_id.to_string(),
+            reason: "cancelled".into(),
+            pages_processed,
+            inserted,
+            updated,
+            skipped,
+            failed,
+            duration_ms,
+            timestamp: Utc::now(),
+        },
+    );
+    info!(
+        "Sync aborted: session_id={} pages={} ins={} upd={} skip={} fail={} duration_ms={}",
+        session_id, pages_processed, inserted, updated, skipped, failed, duration_ms
+    );
+    format!("Sync {session_id} cancelled after {pages_processed} page(s)")
+}
+
+/// Finish a drained sync after `pause_sync_session`: in-flight pages committed, the rest stay in
+/// `sync_remaining_pages`. Marks the session paused, emits `SyncPaused` and returns the command error.
+async fn park_paused_sync_session<S: EventSink + ?Sized>(
+    sink: &S,
+    pool: &sqlx::SqlitePool,
+    session_id: &str,
+    started: std::time::Instant,
+    remaining_pages: u32,
+    counters: [&AtomicU32; 5],
+) -> String {
+    let [pages_processed, inserted, updated, skipped, failed] =
+        counters.map(|c| c.load(Ordering::SeqCst));
+    let duration_ms = started.elapsed().as_millis() as u64;
+    if let Err(e) = sqlx::query("UPDATE sync_sessions SET status='paused' WHERE session_id = ?")
+        .bind(session_id)
+        .execute(pool)
+        .await
+    {
+        error!("Failed to mark sync session paused: {}", e);
+    }
+    emit_actor_event(
+        sink,
+        AppEvent::SyncPaused {
+            session_id: session_id.to_string(),
+            pages_processed,
+            remaining_pages,
+            inserted,
+            updated,
+            skipped,
+            failed,
+            duration_ms,
+            timestamp: Utc::now(),
+        },
+    );
+    info!(
+        "Sync paused: session_id={} pages={} remaining={} ins={} upd={} skip={} fail={} duration_ms={}",
+        session_id,
+        pages_processed,
+        remaining_pages,
+        inserted,
+        updated,
+        skipped,
+        failed,
+        duration_ms
+    );
+    ErrorCode::Cancelled.tag(format!(
+        "Sync {session_id} paused after {pages_processed} page(s); {remaining_pages} page(s) left for resume_sync_session"
+    ))
+}
+
+/// Keep the page's final URL list (`advanced.page_url_sets`); an empty failed fetch is not an
+/// observation, so the retry pass records that page instead
+async fn record_page_urls(
+    pool: &sqlx::SqlitePool,
+    config: &PageUrlSetConfig,
+    session_id: &str,
+    physical_page: u32,
+    expected_count: u32,
+    urls: &[String],
+) {
+    if urls.is_empty() && expected_count > 0 {
+        return;
+    }
+    if let Err(e) = page_url_sets::record_page(
+        pool,
+        config,
+        session_id,
+        physical_page,
+        expected_count,
+        urls,
+    )
+    .await
+    {
+        error!("Failed to record URL set of page {}: {}", physical_page, e);
+    }
+}
+
+/// Trim stored page URL sets to their retention after a session
+async fn prune_page_url_sets(pool: &sqlx::SqlitePool, config: &PageUrlSetConfig) {
+    match page_url_sets::prune(pool, config).await {
+        Ok(r) if r.lists_dropped + r.rows_deleted > 0 => info!(
+            "🧾 Page URL sets pruned: {} lists dropped, {} rows deleted",
+            r.lists_dropped, r.rows_deleted
+        ),
+        Ok(_) => {}
+        Err(e) => error!("Failed to prune page URL sets: {}", e),
+    }
+}
+
+/// Roll back a page transaction interrupted by cancellation
+async fn roll_back_cancelled_page<S: EventSink + ?Sized>(
+    sink: &S,
+    tx: sqlx::Transaction<'_, sqlx::Sqlite>,
+    session_id: &str,
+    physical_page: u32,
+) {
+    let detail = match tx.rollback().await {
+        Ok(()) => format!("page {}: cancelled", physical_page),
+        Err(e) => format!("page {}: cancelled, rollback failed: {}", physical_page, e),
+    };
+    emit_actor_event(
+        sink,
+        AppEvent::SyncWarning {
+            session_id: session_id.to_string(),
+            code: WarningCode::PageRolledBack.into(),
+            detail,
+            timestamp: Utc::now(),
+        },
+    );
+}
+
+/// Write the details fetched for a committed page through the persistence queue (batched
+/// upsert that also fills the products core fields) and return the URLs saved. Flushed right
+/// away because the in-range retry that follows reads `certificate_id` back; on a failed flush
+/// the rows stay queued for the interval flusher.
+async fn persist_page_details<S: EventSink + ?Sized>(
+    sink: &S,
+    queue: &PersistenceQueue,
+    session_id: &str,
+    physical_page: u32,
+    details: Vec<(ProductDetail, u32)>,
+) -> Vec<String> {
+    if details.is_empty() {
+        return Vec::new();
+    }
+    let (rows, attempts): (Vec<ProductDetail>, Vec<u32>) = details.into_iter().unzip();
+    let urls: Vec<String> = rows.iter().map(|d| d.url.clone()).collect();
+    let written = async {
+        queue.enqueue(rows).await?;
+        queue.flush().await
+    };
+    let report = match written.await {
+        Ok(report) => report,
+        Err(e) => {
+            emit_actor_event(
+                sink,
+                AppEvent::SyncWarning {
+                    session_id: session_id.to_string(),
+                    code: WarningCode::DetailsInsertFailed.into(),
+                    detail: format!("page {}: {:#}", physical_page, e),
+                    timestamp: Utc::now(),
+                },
+            );
+            return Vec::new();
+        }
+    };
+    info!(target: "kpi.sync", "{}",
+        format!(
+            r#"{{"event":"details_flush","page":{},"rows":{},"inserted":{},"updated":{},"unchanged":{},"rejected":{}}}"#,
+            physical_page, report.rows, report.inserted, report.updated, report.unchanged, report.rejected
+        )
+    );
+    for (url, attempt) in urls.iter().zip(attempts) {
+        emit_actor_event(
+            sink,
+            AppEvent::ProductLifecycle {
+                session_id: session_id.to_string(),
+                batch_id: None,
+                page_number: Some(physical_page),
+                product_ref: url.clone(),
+                status: "details_persisted".into(),
+                retry: Some(attempt - 1),
+                duration_ms: None,
+                metrics: None,
+                timestamp: Utc::now(),
+            },
+        );
+    }
+    urls
+}
+
+/// End of a sync page pass: fold the pages that failed outright into `retry` and return the ones
+/// to run again, or None when none failed, no pass is left or the session is stopping
+fn next_failed_page_pass<S: EventSink + ?Sized>(
+    sink: &S,
+    session_id: &str,
+    retry: &mut Option<SyncPageRetry>,
+    mut failed: Vec<u32>,
+    max_passes: u32,
+    stopping: bool,
+) -> Option<Vec<u32>> {
+    failed.sort_unstable();
+    failed.dedup();
+    if let Some(state) = retry.as_mut() {
+        state.recovered = state
+            .retried
+            .iter()
+            .filter(|p| !failed.contains(p))
+            .copied()
+            .collect();
+        state.still_failed = failed.clone();
+    } else if failed.is_empty() {
+        return None;
+    } else {
+        *retry = Some(SyncPageRetry {
+            retried: failed.clone(),
+            still_failed: failed.clone(),
+            ..SyncPageRetry::default()
+        });
+    }
+    let retry = retry.as_mut()?;
+    if failed.is_empty() || stopping || retry.passes >= max_passes {
+        return None;
+    }
+    retry.passes += 1;
+    emit_actor_event(
+        sink,
+        AppEvent::SyncWarning {
+            session_id: session_id.to_string(),
+            code: WarningCode::FailedPagesRetry.into(),
+            detail: format!(
+                "pass {}/{}: retrying {} page(s) {:?}",
+                retry.passes,
+                max_passes,
+                failed.len(),
+                failed
+            ),
+            timestamp: Utc::now(),
+        },
+    );
+    Some(failed)
+}
+
+/// Reserve (page_id, index_in_page) for `url` in this session before its coordinates are written
+/// (`services::slot_reservation`). Displaced rows and collisions are surfaced as SyncWarning;
+/// returns false when the coordinates must be left alone.
+async fn claim_page_slot<S: EventSink + ?Sized>(
+    sink: &S,
+    conn: &mut sqlx::SqliteConnection,
+    session_id: &str,
+    url: &str,
+    page_id: i32,
+    index_in_page: i32,
+) -> bool {
+    let slot = format!("p{:04}i{:02}", page_id, index_in_page);
+    let claim = slot_reservation::reserve(conn, session_id, url, page_id, index_in_page).await;
+    let proceed = claim.as_ref().is_ok_and(SlotClaim::is_reserved);
+    let (code, detail) = match claim {
+        Ok(SlotClaim::Reserved) => return true,
+        Ok(SlotClaim::Displaced { previous_url }) => (
+            WarningCode::SlotDisplaced,
+            format!("{slot}: {url} replaces {previous_url} (its coordinates were cleared)"),
+        ),
+        Ok(SlotClaim::Collision { holder_url }) => (
+            WarningCode::SlotCollision,
+            format!("{slot}: {url} skipped, slot already reserved by {holder_url}"),
+        ),
+        Ok(SlotClaim::UrlElsewhere {
+            page_id: other_page,
+            index_in_page: other_index,
+        }) => (
+            WarningCode::SlotUrlElsewhere,
+            format!(
+                "{slot}: {url} skipped, already placed at p{:04}i{:02} in this session",
+                other_page, other_index
+            ),
+        ),
+        Err(e) => (
+            WarningCode::SlotReserveFailed,
+            format!("{slot}: {url}: {e:#}"),
+        ),
+    };
+    emit_actor_event(
+        sink,
+        AppEvent::SyncWarning {
+            session_id: session_id.to_string(),
+            code: code.into(),
+            detail,
+            timestamp: Utc::now(),
+        },
+    );
+    proceed
+}
+
 // Minimal summary returned by sync commands
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SyncSummary {
@@ -37,13 +586,30 @@ pub struct SyncSummary {
 /// using the new page_filter path (avoids delegating to partial sync).
 #[tauri::command(async)]
 pub async fn start_basic_sync_pages(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+    pages: Vec<u32>,
+    dry_run: Option<bool>,
+) -> Result<SyncSummary, CommandError> {
+    let args = (pages.clone(), dry_run);
+    CommandDedup::shared()
+        .run("start_basic_sync_pages", &args, || async move {
+            basic_sync_pages(app, app_state, pages, dry_run)
+                .await
+                .map_err(String::from)
+        })
+        .await
+        .map_err(CommandError::from)
+}
+
+async fn basic_sync_pages(
     app: AppHandle,
     app_state: State<'_, AppState>,
     mut pages: Vec<u32>,
     dry_run: Option<bool>,
-) -> Result<SyncSummary, String> {
+) -> Result<SyncSummary, CommandError> {
     if pages.is_empty() {
-        return Err("No pages provided".into());
+        return Err(CommandError::invalid_request("No pages provided"));
     }
 
     // Normalize page set: newest → oldest
@@ -55,11 +621,18 @@ pub async fn start_basic_sync_pages(
     let app_config = app_state.config.read().await.clone();
     let http = app_state.get_http_client().await?;
     let sync_ua = app_config.user.crawling.workers.user_agent_sync.clone();
-    let extractor = MatterDataExtractor::new().map_err(|e| e.to_string())?;
+    let extractor = MatterDataExtractor::with_profile(resolve_site_profile(&app_config))
+        .map_err(|e| e.to_string())?;
     let pool = app_state
         .get_database_pool()
         .await
         .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    // product_history watermark: the SyncCompleted changeset covers rows recorded after it
+    let history_since = history_watermark(&pool).await;
+    // Per-session slot reservations guard (page_id, index_in_page) against concurrent workers
+    if let Err(e) = slot_reservation::prune_stale(&pool).await {
+        error!("Failed to prune stale slot reservations: {}", e);
+    }
 
     // Schema capability: does products have an 'id' column?
     let products_has_id_column: bool = match sqlx::query("PRAGMA table_info(products)")
@@ -74,13 +647,13 @@ pub async fn start_basic_sync_pages(
     };
 
     // Discover site meta (Stage 1-equivalent)
-    let newest_url = csa_iot::PRODUCTS_PAGE_MATTER_ONLY.to_string();
+    let newest_url = list_page_url(&extractor, 1);
     let newest_html = match http
         .fetch_response_with_options(
             &newest_url,
             &RequestOptions {
                 user_agent_override: sync_ua.clone(),
-                referer: Some(csa_iot::PRODUCTS_BASE.to_string()),
+                referer: Some(listing_referer(&extractor)),
                 skip_robots_check: false,
                 attempt: None,
                 max_attempts: None,
@@ -89,7 +662,7 @@ pub async fn start_basic_sync_pages(
         .await
     {
         Ok(resp) => resp.text().await.map_err(|e| e.to_string())?,
-        Err(e) => return Err(e.to_string()),
+        Err(e) => return Err(e.to_string().into()),
     };
     let total_pages = extractor
         .extract_total_pages(&newest_html)
@@ -99,13 +672,13 @@ pub async fn start_basic_sync_pages(
     let oldest_html = if oldest_page == 1 {
         newest_html.clone()
     } else {
-        let oldest_url = csa_iot::PRODUCTS_PAGE_MATTER_PAGINATED.replace("{}", &oldest_page.to_string());
+        let oldest_url = list_page_url(&extractor, oldest_page);
         match http
             .fetch_response_with_options(
                 &oldest_url,
                 &RequestOptions {
                     user_agent_override: sync_ua.clone(),
-                    referer: Some(csa_iot::PRODUCTS_BASE.to_string()),
+                    referer: Some(listing_referer(&extractor)),
                     skip_robots_check: false,
                     attempt: None,
                     max_attempts: None,
@@ -114,7 +687,7 @@ pub async fn start_basic_sync_pages(
             .await
         {
             Ok(resp) => resp.text().await.map_err(|e| e.to_string())?,
-            Err(e) => return Err(e.to_string()),
+            Err(e) => return Err(e.to_string().into()),
         }
     };
     let items_on_last_page = extractor
@@ -125,23 +698,35 @@ pub async fn start_basic_sync_pages(
 
     // Emit SyncStarted with explicit pages as singleton ranges
     let session_id = format!("basic-{}", Utc::now().format("%Y%m%d%H%M%S"));
+    let cancel_guard = SyncCancellationRegistry::shared().register(&session_id);
+    let cancel = cancel_guard.token();
+    let max_concurrent = app_config
+        .user
+        .crawling
+        .workers
+        .list_page_max_concurrent
+        .max(1);
+    let rate_limit = app_config.user.crawling.workers.max_requests_per_second;
+    let in_range = pages.iter().filter(|p| (1..=total_pages).contains(*p));
+    let eta = sync_eta::SyncEta::new(
+        in_range.count() as u32,
+        max_concurrent,
+        Some(rate_limit),
+        sync_eta::prior_page_ms(&pool).await,
+    );
     emit_actor_event(
         &app,
         AppEvent::SyncStarted {
             session_id: session_id.clone(),
             ranges: pages.iter().map(|p| (*p, *p)).collect(),
-            rate_limit: Some(app_config.user.crawling.workers.max_requests_per_second),
+            rate_limit: Some(rate_limit),
+            estimated_finish_at: Some(eta.estimated_finish_at()),
             timestamp: Utc::now(),
         },
     );
+    let eta = eta.shared();
 
     // Concurrency and counters
-    let max_concurrent = app_config
-        .user
-        .crawling
-        .workers
-        .list_page_max_concurrent
-        .max(1);
     let semaphore = Arc::new(Semaphore::new(max_concurrent));
 
     let pages_processed = Arc::new(AtomicU32::new(0));
@@ -150,10 +735,17 @@ pub async fn start_basic_sync_pages(
     let skipped = Arc::new(AtomicU32::new(0));
     let failed = Arc::new(AtomicU32::new(0));
 
-    // Retry configs
-    let list_retry_count: u32 = app_config.user.crawling.product_list_retry_count.max(1);
-    let detail_retry_count: u32 = app_config.user.crawling.product_detail_retry_count.max(1);
+    // Retry configs (stage policy max_retries overrides the crawling retry counts)
+    let retry_policies = &app_config.advanced.retry_policies;
+    let list_retry_count: u32 = retry_policies
+        .list()
+        .max_retries_or(app_config.user.crawling.product_list_retry_count.max(1));
+    let detail_retry_count: u32 = retry_policies
+        .detail()
+        .max_retries_or(app_config.user.crawling.product_detail_retry_count.max(1));
+    let retry_budget = retry_policies.session_budget(&session_id);
     let is_dry_run = dry_run.unwrap_or(false);
+    let url_set_config = app_config.advanced.page_url_sets.clone();
 
     let started = std::time::Instant::now();
 
@@ -182,8 +774,15 @@ pub async fn start_basic_sync_pages(
         let products_has_id_col = products_has_id_column;
         let max_list_retries = list_retry_count;
         let max_detail_retries_cfg = detail_retry_count;
-
-        let handle = tokio::spawn(async move {
+        let list_policy = retry_policies.list().clone();
+        let detail_policy = retry_policies.detail().clone();
+        let retry_budget = retry_budget.clone();
+        let cancel = cancel.clone();
+        let eta = eta.clone();
+        let url_set_config = url_set_config.clone();
+
+        let task_name = format!("sync-page:{}:{}", session_id, physical_page);
+        let handle = spawn_tracked(task_name, Some(PAGE_WORKER_LIFETIME), async move {
             // Acquire slot
             let _permit = match permit.await {
                 Ok(p) => p,
@@ -192,6 +791,9 @@ pub async fn start_basic_sync_pages(
                     return;
                 }
             };
+            if cancel.is_cancelled() {
+                return;
+            }
 
             emit_actor_event(
                 &app,
@@ -212,13 +814,13 @@ pub async fn start_basic_sync_pages(
                 let page_html = if use_cache {
                     if physical_page == oldest_page { oldest_html_clone.clone() } else { newest_html_clone.clone() }
                 } else {
-                    let url = csa_iot::PRODUCTS_PAGE_MATTER_PAGINATED.replace("{}", &physical_page.to_string());
+                    let url = list_page_url(&extractor, physical_page);
                     match http
                         .fetch_response_with_options(
                             &url,
                             &RequestOptions {
                                 user_agent_override: sync_ua_cloned.clone(),
-                                referer: Some(csa_iot::PRODUCTS_BASE.to_string()),
+                                referer: Some(listing_referer(&extractor)),
                                 skip_robots_check: false,
                                 attempt: Some(std::cmp::max(1, attempt + 1)),
                                 max_attempts: Some(std::cmp::max(1, max_list_retries + 1)),
@@ -234,7 +836,7 @@ pub async fn start_basic_sync_pages(
                 if !page_html.is_empty() {
                     match extractor.extract_product_urls_from_content(&page_html) {
                         Ok(v) => {
-                            product_urls = v;
+                            product_urls = v.iter().map(|u| canonical_product_url(u)).collect();
                             if product_urls.len() as u32 == expected_count { break; } else {
                                 last_err_msg = Some(format!("count_mismatch: expected {} got {}", expected_count, product_urls.len()));
                             }
@@ -243,7 +845,7 @@ pub async fn start_basic_sync_pages(
                     }
                 }
 
-                if attempt >= max_list_retries { break; }
+                if attempt >= max_list_retries || !retry_allowed(&retry_budget, &session_id, "list_page") { break; }
                 // Emit retrying event
                 emit_actor_event(
                     &app,
@@ -258,8 +860,7 @@ pub async fn start_basic_sync_pages(
                         timestamp: Utc::now(),
                     },
                 );
-                let backoff_ms = 200u64 * (1u64 << attempt);
-                tokio::time::sleep(std::time::Duration::from_millis(backoff_ms + (physical_page as u64 % 37))).await;
+                tokio::time::sleep(list_policy.delay(attempt + 1)).await;
                 attempt += 1;
             }
 
@@ -269,13 +870,24 @@ pub async fn start_basic_sync_pages(
                         &app,
                         AppEvent::SyncWarning {
                             session_id: session_id.clone(),
-                            code: "count_mismatch".into(),
+                            code: WarningCode::CountMismatch.into(),
                             detail: format!("page {}: {} (got {} of {})", physical_page, msg, product_urls.len(), expected_count),
                             timestamp: Utc::now(),
                         },
                     );
                 }
             }
+            if !is_dry_run {
+                record_page_urls(
+                    &pool,
+                    &url_set_config,
+                    &session_id,
+                    physical_page,
+                    expected_count,
+                    &product_urls,
+                )
+                .await;
+            }
 
             // Transaction per page
             let mut tx = match pool.begin().await {
@@ -286,7 +898,7 @@ pub async fn start_basic_sync_pages(
                         &app,
                         AppEvent::SyncWarning {
                             session_id: session_id.clone(),
-                            code: "tx_begin_failed".into(),
+                            code: WarningCode::TxBeginFailed.into(),
                             detail: format!("page {}: {}", physical_page, e),
                             timestamp: Utc::now(),
                         },
@@ -299,9 +911,14 @@ pub async fn start_basic_sync_pages(
             let mut page_updated = 0u32;
             let mut page_skipped = 0u32;
             let mut page_failed = 0u32;
-            let page_start = std::time::Instant::now();
+            let page_timer =
+                LatencyTimer::start(LatencyKey::op(LatencyOp::SyncPage), Some(&session_id));
 
             for (i, url) in product_urls.iter().enumerate() {
+                if cancel.is_cancelled() {
+                    roll_back_cancelled_page(&app, tx, &session_id, physical_page).await;
+                    return;
+                }
                 let calc = calculator.calculate(physical_page, i);
                 if is_dry_run {
                     page_skipped += 1;
@@ -326,9 +943,24 @@ pub async fn start_basic_sync_pages(
                     .fetch_optional(&mut *tx)
                     .await {
                         Ok(r) => r,
-                        Err(e) => { page_failed += 1; failed_c.fetch_add(1, Ordering::SeqCst); emit_actor_event(&app, AppEvent::SyncWarning { session_id: session_id.clone(), code: "select_failed".into(), detail: format!("{}: {}", url, e), timestamp: Utc::now() }); continue; }
+                        Err(e) => { page_failed += 1; failed_c.fetch_add(1, Ordering::SeqCst); emit_actor_event(&app, AppEvent::SyncWarning { session_id: session_id.clone(), code: WarningCode::SelectFailed.into(), detail: format!("{}: {}", url, e), timestamp: Utc::now() }); continue; }
                     };
 
+                // Reserve the slot before touching coordinates; collisions are reported, not overwritten
+                if !claim_page_slot(
+                    &app,
+                    &mut tx,
+                    &session_id,
+                    url,
+                    calc.page_id,
+                    calc.index_in_page,
+                )
+                .await
+                {
+                    page_failed += 1;
+                    failed_c.fetch_add(1, Ordering::SeqCst);
+                    continue;
+                }
                 match row {
                     None => {
                         // Insert product
@@ -339,7 +971,7 @@ pub async fn start_basic_sync_pages(
                                 .bind(calc.index_in_page)
                                 .execute(&mut *tx).await {
                                     Ok(_) => { page_inserted += 1; inserted_c.fetch_add(1, Ordering::SeqCst); emit_actor_event(&app, AppEvent::ProductLifecycle { session_id: session_id.clone(), batch_id: None, page_number: Some(physical_page), product_ref: url.clone(), status: "product_inserted".into(), retry: None, duration_ms: None, metrics: None, timestamp: Utc::now() }); },
-                                    Err(e) => { page_failed += 1; failed_c.fetch_add(1, Ordering::SeqCst); emit_actor_event(&app, AppEvent::SyncWarning { session_id: session_id.clone(), code: "insert_failed".into(), detail: format!("{}: {}", url, e), timestamp: Utc::now() }); continue; }
+                                    Err(e) => { page_failed += 1; failed_c.fetch_add(1, Ordering::SeqCst); emit_actor_event(&app, AppEvent::SyncWarning { session_id: session_id.clone(), code: WarningCode::InsertFailed.into(), detail: format!("{}: {}", url, e), timestamp: Utc::now() }); continue; }
                                 }
                         }
                         // Ensure product_details placeholder with synthetic id
@@ -372,7 +1004,7 @@ pub async fn start_basic_sync_pages(
                                 .execute(&mut *tx)
                                 .await {
                                     Ok(_) => { page_updated += 1; updated_c.fetch_add(1, Ordering::SeqCst); emit_actor_event(&app, AppEvent::ProductLifecycle { session_id: session_id.clone(), batch_id: None, page_number: Some(physical_page), product_ref: url.clone(), status: "product_updated".into(), retry: None, duration_ms: None, metrics: None, timestamp: Utc::now() }); },
-                                    Err(e) => { page_failed += 1; failed_c.fetch_add(1, Ordering::SeqCst); emit_actor_event(&app, AppEvent::SyncWarning { session_id: session_id.clone(), code: "update_failed".into(), detail: format!("{}: {}", url, e), timestamp: Utc::now() }); emit_actor_event(&app, AppEvent::ProductLifecycle { session_id: session_id.clone(), batch_id: None, page_number: Some(physical_page), product_ref: url.clone(), status: "product_update_failed".into(), retry: None, duration_ms: None, metrics: None, timestamp: Utc::now() }); }
+                                    Err(e) => { page_failed += 1; failed_c.fetch_add(1, Ordering::SeqCst); emit_actor_event(&app, AppEvent::SyncWarning { session_id: session_id.clone(), code: WarningCode::UpdateFailed.into(), detail: format!("{}: {}", url, e), timestamp: Utc::now() }); emit_actor_event(&app, AppEvent::ProductLifecycle { session_id: session_id.clone(), batch_id: None, page_number: Some(physical_page), product_ref: url.clone(), status: "product_update_failed".into(), retry: None, duration_ms: None, metrics: None, timestamp: Utc::now() }); }
                                 }
                         } else { page_skipped += 1; skipped_c.fetch_add(1, Ordering::SeqCst); emit_actor_event(&app, AppEvent::ProductLifecycle { session_id: session_id.clone(), batch_id: None, page_number: Some(physical_page), product_ref: url.clone(), status: "product_skipped_nochange".into(), retry: None, duration_ms: None, metrics: None, timestamp: Utc::now() }); }
 
@@ -402,7 +1034,7 @@ pub async fn start_basic_sync_pages(
                         if details_missing && !is_dry_run {
                             let mut success = false;
                             for attempt in 1..=max_detail_retries_cfg {
-                                let referer_url = if physical_page == 1 { csa_iot::PRODUCTS_PAGE_MATTER_ONLY.to_string() } else { csa_iot::PRODUCTS_PAGE_MATTER_PAGINATED.replace("{}", &physical_page.to_string()) };
+                                let referer_url = list_page_url(&extractor, physical_page);
                                 match http
                                     .fetch_response_with_options(
                                         url,
@@ -524,12 +1156,12 @@ pub async fn start_basic_sync_pages(
                                         Err(_) => { /* fetch failed */ }
                                     }
                                 if attempt < max_detail_retries_cfg && !success {
+                                    if !retry_allowed(&retry_budget, &session_id, "product_detail") { break; }
                                     emit_actor_event(
                                         &app,
                                         AppEvent::SyncRetrying { session_id: session_id.clone(), scope: "product_detail".into(), physical_page: Some(physical_page), url: Some(url.clone()), attempt, max_attempts: max_detail_retries_cfg, reason: None, timestamp: Utc::now() },
                                     );
-                                    let shift = attempt - 1; let backoff_ms = 200u64 * (1u64 << shift);
-                                    tokio::time::sleep(std::time::Duration::from_millis(backoff_ms + (physical_page as u64 % 29))).await;
+                                    tokio::time::sleep(detail_policy.delay(attempt)).await;
                                 }
                             }
                             if !success { failed_c.fetch_add(1, Ordering::SeqCst); page_failed += 1; }
@@ -560,22 +1192,37 @@ pub async fn start_basic_sync_pages(
             .execute(&mut *tx)
             .await;
 
-            if let Err(e) = tx.commit().await {
+            let committed = tx.commit().await;
+            read_snapshot::mark_page_boundary();
+            if let Err(e) = committed {
                 page_failed += 1; failed_c.fetch_add(1, Ordering::SeqCst);
-                emit_actor_event(&app, AppEvent::SyncWarning { session_id: session_id.clone(), code: "tx_commit_failed".into(), detail: format!("page {}: {}", physical_page, e), timestamp: Utc::now() });
+                emit_actor_event(&app, AppEvent::SyncWarning { session_id: session_id.clone(), code: WarningCode::TxCommitFailed.into(), detail: format!("page {}: {}", physical_page, e), timestamp: Utc::now() });
+                queue_revalidation(&pool, &session_id, physical_page, canonical_pid, &e).await;
             }
 
-            let ms = page_start.elapsed().as_millis() as u64;
+            let ms = page_timer.finish().as_millis() as u64;
             pages_processed_c.fetch_add(1, Ordering::SeqCst);
+            let estimated_finish_at = sync_eta::page_completed(&eta, ms);
             emit_actor_event(
                 &app,
-                AppEvent::SyncPageCompleted { session_id: session_id.clone(), physical_page, inserted: page_inserted, updated: page_updated, skipped: page_skipped, failed: page_failed, ms, timestamp: Utc::now() },
+                AppEvent::SyncPageCompleted { session_id: session_id.clone(), physical_page, inserted: page_inserted, updated: page_updated, skipped: page_skipped, failed: page_failed, ms, estimated_finish_at, timestamp: Utc::now() },
             );
         });
         handles.push(handle);
     }
 
     for h in handles { let _ = h.await; }
+    if cancel_guard.is_cancelled() {
+        return Err(abort_sync_session(
+            &app,
+            &pool,
+            &session_id,
+            started,
+            [&pages_processed, &inserted, &updated, &skipped, &failed],
+        )
+        .await
+        .into());
+    }
 
     let duration_ms = started.elapsed().as_millis() as u64;
     let summary = SyncSummary {
@@ -587,6 +1234,16 @@ pub async fn start_basic_sync_pages(
         duration_ms,
     };
 
+    // Bulk sync SQL bypasses the search-index delta hooks
+    crate::services::search_index::reconcile_after_bulk_write(&pool).await;
+    if let Err(e) = slot_reservation::release_session(&pool, &session_id).await {
+        error!("Failed to release slot reservations: {}", e);
+    }
+    session_spill::shared_spill_store()
+        .end_session(&session_id)
+        .await;
+    prune_page_url_sets(&pool, &app_config.advanced.page_url_sets).await;
+    let changeset = session_changeset(&pool, history_since, Some(session_id.as_str())).await;
     emit_actor_event(
         &app,
         AppEvent::SyncCompleted {
@@ -601,9 +1258,15 @@ pub async fn start_basic_sync_pages(
             total_pages: Some(total_pages),
             items_on_last_page: Some(items_on_last_page as u32),
             anomalies: None,
+            changeset,
+            page_retry: None,
             timestamp: Utc::now(),
         },
     );
+    if let Err(e) = sync_eta::remember_session(&pool, &session_id).await {
+        error!("Failed to record sync page timing: {}", e);
+    }
+    log_sync_latency(&session_id);
 
     Ok(summary)
 }
@@ -617,10 +1280,28 @@ pub async fn start_batched_sync(
     ranges: String,
     _batch_size_override: Option<u32>,
     dry_run: Option<bool>,
-) -> Result<SyncSummary, String> {
+) -> Result<SyncSummary, CommandError> {
+    let args = (ranges.clone(), _batch_size_override, dry_run);
+    CommandDedup::shared()
+        .run("start_batched_sync", &args, || async move {
+            batched_sync(app, app_state, ranges, _batch_size_override, dry_run)
+                .await
+                .map_err(String::from)
+        })
+        .await
+        .map_err(CommandError::from)
+}
+
+async fn batched_sync(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+    ranges: String,
+    _batch_size_override: Option<u32>,
+    dry_run: Option<bool>,
+) -> Result<SyncSummary, CommandError> {
     // If no explicit ranges, keep existing policy by delegating directly (default span inside partial_sync)
     if ranges.trim().is_empty() {
-        return start_partial_sync(app, app_state, ranges, dry_run).await;
+        return start_partial_sync(app, app_state, ranges, dry_run, None).await;
     }
 
     // Resolve batch size: override > config > sane default
@@ -630,7 +1311,7 @@ pub async fn start_batched_sync(
 
     // Expand ranges into distinct physical pages (desc), then chunk
     let mut pages: Vec<u32> = Vec::new();
-    for (s, e) in parse_ranges(&ranges)? {
+    for (s, e) in parse_ranges(&ranges).map_err(CommandError::invalid_request)? {
         // physical pages are descending: s (older) .. e (newer)
         for p in (e..=s).rev() {
             pages.push(p);
@@ -641,7 +1322,9 @@ pub async fn start_batched_sync(
     pages.reverse();
 
     if pages.is_empty() {
-        return Err("No pages to sync after parsing ranges".into());
+        return Err(CommandError::invalid_request(
+            "No pages to sync after parsing ranges",
+        ));
     }
 
     // Prepare aggregate summary
@@ -664,7 +1347,7 @@ pub async fn start_batched_sync(
             .map(|p| p.to_string())
             .collect::<Vec<_>>()
             .join(",");
-        let res = start_partial_sync(app.clone(), app_state.clone(), batch_expr, dry_run).await?;
+        let res = start_partial_sync(app.clone(), app_state.clone(), batch_expr, dry_run, None).await?;
         agg.pages_processed = agg.pages_processed.saturating_add(res.pages_processed);
         agg.inserted = agg.inserted.saturating_add(res.inserted);
         agg.updated = agg.updated.saturating_add(res.updated);
@@ -685,12 +1368,27 @@ pub async fn start_repair_sync(
     app_state: State<'_, AppState>,
     buffer: Option<u32>,
     dry_run: Option<bool>,
+) -> Result<SyncSummary, CommandError> {
+    CommandDedup::shared()
+        .run("start_repair_sync", &(buffer, dry_run), || {
+            repair_sync(app, app_state, buffer, dry_run)
+        })
+        .await
+        .map_err(CommandError::from)
+}
+
+async fn repair_sync(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+    buffer: Option<u32>,
+    dry_run: Option<bool>,
 ) -> Result<SyncSummary, String> {
     // 1) Discover site meta (same approach as partial sync)
     let app_config = app_state.config.read().await.clone();
     let http = app_state.get_http_client().await?;
     let sync_ua = app_config.user.crawling.workers.user_agent_sync.clone();
-    let extractor = MatterDataExtractor::new().map_err(|e| e.to_string())?;
+    let extractor = MatterDataExtractor::with_profile(resolve_site_profile(&app_config))
+        .map_err(|e| e.to_string())?;
     let pool = app_state
         .get_database_pool()
         .await
@@ -709,13 +1407,13 @@ pub async fn start_repair_sync(
     };
     // (deduped id-column detection)
 
-    let newest_url = csa_iot::PRODUCTS_PAGE_MATTER_ONLY.to_string();
+    let newest_url = list_page_url(&extractor, 1);
     let newest_html = match http
         .fetch_response_with_options(
             &newest_url,
             &RequestOptions {
                 user_agent_override: sync_ua.clone(),
-                referer: Some(csa_iot::PRODUCTS_BASE.to_string()),
+                referer: Some(listing_referer(&extractor)),
                 skip_robots_check: false,
                 attempt: None,
                 max_attempts: None,
@@ -795,7 +1493,7 @@ pub async fn start_repair_sync(
         .map(|(s, e)| if s == e { s.to_string() } else { format!("{}-{}", s, e) })
         .collect::<Vec<_>>()
         .join(",");
-    start_partial_sync(app, app_state, expr, dry_run).await
+    start_partial_sync(app, app_state, expr, dry_run, None).await
 }
 
 /// Diagnostic input: specific pages and slot indices to repair
@@ -866,38 +1564,141 @@ pub async fn start_partial_sync(
     app_state: State<'_, AppState>,
     ranges: String, // e.g., "498-492,489,487-485"
     dry_run: Option<bool>,
+    export: Option<SessionExportRequest>,
+) -> Result<SyncSummary, CommandError> {
+    let args = (ranges.clone(), dry_run, export.clone());
+    CommandDedup::shared()
+        .run("start_partial_sync", &args, || {
+            run_partial_sync(Arc::new(app), &app_state, ranges, dry_run, export)
+        })
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Shell-independent core of `start_partial_sync`: events go to `sink`, so tests and
+/// headless tools can run it with a log/null sink instead of an `AppHandle`.
+pub async fn run_partial_sync(
+    sink: Arc<dyn EventSink>,
+    app_state: &AppState,
+    ranges: String,
+    dry_run: Option<bool>,
+    export: Option<SessionExportRequest>,
 ) -> Result<SyncSummary, String> {
     let session_id = format!("sync-{}", Utc::now().format("%Y%m%d%H%M%S"));
-    let started = std::time::Instant::now();
     info!(
         "start_partial_sync args: ranges=\"{}\" dry_run={:?}",
         ranges, dry_run
     );
-    let mut ranges = parse_ranges(&ranges)?;
+    let ranges = parse_ranges(&ranges).map_err(|e| ErrorCode::InvalidRequest.tag(e))?;
+    run_sync_session(sink, app_state, session_id, ranges, false, dry_run, export).await
+}
+
+/// Continue a paused sync under its original session id: only the remaining pages run, and the
+/// sweep/changeset at the end cover the whole original coverage.
+pub async fn run_resumed_sync(
+    sink: Arc<dyn EventSink>,
+    app_state: &AppState,
+    session_id: String,
+) -> Result<SyncSummary, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let coverage: Option<Option<String>> =
+        sqlx::query_scalar("SELECT coverage_text FROM sync_sessions WHERE session_id = ?")
+            .bind(&session_id)
+            .fetch_optional(&pool)
+            .await
+            .map_err(|e| format!("Failed to load sync session: {e}"))?;
+    let Some(coverage) = coverage else {
+        return Err(ErrorCode::InvalidRequest.tag(format!("Unknown sync session {session_id}")));
+    };
+    // Claim the session so two resumes cannot run it twice
+    let claimed = sqlx::query(
+        "UPDATE sync_sessions SET status='running', finished_at=NULL WHERE session_id = ? AND status='paused'",
+    )
+    .bind(&session_id)
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Failed to resume sync session: {e}"))?
+    .rows_affected();
+    if claimed == 0 {
+        return Err(ErrorCode::InvalidRequest.tag(format!("Sync {session_id} is not paused")));
+    }
+    info!("Resuming paused sync: session_id={}", session_id);
+    let ranges = parse_ranges(coverage.as_deref().unwrap_or_default()).unwrap_or_default();
+    run_sync_session(sink, app_state, session_id, ranges, true, None, None).await
+}
+
+/// Body shared by new and resumed partial syncs. A resumed session keeps its coverage and
+/// session row, replaces `ranges` with its remaining pages and skips boundary expansion.
+async fn run_sync_session(
+    sink: Arc<dyn EventSink>,
+    app_state: &AppState,
+    session_id: String,
+    mut ranges: Vec<(u32, u32)>,
+    resuming: bool,
+    dry_run: Option<bool>,
+    export: Option<SessionExportRequest>,
+) -> Result<SyncSummary, String> {
+    let started = std::time::Instant::now();
+    // Optional export sink; the guard closes it on every return path
+    let _export_guard = match &export {
+        Some(request) => Some(session_export::open_scoped(&session_id, request)?),
+        None => None,
+    };
+    // Registered for `cancel_sync_session` (and `pause_sync_session` unless dry-run) until this
+    // function returns
+    let registry = SyncCancellationRegistry::shared();
+    let cancel_guard = if dry_run.unwrap_or(false) {
+        registry.register(&session_id)
+    } else {
+        registry.register_pausable(&session_id)
+    };
+    let cancel = cancel_guard.token();
+    let pause = cancel_guard.pause_token();
 
-    // Emit a preflight start event immediately so the UI reacts without waiting for network or DB
-    emit_actor_event(
-        &app,
-        AppEvent::SyncStarted {
-            session_id: session_id.clone(),
-            ranges: ranges.clone(),
-            rate_limit: None,
-            timestamp: Utc::now(),
-        },
-    );
     info!("Sync preflight: session_id={} ranges={:?} dry_run={}", session_id, ranges, dry_run.unwrap_or(false));
 
     // Use shared AppConfig and HttpClient from AppState (DI)
     let app_config = app_state.config.read().await.clone();
     let http = app_state.get_http_client().await?;
     let sync_ua = app_config.user.crawling.workers.user_agent_sync.clone();
-    let extractor = MatterDataExtractor::new().map_err(|e| e.to_string())?;
+    let extractor = MatterDataExtractor::with_profile(resolve_site_profile(&app_config))
+        .map_err(|e| e.to_string())?;
     let pool = app_state
         .get_database_pool()
         .await
         .map_err(|e| format!("DB pool unavailable: {e}"))?;
 
-    // (rate_limit will be included in subsequent events if needed)
+    // Emit the start event before any network work so the UI reacts immediately; the estimate
+    // covers the requested ranges and is refined by every SyncPageCompleted
+    let workers = &app_config.user.crawling.workers;
+    let rate_limit = workers.max_requests_per_second;
+    let eta = sync_eta::SyncEta::new(
+        sync_eta::page_count(&ranges),
+        workers.list_page_max_concurrent.max(1),
+        Some(rate_limit),
+        sync_eta::prior_page_ms(&pool).await,
+    );
+    emit_actor_event(
+        &sink,
+        AppEvent::SyncStarted {
+            session_id: session_id.clone(),
+            ranges: ranges.clone(),
+            rate_limit: Some(rate_limit),
+            estimated_finish_at: Some(eta.estimated_finish_at()),
+            timestamp: Utc::now(),
+        },
+    );
+    let eta = eta.shared();
+
+    // product_history watermark: the SyncCompleted changeset covers rows recorded after it
+    let history_since = history_watermark(&pool).await;
+    // Per-session slot reservations guard (page_id, index_in_page) against concurrent workers
+    if let Err(e) = slot_reservation::prune_stale(&pool).await {
+        error!("Failed to prune stale slot reservations: {}", e);
+    }
 
     // start_partial_sync: Detect if products table has an 'id' column (legacy/production schema)
     let products_has_id_column: bool = match sqlx::query("PRAGMA table_info(products)")
@@ -912,13 +1713,13 @@ pub async fn start_partial_sync(
     };
 
     // Discover site meta for calculator
-    let newest_url = csa_iot::PRODUCTS_PAGE_MATTER_ONLY.to_string();
+    let newest_url = list_page_url(&extractor, 1);
     let newest_html = match http
         .fetch_response_with_options(
             &newest_url,
             &RequestOptions {
                 user_agent_override: sync_ua.clone(),
-                referer: Some(csa_iot::PRODUCTS_BASE.to_string()),
+                referer: Some(listing_referer(&extractor)),
                 skip_robots_check: false,
                 attempt: None,
                 max_attempts: None,
@@ -938,13 +1739,13 @@ pub async fn start_partial_sync(
         newest_html.clone()
     } else {
         let oldest_url =
-            csa_iot::PRODUCTS_PAGE_MATTER_PAGINATED.replace("{}", &oldest_page.to_string());
+            list_page_url(&extractor, oldest_page);
         match http
             .fetch_response_with_options(
                 &oldest_url,
                 &RequestOptions {
                     user_agent_override: sync_ua.clone(),
-                    referer: Some(csa_iot::PRODUCTS_BASE.to_string()),
+                    referer: Some(listing_referer(&extractor)),
                     skip_robots_check: false,
                     attempt: None,
                     max_attempts: None,
@@ -966,11 +1767,25 @@ pub async fn start_partial_sync(
         "Sync site meta: total_pages={} items_on_last_page={}",
         total_pages, items_on_last_page
     );
+    // A resumed session runs exactly the pages it had left when it was paused
+    if resuming {
+        ranges = sync_resume::remaining(&pool, &session_id, total_pages)
+            .await
+            .map_err(|e| format!("Failed to load remaining sync pages: {e}"))?
+            .into_iter()
+            .map(|page| (page, page))
+            .collect();
+        info!(
+            "Resumed sync pages: session_id={} pages={}",
+            session_id,
+            ranges.len()
+        );
+    }
 
     // Determine effective page span limit based on conditional policy
     // - If no explicit ranges provided: default span limit = 50 pages
     // - If explicit ranges provided: span limit = floor(local DB product count / 12)
-    let limit: u32 = if ranges.is_empty() {
+    let limit: u32 = if ranges.is_empty() && !resuming {
         let default_limit = 50u32;
         let end_newest = total_pages.saturating_sub(default_limit - 1).max(1);
         ranges = vec![(total_pages, end_newest)];
@@ -1031,49 +1846,70 @@ pub async fn start_partial_sync(
         }
     }
 
-    // Persist final coverage_text after clamping/defaulting
-    let coverage_text = if ranges.is_empty() {
-        String::new()
-    } else {
-        ranges
-            .iter()
-            .map(|(s, e)| {
-                if s == e {
-                    s.to_string()
-                } else {
-                    format!("{}-{}", s, e)
+    // Pages left in an unknown state by earlier commit failures ride along for re-verification
+    let revalidation_pass = !dry_run.unwrap_or(false);
+    // A resumed session keeps the pass its first run began (and the pages it added)
+    if revalidation_pass && !resuming {
+        match page_revalidation::begin_pass(&pool, total_pages).await {
+            Ok(pages) => {
+                for page in pages {
+                    if !ranges.iter().any(|&(s, e)| page <= s && page >= e) {
+                        ranges.push((page, page));
+                    }
                 }
-            })
-            .collect::<Vec<_>>()
-            .join(",")
-    };
-    if let Err(e) = sqlx::query("UPDATE sync_sessions SET coverage_text = ? WHERE session_id = ?")
-        .bind(&coverage_text)
+            }
+            Err(e) => error!("Failed to load pages pending revalidation: {}", e),
+        }
+    }
+
+    // A resumed session keeps its original coverage (the sweep covers all of it) and its row,
+    // already claimed by `run_resumed_sync`
+    if !resuming {
+        // Persist final coverage_text after clamping/defaulting
+        let coverage_text = if ranges.is_empty() {
+            String::new()
+        } else {
+            ranges
+                .iter()
+                .map(|(s, e)| {
+                    if s == e {
+                        s.to_string()
+                    } else {
+                        format!("{}-{}", s, e)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        if let Err(e) = sqlx::query("UPDATE sync_sessions SET coverage_text = ? WHERE session_id = ?")
+            .bind(&coverage_text)
+            .bind(&session_id)
+            .execute(&pool)
+            .await
+        {
+            error!("Failed to update sync session coverage: {}", e);
+        }
+
+        // Record session start in DB (idempotent upsert by primary key)
+        if let Err(e) = sqlx::query(
+            "INSERT INTO sync_sessions(session_id, status, coverage_text, workspace, started_at) VALUES(?, 'running', ?, ?, CURRENT_TIMESTAMP)
+             ON CONFLICT(session_id) DO UPDATE SET status='running', coverage_text=excluded.coverage_text, workspace=excluded.workspace, started_at=excluded.started_at, finished_at=NULL",
+        )
         .bind(&session_id)
+        .bind(match ranges.as_slice() {
+            rs if rs.is_empty() => String::new(),
+            rs => rs
+                .iter()
+                .map(|(s, e)| if s == e { s.to_string() } else { format!("{}-{}", s, e) })
+                .collect::<Vec<_>>()
+                .join(","),
+        })
+        .bind(database_paths::active_workspace())
         .execute(&pool)
         .await
-    {
-        error!("Failed to update sync session coverage: {}", e);
-    }
-
-    // Record session start in DB (idempotent upsert by primary key)
-    if let Err(e) = sqlx::query(
-        "INSERT INTO sync_sessions(session_id, status, coverage_text, started_at) VALUES(?, 'running', ?, CURRENT_TIMESTAMP)
-         ON CONFLICT(session_id) DO UPDATE SET status='running', coverage_text=excluded.coverage_text, started_at=excluded.started_at, finished_at=NULL",
-    )
-    .bind(&session_id)
-    .bind(match ranges.as_slice() {
-        rs if rs.is_empty() => String::new(),
-        rs => rs
-            .iter()
-            .map(|(s, e)| if s == e { s.to_string() } else { format!("{}-{}", s, e) })
-            .collect::<Vec<_>>()
-            .join(","),
-    })
-    .execute(&pool)
-    .await
-    {
-        error!("Failed to record sync session start: {}", e);
+        {
+            error!("Failed to record sync session start: {}", e);
+        }
     }
 
     // Note: detailed "Sync started" log was moved to preflight above
@@ -1083,7 +1919,10 @@ pub async fn start_partial_sync(
     // - 오래된 쪽 이웃: start_oldest + 1 (더 오래된 물리 페이지)
     // - 최신 쪽 이웃: end_newest - 1 (더 최신 물리 페이지)
     // 예) 단일 페이지 377 지정 시 실행 집합: {378, 376, 377} (범위 포함 시 중복은 HashSet으로 제거)
-    let pages_vec: Vec<u32> = {
+    let pages_vec: Vec<u32> = if resuming {
+        // Remaining pages already include the boundary neighbours of the original plan
+        ranges.iter().map(|&(page, _)| page).collect()
+    } else {
         use std::collections::HashSet;
         let mut ordered: Vec<u32> = Vec::new();
         let mut seen: HashSet<u32> = HashSet::new();
@@ -1124,6 +1963,18 @@ pub async fn start_partial_sync(
         ordered
     };
 
+    if let Ok(mut eta) = eta.lock() {
+        eta.set_total_pages(pages_vec.len() as u32);
+    }
+
+    // Pages leave the set as they complete; whatever is left after a pause is resumed later
+    if !dry_run.unwrap_or(false) {
+        if let Err(e) = sync_resume::record_plan(&pool, &session_id, total_pages, &pages_vec).await
+        {
+            error!("Failed to record sync page plan: {}", e);
+        }
+    }
+
     let max_concurrent = app_config
         .user
         .crawling
@@ -1142,20 +1993,90 @@ pub async fn start_partial_sync(
     let skipped = Arc::new(AtomicU32::new(0));
     let failed = Arc::new(AtomicU32::new(0));
 
-    let app_handle = app.clone();
+    let task_sink = sink.clone();
     let pool_arc = pool.clone();
     let http_client = http.clone();
     let extractor_global = extractor.clone();
     let calculator_global = calculator.clone();
+    let url_set_config = app_config.advanced.page_url_sets.clone();
 
     // Cache retry configs to avoid moving config into tasks
-    let list_retry_count: u32 = app_config.user.crawling.product_list_retry_count.max(1);
-    let detail_retry_count: u32 = app_config.user.crawling.product_detail_retry_count.max(1);
+    // (stage policy max_retries overrides the crawling retry counts)
+    let retry_policies = &app_config.advanced.retry_policies;
+    let list_retry_count: u32 = retry_policies
+        .list()
+        .max_retries_or(app_config.user.crawling.product_list_retry_count.max(1));
+    let detail_retry_count: u32 = retry_policies
+        .detail()
+        .max_retries_or(app_config.user.crawling.product_detail_retry_count.max(1));
+    let retry_budget = retry_policies.session_budget(&session_id);
+    // Missing details go through the detail collector: bounded concurrency per page worker,
+    // while the shared per-host rate limiter caps the total request rate
+    let detail_max_concurrent = app_config
+        .user
+        .crawling
+        .workers
+        .product_detail_max_concurrent
+        .max(1) as u32;
+    let workers = &app_config.user.crawling.workers;
+    let detail_collector = Arc::new(
+        ProductDetailCollectorImpl::new(
+            Arc::new(http.clone()),
+            Arc::new(extractor.clone()),
+            CollectorConfig {
+                max_concurrent: detail_max_concurrent,
+                concurrency: detail_max_concurrent,
+                retry_attempts: detail_retry_count,
+                retry_max: detail_retry_count,
+                ..CollectorConfig::default()
+            },
+        )
+        .with_fetch_order(workers.detail_fetch_order, workers.detail_fetch_seed),
+    );
 
-    let mut handles = Vec::with_capacity(pages_vec.len());
-    for physical_page in pages_vec {
+    // Pages that fail outright (tx_begin failure, nothing extracted after retries) are collected
+    // and run again once every page had its turn, up to `failed_page_passes` more times
+    let failed_pages: Arc<Mutex<Vec<u32>>> = Arc::default();
+    let max_page_passes = retry_policies.failed_page_passes();
+    // Attempt history of failing pages, and items that exhausted retries (`failed_items`)
+    let page_attempts: Arc<Mutex<HashMap<u32, Vec<FailedAttempt>>>> = Arc::default();
+    let dead_letters: Arc<Mutex<Vec<DeadLetter>>> = Arc::default();
+    let mut page_retry: Option<SyncPageRetry> = None;
+    let mut pending: VecDeque<u32> = pages_vec.into();
+    let mut handles = Vec::with_capacity(pending.len());
+    loop {
+        let Some(physical_page) = pending.pop_front() else {
+            for h in handles.drain(..) {
+                let _ = h.await;
+            }
+            let failed_now =
+                std::mem::take(&mut *failed_pages.lock().unwrap_or_else(|e| e.into_inner()));
+            let stopping = cancel_guard.is_cancelled() || cancel_guard.is_paused();
+            match next_failed_page_pass(
+                &sink,
+                &session_id,
+                &mut page_retry,
+                failed_now,
+                max_page_passes,
+                stopping,
+            ) {
+                Some(pages) => {
+                    if let Ok(mut eta) = eta.lock() {
+                        eta.add_pages(pages.len() as u32);
+                    }
+                    pending.extend(pages);
+                    continue;
+                }
+                None => break,
+            }
+        };
+        // Last pass: failed pages are counted as failed instead of deferred
+        let final_pass = page_retry.as_ref().map_or(0, |r| r.passes) >= max_page_passes;
+        let failed_pages_c = failed_pages.clone();
+        let page_attempts_c = page_attempts.clone();
+        let dead_letters_c = dead_letters.clone();
         let permit = semaphore.clone().acquire_owned();
-        let app = app_handle.clone();
+        let sink = task_sink.clone();
         let session_id = session_id.clone();
         let pool = pool_arc.clone();
         let http = http_client.clone();
@@ -1173,9 +2094,18 @@ pub async fn start_partial_sync(
     let is_dry_run = dry_run.unwrap_or(false);
         let max_list_retries = list_retry_count;
         let max_detail_retries_cfg = detail_retry_count;
+        let list_policy = retry_policies.list().clone();
+        let detail_policy = retry_policies.detail().clone();
+        let retry_budget = retry_budget.clone();
+        let detail_collector = detail_collector.clone();
+        let cancel = cancel.clone();
+        let pause = pause.clone();
+        let eta = eta.clone();
+        let url_set_config = url_set_config.clone();
 
     let has_id_col = products_has_id_column; // copy into task
-    let handle = tokio::spawn(async move {
+    let task_name = format!("sync-page:{}:{}", session_id, physical_page);
+    let handle = spawn_tracked(task_name, Some(PAGE_WORKER_LIFETIME), async move {
             // Acquire concurrency slot
             let _permit = match permit.await {
                 Ok(p) => p,
@@ -1184,9 +2114,16 @@ pub async fn start_partial_sync(
                     return;
                 }
             };
+            if cancel.is_cancelled() {
+                return;
+            }
+            // Paused: pages not started yet stay in the remaining set; pages in flight drain
+            if pause.is_cancelled() {
+                return;
+            }
 
             emit_actor_event(
-                &app,
+                &sink,
                 AppEvent::SyncPageStarted {
                     session_id: session_id.clone(),
                     physical_page,
@@ -1206,8 +2143,11 @@ pub async fn start_partial_sync(
             info!(target: "kpi.sync", "{{\"event\":\"sync_retry_config\",\"session_id\":\"{}\",\"page\":{},\"max_retries\":{}}}", session_id, physical_page, max_retries);
             let mut attempt = 0u32;
             let mut product_urls: Vec<String> = Vec::new();
-            let mut last_err_msg: Option<String> = None;
             loop {
+                if cancel.is_cancelled() {
+                    return;
+                }
+                let mut last_err_msg: Option<String> = None;
                 // Choose source: first attempt can reuse cached for edges; retries always fetch fresh
                 let use_cache =
                     attempt == 0 && (physical_page == oldest_page || physical_page == 1);
@@ -1218,15 +2158,14 @@ pub async fn start_partial_sync(
                         newest_html_clone.clone()
                     }
                 } else {
-                    let url = csa_iot::PRODUCTS_PAGE_MATTER_PAGINATED
-                        .replace("{}", &physical_page.to_string());
+                    let url = list_page_url(&extractor, physical_page);
                     // Convey attempt/max to HttpClient for improved logging
                     match http
                         .fetch_response_with_options(
                             &url,
                             &RequestOptions {
                                 user_agent_override: sync_ua_cloned.clone(),
-                                referer: Some(csa_iot::PRODUCTS_BASE.to_string()),
+                                referer: Some(listing_referer(&extractor)),
                                 skip_robots_check: false,
                                 attempt: Some(std::cmp::max(1, attempt + 1)),
                                 max_attempts: Some(std::cmp::max(1, max_retries + 1)),
@@ -1254,7 +2193,7 @@ pub async fn start_partial_sync(
                 } else {
                     match extractor.extract_product_urls_from_content(&page_html) {
                         Ok(v) => {
-                            product_urls = v;
+                            product_urls = v.iter().map(|u| canonical_product_url(u)).collect();
                             if product_urls.len() as u32 == expected_count {
                                 // success; no need to reset last_err_msg explicitly
                                 // success
@@ -1273,14 +2212,21 @@ pub async fn start_partial_sync(
                     }
                 }
 
-                if attempt >= max_retries {
+                // Every failed attempt goes into the page's history; a dead letter keeps them all
+                note_attempt(
+                    &page_attempts_c,
+                    physical_page,
+                    list_attempt_error(last_err_msg.as_deref(), expected_count),
+                );
+
+                if attempt >= max_retries || !retry_allowed(&retry_budget, &session_id, "list_page") {
                     // Give up, emit warning and proceed with what we have (possibly empty/partial)
                     if let Some(msg) = &last_err_msg {
                         emit_actor_event(
-                            &app,
+                            &sink,
                             AppEvent::SyncWarning {
                                 session_id: session_id.clone(),
-                                code: "page_incomplete_after_retries".into(),
+                                code: WarningCode::PageIncompleteAfterRetries.into(),
                                 detail: format!(
                                     "page {}: {} after {} retries",
                                     physical_page, msg, attempt
@@ -1296,7 +2242,7 @@ pub async fn start_partial_sync(
                 if let Some(msg) = &last_err_msg {
                     info!(target: "kpi.sync", "{{\"event\":\"retry_attempt\",\"session_id\":\"{}\",\"page\":{},\"attempt\":{},\"max_retries\":{},\"reason\":\"{}\"}}", session_id, physical_page, attempt + 1, max_retries, msg);
                     emit_actor_event(
-                        &app,
+                        &sink,
                         AppEvent::SyncRetrying {
                             session_id: session_id.clone(),
                             scope: "list_page".into(),
@@ -1311,7 +2257,7 @@ pub async fn start_partial_sync(
                 } else {
                     info!(target: "kpi.sync", "{{\"event\":\"retry_attempt\",\"session_id\":\"{}\",\"page\":{},\"attempt\":{},\"max_retries\":{}}}", session_id, physical_page, attempt + 1, max_retries);
                     emit_actor_event(
-                        &app,
+                        &sink,
                         AppEvent::SyncRetrying {
                             session_id: session_id.clone(),
                             scope: "list_page".into(),
@@ -1325,22 +2271,18 @@ pub async fn start_partial_sync(
                     );
                 }
 
-                // Backoff with jitter
-                let backoff_ms = 200u64 * (1u64 << attempt);
-                tokio::time::sleep(std::time::Duration::from_millis(
-                    backoff_ms + (physical_page as u64 % 50),
-                ))
-                .await;
+                // Backoff per list retry policy
+                tokio::time::sleep(list_policy.delay(attempt + 1)).await;
                 attempt += 1;
             }
 
             // Log mismatch if persists
             if product_urls.len() as u32 != expected_count {
                 emit_actor_event(
-                    &app,
+                    &sink,
                     AppEvent::SyncWarning {
                         session_id: session_id.clone(),
-                        code: "count_mismatch".into(),
+                        code: WarningCode::CountMismatch.into(),
                         detail: format!(
                             "page {}: expected {} items, extracted {} (after retries)",
                             physical_page,
@@ -1352,29 +2294,39 @@ pub async fn start_partial_sync(
                 );
             }
 
+            if !is_dry_run {
+                record_page_urls(
+                    &pool,
+                    &url_set_config,
+                    &session_id,
+                    physical_page,
+                    expected_count,
+                    &product_urls,
+                )
+                .await;
+            }
+
+            // Nothing extracted even after retries: leave the page to the next retry pass
+            if product_urls.is_empty() && expected_count > 0 {
+                failed_pages_c
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .push(physical_page);
+                if !final_pass {
+                    return;
+                }
+            }
+
             let mut page_inserted = 0u32;
             let mut page_updated = 0u32;
             let mut page_skipped = 0u32;
             let mut page_failed = 0u32; // aggregated into failed_c
-            let page_start = std::time::Instant::now();
-
-            // Begin a transaction for this page
-            let mut tx = match pool.begin().await {
-                Ok(t) => t,
-                Err(e) => {
-                    failed_c.fetch_add(product_urls.len() as u32, Ordering::SeqCst);
-                    emit_actor_event(
-                        &app,
-                        AppEvent::SyncWarning {
-                            session_id: session_id.clone(),
-                            code: "tx_begin_failed".into(),
-                            detail: format!("page {}: {}", physical_page, e),
-                            timestamp: Utc::now(),
-                        },
-                    );
-                    return;
-                }
-            };
+            // URLs saved on this page, streamed to the session export sink after commit
+            let mut export_urls: Vec<String> = Vec::new();
+            // Fetched details (with their attempt count), persisted after the page commits
+            let mut page_details: Vec<(ProductDetail, u32)> = Vec::new();
+            let page_timer =
+                LatencyTimer::start(LatencyKey::op(LatencyOp::SyncPage), Some(&session_id));
 
             // 0) 우선순위 재정렬: products에는 존재하지만 product_details에 미존재한 URL을 먼저 처리
             let mut missing_first: Vec<usize> = Vec::new();
@@ -1384,7 +2336,7 @@ pub async fn start_partial_sync(
                     "SELECT 1 FROM products WHERE url = ? LIMIT 1",
                 )
                 .bind(url)
-                .fetch_optional(&mut *tx)
+                .fetch_optional(&pool)
                 .await
                 {
                     Ok(opt) => opt.is_some(),
@@ -1395,7 +2347,7 @@ pub async fn start_partial_sync(
                         "SELECT 1 FROM product_details WHERE url = ? LIMIT 1",
                     )
                     .bind(url)
-                    .fetch_optional(&mut *tx)
+                    .fetch_optional(&pool)
                     .await
                     {
                         Ok(opt) => opt.is_some(),
@@ -1411,14 +2363,147 @@ pub async fn start_partial_sync(
                 }
             }
 
+            // 1) 상세 누락 URL은 트랜잭션 밖에서 collector로 병렬 수집 (동시성/재시도 제한 적용)
+            let referer_url = list_page_url(&extractor, physical_page);
+            let detail_attempts: Mutex<HashMap<String, Vec<FailedAttempt>>> = Mutex::default();
+            let on_detail_failure = |product_url: &ProductUrl,
+                                     attempt: u32,
+                                     max_attempts: u32,
+                                     e: &DetailFetchError| {
+                note_attempt(&detail_attempts, product_url.url.clone(), e.to_string());
+                emit_actor_event(
+                    &sink,
+                    AppEvent::SyncWarning {
+                        session_id: session_id.clone(),
+                        code: e.code().into(),
+                        detail: format!("{}: {}", product_url.url, e),
+                        timestamp: Utc::now(),
+                    },
+                );
+                info!(target: "kpi.sync", "{}",
+                    format!(
+                        r#"{{"event":"details_upsert","action":"{}","page":{},"page_id":{},"index":{},"url":"{}","attempt":{},"max":{},"error":"{}"}}"#,
+                        e.code().as_str().trim_start_matches("details_"), physical_page, product_url.page_id, product_url.index_in_page, product_url.url, attempt, max_attempts, e
+                    )
+                );
+                if attempt >= max_attempts
+                    || cancel.is_cancelled()
+                    || !retry_allowed(&retry_budget, &session_id, "product_detail")
+                {
+                    // A cancelled URL did not exhaust its retries
+                    if !cancel.is_cancelled() {
+                        let letter = dead_letter_detail(
+                            &detail_attempts,
+                            &session_id,
+                            physical_page,
+                            product_url,
+                            e,
+                        );
+                        dead_letters_c
+                            .lock()
+                            .unwrap_or_else(|e| e.into_inner())
+                            .push(letter);
+                    }
+                    return None;
+                }
+                emit_actor_event(
+                    &sink,
+                    AppEvent::SyncRetrying {
+                        session_id: session_id.clone(),
+                        scope: "product_detail".into(),
+                        physical_page: Some(physical_page),
+                        url: Some(product_url.url.clone()),
+                        attempt,
+                        max_attempts,
+                        reason: None,
+                        timestamp: Utc::now(),
+                    },
+                );
+                let backoff = detail_policy.delay(attempt);
+                info!(target: "kpi.sync", "{}",
+                    format!(
+                        r#"{{"event":"details_retry_attempt","page":{},"page_id":{},"index":{},"url":"{}","next_delay_ms":{},"attempt":{},"max":{}}}"#,
+                        physical_page, product_url.page_id, product_url.index_in_page, product_url.url, backoff.as_millis(), attempt, max_attempts
+                    )
+                );
+                Some(backoff)
+            };
+            let detail_fetch = BoundedDetailFetch {
+                user_agent_override: sync_ua_cloned.clone(),
+                referer: Some(referer_url),
+                on_failure: &on_detail_failure,
+            };
+            let mut prefetched: HashMap<String, DetailFetchOutcome> = HashMap::new();
+            if !is_dry_run && !missing_first.is_empty() && !cancel.is_cancelled() {
+                let targets: Vec<ProductUrl> = missing_first
+                    .iter()
+                    .map(|&i| {
+                        let calc = calculator.calculate(physical_page, i);
+                        ProductUrl {
+                            url: product_urls[i].clone(),
+                            page_id: calc.page_id,
+                            index_in_page: calc.index_in_page,
+                        }
+                    })
+                    .collect();
+                let fetch_started = std::time::Instant::now();
+                for outcome in detail_collector
+                    .collect_details_bounded(&targets, &detail_fetch)
+                    .await
+                {
+                    prefetched.insert(outcome.product_url.url.clone(), outcome);
+                }
+                info!(target: "kpi.sync", "{}",
+                    format!(
+                        r#"{{"event":"details_prefetch","page":{},"count":{},"fetched":{},"elapsed_ms":{}}}"#,
+                        physical_page, targets.len(), prefetched.values().filter(|o| o.result.is_ok()).count(), fetch_started.elapsed().as_millis()
+                    )
+                );
+            }
+
+            // Begin a transaction for this page
+            let mut tx = match pool.begin().await {
+                Ok(t) => t,
+                Err(e) => {
+                    note_attempt(
+                        &page_attempts_c,
+                        physical_page,
+                        ErrorCode::DatabaseError.tag(format!("page {}: {}", physical_page, e)),
+                    );
+                    failed_pages_c
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .push(physical_page);
+                    // Counted once no retry pass is left
+                    if final_pass {
+                        failed_c.fetch_add(product_urls.len() as u32, Ordering::SeqCst);
+                    }
+                    emit_actor_event(
+                        &sink,
+                        AppEvent::SyncWarning {
+                            session_id: session_id.clone(),
+                            code: WarningCode::TxBeginFailed.into(),
+                            detail: format!("page {}: {}", physical_page, e),
+                            timestamp: Utc::now(),
+                        },
+                    );
+                    return;
+                }
+            };
+
             for idx in missing_first.into_iter().chain(remaining.into_iter()) {
+                // Cancelled mid-page: drop the partial page instead of committing it
+                if cancel.is_cancelled() {
+                    roll_back_cancelled_page(&sink, tx, &session_id, physical_page).await;
+                    return;
+                }
                 let i = idx;
                 let url = &product_urls[i];
                 let calc = calculator.calculate(physical_page, i);
                 if is_dry_run {
                     page_skipped += 1; // dry-run counts as skipped
                     emit_actor_event(
-                        &app,
+                        &sink,
                         AppEvent::SyncUpsertProgress {
                             session_id: session_id.clone(),
                             physical_page,
@@ -1445,10 +2530,10 @@ pub async fn start_partial_sync(
                 .await
                 {
                     emit_actor_event(
-                        &app,
+                        &sink,
                         AppEvent::SyncWarning {
                             session_id: session_id.clone(),
-                            code: "observed_record_failed".into(),
+                            code: WarningCode::ObservedRecordFailed.into(),
                             detail: format!("{}: {}", url, e),
                             timestamp: Utc::now(),
                         },
@@ -1468,10 +2553,10 @@ pub async fn start_partial_sync(
                         page_failed += 1;
                         failed_c.fetch_add(1, Ordering::SeqCst);
                         emit_actor_event(
-                            &app,
+                            &sink,
                             AppEvent::SyncWarning {
                                 session_id: session_id.clone(),
-                                code: "select_failed".into(),
+                                code: WarningCode::SelectFailed.into(),
                                 detail: format!("{}: {}", url, e),
                                 timestamp: Utc::now(),
                             },
@@ -1480,6 +2565,21 @@ pub async fn start_partial_sync(
                     }
                 };
 
+                // Reserve the slot before touching coordinates; collisions are reported, not overwritten
+                if !claim_page_slot(
+                    &sink,
+                    &mut tx,
+                    &session_id,
+                    url,
+                    calc.page_id,
+                    calc.index_in_page,
+                )
+                .await
+                {
+                    page_failed += 1;
+                    failed_c.fetch_add(1, Ordering::SeqCst);
+                    continue;
+                }
                 match row {
                     None => {
                         // Log attempt to insert a missing product
@@ -1503,6 +2603,7 @@ pub async fn start_partial_sync(
                                 Ok(_) => {
                                     page_inserted += 1;
                                     inserted_c.fetch_add(1, Ordering::SeqCst);
+                                    export_urls.push(url.clone());
                                     // Success logs + FE lifecycle
                                     info!(target: "kpi.sync", "{}",
                                         format!(
@@ -1511,7 +2612,7 @@ pub async fn start_partial_sync(
                                         )
                                     );
                                     emit_actor_event(
-                                        &app,
+                                        &sink,
                                         AppEvent::ProductLifecycle {
                                             session_id: session_id.clone(),
                                             batch_id: None,
@@ -1527,10 +2628,10 @@ pub async fn start_partial_sync(
                                 }
                                 Err(e) => {
                                     emit_actor_event(
-                                        &app,
+                                        &sink,
                                         AppEvent::SyncWarning {
                                             session_id: session_id.clone(),
-                                            code: "insert_failed".into(),
+                                            code: WarningCode::InsertFailed.into(),
                                             detail: format!("{}: {}", url, e),
                                             timestamp: Utc::now(),
                                         },
@@ -1541,10 +2642,10 @@ pub async fn start_partial_sync(
                         } else {
                             // skip invalid coordinates
                             emit_actor_event(
-                                &app,
+                                &sink,
                                 AppEvent::SyncWarning {
                                     session_id: session_id.clone(),
-                                    code: "invalid_coordinates".into(),
+                                    code: WarningCode::InvalidCoordinates.into(),
                                     detail: format!("skip url={} pid={} idx={}", url, calc.page_id, calc.index_in_page),
                                     timestamp: Utc::now(),
                                 },
@@ -1598,6 +2699,7 @@ pub async fn start_partial_sync(
                                 Ok(_) => {
                                     page_updated += 1;
                                     updated_c.fetch_add(1, Ordering::SeqCst);
+                                    export_urls.push(url.clone());
                                     info!(target: "kpi.sync", "{}",
                                         format!(
                                             r#"{{"event":"product_upsert","action":"updated","page":{},"page_id":{},"index":{},"url":"{}"}}"#,
@@ -1605,7 +2707,7 @@ pub async fn start_partial_sync(
                                         )
                                     );
                                     emit_actor_event(
-                                        &app,
+                                        &sink,
                                         AppEvent::ProductLifecycle {
                                             session_id: session_id.clone(),
                                             batch_id: None,
@@ -1623,10 +2725,10 @@ pub async fn start_partial_sync(
                                     page_failed += 1;
                                     failed_c.fetch_add(1, Ordering::SeqCst);
                                     emit_actor_event(
-                                        &app,
+                                        &sink,
                                         AppEvent::SyncWarning {
                                             session_id: session_id.clone(),
-                                            code: "update_failed".into(),
+                                            code: WarningCode::UpdateFailed.into(),
                                             detail: format!("{}: {}", url, e),
                                             timestamp: Utc::now(),
                                         },
@@ -1638,7 +2740,7 @@ pub async fn start_partial_sync(
                                         )
                                     );
                                     emit_actor_event(
-                                        &app,
+                                        &sink,
                                         AppEvent::ProductLifecycle {
                                             session_id: session_id.clone(),
                                             batch_id: None,
@@ -1663,7 +2765,7 @@ pub async fn start_partial_sync(
                                 )
                             );
                             emit_actor_event(
-                                &app,
+                                &sink,
                                 AppEvent::ProductLifecycle {
                                     session_id: session_id.clone(),
                                     batch_id: None,
@@ -1705,10 +2807,10 @@ pub async fn start_partial_sync(
                             }
                             Err(e) => {
                                 emit_actor_event(
-                                    &app,
+                                    &sink,
                                     AppEvent::SyncWarning {
                                         session_id: session_id.clone(),
-                                        code: "details_update_failed".into(),
+                                        code: WarningCode::DetailsUpdateFailed.into(),
                                         detail: format!("{}: {}", url, e),
                                         timestamp: Utc::now(),
                                     },
@@ -1735,264 +2837,37 @@ pub async fn start_partial_sync(
                         if details_missing {
                             let max_detail_retries = max_detail_retries_cfg;
                             let mut success = false;
-                            for attempt in 1..=max_detail_retries {
-                                let referer_url = if physical_page == 1 {
-                                    csa_iot::PRODUCTS_PAGE_MATTER_ONLY.to_string()
-                                } else {
-                                    csa_iot::PRODUCTS_PAGE_MATTER_PAGINATED
-                                        .replace("{}", &physical_page.to_string())
-                                };
-                                match http
-                                    .fetch_response_with_options(
-                                        url,
-                                        &RequestOptions {
-                                            user_agent_override: sync_ua_cloned.clone(),
-                                            referer: Some(referer_url),
-                                            skip_robots_check: false,
-                                            attempt: Some(attempt),
-                                            max_attempts: Some(max_detail_retries),
-                                        },
+                            // Prefetched before the page transaction; a URL that became missing since is
+                            // fetched on its own through the same collector
+                            let outcome = match prefetched.remove(url.as_str()) {
+                                Some(outcome) => Some(outcome),
+                                None => detail_collector
+                                    .collect_details_bounded(
+                                        &[ProductUrl {
+                                            url: url.clone(),
+                                            page_id: calc.page_id,
+                                            index_in_page: calc.index_in_page,
+                                        }],
+                                        &detail_fetch,
                                     )
                                     .await
-                                {
-                                    Ok(resp) => match resp.text().await {
-                                        Ok(body) => {
-                                            let extracted = {
-                                                let doc = Html::parse_document(&body);
-                                                extractor.extract_product_detail(&doc, url.clone())
-                                            };
-                                            match extracted {
-                                                Ok(mut detail) => {
-                                                    detail.page_id = Some(calc.page_id);
-                                                    detail.index_in_page = Some(calc.index_in_page);
-                                                    if detail.id.is_none() {
-                                                        detail.id = Some(format!(
-                                                            "p{:04}i{:02}",
-                                                            calc.page_id, calc.index_in_page
-                                                        ));
-                                                    }
-                                                    let program_type =
-                                                        Some(detail.program_type.unwrap_or_else(
-                                                            || "Matter".to_string(),
-                                                        ));
-                                                    // Clone fields we need later for products backfill to avoid move
-                                                    let man_clone = detail.manufacturer.clone();
-                                                    let model_clone = detail.model.clone();
-                                                    let cert_clone = detail.certificate_id.clone();
-                                                    if let Err(e) = sqlx::query(
-                                                        r#"INSERT INTO product_details (
-                                                            url, page_id, index_in_page, id, manufacturer, model, device_type,
-                                                            certificate_id, certification_date, software_version, hardware_version, firmware_version,
-                                                            specification_version, vid, pid, family_sku, family_variant_sku, family_id,
-                                                            tis_trp_tested, transport_interface, primary_device_type_id, application_categories,
-                                                            description, compliance_document_url, program_type
-                                                        ) VALUES (
-                                                            ?, ?, ?, ?, ?, ?, ?,
-                                                            ?, ?, ?, ?, ?,
-                                                            ?, ?, ?, ?, ?, ?,
-                                                            ?, ?, ?, ?,
-                                                            ?, ?, ?
-                                                        ) ON CONFLICT(url) DO UPDATE SET
-                                                            page_id=COALESCE(excluded.page_id, product_details.page_id),
-                                                            index_in_page=COALESCE(excluded.index_in_page, product_details.index_in_page),
-                                                            id=COALESCE(excluded.id, product_details.id),
-                                                            manufacturer=COALESCE(excluded.manufacturer, product_details.manufacturer),
-                                                            model=COALESCE(excluded.model, product_details.model),
-                                                            device_type=COALESCE(excluded.device_type, product_details.device_type),
-                                                            certificate_id=COALESCE(excluded.certificate_id, product_details.certificate_id),
-                                                            certification_date=COALESCE(excluded.certification_date, product_details.certification_date),
-                                                            software_version=COALESCE(excluded.software_version, product_details.software_version),
-                                                            hardware_version=COALESCE(excluded.hardware_version, product_details.hardware_version),
-                                                            firmware_version=COALESCE(excluded.firmware_version, product_details.firmware_version),
-                                                            specification_version=COALESCE(excluded.specification_version, product_details.specification_version),
-                                                            vid=COALESCE(excluded.vid, product_details.vid),
-                                                            pid=COALESCE(excluded.pid, product_details.pid),
-                                                            family_sku=COALESCE(excluded.family_sku, product_details.family_sku),
-                                                            family_variant_sku=COALESCE(excluded.family_variant_sku, product_details.family_variant_sku),
-                                                            family_id=COALESCE(excluded.family_id, product_details.family_id),
-                                                            tis_trp_tested=COALESCE(excluded.tis_trp_tested, product_details.tis_trp_tested),
-                                                            transport_interface=COALESCE(excluded.transport_interface, product_details.transport_interface),
-                                                            primary_device_type_id=COALESCE(excluded.primary_device_type_id, product_details.primary_device_type_id),
-                                                            application_categories=COALESCE(excluded.application_categories, product_details.application_categories),
-                                                            description=COALESCE(excluded.description, product_details.description),
-                                                            compliance_document_url=COALESCE(excluded.compliance_document_url, product_details.compliance_document_url),
-                                                            program_type=COALESCE(excluded.program_type, product_details.program_type),
-                                                            updated_at=CURRENT_TIMESTAMP
-                                                        "#,
-                                                    )
-                                                    .bind(&detail.url)
-                                                    .bind(detail.page_id)
-                                                    .bind(detail.index_in_page)
-                                                    .bind(detail.id)
-                                                    .bind(detail.manufacturer)
-                                                    .bind(detail.model)
-                                                    .bind(detail.device_type)
-                                                    .bind(detail.certificate_id)
-                                                    .bind(detail.certification_date)
-                                                    .bind(detail.software_version)
-                                                    .bind(detail.hardware_version)
-                                                    .bind(detail.firmware_version)
-                                                    .bind(detail.specification_version)
-                                                    .bind(detail.vid)
-                                                    .bind(detail.pid)
-                                                    .bind(detail.family_sku)
-                                                    .bind(detail.family_variant_sku)
-                                                    .bind(detail.family_id)
-                                                    .bind(detail.tis_trp_tested)
-                                                    .bind(detail.transport_interface)
-                                                    .bind(detail.primary_device_type_id)
-                                                    .bind(detail.application_categories)
-                                                    .bind(detail.description)
-                                                    .bind(detail.compliance_document_url)
-                                                    .bind(program_type)
-                                                    .execute(&mut *tx)
-                                                    .await
-                                                    {
-                                                        emit_actor_event(
-                                                            &app,
-                                                            AppEvent::SyncWarning {
-                                                                session_id: session_id.clone(),
-                                                                code: "details_insert_failed".into(),
-                                                                detail: format!("{}: {}", url, e),
-                                                                timestamp: Utc::now(),
-                                                            },
-                                                        );
-                                                        info!(target: "kpi.sync", "{}",
-                                                            format!(
-                                                                r#"{{"event":"details_upsert","action":"insert_failed","page":{},"page_id":{},"index":{},"url":"{}","attempt":{},"max":{},"error":"{}"}}"#,
-                                                                physical_page, calc.page_id, calc.index_in_page, url, attempt, max_detail_retries, e
-                                                            )
-                                                        );
-                                                    } else if let Ok(res) = sqlx::query(
-                                                        r#"SELECT changes() as affected"#,
-                                                    )
-                                                    .fetch_one(&mut *tx)
-                                                    .await
-                                                    {
-                                                        let affected: i64 = res.get::<i64, _>("affected");
-                                                        emit_actor_event(
-                                                            &app,
-                                                            AppEvent::ProductLifecycle {
-                                                                session_id: session_id.clone(),
-                                                                batch_id: None,
-                                                                page_number: Some(physical_page),
-                                                                product_ref: url.clone(),
-                                                                status: if affected > 0 { "details_persisted".into() } else { "details_skipped_exists".into() },
-                                                                retry: Some(attempt - 1),
-                                                                duration_ms: None,
-                                                                metrics: None,
-                                                                timestamp: Utc::now(),
-                                                            },
-                                                        );
-                                                        info!(target: "kpi.sync", "{}",
-                                                            format!(
-                                                                r#"{{"event":"details_upsert","action":"{}","page":{},"page_id":{},"index":{},"url":"{}","attempt":{},"max":{}}}"#,
-                                                                if affected > 0 { "inserted" } else { "skipped_exists" },
-                                                                physical_page, calc.page_id, calc.index_in_page, url, attempt, max_detail_retries
-                                                            )
-                                                        );
-                                                        // 성공적으로 상세를 확보했으므로 products의 코어 필드도 채움(누락만 채움)
-                                                        let _ = sqlx::query(
-                                                            r#"UPDATE products SET
-                                                                manufacturer = COALESCE(?, manufacturer),
-                                                                model = COALESCE(?, model),
-                                                                certificate_id = COALESCE(?, certificate_id),
-                                                                updated_at = CURRENT_TIMESTAMP
-                                                            WHERE url = ?"#,
-                                                        )
-                                                        .bind(&man_clone)
-                                                        .bind(&model_clone)
-                                                        .bind(&cert_clone)
-                                                        .bind(&detail.url)
-                                                        .execute(&mut *tx)
-                                                        .await;
-                                                        success = true;
-                                                        break;
-                                                    }
-                                                }
-                                                Err(e) => {
-                                                    emit_actor_event(
-                                                        &app,
-                                                        AppEvent::SyncWarning {
-                                                            session_id: session_id.clone(),
-                                                            code: "details_extract_failed".into(),
-                                                            detail: format!("{}: {}", url, e),
-                                                            timestamp: Utc::now(),
-                                                        },
-                                                    );
-                                                    info!(target: "kpi.sync", "{}",
-                                                        format!(
-                                                            r#"{{"event":"details_upsert","action":"extract_failed","page":{},"page_id":{},"index":{},"url":"{}","attempt":{},"max":{},"error":"{}"}}"#,
-                                                            physical_page, calc.page_id, calc.index_in_page, url, attempt, max_detail_retries, e
-                                                        )
-                                                    );
-                                                }
-                                            }
-                                        }
-                                        Err(e) => {
-                                            emit_actor_event(
-                                                &app,
-                                                AppEvent::SyncWarning {
-                                                    session_id: session_id.clone(),
-                                                    code: "details_read_failed".into(),
-                                                    detail: format!("{}: {}", url, e),
-                                                    timestamp: Utc::now(),
-                                                },
-                                            );
-                                            info!(target: "kpi.sync", "{}",
-                                                format!(
-                                                    r#"{{"event":"details_upsert","action":"read_failed","page":{},"page_id":{},"index":{},"url":"{}","attempt":{},"max":{},"error":"{}"}}"#,
-                                                    physical_page, calc.page_id, calc.index_in_page, url, attempt, max_detail_retries, e
-                                                )
-                                            );
-                                        }
-                                    },
-                                    Err(e) => {
-                                        emit_actor_event(
-                                            &app,
-                                            AppEvent::SyncWarning {
-                                                session_id: session_id.clone(),
-                                                code: "details_fetch_failed".into(),
-                                                detail: format!("{}: {}", url, e),
-                                                timestamp: Utc::now(),
-                                            },
-                                        );
-                                        info!(target: "kpi.sync", "{}",
-                                            format!(
-                                                r#"{{"event":"details_upsert","action":"fetch_failed","page":{},"page_id":{},"index":{},"url":"{}","attempt":{},"max":{},"error":"{}"}}"#,
-                                                physical_page, calc.page_id, calc.index_in_page, url, attempt, max_detail_retries, e
-                                            )
-                                        );
-                                    }
-                                }
-                                if attempt < max_detail_retries && !success {
-                                    // Emit detail retrying
-                                    emit_actor_event(
-                                        &app,
-                                        AppEvent::SyncRetrying {
-                                            session_id: session_id.clone(),
-                                            scope: "product_detail".into(),
-                                            physical_page: Some(physical_page),
-                                            url: Some(url.clone()),
-                                            attempt,
-                                            max_attempts: max_detail_retries,
-                                            reason: None,
-                                            timestamp: Utc::now(),
-                                        },
-                                    );
-                                    let shift = attempt - 1;
-                                    let backoff_ms = 200u64 * (1u64 << shift);
-                                    info!(target: "kpi.sync", "{}",
-                                        format!(
-                                            r#"{{"event":"details_retry_attempt","page":{},"page_id":{},"index":{},"url":"{}","next_delay_ms":{},"attempt":{},"max":{}}}"#,
-                                            physical_page, calc.page_id, calc.index_in_page, url, backoff_ms, attempt, max_detail_retries
-                                        )
-                                    );
-                                    tokio::time::sleep(std::time::Duration::from_millis(
-                                        backoff_ms + (physical_page as u64 % 23),
-                                    ))
-                                    .await;
-                                }
+                                    .pop(),
+                            };
+                            if let Some(DetailFetchOutcome {
+                                attempts: attempt,
+                                result: Ok(detail),
+                                ..
+                            }) = outcome
+                            {
+                                // Written through the persistence queue once the page commits
+                                info!(target: "kpi.sync", "{}",
+                                    format!(
+                                        r#"{{"event":"details_upsert","action":"queued","page":{},"page_id":{},"index":{},"url":"{}","attempt":{},"max":{}}}"#,
+                                        physical_page, calc.page_id, calc.index_in_page, url, attempt, max_detail_retries
+                                    )
+                                );
+                                page_details.push((detail, attempt));
+                                success = true;
                             }
                             if !success {
                                 info!(target: "kpi.sync", "{}",
@@ -2034,7 +2909,7 @@ pub async fn start_partial_sync(
                 }
                 if (page_inserted + page_updated + page_skipped + page_failed) % 10 == 0 {
                     emit_actor_event(
-                        &app,
+                        &sink,
                         AppEvent::SyncUpsertProgress {
                             session_id: session_id.clone(),
                             physical_page,
@@ -2080,10 +2955,10 @@ pub async fn start_partial_sync(
                 }
                 Err(e) => {
                     emit_actor_event(
-                        &app,
+                        &sink,
                         AppEvent::SyncWarning {
                             session_id: session_id.clone(),
-                            code: "db_only_placeholder_failed".into(),
+                            code: WarningCode::DbOnlyPlaceholderFailed.into(),
                             detail: format!("page {} (pid {}): {}", physical_page, canonical_pid, e),
                             timestamp: Utc::now(),
                         },
@@ -2114,10 +2989,10 @@ pub async fn start_partial_sync(
                 }
                 Err(e) => {
                     emit_actor_event(
-                        &app,
+                        &sink,
                         AppEvent::SyncWarning {
                             session_id: session_id.clone(),
-                            code: "db_only_backfill_failed".into(),
+                            code: WarningCode::DbOnlyBackfillFailed.into(),
                             detail: format!("page {} (pid {}): {}", physical_page, canonical_pid, e),
                             timestamp: Utc::now(),
                         },
@@ -2150,10 +3025,10 @@ pub async fn start_partial_sync(
                     }
                     Err(e) => {
                         emit_actor_event(
-                            &app,
+                            &sink,
                             AppEvent::SyncWarning {
                                 session_id: session_id.clone(),
-                                code: "db_only_products_id_backfill_failed".into(),
+                                code: WarningCode::DbOnlyProductsIdBackfillFailed.into(),
                                 detail: format!("page {} (pid {}): {}", physical_page, canonical_pid, e),
                                 timestamp: Utc::now(),
                             },
@@ -2162,18 +3037,30 @@ pub async fn start_partial_sync(
                 }
             }
 
-            if let Err(e) = tx.commit().await {
+            let committed = tx.commit().await;
+            read_snapshot::mark_page_boundary();
+            if let Err(e) = committed {
                 page_failed += 1;
                 failed_c.fetch_add(1, Ordering::SeqCst);
                 emit_actor_event(
-                    &app,
+                    &sink,
                     AppEvent::SyncWarning {
                         session_id: session_id.clone(),
-                        code: "tx_commit_failed".into(),
+                        code: WarningCode::TxCommitFailed.into(),
                         detail: format!("page {}: {}", physical_page, e),
                         timestamp: Utc::now(),
                     },
                 );
+                queue_revalidation(&pool, &session_id, physical_page, canonical_pid, &e).await;
+            } else {
+                let queue = persistence_queue::shared_queue(&pool);
+                export_urls.extend(
+                    persist_page_details(&sink, &queue, &session_id, physical_page, page_details)
+                        .await,
+                );
+                if !export_urls.is_empty() {
+                    session_export::record_session_urls(&pool, &session_id, &export_urls).await;
+                }
             }
 
             // Emit a compact metric snapshot for per-page DB-only backfills
@@ -2185,10 +3072,10 @@ pub async fn start_partial_sync(
             );
             // Also emit as SyncWarning for UI consumption
             emit_actor_event(
-                &app,
+                &sink,
                 AppEvent::SyncWarning {
                     session_id: session_id.clone(),
-                    code: "db_only_backfill_metrics".into(),
+                    code: WarningCode::DbOnlyBackfillMetrics.into(),
                     detail: format!(
                         r#"{{"page":{},"pid":{},"placeholders":{},"product_core_backfilled":{},"products_id_backfilled":{}}}"#,
                         physical_page, canonical_pid, aff_placeholder, aff_prod_backfill, aff_id_backfill
@@ -2199,7 +3086,7 @@ pub async fn start_partial_sync(
 
             // In-range retry: attempt details for URLs on this page with NULL certificate_id (bounded within this page)
             // Runs outside the main per-page transaction
-            if !is_dry_run {
+            if !is_dry_run && !cancel.is_cancelled() {
                 let to_retry: Vec<(String, Option<i64>)> = match sqlx::query_as(
                     r#"SELECT url, index_in_page FROM products WHERE page_id = ? AND certificate_id IS NULL ORDER BY index_in_page ASC"#,
                 )
@@ -2210,10 +3097,10 @@ pub async fn start_partial_sync(
                     Ok(rows) => rows,
                     Err(e) => {
                         emit_actor_event(
-                            &app,
+                            &sink,
                             AppEvent::SyncWarning {
                                 session_id: session_id.clone(),
-                                code: "in_range_retry_query_failed".into(),
+                                code: WarningCode::InRangeRetryQueryFailed.into(),
                                 detail: format!("page {} (pid {}): {}", physical_page, canonical_pid, e),
                                 timestamp: Utc::now(),
                             },
@@ -2235,11 +3122,7 @@ pub async fn start_partial_sync(
                     let max_detail_retries = max_detail_retries_cfg;
                     let mut success = false;
                     for attempt in 1..=max_detail_retries {
-                        let referer_url = if physical_page == 1 {
-                            csa_iot::PRODUCTS_PAGE_MATTER_ONLY.to_string()
-                        } else {
-                            csa_iot::PRODUCTS_PAGE_MATTER_PAGINATED.replace("{}", &physical_page.to_string())
-                        };
+                        let referer_url = list_page_url(&extractor, physical_page);
                         match http
                             .fetch_response_with_options(
                                 &url,
@@ -2389,8 +3272,11 @@ pub async fn start_partial_sync(
                             Err(_) => { /* fetch failed; will retry */ }
                         }
                         if attempt < max_detail_retries && !success {
+                            if !retry_allowed(&retry_budget, &session_id, "product_detail") {
+                                break;
+                            }
                             emit_actor_event(
-                                &app,
+                                &sink,
                                 AppEvent::SyncRetrying {
                                     session_id: session_id.clone(),
                                     scope: "product_detail".into(),
@@ -2402,12 +3288,7 @@ pub async fn start_partial_sync(
                                     timestamp: Utc::now(),
                                 },
                             );
-                            let shift = attempt - 1;
-                            let backoff_ms = 200u64 * (1u64 << shift);
-                            tokio::time::sleep(std::time::Duration::from_millis(
-                                backoff_ms + (physical_page as u64 % 17),
-                            ))
-                            .await;
+                            tokio::time::sleep(detail_policy.delay(attempt)).await;
                         }
                     }
                     if !success {
@@ -2424,10 +3305,18 @@ pub async fn start_partial_sync(
                 );
             }
 
-            let ms = page_start.elapsed().as_millis() as u64;
+            let ms = page_timer.finish().as_millis() as u64;
             pages_processed_c.fetch_add(1, Ordering::SeqCst);
+            if !is_dry_run {
+                if let Err(e) =
+                    sync_resume::mark_done(&pool, &session_id, total_pages, physical_page).await
+                {
+                    error!("Failed to mark sync page done: {}", e);
+                }
+            }
+            let estimated_finish_at = sync_eta::page_completed(&eta, ms);
             emit_actor_event(
-                &app,
+                &sink,
                 AppEvent::SyncPageCompleted {
                     session_id: session_id.clone(),
                     physical_page,
@@ -2436,6 +3325,7 @@ pub async fn start_partial_sync(
                     skipped: page_skipped,
                     failed: page_failed,
                     ms,
+                    estimated_finish_at,
                     timestamp: Utc::now(),
                 },
             );
@@ -2447,9 +3337,53 @@ pub async fn start_partial_sync(
         handles.push(handle);
     }
 
-    // Await all page tasks
-    for h in handles {
-        let _ = h.await;
+    // Exhausted details and, unless the session is stopping, pages failing after every pass
+    if !dry_run.unwrap_or(false) {
+        let mut letters =
+            std::mem::take(&mut *dead_letters.lock().unwrap_or_else(|e| e.into_inner()));
+        let stopping = cancel_guard.is_cancelled() || cancel_guard.is_paused();
+        if let (Some(retry), false) = (&page_retry, stopping) {
+            let attempts =
+                std::mem::take(&mut *page_attempts.lock().unwrap_or_else(|e| e.into_inner()));
+            letters.extend(dead_letter_pages(
+                &session_id,
+                &calculator_global,
+                &retry.still_failed,
+                attempts,
+            ));
+        }
+        record_dead_letters(&pool, &session_id, letters).await;
+    }
+
+    if cancel_guard.is_cancelled() {
+        return Err(abort_sync_session(
+            &sink,
+            &pool,
+            &session_id,
+            started,
+            [&pages_processed, &inserted, &updated, &skipped, &failed],
+        )
+        .await);
+    }
+    if cancel_guard.is_paused() {
+        // A pause that arrived after the last page started leaves nothing to resume
+        let remaining = sync_resume::remaining_count(&pool, &session_id)
+            .await
+            .unwrap_or_else(|e| {
+                error!("Failed to count remaining sync pages: {}", e);
+                0
+            });
+        if remaining > 0 {
+            return Err(park_paused_sync_session(
+                &sink,
+                &pool,
+                &session_id,
+                started,
+                remaining,
+                [&pages_processed, &inserted, &updated, &skipped, &failed],
+            )
+            .await);
+        }
     }
     // Global safety sweep: backfill products.id across the DB (NULL/empty), regardless of page coverage
     if products_has_id_column {
@@ -2472,10 +3406,10 @@ pub async fn start_partial_sync(
                 );
                 // Emit a lightweight event for FE visibility
                 emit_actor_event(
-                    &app,
+                    &sink,
                     AppEvent::SyncWarning {
                         session_id: session_id.clone(),
-                        code: "global_products_id_backfill_sweep".into(),
+                        code: WarningCode::GlobalProductsIdBackfillSweep.into(),
                         detail: format!("affected_rows={}", affected),
                         timestamp: Utc::now(),
                     },
@@ -2483,10 +3417,10 @@ pub async fn start_partial_sync(
             }
             Err(e) => {
                 emit_actor_event(
-                    &app,
+                    &sink,
                     AppEvent::SyncWarning {
                         session_id: session_id.clone(),
-                        code: "global_products_id_backfill_failed".into(),
+                        code: WarningCode::GlobalProductsIdBackfillFailed.into(),
                         detail: format!("{}", e),
                         timestamp: Utc::now(),
                     },
@@ -2506,7 +3440,9 @@ pub async fn start_partial_sync(
     // Phase-2: bounded sweep for pages covered in this session
     // Only if not a dry_run and some pages were processed
     let mut deleted_total: u32 = 0;
+    let mut storage_before = None;
     if !dry_run.unwrap_or(false) && pages_processed > 0 {
+        storage_before = db_maintenance::storage_snapshot(&pool).await.ok();
         // Merge and normalize ranges again for safety
         let mut sweep_ranges: Vec<(u32, u32)> = Vec::new();
         if let Ok(parsed) = parse_ranges(&match sqlx::query_scalar::<_, String>(
@@ -2555,7 +3491,6 @@ pub async fn start_partial_sync(
             .bind(high)
             .bind(&session_id)
             .bind(&session_id)
-            .bind(&session_id)
             .execute(&pool)
             .await
             {
@@ -2571,10 +3506,10 @@ pub async fn start_partial_sync(
                 }
                 Err(err) => {
                     emit_actor_event(
-                        &app,
+                        &sink,
                         AppEvent::SyncWarning {
                             session_id: session_id.clone(),
-                            code: "sweep_failed".into(),
+                            code: WarningCode::SweepFailed.into(),
                             detail: format!(
                                 "range {}-{} (pid {}-{}): {}",
                                 phys_start, phys_end, low, high, err
@@ -2597,6 +3532,9 @@ pub async fn start_partial_sync(
     {
         error!("Failed to mark sync session completed: {}", e);
     }
+    if let Err(e) = sync_resume::clear(&pool, &session_id).await {
+        error!("Failed to clear remaining sync pages: {}", e);
+    }
 
     // Build anomaly summary for observability (page_id groups with cnt != 12)
     let mut anomalies: Vec<SyncAnomalyEntry> = Vec::new();
@@ -2618,8 +3556,61 @@ pub async fn start_partial_sync(
             }
         }
     }
+    // Close the revalidation pass: pages that committed this time leave the queue
+    if revalidation_pass {
+        match page_revalidation::finish_pass(&pool).await {
+            Ok(outcome) if outcome != page_revalidation::RevalidationOutcome::default() => {
+                emit_actor_event(
+                    &sink,
+                    AppEvent::SyncWarning {
+                        session_id: session_id.clone(),
+                        code: WarningCode::PagesRevalidated.into(),
+                        detail: format!(
+                            "repaired={} pending={} exhausted={}",
+                            outcome.repaired, outcome.pending, outcome.exhausted
+                        ),
+                        timestamp: Utc::now(),
+                    },
+                );
+            }
+            Ok(_) => {}
+            Err(e) => error!("Failed to close revalidation pass: {}", e),
+        }
+    }
+    // Bulk sync SQL bypasses the search-index delta hooks
+    crate::services::search_index::reconcile_after_bulk_write(&pool).await;
+    if let Err(e) = slot_reservation::release_session(&pool, &session_id).await {
+        error!("Failed to release slot reservations: {}", e);
+    }
+    session_spill::shared_spill_store()
+        .end_session(&session_id)
+        .await;
+    prune_page_url_sets(&pool, &app_config.advanced.page_url_sets).await;
+    // One reclaim for the whole sweep rather than per range
+    if let Some(before) = storage_before.filter(|_| deleted_total > 0) {
+        let vacuum = db_maintenance::reclaim_after_deletion(
+            &pool,
+            &app_config.advanced.db_maintenance,
+            "sync_sweep",
+            u64::from(deleted_total),
+            &before,
+        )
+        .await;
+        debug!("Sync sweep reclaim: {:?}", vacuum.action);
+    }
+    let changeset = session_changeset(&pool, history_since, Some(session_id.as_str())).await;
+    if let Some(changeset) = &changeset {
+        if let Err(e) = sqlx::query("UPDATE sync_sessions SET changeset_json = ? WHERE session_id = ?")
+            .bind(serde_json::to_string(changeset).unwrap_or_default())
+            .bind(&session_id)
+            .execute(&pool)
+            .await
+        {
+            error!("Failed to store sync changeset: {}", e);
+        }
+    }
     emit_actor_event(
-        &app,
+        &sink,
         AppEvent::SyncCompleted {
             session_id: session_id.clone(),
             pages_processed,
@@ -2640,6 +3631,8 @@ pub async fn start_partial_sync(
             } else {
                 Some(anomalies)
             },
+            changeset,
+            page_retry,
             timestamp: Utc::now(),
         },
     );
@@ -2647,6 +3640,10 @@ pub async fn start_partial_sync(
         "Sync completed: session_id={} pages={} ins={} upd={} skip={} fail={} duration_ms={}",
         session_id, pages_processed, inserted, updated, skipped, failed, duration_ms
     );
+    if let Err(e) = sync_eta::remember_session(&pool, &session_id).await {
+        error!("Failed to record sync page timing: {}", e);
+    }
+    log_sync_latency(&session_id);
     Ok(SyncSummary {
         pages_processed,
         inserted,
@@ -2666,9 +3663,10 @@ pub async fn start_sync_pages(
     app_state: State<'_, AppState>,
     mut pages: Vec<u32>,
     dry_run: Option<bool>,
-) -> Result<SyncSummary, String> {
+    export: Option<SessionExportRequest>,
+) -> Result<SyncSummary, CommandError> {
     if pages.is_empty() {
-        return Err("No pages provided".into());
+        return Err(CommandError::invalid_request("No pages provided"));
     }
     // Deduplicate and sort descending (newest first, consistent with ranges parse ordering)
     pages.sort_unstable();
@@ -2680,7 +3678,7 @@ pub async fn start_sync_pages(
         .map(|p| p.to_string())
         .collect::<Vec<_>>()
         .join(",");
-    start_partial_sync(app, app_state, expr, dry_run).await
+    start_partial_sync(app, app_state, expr, dry_run, export).await
 }
 
 /// Run a diagnostic-driven sync for specific pages and slot indices.
@@ -2692,9 +3690,25 @@ pub async fn start_diagnostic_sync(
     pages: Vec<DiagnosticPageInput>,
     snapshot: Option<DiagnosticSnapshotInput>,
     dry_run: Option<bool>,
+) -> Result<SyncSummary, CommandError> {
+    let args = (pages.clone(), snapshot.clone(), dry_run);
+    CommandDedup::shared()
+        .run("start_diagnostic_sync", &args, || {
+            diagnostic_sync(app, app_state, pages, snapshot, dry_run)
+        })
+        .await
+        .map_err(CommandError::from)
+}
+
+async fn diagnostic_sync(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+    pages: Vec<DiagnosticPageInput>,
+    snapshot: Option<DiagnosticSnapshotInput>,
+    dry_run: Option<bool>,
 ) -> Result<SyncSummary, String> {
     if pages.is_empty() {
-        return Err("No diagnostic pages provided".into());
+        return Err(ErrorCode::InvalidRequest.tag("No diagnostic pages provided"));
     }
 
     // Build page -> indices map and a sorted page list (desc)
@@ -2717,11 +3731,18 @@ pub async fn start_diagnostic_sync(
     let app_config = app_state.config.read().await.clone();
     let http = app_state.get_http_client().await?;
     let sync_ua = app_config.user.crawling.workers.user_agent_sync.clone();
-    let extractor = MatterDataExtractor::new().map_err(|e| e.to_string())?;
+    let extractor = MatterDataExtractor::with_profile(resolve_site_profile(&app_config))
+        .map_err(|e| e.to_string())?;
     let pool = app_state
         .get_database_pool()
         .await
         .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    // product_history watermark: the SyncCompleted changeset covers rows recorded after it
+    let history_since = history_watermark(&pool).await;
+    // Per-session slot reservations guard (page_id, index_in_page) against concurrent workers
+    if let Err(e) = slot_reservation::prune_stale(&pool).await {
+        error!("Failed to prune stale slot reservations: {}", e);
+    }
 
     // Discover or use snapshot for site meta
     let (total_pages, items_on_last_page, newest_html, oldest_html, oldest_page) =
@@ -2738,13 +3759,13 @@ pub async fn start_diagnostic_sync(
                 oldest_page,
             )
         } else {
-            let newest_url = csa_iot::PRODUCTS_PAGE_MATTER_ONLY.to_string();
+            let newest_url = list_page_url(&extractor, 1);
             let newest_html = match http
                 .fetch_response_with_options(
                     &newest_url,
                     &RequestOptions {
                         user_agent_override: sync_ua.clone(),
-                        referer: Some(csa_iot::PRODUCTS_BASE.to_string()),
+                        referer: Some(listing_referer(&extractor)),
                         skip_robots_check: false,
                         attempt: None,
                         max_attempts: None,
@@ -2764,13 +3785,13 @@ pub async fn start_diagnostic_sync(
                 newest_html.clone()
             } else {
                 let oldest_url =
-                    csa_iot::PRODUCTS_PAGE_MATTER_PAGINATED.replace("{}", &oldest_page.to_string());
+                    list_page_url(&extractor, oldest_page);
                 match http
                     .fetch_response_with_options(
                         &oldest_url,
                         &RequestOptions {
                             user_agent_override: sync_ua.clone(),
-                            referer: Some(csa_iot::PRODUCTS_BASE.to_string()),
+                            referer: Some(listing_referer(&extractor)),
                             skip_robots_check: false,
                             attempt: None,
                             max_attempts: None,
@@ -2798,22 +3819,34 @@ pub async fn start_diagnostic_sync(
 
     // Emit start event
     let session_id = format!("sync-{}", Utc::now().format("%Y%m%d%H%M%S"));
+    let cancel_guard = SyncCancellationRegistry::shared().register(&session_id);
+    let cancel = cancel_guard.token();
+    let started = std::time::Instant::now();
+    let max_concurrent = app_config
+        .user
+        .crawling
+        .workers
+        .list_page_max_concurrent
+        .max(1);
+    let rate_limit = app_config.user.crawling.workers.max_requests_per_second;
+    let eta = sync_eta::SyncEta::new(
+        pages_vec.len() as u32,
+        max_concurrent,
+        Some(rate_limit),
+        sync_eta::prior_page_ms(&pool).await,
+    );
     emit_actor_event(
         &app,
         AppEvent::SyncStarted {
             session_id: session_id.clone(),
             ranges: pages_vec.iter().map(|p| (*p, *p)).collect(),
-            rate_limit: Some(app_config.user.crawling.workers.max_requests_per_second),
+            rate_limit: Some(rate_limit),
+            estimated_finish_at: Some(eta.estimated_finish_at()),
             timestamp: Utc::now(),
         },
     );
+    let eta = eta.shared();
 
-    let max_concurrent = app_config
-        .user
-        .crawling
-        .workers
-        .list_page_max_concurrent
-        .max(1);
     let semaphore = Arc::new(Semaphore::new(max_concurrent));
     let pages_processed = Arc::new(AtomicU32::new(0));
     let inserted = Arc::new(AtomicU32::new(0));
@@ -2827,6 +3860,14 @@ pub async fn start_diagnostic_sync(
     let extractor_global = extractor.clone();
     let calculator_global = calculator.clone();
     let dry = dry_run.unwrap_or(false);
+    let retry_policies = &app_config.advanced.retry_policies;
+    let list_retry_count: u32 = retry_policies
+        .list()
+        .max_retries_or(app_config.user.crawling.product_list_retry_count.max(1));
+    let detail_retry_count: u32 = retry_policies
+        .detail()
+        .max_retries_or(app_config.user.crawling.product_detail_retry_count.max(1));
+    let retry_budget = retry_policies.session_budget(&session_id);
 
     let mut handles = Vec::with_capacity(pages_vec.len());
     for physical_page in pages_vec {
@@ -2834,6 +3875,10 @@ pub async fn start_diagnostic_sync(
         if selected.is_empty() {
             continue;
         }
+        let list_policy = retry_policies.list().clone();
+        let detail_policy = retry_policies.detail().clone();
+        let retry_budget = retry_budget.clone();
+        let cancel = cancel.clone();
         let permit = semaphore.clone().acquire_owned();
         let app = app_handle.clone();
         let session_id = session_id.clone();
@@ -2849,8 +3894,10 @@ pub async fn start_diagnostic_sync(
         let updated_c = updated.clone();
         let skipped_c = skipped.clone();
         let failed_c = failed.clone();
+        let eta = eta.clone();
 
-        let handle = tokio::spawn(async move {
+        let task_name = format!("sync-page:{}:{}", session_id, physical_page);
+        let handle = spawn_tracked(task_name, Some(PAGE_WORKER_LIFETIME), async move {
             let _permit = match permit.await {
                 Ok(p) => p,
                 Err(e) => {
@@ -2858,6 +3905,10 @@ pub async fn start_diagnostic_sync(
                     return;
                 }
             };
+            let page_started = std::time::Instant::now();
+            if cancel.is_cancelled() {
+                return;
+            }
 
             emit_actor_event(
                 &app,
@@ -2871,7 +3922,7 @@ pub async fn start_diagnostic_sync(
             // Fetch page HTML (same retry logic as partial sync but simplified)
             // expected item count for observability (not used for gating here)
             // let expected_count = if physical_page == oldest_page { items_on_last_page as u32 } else { 12u32 };
-            let max_retries = app_config.user.crawling.product_list_retry_count.max(1);
+            let max_retries = list_retry_count;
             let mut attempt = 0u32;
             let mut product_urls: Vec<String> = Vec::new();
             loop {
@@ -2884,14 +3935,13 @@ pub async fn start_diagnostic_sync(
                         newest_html_clone.clone()
                     }
                 } else {
-                    let url = csa_iot::PRODUCTS_PAGE_MATTER_PAGINATED
-                        .replace("{}", &physical_page.to_string());
+                    let url = list_page_url(&extractor, physical_page);
                     match http
                         .fetch_response_with_options(
                             &url,
                             &RequestOptions {
                                 user_agent_override: sync_ua.clone(),
-                                referer: Some(csa_iot::PRODUCTS_BASE.to_string()),
+                                referer: Some(listing_referer(&extractor)),
                                 skip_robots_check: false,
                                 attempt: Some(attempt + 1),
                                 max_attempts: Some(max_retries + 1),
@@ -2905,13 +3955,16 @@ pub async fn start_diagnostic_sync(
                 };
                 if !page_html.is_empty() {
                     if let Ok(v) = extractor.extract_product_urls_from_content(&page_html) {
-                        product_urls = v;
+                        product_urls = v.iter().map(|u| canonical_product_url(u)).collect();
                     }
                 }
-                if !product_urls.is_empty() || attempt >= max_retries {
+                if !product_urls.is_empty()
+                    || attempt >= max_retries
+                    || !retry_allowed(&retry_budget, &session_id, "list_page")
+                {
                     break;
                 }
-                tokio::time::sleep(std::time::Duration::from_millis(200 * (1u64 << attempt))).await;
+                tokio::time::sleep(list_policy.delay(attempt + 1)).await;
                 attempt += 1;
             }
 
@@ -2924,7 +3977,7 @@ pub async fn start_diagnostic_sync(
                         &app,
                         AppEvent::SyncWarning {
                             session_id: session_id.clone(),
-                            code: "tx_begin_failed".into(),
+                            code: WarningCode::TxBeginFailed.into(),
                             detail: format!("page {}: {}", physical_page, e),
                             timestamp: Utc::now(),
                         },
@@ -2938,6 +3991,10 @@ pub async fn start_diagnostic_sync(
             let mut page_skipped = 0u32;
             let mut page_failed = 0u32;
             for i in 0..product_urls.len() {
+                if cancel.is_cancelled() {
+                    roll_back_cancelled_page(&app, tx, &session_id, physical_page).await;
+                    return;
+                }
                 if !selected.contains(&i) {
                     continue;
                 }
@@ -2983,7 +4040,7 @@ pub async fn start_diagnostic_sync(
                             &app,
                             AppEvent::SyncWarning {
                                 session_id: session_id.clone(),
-                                code: "select_failed".into(),
+                                code: WarningCode::SelectFailed.into(),
                                 detail: format!("{}: {}", url, e),
                                 timestamp: Utc::now(),
                             },
@@ -2991,6 +4048,21 @@ pub async fn start_diagnostic_sync(
                         continue;
                     }
                 };
+                // Reserve the slot before touching coordinates; collisions are reported, not overwritten
+                if !claim_page_slot(
+                    &app,
+                    &mut tx,
+                    &session_id,
+                    &url,
+                    calc.page_id,
+                    calc.index_in_page,
+                )
+                .await
+                {
+                    page_failed += 1;
+                    failed_c.fetch_add(1, Ordering::SeqCst);
+                    continue;
+                }
                 match row {
                     None => {
                         let res = sqlx::query(
@@ -3013,7 +4085,7 @@ pub async fn start_diagnostic_sync(
                                     &app,
                                     AppEvent::SyncWarning {
                                         session_id: session_id.clone(),
-                                        code: "insert_failed".into(),
+                                        code: WarningCode::InsertFailed.into(),
                                         detail: format!("{}: {}", url, e),
                                         timestamp: Utc::now(),
                                     },
@@ -3031,7 +4103,7 @@ pub async fn start_diagnostic_sync(
                             _ => true,
                         };
                         if needs_update {
-                            match sqlx::query("UPDATE products SET page_id = ?, index_in_page = ?, updated_at = CURRENT_TIMESTAMP WHERE url = ?").bind(calc.page_id).bind(calc.index_in_page).bind(&url).execute(&mut *tx).await { Ok(_) => { page_updated += 1; updated_c.fetch_add(1, Ordering::SeqCst); }, Err(e) => { page_failed += 1; failed_c.fetch_add(1, Ordering::SeqCst); emit_actor_event(&app, AppEvent::SyncWarning { session_id: session_id.clone(), code: "update_failed".into(), detail: format!("{}: {}", url, e), timestamp: Utc::now() }); } }
+                            match sqlx::query("UPDATE products SET page_id = ?, index_in_page = ?, updated_at = CURRENT_TIMESTAMP WHERE url = ?").bind(calc.page_id).bind(calc.index_in_page).bind(&url).execute(&mut *tx).await { Ok(_) => { page_updated += 1; updated_c.fetch_add(1, Ordering::SeqCst); }, Err(e) => { page_failed += 1; failed_c.fetch_add(1, Ordering::SeqCst); emit_actor_event(&app, AppEvent::SyncWarning { session_id: session_id.clone(), code: WarningCode::UpdateFailed.into(), detail: format!("{}: {}", url, e), timestamp: Utc::now() }); } }
                         } else {
                             page_skipped += 1;
                             skipped_c.fetch_add(1, Ordering::SeqCst);
@@ -3046,18 +4118,12 @@ pub async fn start_diagnostic_sync(
                     details_is_complete = man.is_some() && model.is_some() && dtype.is_some() && cert.is_some();
                 }
                 if !details_is_complete {
-                    let max_detail_retries =
-                        app_config.user.crawling.product_detail_retry_count.max(1);
+                    let max_detail_retries = detail_retry_count;
                     let mut success = false;
                     for attempt in 1..=max_detail_retries {
                         // Fetch detail page
                         // Compute appropriate referer based on physical page
-                        let referer = if physical_page == 1 {
-                            csa_iot::PRODUCTS_PAGE_MATTER_ONLY.to_string()
-                        } else {
-                            csa_iot::PRODUCTS_PAGE_MATTER_PAGINATED
-                                .replace("{}", &physical_page.to_string())
-                        };
+                        let referer = list_page_url(&extractor, physical_page);
                         let fetched = http
                             .fetch_response_with_options(
                                 &url,
@@ -3173,10 +4239,10 @@ pub async fn start_diagnostic_sync(
                             }
                         }
                         if !success && attempt < max_detail_retries {
-                            let shift = attempt - 1;
-                            let base = 1u64.checked_shl(shift).unwrap_or(u64::MAX / 200);
-                            let delay: u64 = 200u64.saturating_mul(base);
-                            tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+                            if !retry_allowed(&retry_budget, &session_id, "product_detail") {
+                                break;
+                            }
+                            tokio::time::sleep(detail_policy.delay(attempt)).await;
                         }
                     }
                     if !success {
@@ -3186,20 +4252,25 @@ pub async fn start_diagnostic_sync(
                 }
             }
 
-            if let Err(e) = tx.commit().await {
+            let committed = tx.commit().await;
+            read_snapshot::mark_page_boundary();
+            if let Err(e) = committed {
                 failed_c.fetch_add(1, Ordering::SeqCst);
                 emit_actor_event(
                     &app,
                     AppEvent::SyncWarning {
                         session_id: session_id.clone(),
-                        code: "tx_commit_failed".into(),
+                        code: WarningCode::TxCommitFailed.into(),
                         detail: format!("page {}: {}", physical_page, e),
                         timestamp: Utc::now(),
                     },
                 );
+                let canonical_pid = calculator.calculate(physical_page, 0).page_id;
+                queue_revalidation(&pool, &session_id, physical_page, canonical_pid, &e).await;
             }
 
             pages_processed_c.fetch_add(1, Ordering::SeqCst);
+            let page_ms = page_started.elapsed().as_millis() as u64;
             emit_actor_event(
                 &app,
                 AppEvent::SyncPageCompleted {
@@ -3210,6 +4281,7 @@ pub async fn start_diagnostic_sync(
                     skipped: page_skipped,
                     failed: page_failed,
                     ms: 0,
+                    estimated_finish_at: sync_eta::page_completed(&eta, page_ms),
                     timestamp: Utc::now(),
                 },
             );
@@ -3220,6 +4292,16 @@ pub async fn start_diagnostic_sync(
     for h in handles {
         let _ = h.await;
     }
+    if cancel_guard.is_cancelled() {
+        return Err(abort_sync_session(
+            &app_handle,
+            &pool,
+            &session_id,
+            started,
+            [&pages_processed, &inserted, &updated, &skipped, &failed],
+        )
+        .await);
+    }
     let summary = SyncSummary {
         pages_processed: pages_processed.load(Ordering::SeqCst),
         inserted: inserted.load(Ordering::SeqCst),
@@ -3228,6 +4310,16 @@ pub async fn start_diagnostic_sync(
         failed: failed.load(Ordering::SeqCst),
         duration_ms: 0,
     };
+    // Bulk sync SQL bypasses the search-index delta hooks
+    crate::services::search_index::reconcile_after_bulk_write(&pool).await;
+    if let Err(e) = slot_reservation::release_session(&pool, &session_id).await {
+        error!("Failed to release slot reservations: {}", e);
+    }
+    session_spill::shared_spill_store()
+        .end_session(&session_id)
+        .await;
+    // Diagnostic sync does not record sync_observed, so its changeset is not URL-scoped
+    let changeset = session_changeset(&pool, history_since, None).await;
     emit_actor_event(
         &app,
         AppEvent::SyncCompleted {
@@ -3242,6 +4334,8 @@ pub async fn start_diagnostic_sync(
             total_pages: Some(total_pages),
             items_on_last_page: Some(items_on_last_page as u32),
             anomalies: None,
+            changeset,
+            page_retry: None,
             timestamp: Utc::now(),
         },
     );
@@ -3256,14 +4350,30 @@ pub async fn retry_failed_details(
     app_state: State<'_, AppState>,
     limit: Option<u32>,
     dry_run: Option<bool>,
-) -> Result<serde_json::Value, String> {
+) -> Result<serde_json::Value, CommandError> {
+    CommandDedup::shared()
+        .run("retry_failed_details", &(limit, dry_run), || async move {
+            retry_details(app_state, limit, dry_run)
+                .await
+                .map_err(String::from)
+        })
+        .await
+        .map_err(CommandError::from)
+}
+
+async fn retry_details(
+    app_state: State<'_, AppState>,
+    limit: Option<u32>,
+    dry_run: Option<bool>,
+) -> Result<serde_json::Value, CommandError> {
     let pool = app_state
         .get_database_pool()
         .await
         .map_err(|e| format!("DB pool unavailable: {e}"))?;
     let http = app_state.get_http_client().await?;
-    let extractor = MatterDataExtractor::new().map_err(|e| e.to_string())?;
     let app_config = app_state.config.read().await.clone();
+    let extractor = MatterDataExtractor::with_profile(resolve_site_profile(&app_config))
+        .map_err(|e| e.to_string())?;
     let sync_ua = app_config.user.crawling.workers.user_agent_sync.clone();
 
     let lim = limit.unwrap_or(200).max(1) as i64;
@@ -3281,6 +4391,7 @@ pub async fn retry_failed_details(
     let succeeded = Arc::new(AtomicU32::new(0));
     let failed = Arc::new(AtomicU32::new(0));
     let dry = dry_run.unwrap_or(false);
+    let queue = crate::infrastructure::persistence_queue::shared_queue(&pool);
 
     let mut handles = Vec::with_capacity(urls.len());
     for (url, page_id_opt, index_opt) in urls.into_iter() {
@@ -3288,16 +4399,17 @@ pub async fn retry_failed_details(
         let http_c = http.clone();
         let extractor_c = extractor.clone();
         let sync_ua_c = sync_ua.clone();
-        let pool_c = pool.clone();
+        let queue_c = queue.clone();
         let attempted_c = attempted.clone();
         let succeeded_c = succeeded.clone();
         let failed_c = failed.clone();
-        let handle = tokio::spawn(async move {
+        let task_name = format!("detail-retry:{}", url);
+        let handle = spawn_tracked(task_name, Some(PAGE_WORKER_LIFETIME), async move {
             let _p = match permit.await { Ok(p) => p, Err(_) => return };
             attempted_c.fetch_add(1, Ordering::SeqCst);
             if dry { return; }
             // Basic referer: CSA base page (sufficient for detail fetch)
-            let referer = csa_iot::PRODUCTS_BASE.to_string();
+            let referer = listing_referer(&extractor_c);
             match http_c
                 .fetch_response_with_options(
                     &url,
@@ -3327,103 +4439,8 @@ pub async fn retry_failed_details(
                                     detail.id = Some(format!("p{:04}i{:02}", pid, ix));
                                 }
                             }
-                            // Clone fields for later backfill
-                            let man_clone = detail.manufacturer.clone();
-                            let model_clone = detail.model.clone();
-                            let cert_clone = detail.certificate_id.clone();
-                            let program_type = Some(
-                                detail
-                                    .program_type
-                                    .clone()
-                                    .unwrap_or_else(|| "Matter".to_string()),
-                            );
-                            let mut tx = match pool_c.begin().await { Ok(t) => t, Err(_) => { failed_c.fetch_add(1, Ordering::SeqCst); return; } };
-                            let _ = sqlx::query(
-                                r#"INSERT INTO product_details (
-                                    url, page_id, index_in_page, id, manufacturer, model, device_type,
-                                    certificate_id, certification_date, software_version, hardware_version, firmware_version,
-                                    specification_version, vid, pid, family_sku, family_variant_sku, family_id,
-                                    tis_trp_tested, transport_interface, primary_device_type_id, application_categories,
-                                    description, compliance_document_url, program_type
-                                ) VALUES (
-                                    ?, ?, ?, ?, ?, ?, ?,
-                                    ?, ?, ?, ?, ?,
-                                    ?, ?, ?, ?, ?, ?,
-                                    ?, ?, ?, ?,
-                                    ?, ?, ?
-                                ) ON CONFLICT(url) DO UPDATE SET
-                                    page_id=COALESCE(excluded.page_id, product_details.page_id),
-                                    index_in_page=COALESCE(excluded.index_in_page, product_details.index_in_page),
-                                    id=COALESCE(product_details.id, excluded.id),
-                                    manufacturer=COALESCE(excluded.manufacturer, product_details.manufacturer),
-                                    model=COALESCE(excluded.model, product_details.model),
-                                    device_type=COALESCE(excluded.device_type, product_details.device_type),
-                                    certificate_id=COALESCE(excluded.certificate_id, product_details.certificate_id),
-                                    certification_date=COALESCE(excluded.certification_date, product_details.certification_date),
-                                    software_version=COALESCE(excluded.software_version, product_details.software_version),
-                                    hardware_version=COALESCE(excluded.hardware_version, product_details.hardware_version),
-                                    firmware_version=COALESCE(excluded.firmware_version, product_details.firmware_version),
-                                    specification_version=COALESCE(excluded.specification_version, product_details.specification_version),
-                                    vid=COALESCE(excluded.vid, product_details.vid),
-                                    pid=COALESCE(excluded.pid, product_details.pid),
-                                    family_sku=COALESCE(excluded.family_sku, product_details.family_sku),
-                                    family_variant_sku=COALESCE(excluded.family_variant_sku, product_details.family_variant_sku),
-                                    family_id=COALESCE(excluded.family_id, product_details.family_id),
-                                    tis_trp_tested=COALESCE(excluded.tis_trp_tested, product_details.tis_trp_tested),
-                                    transport_interface=COALESCE(excluded.transport_interface, product_details.transport_interface),
-                                    primary_device_type_id=COALESCE(excluded.primary_device_type_id, product_details.primary_device_type_id),
-                                    application_categories=COALESCE(excluded.application_categories, product_details.application_categories),
-                                    description=COALESCE(excluded.description, product_details.description),
-                                    compliance_document_url=COALESCE(excluded.compliance_document_url, product_details.compliance_document_url),
-                                    program_type=COALESCE(excluded.program_type, product_details.program_type),
-                                    updated_at=CURRENT_TIMESTAMP
-                            "#,
-                            )
-                            .bind(&detail.url)
-                            .bind(detail.page_id)
-                            .bind(detail.index_in_page)
-                            .bind(detail.id)
-                            .bind(detail.manufacturer)
-                            .bind(detail.model)
-                            .bind(detail.device_type)
-                            .bind(detail.certificate_id)
-                            .bind(detail.certification_date)
-                            .bind(detail.software_version)
-                            .bind(detail.hardware_version)
-                            .bind(detail.firmware_version)
-                            .bind(detail.specification_version)
-                            .bind(detail.vid)
-                            .bind(detail.pid)
-                            .bind(detail.family_sku)
-                            .bind(detail.family_variant_sku)
-                            .bind(detail.family_id)
-                            .bind(detail.tis_trp_tested)
-                            .bind(detail.transport_interface)
-                            .bind(detail.primary_device_type_id)
-                            .bind(detail.application_categories)
-                            .bind(detail.description)
-                            .bind(detail.compliance_document_url)
-                            .bind(program_type)
-                            .execute(&mut *tx)
-                            .await;
-
-                            // Backfill core products fields
-                            let _ = sqlx::query(
-                                r#"UPDATE products SET
-                                    manufacturer = COALESCE(?, manufacturer),
-                                    model = COALESCE(?, model),
-                                    certificate_id = COALESCE(?, certificate_id),
-                                    updated_at = CURRENT_TIMESTAMP
-                                WHERE url = ?"#,
-                            )
-                            .bind(&man_clone)
-                            .bind(&model_clone)
-                            .bind(&cert_clone)
-                            .bind(&detail.url)
-                            .execute(&mut *tx)
-                            .await;
-
-                            if tx.commit().await.is_ok() {
+                            // Write-behind: committed with other rows by size/interval or the final flush
+                            if queue_c.enqueue([detail]).await.is_ok() {
                                 succeeded_c.fetch_add(1, Ordering::SeqCst);
                             } else {
                                 failed_c.fetch_add(1, Ordering::SeqCst);
@@ -3440,9 +4457,154 @@ pub async fn retry_failed_details(
         handles.push(handle);
     }
     for h in handles { let _ = h.await; }
+    // Rows may already have been committed by size/interval flushes; this commits the rest
+    queue
+        .flush()
+        .await
+        .map_err(|e| format!("Failed to persist retried details: {e:#}"))?;
     Ok(serde_json::json!({
         "attempted": attempted.load(Ordering::SeqCst),
         "succeeded": succeeded.load(Ordering::SeqCst),
         "failed": failed.load(Ordering::SeqCst),
     }))
 }
+
+/// Field-level changeset stored for a completed partial sync session (None when not recorded)
+#[tauri::command(async)]
+pub async fn get_sync_changeset(
+    app_state: State<'_, AppState>,
+    session_id: String,
+) -> Result<Option<SyncChangeset>, CommandError> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let raw: Option<Option<String>> =
+        sqlx::query_scalar("SELECT changeset_json FROM sync_sessions WHERE session_id = ?")
+            .bind(&session_id)
+            .fetch_optional(&pool)
+            .await
+            .map_err(|e| format!("Failed to load sync session: {e}"))?;
+    raw.flatten()
+        .map(|json| {
+            serde_json::from_str(&json).map_err(|e| {
+                CommandError::new(
+                    ErrorCode::SerializationFailed,
+                    format!("Corrupt changeset: {e}"),
+                )
+            })
+        })
+        .transpose()
+}
+
+/// Cancel a running sync session. Page workers stop at the next URL, rolling back the page
+/// transaction in flight; the session finishes with `SyncAborted`. Returns false when no sync
+/// with that id is running.
+#[tauri::command(async)]
+pub async fn cancel_sync_session(
+    shared_state: State<'_, SharedStateCache>,
+    session_id: String,
+) -> Result<bool, CommandError> {
+    let cancelled = shared_state.sync_cancellations.cancel(&session_id);
+    if cancelled {
+        info!("Sync cancellation requested: session_id={}", session_id);
+    }
+    Ok(cancelled)
+}
+
+/// Session ids of syncs currently running (cancellable)
+#[tauri::command(async)]
+pub async fn list_running_syncs(
+    shared_state: State<'_, SharedStateCache>,
+) -> Result<Vec<String>, CommandError> {
+    Ok(shared_state.sync_cancellations.active_sessions())
+}
+
+/// Pause a running sync: pages not started yet are kept for `resume_sync_session`, pages in
+/// flight finish and commit, then the session ends with `SyncPaused`. Returns false when no
+/// pausable sync with that id is running (dry runs and basic/diagnostic syncs cannot pause).
+#[tauri::command(async)]
+pub async fn pause_sync_session(
+    shared_state: State<'_, SharedStateCache>,
+    session_id: String,
+) -> Result<bool, CommandError> {
+    let paused = shared_state.sync_cancellations.pause(&session_id);
+    if paused {
+        info!("Sync pause requested: session_id={}", session_id);
+    }
+    Ok(paused)
+}
+
+/// Run the remaining pages of a paused sync under its original session id
+#[tauri::command(async)]
+pub async fn resume_sync_session(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+    session_id: String,
+) -> Result<SyncSummary, CommandError> {
+    CommandDedup::shared()
+        .run("resume_sync_session", &session_id, || {
+            run_resumed_sync(Arc::new(app), &app_state, session_id.clone())
+        })
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Paused syncs with their remaining page counts, newest first
+#[tauri::command(async)]
+pub async fn list_paused_syncs(
+    app_state: State<'_, AppState>,
+) -> Result<Vec<sync_resume::PausedSync>, CommandError> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    sync_resume::paused_sessions(&pool).await.map_err(|e| {
+        CommandError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to list paused syncs: {e:#}"),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::event_sink::NullEventSink;
+
+    #[test]
+    fn failed_pages_get_bounded_retry_passes() {
+        let sink = NullEventSink;
+        let mut retry = None;
+        assert_eq!(
+            next_failed_page_pass(&sink, "s", &mut retry, vec![], 1, false),
+            None
+        );
+        assert!(retry.is_none());
+
+        let pages = next_failed_page_pass(&sink, "s", &mut retry, vec![7, 3, 7], 1, false);
+        assert_eq!(pages, Some(vec![3, 7]));
+        // Page 3 went through on the retry pass; no pass left for page 7
+        assert_eq!(
+            next_failed_page_pass(&sink, "s", &mut retry, vec![7], 1, false),
+            None
+        );
+        assert_eq!(
+            retry,
+            Some(SyncPageRetry {
+                passes: 1,
+                retried: vec![3, 7],
+                recovered: vec![3],
+                still_failed: vec![7],
+            })
+        );
+
+        // A stopping session defers nothing
+        let mut retry = None;
+        assert_eq!(
+            next_failed_page_pass(&sink, "s", &mut retry, vec![4], 2, true),
+            None
+        );
+        assert_eq!(retry.map(|r| r.passes), Some(0));
+    }
+}
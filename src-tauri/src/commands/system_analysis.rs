@@ -17,6 +17,7 @@ pub struct CrawlingResponse {
     pub data: Option<serde_json::Value>,
 }
 use crate::application::shared_state::{DbAnalysisResult, SharedStateCache, SiteAnalysisResult};
+use crate::crawl_engine::runtime::command_dedup::CommandDedup;
 use crate::domain::constants::{crawling::ttl, site};
 
 /// 시스템 종합 분석 커맨드 (StatusTab용)
@@ -105,11 +106,26 @@ pub async fn analyze_system_status(
 }
 
 /// 진단: products / product_details 간 미스매치 및 이상치 탐지/정리
+/// 같은 옵션으로 겹쳐 호출되면(더블클릭) 진행 중인 진단 결과를 함께 받는다.
 #[tauri::command]
 pub async fn diagnose_and_repair_data(
     shared_state: State<'_, SharedStateCache>,
     delete_mismatches: Option<bool>,
     sync_orphans: Option<bool>,
+) -> Result<CrawlingResponse, String> {
+    CommandDedup::shared()
+        .run(
+            "diagnose_and_repair_data",
+            &(delete_mismatches, sync_orphans),
+            || diagnose_and_repair(shared_state, delete_mismatches, sync_orphans),
+        )
+        .await
+}
+
+async fn diagnose_and_repair(
+    shared_state: State<'_, SharedStateCache>,
+    delete_mismatches: Option<bool>,
+    sync_orphans: Option<bool>,
 ) -> Result<CrawlingResponse, String> {
     let pool = crate::infrastructure::database_connection::get_or_init_global_pool()
         .await
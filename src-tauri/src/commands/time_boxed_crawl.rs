@@ -0,0 +1,292 @@
+//! time-boxed 크롤 프로필 (마감 시간 안에서 가능한 만큼 처리)
+//!
+//! page_id 그룹을 가장 오래 갱신되지 않은 제품 기준으로 정렬하고(상세 없는 제품이 있으면 최우선),
+//! `advanced.time_boxed_crawl.chunk_pages` 단위로 partial sync에 위임한다. 청크 사이마다 남은
+//! 시간과 지금까지의 페이지당 평균 소요 시간을 비교해, 마감 안에 끝낼 수 있는 만큼만 다음 청크로
+//! 보낸다. 마감 이후에는 새 청크를 시작하지 않고, 처리/미처리 범위와 나머지를 이어서 처리할
+//! 후속 계획(`follow_up.pages` 를 그대로 `run_time_boxed_crawl` 에 전달)을 보고한다.
+
+use crate::application::AppState;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, State};
+use tracing::{info, warn};
+
+use super::rolling_refresh::range_expr;
+use super::sync_commands::{SyncSummary, start_partial_sync};
+
+/// Pages a time-boxed run works through, most stale first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeBoxedCrawlPlan {
+    pub max_duration_secs: u64,
+    /// Physical pages (1 = newest) in processing order
+    pub physical_pages: Vec<u32>,
+    /// Range expression covering every planned page
+    pub ranges: String,
+    /// Pages holding products without a detail row (planned first)
+    pub pages_missing_details: u32,
+}
+
+/// Remainder of a run that hit its deadline; pass `pages` back to `run_time_boxed_crawl`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeBoxedFollowUp {
+    pub pages: Vec<u32>,
+    pub ranges: String,
+    /// Based on this run's average time per page
+    pub estimated_duration_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeBoxedCrawlReport {
+    pub max_duration_secs: u64,
+    pub elapsed_ms: u64,
+    /// True when pages were left because the deadline came first
+    pub deadline_reached: bool,
+    pub chunks: u32,
+    pub covered_pages: Vec<u32>,
+    pub covered_ranges: String,
+    pub remaining_pages: Vec<u32>,
+    pub remaining_ranges: String,
+    /// Totals over all chunks
+    pub sync: SyncSummary,
+    /// First chunk error; the run stops there and the chunk's pages count as remaining
+    pub error: Option<String>,
+    pub follow_up: Option<TimeBoxedFollowUp>,
+}
+
+/// Compute the staleness-ordered page list without crawling
+#[tauri::command(async)]
+pub async fn preview_time_boxed_crawl(
+    app_state: State<'_, AppState>,
+    max_duration_secs: Option<u64>,
+) -> Result<TimeBoxedCrawlPlan, String> {
+    let cfg = app_state.get_config().await.advanced.time_boxed_crawl;
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let max_duration_secs = max_duration_secs.unwrap_or(cfg.default_max_duration_secs);
+    build_plan(&pool, max_duration_secs)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Crawl the most stale pages until the deadline. `pages` (e.g. a previous report's
+/// `follow_up.pages`) replaces the staleness plan and is processed in the given order.
+#[tauri::command(async)]
+pub async fn run_time_boxed_crawl(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+    max_duration_secs: Option<u64>,
+    pages: Option<Vec<u32>>,
+) -> Result<TimeBoxedCrawlReport, String> {
+    let cfg = app_state.get_config().await.advanced.time_boxed_crawl;
+    let max_duration_secs = max_duration_secs.unwrap_or(cfg.default_max_duration_secs);
+    if max_duration_secs == 0 {
+        return Err("max_duration_secs must be greater than 0".into());
+    }
+    let queue: Vec<u32> = match pages {
+        Some(pages) => {
+            let mut seen = std::collections::HashSet::new();
+            pages
+                .into_iter()
+                .filter(|&p| p > 0 && seen.insert(p))
+                .collect()
+        }
+        None => {
+            let pool = app_state
+                .get_database_pool()
+                .await
+                .map_err(|e| format!("DB pool unavailable: {e}"))?;
+            build_plan(&pool, max_duration_secs)
+                .await
+                .map_err(|e| e.to_string())?
+                .physical_pages
+        }
+    };
+    info!(
+        "⏱️ Time-boxed crawl: {} pages queued, deadline {}s, chunk {} pages",
+        queue.len(),
+        max_duration_secs,
+        cfg.chunk_pages
+    );
+
+    let budget = Duration::from_secs(max_duration_secs);
+    let started = Instant::now();
+    let mut sync = SyncSummary {
+        pages_processed: 0,
+        inserted: 0,
+        updated: 0,
+        skipped: 0,
+        failed: 0,
+        duration_ms: 0,
+    };
+    let mut covered: Vec<u32> = Vec::new();
+    let mut chunks = 0u32;
+    let mut error = None;
+    let mut next = 0usize;
+    while next < queue.len() {
+        let elapsed = started.elapsed();
+        let avg_page_ms =
+            (!covered.is_empty()).then(|| elapsed.as_millis() as u64 / covered.len() as u64);
+        let remaining_ms = budget.saturating_sub(elapsed).as_millis() as u64;
+        let len = chunk_len(remaining_ms, avg_page_ms, cfg.chunk_pages).min(queue.len() - next);
+        if len == 0 {
+            break;
+        }
+        let chunk = &queue[next..next + len];
+        let expr = range_expr(chunk);
+        match start_partial_sync(
+            app.clone(),
+            app_state.clone(),
+            expr.clone(),
+            Some(false),
+            None,
+        )
+        .await
+        {
+            Ok(res) => {
+                sync.pages_processed = sync.pages_processed.saturating_add(res.pages_processed);
+                sync.inserted = sync.inserted.saturating_add(res.inserted);
+                sync.updated = sync.updated.saturating_add(res.updated);
+                sync.skipped = sync.skipped.saturating_add(res.skipped);
+                sync.failed = sync.failed.saturating_add(res.failed);
+                covered.extend_from_slice(chunk);
+                chunks += 1;
+                next += len;
+            }
+            Err(e) => {
+                warn!("⏱️ Time-boxed chunk \"{}\" failed: {}", expr, e);
+                error = Some(e.to_string());
+                break;
+            }
+        }
+    }
+
+    let elapsed_ms = started.elapsed().as_millis() as u64;
+    sync.duration_ms = elapsed_ms;
+    let remaining: Vec<u32> = queue[next..].to_vec();
+    let deadline_reached = !remaining.is_empty() && error.is_none();
+    let follow_up = (!remaining.is_empty()).then(|| TimeBoxedFollowUp {
+        ranges: range_expr(&remaining),
+        estimated_duration_secs: (!covered.is_empty())
+            .then(|| elapsed_ms / covered.len() as u64 * remaining.len() as u64 / 1000),
+        pages: remaining.clone(),
+    });
+    info!(
+        "⏱️ Time-boxed crawl finished in {}ms: covered={} remaining={} deadline_reached={}",
+        elapsed_ms,
+        covered.len(),
+        remaining.len(),
+        deadline_reached
+    );
+    Ok(TimeBoxedCrawlReport {
+        max_duration_secs,
+        elapsed_ms,
+        deadline_reached,
+        chunks,
+        covered_ranges: range_expr(&covered),
+        covered_pages: covered,
+        remaining_ranges: range_expr(&remaining),
+        remaining_pages: remaining,
+        sync,
+        error,
+        follow_up,
+    })
+}
+
+async fn build_plan(
+    pool: &SqlitePool,
+    max_duration_secs: u64,
+) -> Result<TimeBoxedCrawlPlan, sqlx::Error> {
+    // Oldest detail refresh per page; '' (sorts first) when a product has no detail row
+    let rows = sqlx::query(
+        "SELECT p.page_id AS page_id, \
+            MIN(CASE WHEN pd.url IS NULL THEN '' ELSE COALESCE(CAST(pd.updated_at AS TEXT), '') END) AS oldest \
+         FROM products p LEFT JOIN product_details pd ON pd.url = p.url \
+         WHERE p.page_id IS NOT NULL GROUP BY p.page_id",
+    )
+    .fetch_all(pool)
+    .await?;
+    let staleness: BTreeMap<i64, String> = rows
+        .iter()
+        .filter_map(|row| {
+            let page_id = row.try_get::<i64, _>("page_id").ok()?;
+            let oldest = row
+                .try_get::<Option<String>, _>("oldest")
+                .ok()
+                .flatten()
+                .unwrap_or_default();
+            Some((page_id, oldest))
+        })
+        .collect();
+    let Some(&max_page_id) = staleness.keys().next_back() else {
+        return Ok(TimeBoxedCrawlPlan {
+            max_duration_secs,
+            physical_pages: Vec::new(),
+            ranges: String::new(),
+            pages_missing_details: 0,
+        });
+    };
+    let pages_missing_details = staleness.values().filter(|v| v.is_empty()).count() as u32;
+    // total_pages ≈ max_pid + 1 → current physical page = total_pages - page_id
+    let physical_pages: Vec<u32> = order_by_staleness(&staleness)
+        .into_iter()
+        .map(|page_id| (max_page_id + 1 - page_id).max(1) as u32)
+        .collect();
+    Ok(TimeBoxedCrawlPlan {
+        max_duration_secs,
+        ranges: range_expr(&physical_pages),
+        physical_pages,
+        pages_missing_details,
+    })
+}
+
+/// page_ids ordered oldest refresh first; ties go to the newer page
+fn order_by_staleness(staleness: &BTreeMap<i64, String>) -> Vec<i64> {
+    let mut ordered: Vec<(&String, i64)> = staleness.iter().map(|(&p, ts)| (ts, p)).collect();
+    ordered.sort_by(|a, b| a.0.cmp(b.0).then(b.1.cmp(&a.1)));
+    ordered.into_iter().map(|(_, page_id)| page_id).collect()
+}
+
+/// Pages to send in the next chunk: the configured size, shrunk to what the average page time
+/// says still fits before the deadline. The first chunk always runs (no timing yet).
+fn chunk_len(remaining_ms: u64, avg_page_ms: Option<u64>, chunk_pages: u32) -> usize {
+    let chunk_pages = chunk_pages.max(1) as usize;
+    if remaining_ms == 0 {
+        return 0;
+    }
+    match avg_page_ms {
+        None | Some(0) => chunk_pages,
+        Some(avg) => chunk_pages.min((remaining_ms / avg) as usize),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_details_then_oldest_refresh_first() {
+        let staleness: BTreeMap<i64, String> = [
+            (1, "2024-03-01 00:00:00".to_string()),
+            (2, String::new()),
+            (3, "2024-01-01 00:00:00".to_string()),
+            (4, "2024-03-01 00:00:00".to_string()),
+        ]
+        .into();
+        assert_eq!(order_by_staleness(&staleness), vec![2, 3, 4, 1]);
+    }
+
+    #[test]
+    fn chunks_shrink_to_fit_the_deadline() {
+        assert_eq!(chunk_len(60_000, None, 5), 5);
+        assert_eq!(chunk_len(60_000, Some(10_000), 5), 5);
+        assert_eq!(chunk_len(25_000, Some(10_000), 5), 2);
+        assert_eq!(chunk_len(5_000, Some(10_000), 5), 0);
+        assert_eq!(chunk_len(0, None, 5), 0);
+        assert_eq!(chunk_len(1_000, None, 0), 1);
+    }
+}
@@ -6,6 +6,10 @@ use tracing::info;
 use crate::commands::actor_system_commands::{
     ActorCrawlingRequest, CrawlingMode, start_actor_system_crawling,
 };
+use crate::crawl_engine::runtime::crawl_limits::CrawlLimits;
+use crate::crawl_engine::stage_type::{StageSelection, StageSkipFlag};
+use crate::domain::command_error::CommandError;
+use crate::services::session_export::SessionExportRequest;
 
 /// 통합 크롤링 요청 구조체
 #[derive(Debug, Deserialize)]
@@ -14,6 +18,15 @@ pub struct StartCrawlingRequest {
     pub override_batch_size: Option<u32>,
     pub override_concurrency: Option<u32>,
     pub delay_ms: Option<u64>,
+    /// 세션이 저장한 제품을 CSV/NDJSON으로 바로 내보내기 (선택)
+    #[serde(default)]
+    pub export: Option<SessionExportRequest>,
+    /// 고급: 건너뛸 배치 스테이지 (skip_validation / skip_status_check / list_only)
+    #[serde(default)]
+    pub stage_flags: Vec<StageSkipFlag>,
+    /// 스팟 체크용 한도: max_new_products / max_pages / max_duration_secs (선택)
+    #[serde(flatten)]
+    pub limits: CrawlLimits,
 }
 
 /// 통합 크롤링 응답 구조체
@@ -27,16 +40,24 @@ pub struct StartCrawlingResponse {
 /// 통합 크롤링 명령어 (Actor 시스템 진입점)
 ///
 /// # Errors
-/// Returns `Err(String)` when the actor system fails to start crawling
-/// (for example, invalid parameters, internal initialization errors,
-/// or runtime failures reported by the actor entrypoint).
+/// Returns a `CommandError` when the actor system fails to start crawling:
+/// `request` category for invalid stage flags or limits, otherwise the category classified
+/// from the actor entrypoint failure (config, DB, site status, ...).
 #[tauri::command]
 pub async fn start_unified_crawling(
     app: AppHandle,
     request: StartCrawlingRequest,
-) -> Result<StartCrawlingResponse, String> {
+) -> Result<StartCrawlingResponse, CommandError> {
     info!("🚀 통합 크롤링 요청 수신: {:?}", request);
 
+    // 알 수 없는 플래그는 역직렬화에서, 서로 겹치는 조합은 여기서 거부
+    let stage_selection =
+        StageSelection::from_flags(&request.stage_flags).map_err(CommandError::invalid_request)?;
+    request
+        .limits
+        .validate()
+        .map_err(CommandError::invalid_request)?;
+
     // 단일 경로: Actor 기반
     let crawling_mode = match request.mode.as_deref() {
         Some("advanced") => Some(CrawlingMode::AdvancedEngine),
@@ -52,10 +73,13 @@ pub async fn start_unified_crawling(
         batch_size: request.override_batch_size,
         delay_ms: request.delay_ms,
         mode: crawling_mode,
+        export: request.export,
+        stage_flags: stage_selection.flags().to_vec(),
+        limits: request.limits,
     };
     let result = start_actor_system_crawling(app.clone(), actor_req)
         .await
-        .map_err(|e| format!("failed to start actor crawling: {}", e))?;
+        .map_err(|e| CommandError::classify(format!("failed to start actor crawling: {}", e)))?;
     Ok(StartCrawlingResponse {
         success: result.success,
         message: result.message,
@@ -63,4 +87,4 @@ pub async fn start_unified_crawling(
     })
 }
 
-// NOTE: engine_type 제거로 호출 단순화됨. FE는 mode + override_* 만 전달.
+// NOTE: engine_type 제거로 호출 단순화됨. FE는 mode + override_* (+ 선택적 stage_flags / 한도) 만 전달.
@@ -0,0 +1,209 @@
+//! URL 템플릿 실사이트 검증
+//!
+//! `advanced.url_templates`(또는 저장 전 후보 템플릿)로 목록 1·2 페이지와 첫 제품 상세 페이지를
+//! 실제로 요청해 응답이 성공하고 제품 링크가 추출되는지 확인한다. 사이트 경로 구조가 바뀌었을 때
+//! 새 템플릿을 저장하기 전에 돌려 보는 용도다.
+
+use crate::application::AppState;
+use crate::domain::site_profile::SiteProfile;
+use crate::infrastructure::config::{UrlTemplateConfig, defaults};
+use crate::infrastructure::html_parser::MatterDataExtractor;
+use crate::infrastructure::simple_http_client::{HttpClient, RequestOptions};
+use crate::infrastructure::site_profiles::site_profile_with_templates;
+use serde::{Deserialize, Serialize};
+use tauri::State;
+use tracing::info;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UrlTemplateCheckKind {
+    /// `first_page_url`
+    FirstPage,
+    /// `list_page_template` with page 2
+    ListPage,
+    /// First product link found on the first page (`product_path`)
+    ProductPage,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UrlTemplateCheck {
+    pub kind: UrlTemplateCheckKind,
+    pub url: String,
+    pub ok: bool,
+    /// Product links extracted (listing checks only)
+    pub product_urls: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UrlTemplateTestReport {
+    pub templates: UrlTemplateConfig,
+    /// Placeholder / URL syntax problems; the site is not contacted when present
+    pub validation_errors: Vec<String>,
+    pub checks: Vec<UrlTemplateCheck>,
+    pub ok: bool,
+}
+
+/// Resolve the configured (or given candidate) URL templates against the live site
+#[tauri::command(async)]
+pub async fn test_url_templates(
+    app_state: State<'_, AppState>,
+    templates: Option<UrlTemplateConfig>,
+) -> Result<UrlTemplateTestReport, String> {
+    let app_config = app_state.get_config().await;
+    let templates = templates.unwrap_or_else(|| app_config.advanced.url_templates.clone());
+    let validation_errors = templates.validate();
+    if !validation_errors.is_empty() {
+        return Ok(UrlTemplateTestReport {
+            templates,
+            validation_errors,
+            checks: Vec::new(),
+            ok: false,
+        });
+    }
+
+    let site = site_profile_with_templates(&app_config.advanced.site_profile, &templates)
+        .or_else(|| site_profile_with_templates(defaults::SITE_PROFILE_ID, &templates))
+        .ok_or_else(|| "No site profile available".to_string())?;
+    let extractor = MatterDataExtractor::with_profile(site.clone()).map_err(|e| e.to_string())?;
+    let http = app_state.get_http_client().await?;
+    let user_agent = app_config.user.crawling.workers.user_agent_sync.clone();
+
+    let mut checks = Vec::with_capacity(3);
+    let mut first_product = None;
+    for (kind, page) in [
+        (UrlTemplateCheckKind::FirstPage, 1),
+        (UrlTemplateCheckKind::ListPage, 2),
+    ] {
+        let url = site.list_page_url(page);
+        let fetched = fetch(&http, &user_agent, site.as_ref(), &url).await;
+        let (check, products) = list_page_check(kind, url, fetched, &extractor);
+        if first_product.is_none() {
+            first_product = products.into_iter().next();
+        }
+        checks.push(check);
+    }
+    if let Some(url) = first_product {
+        let error = fetch(&http, &user_agent, site.as_ref(), &url).await.err();
+        checks.push(UrlTemplateCheck {
+            kind: UrlTemplateCheckKind::ProductPage,
+            ok: error.is_none(),
+            url,
+            product_urls: 0,
+            error,
+        });
+    }
+
+    let ok = checks.len() == 3 && checks.iter().all(|c| c.ok);
+    info!(
+        "🔗 URL template test: {} ({} checks)",
+        if ok { "ok" } else { "failed" },
+        checks.len()
+    );
+    Ok(UrlTemplateTestReport {
+        templates,
+        validation_errors,
+        checks,
+        ok,
+    })
+}
+
+/// Judge a fetched listing page; returns the check and the product links it yielded
+fn list_page_check(
+    kind: UrlTemplateCheckKind,
+    url: String,
+    fetched: Result<String, String>,
+    extractor: &MatterDataExtractor,
+) -> (UrlTemplateCheck, Vec<String>) {
+    let products = fetched.and_then(|html| {
+        extractor
+            .extract_product_urls_from_content(&html)
+            .map_err(|e| e.to_string())
+    });
+    let (products, error) = match products {
+        Ok(urls) if urls.is_empty() => (urls, Some("No product links found".to_string())),
+        Ok(urls) => (urls, None),
+        Err(e) => (Vec::new(), Some(e)),
+    };
+    let check = UrlTemplateCheck {
+        kind,
+        url,
+        ok: error.is_none(),
+        product_urls: products.len(),
+        error,
+    };
+    (check, products)
+}
+
+async fn fetch(
+    http: &HttpClient,
+    user_agent: &Option<String>,
+    site: &dyn SiteProfile,
+    url: &str,
+) -> Result<String, String> {
+    let resp = http
+        .fetch_response_with_options(
+            url,
+            &RequestOptions {
+                user_agent_override: user_agent.clone(),
+                referer: Some(site.listing_base_url().to_string()),
+                skip_robots_check: false,
+                attempt: None,
+                max_attempts: None,
+            },
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    resp.text().await.map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extractor(urls: &UrlTemplateConfig) -> MatterDataExtractor {
+        let site = site_profile_with_templates(defaults::SITE_PROFILE_ID, urls).unwrap();
+        MatterDataExtractor::with_profile(site).unwrap()
+    }
+
+    #[test]
+    fn listing_check_requires_product_links_matching_the_template() {
+        let html = r#"<div class="post-feed">
+            <article><a href="/csa_product/lamp/">Lamp</a></article>
+            <article><a href="/csa_product/plug/">Plug</a></article>
+        </div>"#;
+        let defaults = UrlTemplateConfig::default();
+        let (check, products) = list_page_check(
+            UrlTemplateCheckKind::FirstPage,
+            defaults.list_page_url(1),
+            Ok(html.to_string()),
+            &extractor(&defaults),
+        );
+        assert!(check.ok, "{check:?}");
+        assert_eq!(check.product_urls, 2);
+        assert_eq!(products[0], "https://csa-iot.org/csa_product/lamp/");
+
+        // Product path moved on the site: links no longer classify as products
+        let moved = UrlTemplateConfig {
+            product_path: "/product/".into(),
+            ..UrlTemplateConfig::default()
+        };
+        let (check, _) = list_page_check(
+            UrlTemplateCheckKind::ListPage,
+            moved.list_page_url(2),
+            Ok(html.to_string()),
+            &extractor(&moved),
+        );
+        assert!(!check.ok);
+        assert_eq!(check.error.as_deref(), Some("No product links found"));
+
+        let (check, products) = list_page_check(
+            UrlTemplateCheckKind::ListPage,
+            defaults.list_page_url(2),
+            Err("HTTP error 404 Not Found".into()),
+            &extractor(&defaults),
+        );
+        assert!(!check.ok && products.is_empty());
+        assert!(check.error.unwrap().contains("404"));
+    }
+}
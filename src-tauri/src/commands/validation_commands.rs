@@ -1,14 +1,28 @@
+use crate::commands::incremental_crawl::fetch_list_page;
+use crate::commands::sync_commands::{
+    DiagnosticPageInput, DiagnosticSnapshotInput, SyncSummary, start_diagnostic_sync,
+};
 use crate::crawl_engine::actors::types::AppEvent;
+use crate::crawl_engine::runtime::sync_cancellation::SyncCancellationRegistry;
+use crate::crawl_engine::validation::findings::{self, RepairOutcome, StoredFinding};
+use crate::crawl_engine::validation::{CrossCheckSummary, ValidationCoordinator};
+use crate::domain::command_error::CommandError;
+use crate::domain::error_catalog::ErrorCode;
 use crate::domain::pagination::CanonicalPageIdCalculator;
+use crate::infrastructure::event_sink::EventSink;
+use crate::infrastructure::query_control::{QueryOptions, run_query};
+use crate::infrastructure::site_profiles::resolve_site_profile;
 use crate::infrastructure::{
     config::csa_iot, html_parser::MatterDataExtractor, simple_http_client::RequestOptions,
 }; // uses ConfigManager (no AppConfigManager)
+use crate::services::validation_repair::{ValidationRepairPlan, plan_repairs};
 use chrono::Utc;
 use serde_json::{Map, Value};
 use sqlx::Row;
+use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
-use tauri::{AppHandle, Emitter, State};
-use tracing::{debug, error, info, warn};
+use tauri::{AppHandle, State};
+use tracing::{debug, info, warn};
 
 static VALIDATION_SEQ: AtomicU64 = AtomicU64::new(1);
 
@@ -141,8 +155,11 @@ pub struct GapRange {
     pub size: u64,
 }
 
-/// Emit an AppEvent directly to the frontend (lightweight bridge clone)
-pub(crate) fn emit_actor_event(app: &AppHandle, event: AppEvent) {
+/// Emit an AppEvent to the given sink (lightweight bridge clone).
+/// `AppHandle` is a sink, so Tauri commands pass `&app`; headless callers pass a log/null sink.
+pub(crate) fn emit_actor_event<S: EventSink + ?Sized>(sink: &S, event: AppEvent) {
+    #[cfg(feature = "metrics-exporter")]
+    crate::infrastructure::metrics_exporter::observe_event(&event);
     // Map variant -> event name (keep in sync with actor_event_bridge.rs)
     let event_name = match &event {
         // Validation event stream
@@ -159,6 +176,17 @@ pub(crate) fn emit_actor_event(app: &AppHandle, event: AppEvent) {
         AppEvent::SyncWarning { .. } => "actor-sync-warning",
     AppEvent::SyncRetrying { .. } => "actor-sync-retrying",
         AppEvent::SyncCompleted { .. } => "actor-sync-completed",
+        AppEvent::SyncAborted { .. } => "actor-sync-aborted",
+        AppEvent::SyncPaused { .. } => "actor-sync-paused",
+        AppEvent::LightSyncReport { .. } => "actor-light-sync-report",
+        AppEvent::DbMaintenanceProgress { .. } => "actor-db-maintenance-progress",
+        AppEvent::WatchlistChanged { .. } => "actor-watchlist-changed",
+        AppEvent::SiteCircuitOpened { .. } => "actor-site-circuit-opened",
+        AppEvent::SiteCircuitClosed { .. } => "actor-site-circuit-closed",
+        AppEvent::DataIntegritySnapshot { .. } => "actor-data-integrity-snapshot",
+        AppEvent::ActorRestarted { .. } => "actor-actor-restarted",
+        AppEvent::SessionStalled { .. } => "actor-session-stalled",
+        AppEvent::ConcurrencyAdjusted { .. } => "actor-concurrency-adjusted",
         // Product lifecycle forwarding
         AppEvent::ProductLifecycle { .. } => "actor-product-lifecycle",
         _ => return,
@@ -197,11 +225,7 @@ pub(crate) fn emit_actor_event(app: &AppHandle, event: AppEvent) {
             o.insert("backend_ts".into(), Value::from(Utc::now().to_rfc3339()));
             o.insert("event_name".into(), Value::from(event_name));
         }
-        if let Err(e) = app.emit(event_name, enriched) {
-            error!("Failed to emit validation event {}: {}", event_name, e);
-        } else {
-            debug!("Emitted validation event {}", event_name);
-        }
+        sink.emit_json(event_name, enriched);
     }
 }
 
@@ -223,7 +247,7 @@ pub async fn start_validation(
     // Optional fallback: a human-friendly expression like "498-489,487~485,480"
     // If provided and explicit numeric args are missing, we'll parse the first range.
     ranges_expr: Option<String>,
-) -> Result<ValidationSummary, String> {
+) -> Result<ValidationSummary, CommandError> {
     // Preserve user-provided scan_pages separately; dynamic default may override if None
     let user_scan_pages = scan_pages.filter(|v| *v > 0);
     info!(
@@ -339,9 +363,19 @@ pub async fn start_validation(
     {
         Ok(resp) => match resp.text().await {
             Ok(t) => t,
-            Err(e) => return Err(format!("Read newest page text error: {e}")),
+            Err(e) => {
+                return Err(CommandError::new(
+                    ErrorCode::NetworkFailure,
+                    format!("Read newest page text error: {e}"),
+                ));
+            }
         },
-        Err(e) => return Err(format!("Failed to fetch newest page: {e}")),
+        Err(e) => {
+            return Err(CommandError::new(
+                ErrorCode::SiteStatusFailed,
+                format!("Failed to fetch newest page: {e}"),
+            ));
+        }
     };
     let total_pages = match extractor.extract_total_pages(&newest_html) {
         Ok(p) if p > 0 => p,
@@ -373,19 +407,24 @@ pub async fn start_validation(
         {
             Ok(resp) => match resp.text().await {
                 Ok(t) => t,
-                Err(e) => return Err(format!("Read oldest page text error: {e}")),
+                Err(e) => {
+                    return Err(CommandError::new(
+                        ErrorCode::NetworkFailure,
+                        format!("Read oldest page text error: {e}"),
+                    ));
+                }
             },
             Err(e) => {
-                return Err(format!(
-                    "Failed to fetch oldest page {}: {e}",
-                    oldest_physical_page
+                return Err(CommandError::new(
+                    ErrorCode::SiteStatusFailed,
+                    format!("Failed to fetch oldest page {}: {e}", oldest_physical_page),
                 ));
             }
         }
     };
     let oldest_urls = extractor
         .extract_product_urls_from_content(&oldest_html)
-        .map_err(|e| e.to_string())?;
+        .map_err(|e| CommandError::new(ErrorCode::ParseFailed, e.to_string()))?;
     let items_on_last_page = oldest_urls.len(); // may be full 12 or partial
     let calculator = CanonicalPageIdCalculator::new(total_pages, items_on_last_page);
 
@@ -458,7 +497,9 @@ pub async fn start_validation(
             let total_products: i64 = sqlx::query("SELECT COUNT(*) as cnt FROM products")
                 .fetch_one(&pool)
                 .await
-                .map_err(|e| format!("DB count failed: {e}"))?
+                .map_err(|e| {
+                    CommandError::new(ErrorCode::DatabaseError, format!("DB count failed: {e}"))
+                })?
                 .try_get::<i64, _>("cnt")
                 .unwrap_or(0);
             let max_page_id: Option<i64> = sqlx::query(
@@ -674,7 +715,9 @@ pub async fn start_validation(
                     .bind(url)
                     .fetch_optional(&pool)
                     .await
-                    .map_err(|e| format!("DB query failed: {e}"))?;
+                    .map_err(|e| {
+                        CommandError::new(ErrorCode::DatabaseError, format!("DB query failed: {e}"))
+                    })?;
             match row {
                 None => {
                     divergences += 1;
@@ -874,3 +917,162 @@ pub async fn start_validation(
     );
     Ok(summary)
 }
+
+/// DB-only cross-check of products against product_details (no site requests).
+/// Missing/orphan details, coordinate mismatches and duplicate slots replace the open rows of
+/// `validation_findings`; progress is emitted as `validation-cross-check` events.
+/// `scan_depth` limits the check to the newest page_ids (0 / None = whole DB).
+/// `options` sets a timeout / cancellable query id.
+#[tauri::command(async)]
+pub async fn run_db_cross_check(
+    app: AppHandle,
+    app_state: State<'_, crate::application::AppState>,
+    scan_depth: Option<u32>,
+    options: Option<QueryOptions>,
+) -> Result<CrossCheckSummary, CommandError> {
+    run_query("run_db_cross_check", options, |_| async move {
+        let pool = app_state
+            .get_database_pool()
+            .await
+            .map_err(|e| format!("DB pool unavailable: {e}"))?;
+        ValidationCoordinator::new(scan_depth.unwrap_or(0), Arc::new(app))
+            .run(&pool)
+            .await
+            .map_err(|e| format!("DB cross-check failed: {e:#}"))
+    })
+    .await
+}
+
+/// Recorded cross-check findings; `status` is `open` or `repaired` (all when None)
+#[tauri::command(async)]
+pub async fn get_validation_findings(
+    app_state: State<'_, crate::application::AppState>,
+    status: Option<String>,
+) -> Result<Vec<StoredFinding>, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    findings::list(&pool, status.as_deref())
+        .await
+        .map_err(|e| format!("Failed to load validation findings: {e:#}"))
+}
+
+/// Apply automatic repairs to open findings (`ids`, or all when None); duplicate slots stay
+/// open for manual review
+#[tauri::command(async)]
+pub async fn repair_validation_findings(
+    app_state: State<'_, crate::application::AppState>,
+    ids: Option<Vec<i64>>,
+) -> Result<RepairOutcome, String> {
+    let running = SyncCancellationRegistry::shared().active_sessions();
+    if !running.is_empty() {
+        return Err(format!(
+            "Findings cannot be repaired while sync is running: {}",
+            running.join(", ")
+        ));
+    }
+    if app_state.is_crawling_active().await {
+        return Err("Findings cannot be repaired while a crawl is running".into());
+    }
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    findings::repair(&pool, ids.as_deref())
+        .await
+        .map_err(|e| format!("Failed to repair validation findings: {e:#}"))
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ValidationRepairReport {
+    pub dry_run: bool,
+    pub plan: ValidationRepairPlan,
+    /// Diagnostic sync outcome (None for dry runs or an empty plan)
+    pub sync: Option<SyncSummary>,
+    /// Cross-check rerun after the sync (open findings left)
+    pub recheck: Option<CrossCheckSummary>,
+}
+
+/// Re-sync the site slots behind open validation findings. Findings are mapped to the current
+/// physical pages and grouped into per-page index sets for `start_diagnostic_sync`, then the
+/// cross-check runs again to refresh the findings. `dry_run` only returns the plan.
+#[tauri::command(async)]
+pub async fn apply_validation_repairs(
+    app: AppHandle,
+    app_state: State<'_, crate::application::AppState>,
+    dry_run: Option<bool>,
+) -> Result<ValidationRepairReport, CommandError> {
+    let dry_run = dry_run.unwrap_or(false);
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| CommandError::classify(format!("DB pool unavailable: {e}")))?;
+    let open = findings::list(&pool, Some("open")).await.map_err(|e| {
+        CommandError::classify(format!("Failed to load validation findings: {e:#}"))
+    })?;
+
+    let app_config = app_state.get_config().await;
+    let sync_ua = app_config.user.crawling.workers.user_agent_sync.clone();
+    let site = resolve_site_profile(&app_config);
+    let extractor = MatterDataExtractor::with_profile(site.clone())
+        .map_err(|e| CommandError::classify(e.to_string()))?;
+    let http = app_state.get_http_client().await?;
+    let newest_html = fetch_list_page(&http, &sync_ua, site.as_ref(), 1).await?;
+    let total_pages = extractor
+        .extract_total_pages(&newest_html)
+        .unwrap_or(1)
+        .max(1);
+    let oldest_html = if total_pages == 1 {
+        newest_html
+    } else {
+        fetch_list_page(&http, &sync_ua, site.as_ref(), total_pages).await?
+    };
+    let items_on_last_page = extractor
+        .extract_product_urls_from_content(&oldest_html)
+        .map_err(|e| CommandError::classify(e.to_string()))?
+        .len() as u32;
+
+    let plan = plan_repairs(&open, total_pages, items_on_last_page);
+    info!(
+        "apply_validation_repairs: {} findings -> {} slots on {} pages ({} unmapped, dry_run={})",
+        plan.findings_considered,
+        plan.slots,
+        plan.pages.len(),
+        plan.unmapped.len(),
+        dry_run
+    );
+    if dry_run || plan.pages.is_empty() {
+        return Ok(ValidationRepairReport {
+            dry_run,
+            plan,
+            sync: None,
+            recheck: None,
+        });
+    }
+
+    let pages = plan
+        .pages
+        .iter()
+        .map(|p| DiagnosticPageInput {
+            physical_page: p.physical_page,
+            miss_indices: p.indices.clone(),
+        })
+        .collect();
+    let snapshot = DiagnosticSnapshotInput {
+        total_pages,
+        items_on_last_page,
+    };
+    let sync =
+        start_diagnostic_sync(app.clone(), app_state, pages, Some(snapshot), Some(false)).await?;
+    let recheck = ValidationCoordinator::new(0, Arc::new(app))
+        .run(&pool)
+        .await
+        .map_err(|e| CommandError::classify(format!("DB cross-check failed: {e:#}")))?;
+    Ok(ValidationRepairReport {
+        dry_run,
+        plan,
+        sync: Some(sync),
+        recheck: Some(recheck),
+    })
+}
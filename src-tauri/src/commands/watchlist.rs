@@ -0,0 +1,153 @@
+//! watchlist 관리 / 관심 제품 우선 재수집 커맨드
+//!
+//! 관심 대상 CRUD 와 함께, 관심 제품이 있는 페이지만 partial sync 로 갱신한 뒤 스냅샷 대비
+//! 필드 변경을 `WatchlistChanged` 이벤트로 알린다. 스케줄러(`ScheduleAction::WatchlistRefresh`)와
+//! rolling-refresh 실행 후에도 같은 변경 확인을 거친다.
+
+use crate::application::AppState;
+use crate::crawl_engine::actors::types::AppEvent;
+use crate::services::watchlist::{self, WatchEntry, WatchTarget, WatchlistCheckReport};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tauri::{AppHandle, State};
+use tracing::info;
+
+use super::rolling_refresh::range_expr;
+use super::sync_commands::{SyncSummary, start_partial_sync};
+use super::validation_commands::emit_actor_event;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchlistRefreshReport {
+    /// Range expression handed to `start_partial_sync` (empty when nothing is watched)
+    pub ranges: String,
+    pub sync: Option<SyncSummary>,
+    pub check: Option<WatchlistCheckReport>,
+}
+
+#[tauri::command(async)]
+pub async fn add_watch_target(
+    app_state: State<'_, AppState>,
+    target: WatchTarget,
+    note: Option<String>,
+) -> Result<i64, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    watchlist::add_watch(&pool, &target, note)
+        .await
+        .map_err(|e| format!("Failed to add watch target: {e:#}"))
+}
+
+#[tauri::command(async)]
+pub async fn remove_watch_target(
+    app_state: State<'_, AppState>,
+    target: WatchTarget,
+) -> Result<bool, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    watchlist::remove_watch(&pool, &target)
+        .await
+        .map_err(|e| format!("Failed to remove watch target: {e:#}"))
+}
+
+#[tauri::command(async)]
+pub async fn list_watch_targets(app_state: State<'_, AppState>) -> Result<Vec<WatchEntry>, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    watchlist::list_watches(&pool)
+        .await
+        .map_err(|e| format!("Failed to list watch targets: {e:#}"))
+}
+
+/// Diff watched products against their snapshots without crawling
+#[tauri::command(async)]
+pub async fn check_watchlist_changes(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+) -> Result<WatchlistCheckReport, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    notify_watchlist_changes(&app, &pool).await
+}
+
+/// Re-crawl only the pages holding watched products, then report field changes
+#[tauri::command(async)]
+pub async fn run_watchlist_refresh(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+) -> Result<WatchlistRefreshReport, String> {
+    let pool = app_state
+        .get_database_pool()
+        .await
+        .map_err(|e| format!("DB pool unavailable: {e}"))?;
+    let watched = watchlist::watched_page_ids(&pool)
+        .await
+        .map_err(|e| format!("Failed to resolve watched pages: {e:#}"))?;
+    let max_page_id: Option<i64> = sqlx::query_scalar("SELECT MAX(page_id) FROM products")
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    let ranges = match max_page_id {
+        Some(max_page_id) => {
+            // total_pages ≈ max_pid + 1 → current physical page = total_pages - page_id
+            let pages: Vec<u32> = watched
+                .iter()
+                .map(|page_id| (max_page_id + 1 - page_id).max(1) as u32)
+                .collect();
+            range_expr(&pages)
+        }
+        None => String::new(),
+    };
+    info!(
+        "👀 Watchlist refresh: {} watched pages ranges=\"{}\"",
+        watched.len(),
+        ranges
+    );
+    if ranges.is_empty() {
+        return Ok(WatchlistRefreshReport {
+            ranges,
+            sync: None,
+            check: None,
+        });
+    }
+    let sync = start_partial_sync(app.clone(), app_state, ranges.clone(), Some(false), None).await?;
+    let check = notify_watchlist_changes(&app, &pool).await?;
+    Ok(WatchlistRefreshReport {
+        ranges,
+        sync: Some(sync),
+        check: Some(check),
+    })
+}
+
+/// Run the snapshot diff and emit `WatchlistChanged` when anything moved
+pub(crate) async fn notify_watchlist_changes(
+    app: &AppHandle,
+    pool: &SqlitePool,
+) -> Result<WatchlistCheckReport, String> {
+    let report = watchlist::check_changes(pool)
+        .await
+        .map_err(|e| format!("Watchlist check failed: {e:#}"))?;
+    if !report.changed.is_empty() {
+        info!(
+            "👀 {} watched products changed ({} watched)",
+            report.changed.len(),
+            report.watched_products
+        );
+        emit_actor_event(
+            app,
+            AppEvent::WatchlistChanged {
+                changes: report.changed.clone(),
+                timestamp: Utc::now(),
+            },
+        );
+    }
+    Ok(report)
+}
@@ -0,0 +1,153 @@
+//! 데이터베이스 워크스페이스 목록 / 생성 / 전환 명령어
+//!
+//! 워크스페이스마다 별도 SQLite 파일을 쓴다(`database_paths` 참고). 전환은 새 파일의 풀을 열고
+//! 마이그레이션한 뒤에야 경로를 바꾸므로 실패해도 현재 워크스페이스가 유지된다. 크롤/동기화 중에는
+//! 진행 중인 세션이 옛 풀에 쓰고 있으므로 거부한다. 풀을 붙잡는 프로세스 전역 객체(저장 큐, 스냅샷
+//! 리더, 차트 캐시)는 전환 때 비워 다음 사용 시 새 풀로 다시 만들어진다.
+
+use crate::application::AppState;
+use crate::application::shared_state::SharedStateCache;
+use crate::crawl_engine::runtime::sync_cancellation::SyncCancellationRegistry;
+use crate::infrastructure::database_connection;
+use crate::infrastructure::database_paths::{self, WorkspaceInfo};
+use crate::infrastructure::{persistence_queue, read_snapshot};
+use crate::services::analytics_cache::AnalyticsCache;
+use crate::services::startup_integrity;
+use anyhow::{Context, Result};
+use sqlx::SqlitePool;
+use tauri::{AppHandle, Manager, State};
+use tracing::{info, warn};
+
+/// Workspaces, default first; `active` marks the one the pool points at
+#[tauri::command(async)]
+pub async fn list_workspaces() -> Result<Vec<WorkspaceInfo>, String> {
+    database_paths::list_workspaces().map_err(|e| format!("Failed to list workspaces: {e:#}"))
+}
+
+/// Create an empty workspace without switching to it
+#[tauri::command(async)]
+pub async fn create_workspace(name: String) -> Result<WorkspaceInfo, String> {
+    database_paths::create_workspace(&name)
+        .await
+        .map_err(|e| format!("Failed to create workspace: {e:#}"))
+}
+
+/// Make `name` the active workspace and re-initialize the database pool against it
+#[tauri::command(async)]
+pub async fn switch_workspace(
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+    name: String,
+) -> Result<WorkspaceInfo, String> {
+    let running = SyncCancellationRegistry::shared().active_sessions();
+    if !running.is_empty() {
+        return Err(format!(
+            "Workspace cannot change while sync is running: {}",
+            running.join(", ")
+        ));
+    }
+    if app_state.is_crawling_active().await {
+        return Err("Workspace cannot change while a crawl is running".into());
+    }
+
+    let manager = database_paths::prepare_workspace(&name)
+        .await
+        .map_err(|e| format!("Failed to open workspace: {e:#}"))?;
+    let workspace = manager.workspace.clone();
+    if workspace != database_paths::active_workspace() {
+        let pool = swap_workspace_pool(&manager.get_main_database_url())
+            .await
+            .map_err(|e| format!("Failed to open workspace database: {e:#}"))?;
+        database_paths::activate_workspace(manager)
+            .await
+            .map_err(|e| format!("Failed to activate workspace: {e:#}"))?;
+        app_state.initialize_database_pool().await?;
+        // Cached analysis and the integrity banner describe the previous database
+        if let Some(cache) = app.try_state::<SharedStateCache>() {
+            cache.clear_all_caches().await;
+        }
+        if let Err(e) = startup_integrity::run_and_cache(&pool).await {
+            warn!("Integrity check after workspace switch failed: {}", e);
+        }
+        info!("🗂️ Switched to workspace '{}'", workspace);
+    }
+
+    database_paths::list_workspaces()
+        .map_err(|e| format!("Failed to list workspaces: {e:#}"))?
+        .into_iter()
+        .find(|w| w.name == workspace)
+        .ok_or_else(|| format!("Workspace disappeared: {workspace}"))
+}
+
+/// Point the global pool at `database_url`. The process-wide persistence queue and snapshot
+/// reader hold the previous pool, so they are flushed and dropped first (the old pool closes on
+/// replace); the chart cache is emptied because its versions are per database.
+async fn swap_workspace_pool(database_url: &str) -> Result<SqlitePool> {
+    persistence_queue::reset_shared_queue()
+        .await
+        .context("Failed to flush the persistence queue into the previous workspace")?;
+    read_snapshot::reset_shared_snapshot_reader().await;
+    let pool = database_connection::replace_global_pool(database_url).await?;
+    AnalyticsCache::reset_shared();
+    Ok(pool)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::ProductDetail;
+    use std::sync::Arc;
+    use tempfile::tempdir;
+
+    fn detail(url: &str) -> ProductDetail {
+        serde_json::from_value(serde_json::json!({
+            "url": url,
+            "page_id": 0,
+            "index_in_page": 0,
+            "created_at": chrono::Utc::now(),
+            "updated_at": chrono::Utc::now(),
+        }))
+        .unwrap()
+    }
+
+    async fn detail_count(pool: &SqlitePool, url: &str) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM product_details WHERE url = ?")
+            .bind(url)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn queue_writes_land_in_the_new_workspace_after_a_switch() {
+        let dir = tempdir().unwrap();
+        let old_url = format!("sqlite:{}", dir.path().join("old.db").display());
+        let new_url = format!("sqlite:{}", dir.path().join("new.db").display());
+
+        let old_pool = swap_workspace_pool(&old_url).await.unwrap();
+        let pending = persistence_queue::shared_queue(&old_pool);
+        pending
+            .enqueue([detail("https://x.test/p/old/")])
+            .await
+            .unwrap();
+
+        let new_pool = swap_workspace_pool(&new_url).await.unwrap();
+        // Rows queued before the switch were written to the old workspace on the way out
+        assert_eq!(pending.depth(), 0);
+        let queue = persistence_queue::shared_queue(&new_pool);
+        assert!(!Arc::ptr_eq(&queue, &pending));
+        queue
+            .enqueue([detail("https://x.test/p/new/")])
+            .await
+            .unwrap();
+        queue.flush().await.unwrap();
+        assert_eq!(detail_count(&new_pool, "https://x.test/p/new/").await, 1);
+        assert_eq!(detail_count(&new_pool, "https://x.test/p/old/").await, 0);
+
+        let old = database_connection::DatabaseConnection::new(&old_url)
+            .await
+            .unwrap();
+        assert_eq!(detail_count(old.pool(), "https://x.test/p/old/").await, 1);
+        assert_eq!(detail_count(old.pool(), "https://x.test/p/new/").await, 0);
+    }
+}
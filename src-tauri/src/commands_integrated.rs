@@ -1,12 +1,16 @@
 use crate::application::integrated_use_cases::IntegratedProductUseCases;
+use crate::commands::validation_commands::emit_actor_event;
+use crate::crawl_engine::actors::types::AppEvent;
+use crate::crawl_engine::runtime::command_dedup::CommandDedup;
+use crate::crawl_engine::runtime::sync_cancellation::SyncCancellationRegistry;
 use crate::domain::integrated_product::DatabaseStatistics;
 use crate::domain::product::ProductSearchCriteria;
 use crate::infrastructure::{
-    database_connection::DatabaseConnection,
+    database_connection::DatabaseConnection, database_paths, db_maintenance,
     integrated_product_repository::IntegratedProductRepository,
 };
 use std::sync::Arc;
-use tauri::State;
+use tauri::{AppHandle, State};
 use tracing::info;
 
 /// Get comprehensive database statistics from the integrated schema
@@ -108,13 +112,77 @@ pub async fn validate_integrated_database_integrity(
 #[tauri::command(async)]
 pub async fn reset_product_storage(
     db: State<'_, DatabaseConnection>,
+    app_state: State<'_, crate::application::AppState>,
 ) -> Result<(u64, u64), String> {
     let repo = IntegratedProductRepository::new(db.pool().clone());
+    let cfg = app_state.get_config().await.advanced.db_maintenance;
+    if cfg.backup_before_destructive {
+        database_paths::backup_database(db.pool(), "reset_product_storage", cfg.max_backups)
+            .await
+            .map_err(|e| format!("Backup before reset failed: {e:#}"))?;
+    }
+    let storage_before = db_maintenance::storage_snapshot(db.pool()).await.ok();
     match repo.clear_all_products_and_details().await {
         Ok((p, d)) => {
             info!("✅ Product storage reset: products={}, details={}", p, d);
+            if let Some(before) = storage_before {
+                db_maintenance::reclaim_after_deletion(
+                    db.pool(),
+                    &cfg,
+                    "reset_product_storage",
+                    p + d,
+                    &before,
+                )
+                .await;
+            }
             Ok((p, d))
         }
         Err(e) => Err(format!("Failed to reset product storage: {e}")),
     }
 }
+
+/// Manual maintenance: VACUUM, ANALYZE, integrity_check and REINDEX in sequence.
+/// Emits `actor-db-maintenance-progress` per step; refused while a sync session is running.
+#[tauri::command(async)]
+pub async fn run_db_maintenance(
+    app: AppHandle,
+    db: State<'_, DatabaseConnection>,
+) -> Result<db_maintenance::DbMaintenanceReport, String> {
+    CommandDedup::shared()
+        .run("run_db_maintenance", &(), || db_maintenance_run(app, db))
+        .await
+}
+
+async fn db_maintenance_run(
+    app: AppHandle,
+    db: State<'_, DatabaseConnection>,
+) -> Result<db_maintenance::DbMaintenanceReport, String> {
+    let running = SyncCancellationRegistry::shared().active_sessions();
+    if !running.is_empty() {
+        return Err(format!(
+            "Database maintenance unavailable while sync is running: {}",
+            running.join(", ")
+        ));
+    }
+    let report = db_maintenance::run_maintenance(db.pool(), |p| {
+        emit_actor_event(
+            &app,
+            AppEvent::DbMaintenanceProgress {
+                step: p.step,
+                index: p.index,
+                total: p.total,
+                phase: p.phase.to_string(),
+                elapsed_ms: p.elapsed_ms,
+                detail: p.detail.map(str::to_string),
+                timestamp: chrono::Utc::now(),
+            },
+        );
+    })
+    .await
+    .map_err(|e| format!("Database maintenance failed: {e}"))?;
+    info!(
+        "✅ Database maintenance: {} -> {} bytes in {}ms",
+        report.size_before_bytes, report.size_after_bytes, report.total_ms
+    );
+    Ok(report)
+}
@@ -19,11 +19,12 @@ pub mod config;
 pub mod events;
 pub mod runtime;
 pub mod services; // session registry & runtime helpers
+pub mod stage_type; // 채널/Actor 공용 StageType
 pub mod stages; // Phase 3: StageLogic strategies
 
 // 🔄 Phase 4: 타입 동기화 및 ts-rs 통합 (새로 추가)
 pub mod ts_gen;
-pub mod validation; // MI-2 Validation skeleton
+pub mod validation; // MI-2 DB cross-check (products ↔ product_details) + findings table
 
 // Re-exports for compatibility - 명시적 export로 ambiguous glob 문제 해결
 pub use context::AppContext;
@@ -5,16 +5,182 @@
 //! 낮은 복잡성의 구현으로도 모든 경우를 다 커버할 수 있도록 함
 
 use crate::crawl_engine::actors::types::{AppEvent, SimpleMetrics};
+use crate::crawl_engine::runtime::task_registry::spawn_tracked;
+use crate::crawl_engine::system_config::{BridgeOverflowPolicy, SystemConfig};
 use crate::domain::events::CrawlingEvent;
 use crate::infrastructure::features::feature_events_generalized_only;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::sync::{
-    Arc,
-    atomic::{AtomicU64, Ordering},
+    Arc, Mutex, RwLock,
+    atomic::{AtomicBool, AtomicU64, Ordering},
 };
-use tauri::{AppHandle, Emitter};
-use tokio::sync::broadcast;
+use tauri::{AppHandle, Emitter, EventTarget};
+use tokio::sync::{Notify, broadcast};
 use tracing::{debug, error, info, warn};
 
+/// 브릿지 대기열/드롭 통계 (프로세스 전역, 모든 브릿지 인스턴스 누적)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EventBridgeStats {
+    pub active_bridges: u32,
+    pub queue_capacity: usize,
+    pub overflow_policy: Option<BridgeOverflowPolicy>,
+    pub queue_depth: usize,
+    pub queue_high_watermark: usize,
+    pub received_total: u64,
+    pub forwarded_total: u64,
+    /// 오버플로 정책 또는 emit 실패로 버려진 이벤트 총합
+    pub dropped_total: u64,
+    /// 이벤트명(actor-*) 기준 드롭 카운트
+    pub dropped_by_type: BTreeMap<String, u64>,
+    /// broadcast 수신 지연(Lagged)으로 유실된 이벤트 수 (타입 식별 불가)
+    pub lagged_total: u64,
+    /// Tauri emit 실패 건수 (창이 닫힌 경우 등)
+    pub emit_failures: u64,
+}
+
+static BRIDGE_STATS: Lazy<Mutex<EventBridgeStats>> =
+    Lazy::new(|| Mutex::new(EventBridgeStats::default()));
+
+fn with_stats(f: impl FnOnce(&mut EventBridgeStats)) {
+    if let Ok(mut stats) = BRIDGE_STATS.lock() {
+        f(&mut stats);
+    }
+}
+
+fn record_drop(event_name: &str) {
+    with_stats(|s| {
+        s.dropped_total += 1;
+        *s.dropped_by_type.entry(event_name.to_string()).or_insert(0) += 1;
+    });
+}
+
+/// 현재 브릿지 통계 스냅샷
+pub fn event_bridge_stats() -> EventBridgeStats {
+    BRIDGE_STATS.lock().map(|s| s.clone()).unwrap_or_default()
+}
+
+/// Slim IPC 모드가 켜진 창(webview window label) 목록
+static SLIM_IPC_WINDOWS: Lazy<RwLock<BTreeSet<String>>> =
+    Lazy::new(|| RwLock::new(BTreeSet::new()));
+
+/// Enable/disable slim IPC payloads for one window. Slim windows receive heavy
+/// stage results as references (counts + ids) and fetch details via query commands.
+pub fn set_slim_ipc_window(label: &str, enabled: bool) {
+    if let Ok(mut windows) = SLIM_IPC_WINDOWS.write() {
+        if enabled {
+            windows.insert(label.to_string());
+        } else {
+            windows.remove(label);
+        }
+    }
+}
+
+/// Whether `label` currently receives slim payloads
+pub fn is_slim_ipc_window(label: &str) -> bool {
+    SLIM_IPC_WINDOWS
+        .read()
+        .map(|w| w.contains(label))
+        .unwrap_or(false)
+}
+
+fn any_slim_ipc_window() -> bool {
+    SLIM_IPC_WINDOWS
+        .read()
+        .map(|w| !w.is_empty())
+        .unwrap_or(false)
+}
+
+fn target_is_slim(target: &EventTarget) -> bool {
+    match target {
+        EventTarget::AnyLabel { label }
+        | EventTarget::Window { label }
+        | EventTarget::Webview { label }
+        | EventTarget::WebviewWindow { label } => is_slim_ipc_window(label),
+        _ => false,
+    }
+}
+
+/// Replace heavy fields of a flattened actor payload with references.
+/// `result.details` (per-item results carrying ProductDetail JSON) becomes
+/// `result.details_ref { count, item_ids, failed_item_ids }`; returns true if anything changed.
+pub fn slim_actor_payload(payload: &mut Value) -> bool {
+    let Some(result) = payload.get_mut("result").and_then(Value::as_object_mut) else {
+        return false;
+    };
+    let Some(Value::Array(details)) = result.remove("details") else {
+        return false;
+    };
+    let item_id = |d: &Value| d.get("item_id").and_then(Value::as_str).map(str::to_string);
+    let item_ids: Vec<String> = details.iter().filter_map(item_id).collect();
+    let failed_item_ids: Vec<String> = details
+        .iter()
+        .filter(|d| d.get("success").and_then(Value::as_bool) == Some(false))
+        .filter_map(item_id)
+        .collect();
+    result.insert(
+        "details_ref".into(),
+        serde_json::json!({
+            "count": details.len(),
+            "item_ids": item_ids,
+            "failed_item_ids": failed_item_ids,
+        }),
+    );
+    if let Some(obj) = payload.as_object_mut() {
+        obj.insert("slim".into(), Value::Bool(true));
+    }
+    true
+}
+
+/// 오버플로 정책이 적용되는 bounded FIFO
+#[derive(Debug)]
+pub struct BoundedEventQueue {
+    items: VecDeque<AppEvent>,
+    capacity: usize,
+    policy: BridgeOverflowPolicy,
+}
+
+impl BoundedEventQueue {
+    pub fn new(capacity: usize, policy: BridgeOverflowPolicy) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            items: VecDeque::with_capacity(capacity.min(1024)),
+            capacity,
+            policy,
+        }
+    }
+
+    /// 이벤트를 적재하고, 정책에 의해 버려진 이벤트가 있으면 반환
+    pub fn push(&mut self, event: AppEvent) -> Option<AppEvent> {
+        if self.items.len() < self.capacity {
+            self.items.push_back(event);
+            return None;
+        }
+        match self.policy {
+            BridgeOverflowPolicy::DropOldest => {
+                let dropped = self.items.pop_front();
+                self.items.push_back(event);
+                dropped
+            }
+            BridgeOverflowPolicy::DropNewest => Some(event),
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<AppEvent> {
+        self.items.pop_front()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
 /// Actor 이벤트를 프론트엔드로 전달하는 브릿지
 pub struct ActorEventBridge {
     /// Tauri AppHandle
@@ -25,16 +191,46 @@ pub struct ActorEventBridge {
     is_active: Arc<std::sync::atomic::AtomicBool>,
     /// 단조 증가 시퀀스 번호
     seq: Arc<AtomicU64>,
+    /// 내부 대기열 최대 길이
+    queue_capacity: usize,
+    /// 대기열 포화 시 정책
+    overflow_policy: BridgeOverflowPolicy,
+    /// 이 깊이에 닿으면 경고 (`channels.bridge_warn_watermark`)
+    warn_depth: usize,
 }
 
 impl ActorEventBridge {
     /// 새로운 브릿지 생성
     pub fn new(app_handle: AppHandle, event_rx: broadcast::Receiver<AppEvent>) -> Self {
+        let channels = SystemConfig::current().channels.clone();
+        let mut bridge = Self::with_overflow_policy(
+            app_handle,
+            event_rx,
+            channels.bridge_queue_capacity,
+            channels.bridge_overflow_policy,
+        );
+        bridge.warn_depth = warn_depth(
+            channels.bridge_queue_capacity,
+            channels.bridge_warn_watermark,
+        );
+        bridge
+    }
+
+    /// 대기열 크기/오버플로 정책을 지정하여 브릿지 생성
+    pub fn with_overflow_policy(
+        app_handle: AppHandle,
+        event_rx: broadcast::Receiver<AppEvent>,
+        queue_capacity: usize,
+        overflow_policy: BridgeOverflowPolicy,
+    ) -> Self {
         Self {
             app_handle,
             event_rx,
             is_active: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             seq: Arc::new(AtomicU64::new(1)),
+            queue_capacity,
+            overflow_policy,
+            warn_depth: queue_capacity,
         }
     }
 
@@ -48,27 +244,105 @@ impl ActorEventBridge {
             return;
         }
 
-        info!("🌉 Starting Actor Event Bridge - connecting Actor events to Frontend");
+        info!(
+            "🌉 Starting Actor Event Bridge - connecting Actor events to Frontend (queue_capacity={}, policy={:?})",
+            self.queue_capacity, self.overflow_policy
+        );
+        with_stats(|s| {
+            s.active_bridges += 1;
+            s.queue_capacity = self.queue_capacity;
+            s.overflow_policy = Some(self.overflow_policy);
+        });
 
-        while self.is_active.load(std::sync::atomic::Ordering::SeqCst) {
-            match self.event_rx.recv().await {
-                Ok(actor_event) => {
-                    debug!("[BridgeRecv] received AppEvent variant (pre-forward)");
-                    if let Err(e) = self.forward_to_frontend(actor_event).await {
-                        error!("Failed to forward Actor event to Frontend: {}", e);
+        // Intake task: broadcast → bounded queue. Never waits on the frontend, so a slow
+        // or closed webview cannot make the broadcast receiver lag silently.
+        let queue = Arc::new(Mutex::new(BoundedEventQueue::new(
+            self.queue_capacity,
+            self.overflow_policy,
+        )));
+        let notify = Arc::new(Notify::new());
+        let intake_closed = Arc::new(AtomicBool::new(false));
+        let (_, placeholder_rx) = broadcast::channel::<AppEvent>(1);
+        let mut event_rx = std::mem::replace(&mut self.event_rx, placeholder_rx);
+        let intake = {
+            let queue = queue.clone();
+            let notify = notify.clone();
+            let intake_closed = intake_closed.clone();
+            let is_active = self.is_active.clone();
+            let warn_depth = self.warn_depth;
+            spawn_tracked("actor-event-bridge-intake", None, async move {
+                let mut above_watermark = false;
+                while is_active.load(Ordering::SeqCst) {
+                    match event_rx.recv().await {
+                        Ok(actor_event) => {
+                            debug!("[BridgeRecv] received AppEvent variant (pre-forward)");
+                            let (dropped, depth) = match queue.lock() {
+                                Ok(mut q) => (q.push(actor_event), q.len()),
+                                Err(_) => break,
+                            };
+                            with_stats(|s| {
+                                s.received_total += 1;
+                                s.queue_depth = depth;
+                                s.queue_high_watermark = s.queue_high_watermark.max(depth);
+                            });
+                            if let Some(dropped) = dropped {
+                                record_drop(frontend_event_name(&dropped));
+                            }
+                            if depth >= warn_depth && !above_watermark {
+                                warn!(
+                                    "Actor event bridge queue at {} (warn watermark {}); frontend is falling behind",
+                                    depth, warn_depth
+                                );
+                            }
+                            above_watermark = depth >= warn_depth;
+                            notify.notify_one();
+                        }
+                        Err(broadcast::error::RecvError::Closed) => {
+                            info!("Actor event channel closed, stopping bridge");
+                            break;
+                        }
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            warn!("Actor event bridge lagged, skipped {} events", skipped);
+                            with_stats(|s| s.lagged_total += skipped);
+                        }
                     }
                 }
-                Err(broadcast::error::RecvError::Closed) => {
-                    info!("Actor event channel closed, stopping bridge");
+                intake_closed.store(true, Ordering::SeqCst);
+                notify.notify_one();
+            })
+        };
+
+        // Forward loop: drain the queue to the frontend
+        while self.is_active.load(std::sync::atomic::Ordering::SeqCst) {
+            let next = queue.lock().ok().and_then(|mut q| {
+                let ev = q.pop();
+                let depth = q.len();
+                with_stats(|s| s.queue_depth = depth);
+                ev
+            });
+            let Some(actor_event) = next else {
+                if intake_closed.load(Ordering::SeqCst) {
                     break;
                 }
-                Err(broadcast::error::RecvError::Lagged(skipped)) => {
-                    warn!("Actor event bridge lagged, skipped {} events", skipped);
-                    continue;
+                notify.notified().await;
+                continue;
+            };
+            let event_name = frontend_event_name(&actor_event);
+            match self.forward_to_frontend(actor_event).await {
+                Ok(()) => with_stats(|s| s.forwarded_total += 1),
+                Err(e) => {
+                    error!("Failed to forward Actor event to Frontend: {}", e);
+                    with_stats(|s| s.emit_failures += 1);
+                    record_drop(event_name);
                 }
             }
         }
 
+        intake.abort();
+        with_stats(|s| {
+            s.active_bridges = s.active_bridges.saturating_sub(1);
+            s.queue_depth = 0;
+        });
         self.is_active
             .store(false, std::sync::atomic::Ordering::SeqCst);
         info!("🌉 Actor Event Bridge stopped");
@@ -105,12 +379,14 @@ impl ActorEventBridge {
             }
             v
         };
+        #[cfg(feature = "event-stream")]
+        crate::infrastructure::event_stream::publish(&event_name, &enriched);
+        #[cfg(feature = "metrics-exporter")]
+        crate::infrastructure::metrics_exporter::observe_event(&actor_event);
         // Generalized-only 모드: 단일 채널로 통일된 이벤트를 방출하고 종료
         if feature_events_generalized_only() {
             let unified_name = "actor-event";
-            self.app_handle
-                .emit(unified_name, &enriched)
-                .map_err(|e| format!("Tauri emit failed: {}", e))?;
+            self.emit_with_payload_mode(unified_name, &enriched)?;
             // Also write a concise info-level line to events.log so stage/page/detail events are visible
             if let Some(obj) = enriched.as_object() {
                 let variant = obj.get("variant").and_then(|v| v.as_str()).unwrap_or("?");
@@ -160,6 +436,23 @@ impl ActorEventBridge {
                         phase, group_size, started, succeeded, failed, duplicates, page_number, batch_id, duration_ms, session_id
                     );
                 }
+                AppEvent::BatchProgressRollup {
+                    session_id,
+                    batch_id,
+                    stage_type,
+                    pages_total,
+                    pages_listed,
+                    urls_queued,
+                    details_fetched,
+                    validated,
+                    saved,
+                    ..
+                } => {
+                    tracing::info!(target: "actor-event",
+                        "[BatchRollup] stage={} pages={}/{} urls={} details={} validated={} saved={} batch={} session={}",
+                        stage_type.as_str(), pages_listed, pages_total, urls_queued, details_fetched, validated, saved, batch_id, session_id
+                    );
+                }
                 // DetailTask* events deprecated and no longer emitted
                 AppEvent::DatabaseStats {
                     session_id,
@@ -235,9 +528,7 @@ impl ActorEventBridge {
         }
 
         // 레거시 호환: 기존 이벤트명으로 전송
-        self.app_handle
-            .emit(&event_name, &enriched)
-            .map_err(|e| format!("Tauri emit failed: {}", e))?;
+        self.emit_with_payload_mode(&event_name, &enriched)?;
 
         debug!("✅ Forwarded Actor event '{}' to Frontend", event_name);
         // Always emit a concise info-level line so users see forwarding even if debug is filtered.
@@ -337,6 +628,27 @@ impl ActorEventBridge {
         Ok(())
     }
 
+    /// Emit full payloads to regular windows and slimmed payloads to slim-mode windows
+    fn emit_with_payload_mode(&self, event_name: &str, payload: &Value) -> Result<(), String> {
+        if !any_slim_ipc_window() {
+            return self
+                .app_handle
+                .emit(event_name, payload)
+                .map_err(|e| format!("Tauri emit failed: {}", e));
+        }
+        let mut slim = payload.clone();
+        if !slim_actor_payload(&mut slim) {
+            return self
+                .app_handle
+                .emit(event_name, payload)
+                .map_err(|e| format!("Tauri emit failed: {}", e));
+        }
+        self.app_handle
+            .emit_filter(event_name, payload, |t| !target_is_slim(t))
+            .and_then(|_| self.app_handle.emit_filter(event_name, &slim, target_is_slim))
+            .map_err(|e| format!("Tauri emit failed: {}", e))
+    }
+
     /// AppEvent를 프론트엔드 이벤트로 변환
     fn convert_actor_event_to_frontend(
         &self,
@@ -344,57 +656,7 @@ impl ActorEventBridge {
     ) -> Result<(String, serde_json::Value), String> {
         use serde_json::{Map, Value};
         // Determine event name (use .. to ignore future fields)
-        let event_name = match &event {
-            AppEvent::SessionStarted { .. } => "actor-session-started",
-            AppEvent::SessionPaused { .. } => "actor-session-paused",
-            AppEvent::SessionResumed { .. } => "actor-session-resumed",
-            AppEvent::SessionCompleted { .. } => "actor-session-completed",
-            AppEvent::NextPlanReady { .. } => "actor-next-plan-ready",
-            AppEvent::SessionFailed { .. } => "actor-session-failed",
-            AppEvent::SessionTimeout { .. } => "actor-session-timeout",
-            AppEvent::BatchStarted { .. } => "actor-batch-started",
-            AppEvent::BatchCompleted { .. } => "actor-batch-completed",
-            AppEvent::BatchFailed { .. } => "actor-batch-failed",
-            AppEvent::StageStarted { .. } => "actor-stage-started",
-            AppEvent::StageCompleted { .. } => "actor-stage-completed",
-            AppEvent::StageFailed { .. } => "actor-stage-failed",
-            AppEvent::StageRetrying { .. } => "actor-stage-retrying",
-            AppEvent::Progress { .. } => "actor-progress",
-            AppEvent::PerformanceMetrics { .. } => "actor-performance-metrics",
-            AppEvent::BatchReport { .. } => "actor-batch-report",
-            AppEvent::CrawlReportSession { .. } => "actor-session-report",
-            AppEvent::PhaseStarted { .. } => "actor-phase-started",
-            AppEvent::PhaseCompleted { .. } => "actor-phase-completed",
-            AppEvent::PhaseAborted { .. } => "actor-phase-aborted",
-            AppEvent::ShutdownRequested { .. } => "actor-shutdown-requested",
-            AppEvent::ShutdownCompleted { .. } => "actor-shutdown-completed",
-            AppEvent::PageTaskStarted { .. } => "actor-page-task-started",
-            AppEvent::PageTaskCompleted { .. } => "actor-page-task-completed",
-            AppEvent::PageTaskFailed { .. } => "actor-page-task-failed",
-            // DetailTask* and detail concurrency downshift events removed
-            AppEvent::StageItemStarted { .. } => "actor-stage-item-started",
-            AppEvent::StageItemCompleted { .. } => "actor-stage-item-completed",
-            AppEvent::PageLifecycle { .. } => "actor-page-lifecycle",
-            AppEvent::ProductLifecycle { .. } => "actor-product-lifecycle",
-            &AppEvent::ProductLifecycleGroup { .. } => "actor-product-lifecycle-group",
-            &AppEvent::HttpRequestTiming { .. } => "actor-http-request-timing",
-            AppEvent::PreflightDiagnostics { .. } => "actor-preflight-diagnostics",
-            AppEvent::PersistenceAnomaly { .. } => "actor-persistence-anomaly",
-            AppEvent::DatabaseStats { .. } => "actor-database-stats",
-            AppEvent::ValidationStarted { .. } => "actor-validation-started",
-            AppEvent::ValidationPageScanned { .. } => "actor-validation-page-scanned",
-            AppEvent::ValidationDivergenceFound { .. } => "actor-validation-divergence",
-            AppEvent::ValidationAnomaly { .. } => "actor-validation-anomaly",
-            AppEvent::ValidationCompleted { .. } => "actor-validation-completed",
-            // Sync events
-            AppEvent::SyncStarted { .. } => "actor-sync-started",
-            AppEvent::SyncPageStarted { .. } => "actor-sync-page-started",
-            AppEvent::SyncUpsertProgress { .. } => "actor-sync-upsert-progress",
-            AppEvent::SyncPageCompleted { .. } => "actor-sync-page-completed",
-            AppEvent::SyncWarning { .. } => "actor-sync-warning",
-            AppEvent::SyncRetrying { .. } => "actor-sync-retrying",
-            AppEvent::SyncCompleted { .. } => "actor-sync-completed",
-        };
+        let event_name = frontend_event_name(&event);
 
         let raw = serde_json::to_value(&event)
             .map_err(|e| format!("Failed to serialize Actor event: {}", e))?;
@@ -559,6 +821,79 @@ impl ActorEventBridge {
     }
 }
 
+/// AppEvent variant → 프론트엔드 이벤트명 매핑
+pub fn frontend_event_name(event: &AppEvent) -> &'static str {
+    match event {
+        AppEvent::SessionStarted { .. } => "actor-session-started",
+        AppEvent::SessionPaused { .. } => "actor-session-paused",
+        AppEvent::SessionResumed { .. } => "actor-session-resumed",
+        AppEvent::SessionCompleted { .. } => "actor-session-completed",
+        AppEvent::NextPlanReady { .. } => "actor-next-plan-ready",
+        AppEvent::SessionReportReady { .. } => "actor-session-report-ready",
+        AppEvent::SessionFailed { .. } => "actor-session-failed",
+        AppEvent::SessionTimeout { .. } => "actor-session-timeout",
+        AppEvent::BatchStarted { .. } => "actor-batch-started",
+        AppEvent::BatchCompleted { .. } => "actor-batch-completed",
+        AppEvent::BatchFailed { .. } => "actor-batch-failed",
+        AppEvent::StageStarted { .. } => "actor-stage-started",
+        AppEvent::StageCompleted { .. } => "actor-stage-completed",
+        AppEvent::StageFailed { .. } => "actor-stage-failed",
+        AppEvent::StageRetrying { .. } => "actor-stage-retrying",
+        AppEvent::Progress { .. } => "actor-progress",
+        AppEvent::PerformanceMetrics { .. } => "actor-performance-metrics",
+        AppEvent::BatchReport { .. } => "actor-batch-report",
+        AppEvent::BatchProgressRollup { .. } => "actor-batch-progress-rollup",
+        AppEvent::CrawlReportSession { .. } => "actor-session-report",
+        AppEvent::PhaseStarted { .. } => "actor-phase-started",
+        AppEvent::PhaseCompleted { .. } => "actor-phase-completed",
+        AppEvent::PhaseAborted { .. } => "actor-phase-aborted",
+        AppEvent::ShutdownRequested { .. } => "actor-shutdown-requested",
+        AppEvent::ShutdownCompleted { .. } => "actor-shutdown-completed",
+        AppEvent::PageTaskStarted { .. } => "actor-page-task-started",
+        AppEvent::PageTaskCompleted { .. } => "actor-page-task-completed",
+        AppEvent::PageTaskFailed { .. } => "actor-page-task-failed",
+        // DetailTask* and detail concurrency downshift events removed
+        AppEvent::StageItemStarted { .. } => "actor-stage-item-started",
+        AppEvent::StageItemCompleted { .. } => "actor-stage-item-completed",
+        AppEvent::PageLifecycle { .. } => "actor-page-lifecycle",
+        AppEvent::ProductLifecycle { .. } => "actor-product-lifecycle",
+        AppEvent::ProductLifecycleGroup { .. } => "actor-product-lifecycle-group",
+        AppEvent::HttpRequestTiming { .. } => "actor-http-request-timing",
+        AppEvent::PreflightDiagnostics { .. } => "actor-preflight-diagnostics",
+        AppEvent::PersistenceAnomaly { .. } => "actor-persistence-anomaly",
+        AppEvent::DatabaseStats { .. } => "actor-database-stats",
+        AppEvent::ValidationStarted { .. } => "actor-validation-started",
+        AppEvent::ValidationPageScanned { .. } => "actor-validation-page-scanned",
+        AppEvent::ValidationDivergenceFound { .. } => "actor-validation-divergence",
+        AppEvent::ValidationAnomaly { .. } => "actor-validation-anomaly",
+        AppEvent::ValidationCompleted { .. } => "actor-validation-completed",
+        // Sync events
+        AppEvent::SyncStarted { .. } => "actor-sync-started",
+        AppEvent::SyncPageStarted { .. } => "actor-sync-page-started",
+        AppEvent::SyncUpsertProgress { .. } => "actor-sync-upsert-progress",
+        AppEvent::SyncPageCompleted { .. } => "actor-sync-page-completed",
+        AppEvent::SyncWarning { .. } => "actor-sync-warning",
+        AppEvent::SyncRetrying { .. } => "actor-sync-retrying",
+        AppEvent::SyncCompleted { .. } => "actor-sync-completed",
+        AppEvent::SyncAborted { .. } => "actor-sync-aborted",
+        AppEvent::SyncPaused { .. } => "actor-sync-paused",
+        AppEvent::LightSyncReport { .. } => "actor-light-sync-report",
+        AppEvent::DbMaintenanceProgress { .. } => "actor-db-maintenance-progress",
+        AppEvent::WatchlistChanged { .. } => "actor-watchlist-changed",
+        AppEvent::SiteCircuitOpened { .. } => "actor-site-circuit-opened",
+        AppEvent::SiteCircuitClosed { .. } => "actor-site-circuit-closed",
+        AppEvent::DataIntegritySnapshot { .. } => "actor-data-integrity-snapshot",
+        AppEvent::ActorRestarted { .. } => "actor-actor-restarted",
+        AppEvent::SessionStalled { .. } => "actor-session-stalled",
+        AppEvent::ConcurrencyAdjusted { .. } => "actor-concurrency-adjusted",
+    }
+}
+
+/// 경고 깊이 = 용량 × 워터마크 (최소 1)
+fn warn_depth(capacity: usize, watermark: f64) -> usize {
+    ((capacity as f64 * watermark).ceil() as usize).clamp(1, capacity.max(1))
+}
+
 /// Actor Event Bridge 시작 유틸리티 함수
 pub async fn start_actor_event_bridge(
     app_handle: AppHandle,
@@ -566,10 +901,97 @@ pub async fn start_actor_event_bridge(
 ) -> Result<tokio::task::JoinHandle<()>, String> {
     let mut bridge = ActorEventBridge::new(app_handle, event_rx);
 
-    let handle = tokio::spawn(async move {
+    let handle = spawn_tracked("actor-event-bridge", None, async move {
         bridge.start().await;
     });
 
     info!("🌉 Actor Event Bridge task spawned");
     Ok(handle)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ev(session_id: &str) -> AppEvent {
+        AppEvent::SessionResumed {
+            session_id: session_id.to_string(),
+            timestamp: chrono::Utc::now(),
+        }
+    }
+
+    fn session_of(event: &AppEvent) -> &str {
+        match event {
+            AppEvent::SessionResumed { session_id, .. } => session_id,
+            _ => "",
+        }
+    }
+
+    #[test]
+    fn drop_oldest_evicts_head_when_full() {
+        let mut q = BoundedEventQueue::new(2, BridgeOverflowPolicy::DropOldest);
+        assert!(q.push(ev("a")).is_none());
+        assert!(q.push(ev("b")).is_none());
+        let dropped = q.push(ev("c")).expect("oldest should be dropped");
+        assert_eq!(session_of(&dropped), "a");
+        assert_eq!(q.len(), 2);
+        assert_eq!(
+            q.pop().map(|e| session_of(&e).to_string()),
+            Some("b".into())
+        );
+    }
+
+    #[test]
+    fn drop_newest_rejects_incoming_when_full() {
+        let mut q = BoundedEventQueue::new(1, BridgeOverflowPolicy::DropNewest);
+        assert!(q.push(ev("a")).is_none());
+        let dropped = q.push(ev("b")).expect("incoming should be dropped");
+        assert_eq!(session_of(&dropped), "b");
+        assert_eq!(
+            q.pop().map(|e| session_of(&e).to_string()),
+            Some("a".into())
+        );
+        assert!(q.is_empty());
+    }
+
+    #[test]
+    fn event_name_is_used_for_drop_accounting() {
+        assert_eq!(frontend_event_name(&ev("x")), "actor-session-resumed");
+    }
+
+    #[test]
+    fn slim_payload_replaces_stage_details_with_refs() {
+        let mut payload = serde_json::json!({
+            "variant": "StageCompleted",
+            "result": {
+                "processed_items": 2,
+                "details": [
+                    {"item_id": "https://a", "success": true, "collected_data": "[{\"vid\":1}]"},
+                    {"item_id": "https://b", "success": false, "collected_data": null},
+                ],
+            },
+        });
+        assert!(slim_actor_payload(&mut payload));
+        assert_eq!(payload["slim"], true);
+        assert!(payload["result"].get("details").is_none());
+        assert_eq!(payload["result"]["processed_items"], 2);
+        assert_eq!(payload["result"]["details_ref"]["count"], 2);
+        assert_eq!(
+            payload["result"]["details_ref"]["failed_item_ids"],
+            serde_json::json!(["https://b"])
+        );
+
+        let mut light = serde_json::json!({"variant": "Progress", "percentage": 10.0});
+        assert!(!slim_actor_payload(&mut light));
+        assert!(light.get("slim").is_none());
+    }
+
+    #[test]
+    fn slim_window_registry_toggles_per_label() {
+        set_slim_ipc_window("slim-test-window", true);
+        assert!(is_slim_ipc_window("slim-test-window"));
+        assert!(!is_slim_ipc_window("main-test-window"));
+        set_slim_ipc_window("slim-test-window", false);
+        assert!(!is_slim_ipc_window("slim-test-window"));
+    }
+}
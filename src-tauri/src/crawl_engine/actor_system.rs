@@ -5,6 +5,10 @@ use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt;
 
+pub mod supervisor; // 하트비트 감시 + 재시작 정책
+
+pub use supervisor::{Heartbeat, RestartPolicy, SupervisionError, Supervisor};
+
 // SessionActor - 기존 코드 호환성
 #[derive(Debug, Clone)]
 pub struct SessionActor {
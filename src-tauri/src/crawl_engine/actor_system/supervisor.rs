@@ -0,0 +1,425 @@
+//! 액터 감독: 하트비트 감시 + 백오프 재시작 정책
+//!
+//! StageActor 가 패닉하거나 진행 없이 멈추면 세션 전체가 조용히 멈춰 있었다. `Supervisor::supervise`
+//! 는 액터 실행 한 번(시도)을 별도 태스크로 돌리며 패닉(JoinError)과 하트비트 정지를 감시하고,
+//! 실패하면 시도를 중단한 뒤 지수 백오프 후 새 액터로 다시 시작한다(`ActorRestarted`). 재시작을
+//! `max_restarts` 번 다 쓰면 세션을 최종 실패로 올린다: `SessionFailed` 를 내고 SessionActor 가
+//! 배치 사이에 `take_escalation` 으로 확인해 남은 배치를 건너뛴다. 액터가 돌려준 `Result` 는 그대로
+//! 전달하며, 재시작 대상은 패닉과 멈춤뿐이다. 세션 워치독은 `intervene` 으로 실행 중인 시도를 밖에서
+//! 재시작하거나 중단(세션 최종 실패)시킬 수 있다.
+
+use crate::crawl_engine::actors::types::AppEvent;
+use crate::crawl_engine::integrated_context::AppContext;
+use crate::crawl_engine::runtime::task_registry::{PAGE_WORKER_LIFETIME, spawn_tracked};
+use chrono::Utc;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::{JoinError, JoinHandle};
+use tracing::{error, warn};
+
+/// Upper bound on how often the heartbeat is checked
+const MAX_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Restart policy for supervised actors (`advanced.actor_supervision`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestartPolicy {
+    /// Restarts after the first attempt before the session fails
+    #[serde(default = "RestartPolicy::default_max_restarts")]
+    pub max_restarts: u32,
+    /// Wait before the first restart; doubles for each further restart
+    #[serde(default = "RestartPolicy::default_base_backoff_ms")]
+    pub base_backoff_ms: u64,
+    #[serde(default = "RestartPolicy::default_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+    /// An actor without a heartbeat for this long is stuck; 0 disables stall detection
+    #[serde(default = "RestartPolicy::default_heartbeat_timeout_secs")]
+    pub heartbeat_timeout_secs: u64,
+}
+
+impl RestartPolicy {
+    fn default_max_restarts() -> u32 {
+        3
+    }
+    fn default_base_backoff_ms() -> u64 {
+        1_000
+    }
+    fn default_max_backoff_ms() -> u64 {
+        30_000
+    }
+    fn default_heartbeat_timeout_secs() -> u64 {
+        600
+    }
+
+    /// Wait before restart `restart` (1 = first restart)
+    pub fn backoff(&self, restart: u32) -> Duration {
+        let factor = 2u64.saturating_pow(restart.max(1) - 1);
+        Duration::from_millis(
+            self.base_backoff_ms
+                .saturating_mul(factor)
+                .min(self.max_backoff_ms),
+        )
+    }
+
+    pub fn heartbeat_timeout(&self) -> Option<Duration> {
+        (self.heartbeat_timeout_secs > 0).then(|| Duration::from_secs(self.heartbeat_timeout_secs))
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: Self::default_max_restarts(),
+            base_backoff_ms: Self::default_base_backoff_ms(),
+            max_backoff_ms: Self::default_max_backoff_ms(),
+            heartbeat_timeout_secs: Self::default_heartbeat_timeout_secs(),
+        }
+    }
+}
+
+/// Liveness signal a supervised actor touches whenever it makes progress
+#[derive(Debug, Clone)]
+pub struct Heartbeat {
+    started: Instant,
+    last_beat_ms: Arc<AtomicU64>,
+}
+
+impl Default for Heartbeat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Heartbeat {
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            last_beat_ms: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    pub fn beat(&self) {
+        let now = self.started.elapsed().as_millis() as u64;
+        self.last_beat_ms.fetch_max(now, Ordering::Relaxed);
+    }
+
+    /// Time since the last beat (or since creation)
+    pub fn idle(&self) -> Duration {
+        let last = Duration::from_millis(self.last_beat_ms.load(Ordering::Relaxed));
+        self.started.elapsed().saturating_sub(last)
+    }
+}
+
+/// Why one attempt of a supervised actor ended
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ActorFailure {
+    #[error("panicked: {0}")]
+    Panicked(String),
+    #[error("no heartbeat for {idle_secs}s")]
+    Stalled { idle_secs: u64 },
+    #[error("task aborted")]
+    Aborted,
+    #[error("restart requested: {0}")]
+    RestartRequested(String),
+    #[error("stopped: {0}")]
+    Stopped(String),
+}
+
+impl ActorFailure {
+    fn from_join_error(e: JoinError) -> Self {
+        if !e.is_panic() {
+            return Self::Aborted;
+        }
+        let payload = e.into_panic();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| (*s).to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "non-string panic payload".into());
+        Self::Panicked(message)
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SupervisionError {
+    #[error("{actor_id} failed after {restarts} restarts: {last_failure}")]
+    RestartsExhausted {
+        actor_id: String,
+        restarts: u32,
+        last_failure: ActorFailure,
+    },
+    #[error("{actor_id} failed while the session was cancelled: {last_failure}")]
+    Cancelled {
+        actor_id: String,
+        last_failure: ActorFailure,
+    },
+    #[error("{actor_id} {last_failure}")]
+    Stopped {
+        actor_id: String,
+        last_failure: ActorFailure,
+    },
+}
+
+/// Outside request against the supervised actor a session is currently running
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Intervention {
+    /// Abort the attempt and start a fresh one (counts against `max_restarts`)
+    Restart(String),
+    /// Abort the attempt and fail the session
+    Stop(String),
+}
+
+impl From<Intervention> for ActorFailure {
+    fn from(intervention: Intervention) -> Self {
+        match intervention {
+            Intervention::Restart(reason) => Self::RestartRequested(reason),
+            Intervention::Stop(reason) => Self::Stopped(reason),
+        }
+    }
+}
+
+static INTERVENTIONS: Lazy<Mutex<HashMap<String, Intervention>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Ask the session's running supervised actor to restart or stop at its next check
+pub fn intervene(session_id: &str, intervention: Intervention) {
+    INTERVENTIONS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(session_id.to_string(), intervention);
+}
+
+/// Pending intervention for the session, if any (clears it)
+pub fn take_intervention(session_id: &str) -> Option<Intervention> {
+    INTERVENTIONS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(session_id)
+}
+
+/// Sessions whose supervised actors ran out of restarts, with the reason
+static ESCALATIONS: Lazy<Mutex<HashMap<String, String>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn record_escalation(session_id: &str, reason: String) {
+    ESCALATIONS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(session_id.to_string(), reason);
+}
+
+/// Reason the session must fail, if a supervised actor exhausted its restarts (clears it)
+pub fn take_escalation(session_id: &str) -> Option<String> {
+    ESCALATIONS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(session_id)
+}
+
+/// Runs actors under a restart policy; events and escalation go to the session in `context`
+#[derive(Clone)]
+pub struct Supervisor {
+    policy: RestartPolicy,
+    heartbeat_timeout: Option<Duration>,
+    context: Option<AppContext>,
+}
+
+impl Supervisor {
+    pub fn new(policy: RestartPolicy) -> Self {
+        Self {
+            heartbeat_timeout: policy.heartbeat_timeout(),
+            policy,
+            context: None,
+        }
+    }
+
+    pub fn with_context(mut self, context: AppContext) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// Run `start` until an attempt finishes without panicking or stalling.
+    /// Each attempt gets a fresh `Heartbeat` the actor must beat while it works.
+    pub async fn supervise<T, F, Fut>(
+        &self,
+        actor_id: &str,
+        mut start: F,
+    ) -> Result<T, SupervisionError>
+    where
+        F: FnMut(Heartbeat) -> Fut,
+        Fut: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let mut restarts = 0u32;
+        loop {
+            let heartbeat = Heartbeat::new();
+            let task = spawn_tracked(
+                format!("supervised:{actor_id}:{restarts}"),
+                Some(PAGE_WORKER_LIFETIME),
+                start(heartbeat.clone()),
+            );
+            let failure = match self.watch(task, &heartbeat).await {
+                Ok(output) => return Ok(output),
+                Err(failure) => failure,
+            };
+
+            if let ActorFailure::Stopped(_) = failure {
+                let err = SupervisionError::Stopped {
+                    actor_id: actor_id.to_string(),
+                    last_failure: failure,
+                };
+                error!("🛑 {}", err);
+                self.escalate(&err);
+                return Err(err);
+            }
+            if self.context.as_ref().is_some_and(|c| c.is_cancelled()) {
+                return Err(SupervisionError::Cancelled {
+                    actor_id: actor_id.to_string(),
+                    last_failure: failure,
+                });
+            }
+            if restarts >= self.policy.max_restarts {
+                let err = SupervisionError::RestartsExhausted {
+                    actor_id: actor_id.to_string(),
+                    restarts,
+                    last_failure: failure,
+                };
+                error!("🛑 {}", err);
+                self.escalate(&err);
+                return Err(err);
+            }
+
+            restarts += 1;
+            let backoff = self.policy.backoff(restarts);
+            warn!(
+                "♻️ Restarting {} ({}/{}) in {}ms: {}",
+                actor_id,
+                restarts,
+                self.policy.max_restarts,
+                backoff.as_millis(),
+                failure
+            );
+            if let Some(context) = &self.context {
+                let _ = context.emit_event(AppEvent::ActorRestarted {
+                    session_id: context.session_id.clone(),
+                    batch_id: context.batch_id.clone(),
+                    actor_id: actor_id.to_string(),
+                    restart: restarts,
+                    max_restarts: self.policy.max_restarts,
+                    reason: failure.to_string(),
+                    backoff_ms: backoff.as_millis() as u64,
+                    timestamp: Utc::now(),
+                });
+            }
+            tokio::time::sleep(backoff).await;
+        }
+    }
+
+    /// Wait for the attempt; abort it once the heartbeat has been silent too long
+    /// or the session asks for an intervention
+    async fn watch<T>(
+        &self,
+        mut task: JoinHandle<T>,
+        heartbeat: &Heartbeat,
+    ) -> Result<T, ActorFailure> {
+        let session_id = self.context.as_ref().map(|c| c.session_id.as_str());
+        let period = match (self.heartbeat_timeout, session_id) {
+            (None, None) => return task.await.map_err(ActorFailure::from_join_error),
+            (Some(limit), _) => (limit / 4).min(MAX_CHECK_INTERVAL),
+            (None, Some(_)) => MAX_CHECK_INTERVAL,
+        };
+        let mut check = tokio::time::interval(period);
+        loop {
+            tokio::select! {
+                joined = &mut task => return joined.map_err(ActorFailure::from_join_error),
+                _ = check.tick() => {
+                    if let Some(intervention) = session_id.and_then(take_intervention) {
+                        task.abort();
+                        return Err(intervention.into());
+                    }
+                    let idle = heartbeat.idle();
+                    if self.heartbeat_timeout.is_some_and(|limit| idle > limit) {
+                        task.abort();
+                        return Err(ActorFailure::Stalled { idle_secs: idle.as_secs() });
+                    }
+                }
+            }
+        }
+    }
+
+    fn escalate(&self, err: &SupervisionError) {
+        let Some(context) = &self.context else {
+            return;
+        };
+        record_escalation(&context.session_id, err.to_string());
+        let _ = context.emit_event(AppEvent::SessionFailed {
+            session_id: context.session_id.clone(),
+            error: err.to_string(),
+            final_failure: true,
+            timestamp: Utc::now(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+
+    #[tokio::test]
+    async fn restarts_panicked_and_stalled_attempts_until_the_policy_runs_out() {
+        let policy = RestartPolicy {
+            max_restarts: 2,
+            base_backoff_ms: 1,
+            max_backoff_ms: 4,
+            heartbeat_timeout_secs: 0,
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(1));
+        assert_eq!(policy.backoff(5), Duration::from_millis(4));
+        let supervisor = Supervisor {
+            heartbeat_timeout: Some(Duration::from_millis(40)),
+            ..Supervisor::new(policy)
+        };
+
+        // 1st attempt panics, 2nd stalls, 3rd succeeds
+        let attempts = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&attempts);
+        let output = supervisor
+            .supervise("stage_test", move |heartbeat| {
+                let attempt = counter.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    match attempt {
+                        0 => panic!("boom"),
+                        1 => std::future::pending::<u32>().await,
+                        _ => {
+                            heartbeat.beat();
+                            7
+                        }
+                    }
+                }
+            })
+            .await
+            .unwrap();
+        assert_eq!(output, 7);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        let err = supervisor
+            .supervise::<(), _, _>("stage_test", |_| async { panic!("always") })
+            .await
+            .unwrap_err();
+        match err {
+            SupervisionError::RestartsExhausted {
+                restarts,
+                last_failure,
+                ..
+            } => {
+                assert_eq!(restarts, 2);
+                assert_eq!(last_failure, ActorFailure::Panicked("always".into()));
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+}
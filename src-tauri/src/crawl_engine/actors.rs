@@ -10,8 +10,12 @@ pub mod contract;
 // pub mod details;
 #[path = "actors/session_actor.rs"]
 pub mod session_actor;
+#[path = "actors/session_watchdog.rs"]
+pub mod session_watchdog;
 #[path = "actors/stage_actor.rs"]
 pub mod stage_actor;
+#[path = "actors/stage_payload.rs"]
+pub mod stage_payload;
 #[path = "actors/traits.rs"]
 pub mod traits;
 #[path = "actors/types.rs"]
@@ -25,5 +29,5 @@ pub use stage_actor::StageActor;
 pub use traits::*;
 pub use types::{
     ActorCommand, ActorError, BatchConfig, CrawlingConfig, StageItem, StageItemResult,
-    StageItemType, StageResult, StageType,
+    StageItemType, StagePayload, StageResult, StageType,
 };
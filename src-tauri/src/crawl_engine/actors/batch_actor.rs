@@ -15,10 +15,18 @@ use tokio::sync::{Semaphore, mpsc};
 use tracing::{debug, error, info, warn};
 
 use super::traits::{Actor, ActorHealth, ActorStatus, ActorType};
-use super::types::{ActorCommand, ActorError, BatchConfig, StageResult, StageType};
+use super::types::{
+    ActorCommand, ActorError, BatchConfig, StageError, StageItemResult, StageItemType,
+    StagePayload, StageResult, StageType,
+};
+use crate::crawl_engine::actor_system::Supervisor;
 use crate::crawl_engine::actors::StageActor;
 use crate::crawl_engine::actors::types::AppEvent;
 use crate::crawl_engine::channels::types::{ProductUrls, StageItem};
+use crate::crawl_engine::runtime::crawl_limits;
+use crate::crawl_engine::runtime::site_hints::{self, PaginationHints};
+use crate::crawl_engine::services::detail_handoff::DetailHandoff;
+use crate::crawl_engine::stage_type::StageSelection;
 // use crate::new_architecture::{
 //     actor_system as actor_sys,
 //     channels::types as ch_types,
@@ -28,8 +36,10 @@ use crate::crawl_engine::context::AppContext;
 // real_crawling_integration provides inherent methods on BatchActor via extension impl; no direct import needed here.
 
 // 실제 서비스 imports 추가
+use crate::domain::product::ProductDetail;
 use crate::domain::services::SiteStatus;
-use crate::infrastructure::config::AppConfig;
+use crate::infrastructure::config::{AppConfig, MemoryBudgetConfig};
+use crate::infrastructure::persistence_queue;
 use crate::infrastructure::{HttpClient, IntegratedProductRepository, MatterDataExtractor};
 
 // Architecture note: StageActor is the single canonical execution path.
@@ -90,6 +100,10 @@ pub struct BatchActor {
     // Unified detail crawling accumulation
     collected_product_urls: Vec<crate::domain::product_url::ProductUrl>,
     defer_detail_crawling: bool,
+    /// 스테이지별 퍼널 카운트 (BatchProgressRollup 이벤트 소스)
+    stage_counts: BatchStageCounts,
+    /// 실행할 파이프라인 스테이지 (스킵 플래그 반영)
+    stage_selection: StageSelection,
 }
 
 // Debug 수동 구현 (의존성들이 Debug를 구현하지 않아서)
@@ -113,6 +127,23 @@ impl std::fmt::Debug for BatchActor {
     }
 }
 
+/// 배치 내 스테이지별 누적 카운트 (퍼널 뷰)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct BatchStageCounts {
+    pub pages_listed: u32,
+    pub urls_queued: u32,
+    pub details_fetched: u32,
+    pub validated: u32,
+    pub saved: u32,
+}
+
+/// 스트리밍 핸드오프로 처리된 Stage 4/5 결과 (기존 단계별 결과 형태)
+struct StreamedDetailStages {
+    validation: Option<StageResult>,
+    saving: StageResult,
+    details_fetched: u32,
+}
+
 /// 배치 상태 열거형
 #[derive(Debug, Clone, PartialEq)]
 pub enum BatchState {
@@ -165,6 +196,65 @@ impl BatchActor {
         self.skip_duplicate_urls = flag;
     }
 
+    /// Run only the pipeline stages left by the session's skip flags.
+    pub fn set_stage_selection(&mut self, selection: StageSelection) {
+        self.stage_selection = selection;
+    }
+
+    /// list_only: 상세 수집 없이 목록 항목(URL + 페이지 좌표)을 products에 저장.
+    /// 세션의 `max_new_products` 허용량을 넘는 신규 항목은 저장하지 않는다.
+    /// Returns (inserted, updated).
+    async fn save_list_entries(
+        &self,
+        session_id: &str,
+        items: &[StageItem],
+    ) -> Result<(u32, u32), BatchError> {
+        let repo = self.product_repo.as_ref().ok_or_else(|| {
+            BatchError::ServiceNotAvailable("product repository (list_only save)".to_string())
+        })?;
+        let capped = crawl_limits::has_new_product_cap(session_id);
+        let (mut inserted, mut updated) = (0u32, 0u32);
+        for item in items {
+            let StageItem::ProductUrls(wrapper) = item else {
+                continue;
+            };
+            for entry in &wrapper.urls {
+                if capped {
+                    let existing = match repo.get_product_by_url(&entry.url).await {
+                        Ok(found) => found.is_some(),
+                        Err(e) => {
+                            warn!("⚠️ list_only lookup failed for {}: {}", entry.url, e);
+                            continue;
+                        }
+                    };
+                    if !existing && crawl_limits::take_new_products(session_id, 1) == 0 {
+                        continue;
+                    }
+                }
+                let now = Utc::now();
+                let product = crate::domain::product::Product {
+                    id: None,
+                    url: entry.url.clone(),
+                    manufacturer: None,
+                    model: None,
+                    certificate_id: None,
+                    page_id: Some(entry.page_id),
+                    index_in_page: Some(entry.index_in_page),
+                    created_at: now,
+                    updated_at: now,
+                }
+                .with_generated_id();
+                match repo.create_or_update_product(&product).await {
+                    Ok((_, true)) => inserted += 1,
+                    Ok((true, false)) => updated += 1,
+                    Ok((false, false)) => {}
+                    Err(e) => warn!("⚠️ list_only save failed for {}: {}", entry.url, e),
+                }
+            }
+        }
+        Ok((inserted, updated))
+    }
+
     /// 내부 보조: Stage 2/3 per-item duration 합계 산출
     pub(crate) fn compute_stage_duration_sums(
         list_page_result: &StageResult,
@@ -198,6 +288,55 @@ impl BatchActor {
             stage2_retries.saturating_add(stage3_retries)
         }
     }
+
+    /// 내부 보조: 다음 스테이지로 큐잉된 Product URL 수 산출
+    pub(crate) fn count_queued_urls(items: &[StageItem]) -> u32 {
+        items
+            .iter()
+            .map(|item| match item {
+                StageItem::ProductUrls(wrapper) => wrapper.urls.len() as u32,
+                StageItem::Url(_) | StageItem::Product(_) => 1,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// 내부 보조: 아이템 목록에 포함된 ProductDetail 수 산출
+    pub(crate) fn count_detail_products(items: &[StageItem]) -> u32 {
+        items
+            .iter()
+            .map(|item| match item {
+                StageItem::ProductDetails(details) => details.products.len() as u32,
+                StageItem::ValidatedProducts(validated) => validated.products.len() as u32,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// 스테이지 경계에서 현재 퍼널 카운트를 BatchProgressRollup 이벤트로 발행
+    fn emit_progress_rollup(
+        &self,
+        context: &AppContext,
+        batch_id: &str,
+        stage_type: StageType,
+    ) -> Result<(), BatchError> {
+        let counts = self.stage_counts;
+        let rollup_event = AppEvent::BatchProgressRollup {
+            session_id: context.session_id.clone(),
+            batch_id: batch_id.to_string(),
+            stage_type,
+            pages_total: self.total_pages,
+            pages_listed: counts.pages_listed,
+            urls_queued: counts.urls_queued,
+            details_fetched: counts.details_fetched,
+            validated: counts.validated,
+            saved: counts.saved,
+            timestamp: Utc::now(),
+        };
+        context
+            .emit_event(rollup_event)
+            .map_err(|e| BatchError::ContextError(e.to_string()))
+    }
     /// 새로운 BatchActor 인스턴스 생성 (기본)
     ///
     /// # Arguments
@@ -237,6 +376,8 @@ impl BatchActor {
                     !(t.eq("0") || t.eq_ignore_ascii_case("false"))
                 })
                 .unwrap_or(false),
+            stage_counts: BatchStageCounts::default(),
+            stage_selection: StageSelection::default(),
         }
     }
 
@@ -271,10 +412,8 @@ impl BatchActor {
             success_count: 0,
             failure_count: 0,
             concurrency_limiter: None,
-            // Provide a default SystemConfig so template/bridge execution paths have required settings
-            config: Some(Arc::new(
-                crate::crawl_engine::config::SystemConfig::default(),
-            )),
+            // Startup SystemConfig so template/bridge execution paths have required settings
+            config: Some(crate::crawl_engine::config::SystemConfig::current()),
             // 실제 서비스 의존성 주입
             http_client: Some(http_client),
             data_extractor: Some(data_extractor),
@@ -296,6 +435,8 @@ impl BatchActor {
                     !(t.eq("0") || t.eq_ignore_ascii_case("false"))
                 })
                 .unwrap_or(false),
+            stage_counts: BatchStageCounts::default(),
+            stage_selection: StageSelection::default(),
         }
     }
 
@@ -360,6 +501,7 @@ impl BatchActor {
         self.completed_pages = 0;
         self.success_count = 0;
         self.failure_count = 0;
+        self.stage_counts = BatchStageCounts::default();
         // 새 배치마다 중복 방지 캐시 초기화 (세션 전체 유지가 아니라 배치 단위로 격리)
         self.recent_product_urls.clear();
         self.recent_product_set.clear();
@@ -404,16 +546,23 @@ impl BatchActor {
             .map(|&page_number| StageItem::Page(page_number))
             .collect();
 
-        // Stage 1: StatusCheck - 사이트 상태 확인 (세션 힌트가 없을 때만 실행)
-        let mut total_pages_hint: Option<u32> = None;
-        let mut last_page_products_hint: Option<u32> = None;
-        if _total_pages > 0 && _products_on_last_page > 0 {
+        // Stage 1: StatusCheck - 사이트 상태 확인 (세션 힌트가 없거나 TTL 이 지났을 때만 실행)
+        let session_hints = site_hints::fresh(&context.session_id, site_hints::SITE_HINT_TTL);
+        let mut pagination_hints = PaginationHints::new(_total_pages, _products_on_last_page);
+        if let Some(hints) = pagination_hints {
             info!(
                 "🧠 Using provided SiteStatus hints from Session: total_pages={}, products_on_last_page={}",
-                _total_pages, _products_on_last_page
+                hints.total_pages, hints.products_on_last_page
+            );
+            site_hints::record(&context.session_id, hints);
+        } else if let Some(hints) = session_hints {
+            info!(
+                "🧠 Reusing session SiteStatus hints ({}s old): total_pages={}, products_on_last_page={}",
+                hints.observed_at.elapsed().as_secs(),
+                hints.total_pages,
+                hints.products_on_last_page
             );
-            total_pages_hint = Some(_total_pages);
-            last_page_products_hint = Some(_products_on_last_page);
+            pagination_hints = Some(hints);
         } else {
             info!("🔍 Starting Stage 1: StatusCheck (no valid session hints)");
             // StatusCheck는 사이트 전체 상태를 확인하므로 특별한 URL 아이템으로 처리
@@ -478,11 +627,13 @@ impl BatchActor {
 
             // StatusCheck에서 수집된 SiteStatus JSON을 파싱하여 페이지네이션 힌트로 사용
             if let Some(first) = status_check_result.details.first() {
-                if let Some(json) = &first.collected_data {
-                    match serde_json::from_str::<SiteStatus>(json) {
+                if let Some(json) = first.collected_json().await {
+                    match serde_json::from_str::<SiteStatus>(&json) {
                         Ok(site_status) => {
-                            total_pages_hint = Some(site_status.total_pages);
-                            last_page_products_hint = Some(site_status.products_on_last_page);
+                            pagination_hints = PaginationHints::new(
+                                site_status.total_pages,
+                                site_status.products_on_last_page,
+                            );
                             info!(
                                 "📊 SiteStatus hints from Stage 1: total_pages={}, products_on_last_page={}",
                                 site_status.total_pages, site_status.products_on_last_page
@@ -503,14 +654,13 @@ impl BatchActor {
         info!("🔍 Starting Stage 2: ListPageCrawling");
 
         // Stage 2는 페이지네이션 힌트를 StageActor에 주입해야 함 → 전용 실행 경로 사용
-        let list_page_result = self
+        let mut list_page_result = self
             .execute_stage_with_actor_with_hints(
                 StageType::ListPageCrawling,
                 initial_items.clone(),
                 concurrency_limit,
                 context,
-                total_pages_hint,
-                last_page_products_hint,
+                pagination_hints,
             )
             .await?;
 
@@ -593,14 +743,43 @@ impl BatchActor {
         }
 
         // Stage 2 결과를 Stage 3 입력으로 변환 또는 누적
-        let product_detail_items = self.transform_stage_output(
-            StageType::ListPageCrawling,
-            initial_items.clone(),
-            &list_page_result,
-        )?;
+        let product_detail_items = self
+            .transform_stage_output(
+                StageType::ListPageCrawling,
+                initial_items.clone(),
+                &list_page_result,
+            )
+            .await?;
+        // 변환이 끝난 목록 페이로드는 더 이상 필요 없음 (성공/재시도 통계만 유지)
+        list_page_result.compact_payloads().await;
+        self.stage_counts.pages_listed = list_page_result.successful_items;
+        self.stage_counts.urls_queued = Self::count_queued_urls(&product_detail_items);
+        self.emit_progress_rollup(context, &batch_id, StageType::ListPageCrawling)?;
 
         let mut detail_result_opt: Option<StageResult> = None;
-        if self.defer_detail_crawling {
+        let list_only = !self.stage_selection.runs(&StageType::ProductDetailCrawling);
+        let skip_details = self.defer_detail_crawling || list_only;
+        let mut list_entries_saved = (0u32, 0u32);
+        // 큰 배치: Stage 3 → 4 → 5 를 페이지 청크 단위 bounded channel 핸드오프로 스트리밍
+        let memory_budget = self
+            .app_config
+            .as_ref()
+            .map(|c| c.advanced.memory_budget.clone())
+            .unwrap_or_default();
+        let stream_details = !skip_details
+            && memory_budget.streaming_handoff
+            && self.stage_selection.runs(&StageType::DataSaving)
+            && product_detail_items.len() > memory_budget.chunk_pages.max(1) as usize;
+        let mut streamed: Option<StreamedDetailStages> = None;
+        if list_only {
+            list_entries_saved = self
+                .save_list_entries(&context.session_id, &product_detail_items)
+                .await?;
+            info!(
+                "⏭️ Stage 3 (ProductDetailCrawling) skipped (list_only): list entries inserted={} updated={}",
+                list_entries_saved.0, list_entries_saved.1
+            );
+        } else if self.defer_detail_crawling {
             for item in &product_detail_items {
                 if let StageItem::ProductUrls(wrapper) = item {
                     self.collected_product_urls.extend(wrapper.urls.clone());
@@ -611,6 +790,42 @@ impl BatchActor {
                 self.collected_product_urls.len(),
                 pages.len()
             );
+        } else if stream_details {
+            info!(
+                "🔍 Starting Stage 3-5 (streamed): {} pages in chunks of {}",
+                product_detail_items.len(),
+                memory_budget.chunk_pages
+            );
+            match self
+                .stream_detail_stages(
+                    &product_detail_items,
+                    &memory_budget,
+                    concurrency_limit,
+                    context,
+                )
+                .await
+            {
+                Ok((detail_result, stages)) => {
+                    detail_result_opt = Some(detail_result);
+                    streamed = Some(stages);
+                }
+                Err(e) => {
+                    let fail_event = AppEvent::BatchFailed {
+                        batch_id: batch_id.clone(),
+                        session_id: context.session_id.clone(),
+                        error: format!("Streamed detail stages failed: {}", e),
+                        final_failure: true,
+                        timestamp: Utc::now(),
+                    };
+                    context
+                        .emit_event(fail_event)
+                        .map_err(|er| BatchError::ContextError(er.to_string()))?;
+                    self.state = BatchState::Failed {
+                        error: format!("Streamed detail stages failed: {}", e),
+                    };
+                    return Err(e);
+                }
+            }
         } else {
             // 즉시 상세 수집
             info!("🔍 Starting Stage 3: ProductDetailCrawling");
@@ -651,7 +866,7 @@ impl BatchActor {
         }
 
         // Summarize failed product detail URLs by correlating input items with Stage 3 results
-        if !self.defer_detail_crawling {
+        if !skip_details {
             // detail_result only exists in non-deferred path; re-execute minimal failure inspection via executing stage again not desired.
             // TODO: Refactor to hold detail_result outside branch if failure summary needed.
             // Skipping failure summary in deferred mode.
@@ -701,8 +916,8 @@ impl BatchActor {
             */
         }
 
-        // Stage 3 결과를 Stage 4 입력으로 변환
-        let data_validation_items = if self.defer_detail_crawling {
+        // Stage 3 결과를 Stage 4 입력으로 변환 (스트리밍 시 이미 저장됨)
+        let data_validation_items = if skip_details || streamed.is_some() {
             // Deferred mode: skip (no items)
             Vec::new()
         } else {
@@ -714,11 +929,16 @@ impl BatchActor {
                         stage: "ProductDetailCrawling".to_string(),
                         error: "detail_result missing when not deferred".to_string(),
                     })?;
-            let per_item = self.transform_stage_output(
-                StageType::ProductDetailCrawling,
-                product_detail_items,
-                detail_result,
-            )?;
+            let per_item = self
+                .transform_stage_output(
+                    StageType::ProductDetailCrawling,
+                    product_detail_items,
+                    detail_result,
+                )
+                .await?;
+            if let Some(detail_result) = detail_result_opt.as_mut() {
+                detail_result.compact_payloads().await;
+            }
             if per_item.is_empty() {
                 Vec::new()
             } else {
@@ -757,34 +977,56 @@ impl BatchActor {
                 }
             }
         };
+        if !skip_details {
+            self.stage_counts.details_fetched = match &streamed {
+                Some(stages) => stages.details_fetched,
+                None => Self::count_detail_products(&data_validation_items),
+            };
+            self.emit_progress_rollup(context, &batch_id, StageType::ProductDetailCrawling)?;
+        }
 
-        // Stage 4: DataValidation - 데이터 품질 분석
-        info!("🔍 Starting Stage 4: DataValidation");
-        let validation_result = match self
-            .execute_stage_with_actor(
-                StageType::DataValidation,
-                data_validation_items.clone(),
-                concurrency_limit,
-                context,
-            )
-            .await
-        {
-            Ok(r) => r,
-            Err(e) => {
-                let fail_event = AppEvent::BatchFailed {
-                    batch_id: batch_id.clone(),
-                    session_id: context.session_id.clone(),
-                    error: format!("Stage 4 failed: {}", e),
-                    final_failure: true,
-                    timestamp: Utc::now(),
-                };
-                context
-                    .emit_event(fail_event)
-                    .map_err(|er| BatchError::ContextError(er.to_string()))?;
-                self.state = BatchState::Failed {
-                    error: format!("Stage 4 failed: {}", e),
-                };
-                return Err(e);
+        // Stage 4: DataValidation - 데이터 품질 분석 (skip_validation / list_only 이면 그대로 통과)
+        let streamed_validation = streamed.as_mut().and_then(|s| s.validation.take());
+        let mut validation_result = if let Some(validation) = streamed_validation {
+            validation
+        } else if !self.stage_selection.runs(&StageType::DataValidation) {
+            info!("⏭️ Stage 4 (DataValidation) skipped by stage flags");
+            let passed = data_validation_items.len() as u32;
+            StageResult {
+                processed_items: passed,
+                successful_items: passed,
+                failed_items: 0,
+                duration_ms: 0,
+                details: Vec::new(),
+            }
+        } else {
+            info!("🔍 Starting Stage 4: DataValidation");
+            match self
+                .execute_stage_with_actor(
+                    StageType::DataValidation,
+                    data_validation_items.clone(),
+                    concurrency_limit,
+                    context,
+                )
+                .await
+            {
+                Ok(r) => r,
+                Err(e) => {
+                    let fail_event = AppEvent::BatchFailed {
+                        batch_id: batch_id.clone(),
+                        session_id: context.session_id.clone(),
+                        error: format!("Stage 4 failed: {}", e),
+                        final_failure: true,
+                        timestamp: Utc::now(),
+                    };
+                    context
+                        .emit_event(fail_event)
+                        .map_err(|er| BatchError::ContextError(er.to_string()))?;
+                    self.state = BatchState::Failed {
+                        error: format!("Stage 4 failed: {}", e),
+                    };
+                    return Err(e);
+                }
             }
         };
 
@@ -796,43 +1038,54 @@ impl BatchActor {
           batch_id, validation_result.successful_items, validation_result.failed_items, pages.len(), chrono::Utc::now());
 
         // Stage 4 결과를 Stage 5 입력으로 변환
-        let data_saving_items = if self.defer_detail_crawling {
+        let data_saving_items = if skip_details || streamed.is_some() {
             Vec::new()
         } else {
             self.transform_stage_output(
                 StageType::DataValidation,
                 data_validation_items,
                 &validation_result,
-            )?
+            )
+            .await?
         };
+        validation_result.compact_payloads().await;
+        self.stage_counts.validated = match &streamed {
+            Some(_) => validation_result.successful_items,
+            None => Self::count_detail_products(&data_saving_items),
+        };
+        self.emit_progress_rollup(context, &batch_id, StageType::DataValidation)?;
 
         // Stage 5: DataSaving - 데이터 저장
-        info!("🔍 Starting Stage 5: DataSaving");
-        let saving_result = match self
-            .execute_stage_with_actor(
-                StageType::DataSaving,
-                data_saving_items,
-                concurrency_limit,
-                context,
-            )
-            .await
-        {
-            Ok(r) => r,
-            Err(e) => {
-                let fail_event = AppEvent::BatchFailed {
-                    batch_id: batch_id.clone(),
-                    session_id: context.session_id.clone(),
-                    error: format!("Stage 5 failed: {}", e),
-                    final_failure: true,
-                    timestamp: Utc::now(),
-                };
-                context
-                    .emit_event(fail_event)
-                    .map_err(|er| BatchError::ContextError(er.to_string()))?;
-                self.state = BatchState::Failed {
-                    error: format!("Stage 5 failed: {}", e),
-                };
-                return Err(e);
+        let mut saving_result = if let Some(stages) = streamed.take() {
+            stages.saving
+        } else {
+            info!("🔍 Starting Stage 5: DataSaving");
+            match self
+                .execute_stage_with_actor(
+                    StageType::DataSaving,
+                    data_saving_items,
+                    concurrency_limit,
+                    context,
+                )
+                .await
+            {
+                Ok(r) => r,
+                Err(e) => {
+                    let fail_event = AppEvent::BatchFailed {
+                        batch_id: batch_id.clone(),
+                        session_id: context.session_id.clone(),
+                        error: format!("Stage 5 failed: {}", e),
+                        final_failure: true,
+                        timestamp: Utc::now(),
+                    };
+                    context
+                        .emit_event(fail_event)
+                        .map_err(|er| BatchError::ContextError(er.to_string()))?;
+                    self.state = BatchState::Failed {
+                        error: format!("Stage 5 failed: {}", e),
+                    };
+                    return Err(e);
+                }
             }
         };
 
@@ -848,9 +1101,9 @@ impl BatchActor {
         let mut inserted_sum = 0u32;
         let mut updated_sum = 0u32;
         for item in &saving_result.details {
-            if let Some(data) = &item.collected_data {
+            if let Some(data) = item.collected_json().await {
                 if data.starts_with('{') {
-                    if let Ok(v) = serde_json::from_str::<serde_json::Value>(data) {
+                    if let Ok(v) = serde_json::from_str::<serde_json::Value>(&data) {
                         if let Some(pi) = v.get("products_inserted").and_then(|x| x.as_u64()) {
                             inserted_sum = inserted_sum.saturating_add(pi as u32);
                         }
@@ -861,8 +1114,12 @@ impl BatchActor {
                 }
             }
         }
-        self.products_inserted = inserted_sum;
-        self.products_updated = updated_sum;
+        saving_result.compact_payloads().await;
+        // list_only 저장분은 Stage 5를 거치지 않으므로 따로 합산
+        self.products_inserted = inserted_sum.saturating_add(list_entries_saved.0);
+        self.products_updated = updated_sum.saturating_add(list_entries_saved.1);
+        self.stage_counts.saved = self.products_inserted.saturating_add(self.products_updated);
+        self.emit_progress_rollup(context, &batch_id, StageType::DataSaving)?;
         if let Some(shared) = &self.shared_metrics {
             if let Ok(mut g) = shared.lock() {
                 *g = (self.products_inserted, self.products_updated);
@@ -890,12 +1147,15 @@ impl BatchActor {
         let (stage2_duration_sum, stage3_duration_sum) = Self::compute_stage_duration_sums(
             &list_page_result,
             detail_result_opt.as_ref(),
-            self.defer_detail_crawling,
+            skip_details,
         );
-        info!(target: "kpi.batch", "{{\"event\":\"batch_stage_durations\",\"batch_id\":\"{}\",\"stage2_duration_ms_total\":{},\"stage3_duration_ms_total\":{},\"ts\":\"{}\"}}",
+        info!(target: "kpi.batch", "{{\"event\":\"batch_stage_durations\",\"batch_id\":\"{}\",\"stage2_duration_ms_total\":{},\"stage3_duration_ms_total\":{},\"latency\":{},\"ts\":\"{}\"}}",
             batch_id,
             stage2_duration_sum,
             stage3_duration_sum,
+            crate::crawl_engine::runtime::latency::kpi_json(
+                &crate::crawl_engine::runtime::latency::session_stats(&context.session_id)
+            ),
             chrono::Utc::now()
         );
         info!(
@@ -913,7 +1173,7 @@ impl BatchActor {
             duration_ms,
             self.products_inserted,
             self.products_updated,
-            self.defer_detail_crawling
+            skip_details
         );
 
         let completion_event = AppEvent::BatchCompleted {
@@ -958,7 +1218,7 @@ impl BatchActor {
         let pages_total = self.total_pages;
         let pages_success = self.success_count.max(list_page_result.successful_items);
         let pages_failed = list_page_result.failed_items;
-        let (details_success, details_failed) = if self.defer_detail_crawling {
+        let (details_success, details_failed) = if skip_details {
             (0, 0)
         } else {
             (
@@ -966,11 +1226,8 @@ impl BatchActor {
                 validation_result.failed_items,
             )
         };
-        let retries_used = Self::compute_retries_used(
-            &list_page_result,
-            detail_result_opt.as_ref(),
-            self.defer_detail_crawling,
-        );
+        let retries_used =
+            Self::compute_retries_used(&list_page_result, detail_result_opt.as_ref(), skip_details);
         let report_event = AppEvent::BatchReport {
             session_id: context.session_id.clone(),
             batch_id: batch_id.clone(),
@@ -991,9 +1248,174 @@ impl BatchActor {
             .emit_event(report_event)
             .map_err(|e| BatchError::ContextError(e.to_string()))?;
 
+        // 배치 크기 튜닝용 처리량/메모리 압박 기록 (실패해도 배치 결과에는 영향 없음)
+        if let Some(repo) = &self.product_repo {
+            let pool = repo.pool().clone();
+            let sample = crate::services::batch_tuning::BatchSample {
+                session_id: context.session_id.clone(),
+                batch_id: batch_id.clone(),
+                batch_size: config.batch_size,
+                pages: pages_total,
+                products: self.products_inserted.saturating_add(self.products_updated),
+                duration_ms,
+                memory_pressure: crate::infrastructure::host_profile::memory_pressure(),
+            };
+            tokio::spawn(async move {
+                if let Err(e) = crate::services::batch_tuning::record_batch(&pool, &sample).await {
+                    warn!("batch_metrics record failed: {}", e);
+                }
+            });
+        }
+
         Ok(())
     }
 
+    /// Stage 3 → 4 → 5 를 페이지 청크 단위로 스트리밍 (`memory_budget.streaming_handoff`)
+    ///
+    /// 청크마다 상세를 수집·변환해 `DetailHandoff` 로 넘기고 저장 태스크가 검증/저장한다.
+    /// 채널이 차 있으면 다음 청크 수집이 기다린다. 반환: (Stage 3 결과, Stage 4/5 결과)
+    async fn stream_detail_stages(
+        &mut self,
+        items: &[StageItem],
+        memory_budget: &MemoryBudgetConfig,
+        concurrency_limit: u32,
+        context: &AppContext,
+    ) -> Result<(StageResult, StreamedDetailStages), BatchError> {
+        let repo = self.product_repo.as_ref().ok_or_else(|| {
+            BatchError::ServiceNotAvailable("ProductRepository not initialized".to_string())
+        })?;
+        let queue = persistence_queue::shared_queue(repo.pool());
+        let validate = self.stage_selection.runs(&StageType::DataValidation);
+        let handoff = DetailHandoff::spawn(
+            queue,
+            context.session_id.clone(),
+            memory_budget.channel_capacity as usize,
+            validate,
+        );
+        let started = Instant::now();
+        let mut detail = StageResult {
+            processed_items: 0,
+            successful_items: 0,
+            failed_items: 0,
+            duration_ms: 0,
+            details: Vec::new(),
+        };
+        let mut details_fetched = 0u32;
+        let mut stage_error = None;
+        for chunk in items.chunks(memory_budget.chunk_pages.max(1) as usize) {
+            let mut chunk_result = match self
+                .execute_stage_with_actor(
+                    StageType::ProductDetailCrawling,
+                    chunk.to_vec(),
+                    concurrency_limit,
+                    context,
+                )
+                .await
+            {
+                Ok(r) => r,
+                Err(e) => {
+                    stage_error = Some(e);
+                    break;
+                }
+            };
+            let per_item = match self
+                .transform_stage_output(
+                    StageType::ProductDetailCrawling,
+                    chunk.to_vec(),
+                    &chunk_result,
+                )
+                .await
+            {
+                Ok(items) => items,
+                Err(e) => {
+                    stage_error = Some(e);
+                    break;
+                }
+            };
+            chunk_result.compact_payloads().await;
+            detail.processed_items += chunk_result.processed_items;
+            detail.successful_items += chunk_result.successful_items;
+            detail.failed_items += chunk_result.failed_items;
+            detail.duration_ms += chunk_result.duration_ms;
+            detail.details.append(&mut chunk_result.details);
+
+            let products: Vec<ProductDetail> = per_item
+                .into_iter()
+                .flat_map(|item| match item {
+                    StageItem::ProductDetails(pd) => pd.products,
+                    _ => Vec::new(),
+                })
+                .collect();
+            details_fetched += products.len() as u32;
+            if handoff.send(products).await.is_err() {
+                // 저장 태스크 종료 → 원인은 finish() 에서 보고
+                break;
+            }
+        }
+        let handoff_result = handoff.finish().await;
+        if let Some(e) = stage_error {
+            return Err(e);
+        }
+        let report = handoff_result.map_err(|e| BatchError::StageProcessingFailed {
+            stage: "DataSaving".to_string(),
+            error: format!("{e:#}"),
+        })?;
+        info!(
+            "✅ Stage 3-5 (streamed) completed: chunks={} details={} validated={} inserted={} updated={}",
+            report.chunks,
+            details_fetched,
+            report.validated,
+            report.flush.inserted,
+            report.flush.updated
+        );
+
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+        let validation = validate.then(|| StageResult {
+            processed_items: report.received,
+            successful_items: report.validated,
+            failed_items: report.validation_failed,
+            duration_ms: elapsed_ms,
+            details: Vec::new(),
+        });
+        // Stage 5 결과는 DataSavingLogic 과 같은 JSON 형태로 (inserted/updated 집계 재사용)
+        let payload = serde_json::json!({
+            "attempted": report.validated,
+            "products_inserted": report.flush.inserted,
+            "products_updated": report.flush.updated,
+            "products_unchanged": report.flush.unchanged,
+            "rejected": report.flush.rejected,
+            "skipped_over_limit": report.over_limit,
+            "flush_ms": report.flush.elapsed_ms
+        });
+        let saving = StageResult {
+            processed_items: 1,
+            successful_items: report.validated,
+            failed_items: report.flush.rejected,
+            duration_ms: elapsed_ms,
+            details: vec![StageItemResult {
+                item_id: format!("persist_streamed_{}", report.validated),
+                item_type: StageItemType::Url {
+                    url_type: "data_saving:streamed".into(),
+                },
+                success: true,
+                error: None,
+                duration_ms: report.flush.elapsed_ms,
+                retry_count: 0,
+                collected_data: Some(
+                    StagePayload::new(&context.session_id, payload.to_string()).await,
+                ),
+            }],
+        };
+        Ok((
+            detail,
+            StreamedDetailStages {
+                validation,
+                saving,
+                details_fetched,
+            },
+        ))
+    }
+
     /// 배치 설정 검증
     ///
     /// # Arguments
@@ -1143,6 +1565,25 @@ mod batch_actor_metrics_tests {
         let retries = BatchActor::compute_retries_used(&list_res, None, true);
         assert_eq!(retries, 3);
     }
+
+    #[test]
+    fn test_count_queued_urls() {
+        let mk_url = |i: i32| crate::domain::product_url::ProductUrl {
+            url: format!("https://example.com/p/{}", i),
+            page_id: 0,
+            index_in_page: i,
+        };
+        let items = vec![
+            StageItem::ProductUrls(ProductUrls {
+                urls: vec![mk_url(0), mk_url(1), mk_url(2)],
+                batch_id: None,
+            }),
+            StageItem::Url("https://example.com/p/9".into()),
+            StageItem::Page(3),
+        ];
+        assert_eq!(BatchActor::count_queued_urls(&items), 4);
+        assert_eq!(BatchActor::count_detail_products(&items), 0);
+    }
 }
 
 #[async_trait::async_trait]
@@ -1387,11 +1828,17 @@ impl BatchActor {
                 retry_max: app_config.user.crawling.workers.max_retries,
             };
             let product_detail_collector: Arc<dyn crate::domain::services::ProductDetailCollector> =
-                Arc::new(impls::ProductDetailCollectorImpl::new(
-                    Arc::clone(http_client),
-                    Arc::clone(data_extractor),
-                    detail_cfg,
-                ));
+                Arc::new(
+                    impls::ProductDetailCollectorImpl::new(
+                        Arc::clone(http_client),
+                        Arc::clone(data_extractor),
+                        detail_cfg,
+                    )
+                    .with_fetch_order(
+                        app_config.user.crawling.workers.detail_fetch_order,
+                        app_config.user.crawling.workers.detail_fetch_seed,
+                    ),
+                );
 
             // 중복 정책: 환경 변수 힌트(MC_DUPLICATE_POLICY)로 제어 (manual 경로에서 설정)
             let dup_policy = match std::env::var("MC_DUPLICATE_POLICY").ok().as_deref() {
@@ -1411,12 +1858,16 @@ impl BatchActor {
             }
         };
 
-        let mut stage_actor = StageActor::new_with_deps(
-            format!("stage_{}_{}", stage_type.as_str(), self.actor_id),
-            self.batch_id.clone().unwrap_or_default(),
-            deps,
-            Arc::new(crate::crawl_engine::stages::DefaultStageLogicFactory),
-        );
+        let stage_actor_id = format!("stage_{}_{}", stage_type.as_str(), self.actor_id);
+        let batch_id = self.batch_id.clone().unwrap_or_default();
+        let build_stage_actor = move || {
+            StageActor::new_with_deps(
+                stage_actor_id.clone(),
+                batch_id.clone(),
+                deps.clone(),
+                Arc::new(crate::crawl_engine::stages::DefaultStageLogicFactory),
+            )
+        };
 
         // StageActor로 Stage 실행 (실제 items 전달)
         // Use configurable operation timeout instead of hard-coded 30s
@@ -1425,19 +1876,21 @@ impl BatchActor {
             .crawling
             .timing
             .operation_timeout_seconds;
-        let stage_result = stage_actor
-            .execute_stage(
-                stage_type,
+        let stage_result = self
+            .execute_stage_supervised(
+                build_stage_actor,
+                stage_type.clone(),
                 items,
                 concurrency_limit,
                 timeout_secs,
                 context,
             )
-            .await
+            .await?
             .map_err(|e| {
                 BatchError::StageExecutionFailed(format!("Stage execution failed: {:?}", e))
             })?;
 
+        Self::record_stage_latency(&context.session_id, stage_type, &stage_result);
         Ok(stage_result)
     }
 
@@ -1448,8 +1901,7 @@ impl BatchActor {
         items: Vec<StageItem>,
         concurrency_limit: u32,
         context: &AppContext,
-        total_pages_hint: Option<u32>,
-        products_on_last_page_hint: Option<u32>,
+        pagination_hints: Option<PaginationHints>,
     ) -> Result<StageResult, BatchError> {
         // 기본 실행 준비는 동일
         let http_client = self.http_client.as_ref().ok_or_else(|| {
@@ -1523,11 +1975,17 @@ impl BatchActor {
                 retry_max: app_config.user.crawling.workers.max_retries,
             };
             let product_detail_collector: Arc<dyn crate::domain::services::ProductDetailCollector> =
-                Arc::new(impls::ProductDetailCollectorImpl::new(
-                    Arc::clone(http_client),
-                    Arc::clone(data_extractor),
-                    detail_cfg,
-                ));
+                Arc::new(
+                    impls::ProductDetailCollectorImpl::new(
+                        Arc::clone(http_client),
+                        Arc::clone(data_extractor),
+                        detail_cfg,
+                    )
+                    .with_fetch_order(
+                        app_config.user.crawling.workers.detail_fetch_order,
+                        app_config.user.crawling.workers.detail_fetch_seed,
+                    ),
+                );
             let dup_policy = match std::env::var("MC_DUPLICATE_POLICY").ok().as_deref() {
                 Some("UpdateIdIndexOnly") => crate::crawl_engine::actors::types::DuplicatePersistencePolicy::UpdateIdIndexOnly,
                 Some("FullUpdate") => crate::crawl_engine::actors::types::DuplicatePersistencePolicy::FullUpdate,
@@ -1544,16 +2002,20 @@ impl BatchActor {
                 duplicate_policy: dup_policy,
             }
         };
-        let mut stage_actor = StageActor::new_with_deps(
-            format!("stage_{}_{}", stage_type.as_str(), self.actor_id),
-            self.batch_id.clone().unwrap_or_default(),
-            deps,
-            Arc::new(crate::crawl_engine::stages::DefaultStageLogicFactory),
-        );
-
-        if let (Some(tp), Some(plp)) = (total_pages_hint, products_on_last_page_hint) {
-            stage_actor.set_site_pagination_hints(tp, plp);
-        }
+        let stage_actor_id = format!("stage_{}_{}", stage_type.as_str(), self.actor_id);
+        let batch_id = self.batch_id.clone().unwrap_or_default();
+        let build_stage_actor = move || {
+            let mut stage_actor = StageActor::new_with_deps(
+                stage_actor_id.clone(),
+                batch_id.clone(),
+                deps.clone(),
+                Arc::new(crate::crawl_engine::stages::DefaultStageLogicFactory),
+            );
+            if let Some(hints) = pagination_hints {
+                stage_actor.set_pagination_hints(hints);
+            }
+            stage_actor
+        };
 
         // Use configurable operation timeout instead of hard-coded 30s
         let timeout_secs = app_config
@@ -1561,16 +2023,82 @@ impl BatchActor {
             .crawling
             .timing
             .operation_timeout_seconds;
-        let stage_result = stage_actor
-            .execute_stage(stage_type, items, concurrency_limit, timeout_secs, context)
-            .await
+        let stage_result = self
+            .execute_stage_supervised(
+                build_stage_actor,
+                stage_type.clone(),
+                items,
+                concurrency_limit,
+                timeout_secs,
+                context,
+            )
+            .await?
             .map_err(|e| {
                 BatchError::StageExecutionFailed(format!("Stage execution failed: {:?}", e))
             })?;
 
+        Self::record_stage_latency(&context.session_id, stage_type, &stage_result);
         Ok(stage_result)
     }
 
+    /// 감독자 아래에서 Stage 실행: 시도마다 `build_stage_actor` 로 새 StageActor 를 만들고, 패닉하거나
+    /// 하트비트가 끊기면 `advanced.actor_supervision` 정책대로 재시작한다. 재시작을 다 쓰면 배치 에러.
+    async fn execute_stage_supervised<B>(
+        &self,
+        build_stage_actor: B,
+        stage_type: StageType,
+        items: Vec<StageItem>,
+        concurrency_limit: u32,
+        timeout_secs: u64,
+        context: &AppContext,
+    ) -> Result<Result<StageResult, StageError>, BatchError>
+    where
+        B: Fn() -> StageActor,
+    {
+        let policy = self
+            .app_config
+            .as_ref()
+            .map(|c| c.advanced.actor_supervision.clone())
+            .unwrap_or_default();
+        let supervisor = Supervisor::new(policy).with_context(context.clone());
+        let actor_id = format!("stage_{}_{}", stage_type.as_str(), self.actor_id);
+        supervisor
+            .supervise(&actor_id, |heartbeat| {
+                let mut stage_actor = build_stage_actor();
+                stage_actor.set_heartbeat(heartbeat);
+                let stage_type = stage_type.clone();
+                let items = items.clone();
+                let context = context.clone();
+                async move {
+                    stage_actor
+                        .execute_stage(stage_type, items, concurrency_limit, timeout_secs, &context)
+                        .await
+                }
+            })
+            .await
+            .map_err(|e| BatchError::StageExecutionFailed(e.to_string()))
+    }
+
+    /// Stage 총 소요 시간과 아이템별 소요 시간을 지연 히스토그램에 기록
+    fn record_stage_latency(session_id: &str, stage_type: StageType, result: &StageResult) {
+        use crate::crawl_engine::runtime::latency::{LatencyKey, LatencyOp, record_latency};
+        use std::time::Duration;
+
+        record_latency(
+            Some(session_id),
+            LatencyKey::stage(LatencyOp::Stage, stage_type.clone()),
+            Duration::from_millis(result.duration_ms),
+        );
+        let item_key = LatencyKey::stage(LatencyOp::StageItem, stage_type);
+        for item in &result.details {
+            record_latency(
+                Some(session_id),
+                item_key.clone(),
+                Duration::from_millis(item.duration_ms),
+            );
+        }
+    }
+
     /// Stage 파이프라인 실행 - Stage 간 데이터 전달 구현
     ///
     /// # Arguments
@@ -1622,8 +2150,8 @@ impl BatchActor {
                 current_items.len()
             );
 
-            // 🔥 Phase 1: 실제 서비스와 함께 StageActor 생성
-            let mut stage_actor = if let (
+            // 🔥 Phase 1: 실제 서비스와 함께 StageActor 생성 (감독자가 시도마다 새로 만든다)
+            let build_stage_actor = if let (
                 Some(http_client),
                 Some(data_extractor),
                 Some(product_repo),
@@ -1695,11 +2223,17 @@ impl BatchActor {
                     };
                     let product_detail_collector: Arc<
                         dyn crate::domain::services::ProductDetailCollector,
-                    > = Arc::new(impls::ProductDetailCollectorImpl::new(
-                        Arc::clone(http_client),
-                        Arc::clone(data_extractor),
-                        detail_cfg,
-                    ));
+                    > = Arc::new(
+                        impls::ProductDetailCollectorImpl::new(
+                            Arc::clone(http_client),
+                            Arc::clone(data_extractor),
+                            detail_cfg,
+                        )
+                        .with_fetch_order(
+                            app_config.user.crawling.workers.detail_fetch_order,
+                            app_config.user.crawling.workers.detail_fetch_seed,
+                        ),
+                    );
                     let dup_policy = match std::env::var("MC_DUPLICATE_POLICY").ok().as_deref() {
                         Some("UpdateIdIndexOnly") => crate::crawl_engine::actors::types::DuplicatePersistencePolicy::UpdateIdIndexOnly,
                         Some("FullUpdate") => crate::crawl_engine::actors::types::DuplicatePersistencePolicy::FullUpdate,
@@ -1716,16 +2250,20 @@ impl BatchActor {
                         duplicate_policy: dup_policy,
                     }
                 };
-                StageActor::new_with_deps(
-                    format!(
-                        "stage_{}_{}",
-                        stage_type.as_str().to_lowercase(),
-                        self.actor_id
-                    ),
-                    self.batch_id.clone().unwrap_or_default(),
-                    deps,
-                    Arc::new(crate::crawl_engine::stages::DefaultStageLogicFactory),
-                )
+                let stage_actor_id = format!(
+                    "stage_{}_{}",
+                    stage_type.as_str().to_lowercase(),
+                    self.actor_id
+                );
+                let batch_id = self.batch_id.clone().unwrap_or_default();
+                move || {
+                    StageActor::new_with_deps(
+                        stage_actor_id.clone(),
+                        batch_id.clone(),
+                        deps.clone(),
+                        Arc::new(crate::crawl_engine::stages::DefaultStageLogicFactory),
+                    )
+                }
             } else {
                 // No DI deps available on self → cannot safely construct services here
                 return Err(BatchError::ServiceNotAvailable(
@@ -1758,15 +2296,16 @@ impl BatchActor {
                 (5, 300)
             };
 
-            let stage_result = stage_actor
-                .execute_stage(
+            let mut stage_result = self
+                .execute_stage_supervised(
+                    build_stage_actor,
                     stage_type.clone(),
                     current_items.clone(),
                     concurrency_limit,
                     timeout_secs,
                     context,
                 )
-                .await
+                .await?
                 .map_err(|e| BatchError::StageProcessingFailed {
                     stage: stage_type.as_str().to_string(),
                     error: format!("Stage execution failed: {:?}", e),
@@ -1787,8 +2326,10 @@ impl BatchActor {
             final_result.duration_ms += stage_result.duration_ms;
 
             // 다음 Stage를 위한 입력 데이터 변환
-            current_items =
-                self.transform_stage_output(stage_type.clone(), current_items, &stage_result)?;
+            current_items = self
+                .transform_stage_output(stage_type.clone(), current_items, &stage_result)
+                .await?;
+            stage_result.compact_payloads().await;
         }
 
         info!("✅ All stages completed in pipeline");
@@ -1796,7 +2337,7 @@ impl BatchActor {
     }
 
     /// Stage 출력을 다음 Stage 입력으로 변환
-    fn transform_stage_output(
+    async fn transform_stage_output(
         &mut self,
         completed_stage: StageType,
         input_items: Vec<StageItem>,
@@ -1829,12 +2370,13 @@ impl BatchActor {
                         if let Some(stage_item_result) = stage_result.details.get(item_index) {
                             if stage_item_result.success {
                                 // 실제 수집된 데이터가 있는지 확인
-                                if let Some(collected_data_json) = &stage_item_result.collected_data
+                                if let Some(collected_data_json) =
+                                    stage_item_result.collected_json().await
                                 {
                                     // JSON에서 ProductURL들을 파싱
                                     match serde_json::from_str::<
                                         Vec<crate::domain::product_url::ProductUrl>,
-                                    >(collected_data_json)
+                                    >(&collected_data_json)
                                     {
                                         Ok(product_urls_vec) => {
                                             if !product_urls_vec.is_empty() {
@@ -2009,7 +2551,8 @@ impl BatchActor {
                             );
                             if stage_item_result.success {
                                 // 실제 수집된 ProductDetails 데이터가 있는지 확인
-                                if let Some(collected_data_json) = &stage_item_result.collected_data
+                                if let Some(collected_data_json) =
+                                    stage_item_result.collected_json().await
                                 {
                                     info!(
                                         "🔄 Attempting to parse ProductDetails JSON: {} chars",
@@ -2018,7 +2561,7 @@ impl BatchActor {
                                     // JSON에서 ProductDetails를 파싱
                                     match serde_json::from_str::<
                                         crate::crawl_engine::channels::types::ProductDetails,
-                                    >(collected_data_json)
+                                    >(&collected_data_json)
                                     {
                                         Ok(product_details_wrapper) => {
                                             if !product_details_wrapper.products.is_empty() {
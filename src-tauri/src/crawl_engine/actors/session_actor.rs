@@ -11,18 +11,25 @@ use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
-use crate::crawl_engine::actors::types::SessionSummary;
+use crate::crawl_engine::actors::types::{CompletionReason, SessionSummary};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
 
 use super::traits::{Actor, ActorHealth, ActorStatus, ActorType};
-use super::types::{ActorCommand, ActorError, CrawlingConfig};
+use super::types::{ActorCommand, ActorError, CrawlingConfig, ExecutionPlan, PageRange};
 use crate::crawl_engine::channels::types::AppEvent;
-use crate::crawl_engine::context::AppContext;
+use crate::crawl_engine::context::{AppContext, SystemConfig};
 use std::sync::Arc;
 
+use crate::crawl_engine::actor_system::supervisor;
 use crate::crawl_engine::actors::BatchActor;
+use crate::crawl_engine::actors::session_watchdog;
 use crate::crawl_engine::actors::types::BatchConfig;
 use crate::crawl_engine::services::CrawlingPlanner;
 use crate::domain::services::{DatabaseAnalyzer, StatusChecker};
+use crate::crawl_engine::runtime::latency;
+use crate::crawl_engine::runtime::task_registry::{SESSION_TASK_LIFETIME, spawn_tracked};
 use crate::infrastructure::config::AppConfig;
 use crate::infrastructure::crawling_service_impls::{DatabaseAnalyzerImpl, StatusCheckerImpl};
 use crate::infrastructure::{HttpClient, IntegratedProductRepository, MatterDataExtractor};
@@ -69,6 +76,8 @@ pub struct SessionActor {
         Option<std::sync::Arc<crate::crawl_engine::services::crawling_planner::CrawlingPlan>>,
     /// 계획 버전 (향후 재계산 허용 시 증가) 현재 0 또는 1
     plan_version: u64,
+    /// 스테이지 정체 감시 태스크 (세션 시작 시 생성)
+    watchdog: Option<tokio::task::JoinHandle<()>>,
 }
 
 /// 세션 상태 열거형
@@ -104,6 +113,12 @@ pub enum SessionError {
 
     #[error("Context communication error: {0}")]
     ContextError(String),
+
+    #[error("Session checkpoint error: {0}")]
+    CheckpointError(String),
+
+    #[error("Supervised actor failed: {0}")]
+    SupervisionFailed(String),
 }
 
 impl SessionActor {
@@ -132,6 +147,7 @@ impl SessionActor {
             aggregated_product_urls: Vec::new(),
             crawling_plan: None,
             plan_version: 0,
+            watchdog: None,
         }
     }
 
@@ -175,6 +191,7 @@ impl SessionActor {
         context
             .emit_event(start_event)
             .map_err(|e| SessionError::ContextError(e.to_string()))?;
+        self.start_watchdog(context);
 
         // 실제 크롤링 실행 로직 시작
         info!(
@@ -325,6 +342,13 @@ impl SessionActor {
         // 캐시 갱신 및 사용 로그
         self.site_status_cache = Some((used_site_status.clone(), Instant::now()));
         let site_status = used_site_status;
+        // Batches / ListPage items of this session reuse these instead of checking again
+        if let Some(hints) = crate::crawl_engine::runtime::site_hints::PaginationHints::new(
+            site_status.total_pages,
+            site_status.products_on_last_page,
+        ) {
+            crate::crawl_engine::runtime::site_hints::record(&session_id, hints);
+        }
         info!(
             "🌐 SiteStatus: total_pages={}, products_on_last_page={}",
             site_status.total_pages, site_status.products_on_last_page
@@ -429,6 +453,13 @@ impl SessionActor {
             {
                 error!("❌ Batch {} failed: {}", batch_id, e);
                 self.errors.push(format!("batch {}: {}", batch_id, e));
+                // 감독자가 이미 최종 실패(SessionFailed)를 알렸다: 남은 배치는 건너뛴다
+                if let SessionError::SupervisionFailed(reason) = &e {
+                    self.state = SessionState::Failed {
+                        error: reason.clone(),
+                    };
+                    return Err(e);
+                }
                 // 세션 실패 이벤트 발행
                 let fail_event = AppEvent::SessionFailed {
                     session_id: session_id.clone(),
@@ -539,9 +570,12 @@ impl SessionActor {
             executed_list_batches: self.processed_batches,
             failed_pages_count: 0,
             failed_page_ids: Vec::new(),
+            latency: latency::session_stats(&session_id),
             products_inserted: self.products_inserted,
             products_updated: self.products_updated,
             final_state: "completed".to_string(),
+            completion_reason: CompletionReason::Finished,
+            limit_reached: None,
             timestamp: Utc::now(),
         });
 
@@ -584,7 +618,7 @@ impl SessionActor {
         };
         let s = &aggregated_summary;
         info!(target: "kpi.session",
-            "{{\"event\":\"session_final_summary\",\"session_id\":\"{}\",\"final_state\":\"{}\",\"duration_ms\":{},\"processed_batches\":{},\"total_pages_processed\":{},\"total_success_count\":{},\"failed_pages_count\":{},\"total_retry_events\":{},\"products_inserted\":{},\"products_updated\":{},\"duplicates_skipped\":{},\"plan_hash\":{},\"ts\":\"{}\"}}",
+            "{{\"event\":\"session_final_summary\",\"session_id\":\"{}\",\"final_state\":\"{}\",\"duration_ms\":{},\"processed_batches\":{},\"total_pages_processed\":{},\"total_success_count\":{},\"failed_pages_count\":{},\"total_retry_events\":{},\"products_inserted\":{},\"products_updated\":{},\"duplicates_skipped\":{},\"latency\":{},\"plan_hash\":{},\"ts\":\"{}\"}}",
             s.session_id,
             s.final_state,
             s.total_duration_ms,
@@ -596,9 +630,11 @@ impl SessionActor {
             s.products_inserted,
             s.products_updated,
             s.duplicates_skipped,
+            latency::kpi_json(&s.latency),
             plan_hash_json,
             chrono::Utc::now()
         );
+        latency::finish_session(&s.session_id);
 
         // Mirror a concise human-readable summary to the main backend log (back_front.log)
         let plan_hash_disp = match &self.active_plan_hash {
@@ -724,11 +760,17 @@ impl SessionActor {
             info!("[DedupCfg] Applied skip_duplicate_urls={} to BatchActor (batch_id={})", flag, batch_id);
         }
         batch_actor.shared_metrics = Some(shared_metrics.clone());
-        let (tx, rx) = mpsc::channel::<super::types::ActorCommand>(100);
+        let (tx, rx) = mpsc::channel::<super::types::ActorCommand>(
+            SystemConfig::channel_capacities().actor_commands,
+        );
         let actor_context = context.clone();
-        let actor_task = tokio::spawn(async move {
-            let _ = batch_actor.run(actor_context, rx).await;
-        });
+        let actor_task = spawn_tracked(
+            format!("batch-actor:{}", batch_id),
+            Some(SESSION_TASK_LIFETIME),
+            async move {
+                let _ = batch_actor.run(actor_context, rx).await;
+            },
+        );
         let batch_config = BatchConfig {
             batch_size: pages.len() as u32,
             concurrency_limit: config_concurrency,
@@ -755,6 +797,10 @@ impl SessionActor {
         actor_task
             .await
             .map_err(|e| SessionError::ContextError(format!("BatchActor join error: {}", e)))?;
+        // A stage actor that ran out of restarts fails the whole session
+        if let Some(reason) = supervisor::take_escalation(&context.session_id) {
+            return Err(SessionError::SupervisionFailed(reason));
+        }
         if let Ok(g) = shared_metrics.lock() {
             self.products_inserted = self.products_inserted.saturating_add(g.0);
             self.products_updated = self.products_updated.saturating_add(g.1);
@@ -912,8 +958,21 @@ impl SessionActor {
         }
     }
 
+    /// 스테이지 정체 워치독 시작 (이전 세션의 워치독은 중지)
+    fn start_watchdog(&mut self, context: &AppContext) {
+        self.stop_watchdog();
+        self.watchdog = Some(session_watchdog::spawn_session_watchdog(context.clone()));
+    }
+
+    fn stop_watchdog(&mut self) {
+        if let Some(watchdog) = self.watchdog.take() {
+            watchdog.abort();
+        }
+    }
+
     /// 세션 정리
     fn cleanup_session(&mut self) {
+        self.stop_watchdog();
         self.session_id = None;
         self.state = SessionState::Idle;
         self.start_time = None;
@@ -986,6 +1045,7 @@ impl SessionActor {
                 executed_list_batches: self.processed_batches,
                 failed_pages_count: 0,
                 failed_page_ids: Vec::new(),
+                latency: latency::session_stats(&session_id),
                 total_retry_events: 0,
                 max_retries_single_page: 0,
                 pages_retried: 0,
@@ -993,6 +1053,8 @@ impl SessionActor {
                 products_inserted: self.products_inserted,
                 products_updated: self.products_updated,
                 final_state: format!("{:?}", self.state),
+                completion_reason: CompletionReason::Finished,
+                limit_reached: None,
                 timestamp: Utc::now(),
             }
         })
@@ -1084,6 +1146,7 @@ impl Actor for SessionActor {
                                                 self.start_time = Some(Instant::now());
                                                 let start_event = AppEvent::SessionStarted { session_id: session_id.clone(), config: CrawlingConfig { site_url: "preplanned".into(), start_page: 1, end_page: 1, concurrency_limit: plan.concurrency_limit, batch_size: plan.batch_size, request_delay_ms: 0, timeout_secs: 300, max_retries: 3, strategy: crate::crawl_engine::actors::types::CrawlingStrategy::NewestFirst }, timestamp: Utc::now() };
                                                 if let Err(e) = context.emit_event(start_event) { error!("Failed to emit start event: {}", e); }
+                                                self.start_watchdog(&context);
                                                 let site_status = plan.input_snapshot_to_site_status();
                                                 for (idx, range) in plan.crawling_ranges.iter().enumerate() {
                                                     let pages: Vec<u32> = if range.reverse_order { (range.start_page..=range.end_page).rev().collect() } else { (range.start_page..=range.end_page).collect() };
@@ -1091,12 +1154,15 @@ impl Actor for SessionActor {
                                                     if let Err(e) = self.run_batch_with_services(&batch_id, &pages, &context, &http_client, &data_extractor, &product_repo, &site_status, Some(plan.skip_duplicate_urls)).await {
                                                         error!("Batch {} failed: {}", batch_id, e);
                                                         self.errors.push(format!("batch {}: {}", batch_id, e));
+                                                        if let SessionError::SupervisionFailed(reason) = &e { self.state = SessionState::Failed { error: reason.clone() }; break; }
                                                         let fail_event = AppEvent::SessionFailed { session_id: session_id.clone(), error: format!("Batch {} failed: {}", batch_id, e), final_failure: false, timestamp: Utc::now() };
                                                         if let Err(er) = context.emit_event(fail_event) { error!("emit batch fail event error: {}", er); }
                                                     }
                                                     self.processed_batches += 1; self.total_success_count += pages.len() as u32;
                                                     // BatchReport 이벤트에서 누적 중복 스킵을 수신할 수 없으므로 여기서는 BatchActor 내부 누적이 반영된 값 없. 향후 이벤트 브릿지에서 BatchReport 수신 시 합산.
                                                 }
+                                                // 감독자가 세션을 최종 실패로 올렸으면 완료 처리 없이 끝낸다
+                                                if matches!(self.state, SessionState::Failed { .. }) { continue; }
                                                 let duration_ms = self.start_time.map(|t| t.elapsed().as_millis() as u64).unwrap_or(0);
                                                 self.state = SessionState::Completed;
                                                 // 에러 집계 (동일 로직 재사용)
@@ -1104,12 +1170,12 @@ impl Actor for SessionActor {
                                                 let mut map: BTreeMap<String, (u32, chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>)> = BTreeMap::new();
                                                 for e in &self.errors { let now = chrono::Utc::now(); map.entry(e.clone()).and_modify(|entry| { entry.0 += 1; entry.2 = now; }).or_insert((1, now, now)); }
                                                 let aggregated: Vec<crate::crawl_engine::actors::types::ErrorSummary> = map.into_iter().map(|(k,(count, first, last))| crate::crawl_engine::actors::types::ErrorSummary { error_type: k, count, first_occurrence: first, last_occurrence: last }).collect();
-                                                let summary = SessionSummary { session_id: session_id.clone(), total_duration_ms: duration_ms, total_pages_processed: self.total_success_count, total_products_processed: 0, success_rate: 1.0, avg_page_processing_time: if self.total_success_count>0 { duration_ms / self.total_success_count as u64 } else {0}, error_summary: aggregated, processed_batches: self.processed_batches, total_success_count: self.total_success_count, duplicates_skipped: self.duplicates_skipped, planned_list_batches: self.processed_batches, executed_list_batches: self.processed_batches, failed_pages_count: 0, failed_page_ids: Vec::new(), total_retry_events: 0, max_retries_single_page: 0, pages_retried: 0, retry_histogram: Vec::new(), products_inserted: 0, products_updated: 0, final_state: "completed".into(), timestamp: Utc::now() };
+                                                let summary = SessionSummary { session_id: session_id.clone(), total_duration_ms: duration_ms, total_pages_processed: self.total_success_count, total_products_processed: 0, success_rate: 1.0, avg_page_processing_time: if self.total_success_count>0 { duration_ms / self.total_success_count as u64 } else {0}, error_summary: aggregated, processed_batches: self.processed_batches, total_success_count: self.total_success_count, duplicates_skipped: self.duplicates_skipped, planned_list_batches: self.processed_batches, executed_list_batches: self.processed_batches, failed_pages_count: 0, failed_page_ids: Vec::new(), latency: latency::session_stats(&session_id), total_retry_events: 0, max_retries_single_page: 0, pages_retried: 0, retry_histogram: Vec::new(), products_inserted: 0, products_updated: 0, final_state: "completed".into(), completion_reason: CompletionReason::Finished, limit_reached: None, timestamp: Utc::now() };
                                                 if let Err(e) = context.emit_event(AppEvent::SessionCompleted { session_id: session_id.clone(), summary: summary.clone(), timestamp: Utc::now() }) { error!("emit completion event failed: {}", e); }
                                                 if let Err(e) = context.emit_event(AppEvent::CrawlReportSession { session_id: session_id.clone(), batches_processed: self.processed_batches, total_pages: self.total_success_count, total_success: self.total_success_count, total_failed: 0, total_retries: 0, duration_ms, products_inserted: 0, products_updated: 0, timestamp: Utc::now() }) { error!("emit crawl report failed: {}", e); }
                                                 // KPI JSON (events.log)
                                                 info!(target: "kpi.session",
-                                                    "{{\"event\":\"session_final_summary\",\"session_id\":\"{}\",\"final_state\":\"{}\",\"duration_ms\":{},\"processed_batches\":{},\"total_pages_processed\":{},\"total_success_count\":{},\"failed_pages_count\":{},\"total_retry_events\":{},\"products_inserted\":{},\"products_updated\":{},\"duplicates_skipped\":{},\"plan_hash\":null,\"ts\":\"{}\"}}",
+                                                    "{{\"event\":\"session_final_summary\",\"session_id\":\"{}\",\"final_state\":\"{}\",\"duration_ms\":{},\"processed_batches\":{},\"total_pages_processed\":{},\"total_success_count\":{},\"failed_pages_count\":{},\"total_retry_events\":{},\"products_inserted\":{},\"products_updated\":{},\"duplicates_skipped\":{},\"latency\":{},\"plan_hash\":null,\"ts\":\"{}\"}}",
                                                     summary.session_id,
                                                     summary.final_state,
                                                     summary.total_duration_ms,
@@ -1121,8 +1187,10 @@ impl Actor for SessionActor {
                                                     summary.products_inserted,
                                                     summary.products_updated,
                                                     summary.duplicates_skipped,
+                                                    latency::kpi_json(&summary.latency),
                                                     chrono::Utc::now()
                                                 );
+                                                latency::finish_session(&summary.session_id);
                                                 // Human-readable mirror (back_front.log)
                                                 info!(
                                                     "📊 Session Final Summary | session_id={} state={} duration_ms={} batches={} pages_processed={} success={} failed={} retries={} inserted={} updated={} duplicates={} ts={}",
@@ -1219,6 +1287,7 @@ impl Actor for SessionActor {
 
             let _ = context.emit_event(completion_event);
         }
+        self.stop_watchdog();
 
         info!("🏁 SessionActor {} execution loop ended", self.actor_id);
         Ok(())
@@ -1277,3 +1346,260 @@ impl Actor for SessionActor {
         Ok(())
     }
 }
+
+// ===== 세션 체크포인트 (재개 가능한 크롤링) =====
+
+/// 세션 체크포인트 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckpointStatus {
+    /// 진행 중 (중단 시 재개 대상)
+    Active,
+    /// 정상 완료 (재개 불가)
+    Completed,
+}
+
+/// 실패한 배치의 페이지를 재개 대상으로 남겨 두는 최대 횟수
+pub const MAX_CHECKPOINT_PAGE_ATTEMPTS: u32 = 3;
+
+/// 세션 체크포인트: 장시간 크롤링 중단(크래시/종료) 후 이어서 실행하기 위한 영속 스냅샷
+///
+/// 배치 완료 시마다 `session_checkpoints` 테이블에 저장되며, 원본 ExecutionPlan과
+/// 아직 처리되지 않은 물리 페이지 목록, 재시도 카운터를 함께 보관한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionCheckpoint {
+    pub session_id: String,
+    /// 최초 실행 시의 ExecutionPlan (범위 방향/스냅샷 복원용)
+    pub plan: ExecutionPlan,
+    pub status: CheckpointStatus,
+    /// 완료된 배치 수 (재개 이전 실행분 포함)
+    pub completed_batches: u32,
+    /// 처리 완료된 물리 페이지 (성공/최종 실패 모두 포함)
+    pub completed_pages: Vec<u32>,
+    /// 아직 처리되지 않은 물리 페이지 (실행 순서 유지)
+    pub pending_pages: Vec<u32>,
+    pub retries_per_page: HashMap<u32, u32>,
+    /// 실패한 배치에 포함된 횟수 (MAX_CHECKPOINT_PAGE_ATTEMPTS 미만이면 재개 시 다시 실행)
+    #[serde(default)]
+    pub failed_attempts: HashMap<u32, u32>,
+    /// 시도 한도를 소진한 최종 실패 페이지
+    pub failed_pages: Vec<u32>,
+    /// 재개 횟수
+    pub resume_count: u32,
+    pub updated_at: chrono::DateTime<Utc>,
+    /// 세션이 실행된 워크스페이스 (워크스페이스 도입 이전 체크포인트는 None)
+    #[serde(default)]
+    pub workspace: Option<String>,
+}
+
+impl SessionCheckpoint {
+    /// ExecutionPlan의 모든 범위를 pending 으로 하는 초기 체크포인트
+    pub fn new(plan: &ExecutionPlan) -> Self {
+        let pending_pages = plan.crawling_ranges.iter().flat_map(range_pages).collect();
+        Self {
+            session_id: plan.session_id.clone(),
+            plan: plan.clone(),
+            status: CheckpointStatus::Active,
+            completed_batches: 0,
+            completed_pages: Vec::new(),
+            pending_pages,
+            retries_per_page: HashMap::new(),
+            failed_attempts: HashMap::new(),
+            failed_pages: Vec::new(),
+            resume_count: 0,
+            updated_at: Utc::now(),
+            workspace: Some(crate::infrastructure::database_paths::active_workspace()),
+        }
+    }
+
+    /// 배치 처리 결과 반영. 성공 페이지는 pending 에서 제거하고, 실패 페이지는 시도 한도
+    /// (MAX_CHECKPOINT_PAGE_ATTEMPTS) 전까지 pending 에 남겨 재개 시 다시 실행한다.
+    pub fn record_batch(&mut self, pages: &[u32], failed: bool) {
+        self.completed_batches += 1;
+        for p in pages {
+            if failed {
+                let attempts = self.failed_attempts.entry(*p).or_insert(0);
+                *attempts += 1;
+                if *attempts < MAX_CHECKPOINT_PAGE_ATTEMPTS {
+                    continue;
+                }
+                if !self.failed_pages.contains(p) {
+                    self.failed_pages.push(*p);
+                }
+            }
+            self.pending_pages.retain(|q| q != p);
+            if !self.completed_pages.contains(p) {
+                self.completed_pages.push(*p);
+            }
+        }
+        self.updated_at = Utc::now();
+    }
+
+    /// 남은 페이지를 원본 범위 방향을 유지한 연속 구간으로 재구성
+    pub fn pending_ranges(&self) -> Vec<PageRange> {
+        let mut ranges = Vec::new();
+        for original in &self.plan.crawling_ranges {
+            let pages: Vec<u32> = range_pages(original)
+                .into_iter()
+                .filter(|p| self.pending_pages.contains(p))
+                .collect();
+            let mut run: Vec<u32> = Vec::new();
+            for p in pages {
+                let contiguous = run.last().is_none_or(|&last| last.abs_diff(p) == 1);
+                if !contiguous {
+                    ranges.push(run_to_range(&run, original.reverse_order));
+                    run.clear();
+                }
+                run.push(p);
+            }
+            if !run.is_empty() {
+                ranges.push(run_to_range(&run, original.reverse_order));
+            }
+        }
+        ranges
+    }
+}
+
+/// 범위에 포함된 물리 페이지 (실행 순서: start_page -> end_page)
+fn range_pages(range: &PageRange) -> Vec<u32> {
+    if range.start_page > range.end_page {
+        (range.end_page..=range.start_page).rev().collect()
+    } else {
+        (range.start_page..=range.end_page).collect()
+    }
+}
+
+fn run_to_range(run: &[u32], reverse_order: bool) -> PageRange {
+    let start_page = run.first().copied().unwrap_or_default();
+    let end_page = run.last().copied().unwrap_or_default();
+    PageRange {
+        start_page,
+        end_page,
+        estimated_products: run.len() as u32 * 12,
+        reverse_order,
+    }
+}
+
+/// 체크포인트 저장 (session_id 기준 upsert)
+pub async fn save_session_checkpoint(
+    pool: &SqlitePool,
+    checkpoint: &SessionCheckpoint,
+) -> Result<(), SessionError> {
+    let payload = serde_json::to_string(checkpoint)
+        .map_err(|e| SessionError::CheckpointError(e.to_string()))?;
+    let status = match checkpoint.status {
+        CheckpointStatus::Active => "active",
+        CheckpointStatus::Completed => "completed",
+    };
+    sqlx::query(
+        "INSERT INTO session_checkpoints (session_id, plan_hash, status, payload, updated_at) \
+         VALUES (?, ?, ?, ?, ?) \
+         ON CONFLICT(session_id) DO UPDATE SET plan_hash=excluded.plan_hash, status=excluded.status, \
+         payload=excluded.payload, updated_at=excluded.updated_at",
+    )
+    .bind(&checkpoint.session_id)
+    .bind(&checkpoint.plan.plan_hash)
+    .bind(status)
+    .bind(payload)
+    .bind(checkpoint.updated_at.to_rfc3339())
+    .execute(pool)
+    .await
+    .map(|_| ())
+    .map_err(|e| SessionError::CheckpointError(e.to_string()))
+}
+
+/// 체크포인트 로드 (없으면 None)
+pub async fn load_session_checkpoint(
+    pool: &SqlitePool,
+    session_id: &str,
+) -> Result<Option<SessionCheckpoint>, SessionError> {
+    let payload: Option<String> =
+        sqlx::query_scalar("SELECT payload FROM session_checkpoints WHERE session_id = ?")
+            .bind(session_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| SessionError::CheckpointError(e.to_string()))?;
+    payload
+        .map(|p| serde_json::from_str(&p).map_err(|e| SessionError::CheckpointError(e.to_string())))
+        .transpose()
+}
+
+#[cfg(test)]
+mod checkpoint_tests {
+    use super::*;
+    use crate::crawl_engine::actors::types::PlanInputSnapshot;
+    use crate::crawl_engine::runtime::crawl_limits::CrawlLimits;
+
+    fn plan(ranges: Vec<PageRange>) -> ExecutionPlan {
+        ExecutionPlan {
+            plan_id: "plan".into(),
+            session_id: "s1".into(),
+            crawling_ranges: ranges,
+            batch_size: 3,
+            concurrency_limit: 1,
+            estimated_duration_secs: 0,
+            created_at: Utc::now(),
+            analysis_summary: String::new(),
+            original_strategy: "NewestFirst".into(),
+            input_snapshot: PlanInputSnapshot {
+                total_pages: 20,
+                products_on_last_page: 12,
+                db_max_page_id: None,
+                db_max_index_in_page: None,
+                db_total_products: 0,
+                page_range_limit: 20,
+                batch_size: 3,
+                concurrency_limit: 1,
+                created_at: Utc::now(),
+            },
+            plan_hash: "hash".into(),
+            skip_duplicate_urls: false,
+            stage_flags: Vec::new(),
+            limits: CrawlLimits::default(),
+            kpi_meta: None,
+            contract_version: crate::crawl_engine::actors::contract::ACTOR_CONTRACT_VERSION,
+            page_slots: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn pending_ranges_skip_completed_batches_and_keep_direction() {
+        let mut cp = SessionCheckpoint::new(&plan(vec![PageRange {
+            start_page: 20,
+            end_page: 11,
+            estimated_products: 120,
+            reverse_order: true,
+        }]));
+        cp.record_batch(&[20, 19, 18], false);
+        cp.record_batch(&[15, 14, 13], false);
+        let ranges = cp.pending_ranges();
+        assert_eq!(ranges.len(), 2);
+        assert_eq!((ranges[0].start_page, ranges[0].end_page), (17, 16));
+        assert_eq!((ranges[1].start_page, ranges[1].end_page), (12, 11));
+        assert!(ranges.iter().all(|r| r.reverse_order));
+        assert_eq!(cp.completed_batches, 2);
+    }
+
+    #[test]
+    fn failed_pages_stay_pending_until_attempts_run_out() {
+        let mut cp = SessionCheckpoint::new(&plan(vec![PageRange {
+            start_page: 20,
+            end_page: 15,
+            estimated_products: 72,
+            reverse_order: true,
+        }]));
+        cp.record_batch(&[20, 19, 18], false);
+        cp.record_batch(&[17, 16, 15], true);
+        let ranges = cp.pending_ranges();
+        assert_eq!(ranges.len(), 1);
+        assert_eq!((ranges[0].start_page, ranges[0].end_page), (17, 15));
+        assert!(cp.failed_pages.is_empty());
+
+        for _ in 1..MAX_CHECKPOINT_PAGE_ATTEMPTS {
+            cp.record_batch(&[17, 16, 15], true);
+        }
+        assert!(cp.pending_ranges().is_empty());
+        assert_eq!(cp.failed_pages, vec![17, 16, 15]);
+        assert_eq!(cp.failed_attempts[&16], MAX_CHECKPOINT_PAGE_ATTEMPTS);
+    }
+}
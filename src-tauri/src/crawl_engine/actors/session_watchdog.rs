@@ -0,0 +1,373 @@
+//! 세션 워치독: 스테이지별 마지막 진행 시각 추적 + 정체 감지
+//!
+//! 오류 없이 20분 넘게 진행이 멈춘 세션이 있었다. SessionActor 가 세션마다 띄우는 워치독은 이벤트
+//! 채널을 구독해 실행 중인 스테이지별 마지막 진행 시각(StageStarted / StageItem* / StageRetrying)을
+//! 기록하고, `idle_threshold_secs` 동안 진행이 없으면 `SessionStalled` 경고를 낸다. `action` 이
+//! `retry` 면 감독자에게 그 스테이지를 재시작시키고, `cancel` 이면 중단시켜 세션을 최종 실패로
+//! 끝낸다. 일시정지 중에는 시계를 멈추고, 정체 한 번에 경고는 한 번만 낸다.
+
+use crate::crawl_engine::actor_system::supervisor::{self, Intervention};
+use crate::crawl_engine::actors::types::{AppEvent, StageType};
+use crate::crawl_engine::context::AppContext;
+use crate::crawl_engine::runtime::task_registry::{SESSION_TASK_LIFETIME, spawn_tracked};
+use crate::infrastructure::config::{AppConfig, ConfigManager};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// Upper bound on how often stages are checked for stalls
+const MAX_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// What the watchdog does beyond the `SessionStalled` warning
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StallAction {
+    #[default]
+    Warn,
+    /// Restart the stuck stage through its supervisor
+    Retry,
+    /// Stop the stuck stage and fail the session
+    Cancel,
+}
+
+/// `advanced.session_watchdog`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchdogConfig {
+    /// A running stage without progress for this long is stalled; 0 disables the watchdog
+    #[serde(default = "WatchdogConfig::default_idle_threshold_secs")]
+    pub idle_threshold_secs: u64,
+    #[serde(default)]
+    pub action: StallAction,
+}
+
+impl WatchdogConfig {
+    fn default_idle_threshold_secs() -> u64 {
+        900
+    }
+
+    pub fn idle_threshold(&self) -> Option<Duration> {
+        (self.idle_threshold_secs > 0).then(|| Duration::from_secs(self.idle_threshold_secs))
+    }
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            idle_threshold_secs: Self::default_idle_threshold_secs(),
+            action: StallAction::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct StageProgress {
+    batch_id: Option<String>,
+    last_progress: Instant,
+    warned: bool,
+}
+
+/// A stage that went past the idle threshold
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stall {
+    pub stage_type: StageType,
+    pub batch_id: Option<String>,
+    pub idle: Duration,
+}
+
+/// Last-progress timestamps of one session's running stages
+#[derive(Debug)]
+pub struct StageProgressTracker {
+    session_id: String,
+    stages: HashMap<StageType, StageProgress>,
+    paused_at: Option<Instant>,
+}
+
+impl StageProgressTracker {
+    pub fn new(session_id: impl Into<String>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            stages: HashMap::new(),
+            paused_at: None,
+        }
+    }
+
+    fn touch(&mut self, stage_type: &StageType, batch_id: &Option<String>, now: Instant) {
+        let progress = self
+            .stages
+            .entry(stage_type.clone())
+            .or_insert_with(|| StageProgress {
+                batch_id: batch_id.clone(),
+                last_progress: now,
+                warned: false,
+            });
+        progress.batch_id = batch_id.clone();
+        progress.last_progress = now;
+        progress.warned = false;
+    }
+
+    /// Count every running stage as progressing (e.g. after missing events)
+    pub fn touch_all(&mut self, now: Instant) {
+        for progress in self.stages.values_mut() {
+            progress.last_progress = now;
+            progress.warned = false;
+        }
+    }
+
+    pub fn observe(&mut self, event: &AppEvent, now: Instant) {
+        match event {
+            AppEvent::StageStarted {
+                session_id,
+                stage_type,
+                batch_id,
+                ..
+            }
+            | AppEvent::StageItemStarted {
+                session_id,
+                stage_type,
+                batch_id,
+                ..
+            }
+            | AppEvent::StageItemCompleted {
+                session_id,
+                stage_type,
+                batch_id,
+                ..
+            }
+            | AppEvent::StageRetrying {
+                session_id,
+                stage_type,
+                batch_id,
+                ..
+            } if *session_id == self.session_id => self.touch(stage_type, batch_id, now),
+            AppEvent::StageCompleted {
+                session_id,
+                stage_type,
+                ..
+            }
+            | AppEvent::StageFailed {
+                session_id,
+                stage_type,
+                ..
+            } if *session_id == self.session_id => {
+                self.stages.remove(stage_type);
+            }
+            AppEvent::SessionPaused { session_id, .. } if *session_id == self.session_id => {
+                self.paused_at.get_or_insert(now);
+            }
+            AppEvent::SessionResumed { session_id, .. } if *session_id == self.session_id => {
+                if let Some(paused_at) = self.paused_at.take() {
+                    let paused_for = now.saturating_duration_since(paused_at);
+                    for progress in self.stages.values_mut() {
+                        progress.last_progress += paused_for;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Stages idle longer than `threshold` that have not been reported yet
+    pub fn take_stalls(&mut self, now: Instant, threshold: Duration) -> Vec<Stall> {
+        if self.paused_at.is_some() {
+            return Vec::new();
+        }
+        let mut stalls = Vec::new();
+        for (stage_type, progress) in &mut self.stages {
+            let idle = now.saturating_duration_since(progress.last_progress);
+            if idle > threshold && !progress.warned {
+                progress.warned = true;
+                stalls.push(Stall {
+                    stage_type: stage_type.clone(),
+                    batch_id: progress.batch_id.clone(),
+                    idle,
+                });
+            }
+        }
+        stalls
+    }
+}
+
+async fn load_watchdog_config() -> WatchdogConfig {
+    let config = match ConfigManager::new() {
+        Ok(manager) => manager.load_config().await.unwrap_or_else(|e| {
+            warn!("⚠️ Watchdog config unavailable, using defaults: {}", e);
+            AppConfig::default()
+        }),
+        Err(e) => {
+            warn!("⚠️ Watchdog config unavailable, using defaults: {}", e);
+            AppConfig::default()
+        }
+    };
+    config.advanced.session_watchdog
+}
+
+fn is_session_end(event: &AppEvent, session_id: &str) -> bool {
+    match event {
+        AppEvent::SessionCompleted {
+            session_id: sid, ..
+        } => sid == session_id,
+        AppEvent::SessionFailed {
+            session_id: sid,
+            final_failure,
+            ..
+        } => *final_failure && sid == session_id,
+        _ => false,
+    }
+}
+
+/// Watch the session in `context` until it completes or fails; abort the handle to stop early
+pub fn spawn_session_watchdog(context: AppContext) -> JoinHandle<()> {
+    let mut rx = context.subscribe_events();
+    let session_id = context.session_id.clone();
+    spawn_tracked(
+        format!("session-watchdog:{}", session_id),
+        Some(SESSION_TASK_LIFETIME),
+        async move {
+            let config = load_watchdog_config().await;
+            let Some(threshold) = config.idle_threshold() else {
+                debug!("🐕 Session watchdog disabled for {}", session_id);
+                return;
+            };
+            let mut tracker = StageProgressTracker::new(session_id.clone());
+            let mut check = tokio::time::interval((threshold / 4).min(MAX_CHECK_INTERVAL));
+            loop {
+                tokio::select! {
+                    received = rx.recv() => match received {
+                        Ok(event) if is_session_end(&event, &session_id) => break,
+                        Ok(event) => tracker.observe(&event, Instant::now()),
+                        Err(broadcast::error::RecvError::Lagged(_)) => {
+                            tracker.touch_all(Instant::now())
+                        }
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                    _ = check.tick() => {
+                        for stall in tracker.take_stalls(Instant::now(), threshold) {
+                            report_stall(&context, &config, threshold, stall);
+                        }
+                    }
+                }
+            }
+            // Drop a request the stage never picked up so it cannot hit a later session
+            supervisor::take_intervention(&session_id);
+        },
+    )
+}
+
+fn report_stall(context: &AppContext, config: &WatchdogConfig, threshold: Duration, stall: Stall) {
+    warn!(
+        "🐕 Session {} stalled: {} without progress for {}s (action={:?})",
+        context.session_id,
+        stall.stage_type.as_str(),
+        stall.idle.as_secs(),
+        config.action
+    );
+    let reason = format!(
+        "watchdog: {} idle for {}s",
+        stall.stage_type.as_str(),
+        stall.idle.as_secs()
+    );
+    let _ = context.emit_event(AppEvent::SessionStalled {
+        session_id: context.session_id.clone(),
+        batch_id: stall.batch_id,
+        stage_type: stall.stage_type,
+        idle_secs: stall.idle.as_secs(),
+        threshold_secs: threshold.as_secs(),
+        action: config.action,
+        timestamp: Utc::now(),
+    });
+    match config.action {
+        StallAction::Warn => {}
+        StallAction::Retry => {
+            supervisor::intervene(&context.session_id, Intervention::Restart(reason))
+        }
+        StallAction::Cancel => {
+            supervisor::intervene(&context.session_id, Intervention::Stop(reason))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage_event(stage_type: StageType, started: bool) -> AppEvent {
+        if started {
+            AppEvent::StageStarted {
+                stage_type,
+                session_id: "s1".into(),
+                batch_id: Some("b1".into()),
+                items_count: 3,
+                timestamp: Utc::now(),
+            }
+        } else {
+            AppEvent::StageFailed {
+                stage_type,
+                session_id: "s1".into(),
+                batch_id: Some("b1".into()),
+                error: "boom".into(),
+                error_code: None,
+                timestamp: Utc::now(),
+            }
+        }
+    }
+
+    #[test]
+    fn reports_each_stall_once_and_ignores_paused_time() {
+        let threshold = Duration::from_secs(60);
+        let t0 = Instant::now();
+        let mut tracker = StageProgressTracker::new("s1");
+        tracker.observe(&stage_event(StageType::ListPageCrawling, true), t0);
+        tracker.observe(&stage_event(StageType::ProductDetailCrawling, true), t0);
+        tracker.observe(&stage_event(StageType::ProductDetailCrawling, false), t0);
+        assert!(
+            tracker
+                .take_stalls(t0 + Duration::from_secs(30), threshold)
+                .is_empty()
+        );
+
+        let stalls = tracker.take_stalls(t0 + Duration::from_secs(61), threshold);
+        assert_eq!(stalls.len(), 1);
+        assert_eq!(stalls[0].stage_type, StageType::ListPageCrawling);
+        assert_eq!(stalls[0].batch_id.as_deref(), Some("b1"));
+        assert!(
+            tracker
+                .take_stalls(t0 + Duration::from_secs(90), threshold)
+                .is_empty()
+        );
+
+        // Progress re-arms the stage; a 10 minute pause does not count as idle
+        let t1 = t0 + Duration::from_secs(100);
+        tracker.observe(&stage_event(StageType::ListPageCrawling, true), t1);
+        let paused = AppEvent::SessionPaused {
+            session_id: "s1".into(),
+            reason: "user".into(),
+            timestamp: Utc::now(),
+        };
+        tracker.observe(&paused, t1 + Duration::from_secs(10));
+        assert!(
+            tracker
+                .take_stalls(t1 + Duration::from_secs(600), threshold)
+                .is_empty()
+        );
+        let resumed = AppEvent::SessionResumed {
+            session_id: "s1".into(),
+            timestamp: Utc::now(),
+        };
+        tracker.observe(&resumed, t1 + Duration::from_secs(610));
+        assert!(
+            tracker
+                .take_stalls(t1 + Duration::from_secs(650), threshold)
+                .is_empty()
+        );
+        assert_eq!(
+            tracker
+                .take_stalls(t1 + Duration::from_secs(700), threshold)
+                .len(),
+            1
+        );
+    }
+}
@@ -13,17 +13,23 @@ use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+use crate::crawl_engine::actor_system::Heartbeat;
 use crate::crawl_engine::actors::traits::{Actor, ActorHealth, ActorStatus, ActorType};
 use crate::crawl_engine::actors::types::{
     ActorCommand, ActorError, AppEvent, SimpleMetrics, StageError, StageItemResult, StageItemType,
     StageResult, StageType,
 };
+use crate::crawl_engine::channels::priority::{self, PriorityQueue};
 use crate::crawl_engine::channels::types::StageItem;
 use crate::crawl_engine::integrated_context::AppContext;
+use crate::crawl_engine::runtime::site_hints::PaginationHints;
+use crate::crawl_engine::runtime::write_coalescer::session_write_coalescer;
+use crate::crawl_engine::services::performance_optimizer::{self, AdaptiveConcurrencyConfig};
 use crate::crawl_engine::stages::DefaultStageLogicFactory;
 use crate::crawl_engine::stages::traits::StageLogicFactory;
 use crate::domain::services::SiteStatus;
 use crate::domain::services::{ProductDetailCollector, ProductListCollector, StatusChecker};
+use crate::crawl_engine::runtime::task_registry::{PAGE_WORKER_LIFETIME, spawn_tracked};
 use crate::infrastructure::config::AppConfig;
 use crate::infrastructure::crawling_service_impls::{
     CollectorConfig, ProductDetailCollectorImpl, ProductListCollectorImpl, StatusCheckerImpl,
@@ -48,6 +54,18 @@ pub struct StageDeps {
 static DATA_SAVING_RUN_GUARD: Lazy<StdMutex<HashSet<String>>> =
     Lazy::new(|| StdMutex::new(HashSet::new()));
 
+/// Aborts item tasks still running when the stage future is dropped
+/// (supervisor restart or overall timeout), so a restarted stage never races its predecessor
+struct AbortItemsOnDrop(Vec<tokio::task::AbortHandle>);
+
+impl Drop for AbortItemsOnDrop {
+    fn drop(&mut self) {
+        for handle in &self.0 {
+            handle.abort();
+        }
+    }
+}
+
 /// 스테이지 상태 열거형 (local to StageActor)
 #[derive(Debug, Clone, PartialEq)]
 enum StageState {
@@ -87,9 +105,11 @@ pub struct StageActor {
     data_extractor: Option<Arc<MatterDataExtractor>>, // HTML 파서
     app_config: Option<AppConfig>,                  // 앱 설정
 
-    // 상위에서 주입되는 페이지네이션 힌트
-    site_total_pages_hint: Option<u32>,
-    products_on_last_page_hint: Option<u32>,
+    // 상위에서 주입되는 페이지네이션 힌트 (세션 상태 확인 시각 포함)
+    pagination_hints: Option<PaginationHints>,
+
+    // 감독자(Supervisor)가 주입하는 하트비트 (아이템 시작/완료마다 갱신)
+    heartbeat: Option<Heartbeat>,
 
     // 전략 분기 (Phase 3)
     strategy_factory: Arc<dyn StageLogicFactory + Send + Sync>,
@@ -159,68 +179,31 @@ impl StageItemExt for StageItem {
     }
 }
 
+/// Map a strategy error onto the StageError variant of its catalog category.
+/// The message keeps the `[E_...]` tag so `StageError::code()` recovers the exact code.
+fn strategy_error_to_stage_error(
+    e: crate::crawl_engine::stages::traits::StageLogicError,
+) -> StageError {
+    use crate::domain::error_catalog::{ErrorCategory, ErrorCode};
+    let code = e.code();
+    let text = e.to_string();
+    let message = if ErrorCode::from_tagged_message(&text).is_some() {
+        format!("Strategy error: {text}")
+    } else {
+        format!("Strategy error: {}", code.tag(text))
+    };
+    match (code, code.category()) {
+        (ErrorCode::ParseFailed, _) => StageError::ParsingError { message },
+        (ErrorCode::ValidationFailed, _) => StageError::ValidationError { message },
+        (_, ErrorCategory::Network) => StageError::NetworkError { message },
+        (_, ErrorCategory::Storage) => StageError::DatabaseError { message },
+        (_, ErrorCategory::Configuration) => StageError::ConfigurationError { message },
+        _ => StageError::GenericError { message },
+    }
+}
+
 #[allow(dead_code)]
 impl StageActor {
-    /// 공통 재시도 래퍼 (Exponential Backoff + Jitter) with telemetry
-    async fn retry_with_backoff<T, Fut, Op>(
-        &self,
-        context: &AppContext,
-        stage_type: StageType,
-        start_attempt: u32,
-        max_attempts: u32,
-        base_delay_ms: u64,
-        max_delay_ms: u64,
-        op: Op,
-    ) -> (Result<T, String>, u32)
-    where
-        Op: Fn() -> Fut,
-        Fut: std::future::Future<Output = Result<T, String>>,
-    {
-        let mut attempt = start_attempt;
-        loop {
-            if context.is_cancelled() {
-                return (Err("Operation cancelled".to_string()), attempt);
-            }
-
-            match op().await {
-                Ok(val) => return (Ok(val), attempt),
-                Err(err) => {
-                    if attempt < max_attempts {
-                        let next = attempt + 1;
-                        let _ = context.emit_event(AppEvent::StageRetrying {
-                            stage_type: stage_type.clone(),
-                            session_id: context.session_id.clone(),
-                            batch_id: Some(self.batch_id.clone()),
-                            attempt: next,
-                            max_attempts,
-                            reason: Some(err.clone()),
-                            timestamp: Utc::now(),
-                        });
-
-                        // exponential backoff: base * 2^(next-1)
-                        let factor = 1u64.checked_shl(next.saturating_sub(1)).unwrap_or(u64::MAX);
-                        let exp = base_delay_ms.saturating_mul(factor);
-                        let capped = std::cmp::min(exp, max_delay_ms);
-                        let jitter = if capped >= 10 {
-                            fastrand::u64(0..=(capped / 5))
-                        } else {
-                            0
-                        };
-                        let delay = capped.saturating_add(jitter);
-                        warn!(
-                            "🔁 {:?} attempt {}/{} after {}ms (reason: {})",
-                            stage_type, next, max_attempts, delay, err
-                        );
-                        tokio::time::sleep(Duration::from_millis(delay)).await;
-                        attempt = next;
-                        continue;
-                    } else {
-                        return (Err(err), attempt);
-                    }
-                }
-            }
-        }
-    }
     /// 새로운 StageActor 인스턴스 생성
     ///
     /// # Arguments
@@ -250,8 +233,8 @@ impl StageActor {
             http_client: None,
             data_extractor: None,
             app_config: None,
-            site_total_pages_hint: None,
-            products_on_last_page_hint: None,
+            pagination_hints: None,
+            heartbeat: None,
             strategy_factory: Arc::new(DefaultStageLogicFactory),
             duplicate_policy: crate::crawl_engine::actors::types::DuplicatePersistencePolicy::Skip,
         }
@@ -287,8 +270,8 @@ impl StageActor {
             data_extractor: Some(deps.data_extractor),
             app_config: Some(deps.app_config),
             // 정책은 이후 execute_real_database_storage 에서 사용하기 위해 필요 시 전파
-            site_total_pages_hint: None,
-            products_on_last_page_hint: None,
+            pagination_hints: None,
+            heartbeat: None,
             strategy_factory,
             duplicate_policy: deps.duplicate_policy,
         }
@@ -414,8 +397,8 @@ impl StageActor {
             http_client: Some(http_client),
             data_extractor: Some(data_extractor),
             app_config: Some(app_config),
-            site_total_pages_hint: None,
-            products_on_last_page_hint: None,
+            pagination_hints: None,
+            heartbeat: None,
             strategy_factory: Arc::new(DefaultStageLogicFactory),
             duplicate_policy: crate::crawl_engine::actors::types::DuplicatePersistencePolicy::Skip,
         }
@@ -458,8 +441,8 @@ impl StageActor {
             http_client: None,
             data_extractor: None,
             app_config: None,
-            site_total_pages_hint: None,
-            products_on_last_page_hint: None,
+            pagination_hints: None,
+            heartbeat: None,
             strategy_factory: Arc::new(DefaultStageLogicFactory),
             duplicate_policy: crate::crawl_engine::actors::types::DuplicatePersistencePolicy::Skip,
         }
@@ -467,11 +450,22 @@ impl StageActor {
 
     /// 사이트 페이지네이션 힌트 설정 (StatusCheck 결과를 상위에서 주입)
     pub fn set_site_pagination_hints(&mut self, total_pages: u32, products_on_last_page: u32) {
-        self.site_total_pages_hint = Some(total_pages);
-        self.products_on_last_page_hint = Some(products_on_last_page);
+        if let Some(hints) = PaginationHints::new(total_pages, products_on_last_page) {
+            self.set_pagination_hints(hints);
+        }
+    }
+
+    /// 감독자의 하트비트 연결; 진행이 멈추면 감독자가 이 액터를 중단하고 새로 만든다
+    pub fn set_heartbeat(&mut self, heartbeat: Heartbeat) {
+        self.heartbeat = Some(heartbeat);
+    }
+
+    /// 세션에서 관측한 힌트를 관측 시각 그대로 주입 (TTL 판단은 ListPageLogic 이 한다)
+    pub fn set_pagination_hints(&mut self, hints: PaginationHints) {
+        self.pagination_hints = Some(hints);
         info!(
             "🔧 Applied site pagination hints: total_pages={}, products_on_last_page={}",
-            total_pages, products_on_last_page
+            hints.total_pages, hints.products_on_last_page
         );
     }
 
@@ -708,7 +702,6 @@ impl StageActor {
                 Duration::from_secs(timeout_secs),
             )
             .await;
-        // TODO: Introduce a generic retry wrapper for stage-level retries and emit AppEvent::StageRetrying accordingly.
 
         match processing_result {
             Ok(stage_result) => {
@@ -741,6 +734,7 @@ impl StageActor {
                     session_id: context.session_id.clone(),
                     batch_id: Some(self.batch_id.clone()),
                     error: format!("{:?}", error),
+                    error_code: Some(error.code().to_string()),
                     timestamp: Utc::now(),
                 };
                 context
@@ -760,6 +754,7 @@ impl StageActor {
                     session_id: context.session_id.clone(),
                     batch_id: Some(self.batch_id.clone()),
                     error: error_msg,
+                    error_code: Some(e.code().to_string()),
                     timestamp: Utc::now(),
                 };
                 context
@@ -808,13 +803,27 @@ impl StageActor {
             }
         };
 
-        // 동시성 제어를 위한 세마포어
-        let semaphore = Arc::new(tokio::sync::Semaphore::new(concurrency_limit as usize));
+        // 동시성 제어: list/detail 은 AIMD 로 허용 수를 조정하는 적응형 리미터, 나머지는 고정
+        let adaptive_config = match stage_type {
+            StageType::ListPageCrawling | StageType::ProductDetailCrawling => {
+                app_config_clone.advanced.adaptive_concurrency.clone()
+            }
+            _ => AdaptiveConcurrencyConfig {
+                enabled: false,
+                ..Default::default()
+            },
+        };
+        let limiter = performance_optimizer::adaptive_limiter(
+            _context,
+            stage_type.clone(),
+            concurrency_limit,
+            &adaptive_config,
+        );
         // 전략/설정 및 의존성 복사
         let strategy_factory_clone = self.strategy_factory.clone();
     // 페이지네이션 힌트 복사 (Copy types; safe to move into tasks)
-    let site_total_pages_hint = self.site_total_pages_hint;
-    let products_on_last_page_hint = self.products_on_last_page_hint;
+    let pagination_hints = self.pagination_hints;
+        let heartbeat = self.heartbeat.clone();
     // Duplicate policy 사전 클론 (self를 태스크 내부에서 캡처하지 않기 위해)
     let duplicate_policy_base = self.duplicate_policy.clone();
 
@@ -823,9 +832,48 @@ impl StageActor {
         // join 전에 추후 abort 대상 추적을 위해 task handle 저장
         let mut handles: Vec<tokio::task::JoinHandle<Result<StageItemResult, StageError>>> =
             Vec::new();
+        let mut abort_items = AbortItemsOnDrop(Vec::new());
     let batch_id_owned = self.batch_id.clone();
-    for item in items {
-            let sem = semaphore.clone();
+        // 우선순위 큐 디스패치: 슬롯이 빌 때마다 가장 높은 우선순위 아이템부터 (부스트는 즉시 반영)
+        let boosts = priority::session_boosts(&_context.session_id).unwrap_or_default();
+        let mut queue = PriorityQueue::new();
+        for item in items {
+            let item_priority = boosts.priority_of(&item).unwrap_or_default();
+            queue.push(item, item_priority);
+        }
+        let mut seen_boosts = priority::boost_generation();
+        while !queue.is_empty() {
+            let permit = match tokio::time::timeout_at(
+                tokio::time::Instant::from_std(deadline),
+                limiter.acquire(),
+            )
+            .await
+            {
+                Ok(Ok(permit)) => permit,
+                Ok(Err(e)) => {
+                    return Err(StageError::GenericError {
+                        message: format!("Semaphore error: {}", e),
+                    });
+                }
+                Err(_) => {
+                    return Err(StageError::TimeoutError {
+                        timeout_ms: overall_timeout.as_millis() as u64,
+                    });
+                }
+            };
+            let generation = priority::boost_generation();
+            if generation != seen_boosts {
+                seen_boosts = generation;
+                if let Some(boosts) = priority::session_boosts(&_context.session_id) {
+                    let raised = queue.raise(|queued| boosts.priority_of(queued));
+                    if raised > 0 {
+                        info!("⏫ {} queued {:?} items boosted", raised, stage_type);
+                    }
+                }
+            }
+            let Some(item) = queue.pop() else {
+                break;
+            };
             let base_item = item.clone(); // used for lifecycle pre-emits
             let stage_type_clone = stage_type.clone();
             let product_repo_clone = product_repo.clone();
@@ -839,14 +887,20 @@ impl StageActor {
             let strategy_factory_iter = strategy_factory_clone.clone();
             let duplicate_policy = duplicate_policy_base.clone();
             // Copy pagination hints into the task scope (avoid referencing self)
-            let tp_hint = site_total_pages_hint;
-            let plp_hint = products_on_last_page_hint;
-            let task = tokio::spawn(async move {
+            let hints = pagination_hints;
+            let item_heartbeat = heartbeat.clone();
+            let task_name = format!(
+                "stage-item:{}:{}",
+                stage_type_clone.as_str(),
+                base_item.id_string()
+            );
+            let task = spawn_tracked(task_name, Some(PAGE_WORKER_LIFETIME), async move {
                 // Separate handle for persistence path to avoid moved value issues
                 let product_repo_for_persist = product_repo_clone.clone();
-                let _permit = sem.acquire().await.map_err(|e| StageError::GenericError {
-                    message: format!("Semaphore error: {}", e),
-                })?;
+                let _permit = permit;
+                if let Some(heartbeat) = &item_heartbeat {
+                    heartbeat.beat();
+                }
                 if let Err(e) = ctx_clone.emit_event(AppEvent::StageItemStarted {
                     session_id: session_id_clone.clone(),
                     batch_id: batch_id_opt.clone(),
@@ -922,30 +976,69 @@ impl StageActor {
 
                 let item_start = Instant::now();
 
-                // Strategy-only execution
+                // Strategy-only execution; retryable failures are retried per the stage's
+                // retry policy (stage-level retries are off unless the policy sets max_retries,
+                // since collectors already retry requests internally)
                 let factory = strategy_factory_iter;
                 let result = if let Some(logic) = factory.logic_for(&stage_type_clone) {
-                    let deps = crate::crawl_engine::stages::traits::Deps {
-                        http: http_client_clone.clone(),
-                        extractor: data_extractor_clone.clone(),
-                        repo: product_repo_clone.clone(),
-                        duplicate_policy: duplicate_policy.clone(),
-                    };
-                    let input = crate::crawl_engine::stages::traits::StageInput {
-                        stage_type: stage_type_clone.clone(),
-                        item: base_item.clone(),
-                        config: app_config_iter.clone(),
-                        deps,
-                        total_pages_hint: tp_hint,
-                        products_on_last_page_hint: plp_hint,
-                    };
-                    match logic.execute(input).await {
-                        Ok(crate::crawl_engine::stages::traits::StageOutput { result }) => {
-                            Ok(result)
+                    let retry_policies = &app_config_iter.advanced.retry_policies;
+                    let policy = retry_policies.for_stage(&stage_type_clone);
+                    let max_retries = policy.max_retries_or(0);
+                    let retry_budget = retry_policies.session_budget(&session_id_clone);
+                    let mut retries = 0u32;
+                    loop {
+                        let deps = crate::crawl_engine::stages::traits::Deps {
+                            http: http_client_clone.clone(),
+                            extractor: data_extractor_clone.clone(),
+                            repo: product_repo_clone.clone(),
+                            duplicate_policy: duplicate_policy.clone(),
+                        };
+                        let input = crate::crawl_engine::stages::traits::StageInput {
+                            stage_type: stage_type_clone.clone(),
+                            item: base_item.clone(),
+                            config: app_config_iter.clone(),
+                            deps,
+                            session_id: session_id_clone.clone(),
+                            pagination_hints: hints,
+                        };
+                        match logic.execute(input).await {
+                            Ok(crate::crawl_engine::stages::traits::StageOutput {
+                                mut result,
+                            }) => {
+                                result.retry_count = result.retry_count.saturating_add(retries);
+                                break Ok(result);
+                            }
+                            Err(e) => {
+                                if retries >= max_retries
+                                    || !e.code().is_retryable()
+                                    || ctx_clone.is_cancelled()
+                                    || !retry_budget.try_acquire()
+                                {
+                                    break Err(strategy_error_to_stage_error(e));
+                                }
+                                retries += 1;
+                                let delay = policy.delay(retries);
+                                let _ = ctx_clone.emit_event(AppEvent::StageRetrying {
+                                    stage_type: stage_type_clone.clone(),
+                                    session_id: session_id_clone.clone(),
+                                    batch_id: batch_id_opt.clone(),
+                                    attempt: retries + 1,
+                                    max_attempts: max_retries + 1,
+                                    reason: Some(e.to_string()),
+                                    timestamp: Utc::now(),
+                                });
+                                warn!(
+                                    "🔁 {:?} {} attempt {}/{} after {}ms (reason: {})",
+                                    stage_type_clone,
+                                    base_item.id_string(),
+                                    retries + 1,
+                                    max_retries + 1,
+                                    delay.as_millis(),
+                                    e
+                                );
+                                tokio::time::sleep(delay).await;
+                            }
                         }
-                        Err(e) => Err(StageError::GenericError {
-                            message: format!("Strategy error: {}", e),
-                        }),
                     }
                 } else {
                     Err(StageError::GenericError {
@@ -957,9 +1050,10 @@ impl StageActor {
                         // Emit Validation events in aggregate for DataValidation stage
                         if matches!(stage_type_clone, StageType::DataValidation) {
                             // Attempt to decode collected data to count items
+                            let collected = r.collected_json().await;
                             let (products_found, products_checked, divergences, anomalies) =
                                 (|| {
-                                    if let Some(json) = &r.collected_data {
+                                    if let Some(json) = collected.as_deref() {
                                         // collected_data for DataValidation is serialized validated products Vec<ProductDetail>
                                         let parsed: Result<
                                             Vec<crate::domain::product::ProductDetail>,
@@ -1015,10 +1109,10 @@ impl StageActor {
                                 timestamp: Utc::now(),
                             });
                             // Emit a few anomaly details to console if present
-                            if let Some(json) = &r.collected_data {
+                            if let Some(json) = collected {
                                 if let Ok(validated) = serde_json::from_str::<
                                     Vec<crate::domain::product::ProductDetail>,
-                                >(json)
+                                >(&json)
                                 {
                                     if let Ok(rep) = crate::crawl_engine::services::data_quality_analyzer::DataQualityAnalyzer::new().analyze_product_quality(&validated) {
                                         for issue in rep.issues.iter().take(3) {
@@ -1246,6 +1340,7 @@ impl StageActor {
                                         match StageActor::execute_real_database_storage(
                                             &lifecycle_item,
                                             repo.clone(),
+                                            &session_id_clone,
                                         )
                                         .await
                                         {
@@ -1486,7 +1581,7 @@ impl StageActor {
                             error: None,
                             duration_ms: item_start.elapsed().as_millis() as u64,
                             retry_count: r.retry_count,
-                            collected_count: r.collected_data.as_ref().map(|d| {
+                            collected_count: r.collected_json().await.map(|d| {
                                 // JSON 배열일 가능성 높음 → 대략 길이 추정 (간단 처리)
                                 if d.starts_with('[') {
                                     d.matches("\"").count() as u32 / 2
@@ -1623,8 +1718,12 @@ impl StageActor {
                         }
                     }
                 }
+                if let Some(heartbeat) = &item_heartbeat {
+                    heartbeat.beat();
+                }
                 result
             });
+            abort_items.0.push(task.abort_handle());
             handles.push(task);
         }
 
@@ -1651,6 +1750,9 @@ impl StageActor {
                     break;
                 }
             };
+            if let Some(heartbeat) = &heartbeat {
+                heartbeat.beat();
+            }
             match join_outcome {
                 Ok(Ok(result)) => {
                     results.push(result);
@@ -1754,12 +1856,9 @@ impl StageActor {
             StageItem::Page(page_number) => {
                 // 실제 리스트 페이지 크롤링
                 // 페이지네이션 힌트 사용, 없으면 필요 시 상태 재확인
-                let (total_pages, products_on_last_page) = match (
-                    self.site_total_pages_hint,
-                    self.products_on_last_page_hint,
-                ) {
-                    (Some(tp), Some(plp)) => (tp, plp),
-                    _ => {
+                let (total_pages, products_on_last_page) = match self.pagination_hints {
+                    Some(hints) => (hints.total_pages, hints.products_on_last_page),
+                    None => {
                         if let Some(checker) = &self.status_checker {
                             // StageActor는 AppHandle에 접근하지 않으므로 여기서는 직접 체크만 수행
                             match checker.check_site_status().await {
@@ -1910,12 +2009,15 @@ impl StageActor {
     }
 
     /// 실제 데이터베이스 저장 처리
+    ///
+    /// 세션 write coalescer에 URL 단위로 병합한 뒤, 이 item에 포함된 페이지를 한 번에 flush 한다.
     async fn execute_real_database_storage(
         item: &StageItem,
         product_repo: Arc<IntegratedProductRepository>,
+        session_id: &str,
     ) -> Result<(u32, u32, u32), String> {
         // (inserted, updated, duplicates)
-        match item {
+        let (products, label) = match item {
             StageItem::ProductDetails(wrapper) => {
                 info!(
                     "[PersistExec] handling ProductDetails count={} extraction_stats=attempted:{} success:{} failed:{}",
@@ -1924,168 +2026,102 @@ impl StageActor {
                     wrapper.extraction_stats.successful,
                     wrapper.extraction_stats.failed
                 );
-                // Debug a small sample of pagination coordinates to verify propagation
-                for (i, d) in wrapper.products.iter().take(3).enumerate() {
-                    debug!(
-                        "[PersistExecSample] idx={} url={} page_id={:?} index_in_page={:?}",
-                        i,
-                        d.url,
-                        d.page_id,
-                        d.index_in_page
-                    );
-                }
-                let products = &wrapper.products;
-                if products.is_empty() {
-                    return Ok((0, 0, 0));
-                }
-                // Duplicate detection by URL
-                let mut seen = std::collections::HashSet::new();
-                let mut duplicates: Vec<String> = Vec::new();
-                for d in products {
-                    if !seen.insert(d.url.clone()) {
-                        duplicates.push(d.url.clone());
-                    }
-                }
-                if !duplicates.is_empty() {
-                    warn!(
-                        "[PersistExec] duplicate urls detected count={} urls={:?}",
-                        duplicates.len(),
-                        duplicates
-                    );
-                }
-                let mut inserted = 0u32;
-                let mut updated = 0u32;
-                let mut duplicates_ct = 0u32;
-                for (idx, detail) in products.iter().enumerate() {
-                    let start = std::time::Instant::now();
-                    debug!(
-                        "[PersistExec] upsert detail idx={} url={} page_id={:?} index_in_page={:?}",
-                        idx, detail.url, detail.page_id, detail.index_in_page
-                    );
-                    match product_repo.create_or_update_product_detail(detail).await {
-                        Ok((was_updated, was_created)) => {
-                            if was_created {
-                                inserted += 1;
-                            }
-                            if was_updated {
-                                updated += 1;
-                            }
-                            if !was_created && !was_updated {
-                                // 중복(no-op) → 정책 적용: UpdateIdIndexOnly면 위치 강제 업데이트 시도
-                                let policy_env = std::env::var("MC_DUPLICATE_POLICY").ok();
-                                if matches!(policy_env.as_deref(), Some("UpdateIdIndexOnly")) {
-                                    if let (Some(pid), Some(idx)) = (detail.page_id, detail.index_in_page) {
-                                        if let Ok((prod_rows, det_rows)) = product_repo
-                                            .force_update_position_by_url(&detail.url, pid, idx)
-                                            .await
-                                        {
-                                            if det_rows > 0 || prod_rows > 0 {
-                                                updated += 1;
-                                                debug!("[PersistExecDetail] forced pos update idx={} url={} pid={} idx_in_page={} (prod_rows={}, det_rows={})", idx, detail.url, pid, idx, prod_rows, det_rows);
-                                                continue;
-                                            }
-                                        }
-                                    }
-                                }
-                                duplicates_ct += 1;
-                            }
-                            debug!(
-                                "[PersistExecDetail] idx={} url={} created={} updated={} elapsed_ms={}",
-                                idx,
-                                detail.url,
-                                was_created,
-                                was_updated,
-                                start.elapsed().as_millis()
-                            );
-                        }
-                        Err(e) => return Err(format!("Database save failed: {}", e)),
-                    }
-                }
-                Ok((inserted, updated, duplicates_ct))
+                (&wrapper.products, "")
             }
             StageItem::ValidatedProducts(wrapper) => {
                 info!(
                     "[PersistExec] handling ValidatedProducts count={}",
                     wrapper.products.len()
                 );
-                for (i, d) in wrapper.products.iter().take(3).enumerate() {
-                    debug!(
-                        "[PersistExecSample] validated idx={} url={} page_id={:?} index_in_page={:?}",
-                        i,
-                        d.url,
-                        d.page_id,
-                        d.index_in_page
-                    );
-                }
-                let products = &wrapper.products;
-                if products.is_empty() {
-                    return Ok((0, 0, 0));
-                }
-                let mut seen = std::collections::HashSet::new();
-                let mut duplicates: Vec<String> = Vec::new();
-                for d in products {
-                    if !seen.insert(d.url.clone()) {
-                        duplicates.push(d.url.clone());
-                    }
-                }
-                if !duplicates.is_empty() {
-                    warn!(
-                        "[PersistExec] duplicate validated urls detected count={} urls={:?}",
-                        duplicates.len(),
-                        duplicates
-                    );
-                }
-                let mut inserted = 0u32;
-                let mut updated = 0u32;
-                let mut duplicates_ct = 0u32;
-                for (idx, detail) in products.iter().enumerate() {
-                    let start = std::time::Instant::now();
-                    debug!(
-                        "[PersistExec] upsert validated detail idx={} url={} page_id={:?} index_in_page={:?}",
-                        idx, detail.url, detail.page_id, detail.index_in_page
-                    );
-                    match product_repo.create_or_update_product_detail(detail).await {
-                        Ok((was_updated, was_created)) => {
-                            if was_created {
-                                inserted += 1;
-                            }
-                            if was_updated {
-                                updated += 1;
-                            }
-                            if !was_created && !was_updated {
-                                let policy_env = std::env::var("MC_DUPLICATE_POLICY").ok();
-                                if matches!(policy_env.as_deref(), Some("UpdateIdIndexOnly")) {
-                                    if let (Some(pid), Some(idx)) = (detail.page_id, detail.index_in_page) {
-                                        if let Ok((prod_rows, det_rows)) = product_repo
-                                            .force_update_position_by_url(&detail.url, pid, idx)
-                                            .await
-                                        {
-                                            if det_rows > 0 || prod_rows > 0 {
-                                                updated += 1;
-                                                debug!("[PersistExecDetail] forced pos update(validated) idx={} url={} pid={} idx_in_page={} (prod_rows={}, det_rows={})", idx, detail.url, pid, idx, prod_rows, det_rows);
-                                                continue;
-                                            }
-                                        }
-                                    }
-                                }
-                                duplicates_ct += 1;
-                            }
+                (&wrapper.products, "validated ")
+            }
+            _ => return Ok((0, 0, 0)),
+        };
+        // Debug a small sample of pagination coordinates to verify propagation
+        for (i, d) in products.iter().take(3).enumerate() {
+            debug!(
+                "[PersistExecSample] {}idx={} url={} page_id={:?} index_in_page={:?}",
+                label, i, d.url, d.page_id, d.index_in_page
+            );
+        }
+        if products.is_empty() {
+            return Ok((0, 0, 0));
+        }
+        // Duplicate detection by URL (merged by the coalescer below)
+        let mut seen = std::collections::HashSet::new();
+        let mut duplicates: Vec<String> = Vec::new();
+        for d in products {
+            if !seen.insert(d.url.clone()) {
+                duplicates.push(d.url.clone());
+            }
+        }
+        if !duplicates.is_empty() {
+            warn!(
+                "[PersistExec] duplicate {}urls detected count={} urls={:?} (coalesced)",
+                label,
+                duplicates.len(),
+                duplicates
+            );
+        }
+
+        let coalescer = session_write_coalescer(session_id);
+        let mut pages: Vec<Option<i32>> = Vec::new();
+        for detail in products {
+            coalescer.stage(detail);
+            if !pages.contains(&detail.page_id) {
+                pages.push(detail.page_id);
+            }
+        }
+        let start = std::time::Instant::now();
+        let flush = coalescer
+            .flush_pages(&pages, &product_repo)
+            .await
+            .map_err(|e| format!("Database save failed: {}", e))?;
+        crate::services::session_export::record_session_products(session_id, products);
+        let mut updated = flush.updated;
+        // Staged upserts folded into another URL's write count as duplicates
+        let mut duplicates_ct = flush.writes_saved;
+        // 중복(no-op) → 정책 적용: UpdateIdIndexOnly면 위치 강제 업데이트 시도
+        let force_position = matches!(
+            std::env::var("MC_DUPLICATE_POLICY").ok().as_deref(),
+            Some("UpdateIdIndexOnly")
+        );
+        for detail in &flush.unchanged_details {
+            if force_position {
+                if let (Some(pid), Some(idx)) = (detail.page_id, detail.index_in_page) {
+                    if let Ok((prod_rows, det_rows)) = product_repo
+                        .force_update_position_by_url(&detail.url, pid, idx)
+                        .await
+                    {
+                        if det_rows > 0 || prod_rows > 0 {
+                            updated += 1;
                             debug!(
-                                "[PersistExecDetail] validated idx={} url={} created={} updated={} elapsed_ms={}",
-                                idx,
+                                "[PersistExecDetail] forced pos update({}) url={} pid={} idx_in_page={} (prod_rows={}, det_rows={})",
+                                label.trim(),
                                 detail.url,
-                                was_created,
-                                was_updated,
-                                start.elapsed().as_millis()
+                                pid,
+                                idx,
+                                prod_rows,
+                                det_rows
                             );
+                            continue;
                         }
-                        Err(e) => return Err(format!("Database save failed: {}", e)),
                     }
                 }
-                Ok((inserted, updated, duplicates_ct))
             }
-            _ => Ok((0, 0, 0)),
+            duplicates_ct += 1;
         }
+        debug!(
+            "[PersistExecDetail] {}pages={:?} inserted={} updated={} duplicates={} writes_saved={} elapsed_ms={}",
+            label,
+            pages,
+            flush.inserted,
+            updated,
+            duplicates_ct,
+            flush.writes_saved,
+            start.elapsed().as_millis()
+        );
+        Ok((flush.inserted, updated, duplicates_ct))
     }
 
     // === 시뮬레이션 함수들 (기존) ===
@@ -0,0 +1,192 @@
+//! 스테이지 간 전달 페이로드 (`StageItemResult::collected_data`)
+//!
+//! 작은 페이로드는 인라인으로 들고 다니고, 큰 페이로드(상세 목록 등)는 세션 임시 저장소
+//! (`session_spill`)에 써 두고 파일 이름만 보관한다. 세션 쿼터를 넘으면 인라인으로 남는다. 다음
+//! 스테이지 입력으로 변환된 뒤에는 `compact()`로 크기 정보만 남겨 1000+ 페이지 세션에서도 스테이지
+//! 결과가 메모리에 쌓이지 않게 한다. compact 되지 않은 파일은 세션이 끝날 때 세션 디렉터리와 함께
+//! 지워진다.
+//!
+//! 복제본은 같은 파일을 공유한다. 한쪽이 compact 하면 저장본이 지워지므로 이후 `json()`은
+//! `None`을 돌려준다 (이벤트로 나간 복제본은 요약 용도로만 쓴다).
+
+use crate::infrastructure::session_spill::{SpillError, SpillStore, shared_spill_store};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use tracing::{debug, warn};
+use ts_rs::TS;
+
+/// Payloads larger than this are spilled to the session's temp storage
+pub const INLINE_LIMIT_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StagePayload {
+    /// Serialized JSON kept in memory
+    Inline { json: String },
+    /// Serialized JSON spilled to the session's temp storage
+    Stored {
+        session_id: String,
+        file: String,
+        len: u64,
+    },
+    /// Consumed payload; only the original size is kept
+    Compacted { len: u64 },
+}
+
+impl StagePayload {
+    /// Wrap a serialized payload, spilling it to `session_id`'s temp storage when large
+    pub async fn new(session_id: &str, json: String) -> Self {
+        Self::with_limit(&shared_spill_store(), session_id, json, INLINE_LIMIT_BYTES).await
+    }
+
+    pub(crate) async fn with_limit(
+        store: &SpillStore,
+        session_id: &str,
+        json: String,
+        inline_limit: usize,
+    ) -> Self {
+        if json.len() <= inline_limit {
+            return Self::Inline { json };
+        }
+        let len = json.len() as u64;
+        match store.spill(session_id, json.as_bytes()).await {
+            Ok(handle) => Self::Stored {
+                session_id: session_id.to_string(),
+                file: handle.file_name(),
+                len,
+            },
+            Err(e @ SpillError::QuotaExceeded { .. }) => {
+                debug!("💾 Stage payload kept inline ({} bytes): {}", len, e);
+                Self::Inline { json }
+            }
+            Err(e) => {
+                warn!(
+                    "⚠️ Stage payload spill failed ({} bytes), keeping inline: {}",
+                    len, e
+                );
+                Self::Inline { json }
+            }
+        }
+    }
+
+    /// Serialize a value into a payload
+    pub async fn from_value<T: Serialize>(session_id: &str, value: &T) -> serde_json::Result<Self> {
+        Ok(Self::new(session_id, serde_json::to_string(value)?).await)
+    }
+
+    /// JSON text of the payload; `None` once compacted or if the stored copy is gone
+    pub async fn json(&self) -> Option<Cow<'_, str>> {
+        self.json_in(&shared_spill_store()).await
+    }
+
+    pub(crate) async fn json_in(&self, store: &SpillStore) -> Option<Cow<'_, str>> {
+        match self {
+            Self::Inline { json } => Some(Cow::Borrowed(json.as_str())),
+            Self::Stored {
+                session_id,
+                file,
+                len,
+            } => {
+                let handle = store.handle(session_id, file, *len)?;
+                let bytes = store.read(&handle).await.ok()?;
+                String::from_utf8(bytes).ok().map(Cow::Owned)
+            }
+            Self::Compacted { .. } => None,
+        }
+    }
+
+    /// Size of the serialized payload in bytes
+    pub fn len(&self) -> u64 {
+        match self {
+            Self::Inline { json } => json.len() as u64,
+            Self::Stored { len, .. } | Self::Compacted { len } => *len,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_compacted(&self) -> bool {
+        matches!(self, Self::Compacted { .. })
+    }
+
+    /// Drop the payload body (and its stored copy, returning its bytes to the session quota),
+    /// keeping only the size
+    pub async fn compact(&mut self) {
+        self.compact_in(&shared_spill_store()).await;
+    }
+
+    pub(crate) async fn compact_in(&mut self, store: &SpillStore) {
+        let handle = match self {
+            Self::Stored {
+                session_id,
+                file,
+                len,
+            } => store.handle(session_id, file, *len),
+            _ => None,
+        };
+        let released = match handle {
+            Some(handle) => store.release(handle).await,
+            None => Ok(()),
+        };
+        if let Err(e) = released {
+            warn!("⚠️ Stage payload spill not released: {}", e);
+        }
+        *self = Self::Compacted { len: self.len() };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn small_payloads_stay_inline_and_large_ones_spill() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SpillStore::new(dir.path().to_path_buf(), 1024);
+        let small = StagePayload::with_limit(&store, "s1", "[1,2]".into(), 16).await;
+        assert!(matches!(small, StagePayload::Inline { .. }));
+        assert_eq!(small.json_in(&store).await.as_deref(), Some("[1,2]"));
+
+        let body = format!("[{}]", ["1"; 20].join(","));
+        let mut large = StagePayload::with_limit(&store, "s1", body.clone(), 16).await;
+        let StagePayload::Stored { len, .. } = large.clone() else {
+            panic!("expected spilled payload, got {large:?}");
+        };
+        assert_eq!(len, body.len() as u64);
+        assert_eq!(large.json_in(&store).await.as_deref(), Some(body.as_str()));
+        assert_eq!(store.usage().total_bytes, len);
+
+        let shared = large.clone();
+        large.compact_in(&store).await;
+        assert_eq!(large, StagePayload::Compacted { len });
+        assert!(large.json_in(&store).await.is_none());
+        // Clones share the stored copy, which compaction removed and returned to the quota
+        assert!(shared.json_in(&store).await.is_none());
+        assert_eq!(store.usage().total_bytes, 0);
+    }
+
+    #[tokio::test]
+    async fn over_quota_payloads_stay_inline() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SpillStore::new(dir.path().to_path_buf(), 32);
+        let body = "x".repeat(40);
+        let payload = StagePayload::with_limit(&store, "s1", body.clone(), 16).await;
+        assert_eq!(payload, StagePayload::Inline { json: body });
+        assert_eq!(store.usage().rejected_spills, 1);
+    }
+
+    #[tokio::test]
+    async fn foreign_file_names_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SpillStore::new(dir.path().to_path_buf(), 1024);
+        let forged = StagePayload::Stored {
+            session_id: "s1".into(),
+            file: "../../etc/passwd".into(),
+            len: 1,
+        };
+        assert!(forged.json_in(&store).await.is_none());
+    }
+}
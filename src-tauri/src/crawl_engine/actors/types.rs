@@ -8,9 +8,13 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
 // 도메인 객체 import 추가
+use crate::crawl_engine::runtime::crawl_limits::{CrawlLimitKind, CrawlLimits};
+use crate::domain::error_catalog::ErrorCode;
 use crate::domain::integrated_product::ProductDetail;
 use crate::domain::product_url::ProductUrl;
 
+pub use super::stage_payload::StagePayload;
+
 /// Actor-Event 계약 버전 (additive-only 정책)
 pub const ACTOR_CONTRACT_VERSION: &str = "v1"; // bump when UI requires new additive schema set
 
@@ -124,6 +128,14 @@ pub enum AppEvent {
         timestamp: DateTime<Utc>,
     },
 
+    /// 세션 종료 후 감사용 리포트(JSON/Markdown) 저장 완료 알림
+    SessionReportReady {
+        session_id: String,
+        json_path: String,
+        markdown_path: String,
+        timestamp: DateTime<Utc>,
+    },
+
     SessionFailed {
         session_id: String,
         error: String,
@@ -224,6 +236,9 @@ pub enum AppEvent {
         #[serde(skip_serializing_if = "Option::is_none")]
         batch_id: Option<String>,
         error: String,
+        /// Error catalog code (`E_...`, see `describe_error`); additive
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error_code: Option<String>,
         timestamp: DateTime<Utc>,
     },
 
@@ -309,6 +324,27 @@ pub enum AppEvent {
         timestamp: DateTime<Utc>,
     },
 
+    /// 배치 진행 롤업: 스테이지별 누적 카운트 스냅샷 (퍼널 뷰 용도, additive v1)
+    /// 스테이지 경계마다 발행되며 마지막 이벤트가 해당 배치의 최종 퍼널 상태를 나타낸다.
+    BatchProgressRollup {
+        session_id: String,
+        batch_id: String,
+        /// 직전에 완료된 스테이지
+        stage_type: StageType,
+        pages_total: u32,
+        /// Stage 2에서 성공적으로 수집된 리스트 페이지 수
+        pages_listed: u32,
+        /// Stage 3 입력으로 큐잉된 Product URL 수 (중복 스킵 이후)
+        urls_queued: u32,
+        /// Stage 3에서 상세 정보가 수집된 제품 수
+        details_fetched: u32,
+        /// Stage 4 검증 통과 수
+        validated: u32,
+        /// Stage 5 저장 수 (inserted + updated)
+        saved: u32,
+        timestamp: DateTime<Utc>,
+    },
+
     /// 세션 전체 요약 리포트
     CrawlReportSession {
         session_id: String,
@@ -487,6 +523,9 @@ pub enum AppEvent {
         ranges: Vec<(u32, u32)>, // (start_oldest, end_newest) inclusive per range
         #[serde(skip_serializing_if = "Option::is_none")]
         rate_limit: Option<u32>,
+        /// Expected completion (see `services::sync_eta`); refined by `SyncPageCompleted`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        estimated_finish_at: Option<DateTime<Utc>>,
         timestamp: DateTime<Utc>,
     },
     SyncPageStarted {
@@ -511,10 +550,14 @@ pub enum AppEvent {
         skipped: u32,
         failed: u32,
         ms: u64,
+        /// Refined completion estimate after this page
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        estimated_finish_at: Option<DateTime<Utc>>,
         timestamp: DateTime<Utc>,
     },
     SyncWarning {
         session_id: String,
+        /// `WarningCode` wire string (`describe_warning`)
         code: String,
         detail: String,
         timestamp: DateTime<Utc>,
@@ -550,6 +593,138 @@ pub enum AppEvent {
         items_on_last_page: Option<u32>,
         #[serde(skip_serializing_if = "Option::is_none")]
         anomalies: Option<Vec<SyncAnomalyEntry>>,
+        /// Field-level summary of values this sync changed (from `product_history`)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        changeset: Option<SyncChangeset>,
+        /// End-of-session retry of pages that failed outright (absent when none failed)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        page_retry: Option<SyncPageRetry>,
+        timestamp: DateTime<Utc>,
+    },
+    /// Sync stopped by `cancel_sync_session`; the page in flight was rolled back
+    SyncAborted {
+        session_id: String,
+        reason: String,
+        pages_processed: u32,
+        inserted: u32,
+        updated: u32,
+        skipped: u32,
+        failed: u32,
+        duration_ms: u64,
+        timestamp: DateTime<Utc>,
+    },
+    /// Sync drained by `pause_sync_session`; `resume_sync_session` runs the remaining pages
+    SyncPaused {
+        session_id: String,
+        pages_processed: u32,
+        remaining_pages: u32,
+        inserted: u32,
+        updated: u32,
+        skipped: u32,
+        failed: u32,
+        duration_ms: u64,
+        timestamp: DateTime<Utc>,
+    },
+    /// 스케줄된 경량 동기화(최신 N페이지) 결과 + 이상치 게이트 판정
+    /// `repair_recommended`가 true면 대규모 크롤 대신 repair sync 실행을 권고한다.
+    LightSyncReport {
+        newest_pages: u32,
+        pages_processed: u32,
+        inserted: u32,
+        updated: u32,
+        failed: u32,
+        anomaly_count: u32,
+        anomaly_threshold: u32,
+        repair_recommended: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        anomalies: Option<Vec<SyncAnomalyEntry>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// `run_db_maintenance` 단계 진행 (`phase`: started / completed / failed)
+    DbMaintenanceProgress {
+        step: crate::infrastructure::db_maintenance::MaintenanceStep,
+        index: u32,
+        total: u32,
+        phase: String,
+        elapsed_ms: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        detail: Option<String>,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// 관심(watchlist) 제품의 필드 변경 알림 (스냅샷 대비 필드 단위 diff)
+    WatchlistChanged {
+        changes: Vec<crate::services::watchlist::WatchedProductChange>,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// 대상 호스트 서킷 열림: 연속 실패로 요청을 차단하고 실행 중 세션을 일시정지
+    SiteCircuitOpened {
+        host: String,
+        consecutive_failures: u32,
+        /// Seconds until the first half-open probe
+        retry_after_secs: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        last_error: Option<String>,
+        /// Sessions paused by the breaker (resumed on close)
+        paused_sessions: Vec<String>,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// half-open probe 성공으로 서킷 닫힘: 요청 재개 + 브레이커가 멈춘 세션 재개
+    SiteCircuitClosed {
+        host: String,
+        /// How long the circuit stayed open
+        open_secs: u64,
+        resumed_sessions: Vec<String>,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// 시작 시 데이터 무결성 자가 점검 결과 (요약 배너)
+    DataIntegritySnapshot {
+        report: crate::services::startup_integrity::DataIntegritySnapshot,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// 감독자가 패닉/멈춘 액터를 백오프 후 다시 시작
+    ActorRestarted {
+        session_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        batch_id: Option<String>,
+        actor_id: String,
+        /// 1 = first restart
+        restart: u32,
+        max_restarts: u32,
+        reason: String,
+        backoff_ms: u64,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// 워치독: 스테이지가 임계 시간 동안 진행 없음 (경고; action 에 따라 재시작/취소)
+    SessionStalled {
+        session_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        batch_id: Option<String>,
+        stage_type: StageType,
+        idle_secs: u64,
+        threshold_secs: u64,
+        action: crate::crawl_engine::actors::session_watchdog::StallAction,
+        timestamp: DateTime<Utc>,
+    },
+
+    /// 적응형 동시성: AIMD 컨트롤러가 스테이지 허용 수를 조정함
+    ConcurrencyAdjusted {
+        session_id: String,
+        stage_type: StageType,
+        previous: u32,
+        target: u32,
+        reason: crate::crawl_engine::services::performance_optimizer::AdjustReason,
+        p95_ms: u64,
+        error_rate: f64,
+        samples: u32,
         timestamp: DateTime<Utc>,
     },
 }
@@ -562,6 +737,38 @@ pub struct SyncAnomalyEntry {
     pub current_page_number: u32,
 }
 
+/// Field-level changes made by one sync session (SyncCompleted / `sync_sessions.changeset_json`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncChangeset {
+    /// Changed field values across all products
+    pub total_changes: u32,
+    /// Distinct products with at least one changed field
+    pub products_changed: u32,
+    /// Most frequently changed fields first
+    pub fields: Vec<SyncFieldChangeCount>,
+}
+
+/// Outcome of the end-of-session retry passes over whole failed pages (SyncCompleted)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncPageRetry {
+    /// Retry passes run
+    pub passes: u32,
+    /// Physical pages that failed in the first pass
+    pub retried: Vec<u32>,
+    /// Failed pages that went through in a retry pass
+    pub recovered: Vec<u32>,
+    /// Failed pages still failing after the last pass (counted in `failed`)
+    pub still_failed: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncFieldChangeCount {
+    pub field: String,
+    pub count: u32,
+    /// First few affected product URLs
+    pub sample_urls: Vec<String>,
+}
+
 // Lightweight TS-friendly metrics container (additive, extensible)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "kind", content = "data")]
@@ -686,38 +893,8 @@ impl Default for BatchConfig {
     }
 }
 
-/// 스테이지 타입
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
-#[ts(export)]
-pub enum StageType {
-    /// 상태 확인
-    StatusCheck,
-
-    /// 리스트 페이지 크롤링
-    ListPageCrawling,
-
-    /// 상품 상세 크롤링
-    ProductDetailCrawling,
-
-    /// 데이터 검증
-    DataValidation,
-
-    /// 데이터 저장
-    DataSaving,
-}
-
-impl StageType {
-    /// StageType을 문자열로 변환
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            StageType::StatusCheck => "status_check",
-            StageType::ListPageCrawling => "list_page_crawling",
-            StageType::ProductDetailCrawling => "product_detail_crawling",
-            StageType::DataValidation => "data_validation",
-            StageType::DataSaving => "data_saving",
-        }
-    }
-}
+/// 스테이지 타입 (채널/Actor 공용)
+pub use crate::crawl_engine::stage_type::{StageSkipFlag, StageType};
 
 /// 스테이지 아이템
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -795,11 +972,38 @@ pub struct StageItemResult {
     /// 재시도 횟수
     pub retry_count: u32,
 
-    /// 수집된 데이터 (JSON 문자열)
+    /// 수집된 데이터 (JSON 페이로드, 큰 경우 임시 저장소 핸들)
     /// ListPageCrawling: ProductURL들의 JSON 배열
     /// ProductDetailCrawling: ProductDetail들의 JSON 배열
     /// DataSaving: 저장된 데이터의 메타정보
-    pub collected_data: Option<String>,
+    pub collected_data: Option<StagePayload>,
+}
+
+impl StageItemResult {
+    /// 수집된 데이터의 JSON 텍스트 (compact 이후에는 `None`)
+    pub async fn collected_json(&self) -> Option<std::borrow::Cow<'_, str>> {
+        match self.collected_data.as_ref() {
+            Some(payload) => payload.json().await,
+            None => None,
+        }
+    }
+}
+
+impl StageResult {
+    /// 다음 스테이지로 넘긴 뒤 성공 아이템의 페이로드를 비워 메모리/임시 파일을 반환한다.
+    /// 반환값은 compact 된 아이템 수.
+    pub async fn compact_payloads(&mut self) -> usize {
+        let mut compacted = 0;
+        for item in self.details.iter_mut().filter(|d| d.success) {
+            if let Some(payload) = item.collected_data.as_mut() {
+                if !payload.is_compacted() {
+                    payload.compact().await;
+                    compacted += 1;
+                }
+            }
+        }
+        compacted
+    }
 }
 
 // =============================================================================
@@ -950,13 +1154,38 @@ pub struct SessionSummary {
     #[serde(default)]
     pub failed_page_ids: Vec<u32>,
 
+    /// 단계/작업별 소요 시간 분위수 (p50/p90/p99)
+    #[serde(default)]
+    pub latency: Vec<crate::crawl_engine::runtime::latency::LatencyStat>,
+
     /// 최종 상태
     pub final_state: String,
 
+    /// Why the session stopped
+    #[serde(default)]
+    pub completion_reason: CompletionReason,
+    /// Bound that stopped a `LimitReached` session
+    #[serde(default)]
+    pub limit_reached: Option<CrawlLimitKind>,
+
     /// 타임스탬프
     pub timestamp: DateTime<Utc>,
 }
 
+/// 세션 종료 사유 (`final_state` 는 실패/불일치 세부 상태)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum CompletionReason {
+    /// The plan ran out
+    #[default]
+    Finished,
+    /// A `CrawlLimits` bound stopped the session before its plan ran out
+    LimitReached,
+    /// The session driver ended without reaching its completion path
+    Aborted,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export)]
 pub struct ErrorSummary {
@@ -1029,6 +1258,33 @@ pub enum StageError {
     GenericError { message: String },
 }
 
+impl StageError {
+    /// Catalog code; a `[E_...]` tag in the message wins over the variant default
+    pub fn code(&self) -> ErrorCode {
+        let tagged_or = |message: &str, fallback: ErrorCode| {
+            ErrorCode::from_tagged_message(message).unwrap_or(fallback)
+        };
+        match self {
+            StageError::NetworkError { message } => match ErrorCode::classify_message(message) {
+                ErrorCode::Internal => ErrorCode::NetworkFailure,
+                code => code,
+            },
+            StageError::ParsingError { message } => tagged_or(message, ErrorCode::ParseFailed),
+            StageError::ValidationError { message } => {
+                tagged_or(message, ErrorCode::ValidationFailed)
+            }
+            StageError::DatabaseError { message } => tagged_or(message, ErrorCode::DatabaseError),
+            StageError::TimeoutError { .. } | StageError::NetworkTimeout { .. } => {
+                ErrorCode::NetworkTimeout
+            }
+            StageError::ConfigurationError { message } => {
+                tagged_or(message, ErrorCode::ConfigurationError)
+            }
+            StageError::GenericError { message } => ErrorCode::classify_message(message),
+        }
+    }
+}
+
 // =============================================================================
 // 성공 결과 타입 정의
 // =============================================================================
@@ -1182,6 +1438,29 @@ pub enum ActorError {
     Unknown(String),
 }
 
+impl ActorError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ActorError::EventBroadcastFailed(_) | ActorError::ChannelError(_) => {
+                ErrorCode::ChannelClosed
+            }
+            ActorError::ConfigurationError(_) => ErrorCode::ConfigurationError,
+            ActorError::Timeout(_) => ErrorCode::NetworkTimeout,
+            ActorError::Cancelled(_) => ErrorCode::Cancelled,
+            ActorError::ResourceExhausted(_) => ErrorCode::ResourceExhausted,
+            ActorError::RequestFailed(message) => match ErrorCode::classify_message(message) {
+                ErrorCode::Internal => ErrorCode::NetworkFailure,
+                code => code,
+            },
+            ActorError::ParsingFailed(_) => ErrorCode::ParseFailed,
+            ActorError::DatabaseError(_) => ErrorCode::DatabaseError,
+            ActorError::CommandProcessingFailed(message)
+            | ActorError::LegacyServiceError(message)
+            | ActorError::Unknown(message) => ErrorCode::classify_message(message),
+        }
+    }
+}
+
 // From 구현들
 impl From<anyhow::Error> for ActorError {
     fn from(err: anyhow::Error) -> Self {
@@ -1230,6 +1509,12 @@ pub struct ExecutionPlan {
     pub plan_hash: String,
     /// 중복 상품 URL 스킵 여부 (경량 dedupe 1단계)
     pub skip_duplicate_urls: bool,
+    /// 배치 스테이지 스킵 플래그 (명령 진입 시 `StageSelection`으로 검증됨)
+    #[serde(default)]
+    pub stage_flags: Vec<StageSkipFlag>,
+    /// 세션 한도 (신규 제품 수 / 페이지 수 / 시간); 배치 사이에서 확인
+    #[serde(default)]
+    pub limits: CrawlLimits,
     pub kpi_meta: Option<ExecutionPlanKpi>,
     /// API / 이벤트 스키마 계약 버전 (additive-only 변경 추적)
     pub contract_version: u32,
@@ -1425,6 +1710,41 @@ mod tests {
         assert_eq!(result.details.len(), 1);
     }
 
+    #[tokio::test]
+    async fn compact_payloads_only_clears_successful_items() {
+        let item = |success: bool| StageItemResult {
+            item_id: "page:1".to_string(),
+            item_type: StageItemType::Page { page_number: 1 },
+            success,
+            error: None,
+            duration_ms: 10,
+            retry_count: 0,
+            collected_data: Some(StagePayload::Inline {
+                json: "[\"u\"]".to_string(),
+            }),
+        };
+        let mut result = StageResult {
+            processed_items: 2,
+            successful_items: 1,
+            failed_items: 1,
+            duration_ms: 20,
+            details: vec![item(true), item(false)],
+        };
+
+        assert_eq!(
+            result.details[0].collected_json().await.as_deref(),
+            Some("[\"u\"]")
+        );
+        assert_eq!(result.compact_payloads().await, 1);
+        assert_eq!(result.compact_payloads().await, 0);
+        assert!(result.details[0].collected_json().await.is_none());
+        assert_eq!(
+            result.details[0].collected_data,
+            Some(StagePayload::Compacted { len: 5 })
+        );
+        assert!(result.details[1].collected_json().await.is_some());
+    }
+
     #[test]
     fn test_performance_metrics() {
         let metrics = PerformanceMetrics {
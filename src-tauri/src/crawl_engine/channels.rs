@@ -1,6 +1,8 @@
 // Rust 2024 gate file for `crawl_engine::channels`
 // Replaces directory-level mod.rs and pins submodules explicitly.
 
+#[path = "channels/priority.rs"]
+pub mod priority;
 #[path = "channels/types.rs"]
 pub mod types;
 
@@ -0,0 +1,225 @@
+//! 스테이지 아이템 우선순위 큐 + 세션별 인터랙티브 부스트
+//!
+//! StageActor 는 배치 아이템을 받은 순서대로 태스크로 띄워, UI 에서 특정 제품을 요청해도 앞선
+//! 백로그가 끝날 때까지 기다려야 했다. 이제 아이템은 `PriorityQueue` 에 넣고 동시성 슬롯이 빌
+//! 때마다 가장 높은 우선순위(같으면 먼저 들어온 것)부터 꺼낸다. `prioritize_items` 명령이
+//! `boost_items` 로 세션의 페이지/URL 을 올리면 디스패처가 다음 슬롯에서 남은 아이템을 다시 매긴다.
+
+use super::types::StageItem;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+
+/// Dispatch priority of a stage item; higher levels are taken first
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    /// Requested from the UI; ahead of everything else
+    Interactive,
+}
+
+#[derive(Debug)]
+struct Entry<T> {
+    priority: ItemPriority,
+    seq: u64,
+    value: T,
+}
+
+impl<T> PartialEq for Entry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Entry<T> {}
+
+impl<T> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Entry<T> {
+    /// Higher priority first, then earlier insertion
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+/// Max-priority queue that keeps insertion order within a priority level
+#[derive(Debug)]
+pub struct PriorityQueue<T> {
+    heap: BinaryHeap<Entry<T>>,
+    next_seq: u64,
+}
+
+impl<T> Default for PriorityQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PriorityQueue<T> {
+    pub fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            next_seq: 0,
+        }
+    }
+
+    pub fn push(&mut self, value: T, priority: ItemPriority) {
+        self.heap.push(Entry {
+            priority,
+            seq: self.next_seq,
+            value,
+        });
+        self.next_seq += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.heap.pop().map(|entry| entry.value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Raise queued entries to the priority `raise` returns (never lowers); returns how many moved
+    pub fn raise(&mut self, mut raise: impl FnMut(&T) -> Option<ItemPriority>) -> usize {
+        let mut raised = 0;
+        let entries = std::mem::take(&mut self.heap).into_vec();
+        self.heap = entries
+            .into_iter()
+            .map(|mut entry| {
+                if let Some(priority) = raise(&entry.value).filter(|p| *p > entry.priority) {
+                    entry.priority = priority;
+                    raised += 1;
+                }
+                entry
+            })
+            .collect();
+        raised
+    }
+}
+
+/// Pages and URLs a session wants crawled ahead of its backlog
+#[derive(Debug, Clone, Default)]
+pub struct ItemBoosts {
+    pages: HashMap<u32, ItemPriority>,
+    urls: HashMap<String, ItemPriority>,
+}
+
+impl ItemBoosts {
+    /// Boosted priority of `item`; for URL lists the highest boosted entry wins
+    pub fn priority_of(&self, item: &StageItem) -> Option<ItemPriority> {
+        match item {
+            StageItem::Page(page) => self.pages.get(page).copied(),
+            StageItem::ProductList(list) => self.pages.get(&list.page_number).copied(),
+            StageItem::Url(url) | StageItem::ValidationTarget(url) => self.urls.get(url).copied(),
+            StageItem::Product(product) => self.urls.get(&product.url).copied(),
+            StageItem::ProductUrls(urls) => urls
+                .urls
+                .iter()
+                .filter_map(|u| {
+                    let page = u32::try_from(u.page_id).ok();
+                    self.urls
+                        .get(&u.url)
+                        .or_else(|| page.and_then(|p| self.pages.get(&p)))
+                        .copied()
+                })
+                .max(),
+            StageItem::ProductDetails(_) | StageItem::ValidatedProducts(_) => None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pages.is_empty() && self.urls.is_empty()
+    }
+}
+
+static BOOSTS: Lazy<Mutex<HashMap<String, ItemBoosts>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+/// Bumped on every `boost_items`, so dispatchers only re-rank after a change
+static BOOST_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Raise `pages` / `urls` of the session to `priority` (an existing higher boost is kept)
+pub fn boost_items(session_id: &str, pages: &[u32], urls: &[String], priority: ItemPriority) {
+    let mut boosts = BOOSTS.lock().unwrap_or_else(|e| e.into_inner());
+    let session = boosts.entry(session_id.to_string()).or_default();
+    for page in pages {
+        let current = session.pages.entry(*page).or_insert(priority);
+        *current = (*current).max(priority);
+    }
+    for url in urls {
+        let current = session
+            .urls
+            .entry(url.trim().to_string())
+            .or_insert(priority);
+        *current = (*current).max(priority);
+    }
+    BOOST_GENERATION.fetch_add(1, AtomicOrdering::Relaxed);
+}
+
+pub fn boost_generation() -> u64 {
+    BOOST_GENERATION.load(AtomicOrdering::Relaxed)
+}
+
+pub fn session_boosts(session_id: &str) -> Option<ItemBoosts> {
+    BOOSTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(session_id)
+        .cloned()
+}
+
+/// Forget a finished session's boosts
+pub fn clear_boosts(session_id: &str) {
+    BOOSTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(session_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boosted_items_jump_the_queue_in_insertion_order() {
+        let mut queue = PriorityQueue::new();
+        for page in 1..=5 {
+            queue.push(StageItem::Page(page), ItemPriority::Normal);
+        }
+        queue.push(StageItem::Page(6), ItemPriority::Low);
+
+        boost_items("s-priority", &[4, 2], &[], ItemPriority::Interactive);
+        boost_items("s-priority", &[4], &[], ItemPriority::High);
+        let boosts = session_boosts("s-priority").unwrap_or_default();
+        assert_eq!(queue.raise(|item| boosts.priority_of(item)), 2);
+
+        let order: Vec<u32> = std::iter::from_fn(|| queue.pop())
+            .map(|item| match item {
+                StageItem::Page(page) => page,
+                _ => 0,
+            })
+            .collect();
+        assert_eq!(order, vec![2, 4, 1, 3, 5, 6]);
+
+        clear_boosts("s-priority");
+        assert!(session_boosts("s-priority").is_none());
+    }
+}
@@ -57,14 +57,8 @@ pub enum ActorCommand {
     ResumeSession { session_id: String },
 }
 
-/// 스테이지 타입 정의
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum StageType {
-    ListCollection,
-    DetailCollection,
-    DataValidation,
-    DatabaseSave,
-}
+/// 스테이지 타입 정의 (채널/Actor 공용)
+pub use crate::crawl_engine::stage_type::StageType;
 
 /// 스테이지 아이템 정의
 #[derive(Debug, Clone)]
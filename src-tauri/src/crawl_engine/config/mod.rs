@@ -1,5 +1,7 @@
 // Config module - Phase 2 legacy compatibility bridge
 // 기존 설정 로직을 새로운 Actor 시스템과 연결하는 브릿지 모듈
 
+pub mod retry_policy; // 공용 재시도 정책 (sync + crawl engine)
+
 // 상위 디렉토리의 system_config 사용 (새 모듈명으로 업데이트)
 pub use crate::crawl_engine::system_config::*;
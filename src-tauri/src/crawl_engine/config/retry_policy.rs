@@ -0,0 +1,305 @@
+//! 공용 재시도 정책 엔진 (단계별 override + 세션 재시도 예산)
+//!
+//! sync 명령과 크롤 엔진(StageActor, CrawlingIntegrationService)이 같은 정책으로 재시도 간격을
+//! 계산한다. `advanced.retry_policies.default` 가 기본이고 list / detail / status 단계는 각각
+//! override 할 수 있다. `max_retries` 가 비어 있으면 호출 측의 기존 설정값(예:
+//! `product_list_retry_count`)을 그대로 쓴다. `session_retry_budget` 은 세션 하나가 쓸 수 있는
+//! 재시도 총량이며, 소진되면 이후 실패는 재시도 없이 바로 실패로 기록된다.
+
+use crate::crawl_engine::stage_type::StageType;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Sessions whose budgets are kept; older ones are dropped first
+const MAX_TRACKED_SESSION_BUDGETS: usize = 32;
+
+/// How the wait before retry N grows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackoffStrategy {
+    /// `base * multiplier^(n-1)`, capped, plus up to `jitter_ms`
+    #[default]
+    Exponential,
+    /// `base * n`, capped, plus up to `jitter_ms`
+    Linear,
+    /// Uniform in `[0, min(cap, base * multiplier^(n-1))]`; spreads synchronized retries the most
+    FullJitter,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; `None` keeps the caller's configured count
+    #[serde(default)]
+    pub max_retries: Option<u32>,
+    #[serde(default)]
+    pub strategy: BackoffStrategy,
+    #[serde(default = "RetryPolicy::default_base_delay_ms")]
+    pub base_delay_ms: u64,
+    #[serde(default = "RetryPolicy::default_max_delay_ms")]
+    pub max_delay_ms: u64,
+    /// Growth factor for `exponential` / `full_jitter`
+    #[serde(default = "RetryPolicy::default_multiplier")]
+    pub multiplier: f64,
+    /// Random extra wait added by `exponential` / `linear`
+    #[serde(default = "RetryPolicy::default_jitter_ms")]
+    pub jitter_ms: u64,
+}
+
+impl RetryPolicy {
+    fn default_base_delay_ms() -> u64 {
+        200
+    }
+    fn default_max_delay_ms() -> u64 {
+        30_000
+    }
+    fn default_multiplier() -> f64 {
+        2.0
+    }
+    fn default_jitter_ms() -> u64 {
+        50
+    }
+
+    pub fn max_retries_or(&self, fallback: u32) -> u32 {
+        self.max_retries.unwrap_or(fallback)
+    }
+
+    /// Wait before retry `retry` (1 = first retry)
+    pub fn delay(&self, retry: u32) -> Duration {
+        self.delay_with(retry, |max| fastrand::u64(0..=max))
+    }
+
+    /// `delay` with an injectable random source returning a value in `[0, max]`
+    pub fn delay_with(&self, retry: u32, mut random: impl FnMut(u64) -> u64) -> Duration {
+        let retry = retry.max(1);
+        let cap = self.max_delay_ms;
+        let grown = || {
+            let factor = self
+                .multiplier
+                .max(1.0)
+                .powi(retry.saturating_sub(1) as i32);
+            let ms = self.base_delay_ms as f64 * factor;
+            if ms >= cap as f64 { cap } else { ms as u64 }
+        };
+        let ms = match self.strategy {
+            BackoffStrategy::Exponential => grown().saturating_add(random(self.jitter_ms)),
+            BackoffStrategy::Linear => self
+                .base_delay_ms
+                .saturating_mul(retry as u64)
+                .min(cap)
+                .saturating_add(random(self.jitter_ms)),
+            BackoffStrategy::FullJitter => random(grown()),
+        };
+        Duration::from_millis(ms)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: None,
+            strategy: BackoffStrategy::default(),
+            base_delay_ms: Self::default_base_delay_ms(),
+            max_delay_ms: Self::default_max_delay_ms(),
+            multiplier: Self::default_multiplier(),
+            jitter_ms: Self::default_jitter_ms(),
+        }
+    }
+}
+
+/// `advanced.retry_policies`: shared default, per-stage overrides and the session budget
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StageRetryPolicies {
+    #[serde(default)]
+    pub default: RetryPolicy,
+    /// List pages (sync list fetch, ListPageCrawling)
+    #[serde(default)]
+    pub list: Option<RetryPolicy>,
+    /// Product detail pages (sync detail fetch, ProductDetailCrawling)
+    #[serde(default)]
+    pub detail: Option<RetryPolicy>,
+    /// Site status checks (StatusCheck)
+    #[serde(default)]
+    pub status: Option<RetryPolicy>,
+    /// Retries one session may spend across all stages (0 = unlimited)
+    #[serde(default)]
+    pub session_retry_budget: u32,
+    /// End-of-session passes over sync pages that failed outright (unset = 1, 0 = off)
+    #[serde(default)]
+    pub failed_page_passes: Option<u32>,
+}
+
+impl StageRetryPolicies {
+    pub fn list(&self) -> &RetryPolicy {
+        self.list.as_ref().unwrap_or(&self.default)
+    }
+
+    pub fn detail(&self) -> &RetryPolicy {
+        self.detail.as_ref().unwrap_or(&self.default)
+    }
+
+    pub fn status(&self) -> &RetryPolicy {
+        self.status.as_ref().unwrap_or(&self.default)
+    }
+
+    pub fn failed_page_passes(&self) -> u32 {
+        self.failed_page_passes.unwrap_or(1)
+    }
+
+    pub fn for_stage(&self, stage: &StageType) -> &RetryPolicy {
+        match stage {
+            StageType::StatusCheck => self.status(),
+            StageType::ListPageCrawling => self.list(),
+            StageType::ProductDetailCrawling => self.detail(),
+            _ => &self.default,
+        }
+    }
+
+    /// Budget shared by every retry site of `session_id`
+    pub fn session_budget(&self, session_id: &str) -> RetryBudget {
+        session_retry_budget(session_id, self.session_retry_budget)
+    }
+}
+
+/// Retry allowance shared across tasks; clones count against the same total
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    limit: u32,
+    used: Arc<AtomicU32>,
+}
+
+impl RetryBudget {
+    /// `limit` 0 = unlimited
+    pub fn new(limit: u32) -> Self {
+        Self {
+            limit,
+            used: Arc::new(AtomicU32::new(0)),
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self::new(0)
+    }
+
+    /// Take one retry; false once the budget is spent
+    pub fn try_acquire(&self) -> bool {
+        if self.limit == 0 {
+            self.used.fetch_add(1, Ordering::Relaxed);
+            return true;
+        }
+        self.used
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |used| {
+                (used < self.limit).then_some(used + 1)
+            })
+            .is_ok()
+    }
+
+    pub fn used(&self) -> u32 {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    pub fn limit(&self) -> Option<u32> {
+        (self.limit > 0).then_some(self.limit)
+    }
+}
+
+type BudgetRegistry = Mutex<(HashMap<String, RetryBudget>, VecDeque<String>)>;
+
+static SESSION_BUDGETS: Lazy<BudgetRegistry> =
+    Lazy::new(|| Mutex::new((HashMap::new(), VecDeque::new())));
+
+/// Get or create the budget for `session_id`; the first caller's `limit` wins
+pub fn session_retry_budget(session_id: &str, limit: u32) -> RetryBudget {
+    let mut guard = SESSION_BUDGETS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let (budgets, order) = &mut *guard;
+    if let Some(budget) = budgets.get(session_id) {
+        return budget.clone();
+    }
+    while order.len() >= MAX_TRACKED_SESSION_BUDGETS {
+        if let Some(oldest) = order.pop_front() {
+            budgets.remove(&oldest);
+        }
+    }
+    let budget = RetryBudget::new(limit);
+    budgets.insert(session_id.to_string(), budget.clone());
+    order.push_back(session_id.to_string());
+    budget
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(strategy: BackoffStrategy) -> RetryPolicy {
+        RetryPolicy {
+            strategy,
+            base_delay_ms: 100,
+            max_delay_ms: 1_000,
+            multiplier: 2.0,
+            jitter_ms: 10,
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[test]
+    fn strategies_grow_and_cap() {
+        let exp = policy(BackoffStrategy::Exponential);
+        let ms = |p: &RetryPolicy, n| p.delay_with(n, |max| max).as_millis() as u64;
+        assert_eq!([1, 2, 3, 5].map(|n| ms(&exp, n)), [110, 210, 410, 1_010]);
+        let linear = policy(BackoffStrategy::Linear);
+        assert_eq!(
+            [1, 2, 3, 20].map(|n| ms(&linear, n)),
+            [110, 210, 310, 1_010]
+        );
+        let full = policy(BackoffStrategy::FullJitter);
+        assert_eq!([1, 3, 9].map(|n| ms(&full, n)), [100, 400, 1_000]);
+        assert_eq!(full.delay_with(3, |_| 0), Duration::ZERO);
+        for n in 1..10 {
+            assert!(full.delay(n) <= Duration::from_millis(1_000));
+        }
+    }
+
+    #[test]
+    fn stage_overrides_fall_back_to_default() {
+        let policies: StageRetryPolicies = serde_json::from_value(serde_json::json!({
+            "default": { "strategy": "linear", "max_retries": 2 },
+            "detail": { "strategy": "full_jitter", "base_delay_ms": 500 },
+            "session_retry_budget": 3
+        }))
+        .unwrap();
+        let list = policies.for_stage(&StageType::ListPageCrawling);
+        assert_eq!(list.strategy, BackoffStrategy::Linear);
+        assert_eq!(list.max_retries_or(5), 2);
+        let detail = policies.for_stage(&StageType::ProductDetailCrawling);
+        assert_eq!(detail.strategy, BackoffStrategy::FullJitter);
+        assert_eq!(detail.base_delay_ms, 500);
+        assert_eq!(detail.max_retries_or(5), 5);
+        assert_eq!(
+            policies.for_stage(&StageType::StatusCheck),
+            &policies.default
+        );
+    }
+
+    #[test]
+    fn session_budget_is_shared_and_bounded() {
+        let policies = StageRetryPolicies {
+            session_retry_budget: 2,
+            ..StageRetryPolicies::default()
+        };
+        let a = policies.session_budget("budget-test-session");
+        let b = policies.session_budget("budget-test-session");
+        assert!(a.try_acquire());
+        assert!(b.try_acquire());
+        assert!(!a.try_acquire());
+        assert_eq!((b.used(), b.limit()), (2, Some(2)));
+
+        let unlimited = RetryBudget::unlimited();
+        assert!((0..100).all(|_| unlimited.try_acquire()));
+        assert_eq!(unlimited.limit(), None);
+    }
+}
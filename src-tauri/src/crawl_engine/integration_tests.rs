@@ -192,10 +192,10 @@ mod actor_integration_tests {
         
         // 각 스테이지 타입 테스트
         let stages = [
-            StageType::ListCollection,
-            StageType::DetailCollection,
+            StageType::ListPageCrawling,
+            StageType::ProductDetailCrawling,
             StageType::DataValidation,
-            StageType::DatabaseSave,
+            StageType::DataSaving,
         ];
         
         for stage_type in &stages {
@@ -254,7 +254,7 @@ mod actor_integration_tests {
             .collect();
         
         let result = stage_actor.execute_stage(
-            StageType::ListCollection,
+            StageType::ListPageCrawling,
             items.clone(),
             3, // concurrency_limit
             Duration::from_secs(30),
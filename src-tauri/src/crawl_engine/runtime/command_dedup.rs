@@ -0,0 +1,244 @@
+//! 중복 명령 호출 병합 (UI 더블클릭 방지)
+//!
+//! 같은 명령이 같은 인자로 다시 호출되면 두 번째 실행을 시작하지 않고 첫 호출(리더)의 결과를 기다려
+//! 그대로 돌려준다. 키는 명령 이름 + 직렬화한 인자의 BLAKE3 해시다.
+//! 리더가 끝난 뒤에도 `DEDUP_WINDOW` 동안은 같은 결과를 재사용한다 (늦게 도착한 두 번째 클릭).
+//! 리더가 끝나기 전에 drop되면 기다리던 호출은 오류를 받는다.
+//! `SyncCancellationRegistry`가 실행 중인 싱크를 추적한다면 이 레지스트리는 명령 진입점에서 중복을 막는다.
+
+use once_cell::sync::OnceCell;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+use tracing::info;
+
+/// How long a finished result is still handed to identical invocations
+pub const DEDUP_WINDOW: Duration = Duration::from_secs(2);
+
+/// None while the leader runs
+type Outcome = Option<Result<Value, String>>;
+
+#[derive(Debug)]
+struct Entry {
+    id: u64,
+    outcome: watch::Receiver<Outcome>,
+    finished_at: Option<Instant>,
+}
+
+type EntryMap = Arc<Mutex<HashMap<String, Entry>>>;
+
+static NEXT_ENTRY: AtomicU64 = AtomicU64::new(1);
+
+/// In-flight and recently finished command invocations, keyed by command + args hash
+#[derive(Debug, Clone)]
+pub struct CommandDedup {
+    entries: EntryMap,
+    window: Duration,
+}
+
+static SHARED: OnceCell<CommandDedup> = OnceCell::new();
+
+enum Role {
+    Leader(LeaderGuard),
+    Follower(watch::Receiver<Outcome>),
+}
+
+impl CommandDedup {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            entries: Arc::default(),
+            window,
+        }
+    }
+
+    /// Process-wide instance used by the Tauri commands
+    pub fn shared() -> Self {
+        SHARED.get_or_init(|| Self::new(DEDUP_WINDOW)).clone()
+    }
+
+    /// Run `op` unless an identical invocation is running or finished within the window, in which
+    /// case that invocation's result is returned instead. Arguments that cannot be serialized
+    /// are never deduplicated.
+    pub async fn run<T, A, F, Fut>(&self, command: &str, args: &A, op: F) -> Result<T, String>
+    where
+        T: Serialize + DeserializeOwned,
+        A: Serialize + ?Sized,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, String>>,
+    {
+        let Ok(args) = serde_json::to_vec(args) else {
+            return op().await;
+        };
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(command.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(&args);
+        let key = hasher.finalize().to_hex().to_string();
+
+        match self.join_or_lead(key) {
+            Role::Leader(leader) => {
+                let result = op().await;
+                leader.finish(&result);
+                result
+            }
+            Role::Follower(mut outcome) => {
+                info!("🔁 [CommandDedup] {command}: joined identical in-flight invocation");
+                loop {
+                    let current = outcome.borrow_and_update().clone();
+                    if let Some(result) = current {
+                        return result
+                            .and_then(|v| serde_json::from_value(v).map_err(|e| e.to_string()));
+                    }
+                    if outcome.changed().await.is_err() {
+                        return Err(format!(
+                            "{command}: the identical invocation this call joined was dropped before finishing"
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    fn join_or_lead(&self, key: String) -> Role {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let window = self.window;
+        entries.retain(|_, entry| match entry.finished_at {
+            Some(at) => at.elapsed() < window,
+            None => true,
+        });
+        if let Some(entry) = entries.get(&key) {
+            return Role::Follower(entry.outcome.clone());
+        }
+        let (tx, rx) = watch::channel(None);
+        let id = NEXT_ENTRY.fetch_add(1, Ordering::Relaxed);
+        entries.insert(
+            key.clone(),
+            Entry {
+                id,
+                outcome: rx,
+                finished_at: None,
+            },
+        );
+        Role::Leader(LeaderGuard {
+            key,
+            id,
+            tx,
+            entries: self.entries.clone(),
+            finished: false,
+        })
+    }
+}
+
+/// Publishes the leader's result; removes the entry if the leader is dropped mid-run
+struct LeaderGuard {
+    key: String,
+    id: u64,
+    tx: watch::Sender<Outcome>,
+    entries: EntryMap,
+    finished: bool,
+}
+
+impl LeaderGuard {
+    fn finish<T: Serialize>(mut self, result: &Result<T, String>) {
+        let outcome = match result {
+            Ok(value) => serde_json::to_value(value).map_err(|e| e.to_string()),
+            Err(e) => Err(e.clone()),
+        };
+        let _ = self.tx.send(Some(outcome));
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(entry) = entries.get_mut(&self.key) {
+            if entry.id == self.id {
+                entry.finished_at = Some(Instant::now());
+            }
+        }
+        self.finished = true;
+    }
+}
+
+impl Drop for LeaderGuard {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if entries
+            .get(&self.key)
+            .is_some_and(|entry| entry.id == self.id)
+        {
+            entries.remove(&self.key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[tokio::test]
+    async fn identical_invocations_share_one_run() {
+        let dedup = CommandDedup::new(Duration::from_millis(200));
+        let runs = AtomicUsize::new(0);
+        let op = || async {
+            runs.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok::<u32, String>(7)
+        };
+        let (a, b) = tokio::join!(
+            dedup.run("start_sync_pages", &(vec![3, 2], false), op),
+            dedup.run("start_sync_pages", &(vec![3, 2], false), op),
+        );
+        assert_eq!((a, b), (Ok(7), Ok(7)));
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        // Within the window the finished result is reused; other args still run
+        assert_eq!(
+            dedup
+                .run("start_sync_pages", &(vec![3, 2], false), op)
+                .await,
+            Ok(7)
+        );
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        dedup
+            .run("start_sync_pages", &(vec![4], false), op)
+            .await
+            .unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        dedup
+            .run("start_sync_pages", &(vec![3, 2], false), op)
+            .await
+            .unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn dropped_leader_fails_followers_and_releases_key() {
+        let dedup = CommandDedup::new(Duration::from_secs(5));
+        let mut leader = Box::pin(dedup.run("run_db_maintenance", &(), || async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok::<(), String>(())
+        }));
+        assert!(futures::poll!(leader.as_mut()).is_pending());
+        let follower = tokio::spawn({
+            let dedup = dedup.clone();
+            async move {
+                dedup
+                    .run("run_db_maintenance", &(), || async { Ok::<(), String>(()) })
+                    .await
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        drop(leader);
+        assert!(follower.await.unwrap().is_err());
+        let next = dedup.run("run_db_maintenance", &(), || async { Ok::<(), String>(()) });
+        assert_eq!(next.await, Ok(()));
+    }
+}
@@ -0,0 +1,210 @@
+//! 세션 크롤 한도 (`max_new_products` / `max_pages` / `max_duration_secs`)
+//!
+//! 스팟 체크처럼 "신규 제품 50개만" 필요할 때 계획 전체를 돌지 않도록, 세션 드라이버가 배치
+//! 사이마다 누적치를 한도와 비교한다. 한도에 닿으면 이후 배치는 시작하지 않으며, 세션은
+//! `CompletionReason::LimitReached` 로 정상 종료된다. `max_new_products` 는 저장 경로에서도
+//! 세션별 잔여 허용량으로 지켜진다: 한도에 닿는 배치는 남은 만큼의 신규 제품만 저장하고
+//! (기존 제품 갱신은 그대로), 나머지 신규 행은 버린다.
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use ts_rs::TS;
+
+/// Optional bounds on one crawl session; all unset = run the whole plan
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct CrawlLimits {
+    /// Stop once this many products were newly inserted
+    #[serde(default)]
+    pub max_new_products: Option<u32>,
+    /// Stop once this many list pages were processed (the last batch is trimmed to fit)
+    #[serde(default)]
+    pub max_pages: Option<u32>,
+    /// Start no new batch after this many seconds
+    #[serde(default)]
+    pub max_duration_secs: Option<u64>,
+}
+
+impl CrawlLimits {
+    pub fn is_unbounded(&self) -> bool {
+        self.max_new_products.is_none()
+            && self.max_pages.is_none()
+            && self.max_duration_secs.is_none()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_new_products == Some(0) {
+            return Err("max_new_products must be greater than 0".to_string());
+        }
+        if self.max_pages == Some(0) {
+            return Err("max_pages must be greater than 0".to_string());
+        }
+        if self.max_duration_secs == Some(0) {
+            return Err("max_duration_secs must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Which bound stopped the session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum CrawlLimitKind {
+    MaxNewProducts,
+    MaxPages,
+    MaxDuration,
+}
+
+/// Running totals the session driver checks between batches
+#[derive(Debug, Clone)]
+pub struct CrawlLimitTracker {
+    limits: CrawlLimits,
+    started: Instant,
+    pages: u32,
+    new_products: u32,
+}
+
+impl CrawlLimitTracker {
+    pub fn new(limits: CrawlLimits) -> Self {
+        Self {
+            limits,
+            started: Instant::now(),
+            pages: 0,
+            new_products: 0,
+        }
+    }
+
+    pub fn pages(&self) -> u32 {
+        self.pages
+    }
+
+    pub fn new_products(&self) -> u32 {
+        self.new_products
+    }
+
+    /// Count a finished batch (failed batches still count their pages)
+    pub fn record_batch(&mut self, pages: u32, new_products: u32) {
+        self.pages = self.pages.saturating_add(pages);
+        self.new_products = self.new_products.saturating_add(new_products);
+    }
+
+    /// Pages the next batch may still take under `max_pages`
+    pub fn remaining_pages(&self) -> Option<u32> {
+        self.limits
+            .max_pages
+            .map(|max| max.saturating_sub(self.pages))
+    }
+
+    /// First bound already hit, checked before starting the next batch
+    pub fn reached(&self) -> Option<CrawlLimitKind> {
+        self.reached_after(self.started.elapsed())
+    }
+
+    fn reached_after(&self, elapsed: Duration) -> Option<CrawlLimitKind> {
+        if self
+            .limits
+            .max_new_products
+            .is_some_and(|max| self.new_products >= max)
+        {
+            return Some(CrawlLimitKind::MaxNewProducts);
+        }
+        if self.remaining_pages() == Some(0) {
+            return Some(CrawlLimitKind::MaxPages);
+        }
+        if self
+            .limits
+            .max_duration_secs
+            .is_some_and(|max| elapsed >= Duration::from_secs(max))
+        {
+            return Some(CrawlLimitKind::MaxDuration);
+        }
+        None
+    }
+}
+
+/// `max_new_products` left per running session, taken by the savers before writing new rows
+static NEW_PRODUCT_ALLOWANCE: Lazy<Mutex<HashMap<String, u32>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn allowances() -> std::sync::MutexGuard<'static, HashMap<String, u32>> {
+    NEW_PRODUCT_ALLOWANCE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// Cap the new products `session_id` may still save at `max`
+pub fn set_new_product_allowance(session_id: &str, max: u32) {
+    allowances().insert(session_id.to_string(), max);
+}
+
+pub fn clear_new_product_allowance(session_id: &str) {
+    allowances().remove(session_id);
+}
+
+pub fn has_new_product_cap(session_id: &str) -> bool {
+    allowances().contains_key(session_id)
+}
+
+/// Take up to `wanted` new-product slots; all of them when the session has no cap
+pub fn take_new_products(session_id: &str, wanted: u32) -> u32 {
+    match allowances().get_mut(session_id) {
+        Some(left) => {
+            let granted = wanted.min(*left);
+            *left -= granted;
+            granted
+        }
+        None => wanted,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stops_at_first_bound_and_trims_pages() {
+        let mut tracker = CrawlLimitTracker::new(CrawlLimits {
+            max_new_products: Some(50),
+            max_pages: Some(12),
+            max_duration_secs: Some(600),
+        });
+        assert_eq!(tracker.reached_after(Duration::ZERO), None);
+
+        tracker.record_batch(5, 30);
+        assert_eq!(tracker.remaining_pages(), Some(7));
+        assert_eq!(tracker.reached_after(Duration::from_secs(60)), None);
+        assert_eq!(
+            tracker.reached_after(Duration::from_secs(600)),
+            Some(CrawlLimitKind::MaxDuration)
+        );
+
+        tracker.record_batch(5, 20);
+        assert_eq!(
+            tracker.reached_after(Duration::ZERO),
+            Some(CrawlLimitKind::MaxNewProducts)
+        );
+
+        let mut pages_only = CrawlLimitTracker::new(CrawlLimits {
+            max_pages: Some(3),
+            ..CrawlLimits::default()
+        });
+        pages_only.record_batch(3, 0);
+        assert_eq!(
+            pages_only.reached_after(Duration::ZERO),
+            Some(CrawlLimitKind::MaxPages)
+        );
+        assert!(CrawlLimits::default().is_unbounded());
+        assert!(
+            CrawlLimits {
+                max_pages: Some(0),
+                ..CrawlLimits::default()
+            }
+            .validate()
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,423 @@
+//! 소요 시간 / 지연 히스토그램 (HDR 스타일)
+//!
+//! 곳곳에서 `elapsed().as_millis()` 를 따로 재던 것을 하나의 기록기로 모은다. 값은 마이크로초 단위로
+//! 로그-선형 버킷(옥타브당 128칸, 상대 오차 < 0.8%)에 세기만 하므로 샘플을 저장하지 않고도
+//! p50/p90/p99 를 뽑을 수 있다. 기록은 프로세스 전역 기록기(KPI 롤업)와, 세션 id 가 있으면
+//! 세션별 기록기(세션 요약) 양쪽에 들어간다.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use ts_rs::TS;
+
+use crate::crawl_engine::stage_type::StageType;
+
+/// Significant bits kept per value (including the leading one): 128 sub-buckets per octave
+const PRECISION_BITS: u32 = 8;
+const EXACT_LIMIT: u64 = 1 << PRECISION_BITS;
+const SUB_BUCKETS: u64 = EXACT_LIMIT / 2;
+/// Session recorders kept for sessions that never reached `finish_session` (errors, aborts)
+const MAX_TRACKED_SESSIONS: usize = 64;
+
+/// Fixed-memory log-linear histogram of microsecond values
+#[derive(Debug, Clone, Default)]
+pub struct LatencyHistogram {
+    counts: Vec<u64>,
+    count: u64,
+    sum_us: u128,
+    min_us: u64,
+    max_us: u64,
+}
+
+impl LatencyHistogram {
+    fn bucket_index(value: u64) -> usize {
+        if value < EXACT_LIMIT {
+            return value as usize;
+        }
+        let msb = 63 - value.leading_zeros();
+        let shift = msb + 1 - PRECISION_BITS;
+        let mantissa = value >> shift;
+        (EXACT_LIMIT + u64::from(shift - 1) * SUB_BUCKETS + (mantissa - SUB_BUCKETS)) as usize
+    }
+
+    /// Midpoint of the bucket's value range
+    fn bucket_value(index: usize) -> u64 {
+        let index = index as u64;
+        if index < EXACT_LIMIT {
+            return index;
+        }
+        let k = index - EXACT_LIMIT;
+        let shift = k / SUB_BUCKETS + 1;
+        let mantissa = SUB_BUCKETS + k % SUB_BUCKETS;
+        (mantissa << shift) + (1u64 << shift) / 2
+    }
+
+    pub fn record(&mut self, duration: Duration) {
+        self.record_micros(u64::try_from(duration.as_micros()).unwrap_or(u64::MAX));
+    }
+
+    pub fn record_micros(&mut self, value: u64) {
+        let index = Self::bucket_index(value);
+        if self.counts.len() <= index {
+            self.counts.resize(index + 1, 0);
+        }
+        self.counts[index] += 1;
+        if self.count == 0 || value < self.min_us {
+            self.min_us = value;
+        }
+        self.max_us = self.max_us.max(value);
+        self.count += 1;
+        self.sum_us += u128::from(value);
+    }
+
+    pub fn merge(&mut self, other: &LatencyHistogram) {
+        if other.count == 0 {
+            return;
+        }
+        if self.counts.len() < other.counts.len() {
+            self.counts.resize(other.counts.len(), 0);
+        }
+        for (mine, theirs) in self.counts.iter_mut().zip(&other.counts) {
+            *mine += theirs;
+        }
+        self.min_us = if self.count == 0 {
+            other.min_us
+        } else {
+            self.min_us.min(other.min_us)
+        };
+        self.max_us = self.max_us.max(other.max_us);
+        self.count += other.count;
+        self.sum_us += other.sum_us;
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Value at quantile `q` (0.0..=1.0) in microseconds, clamped to the observed min/max
+    pub fn value_at_quantile(&self, q: f64) -> u64 {
+        if self.count == 0 {
+            return 0;
+        }
+        let rank = ((q.clamp(0.0, 1.0) * self.count as f64).ceil() as u64).clamp(1, self.count);
+        let mut seen = 0u64;
+        for (index, bucket) in self.counts.iter().enumerate() {
+            seen += bucket;
+            if seen >= rank {
+                return Self::bucket_value(index).clamp(self.min_us, self.max_us);
+            }
+        }
+        self.max_us
+    }
+
+    pub fn summary(&self) -> LatencySummary {
+        let ms = |us: u64| us as f64 / 1000.0;
+        LatencySummary {
+            count: self.count,
+            min_ms: ms(self.min_us),
+            mean_ms: if self.count == 0 {
+                0.0
+            } else {
+                self.sum_us as f64 / self.count as f64 / 1000.0
+            },
+            p50_ms: ms(self.value_at_quantile(0.50)),
+            p90_ms: ms(self.value_at_quantile(0.90)),
+            p99_ms: ms(self.value_at_quantile(0.99)),
+            max_ms: ms(self.max_us),
+        }
+    }
+}
+
+/// What was timed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum LatencyOp {
+    /// One stage run of a batch (all items)
+    Stage,
+    /// One item inside a stage (list page, product detail, save)
+    StageItem,
+    /// One HTTP GET round trip (headers received; rate-limit wait excluded)
+    HttpRequest,
+    /// One page of a partial / basic sync
+    SyncPage,
+    /// One product write (coalesced upsert or persistence-queue batch transaction)
+    DbWrite,
+}
+
+impl LatencyOp {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LatencyOp::Stage => "stage",
+            LatencyOp::StageItem => "stage_item",
+            LatencyOp::HttpRequest => "http_request",
+            LatencyOp::SyncPage => "sync_page",
+            LatencyOp::DbWrite => "db_write",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LatencyKey {
+    pub op: LatencyOp,
+    pub stage: Option<StageType>,
+}
+
+impl LatencyKey {
+    pub fn op(op: LatencyOp) -> Self {
+        Self { op, stage: None }
+    }
+
+    pub fn stage(op: LatencyOp, stage: StageType) -> Self {
+        Self {
+            op,
+            stage: Some(stage),
+        }
+    }
+}
+
+/// Percentiles of one histogram, in milliseconds
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct LatencySummary {
+    #[ts(type = "number")]
+    pub count: u64,
+    pub min_ms: f64,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
+/// Summary for one (operation, stage) key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct LatencyStat {
+    pub op: LatencyOp,
+    pub stage: Option<StageType>,
+    #[serde(flatten)]
+    pub summary: LatencySummary,
+}
+
+/// Histograms keyed by operation / stage
+#[derive(Debug, Default)]
+pub struct LatencyRecorder {
+    histograms: Mutex<HashMap<LatencyKey, LatencyHistogram>>,
+}
+
+impl LatencyRecorder {
+    /// Process-wide recorder behind the KPI rollups
+    pub fn global() -> &'static LatencyRecorder {
+        static GLOBAL: OnceLock<LatencyRecorder> = OnceLock::new();
+        GLOBAL.get_or_init(LatencyRecorder::default)
+    }
+
+    pub fn record(&self, key: LatencyKey, duration: Duration) {
+        if let Ok(mut map) = self.histograms.lock() {
+            map.entry(key).or_default().record(duration);
+        }
+    }
+
+    /// Stats ordered by operation then stage pipeline order
+    pub fn stats(&self) -> Vec<LatencyStat> {
+        let Ok(map) = self.histograms.lock() else {
+            return Vec::new();
+        };
+        let mut stats: Vec<LatencyStat> = map
+            .iter()
+            .map(|(key, hist)| LatencyStat {
+                op: key.op,
+                stage: key.stage.clone(),
+                summary: hist.summary(),
+            })
+            .collect();
+        let stage_rank = |s: &Option<StageType>| {
+            s.as_ref().map_or(0, |s| {
+                crate::crawl_engine::stage_type::BATCH_PIPELINE
+                    .iter()
+                    .position(|p| p == s)
+                    .map_or(0, |i| i + 1)
+            })
+        };
+        stats.sort_by_key(|s| (s.op as u8, stage_rank(&s.stage)));
+        stats
+    }
+
+    pub fn reset(&self) {
+        if let Ok(mut map) = self.histograms.lock() {
+            map.clear();
+        }
+    }
+}
+
+#[derive(Default)]
+struct SessionRecorders {
+    by_session: HashMap<String, Arc<LatencyRecorder>>,
+    order: VecDeque<String>,
+}
+
+fn sessions() -> &'static Mutex<SessionRecorders> {
+    static SESSIONS: OnceLock<Mutex<SessionRecorders>> = OnceLock::new();
+    SESSIONS.get_or_init(Default::default)
+}
+
+fn session_recorder(session_id: &str) -> Option<Arc<LatencyRecorder>> {
+    let mut guard = sessions().lock().ok()?;
+    if let Some(recorder) = guard.by_session.get(session_id) {
+        return Some(Arc::clone(recorder));
+    }
+    while guard.order.len() >= MAX_TRACKED_SESSIONS {
+        if let Some(oldest) = guard.order.pop_front() {
+            guard.by_session.remove(&oldest);
+        }
+    }
+    let recorder = Arc::new(LatencyRecorder::default());
+    guard
+        .by_session
+        .insert(session_id.to_string(), Arc::clone(&recorder));
+    guard.order.push_back(session_id.to_string());
+    Some(recorder)
+}
+
+/// Record into the global recorder and, with a session id, that session's recorder
+pub fn record_latency(session_id: Option<&str>, key: LatencyKey, duration: Duration) {
+    if let Some(recorder) = session_id.and_then(session_recorder) {
+        recorder.record(key.clone(), duration);
+    }
+    LatencyRecorder::global().record(key, duration);
+}
+
+/// Current stats of a session (empty when nothing was recorded)
+pub fn session_stats(session_id: &str) -> Vec<LatencyStat> {
+    sessions()
+        .lock()
+        .ok()
+        .and_then(|g| g.by_session.get(session_id).cloned())
+        .map(|r| r.stats())
+        .unwrap_or_default()
+}
+
+/// Final stats of a session; its recorder is dropped
+pub fn finish_session(session_id: &str) -> Vec<LatencyStat> {
+    let recorder = sessions().lock().ok().and_then(|mut g| {
+        g.order.retain(|s| s != session_id);
+        g.by_session.remove(session_id)
+    });
+    recorder.map(|r| r.stats()).unwrap_or_default()
+}
+
+/// Compact `{"<op>[.<stage>]": {"n":..,"p50":..,"p90":..,"p99":..,"max":..}}` for kpi.* log lines
+pub fn kpi_json(stats: &[LatencyStat]) -> String {
+    let mut map = serde_json::Map::new();
+    for stat in stats {
+        let key = match &stat.stage {
+            Some(stage) => format!("{}.{}", stat.op.as_str(), stage.as_str()),
+            None => stat.op.as_str().to_string(),
+        };
+        let s = &stat.summary;
+        map.insert(
+            key,
+            serde_json::json!({
+                "n": s.count,
+                "p50": s.p50_ms,
+                "p90": s.p90_ms,
+                "p99": s.p99_ms,
+                "max": s.max_ms,
+            }),
+        );
+    }
+    serde_json::Value::Object(map).to_string()
+}
+
+/// Started timer; `finish` records and returns the elapsed time
+pub struct LatencyTimer {
+    started: Instant,
+    key: LatencyKey,
+    session_id: Option<String>,
+}
+
+impl LatencyTimer {
+    pub fn start(key: LatencyKey, session_id: Option<&str>) -> Self {
+        Self {
+            started: Instant::now(),
+            key,
+            session_id: session_id.map(str::to_string),
+        }
+    }
+
+    pub fn finish(self) -> Duration {
+        let elapsed = self.started.elapsed();
+        record_latency(self.session_id.as_deref(), self.key, elapsed);
+        elapsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantiles_stay_within_bucket_precision() {
+        let mut hist = LatencyHistogram::default();
+        for ms in 1..=10_000u64 {
+            hist.record(Duration::from_millis(ms));
+        }
+        let s = hist.summary();
+        assert_eq!(s.count, 10_000);
+        assert_eq!(s.min_ms, 1.0);
+        assert_eq!(s.max_ms, 10_000.0);
+        for (got, want) in [
+            (s.p50_ms, 5_000.0),
+            (s.p90_ms, 9_000.0),
+            (s.p99_ms, 9_900.0),
+        ] {
+            assert!((got - want).abs() / want < 0.01, "got {got}, want ≈{want}");
+        }
+        assert!((s.mean_ms - 5_000.5).abs() < 1e-6);
+
+        // Small values are exact
+        let mut small = LatencyHistogram::default();
+        for us in [3u64, 3, 7, 200] {
+            small.record_micros(us);
+        }
+        assert_eq!(small.value_at_quantile(0.5), 3);
+        assert_eq!(small.value_at_quantile(1.0), 200);
+    }
+
+    #[test]
+    fn merge_matches_single_histogram() {
+        let (mut a, mut b, mut all) = (
+            LatencyHistogram::default(),
+            LatencyHistogram::default(),
+            LatencyHistogram::default(),
+        );
+        for v in 0..5_000u64 {
+            let us = v * 37 % 900_000;
+            let half = if v % 2 == 0 { &mut a } else { &mut b };
+            half.record_micros(us);
+            all.record_micros(us);
+        }
+        a.merge(&b);
+        assert_eq!(a.summary(), all.summary());
+    }
+
+    #[test]
+    fn session_stats_are_scoped_and_dropped_on_finish() {
+        let key = LatencyKey::stage(LatencyOp::StageItem, StageType::ListPageCrawling);
+        record_latency(
+            Some("latency-test-a"),
+            key.clone(),
+            Duration::from_millis(12),
+        );
+        record_latency(Some("latency-test-b"), key, Duration::from_millis(30));
+        let a = session_stats("latency-test-a");
+        assert_eq!(a.len(), 1);
+        assert_eq!(a[0].summary.count, 1);
+        assert!(kpi_json(&a).contains("stage_item.list_page_crawling"));
+        assert_eq!(finish_session("latency-test-a").len(), 1);
+        assert!(session_stats("latency-test-a").is_empty());
+        assert!(!finish_session("latency-test-b").is_empty());
+    }
+}
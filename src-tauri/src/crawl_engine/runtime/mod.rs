@@ -1 +1,8 @@
+pub mod command_dedup; // 같은 명령+인자 중복 호출 병합 (UI 더블클릭)
+pub mod crawl_limits; // 세션 크롤 한도 (신규 제품 수 / 페이지 수 / 시간) → LimitReached
+pub mod latency; // 단계/작업별 소요 시간 히스토그램 (p50/p90/p99)
 pub mod session_registry;
+pub mod site_hints; // 세션 단위 페이지네이션 힌트 (ListPage 가 재확인 없이 재사용)
+pub mod sync_cancellation; // 싱크 세션 취소 토큰 (cancel_sync_session)
+pub mod task_registry; // 백그라운드 태스크 추적 + 수명 초과 watchdog
+pub mod write_coalescer; // 세션별 URL 단위 upsert 병합
@@ -0,0 +1,139 @@
+//! 세션 단위 사이트 페이지네이션 힌트 (total_pages / products_on_last_page)
+//!
+//! 세션 시작 시 한 번 확인한 사이트 상태를 배치/스테이지가 `StageInput` 으로 받아 ListPageLogic 에서
+//! 그대로 쓴다. 힌트가 빠졌거나 TTL 이 지났을 때만 다시 확인하고, 같은 세션의 동시 아이템이 각자
+//! `check_site_status` 를 부르지 않도록 확인은 한 번에 하나만 돌린 뒤 결과를 공유한다.
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Same window as the SessionActor site-status cache
+pub const SITE_HINT_TTL: Duration = Duration::from_secs(300);
+
+/// Pagination facts from one site status check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaginationHints {
+    pub total_pages: u32,
+    pub products_on_last_page: u32,
+    pub observed_at: Instant,
+}
+
+impl PaginationHints {
+    /// Hints observed now; `None` for the zero values callers use as "unknown"
+    pub fn new(total_pages: u32, products_on_last_page: u32) -> Option<Self> {
+        (total_pages > 0 && products_on_last_page > 0).then(|| Self {
+            total_pages,
+            products_on_last_page,
+            observed_at: Instant::now(),
+        })
+    }
+
+    pub fn is_fresh(&self, ttl: Duration) -> bool {
+        self.observed_at.elapsed() <= ttl
+    }
+}
+
+static HINTS: Lazy<Mutex<HashMap<String, PaginationHints>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Serializes fallback checks so concurrent items wait for one result
+static CHECK_LOCK: Lazy<tokio::sync::Mutex<()>> = Lazy::new(|| tokio::sync::Mutex::new(()));
+
+/// Remember `hints` for `session_id` (newer observations win)
+pub fn record(session_id: &str, hints: PaginationHints) {
+    let mut map = HINTS.lock().unwrap_or_else(|e| e.into_inner());
+    let keep = map
+        .get(session_id)
+        .is_some_and(|current| current.observed_at > hints.observed_at);
+    if !keep {
+        map.insert(session_id.to_string(), hints);
+    }
+}
+
+/// Hints for `session_id` observed within `ttl`
+pub fn fresh(session_id: &str, ttl: Duration) -> Option<PaginationHints> {
+    HINTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(session_id)
+        .copied()
+        .filter(|h| h.is_fresh(ttl))
+}
+
+/// Fresh hints for the session, or run `check` once and share its result
+pub async fn get_or_check<F, Fut>(
+    session_id: &str,
+    ttl: Duration,
+    check: F,
+) -> anyhow::Result<PaginationHints>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = anyhow::Result<(u32, u32)>>,
+{
+    if let Some(hints) = fresh(session_id, ttl) {
+        return Ok(hints);
+    }
+    let _guard = CHECK_LOCK.lock().await;
+    // Another item may have finished the check while we waited
+    if let Some(hints) = fresh(session_id, ttl) {
+        return Ok(hints);
+    }
+    let (total_pages, products_on_last_page) = check().await?;
+    let hints = PaginationHints::new(total_pages, products_on_last_page)
+        .ok_or_else(|| anyhow::anyhow!("Site status check returned no pages"))?;
+    record(session_id, hints);
+    Ok(hints)
+}
+
+/// Drop the session's hints (session finished)
+pub fn clear(session_id: &str) {
+    HINTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(session_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[tokio::test]
+    async fn concurrent_items_share_one_check_until_the_ttl_expires() {
+        let session = "site-hints-test";
+        clear(session);
+        assert!(PaginationHints::new(0, 12).is_none());
+
+        let checks = Arc::new(AtomicU32::new(0));
+        let mut tasks = Vec::new();
+        for _ in 0..4 {
+            let checks = Arc::clone(&checks);
+            tasks.push(tokio::spawn(async move {
+                get_or_check(session, SITE_HINT_TTL, || async move {
+                    checks.fetch_add(1, Ordering::SeqCst);
+                    Ok((481, 7))
+                })
+                .await
+                .unwrap()
+            }));
+        }
+        for task in tasks {
+            assert_eq!(task.await.unwrap().total_pages, 481);
+        }
+        assert_eq!(checks.load(Ordering::SeqCst), 1);
+
+        // Expired hints are not reused
+        tokio::time::sleep(Duration::from_millis(2)).await;
+        assert!(fresh(session, Duration::ZERO).is_none());
+        let hints = get_or_check(session, Duration::ZERO, || async { Ok((482, 1)) })
+            .await
+            .unwrap();
+        assert_eq!((hints.total_pages, hints.products_on_last_page), (482, 1));
+        clear(session);
+        assert!(fresh(session, SITE_HINT_TTL).is_none());
+    }
+}
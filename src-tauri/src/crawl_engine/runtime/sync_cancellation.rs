@@ -0,0 +1,191 @@
+//! 싱크 세션 취소/일시정지 토큰 레지스트리
+//!
+//! 싱크 명령은 시작할 때 session_id로 토큰을 등록하고, `cancel_sync_session`이 그 토큰을 취소한다.
+//! 페이지 워커는 URL 단위로 토큰을 확인해 진행 중인 페이지 트랜잭션을 롤백하고 빠져나온다.
+//! 일시정지를 지원하는 싱크(`register_pausable`)는 `pause_sync_session`이 별도 토큰을 올린다:
+//! 아직 시작하지 않은 페이지만 건너뛰고 진행 중인 페이지는 끝까지 커밋한다(drain).
+//! 레지스트리는 프로세스 전역 하나이며 `SharedStateCache`도 같은 인스턴스를 들고 있다.
+
+use once_cell::sync::OnceCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio_util::sync::CancellationToken;
+
+#[derive(Debug)]
+struct Registration {
+    id: u64,
+    cancel: CancellationToken,
+    pause: CancellationToken,
+    pausable: bool,
+}
+
+type TokenMap = Arc<Mutex<HashMap<String, Registration>>>;
+
+static NEXT_REGISTRATION: AtomicU64 = AtomicU64::new(1);
+
+/// Cancellation/pause tokens of running sync sessions, keyed by session_id
+#[derive(Debug, Clone, Default)]
+pub struct SyncCancellationRegistry {
+    tokens: TokenMap,
+}
+
+static SHARED: OnceCell<SyncCancellationRegistry> = OnceCell::new();
+
+impl SyncCancellationRegistry {
+    /// Process-wide registry used by every sync command
+    pub fn shared() -> Self {
+        SHARED.get_or_init(Self::default).clone()
+    }
+
+    /// Register a session; the entry is removed when the returned guard drops
+    pub fn register(&self, session_id: &str) -> SyncCancellationGuard {
+        self.insert(session_id, false)
+    }
+
+    /// `register` for syncs that track their remaining pages and can be resumed
+    pub fn register_pausable(&self, session_id: &str) -> SyncCancellationGuard {
+        self.insert(session_id, true)
+    }
+
+    fn insert(&self, session_id: &str, pausable: bool) -> SyncCancellationGuard {
+        let registration = Registration {
+            id: NEXT_REGISTRATION.fetch_add(1, Ordering::Relaxed),
+            cancel: CancellationToken::new(),
+            pause: CancellationToken::new(),
+            pausable,
+        };
+        let guard = SyncCancellationGuard {
+            session_id: session_id.to_string(),
+            registration: registration.id,
+            token: registration.cancel.clone(),
+            pause: registration.pause.clone(),
+            tokens: self.tokens.clone(),
+        };
+        self.tokens
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(session_id.to_string(), registration);
+        guard
+    }
+
+    /// Request cancellation; false when no such session is running
+    pub fn cancel(&self, session_id: &str) -> bool {
+        match self
+            .tokens
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(session_id)
+        {
+            Some(registration) => {
+                registration.cancel.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Request a pause; false when no pausable session with that id is running
+    pub fn pause(&self, session_id: &str) -> bool {
+        match self
+            .tokens
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(session_id)
+        {
+            Some(registration) if registration.pausable => {
+                registration.pause.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Running session ids, sorted
+    pub fn active_sessions(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .tokens
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .cloned()
+            .collect();
+        ids.sort();
+        ids
+    }
+}
+
+/// Keeps a session registered while its sync runs
+#[derive(Debug)]
+pub struct SyncCancellationGuard {
+    session_id: String,
+    registration: u64,
+    token: CancellationToken,
+    pause: CancellationToken,
+    tokens: TokenMap,
+}
+
+impl SyncCancellationGuard {
+    pub fn token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Raised by `pause`; never fires for sessions registered with `register`
+    pub fn pause_token(&self) -> CancellationToken {
+        self.pause.clone()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.pause.is_cancelled()
+    }
+}
+
+impl Drop for SyncCancellationGuard {
+    fn drop(&mut self) {
+        let mut tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        // A newer registration under the same id keeps its own token
+        if tokens
+            .get(&self.session_id)
+            .is_some_and(|registration| registration.id == self.registration)
+        {
+            tokens.remove(&self.session_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel_reaches_registered_session_until_guard_drops() {
+        let registry = SyncCancellationRegistry::default();
+        assert!(!registry.cancel("sync-1"));
+
+        let guard = registry.register("sync-1");
+        let token = guard.token();
+        assert_eq!(registry.active_sessions(), vec!["sync-1".to_string()]);
+        assert!(registry.cancel("sync-1"));
+        assert!(token.is_cancelled() && guard.is_cancelled());
+
+        drop(guard);
+        assert!(registry.active_sessions().is_empty());
+        assert!(!registry.cancel("sync-1"));
+    }
+
+    #[test]
+    fn only_pausable_sessions_accept_pause() {
+        let registry = SyncCancellationRegistry::default();
+        let plain = registry.register("sync-plain");
+        let pausable = registry.register_pausable("sync-pausable");
+
+        assert!(!registry.pause("sync-plain") && !plain.is_paused());
+        assert!(registry.pause("sync-pausable"));
+        assert!(pausable.is_paused() && !pausable.is_cancelled());
+        assert!(!registry.pause("sync-missing"));
+    }
+}
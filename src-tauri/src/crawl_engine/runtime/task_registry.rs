@@ -0,0 +1,300 @@
+//! Background task registry & watchdog
+//!
+//! 크롤/싱크 경로에서 spawn 되는 태스크를 이름·시작 시각·예상 수명과 함께 등록해 두고,
+//! 완료(정상/중단)를 추적한다. 예상 수명을 넘긴 태스크는 watchdog 이 한 번 경고하며,
+//! `list_background_tasks` 로 현재/최근 태스크를 조회할 수 있다.
+use chrono::{DateTime, Utc};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+/// Finished tasks kept for inspection (oldest evicted first)
+const FINISHED_HISTORY: usize = 200;
+
+/// Expected lifetime of one sync/stage page worker, including its wait for a permit
+pub const PAGE_WORKER_LIFETIME: Duration = Duration::from_secs(30 * 60);
+
+/// Expected lifetime of one crawl session's driver task
+pub const SESSION_TASK_LIFETIME: Duration = Duration::from_secs(6 * 60 * 60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackgroundTaskState {
+    Running,
+    Completed,
+    /// Dropped before finishing (aborted, runtime shutdown or panic)
+    Aborted,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackgroundTaskInfo {
+    pub id: u64,
+    pub name: String,
+    pub state: BackgroundTaskState,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub elapsed_ms: u64,
+    /// None = long-lived by design (never reported as overdue)
+    pub expected_lifetime_ms: Option<u64>,
+    pub overdue: bool,
+}
+
+#[derive(Debug)]
+struct TaskEntry {
+    name: String,
+    started_at: DateTime<Utc>,
+    expected_lifetime: Option<Duration>,
+    finished_at: Option<DateTime<Utc>>,
+    state: BackgroundTaskState,
+    warned: bool,
+}
+
+impl TaskEntry {
+    fn info(&self, id: u64, now: DateTime<Utc>) -> BackgroundTaskInfo {
+        let end = self.finished_at.unwrap_or(now);
+        let elapsed_ms = (end - self.started_at).num_milliseconds().max(0) as u64;
+        let expected_lifetime_ms = self.expected_lifetime.map(|d| d.as_millis() as u64);
+        BackgroundTaskInfo {
+            id,
+            name: self.name.clone(),
+            state: self.state,
+            started_at: self.started_at,
+            finished_at: self.finished_at,
+            elapsed_ms,
+            expected_lifetime_ms,
+            overdue: expected_lifetime_ms.is_some_and(|limit| elapsed_ms > limit),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct RegistryInner {
+    running: HashMap<u64, TaskEntry>,
+    finished: VecDeque<(u64, TaskEntry)>,
+}
+
+#[derive(Debug, Default)]
+pub struct TaskRegistry {
+    next_id: AtomicU64,
+    inner: Mutex<RegistryInner>,
+}
+
+impl TaskRegistry {
+    fn register(&self, name: String, expected_lifetime: Option<Duration>) -> u64 {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let entry = TaskEntry {
+            name,
+            started_at: Utc::now(),
+            expected_lifetime,
+            finished_at: None,
+            state: BackgroundTaskState::Running,
+            warned: false,
+        };
+        if let Ok(mut g) = self.inner.lock() {
+            g.running.insert(id, entry);
+        }
+        id
+    }
+
+    fn finish(&self, id: u64, state: BackgroundTaskState) {
+        let Ok(mut g) = self.inner.lock() else {
+            return;
+        };
+        if let Some(mut entry) = g.running.remove(&id) {
+            entry.state = state;
+            entry.finished_at = Some(Utc::now());
+            if g.finished.len() >= FINISHED_HISTORY {
+                g.finished.pop_front();
+            }
+            g.finished.push_back((id, entry));
+        }
+    }
+
+    /// Running tasks (oldest first) followed by recently finished ones (newest first)
+    pub fn list(&self) -> Vec<BackgroundTaskInfo> {
+        let now = Utc::now();
+        let Ok(g) = self.inner.lock() else {
+            return Vec::new();
+        };
+        let mut running: Vec<BackgroundTaskInfo> =
+            g.running.iter().map(|(id, e)| e.info(*id, now)).collect();
+        running.sort_by_key(|t| (t.started_at, t.id));
+        running.extend(g.finished.iter().rev().map(|(id, e)| e.info(*id, now)));
+        running
+    }
+
+    /// Running tasks that just passed their expected lifetime (each reported once)
+    pub fn take_newly_overdue(&self) -> Vec<BackgroundTaskInfo> {
+        let now = Utc::now();
+        let Ok(mut g) = self.inner.lock() else {
+            return Vec::new();
+        };
+        let mut overdue = Vec::new();
+        for (id, entry) in g.running.iter_mut() {
+            if entry.warned {
+                continue;
+            }
+            let info = entry.info(*id, now);
+            if info.overdue {
+                entry.warned = true;
+                overdue.push(info);
+            }
+        }
+        overdue
+    }
+
+    pub fn running_count(&self) -> usize {
+        self.inner.lock().map(|g| g.running.len()).unwrap_or(0)
+    }
+}
+
+/// Marks the task finished when its future completes or is dropped
+struct TaskGuard {
+    registry: Arc<TaskRegistry>,
+    id: u64,
+    completed: bool,
+}
+
+impl Drop for TaskGuard {
+    fn drop(&mut self) {
+        let state = if self.completed {
+            BackgroundTaskState::Completed
+        } else {
+            BackgroundTaskState::Aborted
+        };
+        self.registry.finish(self.id, state);
+    }
+}
+
+static TASK_REGISTRY: OnceCell<Arc<TaskRegistry>> = OnceCell::new();
+
+pub fn task_registry() -> Arc<TaskRegistry> {
+    TASK_REGISTRY
+        .get_or_init(|| Arc::new(TaskRegistry::default()))
+        .clone()
+}
+
+/// `tokio::spawn` that records the task in the global registry
+pub fn spawn_tracked<F>(
+    name: impl Into<String>,
+    expected_lifetime: Option<Duration>,
+    future: F,
+) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    spawn_in(task_registry(), name.into(), expected_lifetime, future)
+}
+
+fn spawn_in<F>(
+    registry: Arc<TaskRegistry>,
+    name: String,
+    expected_lifetime: Option<Duration>,
+    future: F,
+) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    let id = registry.register(name, expected_lifetime);
+    let mut guard = TaskGuard {
+        registry,
+        id,
+        completed: false,
+    };
+    tokio::spawn(async move {
+        let output = future.await;
+        guard.completed = true;
+        output
+    })
+}
+
+/// Periodically warn about tasks running past their expected lifetime
+pub fn start_task_watchdog(interval: Duration) {
+    spawn_tracked("task-watchdog", None, async move {
+        let registry = task_registry();
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            for task in registry.take_newly_overdue() {
+                warn!(
+                    "⏱️ Background task '{}' (#{}) running for {}s, expected ≤ {}s — possible leak",
+                    task.name,
+                    task.id,
+                    task.elapsed_ms / 1000,
+                    task.expected_lifetime_ms.unwrap_or(0) / 1000
+                );
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> Arc<TaskRegistry> {
+        Arc::new(TaskRegistry::default())
+    }
+
+    #[tokio::test]
+    async fn tracks_completion_and_abort() {
+        let reg = registry();
+        let done = spawn_in(reg.clone(), "done".into(), None, async { 7 });
+        assert_eq!(done.await.unwrap(), 7);
+
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let stuck = spawn_in(reg.clone(), "stuck".into(), None, async move {
+            let _ = rx.await;
+        });
+        tokio::task::yield_now().await;
+        assert_eq!(reg.running_count(), 1);
+        stuck.abort();
+        let _ = stuck.await;
+        drop(tx);
+
+        let tasks = reg.list();
+        assert_eq!(reg.running_count(), 0);
+        let state_of = |name: &str| tasks.iter().find(|t| t.name == name).unwrap().state;
+        assert_eq!(state_of("done"), BackgroundTaskState::Completed);
+        assert_eq!(state_of("stuck"), BackgroundTaskState::Aborted);
+    }
+
+    #[tokio::test]
+    async fn overdue_tasks_reported_once() {
+        let reg = registry();
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let handle = spawn_in(
+            reg.clone(),
+            "slow".into(),
+            Some(Duration::from_millis(1)),
+            async move {
+                let _ = rx.await;
+            },
+        );
+        let _forever = spawn_in(
+            reg.clone(),
+            "daemon".into(),
+            None,
+            std::future::pending::<()>(),
+        );
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let overdue = reg.take_newly_overdue();
+        assert_eq!(overdue.len(), 1);
+        assert_eq!(overdue[0].name, "slow");
+        assert!(reg.take_newly_overdue().is_empty());
+
+        tx.send(()).unwrap();
+        handle.await.unwrap();
+        let slow = reg.list().into_iter().find(|t| t.name == "slow").unwrap();
+        assert_eq!(slow.state, BackgroundTaskState::Completed);
+    }
+}
@@ -0,0 +1,288 @@
+//! Per-session write coalescing for product upserts
+//!
+//! 같은 URL이 한 세션 안에서 여러 번(placeholder, detail, backfill) upsert 되면 그만큼 쓰기와
+//! 락 점유가 늘어난다. 세션별 coalescer가 URL 단위로 필드 변경을 누적(`stage`)했다가,
+//! 페이지 저장이 끝날 때 병합된 한 건만 `create_or_update_product_detail`로 기록한다(`flush_pages`).
+//! flush 직후 바뀐 URL만 검색 인덱스/rollup에 반영한다(`services::search_index::apply_deltas`).
+use crate::crawl_engine::runtime::latency::{LatencyKey, LatencyOp, LatencyTimer};
+use crate::domain::product::ProductDetail;
+use crate::infrastructure::IntegratedProductRepository;
+use crate::infrastructure::features::feature_search_index_hooks;
+use crate::infrastructure::read_snapshot;
+use crate::services::search_index;
+use once_cell::sync::OnceCell;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, warn};
+
+#[derive(Debug, Clone)]
+struct PendingWrite {
+    detail: ProductDetail,
+    /// Number of staged upserts folded into this entry
+    staged: u32,
+}
+
+/// Outcome of one flush (counts are per merged URL, not per staged upsert)
+#[derive(Debug, Clone, Default)]
+pub struct CoalescedFlush {
+    pub inserted: u32,
+    pub updated: u32,
+    pub unchanged: u32,
+    /// Staged upserts that never reached the database because they merged into another
+    pub writes_saved: u32,
+    /// URLs whose merged write was a no-op (candidates for duplicate-policy handling)
+    pub unchanged_details: Vec<ProductDetail>,
+}
+
+/// Accumulates field changes per URL until the owning page is flushed
+#[derive(Debug, Default)]
+pub struct SessionWriteCoalescer {
+    pending: Mutex<HashMap<String, PendingWrite>>,
+}
+
+impl SessionWriteCoalescer {
+    /// Fold `detail` into the pending write for its URL. Later values win; `None` never erases.
+    pub fn stage(&self, detail: &ProductDetail) {
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        match pending.get_mut(&detail.url) {
+            Some(existing) => {
+                merge_detail(&mut existing.detail, detail);
+                existing.staged += 1;
+            }
+            None => {
+                pending.insert(
+                    detail.url.clone(),
+                    PendingWrite {
+                        detail: detail.clone(),
+                        staged: 1,
+                    },
+                );
+            }
+        }
+    }
+
+    pub fn pending_len(&self) -> usize {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Remove pending writes whose page_id is in `pages` (ordered by page/index for stable writes)
+    fn take_pages(&self, pages: &[Option<i32>]) -> Vec<PendingWrite> {
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        let urls: Vec<String> = pending
+            .iter()
+            .filter(|(_, w)| pages.contains(&w.detail.page_id))
+            .map(|(url, _)| url.clone())
+            .collect();
+        let mut taken: Vec<PendingWrite> =
+            urls.iter().filter_map(|url| pending.remove(url)).collect();
+        taken.sort_by_key(|w| (w.detail.page_id, w.detail.index_in_page));
+        taken
+    }
+
+    /// Write one merged upsert per URL for the completed pages
+    pub async fn flush_pages(
+        &self,
+        pages: &[Option<i32>],
+        repo: &IntegratedProductRepository,
+    ) -> anyhow::Result<CoalescedFlush> {
+        let writes = self.take_pages(pages);
+        let mut out = CoalescedFlush::default();
+        // Row-by-row writes: snapshot readers wait for the whole page
+        let page_write = read_snapshot::begin_page_write().await;
+        let mut changed_urls = Vec::new();
+        for write in writes {
+            out.writes_saved += write.staged.saturating_sub(1);
+            let timer = LatencyTimer::start(LatencyKey::op(LatencyOp::DbWrite), None);
+            let (was_updated, was_created) =
+                repo.create_or_update_product_detail(&write.detail).await?;
+            timer.finish();
+            if was_created {
+                out.inserted += 1;
+            }
+            if was_updated {
+                out.updated += 1;
+            }
+            if was_created || was_updated {
+                // Stored under the canonical URL
+                changed_urls.push(IntegratedProductRepository::normalize_url(
+                    &write.detail.url,
+                ));
+            }
+            if !was_created && !was_updated {
+                out.unchanged += 1;
+                out.unchanged_details.push(write.detail);
+            }
+        }
+        drop(page_write);
+        // Post-flush hook: derived tables lag at worst until the next consistency check
+        if feature_search_index_hooks() && !changed_urls.is_empty() {
+            if let Err(e) = search_index::apply_deltas(repo.pool(), &changed_urls).await {
+                warn!("[WriteCoalescer] search index delta failed (run check_search_index): {e:#}");
+            }
+        }
+        if out.writes_saved > 0 {
+            debug!(
+                "[WriteCoalescer] pages={:?} inserted={} updated={} unchanged={} writes_saved={}",
+                pages, out.inserted, out.updated, out.unchanged, out.writes_saved
+            );
+        }
+        Ok(out)
+    }
+}
+
+/// Overlay the populated fields of `incoming` onto `target`
+fn merge_detail(target: &mut ProductDetail, incoming: &ProductDetail) {
+    macro_rules! overlay_copy {
+        ($($field:ident),* $(,)?) => {
+            $( target.$field = incoming.$field.or(target.$field); )*
+        };
+    }
+    macro_rules! overlay_str {
+        ($($field:ident),* $(,)?) => {
+            $(
+                if incoming.$field.is_some() {
+                    target.$field = incoming.$field.clone();
+                }
+            )*
+        };
+    }
+    overlay_copy!(page_id, index_in_page, vid, pid);
+    overlay_str!(
+        id,
+        manufacturer,
+        model,
+        device_type,
+        certificate_id,
+        certification_date,
+        software_version,
+        hardware_version,
+        family_sku,
+        family_variant_sku,
+        firmware_version,
+        family_id,
+        tis_trp_tested,
+        specification_version,
+        transport_interface,
+        primary_device_type_id,
+        application_categories,
+        description,
+        compliance_document_url,
+        program_type,
+    );
+    target.created_at = target.created_at.min(incoming.created_at);
+    target.updated_at = target.updated_at.max(incoming.updated_at);
+}
+
+static SESSION_COALESCERS: OnceCell<Mutex<HashMap<String, Arc<SessionWriteCoalescer>>>> =
+    OnceCell::new();
+
+fn coalescers() -> &'static Mutex<HashMap<String, Arc<SessionWriteCoalescer>>> {
+    SESSION_COALESCERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Coalescer owned by `session_id` (created on first use)
+pub fn session_write_coalescer(session_id: &str) -> Arc<SessionWriteCoalescer> {
+    coalescers()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry(session_id.to_string())
+        .or_default()
+        .clone()
+}
+
+/// Drop the session's coalescer; returns how many writes were still pending (should be 0)
+pub fn release_session_write_coalescer(session_id: &str) -> usize {
+    coalescers()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(session_id)
+        .map_or(0, |c| c.pending_len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn detail(url: &str, page_id: i32, index_in_page: i32) -> ProductDetail {
+        let now = Utc::now();
+        ProductDetail {
+            url: url.to_string(),
+            page_id: Some(page_id),
+            index_in_page: Some(index_in_page),
+            id: None,
+            manufacturer: None,
+            model: None,
+            device_type: None,
+            certificate_id: None,
+            certification_date: None,
+            software_version: None,
+            hardware_version: None,
+            vid: None,
+            pid: None,
+            family_sku: None,
+            family_variant_sku: None,
+            firmware_version: None,
+            family_id: None,
+            tis_trp_tested: None,
+            specification_version: None,
+            transport_interface: None,
+            primary_device_type_id: None,
+            application_categories: None,
+            description: None,
+            compliance_document_url: None,
+            program_type: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn repeated_upserts_merge_into_one_write() {
+        let coalescer = SessionWriteCoalescer::default();
+        let placeholder = detail("https://x.test/a", 3, 1);
+        let mut full = detail("https://x.test/a", 3, 1);
+        full.manufacturer = Some("Acme".into());
+        full.model = Some("M1".into());
+        full.updated_at = placeholder.updated_at + Duration::seconds(5);
+        let mut backfill = detail("https://x.test/a", 3, 1);
+        backfill.vid = Some(0x1234);
+        backfill.model = None;
+
+        coalescer.stage(&placeholder);
+        coalescer.stage(&full);
+        coalescer.stage(&backfill);
+        assert_eq!(coalescer.pending_len(), 1);
+
+        let taken = coalescer.take_pages(&[Some(3)]);
+        assert_eq!(taken.len(), 1);
+        let merged = &taken[0];
+        assert_eq!(merged.staged, 3);
+        assert_eq!(merged.detail.manufacturer.as_deref(), Some("Acme"));
+        assert_eq!(merged.detail.model.as_deref(), Some("M1"));
+        assert_eq!(merged.detail.vid, Some(0x1234));
+        assert_eq!(merged.detail.updated_at, full.updated_at);
+    }
+
+    #[test]
+    fn flush_only_takes_completed_pages() {
+        let coalescer = SessionWriteCoalescer::default();
+        coalescer.stage(&detail("https://x.test/a", 3, 1));
+        coalescer.stage(&detail("https://x.test/b", 3, 0));
+        coalescer.stage(&detail("https://x.test/c", 4, 0));
+
+        let taken = coalescer.take_pages(&[Some(3)]);
+        let urls: Vec<&str> = taken.iter().map(|w| w.detail.url.as_str()).collect();
+        assert_eq!(urls, vec!["https://x.test/b", "https://x.test/a"]);
+        assert_eq!(coalescer.pending_len(), 1);
+    }
+
+    #[test]
+    fn sessions_get_independent_coalescers() {
+        let a = session_write_coalescer("coalescer-test-a");
+        a.stage(&detail("https://x.test/a", 1, 0));
+        assert_eq!(session_write_coalescer("coalescer-test-b").pending_len(), 0);
+        assert_eq!(release_session_write_coalescer("coalescer-test-a"), 1);
+        assert_eq!(release_session_write_coalescer("coalescer-test-b"), 0);
+    }
+}
@@ -35,7 +35,7 @@ pub struct CrawlingIntegrationService {
     database_analyzer: Arc<dyn DatabaseAnalyzer>, // REMOVE_CANDIDATE(if still unused)
     product_repository: Arc<IntegratedProductRepository>,
     config: Arc<SystemConfig>, // REMOVE_CANDIDATE(if still unused)
-    app_config: AppConfig,     // REMOVE_CANDIDATE(if still unused)
+    app_config: AppConfig,
 }
 
 impl CrawlingIntegrationService {
@@ -283,8 +283,14 @@ impl CrawlingIntegrationService {
                 break;
             }
 
+            let max_retries = self
+                .app_config
+                .advanced
+                .retry_policies
+                .list()
+                .max_retries_or(3);
             match self
-                .collect_single_page_with_retry_with_meta(page, 3, perform_site_check)
+                .collect_single_page_with_retry_with_meta(page, max_retries, perform_site_check)
                 .await
             {
                 Ok((urls, retry_count, duration_ms)) => {
@@ -399,7 +405,13 @@ impl CrawlingIntegrationService {
         urls: Vec<ProductUrl>,
         cancellation_token: CancellationToken,
     ) -> anyhow::Result<(Vec<ProductDetail>, u32, u64)> {
-        self.collect_detail_batch_with_retry_with_meta(&urls, cancellation_token, 2)
+        let max_retries = self
+            .app_config
+            .advanced
+            .retry_policies
+            .detail()
+            .max_retries_or(2);
+        self.collect_detail_batch_with_retry_with_meta(&urls, cancellation_token, max_retries)
             .await
             .map_err(|e| anyhow::anyhow!("{}", e))
     }
@@ -462,8 +474,14 @@ impl CrawlingIntegrationService {
                 break;
             }
 
+            let max_retries = self
+                .app_config
+                .advanced
+                .retry_policies
+                .list()
+                .max_retries_or(3);
             match self
-                .collect_single_page_with_retry(page, 3, perform_site_check)
+                .collect_single_page_with_retry(page, max_retries, perform_site_check)
                 .await
             {
                 Ok(urls) => {
@@ -534,7 +552,12 @@ impl CrawlingIntegrationService {
                             page, expected_per_page, urls.len()
                         ));
                         if attempt < max_retries {
-                            let delay = Duration::from_millis(1000 * (2_u64.pow(attempt)));
+                            let delay = self
+                                .app_config
+                                .advanced
+                                .retry_policies
+                                .list()
+                                .delay(attempt + 1);
                             debug!(
                                 page = page,
                                 attempt = attempt,
@@ -557,7 +580,12 @@ impl CrawlingIntegrationService {
                 Err(e) => {
                     last_error = Some(e);
                     if attempt < max_retries {
-                        let delay = Duration::from_millis(1000 * (2_u64.pow(attempt)));
+                        let delay = self
+                            .app_config
+                            .advanced
+                            .retry_policies
+                            .list()
+                            .delay(attempt + 1);
                         debug!(
                             page = page,
                             attempt = attempt,
@@ -628,7 +656,12 @@ impl CrawlingIntegrationService {
                             page, expected_per_page, urls.len()
                         ));
                         if attempt < max_retries {
-                            let delay = Duration::from_millis(1000 * (2_u64.pow(attempt)));
+                            let delay = self
+                                .app_config
+                                .advanced
+                                .retry_policies
+                                .list()
+                                .delay(attempt + 1);
                             debug!(
                                 page = page,
                                 attempt = attempt,
@@ -652,7 +685,12 @@ impl CrawlingIntegrationService {
                 Err(e) => {
                     last_error = Some(e);
                     if attempt < max_retries {
-                        let delay = Duration::from_millis(1000 * (2_u64.pow(attempt)));
+                        let delay = self
+                            .app_config
+                            .advanced
+                            .retry_policies
+                            .list()
+                            .delay(attempt + 1);
                         debug!(
                             page = page,
                             attempt = attempt,
@@ -716,7 +754,12 @@ impl CrawlingIntegrationService {
                 Err(e) => {
                     last_error = Some(e);
                     if attempt < max_retries {
-                        let delay = Duration::from_millis(1000 * (2_u64.pow(attempt)));
+                        let delay = self
+                            .app_config
+                            .advanced
+                            .retry_policies
+                            .detail()
+                            .delay(attempt + 1);
                         debug!(
                             attempt = attempt,
                             delay_ms = delay.as_millis(),
@@ -753,7 +796,15 @@ impl RealCrawlingStageExecutor {
         cancellation_token: CancellationToken,
     ) -> StageResult {
         match stage_type {
-            StageType::ListCollection => {
+            StageType::StatusCheck => {
+                // 상태 확인은 채널 파이프라인 밖(StatusChecker)에서 수행됨
+                StageResult::Success {
+                    processed_items: items.len() as u32,
+                    duration_ms: 0,
+                }
+            }
+
+            StageType::ListPageCrawling => {
                 let pages: Vec<u32> = items
                     .into_iter()
                     .filter_map(|item| match item {
@@ -767,7 +818,7 @@ impl RealCrawlingStageExecutor {
                     .await
             }
 
-            StageType::DetailCollection => {
+            StageType::ProductDetailCrawling => {
                 // 현재는 URL 아이템이 없으므로 빈 처리
                 // 실제로는 이전 단계에서 수집된 URL을 받아야 함
                 let urls = Vec::new(); // TODO: 실제 URL 전달 구현
@@ -785,7 +836,7 @@ impl RealCrawlingStageExecutor {
                 }
             }
 
-            StageType::DatabaseSave => {
+            StageType::DataSaving => {
                 // 데이터베이스 저장 로직 (현재는 성공으로 처리)
                 StageResult::Success {
                     processed_items: items.len() as u32,
@@ -742,23 +742,33 @@ impl CrawlingPlanner {
     /// 최적 배치 크기를 계산합니다.
     fn calculate_optimal_batch_size(&self, total_pages: u32) -> u32 {
         // 총 페이지 수에 따른 적응적 배치 크기
-        match total_pages {
+        let adaptive = match total_pages {
             1..=50 => 10,
             51..=200 => 20,
             201..=1000 => 50,
             _ => 100,
+        };
+        // 호스트 프로파일이 있으면 권장 배치 크기로 상한
+        match crate::infrastructure::host_profile::current() {
+            Some(profile) => adaptive.min(profile.recommended.batch_size.max(1)),
+            None => adaptive,
         }
     }
 
     /// 최적 동시성 수준을 계산합니다.
     fn calculate_optimal_concurrency(&self) -> u32 {
-        // 시스템 설정 기반 동시성 계산
-        self.config
+        // 시스템 설정 기반 동시성 계산 (호스트 프로파일 권장값으로 상한)
+        let configured = self
+            .config
             .crawling
             .as_ref()
             .and_then(|c| c.default_concurrency_limit)
             .unwrap_or(5)
-            .min(10)
+            .min(10);
+        match crate::infrastructure::host_profile::current() {
+            Some(profile) => configured.min(profile.recommended.max_concurrent_requests.max(1)),
+            None => configured,
+        }
     }
 
     /// 최적 지연 시간을 계산합니다.
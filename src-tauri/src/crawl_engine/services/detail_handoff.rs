@@ -0,0 +1,205 @@
+//! 상세 수집 → 저장 스트리밍 핸드오프 (`advanced.memory_budget.streaming_handoff`)
+//!
+//! 배치 전체의 ProductDetail 을 Vec 으로 모았다가 DataValidation / DataSaving 에 넘기면 큰
+//! 세션에서 메모리가 부푼다. BatchActor 는 상세 수집을 페이지 청크 단위로 돌려 청크 결과를
+//! bounded channel 로 저장 태스크에 넘기고, 채널이 차 있으면 다음 청크 수집 전에 기다린다.
+//! 저장 태스크는 검증 후 persistence queue 에 넣는다 (메모리 예산 초과 시 queue 가 조기 flush).
+
+use crate::crawl_engine::services::data_quality_analyzer::DataQualityAnalyzer;
+use crate::domain::product::ProductDetail;
+use crate::infrastructure::memory_budget;
+use crate::infrastructure::persistence_queue::{FlushReport, PersistenceQueue};
+use anyhow::{Result, anyhow};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+/// What the saver did with the handed-off chunks
+#[derive(Debug, Clone, Default)]
+pub struct HandoffReport {
+    pub chunks: u32,
+    pub received: u32,
+    /// Rows that passed validation (all received rows when validation is skipped)
+    pub validated: u32,
+    /// Rows of chunks the validator refused
+    pub validation_failed: u32,
+    /// New rows dropped because the session's `max_new_products` ran out mid-chunk
+    pub over_limit: u32,
+    pub flush: FlushReport,
+}
+
+pub struct DetailHandoff {
+    tx: mpsc::Sender<(Vec<ProductDetail>, u64)>,
+    saver: JoinHandle<Result<HandoffReport>>,
+    /// Bytes sent but not yet taken by the saver (released on finish if the saver died)
+    in_flight: Arc<AtomicU64>,
+}
+
+impl DetailHandoff {
+    /// Start the saver for `session_id`; at most `capacity` chunks wait for it before `send`
+    /// blocks
+    pub fn spawn(
+        queue: Arc<PersistenceQueue>,
+        session_id: String,
+        capacity: usize,
+        validate: bool,
+    ) -> Self {
+        let (tx, mut rx) = mpsc::channel::<(Vec<ProductDetail>, u64)>(capacity.max(1));
+        let in_flight = Arc::new(AtomicU64::new(0));
+        let taken = Arc::clone(&in_flight);
+        let saver = tokio::spawn(async move {
+            let analyzer = DataQualityAnalyzer::new();
+            let mut report = HandoffReport::default();
+            while let Some((chunk, bytes)) = rx.recv().await {
+                taken.fetch_sub(bytes, Ordering::Relaxed);
+                memory_budget::release_handoff(bytes);
+                report.chunks += 1;
+                report.received += chunk.len() as u32;
+                let rows = if validate {
+                    match analyzer.validate_before_storage(&chunk) {
+                        Ok(rows) => rows,
+                        Err(e) => {
+                            warn!(
+                                "🧮 handoff chunk failed validation ({} rows): {}",
+                                chunk.len(),
+                                e
+                            );
+                            report.validation_failed += chunk.len() as u32;
+                            continue;
+                        }
+                    }
+                } else {
+                    chunk
+                };
+                report.validated += rows.len() as u32;
+                let validated = rows.len() as u32;
+                let rows = queue.cap_new_products(&session_id, rows).await?;
+                report.over_limit += validated - rows.len() as u32;
+                report.flush.absorb(&queue.enqueue(rows).await?);
+            }
+            // Commit the tail so the batch's inserted/updated counts are backed by rows
+            report.flush.absorb(&queue.flush().await?);
+            Ok(report)
+        });
+        Self {
+            tx,
+            saver,
+            in_flight,
+        }
+    }
+
+    /// Hand one collected chunk to the saver, waiting while the channel is full
+    pub async fn send(&self, products: Vec<ProductDetail>) -> Result<()> {
+        if products.is_empty() {
+            return Ok(());
+        }
+        let permit = match self.tx.try_reserve() {
+            Ok(permit) => permit,
+            Err(TrySendError::Full(())) => {
+                memory_budget::note_backpressure();
+                self.tx
+                    .reserve()
+                    .await
+                    .map_err(|_| anyhow!("detail handoff saver stopped"))?
+            }
+            Err(TrySendError::Closed(())) => return Err(anyhow!("detail handoff saver stopped")),
+        };
+        let bytes = memory_budget::estimated_bytes(&products);
+        self.in_flight.fetch_add(bytes, Ordering::Relaxed);
+        memory_budget::hold_handoff(bytes);
+        permit.send((products, bytes));
+        Ok(())
+    }
+
+    /// Close the channel and wait for the saver's final flush
+    pub async fn finish(self) -> Result<HandoffReport> {
+        drop(self.tx);
+        let result = self
+            .saver
+            .await
+            .map_err(|e| anyhow!("detail handoff saver panicked: {e}"));
+        memory_budget::release_handoff(self.in_flight.swap(0, Ordering::Relaxed));
+        result?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence_queue::QueueLimits;
+    use chrono::Utc;
+
+    fn detail(n: i32) -> ProductDetail {
+        let now = Utc::now();
+        ProductDetail {
+            url: format!("https://x.test/handoff/{n}/"),
+            page_id: Some(n / 12),
+            index_in_page: Some(n % 12),
+            id: None,
+            manufacturer: Some("Acme".into()),
+            model: Some(format!("M{n}")),
+            device_type: None,
+            certificate_id: None,
+            certification_date: None,
+            software_version: None,
+            hardware_version: None,
+            vid: None,
+            pid: None,
+            family_sku: None,
+            family_variant_sku: None,
+            firmware_version: None,
+            family_id: None,
+            tis_trp_tested: None,
+            specification_version: None,
+            transport_interface: None,
+            primary_device_type_id: None,
+            application_categories: None,
+            description: None,
+            compliance_document_url: None,
+            program_type: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn chunks_stream_through_a_bounded_channel_and_commit_on_finish() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query(include_str!(
+            "../../../migrations/003_integrated_schema.sql"
+        ))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let limits = QueueLimits {
+            batch_size: 50,
+            memory_budget_bytes: u64::MAX,
+        };
+        let queue = Arc::new(PersistenceQueue::new(pool.clone(), limits));
+        let handoff = DetailHandoff::spawn(queue, "handoff-test".into(), 1, false);
+        for chunk in 0..4 {
+            let rows: Vec<_> = (chunk * 12..chunk * 12 + 12).map(detail).collect();
+            handoff.send(rows).await.unwrap();
+        }
+        handoff.send(Vec::new()).await.unwrap();
+        let report = handoff.finish().await.unwrap();
+
+        assert_eq!(
+            (report.chunks, report.received, report.validated),
+            (4, 48, 48)
+        );
+        let saved: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM product_details WHERE url LIKE '%/handoff/%'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(saved, 48);
+    }
+}
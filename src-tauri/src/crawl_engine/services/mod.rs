@@ -1,9 +1,11 @@
 pub mod crawling_integration;
 pub mod crawling_planner;
 pub mod data_quality_analyzer;
+pub mod detail_handoff; // 상세 수집 → 저장 bounded channel 핸드오프 (메모리 가드)
 pub mod performance_optimizer; // 🔧 Phase C: 성능 최적화 서비스
 pub mod real_crawling_commands;
 pub mod real_crawling_integration; // 🔍 데이터 품질 분석 서비스
+pub mod session_report; // 세션 종료 리포트 (JSON/Markdown)
 
 pub use crawling_planner::CrawlingPlanner;
 pub mod data_consistency_checker;
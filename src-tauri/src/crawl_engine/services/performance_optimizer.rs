@@ -1,13 +1,26 @@
 //! 크롤링 성능 최적화 서비스
 //! Phase C: 실시간 성능 모니터링 및 자동 최적화
-
+//!
+//! 적응형 동시성: 고정된 list/detail 동시성은 너무 느리거나 사이트 스로틀링(429)을 유발했다.
+//! StageActor 는 이제 `adaptive_limiter` 로 받은 세마포어를 쓰고, 세션-스테이지마다 도는 AIMD
+//! 컨트롤러가 `window_secs` 마다 HTTP 응답 창(p95 지연, 429/5xx 비율)을 보고 허용 수를 조정한다.
+//! 오류율이나 p95 가 목표를 넘으면 `decrease_factor` 배로 줄이고, 건강하면 1 씩 늘린다.
+//! 조정은 `ConcurrencyAdjusted` 이벤트로 알리고, 현재 목표는 `concurrency_targets` 로 조회한다.
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use tokio::sync::{AcquireError, OwnedSemaphorePermit, RwLock, Semaphore};
 use tracing::{debug, info};
 use ts_rs::TS;
 
+use crate::crawl_engine::actors::types::{AppEvent, StageType};
 use crate::crawl_engine::config::SystemConfig;
+use crate::crawl_engine::integrated_context::AppContext;
+use crate::crawl_engine::runtime::task_registry::{SESSION_TASK_LIFETIME, spawn_tracked};
 
 /// 크롤링 성능 메트릭
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -122,6 +135,9 @@ impl CrawlingPerformanceOptimizer {
             )
             .await;
 
+        #[cfg(feature = "metrics-exporter")]
+        crate::infrastructure::metrics_exporter::observe_performance(&metrics);
+
         // 히스토리에 추가
         let mut history = self.metrics_history.write().await;
         history.push(metrics.clone());
@@ -352,3 +368,488 @@ impl CrawlingPerformanceOptimizer {
 
 /// Type alias for compatibility
 pub type PerformanceOptimizer = CrawlingPerformanceOptimizer;
+
+// ===== 적응형 동시성 (AIMD) =====
+
+/// Bound on latency samples kept between controller ticks
+const MAX_WINDOW_SAMPLES: usize = 4096;
+
+/// `advanced.adaptive_concurrency`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdaptiveConcurrencyConfig {
+    /// Off = stages keep the configured fixed concurrency
+    #[serde(default = "AdaptiveConcurrencyConfig::default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "AdaptiveConcurrencyConfig::default_min_permits")]
+    pub min_permits: u32,
+    #[serde(default = "AdaptiveConcurrencyConfig::default_max_permits")]
+    pub max_permits: u32,
+    /// HTTP p95 above this is treated as overload
+    #[serde(default = "AdaptiveConcurrencyConfig::default_target_p95_ms")]
+    pub target_p95_ms: u64,
+    /// Share of 429/5xx responses above this is treated as throttling
+    #[serde(default = "AdaptiveConcurrencyConfig::default_max_error_rate")]
+    pub max_error_rate: f64,
+    /// Controller tick; each tick looks at the responses since the previous one
+    #[serde(default = "AdaptiveConcurrencyConfig::default_window_secs")]
+    pub window_secs: u64,
+    /// Ticks with fewer responses leave the target alone
+    #[serde(default = "AdaptiveConcurrencyConfig::default_min_samples")]
+    pub min_samples: u32,
+    /// Multiplier applied on overload (0.5 = halve)
+    #[serde(default = "AdaptiveConcurrencyConfig::default_decrease_factor")]
+    pub decrease_factor: f64,
+}
+
+impl AdaptiveConcurrencyConfig {
+    fn default_enabled() -> bool {
+        true
+    }
+
+    fn default_min_permits() -> u32 {
+        1
+    }
+
+    fn default_max_permits() -> u32 {
+        32
+    }
+
+    fn default_target_p95_ms() -> u64 {
+        3000
+    }
+
+    fn default_max_error_rate() -> f64 {
+        0.05
+    }
+
+    fn default_window_secs() -> u64 {
+        10
+    }
+
+    fn default_min_samples() -> u32 {
+        5
+    }
+
+    fn default_decrease_factor() -> f64 {
+        0.5
+    }
+}
+
+impl Default for AdaptiveConcurrencyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Self::default_enabled(),
+            min_permits: Self::default_min_permits(),
+            max_permits: Self::default_max_permits(),
+            target_p95_ms: Self::default_target_p95_ms(),
+            max_error_rate: Self::default_max_error_rate(),
+            window_secs: Self::default_window_secs(),
+            min_samples: Self::default_min_samples(),
+            decrease_factor: Self::default_decrease_factor(),
+        }
+    }
+}
+
+/// HTTP responses seen since the controller last looked
+#[derive(Debug, Default)]
+struct HttpWindow {
+    latencies_ms: Vec<u64>,
+    requests: u32,
+    throttled: u32,
+    server_errors: u32,
+}
+
+static HTTP_WINDOW: Lazy<Mutex<HttpWindow>> = Lazy::new(|| Mutex::new(HttpWindow::default()));
+
+/// Feed one HTTP round trip to the controller (`status` is None for network errors)
+pub fn record_http_outcome(elapsed: Duration, status: Option<u16>) {
+    let mut window = HTTP_WINDOW.lock().unwrap_or_else(|e| e.into_inner());
+    if window.latencies_ms.len() < MAX_WINDOW_SAMPLES {
+        window.latencies_ms.push(elapsed.as_millis() as u64);
+    }
+    window.requests += 1;
+    match status {
+        Some(429) => window.throttled += 1,
+        Some(code) if code >= 500 => window.server_errors += 1,
+        _ => {}
+    }
+}
+
+/// Summary of one controller window
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct WindowSignals {
+    pub samples: u32,
+    pub p95_ms: u64,
+    /// 429 + 5xx share of `samples`
+    pub error_rate: f64,
+}
+
+impl HttpWindow {
+    fn signals(mut self) -> WindowSignals {
+        if self.requests == 0 {
+            return WindowSignals::default();
+        }
+        self.latencies_ms.sort_unstable();
+        let rank = (self.latencies_ms.len() as f64 * 0.95).ceil() as usize;
+        let p95_ms = self
+            .latencies_ms
+            .get(rank.saturating_sub(1))
+            .copied()
+            .unwrap_or_default();
+        WindowSignals {
+            samples: self.requests,
+            p95_ms,
+            error_rate: f64::from(self.throttled + self.server_errors) / f64::from(self.requests),
+        }
+    }
+}
+
+fn take_http_signals() -> WindowSignals {
+    std::mem::take(&mut *HTTP_WINDOW.lock().unwrap_or_else(|e| e.into_inner())).signals()
+}
+
+/// Why the controller moved (or held) a target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdjustReason {
+    /// 429/5xx share over `max_error_rate`
+    ErrorRate,
+    /// p95 over `target_p95_ms`
+    Latency,
+    /// Healthy window; probe one more permit
+    Headroom,
+}
+
+/// AIMD step: multiplicative decrease on overload, +1 otherwise; None when the window is too thin
+pub fn aimd_target(
+    current: u32,
+    signals: &WindowSignals,
+    config: &AdaptiveConcurrencyConfig,
+) -> Option<(u32, AdjustReason)> {
+    if signals.samples < config.min_samples.max(1) {
+        return None;
+    }
+    let decreased = ((f64::from(current) * config.decrease_factor).floor() as u32)
+        .min(current.saturating_sub(1));
+    let (target, reason) = if signals.error_rate > config.max_error_rate {
+        (decreased, AdjustReason::ErrorRate)
+    } else if signals.p95_ms > config.target_p95_ms {
+        (decreased, AdjustReason::Latency)
+    } else {
+        (current.saturating_add(1), AdjustReason::Headroom)
+    };
+    let min = config.min_permits.max(1);
+    Some((target.clamp(min, config.max_permits.max(min)), reason))
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    target: u32,
+    /// Permits still to retire as holders release them (shrinks below the in-flight count)
+    debt: u32,
+}
+
+/// Semaphore whose permit count can be resized while permits are held
+#[derive(Debug)]
+pub struct AdaptiveLimiter {
+    semaphore: Arc<Semaphore>,
+    state: Mutex<LimiterState>,
+    min_permits: u32,
+    max_permits: u32,
+}
+
+impl AdaptiveLimiter {
+    pub fn new(initial: u32, min_permits: u32, max_permits: u32) -> Arc<Self> {
+        let min_permits = min_permits.max(1);
+        let max_permits = max_permits.max(min_permits);
+        let target = initial.clamp(min_permits, max_permits);
+        Arc::new(Self {
+            semaphore: Arc::new(Semaphore::new(target as usize)),
+            state: Mutex::new(LimiterState { target, debt: 0 }),
+            min_permits,
+            max_permits,
+        })
+    }
+
+    pub fn target(&self) -> u32 {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).target
+    }
+
+    pub async fn acquire(self: &Arc<Self>) -> Result<AdaptivePermit, AcquireError> {
+        let permit = self.semaphore.clone().acquire_owned().await?;
+        Ok(AdaptivePermit {
+            permit: Some(permit),
+            limiter: Arc::clone(self),
+        })
+    }
+
+    /// Move to `target` (clamped); returns the previous target
+    pub fn resize(&self, target: u32) -> u32 {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let target = target.clamp(self.min_permits, self.max_permits);
+        let previous = state.target;
+        if target > previous {
+            let grow = target - previous;
+            let repaid = grow.min(state.debt);
+            state.debt -= repaid;
+            self.semaphore.add_permits((grow - repaid) as usize);
+        } else if target < previous {
+            let shrink = previous - target;
+            let forgotten = self.semaphore.forget_permits(shrink as usize) as u32;
+            state.debt += shrink - forgotten;
+        }
+        state.target = target;
+        previous
+    }
+
+    fn take_debt(&self) -> bool {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.debt == 0 {
+            return false;
+        }
+        state.debt -= 1;
+        true
+    }
+}
+
+/// Permit from an `AdaptiveLimiter`; retired instead of returned while the limiter is shrinking
+#[derive(Debug)]
+pub struct AdaptivePermit {
+    permit: Option<OwnedSemaphorePermit>,
+    limiter: Arc<AdaptiveLimiter>,
+}
+
+impl Drop for AdaptivePermit {
+    fn drop(&mut self) {
+        let Some(permit) = self.permit.take() else {
+            return;
+        };
+        if self.limiter.take_debt() {
+            permit.forget();
+        }
+    }
+}
+
+/// Current adaptive target of one session stage
+#[derive(Debug, Clone, Serialize)]
+pub struct ConcurrencyTarget {
+    pub session_id: String,
+    pub stage_type: StageType,
+    pub target: u32,
+    pub min_permits: u32,
+    pub max_permits: u32,
+    /// A stage of this kind is running right now
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_signals: Option<WindowSignals>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_reason: Option<AdjustReason>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+struct ControlledStage {
+    limiter: Weak<AdaptiveLimiter>,
+    target: ConcurrencyTarget,
+}
+
+type StageKey = (String, StageType);
+
+static CONTROLLED: Lazy<Mutex<HashMap<StageKey, ControlledStage>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Limiter for one stage run; starts from the target earlier runs of the session settled on
+pub fn adaptive_limiter(
+    context: &AppContext,
+    stage_type: StageType,
+    configured: u32,
+    config: &AdaptiveConcurrencyConfig,
+) -> Arc<AdaptiveLimiter> {
+    if !config.enabled {
+        let fixed = configured.max(1);
+        return AdaptiveLimiter::new(fixed, fixed, fixed);
+    }
+    let key = (context.session_id.clone(), stage_type.clone());
+    let mut controlled = CONTROLLED.lock().unwrap_or_else(|e| e.into_inner());
+    let initial = controlled
+        .get(&key)
+        .map(|c| c.target.target)
+        .unwrap_or(configured);
+    let limiter = AdaptiveLimiter::new(initial, config.min_permits, config.max_permits);
+    controlled.insert(
+        key.clone(),
+        ControlledStage {
+            limiter: Arc::downgrade(&limiter),
+            target: ConcurrencyTarget {
+                session_id: key.0.clone(),
+                stage_type,
+                target: limiter.target(),
+                min_permits: limiter.min_permits,
+                max_permits: limiter.max_permits,
+                active: true,
+                last_signals: None,
+                last_reason: None,
+                updated_at: Utc::now(),
+            },
+        },
+    );
+    drop(controlled);
+    spawn_controller(
+        Arc::downgrade(&limiter),
+        key,
+        context.clone(),
+        config.clone(),
+    );
+    limiter
+}
+
+fn spawn_controller(
+    limiter: Weak<AdaptiveLimiter>,
+    key: StageKey,
+    context: AppContext,
+    config: AdaptiveConcurrencyConfig,
+) {
+    let name = format!("adaptive-concurrency:{}:{}", key.0, key.1.as_str());
+    spawn_tracked(name, Some(SESSION_TASK_LIFETIME), async move {
+        let mut tick = tokio::time::interval(Duration::from_secs(config.window_secs.max(1)));
+        tick.tick().await;
+        // Responses from before this stage say nothing about its load
+        take_http_signals();
+        loop {
+            tick.tick().await;
+            let Some(limiter) = limiter.upgrade() else {
+                break;
+            };
+            let signals = take_http_signals();
+            let current = limiter.target();
+            let Some((target, reason)) = aimd_target(current, &signals, &config) else {
+                continue;
+            };
+            if target != current {
+                limiter.resize(target);
+                info!(
+                    "🎚️ {} {} concurrency {} -> {} ({:?}, p95={}ms, errors={:.1}%)",
+                    key.0,
+                    key.1.as_str(),
+                    current,
+                    target,
+                    reason,
+                    signals.p95_ms,
+                    signals.error_rate * 100.0
+                );
+                let _ = context.emit_event(AppEvent::ConcurrencyAdjusted {
+                    session_id: key.0.clone(),
+                    stage_type: key.1.clone(),
+                    previous: current,
+                    target,
+                    reason,
+                    p95_ms: signals.p95_ms,
+                    error_rate: signals.error_rate,
+                    samples: signals.samples,
+                    timestamp: Utc::now(),
+                });
+            }
+            let mut controlled = CONTROLLED.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(entry) = controlled.get_mut(&key) {
+                entry.target.target = limiter.target();
+                entry.target.last_signals = Some(signals);
+                entry.target.last_reason = Some(reason);
+                entry.target.updated_at = Utc::now();
+            }
+        }
+        debug!(
+            "🎚️ Adaptive concurrency controller for {} {} done",
+            key.0,
+            key.1.as_str()
+        );
+    });
+}
+
+/// Adaptive targets of every session stage seen so far
+pub fn concurrency_targets() -> Vec<ConcurrencyTarget> {
+    let controlled = CONTROLLED.lock().unwrap_or_else(|e| e.into_inner());
+    let mut targets: Vec<ConcurrencyTarget> = controlled
+        .values()
+        .map(|c| ConcurrencyTarget {
+            active: c.limiter.strong_count() > 0,
+            ..c.target.clone()
+        })
+        .collect();
+    targets.sort_by(|a, b| {
+        (&a.session_id, a.stage_type.as_str()).cmp(&(&b.session_id, b.stage_type.as_str()))
+    });
+    targets
+}
+
+/// Forget a finished session's learned targets
+pub fn clear_concurrency_targets(session_id: &str) {
+    CONTROLLED
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .retain(|(sid, _), _| sid != session_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn aimd_shrinks_under_throttling_and_retires_held_permits() {
+        let config = AdaptiveConcurrencyConfig::default();
+        let healthy = WindowSignals {
+            samples: 20,
+            p95_ms: 800,
+            error_rate: 0.0,
+        };
+        let throttled = WindowSignals {
+            error_rate: 0.2,
+            ..healthy
+        };
+        let slow = WindowSignals {
+            p95_ms: 9000,
+            ..healthy
+        };
+        assert_eq!(
+            aimd_target(4, &healthy, &config),
+            Some((5, AdjustReason::Headroom))
+        );
+        assert_eq!(
+            aimd_target(8, &throttled, &config),
+            Some((4, AdjustReason::ErrorRate))
+        );
+        assert_eq!(
+            aimd_target(3, &slow, &config),
+            Some((1, AdjustReason::Latency))
+        );
+        assert_eq!(
+            aimd_target(1, &slow, &config),
+            Some((1, AdjustReason::Latency))
+        );
+        assert_eq!(
+            aimd_target(32, &healthy, &config),
+            Some((32, AdjustReason::Headroom))
+        );
+        let thin = WindowSignals {
+            samples: 2,
+            ..throttled
+        };
+        assert_eq!(aimd_target(8, &thin, &config), None);
+
+        // Shrink 4 -> 2 with 3 permits in flight: one is forgotten now, one on release
+        let limiter = AdaptiveLimiter::new(4, 1, 8);
+        let mut held = Vec::new();
+        for _ in 0..3 {
+            held.push(limiter.acquire().await.unwrap());
+        }
+        assert_eq!(limiter.resize(2), 4);
+        assert_eq!(limiter.semaphore.available_permits(), 0);
+        held.pop();
+        assert_eq!(limiter.semaphore.available_permits(), 0);
+        held.clear();
+        assert_eq!(limiter.semaphore.available_permits(), 2);
+
+        limiter.resize(5);
+        assert_eq!(limiter.target(), 5);
+        assert_eq!(limiter.semaphore.available_permits(), 5);
+    }
+}
@@ -166,6 +166,7 @@ impl crate::crawl_engine::actors::BatchActor {
     /// Stage 1용: 사이트 상태 점검을 수행하고 레거시 StageResult(details 포함)로 브리징
     pub async fn execute_status_check_with_details(
         &self,
+        session_id: &str,
         app_config: AppConfig,
     ) -> crate::crawl_engine::actors::types::StageResult {
         let config_arc = match self.config.as_ref() {
@@ -201,7 +202,12 @@ impl crate::crawl_engine::actors::BatchActor {
 
         match integration_service.execute_site_analysis().await {
             Ok(site_status) => {
-                let collected_data = serde_json::to_string(&site_status).ok();
+                let collected_data = crate::crawl_engine::actors::types::StagePayload::from_value(
+                    session_id,
+                    &site_status,
+                )
+                .await
+                .ok();
                 details.push(crate::crawl_engine::actors::types::StageItemResult {
                     item_id: "site_status_check:0".to_string(),
                     item_type: crate::crawl_engine::actors::types::StageItemType::SiteCheck,
@@ -381,6 +387,7 @@ impl crate::crawl_engine::actors::BatchActor {
     /// Stage 3용: ProductUrls 입력을 받아 ProductDetails를 수집하고 레거시 StageResult(details 포함)로 브리징
     pub async fn execute_detail_collection_with_details(
         &self,
+        session_id: &str,
         product_urls_items: Vec<crate::crawl_engine::channels::types::ProductUrls>,
         app_config: AppConfig,
     ) -> crate::crawl_engine::actors::types::StageResult {
@@ -458,10 +465,11 @@ impl crate::crawl_engine::actors::BatchActor {
                                     empty_responses: 0,
                                 },
                         };
-                        match serde_json::to_string(&wrapper) {
-                            Ok(json) => Some(json),
-                            Err(_) => None,
-                        }
+                        crate::crawl_engine::actors::types::StagePayload::from_value(
+                            session_id, &wrapper,
+                        )
+                        .await
+                        .ok()
                     } else {
                         None
                     };
@@ -512,6 +520,7 @@ impl crate::crawl_engine::actors::BatchActor {
     /// Stage 2용: 페이지별 상세 URL 결과를 수집하여 레거시 StageResult(details 포함)로 브리징
     pub async fn execute_list_collection_with_details(
         &self,
+        session_id: &str,
         pages: Vec<u32>,
         app_config: AppConfig,
     ) -> crate::crawl_engine::actors::types::StageResult {
@@ -576,10 +585,9 @@ impl crate::crawl_engine::actors::BatchActor {
                 failed += 1;
             }
             let collected_data = if success {
-                match serde_json::to_string(&urls) {
-                    Ok(json) => Some(json),
-                    Err(_) => None,
-                }
+                crate::crawl_engine::actors::types::StagePayload::from_value(session_id, &urls)
+                    .await
+                    .ok()
             } else {
                 None
             };
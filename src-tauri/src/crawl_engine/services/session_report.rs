@@ -0,0 +1,661 @@
+//! 세션 종료 시 감사용 크롤 리포트 생성
+//!
+//! 세션 이벤트 채널을 구독해 StageCompleted의 `StageItemResult`, 재시도, 배치/스테이지 소요
+//! 시간을 집계하고, 세션 시작/종료 시점의 DB 행 수 차이를 더해 JSON + Markdown 리포트를
+//! `<app_data>/reports/`에 저장한다. 저장이 끝나면 `SessionReportReady` 이벤트로 경로를 알린다.
+
+use crate::crawl_engine::actors::types::{AppEvent, SessionSummary};
+use crate::crawl_engine::runtime::task_registry::{SESSION_TASK_LIFETIME, spawn_tracked};
+use crate::crawl_engine::stage_type::StageType;
+use crate::infrastructure::config::ConfigManager;
+use crate::services::session_notes::{self, SessionNote};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+
+/// Failed items listed individually in a report (the rest are only counted)
+const MAX_FAILED_ITEMS: usize = 200;
+
+/// Per-stage aggregation of StageCompleted results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageReport {
+    pub stage_type: StageType,
+    /// StageCompleted events seen (one per batch that ran the stage)
+    pub runs: u32,
+    /// StageFailed events seen
+    pub failed_runs: u32,
+    pub processed_items: u32,
+    pub successful_items: u32,
+    pub failed_items: u32,
+    pub total_duration_ms: u64,
+    /// Items with a `StageItemResult` (basis for the per-item timings)
+    pub measured_items: u32,
+    pub item_duration_sum_ms: u64,
+    pub avg_item_duration_ms: u64,
+    pub max_item_duration_ms: u64,
+    pub item_retries: u32,
+}
+
+impl StageReport {
+    fn new(stage_type: StageType) -> Self {
+        Self {
+            stage_type,
+            runs: 0,
+            failed_runs: 0,
+            processed_items: 0,
+            successful_items: 0,
+            failed_items: 0,
+            total_duration_ms: 0,
+            measured_items: 0,
+            item_duration_sum_ms: 0,
+            avg_item_duration_ms: 0,
+            max_item_duration_ms: 0,
+            item_retries: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RetryTotals {
+    /// StageRetrying events
+    pub stage_retry_events: u32,
+    /// Sum of `StageItemResult::retry_count`
+    pub item_retries: u32,
+    /// Items that needed at least one retry
+    pub items_retried: u32,
+    pub max_item_retries: u32,
+    /// Sum of `BatchReport::retries_used`
+    pub batch_retries_used: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchTotals {
+    pub completed: u32,
+    pub failed: u32,
+    pub total_duration_ms: u64,
+    pub max_duration_ms: u64,
+    pub products_inserted: u32,
+    pub products_updated: u32,
+    pub duplicates_skipped: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DbCounts {
+    pub products: i64,
+    pub product_details: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DbDelta {
+    pub before: DbCounts,
+    pub after: DbCounts,
+    pub products: i64,
+    pub product_details: i64,
+}
+
+impl DbDelta {
+    fn between(before: DbCounts, after: DbCounts) -> Self {
+        Self {
+            before,
+            after,
+            products: after.products - before.products,
+            product_details: after.product_details - before.product_details,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedItem {
+    pub stage_type: StageType,
+    pub batch_id: Option<String>,
+    pub item_id: String,
+    pub error: Option<String>,
+    pub retry_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionReport {
+    pub session_id: String,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    pub final_state: String,
+    pub stages: Vec<StageReport>,
+    pub batches: BatchTotals,
+    pub retries: RetryTotals,
+    /// None when the database was unavailable at session start or end
+    pub db_delta: Option<DbDelta>,
+    pub failed_items: Vec<FailedItem>,
+    /// Failed items beyond `MAX_FAILED_ITEMS` that are only counted
+    pub failed_items_omitted: u32,
+    /// Events missed because the report task lagged behind the channel
+    pub events_lagged: u64,
+    /// Operator notes (`annotate_session`), oldest first
+    #[serde(default)]
+    pub notes: Vec<SessionNote>,
+    pub summary: SessionSummary,
+}
+
+/// Folds one session's events into a `SessionReport`
+#[derive(Debug)]
+pub struct SessionReportCollector {
+    session_id: String,
+    started_at: DateTime<Utc>,
+    stages: Vec<StageReport>,
+    batches: BatchTotals,
+    retries: RetryTotals,
+    failed_items: Vec<FailedItem>,
+    failed_items_omitted: u32,
+    events_lagged: u64,
+    db_before: Option<DbCounts>,
+}
+
+impl SessionReportCollector {
+    pub fn new(session_id: impl Into<String>, db_before: Option<DbCounts>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            started_at: Utc::now(),
+            stages: Vec::new(),
+            batches: BatchTotals::default(),
+            retries: RetryTotals::default(),
+            failed_items: Vec::new(),
+            failed_items_omitted: 0,
+            events_lagged: 0,
+            db_before,
+        }
+    }
+
+    fn stage_mut(&mut self, stage_type: &StageType) -> &mut StageReport {
+        match self.stages.iter().position(|s| &s.stage_type == stage_type) {
+            Some(i) => &mut self.stages[i],
+            None => {
+                self.stages.push(StageReport::new(stage_type.clone()));
+                self.stages.last_mut().expect("just pushed")
+            }
+        }
+    }
+
+    /// Fold one event; events of other sessions are ignored
+    pub fn observe(&mut self, event: &AppEvent) {
+        match event {
+            AppEvent::StageCompleted {
+                stage_type,
+                session_id,
+                batch_id,
+                result,
+                ..
+            } if *session_id == self.session_id => {
+                let stage = self.stage_mut(stage_type);
+                stage.runs += 1;
+                stage.processed_items += result.processed_items;
+                stage.successful_items += result.successful_items;
+                stage.failed_items += result.failed_items;
+                stage.total_duration_ms += result.duration_ms;
+                let mut failures = Vec::new();
+                for item in &result.details {
+                    stage.measured_items += 1;
+                    stage.item_duration_sum_ms += item.duration_ms;
+                    stage.max_item_duration_ms = stage.max_item_duration_ms.max(item.duration_ms);
+                    stage.item_retries += item.retry_count;
+                    if !item.success {
+                        failures.push(FailedItem {
+                            stage_type: stage_type.clone(),
+                            batch_id: batch_id.clone(),
+                            item_id: item.item_id.clone(),
+                            error: item.error.clone(),
+                            retry_count: item.retry_count,
+                        });
+                    }
+                }
+                for item in &result.details {
+                    self.retries.item_retries += item.retry_count;
+                    if item.retry_count > 0 {
+                        self.retries.items_retried += 1;
+                    }
+                    self.retries.max_item_retries =
+                        self.retries.max_item_retries.max(item.retry_count);
+                }
+                for failure in failures {
+                    if self.failed_items.len() < MAX_FAILED_ITEMS {
+                        self.failed_items.push(failure);
+                    } else {
+                        self.failed_items_omitted += 1;
+                    }
+                }
+            }
+            AppEvent::StageFailed {
+                stage_type,
+                session_id,
+                ..
+            } if *session_id == self.session_id => {
+                self.stage_mut(stage_type).failed_runs += 1;
+            }
+            AppEvent::StageRetrying { session_id, .. } if *session_id == self.session_id => {
+                self.retries.stage_retry_events += 1;
+            }
+            AppEvent::BatchCompleted {
+                session_id,
+                duration,
+                ..
+            } if *session_id == self.session_id => {
+                self.batches.completed += 1;
+                self.batches.total_duration_ms += duration;
+                self.batches.max_duration_ms = self.batches.max_duration_ms.max(*duration);
+            }
+            AppEvent::BatchFailed { session_id, .. } if *session_id == self.session_id => {
+                self.batches.failed += 1;
+            }
+            AppEvent::BatchReport {
+                session_id,
+                retries_used,
+                duplicates_skipped,
+                products_inserted,
+                products_updated,
+                ..
+            } if *session_id == self.session_id => {
+                self.retries.batch_retries_used += retries_used;
+                self.batches.duplicates_skipped += duplicates_skipped;
+                self.batches.products_inserted += products_inserted;
+                self.batches.products_updated += products_updated;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn record_lag(&mut self, missed: u64) {
+        self.events_lagged += missed;
+    }
+
+    pub fn finish(mut self, summary: &SessionSummary, db_after: Option<DbCounts>) -> SessionReport {
+        let completed_at = Utc::now();
+        for stage in &mut self.stages {
+            if stage.measured_items > 0 {
+                stage.avg_item_duration_ms =
+                    stage.item_duration_sum_ms / u64::from(stage.measured_items);
+            }
+        }
+        SessionReport {
+            duration_ms: if summary.total_duration_ms > 0 {
+                summary.total_duration_ms
+            } else {
+                (completed_at - self.started_at).num_milliseconds().max(0) as u64
+            },
+            session_id: self.session_id,
+            started_at: self.started_at,
+            completed_at,
+            final_state: summary.final_state.clone(),
+            stages: self.stages,
+            batches: self.batches,
+            retries: self.retries,
+            db_delta: self
+                .db_before
+                .zip(db_after)
+                .map(|(before, after)| DbDelta::between(before, after)),
+            failed_items: self.failed_items,
+            failed_items_omitted: self.failed_items_omitted,
+            events_lagged: self.events_lagged,
+            notes: Vec::new(),
+            summary: summary.clone(),
+        }
+    }
+}
+
+/// Row counts used for the report's DB delta
+pub async fn db_counts(pool: &SqlitePool) -> Result<DbCounts> {
+    let products: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM products")
+        .fetch_one(pool)
+        .await?;
+    let product_details: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM product_details")
+        .fetch_one(pool)
+        .await?;
+    Ok(DbCounts {
+        products,
+        product_details,
+    })
+}
+
+/// Human-readable rendering of a report
+pub fn render_markdown(report: &SessionReport) -> String {
+    let mut md = String::new();
+    let _ = writeln!(md, "# Crawl Session Report `{}`\n", report.session_id);
+    let _ = writeln!(md, "| | |\n|---|---|");
+    let _ = writeln!(md, "| Final state | {} |", report.final_state);
+    let _ = writeln!(md, "| Started | {} |", report.started_at.to_rfc3339());
+    let _ = writeln!(md, "| Completed | {} |", report.completed_at.to_rfc3339());
+    let _ = writeln!(md, "| Duration | {} ms |", report.duration_ms);
+    let _ = writeln!(
+        md,
+        "| Batches | {} completed / {} failed |",
+        report.batches.completed, report.batches.failed
+    );
+    let _ = writeln!(
+        md,
+        "| Products | {} inserted / {} updated / {} duplicates skipped |",
+        report.batches.products_inserted,
+        report.batches.products_updated,
+        report.batches.duplicates_skipped
+    );
+
+    let _ = writeln!(md, "\n## Stages\n");
+    let _ = writeln!(
+        md,
+        "| Stage | Runs | Failed runs | Items | Success | Failed | Retries | Duration (ms) | Avg item (ms) | Max item (ms) |"
+    );
+    let _ = writeln!(md, "|---|---|---|---|---|---|---|---|---|---|");
+    for s in &report.stages {
+        let _ = writeln!(
+            md,
+            "| {} | {} | {} | {} | {} | {} | {} | {} | {} | {} |",
+            s.stage_type.as_str(),
+            s.runs,
+            s.failed_runs,
+            s.processed_items,
+            s.successful_items,
+            s.failed_items,
+            s.item_retries,
+            s.total_duration_ms,
+            s.avg_item_duration_ms,
+            s.max_item_duration_ms
+        );
+    }
+
+    let r = &report.retries;
+    let _ = writeln!(md, "\n## Retries\n");
+    let _ = writeln!(
+        md,
+        "- stage retry events: {}\n- item retries: {} across {} items (max {} on one item)\n- batch retries used: {}",
+        r.stage_retry_events,
+        r.item_retries,
+        r.items_retried,
+        r.max_item_retries,
+        r.batch_retries_used
+    );
+
+    let _ = writeln!(md, "\n## Database delta\n");
+    match &report.db_delta {
+        Some(d) => {
+            let _ = writeln!(md, "| Table | Before | After | Delta |\n|---|---|---|---|");
+            let _ = writeln!(
+                md,
+                "| products | {} | {} | {:+} |",
+                d.before.products, d.after.products, d.products
+            );
+            let _ = writeln!(
+                md,
+                "| product_details | {} | {} | {:+} |",
+                d.before.product_details, d.after.product_details, d.product_details
+            );
+        }
+        None => {
+            let _ = writeln!(md, "_database unavailable; no delta recorded_");
+        }
+    }
+
+    if !report.failed_items.is_empty() {
+        let _ = writeln!(md, "\n## Failed items\n");
+        let _ = writeln!(
+            md,
+            "| Stage | Batch | Item | Retries | Error |\n|---|---|---|---|---|"
+        );
+        for f in &report.failed_items {
+            let _ = writeln!(
+                md,
+                "| {} | {} | {} | {} | {} |",
+                f.stage_type.as_str(),
+                f.batch_id.as_deref().unwrap_or("-"),
+                f.item_id,
+                f.retry_count,
+                f.error.as_deref().unwrap_or("-").replace('|', "\\|")
+            );
+        }
+        if report.failed_items_omitted > 0 {
+            let _ = writeln!(md, "\n… and {} more", report.failed_items_omitted);
+        }
+    }
+    if !report.notes.is_empty() {
+        let _ = writeln!(md, "\n## Operator notes\n");
+        for n in &report.notes {
+            let scope = n
+                .batch_id
+                .as_deref()
+                .map(|b| format!(" (batch `{b}`)"))
+                .unwrap_or_default();
+            let _ = writeln!(md, "- {}{}: {}", n.created_at, scope, n.note);
+        }
+    }
+    if report.events_lagged > 0 {
+        let _ = writeln!(
+            md,
+            "\n> ⚠️ {} events were missed while building this report; totals may be low.",
+            report.events_lagged
+        );
+    }
+    md
+}
+
+/// Write `session_<id>.json` and `session_<id>.md` into `dir`
+pub async fn write_report(dir: &Path, report: &SessionReport) -> Result<(PathBuf, PathBuf)> {
+    tokio::fs::create_dir_all(dir).await?;
+    let stem = format!("session_{}", report.session_id);
+    let json_path = dir.join(format!("{stem}.json"));
+    let markdown_path = dir.join(format!("{stem}.md"));
+    tokio::fs::write(&json_path, serde_json::to_vec_pretty(report)?).await?;
+    tokio::fs::write(&markdown_path, render_markdown(report)).await?;
+    Ok((json_path, markdown_path))
+}
+
+/// Directory session reports are written to
+pub fn reports_dir() -> Result<PathBuf> {
+    Ok(ConfigManager::get_app_data_dir()?.join("reports"))
+}
+
+/// Rewrite an already written report with `notes`; false when the session has no report yet
+pub async fn refresh_report_notes(
+    dir: &Path,
+    session_id: &str,
+    notes: Vec<SessionNote>,
+) -> Result<bool> {
+    let json_path = dir.join(format!("session_{session_id}.json"));
+    let raw = match tokio::fs::read(&json_path).await {
+        Ok(raw) => raw,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e.into()),
+    };
+    let mut report: SessionReport = serde_json::from_slice(&raw)?;
+    report.notes = notes;
+    write_report(dir, &report).await?;
+    Ok(true)
+}
+
+/// Subscribe to a session's event channel and write its report once `SessionCompleted`
+/// arrives, then announce it with `SessionReportReady` on the same channel.
+pub fn spawn_session_report_task(session_id: String, event_tx: broadcast::Sender<AppEvent>) {
+    let mut rx = event_tx.subscribe();
+    let task_name = format!("session-report:{}", session_id);
+    spawn_tracked(task_name, Some(SESSION_TASK_LIFETIME), async move {
+        let pool = crate::infrastructure::database_connection::get_or_init_global_pool()
+            .await
+            .ok();
+        let db_before = match &pool {
+            Some(pool) => db_counts(pool).await.ok(),
+            None => None,
+        };
+        let mut collector = SessionReportCollector::new(session_id.clone(), db_before);
+        let summary = loop {
+            match rx.recv().await {
+                Ok(AppEvent::SessionCompleted {
+                    session_id: sid,
+                    summary,
+                    ..
+                }) if sid == session_id => break summary,
+                Ok(event) => collector.observe(&event),
+                Err(broadcast::error::RecvError::Lagged(missed)) => {
+                    warn!(
+                        "📝 Session report for {} lagged by {} events",
+                        session_id, missed
+                    );
+                    collector.record_lag(missed);
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    warn!(
+                        "📝 Event channel closed before session {} completed; no report",
+                        session_id
+                    );
+                    return;
+                }
+            }
+        };
+
+        let db_after = match &pool {
+            Some(pool) => db_counts(pool).await.ok(),
+            None => None,
+        };
+        let mut report = collector.finish(&summary, db_after);
+        if let Some(pool) = &pool {
+            report.notes = session_notes::list_notes(pool, &report.session_id)
+                .await
+                .unwrap_or_default();
+        }
+        let dir = match reports_dir() {
+            Ok(dir) => dir,
+            Err(e) => {
+                warn!("📝 Session report skipped: app data dir unavailable: {}", e);
+                return;
+            }
+        };
+        match write_report(&dir, &report).await {
+            Ok((json_path, markdown_path)) => {
+                info!(
+                    "📝 Session report written: {} ({})",
+                    json_path.display(),
+                    markdown_path.display()
+                );
+                let _ = event_tx.send(AppEvent::SessionReportReady {
+                    session_id,
+                    json_path: json_path.display().to_string(),
+                    markdown_path: markdown_path.display().to_string(),
+                    timestamp: Utc::now(),
+                });
+            }
+            Err(e) => warn!(
+                "📝 Failed to write session report for {}: {}",
+                session_id, e
+            ),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crawl_engine::actors::types::{StageItemResult, StageItemType, StageResult};
+
+    fn item(id: &str, success: bool, duration_ms: u64, retry_count: u32) -> StageItemResult {
+        StageItemResult {
+            item_id: id.to_string(),
+            item_type: StageItemType::Url {
+                url_type: "product_detail".to_string(),
+            },
+            success,
+            error: (!success).then(|| "timeout".to_string()),
+            duration_ms,
+            retry_count,
+            collected_data: None,
+        }
+    }
+
+    fn stage_completed(session_id: &str, details: Vec<StageItemResult>) -> AppEvent {
+        AppEvent::StageCompleted {
+            stage_type: StageType::ProductDetailCrawling,
+            session_id: session_id.to_string(),
+            batch_id: Some("b1".to_string()),
+            result: StageResult {
+                processed_items: details.len() as u32,
+                successful_items: details.iter().filter(|d| d.success).count() as u32,
+                failed_items: details.iter().filter(|d| !d.success).count() as u32,
+                duration_ms: details.iter().map(|d| d.duration_ms).sum(),
+                details,
+            },
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn summary(session_id: &str) -> SessionSummary {
+        serde_json::from_value(serde_json::json!({
+            "session_id": session_id,
+            "total_duration_ms": 1200,
+            "total_pages_processed": 2,
+            "total_products_processed": 3,
+            "success_rate": 1.0,
+            "avg_page_processing_time": 600,
+            "error_summary": [],
+            "processed_batches": 1,
+            "total_success_count": 2,
+            "final_state": "Completed",
+            "timestamp": Utc::now(),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn aggregates_items_retries_and_db_delta_for_own_session_only() {
+        let before = DbCounts {
+            products: 10,
+            product_details: 8,
+        };
+        let mut collector = SessionReportCollector::new("s1", Some(before));
+        collector.observe(&stage_completed(
+            "s1",
+            vec![
+                item("a", true, 100, 0),
+                item("b", false, 300, 2),
+                item("c", true, 200, 1),
+            ],
+        ));
+        collector.observe(&stage_completed("other", vec![item("x", false, 1, 5)]));
+        collector.observe(&AppEvent::BatchReport {
+            session_id: "s1".to_string(),
+            batch_id: "b1".to_string(),
+            pages_total: 2,
+            pages_success: 2,
+            pages_failed: 0,
+            list_pages_failed: vec![],
+            details_success: 2,
+            details_failed: 1,
+            retries_used: 3,
+            duration_ms: 900,
+            duplicates_skipped: 1,
+            products_inserted: 2,
+            products_updated: 0,
+            timestamp: Utc::now(),
+        });
+
+        let after = DbCounts {
+            products: 12,
+            product_details: 10,
+        };
+        let report = collector.finish(&summary("s1"), Some(after));
+        assert_eq!(report.stages.len(), 1);
+        let stage = &report.stages[0];
+        assert_eq!((stage.processed_items, stage.failed_items), (3, 1));
+        assert_eq!((stage.item_retries, stage.max_item_duration_ms), (3, 300));
+        assert_eq!(stage.avg_item_duration_ms, 200);
+        assert_eq!(report.retries.items_retried, 2);
+        assert_eq!(report.retries.batch_retries_used, 3);
+        assert_eq!(report.failed_items.len(), 1);
+        assert_eq!(report.failed_items[0].item_id, "b");
+        let delta = report.db_delta.as_ref().unwrap();
+        assert_eq!((delta.products, delta.product_details), (2, 2));
+        assert_eq!(report.duration_ms, 1200);
+
+        let md = render_markdown(&report);
+        assert!(md.contains("| product_detail_crawling | 1 | 0 | 3 | 2 | 1 | 3 |"));
+        assert!(md.contains("| products | 10 | 12 | +2 |"));
+        assert!(!md.contains("Operator notes"));
+    }
+}
@@ -0,0 +1,231 @@
+//! 채널/Actor 공용 스테이지 타입
+//!
+//! 과거 `channels::types::StageType`(ListCollection/DetailCollection/DatabaseSave)과
+//! `actors::types::StageType`이 별도로 존재했으나 하나로 통합했다. 직렬화 이름은 Actor 쪽
+//! 이름을 유지하고, 이전 채널 이름과 `as_str()` 표기는 역직렬화 별칭으로 받아들인다.
+//!
+//! `BATCH_PIPELINE`은 BatchActor가 배치마다 실행하는 스테이지 순서이고, `StageSkipFlag`는 고급 사용자가
+//! 안전성 대신 속도를 택할 때 그 중 일부를 건너뛰는 플래그다. 플래그 조합은 `StageSelection`이
+//! 파이프라인 정의에 비추어 검증한다.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// 스테이지 타입
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub enum StageType {
+    /// 상태 확인
+    #[serde(alias = "status_check")]
+    StatusCheck,
+
+    /// 리스트 페이지 크롤링
+    #[serde(alias = "ListCollection", alias = "list_page_crawling")]
+    ListPageCrawling,
+
+    /// 상품 상세 크롤링
+    #[serde(alias = "DetailCollection", alias = "product_detail_crawling")]
+    ProductDetailCrawling,
+
+    /// 데이터 검증
+    #[serde(alias = "data_validation")]
+    DataValidation,
+
+    /// 데이터 저장
+    #[serde(alias = "DatabaseSave", alias = "data_saving")]
+    DataSaving,
+}
+
+impl StageType {
+    /// StageType을 문자열로 변환
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StageType::StatusCheck => "status_check",
+            StageType::ListPageCrawling => "list_page_crawling",
+            StageType::ProductDetailCrawling => "product_detail_crawling",
+            StageType::DataValidation => "data_validation",
+            StageType::DataSaving => "data_saving",
+        }
+    }
+}
+
+/// Stages a batch runs, in order
+pub const BATCH_PIPELINE: [StageType; 5] = [
+    StageType::StatusCheck,
+    StageType::ListPageCrawling,
+    StageType::ProductDetailCrawling,
+    StageType::DataValidation,
+    StageType::DataSaving,
+];
+
+/// Stages no flag may remove: nothing is collected or stored without them
+const REQUIRED_STAGES: [StageType; 2] = [StageType::ListPageCrawling, StageType::DataSaving];
+
+/// Batch stage skip flag (`start_unified_crawling` 고급 옵션)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum StageSkipFlag {
+    /// Save details without the DataValidation quality pass
+    SkipValidation,
+    /// Plan from the cached site meta instead of a fresh status check
+    SkipStatusCheck,
+    /// Save list entries (URL + page coordinates) only; no detail pages
+    ListOnly,
+}
+
+impl StageSkipFlag {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StageSkipFlag::SkipValidation => "skip_validation",
+            StageSkipFlag::SkipStatusCheck => "skip_status_check",
+            StageSkipFlag::ListOnly => "list_only",
+        }
+    }
+
+    /// Pipeline stages this flag removes
+    pub fn skipped_stages(self) -> &'static [StageType] {
+        match self {
+            StageSkipFlag::SkipValidation => &[StageType::DataValidation],
+            StageSkipFlag::SkipStatusCheck => &[StageType::StatusCheck],
+            StageSkipFlag::ListOnly => {
+                &[StageType::ProductDetailCrawling, StageType::DataValidation]
+            }
+        }
+    }
+}
+
+/// `BATCH_PIPELINE` minus the stages removed by a validated flag set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StageSelection {
+    flags: Vec<StageSkipFlag>,
+    stages: Vec<StageType>,
+}
+
+impl Default for StageSelection {
+    fn default() -> Self {
+        Self {
+            flags: Vec::new(),
+            stages: BATCH_PIPELINE.to_vec(),
+        }
+    }
+}
+
+impl StageSelection {
+    /// Resolve `flags` against the pipeline. Rejects a flag whose stages another flag already
+    /// removes (e.g. `skip_validation` with `list_only`) and any set that drops a required stage.
+    pub fn from_flags(flags: &[StageSkipFlag]) -> Result<Self, String> {
+        let mut unique: Vec<StageSkipFlag> = Vec::with_capacity(flags.len());
+        for flag in flags {
+            if !unique.contains(flag) {
+                unique.push(*flag);
+            }
+        }
+        for flag in &unique {
+            let covered_by = unique.iter().filter(|other| *other != flag).find(|other| {
+                flag.skipped_stages()
+                    .iter()
+                    .all(|stage| other.skipped_stages().contains(stage))
+            });
+            if let Some(other) = covered_by {
+                return Err(format!(
+                    "{} has no effect: {} already skips {}",
+                    flag.as_str(),
+                    other.as_str(),
+                    flag.skipped_stages()
+                        .iter()
+                        .map(StageType::as_str)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+        }
+        let stages: Vec<StageType> = BATCH_PIPELINE
+            .iter()
+            .filter(|stage| !unique.iter().any(|f| f.skipped_stages().contains(*stage)))
+            .cloned()
+            .collect();
+        if let Some(missing) = REQUIRED_STAGES.iter().find(|s| !stages.contains(*s)) {
+            return Err(format!(
+                "stage {} is required and cannot be skipped",
+                missing.as_str()
+            ));
+        }
+        Ok(Self {
+            flags: unique,
+            stages,
+        })
+    }
+
+    pub fn runs(&self, stage: &StageType) -> bool {
+        self.stages.contains(stage)
+    }
+
+    pub fn flags(&self) -> &[StageSkipFlag] {
+        &self.flags
+    }
+
+    pub fn has(&self, flag: StageSkipFlag) -> bool {
+        self.flags.contains(&flag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skip_flags_are_validated_against_the_pipeline() {
+        let full = StageSelection::from_flags(&[]).unwrap();
+        assert_eq!(full, StageSelection::default());
+        assert!(BATCH_PIPELINE.iter().all(|s| full.runs(s)));
+
+        let list_only =
+            StageSelection::from_flags(&[StageSkipFlag::ListOnly, StageSkipFlag::SkipStatusCheck])
+                .unwrap();
+        assert!(!list_only.runs(&StageType::ProductDetailCrawling));
+        assert!(!list_only.runs(&StageType::DataValidation));
+        assert!(list_only.runs(&StageType::DataSaving));
+
+        // Order does not matter: skip_validation is redundant next to list_only
+        for flags in [
+            [StageSkipFlag::ListOnly, StageSkipFlag::SkipValidation],
+            [StageSkipFlag::SkipValidation, StageSkipFlag::ListOnly],
+        ] {
+            let err = StageSelection::from_flags(&flags).unwrap_err();
+            assert!(err.starts_with("skip_validation has no effect"), "{err}");
+        }
+        // Repeating a flag is harmless
+        let twice = StageSelection::from_flags(&[
+            StageSkipFlag::SkipValidation,
+            StageSkipFlag::SkipValidation,
+        ])
+        .unwrap();
+        assert_eq!(twice.flags(), &[StageSkipFlag::SkipValidation]);
+
+        assert!(serde_json::from_str::<Vec<StageSkipFlag>>("[\"skip_saving\"]").is_err());
+    }
+
+    #[test]
+    fn legacy_channel_names_deserialize_to_unified_variants() {
+        let cases = [
+            ("\"ListCollection\"", StageType::ListPageCrawling),
+            ("\"DetailCollection\"", StageType::ProductDetailCrawling),
+            ("\"DatabaseSave\"", StageType::DataSaving),
+            ("\"data_validation\"", StageType::DataValidation),
+            ("\"StatusCheck\"", StageType::StatusCheck),
+        ];
+        for (json, expected) in cases {
+            let parsed: StageType = serde_json::from_str(json).unwrap();
+            assert_eq!(parsed, expected, "{json}");
+        }
+    }
+
+    #[test]
+    fn serializes_with_canonical_names() {
+        assert_eq!(
+            serde_json::to_string(&StageType::DataSaving).unwrap(),
+            "\"DataSaving\""
+        );
+    }
+}
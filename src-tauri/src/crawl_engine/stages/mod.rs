@@ -5,6 +5,7 @@ pub mod strategies {
     pub mod default;
 }
 
+use crate::crawl_engine::stage_type::StageType;
 use std::sync::Arc;
 use traits::{StageLogic, StageLogicFactory};
 
@@ -12,30 +13,17 @@ use traits::{StageLogic, StageLogicFactory};
 pub struct DefaultStageLogicFactory;
 
 impl StageLogicFactory for DefaultStageLogicFactory {
-    fn logic_for(
-        &self,
-        stage_type: &crate::crawl_engine::actors::types::StageType,
-    ) -> Option<Arc<dyn StageLogic>> {
+    fn logic_for(&self, stage_type: &StageType) -> Option<Arc<dyn StageLogic>> {
         use crate::crawl_engine::stages::strategies::default::{
             DataSavingLogic, DataValidationLogic, ListPageLogic, ProductDetailLogic,
             StatusCheckLogic,
         };
         match stage_type {
-            crate::crawl_engine::actors::types::StageType::StatusCheck => {
-                Some(Arc::new(StatusCheckLogic))
-            }
-            crate::crawl_engine::actors::types::StageType::ListPageCrawling => {
-                Some(Arc::new(ListPageLogic))
-            }
-            crate::crawl_engine::actors::types::StageType::ProductDetailCrawling => {
-                Some(Arc::new(ProductDetailLogic))
-            }
-            crate::crawl_engine::actors::types::StageType::DataValidation => {
-                Some(Arc::new(DataValidationLogic))
-            }
-            crate::crawl_engine::actors::types::StageType::DataSaving => {
-                Some(Arc::new(DataSavingLogic))
-            }
+            StageType::StatusCheck => Some(Arc::new(StatusCheckLogic)),
+            StageType::ListPageCrawling => Some(Arc::new(ListPageLogic)),
+            StageType::ProductDetailCrawling => Some(Arc::new(ProductDetailLogic)),
+            StageType::DataValidation => Some(Arc::new(DataValidationLogic)),
+            StageType::DataSaving => Some(Arc::new(DataSavingLogic)),
         }
     }
 }
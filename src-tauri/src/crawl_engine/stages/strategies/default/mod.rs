@@ -1,11 +1,15 @@
 // Default strategy implementations for each Stage
 
-use crate::crawl_engine::actors::types::{StageItemType, StageType as ActorStageType};
+use crate::crawl_engine::actors::types::{StageItemType, StagePayload};
+use crate::crawl_engine::runtime::site_hints;
+use crate::crawl_engine::stage_type::StageType;
 use crate::crawl_engine::stages::traits::{StageInput, StageLogic, StageLogicError, StageOutput};
+use crate::domain::error_catalog::ErrorCode;
 use std::sync::Arc;
 // Bring trait methods into scope for collector impls
 use crate::domain::services::StatusChecker;
 use crate::domain::services::crawling_services::{ProductDetailCollector, ProductListCollector};
+use crate::infrastructure::config::ListCollectionStrategy;
 
 pub struct ListPageLogic;
 pub struct StatusCheckLogic;
@@ -21,69 +25,102 @@ impl StageLogic for ListPageLogic {
     async fn execute(&self, input: StageInput) -> Result<StageOutput, StageLogicError> {
         let start = std::time::Instant::now();
         let st = input.stage_type.clone();
-        if !matches!(st, ActorStageType::ListPageCrawling) {
+        if !matches!(st, StageType::ListPageCrawling) {
             return Err(StageLogicError::Unsupported(st));
         }
         let page_number = match &input.item {
             crate::crawl_engine::channels::types::StageItem::Page(p) => *p,
             other => {
-                return Err(StageLogicError::Internal(format!(
+                return Err(StageLogicError::coded(ErrorCode::UnexpectedStageItem, format!(
                     "ListPageLogic received unexpected item: {:?}",
                     other
                 )));
             }
         };
 
-        let cfg = crate::infrastructure::crawling_service_impls::CollectorConfig {
-            max_concurrent: input.config.user.crawling.workers.list_page_max_concurrent as u32,
-            concurrency: input.config.user.crawling.workers.list_page_max_concurrent as u32,
-            delay_between_requests: std::time::Duration::from_millis(
-                input.config.user.request_delay_ms,
+        // 목록 수집 전략: 기본은 페이지네이션 스크래핑, sitemap 은 레이아웃 변경에 대한 대안
+        let collector: Box<dyn ProductListCollector> = match input
+            .config
+            .advanced
+            .list_collection
+            .strategy
+        {
+            ListCollectionStrategy::Sitemap => Box::new(
+                crate::infrastructure::crawling_service_impls::SitemapListCollector::from_config(
+                    Arc::clone(&input.deps.http),
+                    &input.config.advanced.list_collection,
+                ),
             ),
-            delay_ms: input.config.user.request_delay_ms,
-            batch_size: input.config.user.batch.batch_size,
-            retry_attempts: input.config.user.crawling.workers.max_retries,
-            retry_max: input.config.user.crawling.workers.max_retries,
-        };
-        let collector =
-            crate::infrastructure::crawling_service_impls::ProductListCollectorImpl::new(
-                Arc::clone(&input.deps.http),
-                Arc::clone(&input.deps.extractor),
-                cfg,
-                // Collector still needs a StatusChecker for edge cases (e.g., Retry-After);
-                // reuse a lightweight checker instance but do NOT call site-level status here.
-                Arc::new(
-                    crate::infrastructure::crawling_service_impls::StatusCheckerImpl::with_product_repo(
-                        (*input.deps.http).clone(),
-                        (*input.deps.extractor).clone(),
-                        input.config.clone(),
-                        Arc::clone(&input.deps.repo),
+            ListCollectionStrategy::Pagination => {
+                let cfg = crate::infrastructure::crawling_service_impls::CollectorConfig {
+                    max_concurrent: input.config.user.crawling.workers.list_page_max_concurrent as u32,
+                    concurrency: input.config.user.crawling.workers.list_page_max_concurrent as u32,
+                    delay_between_requests: std::time::Duration::from_millis(
+                        input.config.user.request_delay_ms,
                     ),
-                ),
-            );
+                    delay_ms: input.config.user.request_delay_ms,
+                    batch_size: input.config.user.batch.batch_size,
+                    retry_attempts: input.config.user.crawling.workers.max_retries,
+                    retry_max: input.config.user.crawling.workers.max_retries,
+                };
+                Box::new(
+                    crate::infrastructure::crawling_service_impls::ProductListCollectorImpl::new(
+                        Arc::clone(&input.deps.http),
+                        Arc::clone(&input.deps.extractor),
+                        cfg,
+                        // Collector still needs a StatusChecker for edge cases (e.g., Retry-After);
+                        // reuse a lightweight checker instance but do NOT call site-level status here.
+                        Arc::new(
+                            crate::infrastructure::crawling_service_impls::StatusCheckerImpl::with_product_repo(
+                                (*input.deps.http).clone(),
+                                (*input.deps.extractor).clone(),
+                                input.config.clone(),
+                                Arc::clone(&input.deps.repo),
+                            ),
+                        ),
+                    ),
+                )
+            }
+        };
 
-        // Use injected pagination hints (from StageActor/BatchActor) to avoid per-item site status calls
-        let (total_pages, products_on_last_page) = match (input.total_pages_hint, input.products_on_last_page_hint) {
-            (Some(tp), Some(plp)) => (tp, plp),
-            // Fallbacks if hints are missing (should be rare): use conservative defaults
-            _ => (input.config.user.crawling.page_range_limit.max(1), 12u32.min(
-                crate::domain::constants::site::PRODUCTS_PER_PAGE as u32
-            )),
+        // Use injected pagination hints (from StageActor/BatchActor) to avoid per-item site status calls.
+        // Missing or stale hints fall back to the session's shared hints, checked at most once per TTL.
+        let injected = input
+            .pagination_hints
+            .filter(|h| h.is_fresh(site_hints::SITE_HINT_TTL));
+        let hints = match injected {
+            Some(hints) => hints,
+            None => {
+                let checker = crate::infrastructure::crawling_service_impls::StatusCheckerImpl::with_product_repo(
+                    (*input.deps.http).clone(),
+                    (*input.deps.extractor).clone(),
+                    input.config.clone(),
+                    Arc::clone(&input.deps.repo),
+                );
+                site_hints::get_or_check(&input.session_id, site_hints::SITE_HINT_TTL, || async move {
+                    let status = checker.check_site_status().await?;
+                    Ok((status.total_pages, status.products_on_last_page))
+                })
+                .await
+                .map_err(|e| StageLogicError::coded(ErrorCode::SiteStatusFailed, format!("Site status for pagination hints failed: {}", e)))?
+            }
         };
+        let (total_pages, products_on_last_page) = (hints.total_pages, hints.products_on_last_page);
 
         let urls = collector
             .collect_single_page(page_number, total_pages, products_on_last_page)
             .await
-            .map_err(|e| StageLogicError::Internal(format!("List page collect failed: {}", e)))?;
+            .map_err(|e| StageLogicError::coded(ErrorCode::ListCollectFailed, format!("List page collect failed: {}", e)))?;
     // 빈 결과 또는 비마지막 페이지에서 기대 수량(12) 미만은 내부 Collector에서 재시도 처리됨.
     // 여기서는 최종적으로 0개인 경우만 실패로 처리.
     if urls.is_empty() {
-            return Err(StageLogicError::Internal(
-                "Empty result from list page".into(),
+            return Err(StageLogicError::coded(
+                ErrorCode::EmptyListPage,
+                "Empty result from list page",
             ));
         }
         let json =
-            serde_json::to_string(&urls).map_err(|e| StageLogicError::Internal(e.to_string()))?;
+            serde_json::to_string(&urls).map_err(|e| StageLogicError::coded(ErrorCode::SerializationFailed, e.to_string()))?;
         let duration_ms = start.elapsed().as_millis() as u64;
         let result = crate::crawl_engine::actors::types::StageItemResult {
             item_id: format!("page_{}", page_number),
@@ -92,7 +129,7 @@ impl StageLogic for ListPageLogic {
             error: None,
             duration_ms,
             retry_count: 0,
-            collected_data: Some(json),
+            collected_data: Some(StagePayload::new(&input.session_id, json).await),
         };
         Ok(StageOutput { result })
     }
@@ -105,7 +142,7 @@ impl StageLogic for StatusCheckLogic {
     }
     async fn execute(&self, input: StageInput) -> Result<StageOutput, StageLogicError> {
         let st = input.stage_type.clone();
-        if !matches!(st, ActorStageType::StatusCheck) {
+        if !matches!(st, StageType::StatusCheck) {
             return Err(StageLogicError::Unsupported(st));
         }
         let status_checker = Arc::new(
@@ -119,9 +156,13 @@ impl StageLogic for StatusCheckLogic {
         let status = status_checker
             .check_site_status()
             .await
-            .map_err(|e| StageLogicError::Internal(format!("Status check failed: {}", e)))?;
+            .map_err(|e| StageLogicError::coded(ErrorCode::SiteStatusFailed, format!("Status check failed: {}", e)))?;
+        // Later ListPage items of this session reuse the result instead of checking again
+        if let Some(hints) = site_hints::PaginationHints::new(status.total_pages, status.products_on_last_page) {
+            site_hints::record(&input.session_id, hints);
+        }
         let json =
-            serde_json::to_string(&status).map_err(|e| StageLogicError::Internal(e.to_string()))?;
+            serde_json::to_string(&status).map_err(|e| StageLogicError::coded(ErrorCode::SerializationFailed, e.to_string()))?;
         let result = crate::crawl_engine::actors::types::StageItemResult {
             item_id: match &input.item {
                 crate::crawl_engine::channels::types::StageItem::Page(n) => format!("page_{}", n),
@@ -135,7 +176,7 @@ impl StageLogic for StatusCheckLogic {
             error: None,
             duration_ms: 0,
             retry_count: 0,
-            collected_data: Some(json),
+            collected_data: Some(StagePayload::new(&input.session_id, json).await),
         };
         Ok(StageOutput { result })
     }
@@ -148,14 +189,14 @@ impl StageLogic for ProductDetailLogic {
     }
     async fn execute(&self, input: StageInput) -> Result<StageOutput, StageLogicError> {
         let st = input.stage_type.clone();
-        if !matches!(st, ActorStageType::ProductDetailCrawling) {
+        if !matches!(st, StageType::ProductDetailCrawling) {
             return Err(StageLogicError::Unsupported(st));
         }
         use crate::crawl_engine::channels::types::{ExtractionStats, ProductDetails};
         let urls = match &input.item {
             crate::crawl_engine::channels::types::StageItem::ProductUrls(u) => u.clone(),
             other => {
-                return Err(StageLogicError::Internal(format!(
+                return Err(StageLogicError::coded(ErrorCode::UnexpectedStageItem, format!(
                     "ProductDetailLogic expected ProductUrls, got {:?}",
                     other
                 )));
@@ -190,7 +231,7 @@ impl StageLogic for ProductDetailLogic {
         let details = collector
             .collect_details(&urls.urls)
             .await
-            .map_err(|e| StageLogicError::Internal(format!("Detail collect failed: {}", e)))?;
+            .map_err(|e| StageLogicError::coded(ErrorCode::DetailCollectFailed, format!("Detail collect failed: {}", e)))?;
         let wrapper = ProductDetails {
             products: details.clone(),
             source_urls: urls.urls.clone(),
@@ -202,7 +243,7 @@ impl StageLogic for ProductDetailLogic {
             },
         };
         let json = serde_json::to_string(&wrapper)
-            .map_err(|e| StageLogicError::Internal(e.to_string()))?;
+            .map_err(|e| StageLogicError::coded(ErrorCode::SerializationFailed, e.to_string()))?;
         let result = crate::crawl_engine::actors::types::StageItemResult {
             item_id: format!("product_urls_{}", wrapper.source_urls.len()),
             item_type: StageItemType::ProductUrls {
@@ -212,7 +253,7 @@ impl StageLogic for ProductDetailLogic {
             error: None,
             duration_ms: 0,
             retry_count: 0,
-            collected_data: Some(json),
+            collected_data: Some(StagePayload::new(&input.session_id, json).await),
         };
         Ok(StageOutput { result })
     }
@@ -225,7 +266,7 @@ impl StageLogic for DataValidationLogic {
     }
     async fn execute(&self, input: StageInput) -> Result<StageOutput, StageLogicError> {
         let st = input.stage_type.clone();
-        if !matches!(st, ActorStageType::DataValidation) {
+        if !matches!(st, StageType::DataValidation) {
             return Err(StageLogicError::Unsupported(st));
         }
         use crate::crawl_engine::services::data_quality_analyzer::DataQualityAnalyzer;
@@ -234,7 +275,7 @@ impl StageLogic for DataValidationLogic {
                 pd.products.clone()
             }
             other => {
-                return Err(StageLogicError::Internal(format!(
+                return Err(StageLogicError::coded(ErrorCode::UnexpectedStageItem, format!(
                     "DataValidation expected ProductDetails, got {:?}",
                     other
                 )));
@@ -243,9 +284,9 @@ impl StageLogic for DataValidationLogic {
         let analyzer = DataQualityAnalyzer::new();
         let validated = analyzer
             .validate_before_storage(&details_vec)
-            .map_err(|e| StageLogicError::Internal(format!("Validation failed: {}", e)))?;
+            .map_err(|e| StageLogicError::coded(ErrorCode::ValidationFailed, format!("Validation failed: {}", e)))?;
         let json = serde_json::to_string(&validated)
-            .map_err(|e| StageLogicError::Internal(e.to_string()))?;
+            .map_err(|e| StageLogicError::coded(ErrorCode::SerializationFailed, e.to_string()))?;
         let result = crate::crawl_engine::actors::types::StageItemResult {
             item_id: format!("validated_products_{}", validated.len()),
             item_type: StageItemType::Url {
@@ -255,7 +296,7 @@ impl StageLogic for DataValidationLogic {
             error: None,
             duration_ms: 0,
             retry_count: 0,
-            collected_data: Some(json),
+            collected_data: Some(StagePayload::new(&input.session_id, json).await),
         };
         Ok(StageOutput { result })
     }
@@ -268,7 +309,7 @@ impl StageLogic for DataSavingLogic {
     }
     async fn execute(&self, input: StageInput) -> Result<StageOutput, StageLogicError> {
         let st = input.stage_type.clone();
-        if !matches!(st, ActorStageType::DataSaving) {
+        if !matches!(st, StageType::DataSaving) {
             return Err(StageLogicError::Unsupported(st));
         }
     // Select products vector based on item type
@@ -284,51 +325,36 @@ impl StageLogic for DataSavingLogic {
                 StageItemType::Url { url_type: "data_saving:validated_products".into() },
             ),
             other => {
-                return Err(StageLogicError::Internal(format!(
+                return Err(StageLogicError::coded(ErrorCode::UnexpectedStageItem, format!(
                     "DataSaving expected ProductDetails|ValidatedProducts, got {:?}",
                     other
                 )));
             }
         };
 
-        // Persist each product detail; count inserts/updates using repository helpers
-        let repo = input.deps.repo;
-        let policy = input
-            .deps
-            .duplicate_policy
-            .clone();
-        let mut inserted: u32 = 0;
-        let mut updated: u32 = 0;
-        for detail in products.iter() {
-            // create_or_update_product_detail internally upserts both product and product_details
-            match repo.create_or_update_product_detail(detail).await {
-                Ok((was_updated, was_created)) => {
-                    if was_created { inserted = inserted.saturating_add(1); }
-                    if was_updated { updated = updated.saturating_add(1); }
-                    if !was_created && !was_updated {
-                        if policy == crate::crawl_engine::actors::types::DuplicatePersistencePolicy::UpdateIdIndexOnly {
-                            if let (Some(pid), Some(idx)) = (detail.page_id, detail.index_in_page) {
-                                // force-update positions for existing URL
-                                let _ = repo
-                                    .force_update_position_by_url(&detail.url, pid, idx)
-                                    .await;
-                            }
-                        }
-                    }
-                }
-                Err(e) => {
-                    return Err(StageLogicError::Internal(format!(
-                        "Persistence failed for URL {}: {}",
-                        detail.url, e
-                    )));
-                }
-            }
-        }
+        // Rows go through the shared write-behind queue; flushing before reporting keeps the
+        // batch's inserted/updated metrics backed by committed rows
+        let queue = crate::infrastructure::persistence_queue::shared_queue(input.deps.repo.pool());
         let attempted = products.len() as u32;
+        let persist_err = |e: anyhow::Error| {
+            StageLogicError::coded(ErrorCode::PersistenceFailed, format!("Persistence failed: {e:#}"))
+        };
+        // A batch crossing max_new_products keeps its updates but only the allowed new rows
+        let admitted = queue
+            .cap_new_products(&input.session_id, products.to_vec())
+            .await
+            .map_err(persist_err)?;
+        let over_limit = attempted - admitted.len() as u32;
+        let mut flushed = queue.enqueue(admitted).await.map_err(persist_err)?;
+        flushed.absorb(&queue.flush().await.map_err(persist_err)?);
         let payload = serde_json::json!({
             "attempted": attempted,
-            "products_inserted": inserted,
-            "products_updated": updated
+            "products_inserted": flushed.inserted,
+            "products_updated": flushed.updated,
+            "products_unchanged": flushed.unchanged,
+            "rejected": flushed.rejected,
+            "skipped_over_limit": over_limit,
+            "flush_ms": flushed.elapsed_ms
         });
         let result = crate::crawl_engine::actors::types::StageItemResult {
             item_id,
@@ -337,7 +363,7 @@ impl StageLogic for DataSavingLogic {
             error: None,
             duration_ms: 0,
             retry_count: 0,
-            collected_data: Some(payload.to_string()),
+            collected_data: Some(StagePayload::new(&input.session_id, payload.to_string()).await),
         };
         Ok(StageOutput { result })
     }
@@ -4,8 +4,10 @@ use async_trait::async_trait;
 use std::sync::Arc;
 
 use crate::crawl_engine::actors::types::StageItemResult;
-use crate::crawl_engine::actors::types::StageType;
 use crate::crawl_engine::channels::types::StageItem;
+use crate::crawl_engine::runtime::site_hints::PaginationHints;
+use crate::crawl_engine::stage_type::StageType;
+use crate::domain::error_catalog::ErrorCode;
 use crate::infrastructure::config::AppConfig;
 use crate::infrastructure::{HttpClient, IntegratedProductRepository, MatterDataExtractor};
 
@@ -24,9 +26,11 @@ pub struct StageInput {
     pub item: StageItem,
     pub config: AppConfig,
     pub deps: Deps,
-    /// Optional pagination hints injected by Batch/Stage actor to avoid per-item site status calls
-    pub total_pages_hint: Option<u32>,
-    pub products_on_last_page_hint: Option<u32>,
+    /// Session the item belongs to (keys the shared `site_hints` fallback)
+    pub session_id: String,
+    /// Pagination hints from the session's status check, injected by Batch/Stage actor to avoid
+    /// per-item site status calls; reused while younger than `site_hints::SITE_HINT_TTL`
+    pub pagination_hints: Option<PaginationHints>,
 }
 
 /// Output from a StageLogic strategy
@@ -42,6 +46,27 @@ pub enum StageLogicError {
 
     #[error("Strategy internal error: {0}")]
     Internal(String),
+
+    /// Failure with a catalog code; Display keeps the `[E_...]` tag so it survives string hops
+    #[error("[{code}] {message}")]
+    Coded { code: ErrorCode, message: String },
+}
+
+impl StageLogicError {
+    pub fn coded(code: ErrorCode, message: impl Into<String>) -> Self {
+        StageLogicError::Coded {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            StageLogicError::Unsupported(_) => ErrorCode::UnsupportedStage,
+            StageLogicError::Internal(message) => ErrorCode::classify_message(message),
+            StageLogicError::Coded { code, .. } => *code,
+        }
+    }
 }
 
 // No direct conversion here; StageActor maps StageLogicError into its own error type.
@@ -5,6 +5,8 @@
 #![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
 use std::{collections::HashMap, time::Duration};
 use thiserror::Error;
 
@@ -117,6 +119,77 @@ pub struct ChannelSettings {
     pub control_buffer_size: usize,
     pub event_buffer_size: usize,
     pub backpressure_threshold: f64,
+    /// ActorEventBridge 내부 대기열 최대 길이 (프론트엔드 지연 시 버퍼 상한)
+    #[serde(default = "ChannelSettings::default_bridge_queue_capacity")]
+    pub bridge_queue_capacity: usize,
+    /// 대기열이 가득 찼을 때의 처리 정책
+    #[serde(default)]
+    pub bridge_overflow_policy: BridgeOverflowPolicy,
+    /// 브릿지 대기열 점유율이 이 비율(0..1]에 닿으면 경고 로그 (프론트엔드 지연 조기 감지)
+    #[serde(default = "ChannelSettings::default_bridge_warn_watermark")]
+    pub bridge_warn_watermark: f64,
+    /// 세션/배치 단계별 채널 용량
+    #[serde(default)]
+    pub stage_capacities: StageChannelCapacities,
+}
+
+impl ChannelSettings {
+    const fn default_bridge_queue_capacity() -> usize {
+        2048
+    }
+
+    const fn default_bridge_warn_watermark() -> f64 {
+        0.8
+    }
+}
+
+/// 채널 생성 지점별 용량 (재컴파일 없이 처리량 튜닝)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageChannelCapacities {
+    /// 세션 AppEvent broadcast (브릿지 / 리포트 / 내보내기 구독)
+    #[serde(default = "StageChannelCapacities::default_session_events")]
+    pub session_events: usize,
+    /// SessionActor → BatchActor 명령 채널
+    #[serde(default = "StageChannelCapacities::default_actor_commands")]
+    pub actor_commands: usize,
+    /// 레거시 실제 크롤링 경로의 프론트엔드 이벤트 broadcast
+    #[serde(default = "StageChannelCapacities::default_frontend_events")]
+    pub frontend_events: usize,
+}
+
+impl StageChannelCapacities {
+    const fn default_session_events() -> usize {
+        1000
+    }
+
+    const fn default_actor_commands() -> usize {
+        100
+    }
+
+    const fn default_frontend_events() -> usize {
+        500
+    }
+}
+
+impl Default for StageChannelCapacities {
+    fn default() -> Self {
+        Self {
+            session_events: Self::default_session_events(),
+            actor_commands: Self::default_actor_commands(),
+            frontend_events: Self::default_frontend_events(),
+        }
+    }
+}
+
+/// 이벤트 브릿지 대기열 오버플로 정책
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BridgeOverflowPolicy {
+    /// 가장 오래된 이벤트를 버리고 새 이벤트를 수용 (최신 상태 우선)
+    #[default]
+    DropOldest,
+    /// 새로 도착한 이벤트를 버림 (기존 순서 보존)
+    DropNewest,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -150,7 +223,46 @@ impl RetryPolicy {
     }
 }
 
+/// 시작 시 설치된 설정 (미설치 시 기본값)
+static CURRENT: OnceLock<Arc<SystemConfig>> = OnceLock::new();
+
+/// 시작 시 읽는 선택적 오버라이드 파일 (앱 설정 디렉터리)
+pub const SYSTEM_CONFIG_FILE: &str = "system_config.toml";
+
 impl SystemConfig {
+    /// 기본값 위에 `dir/system_config.toml` 과 `RMATTERCERTIS_<SECTION>__<KEY>` 환경 변수를
+    /// 덮어쓴 뒤 검증한다. 파일은 없어도 된다.
+    pub fn load_overrides(dir: &Path) -> Result<Self, ConfigError> {
+        let settings = config::Config::builder()
+            .add_source(config::Config::try_from(&Self::default())?)
+            .add_source(config::File::from(dir.join(SYSTEM_CONFIG_FILE)).required(false))
+            .add_source(
+                config::Environment::with_prefix("RMATTERCERTIS")
+                    .separator("__")
+                    .try_parsing(true),
+            )
+            .build()?;
+
+        let config: Self = settings.try_deserialize()?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// 시작 시 한 번 설치; 이미 설치되어 있으면 false
+    pub fn install(config: Self) -> bool {
+        CURRENT.set(Arc::new(config)).is_ok()
+    }
+
+    /// 채널/배치 생성 지점에서 쓰는 현재 설정
+    pub fn current() -> Arc<Self> {
+        CURRENT.get_or_init(|| Arc::new(Self::default())).clone()
+    }
+
+    /// 현재 설정의 단계별 채널 용량
+    pub fn channel_capacities() -> StageChannelCapacities {
+        Self::current().channels.stage_capacities
+    }
+
     pub fn from_file(path: &str) -> Result<Self, ConfigError> {
         let settings = config::Config::builder()
             .add_source(config::File::with_name(path))
@@ -190,6 +302,53 @@ impl SystemConfig {
             });
         }
 
+        let batch = &self.performance.batch_sizes;
+        if batch.min_size == 0 || !(batch.min_size..=batch.max_size).contains(&batch.initial_size) {
+            return Err(ConfigError::Validation {
+                message: format!(
+                    "batch_sizes must satisfy 0 < min_size <= initial_size <= max_size (got {}/{}/{})",
+                    batch.min_size, batch.initial_size, batch.max_size
+                ),
+            });
+        }
+
+        let channels = &self.channels;
+        let capacities = [
+            ("control_buffer_size", channels.control_buffer_size),
+            ("event_buffer_size", channels.event_buffer_size),
+            ("bridge_queue_capacity", channels.bridge_queue_capacity),
+            (
+                "stage_capacities.session_events",
+                channels.stage_capacities.session_events,
+            ),
+            (
+                "stage_capacities.actor_commands",
+                channels.stage_capacities.actor_commands,
+            ),
+            (
+                "stage_capacities.frontend_events",
+                channels.stage_capacities.frontend_events,
+            ),
+        ];
+        if let Some((name, _)) = capacities.iter().find(|(_, capacity)| *capacity == 0) {
+            return Err(ConfigError::Validation {
+                message: format!("channels.{name} must be greater than 0"),
+            });
+        }
+
+        let watermarks = [
+            ("backpressure_threshold", channels.backpressure_threshold),
+            ("bridge_warn_watermark", channels.bridge_warn_watermark),
+        ];
+        if let Some((name, value)) = watermarks
+            .iter()
+            .find(|(_, value)| !(*value > 0.0 && *value <= 1.0))
+        {
+            return Err(ConfigError::Validation {
+                message: format!("channels.{name} must be in (0, 1] (got {value})"),
+            });
+        }
+
         Ok(())
     }
 
@@ -294,6 +453,10 @@ impl SystemConfig {
                 control_buffer_size: 100,
                 event_buffer_size: 500,
                 backpressure_threshold: 0.8,
+                bridge_queue_capacity: ChannelSettings::default_bridge_queue_capacity(),
+                bridge_overflow_policy: BridgeOverflowPolicy::DropOldest,
+                bridge_warn_watermark: ChannelSettings::default_bridge_warn_watermark(),
+                stage_capacities: StageChannelCapacities::default(),
             },
             actor: ActorSettings {
                 session_timeout_secs: 300,
@@ -342,3 +505,43 @@ pub struct LegacyActorSettings {
     pub max_actors: Option<u32>,
     pub restart_policy: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_layer_on_defaults_and_are_validated() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        // No file: defaults (which must themselves validate)
+        let config = SystemConfig::load_overrides(dir.path())?;
+        assert_eq!(
+            config.channels.stage_capacities,
+            StageChannelCapacities::default()
+        );
+
+        std::fs::write(
+            dir.path().join(SYSTEM_CONFIG_FILE),
+            "[channels]\nbridge_warn_watermark = 0.5\n\n[channels.stage_capacities]\nsession_events = 4096\n",
+        )?;
+        let config = SystemConfig::load_overrides(dir.path())?;
+        assert_eq!(config.channels.stage_capacities.session_events, 4096);
+        assert_eq!(config.channels.stage_capacities.actor_commands, 100);
+        assert!((config.channels.bridge_warn_watermark - 0.5).abs() < f64::EPSILON);
+        assert_eq!(config.channels.control_buffer_size, 100);
+
+        std::fs::write(
+            dir.path().join(SYSTEM_CONFIG_FILE),
+            "[channels.stage_capacities]\nactor_commands = 0\n",
+        )?;
+        assert!(matches!(
+            SystemConfig::load_overrides(dir.path()),
+            Err(ConfigError::Validation { .. })
+        ));
+
+        let mut config = SystemConfig::default();
+        config.performance.batch_sizes.initial_size = 5;
+        assert!(config.validate().is_err());
+        Ok(())
+    }
+}
@@ -377,10 +377,10 @@ impl TaskActor {
     /// 태스크 타입에 따른 스테이지 타입 반환
     fn get_stage_type_for_task(&self) -> StageType {
         match &self.task_type {
-            TaskType::PageCollection { .. } => StageType::ListCollection,
-            TaskType::UrlProcessing { .. } => StageType::DetailCollection,
+            TaskType::PageCollection { .. } => StageType::ListPageCrawling,
+            TaskType::UrlProcessing { .. } => StageType::ProductDetailCrawling,
             TaskType::DataValidation { .. } => StageType::DataValidation,
-            TaskType::DatabaseSave { .. } => StageType::DatabaseSave,
+            TaskType::DatabaseSave { .. } => StageType::DataSaving,
         }
     }
 
@@ -0,0 +1,257 @@
+//! Cross-check findings table (`validation_findings`) and its repairs
+//!
+//! Each run replaces the open findings; repaired rows are kept with `resolved_at` as an audit
+//! trail. Repairs follow `products` as the source of truth except for orphan details, which are
+//! restored as products. Duplicate slots are left for manual review.
+
+use anyhow::Result;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use tracing::info;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingKind {
+    MissingDetail,
+    OrphanDetail,
+    CoordMismatch,
+    DuplicateSlot,
+}
+
+impl FindingKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FindingKind::MissingDetail => "missing_detail",
+            FindingKind::OrphanDetail => "orphan_detail",
+            FindingKind::CoordMismatch => "coord_mismatch",
+            FindingKind::DuplicateSlot => "duplicate_slot",
+        }
+    }
+
+    pub fn repair(&self) -> FindingRepair {
+        match self {
+            FindingKind::MissingDetail => FindingRepair::CreateDetail,
+            FindingKind::OrphanDetail => FindingRepair::RestoreProduct,
+            FindingKind::CoordMismatch => FindingRepair::CopyCoordinates,
+            FindingKind::DuplicateSlot => FindingRepair::Manual,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingRepair {
+    /// Insert a product_details row from products
+    CreateDetail,
+    /// Insert a products row from the orphan detail
+    RestoreProduct,
+    /// Copy products coordinates onto product_details
+    CopyCoordinates,
+    Manual,
+}
+
+impl FindingRepair {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FindingRepair::CreateDetail => "create_detail",
+            FindingRepair::RestoreProduct => "restore_product",
+            FindingRepair::CopyCoordinates => "copy_coordinates",
+            FindingRepair::Manual => "manual",
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        match value {
+            "create_detail" => FindingRepair::CreateDetail,
+            "restore_product" => FindingRepair::RestoreProduct,
+            "copy_coordinates" => FindingRepair::CopyCoordinates,
+            _ => FindingRepair::Manual,
+        }
+    }
+}
+
+/// One inconsistency found by a scan
+#[derive(Debug, Clone, Serialize)]
+pub struct Finding {
+    pub kind: FindingKind,
+    /// Table holding the offending row
+    pub table_name: String,
+    pub url: String,
+    /// products coordinates (None when the row or value is absent)
+    pub page_id: Option<i64>,
+    pub index_in_page: Option<i64>,
+    /// product_details coordinates
+    pub detail_page_id: Option<i64>,
+    pub detail_index_in_page: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StoredFinding {
+    pub id: i64,
+    pub run_id: String,
+    pub kind: String,
+    pub table_name: String,
+    pub url: String,
+    pub page_id: Option<i64>,
+    pub index_in_page: Option<i64>,
+    pub detail_page_id: Option<i64>,
+    pub detail_index_in_page: Option<i64>,
+    pub repair: String,
+    /// open | repaired
+    pub status: String,
+    pub created_at: String,
+    pub resolved_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct RepairOutcome {
+    pub repaired: u32,
+    /// Automatic repairs that could not apply (e.g. the target slot is taken)
+    pub skipped: u32,
+    /// Findings that need manual review
+    pub manual: u32,
+}
+
+/// Replace the open findings with `findings`; returns the new run id
+pub async fn record(pool: &SqlitePool, findings: &[Finding]) -> Result<String> {
+    let now = Utc::now();
+    let run_id = format!("xcheck-{}", now.format("%Y%m%d-%H%M%S%3f"));
+    let created_at = now.to_rfc3339();
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM validation_findings WHERE status = 'open'")
+        .execute(&mut *tx)
+        .await?;
+    for finding in findings {
+        sqlx::query(
+            "INSERT INTO validation_findings \
+                (run_id, kind, table_name, url, page_id, index_in_page, detail_page_id, \
+                 detail_index_in_page, repair, status, created_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, 'open', ?)",
+        )
+        .bind(&run_id)
+        .bind(finding.kind.as_str())
+        .bind(&finding.table_name)
+        .bind(&finding.url)
+        .bind(finding.page_id)
+        .bind(finding.index_in_page)
+        .bind(finding.detail_page_id)
+        .bind(finding.detail_index_in_page)
+        .bind(finding.kind.repair().as_str())
+        .bind(&created_at)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+    Ok(run_id)
+}
+
+/// Findings with `status` (all when None), oldest first
+pub async fn list(pool: &SqlitePool, status: Option<&str>) -> Result<Vec<StoredFinding>> {
+    let rows = sqlx::query(
+        "SELECT id, run_id, kind, table_name, url, page_id, index_in_page, detail_page_id, \
+            detail_index_in_page, repair, status, created_at, resolved_at \
+         FROM validation_findings WHERE ?1 IS NULL OR status = ?1 ORDER BY id",
+    )
+    .bind(status)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|r| StoredFinding {
+            id: r.get("id"),
+            run_id: r.get("run_id"),
+            kind: r.get("kind"),
+            table_name: r.get("table_name"),
+            url: r.get("url"),
+            page_id: r.get("page_id"),
+            index_in_page: r.get("index_in_page"),
+            detail_page_id: r.get("detail_page_id"),
+            detail_index_in_page: r.get("detail_index_in_page"),
+            repair: r.get("repair"),
+            status: r.get("status"),
+            created_at: r.get("created_at"),
+            resolved_at: r.get("resolved_at"),
+        })
+        .collect())
+}
+
+/// Apply the automatic repair of open findings (`ids`, or all when None)
+pub async fn repair(pool: &SqlitePool, ids: Option<&[i64]>) -> Result<RepairOutcome> {
+    let mut outcome = RepairOutcome::default();
+    for finding in list(pool, Some("open")).await? {
+        if ids.is_some_and(|ids| !ids.contains(&finding.id)) {
+            continue;
+        }
+        let applied = match FindingRepair::parse(&finding.repair) {
+            FindingRepair::Manual => {
+                outcome.manual += 1;
+                continue;
+            }
+            FindingRepair::CreateDetail => create_detail(pool, &finding.url).await?,
+            FindingRepair::RestoreProduct => {
+                sqlx::query(
+                    "INSERT OR IGNORE INTO products \
+                        (url, manufacturer, model, certificate_id, page_id, index_in_page) \
+                     SELECT url, manufacturer, model, certificate_id, page_id, index_in_page \
+                     FROM product_details WHERE url = ?1",
+                )
+                .bind(&finding.url)
+                .execute(pool)
+                .await?
+                .rows_affected()
+                    > 0
+            }
+            FindingRepair::CopyCoordinates => {
+                sqlx::query(
+                    "UPDATE OR IGNORE product_details SET \
+                        page_id = (SELECT page_id FROM products WHERE url = ?1), \
+                        index_in_page = (SELECT index_in_page FROM products WHERE url = ?1) \
+                     WHERE url = ?1 AND EXISTS (SELECT 1 FROM products WHERE url = ?1)",
+                )
+                .bind(&finding.url)
+                .execute(pool)
+                .await?
+                .rows_affected()
+                    > 0
+            }
+        };
+        if applied {
+            sqlx::query(
+                "UPDATE validation_findings SET status = 'repaired', resolved_at = ? WHERE id = ?",
+            )
+            .bind(Utc::now().to_rfc3339())
+            .bind(finding.id)
+            .execute(pool)
+            .await?;
+            outcome.repaired += 1;
+        } else {
+            outcome.skipped += 1;
+        }
+    }
+    info!(
+        "[VALIDATION] Repaired {} findings ({} skipped, {} manual)",
+        outcome.repaired, outcome.skipped, outcome.manual
+    );
+    Ok(outcome)
+}
+
+/// Detail row from products; coordinates are left empty when the detail slot is taken
+async fn create_detail(pool: &SqlitePool, url: &str) -> Result<bool> {
+    for coordinates in ["page_id, index_in_page", "NULL, NULL"] {
+        let inserted = sqlx::query(&format!(
+            "INSERT OR IGNORE INTO product_details \
+                (url, manufacturer, model, certificate_id, page_id, index_in_page) \
+             SELECT url, manufacturer, model, certificate_id, {coordinates} \
+             FROM products WHERE url = ?1"
+        ))
+        .bind(url)
+        .execute(pool)
+        .await?
+        .rows_affected();
+        if inserted > 0 {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
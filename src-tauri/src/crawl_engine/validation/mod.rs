@@ -1,8 +1,23 @@
-//! Validation (MI-2) Skeleton
-//! Provides event enums and coordinator stub for page_id/index_in_page integrity validation.
+//! Validation (MI-2): products ↔ product_details DB cross-check
+//!
+//! `ValidationCoordinator` scans both tables for missing details, orphan details, coordinate
+//! mismatches and duplicate (page_id, index_in_page) slots. Progress goes out as `ValidationEvent`s
+//! through an `EventSink`; every run replaces the open rows of the `validation_findings` table,
+//! which `findings::repair` works through. Site-backed validation stays in `start_validation`.
 
+pub mod findings;
+
+use crate::infrastructure::event_sink::EventSink;
+use anyhow::Result;
+use findings::{Finding, FindingKind};
 use serde::Serialize;
-use tracing::info;
+use sqlx::{Row, SqlitePool};
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{info, warn};
+
+/// Event name for coordinator `ValidationEvent`s
+pub const VALIDATION_EVENT_NAME: &str = "validation-cross-check";
 
 #[derive(Debug, Serialize, Clone)]
 #[serde(tag = "type", content = "data")]
@@ -40,24 +55,282 @@ pub enum ValidationEvent {
     },
 }
 
+/// Outcome of one cross-check run
+#[derive(Debug, Clone, Serialize)]
+pub struct CrossCheckSummary {
+    pub run_id: String,
+    pub scan_depth: u32,
+    /// Lowest page_id covered (0 = whole DB)
+    pub min_page_id: i64,
+    pub products_scanned: i64,
+    pub details_scanned: i64,
+    /// products rows without a product_details row
+    pub missing_details: usize,
+    /// product_details rows without a products row
+    pub orphan_details: usize,
+    pub coord_mismatches: usize,
+    /// Rows sharing a (page_id, index_in_page) slot with another row of the same table
+    pub duplicate_slots: usize,
+    pub duration_ms: u128,
+}
+
+impl CrossCheckSummary {
+    pub fn total_findings(&self) -> usize {
+        self.missing_details + self.orphan_details + self.coord_mismatches + self.duplicate_slots
+    }
+}
+
+const MISSING_DETAILS_SQL: &str = "SELECT p.url, p.page_id, p.index_in_page, \
+        NULL AS detail_page_id, NULL AS detail_index_in_page \
+     FROM products p LEFT JOIN product_details d ON d.url = p.url \
+     WHERE d.url IS NULL AND IFNULL(p.page_id, ?1) >= ?1 ORDER BY p.page_id, p.index_in_page";
+
+const ORPHAN_DETAILS_SQL: &str = "SELECT d.url, NULL AS page_id, NULL AS index_in_page, \
+        d.page_id AS detail_page_id, d.index_in_page AS detail_index_in_page \
+     FROM product_details d LEFT JOIN products p ON p.url = d.url \
+     WHERE p.url IS NULL AND IFNULL(d.page_id, ?1) >= ?1 ORDER BY d.page_id, d.index_in_page";
+
+const COORD_MISMATCH_SQL: &str = "SELECT p.url, p.page_id, p.index_in_page, \
+        d.page_id AS detail_page_id, d.index_in_page AS detail_index_in_page \
+     FROM products p JOIN product_details d ON d.url = p.url \
+     WHERE (p.page_id IS NOT d.page_id OR p.index_in_page IS NOT d.index_in_page) \
+       AND IFNULL(p.page_id, ?1) >= ?1 ORDER BY p.page_id, p.index_in_page";
+
+const DUPLICATE_PRODUCT_SLOTS_SQL: &str = "SELECT p.url, p.page_id, p.index_in_page, \
+        NULL AS detail_page_id, NULL AS detail_index_in_page \
+     FROM products p JOIN ( \
+        SELECT page_id, index_in_page FROM products \
+        WHERE page_id IS NOT NULL AND index_in_page IS NOT NULL \
+        GROUP BY page_id, index_in_page HAVING COUNT(*) > 1 \
+     ) s ON s.page_id = p.page_id AND s.index_in_page = p.index_in_page \
+     WHERE p.page_id >= ?1 ORDER BY p.page_id, p.index_in_page, p.url";
+
+const DUPLICATE_DETAIL_SLOTS_SQL: &str = "SELECT d.url, NULL AS page_id, NULL AS index_in_page, \
+        d.page_id AS detail_page_id, d.index_in_page AS detail_index_in_page \
+     FROM product_details d JOIN ( \
+        SELECT page_id, index_in_page FROM product_details \
+        WHERE page_id IS NOT NULL AND index_in_page IS NOT NULL \
+        GROUP BY page_id, index_in_page HAVING COUNT(*) > 1 \
+     ) s ON s.page_id = d.page_id AND s.index_in_page = d.index_in_page \
+     WHERE d.page_id >= ?1 ORDER BY d.page_id, d.index_in_page, d.url";
+
 pub struct ValidationCoordinator {
     scan_depth: u32,
+    sink: Arc<dyn EventSink>,
 }
 
 impl ValidationCoordinator {
-    pub fn new(scan_depth: u32) -> Self {
-        Self { scan_depth }
+    /// Checks the `scan_depth` newest page_ids (0 = every page)
+    pub fn new(scan_depth: u32, sink: Arc<dyn EventSink>) -> Self {
+        Self { scan_depth, sink }
+    }
+
+    fn emit(&self, event: ValidationEvent) {
+        match serde_json::to_value(&event) {
+            Ok(payload) => self.sink.emit_json(VALIDATION_EVENT_NAME, payload),
+            Err(e) => warn!("[VALIDATION] Failed to serialize event: {}", e),
+        }
     }
 
-    pub fn run(&self) {
-        let start = std::time::Instant::now();
-        info!("[VALIDATION] Started scan_depth={}", self.scan_depth);
-        // TODO(MI-2): integrate with site state + page fetch actors
+    pub async fn run(&self, pool: &SqlitePool) -> Result<CrossCheckSummary> {
+        let start = Instant::now();
+        let max_page_id: Option<i64> = sqlx::query_scalar(
+            "SELECT MAX(page_id) FROM (SELECT page_id FROM products \
+             UNION ALL SELECT page_id FROM product_details)",
+        )
+        .fetch_one(pool)
+        .await?;
+        let page_count = max_page_id.map_or(0, |max| max + 1);
+        let min_page_id = if self.scan_depth == 0 {
+            0
+        } else {
+            (page_count - i64::from(self.scan_depth)).max(0)
+        };
         info!(
-            "[VALIDATION] Completed in {} ms",
-            start.elapsed().as_millis()
+            "[VALIDATION] Started scan_depth={} pages {}..{}",
+            self.scan_depth, min_page_id, page_count
         );
+        self.emit(ValidationEvent::Started {
+            scan_depth: self.scan_depth,
+            last_physical_page: page_count as u32,
+        });
+
+        let products_scanned: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM products WHERE IFNULL(page_id, ?1) >= ?1")
+                .bind(min_page_id)
+                .fetch_one(pool)
+                .await?;
+        let details_scanned: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM product_details WHERE IFNULL(page_id, ?1) >= ?1",
+        )
+        .bind(min_page_id)
+        .fetch_one(pool)
+        .await?;
+        self.emit(ValidationEvent::AssignmentProgress {
+            assigned_total: (products_scanned + details_scanned) as usize,
+            last_page_id: max_page_id.unwrap_or(0) as u32,
+        });
+
+        let missing = scan(
+            pool,
+            MISSING_DETAILS_SQL,
+            FindingKind::MissingDetail,
+            "products",
+            min_page_id,
+        )
+        .await?;
+        let orphans = scan(
+            pool,
+            ORPHAN_DETAILS_SQL,
+            FindingKind::OrphanDetail,
+            "product_details",
+            min_page_id,
+        )
+        .await?;
+        let mismatches = scan(
+            pool,
+            COORD_MISMATCH_SQL,
+            FindingKind::CoordMismatch,
+            "product_details",
+            min_page_id,
+        )
+        .await?;
+        let mut duplicates = scan(
+            pool,
+            DUPLICATE_PRODUCT_SLOTS_SQL,
+            FindingKind::DuplicateSlot,
+            "products",
+            min_page_id,
+        )
+        .await?;
+        duplicates.extend(
+            scan(
+                pool,
+                DUPLICATE_DETAIL_SLOTS_SQL,
+                FindingKind::DuplicateSlot,
+                "product_details",
+                min_page_id,
+            )
+            .await?,
+        );
+
+        let mut summary = CrossCheckSummary {
+            run_id: String::new(),
+            scan_depth: self.scan_depth,
+            min_page_id,
+            products_scanned,
+            details_scanned,
+            missing_details: missing.len(),
+            orphan_details: orphans.len(),
+            coord_mismatches: mismatches.len(),
+            duplicate_slots: duplicates.len(),
+            duration_ms: 0,
+        };
+        self.emit(ValidationEvent::DiffReady {
+            total: summary.total_findings(),
+            missing_in_db: summary.missing_details,
+            orphan_in_db: summary.orphan_details,
+            coord_mismatch: summary.coord_mismatches,
+        });
+        if !duplicates.is_empty() {
+            self.emit(ValidationEvent::Warning {
+                code: "duplicate_slot".into(),
+                detail: format!(
+                    "{} rows share a (page_id, index_in_page) slot; review them manually",
+                    duplicates.len()
+                ),
+            });
+        }
+
+        let all: Vec<Finding> = missing
+            .into_iter()
+            .chain(orphans)
+            .chain(mismatches)
+            .chain(duplicates)
+            .collect();
+        summary.run_id = findings::record(pool, &all).await?;
+        summary.duration_ms = start.elapsed().as_millis();
+        self.emit(ValidationEvent::Completed {
+            duration_ms: summary.duration_ms,
+            remaining_mismatch: summary.total_findings(),
+        });
+        info!(
+            "[VALIDATION] Completed in {} ms: missing={} orphan={} mismatch={} duplicate_slot={}",
+            summary.duration_ms,
+            summary.missing_details,
+            summary.orphan_details,
+            summary.coord_mismatches,
+            summary.duplicate_slots
+        );
+        Ok(summary)
     }
 }
 
-// Future: emit events via EventEmitter (MI-2 integration)
+async fn scan(
+    pool: &SqlitePool,
+    sql: &str,
+    kind: FindingKind,
+    table_name: &str,
+    min_page_id: i64,
+) -> Result<Vec<Finding>> {
+    let rows = sqlx::query(sql).bind(min_page_id).fetch_all(pool).await?;
+    rows.into_iter()
+        .map(|row| {
+            Ok(Finding {
+                kind,
+                table_name: table_name.to_string(),
+                url: row.try_get("url")?,
+                page_id: row.try_get("page_id")?,
+                index_in_page: row.try_get("index_in_page")?,
+                detail_page_id: row.try_get("detail_page_id")?,
+                detail_index_in_page: row.try_get("detail_index_in_page")?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::event_sink::NullEventSink;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    #[tokio::test]
+    async fn cross_check_finds_and_repairs_inconsistencies() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        for sql in [
+            "CREATE TABLE products (url TEXT PRIMARY KEY, manufacturer TEXT, model TEXT, \
+             certificate_id TEXT, page_id INTEGER, index_in_page INTEGER)",
+            "CREATE TABLE product_details (url TEXT PRIMARY KEY, manufacturer TEXT, model TEXT, \
+             certificate_id TEXT, page_id INTEGER, index_in_page INTEGER)",
+            // a: consistent, b: no details, c: coordinates drifted, d: orphan detail
+            "INSERT INTO products (url, page_id, index_in_page) VALUES \
+             ('a', 0, 0), ('b', 0, 1), ('c', 0, 2)",
+            "INSERT INTO product_details (url, page_id, index_in_page) VALUES \
+             ('a', 0, 0), ('c', 0, 5), ('d', 1, 0), ('e', 1, 0)",
+            "INSERT INTO products (url, page_id, index_in_page) VALUES ('e', 1, 0)",
+        ] {
+            sqlx::query(sql).execute(&pool).await.unwrap();
+        }
+
+        let coordinator = ValidationCoordinator::new(0, Arc::new(NullEventSink));
+        let summary = coordinator.run(&pool).await.unwrap();
+        assert_eq!(summary.missing_details, 1);
+        assert_eq!(summary.orphan_details, 1);
+        assert_eq!(summary.coord_mismatches, 1);
+        // 'd' and 'e' details share slot (1, 0)
+        assert_eq!(summary.duplicate_slots, 2);
+        assert_eq!(findings::list(&pool, Some("open")).await.unwrap().len(), 5);
+
+        let outcome = findings::repair(&pool, None).await.unwrap();
+        assert_eq!((outcome.manual, outcome.skipped), (2, 0));
+        // The orphan came back as a product; its slot is still shared with 'e'
+        let summary = coordinator.run(&pool).await.unwrap();
+        assert_eq!(summary.total_findings(), summary.duplicate_slots);
+        assert_eq!(summary.duplicate_slots, 4);
+    }
+}
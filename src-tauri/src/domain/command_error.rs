@@ -0,0 +1,178 @@
+//! 커맨드 경계의 구조화된 에러
+//!
+//! `Result<_, String>` 은 에러 종류(네트워크/파싱/DB/설정)를 문자열 속에 묻어 버린다.
+//! `CommandError` 는 에러 카탈로그의 카테고리·재시도 가능 여부·조치 안내를 그대로 직렬화해
+//! 프런트엔드가 코드 기반으로 메시지를 렌더링하게 한다. 내부 로직은 문자열 에러를 유지하고
+//! 커맨드 경계에서 `From<String>` 으로 분류한다 (`[E_...]` 태그가 휴리스틱보다 우선).
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use ts_rs::TS;
+
+use super::error_catalog::{ErrorCategory, ErrorCode};
+
+/// Payload shared by every `CommandError` category
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../src/types/generated/")]
+pub struct CommandErrorDetail {
+    pub code: ErrorCode,
+    /// Human-readable message without the `[E_...]` tag
+    pub message: String,
+    /// Whether retrying the same command later can reasonably succeed
+    pub retryable: bool,
+    pub title: String,
+    pub remediation: Vec<String>,
+}
+
+/// Error returned by sync, validation and crawling commands, tagged by `category`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(tag = "category", rename_all = "snake_case")]
+#[ts(export, export_to = "../src/types/generated/")]
+pub enum CommandError {
+    Network(CommandErrorDetail),
+    Site(CommandErrorDetail),
+    Data(CommandErrorDetail),
+    Storage(CommandErrorDetail),
+    Configuration(CommandErrorDetail),
+    Runtime(CommandErrorDetail),
+    Request(CommandErrorDetail),
+}
+
+impl CommandError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        let described = code.describe();
+        let detail = CommandErrorDetail {
+            code,
+            message: strip_leading_tag(message.into()),
+            retryable: described.retryable,
+            title: described.title,
+            remediation: described.remediation,
+        };
+        match described.category {
+            ErrorCategory::Network => CommandError::Network(detail),
+            ErrorCategory::Site => CommandError::Site(detail),
+            ErrorCategory::Data => CommandError::Data(detail),
+            ErrorCategory::Storage => CommandError::Storage(detail),
+            ErrorCategory::Configuration => CommandError::Configuration(detail),
+            ErrorCategory::Runtime => CommandError::Runtime(detail),
+            ErrorCategory::Request => CommandError::Request(detail),
+        }
+    }
+
+    /// Rejected arguments (empty page set, malformed ranges, conflicting flags)
+    pub fn invalid_request(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::InvalidRequest, message)
+    }
+
+    /// `command` did not finish within the caller's `limit`
+    pub fn timed_out(command: &str, limit: std::time::Duration) -> Self {
+        Self::new(
+            ErrorCode::TimedOut,
+            format!("{command} did not finish within {} ms", limit.as_millis()),
+        )
+    }
+
+    /// Classify a legacy free-form message via `ErrorCode::classify_message`
+    pub fn classify(message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::new(ErrorCode::classify_message(&message), message)
+    }
+
+    pub fn detail(&self) -> &CommandErrorDetail {
+        match self {
+            CommandError::Network(d)
+            | CommandError::Site(d)
+            | CommandError::Data(d)
+            | CommandError::Storage(d)
+            | CommandError::Configuration(d)
+            | CommandError::Runtime(d)
+            | CommandError::Request(d) => d,
+        }
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        self.detail().code
+    }
+
+    pub fn category(&self) -> ErrorCategory {
+        self.code().category()
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        self.detail().retryable
+    }
+}
+
+/// `[E_CODE] message` → `message`; the code is carried by the detail instead
+fn strip_leading_tag(message: String) -> String {
+    if message.starts_with("[E_") {
+        if let Some(end) = message.find("] ") {
+            return message[end + 2..].to_string();
+        }
+    }
+    message
+}
+
+/// Tagged form, so converting back into `CommandError` keeps the code
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.code().tag(&self.detail().message))
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        Self::classify(message)
+    }
+}
+
+impl From<&str> for CommandError {
+    fn from(message: &str) -> Self {
+        Self::classify(message)
+    }
+}
+
+impl From<anyhow::Error> for CommandError {
+    fn from(e: anyhow::Error) -> Self {
+        Self::classify(format!("{e:#}"))
+    }
+}
+
+/// String-returning callers (schedulers, composite commands) keep using `?`
+impl From<CommandError> for String {
+    fn from(e: CommandError) -> Self {
+        e.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_with_category_tag_and_catalog_hints() {
+        let err = CommandError::classify("Failed to fetch newest page: operation timed out");
+        assert_eq!(err.code(), ErrorCode::NetworkTimeout);
+        assert!(err.is_retryable());
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["category"], "network");
+        assert_eq!(json["code"], "NETWORK_TIMEOUT");
+        assert!(!json["remediation"].as_array().unwrap().is_empty());
+        let back: CommandError = serde_json::from_value(json).unwrap();
+        assert_eq!(back, err);
+    }
+
+    #[test]
+    fn tags_survive_string_round_trip() {
+        let err = CommandError::invalid_request("No pages provided");
+        assert_eq!(err.category(), ErrorCategory::Request);
+        assert!(!err.is_retryable());
+        let text: String = err.clone().into();
+        assert_eq!(text, "[E_INVALID_REQUEST] No pages provided");
+        let back = CommandError::from(text);
+        assert_eq!(back, err);
+        assert_eq!(back.detail().message, "No pages provided");
+    }
+}
@@ -0,0 +1,416 @@
+//! 스테이지 무관 에러 코드 카탈로그
+//!
+//! 전략(`StageLogicError`), 스테이지(`StageError`), 액터(`ActorError`) 에러와 실패 이벤트가
+//! 같은 코드 집합을 공유한다. 같은 원인은 어느 계층에서 나든 하나의 코드로 모이므로,
+//! 프런트엔드는 문자열 대신 코드로 집계하고 `describe_error(code)` 로 조치 안내를 받는다.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use ts_rs::TS;
+
+/// Machine-readable error code shared by every crawl layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[ts(export, export_to = "../src/types/generated/")]
+pub enum ErrorCode {
+    NetworkTimeout,
+    NetworkFailure,
+    HttpServerError,
+    RateLimited,
+    AccessDenied,
+    ParseFailed,
+    EmptyListPage,
+    ListCollectFailed,
+    SiteStatusFailed,
+    DetailCollectFailed,
+    ValidationFailed,
+    PersistenceFailed,
+    DatabaseError,
+    ConfigurationError,
+    UnsupportedStage,
+    UnexpectedStageItem,
+    SerializationFailed,
+    ChannelClosed,
+    Cancelled,
+    /// A command exceeded the caller's timeout (not a network timeout)
+    TimedOut,
+    ResourceExhausted,
+    InvalidRequest,
+    Internal,
+}
+
+/// Broad grouping used for dashboards and retry decisions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "../src/types/generated/")]
+pub enum ErrorCategory {
+    Network,
+    Site,
+    Data,
+    Storage,
+    Configuration,
+    Runtime,
+    /// Arguments the command cannot act on; fix the request rather than retry
+    Request,
+}
+
+/// Catalog entry returned by `describe_error`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorDescription {
+    pub code: ErrorCode,
+    pub category: ErrorCategory,
+    pub title: String,
+    /// Whether retrying the same work later can reasonably succeed
+    pub retryable: bool,
+    pub remediation: Vec<String>,
+}
+
+impl ErrorCode {
+    pub const ALL: [ErrorCode; 23] = [
+        ErrorCode::NetworkTimeout,
+        ErrorCode::NetworkFailure,
+        ErrorCode::HttpServerError,
+        ErrorCode::RateLimited,
+        ErrorCode::AccessDenied,
+        ErrorCode::ParseFailed,
+        ErrorCode::EmptyListPage,
+        ErrorCode::ListCollectFailed,
+        ErrorCode::SiteStatusFailed,
+        ErrorCode::DetailCollectFailed,
+        ErrorCode::ValidationFailed,
+        ErrorCode::PersistenceFailed,
+        ErrorCode::DatabaseError,
+        ErrorCode::ConfigurationError,
+        ErrorCode::UnsupportedStage,
+        ErrorCode::UnexpectedStageItem,
+        ErrorCode::SerializationFailed,
+        ErrorCode::ChannelClosed,
+        ErrorCode::Cancelled,
+        ErrorCode::TimedOut,
+        ErrorCode::ResourceExhausted,
+        ErrorCode::InvalidRequest,
+        ErrorCode::Internal,
+    ];
+
+    /// Stable wire string (e.g. `E_NETWORK_TIMEOUT`)
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::NetworkTimeout => "E_NETWORK_TIMEOUT",
+            ErrorCode::NetworkFailure => "E_NETWORK_FAILURE",
+            ErrorCode::HttpServerError => "E_HTTP_SERVER_ERROR",
+            ErrorCode::RateLimited => "E_RATE_LIMITED",
+            ErrorCode::AccessDenied => "E_ACCESS_DENIED",
+            ErrorCode::ParseFailed => "E_PARSE_FAILED",
+            ErrorCode::EmptyListPage => "E_EMPTY_LIST_PAGE",
+            ErrorCode::ListCollectFailed => "E_LIST_COLLECT_FAILED",
+            ErrorCode::SiteStatusFailed => "E_SITE_STATUS_FAILED",
+            ErrorCode::DetailCollectFailed => "E_DETAIL_COLLECT_FAILED",
+            ErrorCode::ValidationFailed => "E_VALIDATION_FAILED",
+            ErrorCode::PersistenceFailed => "E_PERSISTENCE_FAILED",
+            ErrorCode::DatabaseError => "E_DATABASE",
+            ErrorCode::ConfigurationError => "E_CONFIGURATION",
+            ErrorCode::UnsupportedStage => "E_UNSUPPORTED_STAGE",
+            ErrorCode::UnexpectedStageItem => "E_UNEXPECTED_STAGE_ITEM",
+            ErrorCode::SerializationFailed => "E_SERIALIZATION",
+            ErrorCode::ChannelClosed => "E_CHANNEL_CLOSED",
+            ErrorCode::Cancelled => "E_CANCELLED",
+            ErrorCode::TimedOut => "E_TIMED_OUT",
+            ErrorCode::ResourceExhausted => "E_RESOURCE_EXHAUSTED",
+            ErrorCode::InvalidRequest => "E_INVALID_REQUEST",
+            ErrorCode::Internal => "E_INTERNAL",
+        }
+    }
+
+    /// Accepts the wire string (`E_NETWORK_TIMEOUT`) or the serde name (`NETWORK_TIMEOUT`)
+    pub fn from_code(code: &str) -> Option<ErrorCode> {
+        let code = code.trim();
+        Self::ALL.into_iter().find(|c| {
+            let wire = c.as_str();
+            wire.eq_ignore_ascii_case(code) || wire[2..].eq_ignore_ascii_case(code)
+        })
+    }
+
+    /// Code embedded as a `[E_...]` prefix by `StageLogicError::Coded` / `ErrorCode::tag`
+    pub fn from_tagged_message(message: &str) -> Option<ErrorCode> {
+        let start = message.find("[E_")?;
+        let rest = &message[start + 1..];
+        let end = rest.find(']')?;
+        Self::from_code(&rest[..end])
+    }
+
+    /// Best-effort code for legacy free-form messages
+    pub fn classify_message(message: &str) -> ErrorCode {
+        if let Some(code) = Self::from_tagged_message(message) {
+            return code;
+        }
+        let m = message.to_ascii_lowercase();
+        if m.contains("timeout") || m.contains("timed out") {
+            ErrorCode::NetworkTimeout
+        } else if m.contains("429") || m.contains("rate limit") || m.contains("too many requests") {
+            ErrorCode::RateLimited
+        } else if m.contains("403") || m.contains("forbidden") || m.contains("unauthorized") {
+            ErrorCode::AccessDenied
+        } else if m.contains("status 5") || m.contains("server error") || m.contains(" 503") {
+            ErrorCode::HttpServerError
+        } else if m.contains("connection") || m.contains("dns") || m.contains("network") {
+            ErrorCode::NetworkFailure
+        } else if m.contains("cancel") {
+            ErrorCode::Cancelled
+        } else if m.contains("sqlite") || m.contains("database") || m.contains("db pool") {
+            ErrorCode::DatabaseError
+        } else if m.contains("parse") || m.contains("selector") {
+            ErrorCode::ParseFailed
+        } else if m.contains("channel") {
+            ErrorCode::ChannelClosed
+        } else if m.contains("config") {
+            ErrorCode::ConfigurationError
+        } else {
+            ErrorCode::Internal
+        }
+    }
+
+    /// `[E_CODE] message`, the form `from_tagged_message` recovers
+    pub fn tag(self, message: impl fmt::Display) -> String {
+        format!("[{}] {}", self.as_str(), message)
+    }
+
+    pub fn category(self) -> ErrorCategory {
+        match self {
+            ErrorCode::NetworkTimeout | ErrorCode::NetworkFailure => ErrorCategory::Network,
+            ErrorCode::HttpServerError
+            | ErrorCode::RateLimited
+            | ErrorCode::AccessDenied
+            | ErrorCode::SiteStatusFailed => ErrorCategory::Site,
+            ErrorCode::ParseFailed
+            | ErrorCode::EmptyListPage
+            | ErrorCode::ListCollectFailed
+            | ErrorCode::DetailCollectFailed
+            | ErrorCode::ValidationFailed
+            | ErrorCode::SerializationFailed => ErrorCategory::Data,
+            ErrorCode::PersistenceFailed | ErrorCode::DatabaseError => ErrorCategory::Storage,
+            ErrorCode::ConfigurationError | ErrorCode::UnsupportedStage => {
+                ErrorCategory::Configuration
+            }
+            ErrorCode::UnexpectedStageItem
+            | ErrorCode::ChannelClosed
+            | ErrorCode::Cancelled
+            | ErrorCode::TimedOut
+            | ErrorCode::ResourceExhausted
+            | ErrorCode::Internal => ErrorCategory::Runtime,
+            ErrorCode::InvalidRequest => ErrorCategory::Request,
+        }
+    }
+
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            ErrorCode::NetworkTimeout
+                | ErrorCode::NetworkFailure
+                | ErrorCode::HttpServerError
+                | ErrorCode::RateLimited
+                | ErrorCode::EmptyListPage
+                | ErrorCode::ListCollectFailed
+                | ErrorCode::SiteStatusFailed
+                | ErrorCode::DetailCollectFailed
+                | ErrorCode::TimedOut
+                | ErrorCode::ResourceExhausted
+        )
+    }
+
+    fn title_and_hints(self) -> (&'static str, &'static [&'static str]) {
+        match self {
+            ErrorCode::NetworkTimeout => (
+                "Request timed out",
+                &[
+                    "Check the network connection to csa-iot.org.",
+                    "Lower list/detail concurrency or raise request_timeout_seconds.",
+                ],
+            ),
+            ErrorCode::NetworkFailure => (
+                "Network request failed",
+                &[
+                    "Check connectivity, proxy and DNS settings.",
+                    "Retry the failed pages once the connection is stable.",
+                ],
+            ),
+            ErrorCode::HttpServerError => (
+                "Site returned a server error",
+                &[
+                    "The site may be under maintenance; retry later.",
+                    "Use retry_failed_details or a partial sync for the affected range.",
+                ],
+            ),
+            ErrorCode::RateLimited => (
+                "Rate limited by the site",
+                &[
+                    "Increase request_delay_ms and reduce concurrency.",
+                    "Wait for the Retry-After period before resuming.",
+                ],
+            ),
+            ErrorCode::AccessDenied => (
+                "Access denied by the site",
+                &[
+                    "Check the user agent and request headers.",
+                    "Pause crawling; repeated 403s may indicate a block.",
+                ],
+            ),
+            ErrorCode::ParseFailed => (
+                "Page could not be parsed",
+                &[
+                    "The site layout may have changed; check the site profile selectors.",
+                    "Consider the sitemap list collection strategy as a fallback.",
+                ],
+            ),
+            ErrorCode::EmptyListPage => (
+                "List page had no products",
+                &[
+                    "Re-check the total page count; the page may be past the end.",
+                    "If every page is empty, the list selectors likely need updating.",
+                ],
+            ),
+            ErrorCode::ListCollectFailed => (
+                "List page collection failed",
+                &[
+                    "Retry the page range with a partial sync.",
+                    "Inspect the underlying network or parse error in the log.",
+                ],
+            ),
+            ErrorCode::SiteStatusFailed => (
+                "Site status check failed",
+                &[
+                    "Verify the site is reachable in a browser.",
+                    "Retry the crawl; planning needs a successful status check.",
+                ],
+            ),
+            ErrorCode::DetailCollectFailed => (
+                "Product detail collection failed",
+                &[
+                    "Run retry_failed_details for the affected URLs.",
+                    "Inspect the underlying network or parse error in the log.",
+                ],
+            ),
+            ErrorCode::ValidationFailed => (
+                "Collected data failed validation",
+                &[
+                    "Review the data quality report for the batch.",
+                    "Re-crawl the affected pages if fields are missing.",
+                ],
+            ),
+            ErrorCode::PersistenceFailed => (
+                "Saving products failed",
+                &[
+                    "Check free disk space and database file permissions.",
+                    "Run the DB diagnostics and repair commands.",
+                ],
+            ),
+            ErrorCode::DatabaseError => (
+                "Database error",
+                &[
+                    "Make sure no other process holds the database locked.",
+                    "Run the DB diagnostics commands; restore from backup if corrupt.",
+                ],
+            ),
+            ErrorCode::ConfigurationError => (
+                "Invalid configuration",
+                &["Review the settings file and reset invalid values to defaults."],
+            ),
+            ErrorCode::UnsupportedStage => (
+                "Stage has no strategy",
+                &["Check that the stage strategy factory registers this stage type."],
+            ),
+            ErrorCode::UnexpectedStageItem => (
+                "Stage received the wrong item type",
+                &["This is a pipeline wiring bug; report it with the session log."],
+            ),
+            ErrorCode::SerializationFailed => (
+                "Serialization failed",
+                &["This is an internal data bug; report it with the session log."],
+            ),
+            ErrorCode::ChannelClosed => (
+                "Actor channel closed",
+                &["The session was likely shut down; start a new session."],
+            ),
+            ErrorCode::Cancelled => (
+                "Cancelled",
+                &["The work was cancelled by the user or a shutdown; no action needed."],
+            ),
+            ErrorCode::TimedOut => (
+                "Command timed out",
+                &[
+                    "Narrow the query (page range, filters) or raise timeout_ms.",
+                    "Retry when no crawl or sync is competing for the database.",
+                ],
+            ),
+            ErrorCode::ResourceExhausted => (
+                "Resources exhausted",
+                &[
+                    "Lower batch size and concurrency.",
+                    "Close other heavy applications and retry.",
+                ],
+            ),
+            ErrorCode::InvalidRequest => (
+                "Invalid request",
+                &["Check the command arguments (page ranges, flags, paths) and try again."],
+            ),
+            ErrorCode::Internal => (
+                "Internal error",
+                &["Check the log for details and report the session id."],
+            ),
+        }
+    }
+
+    pub fn describe(self) -> ErrorDescription {
+        let (title, hints) = self.title_and_hints();
+        ErrorDescription {
+            code: self,
+            category: self.category(),
+            title: title.to_string(),
+            retryable: self.is_retryable(),
+            remediation: hints.iter().map(|h| h.to_string()).collect(),
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wire_codes_are_unique_and_round_trip() {
+        let mut seen = std::collections::HashSet::new();
+        for code in ErrorCode::ALL {
+            assert!(seen.insert(code.as_str()), "duplicate {}", code);
+            assert_eq!(ErrorCode::from_code(code.as_str()), Some(code));
+            assert!(!code.describe().remediation.is_empty());
+        }
+        assert_eq!(
+            ErrorCode::from_code("rate_limited"),
+            Some(ErrorCode::RateLimited)
+        );
+        assert_eq!(ErrorCode::from_code("E_NOPE"), None);
+    }
+
+    #[test]
+    fn tagged_messages_win_over_heuristics() {
+        let msg = ErrorCode::ListCollectFailed.tag("timeout while fetching page 3");
+        assert_eq!(
+            ErrorCode::classify_message(&format!("Strategy error: {msg}")),
+            ErrorCode::ListCollectFailed
+        );
+        assert_eq!(
+            ErrorCode::classify_message("operation timed out"),
+            ErrorCode::NetworkTimeout
+        );
+        assert_eq!(
+            ErrorCode::classify_message("HTTP 429 Too Many Requests"),
+            ErrorCode::RateLimited
+        );
+        assert_eq!(ErrorCode::classify_message("boom"), ErrorCode::Internal);
+    }
+}
@@ -85,6 +85,18 @@ pub struct ProductSearchCriteria {
     pub limit: Option<i32>,
 }
 
+/// Filter for bulk export of products joined with product_details.
+/// Ranges are inclusive; certification dates compare as stored strings (YYYY-MM-DD).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProductExportFilter {
+    pub page_id_min: Option<i32>,
+    pub page_id_max: Option<i32>,
+    pub manufacturer: Option<String>,
+    pub device_type: Option<String>,
+    pub certification_date_from: Option<String>,
+    pub certification_date_to: Option<String>,
+}
+
 /// Search results with pagination
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProductSearchResult {
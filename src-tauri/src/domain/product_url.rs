@@ -1,6 +1,68 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use ts_rs::TS;
+use url::Url;
+
+/// Query parameters added by trackers/campaign links; never part of a product's identity
+const TRACKING_PARAMS: &[&str] = &[
+    "fbclid", "gclid", "dclid", "gbraid", "wbraid", "msclkid", "yclid", "igshid", "mc_cid",
+    "mc_eid", "_ga", "_gl", "ref", "ref_src",
+];
+
+fn is_tracking_param(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    name.starts_with("utm_") || TRACKING_PARAMS.contains(&name.as_str())
+}
+
+/// Canonical form of a product URL, applied on every write path so the same product never gets
+/// a second row from a cosmetic URL variant:
+/// - trims whitespace, lowercases the host, drops the fragment
+/// - removes tracking query parameters (`utm_*`, `fbclid`, ...); other parameters keep their order
+/// - path ends with exactly one `/` (as on the certification site), unless the last segment
+///   looks like a file (`.pdf`, ...)
+///
+/// Strings that do not parse as URLs are only trimmed.
+pub fn canonical_product_url(url: &str) -> String {
+    let trimmed = url.trim();
+    let Ok(mut parsed) = Url::parse(trimmed) else {
+        return trimmed.to_string();
+    };
+    if let Some(host) = parsed.host_str() {
+        let lower = host.to_ascii_lowercase();
+        if lower != host {
+            let _ = parsed.set_host(Some(&lower));
+        }
+    }
+    parsed.set_fragment(None);
+
+    if parsed.query().is_some() {
+        let pairs: Vec<(String, String)> = parsed
+            .query_pairs()
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect();
+        let kept: Vec<&(String, String)> = pairs
+            .iter()
+            .filter(|(k, _)| !is_tracking_param(k))
+            .collect();
+        if kept.is_empty() {
+            parsed.set_query(None);
+        } else if kept.len() < pairs.len() {
+            parsed.query_pairs_mut().clear().extend_pairs(kept);
+        }
+    }
+
+    if !parsed.cannot_be_a_base() {
+        let path = parsed.path().trim_end_matches('/').to_string();
+        let is_file = path
+            .rsplit('/')
+            .next()
+            .is_some_and(|last| last.contains('.'));
+        if !is_file {
+            parsed.set_path(&format!("{path}/"));
+        }
+    }
+    parsed.to_string()
+}
 
 /// URL과 함께 페이지 위치 정보를 담는 구조체
 /// ProductListCollector에서 ProductDetailCollector로 메타데이터를 전달하기 위해 사용
@@ -34,6 +96,12 @@ impl ProductUrl {
     pub fn get_position(&self) -> (i32, i32) {
         (self.page_id, self.index_in_page)
     }
+
+    /// URL을 저장용 정규형으로 바꾼 ProductUrl (`canonical_product_url`)
+    pub fn canonicalized(mut self) -> Self {
+        self.url = canonical_product_url(&self.url);
+        self
+    }
 }
 
 impl From<ProductUrl> for String {
@@ -57,3 +125,32 @@ impl fmt::Display for ProductUrl {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_url_normalizes_cosmetic_variants() {
+        let canonical = "https://csa-iot.org/csa_product/wi-fi-plug-27/";
+        for variant in [
+            "https://csa-iot.org/csa_product/wi-fi-plug-27/",
+            "https://csa-iot.org/csa_product/wi-fi-plug-27",
+            " https://CSA-IoT.org/csa_product/wi-fi-plug-27// ",
+            "https://csa-iot.org/csa_product/wi-fi-plug-27/?utm_source=x&fbclid=y#specs",
+        ] {
+            assert_eq!(canonical_product_url(variant), canonical, "{variant}");
+        }
+        // Path case and meaningful parameters are kept
+        assert_eq!(
+            canonical_product_url("https://x.test/P/Lamp?lang=ko&utm_medium=mail&v=2"),
+            "https://x.test/P/Lamp/?lang=ko&v=2"
+        );
+        assert_eq!(
+            canonical_product_url("https://x.test/docs/cert.pdf"),
+            "https://x.test/docs/cert.pdf"
+        );
+        assert_eq!(canonical_product_url("https://x.test"), "https://x.test/");
+        assert_eq!(canonical_product_url(" not a url "), "not a url");
+    }
+}
@@ -5,9 +5,10 @@
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::domain::product::Product;
+use crate::domain::product::{Product, ProductDetail};
 
 /// 중복 제거 서비스
 #[async_trait]
@@ -20,6 +21,12 @@ pub trait DeduplicationService: Send + Sync {
 
     /// 중복 검사 (단일 제품)
     async fn is_duplicate(&self, product: &Product, existing: &[Product]) -> Result<bool>;
+
+    /// 식별 키 기준 중복 그룹 (URL 이 달라도 certificate_id 또는 vid/pid/model 이 같으면 같은 제품)
+    async fn analyze_identity_duplicates(
+        &self,
+        records: &[IdentityRecord],
+    ) -> Result<Vec<IdentityDuplicateGroup>>;
 }
 
 /// 데이터 유효성 검사 서비스
@@ -147,6 +154,93 @@ pub enum DuplicationType {
     SimilarCertificationId,
 }
 
+/// 식별 키 분석 입력 (products / product_details 한 행)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdentityRecord {
+    pub url: String,
+    pub certificate_id: Option<String>,
+    pub vid: Option<i32>,
+    pub pid: Option<i32>,
+    pub model: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&Product> for IdentityRecord {
+    fn from(product: &Product) -> Self {
+        Self {
+            url: product.url.clone(),
+            certificate_id: product.certificate_id.clone(),
+            vid: None,
+            pid: None,
+            model: product.model.clone(),
+            updated_at: product.updated_at,
+        }
+    }
+}
+
+impl From<&ProductDetail> for IdentityRecord {
+    fn from(detail: &ProductDetail) -> Self {
+        Self {
+            url: detail.url.clone(),
+            certificate_id: detail.certificate_id.clone(),
+            vid: detail.vid,
+            pid: detail.pid,
+            model: detail.model.clone(),
+            updated_at: detail.updated_at,
+        }
+    }
+}
+
+impl IdentityRecord {
+    /// 정규화된 식별 키 (trim, model 은 소문자; 빈 값은 키가 아님)
+    pub fn identity_keys(&self) -> Vec<IdentityKey> {
+        let mut keys = Vec::new();
+        let certificate_id = self.certificate_id.as_deref().map(str::trim);
+        if let Some(certificate_id) = certificate_id.filter(|c| !c.is_empty()) {
+            keys.push(IdentityKey::CertificateId {
+                certificate_id: certificate_id.to_string(),
+            });
+        }
+        let model = self.model.as_deref().map(|m| m.trim().to_lowercase());
+        if let (Some(vid), Some(pid), Some(model)) =
+            (self.vid, self.pid, model.filter(|m| !m.is_empty()))
+        {
+            keys.push(IdentityKey::VidPidModel { vid, pid, model });
+        }
+        keys
+    }
+}
+
+/// 제품 식별 키
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum IdentityKey {
+    CertificateId { certificate_id: String },
+    VidPidModel { vid: i32, pid: i32, model: String },
+}
+
+/// 식별 키를 공유하는 URL 그룹
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdentityDuplicateGroup {
+    /// 그룹 안의 두 URL 이상이 공유하는 키
+    pub keys: Vec<IdentityKey>,
+    /// 최근 갱신 순; 병합 시 첫 URL 이 남는다
+    pub urls: Vec<String>,
+    /// 그룹에 나타난 서로 다른 certificate_id (2개 이상이면 수동 확인 대상)
+    pub certificate_ids: Vec<String>,
+}
+
+impl IdentityDuplicateGroup {
+    pub fn keeper(&self) -> &str {
+        &self.urls[0]
+    }
+
+    /// vid/pid/model 로 묶였지만 certificate_id 가 서로 다른 그룹
+    pub fn has_conflicting_certificates(&self) -> bool {
+        self.certificate_ids.len() > 1
+    }
+}
+
 /// 유효성 검사 결과
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationResult {
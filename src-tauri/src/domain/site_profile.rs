@@ -0,0 +1,74 @@
+//! 크롤링 대상 사이트 프로필
+//!
+//! 인증 데이터베이스 사이트마다 다른 URL 구조, 목록/상세 셀렉터, 페이지당 제품 수를 한 곳에 모은다.
+//! 추출기(`MatterDataExtractor`), 상태 확인(`StatusCheckerImpl`), sync 커맨드는 이 트레이트만 보고
+//! 동작하므로, 새 사이트는 구현체 하나(`infrastructure::site_profiles`)를 추가해 지원한다.
+
+use std::fmt::Debug;
+
+/// Selectors used on paginated listing pages
+#[derive(Debug, Clone, Copy)]
+pub struct ListPageSelectors {
+    /// Product item containers; the first is primary, the rest are tried when a page comes up short
+    pub item_containers: &'static [&'static str],
+    /// Anchor inside an item container that points at the product page
+    pub product_link: &'static str,
+    pub manufacturer: &'static str,
+    pub model: &'static str,
+    /// Certificate id elements, tried in order
+    pub certificate_id: &'static [&'static str],
+    /// Label prefix stripped from certificate id text (e.g. "Certificate ID: ")
+    pub certificate_id_label: &'static str,
+    /// Pagination links whose `page=` parameter or text carries page numbers
+    pub pagination_links: &'static [&'static str],
+    /// Elements holding "Page X of Y" text
+    pub page_info: &'static [&'static str],
+}
+
+/// Selectors used on product detail pages
+#[derive(Debug, Clone, Copy)]
+pub struct DetailPageSelectors {
+    pub model: &'static str,
+    pub manufacturer: &'static str,
+    pub device_type: &'static str,
+    pub certificate_id: &'static str,
+    pub certification_date: &'static str,
+    /// Two-column key/value table
+    pub info_table: &'static str,
+    /// Label/value list items (`item_label` / `item_value` children)
+    pub detail_items: &'static str,
+    pub item_label: &'static str,
+    pub item_value: &'static str,
+    /// Older layout: "Label: value" list items
+    pub legacy_detail_items: &'static str,
+}
+
+/// One certification database site
+pub trait SiteProfile: Debug + Send + Sync {
+    /// Stable identifier used in config (`advanced.site_profile`)
+    fn id(&self) -> &'static str;
+
+    /// Origin used to resolve relative links
+    fn base_url(&self) -> &str;
+
+    /// Listing root, sent as referer for list and detail requests
+    fn listing_base_url(&self) -> &str;
+
+    /// Physical listing page URL (1 = newest)
+    fn list_page_url(&self, page: u32) -> String;
+
+    /// Items on every non-terminal listing page
+    fn products_per_page(&self) -> u32;
+
+    /// True for links that lead to a product detail page
+    fn is_product_url(&self, url: &str) -> bool;
+
+    /// True when `url` is a listing page rather than a product page
+    fn is_listing_url(&self, url: &str) -> bool {
+        !self.is_product_url(url)
+    }
+
+    fn list_selectors(&self) -> &ListPageSelectors;
+
+    fn detail_selectors(&self) -> &DetailPageSelectors;
+}
@@ -0,0 +1,242 @@
+//! 싱크 경고 코드 카탈로그 (`SyncWarning.code`)
+//!
+//! 경고 코드는 발행 지점마다 문자열 리터럴로 흩어져 있어 의미와 심각도를 알 수 없었다. 모든
+//! 코드는 `warning_codes!` 한 곳에 와이어 문자열 / 심각도 / 제목 / 설명과 함께 선언되므로,
+//! 메타데이터 없이 새 코드를 추가하면 컴파일되지 않는다. 와이어 문자열은 기존 값 그대로다.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use ts_rs::TS;
+
+/// How much attention a warning needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum WarningSeverity {
+    /// Progress / housekeeping notice; nothing was lost
+    Info,
+    /// Data may be incomplete until a later pass or sync repairs it
+    Warning,
+    /// A write failed; the affected rows were not saved
+    Error,
+}
+
+/// Catalog entry returned by `describe_warning`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarningDescription {
+    pub code: WarningCode,
+    pub severity: WarningSeverity,
+    pub title: String,
+    pub documentation: String,
+}
+
+/// Declares `WarningCode` with its wire string and metadata; every entry needs all four
+macro_rules! warning_codes {
+    ($($variant:ident = $wire:literal, $severity:ident, $title:literal, $doc:literal;)+) => {
+        /// Machine-readable `SyncWarning` code
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+        #[ts(export)]
+        pub enum WarningCode {
+            $(
+                #[serde(rename = $wire)]
+                $variant,
+            )+
+        }
+
+        impl WarningCode {
+            pub const ALL: &'static [WarningCode] = &[$(WarningCode::$variant),+];
+
+            /// Stable wire string (e.g. `count_mismatch`)
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(WarningCode::$variant => $wire,)+
+                }
+            }
+
+            pub fn severity(self) -> WarningSeverity {
+                match self {
+                    $(WarningCode::$variant => WarningSeverity::$severity,)+
+                }
+            }
+
+            fn title_and_doc(self) -> (&'static str, &'static str) {
+                match self {
+                    $(WarningCode::$variant => ($title, $doc),)+
+                }
+            }
+        }
+    };
+}
+
+warning_codes! {
+    PageRolledBack = "page_rolled_back", Info,
+        "Page rolled back on cancel",
+        "The session was cancelled mid-page; that page's transaction was rolled back and \
+         nothing from it was written. Resume or re-run the range to sync it.";
+    FailedPagesRetry = "failed_pages_retry", Info,
+        "Retrying failed pages",
+        "Pages that failed outright are queued for another end-of-session pass. The final \
+         SyncCompleted reports which of them recovered.";
+    SlotDisplaced = "slot_displaced", Warning,
+        "Coordinate slot taken over",
+        "Another URL held this (page_id, index_in_page) from an earlier sync; its coordinates \
+         were cleared so the current URL could take the slot.";
+    SlotCollision = "slot_collision", Warning,
+        "Coordinate slot collision",
+        "Two URLs claimed the same (page_id, index_in_page) in one session. The later one was \
+         skipped; run validation to find which placement is correct.";
+    SlotUrlElsewhere = "slot_url_elsewhere", Warning,
+        "URL already placed elsewhere",
+        "The URL was already given other coordinates in this session, usually because the \
+         listing shifted while syncing. Its second placement was skipped.";
+    SlotReserveFailed = "slot_reserve_failed", Error,
+        "Slot reservation failed",
+        "The coordinate reservation could not be recorded, so the coordinates were left \
+         untouched. Check the database for lock or disk errors.";
+    CountMismatch = "count_mismatch", Warning,
+        "Unexpected product count on page",
+        "A list page yielded a different number of products than the page layout implies \
+         after retries. The page may be partially synced; resync it later.";
+    PageIncompleteAfterRetries = "page_incomplete_after_retries", Warning,
+        "Page incomplete after retries",
+        "List page extraction kept failing until the retry limit or budget ran out; whatever \
+         was extracted was used.";
+    TxBeginFailed = "tx_begin_failed", Error,
+        "Page transaction could not start",
+        "The database refused a new transaction (locked or unavailable); the page was not \
+         written. It is retried in the failed-page pass.";
+    TxCommitFailed = "tx_commit_failed", Error,
+        "Page commit failed",
+        "The page's writes were rolled back at commit. The page is queued for revalidation in \
+         the next sync.";
+    SelectFailed = "select_failed", Error,
+        "Product lookup failed",
+        "Reading the existing row for a URL failed, so that product was skipped on this page.";
+    InsertFailed = "insert_failed", Error,
+        "Product insert failed",
+        "A new product row could not be inserted; the URL is picked up again on the next sync \
+         of its page.";
+    UpdateFailed = "update_failed", Error,
+        "Product update failed",
+        "Updating an existing product's coordinates failed; the old values are kept.";
+    ObservedRecordFailed = "observed_record_failed", Warning,
+        "Observation not recorded",
+        "The per-session observation used for sweeping stale rows could not be written. The \
+         product itself was still synced, but the sweep may keep a stale row.";
+    InvalidCoordinates = "invalid_coordinates", Warning,
+        "Invalid coordinates skipped",
+        "The computed (page_id, index_in_page) was out of range, so the URL was skipped. \
+         Check the site's total page count and last-page size.";
+    DetailsFetchFailed = "details_fetch_failed", Warning,
+        "Detail page fetch failed",
+        "A product detail request failed (network or HTTP error). The product keeps its list \
+         data; use retry_failed_details to fetch it again.";
+    DetailsReadFailed = "details_read_failed", Warning,
+        "Detail page body unreadable",
+        "The detail response arrived but its body could not be read. Retry the URL later.";
+    DetailsExtractFailed = "details_extract_failed", Warning,
+        "Detail extraction failed",
+        "The detail page did not parse into product fields; the site layout may have changed.";
+    DetailsInsertFailed = "details_insert_failed", Error,
+        "Detail insert failed",
+        "A product_details row could not be inserted; the product has no details until the \
+         next sync or detail retry.";
+    DetailsUpdateFailed = "details_update_failed", Error,
+        "Detail update failed",
+        "Merging fresh detail fields into product_details failed; the previous values are kept.";
+    DbOnlyPlaceholderFailed = "db_only_placeholder_failed", Error,
+        "DB-only placeholder failed",
+        "Creating placeholder detail rows for a DB-only page failed; that page's products stay \
+         without detail rows.";
+    DbOnlyBackfillFailed = "db_only_backfill_failed", Error,
+        "DB-only backfill failed",
+        "Copying product core fields into detail rows for a DB-only page failed.";
+    DbOnlyProductsIdBackfillFailed = "db_only_products_id_backfill_failed", Error,
+        "DB-only id backfill failed",
+        "Filling products.id for a DB-only page failed; ids are filled by the global sweep at \
+         the end of the session.";
+    DbOnlyBackfillMetrics = "db_only_backfill_metrics", Info,
+        "DB-only backfill summary",
+        "Row counts of the DB-only placeholder / backfill steps for one page (JSON in detail).";
+    InRangeRetryQueryFailed = "in_range_retry_query_failed", Error,
+        "In-range retry lookup failed",
+        "Looking up products of a page that still lack details failed, so their detail retry \
+         was skipped for this session.";
+    GlobalProductsIdBackfillSweep = "global_products_id_backfill_sweep", Info,
+        "products.id backfill sweep",
+        "End-of-session sweep that fills missing products.id values; detail holds the row count.";
+    GlobalProductsIdBackfillFailed = "global_products_id_backfill_failed", Error,
+        "products.id backfill sweep failed",
+        "The end-of-session products.id sweep failed; missing ids are retried by the next sync.";
+    SweepFailed = "sweep_failed", Error,
+        "Stale row sweep failed",
+        "Deleting rows that were not observed in the synced range failed; stale rows may remain \
+         until the next sync of that range.";
+    PagesRevalidated = "pages_revalidated", Info,
+        "Revalidation pass finished",
+        "Pages queued after earlier commit failures were synced again; detail reports how many \
+         were repaired, are still pending, or gave up.";
+}
+
+impl WarningCode {
+    /// Accepts the wire string, case-insensitively
+    pub fn from_code(code: &str) -> Option<WarningCode> {
+        let code = code.trim();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|c| c.as_str().eq_ignore_ascii_case(code))
+    }
+
+    pub fn describe(self) -> WarningDescription {
+        let (title, documentation) = self.title_and_doc();
+        WarningDescription {
+            code: self,
+            severity: self.severity(),
+            title: title.to_string(),
+            documentation: documentation.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for WarningCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// `SyncWarning.code` stays a string on the wire
+impl From<WarningCode> for String {
+    fn from(code: WarningCode) -> Self {
+        code.as_str().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wire_codes_are_unique_documented_and_match_serde() {
+        let mut seen = std::collections::HashSet::new();
+        for &code in WarningCode::ALL {
+            assert!(seen.insert(code.as_str()), "duplicate {}", code);
+            assert_eq!(WarningCode::from_code(code.as_str()), Some(code));
+            assert_eq!(
+                serde_json::to_string(&code).unwrap(),
+                format!("\"{}\"", code.as_str())
+            );
+            let description = code.describe();
+            assert!(!description.title.is_empty() && !description.documentation.is_empty());
+        }
+        assert_eq!(
+            WarningCode::from_code("COUNT_MISMATCH"),
+            Some(WarningCode::CountMismatch)
+        );
+        assert_eq!(WarningCode::from_code("nope"), None);
+        assert_eq!(
+            WarningCode::TxCommitFailed.severity(),
+            WarningSeverity::Error
+        );
+    }
+}
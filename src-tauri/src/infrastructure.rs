@@ -4,23 +4,53 @@
 //! web crawling, and external service integrations following the guide's architecture.
 
 pub mod advanced_crawling_engine; // Phase 2 advanced crawling engine with data pipeline
+pub mod circuit_breaker; // Per-host circuit breaker shared by all HTTP consumers
 pub mod config; // Configuration constants and helpers
+pub mod coordinate_guard; // Strict (page_id, index_in_page) invariant enforcement
 // pub mod crawling; // Web crawler implementation (deprecated)
 pub mod crawling_engine; // 4-stage batch crawling engine
 pub mod crawling_service_impls; // Service implementations
 pub mod data_processing_service_impls; // Data processing service implementations
+pub mod postgres_check; // Postgres connectivity check / migrations
 pub mod database_connection;
 pub mod database_paths; // 중앙집중식 데이터베이스 경로 관리 (Modern Rust 2024)
+pub mod db_maintenance; // 삭제 후 freelist 추적 및 vacuum 자동화
+pub mod detail_fetch_order; // Detail request ordering within a batch (sequential / shuffled / interleaved)
+pub mod event_sink; // Event destinations (Tauri window / log / null) for shell-independent cores
+#[cfg(feature = "event-stream")]
+pub mod event_stream; // Local SSE endpoint mirroring frontend events (headless monitoring)
+pub mod extractor_rollout; // Shadow comparison of new extraction rules before promotion
 pub mod features;
+#[cfg(feature = "ipc-server")]
+pub mod ipc_server; // Local JSON-RPC socket / named pipe for companion tools
+pub mod host_profile; // Host capability probe → concurrency recommendations
+pub mod html_cache; // Disk-backed HTML cache revalidated with ETag / Last-Modified
 pub mod html_parser; // HTML parser with integrated tests
 pub mod integrated_product_repository;
 pub mod logging; // Logging infrastructure
+pub mod memory_budget; // Uncommitted-row memory budget + process/handoff memory stats
+#[cfg(feature = "metrics-exporter")]
+pub mod metrics_exporter; // Local Prometheus scrape endpoint (headless monitoring)
+#[cfg(any(test, feature = "mock-site"))]
+pub mod mock_http_client; // Canned-fixture transport for simulated crawls (mock-site feature)
 pub mod parsing; // Modern parsing architecture following the guide
 pub mod parsing_error; // Enhanced error types
+pub mod persistence_queue; // Write-behind batching queue for ProductDetail upserts
+pub mod postgres_product_repository; // Core product upserts/reads on Postgres
+pub mod product_identity; // Upsert identity strategy (URL / certificate_id) with row move/merge helpers
+pub mod proxy_pool; // Proxy rotation with per-proxy health tracking
+pub mod query_control; // Timeout / cancellation options shared by long-running query commands
+pub mod rate_limiter; // Process-wide per-host token bucket shared by all HTTP consumers
+pub mod read_snapshot; // Shared read transaction refreshed at page-write boundaries (UI grids)
+pub mod robots; // Per-origin robots.txt rule cache with skip/override records
 pub mod retry_manager; // 재시도 관리자 - INTEGRATED_PHASE2_PLAN Week 1 Day 3-4
 pub mod service_based_crawling_engine; // Deprecated legacy engine (kept for compatibility; not used in prod commands)
+pub mod session_log; // Per-session JSONL capture of session-tagged tracing events
+pub mod session_spill; // Per-session temp storage for spilled payloads (quota + cleanup)
 pub mod simple_http_client;
+pub mod site_profiles; // SiteProfile implementations (csa-iot) + id lookup
 pub mod system_broadcaster; // 실시간 시스템 상태 브로드캐스터 // Feature flags for phased rollout
+pub mod xlsx_writer; // Minimal streaming single-sheet XLSX writer for exports
 
 // Temporarily disabled - working on schema compatibility
 // pub mod product_repository;
@@ -0,0 +1,673 @@
+//! Per-host circuit breaker for the target site.
+//!
+//! `HttpClient` asks the shared breaker before every send and reports the outcome afterwards.
+//! After `failure_threshold` consecutive failures (5xx, 408, timeouts, connection errors) a
+//! host's circuit opens and requests to it fail fast with `CircuitOpenError` instead of
+//! hammering the site. Once `open_secs` pass the circuit goes half-open and lets a single
+//! probe request through: success closes it, failure re-opens it with the wait doubled (capped
+//! at `max_open_secs`).
+//!
+//! Transitions are published as `SiteCircuitOpened` / `SiteCircuitClosed`. With
+//! `pause_sessions`, running sessions are paused through the session registry's pause channel
+//! (the same path as the `pause_session` command) and only the sessions the breaker paused are
+//! resumed when the circuit closes.
+//!
+//! With `persist_state`, every open/re-open/close rewrites `circuit_state.json` in the app data
+//! directory with wall-clock probe times and the current backoff wait. `restore_persisted_state`
+//! loads it at startup, so a restart during a hostile period keeps the remaining cool-down (and
+//! a failed probe keeps doubling from where it was) instead of hitting the site again at once.
+//! Cool-downs that ended more than `max_open_secs` ago are dropped.
+
+use crate::crawl_engine::actors::types::AppEvent;
+use crate::crawl_engine::runtime::session_registry::{SessionStatus, session_registry};
+use crate::infrastructure::config::{CircuitBreakerConfig, ConfigManager};
+use crate::infrastructure::event_sink::EventSink;
+use crate::infrastructure::rate_limiter::host_key;
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+/// Circuit state file in the app data directory
+pub const STATE_FILE_NAME: &str = "circuit_state.json";
+
+/// Request refused because the host's circuit is open
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Circuit open for {host}; next probe in {}s", .retry_after.as_secs())]
+pub struct CircuitOpenError {
+    pub host: String,
+    pub retry_after: Duration,
+}
+
+/// State change the caller should publish
+#[derive(Debug, Clone, PartialEq)]
+pub enum CircuitTransition {
+    Opened {
+        host: String,
+        consecutive_failures: u32,
+        retry_after: Duration,
+        last_error: Option<String>,
+    },
+    Closed {
+        host: String,
+        open_for: Duration,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CircuitState {
+    Closed {
+        failures: u32,
+    },
+    Open {
+        until: Instant,
+        wait: Duration,
+    },
+    /// One probe is in flight; another is allowed if it never reports back within `wait`
+    HalfOpen {
+        probe_started: Instant,
+        wait: Duration,
+    },
+}
+
+#[derive(Debug)]
+struct HostCircuit {
+    state: CircuitState,
+    opened_at: Option<Instant>,
+}
+
+impl Default for HostCircuit {
+    fn default() -> Self {
+        Self {
+            state: CircuitState::Closed { failures: 0 },
+            opened_at: None,
+        }
+    }
+}
+
+/// A non-closed circuit as saved across restarts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PersistedCircuit {
+    pub host: String,
+    /// Earliest time for the next half-open probe
+    pub probe_after: DateTime<Utc>,
+    /// Current backoff wait; a failed probe doubles it
+    pub wait_secs: u64,
+    pub opened_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedState {
+    saved_at: DateTime<Utc>,
+    circuits: Vec<PersistedCircuit>,
+}
+
+#[derive(Debug)]
+pub struct SiteCircuitBreaker {
+    config: RwLock<CircuitBreakerConfig>,
+    hosts: Mutex<HashMap<String, HostCircuit>>,
+    /// Set when a circuit opens, re-opens or closes; cleared when persisted
+    changed: AtomicBool,
+}
+
+static SHARED_CIRCUIT_BREAKER: OnceLock<Arc<SiteCircuitBreaker>> = OnceLock::new();
+static EVENT_SINK: RwLock<Option<Arc<dyn EventSink>>> = RwLock::new(None);
+/// Where transitions are persisted (set by `restore_persisted_state`)
+static STATE_FILE: RwLock<Option<PathBuf>> = RwLock::new(None);
+/// Serializes snapshot + write so the file always holds the latest state
+static SAVE_LOCK: Mutex<()> = Mutex::new(());
+/// Sessions paused by an open circuit, per host
+static PAUSED_BY_BREAKER: Mutex<Vec<(String, Vec<String>)>> = Mutex::new(Vec::new());
+
+/// Shared breaker used by every `HttpClient` in the process
+pub fn shared_circuit_breaker() -> Arc<SiteCircuitBreaker> {
+    SHARED_CIRCUIT_BREAKER
+        .get_or_init(|| Arc::new(SiteCircuitBreaker::new(CircuitBreakerConfig::default())))
+        .clone()
+}
+
+/// Apply `advanced.circuit_breaker` to the shared breaker (AppState init / config updates)
+pub fn configure(config: &CircuitBreakerConfig) {
+    shared_circuit_breaker().set_config(config.clone());
+}
+
+/// Where `SiteCircuitOpened` / `SiteCircuitClosed` go (set once the app handle exists)
+pub fn set_event_sink(sink: Arc<dyn EventSink>) {
+    if let Ok(mut guard) = EVENT_SINK.write() {
+        *guard = Some(sink);
+    }
+}
+
+/// Restore circuits saved by the previous run and persist transitions from now on (app startup)
+pub fn restore_persisted_state() {
+    let breaker = shared_circuit_breaker();
+    if !breaker.config().persist_state {
+        return;
+    }
+    let path = match ConfigManager::get_app_data_dir() {
+        Ok(dir) => dir.join(STATE_FILE_NAME),
+        Err(e) => {
+            warn!("🔌 [circuit] state not persisted (no app data dir): {}", e);
+            return;
+        }
+    };
+    match load_state(&path) {
+        Ok(circuits) => {
+            let restored = breaker.restore_at(circuits, Instant::now(), Utc::now());
+            if restored > 0 {
+                warn!(
+                    "🔌 [circuit] {} circuit(s) still cooling down from the previous run",
+                    restored
+                );
+            }
+        }
+        Err(e) => warn!("🔌 [circuit] ignoring unreadable {}: {}", path.display(), e),
+    }
+    if let Ok(mut guard) = STATE_FILE.write() {
+        *guard = Some(path);
+    }
+}
+
+fn load_state(path: &Path) -> anyhow::Result<Vec<PersistedCircuit>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let state: PersistedState = serde_json::from_slice(&std::fs::read(path)?)?;
+    Ok(state.circuits)
+}
+
+fn save_state(path: &Path, circuits: &[PersistedCircuit]) -> anyhow::Result<()> {
+    let state = PersistedState {
+        saved_at: Utc::now(),
+        circuits: circuits.to_vec(),
+    };
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_vec_pretty(&state)?)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Statuses that count as the site failing (rate limiting and 4xx do not)
+pub fn is_failure_status(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::REQUEST_TIMEOUT
+}
+
+impl SiteCircuitBreaker {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config: RwLock::new(config),
+            hosts: Mutex::new(HashMap::new()),
+            changed: AtomicBool::new(false),
+        }
+    }
+
+    pub fn set_config(&self, config: CircuitBreakerConfig) {
+        if let Ok(mut guard) = self.config.write() {
+            *guard = config;
+        }
+    }
+
+    fn config(&self) -> CircuitBreakerConfig {
+        self.config.read().map(|c| c.clone()).unwrap_or_default()
+    }
+
+    /// Refuse the request while the URL's host circuit is open
+    pub fn check(&self, url: &str) -> Result<(), CircuitOpenError> {
+        self.check_at(&host_key(url), Instant::now())
+    }
+
+    /// Report a response or transport error for the URL's host and publish any transition
+    pub fn record(&self, url: &str, result: &reqwest::Result<reqwest::Response>) {
+        let host = host_key(url);
+        let transition = match result {
+            Ok(resp) if !is_failure_status(resp.status()) => {
+                self.record_success_at(&host, Instant::now())
+            }
+            Ok(resp) => {
+                self.record_failure_at(&host, Some(resp.status().to_string()), Instant::now())
+            }
+            Err(e) => self.record_failure_at(&host, Some(e.to_string()), Instant::now()),
+        };
+        if self.changed.swap(false, Ordering::Relaxed) {
+            self.persist();
+        }
+        if let Some(transition) = transition {
+            publish_transition(transition, self.config().pause_sessions);
+        }
+    }
+
+    fn persist(&self) {
+        let Some(path) = STATE_FILE.read().ok().and_then(|g| g.clone()) else {
+            return;
+        };
+        if !self.config().persist_state {
+            return;
+        }
+        let _guard = SAVE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let circuits = self.snapshot_at(Instant::now(), Utc::now());
+        if let Err(e) = save_state(&path, &circuits) {
+            warn!("🔌 [circuit] failed to persist state: {}", e);
+        }
+    }
+
+    /// Circuits currently open or half-open, by host
+    pub fn open_circuits(&self) -> Vec<PersistedCircuit> {
+        self.snapshot_at(Instant::now(), Utc::now())
+    }
+
+    /// Non-closed circuits with `now` mapped onto the wall clock `wall`
+    fn snapshot_at(&self, now: Instant, wall: DateTime<Utc>) -> Vec<PersistedCircuit> {
+        let to_wall = |d: Duration| chrono::Duration::from_std(d).unwrap_or_default();
+        let hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        let mut circuits: Vec<PersistedCircuit> = hosts
+            .iter()
+            .filter_map(|(host, circuit)| {
+                let (probe_after, wait) = match circuit.state {
+                    CircuitState::Closed { .. } => return None,
+                    CircuitState::Open { until, wait } => {
+                        (wall + to_wall(until.saturating_duration_since(now)), wait)
+                    }
+                    // The probe in flight dies with the process; probe again right away
+                    CircuitState::HalfOpen { wait, .. } => (wall, wait),
+                };
+                let open_for = circuit
+                    .opened_at
+                    .map(|at| now.saturating_duration_since(at))
+                    .unwrap_or_default();
+                Some(PersistedCircuit {
+                    host: host.clone(),
+                    probe_after,
+                    wait_secs: wait.as_secs(),
+                    opened_at: wall - to_wall(open_for),
+                })
+            })
+            .collect();
+        circuits.sort_by(|a, b| a.host.cmp(&b.host));
+        circuits
+    }
+
+    /// Re-open saved circuits relative to `now`/`wall`; returns how many were restored
+    fn restore_at(
+        &self,
+        circuits: Vec<PersistedCircuit>,
+        now: Instant,
+        wall: DateTime<Utc>,
+    ) -> usize {
+        let config = self.config();
+        if !config.enabled {
+            return 0;
+        }
+        let base_wait = Duration::from_secs(config.open_secs.max(1));
+        let max_wait = Duration::from_secs(config.max_open_secs).max(base_wait);
+        let mut hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        let mut restored = 0;
+        for saved in circuits {
+            let overdue = (wall - saved.probe_after).to_std().unwrap_or_default();
+            if saved.host.is_empty() || overdue > max_wait {
+                continue;
+            }
+            let remaining = (saved.probe_after - wall).to_std().unwrap_or_default();
+            let open_for = (wall - saved.opened_at).to_std().unwrap_or_default();
+            hosts.insert(
+                saved.host,
+                HostCircuit {
+                    state: CircuitState::Open {
+                        until: now + remaining,
+                        wait: Duration::from_secs(saved.wait_secs).clamp(base_wait, max_wait),
+                    },
+                    opened_at: Some(now.checked_sub(open_for).unwrap_or(now)),
+                },
+            );
+            restored += 1;
+        }
+        restored
+    }
+
+    fn check_at(&self, host: &str, now: Instant) -> Result<(), CircuitOpenError> {
+        if host.is_empty() || !self.config().enabled {
+            return Ok(());
+        }
+        let mut hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(circuit) = hosts.get_mut(host) else {
+            return Ok(());
+        };
+        match circuit.state {
+            CircuitState::Closed { .. } => Ok(()),
+            CircuitState::Open { until, wait } if now >= until => {
+                info!("🔌 [circuit] {} half-open; sending probe", host);
+                circuit.state = CircuitState::HalfOpen {
+                    probe_started: now,
+                    wait,
+                };
+                Ok(())
+            }
+            CircuitState::Open { until, .. } => Err(CircuitOpenError {
+                host: host.to_string(),
+                retry_after: until - now,
+            }),
+            CircuitState::HalfOpen {
+                probe_started,
+                wait,
+            } if now.duration_since(probe_started) >= wait => {
+                // The previous probe never reported back (cancelled); allow another
+                circuit.state = CircuitState::HalfOpen {
+                    probe_started: now,
+                    wait,
+                };
+                Ok(())
+            }
+            CircuitState::HalfOpen {
+                probe_started,
+                wait,
+            } => Err(CircuitOpenError {
+                host: host.to_string(),
+                retry_after: wait.saturating_sub(now.duration_since(probe_started)),
+            }),
+        }
+    }
+
+    fn record_success_at(&self, host: &str, now: Instant) -> Option<CircuitTransition> {
+        let mut hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        let circuit = hosts.get_mut(host)?;
+        let was_open = !matches!(circuit.state, CircuitState::Closed { .. });
+        circuit.state = CircuitState::Closed { failures: 0 };
+        if was_open {
+            self.changed.store(true, Ordering::Relaxed);
+        }
+        let opened_at = circuit.opened_at.take()?;
+        was_open.then(|| CircuitTransition::Closed {
+            host: host.to_string(),
+            open_for: now.duration_since(opened_at),
+        })
+    }
+
+    fn record_failure_at(
+        &self,
+        host: &str,
+        error: Option<String>,
+        now: Instant,
+    ) -> Option<CircuitTransition> {
+        let config = self.config();
+        if host.is_empty() || !config.enabled {
+            return None;
+        }
+        let threshold = config.failure_threshold.max(1);
+        let base_wait = Duration::from_secs(config.open_secs.max(1));
+        let max_wait = Duration::from_secs(config.max_open_secs).max(base_wait);
+        let mut hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        let circuit = hosts.entry(host.to_string()).or_default();
+        let (wait, failures) = match circuit.state {
+            CircuitState::Closed { failures } if failures + 1 < threshold => {
+                circuit.state = CircuitState::Closed {
+                    failures: failures + 1,
+                };
+                return None;
+            }
+            CircuitState::Closed { failures } => (base_wait, failures + 1),
+            CircuitState::HalfOpen { wait, .. } => ((wait * 2).min(max_wait), threshold),
+            // Requests admitted before the circuit opened are still finishing
+            CircuitState::Open { .. } => return None,
+        };
+        let reopened = circuit.opened_at.is_some();
+        circuit.state = CircuitState::Open {
+            until: now + wait,
+            wait,
+        };
+        self.changed.store(true, Ordering::Relaxed);
+        if reopened {
+            warn!(
+                "🔌 [circuit] {} probe failed; next probe in {}s",
+                host,
+                wait.as_secs()
+            );
+            return None;
+        }
+        circuit.opened_at = Some(now);
+        Some(CircuitTransition::Opened {
+            host: host.to_string(),
+            consecutive_failures: failures,
+            retry_after: wait,
+            last_error: error,
+        })
+    }
+}
+
+/// Emit the event and pause / resume sessions off the request path
+fn publish_transition(transition: CircuitTransition, pause_sessions: bool) {
+    let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+        warn!("🔌 [circuit] {:?} (no runtime to publish on)", transition);
+        return;
+    };
+    runtime.spawn(async move {
+        let event = match transition {
+            CircuitTransition::Opened {
+                host,
+                consecutive_failures,
+                retry_after,
+                last_error,
+            } => {
+                warn!(
+                    "🔌 [circuit] {} opened after {} consecutive failures; probing in {}s",
+                    host,
+                    consecutive_failures,
+                    retry_after.as_secs()
+                );
+                let paused_sessions = if pause_sessions {
+                    pause_running_sessions(&host).await
+                } else {
+                    Vec::new()
+                };
+                AppEvent::SiteCircuitOpened {
+                    host,
+                    consecutive_failures,
+                    retry_after_secs: retry_after.as_secs(),
+                    last_error,
+                    paused_sessions,
+                    timestamp: Utc::now(),
+                }
+            }
+            CircuitTransition::Closed { host, open_for } => {
+                info!("🔌 [circuit] {} closed after {}s", host, open_for.as_secs());
+                let resumed_sessions = resume_paused_sessions(&host).await;
+                AppEvent::SiteCircuitClosed {
+                    host,
+                    open_secs: open_for.as_secs(),
+                    resumed_sessions,
+                    timestamp: Utc::now(),
+                }
+            }
+        };
+        let sink = EVENT_SINK.read().ok().and_then(|g| g.clone());
+        if let Some(sink) = sink {
+            crate::commands::validation_commands::emit_actor_event(sink.as_ref(), event);
+        }
+    });
+}
+
+async fn pause_running_sessions(host: &str) -> Vec<String> {
+    let registry = session_registry();
+    let mut sessions = registry.write().await;
+    let mut paused = Vec::new();
+    for (session_id, entry) in sessions.iter_mut() {
+        if entry.status == SessionStatus::Running {
+            let _ = entry.pause_tx.send(true);
+            entry.status = SessionStatus::Paused;
+            paused.push(session_id.clone());
+        }
+    }
+    if !paused.is_empty() {
+        let mut by_host = PAUSED_BY_BREAKER.lock().unwrap_or_else(|e| e.into_inner());
+        by_host.retain(|(h, _)| h != host);
+        by_host.push((host.to_string(), paused.clone()));
+    }
+    paused
+}
+
+async fn resume_paused_sessions(host: &str) -> Vec<String> {
+    let paused = {
+        let mut by_host = PAUSED_BY_BREAKER.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(pos) = by_host.iter().position(|(h, _)| h == host) else {
+            return Vec::new();
+        };
+        by_host.remove(pos).1
+    };
+    let registry = session_registry();
+    let mut sessions = registry.write().await;
+    let mut resumed = Vec::new();
+    for session_id in paused {
+        // Sessions the user paused or that ended meanwhile stay as they are
+        if let Some(entry) = sessions.get_mut(&session_id) {
+            if entry.status == SessionStatus::Paused {
+                let _ = entry.pause_tx.send(false);
+                entry.status = SessionStatus::Running;
+                resumed.push(session_id);
+            }
+        }
+    }
+    resumed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker() -> SiteCircuitBreaker {
+        SiteCircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 3,
+            open_secs: 10,
+            max_open_secs: 30,
+            ..CircuitBreakerConfig::default()
+        })
+    }
+
+    #[test]
+    fn opens_after_consecutive_failures_and_success_resets() {
+        let cb = breaker();
+        let t0 = Instant::now();
+        let host = "csa-iot.org";
+        assert!(cb.record_failure_at(host, None, t0).is_none());
+        assert!(cb.record_failure_at(host, None, t0).is_none());
+        // A success in between restarts the count
+        assert!(cb.record_success_at(host, t0).is_none());
+        assert!(cb.record_failure_at(host, None, t0).is_none());
+        assert!(cb.record_failure_at(host, None, t0).is_none());
+        assert_eq!(
+            cb.record_failure_at(host, Some("503".into()), t0),
+            Some(CircuitTransition::Opened {
+                host: host.into(),
+                consecutive_failures: 3,
+                retry_after: Duration::from_secs(10),
+                last_error: Some("503".into()),
+            })
+        );
+        let err = cb.check_at(host, t0 + Duration::from_secs(4)).unwrap_err();
+        assert_eq!(err.retry_after, Duration::from_secs(6));
+        assert!(cb.check_at("other.org", t0).is_ok());
+    }
+
+    #[test]
+    fn half_open_probe_closes_or_backs_off() {
+        let cb = breaker();
+        let host = "csa-iot.org";
+        let t0 = Instant::now();
+        for _ in 0..3 {
+            cb.record_failure_at(host, None, t0);
+        }
+
+        // First probe after the wait; concurrent requests stay blocked
+        let t1 = t0 + Duration::from_secs(10);
+        assert!(cb.check_at(host, t1).is_ok());
+        assert!(cb.check_at(host, t1).is_err());
+        // Failed probe: wait doubles without a second Opened event
+        assert!(cb.record_failure_at(host, None, t1).is_none());
+        assert!(cb.check_at(host, t1 + Duration::from_secs(19)).is_err());
+
+        let t2 = t1 + Duration::from_secs(20);
+        assert!(cb.check_at(host, t2).is_ok());
+        assert!(cb.record_failure_at(host, None, t2).is_none());
+        // Capped at max_open_secs
+        let t3 = t2 + Duration::from_secs(30);
+        assert!(cb.check_at(host, t3).is_ok());
+        assert_eq!(
+            cb.record_success_at(host, t3),
+            Some(CircuitTransition::Closed {
+                host: host.into(),
+                open_for: Duration::from_secs(60),
+            })
+        );
+        assert!(cb.check_at(host, t3).is_ok());
+        assert!(cb.record_success_at(host, t3).is_none());
+    }
+
+    #[test]
+    fn open_circuits_survive_a_restart() {
+        let cb = breaker();
+        let host = "csa-iot.org";
+        let t0 = Instant::now();
+        for _ in 0..3 {
+            cb.record_failure_at(host, None, t0);
+        }
+        let t1 = t0 + Duration::from_secs(10);
+        assert!(cb.check_at(host, t1).is_ok());
+        cb.record_failure_at(host, None, t1);
+
+        // Saved 5s into the doubled 20s wait
+        let wall = Utc::now();
+        let saved = cb.snapshot_at(t1 + Duration::from_secs(5), wall);
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].wait_secs, 20);
+        assert_eq!(saved[0].probe_after, wall + chrono::Duration::seconds(15));
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(STATE_FILE_NAME);
+        save_state(&path, &saved).unwrap();
+        let loaded = load_state(&path).unwrap();
+        assert_eq!(loaded, saved);
+
+        // Restarted 5s later: 10s of the cool-down remain, then backoff continues from 20s
+        let restarted = breaker();
+        let now = Instant::now();
+        let later = wall + chrono::Duration::seconds(5);
+        assert_eq!(restarted.restore_at(loaded.clone(), now, later), 1);
+        let err = restarted.check_at(host, now).unwrap_err();
+        assert_eq!(err.retry_after, Duration::from_secs(10));
+        let probe = now + Duration::from_secs(10);
+        assert!(restarted.check_at(host, probe).is_ok());
+        assert!(restarted.record_failure_at(host, None, probe).is_none());
+        assert!(
+            restarted
+                .check_at(host, probe + Duration::from_secs(29))
+                .is_err()
+        );
+
+        // A cool-down that ended long before the restart is dropped
+        let fresh = breaker();
+        let much_later = wall + chrono::Duration::seconds(120);
+        assert_eq!(fresh.restore_at(loaded, now, much_later), 0);
+        assert!(fresh.check_at(host, now).is_ok());
+        assert!(
+            load_state(&dir.path().join("missing.json"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn disabled_breaker_never_opens() {
+        let cb = SiteCircuitBreaker::new(CircuitBreakerConfig {
+            enabled: false,
+            failure_threshold: 1,
+            ..CircuitBreakerConfig::default()
+        });
+        let now = Instant::now();
+        assert!(cb.record_failure_at("csa-iot.org", None, now).is_none());
+        assert!(cb.check_at("csa-iot.org", now).is_ok());
+        assert!(is_failure_status(StatusCode::BAD_GATEWAY));
+        assert!(!is_failure_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(!is_failure_status(StatusCode::NOT_FOUND));
+    }
+}
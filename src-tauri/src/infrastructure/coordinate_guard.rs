@@ -0,0 +1,151 @@
+//! Strict mode for (page_id, index_in_page) coordinate invariants.
+//!
+//! When `advanced.strict_coordinate_mode` is enabled, repository writes that would produce an
+//! invalid coordinate are rejected instead of being skipped or silently relocated (vacating the
+//! occupant of a slot). Each rejection is logged with full context and kept in a bounded in-memory
+//! log so it can be correlated with the groups reported by `scan_db_pagination_mismatches`.
+//!
+//! Coordinates are 0-based: page_id 0 is the oldest page, index_in_page is `0..products_per_page`
+//! of the active site profile.
+
+use crate::infrastructure::config::defaults::DEFAULT_PRODUCTS_PER_PAGE;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use tracing::warn;
+
+/// Maximum number of violations retained for inspection
+const VIOLATION_LOG_CAPACITY: usize = 500;
+
+static STRICT_MODE: AtomicBool = AtomicBool::new(false);
+static PRODUCTS_PER_PAGE: AtomicU32 = AtomicU32::new(DEFAULT_PRODUCTS_PER_PAGE);
+static VIOLATIONS: Lazy<Mutex<VecDeque<CoordinateViolation>>> =
+    Lazy::new(|| Mutex::new(VecDeque::new()));
+
+/// Kind of coordinate invariant that a write would break
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoordinateViolationKind {
+    /// page_id below the canonical lower bound (0)
+    InvalidPageId,
+    /// index_in_page outside `0..products_per_page`
+    IndexOutOfRange,
+    /// Target slot already belongs to a different URL
+    DuplicateSlot,
+}
+
+/// Rejected write with full context
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoordinateViolation {
+    pub operation: String,
+    pub url: String,
+    pub page_id: Option<i32>,
+    pub index_in_page: Option<i32>,
+    pub kind: CoordinateViolationKind,
+    /// URL currently occupying the slot (DuplicateSlot only)
+    pub conflicting_url: Option<String>,
+    pub detected_at: DateTime<Utc>,
+}
+
+pub fn set_strict_mode(enabled: bool) {
+    STRICT_MODE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_strict_mode() -> bool {
+    STRICT_MODE.load(Ordering::Relaxed)
+}
+
+/// Record the active site profile's page size (`SiteProfile::products_per_page`)
+pub fn set_products_per_page(products_per_page: u32) {
+    PRODUCTS_PER_PAGE.store(products_per_page, Ordering::Relaxed);
+}
+
+/// Page size of the active site profile, the upper bound for index_in_page
+pub fn products_per_page() -> u32 {
+    PRODUCTS_PER_PAGE.load(Ordering::Relaxed)
+}
+
+/// Range check for a coordinate pair (pure; unset coordinates are always valid)
+pub fn check_coordinates(
+    page_id: Option<i32>,
+    index_in_page: Option<i32>,
+    products_per_page: u32,
+) -> Option<CoordinateViolationKind> {
+    if page_id.is_some_and(|pid| pid < 0) {
+        return Some(CoordinateViolationKind::InvalidPageId);
+    }
+    if index_in_page.is_some_and(|idx| idx < 0 || idx >= products_per_page as i32) {
+        return Some(CoordinateViolationKind::IndexOutOfRange);
+    }
+    None
+}
+
+/// Record a violation and turn it into the error returned by the rejected write
+pub fn reject(violation: CoordinateViolation) -> anyhow::Error {
+    warn!(target: "kpi.coordinate",
+        "{{\"event\":\"coordinate_violation\",\"operation\":\"{}\",\"kind\":\"{:?}\",\"url\":\"{}\",\"page_id\":{:?},\"index_in_page\":{:?},\"conflicting_url\":{:?}}}",
+        violation.operation,
+        violation.kind,
+        violation.url,
+        violation.page_id,
+        violation.index_in_page,
+        violation.conflicting_url
+    );
+    let err = anyhow::anyhow!(
+        "Strict coordinate violation ({:?}) in {}: url={} page_id={:?} index_in_page={:?} conflicting_url={:?}",
+        violation.kind,
+        violation.operation,
+        violation.url,
+        violation.page_id,
+        violation.index_in_page,
+        violation.conflicting_url
+    );
+    if let Ok(mut log) = VIOLATIONS.lock() {
+        if log.len() >= VIOLATION_LOG_CAPACITY {
+            log.pop_front();
+        }
+        log.push_back(violation);
+    }
+    err
+}
+
+/// Most recent violations (oldest first)
+pub fn recent_violations() -> Vec<CoordinateViolation> {
+    VIOLATIONS
+        .lock()
+        .map(|log| log.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_out_of_range_coordinates() {
+        assert_eq!(check_coordinates(Some(0), Some(0), 12), None);
+        assert_eq!(check_coordinates(Some(10), Some(11), 12), None);
+        assert_eq!(check_coordinates(None, None, 12), None);
+        assert_eq!(
+            check_coordinates(Some(-1), Some(3), 12),
+            Some(CoordinateViolationKind::InvalidPageId)
+        );
+        assert_eq!(
+            check_coordinates(Some(4), Some(12), 12),
+            Some(CoordinateViolationKind::IndexOutOfRange)
+        );
+        assert_eq!(
+            check_coordinates(Some(4), Some(-1), 12),
+            Some(CoordinateViolationKind::IndexOutOfRange)
+        );
+        // Bounds follow the site profile's page size
+        assert_eq!(check_coordinates(Some(4), Some(12), 20), None);
+        assert_eq!(
+            check_coordinates(Some(4), Some(2), 2),
+            Some(CoordinateViolationKind::IndexOutOfRange)
+        );
+    }
+}
@@ -15,6 +15,7 @@ use tokio::sync::Semaphore;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
+use crate::domain::error_catalog::ErrorCode;
 use crate::domain::product::{Product, ProductDetail};
 use crate::domain::product_url::ProductUrl;
 use crate::domain::services::crawling_services::{
@@ -25,8 +26,11 @@ use crate::domain::services::{
     DatabaseAnalyzer, DuplicateAnalysis, FieldAnalysis, ProcessingStrategy, ProductDetailCollector,
     ProductListCollector, SiteStatus, StatusChecker,
 };
-use crate::infrastructure::config::utils as config_utils;
+use crate::domain::site_profile::SiteProfile;
+use crate::domain::warning_catalog::WarningCode;
 use crate::infrastructure::config::{AppConfig, CrawlingConfig};
+use crate::infrastructure::detail_fetch_order::{DetailFetchOrder, fetch_sequence};
+use crate::infrastructure::simple_http_client::RequestOptions;
 use crate::infrastructure::{HttpClient, IntegratedProductRepository, MatterDataExtractor};
 // Canonical pagination calculator (legacy utils::PageIdCalculator via domain alias)
 use crate::domain::pagination::CanonicalPageIdCalculator;
@@ -39,6 +43,8 @@ pub struct StatusCheckerImpl {
     pub http_client: Arc<HttpClient>,
     pub data_extractor: Arc<MatterDataExtractor>,
     pub config: AppConfig,
+    /// Site whose listing pages are probed (taken from the extractor)
+    site_profile: Arc<dyn SiteProfile>,
     page_cache: Arc<tokio::sync::Mutex<HashMap<u32, PageAnalysisCache>>>,
     pub product_repo: Option<Arc<IntegratedProductRepository>>,
 }
@@ -56,10 +62,12 @@ impl StatusCheckerImpl {
         data_extractor: MatterDataExtractor,
         config: AppConfig,
     ) -> Self {
+        let site_profile = data_extractor.site_profile();
         Self {
             http_client: Arc::new(http_client),
             data_extractor: Arc::new(data_extractor),
             config,
+            site_profile,
             page_cache: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
             product_repo: None,
         }
@@ -74,6 +82,11 @@ impl StatusCheckerImpl {
         let mut guard = self.page_cache.lock().await;
         guard.clear();
     }
+
+    /// Physical listing page URL of the configured site (1 = newest)
+    fn list_page_url(&self, page: u32) -> String {
+        self.site_profile.list_page_url(page)
+    }
 }
 
 impl StatusCheckerImpl {
@@ -128,7 +141,7 @@ impl StatusChecker for StatusCheckerImpl {
         info!("Checking site status and discovering pages...");
 
         // Step 1: 기본 사이트 접근성 확인
-        let url = config_utils::matter_products_page_url_simple(1);
+        let url = self.list_page_url(1);
 
         // 접근성 테스트
         let access_test = {
@@ -441,7 +454,7 @@ impl StatusCheckerImpl {
         info!("Starting downward search from page {}", current_page);
 
         while current_page >= min_page {
-            let test_url = config_utils::matter_products_page_url_simple(current_page);
+            let test_url = self.list_page_url(current_page);
 
             // Use configured HttpClient
             let _client = self.create_configured_http_client()?;
@@ -504,7 +517,7 @@ impl StatusCheckerImpl {
         while current_page > min_page {
             current_page = current_page.saturating_sub(1);
 
-            let test_url = config_utils::matter_products_page_url_simple(current_page);
+            let test_url = self.list_page_url(current_page);
             info!(
                 "🔍 Checking page {} (consecutive empty: {})",
                 current_page, consecutive_empty_pages
@@ -712,7 +725,7 @@ impl StatusCheckerImpl {
                 break;
             }
 
-            let test_url = config_utils::matter_products_page_url_simple(current_page);
+            let test_url = self.list_page_url(current_page);
 
             let (has_products, max_page_in_pagination) = {
                 match self.http_client.fetch_html_string(&test_url).await {
@@ -740,7 +753,7 @@ impl StatusCheckerImpl {
                 let last_page = self
                     .find_last_valid_page_with_safety_check(current_page)
                     .await?;
-                let test_url = config_utils::matter_products_page_url_simple(last_page);
+                let test_url = self.list_page_url(last_page);
 
                 let html = self.http_client.fetch_html_string(&test_url).await?;
 
@@ -755,7 +768,7 @@ impl StatusCheckerImpl {
                 continue;
             }
             // 마지막 페이지 도달, 제품 수 확인
-            let test_url = config_utils::matter_products_page_url_simple(current_page);
+            let test_url = self.list_page_url(current_page);
 
             let html = self.http_client.fetch_html_string(&test_url).await?;
 
@@ -765,7 +778,7 @@ impl StatusCheckerImpl {
         }
 
         // 최대 시도 횟수 도달 시 현재 페이지의 제품 수 확인
-        let test_url = config_utils::matter_products_page_url_simple(current_page);
+        let test_url = self.list_page_url(current_page);
 
         let html = self.http_client.fetch_html_string(&test_url).await?;
 
@@ -776,7 +789,7 @@ impl StatusCheckerImpl {
 
     /// 특정 페이지에 제품이 있는지 확인 - 활성 페이지네이션 값도 함께 확인
     async fn check_page_has_products(&self, page: u32) -> Result<bool> {
-        let test_url = config_utils::matter_products_page_url_simple(page);
+        let test_url = self.list_page_url(page);
 
         // Use configured HttpClient
         let _client = self.create_configured_http_client()?;
@@ -1197,7 +1210,7 @@ impl StatusCheckerImpl {
 
         // 캐시에 없으면 새로 분석
         debug!("🔍 Analyzing page {} (not in cache)", page_number);
-        let url = config_utils::matter_products_page_url_simple(page_number);
+        let url = self.list_page_url(page_number);
 
         let (product_count, max_pagination_page, _active_page, has_products) = {
             // Use consistent HttpClient
@@ -2497,6 +2510,371 @@ impl ProductListCollector for ProductListCollectorImpl {
     }
 }
 
+/// sitemap.xml 의 `<url>` / `<sitemap>` 엔트리
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SitemapEntry {
+    pub loc: String,
+    pub lastmod: Option<String>,
+}
+
+/// 파싱된 sitemap 문서 (인덱스 또는 URL 목록)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SitemapDocument {
+    Index(Vec<SitemapEntry>),
+    UrlSet(Vec<SitemapEntry>),
+}
+
+/// Child sitemaps followed from one sitemap index
+const SITEMAP_MAX_CHILDREN: usize = 64;
+
+/// Nested sitemap indexes followed before giving up
+const SITEMAP_MAX_DEPTH: u32 = 2;
+
+static SITEMAP_BLOCK_RE: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+    regex::Regex::new(r"(?s)<(?:[A-Za-z0-9_]+:)?(url|sitemap)(?:\s[^>]*)?>(.*?)</(?:[A-Za-z0-9_]+:)?(?:url|sitemap)>")
+        .expect("valid sitemap block regex")
+});
+
+static SITEMAP_LOC_RE: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+    regex::Regex::new(
+        r"(?s)<(?:[A-Za-z0-9_]+:)?loc>\s*(?:<!\[CDATA\[)?\s*(.*?)\s*(?:\]\]>)?\s*</(?:[A-Za-z0-9_]+:)?loc>",
+    )
+    .expect("valid sitemap loc regex")
+});
+
+static SITEMAP_LASTMOD_RE: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+    regex::Regex::new(r"(?s)<(?:[A-Za-z0-9_]+:)?lastmod>\s*(.*?)\s*</(?:[A-Za-z0-9_]+:)?lastmod>")
+        .expect("valid sitemap lastmod regex")
+});
+
+/// Fetched product entries shared by every list-page item of a session (url, fetched_at, entries)
+type SitemapCacheSlot = Option<(String, Instant, Arc<Vec<SitemapEntry>>)>;
+
+static SITEMAP_CACHE: once_cell::sync::Lazy<tokio::sync::Mutex<SitemapCacheSlot>> =
+    once_cell::sync::Lazy::new(|| tokio::sync::Mutex::new(None));
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// `<urlset>` / `<sitemapindex>` 를 엔트리 목록으로 변환 (XML 네임스페이스 접두사 허용)
+pub fn parse_sitemap(xml: &str) -> SitemapDocument {
+    let is_index = xml.contains("<sitemapindex") || xml.contains(":sitemapindex");
+    let wanted = if is_index { "sitemap" } else { "url" };
+    let entries = SITEMAP_BLOCK_RE
+        .captures_iter(xml)
+        .filter(|c| &c[1] == wanted)
+        .filter_map(|c| {
+            let body = &c[2];
+            let loc = SITEMAP_LOC_RE.captures(body)?.get(1)?.as_str();
+            if loc.is_empty() {
+                return None;
+            }
+            let lastmod = SITEMAP_LASTMOD_RE
+                .captures(body)
+                .and_then(|m| m.get(1))
+                .map(|m| m.as_str().to_string())
+                .filter(|s| !s.is_empty());
+            Some(SitemapEntry {
+                loc: unescape_xml(loc),
+                lastmod,
+            })
+        })
+        .collect();
+    if is_index {
+        SitemapDocument::Index(entries)
+    } else {
+        SitemapDocument::UrlSet(entries)
+    }
+}
+
+/// gzip 매직 바이트(1f 8b)면 압축 해제 (`.xml.gz` 는 Content-Encoding 없이 내려오는 경우가 많음)
+pub fn decode_sitemap_body(bytes: &[u8]) -> Result<String> {
+    if bytes.starts_with(&[0x1f, 0x8b]) {
+        use std::io::Read;
+        let mut xml = String::new();
+        flate2::read::GzDecoder::new(bytes)
+            .read_to_string(&mut xml)
+            .map_err(|e| anyhow!("Failed to decompress gzipped sitemap: {}", e))?;
+        Ok(xml)
+    } else {
+        Ok(String::from_utf8_lossy(bytes).into_owned())
+    }
+}
+
+/// Sortable key for `<lastmod>` (W3C datetime or plain date); undated entries sort oldest
+fn lastmod_key(lastmod: Option<&str>) -> i64 {
+    let Some(raw) = lastmod else {
+        return i64::MIN;
+    };
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(raw) {
+        return dt.timestamp();
+    }
+    chrono::NaiveDate::parse_from_str(raw.get(..10).unwrap_or(raw), "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|d| d.and_utc().timestamp())
+        .unwrap_or(i64::MIN)
+}
+
+/// 제품 상세 URL만 남기고 중복 제거 후 최신(lastmod) 순으로 정렬
+pub fn select_product_entries(entries: Vec<SitemapEntry>) -> Vec<SitemapEntry> {
+    let mut seen = std::collections::HashSet::new();
+    let mut products: Vec<SitemapEntry> = entries
+        .into_iter()
+        .filter(|e| {
+            e.loc
+                .split_once("/csa_product/")
+                .is_some_and(|(_, slug)| !slug.trim_matches('/').is_empty())
+        })
+        .filter(|e| seen.insert(e.loc.clone()))
+        .collect();
+    // stable: equal timestamps keep sitemap order
+    products.sort_by_key(|e| std::cmp::Reverse(lastmod_key(e.lastmod.as_deref())));
+    products
+}
+
+/// (total_pages, products_on_last_page) when `total_products` are laid out newest-first
+pub fn sitemap_page_layout(total_products: usize) -> (u32, u32) {
+    let per_page = DEFAULT_PRODUCTS_PER_PAGE as usize;
+    if total_products == 0 {
+        return (0, 0);
+    }
+    let total_pages = total_products.div_ceil(per_page);
+    let on_last = total_products - (total_pages - 1) * per_page;
+    (total_pages as u32, on_last as u32)
+}
+
+/// 물리 페이지(1 = 최신)에 해당하는 12개 구간을 ProductUrl 로 변환
+fn sitemap_page_urls(products: &[SitemapEntry], page: u32) -> Vec<ProductUrl> {
+    let (total_pages, on_last) = sitemap_page_layout(products.len());
+    if page == 0 || page > total_pages {
+        return Vec::new();
+    }
+    let per_page = DEFAULT_PRODUCTS_PER_PAGE as usize;
+    let start = (page as usize - 1) * per_page;
+    let end = (start + per_page).min(products.len());
+    let calculator = CanonicalPageIdCalculator::new(total_pages, on_last as usize);
+    products[start..end]
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let calculation = calculator.calculate(page, index);
+            ProductUrl {
+                url: entry.loc.clone(),
+                page_id: calculation.page_id,
+                index_in_page: calculation.index_in_page,
+            }
+        })
+        .collect()
+}
+
+/// sitemap.xml 기반 제품 목록 수집기
+///
+/// 목록 페이지 레이아웃에 의존하지 않고 sitemap 의 제품 URL을 lastmod 최신순으로 12개씩 잘라
+/// 물리 페이지로 취급한다. sitemap 자체의 총 페이지 수를 기준으로 page_id/index_in_page 를
+/// 계산하므로 호출자가 넘긴 `total_pages` 힌트는 사용하지 않는다. sitemap 은 인증 프로그램
+/// 필터가 없고 lastmod 는 수정 시각이라, 좌표가 페이지네이션 수집 결과와 다를 수 있다.
+pub struct SitemapListCollector {
+    http_client: Arc<HttpClient>,
+    sitemap_url: String,
+    cache_ttl: Duration,
+}
+
+impl SitemapListCollector {
+    pub fn new(http_client: Arc<HttpClient>, sitemap_url: String, cache_ttl: Duration) -> Self {
+        Self {
+            http_client,
+            sitemap_url,
+            cache_ttl,
+        }
+    }
+
+    pub fn from_config(
+        http_client: Arc<HttpClient>,
+        config: &crate::infrastructure::config::ListCollectionConfig,
+    ) -> Self {
+        Self::new(
+            http_client,
+            config.sitemap_url.clone(),
+            Duration::from_secs(config.sitemap_cache_ttl_secs),
+        )
+    }
+
+    /// Product entries newest-first, fetched at most once per cache TTL
+    pub async fn product_entries(&self) -> Result<Arc<Vec<SitemapEntry>>> {
+        let mut cache = SITEMAP_CACHE.lock().await;
+        let fresh = cache.as_ref().filter(|(url, fetched_at, _)| {
+            *url == self.sitemap_url && fetched_at.elapsed() < self.cache_ttl
+        });
+        if let Some((_, _, entries)) = fresh {
+            return Ok(Arc::clone(entries));
+        }
+        let raw = self.fetch_entries(&self.sitemap_url).await?;
+        let entries = Arc::new(select_product_entries(raw));
+        info!(
+            "🗺️ Sitemap {} yielded {} product URLs",
+            self.sitemap_url,
+            entries.len()
+        );
+        if entries.is_empty() {
+            return Err(anyhow!(
+                "Sitemap {} contains no product URLs",
+                self.sitemap_url
+            ));
+        }
+        *cache = Some((
+            self.sitemap_url.clone(),
+            Instant::now(),
+            Arc::clone(&entries),
+        ));
+        Ok(entries)
+    }
+
+    /// (total_pages, products_on_last_page) of the sitemap-derived layout
+    pub async fn page_layout(&self) -> Result<(u32, u32)> {
+        Ok(sitemap_page_layout(self.product_entries().await?.len()))
+    }
+
+    /// Walk a sitemap index (product child sitemaps preferred) down to URL entries
+    async fn fetch_entries(&self, root_url: &str) -> Result<Vec<SitemapEntry>> {
+        let mut pending = vec![(root_url.to_string(), 0u32)];
+        let mut urls = Vec::new();
+        while let Some((url, depth)) = pending.pop() {
+            match self.fetch_document(&url).await? {
+                SitemapDocument::UrlSet(entries) => urls.extend(entries),
+                SitemapDocument::Index(children) => {
+                    if depth >= SITEMAP_MAX_DEPTH {
+                        warn!("Sitemap index nesting too deep, skipping {}", url);
+                        continue;
+                    }
+                    let product_children: Vec<SitemapEntry> = children
+                        .iter()
+                        .filter(|c| c.loc.contains("csa_product"))
+                        .cloned()
+                        .collect();
+                    let selected = if product_children.is_empty() {
+                        children
+                    } else {
+                        product_children
+                    };
+                    if selected.len() > SITEMAP_MAX_CHILDREN {
+                        warn!(
+                            "Sitemap index {} lists {} children; following first {}",
+                            url,
+                            selected.len(),
+                            SITEMAP_MAX_CHILDREN
+                        );
+                    }
+                    // reversed so pop() visits children in document order
+                    pending.extend(
+                        selected
+                            .into_iter()
+                            .take(SITEMAP_MAX_CHILDREN)
+                            .rev()
+                            .map(|c| (c.loc, depth + 1)),
+                    );
+                }
+            }
+        }
+        Ok(urls)
+    }
+
+    async fn fetch_document(&self, url: &str) -> Result<SitemapDocument> {
+        debug!("🗺️ Fetching sitemap {}", url);
+        let response = self.http_client.fetch_response_with_policy(url).await?;
+        let bytes = response.bytes().await?;
+        Ok(parse_sitemap(&decode_sitemap_body(&bytes)?))
+    }
+
+    async fn collect_pages(&self, pages: &[u32]) -> Result<Vec<ProductUrl>> {
+        let products = self.product_entries().await?;
+        Ok(pages
+            .iter()
+            .flat_map(|&page| sitemap_page_urls(&products, page))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl ProductListCollector for SitemapListCollector {
+    async fn collect_all_pages(
+        &self,
+        _total_pages: u32,
+        _products_on_last_page: u32,
+    ) -> Result<Vec<ProductUrl>> {
+        let (total_pages, _) = self.page_layout().await?;
+        let pages: Vec<u32> = (1..=total_pages).collect();
+        self.collect_pages(&pages).await
+    }
+
+    async fn collect_page_range(
+        &self,
+        start_page: u32,
+        end_page: u32,
+        _total_pages: u32,
+        _products_on_last_page: u32,
+    ) -> Result<Vec<ProductUrl>> {
+        let pages: Vec<u32> = if start_page > end_page {
+            (end_page..=start_page).rev().collect()
+        } else {
+            (start_page..=end_page).collect()
+        };
+        self.collect_pages(&pages).await
+    }
+
+    async fn collect_page_range_with_cancellation(
+        &self,
+        start_page: u32,
+        end_page: u32,
+        total_pages: u32,
+        products_on_last_page: u32,
+        cancellation_token: CancellationToken,
+    ) -> Result<Vec<ProductUrl>> {
+        if cancellation_token.is_cancelled() {
+            return Err(anyhow!("Sitemap collection cancelled"));
+        }
+        tokio::select! {
+            _ = cancellation_token.cancelled() => Err(anyhow!("Sitemap collection cancelled")),
+            result = self.collect_page_range(start_page, end_page, total_pages, products_on_last_page) => result,
+        }
+    }
+
+    async fn collect_single_page(
+        &self,
+        page: u32,
+        total_pages: u32,
+        _products_on_last_page: u32,
+    ) -> Result<Vec<ProductUrl>> {
+        let products = self.product_entries().await?;
+        let (sitemap_pages, _) = sitemap_page_layout(products.len());
+        if sitemap_pages != total_pages {
+            debug!(
+                "Sitemap layout has {} pages (pagination hint {}), page {} sliced from sitemap",
+                sitemap_pages, total_pages, page
+            );
+        }
+        Ok(sitemap_page_urls(&products, page))
+    }
+
+    async fn collect_page_batch(
+        &self,
+        pages: &[u32],
+        _total_pages: u32,
+        _products_on_last_page: u32,
+    ) -> Result<Vec<ProductUrl>> {
+        self.collect_pages(pages).await
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 /// 데이터베이스 분석 서비스 구현체
 pub struct DatabaseAnalyzerImpl {
     product_repo: Arc<IntegratedProductRepository>,
@@ -2607,11 +2985,73 @@ impl DatabaseAnalyzer for DatabaseAnalyzerImpl {
     }
 }
 
+/// Why a bounded detail fetch attempt failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DetailFetchError {
+    /// HTTP request failed
+    Fetch(String),
+    /// Response body could not be read
+    Read(String),
+    /// Page fetched but the extractor rejected it
+    Extract(String),
+}
+
+impl DetailFetchError {
+    /// Sync warning code for this failure
+    pub fn code(&self) -> WarningCode {
+        match self {
+            Self::Fetch(_) => WarningCode::DetailsFetchFailed,
+            Self::Read(_) => WarningCode::DetailsReadFailed,
+            Self::Extract(_) => WarningCode::DetailsExtractFailed,
+        }
+    }
+
+    /// Error class recorded when the URL is dead-lettered
+    pub fn error_code(&self) -> ErrorCode {
+        match self {
+            Self::Fetch(e) => ErrorCode::classify_message(e),
+            Self::Read(_) => ErrorCode::NetworkFailure,
+            Self::Extract(_) => ErrorCode::ParseFailed,
+        }
+    }
+}
+
+impl std::fmt::Display for DetailFetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fetch(e) | Self::Read(e) | Self::Extract(e) => f.write_str(e),
+        }
+    }
+}
+
+/// Per-URL result of `ProductDetailCollectorImpl::collect_details_bounded`
+#[derive(Debug)]
+pub struct DetailFetchOutcome {
+    pub product_url: ProductUrl,
+    /// Attempts made (1-based)
+    pub attempts: u32,
+    pub result: std::result::Result<ProductDetail, DetailFetchError>,
+}
+
+/// Called after every failed attempt with (url, attempt, max_attempts, error); returns the
+/// delay before the next attempt, or `None` to give up on the URL
+pub type DetailRetryHook<'a> =
+    &'a (dyn Fn(&ProductUrl, u32, u32, &DetailFetchError) -> Option<Duration> + Sync);
+
+/// Request options shared by every fetch of a `collect_details_bounded` call
+pub struct BoundedDetailFetch<'a> {
+    pub user_agent_override: Option<String>,
+    pub referer: Option<String>,
+    pub on_failure: DetailRetryHook<'a>,
+}
+
 /// 제품 상세정보 수집 서비스 구현체
 pub struct ProductDetailCollectorImpl {
     http_client: Arc<HttpClient>, // 🔥 Mutex 제거 - GlobalRateLimiter가 동시성 관리
     data_extractor: Arc<MatterDataExtractor>,
     config: CollectorConfig,
+    fetch_order: DetailFetchOrder,
+    fetch_seed: Option<u64>,
 }
 
 impl ProductDetailCollectorImpl {
@@ -2624,9 +3064,24 @@ impl ProductDetailCollectorImpl {
             http_client,
             data_extractor,
             config,
+            fetch_order: DetailFetchOrder::Sequential,
+            fetch_seed: None,
         }
     }
 
+    /// Request details in `order` instead of slot order; results keep input order.
+    /// `seed` makes `Shuffled` reproducible.
+    #[must_use]
+    pub fn with_fetch_order(mut self, order: DetailFetchOrder, seed: Option<u64>) -> Self {
+        self.fetch_order = order;
+        self.fetch_seed = seed;
+        self
+    }
+
+    fn fetch_sequence(&self, product_urls: &[ProductUrl]) -> Vec<usize> {
+        fetch_sequence(product_urls, self.fetch_order, self.fetch_seed)
+    }
+
     /// 🔥 ProductDetail 이벤트 처리기 (비동기, 논블로킹)
     async fn handle_product_detail_event(
         event: ProductDetailEvent,
@@ -2719,7 +3174,8 @@ impl ProductDetailCollector for ProductDetailCollectorImpl {
         let mut details = Vec::with_capacity(product_urls.len());
         let max_retries = self.config.retry_attempts.max(1);
 
-        for product_url in product_urls {
+        for position in self.fetch_sequence(product_urls) {
+            let product_url = &product_urls[position];
             let url = product_url.url.clone();
             let page_id = product_url.page_id;
             let index_in_page = product_url.index_in_page;
@@ -2776,7 +3232,7 @@ impl ProductDetailCollector for ProductDetailCollectorImpl {
                     detail.page_id = Some(page_id);
                     detail.index_in_page = Some(index_in_page);
                     detail.id = Some(format!("p{:04}i{:02}", page_id, index_in_page));
-                    details.push(detail);
+                    details.push((position, detail));
                 }
                 Err(e) => {
                     warn!("Failed to parse product detail for {}: {}", url, e);
@@ -2785,7 +3241,8 @@ impl ProductDetailCollector for ProductDetailCollectorImpl {
         }
 
         debug!("Successfully collected {} product details (sequential)", details.len());
-        Ok(details)
+        details.sort_by_key(|(position, _)| *position);
+        Ok(details.into_iter().map(|(_, detail)| detail).collect())
     }
 
     async fn collect_details_with_cancellation(
@@ -2801,7 +3258,8 @@ impl ProductDetailCollector for ProductDetailCollectorImpl {
         let mut details = Vec::with_capacity(product_urls.len());
         let max_retries = self.config.retry_attempts.max(1);
 
-    'outer: for product_url in product_urls {
+    'outer: for position in self.fetch_sequence(product_urls) {
+            let product_url = &product_urls[position];
             if cancellation_token.is_cancelled() {
                 warn!("Cancellation requested; stopping detail collection early");
                 break 'outer;
@@ -2866,7 +3324,7 @@ impl ProductDetailCollector for ProductDetailCollectorImpl {
                     detail.page_id = Some(page_id);
                     detail.index_in_page = Some(index_in_page);
                     detail.id = Some(format!("p{:04}i{:02}", page_id, index_in_page));
-                    details.push(detail);
+                    details.push((position, detail));
                 }
                 Err(e) => {
                     warn!("Failed to parse product detail for {}: {}", url, e);
@@ -2875,7 +3333,8 @@ impl ProductDetailCollector for ProductDetailCollectorImpl {
         }
 
         info!("Successfully collected {} product details (sequential)", details.len());
-        Ok(details)
+        details.sort_by_key(|(position, _)| *position);
+        Ok(details.into_iter().map(|(_, detail)| detail).collect())
     }
 
     async fn collect_single_product(&self, product_url: &ProductUrl) -> Result<ProductDetail> {
@@ -2899,6 +3358,108 @@ impl ProductDetailCollector for ProductDetailCollectorImpl {
 }
 
 impl ProductDetailCollectorImpl {
+    /// Fetch and parse details with at most `config.max_concurrent` requests in flight and up to
+    /// `config.retry_attempts` attempts per URL. Requests go out in the collector's fetch order;
+    /// outcomes come back in input order, one per URL, so callers can persist them inside their
+    /// own transaction.
+    pub async fn collect_details_bounded(
+        &self,
+        product_urls: &[ProductUrl],
+        fetch: &BoundedDetailFetch<'_>,
+    ) -> Vec<DetailFetchOutcome> {
+        use futures::stream::{self, StreamExt};
+
+        let max_concurrent = self.config.max_concurrent.max(1) as usize;
+        debug!(
+            "Collecting details for {} products (max_concurrent={})",
+            product_urls.len(),
+            max_concurrent
+        );
+        let sequence = self.fetch_sequence(product_urls);
+        let mut outcomes: Vec<(usize, DetailFetchOutcome)> = stream::iter(sequence)
+            .map(|position| async move {
+                let product_url = product_urls[position].clone();
+                (
+                    position,
+                    self.fetch_detail_with_retries(product_url, fetch).await,
+                )
+            })
+            .buffered(max_concurrent)
+            .collect()
+            .await;
+        outcomes.sort_by_key(|(position, _)| *position);
+        outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+    }
+
+    async fn fetch_detail_with_retries(
+        &self,
+        product_url: ProductUrl,
+        fetch: &BoundedDetailFetch<'_>,
+    ) -> DetailFetchOutcome {
+        let max_attempts = self.config.retry_attempts.max(1);
+        let mut attempt = 0u32;
+        loop {
+            attempt += 1;
+            let opts = RequestOptions {
+                user_agent_override: fetch.user_agent_override.clone(),
+                referer: fetch.referer.clone(),
+                skip_robots_check: false,
+                attempt: Some(attempt),
+                max_attempts: Some(max_attempts),
+            };
+            let error = match self.fetch_detail_once(&product_url, &opts).await {
+                Ok(detail) => {
+                    return DetailFetchOutcome {
+                        product_url,
+                        attempts: attempt,
+                        result: Ok(detail),
+                    };
+                }
+                Err(e) => e,
+            };
+            match (fetch.on_failure)(&product_url, attempt, max_attempts, &error) {
+                Some(delay) if attempt < max_attempts => tokio::time::sleep(delay).await,
+                _ => {
+                    return DetailFetchOutcome {
+                        product_url,
+                        attempts: attempt,
+                        result: Err(error),
+                    };
+                }
+            }
+        }
+    }
+
+    async fn fetch_detail_once(
+        &self,
+        product_url: &ProductUrl,
+        opts: &RequestOptions,
+    ) -> std::result::Result<ProductDetail, DetailFetchError> {
+        let response = self
+            .http_client
+            .fetch_response_with_options(&product_url.url, opts)
+            .await
+            .map_err(|e| DetailFetchError::Fetch(e.to_string()))?;
+        let body = response
+            .text()
+            .await
+            .map_err(|e| DetailFetchError::Read(e.to_string()))?;
+        let doc = scraper::Html::parse_document(&body);
+        let mut detail = self
+            .data_extractor
+            .extract_product_detail(&doc, product_url.url.clone())
+            .map_err(|e| DetailFetchError::Extract(e.to_string()))?;
+        detail.page_id = Some(product_url.page_id);
+        detail.index_in_page = Some(product_url.index_in_page);
+        if detail.id.is_none() {
+            detail.id = Some(format!(
+                "p{:04}i{:02}",
+                product_url.page_id, product_url.index_in_page
+            ));
+        }
+        Ok(detail)
+    }
+
     /// 🔥 동시성을 보장하는 이벤트 기반 제품 상세정보 수집 메서드 (비동기 이벤트 큐 사용)
     ///
     /// Errors
@@ -3553,3 +4114,95 @@ enum PageEvent {
         page_number: u32,
     },
 }
+
+#[cfg(test)]
+mod sitemap_tests {
+    use super::*;
+
+    const INDEX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <sitemap><loc>https://csa-iot.org/post-sitemap.xml</loc></sitemap>
+  <sitemap><loc>https://csa-iot.org/csa_product-sitemap.xml</loc><lastmod>2025-01-02T00:00:00+00:00</lastmod></sitemap>
+</sitemapindex>"#;
+
+    fn url_set(n: usize) -> String {
+        let body: String = (0..n)
+            .map(|i| {
+                format!(
+                    "<url><loc>https://csa-iot.org/csa_product/p{i}/</loc><lastmod>2024-01-{:02}T00:00:00+00:00</lastmod></url>",
+                    i % 28 + 1
+                )
+            })
+            .collect();
+        format!(r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">{body}</urlset>"#)
+    }
+
+    #[test]
+    fn parses_index_and_url_set() {
+        let SitemapDocument::Index(children) = parse_sitemap(INDEX) else {
+            panic!("expected sitemap index");
+        };
+        assert_eq!(children.len(), 2);
+        assert_eq!(
+            children[1].loc,
+            "https://csa-iot.org/csa_product-sitemap.xml"
+        );
+
+        let xml = r#"<urlset><url><loc><![CDATA[https://csa-iot.org/csa_product/a/?x=1&amp;y=2]]></loc></url>
+            <url><loc>https://csa-iot.org/csa_product/</loc></url>
+            <url><loc>https://csa-iot.org/news/</loc><lastmod>2024-05-01</lastmod></url></urlset>"#;
+        let SitemapDocument::UrlSet(entries) = parse_sitemap(xml) else {
+            panic!("expected url set");
+        };
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].loc, "https://csa-iot.org/csa_product/a/?x=1&y=2");
+        assert_eq!(entries[2].lastmod.as_deref(), Some("2024-05-01"));
+        // listing root and non-product pages are dropped
+        assert_eq!(select_product_entries(entries).len(), 1);
+    }
+
+    #[test]
+    fn decodes_gzip_body() {
+        use std::io::Write;
+        let xml = url_set(3);
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(xml.as_bytes()).unwrap();
+        let gz = encoder.finish().unwrap();
+        assert_eq!(decode_sitemap_body(&gz).unwrap(), xml);
+        assert_eq!(decode_sitemap_body(xml.as_bytes()).unwrap(), xml);
+    }
+
+    #[test]
+    fn slices_newest_first_pages_with_canonical_coordinates() {
+        let SitemapDocument::UrlSet(entries) = parse_sitemap(&url_set(26)) else {
+            panic!("expected url set");
+        };
+        let products = select_product_entries(entries);
+        assert_eq!(sitemap_page_layout(products.len()), (3, 2));
+
+        let newest = sitemap_page_urls(&products, 1);
+        assert_eq!(newest.len(), 12);
+        // newest product sits at the top of the highest page_id
+        assert_eq!((newest[0].page_id, newest[0].index_in_page), (2, 1));
+        let oldest = sitemap_page_urls(&products, 3);
+        assert_eq!(oldest.len(), 2);
+        assert_eq!((oldest[1].page_id, oldest[1].index_in_page), (0, 0));
+        assert!(sitemap_page_urls(&products, 4).is_empty());
+    }
+
+    #[test]
+    fn undated_entries_sort_last() {
+        let entries = vec![
+            SitemapEntry {
+                loc: "https://csa-iot.org/csa_product/old/".into(),
+                lastmod: None,
+            },
+            SitemapEntry {
+                loc: "https://csa-iot.org/csa_product/new/".into(),
+                lastmod: Some("2025-03-01".into()),
+            },
+        ];
+        let sorted = select_product_entries(entries);
+        assert!(sorted[0].loc.ends_with("/new/"));
+    }
+}
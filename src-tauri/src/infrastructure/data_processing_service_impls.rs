@@ -4,7 +4,8 @@
 
 use anyhow::{Result, anyhow};
 use async_trait::async_trait;
-use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use tracing::{debug, info, warn};
 
 use crate::domain::product::Product;
@@ -194,6 +195,115 @@ impl DeduplicationService for DeduplicationServiceImpl {
         }
         Ok(false)
     }
+
+    async fn analyze_identity_duplicates(
+        &self,
+        records: &[IdentityRecord],
+    ) -> Result<Vec<IdentityDuplicateGroup>> {
+        // URL 하나가 노드 하나; 같은 식별 키를 가진 노드끼리 합친다 (union-find)
+        let mut url_index: HashMap<&str, usize> = HashMap::new();
+        let mut nodes: Vec<IdentityNode> = Vec::new();
+        let mut parent: Vec<usize> = Vec::new();
+        let mut key_nodes: HashMap<IdentityKey, BTreeSet<usize>> = HashMap::new();
+
+        for record in records {
+            let node = *url_index.entry(record.url.as_str()).or_insert_with(|| {
+                nodes.push(IdentityNode {
+                    url: &record.url,
+                    updated_at: record.updated_at,
+                    certificate_ids: BTreeSet::new(),
+                });
+                parent.push(parent.len());
+                parent.len() - 1
+            });
+            let entry = &mut nodes[node];
+            entry.updated_at = entry.updated_at.max(record.updated_at);
+            let certificate_id = record.certificate_id.as_deref().map(str::trim);
+            if let Some(certificate_id) = certificate_id.filter(|c| !c.is_empty()) {
+                entry.certificate_ids.insert(certificate_id.to_string());
+            }
+            for key in record.identity_keys() {
+                let owners = key_nodes.entry(key).or_default();
+                if let Some(&owner) = owners.iter().next() {
+                    union_roots(&mut parent, owner, node);
+                }
+                owners.insert(node);
+            }
+        }
+
+        let mut members: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for node in 0..nodes.len() {
+            members
+                .entry(find_root(&mut parent, node))
+                .or_default()
+                .push(node);
+        }
+        let mut group_keys: HashMap<usize, Vec<IdentityKey>> = HashMap::new();
+        for (key, owners) in key_nodes.into_iter().filter(|(_, o)| o.len() > 1) {
+            let first = *owners.iter().next().expect("key has owners");
+            group_keys
+                .entry(find_root(&mut parent, first))
+                .or_default()
+                .push(key);
+        }
+
+        let mut groups: Vec<IdentityDuplicateGroup> = members
+            .into_iter()
+            .filter(|(_, m)| m.len() > 1)
+            .map(|(root, mut member_nodes)| {
+                member_nodes.sort_by(|a, b| {
+                    nodes[*b]
+                        .updated_at
+                        .cmp(&nodes[*a].updated_at)
+                        .then_with(|| nodes[*a].url.cmp(nodes[*b].url))
+                });
+                let mut keys = group_keys.remove(&root).unwrap_or_default();
+                keys.sort();
+                let certificate_ids: BTreeSet<&String> = member_nodes
+                    .iter()
+                    .flat_map(|n| nodes[*n].certificate_ids.iter())
+                    .collect();
+                IdentityDuplicateGroup {
+                    keys,
+                    urls: member_nodes
+                        .iter()
+                        .map(|n| nodes[*n].url.to_string())
+                        .collect(),
+                    certificate_ids: certificate_ids.into_iter().cloned().collect(),
+                }
+            })
+            .collect();
+        groups.sort_by(|a, b| a.keys.cmp(&b.keys).then_with(|| a.urls.cmp(&b.urls)));
+
+        info!(
+            "Identity duplicate analysis: {} records, {} groups",
+            records.len(),
+            groups.len()
+        );
+        Ok(groups)
+    }
+}
+
+/// 식별 키 분석의 URL 노드
+struct IdentityNode<'a> {
+    url: &'a str,
+    updated_at: DateTime<Utc>,
+    certificate_ids: BTreeSet<String>,
+}
+
+fn find_root(parent: &mut [usize], mut node: usize) -> usize {
+    while parent[node] != node {
+        parent[node] = parent[parent[node]];
+        node = parent[node];
+    }
+    node
+}
+
+fn union_roots(parent: &mut [usize], a: usize, b: usize) {
+    let (a, b) = (find_root(parent, a), find_root(parent, b));
+    if a != b {
+        parent[b.max(a)] = a.min(b);
+    }
 }
 
 /// 데이터 유효성 검사 서비스 구현체
@@ -5,10 +5,17 @@
 #![allow(clippy::unnecessary_operation)]
 #![allow(unused_must_use)]
 
+use crate::infrastructure::config::{SqliteJournalMode, SqliteRuntimeConfig, SqliteSynchronous};
 use anyhow::Result;
-use sqlx::{SqlitePool, sqlite::SqlitePoolOptions};
+use sqlx::SqlitePool;
+use sqlx::sqlite::{
+    SqliteConnectOptions, SqliteJournalMode as SqlxJournalMode, SqlitePoolOptions,
+    SqliteSynchronous as SqlxSynchronous,
+};
 use std::path::Path;
-use std::sync::OnceLock;
+use std::str::FromStr;
+use std::sync::RwLock;
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
 #[derive(Clone)]
@@ -41,7 +48,7 @@ impl DatabaseConnection {
 
         let pool = SqlitePoolOptions::new()
             .max_connections(crate::infrastructure::config::defaults::MAX_CONCURRENT_REQUESTS)
-            .connect(database_url)
+            .connect_with(sqlite_connect_options(database_url)?)
             .await?;
 
         Ok(Self { pool })
@@ -224,6 +231,131 @@ impl DatabaseConnection {
             debug!("ℹ️ Migration 006 not needed (unique slot indexes exist)");
         }
 
+        // Apply 007_product_history.sql if the change-history trigger is missing
+        let has_history_trigger: Option<i64> = sqlx::query_scalar(
+            "SELECT 1 FROM sqlite_master WHERE type='trigger' AND name='product_details_history' LIMIT 1;",
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .flatten();
+
+        if has_history_trigger.is_none() {
+            if concise {
+                debug!("🧩 Applying migration 007_product_history.sql (product change history)");
+            } else {
+                info!("🧩 Applying migration 007_product_history.sql (product change history)");
+            }
+            let migration_path = std::path::Path::new("migrations/007_product_history.sql");
+            if migration_path.exists() {
+                let migration_sql = fs::read_to_string(migration_path)?;
+                sqlx::query(&migration_sql).execute(&self.pool).await?;
+            } else {
+                sqlx::query(PRODUCT_HISTORY_MIGRATION)
+                    .execute(&self.pool)
+                    .await?;
+            }
+            if concise {
+                debug!("✅ Migration 007 applied");
+            } else {
+                info!("✅ Migration 007 applied");
+            }
+        } else if !concise {
+            debug!("ℹ️ Migration 007 not needed (product history trigger exists)");
+        }
+
+        // Apply 008_sync_session_changeset.sql if sync_sessions.changeset_json is missing
+        let has_changeset_col: Option<i64> = sqlx::query_scalar(
+            "SELECT 1 FROM pragma_table_info('sync_sessions') WHERE name='changeset_json' LIMIT 1;",
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .flatten();
+
+        if has_changeset_col.is_none() {
+            if concise {
+                debug!("🧩 Applying migration 008_sync_session_changeset.sql (sync changesets)");
+            } else {
+                info!("🧩 Applying migration 008_sync_session_changeset.sql (sync changesets)");
+            }
+            let migration_path = std::path::Path::new("migrations/008_sync_session_changeset.sql");
+            if migration_path.exists() {
+                let migration_sql = fs::read_to_string(migration_path)?;
+                sqlx::query(&migration_sql).execute(&self.pool).await?;
+            } else {
+                let migration_sql = include_str!("../../migrations/008_sync_session_changeset.sql");
+                sqlx::query(migration_sql).execute(&self.pool).await?;
+            }
+            if concise {
+                debug!("✅ Migration 008 applied");
+            } else {
+                info!("✅ Migration 008 applied");
+            }
+        } else if !concise {
+            debug!("ℹ️ Migration 008 not needed (sync_sessions.changeset_json exists)");
+        }
+
+        // Apply 009_sync_session_workspace.sql if sync_sessions.workspace is missing
+        let has_workspace_col: Option<i64> = sqlx::query_scalar(
+            "SELECT 1 FROM pragma_table_info('sync_sessions') WHERE name='workspace' LIMIT 1;",
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .flatten();
+
+        if has_workspace_col.is_none() {
+            if concise {
+                debug!("🧩 Applying migration 009_sync_session_workspace.sql (session workspace)");
+            } else {
+                info!("🧩 Applying migration 009_sync_session_workspace.sql (session workspace)");
+            }
+            let migration_path = std::path::Path::new("migrations/009_sync_session_workspace.sql");
+            if migration_path.exists() {
+                let migration_sql = fs::read_to_string(migration_path)?;
+                sqlx::query(&migration_sql).execute(&self.pool).await?;
+            } else {
+                let migration_sql = include_str!("../../migrations/009_sync_session_workspace.sql");
+                sqlx::query(migration_sql).execute(&self.pool).await?;
+            }
+            if concise {
+                debug!("✅ Migration 009 applied");
+            } else {
+                info!("✅ Migration 009 applied");
+            }
+        } else if !concise {
+            debug!("ℹ️ Migration 009 not needed (sync_sessions.workspace exists)");
+        }
+
+        // Apply 010+ (feature tables) whose probe object is missing
+        for &(name, probe, embedded_sql) in TABLE_MIGRATIONS {
+            let present: Option<i64> = sqlx::query_scalar(probe)
+                .fetch_optional(&self.pool)
+                .await?
+                .flatten();
+            if present.is_some() {
+                if !concise {
+                    debug!("ℹ️ Migration {} not needed", name);
+                }
+                continue;
+            }
+            if concise {
+                debug!("🧩 Applying migration {}.sql", name);
+            } else {
+                info!("🧩 Applying migration {}.sql", name);
+            }
+            let migration_path = Path::new("migrations").join(format!("{name}.sql"));
+            if migration_path.exists() {
+                let migration_sql = fs::read_to_string(migration_path)?;
+                sqlx::query(&migration_sql).execute(&self.pool).await?;
+            } else {
+                sqlx::query(embedded_sql).execute(&self.pool).await?;
+            }
+            if concise {
+                debug!("✅ Migration {} applied", name);
+            } else {
+                info!("✅ Migration {} applied", name);
+            }
+        }
+
         // Report on database status
         let product_count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM products")
             .fetch_one(&self.pool)
@@ -248,27 +380,258 @@ impl DatabaseConnection {
     }
 }
 
+/// Migrations `DatabaseConnection::migrate` would apply to `pool`, judged with the same probes
+/// (003 is `CREATE ... IF NOT EXISTS` and always runs, so it is not listed)
+pub async fn pending_migrations(pool: &SqlitePool) -> Result<Vec<&'static str>> {
+    async fn exists(pool: &SqlitePool, sql: &str) -> Result<bool> {
+        let found: Option<i64> = sqlx::query_scalar(sql).fetch_optional(pool).await?;
+        Ok(found.is_some())
+    }
+
+    let mut pending = Vec::new();
+    if exists(
+        pool,
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name='matter_products' LIMIT 1",
+    )
+    .await?
+        && exists(pool, "SELECT 1 FROM matter_products LIMIT 1").await?
+    {
+        pending.push("004_migrate_legacy_data");
+    }
+    if !exists(
+        pool,
+        "SELECT 1 FROM pragma_table_info('products') WHERE name='id' LIMIT 1",
+    )
+    .await?
+    {
+        pending.push("005_add_product_id");
+    }
+    if !exists(
+        pool,
+        "SELECT 1 FROM sqlite_master WHERE type='index' AND name='ux_products_slot' LIMIT 1",
+    )
+    .await?
+        || !exists(
+            pool,
+            "SELECT 1 FROM sqlite_master WHERE type='index' AND name='ux_product_details_slot' LIMIT 1",
+        )
+        .await?
+    {
+        pending.push("006_add_unique_slot_index");
+    }
+    if !exists(
+        pool,
+        "SELECT 1 FROM sqlite_master WHERE type='trigger' AND name='product_details_history' LIMIT 1",
+    )
+    .await?
+    {
+        pending.push("007_product_history");
+    }
+    if !exists(
+        pool,
+        "SELECT 1 FROM pragma_table_info('sync_sessions') WHERE name='changeset_json' LIMIT 1",
+    )
+    .await?
+    {
+        pending.push("008_sync_session_changeset");
+    }
+    if !exists(
+        pool,
+        "SELECT 1 FROM pragma_table_info('sync_sessions') WHERE name='workspace' LIMIT 1",
+    )
+    .await?
+    {
+        pending.push("009_sync_session_workspace");
+    }
+    for &(name, probe, _) in TABLE_MIGRATIONS {
+        if !exists(pool, probe).await? {
+            pending.push(name);
+        }
+    }
+    Ok(pending)
+}
+
+/// Migrations 010+ that create feature tables: (name, probe, embedded SQL). A migration applies
+/// when its probe (the last object the file creates) finds nothing; `migrations/<name>.sql` wins
+/// over the embedded copy, as for 004–009.
+const TABLE_MIGRATIONS: &[(&str, &str, &str)] = &[
+    (
+        "010_session_checkpoints",
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name='session_checkpoints' LIMIT 1",
+        include_str!("../../migrations/010_session_checkpoints.sql"),
+    ),
+    (
+        "011_crawl_schedules",
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name='crawl_schedules' LIMIT 1",
+        include_str!("../../migrations/011_crawl_schedules.sql"),
+    ),
+    (
+        "012_rolling_refresh_state",
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name='rolling_refresh_state' LIMIT 1",
+        include_str!("../../migrations/012_rolling_refresh_state.sql"),
+    ),
+    (
+        "013_dedup_conflicts",
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name='dedup_resolutions' LIMIT 1",
+        include_str!("../../migrations/013_dedup_conflicts.sql"),
+    ),
+    (
+        "014_watchlist",
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name='watchlist_snapshots' LIMIT 1",
+        include_str!("../../migrations/014_watchlist.sql"),
+    ),
+    (
+        "015_search_index",
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name='manufacturer_rollups' LIMIT 1",
+        include_str!("../../migrations/015_search_index.sql"),
+    ),
+    (
+        "016_needs_revalidation",
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name='needs_revalidation' LIMIT 1",
+        include_str!("../../migrations/016_needs_revalidation.sql"),
+    ),
+    (
+        "017_analytics_versions",
+        "SELECT 1 FROM sqlite_master WHERE type='trigger' AND name='analytics_product_details_device_type' LIMIT 1",
+        include_str!("../../migrations/017_analytics_versions.sql"),
+    ),
+    (
+        "018_sync_remaining_pages",
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name='sync_remaining_pages' LIMIT 1",
+        include_str!("../../migrations/018_sync_remaining_pages.sql"),
+    ),
+    (
+        "019_validation_findings",
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name='validation_findings' LIMIT 1",
+        include_str!("../../migrations/019_validation_findings.sql"),
+    ),
+    (
+        "020_session_notes",
+        "SELECT 1 FROM sqlite_master WHERE type='index' AND name='idx_session_notes_session' LIMIT 1",
+        include_str!("../../migrations/020_session_notes.sql"),
+    ),
+    (
+        "021_slot_reservations",
+        "SELECT 1 FROM sqlite_master WHERE type='index' AND name='idx_slot_reservations_url' LIMIT 1",
+        include_str!("../../migrations/021_slot_reservations.sql"),
+    ),
+    (
+        "022_sync_page_timings",
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name='sync_page_timings' LIMIT 1",
+        include_str!("../../migrations/022_sync_page_timings.sql"),
+    ),
+    (
+        "023_batch_metrics",
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name='batch_metrics' LIMIT 1",
+        include_str!("../../migrations/023_batch_metrics.sql"),
+    ),
+    (
+        "024_page_url_sets",
+        "SELECT 1 FROM sqlite_master WHERE type='index' AND name='idx_page_url_sets_page' LIMIT 1",
+        include_str!("../../migrations/024_page_url_sets.sql"),
+    ),
+    (
+        "025_failed_items",
+        "SELECT 1 FROM sqlite_master WHERE type='index' AND name='idx_failed_items_status' LIMIT 1",
+        include_str!("../../migrations/025_failed_items.sql"),
+    ),
+];
+
+/// Embedded 007 migration; also used by `services::product_history` to ensure the table lazily
+pub(crate) const PRODUCT_HISTORY_MIGRATION: &str =
+    include_str!("../../migrations/007_product_history.sql");
+
 // -----------------------------------------------------------------------------
 // Global, reusable Sqlite pool (reuse-first; safe fallback to init when absent)
 // -----------------------------------------------------------------------------
 
-static GLOBAL_SQLITE_POOL: OnceLock<SqlitePool> = OnceLock::new();
+/// Replaced (not just set) when the active workspace switches
+static GLOBAL_SQLITE_POOL: RwLock<Option<SqlitePool>> = RwLock::new(None);
+
+/// Pragmas applied to every new connection; set from `advanced.sqlite` at startup and on config
+/// update. Pools that already exist keep their settings until the app restarts.
+static SQLITE_SETTINGS: RwLock<Option<SqliteRuntimeConfig>> = RwLock::new(None);
+
+pub fn set_sqlite_settings(settings: SqliteRuntimeConfig) {
+    if let Ok(mut guard) = SQLITE_SETTINGS.write() {
+        *guard = Some(settings);
+    }
+}
+
+pub fn current_sqlite_settings() -> SqliteRuntimeConfig {
+    SQLITE_SETTINGS
+        .read()
+        .ok()
+        .and_then(|guard| guard.clone())
+        .unwrap_or_default()
+}
+
+/// Connect options for `database_url` with the configured journal mode, busy timeout and
+/// synchronous level
+pub fn sqlite_connect_options(database_url: &str) -> Result<SqliteConnectOptions> {
+    let settings = current_sqlite_settings();
+    let options = SqliteConnectOptions::from_str(database_url)?
+        .create_if_missing(true)
+        .journal_mode(match settings.journal_mode {
+            SqliteJournalMode::Wal => SqlxJournalMode::Wal,
+            SqliteJournalMode::Delete => SqlxJournalMode::Delete,
+            SqliteJournalMode::Truncate => SqlxJournalMode::Truncate,
+            SqliteJournalMode::Persist => SqlxJournalMode::Persist,
+            SqliteJournalMode::Memory => SqlxJournalMode::Memory,
+            SqliteJournalMode::Off => SqlxJournalMode::Off,
+        })
+        .synchronous(match settings.synchronous {
+            SqliteSynchronous::Off => SqlxSynchronous::Off,
+            SqliteSynchronous::Normal => SqlxSynchronous::Normal,
+            SqliteSynchronous::Full => SqlxSynchronous::Full,
+            SqliteSynchronous::Extra => SqlxSynchronous::Extra,
+        })
+        .busy_timeout(Duration::from_millis(settings.busy_timeout_ms));
+    Ok(options)
+}
 
 /// Get the global Sqlite pool if initialized, or initialize it on first use.
 /// Uses the centralized database URL and standard pool options.
 pub async fn get_or_init_global_pool() -> Result<SqlitePool> {
-    if let Some(pool) = GLOBAL_SQLITE_POOL.get() {
-        return Ok(pool.clone());
+    if let Some(pool) = current_global_pool() {
+        return Ok(pool);
     }
 
     let database_url = crate::infrastructure::database_paths::get_main_database_url();
     let pool = SqlitePoolOptions::new()
         .max_connections(crate::infrastructure::config::defaults::MAX_CONCURRENT_REQUESTS)
-        .connect(&database_url)
+        .connect_with(sqlite_connect_options(&database_url)?)
         .await?;
 
     // Best-effort set; if already set by a racy concurrent init, prefer the existing one
-    let _ = GLOBAL_SQLITE_POOL.set(pool.clone());
+    let mut guard = GLOBAL_SQLITE_POOL
+        .write()
+        .unwrap_or_else(|e| e.into_inner());
+    Ok(guard.get_or_insert(pool).clone())
+}
+
+fn current_global_pool() -> Option<SqlitePool> {
+    GLOBAL_SQLITE_POOL
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Open and migrate `database_url`, then make it the global pool (workspace switch).
+/// The previous pool is closed so clones still held elsewhere fail instead of writing to the
+/// old workspace.
+pub async fn replace_global_pool(database_url: &str) -> Result<SqlitePool> {
+    let db = DatabaseConnection::new(database_url).await?;
+    db.migrate().await?;
+    let pool = db.pool().clone();
+    let previous = GLOBAL_SQLITE_POOL
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .replace(pool.clone());
+    if let Some(previous) = previous {
+        previous.close().await;
+    }
+    info!("🔌 Global database pool switched to {}", database_url);
     Ok(pool)
 }
 
@@ -302,6 +665,29 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_connection_applies_sqlite_pragmas() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let database_url = format!(
+            "sqlite:{}",
+            temp_dir.path().join("wal.db").to_string_lossy()
+        );
+        let db = DatabaseConnection::new(&database_url).await?;
+
+        let journal_mode: String = sqlx::query_scalar("PRAGMA journal_mode")
+            .fetch_one(db.pool())
+            .await?;
+        assert_eq!(journal_mode.to_lowercase(), "wal");
+        let busy_timeout: i64 = sqlx::query_scalar("PRAGMA busy_timeout")
+            .fetch_one(db.pool())
+            .await?;
+        assert_eq!(
+            busy_timeout as u64,
+            crate::infrastructure::config::defaults::SQLITE_BUSY_TIMEOUT_MS
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_database_migration() -> Result<()> {
         let temp_dir = tempdir()?;
@@ -5,37 +5,74 @@
 //!
 //! 핵심 원칙:
 //! 1. 단일 책임: 데이터베이스 경로 관리만 담당
-//! 2. 불변성: 한번 설정된 경로는 변경되지 않음
+//! 2. 불변성: 경로는 워크스페이스 전환(`activate_workspace`) 외에는 변경되지 않음
 //! 3. 예측 가능성: 항상 동일한 경로 생성 로직
 //! 4. 에러 안전성: 경로 생성 실패 시 안전한 폴백
+//!
+//! 파괴적 명령(`reset_product_storage`, `cleanup_duplicate_urls`) 전에는 DB 파일 옆 `backups/`에
+//! WAL 체크포인트 후 타임스탬프 백업을 남긴다. 복원은 열린 풀 아래에서 파일을 덮어쓸 수 없으므로
+//! `<db>.restore-pending`으로 예약해 두고 다음 시작 시 풀을 열기 전에 적용한다.
+//!
+//! 워크스페이스: `default` 는 기존 `database/matter_certis.db`, 그 외 이름은
+//! `database/workspaces/<name>/matter_certis.db` 로 분리된 SQLite 파일을 쓴다. 활성 워크스페이스
+//! 이름은 `database/active_workspace` 에 남겨 다음 시작 때도 같은 파일을 연다.
 
 use anyhow::{Context, Result};
-use std::path::PathBuf;
-use std::sync::OnceLock;
-use tracing::{debug, info};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tracing::{debug, info, warn};
+
+/// Backup directory, next to the database file
+pub const BACKUP_DIR_NAME: &str = "backups";
+
+/// Suffix of a staged restore applied at the next startup
+const PENDING_RESTORE_SUFFIX: &str = ".restore-pending";
+
+/// Workspace backed by the original `database/matter_certis.db`
+pub const DEFAULT_WORKSPACE: &str = "default";
+
+/// Directory under `database/` holding the named workspaces
+const WORKSPACES_DIR_NAME: &str = "workspaces";
+
+/// Marker file under `database/` naming the active workspace
+const ACTIVE_WORKSPACE_FILE: &str = "active_workspace";
+
+const DATABASE_FILE_NAME: &str = "matter_certis.db";
 
-/// 전역 데이터베이스 경로 관리자 (싱글톤)
-static DATABASE_PATH_MANAGER: OnceLock<DatabasePathManager> = OnceLock::new();
+/// 전역 데이터베이스 경로 관리자 (싱글톤, 워크스페이스 전환 시 교체)
+static DATABASE_PATH_MANAGER: RwLock<Option<DatabasePathManager>> = RwLock::new(None);
 
-/// 데이터베이스 경로 관리자 (단순화: 메인 DB만 사용)
+/// 데이터베이스 경로 관리자 (활성 워크스페이스의 DB 하나만 사용)
 #[derive(Debug, Clone)]
 pub struct DatabasePathManager {
     /// 기본 데이터베이스 파일 경로 (절대 경로)
     pub main_database_path: PathBuf,
-    /// 데이터베이스 디렉토리 경로
+    /// 데이터베이스 디렉토리 경로 (워크스페이스와 무관한 루트)
     pub database_directory: PathBuf,
+    /// 워크스페이스 이름
+    pub workspace: String,
 }
 
 impl DatabasePathManager {
-    /// 새로운 경로 관리자 생성 (내부 용도)
+    /// 새로운 경로 관리자 생성 (내부 용도, 마지막 활성 워크스페이스)
     fn new() -> Result<Self> {
         let app_data_dir = Self::get_app_data_directory()?;
         let database_directory = app_data_dir.join("database");
+        let workspace = read_active_workspace(&database_directory);
 
-        Ok(Self {
-            main_database_path: database_directory.join("matter_certis.db"),
-            database_directory,
-        })
+        Ok(Self::for_workspace(&database_directory, &workspace))
+    }
+
+    /// `database_directory` 아래 `workspace` 의 경로 관리자 (이름은 검증된 것이어야 함)
+    pub fn for_workspace(database_directory: &Path, workspace: &str) -> Self {
+        Self {
+            main_database_path: workspace_database_path(database_directory, workspace),
+            database_directory: database_directory.to_path_buf(),
+            workspace: workspace.to_string(),
+        }
     }
 
     /// 앱 데이터 디렉토리 결정 (Modern Rust 2024 방식)
@@ -56,16 +93,22 @@ impl DatabasePathManager {
     /// 전역 인스턴스 초기화 (앱 시작 시 한 번만 호출)
     pub fn initialize() -> Result<()> {
         let manager = Self::new()?;
-        DATABASE_PATH_MANAGER
-            .set(manager)
-            .map_err(|_| anyhow::anyhow!("DatabasePathManager가 이미 초기화되었습니다"))?;
+        let mut guard = DATABASE_PATH_MANAGER
+            .write()
+            .unwrap_or_else(|e| e.into_inner());
+        if guard.is_some() {
+            anyhow::bail!("DatabasePathManager가 이미 초기화되었습니다");
+        }
+        *guard = Some(manager);
         Ok(())
     }
 
-    /// 전역 인스턴스 가져오기
-    pub fn global() -> &'static DatabasePathManager {
+    /// 전역 인스턴스 가져오기 (전환될 수 있으므로 복사본)
+    pub fn global() -> DatabasePathManager {
         DATABASE_PATH_MANAGER
-            .get()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
             .expect("DatabasePathManager가 초기화되지 않았습니다. initialize()를 먼저 호출하세요")
     }
 
@@ -147,12 +190,17 @@ impl DatabasePathManager {
             .await
             .context("디렉토리 생성 단계 실패")?;
 
-        // 2. 파일 생성
+        // 2. 예약된 백업 복원 적용 (풀을 열기 전)
+        if let Some(source) = self.apply_pending_restore().await? {
+            info!("♻️ 예약된 DB 백업 복원 적용: {}", source);
+        }
+
+        // 3. 파일 생성
         self.ensure_database_file_exists()
             .await
             .context("파일 생성 단계 실패")?;
 
-        // 3. 권한 확인
+        // 4. 권한 확인
         if !self.is_database_writable() {
             anyhow::bail!(
                 "데이터베이스 파일에 쓰기 권한이 없습니다: {}",
@@ -170,6 +218,369 @@ impl DatabasePathManager {
 
         Ok(())
     }
+
+    /// Replace the database with a staged restore, if any; returns the staged file's name.
+    /// WAL/SHM files of the replaced database are removed so they are not replayed onto it.
+    pub async fn apply_pending_restore(&self) -> Result<Option<String>> {
+        let pending = pending_restore_path(&self.main_database_path);
+        if !pending.exists() {
+            return Ok(None);
+        }
+        for suffix in ["-wal", "-shm"] {
+            let side = sidecar_path(&self.main_database_path, suffix);
+            if side.exists() {
+                tokio::fs::remove_file(&side)
+                    .await
+                    .with_context(|| format!("Failed to remove {}", side.display()))?;
+            }
+        }
+        tokio::fs::rename(&pending, &self.main_database_path)
+            .await
+            .context("예약된 복원 파일 적용 실패")?;
+        Ok(Some(pending.display().to_string()))
+    }
+}
+
+/// A database backup file in `backups/`
+#[derive(Debug, Clone, Serialize)]
+pub struct DbBackup {
+    pub file_name: String,
+    /// Command that triggered the backup (e.g. `reset_product_storage`, `pre_restore`)
+    pub reason: String,
+    pub created_at: DateTime<Utc>,
+    pub size_bytes: u64,
+}
+
+/// Result of `stage_db_restore`; the restore takes effect on the next app start
+#[derive(Debug, Clone, Serialize)]
+pub struct DbRestorePlan {
+    pub restored_from: String,
+    /// Backup of the current database taken before staging
+    pub safety_backup: DbBackup,
+    pub restart_required: bool,
+}
+
+fn sidecar_path(db: &Path, suffix: &str) -> PathBuf {
+    let mut name = db.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+fn pending_restore_path(db: &Path) -> PathBuf {
+    sidecar_path(db, PENDING_RESTORE_SUFFIX)
+}
+
+/// File path of the pool's main database (errors for in-memory databases)
+pub(crate) async fn database_file(pool: &SqlitePool) -> Result<PathBuf> {
+    let files: Vec<(i64, String, String)> = sqlx::query_as("PRAGMA database_list")
+        .fetch_all(pool)
+        .await?;
+    files
+        .into_iter()
+        .find(|(_, name, file)| name == "main" && !file.is_empty())
+        .map(|(_, _, file)| PathBuf::from(file))
+        .context("In-memory database has no file to back up")
+}
+
+fn backup_dir(db: &Path) -> PathBuf {
+    db.parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(BACKUP_DIR_NAME)
+}
+
+fn backup_stem(db: &Path) -> String {
+    db.file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "database".into())
+}
+
+/// Checkpoint the WAL into the main file and copy it to `backups/<stem>-<utc stamp>-<reason>.db`,
+/// then delete the oldest backups beyond `max_backups`.
+pub async fn backup_database(
+    pool: &SqlitePool,
+    reason: &str,
+    max_backups: u32,
+) -> Result<DbBackup> {
+    let db = database_file(pool).await?;
+    let dir = backup_dir(&db);
+    tokio::fs::create_dir_all(&dir)
+        .await
+        .with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    // A busy checkpoint leaves committed pages in the WAL, so the copy would miss them
+    let (busy, _, _): (i64, i64, i64) = sqlx::query_as("PRAGMA wal_checkpoint(TRUNCATE)")
+        .fetch_one(pool)
+        .await
+        .context("WAL checkpoint failed")?;
+    if busy != 0 {
+        anyhow::bail!("Database is busy; WAL checkpoint incomplete, backup not taken");
+    }
+
+    let reason: String = reason
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let now = Utc::now();
+    let file_name = format!(
+        "{}-{}-{}.db",
+        backup_stem(&db),
+        now.format("%Y%m%d-%H%M%S%3f"),
+        reason
+    );
+    let target = dir.join(&file_name);
+    let size_bytes = tokio::fs::copy(&db, &target)
+        .await
+        .with_context(|| format!("Failed to copy database to {}", target.display()))?;
+    info!(
+        "💾 DB backup created: {} ({} bytes)",
+        target.display(),
+        size_bytes
+    );
+
+    let backups = list_backups_in(&db).await?;
+    for stale in backups.iter().skip(max_backups.max(1) as usize) {
+        if let Err(e) = tokio::fs::remove_file(dir.join(&stale.file_name)).await {
+            warn!("Failed to prune DB backup {}: {}", stale.file_name, e);
+        }
+    }
+
+    Ok(DbBackup {
+        file_name,
+        reason,
+        created_at: now,
+        size_bytes,
+    })
+}
+
+/// Backups of the pool's database, newest first
+pub async fn list_db_backups(pool: &SqlitePool) -> Result<Vec<DbBackup>> {
+    list_backups_in(&database_file(pool).await?).await
+}
+
+async fn list_backups_in(db: &Path) -> Result<Vec<DbBackup>> {
+    let dir = backup_dir(db);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let prefix = format!("{}-", backup_stem(db));
+    let mut backups = Vec::new();
+    let mut entries = tokio::fs::read_dir(&dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        // <stem>-YYYYMMDD-HHMMSSmmm-<reason>.db
+        let Some(rest) = file_name
+            .strip_prefix(&prefix)
+            .and_then(|r| r.strip_suffix(".db"))
+        else {
+            continue;
+        };
+        let Some((stamp, reason)) = rest.get(..18).zip(rest.get(19..)) else {
+            continue;
+        };
+        let Ok(created) = chrono::NaiveDateTime::parse_from_str(stamp, "%Y%m%d-%H%M%S%3f") else {
+            continue;
+        };
+        let size_bytes = entry.metadata().await.map(|m| m.len()).unwrap_or(0);
+        backups.push(DbBackup {
+            file_name,
+            reason: reason.to_string(),
+            created_at: created.and_utc(),
+            size_bytes,
+        });
+    }
+    backups.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(backups)
+}
+
+/// Back up the current database, then stage `file_name` to replace it at the next startup
+pub async fn stage_db_restore(
+    pool: &SqlitePool,
+    file_name: &str,
+    max_backups: u32,
+) -> Result<DbRestorePlan> {
+    let db = database_file(pool).await?;
+    // Only plain names listed in backups/ are accepted
+    let source = list_backups_in(&db)
+        .await?
+        .into_iter()
+        .find(|b| b.file_name == file_name)
+        .with_context(|| format!("No such backup: {file_name}"))?;
+    let safety_backup = backup_database(pool, "pre_restore", max_backups.saturating_add(1)).await?;
+    let pending = pending_restore_path(&db);
+    tokio::fs::copy(backup_dir(&db).join(&source.file_name), &pending)
+        .await
+        .with_context(|| format!("Failed to stage {}", pending.display()))?;
+    info!(
+        "♻️ DB restore staged from {} (applies on restart)",
+        source.file_name
+    );
+    Ok(DbRestorePlan {
+        restored_from: source.file_name,
+        safety_backup,
+        restart_required: true,
+    })
+}
+
+/// A named database workspace
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WorkspaceInfo {
+    pub name: String,
+    pub database_path: String,
+    pub active: bool,
+    /// Size of the main database file (0 before the first connection)
+    pub size_bytes: u64,
+}
+
+/// Trimmed, lower-cased workspace name; 1-40 of `a-z`, `0-9`, `-`, `_`
+pub fn validate_workspace_name(name: &str) -> Result<String> {
+    let name = name.trim().to_ascii_lowercase();
+    if name.is_empty() || name.len() > 40 {
+        anyhow::bail!("Workspace name must be 1-40 characters");
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        anyhow::bail!("Workspace name may only contain letters, digits, '-' and '_': {name}");
+    }
+    Ok(name)
+}
+
+fn workspace_database_path(database_directory: &Path, workspace: &str) -> PathBuf {
+    if workspace == DEFAULT_WORKSPACE {
+        database_directory.join(DATABASE_FILE_NAME)
+    } else {
+        database_directory
+            .join(WORKSPACES_DIR_NAME)
+            .join(workspace)
+            .join(DATABASE_FILE_NAME)
+    }
+}
+
+/// Active workspace recorded under `database_directory`; unknown or missing falls back to default
+fn read_active_workspace(database_directory: &Path) -> String {
+    std::fs::read_to_string(database_directory.join(ACTIVE_WORKSPACE_FILE))
+        .ok()
+        .and_then(|raw| validate_workspace_name(&raw).ok())
+        .filter(|name| {
+            name == DEFAULT_WORKSPACE
+                || workspace_database_path(database_directory, name)
+                    .parent()
+                    .is_some_and(Path::exists)
+        })
+        .unwrap_or_else(|| DEFAULT_WORKSPACE.to_string())
+}
+
+fn workspace_info(database_directory: &Path, name: &str, active: &str) -> WorkspaceInfo {
+    let path = workspace_database_path(database_directory, name);
+    WorkspaceInfo {
+        name: name.to_string(),
+        size_bytes: std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
+        database_path: path.display().to_string(),
+        active: name == active,
+    }
+}
+
+/// Default first, then named workspaces alphabetically
+fn list_workspaces_in(database_directory: &Path, active: &str) -> Result<Vec<WorkspaceInfo>> {
+    let mut names = Vec::new();
+    let dir = database_directory.join(WORKSPACES_DIR_NAME);
+    if dir.exists() {
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().into_owned();
+            // Directories that do not hold a valid name are not ours
+            if validate_workspace_name(&name).is_ok_and(|valid| valid == name) {
+                names.push(name);
+            }
+        }
+    }
+    names.sort();
+    names.retain(|name| name != DEFAULT_WORKSPACE);
+    Ok(std::iter::once(DEFAULT_WORKSPACE.to_string())
+        .chain(names)
+        .map(|name| workspace_info(database_directory, &name, active))
+        .collect())
+}
+
+async fn create_workspace_in(database_directory: &Path, name: &str) -> Result<DatabasePathManager> {
+    let name = validate_workspace_name(name)?;
+    let manager = DatabasePathManager::for_workspace(database_directory, &name);
+    if name == DEFAULT_WORKSPACE || manager.database_exists() {
+        anyhow::bail!("Workspace already exists: {name}");
+    }
+    manager.ensure_database_file_exists().await?;
+    info!(
+        "🗂️ Workspace created: {} ({})",
+        name,
+        manager.main_database_path.display()
+    );
+    Ok(manager)
+}
+
+/// Workspace the current paths point at (default before initialization)
+pub fn active_workspace() -> String {
+    DATABASE_PATH_MANAGER
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .map(|m| m.workspace.clone())
+        .unwrap_or_else(|| DEFAULT_WORKSPACE.to_string())
+}
+
+/// All workspaces next to the active database
+pub fn list_workspaces() -> Result<Vec<WorkspaceInfo>> {
+    let current = DatabasePathManager::global();
+    list_workspaces_in(&current.database_directory, &current.workspace)
+}
+
+/// Create an empty workspace database; it is migrated when first activated
+pub async fn create_workspace(name: &str) -> Result<WorkspaceInfo> {
+    let current = DatabasePathManager::global();
+    let manager = create_workspace_in(&current.database_directory, name).await?;
+    Ok(workspace_info(
+        &current.database_directory,
+        &manager.workspace,
+        &current.workspace,
+    ))
+}
+
+/// Paths for an existing workspace, fully initialized but not yet active.
+/// The caller opens the pool first and only then calls `activate_workspace`,
+/// so a database that cannot be opened leaves the current workspace in place.
+pub async fn prepare_workspace(name: &str) -> Result<DatabasePathManager> {
+    let current = DatabasePathManager::global();
+    let name = validate_workspace_name(name)?;
+    let manager = DatabasePathManager::for_workspace(&current.database_directory, &name);
+    if name != DEFAULT_WORKSPACE && !manager.database_exists() {
+        anyhow::bail!("No such workspace: {name}");
+    }
+    manager.full_initialization().await?;
+    Ok(manager)
+}
+
+/// Make `manager` the global paths and remember it for the next start
+pub async fn activate_workspace(manager: DatabasePathManager) -> Result<()> {
+    tokio::fs::write(
+        manager.database_directory.join(ACTIVE_WORKSPACE_FILE),
+        &manager.workspace,
+    )
+    .await
+    .context("활성 워크스페이스 기록 실패")?;
+    info!("🗂️ Active workspace: {}", manager.workspace);
+    *DATABASE_PATH_MANAGER
+        .write()
+        .unwrap_or_else(|e| e.into_inner()) = Some(manager);
+    Ok(())
 }
 
 /// 편의 함수들 - 전역에서 쉽게 사용할 수 있도록
@@ -215,6 +626,98 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_backup_list_prune_and_staged_restore() {
+        let temp_dir = TempDir::new().expect("임시 디렉토리 생성 실패");
+        let db_path = temp_dir.path().join("matter_certis.db");
+        let options = sqlx::sqlite::SqliteConnectOptions::new()
+            .filename(&db_path)
+            .create_if_missing(true)
+            .journal_mode(sqlx::sqlite::SqliteJournalMode::Wal);
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE t (v INTEGER)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO t VALUES (1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let first = backup_database(&pool, "reset_product_storage", 2)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM t").execute(&pool).await.unwrap();
+        for _ in 0..2 {
+            // Backup names carry millisecond stamps
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            backup_database(&pool, "cleanup duplicate", 2)
+                .await
+                .unwrap();
+        }
+        let backups = list_db_backups(&pool).await.unwrap();
+        assert_eq!(backups.len(), 2, "oldest backup pruned");
+        assert_eq!(backups[0].reason, "cleanup_duplicate");
+        assert!(backups.iter().all(|b| b.file_name != first.file_name));
+
+        let kept = backups[1].file_name.clone();
+        assert!(
+            stage_db_restore(&pool, "../matter_certis.db", 2)
+                .await
+                .is_err()
+        );
+        let plan = stage_db_restore(&pool, &kept, 2).await.unwrap();
+        assert_eq!(plan.safety_backup.reason, "pre_restore");
+        pool.close().await;
+
+        let manager = DatabasePathManager {
+            main_database_path: db_path.clone(),
+            database_directory: temp_dir.path().to_path_buf(),
+            workspace: DEFAULT_WORKSPACE.to_string(),
+        };
+        assert!(manager.apply_pending_restore().await.unwrap().is_some());
+        assert!(manager.apply_pending_restore().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_named_workspaces_are_separate_files() {
+        let temp_dir = TempDir::new().expect("임시 디렉토리 생성 실패");
+        let root = temp_dir.path();
+        assert!(validate_workspace_name("../etc").is_err());
+        assert!(validate_workspace_name("").is_err());
+        assert_eq!(validate_workspace_name(" Lab-2 ").unwrap(), "lab-2");
+
+        let lab = create_workspace_in(root, "Lab-2").await.unwrap();
+        assert!(
+            lab.main_database_path
+                .starts_with(root.join(WORKSPACES_DIR_NAME))
+        );
+        assert!(create_workspace_in(root, "lab-2").await.is_err());
+        assert!(create_workspace_in(root, DEFAULT_WORKSPACE).await.is_err());
+
+        let names: Vec<String> = list_workspaces_in(root, "lab-2")
+            .unwrap()
+            .into_iter()
+            .map(|w| w.name)
+            .collect();
+        assert_eq!(names, vec!["default", "lab-2"]);
+        assert_eq!(
+            DatabasePathManager::for_workspace(root, DEFAULT_WORKSPACE).main_database_path,
+            root.join(DATABASE_FILE_NAME)
+        );
+
+        // Marker round trip; a marker naming a missing workspace falls back to default
+        assert_eq!(read_active_workspace(root), DEFAULT_WORKSPACE);
+        std::fs::write(root.join(ACTIVE_WORKSPACE_FILE), "lab-2\n").unwrap();
+        assert_eq!(read_active_workspace(root), "lab-2");
+        std::fs::write(root.join(ACTIVE_WORKSPACE_FILE), "gone").unwrap();
+        assert_eq!(read_active_workspace(root), DEFAULT_WORKSPACE);
+    }
+
     #[tokio::test]
     async fn test_directory_creation() {
         let temp_dir = TempDir::new().expect("임시 디렉토리 생성 실패");
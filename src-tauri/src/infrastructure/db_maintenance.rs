@@ -0,0 +1,441 @@
+//! Post-deletion space reclamation for the SQLite database.
+//!
+//! Destructive operations (dedup cleanup, storage reset, sync sweeps) free pages but
+//! SQLite keeps them on the freelist until a vacuum runs. This module measures the
+//! freelist around each operation and, depending on `DbMaintenanceConfig`, either runs
+//! `PRAGMA incremental_vacuum` (when the file uses auto_vacuum=INCREMENTAL) or recommends
+//! a full `VACUUM` once fragmentation crosses the configured threshold.
+//!
+//! `run_maintenance` is the manual counterpart: VACUUM, ANALYZE, integrity_check and REINDEX in
+//! sequence, reporting progress per step and the database file size before/after.
+
+use crate::infrastructure::config::DbMaintenanceConfig;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::time::Instant;
+use tracing::{debug, info, warn};
+
+/// integrity_check messages kept in a maintenance report
+const MAX_INTEGRITY_ISSUES: i64 = 50;
+
+/// SQLite page-level storage snapshot
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageSnapshot {
+    pub page_size: i64,
+    pub page_count: i64,
+    pub freelist_count: i64,
+    /// 0 = NONE, 1 = FULL, 2 = INCREMENTAL
+    pub auto_vacuum: i64,
+}
+
+impl StorageSnapshot {
+    /// Fraction of database pages currently sitting on the freelist
+    pub fn fragmentation_ratio(&self) -> f64 {
+        if self.page_count <= 0 {
+            0.0
+        } else {
+            self.freelist_count as f64 / self.page_count as f64
+        }
+    }
+}
+
+/// Action taken (or suggested) after a destructive operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VacuumAction {
+    /// Nothing to do (below thresholds or automation disabled)
+    None,
+    /// `PRAGMA incremental_vacuum` was executed
+    Incremental,
+    /// Fragmentation crossed the threshold; a full `VACUUM` is recommended
+    FullVacuumRecommended,
+}
+
+/// Space reclamation report attached to destructive command results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VacuumReport {
+    pub operation: String,
+    pub rows_deleted: u64,
+    /// Pages added to the freelist by the operation (after - before)
+    pub freed_pages_estimate: i64,
+    pub freed_bytes_estimate: i64,
+    pub fragmentation_ratio: f64,
+    pub action: VacuumAction,
+    /// Bytes actually returned to the filesystem (incremental vacuum only)
+    pub bytes_reclaimed: i64,
+}
+
+/// Read page size / count / freelist / auto_vacuum mode
+pub async fn storage_snapshot(pool: &SqlitePool) -> Result<StorageSnapshot, String> {
+    let page_size: i64 = sqlx::query_scalar("PRAGMA page_size")
+        .fetch_one(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    let page_count: i64 = sqlx::query_scalar("PRAGMA page_count")
+        .fetch_one(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    let freelist_count: i64 = sqlx::query_scalar("PRAGMA freelist_count")
+        .fetch_one(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    let auto_vacuum: i64 = sqlx::query_scalar("PRAGMA auto_vacuum")
+        .fetch_one(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(StorageSnapshot {
+        page_size,
+        page_count,
+        freelist_count,
+        auto_vacuum,
+    })
+}
+
+/// Decide what to do given the post-operation snapshot (pure; unit-tested)
+pub fn decide_vacuum_action(
+    cfg: &DbMaintenanceConfig,
+    after: &StorageSnapshot,
+    freed_pages: i64,
+) -> VacuumAction {
+    if !cfg.auto_vacuum_enabled || after.freelist_count <= 0 {
+        return VacuumAction::None;
+    }
+    if after.auto_vacuum == 2 && freed_pages >= i64::from(cfg.incremental_min_freed_pages) {
+        return VacuumAction::Incremental;
+    }
+    if after.fragmentation_ratio() >= cfg.full_vacuum_fragmentation_threshold {
+        return VacuumAction::FullVacuumRecommended;
+    }
+    VacuumAction::None
+}
+
+/// Compare freelist before/after a destructive operation and reclaim space per config.
+///
+/// Never fails the calling operation: errors are logged and a `None` action is reported.
+pub async fn reclaim_after_deletion(
+    pool: &SqlitePool,
+    cfg: &DbMaintenanceConfig,
+    operation: &str,
+    rows_deleted: u64,
+    before: &StorageSnapshot,
+) -> VacuumReport {
+    let mut report = VacuumReport {
+        operation: operation.to_string(),
+        rows_deleted,
+        freed_pages_estimate: 0,
+        freed_bytes_estimate: 0,
+        fragmentation_ratio: 0.0,
+        action: VacuumAction::None,
+        bytes_reclaimed: 0,
+    };
+    let after = match storage_snapshot(pool).await {
+        Ok(s) => s,
+        Err(e) => {
+            warn!("[DbMaintenance] snapshot failed after {}: {}", operation, e);
+            return report;
+        }
+    };
+    let freed_pages = (after.freelist_count - before.freelist_count).max(0);
+    report.freed_pages_estimate = freed_pages;
+    report.freed_bytes_estimate = freed_pages * after.page_size;
+    report.fragmentation_ratio = after.fragmentation_ratio();
+    report.action = decide_vacuum_action(cfg, &after, freed_pages);
+
+    match report.action {
+        VacuumAction::Incremental => {
+            if let Err(e) = sqlx::query("PRAGMA incremental_vacuum").execute(pool).await {
+                warn!("[DbMaintenance] incremental_vacuum failed: {}", e);
+                report.action = VacuumAction::None;
+            } else if let Ok(post) = storage_snapshot(pool).await {
+                report.bytes_reclaimed =
+                    ((after.page_count - post.page_count).max(0)) * after.page_size;
+                report.fragmentation_ratio = post.fragmentation_ratio();
+            }
+        }
+        VacuumAction::FullVacuumRecommended => {
+            warn!(
+                "[DbMaintenance] {} left fragmentation at {:.1}% (freelist={} pages); full VACUUM recommended",
+                operation,
+                report.fragmentation_ratio * 100.0,
+                after.freelist_count
+            );
+        }
+        VacuumAction::None => {
+            debug!(
+                "[DbMaintenance] {} freed ~{} pages; no vacuum needed",
+                operation, freed_pages
+            );
+        }
+    }
+    info!(
+        "🧹 [DbMaintenance] op={} rows_deleted={} freed_pages≈{} action={:?} reclaimed_bytes={}",
+        report.operation,
+        report.rows_deleted,
+        report.freed_pages_estimate,
+        report.action,
+        report.bytes_reclaimed
+    );
+    report
+}
+
+/// One step of `run_maintenance`, in execution order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MaintenanceStep {
+    Vacuum,
+    Analyze,
+    IntegrityCheck,
+    Reindex,
+}
+
+impl MaintenanceStep {
+    pub const ALL: [MaintenanceStep; 4] = [
+        MaintenanceStep::Vacuum,
+        MaintenanceStep::Analyze,
+        MaintenanceStep::IntegrityCheck,
+        MaintenanceStep::Reindex,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MaintenanceStep::Vacuum => "vacuum",
+            MaintenanceStep::Analyze => "analyze",
+            MaintenanceStep::IntegrityCheck => "integrity_check",
+            MaintenanceStep::Reindex => "reindex",
+        }
+    }
+}
+
+/// Progress callback payload: `phase` is `started`, `completed` or `failed`
+#[derive(Debug, Clone)]
+pub struct MaintenanceProgress<'a> {
+    pub step: MaintenanceStep,
+    /// 1-based position in `MaintenanceStep::ALL`
+    pub index: u32,
+    pub total: u32,
+    pub phase: &'static str,
+    pub elapsed_ms: u64,
+    pub detail: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenanceStepReport {
+    pub step: MaintenanceStep,
+    pub elapsed_ms: u64,
+    /// None when the step succeeded; later steps still run after a failure
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DbMaintenanceReport {
+    /// Database file plus WAL, in bytes
+    pub size_before_bytes: u64,
+    pub size_after_bytes: u64,
+    pub integrity_ok: bool,
+    /// integrity_check messages (first `MAX_INTEGRITY_ISSUES`), empty when ok
+    pub integrity_issues: Vec<String>,
+    pub steps: Vec<MaintenanceStepReport>,
+    pub total_ms: u64,
+}
+
+/// Size of the main database file plus its WAL; page_count × page_size for in-memory databases
+pub async fn database_file_bytes(pool: &SqlitePool) -> Result<u64, String> {
+    let files: Vec<(i64, String, String)> = sqlx::query_as("PRAGMA database_list")
+        .fetch_all(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    match files.into_iter().find(|(_, name, _)| name == "main") {
+        Some((_, _, file)) if !file.is_empty() => {
+            let size = |path: &str| std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+            Ok(size(&file) + size(&format!("{file}-wal")))
+        }
+        _ => {
+            let snapshot = storage_snapshot(pool).await?;
+            Ok((snapshot.page_count * snapshot.page_size).max(0) as u64)
+        }
+    }
+}
+
+/// Run VACUUM, ANALYZE, integrity_check and REINDEX in order. A failing step is recorded and the
+/// next one still runs; `on_progress` sees `started` and `completed`/`failed` for every step.
+pub async fn run_maintenance(
+    pool: &SqlitePool,
+    mut on_progress: impl FnMut(&MaintenanceProgress<'_>),
+) -> Result<DbMaintenanceReport, String> {
+    let started = Instant::now();
+    let size_before_bytes = database_file_bytes(pool).await?;
+    let total = MaintenanceStep::ALL.len() as u32;
+    let mut report = DbMaintenanceReport {
+        size_before_bytes,
+        size_after_bytes: size_before_bytes,
+        integrity_ok: false,
+        integrity_issues: Vec::new(),
+        steps: Vec::with_capacity(MaintenanceStep::ALL.len()),
+        total_ms: 0,
+    };
+    for (i, step) in MaintenanceStep::ALL.into_iter().enumerate() {
+        let step_started = Instant::now();
+        let mut progress = MaintenanceProgress {
+            step,
+            index: i as u32 + 1,
+            total,
+            phase: "started",
+            elapsed_ms: 0,
+            detail: None,
+        };
+        on_progress(&progress);
+        let outcome = run_step(pool, step, &mut report).await;
+        let elapsed_ms = step_started.elapsed().as_millis() as u64;
+        progress.elapsed_ms = elapsed_ms;
+        match &outcome {
+            Ok(()) => {
+                progress.phase = "completed";
+                on_progress(&progress);
+            }
+            Err(e) => {
+                warn!("[DbMaintenance] {} failed: {}", step.as_str(), e);
+                progress.phase = "failed";
+                progress.detail = Some(e);
+                on_progress(&progress);
+            }
+        }
+        report.steps.push(MaintenanceStepReport {
+            step,
+            elapsed_ms,
+            error: outcome.err(),
+        });
+    }
+    report.size_after_bytes = database_file_bytes(pool).await?;
+    report.total_ms = started.elapsed().as_millis() as u64;
+    info!(
+        "🧹 [DbMaintenance] manual run: {} -> {} bytes, integrity_ok={} in {}ms",
+        report.size_before_bytes, report.size_after_bytes, report.integrity_ok, report.total_ms
+    );
+    Ok(report)
+}
+
+async fn run_step(
+    pool: &SqlitePool,
+    step: MaintenanceStep,
+    report: &mut DbMaintenanceReport,
+) -> Result<(), String> {
+    match step {
+        MaintenanceStep::Vacuum => {
+            sqlx::query("VACUUM")
+                .execute(pool)
+                .await
+                .map_err(|e| e.to_string())?;
+            // VACUUM goes through the WAL in WAL mode; truncate it so the size reflects the result
+            sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+                .execute(pool)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        MaintenanceStep::Analyze => {
+            sqlx::query("ANALYZE")
+                .execute(pool)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        MaintenanceStep::IntegrityCheck => {
+            let messages: Vec<String> =
+                sqlx::query_scalar(&format!("PRAGMA integrity_check({MAX_INTEGRITY_ISSUES})"))
+                    .fetch_all(pool)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            report.integrity_ok = messages.len() == 1 && messages[0] == "ok";
+            if !report.integrity_ok {
+                report.integrity_issues = messages;
+            }
+        }
+        MaintenanceStep::Reindex => {
+            sqlx::query("REINDEX")
+                .execute(pool)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snap(page_count: i64, freelist_count: i64, auto_vacuum: i64) -> StorageSnapshot {
+        StorageSnapshot {
+            page_size: 4096,
+            page_count,
+            freelist_count,
+            auto_vacuum,
+        }
+    }
+
+    #[test]
+    fn incremental_mode_runs_incremental_vacuum_above_min_pages() {
+        let cfg = DbMaintenanceConfig::default();
+        let after = snap(10_000, 2_000, 2);
+        assert_eq!(
+            decide_vacuum_action(&cfg, &after, i64::from(cfg.incremental_min_freed_pages)),
+            VacuumAction::Incremental
+        );
+    }
+
+    #[test]
+    fn non_incremental_mode_recommends_full_vacuum_when_fragmented() {
+        let cfg = DbMaintenanceConfig::default();
+        let after = snap(1_000, 600, 0);
+        assert_eq!(
+            decide_vacuum_action(&cfg, &after, 600),
+            VacuumAction::FullVacuumRecommended
+        );
+        let light = snap(1_000, 10, 0);
+        assert_eq!(decide_vacuum_action(&cfg, &light, 10), VacuumAction::None);
+    }
+
+    #[tokio::test]
+    async fn manual_maintenance_runs_every_step_and_shrinks_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let options = sqlx::sqlite::SqliteConnectOptions::new()
+            .filename(dir.path().join("maint.db"))
+            .create_if_missing(true);
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE t (id INTEGER PRIMARY KEY, body TEXT)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 2000) \
+             INSERT INTO t (body) SELECT printf('%.500d', i) FROM n",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("DELETE FROM t").execute(&pool).await.unwrap();
+
+        let mut phases = Vec::new();
+        let report = run_maintenance(&pool, |p| phases.push((p.step, p.phase)))
+            .await
+            .unwrap();
+        assert_eq!(phases.len(), 2 * MaintenanceStep::ALL.len());
+        assert_eq!(phases[0], (MaintenanceStep::Vacuum, "started"));
+        assert_eq!(phases[7], (MaintenanceStep::Reindex, "completed"));
+        assert!(report.steps.iter().all(|s| s.error.is_none()));
+        assert!(report.integrity_ok && report.integrity_issues.is_empty());
+        assert!(report.size_after_bytes < report.size_before_bytes);
+    }
+
+    #[test]
+    fn disabled_automation_never_acts() {
+        let cfg = DbMaintenanceConfig {
+            auto_vacuum_enabled: false,
+            ..DbMaintenanceConfig::default()
+        };
+        let after = snap(1_000, 900, 2);
+        assert_eq!(decide_vacuum_action(&cfg, &after, 900), VacuumAction::None);
+    }
+}
@@ -0,0 +1,105 @@
+//! 상세 페이지 요청 순서 (순차 / 셔플 / 페이지 교차)
+//!
+//! 슬롯 순서 그대로 상세를 받으면 접근 패턴이 눈에 띄고 한 목록 페이지의 제품에 요청이 몰린다.
+//! 수집기는 여기서 만든 순열대로 요청을 보내고 결과는 입력 순서로 되돌려 준다. `sequential` 은
+//! 기존 동작, `shuffled` 는 seed 를 주면 재현 가능한 순서(테스트용 결정적 모드)가 된다.
+
+use crate::domain::product_url::ProductUrl;
+use serde::{Deserialize, Serialize};
+
+/// Order detail pages are requested in (`user.crawling.workers.detail_fetch_order`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DetailFetchOrder {
+    /// Input (slot) order
+    #[default]
+    Sequential,
+    /// Random permutation of the whole batch
+    Shuffled,
+    /// Round-robin across list pages: first slot of every page, then the second, …
+    Interleaved,
+}
+
+/// Indices into `urls` in the order they should be fetched.
+/// `seed` fixes the `Shuffled` permutation; without it every call differs.
+pub fn fetch_sequence(
+    urls: &[ProductUrl],
+    order: DetailFetchOrder,
+    seed: Option<u64>,
+) -> Vec<usize> {
+    let mut sequence: Vec<usize> = (0..urls.len()).collect();
+    match order {
+        DetailFetchOrder::Sequential => {}
+        DetailFetchOrder::Shuffled => {
+            let mut rng = match seed {
+                Some(seed) => fastrand::Rng::with_seed(seed),
+                None => fastrand::Rng::new(),
+            };
+            rng.shuffle(&mut sequence);
+        }
+        DetailFetchOrder::Interleaved => {
+            // Pages in order of first appearance, each keeping its slot order
+            let mut pages: Vec<(i32, Vec<usize>)> = Vec::new();
+            for (i, url) in urls.iter().enumerate() {
+                match pages
+                    .iter_mut()
+                    .find(|(page_id, _)| *page_id == url.page_id)
+                {
+                    Some((_, slots)) => slots.push(i),
+                    None => pages.push((url.page_id, vec![i])),
+                }
+            }
+            let longest = pages
+                .iter()
+                .map(|(_, slots)| slots.len())
+                .max()
+                .unwrap_or(0);
+            sequence = (0..longest)
+                .flat_map(|rank| {
+                    pages
+                        .iter()
+                        .filter_map(move |(_, slots)| slots.get(rank).copied())
+                })
+                .collect();
+        }
+    }
+    sequence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(slots: &[(i32, i32)]) -> Vec<ProductUrl> {
+        slots
+            .iter()
+            .map(|&(page_id, index_in_page)| ProductUrl {
+                url: format!("https://x/p{page_id}-{index_in_page}/"),
+                page_id,
+                index_in_page,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn orders_are_permutations_and_seeded_shuffle_is_stable() {
+        let batch = urls(&[(5, 0), (5, 1), (5, 2), (4, 0), (4, 1), (3, 0)]);
+        assert_eq!(
+            fetch_sequence(&batch, DetailFetchOrder::Sequential, None),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            fetch_sequence(&batch, DetailFetchOrder::Interleaved, None),
+            vec![0, 3, 5, 1, 4, 2]
+        );
+
+        let shuffled = fetch_sequence(&batch, DetailFetchOrder::Shuffled, Some(7));
+        assert_eq!(
+            shuffled,
+            fetch_sequence(&batch, DetailFetchOrder::Shuffled, Some(7))
+        );
+        let mut sorted = shuffled.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, vec![0, 1, 2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,127 @@
+//! 이벤트 출력 대상 추상화 (Tauri 셸 밖에서도 sync/crawl 코어 실행)
+//!
+//! `emit_actor_event` 등 이벤트 발행 함수는 `AppHandle` 대신 `EventSink`를 받는다.
+//! `AppHandle` 자체가 `EventSink`를 구현하므로 기존 커맨드 경로는 그대로 동작하고,
+//! 테스트/CLI에서는 로그 또는 null sink를 넘겨 같은 코어를 실행할 수 있다.
+//!
+//! Support matrix:
+//!
+//! | Environment                  | Sink              | Behaviour                                   |
+//! |------------------------------|-------------------|---------------------------------------------|
+//! | Tauri app (window available) | `AppHandle`       | `app.emit` to all webviews                  |
+//! | Tauri app, window closed     | `AppHandle`       | emit error logged, core keeps running       |
+//! | CLI / headless tools         | `LogEventSink`    | event name + payload written to tracing     |
+//! | `event-stream` feature       | (window and log)  | also copied to the local SSE endpoint       |
+//! | Unit / integration tests     | `NullEventSink`   | events dropped                              |
+
+use serde_json::Value;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tracing::{debug, error};
+
+/// Destination for named JSON events
+pub trait EventSink: Send + Sync {
+    fn emit_json(&self, event_name: &str, payload: Value);
+
+    /// Short name for logs / diagnostics
+    fn sink_kind(&self) -> &'static str;
+}
+
+/// Real window sink: forwards to the Tauri frontend
+impl EventSink for AppHandle {
+    fn emit_json(&self, event_name: &str, payload: Value) {
+        #[cfg(feature = "event-stream")]
+        crate::infrastructure::event_stream::publish(event_name, &payload);
+        if let Err(e) = self.emit(event_name, payload) {
+            error!("Failed to emit event {}: {}", event_name, e);
+        } else {
+            debug!("Emitted event {}", event_name);
+        }
+    }
+
+    fn sink_kind(&self) -> &'static str {
+        "tauri"
+    }
+}
+
+impl<T: EventSink + ?Sized> EventSink for Arc<T> {
+    fn emit_json(&self, event_name: &str, payload: Value) {
+        (**self).emit_json(event_name, payload)
+    }
+
+    fn sink_kind(&self) -> &'static str {
+        (**self).sink_kind()
+    }
+}
+
+/// Writes events to the log instead of a window (CLI / headless runs)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogEventSink;
+
+impl EventSink for LogEventSink {
+    fn emit_json(&self, event_name: &str, payload: Value) {
+        #[cfg(feature = "event-stream")]
+        crate::infrastructure::event_stream::publish(event_name, &payload);
+        debug!(target: "event_sink", "{} {}", event_name, payload);
+    }
+
+    fn sink_kind(&self) -> &'static str {
+        "log"
+    }
+}
+
+/// Drops every event (tests)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullEventSink;
+
+impl EventSink for NullEventSink {
+    fn emit_json(&self, _event_name: &str, _payload: Value) {}
+
+    fn sink_kind(&self) -> &'static str {
+        "null"
+    }
+}
+
+/// Window sink when an `AppHandle` exists, otherwise the log sink
+pub fn event_sink_for(app: Option<AppHandle>) -> Arc<dyn EventSink> {
+    match app {
+        Some(app) => Arc::new(app),
+        None => Arc::new(LogEventSink),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingSink(Mutex<Vec<String>>);
+
+    impl EventSink for RecordingSink {
+        fn emit_json(&self, event_name: &str, _payload: Value) {
+            self.0.lock().unwrap().push(event_name.to_string());
+        }
+
+        fn sink_kind(&self) -> &'static str {
+            "recording"
+        }
+    }
+
+    #[test]
+    fn arc_sink_forwards_to_inner() {
+        let inner = Arc::new(RecordingSink::default());
+        let shared: Arc<dyn EventSink> = inner.clone();
+        shared.emit_json("actor-sync-started", Value::Null);
+        assert_eq!(shared.sink_kind(), "recording");
+        assert_eq!(*inner.0.lock().unwrap(), vec!["actor-sync-started"]);
+    }
+
+    #[test]
+    fn missing_app_handle_degrades_to_log_sink() {
+        let sink = event_sink_for(None);
+        assert_eq!(sink.sink_kind(), "log");
+        sink.emit_json("actor-sync-warning", Value::Null);
+        NullEventSink.emit_json("actor-sync-warning", Value::Null);
+    }
+}
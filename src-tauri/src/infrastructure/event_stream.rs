@@ -0,0 +1,412 @@
+//! 이벤트 SSE 스트리밍 (헤드리스 운영, `event-stream` feature)
+//!
+//! 프런트엔드로 나가는 이벤트(`ActorEventBridge`, `EventEmitter`, `EventSink`)를 프로세스 전역 허브에
+//! 한 번 더 복사해 두고, 로컬 HTTP 엔드포인트가 Server-Sent Events 로 브라우저에 흘려 보낸다.
+//! 페이로드는 Tauri 로 나가는 JSON 과 같은 직렬화 결과를 `{seq, event, payload}` 로 감쌀 뿐이다.
+//!
+//! | Path       | Response                                                        |
+//! |------------|-----------------------------------------------------------------|
+//! | `/`        | Minimal HTML viewer (EventSource on `/events`)                  |
+//! | `/events`  | `text/event-stream`; `?session_id=` and `?events=a,b` filters   |
+//! | `/health`  | `{"subscribers": n}`                                            |
+//!
+//! 구독자가 없으면 `publish` 는 직렬화도 하지 않으므로 기능을 켜 두어도 비용이 거의 없다.
+
+use crate::crawl_engine::runtime::task_registry::spawn_tracked;
+use crate::infrastructure::config::EventStreamConfig;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::Value;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+/// Frames buffered per subscriber before it is reported as lagged
+const HUB_CAPACITY: usize = 1024;
+
+/// Largest request head accepted (request line + headers)
+const MAX_REQUEST_HEAD: usize = 8 * 1024;
+
+const SSE_RESPONSE_HEAD: &str = "HTTP/1.1 200 OK\r\n\
+Content-Type: text/event-stream\r\n\
+Cache-Control: no-cache\r\n\
+Connection: keep-alive\r\n\
+Access-Control-Allow-Origin: *\r\n\r\n";
+
+const VIEWER_HTML: &str = r#"<!doctype html>
+<html><head><meta charset="utf-8"><title>rMatterCertis events</title>
+<style>body{font:12px monospace;margin:8px}div{white-space:pre;border-bottom:1px solid #eee}</style>
+</head><body><div id="status">connecting…</div><div id="log"></div>
+<script>
+const log = document.getElementById('log');
+const status = document.getElementById('status');
+const source = new EventSource('/events' + location.search);
+source.onopen = () => status.textContent = 'connected';
+source.onerror = () => status.textContent = 'disconnected (retrying)';
+source.onmessage = (m) => {
+  const f = JSON.parse(m.data);
+  const row = document.createElement('div');
+  row.textContent = `#${f.seq} ${f.event} ${JSON.stringify(f.payload)}`;
+  log.prepend(row);
+  while (log.childElementCount > 500) log.lastChild.remove();
+};
+</script></body></html>"#;
+
+/// One event as sent to stream subscribers
+#[derive(Debug, Clone)]
+pub struct StreamFrame {
+    pub seq: u64,
+    pub event_name: String,
+    pub session_id: Option<String>,
+    /// `{"seq","event","payload"}` JSON (single line)
+    pub data: String,
+}
+
+impl StreamFrame {
+    fn to_sse(&self) -> String {
+        format!("id: {}\ndata: {}\n\n", self.seq, self.data)
+    }
+}
+
+static HUB: Lazy<broadcast::Sender<Arc<StreamFrame>>> =
+    Lazy::new(|| broadcast::channel(HUB_CAPACITY).0);
+
+static SEQ: AtomicU64 = AtomicU64::new(1);
+
+/// Copy an outgoing event to stream subscribers (no-op without subscribers)
+pub fn publish<T: Serialize + ?Sized>(event_name: &str, payload: &T) {
+    if HUB.receiver_count() == 0 {
+        return;
+    }
+    let payload = match serde_json::to_value(payload) {
+        Ok(v) => v,
+        Err(e) => {
+            debug!("event stream: failed to serialize {}: {}", event_name, e);
+            return;
+        }
+    };
+    let _ = HUB.send(Arc::new(build_frame(event_name, payload)));
+}
+
+pub fn subscriber_count() -> usize {
+    HUB.receiver_count()
+}
+
+fn build_frame(event_name: &str, payload: Value) -> StreamFrame {
+    let seq = SEQ.fetch_add(1, Ordering::Relaxed);
+    let session_id = session_id_of(&payload);
+    let data = serde_json::json!({
+        "seq": seq,
+        "event": event_name,
+        "payload": payload,
+    })
+    .to_string();
+    StreamFrame {
+        seq,
+        event_name: event_name.to_string(),
+        session_id,
+        data,
+    }
+}
+
+/// `session_id` at the top level or one object level down (actor payloads nest it)
+fn session_id_of(payload: &Value) -> Option<String> {
+    let obj = payload.as_object()?;
+    if let Some(id) = obj.get("session_id").and_then(Value::as_str) {
+        return Some(id.to_string());
+    }
+    obj.values()
+        .filter_map(Value::as_object)
+        .find_map(|inner| inner.get("session_id").and_then(Value::as_str))
+        .map(str::to_string)
+}
+
+/// Subscriber-side filter parsed from the `/events` query string
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct StreamFilter {
+    session_id: Option<String>,
+    events: Vec<String>,
+}
+
+impl StreamFilter {
+    fn from_query(query: &str) -> Self {
+        let mut filter = StreamFilter::default();
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "session_id" if !value.is_empty() => filter.session_id = Some(value.into_owned()),
+                "events" => filter.events.extend(
+                    value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|s| !s.is_empty())
+                        .map(str::to_string),
+                ),
+                _ => {}
+            }
+        }
+        filter
+    }
+
+    fn matches(&self, frame: &StreamFrame) -> bool {
+        if self
+            .session_id
+            .as_deref()
+            .is_some_and(|id| frame.session_id.as_deref() != Some(id))
+        {
+            return false;
+        }
+        self.events.is_empty() || self.events.iter().any(|e| *e == frame.event_name)
+    }
+}
+
+/// Bind `config.bind_addr` and serve in the background; no-op when disabled
+pub fn start_event_stream_server(config: &EventStreamConfig) {
+    if !config.enabled {
+        return;
+    }
+    let addr: SocketAddr = match config.bind_addr.parse() {
+        Ok(addr) => addr,
+        Err(e) => {
+            warn!(
+                "📡 Event stream disabled: invalid bind_addr '{}': {}",
+                config.bind_addr, e
+            );
+            return;
+        }
+    };
+    if !addr.ip().is_loopback() {
+        warn!(
+            "📡 Event stream listening on non-loopback address {}; events are unauthenticated",
+            addr
+        );
+    }
+    let heartbeat = Duration::from_secs(config.heartbeat_secs.max(1));
+    spawn_tracked("event-stream-server", None, async move {
+        match TcpListener::bind(addr).await {
+            Ok(listener) => {
+                info!("📡 Event stream serving SSE on http://{}/events", addr);
+                serve(listener, heartbeat).await;
+            }
+            Err(e) => warn!("📡 Event stream failed to bind {}: {}", addr, e),
+        }
+    });
+}
+
+/// Accept loop; one tracked task per connection
+pub async fn serve(listener: TcpListener, heartbeat: Duration) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, peer)) => {
+                spawn_tracked(format!("event-stream-client:{peer}"), None, async move {
+                    if let Err(e) = handle_connection(stream, heartbeat).await {
+                        debug!("📡 Event stream client {} closed: {}", peer, e);
+                    }
+                });
+            }
+            Err(e) => {
+                warn!("📡 Event stream accept failed: {}", e);
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            }
+        }
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, heartbeat: Duration) -> std::io::Result<()> {
+    let Some((method, target)) = read_request_line(&mut stream).await? else {
+        return Ok(());
+    };
+    let (path, query) = target.split_once('?').unwrap_or((target.as_str(), ""));
+    match (method.as_str(), path) {
+        ("GET", "/events") => {
+            stream_events(stream, StreamFilter::from_query(query), heartbeat).await
+        }
+        ("GET", "/") => {
+            respond(
+                &mut stream,
+                "200 OK",
+                "text/html; charset=utf-8",
+                VIEWER_HTML,
+            )
+            .await
+        }
+        ("GET", "/health") => {
+            let body = serde_json::json!({ "subscribers": subscriber_count() }).to_string();
+            respond(&mut stream, "200 OK", "application/json", &body).await
+        }
+        ("GET", _) => respond(&mut stream, "404 Not Found", "text/plain", "not found").await,
+        _ => {
+            respond(
+                &mut stream,
+                "405 Method Not Allowed",
+                "text/plain",
+                "method not allowed",
+            )
+            .await
+        }
+    }
+}
+
+/// Method and target of the request line, after the full head has been read
+async fn read_request_line(stream: &mut TcpStream) -> std::io::Result<Option<(String, String)>> {
+    let mut head = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        if head.len() > MAX_REQUEST_HEAD {
+            return Ok(None);
+        }
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(None);
+        }
+        head.extend_from_slice(&chunk[..n]);
+    }
+    let head = String::from_utf8_lossy(&head);
+    let mut parts = head.lines().next().unwrap_or_default().split_whitespace();
+    Ok(match (parts.next(), parts.next()) {
+        (Some(method), Some(target)) => Some((method.to_string(), target.to_string())),
+        _ => None,
+    })
+}
+
+async fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> std::io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+async fn stream_events(
+    mut stream: TcpStream,
+    filter: StreamFilter,
+    heartbeat: Duration,
+) -> std::io::Result<()> {
+    let mut rx = HUB.subscribe();
+    stream.write_all(SSE_RESPONSE_HEAD.as_bytes()).await?;
+    stream.write_all(b"retry: 3000\n\n").await?;
+    let mut ticker = tokio::time::interval(heartbeat);
+    ticker.tick().await;
+    loop {
+        tokio::select! {
+            recv = rx.recv() => match recv {
+                Ok(frame) => {
+                    if filter.matches(&frame) {
+                        stream.write_all(frame.to_sse().as_bytes()).await?;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    let notice = build_frame(
+                        "stream-lagged",
+                        serde_json::json!({ "skipped": skipped }),
+                    );
+                    stream.write_all(notice.to_sse().as_bytes()).await?;
+                }
+                Err(broadcast::error::RecvError::Closed) => return Ok(()),
+            },
+            _ = ticker.tick() => stream.write_all(b": keep-alive\n\n").await?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_parses_query_and_matches_session_and_names() {
+        let filter = StreamFilter::from_query(
+            "session_id=s1&events=actor-stage-failed,%20actor-session-completed",
+        );
+        assert_eq!(filter.session_id.as_deref(), Some("s1"));
+        assert_eq!(
+            filter.events,
+            vec!["actor-stage-failed", "actor-session-completed"]
+        );
+
+        let hit = build_frame(
+            "actor-stage-failed",
+            serde_json::json!({ "session_id": "s1" }),
+        );
+        let nested = build_frame(
+            "actor-session-completed",
+            serde_json::json!({ "payload": { "session_id": "s1" } }),
+        );
+        let other_session = build_frame(
+            "actor-stage-failed",
+            serde_json::json!({ "session_id": "s2" }),
+        );
+        let other_name = build_frame(
+            "actor-page-task-completed",
+            serde_json::json!({ "session_id": "s1" }),
+        );
+        assert!(filter.matches(&hit));
+        assert!(filter.matches(&nested));
+        assert!(!filter.matches(&other_session));
+        assert!(!filter.matches(&other_name));
+        assert!(StreamFilter::default().matches(&other_name));
+    }
+
+    #[test]
+    fn frames_are_single_line_sse_with_envelope() {
+        let frame = build_frame("crawling-error", serde_json::json!({ "message": "a\nb" }));
+        let sse = frame.to_sse();
+        assert!(sse.starts_with(&format!("id: {}\ndata: ", frame.seq)));
+        assert_eq!(sse.matches('\n').count(), 3);
+        let data: Value = serde_json::from_str(&frame.data).unwrap();
+        assert_eq!(data["event"], "crawling-error");
+        assert_eq!(data["payload"]["message"], "a\nb");
+    }
+
+    #[tokio::test]
+    async fn sse_client_receives_published_events_for_its_session() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(serve(listener, Duration::from_secs(30)));
+
+        let mut client = TcpStream::connect(addr).await.unwrap();
+        client
+            .write_all(b"GET /events?session_id=sse-test HTTP/1.1\r\nHost: x\r\n\r\n")
+            .await
+            .unwrap();
+        for _ in 0..100 {
+            if subscriber_count() > 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        publish(
+            "actor-session-started",
+            &serde_json::json!({ "session_id": "other" }),
+        );
+        publish(
+            "actor-session-started",
+            &serde_json::json!({ "session_id": "sse-test" }),
+        );
+
+        let mut received = String::new();
+        let mut buf = [0u8; 4096];
+        while !received.contains("\"sse-test\"") {
+            let n = tokio::time::timeout(Duration::from_secs(5), client.read(&mut buf))
+                .await
+                .expect("event within timeout")
+                .unwrap();
+            assert!(n > 0, "stream closed early");
+            received.push_str(&String::from_utf8_lossy(&buf[..n]));
+        }
+        assert!(received.starts_with("HTTP/1.1 200 OK"));
+        assert!(received.contains("text/event-stream"));
+        assert!(!received.contains("\"other\""));
+        server.abort();
+    }
+}
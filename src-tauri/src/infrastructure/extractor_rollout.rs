@@ -0,0 +1,658 @@
+//! 추출 규칙(사이트 프로필) 무중단 교체 — shadow 비교 후 승격
+//!
+//! `advanced.site_profile` 이 승격된 규칙과 다른 프로필을 가리켜도 바로 바꾸지 않는다. 기존
+//! 규칙이 계속 결과를 내고, 새 규칙은 상세 페이지 `shadow_pages` 개에서 같은 HTML 을 한 번 더
+//! 추출해 필드별로 비교만 한다. 비교 통계는 앱 데이터 디렉터리의 `extractor_rollout.json` 에
+//! 남아 재시작 후에도 이어진다. 비교가 끝나면 `promote_extractor_rules` 로 승격하거나
+//! `rollback_extractor_rules` 로 설정을 되돌린다. `auto_promote` 이면 차이 비율이
+//! `max_divergence_ratio` 이하일 때 스스로 승격한다.
+//!
+//! 같은 프로필 id 의 셀렉터가 앱 업데이트로 바뀐 경우에는 비교할 옛 규칙이 남아 있지 않으므로
+//! 그대로 승격된 것으로 기록한다.
+
+use crate::domain::product::ProductDetail;
+use crate::domain::site_profile::SiteProfile;
+use crate::infrastructure::config::ConfigManager;
+use anyhow::{Result, bail};
+use chrono::Utc;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use tracing::{info, warn};
+
+/// Rollout state file in the app data directory
+pub const STATE_FILE_NAME: &str = "extractor_rollout.json";
+
+/// Divergent values kept per rollout for review
+pub const MAX_SAMPLES: usize = 50;
+
+/// `advanced.extractor_rollout`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtractorRolloutConfig {
+    /// Detail pages extracted with both rule sets before a decision (0 = switch immediately)
+    #[serde(default = "ExtractorRolloutConfig::default_shadow_pages")]
+    pub shadow_pages: u32,
+    /// Promote without a decision once `shadow_pages` stay within `max_divergence_ratio`
+    #[serde(default)]
+    pub auto_promote: bool,
+    /// Share of compared field values allowed to differ for `auto_promote`
+    #[serde(default = "ExtractorRolloutConfig::default_max_divergence_ratio")]
+    pub max_divergence_ratio: f64,
+}
+
+impl ExtractorRolloutConfig {
+    fn default_shadow_pages() -> u32 {
+        50
+    }
+
+    fn default_max_divergence_ratio() -> f64 {
+        0.02
+    }
+}
+
+impl Default for ExtractorRolloutConfig {
+    fn default() -> Self {
+        Self {
+            shadow_pages: Self::default_shadow_pages(),
+            auto_promote: false,
+            max_divergence_ratio: Self::default_max_divergence_ratio(),
+        }
+    }
+}
+
+/// A site profile and the fingerprint of its extraction selectors
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleSet {
+    pub site_profile: String,
+    /// blake3 of the profile id and its list/detail selectors (URL templates are not rules)
+    pub fingerprint: String,
+}
+
+impl RuleSet {
+    pub fn of(profile: &dyn SiteProfile) -> Self {
+        let rules = format!(
+            "{}\n{:?}\n{:?}",
+            profile.id(),
+            profile.list_selectors(),
+            profile.detail_selectors()
+        );
+        Self {
+            site_profile: profile.id().to_string(),
+            fingerprint: blake3::hash(rules.as_bytes()).to_hex().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RolloutStatus {
+    /// New rules extract alongside the promoted ones
+    Shadowing,
+    /// `shadow_pages` compared; waiting for promote / rollback
+    AwaitingDecision,
+    Promoted,
+    /// Rolled back, or replaced by settings pointing elsewhere
+    RolledBack,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldDivergence {
+    pub field: String,
+    pub matched: u32,
+    /// Both rule sets found a value and the values differ
+    pub diverged: u32,
+    /// Only the promoted rules found a value
+    pub baseline_only: u32,
+    /// Only the new rules found a value
+    pub candidate_only: u32,
+}
+
+impl FieldDivergence {
+    fn differing(&self) -> u32 {
+        self.diverged + self.baseline_only + self.candidate_only
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DivergenceSample {
+    pub url: String,
+    pub field: String,
+    pub baseline: Option<String>,
+    pub candidate: Option<String>,
+}
+
+/// One shadow rollout and its field-level comparison
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RolloutComparison {
+    pub baseline: RuleSet,
+    pub candidate: RuleSet,
+    pub status: RolloutStatus,
+    pub shadow_pages: u32,
+    pub pages_compared: u32,
+    /// Pages where at least one field differed
+    pub pages_diverged: u32,
+    /// Pages the new rules failed to extract at all
+    pub candidate_errors: u32,
+    pub fields: Vec<FieldDivergence>,
+    pub samples: Vec<DivergenceSample>,
+    pub started_at: String,
+    pub decided_at: Option<String>,
+}
+
+impl RolloutComparison {
+    fn new(baseline: RuleSet, candidate: RuleSet, shadow_pages: u32) -> Self {
+        let fields = COMPARED_FIELDS
+            .iter()
+            .map(|field| FieldDivergence {
+                field: field.to_string(),
+                ..FieldDivergence::default()
+            })
+            .collect();
+        Self {
+            baseline,
+            candidate,
+            status: RolloutStatus::Shadowing,
+            shadow_pages,
+            pages_compared: 0,
+            pages_diverged: 0,
+            candidate_errors: 0,
+            fields,
+            samples: Vec::new(),
+            started_at: Utc::now().to_rfc3339(),
+            decided_at: None,
+        }
+    }
+
+    /// Still serving the promoted rules while the new ones wait
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self.status,
+            RolloutStatus::Shadowing | RolloutStatus::AwaitingDecision
+        )
+    }
+
+    /// Share of compared field values that differ; a failed extraction counts every field
+    pub fn divergence_ratio(&self) -> f64 {
+        let failed = self.candidate_errors * self.fields.len() as u32;
+        let differing: u32 = self.fields.iter().map(FieldDivergence::differing).sum();
+        let compared: u32 = self.fields.iter().map(|f| f.matched).sum::<u32>() + differing;
+        if compared + failed == 0 {
+            return 0.0;
+        }
+        f64::from(differing + failed) / f64::from(compared + failed)
+    }
+
+    fn decide(&mut self, status: RolloutStatus) {
+        self.status = status;
+        self.decided_at = Some(Utc::now().to_rfc3339());
+    }
+}
+
+/// Promoted rules and the latest rollout
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RolloutSnapshot {
+    /// None until the first profile is resolved
+    pub active: Option<RuleSet>,
+    pub rollout: Option<RolloutComparison>,
+}
+
+#[derive(Debug, Default)]
+struct RolloutState {
+    snapshot: RolloutSnapshot,
+    /// Profile of the open rollout's new rules (set when settings resolve it)
+    candidate: Option<Arc<dyn SiteProfile>>,
+    config: ExtractorRolloutConfig,
+}
+
+impl RolloutState {
+    /// Profile id that should serve instead of `configured` (None = `configured` itself), and
+    /// whether the state changed. `known` tells whether a profile id is still registered.
+    fn route(
+        &mut self,
+        configured: &Arc<dyn SiteProfile>,
+        config: &ExtractorRolloutConfig,
+        known: impl Fn(&str) -> bool,
+    ) -> (Option<String>, bool) {
+        self.config = config.clone();
+        let rules = RuleSet::of(configured.as_ref());
+        let Some(active) = self.snapshot.active.clone() else {
+            self.snapshot.active = Some(rules);
+            return (None, true);
+        };
+        if active == rules {
+            // Settings went back to the promoted rules before a decision
+            return (None, self.close_open(RolloutStatus::RolledBack));
+        }
+        if active.site_profile == rules.site_profile
+            || config.shadow_pages == 0
+            || !known(&active.site_profile)
+        {
+            info!(
+                "🧪 Extraction rules switched without shadowing: {} -> {}",
+                active.site_profile, rules.site_profile
+            );
+            self.close_open(RolloutStatus::RolledBack);
+            self.snapshot.active = Some(rules);
+            return (None, true);
+        }
+        self.candidate = Some(configured.clone());
+        let resumed = self
+            .snapshot
+            .rollout
+            .as_ref()
+            .is_some_and(|r| r.is_open() && r.baseline == active && r.candidate == rules);
+        if resumed {
+            return (Some(active.site_profile), false);
+        }
+        self.close_open(RolloutStatus::RolledBack);
+        info!(
+            "🧪 Shadowing extraction rules '{}' against '{}' for {} detail pages",
+            rules.site_profile, active.site_profile, config.shadow_pages
+        );
+        self.snapshot.rollout = Some(RolloutComparison::new(
+            active.clone(),
+            rules,
+            config.shadow_pages,
+        ));
+        (Some(active.site_profile), true)
+    }
+
+    fn close_open(&mut self, status: RolloutStatus) -> bool {
+        match self.snapshot.rollout.as_mut().filter(|r| r.is_open()) {
+            Some(rollout) => {
+                rollout.decide(status);
+                self.candidate = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn shadow_candidate(&self, serving: &str) -> Option<Arc<dyn SiteProfile>> {
+        let rollout = self.snapshot.rollout.as_ref()?;
+        if rollout.status != RolloutStatus::Shadowing || rollout.baseline.site_profile != serving {
+            return None;
+        }
+        self.candidate.clone()
+    }
+
+    /// Count one shadow extraction; false when no rollout is shadowing
+    fn record(
+        &mut self,
+        baseline: &ProductDetail,
+        candidate: std::result::Result<&ProductDetail, String>,
+    ) -> bool {
+        let Some(rollout) = self
+            .snapshot
+            .rollout
+            .as_mut()
+            .filter(|r| r.status == RolloutStatus::Shadowing)
+        else {
+            return false;
+        };
+        rollout.pages_compared += 1;
+        match candidate {
+            Ok(candidate) => {
+                let pairs = field_values(baseline)
+                    .into_iter()
+                    .zip(field_values(candidate));
+                let mut page_diverged = false;
+                for (stats, (old, new)) in rollout.fields.iter_mut().zip(pairs) {
+                    match (&old, &new) {
+                        (a, b) if a == b => stats.matched += 1,
+                        (Some(_), None) => stats.baseline_only += 1,
+                        (None, Some(_)) => stats.candidate_only += 1,
+                        _ => stats.diverged += 1,
+                    }
+                    if old == new {
+                        continue;
+                    }
+                    page_diverged = true;
+                    if rollout.samples.len() < MAX_SAMPLES {
+                        rollout.samples.push(DivergenceSample {
+                            url: baseline.url.clone(),
+                            field: stats.field.clone(),
+                            baseline: old,
+                            candidate: new,
+                        });
+                    }
+                }
+                if page_diverged {
+                    rollout.pages_diverged += 1;
+                }
+            }
+            Err(e) => {
+                warn!("🧪 New extraction rules failed on {}: {}", baseline.url, e);
+                rollout.candidate_errors += 1;
+                rollout.pages_diverged += 1;
+            }
+        }
+        if rollout.pages_compared < rollout.shadow_pages {
+            return true;
+        }
+        let ratio = rollout.divergence_ratio();
+        if self.config.auto_promote && ratio <= self.config.max_divergence_ratio {
+            info!(
+                "🧪 Auto-promoting extraction rules '{}' (divergence {:.4})",
+                rollout.candidate.site_profile, ratio
+            );
+            let _ = self.promote();
+        } else {
+            info!(
+                "🧪 Shadow comparison of '{}' done: {} pages, divergence {:.4}; awaiting decision",
+                rollout.candidate.site_profile, rollout.pages_compared, ratio
+            );
+            rollout.status = RolloutStatus::AwaitingDecision;
+        }
+        true
+    }
+
+    fn promote(&mut self) -> Result<RolloutComparison> {
+        let Some(rollout) = self.snapshot.rollout.as_mut().filter(|r| r.is_open()) else {
+            bail!("No extractor rule rollout in progress");
+        };
+        rollout.decide(RolloutStatus::Promoted);
+        self.snapshot.active = Some(rollout.candidate.clone());
+        self.candidate = None;
+        Ok(rollout.clone())
+    }
+
+    fn rollback(&mut self) -> Result<RolloutComparison> {
+        self.close_open(RolloutStatus::RolledBack);
+        match &self.snapshot.rollout {
+            Some(rollout) if rollout.status == RolloutStatus::RolledBack => Ok(rollout.clone()),
+            _ => bail!("No extractor rule rollout in progress"),
+        }
+    }
+}
+
+/// Compared detail fields in column order
+const COMPARED_FIELDS: [&str; 21] = [
+    "manufacturer",
+    "model",
+    "device_type",
+    "certificate_id",
+    "certification_date",
+    "software_version",
+    "hardware_version",
+    "vid",
+    "pid",
+    "family_sku",
+    "family_variant_sku",
+    "firmware_version",
+    "family_id",
+    "tis_trp_tested",
+    "specification_version",
+    "transport_interface",
+    "primary_device_type_id",
+    "application_categories",
+    "description",
+    "compliance_document_url",
+    "program_type",
+];
+
+/// Values of `COMPARED_FIELDS`
+fn field_values(d: &ProductDetail) -> [Option<String>; 21] {
+    let int = |v: Option<i32>| v.map(|v| v.to_string());
+    [
+        d.manufacturer.clone(),
+        d.model.clone(),
+        d.device_type.clone(),
+        d.certificate_id.clone(),
+        d.certification_date.clone(),
+        d.software_version.clone(),
+        d.hardware_version.clone(),
+        int(d.vid),
+        int(d.pid),
+        d.family_sku.clone(),
+        d.family_variant_sku.clone(),
+        d.firmware_version.clone(),
+        d.family_id.clone(),
+        d.tis_trp_tested.clone(),
+        d.specification_version.clone(),
+        d.transport_interface.clone(),
+        d.primary_device_type_id.clone(),
+        d.application_categories.clone(),
+        d.description.clone(),
+        d.compliance_document_url.clone(),
+        d.program_type.clone(),
+    ]
+}
+
+static STATE: Lazy<Mutex<RolloutState>> = Lazy::new(Mutex::default);
+/// Where the state is persisted (set by `restore_persisted_state`)
+static STATE_FILE: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+fn with_state<T>(f: impl FnOnce(&mut RolloutState) -> (T, bool)) -> T {
+    let mut state = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    let (value, changed) = f(&mut state);
+    if changed {
+        persist(&state.snapshot);
+    }
+    value
+}
+
+fn persist(snapshot: &RolloutSnapshot) {
+    let Some(path) = STATE_FILE.read().ok().and_then(|g| g.clone()) else {
+        return;
+    };
+    if let Err(e) = save_state(&path, snapshot) {
+        warn!("🧪 [rollout] failed to persist state: {}", e);
+    }
+}
+
+fn load_state(path: &Path) -> Result<Option<RolloutSnapshot>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::from_slice(&std::fs::read(path)?)?))
+}
+
+fn save_state(path: &Path, snapshot: &RolloutSnapshot) -> Result<()> {
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_vec_pretty(snapshot)?)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Restore the promoted rules and an open rollout saved by the previous run (app startup)
+pub fn restore_persisted_state() {
+    let path = match ConfigManager::get_app_data_dir() {
+        Ok(dir) => dir.join(STATE_FILE_NAME),
+        Err(e) => {
+            warn!("🧪 [rollout] state not persisted (no app data dir): {}", e);
+            return;
+        }
+    };
+    if let Ok(mut guard) = STATE_FILE.write() {
+        *guard = Some(path.clone());
+    }
+    with_state(|state| match load_state(&path) {
+        Ok(Some(snapshot)) => {
+            state.snapshot = snapshot;
+            state.candidate = None;
+            ((), false)
+        }
+        // First run with rollouts: remember what is serving now
+        Ok(None) => ((), true),
+        Err(e) => {
+            warn!("🧪 [rollout] ignoring unreadable {}: {}", path.display(), e);
+            ((), false)
+        }
+    });
+}
+
+/// Profile id that should serve instead of the configured profile while its rules shadow the
+/// promoted ones (`resolve_site_profile`)
+pub fn route(
+    configured: &Arc<dyn SiteProfile>,
+    config: &ExtractorRolloutConfig,
+    known: impl Fn(&str) -> bool,
+) -> Option<String> {
+    with_state(|state| state.route(configured, config, known))
+}
+
+/// New rules to run next to an extractor serving profile `serving`, if a rollout is shadowing
+pub fn shadow_candidate(serving: &str) -> Option<Arc<dyn SiteProfile>> {
+    STATE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .shadow_candidate(serving)
+}
+
+/// Compare one page extracted with both rule sets
+pub fn record_comparison(
+    baseline: &ProductDetail,
+    candidate: std::result::Result<&ProductDetail, String>,
+) {
+    with_state(|state| ((), state.record(baseline, candidate)));
+}
+
+pub fn snapshot() -> RolloutSnapshot {
+    STATE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .snapshot
+        .clone()
+}
+
+/// Make the open rollout's rules the promoted ones (new extractors use them)
+pub fn promote() -> Result<RolloutComparison> {
+    with_state(|state| {
+        let result = state.promote();
+        let changed = result.is_ok();
+        (result, changed)
+    })
+}
+
+/// Close the open rollout keeping the promoted rules; the caller points the settings back
+pub fn rollback() -> Result<RolloutComparison> {
+    with_state(|state| {
+        let result = state.rollback();
+        let changed = result.is_ok();
+        (result, changed)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::site_profile::{DetailPageSelectors, ListPageSelectors};
+    use crate::infrastructure::site_profiles::CsaIotProfile;
+
+    /// csa-iot rules under another id
+    #[derive(Debug)]
+    struct RenamedProfile(CsaIotProfile);
+
+    impl SiteProfile for RenamedProfile {
+        fn id(&self) -> &'static str {
+            "csa-iot-next"
+        }
+        fn base_url(&self) -> &str {
+            self.0.base_url()
+        }
+        fn listing_base_url(&self) -> &str {
+            self.0.listing_base_url()
+        }
+        fn list_page_url(&self, page: u32) -> String {
+            self.0.list_page_url(page)
+        }
+        fn products_per_page(&self) -> u32 {
+            self.0.products_per_page()
+        }
+        fn is_product_url(&self, url: &str) -> bool {
+            self.0.is_product_url(url)
+        }
+        fn list_selectors(&self) -> &ListPageSelectors {
+            self.0.list_selectors()
+        }
+        fn detail_selectors(&self) -> &DetailPageSelectors {
+            self.0.detail_selectors()
+        }
+    }
+
+    fn detail(model: Option<&str>, vid: Option<i32>) -> ProductDetail {
+        ProductDetail {
+            url: "https://x/p/1".into(),
+            page_id: None,
+            index_in_page: None,
+            id: None,
+            manufacturer: None,
+            model: model.map(str::to_string),
+            device_type: None,
+            certificate_id: None,
+            certification_date: None,
+            software_version: None,
+            hardware_version: None,
+            vid,
+            pid: None,
+            family_sku: None,
+            family_variant_sku: None,
+            firmware_version: None,
+            family_id: None,
+            tis_trp_tested: None,
+            specification_version: None,
+            transport_interface: None,
+            primary_device_type_id: None,
+            application_categories: None,
+            description: None,
+            compliance_document_url: None,
+            program_type: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn new_rules_shadow_until_decided() {
+        let current: Arc<dyn SiteProfile> = Arc::new(CsaIotProfile::default());
+        let next: Arc<dyn SiteProfile> = Arc::new(RenamedProfile(CsaIotProfile::default()));
+        let config = ExtractorRolloutConfig {
+            shadow_pages: 2,
+            ..ExtractorRolloutConfig::default()
+        };
+        let mut state = RolloutState::default();
+        let known = |_: &str| true;
+
+        // First resolve adopts the configured rules
+        assert_eq!(state.route(&current, &config, known), (None, true));
+        // New profile: the promoted one keeps serving, the new one shadows it
+        let serving = Some(CsaIotProfile::ID.to_string());
+        assert_eq!(state.route(&next, &config, known), (serving.clone(), true));
+        assert_eq!(state.route(&next, &config, known), (serving, false));
+        assert!(state.shadow_candidate(CsaIotProfile::ID).is_some());
+        assert!(state.shadow_candidate("csa-iot-next").is_none());
+
+        let base = detail(Some("A"), Some(1));
+        assert!(state.record(&base, Ok(&detail(Some("A"), Some(1)))));
+        assert!(state.record(&base, Ok(&detail(Some("B"), None))));
+        let rollout = state.snapshot.rollout.clone().unwrap();
+        assert_eq!(rollout.status, RolloutStatus::AwaitingDecision);
+        assert_eq!((rollout.pages_compared, rollout.pages_diverged), (2, 1));
+        let model = rollout.fields.iter().find(|f| f.field == "model").unwrap();
+        assert_eq!((model.matched, model.diverged), (1, 1));
+        let vid = rollout.fields.iter().find(|f| f.field == "vid").unwrap();
+        assert_eq!((vid.matched, vid.baseline_only), (1, 1));
+        assert_eq!(rollout.samples.len(), 2);
+        // 2 of 42 compared values differ
+        assert!((rollout.divergence_ratio() - 2.0 / 42.0).abs() < 1e-9);
+        // Comparison finished: no more shadow extraction
+        assert!(state.shadow_candidate(CsaIotProfile::ID).is_none());
+
+        let promoted = state.promote().unwrap();
+        assert_eq!(promoted.status, RolloutStatus::Promoted);
+        assert_eq!(state.route(&next, &config, known), (None, false));
+        assert!(state.rollback().is_err());
+
+        // Going back to the old profile opens a new rollout, which a rollback closes
+        let serving = Some("csa-iot-next".to_string());
+        assert_eq!(state.route(&current, &config, known), (serving, true));
+        let rolled_back = state.rollback().unwrap();
+        assert_eq!(rolled_back.status, RolloutStatus::RolledBack);
+        assert_eq!(
+            state.snapshot.active.as_ref().unwrap().site_profile,
+            "csa-iot-next"
+        );
+    }
+}
@@ -4,6 +4,7 @@
 //! - MC_FEATURE_HTTP_CLIENT_UNIFIED (default: false)
 //! - MC_FEATURE_STAGE_EXECUTOR_TEMPLATE (deprecated, permanently enabled)
 //! - MC_FEATURE_EVENTS_GENERALIZED_ONLY (default: true)
+//! - MC_FEATURE_SEARCH_INDEX_HOOKS (default: true)
 //!
 //! Values: "1"/"true" enable, "0"/"false" disable (case-insensitive)
 
@@ -60,6 +61,36 @@ pub fn feature_events_generalized_only() -> bool {
     read_flag("MC_FEATURE_EVENTS_GENERALIZED_ONLY", true)
 }
 
+/// Keep the FTS search index and manufacturer rollups updated from the writer path
+pub fn feature_search_index_hooks() -> bool {
+    read_flag("MC_FEATURE_SEARCH_INDEX_HOOKS", true)
+}
+
+/// Current value of every runtime flag, keyed by environment variable
+pub fn feature_flag_snapshot() -> std::collections::BTreeMap<String, bool> {
+    [
+        (
+            "MC_FEATURE_HTTP_CLIENT_UNIFIED",
+            feature_http_client_unified(),
+        ),
+        (
+            "MC_FEATURE_STAGE_EXECUTOR_TEMPLATE",
+            feature_stage_executor_template(),
+        ),
+        (
+            "MC_FEATURE_EVENTS_GENERALIZED_ONLY",
+            feature_events_generalized_only(),
+        ),
+        (
+            "MC_FEATURE_SEARCH_INDEX_HOOKS",
+            feature_search_index_hooks(),
+        ),
+    ]
+    .into_iter()
+    .map(|(name, value)| (name.to_string(), value))
+    .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,6 +104,7 @@ mod tests {
         assert!(!feature_http_client_unified());
         assert!(feature_stage_executor_template());
         assert!(feature_events_generalized_only());
+        assert!(feature_search_index_hooks());
     }
 
     #[test]
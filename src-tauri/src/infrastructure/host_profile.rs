@@ -0,0 +1,238 @@
+//! Host capability probe and derived concurrency recommendations.
+//!
+//! `probe_host_capabilities` measures CPU cores, memory, a small disk IO benchmark and the
+//! network RTT to the target site, then derives worker/concurrency defaults. The resulting
+//! `HostProfile` is persisted in `app_managed.host_profile` and published through
+//! [`set_current`] so the CrawlingPlanner can cap its concurrency/batch sizing.
+
+use crate::infrastructure::config::csa_iot;
+use crate::infrastructure::rate_limiter::shared_rate_limiter;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+/// Size of the disk benchmark file
+const DISK_PROBE_BYTES: usize = 8 * 1024 * 1024;
+/// Number of RTT samples (median is reported)
+const RTT_SAMPLES: usize = 3;
+
+static CURRENT_PROFILE: Lazy<RwLock<Option<HostProfile>>> = Lazy::new(|| RwLock::new(None));
+
+/// Measured host capabilities plus derived recommendations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HostProfile {
+    pub cpu_cores: u32,
+    pub total_memory_mb: Option<u64>,
+    pub available_memory_mb: Option<u64>,
+    pub disk_write_mb_per_sec: Option<f64>,
+    pub disk_read_mb_per_sec: Option<f64>,
+    pub network_rtt_ms: Option<u64>,
+    pub recommended: HostRecommendation,
+    pub probed_at: DateTime<Utc>,
+}
+
+/// Worker/concurrency defaults recommended for this host
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HostRecommendation {
+    pub list_page_max_concurrent: usize,
+    pub product_detail_max_concurrent: usize,
+    pub max_concurrent_requests: u32,
+    pub batch_size: u32,
+}
+
+/// Publish the active host profile (used by the planner)
+pub fn set_current(profile: Option<HostProfile>) {
+    if let Ok(mut guard) = CURRENT_PROFILE.write() {
+        *guard = profile;
+    }
+}
+
+/// Active host profile, if one has been probed
+pub fn current() -> Option<HostProfile> {
+    CURRENT_PROFILE.read().ok().and_then(|g| g.clone())
+}
+
+/// Derive recommendations from measurements (pure; unit-tested)
+///
+/// Requests are network-bound, so CPU count is only a soft factor; memory and RTT bound the
+/// number of in-flight detail pages (each parsed HTML document is held in memory).
+pub fn recommend(
+    cpu_cores: u32,
+    available_memory_mb: Option<u64>,
+    network_rtt_ms: Option<u64>,
+) -> HostRecommendation {
+    let cpu_cores = cpu_cores.max(1);
+    let mut detail = (cpu_cores * 2).clamp(4, 24);
+    if let Some(mem) = available_memory_mb {
+        // ~64MB headroom per in-flight detail worker
+        detail = detail.min(((mem / 64) as u32).max(2));
+    }
+    if let Some(rtt) = network_rtt_ms {
+        // Slow links benefit from more parallel requests, but stay polite to the site
+        if rtt >= 400 {
+            detail = (detail + detail / 2).min(24);
+        } else if rtt < 50 {
+            detail = detail.min(12);
+        }
+    }
+    let list = (detail / 2).clamp(2, 8);
+    let batch_size = match available_memory_mb {
+        Some(mem) if mem < 2048 => 10,
+        Some(mem) if mem < 8192 => 20,
+        _ => 50,
+    };
+    HostRecommendation {
+        list_page_max_concurrent: list as usize,
+        product_detail_max_concurrent: detail as usize,
+        max_concurrent_requests: detail,
+        batch_size,
+    }
+}
+
+fn probe_cpu_cores() -> u32 {
+    std::thread::available_parallelism()
+        .map(|n| n.get() as u32)
+        .unwrap_or(1)
+}
+
+/// (total, available) memory in MB; Linux only (/proc/meminfo), None elsewhere
+fn probe_memory() -> (Option<u64>, Option<u64>) {
+    let Ok(meminfo) = std::fs::read_to_string("/proc/meminfo") else {
+        return (None, None);
+    };
+    let field = |name: &str| {
+        meminfo
+            .lines()
+            .find(|l| l.starts_with(name))
+            .and_then(|l| l.split_whitespace().nth(1))
+            .and_then(|kb| kb.parse::<u64>().ok())
+            .map(|kb| kb / 1024)
+    };
+    (field("MemTotal:"), field("MemAvailable:"))
+}
+
+/// Share of memory in use (0.0-1.0) right now; None where /proc/meminfo is unavailable
+pub fn memory_pressure() -> Option<f64> {
+    match probe_memory() {
+        (Some(total), Some(available)) if total > 0 => {
+            Some(1.0 - available.min(total) as f64 / total as f64)
+        }
+        _ => None,
+    }
+}
+
+/// Write + fsync + read back a small file; returns (write, read) MB/s
+async fn probe_disk(dir: &Path) -> (Option<f64>, Option<f64>) {
+    let path = dir.join(".host_probe.tmp");
+    let data = vec![0xA5u8; DISK_PROBE_BYTES];
+    let mb = DISK_PROBE_BYTES as f64 / (1024.0 * 1024.0);
+    let result = async {
+        tokio::fs::create_dir_all(dir).await?;
+        let started = Instant::now();
+        {
+            use tokio::io::AsyncWriteExt;
+            let mut f = tokio::fs::File::create(&path).await?;
+            f.write_all(&data).await?;
+            f.sync_all().await?;
+        }
+        let write_secs = started.elapsed().as_secs_f64().max(1e-6);
+        let started = Instant::now();
+        let read_back = tokio::fs::read(&path).await?;
+        let read_secs = started.elapsed().as_secs_f64().max(1e-6);
+        debug!("[HostProbe] disk read back {} bytes", read_back.len());
+        Ok::<_, std::io::Error>((mb / write_secs, mb / read_secs))
+    }
+    .await;
+    let _ = tokio::fs::remove_file(&path).await;
+    match result {
+        Ok((w, r)) => (Some(w), Some(r)),
+        Err(e) => {
+            warn!("[HostProbe] disk benchmark failed: {}", e);
+            (None, None)
+        }
+    }
+}
+
+/// Median HEAD round-trip to the target site
+async fn probe_network_rtt() -> Option<u64> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .ok()?;
+    let limiter = shared_rate_limiter();
+    let mut samples = Vec::with_capacity(RTT_SAMPLES);
+    for _ in 0..RTT_SAMPLES {
+        limiter.acquire(csa_iot::PRODUCTS_BASE).await;
+        let started = Instant::now();
+        match client.head(csa_iot::PRODUCTS_BASE).send().await {
+            Ok(_) => samples.push(started.elapsed().as_millis() as u64),
+            Err(e) => warn!("[HostProbe] RTT sample failed: {}", e),
+        }
+    }
+    samples.sort_unstable();
+    samples.get(samples.len() / 2).copied()
+}
+
+/// Run all probes and derive recommendations. `scratch_dir` hosts the disk benchmark file.
+pub async fn probe_host_capabilities(scratch_dir: &Path) -> HostProfile {
+    let cpu_cores = probe_cpu_cores();
+    let (total_memory_mb, available_memory_mb) = probe_memory();
+    let (disk_write_mb_per_sec, disk_read_mb_per_sec) = probe_disk(scratch_dir).await;
+    let network_rtt_ms = probe_network_rtt().await;
+    let recommended = recommend(cpu_cores, available_memory_mb, network_rtt_ms);
+    info!(
+        "🖥️ [HostProbe] cores={} mem_total={:?}MB mem_avail={:?}MB disk_w={:?}MB/s disk_r={:?}MB/s rtt={:?}ms => {:?}",
+        cpu_cores,
+        total_memory_mb,
+        available_memory_mb,
+        disk_write_mb_per_sec,
+        disk_read_mb_per_sec,
+        network_rtt_ms,
+        recommended
+    );
+    HostProfile {
+        cpu_cores,
+        total_memory_mb,
+        available_memory_mb,
+        disk_write_mb_per_sec,
+        disk_read_mb_per_sec,
+        network_rtt_ms,
+        recommended,
+        probed_at: Utc::now(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recommendation_scales_with_cores_and_memory() {
+        let small = recommend(2, Some(1024), Some(120));
+        assert_eq!(small.product_detail_max_concurrent, 4);
+        assert_eq!(small.list_page_max_concurrent, 2);
+        assert_eq!(small.batch_size, 10);
+
+        let big = recommend(16, Some(32_000), Some(120));
+        assert_eq!(big.product_detail_max_concurrent, 24);
+        assert_eq!(big.list_page_max_concurrent, 8);
+        assert_eq!(big.batch_size, 50);
+    }
+
+    #[test]
+    fn low_memory_caps_detail_workers() {
+        let r = recommend(16, Some(256), None);
+        assert_eq!(r.product_detail_max_concurrent, 4);
+        assert_eq!(r.max_concurrent_requests, 4);
+    }
+
+    #[test]
+    fn fast_link_is_capped_for_politeness() {
+        let r = recommend(16, None, Some(10));
+        assert_eq!(r.product_detail_max_concurrent, 12);
+    }
+}
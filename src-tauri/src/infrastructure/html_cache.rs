@@ -0,0 +1,310 @@
+//! 디스크 기반 HTML 응답 캐시 (ETag / Last-Modified 조건부 요청)
+//!
+//! URL별로 본문과 검증자(ETag, Last-Modified)를 앱 데이터 디렉터리 `cache/html` 아래에 저장한다.
+//! `HttpClient`는 캐시된 검증자로 `If-None-Match` / `If-Modified-Since` 요청을 보내고,
+//! 서버가 304로 답하면 캐시된 본문으로 200 응답을 재구성해 돌려준다. 검증자가 없는 응답은
+//! 저장하지 않으므로 캐시 때문에 변경 내용을 놓치는 일은 없다.
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use reqwest::header::{
+    CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE, ETAG, HeaderMap, HeaderValue,
+    IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use reqwest::{RequestBuilder, Response, ResponseBuilderExt, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use tracing::{debug, warn};
+
+/// Stored validators and response metadata of one cached URL
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CachedMeta {
+    pub url: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub content_type: Option<String>,
+    pub stored_at: DateTime<Utc>,
+}
+
+impl CachedMeta {
+    /// Attach conditional request headers for this entry
+    pub fn apply_validators(&self, mut rb: RequestBuilder) -> RequestBuilder {
+        if let Some(etag) = &self.etag {
+            rb = rb.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &self.last_modified {
+            rb = rb.header(IF_MODIFIED_SINCE, last_modified);
+        }
+        rb
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HtmlCacheStats {
+    pub directory: String,
+    /// Responses served from cache after a 304
+    pub hits: u64,
+    /// Successful responses fetched in full
+    pub misses: u64,
+    /// Entries written or refreshed
+    pub stores: u64,
+    pub hit_ratio: f64,
+    pub entries: u64,
+    pub bytes: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HtmlCacheCleared {
+    pub removed_entries: u64,
+    pub freed_bytes: u64,
+}
+
+/// URL-keyed HTML cache shared by every `HttpClient`
+#[derive(Debug)]
+pub struct HtmlCache {
+    dir: PathBuf,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    stores: AtomicU64,
+}
+
+static SHARED_HTML_CACHE: OnceLock<Arc<HtmlCache>> = OnceLock::new();
+
+/// Process-wide cache under `<app data>/cache/html`
+pub fn shared_html_cache() -> Arc<HtmlCache> {
+    SHARED_HTML_CACHE
+        .get_or_init(|| {
+            let base = crate::infrastructure::config::ConfigManager::get_app_data_dir()
+                .unwrap_or_else(|_| std::env::temp_dir().join("matter-certis-v2"));
+            Arc::new(HtmlCache::new(base.join("cache").join("html")))
+        })
+        .clone()
+}
+
+fn header_string(headers: &HeaderMap, name: reqwest::header::HeaderName) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+impl HtmlCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            stores: AtomicU64::new(0),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn key(url: &str) -> String {
+        blake3::hash(url.as_bytes()).to_hex().to_string()
+    }
+
+    fn meta_path(&self, url: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", Self::key(url)))
+    }
+
+    fn body_path(&self, url: &str) -> PathBuf {
+        self.dir.join(format!("{}.html", Self::key(url)))
+    }
+
+    /// Validators of a cached entry (None = not cached)
+    pub async fn lookup(&self, url: &str) -> Option<CachedMeta> {
+        let raw = tokio::fs::read(self.meta_path(url)).await.ok()?;
+        let meta: CachedMeta = serde_json::from_slice(&raw).ok()?;
+        // Guard against hash collisions and hand-edited entries
+        (meta.url == url).then_some(meta)
+    }
+
+    /// Write body and validators; only responses carrying a validator are cacheable
+    pub async fn store(&self, url: &str, headers: &HeaderMap, body: &[u8]) -> Result<bool> {
+        let meta = CachedMeta {
+            url: url.to_string(),
+            etag: header_string(headers, ETAG),
+            last_modified: header_string(headers, LAST_MODIFIED),
+            content_type: header_string(headers, CONTENT_TYPE),
+            stored_at: Utc::now(),
+        };
+        if meta.etag.is_none() && meta.last_modified.is_none() {
+            return Ok(false);
+        }
+        tokio::fs::create_dir_all(&self.dir).await?;
+        // Body first: a meta file only ever points at a complete body
+        tokio::fs::write(self.body_path(url), body).await?;
+        tokio::fs::write(self.meta_path(url), serde_json::to_vec(&meta)?).await?;
+        self.stores.fetch_add(1, Ordering::Relaxed);
+        Ok(true)
+    }
+
+    /// Settle a network response against the cache: a 304 becomes the cached 200, a fresh
+    /// HTML 200 with validators is stored (and handed back rebuilt from the buffered body).
+    pub async fn resolve(
+        &self,
+        url: &str,
+        response: Response,
+        cached: Option<CachedMeta>,
+    ) -> Result<Response> {
+        let status = response.status();
+        if status == StatusCode::NOT_MODIFIED {
+            let Some(meta) = cached else {
+                return Ok(response);
+            };
+            match tokio::fs::read(self.body_path(url)).await {
+                Ok(body) => {
+                    self.hits.fetch_add(1, Ordering::Relaxed);
+                    debug!("🗄️ [html-cache] 304 hit: {}", url);
+                    let mut headers = HeaderMap::new();
+                    for (name, value) in [
+                        (CONTENT_TYPE, &meta.content_type),
+                        (ETAG, &meta.etag),
+                        (LAST_MODIFIED, &meta.last_modified),
+                    ] {
+                        if let Some(v) =
+                            value.as_deref().and_then(|v| HeaderValue::from_str(v).ok())
+                        {
+                            headers.insert(name, v);
+                        }
+                    }
+                    return rebuild(url, StatusCode::OK, headers, body);
+                }
+                Err(e) => {
+                    // Body vanished (cleared mid-flight): drop the entry so the next attempt
+                    // goes out unconditional
+                    warn!(
+                        "⚠️ [html-cache] 304 for {} but cached body unreadable: {}",
+                        url, e
+                    );
+                    let _ = tokio::fs::remove_file(self.meta_path(url)).await;
+                    return Ok(response);
+                }
+            }
+        }
+        if !status.is_success() {
+            return Ok(response);
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        let headers = response.headers().clone();
+        let has_validator = headers.contains_key(ETAG) || headers.contains_key(LAST_MODIFIED);
+        let is_html = header_string(&headers, CONTENT_TYPE).is_none_or(|ct| ct.contains("html"));
+        if !has_validator || !is_html {
+            return Ok(response);
+        }
+        let body = response.bytes().await?.to_vec();
+        if let Err(e) = self.store(url, &headers, &body).await {
+            warn!("⚠️ [html-cache] store failed for {}: {}", url, e);
+        }
+        let mut headers = headers;
+        // The buffered body is already decoded
+        headers.remove(CONTENT_ENCODING);
+        headers.remove(CONTENT_LENGTH);
+        rebuild(url, status, headers, body)
+    }
+
+    pub fn stats(&self) -> HtmlCacheStats {
+        let (entries, bytes) = self.disk_usage();
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let total = hits + misses;
+        HtmlCacheStats {
+            directory: self.dir.display().to_string(),
+            hits,
+            misses,
+            stores: self.stores.load(Ordering::Relaxed),
+            hit_ratio: if total == 0 {
+                0.0
+            } else {
+                hits as f64 / total as f64
+            },
+            entries,
+            bytes,
+        }
+    }
+
+    /// (cached URLs, bytes on disk)
+    fn disk_usage(&self) -> (u64, u64) {
+        let Ok(dir) = std::fs::read_dir(&self.dir) else {
+            return (0, 0);
+        };
+        let mut entries = 0;
+        let mut bytes = 0;
+        for entry in dir.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|e| e == "json") {
+                entries += 1;
+            }
+            bytes += entry.metadata().map(|m| m.len()).unwrap_or(0);
+        }
+        (entries, bytes)
+    }
+
+    /// Delete every cached entry and reset the hit/miss counters
+    pub fn clear(&self) -> Result<HtmlCacheCleared> {
+        let (removed_entries, freed_bytes) = self.disk_usage();
+        match std::fs::remove_dir_all(&self.dir) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(anyhow!("Failed to clear {}: {}", self.dir.display(), e)),
+        }
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+        self.stores.store(0, Ordering::Relaxed);
+        Ok(HtmlCacheCleared {
+            removed_entries,
+            freed_bytes,
+        })
+    }
+}
+
+fn rebuild(url: &str, status: StatusCode, headers: HeaderMap, body: Vec<u8>) -> Result<Response> {
+    let mut builder = http::Response::builder().status(status);
+    if let Ok(parsed) = Url::parse(url) {
+        builder = builder.url(parsed);
+    }
+    if let Some(h) = builder.headers_mut() {
+        *h = headers;
+    }
+    let response = builder
+        .body(body)
+        .map_err(|e| anyhow!("Failed to rebuild cached response: {}", e))?;
+    Ok(Response::from(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn store_requires_validators_and_clear_resets() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = HtmlCache::new(dir.path().join("html"));
+        let url = "https://csa-iot.org/csa_product/lamp/";
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/html"));
+        assert!(!cache.store(url, &headers, b"<html/>").await.unwrap());
+        assert!(cache.lookup(url).await.is_none());
+
+        headers.insert(ETAG, HeaderValue::from_static("\"v1\""));
+        assert!(cache.store(url, &headers, b"<html/>").await.unwrap());
+        let meta = cache.lookup(url).await.unwrap();
+        assert_eq!(meta.etag.as_deref(), Some("\"v1\""));
+        assert!(cache.lookup("https://csa-iot.org/other").await.is_none());
+
+        let stats = cache.stats();
+        assert_eq!((stats.entries, stats.stores), (1, 1));
+        let cleared = cache.clear().unwrap();
+        assert_eq!(cleared.removed_entries, 1);
+        assert!(cleared.freed_bytes > 0);
+        assert!(cache.lookup(url).await.is_none());
+        assert_eq!(cache.stats().stores, 0);
+    }
+}
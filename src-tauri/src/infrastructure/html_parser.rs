@@ -8,7 +8,10 @@
 #![allow(clippy::uninlined_format_args)]
 
 use crate::domain::product::{Product, ProductDetail};
+use crate::domain::site_profile::SiteProfile;
 use crate::infrastructure::csa_iot;
+use crate::infrastructure::extractor_rollout;
+use crate::infrastructure::site_profiles::default_site_profile;
 use anyhow::{Result, anyhow};
 use scraper::{ElementRef, Html, Selector};
 use std::sync::{Arc, RwLock};
@@ -98,11 +101,84 @@ impl Default for MatterExtractorConfig {
     }
 }
 
+/// Site profile selectors, parsed once when the extractor is built
+struct ParsedSelectors {
+    item_containers: Vec<Selector>,
+    /// Every item container as one selector group
+    any_item_container: Selector,
+    product_link: Selector,
+    list_manufacturer: Selector,
+    list_model: Selector,
+    list_certificate_id: Vec<Selector>,
+    pagination_links: Vec<Selector>,
+    page_info: Vec<Selector>,
+    detail_model: Selector,
+    detail_manufacturer: Selector,
+    detail_device_type: Selector,
+    detail_certificate_id: Selector,
+    detail_certification_date: Selector,
+    info_table: Selector,
+    detail_items: Selector,
+    item_label: Selector,
+    item_value: Selector,
+    legacy_detail_items: Selector,
+}
+
+impl ParsedSelectors {
+    /// Parse every selector of `profile`; the first invalid one is an error
+    fn from_profile(profile: &dyn SiteProfile) -> Result<Self> {
+        let parse = |name: &str, css: &str| {
+            Selector::parse(css).map_err(|e| {
+                anyhow!(
+                    "Invalid {} selector '{}' in site profile '{}': {}",
+                    name,
+                    css,
+                    profile.id(),
+                    e
+                )
+            })
+        };
+        let parse_all = |name: &str, list: &[&str]| {
+            list.iter()
+                .map(|css| parse(name, css))
+                .collect::<Result<Vec<_>>>()
+        };
+
+        let list = profile.list_selectors();
+        let detail = profile.detail_selectors();
+        Ok(Self {
+            item_containers: parse_all("item container", list.item_containers)?,
+            any_item_container: parse("item container", &list.item_containers.join(", "))?,
+            product_link: parse("product link", list.product_link)?,
+            list_manufacturer: parse("list manufacturer", list.manufacturer)?,
+            list_model: parse("list model", list.model)?,
+            list_certificate_id: parse_all("list certificate id", list.certificate_id)?,
+            pagination_links: parse_all("pagination link", list.pagination_links)?,
+            page_info: parse_all("page info", list.page_info)?,
+            detail_model: parse("detail model", detail.model)?,
+            detail_manufacturer: parse("detail manufacturer", detail.manufacturer)?,
+            detail_device_type: parse("detail device type", detail.device_type)?,
+            detail_certificate_id: parse("detail certificate id", detail.certificate_id)?,
+            detail_certification_date: parse(
+                "detail certification date",
+                detail.certification_date,
+            )?,
+            info_table: parse("info table", detail.info_table)?,
+            detail_items: parse("detail list item", detail.detail_items)?,
+            item_label: parse("item label", detail.item_label)?,
+            item_value: parse("item value", detail.item_value)?,
+            legacy_detail_items: parse("legacy detail list item", detail.legacy_detail_items)?,
+        })
+    }
+}
+
 /// Specialized data extractor for Matter certification websites
 /// Following the guide approach for clean, direct DOM extraction
 #[derive(Clone)]
 pub struct MatterDataExtractor {
     config: MatterExtractorConfig,
+    profile: Arc<dyn SiteProfile>,
+    selectors: Arc<ParsedSelectors>,
     pagination_context: Arc<RwLock<Option<PaginationContext>>>,
 }
 
@@ -114,11 +190,36 @@ impl MatterDataExtractor {
 
     /// Create a new data extractor with custom configuration
     pub fn with_config(config: MatterExtractorConfig) -> Result<Self> {
+        let profile = default_site_profile();
+        let selectors = ParsedSelectors::from_profile(profile.as_ref())?;
         Ok(Self {
             config,
+            profile,
+            selectors: Arc::new(selectors),
             pagination_context: Arc::new(RwLock::new(None)),
         })
     }
+
+    /// Create an extractor for another certification site; fails if any profile selector is
+    /// invalid
+    pub fn with_profile(profile: Arc<dyn SiteProfile>) -> Result<Self> {
+        let selectors = ParsedSelectors::from_profile(profile.as_ref())?;
+        let config = MatterExtractorConfig {
+            base_url: profile.base_url().to_string(),
+            ..MatterExtractorConfig::default()
+        };
+        Ok(Self {
+            config,
+            profile,
+            selectors: Arc::new(selectors),
+            pagination_context: Arc::new(RwLock::new(None)),
+        })
+    }
+
+    /// Site whose selectors and URL rules this extractor applies
+    pub fn site_profile(&self) -> Arc<dyn SiteProfile> {
+        Arc::clone(&self.profile)
+    }
     /// Set pagination context for proper pageId and indexInPage calculation
     pub fn set_pagination_context(&self, context: PaginationContext) -> Result<()> {
         let mut pagination_context = self
@@ -139,82 +240,55 @@ impl MatterDataExtractor {
     pub fn extract_product_urls(&self, html: &Html, base_url: &str) -> Result<Vec<String>> {
         debug!("Extracting product URLs from listing page");
 
-        // Primary container observed across most pages
-        let primary_article_selector = Selector::parse("div.post-feed article")
-            .map_err(|e| anyhow!("Invalid article selector: {}", e))?;
-        // Fallback container seen on some pages/themes
-        let fallback_article_selector =
-            Selector::parse(".wp-block-crown-blocks-product-index article.product").ok();
-        let link_selector =
-            Selector::parse("a").map_err(|e| anyhow!("Invalid link selector: {}", e))?;
+        let selectors = self.profile.list_selectors();
+        let link_selector = &self.selectors.product_link;
+        let expected_per_page = self.profile.products_per_page() as usize;
+
+        // First anchor per item that clearly points to a product page
+        let first_product_link = |article: ElementRef| -> Option<String> {
+            article.select(link_selector).find_map(|link| {
+                let href = link.value().attr("href")?;
+                let url = self.resolve_url(href, base_url);
+                self.profile.is_product_url(&url).then_some(url)
+            })
+        };
 
         let mut urls: Vec<String> = Vec::new();
-
-        // Helper to scan a set of article nodes and push the first valid product link per article
-        let mut scan_articles = |articles: Vec<ElementRef>| {
-            for article in articles {
-                let mut chosen: Option<String> = None;
-                for link in article.select(&link_selector) {
-                    if let Some(href) = link.value().attr("href") {
-                        let url = self.resolve_url(href, base_url);
-                        // Pick the first anchor that clearly points to a product page
-                        if url.contains("/csa_product/") && !url.contains("/csa-iot_products/") {
-                            chosen = Some(url);
-                            break; // stop at the first matching product link in this article
-                        }
-                    }
-                }
-                if let Some(u) = chosen {
-                    urls.push(u);
+        let mut seen: std::collections::HashSet<String> = std::collections::HashSet::new();
+        for (position, container) in selectors.item_containers.iter().enumerate() {
+            let article_selector = &self.selectors.item_containers[position];
+            // Fallback containers only when the page came up short of a full page
+            if position > 0 && urls.len() >= expected_per_page {
+                break;
+            }
+            let articles: Vec<_> = html.select(article_selector).collect();
+            if position == 0 {
+                debug!("Found {} primary article elements", articles.len());
+                // primary keeps every match (duplicates included) as before
+                for url in articles.into_iter().filter_map(&first_product_link) {
+                    seen.insert(url.clone());
+                    urls.push(url);
                 }
+                continue;
             }
-        };
-
-        let primary: Vec<_> = html.select(&primary_article_selector).collect();
-        debug!("Found {} primary article elements", primary.len());
-        scan_articles(primary);
-
-        // If we extracted fewer than 12 products on a non-terminal page, try a fallback container
-        if urls.len() < 12 {
-            if let Some(fallback_sel) = &fallback_article_selector {
-                let fallback: Vec<_> = html.select(fallback_sel).collect();
-                if !fallback.is_empty() {
-                    debug!(
-                        "Primary extraction yielded {} items; trying fallback container ({} elems)",
-                        urls.len(),
-                        fallback.len()
-                    );
-                    // Track already seen URLs to avoid duplicates while preserving order
-                    let mut seen: std::collections::HashSet<String> =
-                        urls.iter().cloned().collect();
-                    // Temporarily collect fallback URLs, then extend if new
-                    let mut fb_urls: Vec<String> = Vec::new();
-                    for article in fallback {
-                        let mut chosen: Option<String> = None;
-                        for link in article.select(&link_selector) {
-                            if let Some(href) = link.value().attr("href") {
-                                let url = self.resolve_url(href, base_url);
-                                if url.contains("/csa_product/")
-                                    && !url.contains("/csa-iot_products/")
-                                {
-                                    chosen = Some(url);
-                                    break;
-                                }
-                            }
-                        }
-                        if let Some(u) = chosen {
-                            if !seen.contains(&u) {
-                                fb_urls.push(u.clone());
-                                seen.insert(u);
-                            }
-                        }
-                    }
-                    if !fb_urls.is_empty() {
-                        debug!("Fallback added {} additional URLs", fb_urls.len());
-                        urls.extend(fb_urls);
-                    }
+            if articles.is_empty() {
+                continue;
+            }
+            debug!(
+                "Primary extraction yielded {} items; trying fallback container {} ({} elems)",
+                urls.len(),
+                container,
+                articles.len()
+            );
+            let before = urls.len();
+            for url in articles.into_iter().filter_map(&first_product_link) {
+                if seen.insert(url.clone()) {
+                    urls.push(url);
                 }
             }
+            if urls.len() > before {
+                debug!("Fallback added {} additional URLs", urls.len() - before);
+            }
         }
 
         debug!("Extracted {} product URLs", urls.len());
@@ -231,50 +305,39 @@ impl MatterDataExtractor {
     pub fn extract_total_pages(&self, html_content: &str) -> Result<u32> {
         let html = Html::parse_document(html_content);
 
-        let pagination_selectors = vec![
-            "a[href*='page=']", // 페이지 링크
-            ".pagination a",    // 페이지네이션 링크
-            ".page-numbers a",  // 워드프레스 스타일
-        ];
-
+        // 페이지 링크 / 페이지네이션 링크 / 워드프레스 스타일 (프로필별)
         let mut max_page = 1u32;
 
-        for selector_str in pagination_selectors {
-            if let Ok(selector) = Selector::parse(selector_str) {
-                for element in html.select(&selector) {
-                    // href에서 page= 파라미터 추출
-                    if let Some(href) = element.value().attr("href") {
-                        if let Some(page_param) = href.split("page=").nth(1) {
-                            let page_num_str = page_param.split('&').next().unwrap_or("");
-                            if let Ok(page_num) = page_num_str.parse::<u32>() {
-                                max_page = max_page.max(page_num);
-                            }
+        for selector in &self.selectors.pagination_links {
+            for element in html.select(selector) {
+                // href에서 page= 파라미터 추출
+                if let Some(href) = element.value().attr("href") {
+                    if let Some(page_param) = href.split("page=").nth(1) {
+                        let page_num_str = page_param.split('&').next().unwrap_or("");
+                        if let Ok(page_num) = page_num_str.parse::<u32>() {
+                            max_page = max_page.max(page_num);
                         }
                     }
+                }
 
-                    // 텍스트에서 페이지 번호 추출
-                    let text = element.text().collect::<String>();
-                    if let Ok(page_num) = text.trim().parse::<u32>() {
-                        max_page = max_page.max(page_num);
-                    }
+                // 텍스트에서 페이지 번호 추출
+                let text = element.text().collect::<String>();
+                if let Ok(page_num) = text.trim().parse::<u32>() {
+                    max_page = max_page.max(page_num);
                 }
             }
         }
 
         // "Page X of Y" 형태의 텍스트에서 총 페이지 수 추출
-        let page_info_selectors = vec![".pagination-info", ".page-info", ".showing-info"];
-
         let re = regex::Regex::new(r"(?i)page\s+\d+\s+of\s+(\d+)").unwrap();
 
-        for selector_str in page_info_selectors {
-            if let Ok(selector) = Selector::parse(selector_str) {
-                for element in html.select(&selector) {
-                    let text = element.text().collect::<String>();
-                    if let Some(captures) = re.captures(&text) {
-                        if let Some(total_str) = captures.get(1) {
-                            if let Ok(total) = total_str.as_str().parse::<u32>() {
-                                max_page = max_page.max(total);
-                            }
+        for selector in &self.selectors.page_info {
+            for element in html.select(selector) {
+                let text = element.text().collect::<String>();
+                if let Some(captures) = re.captures(&text) {
+                    if let Some(total_str) = captures.get(1) {
+                        if let Ok(total) = total_str.as_str().parse::<u32>() {
+                            max_page = max_page.max(total);
                         }
                     }
                 }
@@ -317,12 +380,12 @@ impl MatterDataExtractor {
 
     /// Check if the given HTML represents a listing page
     fn is_listing_page(&self, html: &Html, url: &str) -> bool {
-        if url.contains("page=") || url.contains("products") && !url.contains("csa_product") {
+        if self.profile.is_listing_url(url) {
             return true;
         }
 
-        if let Ok(article_selector) = Selector::parse("div.post-feed article") {
-            let article_count = html.select(&article_selector).count();
+        if let Some(article_selector) = self.selectors.item_containers.first() {
+            let article_count = html.select(article_selector).count();
             return article_count > 1;
         }
 
@@ -346,12 +409,14 @@ impl MatterDataExtractor {
         debug!("Extracting single product from detail page: {}", url);
         let now = chrono::Utc::now();
 
-        let manufacturer = self.extract_field_text(html, ".manufacturer, .company-info");
-        let model = self.extract_field_text(html, "h1.entry-title, h1");
-        let certificate_id = self.extract_field_text(html, ".cert-id, .certification-id");
+        let selectors = &self.selectors;
+        let manufacturer = self.extract_field_text(html, &selectors.detail_manufacturer);
+        let model = self.extract_field_text(html, &selectors.detail_model);
+        let certificate_id = self.extract_field_text(html, &selectors.detail_certificate_id);
         // Product 구조체에는 아직 device_type / certification_date 필드가 없어 경고 발생 → 추후 확장 대비 유지
-        let _device_type = self.extract_field_text(html, ".device-type, .category");
-        let _certification_date = self.extract_field_text(html, ".cert-date, .certification-date");
+        let _device_type = self.extract_field_text(html, &selectors.detail_device_type);
+        let _certification_date =
+            self.extract_field_text(html, &selectors.detail_certification_date);
 
         debug!(
             "Extracted from {}: manufacturer={:?}, model={:?}, cert_id={:?}",
@@ -375,13 +440,8 @@ impl MatterDataExtractor {
     pub fn extract_products_from_list(&self, html: &Html, page_id: i32) -> Result<Vec<Product>> {
         debug!("Extracting products from listing page {}", page_id);
 
-        let article_selector = Selector::parse(
-            "div.post-feed article, .wp-block-crown-blocks-product-index article.product",
-        )
-        .map_err(|e| anyhow!("Invalid article selector: {}", e))?;
-
         let mut products = Vec::new();
-        let articles: Vec<_> = html.select(&article_selector).collect();
+        let articles: Vec<_> = html.select(&self.selectors.any_item_container).collect();
         debug!("Found {} article elements", articles.len());
 
         // Process articles in reverse order to match expected index order (guide approach)
@@ -399,17 +459,29 @@ impl MatterDataExtractor {
 
     /// Extract detailed product information from a product detail page (guide-based approach)
     pub fn extract_product_detail(&self, html: &Html, url: String) -> Result<ProductDetail> {
+        let detail = self.extract_detail_with_rules(html, url)?;
+        // New extraction rules in shadow mode run on the same page for comparison only
+        if let Some(candidate) = extractor_rollout::shadow_candidate(self.profile.id()) {
+            let shadow = Self::with_profile(candidate)
+                .and_then(|e| e.extract_detail_with_rules(html, detail.url.clone()));
+            extractor_rollout::record_comparison(
+                &detail,
+                shadow.as_ref().map_err(|e| e.to_string()),
+            );
+        }
+        Ok(detail)
+    }
+
+    fn extract_detail_with_rules(&self, html: &Html, url: String) -> Result<ProductDetail> {
         debug!("Extracting product detail from: {}", url);
 
         let now = chrono::Utc::now();
 
         // Extract basic product information from page headers/title
-        let model = self.extract_field_text(html, &self.config.product_detail_selectors.model);
-        let manufacturer = self.extract_field_text(
-            html,
-            "p.company-info, .company-name, .manufacturer, p.entry-company, .entry-company",
-        );
-        let device_type = self.extract_field_text(html, "p.device-category, .product-type, .device-type, p.entry-category, .entry-category, h6.entry-category");
+        let selectors = &self.selectors;
+        let model = self.extract_field_text(html, &selectors.detail_model);
+        let manufacturer = self.extract_field_text(html, &selectors.detail_manufacturer);
+        let device_type = self.extract_field_text(html, &selectors.detail_device_type);
 
         let mut detail = ProductDetail {
             url,
@@ -482,26 +554,23 @@ impl MatterDataExtractor {
         };
 
         // Extract URL - simple and direct approach
-        let link_selector = Selector::parse("a").unwrap();
         let url = article
-            .select(&link_selector)
+            .select(&self.selectors.product_link)
             .next()
             .and_then(|link| link.value().attr("href"))
             .map(|href| self.resolve_url(href, &self.config.base_url))
             .unwrap_or_else(|| format!("unknown-{}-{}", page_id, index_in_page));
 
         // Extract manufacturer - exactly as in guide
-        let manufacturer_selector = Selector::parse("p.entry-company.notranslate").unwrap();
         let manufacturer = article
-            .select(&manufacturer_selector)
+            .select(&self.selectors.list_manufacturer)
             .next()
             .map(|el| el.text().collect::<Vec<_>>().join("").trim().to_string())
             .filter(|s| !s.is_empty());
 
         // Extract model - exactly as in guide
-        let model_selector = Selector::parse("h3.entry-title").unwrap();
         let model = article
-            .select(&model_selector)
+            .select(&self.selectors.list_model)
             .next()
             .map(|el| el.text().collect::<Vec<_>>().join("").trim().to_string())
             .filter(|s| !s.is_empty());
@@ -536,33 +605,21 @@ impl MatterDataExtractor {
 
     /// Extract certificate ID from article element following the guide's approach
     fn extract_certificate_id_from_article(&self, article: &ElementRef) -> Option<String> {
-        // Try p.entry-certificate-id first (guide approach)
-        let cert_id_p_selector = Selector::parse("p.entry-certificate-id").unwrap();
-        if let Some(cert_p_el) = article.select(&cert_id_p_selector).next() {
-            let text = cert_p_el
-                .text()
-                .collect::<Vec<_>>()
-                .join("")
-                .trim()
-                .to_string();
-            if text.starts_with("Certificate ID: ") {
-                return Some(text.replace("Certificate ID: ", "").trim().to_string());
-            } else if !text.is_empty() {
-                return Some(text);
-            }
-        }
-
-        // Fallback to span.entry-cert-id (guide approach)
-        let cert_id_selector = Selector::parse("span.entry-cert-id").unwrap();
-        if let Some(cert_span_el) = article.select(&cert_id_selector).next() {
-            let text = cert_span_el
-                .text()
-                .collect::<Vec<_>>()
-                .join("")
-                .trim()
-                .to_string();
-            if !text.is_empty() {
-                return Some(text);
+        // Try each certificate selector in order (e.g. p.entry-certificate-id, then span.entry-cert-id)
+        for selector in &self.selectors.list_certificate_id {
+            if let Some(cert_el) = article.select(selector).next() {
+                let text = cert_el
+                    .text()
+                    .collect::<Vec<_>>()
+                    .join("")
+                    .trim()
+                    .to_string();
+                let label = self.profile.list_selectors().certificate_id_label;
+                if !label.is_empty() && text.starts_with(label) {
+                    return Some(text.replace(label, "").trim().to_string());
+                } else if !text.is_empty() {
+                    return Some(text);
+                }
             }
         }
 
@@ -571,10 +628,7 @@ impl MatterDataExtractor {
 
     /// Extract product information from table elements (guide-based approach)
     fn extract_from_table(&self, html: &Html, detail: &mut ProductDetail) -> Result<()> {
-        let table_selector = Selector::parse(".product-certificates-table")
-            .map_err(|e| anyhow!("Invalid table selector: {}", e))?;
-
-        if let Some(table) = html.select(&table_selector).next() {
+        if let Some(table) = html.select(&self.selectors.info_table).next() {
             let row_selector = Selector::parse("tr").unwrap();
             let cell_selector = Selector::parse("td").unwrap();
 
@@ -606,23 +660,14 @@ impl MatterDataExtractor {
 
     /// Extract product information from detail list items (guide-based approach)
     fn extract_from_detail_list(&self, html: &Html, detail: &mut ProductDetail) -> Result<()> {
-        // First try the new CSA-IoT site format with label/value spans
-        let item_selector = Selector::parse("div.entry-product-details li.item")
-            .or_else(|_| Selector::parse("ul.detail-items li.item"))
-            .map_err(|e| anyhow!("Invalid detail list item selector: {}", e))?;
+        let selectors = &self.selectors;
 
-        let label_selector =
-            Selector::parse("span.label").map_err(|e| anyhow!("Invalid label selector: {}", e))?;
-
-        let value_selector =
-            Selector::parse("span.value").map_err(|e| anyhow!("Invalid value selector: {}", e))?;
-
-        // Try to extract from structured list items with label/value spans
+        // First try the current site format with label/value spans
         let mut found_items = false;
-        for item in html.select(&item_selector) {
+        for item in html.select(&selectors.detail_items) {
             if let (Some(label_el), Some(value_el)) = (
-                item.select(&label_selector).next(),
-                item.select(&value_selector).next(),
+                item.select(&selectors.item_label).next(),
+                item.select(&selectors.item_value).next(),
             ) {
                 let label = label_el.text().collect::<String>().trim().to_lowercase();
                 let value = value_el.text().collect::<String>().trim().to_string();
@@ -637,10 +682,7 @@ impl MatterDataExtractor {
         // If we didn't find any items with the span structure, try the old format with colon-separated text
         if !found_items {
             // Fall back to the original selector for backwards compatibility
-            let fallback_selector = Selector::parse("div.entry-product-details > div > ul li")
-                .map_err(|e| anyhow!("Invalid fallback list selector: {}", e))?;
-
-            for item in html.select(&fallback_selector) {
+            for item in html.select(&selectors.legacy_detail_items) {
                 let full_text = item.text().collect::<Vec<_>>().join("").trim().to_string();
 
                 if let Some(colon_index) = full_text.find(':') {
@@ -777,9 +819,8 @@ impl MatterDataExtractor {
     }
 
     /// Extract text content from an element using a CSS selector
-    fn extract_field_text(&self, html: &Html, selector: &str) -> Option<String> {
-        let selector_parsed = Selector::parse(selector).ok()?;
-        html.select(&selector_parsed)
+    fn extract_field_text(&self, html: &Html, selector: &Selector) -> Option<String> {
+        html.select(selector)
             .next()
             .map(|element| element.text().collect::<String>().trim().to_string())
             .filter(|text| !text.is_empty())
@@ -856,6 +897,7 @@ impl PaginationContext {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::site_profile::{DetailPageSelectors, ListPageSelectors};
     use scraper::Html;
 
     // Minimal test data - only what's needed for comprehensive testing
@@ -1226,4 +1268,139 @@ mod tests {
         assert_eq!(page_id, 481, "Newest product should have pageId=481");
         assert_eq!(index_in_page, 1, "Newest product should have indexInPage=1");
     }
+
+    #[derive(Debug)]
+    struct ExampleRegistryProfile;
+
+    impl ExampleRegistryProfile {
+        const LIST: ListPageSelectors = ListPageSelectors {
+            item_containers: &["li.cert-row"],
+            product_link: "a.detail",
+            manufacturer: "span.vendor",
+            model: "span.model",
+            certificate_id: &["span.cert"],
+            certificate_id_label: "Cert #",
+            pagination_links: &["nav a"],
+            page_info: &[],
+        };
+        const DETAIL: DetailPageSelectors = DetailPageSelectors {
+            model: "h1",
+            manufacturer: "dd.vendor",
+            device_type: "dd.kind",
+            certificate_id: "dd.cert",
+            certification_date: "dd.date",
+            info_table: "table.specs",
+            detail_items: "ul.facts li",
+            item_label: "b",
+            item_value: "i",
+            legacy_detail_items: "div.legacy li",
+        };
+    }
+
+    impl SiteProfile for ExampleRegistryProfile {
+        fn id(&self) -> &'static str {
+            "example-registry"
+        }
+        fn base_url(&self) -> &str {
+            "https://registry.example.org"
+        }
+        fn listing_base_url(&self) -> &str {
+            "https://registry.example.org/certs"
+        }
+        fn list_page_url(&self, page: u32) -> String {
+            format!("https://registry.example.org/certs?page={}", page)
+        }
+        fn products_per_page(&self) -> u32 {
+            2
+        }
+        fn is_product_url(&self, url: &str) -> bool {
+            url.contains("/cert/")
+        }
+        fn list_selectors(&self) -> &ListPageSelectors {
+            &Self::LIST
+        }
+        fn detail_selectors(&self) -> &DetailPageSelectors {
+            &Self::DETAIL
+        }
+    }
+
+    #[test]
+    fn test_custom_site_profile_drives_list_extraction() {
+        let extractor =
+            MatterDataExtractor::with_profile(Arc::new(ExampleRegistryProfile)).unwrap();
+        let html = r#"
+            <ul>
+              <li class="cert-row"><a class="detail" href="/cert/a-1">A</a><span class="vendor">Acme</span>
+                <span class="model">A1</span><span class="cert">Cert # 0001</span></li>
+              <li class="cert-row"><a href="/about">about</a><a class="detail" href="/cert/b-2">B</a></li>
+            </ul>
+            <nav><a href="/certs?page=2">2</a><a href="/certs?page=9">9</a></nav>
+        "#;
+        let urls = extractor.extract_product_urls_from_content(html).unwrap();
+        assert_eq!(
+            urls,
+            vec![
+                "https://registry.example.org/cert/a-1".to_string(),
+                "https://registry.example.org/cert/b-2".to_string(),
+            ]
+        );
+        assert_eq!(extractor.extract_total_pages(html).unwrap(), 9);
+
+        let products = extractor
+            .extract_products_from_list(&Html::parse_document(html), 3)
+            .unwrap();
+        let acme = products
+            .iter()
+            .find(|p| p.manufacturer.as_deref() == Some("Acme"))
+            .unwrap();
+        assert_eq!(acme.model.as_deref(), Some("A1"));
+        assert_eq!(acme.certificate_id.as_deref(), Some("0001"));
+    }
+    /// Example registry whose list manufacturer selector does not parse
+    #[derive(Debug)]
+    struct BrokenSelectorProfile;
+
+    impl BrokenSelectorProfile {
+        const LIST: ListPageSelectors = ListPageSelectors {
+            manufacturer: "span[vendor",
+            ..ExampleRegistryProfile::LIST
+        };
+    }
+
+    impl SiteProfile for BrokenSelectorProfile {
+        fn id(&self) -> &'static str {
+            "broken-registry"
+        }
+        fn base_url(&self) -> &str {
+            "https://registry.example.org"
+        }
+        fn listing_base_url(&self) -> &str {
+            "https://registry.example.org/certs"
+        }
+        fn list_page_url(&self, page: u32) -> String {
+            ExampleRegistryProfile.list_page_url(page)
+        }
+        fn products_per_page(&self) -> u32 {
+            ExampleRegistryProfile.products_per_page()
+        }
+        fn is_product_url(&self, url: &str) -> bool {
+            ExampleRegistryProfile.is_product_url(url)
+        }
+        fn list_selectors(&self) -> &ListPageSelectors {
+            &Self::LIST
+        }
+        fn detail_selectors(&self) -> &DetailPageSelectors {
+            &ExampleRegistryProfile::DETAIL
+        }
+    }
+
+    #[test]
+    fn test_invalid_profile_selector_is_rejected_up_front() {
+        let err = MatterDataExtractor::with_profile(Arc::new(BrokenSelectorProfile))
+            .err()
+            .expect("invalid selector must fail construction")
+            .to_string();
+        assert!(err.contains("list manufacturer"), "{err}");
+        assert!(err.contains("broken-registry"), "{err}");
+    }
 }
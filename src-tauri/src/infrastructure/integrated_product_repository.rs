@@ -11,11 +11,16 @@
 
 use crate::domain::integrated_product::DatabaseStatistics;
 use crate::domain::product::{
-    Product, ProductDetail, ProductSearchCriteria, ProductSearchResult, ProductWithDetails, Vendor,
+    Product, ProductDetail, ProductExportFilter, ProductSearchCriteria, ProductSearchResult,
+    ProductWithDetails, Vendor,
 };
+use crate::domain::product_url::canonical_product_url;
 use crate::domain::session_manager::CrawlingResult;
+use crate::infrastructure::coordinate_guard::{self, CoordinateViolation, CoordinateViolationKind};
+use crate::infrastructure::product_identity::{self, IdentityTable};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use futures::TryStreamExt;
 use sqlx::{Row, sqlite::SqlitePool};
 use std::sync::Arc;
 use tracing::{debug, info};
@@ -27,19 +32,13 @@ pub struct IntegratedProductRepository {
 }
 
 impl IntegratedProductRepository {
-    /// Vacate an occupied (page_id, index_in_page) slot if it's taken by a different URL.
-    /// This helps avoid UNIQUE constraint violations when moving an item to a new position.
-    /// Returns the URL that was occupying the slot, if any, after setting its position to NULL.
-    async fn vacate_position_if_occupied(
+    /// Find the URL occupying (page_id, index_in_page), ignoring `keep_norm` itself.
+    async fn find_slot_occupant(
         &self,
         page_id: i32,
         index_in_page: i32,
-        keep_url: &str,
+        keep_norm: &str,
     ) -> Result<Option<String>> {
-        let now = chrono::Utc::now();
-        let keep_norm = Self::normalize_url(keep_url);
-
-        // Find occupant different from the target URL
         // First check product_details (source of truth), then fallback to products
         let mut occupant_url: Option<String> = sqlx::query_scalar(
             r#"SELECT url FROM product_details
@@ -48,7 +47,7 @@ impl IntegratedProductRepository {
         )
         .bind(page_id)
         .bind(index_in_page)
-        .bind(&keep_norm)
+        .bind(keep_norm)
         .fetch_optional(&*self.pool)
         .await?;
         if occupant_url.is_none() {
@@ -59,10 +58,42 @@ impl IntegratedProductRepository {
             )
             .bind(page_id)
             .bind(index_in_page)
-            .bind(&keep_norm)
+            .bind(keep_norm)
             .fetch_optional(&*self.pool)
             .await?;
         }
+        Ok(occupant_url)
+    }
+
+    /// Vacate an occupied (page_id, index_in_page) slot if it's taken by a different URL.
+    /// This helps avoid UNIQUE constraint violations when moving an item to a new position.
+    /// Returns the URL that was occupying the slot, if any, after setting its position to NULL.
+    /// In strict coordinate mode the write is rejected instead of relocating the occupant.
+    async fn vacate_position_if_occupied(
+        &self,
+        operation: &str,
+        page_id: i32,
+        index_in_page: i32,
+        keep_url: &str,
+    ) -> Result<Option<String>> {
+        let now = chrono::Utc::now();
+        let keep_norm = Self::normalize_url(keep_url);
+
+        // Find occupant different from the target URL
+        let occupant_url = self
+            .find_slot_occupant(page_id, index_in_page, &keep_norm)
+            .await?;
+        if let (Some(occ_url), true) = (&occupant_url, coordinate_guard::is_strict_mode()) {
+            return Err(coordinate_guard::reject(CoordinateViolation {
+                operation: operation.to_string(),
+                url: keep_norm,
+                page_id: Some(page_id),
+                index_in_page: Some(index_in_page),
+                kind: CoordinateViolationKind::DuplicateSlot,
+                conflicting_url: Some(occ_url.clone()),
+                detected_at: now,
+            }));
+        }
 
         if let Some(occ_url) = occupant_url.clone() {
             // Set occupant's position to NULL to free the slot
@@ -110,7 +141,12 @@ impl IntegratedProductRepository {
         // Pre-vacate target slot to avoid UNIQUE(page_id, index_in_page) violations
         // if another record currently occupies the desired position.
         let _ = self
-            .vacate_position_if_occupied(page_id, index_in_page, &normalized)
+            .vacate_position_if_occupied(
+                "force_update_position",
+                page_id,
+                index_in_page,
+                &normalized,
+            )
             .await?;
 
      // products 테이블 업데이트 (include id derived from position)
@@ -147,25 +183,9 @@ impl IntegratedProductRepository {
         Ok((prod_rows, det_rows))
     }
     /// Normalize URL for consistent storage and comparison
-    /// - Trims whitespace
-    /// - Lowercases the hostname
-    /// - Leaves path/query as-is (CSA URLs are case-sensitive there)
-    fn normalize_url(url: &str) -> String {
-        let trimmed = url.trim();
-        if let Ok(mut parsed) = url::Url::parse(trimmed) {
-            if let Some(host) = parsed.host_str() {
-                let lower = host.to_ascii_lowercase();
-                // Only replace host if changed
-                if lower != host {
-                    // Rebuild with lowercased host
-                    let _ = parsed.set_host(Some(&lower));
-                }
-            }
-            parsed.to_string()
-        } else {
-            // Fallback to trimmed original
-            trimmed.to_string()
-        }
+    /// (`domain::product_url::canonical_product_url`: host case, tracking params, trailing slash)
+    pub(crate) fn normalize_url(url: &str) -> String {
+        canonical_product_url(url)
     }
     /// Expose underlying pool reference (read-only operations convenience)
     pub fn pool(&self) -> &sqlx::SqlitePool {
@@ -214,6 +234,40 @@ impl IntegratedProductRepository {
     // PRODUCT OPERATIONS
     // ===============================
 
+    /// Strict coordinate mode check for a products write (range + slot ownership)
+    async fn enforce_strict_coordinates(
+        &self,
+        operation: &str,
+        normalized_url: &str,
+        product: &Product,
+    ) -> Result<()> {
+        let violation = |kind, conflicting_url| CoordinateViolation {
+            operation: operation.to_string(),
+            url: normalized_url.to_string(),
+            page_id: product.page_id,
+            index_in_page: product.index_in_page,
+            kind,
+            conflicting_url,
+            detected_at: chrono::Utc::now(),
+        };
+        if let Some(kind) = coordinate_guard::check_coordinates(
+            product.page_id,
+            product.index_in_page,
+            coordinate_guard::products_per_page(),
+        ) {
+            return Err(coordinate_guard::reject(violation(kind, None)));
+        }
+        if let (Some(pid), Some(idx)) = (product.page_id, product.index_in_page) {
+            if let Some(occupant) = self.find_slot_occupant(pid, idx, normalized_url).await? {
+                return Err(coordinate_guard::reject(violation(
+                    CoordinateViolationKind::DuplicateSlot,
+                    Some(occupant),
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// Insert or update basic product information from listing page
     /// 🎯 지능적 비교: 실제로 변경된 필드가 있을 때만 업데이트
     /// Returns: (was_updated: bool, was_created: bool)
@@ -235,6 +289,23 @@ impl IntegratedProductRepository {
                 anyhow::bail!("Invalid index_in_page: {}", idx);
             }
         }
+        // Identity strategy: a stored row of the same product under another URL is moved (or
+        // merged) onto this URL first, so the slot checks below see the final owner
+        if !product_identity::prepare_write(
+            &self.pool,
+            IdentityTable::Products,
+            &normalized_url,
+            product.certificate_id.as_deref(),
+        )
+        .await?
+        {
+            return Ok((false, false));
+        }
+        // Strict mode: reject out-of-range coordinates and occupied slots with full context
+        if coordinate_guard::is_strict_mode() {
+            self.enforce_strict_coordinates("products.upsert", &normalized_url, product)
+                .await?;
+        }
 
         // Optional: quick trace for incoming coords when verbose
         if std::env::var("MC_PERSIST_VERBOSE").ok().as_deref().map(|v| v=="1" || v.eq_ignore_ascii_case("true")).unwrap_or(false) {
@@ -364,6 +435,17 @@ impl IntegratedProductRepository {
         let mut detail = detail.clone();
         detail.url = normalized_url;
 
+        if !product_identity::prepare_write(
+            &self.pool,
+            IdentityTable::ProductDetails,
+            &detail.url,
+            detail.certificate_id.as_deref(),
+        )
+        .await?
+        {
+            return Ok((false, false));
+        }
+
         let existing = self.get_product_detail_by_url(&detail.url).await?;
 
     if let Some(existing_detail) = existing {
@@ -482,7 +564,12 @@ impl IntegratedProductRepository {
                 {
                     if let (Some(pid), Some(idx)) = (detail.page_id, detail.index_in_page) {
                         let _ = self
-                            .vacate_position_if_occupied(pid, idx, &detail.url)
+                            .vacate_position_if_occupied(
+                                "product_details.update",
+                                pid,
+                                idx,
+                                &detail.url,
+                            )
                             .await?;
                     }
                 }
@@ -724,7 +811,7 @@ impl IntegratedProductRepository {
             // If target position is provided, pre-vacate to avoid UNIQUE violation BEFORE inserting into products
             if let (Some(pid), Some(idx)) = (detail.page_id, detail.index_in_page) {
                 let _ = self
-                    .vacate_position_if_occupied(pid, idx, &detail.url)
+                    .vacate_position_if_occupied("product_details.insert", pid, idx, &detail.url)
                     .await?;
             }
 
@@ -865,6 +952,15 @@ impl IntegratedProductRepository {
 
     /// Get all products with pagination
     pub async fn get_products_paginated(&self, page: i32, limit: i32) -> Result<Vec<Product>> {
+        Self::products_paginated_on(&*self.pool, page, limit).await
+    }
+
+    /// `get_products_paginated` on any executor (e.g. a snapshot read connection)
+    pub async fn products_paginated_on<'e, E: sqlx::SqliteExecutor<'e>>(
+        executor: E,
+        page: i32,
+        limit: i32,
+    ) -> Result<Vec<Product>> {
         let offset = (page - 1) * limit;
         let rows = sqlx::query(
             r"
@@ -876,25 +972,24 @@ impl IntegratedProductRepository {
         )
         .bind(limit)
         .bind(offset)
-        .fetch_all(&*self.pool)
+        .fetch_all(executor)
         .await?;
 
-        let products = rows
-            .into_iter()
-            .map(|row| Product {
-                id: None, // products 테이블에는 id 컬럼이 없음
-                url: row.get("url"),
-                manufacturer: row.get("manufacturer"),
-                model: row.get("model"),
-                certificate_id: row.get("certificate_id"),
-                page_id: row.get("page_id"),
-                index_in_page: row.get("index_in_page"),
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            })
-            .collect();
+        Ok(rows.iter().map(Self::product_from_row).collect())
+    }
 
-        Ok(products)
+    fn product_from_row(row: &sqlx::sqlite::SqliteRow) -> Product {
+        Product {
+            id: None, // products 테이블에는 id 컬럼이 없음
+            url: row.get("url"),
+            manufacturer: row.get("manufacturer"),
+            model: row.get("model"),
+            certificate_id: row.get("certificate_id"),
+            page_id: row.get("page_id"),
+            index_in_page: row.get("index_in_page"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
     }
 
     /// Get product by URL
@@ -993,6 +1088,58 @@ impl IntegratedProductRepository {
         }
     }
 
+    /// Map a `products p LEFT JOIN product_details pd` row (column aliases as in
+    /// `search_products`) into a `ProductWithDetails`.
+    fn product_with_details_from_joined_row(row: &sqlx::sqlite::SqliteRow) -> ProductWithDetails {
+        let product = Product {
+            id: row.get("id"),
+            url: row.get("url"),
+            manufacturer: row.get("manufacturer"),
+            model: row.get("model"),
+            certificate_id: row.get("certificate_id"),
+            page_id: row.get("page_id"),
+            index_in_page: row.get("index_in_page"),
+            created_at: row.get("p_created_at"),
+            updated_at: row.get("p_updated_at"),
+        };
+
+        let details = if row.get::<Option<String>, _>("id").is_some() {
+            Some(ProductDetail {
+                url: row.get("url"),
+                page_id: row.get("page_id"),
+                index_in_page: row.get("index_in_page"),
+                id: row.get("id"),
+                manufacturer: row.get("manufacturer"),
+                model: row.get("model"),
+                device_type: row.get("pd_device_type"),
+                certificate_id: row.get("certificate_id"),
+                certification_date: row.get("pd_certification_date"),
+                software_version: row.get("software_version"),
+                hardware_version: row.get("hardware_version"),
+                vid: row.get("vid"),
+                pid: row.get("pid"),
+                family_sku: row.get("family_sku"),
+                family_variant_sku: row.get("family_variant_sku"),
+                firmware_version: row.get("firmware_version"),
+                family_id: row.get("family_id"),
+                tis_trp_tested: row.get("tis_trp_tested"),
+                specification_version: row.get("specification_version"),
+                transport_interface: row.get("transport_interface"),
+                primary_device_type_id: row.get("primary_device_type_id"),
+                application_categories: row.get("application_categories"),
+                description: row.get("description"),
+                compliance_document_url: row.get("compliance_document_url"),
+                program_type: row.get("program_type"),
+                created_at: row.get("pd_created_at"),
+                updated_at: row.get("pd_updated_at"),
+            })
+        } else {
+            None
+        };
+
+        ProductWithDetails { product, details }
+    }
+
     /// Search products with criteria and pagination
     pub async fn search_products(
         &self,
@@ -1082,56 +1229,8 @@ impl IntegratedProductRepository {
         let rows = data_query_builder.fetch_all(&*self.pool).await?;
 
         let products = rows
-            .into_iter()
-            .map(|row| {
-                let product = Product {
-                    id: row.get("id"),
-                    url: row.get("url"),
-                    manufacturer: row.get("manufacturer"),
-                    model: row.get("model"),
-                    certificate_id: row.get("certificate_id"),
-                    page_id: row.get("page_id"),
-                    index_in_page: row.get("index_in_page"),
-                    created_at: row.get("p_created_at"),
-                    updated_at: row.get("p_updated_at"),
-                };
-
-                let details = if row.get::<Option<String>, _>("id").is_some() {
-                    Some(ProductDetail {
-                        url: row.get("url"),
-                        page_id: row.get("page_id"),
-                        index_in_page: row.get("index_in_page"),
-                        id: row.get("id"),
-                        manufacturer: row.get("manufacturer"),
-                        model: row.get("model"),
-                        device_type: row.get("pd_device_type"),
-                        certificate_id: row.get("certificate_id"),
-                        certification_date: row.get("pd_certification_date"),
-                        software_version: row.get("software_version"),
-                        hardware_version: row.get("hardware_version"),
-                        vid: row.get("vid"),
-                        pid: row.get("pid"),
-                        family_sku: row.get("family_sku"),
-                        family_variant_sku: row.get("family_variant_sku"),
-                        firmware_version: row.get("firmware_version"),
-                        family_id: row.get("family_id"),
-                        tis_trp_tested: row.get("tis_trp_tested"),
-                        specification_version: row.get("specification_version"),
-                        transport_interface: row.get("transport_interface"),
-                        primary_device_type_id: row.get("primary_device_type_id"),
-                        application_categories: row.get("application_categories"),
-                        description: row.get("description"),
-                        compliance_document_url: row.get("compliance_document_url"),
-                        program_type: row.get("program_type"),
-                        created_at: row.get("pd_created_at"),
-                        updated_at: row.get("pd_updated_at"),
-                    })
-                } else {
-                    None
-                };
-
-                ProductWithDetails { product, details }
-            })
+            .iter()
+            .map(Self::product_with_details_from_joined_row)
             .collect();
 
         let total_pages = (total_count + limit - 1) / limit;
@@ -1145,6 +1244,151 @@ impl IntegratedProductRepository {
         })
     }
 
+    /// WHERE clause (aliases `p` / `pd`) for `filter`; integer binds come before text binds
+    fn export_filter_clause(filter: &ProductExportFilter) -> (String, Vec<i32>, Vec<String>) {
+        // Integer bounds are bound first, then text filters, matching clause order below
+        let mut int_conditions = Vec::new();
+        let mut int_values = Vec::new();
+        if let Some(min) = filter.page_id_min {
+            int_conditions.push("p.page_id >= ?");
+            int_values.push(min);
+        }
+        if let Some(max) = filter.page_id_max {
+            int_conditions.push("p.page_id <= ?");
+            int_values.push(max);
+        }
+
+        let mut text_conditions = Vec::new();
+        let mut text_values = Vec::new();
+        if let Some(manufacturer) = &filter.manufacturer {
+            text_conditions.push("p.manufacturer LIKE ?");
+            text_values.push(format!("%{}%", manufacturer));
+        }
+        if let Some(device_type) = &filter.device_type {
+            text_conditions.push("pd.device_type LIKE ?");
+            text_values.push(format!("%{}%", device_type));
+        }
+        if let Some(from) = &filter.certification_date_from {
+            text_conditions.push("pd.certification_date >= ?");
+            text_values.push(from.clone());
+        }
+        if let Some(to) = &filter.certification_date_to {
+            text_conditions.push("pd.certification_date <= ?");
+            text_values.push(to.clone());
+        }
+
+        let conditions: Vec<&str> = int_conditions.into_iter().chain(text_conditions).collect();
+        let where_clause = if conditions.is_empty() {
+            "".to_string()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+        (where_clause, int_values, text_values)
+    }
+
+    /// Stream products (LEFT JOIN product_details) matching `filter` row by row,
+    /// handing each to `on_row` without buffering the result set.
+    /// Returns the number of rows delivered.
+    pub async fn stream_products_for_export<F>(
+        &self,
+        filter: &ProductExportFilter,
+        mut on_row: F,
+    ) -> Result<u64>
+    where
+        F: FnMut(ProductWithDetails) -> Result<()>,
+    {
+        let (where_clause, int_values, text_values) = Self::export_filter_clause(filter);
+
+        let data_query = format!(
+            r"
+            SELECT p.url, p.manufacturer, p.model, p.certificate_id, p.page_id, p.index_in_page,
+                   p.created_at as p_created_at, p.updated_at as p_updated_at,
+                   pd.id, pd.device_type as pd_device_type, pd.certification_date as pd_certification_date, pd.software_version, pd.hardware_version,
+                   pd.vid, pd.pid, pd.family_sku, pd.family_variant_sku, pd.firmware_version, pd.family_id,
+                   pd.tis_trp_tested, pd.specification_version, pd.transport_interface,
+                   pd.primary_device_type_id, pd.application_categories, pd.description,
+                   pd.compliance_document_url, pd.program_type,
+                   pd.created_at as pd_created_at, pd.updated_at as pd_updated_at
+            FROM products p
+            LEFT JOIN product_details pd ON p.url = pd.url
+            {}
+            ORDER BY p.page_id DESC, p.index_in_page ASC
+            ",
+            where_clause
+        );
+
+        let mut query = sqlx::query(&data_query);
+        for value in &int_values {
+            query = query.bind(value);
+        }
+        for value in &text_values {
+            query = query.bind(value);
+        }
+
+        let mut rows = query.fetch(&*self.pool);
+        let mut delivered: u64 = 0;
+        while let Some(row) = rows.try_next().await? {
+            on_row(Self::product_with_details_from_joined_row(&row))?;
+            delivered += 1;
+        }
+
+        debug!("stream_products_for_export delivered {} rows", delivered);
+        Ok(delivered)
+    }
+
+    /// `products.rowid` keys matching `filter`, in export order (snapshot for chunked reads)
+    pub async fn export_row_keys(&self, filter: &ProductExportFilter) -> Result<Vec<i64>> {
+        let (where_clause, int_values, text_values) = Self::export_filter_clause(filter);
+        let key_query = format!(
+            "SELECT p.rowid FROM products p LEFT JOIN product_details pd ON p.url = pd.url {} \
+             ORDER BY p.page_id DESC, p.index_in_page ASC",
+            where_clause
+        );
+        let mut query = sqlx::query_scalar::<_, i64>(&key_query);
+        for value in &int_values {
+            query = query.bind(value);
+        }
+        for value in &text_values {
+            query = query.bind(value);
+        }
+        Ok(query.fetch_all(&*self.pool).await?)
+    }
+
+    /// Joined export rows for `keys` (`products.rowid`), returned in the order given.
+    /// Keys whose product was deleted since the snapshot are skipped.
+    pub async fn fetch_export_rows_by_keys(
+        &self,
+        keys: &[i64],
+    ) -> Result<Vec<ProductWithDetails>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+        let keys_json = serde_json::to_string(keys)?;
+        let rows = sqlx::query(
+            r"
+            SELECT p.url, p.manufacturer, p.model, p.certificate_id, p.page_id, p.index_in_page,
+                   p.created_at as p_created_at, p.updated_at as p_updated_at,
+                   pd.id, pd.device_type as pd_device_type, pd.certification_date as pd_certification_date, pd.software_version, pd.hardware_version,
+                   pd.vid, pd.pid, pd.family_sku, pd.family_variant_sku, pd.firmware_version, pd.family_id,
+                   pd.tis_trp_tested, pd.specification_version, pd.transport_interface,
+                   pd.primary_device_type_id, pd.application_categories, pd.description,
+                   pd.compliance_document_url, pd.program_type,
+                   pd.created_at as pd_created_at, pd.updated_at as pd_updated_at
+            FROM json_each(?) k
+            JOIN products p ON p.rowid = k.value
+            LEFT JOIN product_details pd ON p.url = pd.url
+            ORDER BY k.key
+            ",
+        )
+        .bind(keys_json)
+        .fetch_all(&*self.pool)
+        .await?;
+        Ok(rows
+            .iter()
+            .map(Self::product_with_details_from_joined_row)
+            .collect())
+    }
+
     /// JSON 형식의 제품 데이터를 DB에 추가 또는 업데이트
     ///
     /// 새로운 제품인 경우 true, 기존 제품 업데이트인 경우 false 반환
@@ -1894,15 +2138,25 @@ impl IntegratedProductRepository {
 
     /// 제품 총 개수 조회 (Backend-Only CRUD 패턴)
     pub async fn count_products(&self) -> Result<i64> {
+        Self::count_products_on(&*self.pool).await
+    }
+
+    pub async fn count_products_on<'e, E: sqlx::SqliteExecutor<'e>>(executor: E) -> Result<i64> {
         let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM products")
-            .fetch_one(&*self.pool)
+            .fetch_one(executor)
             .await?;
         Ok(count)
     }
 
     /// 최근 업데이트된 제품들 조회 (Backend-Only CRUD 패턴)
     pub async fn get_latest_updated_products(&self, limit: u32) -> Result<Vec<Product>> {
-        let _offset = 0;
+        Self::latest_updated_products_on(&*self.pool, limit).await
+    }
+
+    pub async fn latest_updated_products_on<'e, E: sqlx::SqliteExecutor<'e>>(
+        executor: E,
+        limit: u32,
+    ) -> Result<Vec<Product>> {
         let rows = sqlx::query(
             r"
             SELECT url, manufacturer, model, certificate_id, page_id, index_in_page, 
@@ -1913,24 +2167,9 @@ impl IntegratedProductRepository {
             ",
         )
         .bind(limit as i32)
-        .fetch_all(&*self.pool)
+        .fetch_all(executor)
         .await?;
 
-        let products = rows
-            .into_iter()
-            .map(|row| Product {
-                id: None, // products 테이블에는 id 컬럼이 없음
-                url: row.get("url"),
-                manufacturer: row.get("manufacturer"),
-                model: row.get("model"),
-                certificate_id: row.get("certificate_id"),
-                page_id: row.get("page_id"),
-                index_in_page: row.get("index_in_page"),
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            })
-            .collect();
-
-        Ok(products)
+        Ok(rows.iter().map(Self::product_from_row).collect())
     }
 }
@@ -0,0 +1,503 @@
+//! 동반 도구용 로컬 IPC (JSON-RPC 2.0, `ipc-server` feature)
+//!
+//! Unix 에서는 소켓 파일(권한 0600), Windows 에서는 named pipe 로 줄 단위 JSON-RPC 요청을 받는다.
+//! 요청 하나 = 한 줄, 응답 하나 = 한 줄이며 배치 요청은 지원하지 않는다. 노출 범위는 상태 조회,
+//! partial sync 시작, 제품 조회로 한정한다(설정 변경·삭제 계열은 프런트엔드 명령으로만).
+//!
+//! | Method            | Params                                       | Result                   |
+//! |-------------------|----------------------------------------------|--------------------------|
+//! | `status`          | –                                            | counts + active sessions |
+//! | `sync.start`      | `{ranges, dry_run?}`                         | `SyncSummary`            |
+//! | `products.search` | `{query, page?, size?, prefix?}`             | `ProductSearchPage`      |
+//! | `products.get`    | `{url}`                                      | `ProductWithDetails?`    |
+
+use crate::application::AppState;
+use crate::commands::data_queries::ProductSearchPage;
+use crate::commands::sync_commands::run_partial_sync;
+use crate::crawl_engine::runtime::session_registry::{SessionStatus, session_registry};
+use crate::crawl_engine::runtime::task_registry::spawn_tracked;
+use crate::infrastructure::config::IpcServerConfig;
+use crate::infrastructure::integrated_product_repository::IntegratedProductRepository;
+use crate::services::search_index;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use tauri::{AppHandle, Manager};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tracing::{debug, info, warn};
+
+/// Largest request line accepted (bytes, excluding the newline)
+const MAX_REQUEST_LINE: usize = 64 * 1024;
+
+const MAX_SEARCH_PAGE_SIZE: u32 = 100;
+
+// JSON-RPC 2.0 error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+/// Implementation-defined: the method cannot run right now
+const UNAVAILABLE: i64 = -32000;
+
+/// One IPC-started sync at a time
+static SYNC_RUNNING: AtomicBool = AtomicBool::new(false);
+
+static CONNECTION_SEQ: AtomicU64 = AtomicU64::new(1);
+
+/// What request handlers can reach
+pub struct IpcContext {
+    pub pool: SqlitePool,
+    /// Needed for `sync.start` (events go to the window like a UI-started sync)
+    pub app: Option<AppHandle>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct IpcStatus {
+    pub version: &'static str,
+    pub products: i64,
+    pub product_details: i64,
+    pub active_sessions: Vec<IpcSession>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IpcSession {
+    pub session_id: String,
+    pub status: String,
+    pub started_at: String,
+    pub processed_pages: u64,
+    pub total_pages_planned: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct SyncStartParams {
+    ranges: String,
+    #[serde(default)]
+    dry_run: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchParams {
+    query: String,
+    page: Option<u32>,
+    size: Option<u32>,
+    prefix: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GetParams {
+    url: String,
+}
+
+/// Default endpoint: a socket in the app data directory, or a fixed pipe name on Windows
+pub fn default_endpoint() -> String {
+    #[cfg(windows)]
+    {
+        r"\\.\pipe\rmattercertis".to_string()
+    }
+    #[cfg(not(windows))]
+    {
+        crate::infrastructure::config::ConfigManager::get_app_data_dir()
+            .map(|dir| dir.join("rmattercertis.sock"))
+            .unwrap_or_else(|_| std::env::temp_dir().join("rmattercertis.sock"))
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+/// Start the IPC server when enabled; startup problems are logged, never fatal
+pub fn start_ipc_server(app: AppHandle, config: &IpcServerConfig) {
+    if !config.enabled {
+        return;
+    }
+    let endpoint = config
+        .endpoint
+        .clone()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(default_endpoint);
+    spawn_tracked("ipc-server", None, async move {
+        let pool = match crate::infrastructure::database_connection::get_or_init_global_pool().await
+        {
+            Ok(pool) => pool,
+            Err(e) => {
+                warn!("🔌 IPC server disabled: DB pool unavailable: {e:#}");
+                return;
+            }
+        };
+        let ctx = Arc::new(IpcContext {
+            pool,
+            app: Some(app),
+        });
+        if let Err(e) = serve(&endpoint, ctx).await {
+            warn!("🔌 IPC server on {} stopped: {}", endpoint, e);
+        }
+    });
+}
+
+/// Accept loop on a Unix socket; one tracked task per connection
+#[cfg(unix)]
+pub async fn serve(path: &str, ctx: Arc<IpcContext>) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    use tokio::net::{UnixListener, UnixStream};
+
+    let path = std::path::Path::new(path);
+    if path.exists() {
+        // A live socket means another instance owns the endpoint; a dead one is a leftover
+        if UnixStream::connect(path).await.is_ok() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AddrInUse,
+                "another instance is serving this socket",
+            ));
+        }
+        std::fs::remove_file(path)?;
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let listener = UnixListener::bind(path)?;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    info!("🔌 IPC server listening on {}", path.display());
+    loop {
+        let (stream, _) = listener.accept().await?;
+        spawn_connection(stream, ctx.clone());
+    }
+}
+
+/// Accept loop on a named pipe; a fresh pipe instance is created before handing one off
+#[cfg(windows)]
+pub async fn serve(name: &str, ctx: Arc<IpcContext>) -> std::io::Result<()> {
+    use tokio::net::windows::named_pipe::ServerOptions;
+
+    let mut server = ServerOptions::new()
+        .first_pipe_instance(true)
+        .reject_remote_clients(true)
+        .create(name)?;
+    info!("🔌 IPC server listening on {}", name);
+    loop {
+        server.connect().await?;
+        let connected = server;
+        server = ServerOptions::new()
+            .reject_remote_clients(true)
+            .create(name)?;
+        spawn_connection(connected, ctx.clone());
+    }
+}
+
+fn spawn_connection<S>(stream: S, ctx: Arc<IpcContext>)
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let id = CONNECTION_SEQ.fetch_add(1, Ordering::Relaxed);
+    spawn_tracked(format!("ipc-client:{id}"), None, async move {
+        if let Err(e) = handle_connection(stream, ctx).await {
+            debug!("🔌 IPC client {} closed: {}", id, e);
+        }
+    });
+}
+
+/// Read newline-delimited requests until EOF, answering each in order
+pub async fn handle_connection<S>(stream: S, ctx: Arc<IpcContext>) -> std::io::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut reader = BufReader::new(reader);
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        let n = (&mut reader)
+            .take(MAX_REQUEST_LINE as u64 + 1)
+            .read_until(b'\n', &mut line)
+            .await?;
+        if n == 0 {
+            return Ok(());
+        }
+        if !line.ends_with(b"\n") && line.len() > MAX_REQUEST_LINE {
+            let response = error_response(
+                Value::Null,
+                RpcError::new(INVALID_REQUEST, "request line too long"),
+            );
+            writer.write_all(format!("{response}\n").as_bytes()).await?;
+            return Ok(());
+        }
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        if let Some(response) = handle_line(&ctx, &line).await {
+            writer.write_all(format!("{response}\n").as_bytes()).await?;
+            writer.flush().await?;
+        }
+    }
+}
+
+/// One request line → response line (`None` for notifications)
+pub async fn handle_line(ctx: &IpcContext, line: &[u8]) -> Option<Value> {
+    let request: Value = match serde_json::from_slice(line) {
+        Ok(value) => value,
+        Err(e) => {
+            return Some(error_response(
+                Value::Null,
+                RpcError::new(PARSE_ERROR, format!("Parse error: {e}")),
+            ));
+        }
+    };
+    let id = request.get("id").cloned();
+    let method = request.get("method").and_then(Value::as_str);
+    let (Some(method), Some("2.0")) = (method, request.get("jsonrpc").and_then(Value::as_str))
+    else {
+        return Some(error_response(
+            id.unwrap_or(Value::Null),
+            RpcError::new(INVALID_REQUEST, "expected a JSON-RPC 2.0 request object"),
+        ));
+    };
+    let params = request.get("params").cloned().unwrap_or(Value::Null);
+    let result = dispatch(ctx, method, params).await;
+    let id = id?;
+    Some(match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(e) => error_response(id, e),
+    })
+}
+
+fn error_response(id: Value, error: RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": error.code, "message": error.message },
+    })
+}
+
+async fn dispatch(ctx: &IpcContext, method: &str, params: Value) -> Result<Value, RpcError> {
+    debug!("🔌 IPC request {}", method);
+    match method {
+        "status" => to_value(status(&ctx.pool).await?),
+        "sync.start" => to_value(sync_start(ctx, parse_params(params)?).await?),
+        "products.search" => to_value(search(&ctx.pool, parse_params(params)?).await?),
+        "products.get" => {
+            let params: GetParams = parse_params(params)?;
+            let product = IntegratedProductRepository::new(ctx.pool.clone())
+                .get_product_with_details(&params.url)
+                .await
+                .map_err(internal)?;
+            to_value(product)
+        }
+        other => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("Method not found: {other}"),
+        )),
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    let params = if params.is_null() { json!({}) } else { params };
+    serde_json::from_value(params)
+        .map_err(|e| RpcError::new(INVALID_PARAMS, format!("Invalid params: {e}")))
+}
+
+fn to_value<T: Serialize>(value: T) -> Result<Value, RpcError> {
+    serde_json::to_value(value).map_err(internal)
+}
+
+fn internal(e: impl std::fmt::Display) -> RpcError {
+    RpcError::new(INTERNAL_ERROR, e.to_string())
+}
+
+async fn status(pool: &SqlitePool) -> Result<IpcStatus, RpcError> {
+    let products: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM products")
+        .fetch_one(pool)
+        .await
+        .map_err(internal)?;
+    let product_details: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM product_details")
+        .fetch_one(pool)
+        .await
+        .map_err(internal)?;
+    let registry = session_registry();
+    let sessions = registry.read().await;
+    let mut active_sessions: Vec<IpcSession> = sessions
+        .iter()
+        .filter(|(_, e)| matches!(e.status, SessionStatus::Running | SessionStatus::Paused))
+        .map(|(id, e)| IpcSession {
+            session_id: id.clone(),
+            status: format!("{:?}", e.status),
+            started_at: e.started_at.to_rfc3339(),
+            processed_pages: e.processed_pages,
+            total_pages_planned: e.total_pages_planned,
+        })
+        .collect();
+    active_sessions.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+    Ok(IpcStatus {
+        version: env!("CARGO_PKG_VERSION"),
+        products,
+        product_details,
+        active_sessions,
+    })
+}
+
+async fn sync_start(
+    ctx: &IpcContext,
+    params: SyncStartParams,
+) -> Result<crate::commands::sync_commands::SyncSummary, RpcError> {
+    let Some(app) = ctx.app.clone() else {
+        return Err(RpcError::new(UNAVAILABLE, "sync is not available here"));
+    };
+    if params.ranges.trim().is_empty() {
+        return Err(RpcError::new(INVALID_PARAMS, "ranges must not be empty"));
+    }
+    if SYNC_RUNNING.swap(true, Ordering::AcqRel) {
+        return Err(RpcError::new(
+            UNAVAILABLE,
+            "a sync started over IPC is still running",
+        ));
+    }
+    info!(
+        "🔌 IPC sync.start ranges=\"{}\" dry_run={}",
+        params.ranges, params.dry_run
+    );
+    let state = app.state::<AppState>();
+    let result = run_partial_sync(
+        Arc::new(app.clone()),
+        state.inner(),
+        params.ranges,
+        Some(params.dry_run),
+        None,
+    )
+    .await;
+    SYNC_RUNNING.store(false, Ordering::Release);
+    result.map_err(|e| RpcError::new(UNAVAILABLE, e))
+}
+
+async fn search(pool: &SqlitePool, params: SearchParams) -> Result<ProductSearchPage, RpcError> {
+    let page = params.page.unwrap_or(0);
+    let size = params.size.unwrap_or(20).clamp(1, MAX_SEARCH_PAGE_SIZE);
+    let Some(match_query) =
+        search_index::build_match_query(&params.query, params.prefix.unwrap_or(true))
+    else {
+        return Err(RpcError::new(INVALID_PARAMS, "query has no search terms"));
+    };
+    let (hits, total_count) = search_index::search(pool, &match_query, size, page * size)
+        .await
+        .map_err(internal)?;
+    Ok(ProductSearchPage {
+        has_next: (page + 1) * size < total_count,
+        hits,
+        total_count,
+        page,
+        size,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn context() -> Arc<IpcContext> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        for sql in [
+            "CREATE TABLE products (url TEXT PRIMARY KEY)",
+            "CREATE TABLE product_details (url TEXT PRIMARY KEY)",
+            "INSERT INTO products (url) VALUES ('https://x/p/1'), ('https://x/p/2')",
+            "INSERT INTO product_details (url) VALUES ('https://x/p/1')",
+        ] {
+            sqlx::query(sql).execute(&pool).await.unwrap();
+        }
+        Arc::new(IpcContext { pool, app: None })
+    }
+
+    #[tokio::test]
+    async fn dispatch_reports_json_rpc_errors() {
+        let ctx = context().await;
+        let parse = handle_line(&ctx, b"{not json").await.unwrap();
+        assert_eq!(parse["error"]["code"], PARSE_ERROR);
+        assert_eq!(parse["id"], Value::Null);
+
+        let invalid = handle_line(&ctx, br#"{"id":1,"method":"status"}"#)
+            .await
+            .unwrap();
+        assert_eq!(invalid["error"]["code"], INVALID_REQUEST);
+
+        let unknown = handle_line(
+            &ctx,
+            br#"{"jsonrpc":"2.0","id":2,"method":"settings.reset"}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(unknown["error"]["code"], METHOD_NOT_FOUND);
+
+        let params = handle_line(
+            &ctx,
+            br#"{"jsonrpc":"2.0","id":3,"method":"products.search","params":{"query":"  "}}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(params["error"]["code"], INVALID_PARAMS);
+
+        let sync = handle_line(
+            &ctx,
+            br#"{"jsonrpc":"2.0","id":4,"method":"sync.start","params":{"ranges":"1-2"}}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(sync["error"]["code"], UNAVAILABLE);
+
+        let notification = handle_line(&ctx, br#"{"jsonrpc":"2.0","method":"status"}"#).await;
+        assert!(notification.is_none());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_socket_round_trip() {
+        use tokio::net::UnixStream;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ipc.sock");
+        // Leftover file from a crashed instance is replaced
+        std::fs::write(&path, b"").unwrap();
+        let endpoint = path.to_string_lossy().into_owned();
+        let ctx = context().await;
+        tokio::spawn(async move { serve(&endpoint, ctx).await });
+
+        let mut stream = loop {
+            if let Ok(stream) = UnixStream::connect(&path).await {
+                break stream;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        };
+        stream
+            .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":\"a\",\"method\":\"status\"}\n\n")
+            .await
+            .unwrap();
+        let mut lines = BufReader::new(stream).lines();
+        let response: Value =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert_eq!(response["id"], "a");
+        assert_eq!(response["result"]["products"], 2);
+        assert_eq!(response["result"]["product_details"], 1);
+
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
@@ -35,6 +35,7 @@ use tracing_subscriber::{
 
 // Re-export LoggingConfig from config module
 pub use crate::infrastructure::config::LoggingConfig;
+use crate::infrastructure::session_log::{SessionLogLayer, session_log_directory};
 
 // Global guard to keep the log file writer alive
 lazy_static! {
@@ -301,8 +302,26 @@ pub fn init_logging_with_config(config: LoggingConfig) -> Result<()> {
         }
     }
 
+    // Per-session capture (logs/sessions/<session_id>.jsonl); a failure only disables this sink
+    let session_layer = if config.session_logs_enabled {
+        let max_file_bytes = config.max_file_size_mb.max(1) * 1024 * 1024;
+        match SessionLogLayer::spawn(
+            session_log_directory(),
+            max_file_bytes,
+            config.session_logs_max_sessions.max(1) as usize,
+        ) {
+            Ok(layer) => Some(layer),
+            Err(e) => {
+                eprintln!("Session log capture disabled: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
     // Build the subscriber registry
-    let registry = Registry::default().with(env_filter);
+    let registry = Registry::default().with(env_filter).with(session_layer);
 
     // Determine log file name based on configuration
     let log_file_name = match config.file_naming_strategy.as_str() {
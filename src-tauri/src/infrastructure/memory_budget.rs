@@ -0,0 +1,143 @@
+//! Memory budget for uncommitted product rows + memory stats
+//!
+//! 상세 수집 결과가 저장될 때까지 쌓이는 양을 추정 바이트로 추적한다. persistence queue 의
+//! 미커밋 행이 `budget_mb` 를 넘으면 배치 크기를 기다리지 않고 flush 하고, 상세 → 저장
+//! 핸드오프 채널에 대기 중인 청크 크기와 채널이 가득 차 수집이 기다린 횟수를 함께 집계한다.
+
+use crate::domain::product::ProductDetail;
+use crate::infrastructure::config::MemoryBudgetConfig;
+use crate::infrastructure::config::defaults;
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const MB: u64 = 1024 * 1024;
+/// Fixed part of a `ProductDetail` beyond its string contents
+const DETAIL_OVERHEAD_BYTES: u64 = std::mem::size_of::<ProductDetail>() as u64;
+
+static BUDGET_BYTES: AtomicU64 = AtomicU64::new(defaults::MEMORY_BUDGET_MB * MB);
+static HANDOFF_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_HANDOFF_BYTES: AtomicU64 = AtomicU64::new(0);
+static QUEUE_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_QUEUE_BYTES: AtomicU64 = AtomicU64::new(0);
+static EARLY_FLUSHES: AtomicU64 = AtomicU64::new(0);
+static BACKPRESSURE_WAITS: AtomicU64 = AtomicU64::new(0);
+
+/// Apply `advanced.memory_budget`; takes effect on the next enqueue
+pub fn configure(config: &MemoryBudgetConfig) {
+    BUDGET_BYTES.store(config.budget_mb.max(1) * MB, Ordering::Relaxed);
+}
+
+pub fn budget_bytes() -> u64 {
+    BUDGET_BYTES.load(Ordering::Relaxed)
+}
+
+/// Rough heap + inline size of the rows
+pub fn estimated_bytes(details: &[ProductDetail]) -> u64 {
+    details
+        .iter()
+        .map(|d| {
+            let strings = [
+                &d.id,
+                &d.manufacturer,
+                &d.model,
+                &d.device_type,
+                &d.certificate_id,
+                &d.certification_date,
+                &d.software_version,
+                &d.hardware_version,
+                &d.family_sku,
+                &d.family_variant_sku,
+                &d.firmware_version,
+                &d.family_id,
+                &d.tis_trp_tested,
+                &d.specification_version,
+                &d.transport_interface,
+                &d.primary_device_type_id,
+                &d.application_categories,
+                &d.description,
+                &d.compliance_document_url,
+                &d.program_type,
+            ];
+            let heap: usize = d.url.len()
+                + strings
+                    .iter()
+                    .map(|s| s.as_ref().map_or(0, String::len))
+                    .sum::<usize>();
+            DETAIL_OVERHEAD_BYTES + heap as u64
+        })
+        .sum()
+}
+
+fn raise_peak(peak: &AtomicU64, now: u64) {
+    peak.fetch_max(now, Ordering::Relaxed);
+}
+
+/// Chunk entered the detail → saving handoff
+pub(crate) fn hold_handoff(bytes: u64) {
+    let now = HANDOFF_BYTES.fetch_add(bytes, Ordering::Relaxed) + bytes;
+    raise_peak(&PEAK_HANDOFF_BYTES, now);
+}
+
+/// Chunk left the handoff (taken by the saver, or dropped with it)
+pub(crate) fn release_handoff(bytes: u64) {
+    let _ = HANDOFF_BYTES.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |cur| {
+        Some(cur.saturating_sub(bytes))
+    });
+}
+
+pub(crate) fn note_backpressure() {
+    BACKPRESSURE_WAITS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Persistence queue's uncommitted rows changed size
+pub(crate) fn track_queue(pending_bytes: u64) {
+    QUEUE_BYTES.store(pending_bytes, Ordering::Relaxed);
+    raise_peak(&PEAK_QUEUE_BYTES, pending_bytes);
+}
+
+pub(crate) fn note_early_flush() {
+    EARLY_FLUSHES.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Resident set size of this process (MB); Linux only (/proc/self/status)
+pub fn process_rss_mb() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    status
+        .lines()
+        .find(|l| l.starts_with("VmRSS:"))
+        .and_then(|l| l.split_whitespace().nth(1))
+        .and_then(|kb| kb.parse::<u64>().ok())
+        .map(|kb| kb / 1024)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryStats {
+    pub process_rss_mb: Option<u64>,
+    /// Share of system memory in use (0.0-1.0)
+    pub system_memory_pressure: Option<f64>,
+    pub budget_bytes: u64,
+    /// Detail chunks handed off but not yet taken by the saver
+    pub handoff_bytes: u64,
+    pub peak_handoff_bytes: u64,
+    /// Rows in the persistence queue not yet committed
+    pub queue_pending_bytes: u64,
+    pub peak_queue_pending_bytes: u64,
+    /// Flushes forced by the budget before the queue reached its batch size
+    pub early_flushes: u64,
+    /// Times detail collection waited on a full handoff channel
+    pub backpressure_waits: u64,
+}
+
+pub fn stats() -> MemoryStats {
+    MemoryStats {
+        process_rss_mb: process_rss_mb(),
+        system_memory_pressure: crate::infrastructure::host_profile::memory_pressure(),
+        budget_bytes: budget_bytes(),
+        handoff_bytes: HANDOFF_BYTES.load(Ordering::Relaxed),
+        peak_handoff_bytes: PEAK_HANDOFF_BYTES.load(Ordering::Relaxed),
+        queue_pending_bytes: QUEUE_BYTES.load(Ordering::Relaxed),
+        peak_queue_pending_bytes: PEAK_QUEUE_BYTES.load(Ordering::Relaxed),
+        early_flushes: EARLY_FLUSHES.load(Ordering::Relaxed),
+        backpressure_waits: BACKPRESSURE_WAITS.load(Ordering::Relaxed),
+    }
+}
@@ -0,0 +1,484 @@
+//! Prometheus 메트릭 엔드포인트 (헤드리스 운영, `metrics-exporter` feature)
+//!
+//! 카운터/게이지는 액터 이벤트 경로(`ActorEventBridge`, `emit_actor_event`)와 성능 최적화기
+//! (`CrawlingPerformanceOptimizer::record_metrics`)가 갱신하고, 지연 분위수는
+//! `runtime::latency` 전역 기록기(HTTP 요청, 단계, 싱크 페이지, DB 쓰기)를 그대로 읽는다.
+//! 로컬 HTTP 포트의 `/metrics` 가 text exposition format(0.0.4)으로 내보낸다.
+//!
+//! | Path       | Response                                   |
+//! |------------|--------------------------------------------|
+//! | `/metrics` | `text/plain; version=0.0.4`                |
+//! | `/health`  | `ok`                                       |
+
+use crate::crawl_engine::actors::types::AppEvent;
+use crate::crawl_engine::runtime::latency::{LatencyRecorder, LatencySummary};
+use crate::crawl_engine::runtime::task_registry::{spawn_tracked, task_registry};
+use crate::crawl_engine::services::performance_optimizer::CrawlingPerformanceMetrics;
+use crate::infrastructure::config::MetricsExporterConfig;
+use once_cell::sync::Lazy;
+use std::fmt::Write as _;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, info, warn};
+
+const PREFIX: &str = "rmattercertis";
+
+/// Largest request head accepted (request line + headers)
+const MAX_REQUEST_HEAD: usize = 8 * 1024;
+
+/// Non-negative gauge moved by start/finish events
+#[derive(Default)]
+struct Gauge(AtomicI64);
+
+impl Gauge {
+    fn inc(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Never below zero (finish events can arrive for work started before the exporter saw it)
+    fn dec(&self) {
+        let _ = self
+            .0
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| {
+                Some((v - 1).max(0))
+            });
+    }
+
+    fn get(&self) -> i64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// `f64` gauge stored as bits
+#[derive(Default)]
+struct FloatGauge(AtomicU64);
+
+impl FloatGauge {
+    fn set(&self, value: f64) {
+        self.0.store(value.to_bits(), Ordering::Relaxed);
+    }
+
+    fn get(&self) -> f64 {
+        f64::from_bits(self.0.load(Ordering::Relaxed))
+    }
+}
+
+#[derive(Default)]
+struct Metrics {
+    crawl_pages_completed: AtomicU64,
+    crawl_pages_failed: AtomicU64,
+    sync_pages_completed: AtomicU64,
+    sync_products_inserted: AtomicU64,
+    sync_products_updated: AtomicU64,
+    stage_retries: AtomicU64,
+    sync_retries: AtomicU64,
+    sessions_started: AtomicU64,
+    sessions_completed: AtomicU64,
+    sessions_failed: AtomicU64,
+    active_sessions: Gauge,
+    active_batches: Gauge,
+    active_stages: Gauge,
+    active_syncs: Gauge,
+    concurrency: FloatGauge,
+    recommended_concurrency: FloatGauge,
+    success_rate: FloatGauge,
+    throughput_rps: FloatGauge,
+    network_error_rate: FloatGauge,
+}
+
+static METRICS: Lazy<Metrics> = Lazy::new(Metrics::default);
+
+fn bump(counter: &AtomicU64, by: u64) {
+    counter.fetch_add(by, Ordering::Relaxed);
+}
+
+/// Update counters / gauges from an actor event (called on the frontend emit paths)
+pub fn observe_event(event: &AppEvent) {
+    let m = &*METRICS;
+    match event {
+        AppEvent::SessionStarted { .. } => {
+            bump(&m.sessions_started, 1);
+            m.active_sessions.inc();
+        }
+        AppEvent::SessionCompleted { .. } => {
+            bump(&m.sessions_completed, 1);
+            m.active_sessions.dec();
+        }
+        AppEvent::SessionFailed { final_failure, .. } if *final_failure => {
+            bump(&m.sessions_failed, 1);
+            m.active_sessions.dec();
+        }
+        AppEvent::SessionTimeout { .. } => {
+            bump(&m.sessions_failed, 1);
+            m.active_sessions.dec();
+        }
+        AppEvent::BatchStarted { .. } => m.active_batches.inc(),
+        AppEvent::BatchCompleted { .. } => m.active_batches.dec(),
+        AppEvent::BatchFailed { final_failure, .. } if *final_failure => m.active_batches.dec(),
+        AppEvent::StageStarted { .. } => m.active_stages.inc(),
+        AppEvent::StageCompleted { .. } | AppEvent::StageFailed { .. } => m.active_stages.dec(),
+        AppEvent::StageRetrying { .. } => bump(&m.stage_retries, 1),
+        AppEvent::PageTaskCompleted { .. } => bump(&m.crawl_pages_completed, 1),
+        AppEvent::PageTaskFailed { final_failure, .. } if *final_failure => {
+            bump(&m.crawl_pages_failed, 1)
+        }
+        AppEvent::SyncStarted { .. } => m.active_syncs.inc(),
+        AppEvent::SyncPageCompleted {
+            inserted, updated, ..
+        } => {
+            bump(&m.sync_pages_completed, 1);
+            bump(&m.sync_products_inserted, u64::from(*inserted));
+            bump(&m.sync_products_updated, u64::from(*updated));
+        }
+        AppEvent::SyncRetrying { .. } => bump(&m.sync_retries, 1),
+        AppEvent::SyncCompleted { .. }
+        | AppEvent::SyncAborted { .. }
+        | AppEvent::SyncPaused { .. } => m.active_syncs.dec(),
+        _ => {}
+    }
+}
+
+/// Mirror the optimizer's latest rolling metrics into gauges
+pub fn observe_performance(metrics: &CrawlingPerformanceMetrics) {
+    let m = &*METRICS;
+    m.concurrency.set(f64::from(metrics.current_concurrency));
+    m.recommended_concurrency
+        .set(f64::from(metrics.recommended_concurrency));
+    m.success_rate.set(metrics.success_rate);
+    m.throughput_rps.set(metrics.throughput_rps);
+    m.network_error_rate.set(metrics.network_error_rate);
+}
+
+/// Exported quantiles in milliseconds
+fn quantiles(s: &LatencySummary) -> [(&'static str, f64); 3] {
+    [("0.5", s.p50_ms), ("0.9", s.p90_ms), ("0.99", s.p99_ms)]
+}
+
+/// `# HELP` / `# TYPE` header followed by one sample per label set
+fn write_family<V: std::fmt::Display>(
+    out: &mut String,
+    name: &str,
+    kind: &str,
+    help: &str,
+    samples: &[(&str, V)],
+) {
+    let _ = writeln!(out, "# HELP {PREFIX}_{name} {help}");
+    let _ = writeln!(out, "# TYPE {PREFIX}_{name} {kind}");
+    for (labels, value) in samples {
+        write_sample(out, name, labels, value);
+    }
+}
+
+fn write_sample(out: &mut String, name: &str, labels: &str, value: impl std::fmt::Display) {
+    if labels.is_empty() {
+        let _ = writeln!(out, "{PREFIX}_{name} {value}");
+    } else {
+        let _ = writeln!(out, "{PREFIX}_{name}{{{labels}}} {value}");
+    }
+}
+
+/// Current metrics in Prometheus text exposition format
+pub fn render() -> String {
+    let m = &*METRICS;
+    let n = |c: &AtomicU64| c.load(Ordering::Relaxed);
+    let mut out = String::with_capacity(4096);
+
+    #[rustfmt::skip]
+    let counters: [(&str, &str, Vec<(&str, u64)>); 5] = [
+        ("pages_crawled_total", "List pages completed", vec![
+            ("mode=\"crawl\"", n(&m.crawl_pages_completed)),
+            ("mode=\"sync\"", n(&m.sync_pages_completed)),
+        ]),
+        ("pages_failed_total", "Crawl pages that failed after all retries", vec![
+            ("", n(&m.crawl_pages_failed)),
+        ]),
+        ("sync_products_total", "Products written by sync pages", vec![
+            ("result=\"inserted\"", n(&m.sync_products_inserted)),
+            ("result=\"updated\"", n(&m.sync_products_updated)),
+        ]),
+        ("retries_total", "Retry attempts", vec![
+            ("scope=\"stage\"", n(&m.stage_retries)),
+            ("scope=\"sync\"", n(&m.sync_retries)),
+        ]),
+        ("sessions_total", "Crawl sessions by outcome", vec![
+            ("outcome=\"started\"", n(&m.sessions_started)),
+            ("outcome=\"completed\"", n(&m.sessions_completed)),
+            ("outcome=\"failed\"", n(&m.sessions_failed)),
+        ]),
+    ];
+    for (name, help, samples) in &counters {
+        write_family(&mut out, name, "counter", help, samples);
+    }
+
+    let actors = [
+        ("kind=\"session\"", m.active_sessions.get()),
+        ("kind=\"batch\"", m.active_batches.get()),
+        ("kind=\"stage\"", m.active_stages.get()),
+        ("kind=\"sync\"", m.active_syncs.get()),
+    ];
+    let help = "Running sessions, batches, stages and syncs";
+    write_family(&mut out, "active_actors", "gauge", help, &actors);
+    write_family(
+        &mut out,
+        "background_tasks",
+        "gauge",
+        "Tracked background tasks still running",
+        &[("", task_registry().running_count())],
+    );
+
+    #[rustfmt::skip]
+    let optimizer = [
+        ("concurrency", "Current crawl concurrency (performance optimizer)", &m.concurrency),
+        ("recommended_concurrency", "Concurrency recommended by the performance optimizer", &m.recommended_concurrency),
+        ("success_rate", "Rolling request success rate (0-1)", &m.success_rate),
+        ("throughput_rps", "Estimated requests per second", &m.throughput_rps),
+        ("network_error_rate", "Rolling network error rate (0-1)", &m.network_error_rate),
+    ];
+    for (name, help, gauge) in optimizer {
+        write_family(&mut out, name, "gauge", help, &[("", gauge.get())]);
+    }
+
+    write_family::<u64>(
+        &mut out,
+        "latency_seconds",
+        "summary",
+        "Operation latency (HTTP request, stage, stage item, sync page, DB write)",
+        &[],
+    );
+    for stat in LatencyRecorder::global().stats() {
+        let op = stat.op.as_str();
+        let stage = stat.stage.as_ref().map_or("", |s| s.as_str());
+        let labels = format!("op=\"{op}\",stage=\"{stage}\"");
+        let s = &stat.summary;
+        for (quantile, ms) in quantiles(s) {
+            let series = format!("{labels},quantile=\"{quantile}\"");
+            write_sample(&mut out, "latency_seconds", &series, ms / 1000.0);
+        }
+        let sum = s.mean_ms * s.count as f64 / 1000.0;
+        write_sample(&mut out, "latency_seconds_sum", &labels, sum);
+        write_sample(&mut out, "latency_seconds_count", &labels, s.count);
+    }
+    out
+}
+
+/// Bind `config.bind_addr` and serve `/metrics` in the background; no-op when disabled
+pub fn start_metrics_exporter(config: &MetricsExporterConfig) {
+    if !config.enabled {
+        return;
+    }
+    let addr: SocketAddr = match config.bind_addr.parse() {
+        Ok(addr) => addr,
+        Err(e) => {
+            warn!(
+                "📈 Metrics exporter disabled: invalid bind_addr '{}': {}",
+                config.bind_addr, e
+            );
+            return;
+        }
+    };
+    if !addr.ip().is_loopback() {
+        warn!(
+            "📈 Metrics exporter listening on non-loopback address {}; metrics are unauthenticated",
+            addr
+        );
+    }
+    spawn_tracked("metrics-exporter", None, async move {
+        match TcpListener::bind(addr).await {
+            Ok(listener) => {
+                info!("📈 Metrics exporter serving on http://{}/metrics", addr);
+                serve(listener).await;
+            }
+            Err(e) => warn!("📈 Metrics exporter failed to bind {}: {}", addr, e),
+        }
+    });
+}
+
+/// Accept loop; scrapes are short so each connection is answered inline in its own task
+pub async fn serve(listener: TcpListener) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, peer)) => {
+                tokio::spawn(async move {
+                    if let Err(e) = handle_connection(stream).await {
+                        debug!("📈 Metrics client {} closed: {}", peer, e);
+                    }
+                });
+            }
+            Err(e) => {
+                warn!("📈 Metrics exporter accept failed: {}", e);
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            }
+        }
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream) -> std::io::Result<()> {
+    let Some((method, target)) = read_request_line(&mut stream).await? else {
+        return Ok(());
+    };
+    let path = target.split('?').next().unwrap_or_default();
+    match (method.as_str(), path) {
+        ("GET", "/metrics") => {
+            respond(
+                &mut stream,
+                "200 OK",
+                "text/plain; version=0.0.4; charset=utf-8",
+                &render(),
+            )
+            .await
+        }
+        ("GET", "/health") => respond(&mut stream, "200 OK", "text/plain", "ok").await,
+        ("GET", _) => respond(&mut stream, "404 Not Found", "text/plain", "not found").await,
+        _ => {
+            respond(
+                &mut stream,
+                "405 Method Not Allowed",
+                "text/plain",
+                "method not allowed",
+            )
+            .await
+        }
+    }
+}
+
+/// Method and target of the request line, after the full head has been read
+async fn read_request_line(stream: &mut TcpStream) -> std::io::Result<Option<(String, String)>> {
+    let mut head = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        if head.len() > MAX_REQUEST_HEAD {
+            return Ok(None);
+        }
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(None);
+        }
+        head.extend_from_slice(&chunk[..n]);
+    }
+    let head = String::from_utf8_lossy(&head);
+    let mut parts = head.lines().next().unwrap_or_default().split_whitespace();
+    Ok(match (parts.next(), parts.next()) {
+        (Some(method), Some(target)) => Some((method.to_string(), target.to_string())),
+        _ => None,
+    })
+}
+
+async fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> std::io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crawl_engine::runtime::latency::{LatencyKey, LatencyOp, record_latency};
+    use chrono::Utc;
+
+    fn sample(text: &str, series: &str) -> f64 {
+        text.lines()
+            .find_map(|l| l.strip_prefix(series)?.strip_prefix(' '))
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_else(|| panic!("missing {series}"))
+    }
+
+    #[test]
+    fn events_move_counters_and_gauges() {
+        let before = render();
+        let pages_before = sample(&before, "rmattercertis_pages_crawled_total{mode=\"sync\"}");
+        let inserted_before = sample(
+            &before,
+            "rmattercertis_sync_products_total{result=\"inserted\"}",
+        );
+
+        observe_event(&AppEvent::SyncStarted {
+            session_id: "metrics-test".into(),
+            ranges: Vec::new(),
+            rate_limit: None,
+            estimated_finish_at: None,
+            timestamp: Utc::now(),
+        });
+        observe_event(&AppEvent::SyncPageCompleted {
+            session_id: "metrics-test".into(),
+            physical_page: 1,
+            inserted: 3,
+            updated: 2,
+            skipped: 0,
+            failed: 0,
+            ms: 40,
+            estimated_finish_at: None,
+            timestamp: Utc::now(),
+        });
+        let after = render();
+        assert_eq!(
+            sample(&after, "rmattercertis_pages_crawled_total{mode=\"sync\"}"),
+            pages_before + 1.0
+        );
+        assert_eq!(
+            sample(
+                &after,
+                "rmattercertis_sync_products_total{result=\"inserted\"}"
+            ),
+            inserted_before + 3.0
+        );
+        assert!(sample(&after, "rmattercertis_active_actors{kind=\"sync\"}") >= 1.0);
+        assert!(after.contains("# TYPE rmattercertis_latency_seconds summary"));
+    }
+
+    #[test]
+    fn gauges_never_go_negative_and_latency_is_exported() {
+        for _ in 0..3 {
+            METRICS.active_stages.dec();
+        }
+        assert!(METRICS.active_stages.get() >= 0);
+
+        record_latency(
+            None,
+            LatencyKey::op(LatencyOp::DbWrite),
+            Duration::from_millis(8),
+        );
+        let text = render();
+        assert!(text.contains(
+            "rmattercertis_latency_seconds{op=\"db_write\",stage=\"\",quantile=\"0.99\"}"
+        ));
+        assert!(
+            sample(
+                &text,
+                "rmattercertis_latency_seconds_count{op=\"db_write\",stage=\"\"}"
+            ) >= 1.0
+        );
+    }
+
+    #[tokio::test]
+    async fn serves_metrics_over_http() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(serve(listener));
+
+        let mut client = TcpStream::connect(addr).await.unwrap();
+        client
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: x\r\n\r\n")
+            .await
+            .unwrap();
+        let mut body = String::new();
+        tokio::time::timeout(Duration::from_secs(5), client.read_to_string(&mut body))
+            .await
+            .expect("response within timeout")
+            .unwrap();
+        assert!(body.starts_with("HTTP/1.1 200 OK"));
+        assert!(body.contains("text/plain; version=0.0.4"));
+        assert!(body.contains("rmattercertis_active_actors{kind=\"session\"}"));
+        server.abort();
+    }
+}
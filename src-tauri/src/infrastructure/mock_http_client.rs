@@ -0,0 +1,154 @@
+//! 모의 HTTP 전송 (`mock-site` feature)
+//!
+//! 활성화되면 `HttpClient` 의 모든 요청을 `test_utils::mock_site` 고정 HTML 로 응답한다. 레이트 리밋,
+//! robots.txt, 프록시, 회로 차단기, HTML 캐시를 거치지 않으므로 actor 파이프라인 전체가 네트워크 없이
+//! 결정적으로 돈다. `advanced.mock_site.enabled` 로 상시 켜거나(CI) `run_simulated_crawl` 이 세션 하나
+//! 동안만 켠다(UI 데모). 세션 시뮬레이션이 설정보다 우선한다.
+
+use crate::infrastructure::config::{MockSiteConfig, UrlTemplateConfig};
+use crate::test_utils::mock_site::{MockRoute, MockSite};
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Response, StatusCode};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tracing::{debug, info};
+
+static CONFIGURED: Lazy<RwLock<Option<Arc<MockHttpClient>>>> = Lazy::new(|| RwLock::new(None));
+static SIMULATION: Lazy<RwLock<Option<Arc<MockHttpClient>>>> = Lazy::new(|| RwLock::new(None));
+
+/// Serves the mock site's fixtures in place of the network
+pub struct MockHttpClient {
+    site: MockSite,
+    latency: Duration,
+    requests: AtomicU64,
+}
+
+impl MockHttpClient {
+    pub fn new(site: MockSite, latency: Duration) -> Self {
+        Self {
+            site,
+            latency,
+            requests: AtomicU64::new(0),
+        }
+    }
+
+    /// Listing URLs follow `templates` (built-in ones when invalid, as the URL builders do)
+    pub fn from_config(config: &MockSiteConfig, templates: &UrlTemplateConfig) -> Self {
+        let urls = if templates.validate().is_empty() {
+            templates.clone()
+        } else {
+            UrlTemplateConfig::default()
+        };
+        Self::new(
+            MockSite::new(urls, config.total_pages, config.products_on_last_page),
+            Duration::from_millis(config.latency_ms),
+        )
+    }
+
+    pub fn site(&self) -> &MockSite {
+        &self.site
+    }
+
+    /// Requests answered so far
+    pub fn request_count(&self) -> u64 {
+        self.requests.load(Ordering::Relaxed)
+    }
+
+    pub async fn respond(&self, url: &str) -> Result<Response> {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        let route = self.site.route(url);
+        let (status, body) = match route {
+            MockRoute::ListPage(page) => (StatusCode::OK, self.site.list_page_html(page)),
+            MockRoute::Product(seq) => (StatusCode::OK, self.site.detail_page_html(seq)),
+            MockRoute::NotFound => (
+                StatusCode::NOT_FOUND,
+                "<!DOCTYPE html><html><body>Not Found</body></html>".to_string(),
+            ),
+        };
+        // Pages past the end stay instant so last-page discovery does not crawl through the delay
+        let exists = match route {
+            MockRoute::ListPage(page) => page <= self.site.total_pages,
+            MockRoute::Product(_) => true,
+            MockRoute::NotFound => false,
+        };
+        if exists && !self.latency.is_zero() {
+            tokio::time::sleep(self.latency).await;
+        }
+        debug!("🧪 [mock-site] {} {}", status.as_u16(), url);
+        let response = http::Response::builder()
+            .status(status)
+            .header(CONTENT_TYPE, "text/html; charset=utf-8")
+            .body(body)?;
+        Ok(Response::from(response))
+    }
+}
+
+/// Apply `advanced.mock_site` (AppState init / config updates)
+pub fn configure(config: &MockSiteConfig, templates: &UrlTemplateConfig) {
+    let client = config.enabled.then(|| {
+        info!(
+            "🧪 [mock-site] enabled: {} pages, {} products on the last page",
+            config.total_pages, config.products_on_last_page
+        );
+        Arc::new(MockHttpClient::from_config(config, templates))
+    });
+    if let Ok(mut guard) = CONFIGURED.write() {
+        *guard = client;
+    }
+}
+
+/// Route requests to a mock site until `end_simulation` (simulated crawl sessions)
+pub fn begin_simulation(
+    config: &MockSiteConfig,
+    templates: &UrlTemplateConfig,
+) -> Arc<MockHttpClient> {
+    let client = Arc::new(MockHttpClient::from_config(config, templates));
+    if let Ok(mut guard) = SIMULATION.write() {
+        *guard = Some(Arc::clone(&client));
+    }
+    client
+}
+
+pub fn end_simulation() {
+    if let Ok(mut guard) = SIMULATION.write() {
+        if let Some(client) = guard.take() {
+            info!(
+                "🧪 [mock-site] simulation ended after {} requests",
+                client.request_count()
+            );
+        }
+    }
+}
+
+/// Mock transport requests go to right now, if any
+pub fn active() -> Option<Arc<MockHttpClient>> {
+    let simulated = SIMULATION.read().ok().and_then(|g| g.clone());
+    simulated.or_else(|| CONFIGURED.read().ok().and_then(|g| g.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn responds_with_fixtures_and_404_for_unknown_urls() {
+        let mock = MockHttpClient::new(
+            MockSite::new(UrlTemplateConfig::default(), 2, 3),
+            Duration::ZERO,
+        );
+        let first = mock.site().urls.list_page_url(1);
+        let response = mock.respond(&first).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.text().await.unwrap();
+        assert_eq!(body.matches("type-product").count(), 12);
+
+        let detail = mock.respond(&mock.site().product_url(14)).await.unwrap();
+        assert_eq!(detail.status(), StatusCode::OK);
+        let missing = mock.respond(&mock.site().product_url(15)).await.unwrap();
+        assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+        assert_eq!(mock.request_count(), 3);
+    }
+}
@@ -0,0 +1,762 @@
+//! Write-behind batching queue for `ProductDetail` persistence
+//!
+//! 행 단위 upsert(행마다 트랜잭션/fsync) 대신 `ProductDetail`을 큐에 모았다가 배치 하나를
+//! 트랜잭션 하나로 기록한다. 배치 크기에 도달하면 `enqueue`가 즉시 flush하고, 남은 행은
+//! 백그라운드 flusher가 `flush_interval_ms`마다 기록한다. 결과 카운트가 필요한 호출자는
+//! `flush`를 직접 호출한다. 병합 규칙은 sync 경로와 같다: NULL이 아닌 값만 기존 값을 덮어쓴다.
+//! 미커밋 행의 추정 크기가 메모리 예산(`memory_budget`)을 넘어도 배치 크기 전에 flush 한다.
+
+use crate::crawl_engine::runtime::crawl_limits;
+use crate::crawl_engine::runtime::latency::{LatencyKey, LatencyOp, LatencyTimer};
+use crate::domain::product::ProductDetail;
+use crate::infrastructure::IntegratedProductRepository;
+use crate::infrastructure::config::PersistenceQueueConfig;
+use crate::infrastructure::config::defaults;
+use crate::infrastructure::coordinate_guard::{self, CoordinateViolation};
+use crate::infrastructure::features::feature_search_index_hooks;
+use crate::infrastructure::memory_budget;
+use crate::infrastructure::read_snapshot;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// `product_details` columns written by the queue (after `url`; bind order in `write_batch`)
+const DETAIL_COLUMNS: [&str; 24] = [
+    "page_id",
+    "index_in_page",
+    "id",
+    "manufacturer",
+    "model",
+    "device_type",
+    "certificate_id",
+    "certification_date",
+    "software_version",
+    "hardware_version",
+    "firmware_version",
+    "specification_version",
+    "vid",
+    "pid",
+    "family_sku",
+    "family_variant_sku",
+    "family_id",
+    "tis_trp_tested",
+    "transport_interface",
+    "primary_device_type_id",
+    "application_categories",
+    "description",
+    "compliance_document_url",
+    "program_type",
+];
+
+/// `products` columns backfilled from the detail row (after `url`)
+const PRODUCT_COLUMNS: [&str; 5] = [
+    "manufacturer",
+    "model",
+    "certificate_id",
+    "page_id",
+    "index_in_page",
+];
+
+static BATCH_SIZE: AtomicUsize = AtomicUsize::new(defaults::PERSISTENCE_QUEUE_BATCH_SIZE as usize);
+static FLUSH_INTERVAL_MS: AtomicU64 = AtomicU64::new(defaults::PERSISTENCE_QUEUE_FLUSH_INTERVAL_MS);
+
+static SHARED_QUEUE: Mutex<Option<Arc<PersistenceQueue>>> = Mutex::new(None);
+
+static DETAIL_UPSERT_SQL: Lazy<String> =
+    Lazy::new(|| coalescing_upsert("product_details", &DETAIL_COLUMNS));
+static PRODUCT_UPSERT_SQL: Lazy<String> =
+    Lazy::new(|| coalescing_upsert("products", &PRODUCT_COLUMNS));
+
+/// Apply `advanced.persistence_queue` (and the memory budget configured before it) to the
+/// process-wide queue; takes effect on the next enqueue / flusher tick
+pub fn configure(config: &PersistenceQueueConfig) {
+    BATCH_SIZE.store(config.batch_size.max(1) as usize, Ordering::Relaxed);
+    FLUSH_INTERVAL_MS.store(config.flush_interval_ms.max(50), Ordering::Relaxed);
+    let shared = SHARED_QUEUE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    if let Some(queue) = shared {
+        queue.set_limits(QueueLimits::configured());
+    }
+}
+
+fn batch_size() -> usize {
+    BATCH_SIZE.load(Ordering::Relaxed)
+}
+
+fn flush_interval() -> Duration {
+    Duration::from_millis(FLUSH_INTERVAL_MS.load(Ordering::Relaxed))
+}
+
+/// Process-wide queue (created on first use, together with its interval flusher)
+pub fn shared_queue(pool: &SqlitePool) -> Arc<PersistenceQueue> {
+    SHARED_QUEUE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get_or_insert_with(|| {
+            let queue = Arc::new(PersistenceQueue::new(
+                pool.clone(),
+                QueueLimits::configured(),
+            ));
+            start_flusher(Arc::downgrade(&queue));
+            queue
+        })
+        .clone()
+}
+
+/// Write what the process-wide queue still holds into its database, then drop it so the next
+/// `shared_queue` call binds to the current pool (workspace switch). On a failed flush the
+/// queue stays in place with its rows.
+pub async fn reset_shared_queue() -> Result<FlushReport> {
+    let queue = SHARED_QUEUE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    let Some(queue) = queue else {
+        return Ok(FlushReport::default());
+    };
+    let report = queue.flush().await?;
+    SHARED_QUEUE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take();
+    Ok(report)
+}
+
+fn start_flusher(queue: std::sync::Weak<PersistenceQueue>) {
+    crate::crawl_engine::runtime::task_registry::spawn_tracked(
+        "persistence-queue-flusher",
+        None,
+        async move {
+            loop {
+                tokio::time::sleep(flush_interval()).await;
+                let Some(queue) = queue.upgrade() else {
+                    break;
+                };
+                if queue.depth() == 0 {
+                    continue;
+                }
+                if let Err(e) = queue.flush().await {
+                    warn!("💾 persistence queue interval flush failed: {e:#}");
+                }
+            }
+        },
+    );
+}
+
+/// Outcome of one `flush` (or of the flush triggered by `enqueue`)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FlushReport {
+    pub batches: u32,
+    pub rows: u32,
+    pub inserted: u32,
+    pub updated: u32,
+    pub unchanged: u32,
+    /// Rows refused by strict coordinate mode
+    pub rejected: u32,
+    pub elapsed_ms: u64,
+}
+
+impl FlushReport {
+    /// Fold another flush into this one (e.g. the inline flush of `enqueue` + a final `flush`)
+    pub fn absorb(&mut self, other: &FlushReport) {
+        self.batches += other.batches;
+        self.rows += other.rows;
+        self.inserted += other.inserted;
+        self.updated += other.updated;
+        self.unchanged += other.unchanged;
+        self.rejected += other.rejected;
+        self.elapsed_ms += other.elapsed_ms;
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PersistenceQueueMetrics {
+    pub queue_depth: usize,
+    pub pending_bytes: u64,
+    pub batch_size: usize,
+    pub flush_interval_ms: u64,
+    pub flushes: u64,
+    pub failed_flushes: u64,
+    pub rows_flushed: u64,
+    pub inserted: u64,
+    pub updated: u64,
+    pub unchanged: u64,
+    pub rejected: u64,
+    pub last_flush_ms: u64,
+    pub max_flush_ms: u64,
+    pub avg_flush_ms: f64,
+    pub last_flush_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default)]
+struct FlushStats {
+    flushes: u64,
+    failed_flushes: u64,
+    rows_flushed: u64,
+    inserted: u64,
+    updated: u64,
+    unchanged: u64,
+    rejected: u64,
+    last_flush_ms: u64,
+    max_flush_ms: u64,
+    total_flush_ms: u64,
+    last_flush_at: Option<DateTime<Utc>>,
+}
+
+/// When a queue flushes without being asked to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueLimits {
+    /// Rows per batch; reaching it flushes inline
+    pub batch_size: usize,
+    /// Estimated uncommitted bytes that force a flush before `batch_size`
+    pub memory_budget_bytes: u64,
+}
+
+impl QueueLimits {
+    /// `advanced.persistence_queue` / `advanced.memory_budget` as last configured
+    pub fn configured() -> Self {
+        Self {
+            batch_size: batch_size(),
+            memory_budget_bytes: memory_budget::budget_bytes(),
+        }
+    }
+}
+
+pub struct PersistenceQueue {
+    pool: SqlitePool,
+    batch_size: AtomicUsize,
+    memory_budget_bytes: AtomicU64,
+    pending: Mutex<Vec<ProductDetail>>,
+    /// Estimated size of `pending` (memory budget)
+    pending_bytes: AtomicU64,
+    /// One flush at a time so batches commit in enqueue order
+    flush_gate: tokio::sync::Mutex<()>,
+    stats: Mutex<FlushStats>,
+}
+
+impl PersistenceQueue {
+    pub fn new(pool: SqlitePool, limits: QueueLimits) -> Self {
+        Self {
+            pool,
+            batch_size: AtomicUsize::new(limits.batch_size.max(1)),
+            memory_budget_bytes: AtomicU64::new(limits.memory_budget_bytes.max(1)),
+            pending: Mutex::new(Vec::new()),
+            pending_bytes: AtomicU64::new(0),
+            flush_gate: tokio::sync::Mutex::new(()),
+            stats: Mutex::new(FlushStats::default()),
+        }
+    }
+
+    pub fn limits(&self) -> QueueLimits {
+        QueueLimits {
+            batch_size: self.batch_size.load(Ordering::Relaxed),
+            memory_budget_bytes: self.memory_budget_bytes.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn set_limits(&self, limits: QueueLimits) {
+        self.batch_size
+            .store(limits.batch_size.max(1), Ordering::Relaxed);
+        self.memory_budget_bytes
+            .store(limits.memory_budget_bytes.max(1), Ordering::Relaxed);
+    }
+
+    pub fn depth(&self) -> usize {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn pending_bytes(&self) -> u64 {
+        self.pending_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Returns true once the pending rows exceed this queue's memory budget
+    fn adjust_pending_bytes(&self, added: u64, removed: u64) -> bool {
+        let now = self
+            .pending_bytes
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |cur| {
+                Some((cur + added).saturating_sub(removed))
+            })
+            .map_or(0, |prev| (prev + added).saturating_sub(removed));
+        memory_budget::track_queue(now);
+        now >= self.memory_budget_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Queue rows; flushes everything pending once the batch size or the memory budget is
+    /// reached (the returned report is empty when no flush happened)
+    pub async fn enqueue(
+        &self,
+        details: impl IntoIterator<Item = ProductDetail>,
+    ) -> Result<FlushReport> {
+        let details: Vec<ProductDetail> = details.into_iter().collect();
+        let bytes = memory_budget::estimated_bytes(&details);
+        let depth = {
+            let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+            pending.extend(details);
+            pending.len()
+        };
+        let over_budget = self.adjust_pending_bytes(bytes, 0);
+        if depth >= self.limits().batch_size {
+            return self.flush().await;
+        }
+        if over_budget && depth > 0 {
+            memory_budget::note_early_flush();
+            debug!(
+                "💾 persistence queue over memory budget ({} rows, {} bytes) -> early flush",
+                depth,
+                self.pending_bytes()
+            );
+            return self.flush().await;
+        }
+        Ok(FlushReport::default())
+    }
+
+    /// Drop the new products `session_id`'s `max_new_products` allowance no longer covers.
+    /// Rows for products already stored (or already queued) always pass, so a batch that
+    /// crosses the cap still updates them and saves only the remaining new ones.
+    pub async fn cap_new_products(
+        &self,
+        session_id: &str,
+        rows: Vec<ProductDetail>,
+    ) -> Result<Vec<ProductDetail>> {
+        if !crawl_limits::has_new_product_cap(session_id) || rows.is_empty() {
+            return Ok(rows);
+        }
+        let mut known: HashSet<String> = self
+            .pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|row| IntegratedProductRepository::normalize_url(&row.url))
+            .collect();
+        let mut kept = Vec::with_capacity(rows.len());
+        let mut dropped = 0usize;
+        for row in rows {
+            let url = IntegratedProductRepository::normalize_url(&row.url);
+            let is_new = !known.contains(&url)
+                && !sqlx::query_scalar::<_, bool>(
+                    "SELECT EXISTS(SELECT 1 FROM product_details WHERE url = ?)",
+                )
+                .bind(&url)
+                .fetch_one(&self.pool)
+                .await?;
+            if is_new && crawl_limits::take_new_products(session_id, 1) == 0 {
+                dropped += 1;
+                continue;
+            }
+            known.insert(url);
+            kept.push(row);
+        }
+        if dropped > 0 {
+            debug!(
+                "💾 max_new_products reached for {}: dropped {} new rows",
+                session_id, dropped
+            );
+        }
+        Ok(kept)
+    }
+
+    /// Write every pending row, one transaction per batch. On failure the unwritten rows go
+    /// back to the front of the queue.
+    pub async fn flush(&self) -> Result<FlushReport> {
+        let _gate = self.flush_gate.lock().await;
+        let rows = std::mem::take(&mut *self.pending.lock().unwrap_or_else(|e| e.into_inner()));
+        let mut report = FlushReport::default();
+        if rows.is_empty() {
+            return Ok(report);
+        }
+        self.adjust_pending_bytes(0, memory_budget::estimated_bytes(&rows));
+        let started = Instant::now();
+        let size = self.limits().batch_size;
+        for (i, chunk) in rows.chunks(size).enumerate() {
+            let timer = LatencyTimer::start(LatencyKey::op(LatencyOp::DbWrite), None);
+            let written = write_batch(&self.pool, chunk).await;
+            timer.finish();
+            read_snapshot::mark_page_boundary();
+            match written {
+                Ok(batch) => {
+                    report.batches += 1;
+                    report.rows += chunk.len() as u32;
+                    report.inserted += batch.inserted;
+                    report.updated += batch.updated;
+                    report.unchanged += batch.unchanged;
+                    report.rejected += batch.rejected;
+                    if feature_search_index_hooks() && !batch.changed_urls.is_empty() {
+                        if let Err(e) = crate::services::search_index::apply_deltas(
+                            &self.pool,
+                            &batch.changed_urls,
+                        )
+                        .await
+                        {
+                            warn!("💾 search index delta failed (run check_search_index): {e:#}");
+                        }
+                    }
+                }
+                Err(e) => {
+                    let mut unwritten = rows[i * size..].to_vec();
+                    let requeued = unwritten.len();
+                    self.adjust_pending_bytes(memory_budget::estimated_bytes(&unwritten), 0);
+                    {
+                        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+                        unwritten.append(&mut pending);
+                        *pending = unwritten;
+                    }
+                    self.stats
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .failed_flushes += 1;
+                    return Err(e.context(format!(
+                        "persistence batch failed; {requeued} rows requeued"
+                    )));
+                }
+            }
+        }
+        report.elapsed_ms = started.elapsed().as_millis() as u64;
+        self.record(&report);
+        debug!(
+            "💾 persistence flush batches={} rows={} inserted={} updated={} unchanged={} rejected={} elapsed_ms={}",
+            report.batches,
+            report.rows,
+            report.inserted,
+            report.updated,
+            report.unchanged,
+            report.rejected,
+            report.elapsed_ms
+        );
+        Ok(report)
+    }
+
+    fn record(&self, report: &FlushReport) {
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        stats.flushes += 1;
+        stats.rows_flushed += u64::from(report.rows);
+        stats.inserted += u64::from(report.inserted);
+        stats.updated += u64::from(report.updated);
+        stats.unchanged += u64::from(report.unchanged);
+        stats.rejected += u64::from(report.rejected);
+        stats.last_flush_ms = report.elapsed_ms;
+        stats.max_flush_ms = stats.max_flush_ms.max(report.elapsed_ms);
+        stats.total_flush_ms += report.elapsed_ms;
+        stats.last_flush_at = Some(Utc::now());
+    }
+
+    pub fn metrics(&self) -> PersistenceQueueMetrics {
+        let stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        PersistenceQueueMetrics {
+            queue_depth: self.depth(),
+            pending_bytes: self.pending_bytes(),
+            batch_size: self.limits().batch_size,
+            flush_interval_ms: flush_interval().as_millis() as u64,
+            flushes: stats.flushes,
+            failed_flushes: stats.failed_flushes,
+            rows_flushed: stats.rows_flushed,
+            inserted: stats.inserted,
+            updated: stats.updated,
+            unchanged: stats.unchanged,
+            rejected: stats.rejected,
+            last_flush_ms: stats.last_flush_ms,
+            max_flush_ms: stats.max_flush_ms,
+            avg_flush_ms: if stats.flushes == 0 {
+                0.0
+            } else {
+                stats.total_flush_ms as f64 / stats.flushes as f64
+            },
+            last_flush_at: stats.last_flush_at,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct BatchOutcome {
+    inserted: u32,
+    updated: u32,
+    unchanged: u32,
+    rejected: u32,
+    changed_urls: Vec<String>,
+}
+
+/// `INSERT .. ON CONFLICT(url) DO UPDATE` that only overwrites with non-NULL values and only
+/// touches the row when some value actually changes (so `rows_affected` tells unchanged apart)
+fn coalescing_upsert(table: &str, columns: &[&str]) -> String {
+    let placeholders = vec!["?"; columns.len() + 1].join(", ");
+    let assignments: Vec<String> = columns
+        .iter()
+        .map(|c| format!("{c} = COALESCE(excluded.{c}, {table}.{c})"))
+        .collect();
+    let changed: Vec<String> = columns
+        .iter()
+        .map(|c| format!("(excluded.{c} IS NOT NULL AND excluded.{c} IS NOT {table}.{c})"))
+        .collect();
+    format!(
+        "INSERT INTO {table} (url, {}) VALUES ({placeholders}) \
+         ON CONFLICT(url) DO UPDATE SET {}, updated_at = CURRENT_TIMESTAMP WHERE {}",
+        columns.join(", "),
+        assignments.join(", "),
+        changed.join(" OR ")
+    )
+}
+
+async fn write_batch(pool: &SqlitePool, rows: &[ProductDetail]) -> Result<BatchOutcome> {
+    let strict = coordinate_guard::is_strict_mode();
+    let products_per_page = coordinate_guard::products_per_page();
+    let mut out = BatchOutcome::default();
+    let mut tx = pool.begin().await?;
+    for row in rows {
+        let url = IntegratedProductRepository::normalize_url(&row.url);
+        if strict {
+            if let Some(kind) = coordinate_guard::check_coordinates(
+                row.page_id,
+                row.index_in_page,
+                products_per_page,
+            ) {
+                // Recorded for get_coordinate_violations; the rest of the batch still commits
+                let _ = coordinate_guard::reject(CoordinateViolation {
+                    operation: "persistence_queue.write_batch".into(),
+                    url,
+                    page_id: row.page_id,
+                    index_in_page: row.index_in_page,
+                    kind,
+                    conflicting_url: None,
+                    detected_at: Utc::now(),
+                });
+                out.rejected += 1;
+                continue;
+            }
+        }
+        let id = row
+            .id
+            .clone()
+            .or_else(|| match (row.page_id, row.index_in_page) {
+                (Some(pid), Some(idx)) => Some(format!("p{:04}i{:02}", pid, idx)),
+                _ => None,
+            });
+        let existed: bool =
+            sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM product_details WHERE url = ?)")
+                .bind(&url)
+                .fetch_one(&mut *tx)
+                .await?;
+
+        // Parent row first (product_details.url references products.url)
+        sqlx::query(&PRODUCT_UPSERT_SQL)
+            .bind(&url)
+            .bind(&row.manufacturer)
+            .bind(&row.model)
+            .bind(&row.certificate_id)
+            .bind(row.page_id)
+            .bind(row.index_in_page)
+            .execute(&mut *tx)
+            .await?;
+        let affected = sqlx::query(&DETAIL_UPSERT_SQL)
+            .bind(&url)
+            .bind(row.page_id)
+            .bind(row.index_in_page)
+            .bind(id)
+            .bind(&row.manufacturer)
+            .bind(&row.model)
+            .bind(&row.device_type)
+            .bind(&row.certificate_id)
+            .bind(&row.certification_date)
+            .bind(&row.software_version)
+            .bind(&row.hardware_version)
+            .bind(&row.firmware_version)
+            .bind(&row.specification_version)
+            .bind(row.vid)
+            .bind(row.pid)
+            .bind(&row.family_sku)
+            .bind(&row.family_variant_sku)
+            .bind(&row.family_id)
+            .bind(&row.tis_trp_tested)
+            .bind(&row.transport_interface)
+            .bind(&row.primary_device_type_id)
+            .bind(&row.application_categories)
+            .bind(&row.description)
+            .bind(&row.compliance_document_url)
+            .bind(
+                row.program_type
+                    .clone()
+                    .or_else(|| Some("Matter".to_string())),
+            )
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+        match (existed, affected > 0) {
+            (false, _) => out.inserted += 1,
+            (true, true) => out.updated += 1,
+            (true, false) => {
+                out.unchanged += 1;
+                continue;
+            }
+        }
+        out.changed_urls.push(url);
+    }
+    tx.commit().await?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    fn limits(batch_size: usize, memory_budget_bytes: u64) -> QueueLimits {
+        QueueLimits {
+            batch_size,
+            memory_budget_bytes,
+        }
+    }
+
+    async fn test_pool() -> SqlitePool {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        db.pool().clone()
+    }
+
+    fn detail(n: i32) -> ProductDetail {
+        let now = Utc::now();
+        ProductDetail {
+            url: format!("https://x.test/p/{n}/"),
+            page_id: Some(n / 12),
+            index_in_page: Some(n % 12),
+            id: None,
+            manufacturer: Some("Acme".into()),
+            model: Some(format!("M{n}")),
+            device_type: None,
+            certificate_id: None,
+            certification_date: None,
+            software_version: None,
+            hardware_version: None,
+            vid: None,
+            pid: None,
+            family_sku: None,
+            family_variant_sku: None,
+            firmware_version: None,
+            family_id: None,
+            tis_trp_tested: None,
+            specification_version: None,
+            transport_interface: None,
+            primary_device_type_id: None,
+            application_categories: None,
+            description: None,
+            compliance_document_url: None,
+            program_type: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn flush_classifies_inserted_updated_and_unchanged() {
+        let pool = test_pool().await;
+        let queue = PersistenceQueue::new(pool.clone(), limits(100, u64::MAX));
+
+        let first = queue.enqueue((0..3).map(detail)).await.unwrap();
+        assert_eq!(first.rows, 0, "below batch size nothing is written yet");
+        assert_eq!(queue.depth(), 3);
+        let report = queue.flush().await.unwrap();
+        assert_eq!(
+            (report.inserted, report.updated, report.unchanged),
+            (3, 0, 0)
+        );
+        assert_eq!(queue.depth(), 0);
+
+        let mut changed = detail(1);
+        changed.certificate_id = Some("CSA-1".into());
+        let mut partial = detail(2);
+        partial.model = None; // NULL never erases
+        queue.enqueue([detail(0), changed, partial]).await.unwrap();
+        let report = queue.flush().await.unwrap();
+        assert_eq!(
+            (report.inserted, report.updated, report.unchanged),
+            (0, 1, 2)
+        );
+
+        let (model, id): (Option<String>, Option<String>) =
+            sqlx::query_as("SELECT model, id FROM product_details WHERE url = ?")
+                .bind(detail(2).url)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(model.as_deref(), Some("M2"));
+        assert_eq!(id.as_deref(), Some("p0000i02"));
+        let cert: Option<String> =
+            sqlx::query_scalar("SELECT certificate_id FROM products WHERE url = ?")
+                .bind(detail(1).url)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(cert.as_deref(), Some("CSA-1"));
+
+        let metrics = queue.metrics();
+        assert_eq!(metrics.flushes, 2);
+        assert_eq!(metrics.rows_flushed, 6);
+        assert_eq!(metrics.queue_depth, 0);
+    }
+
+    #[tokio::test]
+    async fn reaching_batch_size_flushes_inline() {
+        let pool = test_pool().await;
+        let queue = PersistenceQueue::new(pool, limits(4, u64::MAX));
+        let below = queue.enqueue((0..3).map(detail)).await.unwrap();
+        assert_eq!(below.rows, 0);
+        let report = queue.enqueue([detail(3)]).await.unwrap();
+        assert_eq!(report.inserted, 4);
+        assert_eq!(report.batches, 1);
+        assert_eq!(queue.depth(), 0);
+    }
+
+    #[tokio::test]
+    async fn memory_budget_flushes_before_batch_size() {
+        let pool = test_pool().await;
+        let queue = PersistenceQueue::new(pool, limits(100, 1024 * 1024));
+        let first = queue.enqueue([detail(0)]).await.unwrap();
+        assert_eq!(first.rows, 0);
+        assert!(queue.pending_bytes() > 0);
+
+        let mut big = detail(1);
+        big.description = Some("x".repeat(2 * 1024 * 1024));
+        let report = queue.enqueue([big]).await.unwrap();
+        assert_eq!(report.rows, 2);
+        assert_eq!((queue.depth(), queue.pending_bytes()), (0, 0));
+    }
+
+    #[tokio::test]
+    async fn new_product_cap_falls_mid_batch() {
+        let pool = test_pool().await;
+        let queue = PersistenceQueue::new(pool, limits(100, u64::MAX));
+        queue.enqueue([detail(0)]).await.unwrap();
+        queue.flush().await.unwrap();
+
+        let session = "cap-mid-batch";
+        crawl_limits::set_new_product_allowance(session, 2);
+        let batch = vec![detail(0), detail(1), detail(2), detail(3)];
+        let kept = queue.cap_new_products(session, batch).await.unwrap();
+        let urls: Vec<String> = kept.iter().map(|d| d.url.clone()).collect();
+        assert_eq!(urls, vec![detail(0).url, detail(1).url, detail(2).url]);
+        queue.enqueue(kept).await.unwrap();
+        let report = queue.flush().await.unwrap();
+        assert_eq!((report.inserted, report.updated + report.unchanged), (2, 1));
+
+        // Allowance spent: stored products still pass, new ones don't
+        let kept = queue
+            .cap_new_products(session, vec![detail(1), detail(4)])
+            .await
+            .unwrap();
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].url, detail(1).url);
+        crawl_limits::clear_new_product_allowance(session);
+        let uncapped = queue
+            .cap_new_products(session, vec![detail(4)])
+            .await
+            .unwrap();
+        assert_eq!(uncapped.len(), 1);
+    }
+}
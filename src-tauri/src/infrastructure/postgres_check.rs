@@ -0,0 +1,170 @@
+//! Postgres 연결 확인 / 스키마 적용
+//!
+//! `advanced.database.postgres_url` 이 있으면 그 서버에 `migrations/postgres` 스키마를 적용하고
+//! 연결 여부와 제품 수를 확인한다. 연결 확인일 뿐 저장소 백엔드 전환은 아니다: 크롤, 동기화, 조회는
+//! 모두 활성 워크스페이스의 SQLite 파일을 쓰고, Postgres 를 저장소로 고르는 설정이나 백엔드 공통
+//! 저장소 트레이트는 없다. `PostgresProductRepository` 는 이전/비교용 저장소다.
+
+use crate::infrastructure::config::PostgresCheckConfig;
+use crate::infrastructure::postgres_product_repository::PostgresProductRepository;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use sqlx::postgres::{PgPool, PgPoolOptions};
+use tracing::info;
+
+/// Postgres migrations, applied in order and recorded in `schema_migrations`
+const POSTGRES_MIGRATIONS: &[(&str, &str)] = &[(
+    "001_core_schema",
+    include_str!("../../migrations/postgres/001_core_schema.sql"),
+)];
+
+/// Whether a connection string points at Postgres (`postgres://` / `postgresql://`)
+pub fn is_postgres_url(url: &str) -> bool {
+    url.starts_with("postgres://") || url.starts_with("postgresql://")
+}
+
+/// Configured `postgres_url`, if any; anything that is not a Postgres URL is an error
+pub fn configured_postgres_url(config: &PostgresCheckConfig) -> Result<Option<String>> {
+    let Some(url) = config
+        .postgres_url
+        .as_deref()
+        .map(str::trim)
+        .filter(|u| !u.is_empty())
+    else {
+        return Ok(None);
+    };
+    if !is_postgres_url(url) {
+        anyhow::bail!("postgres_url must start with postgres:// or postgresql://");
+    }
+    Ok(Some(url.to_string()))
+}
+
+/// Connection string with the password masked, for logs and status output
+pub fn redact_url(url: &str) -> String {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return url.to_string();
+    };
+    match rest.split_once('@') {
+        Some((credentials, host)) => match credentials.split_once(':') {
+            Some((user, _)) => format!("{scheme}://{user}:***@{host}"),
+            None => url.to_string(),
+        },
+        None => url.to_string(),
+    }
+}
+
+/// Apply pending Postgres migrations; returns the versions applied by this call
+pub async fn migrate_postgres(pool: &PgPool) -> Result<Vec<&'static str>> {
+    sqlx::raw_sql(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (\
+            version TEXT PRIMARY KEY,\
+            applied_at TIMESTAMPTZ NOT NULL DEFAULT now())",
+    )
+    .execute(pool)
+    .await?;
+    let mut applied = Vec::new();
+    for (version, sql) in POSTGRES_MIGRATIONS {
+        let done: Option<i32> =
+            sqlx::query_scalar("SELECT 1 FROM schema_migrations WHERE version = $1")
+                .bind(version)
+                .fetch_optional(pool)
+                .await?;
+        if done.is_some() {
+            continue;
+        }
+        let mut tx = pool.begin().await?;
+        sqlx::raw_sql(sql)
+            .execute(&mut *tx)
+            .await
+            .with_context(|| format!("Postgres migration {version} failed"))?;
+        sqlx::query("INSERT INTO schema_migrations (version) VALUES ($1)")
+            .bind(version)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        info!("🧩 Postgres migration {} applied", version);
+        applied.push(*version);
+    }
+    Ok(applied)
+}
+
+/// Connect to the configured Postgres server and apply its migrations
+pub async fn open_postgres(url: &str) -> Result<PostgresProductRepository> {
+    let pool = PgPoolOptions::new()
+        .max_connections(crate::infrastructure::config::defaults::MAX_CONCURRENT_REQUESTS)
+        .connect(url)
+        .await
+        .with_context(|| format!("Failed to connect to {}", redact_url(url)))?;
+    migrate_postgres(&pool).await?;
+    Ok(PostgresProductRepository::new(pool))
+}
+
+/// Whether a Postgres server is configured and reachable, and how many products it holds
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PostgresCheckStatus {
+    /// `advanced.database.postgres_url` is set
+    pub configured: bool,
+    /// Connection string with the password masked
+    pub url: String,
+    pub connected: bool,
+    pub product_count: Option<i32>,
+    pub error: Option<String>,
+}
+
+/// Connect (migrating on the way) and count the Postgres store's products
+pub async fn check_postgres(config: &PostgresCheckConfig) -> PostgresCheckStatus {
+    let url = match configured_postgres_url(config) {
+        Ok(Some(url)) => url,
+        Ok(None) => return PostgresCheckStatus::default(),
+        Err(e) => {
+            return PostgresCheckStatus {
+                configured: true,
+                error: Some(format!("{e:#}")),
+                ..PostgresCheckStatus::default()
+            };
+        }
+    };
+    let counted = match open_postgres(&url).await {
+        Ok(repo) => repo.get_product_count().await,
+        Err(e) => Err(e),
+    };
+    let (product_count, error) = match counted {
+        Ok(count) => (Some(count), None),
+        Err(e) => (None, Some(format!("{e:#}"))),
+    };
+    PostgresCheckStatus {
+        configured: true,
+        url: redact_url(&url),
+        connected: error.is_none(),
+        product_count,
+        error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn validates_postgres_url_and_masks_password() {
+        assert!(is_postgres_url("postgresql://u@h/db"));
+        assert!(!is_postgres_url("mysql://h/db"));
+        assert_eq!(
+            redact_url("postgres://crawler:secret@db:5432/matter"),
+            "postgres://crawler:***@db:5432/matter"
+        );
+
+        let mut config = PostgresCheckConfig::default();
+        assert_eq!(configured_postgres_url(&config).unwrap(), None);
+        assert!(!check_postgres(&config).await.configured);
+        config.postgres_url = Some("sqlite:/tmp/b.db".into());
+        assert!(configured_postgres_url(&config).is_err());
+        let status = check_postgres(&config).await;
+        assert!(status.configured && !status.connected && status.error.is_some());
+        config.postgres_url = Some(" postgres://u:p@h/db ".into());
+        assert_eq!(
+            configured_postgres_url(&config).unwrap().as_deref(),
+            Some("postgres://u:p@h/db")
+        );
+    }
+}
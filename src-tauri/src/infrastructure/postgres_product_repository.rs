@@ -0,0 +1,308 @@
+//! Postgres 제품 저장소 (`advanced.database.postgres_url`)
+//!
+//! `IntegratedProductRepository` 의 핵심 upsert/조회를 Postgres 문법으로 옮긴 것. 변경이 없는 upsert 는
+//! `WHERE ... IS DISTINCT FROM` 으로 건너뛰고, 새 값이 NULL 이면 기존 값을 유지한다(빈 필드 채움).
+//! 슬롯 재배치와 식별 전략(`product_identity`)은 SQLite 전용이라 여기서는 슬롯 충돌이 에러로 드러난다.
+
+use crate::domain::product::{Product, ProductDetail};
+use crate::domain::product_url::canonical_product_url;
+use anyhow::Result;
+use chrono::Utc;
+use sqlx::Row;
+use sqlx::postgres::{PgPool, PgRow};
+use std::sync::Arc;
+
+const PRODUCT_COLUMNS: &[&str] = &[
+    "manufacturer",
+    "model",
+    "certificate_id",
+    "page_id",
+    "index_in_page",
+    "id",
+];
+
+const DETAIL_COLUMNS: &[&str] = &[
+    "page_id",
+    "index_in_page",
+    "id",
+    "manufacturer",
+    "model",
+    "device_type",
+    "certificate_id",
+    "certification_date",
+    "software_version",
+    "hardware_version",
+    "firmware_version",
+    "specification_version",
+    "vid",
+    "pid",
+    "family_sku",
+    "family_variant_sku",
+    "family_id",
+    "tis_trp_tested",
+    "transport_interface",
+    "primary_device_type_id",
+    "application_categories",
+    "description",
+    "compliance_document_url",
+    "program_type",
+];
+
+/// `INSERT ... ON CONFLICT (url) DO UPDATE` that only writes when a non-NULL value differs.
+/// Binds: `$1` url, then `columns` in order, then the timestamp used for both audit fields.
+/// `RETURNING (xmax = 0)` is true for a fresh insert.
+fn upsert_sql(table: &str, columns: &[&str]) -> String {
+    let names = columns.join(", ");
+    let values: Vec<String> = (2..columns.len() + 2).map(|i| format!("${i}")).collect();
+    let now = columns.len() + 2;
+    let merged: Vec<String> = columns
+        .iter()
+        .map(|c| format!("COALESCE(EXCLUDED.{c}, {table}.{c})"))
+        .collect();
+    let current: Vec<String> = columns.iter().map(|c| format!("{table}.{c}")).collect();
+    let assignments: Vec<String> = columns
+        .iter()
+        .zip(&merged)
+        .map(|(c, m)| format!("{c} = {m}"))
+        .collect();
+    format!(
+        "INSERT INTO {table} (url, {names}, created_at, updated_at) \
+         VALUES ($1, {}, ${now}, ${now}) \
+         ON CONFLICT (url) DO UPDATE SET {}, updated_at = EXCLUDED.updated_at \
+         WHERE ({}) IS DISTINCT FROM ({}) \
+         RETURNING (xmax = 0) AS inserted",
+        values.join(", "),
+        assignments.join(", "),
+        current.join(", "),
+        merged.join(", "),
+    )
+}
+
+fn slot_id(page_id: Option<i32>, index_in_page: Option<i32>) -> Option<String> {
+    match (page_id, index_in_page) {
+        (Some(page_id), Some(index)) => Some(format!("p{:04}i{:02}", page_id, index)),
+        _ => None,
+    }
+}
+
+/// `(was_updated, was_created)` like the SQLite repository; no row means nothing changed
+fn upsert_outcome(inserted: Option<bool>) -> (bool, bool) {
+    match inserted {
+        Some(true) => (false, true),
+        Some(false) => (true, false),
+        None => (false, false),
+    }
+}
+
+/// Product repository on a Postgres pool (schema: `migrations/postgres`)
+#[derive(Clone)]
+pub struct PostgresProductRepository {
+    pool: Arc<PgPool>,
+}
+
+impl PostgresProductRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool: Arc::new(pool),
+        }
+    }
+
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    /// Insert or update basic product information from listing page
+    /// Returns: (was_updated: bool, was_created: bool)
+    pub async fn create_or_update_product(&self, product: &Product) -> Result<(bool, bool)> {
+        if product.page_id.is_some_and(|p| p < 0) || product.index_in_page.is_some_and(|i| i < 0) {
+            anyhow::bail!(
+                "Invalid coordinates: page_id={:?}, index_in_page={:?}",
+                product.page_id,
+                product.index_in_page
+            );
+        }
+        let id = product
+            .id
+            .clone()
+            .or_else(|| slot_id(product.page_id, product.index_in_page));
+        let inserted: Option<bool> = sqlx::query_scalar(&upsert_sql("products", PRODUCT_COLUMNS))
+            .bind(canonical_product_url(&product.url))
+            .bind(&product.manufacturer)
+            .bind(&product.model)
+            .bind(&product.certificate_id)
+            .bind(product.page_id)
+            .bind(product.index_in_page)
+            .bind(id)
+            .bind(Utc::now())
+            .fetch_optional(&*self.pool)
+            .await?;
+        Ok(upsert_outcome(inserted))
+    }
+
+    /// Insert or update detailed product specifications
+    /// Returns: (was_updated: bool, was_created: bool)
+    pub async fn create_or_update_product_detail(
+        &self,
+        detail: &ProductDetail,
+    ) -> Result<(bool, bool)> {
+        let id = detail
+            .id
+            .clone()
+            .or_else(|| slot_id(detail.page_id, detail.index_in_page));
+        let inserted: Option<bool> =
+            sqlx::query_scalar(&upsert_sql("product_details", DETAIL_COLUMNS))
+                .bind(canonical_product_url(&detail.url))
+                .bind(detail.page_id)
+                .bind(detail.index_in_page)
+                .bind(id)
+                .bind(&detail.manufacturer)
+                .bind(&detail.model)
+                .bind(&detail.device_type)
+                .bind(&detail.certificate_id)
+                .bind(&detail.certification_date)
+                .bind(&detail.software_version)
+                .bind(&detail.hardware_version)
+                .bind(&detail.firmware_version)
+                .bind(&detail.specification_version)
+                .bind(detail.vid)
+                .bind(detail.pid)
+                .bind(&detail.family_sku)
+                .bind(&detail.family_variant_sku)
+                .bind(&detail.family_id)
+                .bind(&detail.tis_trp_tested)
+                .bind(&detail.transport_interface)
+                .bind(&detail.primary_device_type_id)
+                .bind(&detail.application_categories)
+                .bind(&detail.description)
+                .bind(&detail.compliance_document_url)
+                .bind(&detail.program_type)
+                .bind(Utc::now())
+                .fetch_optional(&*self.pool)
+                .await?;
+        Ok(upsert_outcome(inserted))
+    }
+
+    fn product_from_row(row: &PgRow) -> Product {
+        Product {
+            id: row.get("id"),
+            url: row.get("url"),
+            manufacturer: row.get("manufacturer"),
+            model: row.get("model"),
+            certificate_id: row.get("certificate_id"),
+            page_id: row.get("page_id"),
+            index_in_page: row.get("index_in_page"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+
+    /// Get product by URL
+    pub async fn get_product_by_url(&self, url: &str) -> Result<Option<Product>> {
+        let row = sqlx::query(
+            "SELECT url, id, manufacturer, model, certificate_id, page_id, index_in_page, \
+             created_at, updated_at FROM products WHERE url = $1",
+        )
+        .bind(canonical_product_url(url))
+        .fetch_optional(&*self.pool)
+        .await?;
+        Ok(row.as_ref().map(Self::product_from_row))
+    }
+
+    /// Get product detail by URL
+    pub async fn get_product_detail_by_url(&self, url: &str) -> Result<Option<ProductDetail>> {
+        let row = sqlx::query(&format!(
+            "SELECT url, {}, created_at, updated_at FROM product_details WHERE url = $1",
+            DETAIL_COLUMNS.join(", ")
+        ))
+        .bind(canonical_product_url(url))
+        .fetch_optional(&*self.pool)
+        .await?;
+        Ok(row.map(|row| ProductDetail {
+            url: row.get("url"),
+            page_id: row.get("page_id"),
+            index_in_page: row.get("index_in_page"),
+            id: row.get("id"),
+            manufacturer: row.get("manufacturer"),
+            model: row.get("model"),
+            device_type: row.get("device_type"),
+            certificate_id: row.get("certificate_id"),
+            certification_date: row.get("certification_date"),
+            software_version: row.get("software_version"),
+            hardware_version: row.get("hardware_version"),
+            vid: row.get("vid"),
+            pid: row.get("pid"),
+            family_sku: row.get("family_sku"),
+            family_variant_sku: row.get("family_variant_sku"),
+            firmware_version: row.get("firmware_version"),
+            family_id: row.get("family_id"),
+            tis_trp_tested: row.get("tis_trp_tested"),
+            specification_version: row.get("specification_version"),
+            transport_interface: row.get("transport_interface"),
+            primary_device_type_id: row.get("primary_device_type_id"),
+            application_categories: row.get("application_categories"),
+            description: row.get("description"),
+            compliance_document_url: row.get("compliance_document_url"),
+            program_type: row.get("program_type"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }))
+    }
+
+    /// Get all products with pagination (newest page first)
+    pub async fn get_products_paginated(&self, page: i32, limit: i32) -> Result<Vec<Product>> {
+        let rows = sqlx::query(
+            "SELECT url, id, manufacturer, model, certificate_id, page_id, index_in_page, \
+             created_at, updated_at FROM products \
+             ORDER BY page_id DESC, index_in_page ASC LIMIT $1 OFFSET $2",
+        )
+        .bind(i64::from(limit))
+        .bind(i64::from((page - 1) * limit))
+        .fetch_all(&*self.pool)
+        .await?;
+        Ok(rows.iter().map(Self::product_from_row).collect())
+    }
+
+    /// Get the maximum page_id and index_in_page from the database
+    pub async fn get_max_page_id_and_index(&self) -> Result<(Option<i32>, Option<i32>)> {
+        let row: Option<(Option<i32>, Option<i32>)> = sqlx::query_as(
+            "SELECT page_id, index_in_page FROM products \
+             WHERE page_id IS NOT NULL AND index_in_page IS NOT NULL \
+             ORDER BY page_id DESC, index_in_page DESC LIMIT 1",
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+        Ok(row.unwrap_or((None, None)))
+    }
+
+    /// Get the count of products stored in the database
+    pub async fn get_product_count(&self) -> Result<i32> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM products")
+            .fetch_one(&*self.pool)
+            .await?;
+        Ok(count as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upsert_sql_binds_in_column_order_and_skips_unchanged_rows() {
+        let sql = upsert_sql("products", &["manufacturer", "model"]);
+        assert!(sql.contains("(url, manufacturer, model, created_at, updated_at)"));
+        assert!(sql.contains("VALUES ($1, $2, $3, $4, $4)"));
+        assert!(sql.contains("model = COALESCE(EXCLUDED.model, products.model)"));
+        assert!(sql.contains(
+            "WHERE (products.manufacturer, products.model) IS DISTINCT FROM \
+             (COALESCE(EXCLUDED.manufacturer, products.manufacturer), \
+             COALESCE(EXCLUDED.model, products.model))"
+        ));
+        // Detail binds: url + every column + timestamp
+        let detail = upsert_sql("product_details", DETAIL_COLUMNS);
+        assert!(detail.contains(&format!("${}", DETAIL_COLUMNS.len() + 2)));
+        assert!(!detail.contains(&format!("${}", DETAIL_COLUMNS.len() + 3)));
+        assert_eq!(upsert_outcome(None), (false, false));
+        assert_eq!(upsert_outcome(Some(true)), (false, true));
+    }
+}
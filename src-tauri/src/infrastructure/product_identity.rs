@@ -0,0 +1,419 @@
+//! 제품 식별 전략 (URL / certificate_id)
+//!
+//! `products`/`product_details` 의 테이블 키는 언제나 URL 이지만, 리포지토리 upsert 가 같은 제품을
+//! 찾을 때 어떤 키를 쓸지는 `advanced.product_identity` 로 정한다.
+//! - `url`: URL 이 같으면 같은 제품. 처음 보는 URL 이 다른 행의 certificate_id 를 가져오면 보조 키 충돌.
+//! - `certificate_id`: certificate_id 가 같으면 같은 제품. URL 이 바뀌었으면 기존 행을 새 URL 로
+//!   옮긴 뒤 쓴다. 새 URL 에 이미 다른 행이 있으면 보조 키 충돌.
+//!
+//! 보조 키 충돌은 `IdentityCollisionPolicy`(두 행 유지 / 병합 / 쓰기 건너뜀)로 처리한다. 행을 옮기거나
+//! 병합할 때는 url 컬럼을 가진 부속 테이블(변경 이력, watchlist 등)도 같은 트랜잭션에서 함께 옮긴다.
+
+use crate::infrastructure::config::{
+    IdentityCollisionPolicy, IdentityStrategy, ProductIdentityConfig,
+};
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use sqlx::{SqliteConnection, SqlitePool};
+use std::sync::RwLock;
+use tracing::{debug, info, warn};
+
+static CURRENT: Lazy<RwLock<ProductIdentityConfig>> =
+    Lazy::new(|| RwLock::new(ProductIdentityConfig::default()));
+
+/// Columns a merge never copies: keys, audit timestamps and sync-managed page coordinates
+const MERGE_SKIP_COLUMNS: &[&str] = &[
+    "url",
+    "created_at",
+    "updated_at",
+    "page_id",
+    "index_in_page",
+];
+
+pub fn configure(config: &ProductIdentityConfig) {
+    if let Ok(mut guard) = CURRENT.write() {
+        *guard = *config;
+    }
+}
+
+pub fn current() -> ProductIdentityConfig {
+    CURRENT.read().map(|guard| *guard).unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentityTable {
+    Products,
+    ProductDetails,
+}
+
+impl IdentityTable {
+    /// Details first: deleting a product row cascades to the detail row at the same URL
+    const ORDER: [IdentityTable; 2] = [IdentityTable::ProductDetails, IdentityTable::Products];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            IdentityTable::Products => "products",
+            IdentityTable::ProductDetails => "product_details",
+        }
+    }
+
+    /// `products.id` is derived from the page coordinates
+    fn skips(self, column: &str) -> bool {
+        MERGE_SKIP_COLUMNS.contains(&column) || (self == IdentityTable::Products && column == "id")
+    }
+}
+
+/// What an upsert of `url` does before writing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdentityAction {
+    /// Write to `url` as usual
+    Write,
+    /// The product is stored under `from`: move it to `url`, then write
+    Rekey { from: String },
+    /// The product is stored under `from` and `url` holds another row: fold that row into it,
+    /// move it to `url`, then write
+    MergeRekey { from: String },
+    /// Secondary key collision under the `skip` policy; `holder` keeps the product
+    Skip { holder: String },
+}
+
+/// Decide from whether `url` already has a row and which other URL holds the certificate_id
+pub fn decide(
+    config: ProductIdentityConfig,
+    url_exists: bool,
+    holder: Option<String>,
+) -> IdentityAction {
+    let Some(from) = holder else {
+        return IdentityAction::Write;
+    };
+    match (config.strategy, url_exists) {
+        // Known URL: the URL decides, whoever else carries the certificate
+        (IdentityStrategy::Url, true) => return IdentityAction::Write,
+        // URL changed and the new URL is free: not a collision, the product just moved
+        (IdentityStrategy::CertificateId, false) => return IdentityAction::Rekey { from },
+        _ => {}
+    }
+    match config.on_secondary_collision {
+        IdentityCollisionPolicy::KeepBoth => IdentityAction::Write,
+        IdentityCollisionPolicy::Skip => IdentityAction::Skip { holder: from },
+        IdentityCollisionPolicy::Merge => match config.strategy {
+            // The new URL is free: the certificate row simply takes it
+            IdentityStrategy::Url => IdentityAction::Rekey { from },
+            IdentityStrategy::CertificateId => IdentityAction::MergeRekey { from },
+        },
+    }
+}
+
+fn certificate(certificate_id: Option<&str>) -> Option<&str> {
+    certificate_id.map(str::trim).filter(|c| !c.is_empty())
+}
+
+/// Resolve the identity of an incoming `table` row at (normalized) `url`
+pub async fn resolve(
+    pool: &SqlitePool,
+    table: IdentityTable,
+    url: &str,
+    certificate_id: Option<&str>,
+) -> Result<IdentityAction> {
+    let config = current();
+    // URL identity keeping both rows is the plain upsert; skip the lookups
+    if config == ProductIdentityConfig::default() {
+        return Ok(IdentityAction::Write);
+    }
+    let Some(certificate_id) = certificate(certificate_id) else {
+        return Ok(IdentityAction::Write);
+    };
+    let name = table.name();
+    // Several URLs may share the certificate on an unmigrated DB; the latest one is the product
+    let holder: Option<String> = sqlx::query_scalar(&format!(
+        "SELECT url FROM {name} WHERE certificate_id = ? AND url <> ? \
+         ORDER BY updated_at DESC LIMIT 1"
+    ))
+    .bind(certificate_id)
+    .bind(url)
+    .fetch_optional(pool)
+    .await?;
+    if holder.is_none() {
+        return Ok(IdentityAction::Write);
+    }
+    let url_exists: bool = sqlx::query_scalar(&format!(
+        "SELECT EXISTS(SELECT 1 FROM {name} WHERE url = ?)"
+    ))
+    .bind(url)
+    .fetch_one(pool)
+    .await?;
+    Ok(decide(config, url_exists, holder))
+}
+
+/// Resolve and carry out the identity action for an upsert; `false` when the write is skipped
+pub async fn prepare_write(
+    pool: &SqlitePool,
+    table: IdentityTable,
+    url: &str,
+    certificate_id: Option<&str>,
+) -> Result<bool> {
+    let (from, merge) = match resolve(pool, table, url, certificate_id).await? {
+        IdentityAction::Write => return Ok(true),
+        IdentityAction::Skip { holder } => {
+            debug!(
+                "🪪 {} write skipped: certificate {:?} belongs to {} (url={})",
+                table.name(),
+                certificate_id,
+                holder,
+                url
+            );
+            return Ok(false);
+        }
+        IdentityAction::Rekey { from } => (from, false),
+        IdentityAction::MergeRekey { from } => (from, true),
+    };
+
+    let mut tx = pool.begin().await?;
+    defer_foreign_keys(&mut tx).await?;
+    if merge {
+        // The certificate row wins; the row at `url` only contributes what it lacks
+        fold(&mut tx, url, &from).await?;
+    }
+    rekey(&mut tx, &from, url).await?;
+    tx.commit().await?;
+    info!(
+        "🪪 product identity: {} → {} ({})",
+        from,
+        url,
+        if merge { "merged" } else { "moved" }
+    );
+    refresh_search_index(pool, &[from, url.to_string()]).await;
+    Ok(true)
+}
+
+/// URL moves update `products.url` while detail rows still point at the old URL (and back)
+pub async fn defer_foreign_keys(conn: &mut SqliteConnection) -> Result<()> {
+    sqlx::query("PRAGMA defer_foreign_keys = ON")
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+/// Other tables that refer to a product by URL (history, watchlist, revalidation queue, ...)
+async fn follower_tables(conn: &mut SqliteConnection) -> Result<Vec<String>> {
+    Ok(sqlx::query_scalar(
+        "SELECT m.name FROM sqlite_master m, pragma_table_info(m.name) c \
+         WHERE m.type = 'table' AND c.name = 'url' \
+           AND m.name NOT IN ('products', 'product_details') \
+           AND m.name NOT LIKE 'sqlite_%' \
+           AND COALESCE(m.sql, '') NOT LIKE 'CREATE VIRTUAL%'",
+    )
+    .fetch_all(&mut *conn)
+    .await?)
+}
+
+async fn move_followers(conn: &mut SqliteConnection, from: &str, to: &str) -> Result<()> {
+    for table in follower_tables(conn).await? {
+        // A follower keyed by URL that already has a row for `to` keeps that row
+        sqlx::query(&format!(
+            "UPDATE OR IGNORE \"{table}\" SET url = ? WHERE url = ?"
+        ))
+        .bind(to)
+        .bind(from)
+        .execute(&mut *conn)
+        .await?;
+        sqlx::query(&format!("DELETE FROM \"{table}\" WHERE url = ?"))
+            .bind(from)
+            .execute(&mut *conn)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Move every row stored under `from` to the unused URL `to`
+pub async fn rekey(conn: &mut SqliteConnection, from: &str, to: &str) -> Result<()> {
+    for table in IdentityTable::ORDER {
+        sqlx::query(&format!(
+            "UPDATE {} SET url = ?, updated_at = CURRENT_TIMESTAMP WHERE url = ?",
+            table.name()
+        ))
+        .bind(to)
+        .bind(from)
+        .execute(&mut *conn)
+        .await?;
+    }
+    move_followers(conn, from, to).await
+}
+
+/// Fold the rows stored under `from` into `into`: blank fields of `into` are filled from `from`,
+/// rows `into` does not have are moved over, and `from` is removed
+pub async fn fold(conn: &mut SqliteConnection, from: &str, into: &str) -> Result<()> {
+    for table in IdentityTable::ORDER {
+        let name = table.name();
+        let into_exists: bool = sqlx::query_scalar(&format!(
+            "SELECT EXISTS(SELECT 1 FROM {name} WHERE url = ?)"
+        ))
+        .bind(into)
+        .fetch_one(&mut *conn)
+        .await?;
+        if !into_exists {
+            sqlx::query(&format!("UPDATE {name} SET url = ?1 WHERE url = ?2"))
+                .bind(into)
+                .bind(from)
+                .execute(&mut *conn)
+                .await?;
+            continue;
+        }
+        let columns: Vec<String> = sqlx::query_scalar("SELECT name FROM pragma_table_info(?)")
+            .bind(name)
+            .fetch_all(&mut *conn)
+            .await?;
+        let fills: Vec<String> = columns
+            .iter()
+            .filter(|c| !table.skips(c))
+            .map(|c| {
+                format!(
+                    "\"{c}\" = CASE WHEN NULLIF(TRIM(CAST(\"{c}\" AS TEXT)), '') IS NULL \
+                     THEN (SELECT f.\"{c}\" FROM {name} f WHERE f.url = ?2) ELSE \"{c}\" END"
+                )
+            })
+            .collect();
+        if !fills.is_empty() {
+            sqlx::query(&format!(
+                "UPDATE {name} SET {}, updated_at = CURRENT_TIMESTAMP \
+                 WHERE url = ?1 AND EXISTS(SELECT 1 FROM {name} f WHERE f.url = ?2)",
+                fills.join(", ")
+            ))
+            .bind(into)
+            .bind(from)
+            .execute(&mut *conn)
+            .await?;
+        }
+        sqlx::query(&format!("DELETE FROM {name} WHERE url = ?"))
+            .bind(from)
+            .execute(&mut *conn)
+            .await?;
+    }
+    move_followers(conn, from, into).await
+}
+
+/// Keep the FTS index in step with moved URLs (same hook as dedup merges)
+pub async fn refresh_search_index(pool: &SqlitePool, urls: &[String]) {
+    if !crate::infrastructure::features::feature_search_index_hooks() {
+        return;
+    }
+    if let Err(e) = crate::services::search_index::apply_deltas(pool, urls).await {
+        warn!("🪪 search index delta after identity change failed: {e:#}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::Row;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    fn config(
+        strategy: IdentityStrategy,
+        policy: IdentityCollisionPolicy,
+    ) -> ProductIdentityConfig {
+        ProductIdentityConfig {
+            strategy,
+            on_secondary_collision: policy,
+        }
+    }
+
+    #[test]
+    fn decide_follows_strategy_and_collision_policy() {
+        use IdentityCollisionPolicy::*;
+        use IdentityStrategy::*;
+        let holder = || Some("https://x/old".to_string());
+        let from = "https://x/old".to_string();
+
+        assert_eq!(
+            decide(config(CertificateId, Merge), false, None),
+            IdentityAction::Write
+        );
+        assert_eq!(
+            decide(config(Url, Merge), true, holder()),
+            IdentityAction::Write
+        );
+        assert_eq!(
+            decide(config(Url, KeepBoth), false, holder()),
+            IdentityAction::Write
+        );
+        assert_eq!(
+            decide(config(Url, Merge), false, holder()),
+            IdentityAction::Rekey { from: from.clone() }
+        );
+        assert_eq!(
+            decide(config(CertificateId, KeepBoth), false, holder()),
+            IdentityAction::Rekey { from: from.clone() }
+        );
+        assert_eq!(
+            decide(config(CertificateId, KeepBoth), true, holder()),
+            IdentityAction::Write
+        );
+        assert_eq!(
+            decide(config(CertificateId, Merge), true, holder()),
+            IdentityAction::MergeRekey { from: from.clone() }
+        );
+        assert_eq!(
+            decide(config(CertificateId, Skip), true, holder()),
+            IdentityAction::Skip { holder: from }
+        );
+    }
+
+    #[tokio::test]
+    async fn fold_then_rekey_keeps_certificate_row_values_and_moves_followers() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        for sql in [
+            "PRAGMA foreign_keys = ON",
+            "CREATE TABLE products (url TEXT PRIMARY KEY, id TEXT, model TEXT, certificate_id TEXT, \
+             page_id INTEGER, index_in_page INTEGER, created_at TEXT, updated_at TEXT)",
+            "CREATE TABLE product_details (url TEXT PRIMARY KEY, model TEXT, certificate_id TEXT, \
+             vid INTEGER, page_id INTEGER, index_in_page INTEGER, updated_at TEXT, \
+             FOREIGN KEY (url) REFERENCES products (url) ON DELETE CASCADE)",
+            "CREATE TABLE product_history (id INTEGER PRIMARY KEY, url TEXT, field TEXT)",
+            "INSERT INTO products VALUES ('https://x/old', 'p0001i01', 'Bulb', 'C-1', 1, 1, '', '')",
+            "INSERT INTO product_details VALUES ('https://x/old', 'Bulb', 'C-1', NULL, 1, 1, '')",
+            "INSERT INTO products VALUES ('https://x/new', 'p0000i00', 'Lamp', NULL, 0, 0, '', '')",
+            "INSERT INTO product_details VALUES ('https://x/new', 'Lamp', NULL, 4660, 0, 0, '')",
+            "INSERT INTO product_history (url, field) VALUES ('https://x/old', 'model')",
+        ] {
+            sqlx::query(sql).execute(&pool).await.unwrap();
+        }
+
+        let mut tx = pool.begin().await.unwrap();
+        defer_foreign_keys(&mut tx).await.unwrap();
+        fold(&mut tx, "https://x/new", "https://x/old")
+            .await
+            .unwrap();
+        rekey(&mut tx, "https://x/old", "https://x/new")
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+
+        let row = sqlx::query(
+            "SELECT p.model, p.certificate_id, p.page_id, d.vid FROM products p \
+             JOIN product_details d ON d.url = p.url WHERE p.url = 'https://x/new'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(row.get::<String, _>(0), "Bulb");
+        assert_eq!(row.get::<String, _>(1), "C-1");
+        assert_eq!(row.get::<i64, _>(2), 1);
+        // Blank on the certificate row, filled from the folded row
+        assert_eq!(row.get::<i64, _>(3), 4660);
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM products")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 1);
+        let history_url: String = sqlx::query_scalar("SELECT url FROM product_history")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(history_url, "https://x/new");
+    }
+}
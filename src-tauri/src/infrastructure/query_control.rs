@@ -0,0 +1,281 @@
+//! 조회 커맨드 공통 타임아웃 / 취소 (커맨드 미들웨어)
+//!
+//! 읽기 전용 커맨드도 오래 걸릴 수 있다 (진단 스캔, 큰 검색). `run_query` 로 감싼 커맨드는 선택적
+//! `QueryOptions` 를 받는다: `timeout_ms` 가 지나면 하위 future 를 드롭하고 `ErrorCode::TimedOut`
+//! `CommandError` 를 돌려주며, `query_id` 를 준 조회는 `cancel_query` 로 취소할 수 있다.
+//! future 드롭은 await 지점에서만 효과가 있으므로, await 없이 도는 스캔 루프는
+//! `QueryCancellation::checkpoint` 로 주기적으로 양보·확인해 스스로 빠져나온다.
+
+use crate::domain::command_error::CommandError;
+use crate::domain::error_catalog::ErrorCode;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+/// Longest timeout a caller may request (10 minutes)
+pub const MAX_QUERY_TIMEOUT_MS: u64 = 10 * 60 * 1000;
+
+/// Optional per-call controls accepted by query commands
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct QueryOptions {
+    /// Give up after this many milliseconds (capped at `MAX_QUERY_TIMEOUT_MS`; 0 = none)
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+    /// Caller-chosen id so the query can be stopped with `cancel_query`
+    #[serde(default)]
+    pub query_id: Option<String>,
+}
+
+impl QueryOptions {
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout_ms
+            .filter(|ms| *ms > 0)
+            .map(|ms| Duration::from_millis(ms.min(MAX_QUERY_TIMEOUT_MS)))
+    }
+}
+
+/// Raised by `QueryCancellation::check` once the query was cancelled or timed out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryInterrupted;
+
+impl fmt::Display for QueryInterrupted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&ErrorCode::Cancelled.tag("query interrupted"))
+    }
+}
+
+impl std::error::Error for QueryInterrupted {}
+
+impl From<QueryInterrupted> for String {
+    fn from(e: QueryInterrupted) -> Self {
+        e.to_string()
+    }
+}
+
+impl From<QueryInterrupted> for CommandError {
+    fn from(e: QueryInterrupted) -> Self {
+        CommandError::from(e.to_string())
+    }
+}
+
+/// Cooperative cancellation handle handed to the wrapped query
+#[derive(Debug, Clone, Default)]
+pub struct QueryCancellation {
+    token: CancellationToken,
+}
+
+impl QueryCancellation {
+    /// Handle that is never cancelled (callers outside `run_query`)
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    pub fn check(&self) -> Result<(), QueryInterrupted> {
+        if self.is_cancelled() {
+            Err(QueryInterrupted)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Yield to the runtime (so a pending timeout can fire), then `check`
+    pub async fn checkpoint(&self) -> Result<(), QueryInterrupted> {
+        tokio::task::yield_now().await;
+        self.check()
+    }
+}
+
+/// Query registered under a `query_id`
+#[derive(Debug, Clone, Serialize)]
+pub struct RunningQuery {
+    pub query_id: String,
+    pub command: String,
+    pub started_at: DateTime<Utc>,
+    pub timeout_ms: Option<u64>,
+}
+
+struct Registration {
+    info: RunningQuery,
+    token: CancellationToken,
+}
+
+static RUNNING: Lazy<Mutex<HashMap<String, Registration>>> = Lazy::new(Default::default);
+
+/// Cancels the token and unregisters the query when the command finishes or is dropped
+struct QueryGuard {
+    query_id: Option<String>,
+    token: CancellationToken,
+}
+
+impl Drop for QueryGuard {
+    fn drop(&mut self) {
+        // Stops detached work (spawned tasks, scan loops) tied to this query
+        self.token.cancel();
+        if let Some(id) = &self.query_id {
+            RUNNING.lock().unwrap_or_else(|e| e.into_inner()).remove(id);
+        }
+    }
+}
+
+fn register(
+    command: &str,
+    options: &QueryOptions,
+    token: &CancellationToken,
+) -> Result<QueryGuard, CommandError> {
+    let query_id = options
+        .query_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty());
+    if let Some(id) = query_id {
+        let mut running = RUNNING.lock().unwrap_or_else(|e| e.into_inner());
+        if running.contains_key(id) {
+            return Err(CommandError::invalid_request(format!(
+                "Query id {id} is already running"
+            )));
+        }
+        let info = RunningQuery {
+            query_id: id.to_string(),
+            command: command.to_string(),
+            started_at: Utc::now(),
+            timeout_ms: options.timeout().map(|t| t.as_millis() as u64),
+        };
+        running.insert(
+            id.to_string(),
+            Registration {
+                info,
+                token: token.clone(),
+            },
+        );
+    }
+    Ok(QueryGuard {
+        query_id: query_id.map(str::to_string),
+        token: token.clone(),
+    })
+}
+
+/// Run `query` under the caller's timeout / cancellation options.
+///
+/// Timing out or cancelling drops the query future and cancels the handle it was given;
+/// string errors from the query are classified into `CommandError` as usual.
+pub async fn run_query<T, E, Fut>(
+    command: &str,
+    options: Option<QueryOptions>,
+    query: impl FnOnce(QueryCancellation) -> Fut,
+) -> Result<T, CommandError>
+where
+    Fut: Future<Output = Result<T, E>>,
+    E: Into<CommandError>,
+{
+    let options = options.unwrap_or_default();
+    let cancel = QueryCancellation::default();
+    let _guard = register(command, &options, &cancel.token)?;
+    let limit = options.timeout();
+    let deadline = async {
+        match limit {
+            Some(limit) => tokio::time::sleep(limit).await,
+            None => std::future::pending().await,
+        }
+    };
+    let token = cancel.token.clone();
+    tokio::select! {
+        result = query(cancel) => result.map_err(Into::into),
+        _ = token.cancelled() => Err(CommandError::new(
+            ErrorCode::Cancelled,
+            format!("{command} was cancelled"),
+        )),
+        _ = deadline => Err(CommandError::timed_out(command, limit.unwrap_or_default())),
+    }
+}
+
+/// Cancel the query registered under `query_id`; false when none is running
+pub fn cancel_query(query_id: &str) -> bool {
+    match RUNNING
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(query_id.trim())
+    {
+        Some(registration) => {
+            registration.token.cancel();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Queries registered with a `query_id`, oldest first
+pub fn running_queries() -> Vec<RunningQuery> {
+    let mut queries: Vec<RunningQuery> = RUNNING
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .values()
+        .map(|r| r.info.clone())
+        .collect();
+    queries.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+    queries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(timeout_ms: Option<u64>, query_id: Option<&str>) -> Option<QueryOptions> {
+        Some(QueryOptions {
+            timeout_ms,
+            query_id: query_id.map(str::to_string),
+        })
+    }
+
+    #[tokio::test]
+    async fn times_out_scan_loops_at_checkpoints_and_cancels_by_id() {
+        let ok: Result<u32, CommandError> =
+            run_query("quick", options(Some(1_000), None), |_| async {
+                Ok::<_, String>(7)
+            })
+            .await;
+        assert_eq!(ok.unwrap(), 7);
+
+        // Loop without real awaits: only the checkpoint lets the timeout fire
+        let timed_out = run_query("scan", options(Some(20), None), |cancel| async move {
+            let started = std::time::Instant::now();
+            loop {
+                cancel.checkpoint().await?;
+                if started.elapsed() > Duration::from_secs(5) {
+                    return Ok::<_, QueryInterrupted>(());
+                }
+            }
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(timed_out.code(), ErrorCode::TimedOut);
+
+        let handle = tokio::spawn(run_query("search", options(None, Some("q-1")), |_| async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok::<_, String>(())
+        }));
+        while running_queries().is_empty() {
+            tokio::task::yield_now().await;
+        }
+        assert!(
+            run_query("dup", options(None, Some("q-1")), |_| async {
+                Ok::<_, String>(())
+            })
+            .await
+            .is_err()
+        );
+        assert!(cancel_query("q-1"));
+        let cancelled = handle.await.unwrap().unwrap_err();
+        assert_eq!(cancelled.code(), ErrorCode::Cancelled);
+        assert!(running_queries().is_empty() && !cancel_query("q-1"));
+    }
+}
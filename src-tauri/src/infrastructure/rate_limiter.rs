@@ -0,0 +1,170 @@
+//! Process-wide token-bucket rate limiter with per-host buckets.
+//!
+//! Every outbound fetch path (`HttpClient`, host probes) acquires a token from the same
+//! `HostRateLimiter` instance before issuing a request. Each host gets its own bucket
+//! refilled at `max_requests_per_second` and capped at one second worth of tokens, so a
+//! burst against one host never starves requests to another.
+
+use crate::infrastructure::config::defaults;
+use reqwest::Url;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, trace};
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Token bucket limiter keyed by request host. A rate of 0 disables limiting.
+#[derive(Debug)]
+pub struct HostRateLimiter {
+    rate: AtomicU32,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+static SHARED_RATE_LIMITER: OnceLock<Arc<HostRateLimiter>> = OnceLock::new();
+
+/// Shared limiter used by all HTTP consumers in the process
+pub fn shared_rate_limiter() -> Arc<HostRateLimiter> {
+    SHARED_RATE_LIMITER
+        .get_or_init(|| {
+            info!(
+                "🚀 HostRateLimiter initialized with {} RPS per host (Token Bucket)",
+                defaults::MAX_REQUESTS_PER_SECOND
+            );
+            Arc::new(HostRateLimiter::new(defaults::MAX_REQUESTS_PER_SECOND))
+        })
+        .clone()
+}
+
+impl HostRateLimiter {
+    pub fn new(max_requests_per_second: u32) -> Self {
+        Self {
+            rate: AtomicU32::new(max_requests_per_second),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Current per-host rate (requests per second)
+    pub fn rate(&self) -> u32 {
+        self.rate.load(Ordering::Relaxed)
+    }
+
+    /// Change the per-host rate; existing buckets are clamped to the new capacity.
+    pub fn set_rate(&self, max_requests_per_second: u32) {
+        let previous = self.rate.swap(max_requests_per_second, Ordering::Relaxed);
+        if previous == max_requests_per_second {
+            return;
+        }
+        info!(
+            "🔄 Updated per-host rate limit: {} -> {} RPS",
+            previous, max_requests_per_second
+        );
+        let capacity = max_requests_per_second as f64;
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        for bucket in buckets.values_mut() {
+            bucket.tokens = bucket.tokens.min(capacity);
+        }
+    }
+
+    /// Wait until a token for the URL's host is available
+    pub async fn acquire(&self, url: &str) {
+        let host = host_key(url);
+        let wait = self.reserve(&host, Instant::now());
+        if wait.is_zero() {
+            trace!("🎫 [rate-limit] token acquired immediately (host={})", host);
+            return;
+        }
+        debug!(
+            "🎫 [rate-limit] waiting {:?} for token (host={})",
+            wait, host
+        );
+        tokio::time::sleep(wait).await;
+    }
+
+    /// Take a token for `host` and return how long the caller must wait before using it.
+    /// Tokens may go negative so that concurrent waiters are queued in arrival order.
+    fn reserve(&self, host: &str, now: Instant) -> Duration {
+        let rate = self.rate();
+        if rate == 0 {
+            return Duration::ZERO;
+        }
+        let rate = rate as f64;
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        let bucket = buckets.entry(host.to_string()).or_insert(Bucket {
+            tokens: rate,
+            last_refill: now,
+        });
+        let elapsed = now
+            .saturating_duration_since(bucket.last_refill)
+            .as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rate).min(rate);
+        bucket.last_refill = now;
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / rate)
+        }
+    }
+}
+
+pub(crate) fn host_key(url: &str) -> String {
+    Url::parse(url)
+        .ok()
+        .and_then(|u| {
+            u.host_str().map(|h| match u.port() {
+                Some(port) => format!("{h}:{port}"),
+                None => h.to_string(),
+            })
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_allows_burst_then_spaces_requests() {
+        let limiter = HostRateLimiter::new(4);
+        let now = Instant::now();
+        for _ in 0..4 {
+            assert_eq!(limiter.reserve("a.test", now), Duration::ZERO);
+        }
+        assert_eq!(limiter.reserve("a.test", now), Duration::from_millis(250));
+        assert_eq!(limiter.reserve("a.test", now), Duration::from_millis(500));
+        // After one second the bucket has refilled past the backlog
+        let later = now + Duration::from_secs(1);
+        assert_eq!(limiter.reserve("a.test", later), Duration::ZERO);
+    }
+
+    #[test]
+    fn hosts_have_independent_buckets() {
+        let limiter = HostRateLimiter::new(1);
+        let now = Instant::now();
+        assert_eq!(limiter.reserve("a.test", now), Duration::ZERO);
+        assert!(limiter.reserve("a.test", now) > Duration::ZERO);
+        assert_eq!(limiter.reserve("b.test", now), Duration::ZERO);
+    }
+
+    #[test]
+    fn zero_rate_disables_limiting() {
+        let limiter = HostRateLimiter::new(0);
+        let now = Instant::now();
+        for _ in 0..100 {
+            assert_eq!(limiter.reserve("a.test", now), Duration::ZERO);
+        }
+    }
+
+    #[test]
+    fn host_key_includes_explicit_port() {
+        assert_eq!(host_key("https://csa-iot.org/x?y=1"), "csa-iot.org");
+        assert_eq!(host_key("http://127.0.0.1:8080/slow"), "127.0.0.1:8080");
+        assert_eq!(host_key("not a url"), "");
+    }
+}
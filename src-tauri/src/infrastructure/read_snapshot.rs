@@ -0,0 +1,325 @@
+//! 쓰기 중 UI 그리드용 일관 스냅샷 읽기
+//!
+//! WAL 모드에서 열린 읽기 트랜잭션은 첫 SELECT 시점의 DB 를 계속 본다. 전용 커넥션 하나에 읽기
+//! 트랜잭션을 열어 두고 `data_queries` 의 snapshot 모드 조회가 이를 공유한다.
+//!
+//! 쓰기 경로는 트랜잭션이 아닌 페이지 단위 쓰기(coalescer flush 등) 동안 `begin_page_write` 가드를
+//! 잡고, 트랜잭션으로 페이지를 커밋하는 경로는 커밋 뒤 `mark_page_boundary` 만 호출한다. 둘 다
+//! epoch 을 올린다. 스냅샷은 조회 시 epoch 이 바뀌었을 때(또는 오래됐을 때)만 게이트를 독점해 진행
+//! 중인 페이지 쓰기가 끝나기를 기다린 뒤 다시 열리므로, 그리드는 반쯤 기록된 페이지를 보지 않는다.
+
+use crate::crawl_engine::runtime::task_registry::spawn_tracked;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use sqlx::pool::PoolConnection;
+use sqlx::{Sqlite, SqliteConnection, SqlitePool};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard};
+use tracing::debug;
+
+/// Refresh even without page boundaries so writes outside the page paths (imports, deletes) show up
+const SNAPSHOT_MAX_AGE: Duration = Duration::from_secs(30);
+
+/// Unused snapshots are closed so the open read transaction does not hold back WAL checkpoints
+const SNAPSHOT_IDLE_RELEASE: Duration = Duration::from_secs(30);
+
+static SHARED_READER: std::sync::Mutex<Option<Arc<SnapshotReader>>> = std::sync::Mutex::new(None);
+
+/// Page-write gate and boundary counter shared by writers and snapshot readers
+#[derive(Default)]
+pub struct PageBoundaries {
+    /// Shared by page writers, taken exclusively while a snapshot is (re)opened
+    gate: RwLock<()>,
+    epoch: AtomicU64,
+}
+
+impl PageBoundaries {
+    pub fn global() -> &'static PageBoundaries {
+        static GLOBAL: Lazy<PageBoundaries> = Lazy::new(PageBoundaries::default);
+        &GLOBAL
+    }
+
+    /// Wait for any snapshot refresh in progress, then hold the gate until the page is written
+    pub async fn begin_page_write(&self) -> PageWriteGuard<'_> {
+        PageWriteGuard {
+            _gate: self.gate.read().await,
+            boundaries: self,
+        }
+    }
+
+    /// A page was committed atomically (single transaction); snapshots may move past it
+    pub fn mark(&self) {
+        self.epoch.fetch_add(1, Ordering::Release);
+    }
+
+    fn epoch(&self) -> u64 {
+        self.epoch.load(Ordering::Acquire)
+    }
+}
+
+/// Held for the duration of one page's non-transactional writes; drop marks the page boundary
+pub struct PageWriteGuard<'a> {
+    _gate: RwLockReadGuard<'a, ()>,
+    boundaries: &'a PageBoundaries,
+}
+
+impl Drop for PageWriteGuard<'_> {
+    fn drop(&mut self) {
+        self.boundaries.mark();
+    }
+}
+
+/// `PageBoundaries::global().begin_page_write()`
+pub async fn begin_page_write() -> PageWriteGuard<'static> {
+    PageBoundaries::global().begin_page_write().await
+}
+
+/// `PageBoundaries::global().mark()`
+pub fn mark_page_boundary() {
+    PageBoundaries::global().mark();
+}
+
+struct Snapshot {
+    conn: PoolConnection<Sqlite>,
+    epoch: u64,
+    opened: Instant,
+    taken_at: DateTime<Utc>,
+}
+
+/// One long-lived read transaction shared by snapshot-mode queries
+pub struct SnapshotReader {
+    pool: SqlitePool,
+    boundaries: &'static PageBoundaries,
+    snapshot: Mutex<Option<Snapshot>>,
+    last_used: std::sync::Mutex<Instant>,
+}
+
+/// Process-wide reader (created on first use, together with its idle-release task)
+pub fn shared_snapshot_reader(pool: &SqlitePool) -> Arc<SnapshotReader> {
+    SHARED_READER
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get_or_insert_with(|| {
+            let reader = Arc::new(SnapshotReader::new(pool.clone()));
+            start_idle_release(Arc::downgrade(&reader));
+            reader
+        })
+        .clone()
+}
+
+/// Close the process-wide reader's snapshot and drop it, so the next `shared_snapshot_reader`
+/// call reads the current pool (workspace switch)
+pub async fn reset_shared_snapshot_reader() {
+    let Some(reader) = SHARED_READER
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+    else {
+        return;
+    };
+    if reader.snapshot.lock().await.take().is_some() {
+        debug!("📸 read snapshot released for pool change");
+    }
+}
+
+fn start_idle_release(reader: Weak<SnapshotReader>) {
+    spawn_tracked("read-snapshot-idle-release", None, async move {
+        loop {
+            tokio::time::sleep(SNAPSHOT_IDLE_RELEASE).await;
+            let Some(reader) = reader.upgrade() else {
+                break;
+            };
+            reader.release_if_idle(SNAPSHOT_IDLE_RELEASE);
+        }
+    });
+}
+
+impl SnapshotReader {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self::with_boundaries(pool, PageBoundaries::global())
+    }
+
+    fn with_boundaries(pool: SqlitePool, boundaries: &'static PageBoundaries) -> Self {
+        Self {
+            pool,
+            boundaries,
+            snapshot: Mutex::new(None),
+            last_used: std::sync::Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Exclusive access to the snapshot connection, reopened first when a page boundary passed
+    pub async fn acquire(&self) -> Result<SnapshotGuard<'_>> {
+        *self.last_used.lock().unwrap_or_else(|e| e.into_inner()) = Instant::now();
+        let mut slot = self.snapshot.lock().await;
+        let stale = match slot.as_ref() {
+            Some(s) => s.epoch != self.boundaries.epoch() || s.opened.elapsed() > SNAPSHOT_MAX_AGE,
+            None => true,
+        };
+        if stale {
+            self.reopen(&mut slot).await?;
+        }
+        Ok(SnapshotGuard { slot })
+    }
+
+    async fn reopen(&self, slot: &mut Option<Snapshot>) -> Result<()> {
+        let mut conn = match slot.take() {
+            Some(old) => {
+                let mut conn = old.conn;
+                sqlx::query("ROLLBACK").execute(&mut *conn).await?;
+                conn
+            }
+            None => {
+                let mut conn = self
+                    .pool
+                    .acquire()
+                    .await
+                    .context("snapshot connection unavailable")?;
+                // Never hand a connection with an open read transaction back to the pool
+                conn.close_on_drop();
+                conn
+            }
+        };
+        // Page writers finish before the new read mark is taken
+        let _exclusive = self.boundaries.gate.write().await;
+        let epoch = self.boundaries.epoch();
+        sqlx::query("BEGIN").execute(&mut *conn).await?;
+        // The WAL read mark is taken on the first read, not at BEGIN
+        sqlx::query("SELECT 1 FROM sqlite_master LIMIT 1")
+            .fetch_optional(&mut *conn)
+            .await?;
+        debug!("📸 read snapshot reopened at epoch {}", epoch);
+        *slot = Some(Snapshot {
+            conn,
+            epoch,
+            opened: Instant::now(),
+            taken_at: Utc::now(),
+        });
+        Ok(())
+    }
+
+    fn release_if_idle(&self, idle: Duration) {
+        let last_used = *self.last_used.lock().unwrap_or_else(|e| e.into_inner());
+        if last_used.elapsed() < idle {
+            return;
+        }
+        // Skip when a query is using the snapshot right now
+        let Ok(mut slot) = self.snapshot.try_lock() else {
+            return;
+        };
+        if slot.take().is_some() {
+            debug!("📸 idle read snapshot released");
+        }
+    }
+}
+
+/// Locked snapshot; queries run on `conn()` all see the same database state
+pub struct SnapshotGuard<'a> {
+    slot: MutexGuard<'a, Option<Snapshot>>,
+}
+
+impl SnapshotGuard<'_> {
+    pub fn conn(&mut self) -> &mut SqliteConnection {
+        let snapshot = self.slot.as_mut().expect("snapshot opened by acquire");
+        &mut snapshot.conn
+    }
+
+    /// When the snapshot's read transaction started
+    pub fn taken_at(&self) -> DateTime<Utc> {
+        self.slot.as_ref().map_or_else(Utc::now, |s| s.taken_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
+    use std::str::FromStr;
+
+    async fn wal_pool(dir: &tempfile::TempDir) -> SqlitePool {
+        let options = SqliteConnectOptions::from_str(&format!(
+            "sqlite://{}",
+            dir.path().join("snap.db").display()
+        ))
+        .unwrap()
+        .create_if_missing(true)
+        .journal_mode(SqliteJournalMode::Wal);
+        let pool = SqlitePoolOptions::new()
+            .max_connections(4)
+            .connect_with(options)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE products (url TEXT PRIMARY KEY, page_id INTEGER)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        pool
+    }
+
+    /// Test-local gate so parallel tests never move each other's snapshots
+    fn isolated_reader(pool: &SqlitePool) -> (Arc<SnapshotReader>, &'static PageBoundaries) {
+        let boundaries: &'static PageBoundaries = Box::leak(Box::default());
+        let reader = SnapshotReader::with_boundaries(pool.clone(), boundaries);
+        (Arc::new(reader), boundaries)
+    }
+
+    async fn count(reader: &SnapshotReader) -> i64 {
+        let mut snap = reader.acquire().await.unwrap();
+        sqlx::query_scalar("SELECT COUNT(*) FROM products")
+            .fetch_one(snap.conn())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn snapshot_hides_half_written_pages_until_the_boundary() {
+        let dir = tempfile::tempdir().unwrap();
+        let pool = wal_pool(&dir).await;
+        let (reader, boundaries) = isolated_reader(&pool);
+        assert_eq!(count(&reader).await, 0);
+
+        let guard = boundaries.begin_page_write().await;
+        for i in 0..3 {
+            sqlx::query("INSERT INTO products (url, page_id) VALUES (?, 1)")
+                .bind(format!("https://x/p/{i}"))
+                .execute(&pool)
+                .await
+                .unwrap();
+            // Mid-page: the snapshot still shows the state before the page started
+            assert_eq!(count(&reader).await, 0);
+        }
+        drop(guard);
+        assert_eq!(count(&reader).await, 3);
+    }
+
+    #[tokio::test]
+    async fn refresh_waits_for_in_flight_page_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let pool = wal_pool(&dir).await;
+        let (reader, boundaries) = isolated_reader(&pool);
+        assert_eq!(count(&reader).await, 0);
+
+        let guard = boundaries.begin_page_write().await;
+        sqlx::query("INSERT INTO products (url, page_id) VALUES ('https://x/p/a', 2)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        // Another writer's boundary forces a refresh, which must wait for this page
+        boundaries.mark();
+        let pending = tokio::spawn({
+            let reader = Arc::clone(&reader);
+            async move { count(&reader).await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!pending.is_finished());
+        sqlx::query("INSERT INTO products (url, page_id) VALUES ('https://x/p/b', 2)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        drop(guard);
+        assert_eq!(pending.await.unwrap(), 2);
+    }
+}
@@ -0,0 +1,424 @@
+//! robots.txt 규칙 캐시 (호스트별 TTL) 및 차단/우회 기록
+//!
+//! `HttpClient`는 요청마다 robots.txt를 받아오지 않고, origin(scheme://host[:port])별로
+//! 파싱된 규칙을 `ttl` 동안 재사용한다. 규칙 때문에 건너뛴 요청과 `skip_robots_check`로
+//! 규칙을 무시한 요청은 최근 기록으로 남겨 `get_robots_status` 커맨드로 노출한다.
+
+use crate::infrastructure::config::defaults;
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+/// Number of skip/override records kept for the status view
+const MAX_RECENT_SKIPS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RobotsRule {
+    pub allow: bool,
+    pub path: String,
+}
+
+impl std::fmt::Display for RobotsRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let directive = if self.allow { "Allow" } else { "Disallow" };
+        write!(f, "{}: {}", directive, self.path)
+    }
+}
+
+/// Rules of the group that applies to our user agent
+#[derive(Debug, Clone, Default)]
+pub struct RobotsRules {
+    rules: Vec<RobotsRule>,
+    crawl_delay_secs: Option<f64>,
+}
+
+impl RobotsRules {
+    /// Rules for a host whose robots.txt is missing or unreachable
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    /// Parse robots.txt and keep the most specific group matching `user_agent`
+    /// (falls back to the `*` group).
+    pub fn parse(text: &str, user_agent: &str) -> Self {
+        let ua = user_agent.to_ascii_lowercase();
+        let mut groups: Vec<(Vec<String>, RobotsRules)> = Vec::new();
+        let mut in_agent_lines = false;
+        for raw in text.lines() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let key = key.trim().to_ascii_lowercase();
+            let value = value.trim();
+            match key.as_str() {
+                "user-agent" => {
+                    if !in_agent_lines {
+                        groups.push((Vec::new(), RobotsRules::default()));
+                    }
+                    in_agent_lines = true;
+                    if let Some((agents, _)) = groups.last_mut() {
+                        agents.push(value.to_ascii_lowercase());
+                    }
+                }
+                "allow" | "disallow" => {
+                    in_agent_lines = false;
+                    // An empty Disallow means "allow everything"; it carries no rule
+                    if value.is_empty() {
+                        continue;
+                    }
+                    if let Some((_, rules)) = groups.last_mut() {
+                        rules.rules.push(RobotsRule {
+                            allow: key == "allow",
+                            path: value.to_string(),
+                        });
+                    }
+                }
+                "crawl-delay" => {
+                    in_agent_lines = false;
+                    if let Some((_, rules)) = groups.last_mut() {
+                        rules.crawl_delay_secs = value.parse().ok();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let specific = groups
+            .iter()
+            .filter_map(|(agents, rules)| {
+                agents
+                    .iter()
+                    .filter(|a| a.as_str() != "*" && !a.is_empty() && ua.contains(a.as_str()))
+                    .map(|a| a.len())
+                    .max()
+                    .map(|len| (len, rules))
+            })
+            .max_by_key(|(len, _)| *len)
+            .map(|(_, rules)| rules);
+        specific
+            .or_else(|| {
+                groups
+                    .iter()
+                    .find(|(agents, _)| agents.iter().any(|a| a == "*"))
+                    .map(|(_, rules)| rules)
+            })
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Rule that blocks `path`, if any (longest match wins; Allow wins ties)
+    pub fn blocking_rule(&self, path: &str) -> Option<&RobotsRule> {
+        self.rules
+            .iter()
+            .filter(|r| path_matches(&r.path, path))
+            .max_by_key(|r| (r.path.len(), r.allow))
+            .filter(|r| !r.allow)
+    }
+
+    pub fn rule_count(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn crawl_delay_secs(&self) -> Option<f64> {
+        self.crawl_delay_secs
+    }
+}
+
+/// robots.txt path pattern match with `*` wildcards and a trailing `$` anchor
+fn path_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(p) => (p, true),
+        None => (pattern, false),
+    };
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let Some(mut rest) = path.strip_prefix(parts[0]) else {
+        return false;
+    };
+    if parts.len() == 1 {
+        return !anchored || rest.is_empty();
+    }
+    let last = parts.len() - 1;
+    for (i, part) in parts.iter().enumerate().skip(1) {
+        if anchored && i == last {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+#[derive(Debug)]
+struct CachedRobots {
+    rules: RobotsRules,
+    fetch_ok: bool,
+    fetched_at: DateTime<Utc>,
+    fetched_instant: Instant,
+    blocked: u64,
+    overridden: u64,
+}
+
+/// One request skipped because of robots rules (or sent anyway with `skip_robots_check`)
+#[derive(Debug, Clone, Serialize)]
+pub struct RobotsSkipRecord {
+    pub url: String,
+    pub origin: String,
+    pub rule: String,
+    /// true = the caller bypassed the rule and the request was sent
+    pub overridden: bool,
+    pub at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RobotsHostStatus {
+    pub origin: String,
+    pub fetched_at: DateTime<Utc>,
+    /// Seconds until the cached rules are refetched (0 = stale)
+    pub expires_in_secs: u64,
+    /// false when robots.txt was missing/unreachable and everything is allowed
+    pub fetch_ok: bool,
+    pub rule_count: usize,
+    pub crawl_delay_secs: Option<f64>,
+    pub blocked: u64,
+    pub overridden: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RobotsStatus {
+    /// Whether `respect_robots_txt` is enabled in the current config
+    pub enabled: bool,
+    pub ttl_secs: u64,
+    pub hosts: Vec<RobotsHostStatus>,
+    pub total_blocked: u64,
+    pub total_overridden: u64,
+    /// Newest first
+    pub recent_skips: Vec<RobotsSkipRecord>,
+}
+
+/// Per-origin robots.txt rule cache shared by every `HttpClient`
+#[derive(Debug)]
+pub struct RobotsCache {
+    ttl_secs: AtomicU64,
+    entries: Mutex<HashMap<String, CachedRobots>>,
+    recent_skips: Mutex<VecDeque<RobotsSkipRecord>>,
+    /// Serializes robots.txt fetches so concurrent cold requests fetch once
+    fetch_gate: tokio::sync::Mutex<()>,
+}
+
+static SHARED_ROBOTS_CACHE: OnceLock<Arc<RobotsCache>> = OnceLock::new();
+
+/// Process-wide robots cache
+pub fn shared_robots_cache() -> Arc<RobotsCache> {
+    SHARED_ROBOTS_CACHE
+        .get_or_init(|| Arc::new(RobotsCache::new(defaults::ROBOTS_CACHE_TTL_SECS)))
+        .clone()
+}
+
+impl RobotsCache {
+    pub fn new(ttl_secs: u64) -> Self {
+        Self {
+            ttl_secs: AtomicU64::new(ttl_secs),
+            entries: Mutex::new(HashMap::new()),
+            recent_skips: Mutex::new(VecDeque::new()),
+            fetch_gate: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    pub fn ttl(&self) -> Duration {
+        Duration::from_secs(self.ttl_secs.load(Ordering::Relaxed))
+    }
+
+    pub fn set_ttl(&self, ttl_secs: u64) {
+        let previous = self.ttl_secs.swap(ttl_secs, Ordering::Relaxed);
+        if previous != ttl_secs {
+            info!("🤖 robots.txt cache TTL: {}s -> {}s", previous, ttl_secs);
+        }
+    }
+
+    /// Cached rules for `origin` if still fresh
+    pub fn fresh_rules(&self, origin: &str) -> Option<RobotsRules> {
+        let ttl = self.ttl();
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries
+            .get(origin)
+            .filter(|c| c.fetched_instant.elapsed() < ttl)
+            .map(|c| c.rules.clone())
+    }
+
+    /// Store freshly fetched rules; block/override counters survive refreshes
+    pub fn store(&self, origin: &str, rules: RobotsRules, fetch_ok: bool) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = entries
+            .entry(origin.to_string())
+            .or_insert_with(|| CachedRobots {
+                rules: RobotsRules::default(),
+                fetch_ok,
+                fetched_at: Utc::now(),
+                fetched_instant: Instant::now(),
+                blocked: 0,
+                overridden: 0,
+            });
+        entry.rules = rules;
+        entry.fetch_ok = fetch_ok;
+        entry.fetched_at = Utc::now();
+        entry.fetched_instant = Instant::now();
+    }
+
+    /// Hold while fetching robots.txt (re-check `fresh_rules` after acquiring)
+    pub async fn lock_fetch(&self) -> tokio::sync::MutexGuard<'_, ()> {
+        self.fetch_gate.lock().await
+    }
+
+    /// Record a request that was not sent (`overridden = false`) or sent despite a
+    /// blocking rule because the caller asked to skip robots checks (`overridden = true`)
+    pub fn record_skip(&self, url: &str, origin: &str, rule: &RobotsRule, overridden: bool) {
+        {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(entry) = entries.get_mut(origin) {
+                if overridden {
+                    entry.overridden += 1;
+                } else {
+                    entry.blocked += 1;
+                }
+            }
+        }
+        if overridden {
+            warn!("🤖 robots.txt rule '{}' overridden for {}", rule, url);
+        } else {
+            warn!("🤖 robots.txt rule '{}' blocks {}", rule, url);
+        }
+        let mut recent = self.recent_skips.lock().unwrap_or_else(|e| e.into_inner());
+        if recent.len() == MAX_RECENT_SKIPS {
+            recent.pop_back();
+        }
+        recent.push_front(RobotsSkipRecord {
+            url: url.to_string(),
+            origin: origin.to_string(),
+            rule: rule.to_string(),
+            overridden,
+            at: Utc::now(),
+        });
+    }
+
+    pub fn status(&self, enabled: bool) -> RobotsStatus {
+        let ttl = self.ttl();
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let mut hosts: Vec<RobotsHostStatus> = entries
+            .iter()
+            .map(|(origin, c)| RobotsHostStatus {
+                origin: origin.clone(),
+                fetched_at: c.fetched_at,
+                expires_in_secs: ttl.saturating_sub(c.fetched_instant.elapsed()).as_secs(),
+                fetch_ok: c.fetch_ok,
+                rule_count: c.rules.rule_count(),
+                crawl_delay_secs: c.rules.crawl_delay_secs(),
+                blocked: c.blocked,
+                overridden: c.overridden,
+            })
+            .collect();
+        hosts.sort_by(|a, b| a.origin.cmp(&b.origin));
+        let recent_skips: Vec<RobotsSkipRecord> = self
+            .recent_skips
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .cloned()
+            .collect();
+        RobotsStatus {
+            enabled,
+            ttl_secs: ttl.as_secs(),
+            total_blocked: hosts.iter().map(|h| h.blocked).sum(),
+            total_overridden: hosts.iter().map(|h| h.overridden).sum(),
+            hosts,
+            recent_skips,
+        }
+    }
+}
+
+/// Cache key and robots.txt location for a target URL
+pub fn robots_origin(url: &Url) -> String {
+    match url.port() {
+        Some(port) => format!(
+            "{}://{}:{}",
+            url.scheme(),
+            url.host_str().unwrap_or(""),
+            port
+        ),
+        None => format!("{}://{}", url.scheme(), url.host_str().unwrap_or("")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROBOTS: &str = "\
+User-agent: *
+Disallow: /wp-admin/
+Allow: /wp-admin/admin-ajax.php
+Disallow: /*?s=
+Crawl-delay: 2
+
+User-agent: matter-certis
+User-agent: otherbot
+Disallow: /private
+";
+
+    #[test]
+    fn wildcard_group_with_longest_match() {
+        let rules = RobotsRules::parse(ROBOTS, "Mozilla/5.0 (compatible)");
+        assert!(rules.blocking_rule("/wp-admin/options.php").is_some());
+        assert!(rules.blocking_rule("/wp-admin/admin-ajax.php").is_none());
+        assert!(rules.blocking_rule("/csa_product/?s=matter").is_some());
+        assert!(rules.blocking_rule("/csa-iot_products/page/2/").is_none());
+        assert_eq!(rules.crawl_delay_secs(), Some(2.0));
+    }
+
+    #[test]
+    fn specific_agent_group_wins() {
+        let rules = RobotsRules::parse(ROBOTS, "matter-certis-v2/1.0 (Research Tool)");
+        assert_eq!(rules.rule_count(), 1);
+        assert!(rules.blocking_rule("/private/x").is_some());
+        assert!(rules.blocking_rule("/wp-admin/").is_none());
+    }
+
+    #[test]
+    fn end_anchor_and_empty_disallow() {
+        assert!(path_matches("/*.pdf$", "/docs/cert.pdf"));
+        assert!(!path_matches("/*.pdf$", "/docs/cert.pdf?x=1"));
+        assert!(path_matches("/*.pdf$", "/a.pdf.pdf"));
+        assert!(path_matches("/exact$", "/exact"));
+        assert!(!path_matches("/exact$", "/exact/more"));
+        let rules = RobotsRules::parse("User-agent: *\nDisallow:\n", "any");
+        assert!(rules.blocking_rule("/anything").is_none());
+    }
+
+    #[test]
+    fn cache_expires_and_counts_skips() {
+        let cache = RobotsCache::new(60);
+        let rules = RobotsRules::parse(ROBOTS, "bot");
+        cache.store("https://a.test", rules.clone(), true);
+        assert!(cache.fresh_rules("https://a.test").is_some());
+        assert!(cache.fresh_rules("https://b.test").is_none());
+
+        let rule = rules.blocking_rule("/wp-admin/x").unwrap().clone();
+        cache.record_skip("https://a.test/wp-admin/x", "https://a.test", &rule, false);
+        cache.record_skip("https://a.test/wp-admin/y", "https://a.test", &rule, true);
+        let status = cache.status(true);
+        assert_eq!(status.total_blocked, 1);
+        assert_eq!(status.total_overridden, 1);
+        assert_eq!(status.recent_skips[0].url, "https://a.test/wp-admin/y");
+        assert_eq!(status.recent_skips[1].rule, "Disallow: /wp-admin/");
+
+        cache.set_ttl(0);
+        assert!(cache.fresh_rules("https://a.test").is_none());
+    }
+}
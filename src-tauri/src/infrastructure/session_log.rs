@@ -0,0 +1,474 @@
+//! 세션 단위 구조화 로그 수집
+//!
+//! 실패한 세션을 디버깅하려고 전역 로그 파일을 grep 하지 않도록, `session_id` 가 붙은 tracing
+//! 이벤트를 `logs/sessions/<session_id>.jsonl` 에 한 줄씩 따로 기록한다. 세션은 이벤트 필드
+//! (`session_id = %id`), 상위 span 필드, 또는 기존 로그 문구의 `session_id=` / `session=` 토큰으로
+//! 식별한다. 파일은 크기 기준으로 회전하고, 보관 세션 수를 넘으면 가장 오래된 세션부터 지운다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError, SyncSender};
+use std::time::{Duration, SystemTime};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Rotated files kept per session (`<id>.1.jsonl` is the newest rotated one)
+const ROTATED_FILES_KEPT: u32 = 2;
+/// Writer handles kept open at once; older sessions are reopened on demand
+const MAX_OPEN_FILES: usize = 16;
+const FLUSH_INTERVAL: Duration = Duration::from_millis(200);
+/// Records buffered for the writer thread; events beyond this are dropped, never blocked on
+const CHANNEL_CAPACITY: usize = 4096;
+
+/// One captured tracing event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionLogEntry {
+    pub timestamp: DateTime<Utc>,
+    /// `ERROR` / `WARN` / `INFO` / `DEBUG` / `TRACE`
+    pub level: String,
+    pub target: String,
+    pub message: String,
+    /// Structured event fields other than `message` / `session_id`
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Page returned by `get_session_logs`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionLogPage {
+    pub session_id: String,
+    /// Entries matching the level filter across all rotated files
+    pub total: usize,
+    pub offset: usize,
+    pub entries: Vec<SessionLogEntry>,
+}
+
+/// `logs/sessions`
+pub fn session_log_directory() -> PathBuf {
+    super::logging::get_log_directory().join("sessions")
+}
+
+/// File stem for a session id: anything outside `[A-Za-z0-9_-]` becomes `_`
+fn file_key(session_id: &str) -> String {
+    session_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn session_file(dir: &Path, key: &str, rotation: u32) -> PathBuf {
+    if rotation == 0 {
+        dir.join(format!("{key}.jsonl"))
+    } else {
+        dir.join(format!("{key}.{rotation}.jsonl"))
+    }
+}
+
+/// `session_id=abc` / `session=abc` inside a free-form message
+fn session_from_message(message: &str) -> Option<String> {
+    for marker in ["session_id=", "session="] {
+        let mut rest = message;
+        while let Some(pos) = rest.find(marker) {
+            // Skip matches inside longer keys such as `sub_session=`
+            let preceded_by_word = rest[..pos]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_');
+            let value: String = rest[pos + marker.len()..]
+                .chars()
+                .take_while(|c| !c.is_whitespace() && !matches!(c, ',' | ';' | ')' | ']'))
+                .collect();
+            let value = value.trim_matches(|c| c == '"' || c == '\'');
+            if !preceded_by_word && !value.is_empty() && value != "None" {
+                return Some(value.to_string());
+            }
+            rest = &rest[pos + marker.len()..];
+        }
+    }
+    None
+}
+
+#[derive(Default)]
+struct FieldCollector {
+    session_id: Option<String>,
+    message: String,
+    fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl FieldCollector {
+    fn put(&mut self, field: &Field, value: serde_json::Value, text: impl FnOnce() -> String) {
+        match field.name() {
+            "session_id" => self.session_id = Some(text()),
+            "message" => self.message = text(),
+            name => {
+                self.fields.insert(name.to_string(), value);
+            }
+        }
+    }
+}
+
+impl Visit for FieldCollector {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.put(field, value.into(), || value.to_string());
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.put(field, value.into(), || value.to_string());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.put(field, value.into(), || value.to_string());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.put(field, value.into(), || value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let text = format!("{value:?}");
+        self.put(field, text.clone().into(), || text);
+    }
+}
+
+/// Session id recorded on a span, inherited by events inside it
+struct SpanSession(String);
+
+/// tracing layer forwarding session-tagged events to the writer thread
+pub struct SessionLogLayer {
+    tx: SyncSender<(String, SessionLogEntry)>,
+}
+
+impl SessionLogLayer {
+    /// Start the writer thread. `max_file_bytes` triggers rotation; `max_sessions` caps the
+    /// number of sessions kept on disk.
+    pub fn spawn(dir: PathBuf, max_file_bytes: u64, max_sessions: usize) -> io::Result<Self> {
+        fs::create_dir_all(&dir)?;
+        let (tx, rx) = mpsc::sync_channel::<(String, SessionLogEntry)>(CHANNEL_CAPACITY);
+        std::thread::Builder::new()
+            .name("session-log-writer".into())
+            .spawn(move || {
+                let mut writer = SessionLogWriter::new(dir, max_file_bytes, max_sessions);
+                let mut dirty = false;
+                loop {
+                    match rx.recv_timeout(FLUSH_INTERVAL) {
+                        Ok((session_id, entry)) => {
+                            // Logging here would recurse into this layer
+                            let _ = writer.append(&session_id, &entry);
+                            dirty = true;
+                        }
+                        Err(RecvTimeoutError::Timeout) => {
+                            if dirty {
+                                writer.flush_all();
+                                dirty = false;
+                            }
+                        }
+                        Err(RecvTimeoutError::Disconnected) => {
+                            writer.flush_all();
+                            break;
+                        }
+                    }
+                }
+            })?;
+        Ok(Self { tx })
+    }
+}
+
+impl<S> Layer<S> for SessionLogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut collector = FieldCollector::default();
+        attrs.record(&mut collector);
+        if let (Some(session_id), Some(span)) = (collector.session_id, ctx.span(id)) {
+            span.extensions_mut().insert(SpanSession(session_id));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut collector = FieldCollector::default();
+        event.record(&mut collector);
+        let session_id = collector
+            .session_id
+            .take()
+            .or_else(|| {
+                ctx.event_scope(event)?
+                    .find_map(|span| span.extensions().get::<SpanSession>().map(|s| s.0.clone()))
+            })
+            .or_else(|| session_from_message(&collector.message));
+        let Some(session_id) = session_id else {
+            return;
+        };
+        let meta = event.metadata();
+        let entry = SessionLogEntry {
+            timestamp: Utc::now(),
+            level: meta.level().as_str().to_string(),
+            target: meta.target().to_string(),
+            message: collector.message,
+            fields: collector.fields,
+        };
+        let _ = self.tx.try_send((session_id, entry));
+    }
+}
+
+struct OpenSessionFile {
+    writer: BufWriter<File>,
+    bytes: u64,
+}
+
+struct SessionLogWriter {
+    dir: PathBuf,
+    max_file_bytes: u64,
+    max_sessions: usize,
+    open: HashMap<String, OpenSessionFile>,
+}
+
+impl SessionLogWriter {
+    fn new(dir: PathBuf, max_file_bytes: u64, max_sessions: usize) -> Self {
+        Self {
+            dir,
+            max_file_bytes: max_file_bytes.max(1),
+            max_sessions: max_sessions.max(1),
+            open: HashMap::new(),
+        }
+    }
+
+    fn append(&mut self, session_id: &str, entry: &SessionLogEntry) -> io::Result<()> {
+        let key = file_key(session_id);
+        let mut line = serde_json::to_vec(entry).map_err(io::Error::other)?;
+        line.push(b'\n');
+        if !self.open.contains_key(&key) {
+            self.open_file(&key)?;
+        }
+        let needs_rotation = self
+            .open
+            .get(&key)
+            .is_some_and(|f| f.bytes > 0 && f.bytes + line.len() as u64 > self.max_file_bytes);
+        if needs_rotation {
+            if let Some(mut file) = self.open.remove(&key) {
+                file.writer.flush()?;
+            }
+            self.rotate(&key)?;
+            self.open_file(&key)?;
+        }
+        let file = self
+            .open
+            .get_mut(&key)
+            .ok_or_else(|| io::Error::other("session log file not open"))?;
+        file.writer.write_all(&line)?;
+        file.bytes += line.len() as u64;
+        Ok(())
+    }
+
+    fn open_file(&mut self, key: &str) -> io::Result<()> {
+        if self.open.len() >= MAX_OPEN_FILES {
+            self.flush_all();
+            self.open.clear();
+        }
+        let path = session_file(&self.dir, key, 0);
+        let is_new = !path.exists();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let bytes = file.metadata().map(|m| m.len()).unwrap_or(0);
+        self.open.insert(
+            key.to_string(),
+            OpenSessionFile {
+                writer: BufWriter::new(file),
+                bytes,
+            },
+        );
+        if is_new {
+            self.prune(key);
+        }
+        Ok(())
+    }
+
+    /// `<id>.jsonl` → `<id>.1.jsonl` → … ; the oldest rotation falls off
+    fn rotate(&self, key: &str) -> io::Result<()> {
+        let _ = fs::remove_file(session_file(&self.dir, key, ROTATED_FILES_KEPT));
+        for n in (1..ROTATED_FILES_KEPT).rev() {
+            let from = session_file(&self.dir, key, n);
+            if from.exists() {
+                fs::rename(from, session_file(&self.dir, key, n + 1))?;
+            }
+        }
+        fs::rename(
+            session_file(&self.dir, key, 0),
+            session_file(&self.dir, key, 1),
+        )
+    }
+
+    /// Delete the least recently written sessions beyond `max_sessions` (never `keep`)
+    fn prune(&mut self, keep: &str) {
+        let Ok(read_dir) = fs::read_dir(&self.dir) else {
+            return;
+        };
+        let mut latest: HashMap<String, SystemTime> = HashMap::new();
+        let mut files: Vec<(String, PathBuf)> = Vec::new();
+        for entry in read_dir.flatten() {
+            let path = entry.path();
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if !name.ends_with(".jsonl") {
+                continue;
+            }
+            let key = name.split('.').next().unwrap_or_default().to_string();
+            let modified = entry
+                .metadata()
+                .and_then(|m| m.modified())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            let slot = latest.entry(key.clone()).or_insert(modified);
+            if modified > *slot {
+                *slot = modified;
+            }
+            files.push((key, path));
+        }
+        if latest.len() <= self.max_sessions {
+            return;
+        }
+        let mut sessions: Vec<(String, SystemTime)> = latest.into_iter().collect();
+        sessions.sort_by(|a, b| b.1.cmp(&a.1));
+        let doomed: Vec<String> = sessions
+            .into_iter()
+            .filter(|(key, _)| key != keep)
+            .skip(self.max_sessions.saturating_sub(1))
+            .map(|(key, _)| key)
+            .collect();
+        for key in &doomed {
+            self.open.remove(key);
+        }
+        for (key, path) in files {
+            if doomed.contains(&key) {
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+
+    fn flush_all(&mut self) {
+        for file in self.open.values_mut() {
+            let _ = file.writer.flush();
+        }
+    }
+}
+
+/// Read one session's entries oldest first, keeping those at `min_level` or more severe.
+/// A session without log files yields an empty page.
+pub fn read_session_logs(
+    dir: &Path,
+    session_id: &str,
+    min_level: Option<Level>,
+    offset: usize,
+    limit: usize,
+) -> io::Result<SessionLogPage> {
+    let key = file_key(session_id);
+    let mut total = 0usize;
+    let mut entries = Vec::new();
+    for rotation in (0..=ROTATED_FILES_KEPT).rev() {
+        let path = session_file(dir, &key, rotation);
+        let file = match File::open(&path) {
+            Ok(f) => f,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            // A torn last line (writer mid-flush) is skipped rather than failing the read
+            let Ok(entry) = serde_json::from_str::<SessionLogEntry>(&line) else {
+                continue;
+            };
+            if let Some(min) = min_level {
+                // tracing orders levels by verbosity: ERROR < WARN < … < TRACE
+                match Level::from_str(&entry.level) {
+                    Ok(level) if level <= min => {}
+                    _ => continue,
+                }
+            }
+            if total >= offset && entries.len() < limit {
+                entries.push(entry);
+            }
+            total += 1;
+        }
+    }
+    Ok(SessionLogPage {
+        session_id: session_id.to_string(),
+        total,
+        offset,
+        entries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(level: &str, message: &str) -> SessionLogEntry {
+        SessionLogEntry {
+            timestamp: Utc::now(),
+            level: level.to_string(),
+            target: "test".to_string(),
+            message: message.to_string(),
+            fields: serde_json::Map::new(),
+        }
+    }
+
+    #[test]
+    fn finds_session_tokens_in_messages() {
+        assert_eq!(
+            session_from_message("Sync aborted: session_id=sync-20250101 pages=3").as_deref(),
+            Some("sync-20250101")
+        );
+        assert_eq!(
+            session_from_message("batch done (session=abc-1, batch=2)").as_deref(),
+            Some("abc-1")
+        );
+        assert_eq!(session_from_message("sub_session=x only"), None);
+        assert_eq!(session_from_message("no session here"), None);
+    }
+
+    #[test]
+    fn rotates_prunes_and_pages_with_level_filter() {
+        let dir = tempfile::tempdir().unwrap();
+        // Small files force a rotation every few lines
+        let mut writer = SessionLogWriter::new(dir.path().to_path_buf(), 400, 2);
+        for i in 0..6 {
+            let level = if i % 2 == 0 { "WARN" } else { "DEBUG" };
+            writer
+                .append("sync/1", &entry(level, &format!("line {i}")))
+                .unwrap();
+        }
+        writer.flush_all();
+        assert!(session_file(dir.path(), "sync_1", 1).exists());
+
+        let all = read_session_logs(dir.path(), "sync/1", None, 0, 100).unwrap();
+        assert!(all.total <= 6 && all.total >= 2);
+        assert_eq!(all.entries.last().unwrap().message, "line 5");
+
+        let warn = read_session_logs(dir.path(), "sync/1", Some(Level::INFO), 1, 1).unwrap();
+        assert!(warn.entries.iter().all(|e| e.level == "WARN"));
+        assert_eq!(warn.entries.len(), 1);
+
+        std::thread::sleep(Duration::from_millis(20));
+        writer.append("b", &entry("INFO", "b")).unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        writer.append("c", &entry("INFO", "c")).unwrap();
+        writer.flush_all();
+        assert!(!session_file(dir.path(), "sync_1", 0).exists());
+        let empty = read_session_logs(dir.path(), "sync/1", None, 0, 10).unwrap();
+        assert_eq!(empty.total, 0);
+    }
+}
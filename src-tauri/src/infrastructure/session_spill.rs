@@ -0,0 +1,361 @@
+//! 세션별 임시 저장소 (대용량 페이로드 디스크 스필) 쿼터
+//!
+//! 큰 페이로드를 메모리 대신 `<app data>/cache/spill/<session_id>/` 에 내려 쓸 때 세션마다 사용량을
+//! 계산하고, 세션 쿼터를 넘는 스필은 쓰기 전에 거부한다. 세션이 끝나면 디렉터리를 통째로 지우고,
+//! 앱 시작 시에는 이전 프로세스(크래시 포함)가 남긴 세션 디렉터리를 정리한다.
+//! 현재 사용량은 `get_system_status` 의 `temp_storage` 로 노출된다.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use tracing::{debug, warn};
+use ts_rs::TS;
+
+/// Temp bytes one session may hold at once (256 MiB)
+pub const DEFAULT_SESSION_SPILL_QUOTA_BYTES: u64 = 256 * 1024 * 1024;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SpillError {
+    #[error(
+        "session {session_id} temp storage quota exceeded: {used} + {requested} > {quota} bytes"
+    )]
+    QuotaExceeded {
+        session_id: String,
+        used: u64,
+        requested: u64,
+        quota: u64,
+    },
+    #[error("spill I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// One spilled payload; pass back to `read` / `release`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpillHandle {
+    session_id: String,
+    path: PathBuf,
+    bytes: u64,
+}
+
+impl SpillHandle {
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// File name inside the session directory (e.g. `000042.bin`)
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct SessionSpillUsage {
+    pub session_id: String,
+    pub files: u32,
+    #[ts(type = "number")]
+    pub bytes: u64,
+}
+
+/// Temp storage snapshot for system health
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct TempStorageUsage {
+    pub directory: String,
+    #[ts(type = "number")]
+    pub quota_bytes_per_session: u64,
+    #[ts(type = "number")]
+    pub total_bytes: u64,
+    /// Spills refused because the session was at its quota (since startup)
+    #[ts(type = "number")]
+    pub rejected_spills: u64,
+    pub sessions: Vec<SessionSpillUsage>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpillSweep {
+    pub removed_sessions: u32,
+    pub freed_bytes: u64,
+}
+
+#[derive(Debug, Default)]
+struct SessionAccount {
+    bytes: u64,
+    files: u32,
+    next_seq: u64,
+}
+
+/// Quota-accounted spill directories, one per session
+#[derive(Debug)]
+pub struct SpillStore {
+    dir: PathBuf,
+    quota_bytes: u64,
+    accounts: Mutex<HashMap<String, SessionAccount>>,
+    rejected: AtomicU64,
+}
+
+static SHARED_SPILL_STORE: OnceLock<Arc<SpillStore>> = OnceLock::new();
+
+/// Process-wide store under `<app data>/cache/spill`
+pub fn shared_spill_store() -> Arc<SpillStore> {
+    SHARED_SPILL_STORE
+        .get_or_init(|| {
+            let base = crate::infrastructure::config::ConfigManager::get_app_data_dir()
+                .unwrap_or_else(|_| std::env::temp_dir().join("matter-certis-v2"));
+            Arc::new(SpillStore::new(
+                base.join("cache").join("spill"),
+                DEFAULT_SESSION_SPILL_QUOTA_BYTES,
+            ))
+        })
+        .clone()
+}
+
+/// (files, bytes) directly under `dir`
+fn dir_usage(dir: &Path) -> (u32, u64) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return (0, 0);
+    };
+    let mut files = 0;
+    let mut bytes = 0;
+    for entry in entries.flatten() {
+        files += 1;
+        bytes += entry.metadata().map(|m| m.len()).unwrap_or(0);
+    }
+    (files, bytes)
+}
+
+impl SpillStore {
+    pub fn new(dir: PathBuf, quota_bytes: u64) -> Self {
+        Self {
+            dir,
+            quota_bytes,
+            accounts: Mutex::new(HashMap::new()),
+            rejected: AtomicU64::new(0),
+        }
+    }
+
+    fn accounts(&self) -> std::sync::MutexGuard<'_, HashMap<String, SessionAccount>> {
+        self.accounts.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn session_dir(&self, session_id: &str) -> PathBuf {
+        let name: String = session_id
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        self.dir.join(name)
+    }
+
+    /// Write `payload` to the session's temp directory, refusing it if the quota would be exceeded.
+    /// Bytes are reserved before writing so concurrent spills cannot overshoot the quota together.
+    pub async fn spill(&self, session_id: &str, payload: &[u8]) -> Result<SpillHandle, SpillError> {
+        let requested = payload.len() as u64;
+        let seq = {
+            let mut accounts = self.accounts();
+            let account = accounts.entry(session_id.to_string()).or_default();
+            if account.bytes + requested > self.quota_bytes {
+                self.rejected.fetch_add(1, Ordering::Relaxed);
+                return Err(SpillError::QuotaExceeded {
+                    session_id: session_id.to_string(),
+                    used: account.bytes,
+                    requested,
+                    quota: self.quota_bytes,
+                });
+            }
+            account.bytes += requested;
+            account.files += 1;
+            account.next_seq += 1;
+            account.next_seq
+        };
+
+        let dir = self.session_dir(session_id);
+        let path = dir.join(format!("{seq:06}.bin"));
+        let written = async {
+            tokio::fs::create_dir_all(&dir).await?;
+            tokio::fs::write(&path, payload).await
+        };
+        if let Err(e) = written.await {
+            self.unaccount(session_id, requested);
+            let _ = tokio::fs::remove_file(&path).await;
+            return Err(e.into());
+        }
+        debug!(
+            "[Spill] {}: {} bytes -> {}",
+            session_id,
+            requested,
+            path.display()
+        );
+        Ok(SpillHandle {
+            session_id: session_id.to_string(),
+            path,
+            bytes: requested,
+        })
+    }
+
+    /// Handle of a payload `session_id` spilled earlier, from its `file_name`; `None` for names
+    /// `spill` never produces (so a stored name cannot point outside the session directory)
+    pub fn handle(&self, session_id: &str, file_name: &str, bytes: u64) -> Option<SpillHandle> {
+        let seq = file_name.strip_suffix(".bin")?;
+        if seq.is_empty() || !seq.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        Some(SpillHandle {
+            session_id: session_id.to_string(),
+            path: self.session_dir(session_id).join(file_name),
+            bytes,
+        })
+    }
+
+    pub async fn read(&self, handle: &SpillHandle) -> Result<Vec<u8>, SpillError> {
+        Ok(tokio::fs::read(&handle.path).await?)
+    }
+
+    /// Delete a spilled payload and return its bytes to the session's quota (once; a handle
+    /// whose file is already gone was released before)
+    pub async fn release(&self, handle: SpillHandle) -> Result<(), SpillError> {
+        match tokio::fs::remove_file(&handle.path).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        }
+        self.unaccount(&handle.session_id, handle.bytes);
+        Ok(())
+    }
+
+    fn unaccount(&self, session_id: &str, bytes: u64) {
+        if let Some(account) = self.accounts().get_mut(session_id) {
+            account.bytes = account.bytes.saturating_sub(bytes);
+            account.files = account.files.saturating_sub(1);
+        }
+    }
+
+    /// Drop everything the session spilled; returns the bytes freed on disk
+    pub async fn end_session(&self, session_id: &str) -> u64 {
+        self.accounts().remove(session_id);
+        let dir = self.session_dir(session_id);
+        let removal = tokio::task::spawn_blocking(move || {
+            let (_, bytes) = dir_usage(&dir);
+            match std::fs::remove_dir_all(&dir) {
+                Ok(()) => bytes,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => 0,
+                Err(e) => {
+                    warn!("⚠️ [Spill] failed to remove {}: {}", dir.display(), e);
+                    0
+                }
+            }
+        });
+        match removal.await {
+            Ok(bytes) => {
+                if bytes > 0 {
+                    debug!("[Spill] {}: released {} temp bytes", session_id, bytes);
+                }
+                bytes
+            }
+            Err(e) => {
+                warn!("⚠️ [Spill] {}: temp cleanup task failed: {}", session_id, e);
+                0
+            }
+        }
+    }
+
+    /// Remove session directories no live session accounts for (left by a crashed process)
+    pub async fn sweep_stale(&self) -> std::io::Result<SpillSweep> {
+        let dir = self.dir.clone();
+        let live: Vec<PathBuf> = self
+            .accounts()
+            .keys()
+            .map(|id| self.session_dir(id))
+            .collect();
+        tokio::task::spawn_blocking(move || {
+            let entries = match std::fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    return Ok(SpillSweep::default());
+                }
+                Err(e) => return Err(e),
+            };
+            let mut sweep = SpillSweep::default();
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if !path.is_dir() || live.contains(&path) {
+                    continue;
+                }
+                let (_, bytes) = dir_usage(&path);
+                std::fs::remove_dir_all(&path)?;
+                sweep.removed_sessions += 1;
+                sweep.freed_bytes += bytes;
+            }
+            Ok(sweep)
+        })
+        .await
+        .map_err(std::io::Error::other)?
+    }
+
+    pub fn usage(&self) -> TempStorageUsage {
+        let mut sessions: Vec<SessionSpillUsage> = self
+            .accounts()
+            .iter()
+            .filter(|(_, account)| account.files > 0)
+            .map(|(session_id, account)| SessionSpillUsage {
+                session_id: session_id.clone(),
+                files: account.files,
+                bytes: account.bytes,
+            })
+            .collect();
+        sessions.sort_by(|a, b| b.bytes.cmp(&a.bytes));
+        TempStorageUsage {
+            directory: self.dir.display().to_string(),
+            quota_bytes_per_session: self.quota_bytes,
+            total_bytes: sessions.iter().map(|s| s.bytes).sum(),
+            rejected_spills: self.rejected.load(Ordering::Relaxed),
+            sessions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn enforces_quota_and_cleans_up_sessions() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SpillStore::new(dir.path().join("spill"), 10);
+
+        let first = store.spill("s1", b"123456").await.unwrap();
+        assert_eq!(store.read(&first).await.unwrap(), b"123456");
+        let refused = store.spill("s1", b"12345").await.unwrap_err();
+        assert!(matches!(refused, SpillError::QuotaExceeded { used: 6, .. }));
+        // Quotas are per session
+        store.spill("s2", b"12345").await.unwrap();
+        let usage = store.usage();
+        assert_eq!((usage.total_bytes, usage.rejected_spills), (11, 1));
+        assert_eq!(usage.sessions[0].session_id, "s1");
+
+        store.release(first).await.unwrap();
+        store.spill("s1", b"12345").await.unwrap();
+        assert_eq!(store.end_session("s1").await, 5);
+        assert!(!dir.path().join("spill").join("s1").exists());
+
+        // A fresh process only knows its own sessions; leftovers are swept
+        let restarted = SpillStore::new(dir.path().join("spill"), 10);
+        let sweep = restarted.sweep_stale().await.unwrap();
+        assert_eq!((sweep.removed_sessions, sweep.freed_bytes), (1, 5));
+        assert_eq!(restarted.usage().total_bytes, 0);
+    }
+}
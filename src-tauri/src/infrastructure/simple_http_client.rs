@@ -3,19 +3,26 @@
 //! This module provides a configurable HTTP client optimized for web crawling
 //! with built-in retry logic, rate limiting, and user agent management.
 
-use crate::infrastructure::config::WorkerConfig;
+use crate::crawl_engine::runtime::latency::{LatencyKey, LatencyOp, LatencyTimer};
+use crate::crawl_engine::services::performance_optimizer;
+use crate::infrastructure::circuit_breaker::{SiteCircuitBreaker, shared_circuit_breaker};
+use crate::infrastructure::config::{ProxyConfig, WorkerConfig};
+use crate::infrastructure::html_cache::{HtmlCache, shared_html_cache};
+#[cfg(any(test, feature = "mock-site"))]
+use crate::infrastructure::mock_http_client::{self, MockHttpClient};
+use crate::infrastructure::proxy_pool::{ProxyPool, is_proxy_failure_status};
+use crate::infrastructure::rate_limiter::{HostRateLimiter, shared_rate_limiter};
+use crate::infrastructure::robots::{RobotsCache, RobotsRules, robots_origin, shared_robots_cache};
 use anyhow::{Result, anyhow};
 use reqwest::{
     Client, ClientBuilder, Response, Url,
     header::{ACCEPT, ACCEPT_LANGUAGE, HeaderMap, HeaderValue, REFERER, USER_AGENT},
 };
 use scraper::Html;
-use std::sync::{Arc, OnceLock};
+use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::{Mutex, Semaphore};
-use tokio::time::interval;
 use tokio_util::sync::CancellationToken;
-use tracing::{debug, error, info, trace, warn};
+use tracing::{debug, error, info, warn};
 
 /// Per-request options to override UA, referer, and toggle robots for a single call
 #[derive(Debug, Default, Clone)]
@@ -42,8 +49,14 @@ pub struct HttpClientConfig {
     pub user_agent: String,
     /// Whether to follow redirects
     pub follow_redirects: bool,
-    /// Respect robots.txt when crawling (simple allow/disallow check)
+    /// Respect robots.txt when crawling (rules cached per origin)
     pub respect_robots_txt: bool,
+    /// How long parsed robots.txt rules are reused per origin (seconds)
+    pub robots_cache_ttl_secs: u64,
+    /// Outbound proxy pool (empty = direct connection)
+    pub proxy: ProxyConfig,
+    /// Revalidate cached HTML with conditional requests (shared disk cache)
+    pub html_cache: bool,
 }
 
 impl HttpClientConfig {
@@ -56,6 +69,9 @@ impl HttpClientConfig {
             user_agent: worker_config.user_agent.clone(),
             follow_redirects: worker_config.follow_redirects,
             respect_robots_txt: worker_config.respect_robots_txt,
+            robots_cache_ttl_secs: worker_config.robots_cache_ttl_secs,
+            proxy: worker_config.proxy.clone(),
+            html_cache: worker_config.html_cache_enabled,
         }
     }
 }
@@ -70,148 +86,34 @@ impl Default for HttpClientConfig {
                 .to_string(),
             follow_redirects: true,
             respect_robots_txt: false,
+            robots_cache_ttl_secs: crate::infrastructure::config::defaults::ROBOTS_CACHE_TTL_SECS,
+            proxy: ProxyConfig::default(),
+            html_cache: false,
         }
     }
 }
 
-/// Global rate limiter shared across all HttpClient instances
-/// Uses token bucket algorithm for true concurrent rate limiting
-#[derive(Debug)]
-struct GlobalRateLimiter {
-    /// Semaphore representing available tokens (permits)
-    /// We refill up to a capped capacity each second by adding 1 token per tick.
-    semaphore: Arc<Semaphore>,
-    /// Current rate limit setting (RPS)
-    current_rate: Arc<Mutex<u32>>,
-    /// Token refill task handle
-    refill_handle: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
-}
-
-/// Truly global rate limiter instance (singleton)
-static GLOBAL_RATE_LIMITER: OnceLock<GlobalRateLimiter> = OnceLock::new();
-
-impl GlobalRateLimiter {
-    fn get_instance() -> &'static GlobalRateLimiter {
-        GLOBAL_RATE_LIMITER.get_or_init(|| {
-            let initial_rate = 50; // Default 50 RPS
-            // Start with 0 available permits; refill task will add permits as rate is applied
-            let semaphore = Arc::new(Semaphore::new(0));
-            let current_rate = Arc::new(Mutex::new(initial_rate));
-
-            info!(
-                "🚀 GlobalRateLimiter initialized with {} RPS (Token Bucket)",
-                initial_rate
-            );
-
-            GlobalRateLimiter {
-                semaphore: semaphore.clone(),
-                current_rate: current_rate.clone(),
-                refill_handle: Arc::new(Mutex::new(None)),
-            }
-        })
-    }
-
-    /// Publicly adjust the global rate limit (RPS) at runtime.
-    /// Safe to call from anywhere; the refill task will be restarted if needed.
-    pub async fn set_global_rate_limit(rps: u32) {
-        let inst = Self::get_instance();
-        inst.update_rate_limit(rps).await;
-    }
-
-    async fn update_rate_limit(&self, max_requests_per_second: u32) {
-        let mut current_rate = self.current_rate.lock().await;
-        let changed = *current_rate != max_requests_per_second;
-        if changed {
-            *current_rate = max_requests_per_second;
-            info!(
-                "🔄 Updated global rate limit to {} RPS",
-                max_requests_per_second
-            );
-        }
-
-        // Ensure refill is running. If rate changed, restart. If unchanged but no task, start.
-        let need_start = {
-            let handle = self.refill_handle.lock().await;
-            match &*handle {
-                None => true,
-                Some(h) => h.is_finished(),
-            }
-        };
-        if changed || need_start {
-            self.start_refill_task(max_requests_per_second).await;
-        }
-    }
-
-    async fn start_refill_task(&self, rate: u32) {
-        let mut handle = self.refill_handle.lock().await;
-
-        // Stop existing task
-        if let Some(old_handle) = handle.take() {
-            old_handle.abort();
-        }
-
-        if rate == 0 {
-            return; // No rate limiting
-        }
-
-        let semaphore = self.semaphore.clone();
-        // Refill once every (1000 / rate) ms; cap bucket size to `rate` (1s worth of tokens)
-        let refill_interval = Duration::from_millis(1000 / rate as u64);
-        let capacity: usize = rate as usize;
-
-        info!(
-            "🎯 Starting token refill task: {} tokens/sec (interval: {:?}, capacity: {})",
-            rate, refill_interval, capacity
-        );
-
-    let new_handle = tokio::spawn(async move {
-            let mut interval = interval(refill_interval);
-            loop {
-                interval.tick().await;
-                // Add one token if below capacity
-                let available = semaphore.available_permits();
-                if available < capacity {
-                    semaphore.add_permits(1);
-            trace!("[rate-limit] token refilled (available={}/{})", available + 1, capacity);
-                } else {
-                    // At capacity; skip adding to avoid unbounded burst
-            trace!("[rate-limit] token refill skipped (at capacity {}/{})", available, capacity);
-                }
-            }
-        });
-
-        *handle = Some(new_handle);
-    }
-
-    async fn apply_rate_limit(&self, max_requests_per_second: u32) {
-        // Update rate limit if needed
-        self.update_rate_limit(max_requests_per_second).await;
-
-        if max_requests_per_second == 0 {
-            debug!("🔓 [rate-limit] off (max_requests_per_second = 0)");
-            return; // No rate limiting
-        }
-
-        debug!("🎫 [rate-limit] awaiting token ({} RPS)", max_requests_per_second);
-
-        // Acquire a token (permit) from the bucket
-        // This will wait if no tokens are available
-    let _permit = self.semaphore.acquire().await.unwrap();
-
-        debug!("🎫 [rate-limit] token acquired ({} RPS)", max_requests_per_second);
-
-        // Permit is automatically released when _permit goes out of scope
-    }
-}
-
 /// HTTP client with built-in rate limiting and error handling
-/// Now uses shared global rate limiter for better concurrency performance
+/// Shares the process-wide per-host token bucket with every other HTTP consumer
 #[derive(Clone)]
 pub struct HttpClient {
     client: Client,
     config: HttpClientConfig,
+    rate_limiter: Arc<HostRateLimiter>,
+    /// Process-wide robots.txt rule cache
+    robots: Arc<RobotsCache>,
+    /// One client per configured proxy, indexed by `ProxyPool` slot (empty = direct)
+    proxy_clients: Arc<Vec<Client>>,
+    proxy_pool: Option<Arc<ProxyPool>>,
+    /// Process-wide per-host circuit breaker
+    circuit: Arc<SiteCircuitBreaker>,
+    /// Conditional-request HTML cache (None = disabled)
+    html_cache: Option<Arc<HtmlCache>>,
     /// Optional context label for provenance in logs (e.g., "BatchActor", "Stage:List")
     context_label: Option<String>,
+    /// Canned responses instead of the network (None = follow `mock_http_client::active`)
+    #[cfg(any(test, feature = "mock-site"))]
+    mock: Option<Arc<MockHttpClient>>,
 }
 
 impl HttpClient {
@@ -259,26 +161,87 @@ impl HttpClient {
         );
         default_headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("en-US,en;q=0.9"));
 
-        let client = ClientBuilder::new()
-            .timeout(Duration::from_secs(config.timeout_seconds))
-            .user_agent(&config.user_agent)
-            .default_headers(default_headers)
-            .cookie_store(true)
-            .gzip(true)
-            .redirect(if config.follow_redirects {
-                reqwest::redirect::Policy::limited(10)
-            } else {
-                reqwest::redirect::Policy::none()
-            })
+        let builder = || {
+            ClientBuilder::new()
+                .timeout(Duration::from_secs(config.timeout_seconds))
+                .user_agent(&config.user_agent)
+                .default_headers(default_headers.clone())
+                .cookie_store(true)
+                .gzip(true)
+                .redirect(if config.follow_redirects {
+                    reqwest::redirect::Policy::limited(10)
+                } else {
+                    reqwest::redirect::Policy::none()
+                })
+        };
+        let client = builder()
             .build()
             .map_err(|e| anyhow!("Failed to create HTTP client: {}", e))?;
 
+        // Each proxy gets its own client (reqwest binds proxies at build time)
+        let mut proxy_clients = Vec::with_capacity(config.proxy.proxies.len());
+        for entry in &config.proxy.proxies {
+            let mut proxy = reqwest::Proxy::all(&entry.url)
+                .map_err(|e| anyhow!("Invalid proxy URL {}: {}", entry.url, e))?;
+            if let Some(username) = &entry.username {
+                proxy = proxy.basic_auth(username, entry.password.as_deref().unwrap_or(""));
+            }
+            proxy_clients.push(
+                builder()
+                    .proxy(proxy)
+                    .build()
+                    .map_err(|e| anyhow!("Failed to create proxied HTTP client: {}", e))?,
+            );
+        }
+        let proxy_pool = ProxyPool::from_config(&config.proxy).map(Arc::new);
+
+        let rate_limiter = shared_rate_limiter();
+        rate_limiter.set_rate(config.max_requests_per_second);
+        let robots = shared_robots_cache();
+        robots.set_ttl(config.robots_cache_ttl_secs);
+
         Ok(Self {
             client,
+            rate_limiter,
+            robots,
+            proxy_clients: Arc::new(proxy_clients),
+            proxy_pool,
+            circuit: shared_circuit_breaker(),
+            html_cache: config.html_cache.then(shared_html_cache),
             config,
             context_label: None,
+            #[cfg(any(test, feature = "mock-site"))]
+            mock: None,
         })
     }
+    /// Use a specific HTML cache instead of the shared one (enables caching)
+    pub fn with_html_cache(mut self, cache: Arc<HtmlCache>) -> Self {
+        self.html_cache = Some(cache);
+        self
+    }
+
+    /// Answer every request from `mock` (bypasses rate limit, robots, proxies, circuit and cache)
+    #[cfg(any(test, feature = "mock-site"))]
+    pub fn with_mock(mut self, mock: Arc<MockHttpClient>) -> Self {
+        self.mock = Some(mock);
+        self
+    }
+
+    #[cfg(any(test, feature = "mock-site"))]
+    fn mock(&self) -> Option<Arc<MockHttpClient>> {
+        self.mock.clone().or_else(mock_http_client::active)
+    }
+
+    #[cfg(not(any(test, feature = "mock-site")))]
+    fn is_mocked(&self) -> bool {
+        false
+    }
+
+    #[cfg(any(test, feature = "mock-site"))]
+    fn is_mocked(&self) -> bool {
+        self.mock().is_some()
+    }
+
     /// Set a human-readable context label for logging provenance (returns self for chaining)
     pub fn with_context_label(mut self, label: &str) -> Self {
         self.context_label = Some(label.to_string());
@@ -293,22 +256,108 @@ impl HttpClient {
     /// Adjust the global RPS limit for all HttpClient instances at runtime.
     /// This does not mutate this instance's stored config but affects the shared token bucket.
     pub async fn set_global_max_rps(rps: u32) {
-        GlobalRateLimiter::set_global_rate_limit(rps).await;
+        shared_rate_limiter().set_rate(rps);
         info!(target: "kpi.network",
             "{{\"event\":\"rate_limit_set\",\"rps\":{},\"source\":\"runtime\",\"ts\":\"{}\"}}",
             rps,
             chrono::Utc::now()
         );
     }
-    fn build_request(&self, url: &str, opts: &RequestOptions) -> Result<reqwest::RequestBuilder> {
-        let mut rb = self.client.get(url);
+
+    /// Wait for a token from the shared per-host bucket
+    async fn acquire_rate_token(&self, url: &str) {
+        if self.is_mocked() {
+            return;
+        }
+        if let Some(label) = &self.context_label {
+            debug!(
+                "⚖️ [rate-limit] {} RPS/host (source: {})",
+                self.rate_limiter.rate(),
+                label
+            );
+        } else {
+            debug!("⚖️ [rate-limit] {} RPS/host", self.rate_limiter.rate());
+        }
+        self.rate_limiter.acquire(url).await;
+    }
+
+    /// Client for the next request and the proxy slot it belongs to (None = direct)
+    fn select_client(&self) -> (&Client, Option<usize>) {
+        match &self.proxy_pool {
+            Some(pool) => {
+                let slot = pool.select();
+                debug!("🧭 [proxy] using {}", pool.label(slot));
+                (&self.proxy_clients[slot], Some(slot))
+            }
+            None => (&self.client, None),
+        }
+    }
+
+    /// Feed the outcome of a send back into proxy health tracking
+    fn record_proxy_outcome(&self, slot: Option<usize>, result: &reqwest::Result<Response>) {
+        let (Some(pool), Some(slot)) = (&self.proxy_pool, slot) else {
+            return;
+        };
+        match result {
+            Ok(resp) if !is_proxy_failure_status(resp.status()) => pool.record_success(slot),
+            Ok(resp) => {
+                warn!("⚠️ [proxy] {} answered {}", pool.label(slot), resp.status());
+                pool.record_failure(slot);
+            }
+            Err(e) => {
+                warn!("⚠️ [proxy] {} request failed: {}", pool.label(slot), e);
+                pool.record_failure(slot);
+            }
+        }
+    }
+
+    /// Send through the selected proxy (or directly) and record proxy and circuit health.
+    /// Fails fast with `CircuitOpenError` while the host's circuit is open.
+    /// With the HTML cache on, cached pages are revalidated and a 304 is served from disk.
+    async fn send_request(&self, url: &str, opts: &RequestOptions) -> Result<Response> {
+        #[cfg(any(test, feature = "mock-site"))]
+        if let Some(mock) = self.mock() {
+            return mock.respond(url).await;
+        }
+        self.circuit.check(url)?;
+        let cached = match &self.html_cache {
+            Some(cache) => cache.lookup(url).await,
+            None => None,
+        };
+        let (client, slot) = self.select_client();
+        let mut rb = self.build_request(client, url, opts);
+        if let Some(meta) = &cached {
+            rb = meta.apply_validators(rb);
+        }
+        let timer = LatencyTimer::start(LatencyKey::op(LatencyOp::HttpRequest), None);
+        let result = rb.send().await;
+        let elapsed = timer.finish();
+        performance_optimizer::record_http_outcome(
+            elapsed,
+            result.as_ref().ok().map(|r| r.status().as_u16()),
+        );
+        self.record_proxy_outcome(slot, &result);
+        self.circuit.record(url, &result);
+        match &self.html_cache {
+            Some(cache) => cache.resolve(url, result?, cached).await,
+            None => Ok(result?),
+        }
+    }
+
+    fn build_request(
+        &self,
+        client: &Client,
+        url: &str,
+        opts: &RequestOptions,
+    ) -> reqwest::RequestBuilder {
+        let mut rb = client.get(url);
         if let Some(ua) = &opts.user_agent_override {
             rb = rb.header(USER_AGENT, ua);
         }
         if let Some(referer) = &opts.referer {
             rb = rb.header(REFERER, referer);
         }
-        Ok(rb)
+        rb
     }
 
     /// Public variant to perform a GET with custom options (UA override, referer, skip robots)
@@ -317,29 +366,9 @@ impl HttpClient {
         url: &str,
         opts: &RequestOptions,
     ) -> Result<Response> {
-        let rate_limiter = GlobalRateLimiter::get_instance();
-        if let Some(label) = &self.context_label {
-            debug!(
-                "⚖️ [rate-limit] {} RPS (source: {})",
-                self.config.max_requests_per_second, label
-            );
-        } else {
-            debug!(
-                "⚖️ [rate-limit] {} RPS",
-                self.config.max_requests_per_second
-            );
-        }
-        rate_limiter
-            .apply_rate_limit(self.config.max_requests_per_second)
-            .await;
+        self.acquire_rate_token(url).await;
 
-        if self.config.respect_robots_txt
-            && !opts.skip_robots_check
-            && !self.robots_allowed(url).await?
-        {
-            warn!("robots.txt disallows: {}", url);
-            return Err(anyhow!("Blocked by robots.txt: {}", url));
-        }
+        self.check_robots(url, opts.skip_robots_check).await?;
 
         // Include attempt info when provided by caller for better observability
         match (opts.attempt, opts.max_attempts) {
@@ -354,8 +383,7 @@ impl HttpClient {
             }
         }
         let response = self
-            .build_request(url, opts)?
-            .send()
+            .send_request(url, opts)
             .await
             .map_err(|e| anyhow!("HTTP request failed: {}", e))?;
 
@@ -367,59 +395,71 @@ impl HttpClient {
         Ok(response)
     }
 
-    async fn robots_allowed(&self, target_url: &str) -> Result<bool> {
-        if !self.config.respect_robots_txt {
-            return Ok(true);
+    /// Enforce robots.txt for `target_url` using the per-origin rule cache.
+    /// With `skip` the request always proceeds; a rule it would have hit (judged from
+    /// already cached rules only, no fetch) is recorded as an override.
+    async fn check_robots(&self, target_url: &str, skip: bool) -> Result<()> {
+        if !self.config.respect_robots_txt || self.is_mocked() {
+            return Ok(());
         }
-        // Parse base origin
         let url =
             Url::parse(target_url).map_err(|e| anyhow!("Invalid URL {}: {}", target_url, e))?;
-        let robots_url = format!(
-            "{}://{}/robots.txt",
-            url.scheme(),
-            url.host_str().unwrap_or("")
-        );
-        // Best-effort fetch; if fails, allow by default
-        let resp = match self.client.get(&robots_url).send().await {
-            Ok(r) => r,
-            Err(_) => return Ok(true),
+        let origin = robots_origin(&url);
+        let rules = if skip {
+            match self.robots.fresh_rules(&origin) {
+                Some(rules) => rules,
+                None => return Ok(()),
+            }
+        } else {
+            self.robots_rules(&origin).await
+        };
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
         };
-        if !resp.status().is_success() {
-            return Ok(true);
+        if let Some(rule) = rules.blocking_rule(&path) {
+            self.robots.record_skip(target_url, &origin, rule, skip);
+            if !skip {
+                return Err(anyhow!("Blocked by robots.txt: {}", target_url));
+            }
         }
-        let text = resp.text().await.unwrap_or_default();
-        if text.is_empty() {
-            return Ok(true);
+        Ok(())
+    }
+
+    /// Cached rules for `origin`, fetching robots.txt once per TTL window.
+    /// A missing or unreachable robots.txt is cached as allow-all.
+    async fn robots_rules(&self, origin: &str) -> RobotsRules {
+        if let Some(rules) = self.robots.fresh_rules(origin) {
+            return rules;
         }
-        // Super-simple check: if a line says Disallow: / then deny everything; otherwise allow
-        // Optionally, we could parse path-specific disallows that match the URL path.
-        for line in text.lines() {
-            let l = line.trim();
-            if l.starts_with('#') {
-                continue;
-            }
-            if l.to_ascii_lowercase().starts_with("user-agent:") {
-                // Not differentiating per-agent in this lightweight pass
-                continue;
+        let _gate = self.robots.lock_fetch().await;
+        if let Some(rules) = self.robots.fresh_rules(origin) {
+            return rules;
+        }
+        let robots_url = format!("{}/robots.txt", origin);
+        let (client, _) = self.select_client();
+        let text = match client.get(&robots_url).send().await {
+            Ok(resp) if resp.status().is_success() => resp.text().await.ok(),
+            Ok(resp) => {
+                debug!("🤖 {} answered {}, allowing all", robots_url, resp.status());
+                None
             }
-            if l.to_ascii_lowercase().starts_with("disallow:") {
-                let rule = l[9..].trim();
-                if rule == "/" {
-                    return Ok(false);
-                }
-                // Basic path prefix match
-                if !rule.is_empty() {
-                    if let Some(path) = url.path().strip_prefix('/') {
-                        if path.starts_with(rule.trim_start_matches('/')) {
-                            return Ok(false);
-                        }
-                    } else if url.path().starts_with(rule) {
-                        return Ok(false);
-                    }
-                }
+            Err(e) => {
+                warn!("🤖 Could not fetch {} ({}), allowing all", robots_url, e);
+                None
             }
-        }
-        Ok(true)
+        };
+        let (rules, fetch_ok) = match text {
+            Some(text) => (RobotsRules::parse(&text, &self.config.user_agent), true),
+            None => (RobotsRules::allow_all(), false),
+        };
+        debug!(
+            "🤖 cached {} robots.txt rules for {}",
+            rules.rule_count(),
+            origin
+        );
+        self.robots.store(origin, rules.clone(), fetch_ok);
+        rules
     }
 
     /// Fetch HTML content from a URL with automatic retry and rate limiting
@@ -442,32 +482,14 @@ impl HttpClient {
 
     /// Fetch raw response from a URL
     pub async fn fetch_response(&self, url: &str) -> Result<Response> {
-        let rate_limiter = GlobalRateLimiter::get_instance();
-        if let Some(label) = &self.context_label {
-            debug!(
-                "⚖️ [rate-limit] {} RPS (source: {})",
-                self.config.max_requests_per_second, label
-            );
-        } else {
-            debug!(
-                "⚖️ [rate-limit] {} RPS",
-                self.config.max_requests_per_second
-            );
-        }
-        rate_limiter
-            .apply_rate_limit(self.config.max_requests_per_second)
-            .await;
+        self.acquire_rate_token(url).await;
 
         // robots.txt check if enabled
-        if self.config.respect_robots_txt && !self.robots_allowed(url).await? {
-            warn!("robots.txt disallows: {}", url);
-            return Err(anyhow!("Blocked by robots.txt: {}", url));
-        }
+        self.check_robots(url, false).await?;
 
         info!("🌐 HTTP GET (HttpClient): {}", url);
         let response = self
-            .build_request(url, &RequestOptions::default())?
-            .send()
+            .send_request(url, &RequestOptions::default())
             .await
             .map_err(|e| anyhow!("HTTP request failed: {}", e))?;
 
@@ -488,38 +510,22 @@ impl HttpClient {
         url: &str,
         cancellation_token: &CancellationToken,
     ) -> Result<Response> {
-        // Apply global rate limiting with cancellation support
-        let rate_limiter = GlobalRateLimiter::get_instance();
-        if let Some(label) = &self.context_label {
-            debug!(
-                "⚖️ [rate-limit] {} RPS (source: {})",
-                self.config.max_requests_per_second, label
-            );
-        } else {
-            debug!(
-                "⚖️ [rate-limit] {} RPS",
-                self.config.max_requests_per_second
-            );
-        }
-
+        // Apply shared per-host rate limiting with cancellation support
         tokio::select! {
-            _ = rate_limiter.apply_rate_limit(self.config.max_requests_per_second) => {},
+            _ = self.acquire_rate_token(url) => {},
             _ = cancellation_token.cancelled() => {
                 return Err(anyhow!("Request cancelled during rate limiting"));
             }
         }
 
         // robots.txt check if enabled
-        if self.config.respect_robots_txt && !self.robots_allowed(url).await? {
-            warn!("robots.txt disallows: {}", url);
-            return Err(anyhow!("Blocked by robots.txt: {}", url));
-        }
+        self.check_robots(url, false).await?;
 
         info!("🌐 HTTP GET (HttpClient,cancel-aware): {}", url);
 
         // Perform request with cancellation
         let response = tokio::select! {
-            res = self.build_request(url, &RequestOptions::default())?.send() => {
+            res = self.send_request(url, &RequestOptions::default()) => {
                 res.map_err(|e| anyhow!("HTTP request failed: {}", e))?
             },
             _ = cancellation_token.cancelled() => {
@@ -548,31 +554,15 @@ impl HttpClient {
         let mut last_err: Option<anyhow::Error> = None;
 
         for attempt in 1..=self.config.max_retries {
-            let rate_limiter = GlobalRateLimiter::get_instance();
-            if let Some(label) = &self.context_label {
-                debug!(
-                    "⚖️ [rate-limit] {} RPS (source: {})",
-                    self.config.max_requests_per_second, label
-                );
-            } else {
-                debug!(
-                    "⚖️ [rate-limit] {} RPS",
-                    self.config.max_requests_per_second
-                );
-            }
-
             tokio::select! {
-                _ = rate_limiter.apply_rate_limit(self.config.max_requests_per_second) => {},
+                _ = self.acquire_rate_token(url) => {},
                 _ = cancellation_token.cancelled() => {
                     return Err(anyhow!("Request cancelled during rate limiting"));
                 }
             }
 
             // robots.txt check if enabled
-            if self.config.respect_robots_txt && !self.robots_allowed(url).await? {
-                warn!("robots.txt disallows: {}", url);
-                return Err(anyhow!("Blocked by robots.txt: {}", url));
-            }
+            self.check_robots(url, false).await?;
 
             if attempt > 1 {
                 info!(
@@ -588,7 +578,7 @@ impl HttpClient {
 
             // Perform request with cancellation
             let send_res: Result<Response, anyhow::Error> = tokio::select! {
-                res = self.build_request(url, &RequestOptions::default())?.send() => {
+                res = self.send_request(url, &RequestOptions::default()) => {
                     res.map_err(|e| anyhow!("HTTP request failed: {}", e))
                 },
                 _ = cancellation_token.cancelled() => {
@@ -675,27 +665,10 @@ impl HttpClient {
         let mut last_err: Option<anyhow::Error> = None;
 
         for attempt in 1..=self.config.max_retries {
-            let rate_limiter = GlobalRateLimiter::get_instance();
-            if let Some(label) = &self.context_label {
-                debug!(
-                    "⚖️ [rate-limit] {} RPS (source: {})",
-                    self.config.max_requests_per_second, label
-                );
-            } else {
-                debug!(
-                    "⚖️ [rate-limit] {} RPS",
-                    self.config.max_requests_per_second
-                );
-            }
-            rate_limiter
-                .apply_rate_limit(self.config.max_requests_per_second)
-                .await;
+            self.acquire_rate_token(url).await;
 
             // robots.txt check if enabled
-            if self.config.respect_robots_txt && !self.robots_allowed(url).await? {
-                warn!("robots.txt disallows: {}", url);
-                return Err(anyhow!("Blocked by robots.txt: {}", url));
-            }
+            self.check_robots(url, false).await?;
 
             if attempt > 1 {
                 info!(
@@ -708,11 +681,7 @@ impl HttpClient {
                     attempt, self.config.max_retries, url
                 );
             }
-            match self
-                .build_request(url, &RequestOptions::default())?
-                .send()
-                .await
-            {
+            match self.send_request(url, &RequestOptions::default()).await {
                 Ok(resp) => {
                     let status = resp.status();
                     if status.is_success() {
@@ -914,6 +883,29 @@ mod tests {
                             let _ = socket.write_all(body).await;
                         }
                     }
+                    "/robots.txt" => {
+                        cnt_clone.fetch_add(1, Ordering::SeqCst);
+                        let body = b"User-agent: *\nDisallow: /private\n";
+                        let resp =
+                            format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", body.len());
+                        let _ = socket.write_all(resp.as_bytes()).await;
+                        let _ = socket.write_all(body).await;
+                    }
+                    "/etag" => {
+                        cnt_clone.fetch_add(1, Ordering::SeqCst);
+                        if req.to_ascii_lowercase().contains("if-none-match: \"v1\"") {
+                            let resp = b"HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\nContent-Length: 0\r\n\r\n";
+                            let _ = socket.write_all(resp).await;
+                        } else {
+                            let body = b"<html>v1</html>";
+                            let resp = format!(
+                                "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Type: text/html\r\nContent-Length: {}\r\n\r\n",
+                                body.len()
+                            );
+                            let _ = socket.write_all(resp.as_bytes()).await;
+                            let _ = socket.write_all(body).await;
+                        }
+                    }
                     "/slow" => {
                         // Write headers then delay body to let cancellation kick in
                         let body = b"delayed";
@@ -951,6 +943,9 @@ mod tests {
             user_agent: "Test Agent".to_string(),
             follow_redirects: false,
             respect_robots_txt: false,
+            robots_cache_ttl_secs: 60,
+            proxy: ProxyConfig::default(),
+            html_cache: false,
         };
 
         let client = HttpClient::with_config(config);
@@ -975,6 +970,9 @@ mod tests {
             user_agent: "test".into(),
             follow_redirects: false,
             respect_robots_txt: false,
+            robots_cache_ttl_secs: 60,
+            proxy: ProxyConfig::default(),
+            html_cache: false,
         };
         let client = HttpClient::with_config(cfg).unwrap();
         let url = format!("http://{}/retry2ok", addr);
@@ -985,6 +983,65 @@ mod tests {
         assert!(resp.status().is_success());
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_robots_rules_cached_per_origin() {
+        let (addr, robots_fetches) = start_test_server().await;
+        let cfg = HttpClientConfig {
+            max_requests_per_second: 100,
+            timeout_seconds: 5,
+            max_retries: 1,
+            user_agent: "test".into(),
+            follow_redirects: false,
+            respect_robots_txt: true,
+            robots_cache_ttl_secs: 60,
+            proxy: ProxyConfig::default(),
+            html_cache: false,
+        };
+        let client = HttpClient::with_config(cfg).unwrap();
+        let blocked = format!("http://{}/private/page", addr);
+        assert!(client.fetch_response(&blocked).await.is_err());
+        assert!(client.fetch_response(&format!("http://{}/a", addr)).await.is_ok());
+        assert!(client.fetch_response(&format!("http://{}/b", addr)).await.is_ok());
+        assert_eq!(robots_fetches.load(Ordering::SeqCst), 1);
+
+        let opts = RequestOptions {
+            skip_robots_check: true,
+            ..Default::default()
+        };
+        assert!(client.fetch_response_with_options(&blocked, &opts).await.is_ok());
+        let origin = format!("http://{}", addr);
+        let status = shared_robots_cache().status(true);
+        let host = status.hosts.iter().find(|h| h.origin == origin).unwrap();
+        assert_eq!((host.blocked, host.overridden), (1, 1));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_html_cache_serves_304_from_disk() {
+        let (addr, requests) = start_test_server().await;
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Arc::new(HtmlCache::new(dir.path().to_path_buf()));
+        let client = HttpClient::with_config(HttpClientConfig {
+            max_requests_per_second: 100,
+            ..Default::default()
+        })
+        .unwrap()
+        .with_html_cache(cache.clone());
+        let url = format!("http://{}/etag", addr);
+
+        assert_eq!(
+            client.fetch_html_string(&url).await.unwrap(),
+            "<html>v1</html>"
+        );
+        // Second fetch is conditional: the server answers 304, the body comes from disk
+        assert_eq!(
+            client.fetch_html_string(&url).await.unwrap(),
+            "<html>v1</html>"
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn test_cancellation_before_start() {
         let cfg = HttpClientConfig {
@@ -994,6 +1051,9 @@ mod tests {
             user_agent: "test".into(),
             follow_redirects: false,
             respect_robots_txt: false,
+            robots_cache_ttl_secs: 60,
+            proxy: ProxyConfig::default(),
+            html_cache: false,
         };
         let client = HttpClient::with_config(cfg).unwrap();
         let token = CancellationToken::new();
@@ -1014,6 +1074,9 @@ mod tests {
             user_agent: "test".into(),
             follow_redirects: false,
             respect_robots_txt: false,
+            robots_cache_ttl_secs: 60,
+            proxy: ProxyConfig::default(),
+            html_cache: false,
         };
         let client = HttpClient::with_config(cfg).unwrap();
         let token = CancellationToken::new();
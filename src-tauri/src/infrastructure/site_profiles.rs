@@ -0,0 +1,219 @@
+//! `SiteProfile` 구현체와 id 기반 조회
+//!
+//! 현재는 csa-iot.org(Matter 필터) 하나뿐이다. 새 인증 사이트는 구현체를 추가하고
+//! `site_profile_by_id` 에 등록한 뒤 `advanced.site_profile` 로 선택한다. csa-iot URL 은
+//! `advanced.url_templates` 로 덮어쓸 수 있다.
+
+use crate::domain::site_profile::{DetailPageSelectors, ListPageSelectors, SiteProfile};
+use crate::infrastructure::config::{AppConfig, UrlTemplateConfig, defaults};
+use crate::infrastructure::extractor_rollout;
+use std::sync::Arc;
+use tracing::warn;
+
+/// csa-iot.org Matter product listing
+#[derive(Debug, Clone, Default)]
+pub struct CsaIotProfile {
+    urls: UrlTemplateConfig,
+}
+
+impl CsaIotProfile {
+    pub const ID: &'static str = "csa-iot";
+
+    /// Profile using `urls` instead of the built-in templates (caller validates)
+    pub fn with_url_templates(urls: UrlTemplateConfig) -> Self {
+        Self { urls }
+    }
+
+    const LIST_SELECTORS: ListPageSelectors = ListPageSelectors {
+        item_containers: &[
+            "div.post-feed article",
+            ".wp-block-crown-blocks-product-index article.product",
+        ],
+        product_link: "a",
+        manufacturer: "p.entry-company.notranslate",
+        model: "h3.entry-title",
+        certificate_id: &["p.entry-certificate-id", "span.entry-cert-id"],
+        certificate_id_label: "Certificate ID: ",
+        pagination_links: &["a[href*='page=']", ".pagination a", ".page-numbers a"],
+        page_info: &[".pagination-info", ".page-info", ".showing-info"],
+    };
+
+    const DETAIL_SELECTORS: DetailPageSelectors = DetailPageSelectors {
+        model: "h1.entry-title, h1",
+        manufacturer: "p.company-info, .company-name, .manufacturer, p.entry-company, .entry-company",
+        device_type: "p.device-category, .product-type, .device-type, p.entry-category, .entry-category, h6.entry-category",
+        certificate_id: ".cert-id, .certification-id",
+        certification_date: ".cert-date, .certification-date",
+        info_table: ".product-certificates-table",
+        detail_items: "div.entry-product-details li.item",
+        item_label: "span.label",
+        item_value: "span.value",
+        legacy_detail_items: "div.entry-product-details > div > ul li",
+    };
+}
+
+impl SiteProfile for CsaIotProfile {
+    fn id(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn base_url(&self) -> &str {
+        &self.urls.base_url
+    }
+
+    fn listing_base_url(&self) -> &str {
+        &self.urls.listing_base_url
+    }
+
+    fn list_page_url(&self, page: u32) -> String {
+        self.urls.list_page_url(page)
+    }
+
+    fn products_per_page(&self) -> u32 {
+        crate::domain::constants::site::PRODUCTS_PER_PAGE as u32
+    }
+
+    fn is_product_url(&self, url: &str) -> bool {
+        url.contains(&self.urls.product_path) && !url.starts_with(&self.urls.listing_base_url)
+    }
+
+    fn is_listing_url(&self, url: &str) -> bool {
+        url.contains("page=") || url.contains("products") && !url.contains(&self.urls.product_path)
+    }
+
+    fn list_selectors(&self) -> &ListPageSelectors {
+        &Self::LIST_SELECTORS
+    }
+
+    fn detail_selectors(&self) -> &DetailPageSelectors {
+        &Self::DETAIL_SELECTORS
+    }
+}
+
+/// Profile registered under `id`
+pub fn site_profile_by_id(id: &str) -> Option<Arc<dyn SiteProfile>> {
+    site_profile_with_templates(id, &UrlTemplateConfig::default())
+}
+
+/// Profile registered under `id`, built with the given URL templates
+pub fn site_profile_with_templates(
+    id: &str,
+    urls: &UrlTemplateConfig,
+) -> Option<Arc<dyn SiteProfile>> {
+    match id {
+        CsaIotProfile::ID => Some(Arc::new(CsaIotProfile::with_url_templates(urls.clone()))),
+        _ => None,
+    }
+}
+
+pub fn default_site_profile() -> Arc<dyn SiteProfile> {
+    site_profile_by_id(defaults::SITE_PROFILE_ID)
+        .unwrap_or_else(|| Arc::new(CsaIotProfile::default()))
+}
+
+/// Profile selected by `advanced.site_profile` with `advanced.url_templates` applied.
+/// Unknown ids and invalid templates fall back to the defaults; while a newly selected profile
+/// shadows the promoted one (`extractor_rollout`), the promoted profile is returned.
+pub fn resolve_site_profile(config: &AppConfig) -> Arc<dyn SiteProfile> {
+    let id = config.advanced.site_profile.as_str();
+    let configured = &config.advanced.url_templates;
+    let errors = configured.validate();
+    let default_urls = UrlTemplateConfig::default();
+    let urls = if errors.is_empty() {
+        configured
+    } else {
+        warn!(
+            "Invalid advanced.url_templates ({}), using built-in URLs",
+            errors.join("; ")
+        );
+        &default_urls
+    };
+    let configured = site_profile_with_templates(id, urls).unwrap_or_else(|| {
+        warn!(
+            "Unknown site profile '{}', falling back to '{}'",
+            id,
+            defaults::SITE_PROFILE_ID
+        );
+        site_profile_with_templates(defaults::SITE_PROFILE_ID, urls)
+            .unwrap_or_else(default_site_profile)
+    });
+    // Rules still shadowing the promoted ones do not serve yet
+    let rollout = &config.advanced.extractor_rollout;
+    match extractor_rollout::route(&configured, rollout, |id| site_profile_by_id(id).is_some()) {
+        Some(serving) => site_profile_with_templates(&serving, urls).unwrap_or(configured),
+        None => configured,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::config::csa_iot;
+
+    #[test]
+    fn csa_profile_builds_listing_urls_and_classifies_links() {
+        let profile = default_site_profile();
+        assert_eq!(profile.id(), CsaIotProfile::ID);
+        assert_eq!(profile.list_page_url(1), csa_iot::PRODUCTS_PAGE_MATTER_ONLY);
+        assert_eq!(
+            profile.list_page_url(7),
+            csa_iot::PRODUCTS_PAGE_MATTER_PAGINATED.replace("{}", "7")
+        );
+        assert!(profile.is_product_url("https://csa-iot.org/csa_product/foo/"));
+        assert!(!profile.is_product_url("https://csa-iot.org/csa-iot_products/page/2/"));
+        assert!(profile.is_listing_url("https://csa-iot.org/csa-iot_products/?page=2"));
+        assert!(!profile.is_listing_url("https://csa-iot.org/csa_product/foo/"));
+    }
+
+    #[test]
+    fn unknown_profile_falls_back_to_default() {
+        assert!(site_profile_by_id("nope").is_none());
+        let mut config = AppConfig::default();
+        config.advanced.site_profile = "nope".into();
+        assert_eq!(
+            resolve_site_profile(&config).id(),
+            defaults::SITE_PROFILE_ID
+        );
+    }
+
+    #[test]
+    fn url_templates_override_and_validate() {
+        let defaults = UrlTemplateConfig::default();
+        assert!(defaults.validate().is_empty());
+        assert_eq!(
+            defaults.list_page_url(3),
+            crate::infrastructure::config::utils::matter_products_page_url(3)
+        );
+
+        let mut config = AppConfig::default();
+        config.advanced.url_templates = UrlTemplateConfig {
+            base_url: "https://example.org".into(),
+            listing_base_url: "https://example.org/products".into(),
+            first_page_url: "https://example.org/products/?matter".into(),
+            list_page_template: "https://example.org/products/p/{page}/?matter".into(),
+            product_path: "/product/".into(),
+        };
+        let profile = resolve_site_profile(&config);
+        assert_eq!(profile.base_url(), "https://example.org");
+        assert_eq!(
+            profile.list_page_url(1),
+            "https://example.org/products/?matter"
+        );
+        assert_eq!(
+            profile.list_page_url(4),
+            "https://example.org/products/p/4/?matter"
+        );
+        assert!(profile.is_product_url("https://example.org/product/x/"));
+        assert!(!profile.is_product_url("https://csa-iot.org/csa_product/x/"));
+
+        config.advanced.url_templates.list_page_template = "https://example.org/products/".into();
+        config.advanced.url_templates.base_url = "not a url".into();
+        let errors = config.advanced.url_templates.validate();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().any(|e| e.contains("{page}")));
+        assert_eq!(
+            resolve_site_profile(&config).list_page_url(1),
+            csa_iot::PRODUCTS_PAGE_MATTER_ONLY
+        );
+    }
+}
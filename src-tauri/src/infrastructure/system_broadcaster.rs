@@ -1,4 +1,5 @@
 use crate::application::shared_state::SharedStateCache;
+use crate::crawl_engine::runtime::task_registry::spawn_tracked;
 use crate::events::{
     AtomicTaskEvent, BatchInfo, DbCursor, LiveSystemState, StageInfo, SystemStatePayload,
 };
@@ -729,7 +730,7 @@ impl SystemStateBroadcaster {
 pub fn start_system_broadcaster(app_handle: AppHandle) {
     let broadcaster = SystemStateBroadcaster::new(app_handle);
 
-    tokio::spawn(async move {
+    spawn_tracked("system-broadcaster", None, async move {
         broadcaster.start_background_broadcast().await;
     });
 }
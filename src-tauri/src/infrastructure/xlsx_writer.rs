@@ -0,0 +1,491 @@
+//! 최소 XLSX 스트리밍 작성기 (단일 시트, inline string)
+//!
+//! 내보내기 행을 메모리에 모으지 않고 시트 XML을 deflate 스트림으로 바로 zip 항목에 기록한다.
+//! 항목 크기/CRC는 data descriptor로 뒤에 적으므로 파일을 되감지 않는다. 공유 문자열 테이블과
+//! 스타일 없이 inline string과 숫자 셀만 쓴다 (zip64 미지원: 4GiB 이하).
+//! 문서 속성(`docProps/custom.xml`)에 문자열 사용자 지정 속성을 넣을 수 있다.
+
+use flate2::Compression;
+use flate2::Crc;
+use flate2::write::DeflateEncoder;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Rows per worksheet allowed by Excel
+pub const MAX_ROWS: u64 = 1_048_576;
+
+/// General purpose flags: sizes in data descriptor (bit 3) + UTF-8 names (bit 11)
+const ZIP_FLAGS: u16 = 0x0808;
+const ZIP_METHOD_DEFLATE: u16 = 8;
+const ZIP_VERSION: u16 = 20;
+/// 1980-01-01 00:00 in DOS date format
+const ZIP_DOS_DATE: u16 = 0x21;
+/// Format id shared by all user-defined document properties
+const CUSTOM_PROPERTIES_FMTID: &str = "{D5CDD505-2E9C-101B-9397-08002B2CF9AE}";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum XlsxCell {
+    Empty,
+    Text(String),
+    Number(f64),
+}
+
+struct Counting<W> {
+    inner: W,
+    written: u64,
+}
+
+impl<W: Write> Write for Counting<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.written += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+struct CentralRecord {
+    name: String,
+    crc: u32,
+    compressed: u32,
+    uncompressed: u32,
+    offset: u32,
+}
+
+struct OpenEntry {
+    name: String,
+    offset: u64,
+    data_start: u64,
+    crc: Crc,
+    encoder: DeflateEncoder<Counting<BufWriter<File>>>,
+}
+
+/// Single-sheet workbook written row by row
+pub struct XlsxWriter {
+    out: Option<Counting<BufWriter<File>>>,
+    entry: Option<OpenEntry>,
+    records: Vec<CentralRecord>,
+    rows: u64,
+}
+
+fn too_large() -> io::Error {
+    io::Error::other("XLSX export exceeds 4 GiB (zip64 not supported)")
+}
+
+fn to_u32(v: u64) -> io::Result<u32> {
+    u32::try_from(v).map_err(|_| too_large())
+}
+
+/// Escape XML text and drop characters XML 1.0 cannot carry
+pub fn xml_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' | '\n' | '\r' => out.push(ch),
+            c if (c as u32) < 0x20 => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Spreadsheet column letters for a 0-based index (0 = A, 26 = AA)
+pub fn column_letters(mut index: usize) -> String {
+    let mut letters = Vec::new();
+    loop {
+        letters.push(b'A' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    letters.reverse();
+    String::from_utf8(letters).unwrap_or_default()
+}
+
+impl XlsxWriter {
+    /// Create (truncate) `path` and write the workbook skeleton; rows follow via `write_row`
+    pub fn create(path: &Path, sheet_name: &str) -> io::Result<Self> {
+        Self::create_with_properties(path, sheet_name, &[])
+    }
+
+    /// `create` plus custom document properties (name, text value), shown in the file's
+    /// properties dialog
+    pub fn create_with_properties(
+        path: &Path,
+        sheet_name: &str,
+        properties: &[(&str, &str)],
+    ) -> io::Result<Self> {
+        let file = File::create(path)?;
+        let mut writer = Self {
+            out: Some(Counting {
+                inner: BufWriter::new(file),
+                written: 0,
+            }),
+            entry: None,
+            records: Vec::new(),
+            rows: 0,
+        };
+        // Sheet names are limited to 31 characters and may not contain []:*?/\
+        let sheet: String = sheet_name
+            .chars()
+            .filter(|c| !matches!(c, '[' | ']' | ':' | '*' | '?' | '/' | '\\'))
+            .take(31)
+            .collect();
+        let sheet = if sheet.trim().is_empty() {
+            "Sheet1".to_string()
+        } else {
+            sheet
+        };
+        let has_properties = !properties.is_empty();
+        let mut parts: Vec<(&str, String)> = vec![
+            (
+                "[Content_Types].xml",
+                format!(
+                    concat!(
+                        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                        r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">"#,
+                        r#"<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>"#,
+                        r#"<Default Extension="xml" ContentType="application/xml"/>"#,
+                        r#"<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>"#,
+                        r#"<Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#,
+                        "{}",
+                        r#"</Types>"#
+                    ),
+                    if has_properties {
+                        r#"<Override PartName="/docProps/custom.xml" ContentType="application/vnd.openxmlformats-officedocument.custom-properties+xml"/>"#
+                    } else {
+                        ""
+                    }
+                ),
+            ),
+            (
+                "_rels/.rels",
+                format!(
+                    concat!(
+                        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                        r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+                        r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>"#,
+                        "{}",
+                        r#"</Relationships>"#
+                    ),
+                    if has_properties {
+                        r#"<Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/custom-properties" Target="docProps/custom.xml"/>"#
+                    } else {
+                        ""
+                    }
+                ),
+            ),
+            (
+                "xl/workbook.xml",
+                format!(
+                    concat!(
+                        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                        r#"<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" "#,
+                        r#"xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">"#,
+                        r#"<sheets><sheet name="{}" sheetId="1" r:id="rId1"/></sheets></workbook>"#
+                    ),
+                    xml_escape(&sheet)
+                ),
+            ),
+            (
+                "xl/_rels/workbook.xml.rels",
+                concat!(
+                    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                    r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+                    r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/>"#,
+                    r#"</Relationships>"#
+                )
+                .to_string(),
+            ),
+        ];
+        if has_properties {
+            // pid 0 and 1 are reserved; user-defined properties start at 2
+            let body: String = properties
+                .iter()
+                .enumerate()
+                .map(|(i, (name, value))| {
+                    format!(
+                        r#"<property fmtid="{}" pid="{}" name="{}"><vt:lpwstr>{}</vt:lpwstr></property>"#,
+                        CUSTOM_PROPERTIES_FMTID,
+                        i + 2,
+                        xml_escape(name),
+                        xml_escape(value)
+                    )
+                })
+                .collect();
+            parts.push((
+                "docProps/custom.xml",
+                format!(
+                    concat!(
+                        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                        r#"<Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/custom-properties" "#,
+                        r#"xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes">{}</Properties>"#
+                    ),
+                    body
+                ),
+            ));
+        }
+        for (name, body) in parts {
+            writer.begin_entry(name)?;
+            writer.write_entry(body.as_bytes())?;
+            writer.end_entry()?;
+        }
+        writer.begin_entry("xl/worksheets/sheet1.xml")?;
+        writer.write_entry(
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                r#"<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>"#
+            )
+            .as_bytes(),
+        )?;
+        Ok(writer)
+    }
+
+    fn begin_entry(&mut self, name: &str) -> io::Result<()> {
+        let mut out = self
+            .out
+            .take()
+            .ok_or_else(|| io::Error::other("XLSX entry already open"))?;
+        let offset = out.written;
+        out.write_all(&0x04034b50u32.to_le_bytes())?;
+        out.write_all(&ZIP_VERSION.to_le_bytes())?;
+        out.write_all(&ZIP_FLAGS.to_le_bytes())?;
+        out.write_all(&ZIP_METHOD_DEFLATE.to_le_bytes())?;
+        out.write_all(&0u16.to_le_bytes())?; // time
+        out.write_all(&ZIP_DOS_DATE.to_le_bytes())?;
+        out.write_all(&[0u8; 12])?; // crc + sizes follow in the data descriptor
+        out.write_all(&(name.len() as u16).to_le_bytes())?;
+        out.write_all(&0u16.to_le_bytes())?; // extra field length
+        out.write_all(name.as_bytes())?;
+        let data_start = out.written;
+        self.entry = Some(OpenEntry {
+            name: name.to_string(),
+            offset,
+            data_start,
+            crc: Crc::new(),
+            encoder: DeflateEncoder::new(out, Compression::default()),
+        });
+        Ok(())
+    }
+
+    fn write_entry(&mut self, data: &[u8]) -> io::Result<()> {
+        let entry = self
+            .entry
+            .as_mut()
+            .ok_or_else(|| io::Error::other("No XLSX entry open"))?;
+        entry.crc.update(data);
+        entry.encoder.write_all(data)
+    }
+
+    fn end_entry(&mut self) -> io::Result<()> {
+        let entry = self
+            .entry
+            .take()
+            .ok_or_else(|| io::Error::other("No XLSX entry open"))?;
+        let mut out = entry.encoder.finish()?;
+        let record = CentralRecord {
+            name: entry.name,
+            crc: entry.crc.sum(),
+            compressed: to_u32(out.written - entry.data_start)?,
+            uncompressed: entry.crc.amount(),
+            offset: to_u32(entry.offset)?,
+        };
+        out.write_all(&0x08074b50u32.to_le_bytes())?;
+        out.write_all(&record.crc.to_le_bytes())?;
+        out.write_all(&record.compressed.to_le_bytes())?;
+        out.write_all(&record.uncompressed.to_le_bytes())?;
+        self.records.push(record);
+        self.out = Some(out);
+        Ok(())
+    }
+
+    /// Append one row (the first call usually writes the header)
+    pub fn write_row(&mut self, cells: &[XlsxCell]) -> io::Result<()> {
+        if self.rows >= MAX_ROWS {
+            return Err(io::Error::other(format!(
+                "XLSX worksheet row limit ({MAX_ROWS}) reached"
+            )));
+        }
+        let row = self.rows + 1;
+        let mut xml = format!(r#"<row r="{row}">"#);
+        for (i, cell) in cells.iter().enumerate() {
+            let reference = format!("{}{row}", column_letters(i));
+            match cell {
+                XlsxCell::Empty => {}
+                XlsxCell::Number(n) if n.is_finite() => {
+                    xml.push_str(&format!(r#"<c r="{reference}"><v>{n}</v></c>"#));
+                }
+                XlsxCell::Number(n) => {
+                    xml.push_str(&format!(
+                        r#"<c r="{reference}" t="inlineStr"><is><t>{n}</t></is></c>"#
+                    ));
+                }
+                XlsxCell::Text(text) => {
+                    xml.push_str(&format!(
+                        r#"<c r="{reference}" t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+                        xml_escape(text)
+                    ));
+                }
+            }
+        }
+        xml.push_str("</row>");
+        self.write_entry(xml.as_bytes())?;
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Rows written so far, header included
+    pub fn rows_written(&self) -> u64 {
+        self.rows
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        match self.entry.as_mut() {
+            Some(entry) => entry.encoder.flush(),
+            None => Ok(()),
+        }
+    }
+
+    /// Close the sheet, write the zip central directory and flush; returns rows written
+    pub fn finish(mut self) -> io::Result<u64> {
+        self.write_entry(b"</sheetData></worksheet>")?;
+        self.end_entry()?;
+        let mut out = self
+            .out
+            .take()
+            .ok_or_else(|| io::Error::other("XLSX writer closed"))?;
+        let cd_offset = out.written;
+        for record in &self.records {
+            out.write_all(&0x02014b50u32.to_le_bytes())?;
+            out.write_all(&ZIP_VERSION.to_le_bytes())?; // made by
+            out.write_all(&ZIP_VERSION.to_le_bytes())?; // needed
+            out.write_all(&ZIP_FLAGS.to_le_bytes())?;
+            out.write_all(&ZIP_METHOD_DEFLATE.to_le_bytes())?;
+            out.write_all(&0u16.to_le_bytes())?;
+            out.write_all(&ZIP_DOS_DATE.to_le_bytes())?;
+            out.write_all(&record.crc.to_le_bytes())?;
+            out.write_all(&record.compressed.to_le_bytes())?;
+            out.write_all(&record.uncompressed.to_le_bytes())?;
+            out.write_all(&(record.name.len() as u16).to_le_bytes())?;
+            out.write_all(&[0u8; 12])?; // extra, comment, disk, internal + external attrs
+            out.write_all(&record.offset.to_le_bytes())?;
+            out.write_all(record.name.as_bytes())?;
+        }
+        let cd_size = to_u32(out.written - cd_offset)?;
+        let count = self.records.len() as u16;
+        out.write_all(&0x06054b50u32.to_le_bytes())?;
+        out.write_all(&[0u8; 4])?; // disk numbers
+        out.write_all(&count.to_le_bytes())?;
+        out.write_all(&count.to_le_bytes())?;
+        out.write_all(&cd_size.to_le_bytes())?;
+        out.write_all(&to_u32(cd_offset)?.to_le_bytes())?;
+        out.write_all(&0u16.to_le_bytes())?; // comment length
+        out.flush()?;
+        Ok(self.rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::DeflateDecoder;
+    use std::io::Read;
+
+    /// Inflate a named entry by walking the central directory
+    fn read_entry(zip: &[u8], wanted: &str) -> Option<String> {
+        let u16_at = |p: usize| u16::from_le_bytes([zip[p], zip[p + 1]]) as usize;
+        let u32_at = |p: usize| u32::from_le_bytes(zip[p..p + 4].try_into().unwrap()) as usize;
+        let eocd = zip.len() - 22;
+        assert_eq!(u32_at(eocd), 0x06054b50);
+        let mut p = u32_at(eocd + 16);
+        for _ in 0..u16_at(eocd + 10) {
+            let (size, name_len, offset) = (u32_at(p + 20), u16_at(p + 28), u32_at(p + 42));
+            let name = std::str::from_utf8(&zip[p + 46..p + 46 + name_len]).unwrap();
+            if name == wanted {
+                let data = offset + 30 + u16_at(offset + 26);
+                let mut out = String::new();
+                DeflateDecoder::new(&zip[data..data + size])
+                    .read_to_string(&mut out)
+                    .unwrap();
+                return Some(out);
+            }
+            p += 46 + name_len;
+        }
+        None
+    }
+
+    #[test]
+    fn column_letters_roll_over() {
+        assert_eq!(column_letters(0), "A");
+        assert_eq!(column_letters(25), "Z");
+        assert_eq!(column_letters(26), "AA");
+        assert_eq!(column_letters(701), "ZZ");
+        assert_eq!(column_letters(702), "AAA");
+    }
+
+    #[test]
+    fn writes_readable_workbook() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.xlsx");
+        let mut writer = XlsxWriter::create(&path, "Products").unwrap();
+        writer
+            .write_row(&[
+                XlsxCell::Text("Vendor".into()),
+                XlsxCell::Text("VID".into()),
+            ])
+            .unwrap();
+        writer
+            .write_row(&[XlsxCell::Text("A & <B>".into()), XlsxCell::Number(4660.0)])
+            .unwrap();
+        writer
+            .write_row(&[XlsxCell::Empty, XlsxCell::Number(1.5)])
+            .unwrap();
+        assert_eq!(writer.finish().unwrap(), 3);
+
+        let zip = std::fs::read(&path).unwrap();
+        let sheet = read_entry(&zip, "xl/worksheets/sheet1.xml").unwrap();
+        assert!(sheet.contains(
+            r#"<c r="A2" t="inlineStr"><is><t xml:space="preserve">A &amp; &lt;B&gt;</t></is></c>"#
+        ));
+        assert!(sheet.contains(r#"<c r="B2"><v>4660</v></c>"#));
+        assert!(sheet.contains(r#"<row r="3"><c r="B3"><v>1.5</v></c></row>"#));
+        assert!(sheet.ends_with("</sheetData></worksheet>"));
+        let workbook = read_entry(&zip, "xl/workbook.xml").unwrap();
+        assert!(workbook.contains(r#"<sheet name="Products""#));
+        assert!(read_entry(&zip, "[Content_Types].xml").is_some());
+        assert!(read_entry(&zip, "docProps/custom.xml").is_none());
+    }
+
+    #[test]
+    fn custom_properties_are_registered() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("props.xlsx");
+        let writer =
+            XlsxWriter::create_with_properties(&path, "Products", &[("schema_version", "1")])
+                .unwrap();
+        writer.finish().unwrap();
+
+        let zip = std::fs::read(&path).unwrap();
+        let custom = read_entry(&zip, "docProps/custom.xml").unwrap();
+        assert!(custom.contains(r#"pid="2" name="schema_version"><vt:lpwstr>1</vt:lpwstr>"#));
+        assert!(
+            read_entry(&zip, "[Content_Types].xml")
+                .unwrap()
+                .contains("/docProps/custom.xml")
+        );
+        assert!(
+            read_entry(&zip, "_rels/.rels")
+                .unwrap()
+                .contains(r#"Target="docProps/custom.xml""#)
+        );
+    }
+}
@@ -46,8 +46,10 @@ pub mod crawl_engine; // renamed from new_architecture
 pub mod domain {
     //! Domain module - Core business logic and entities
     pub mod atomic_events; // 추가: 원자적 태스크 이벤트
+    pub mod command_error; // 커맨드 경계의 구조화된 에러 (카테고리 + 재시도 힌트)
     pub mod constants; // 추가: 사이트 및 도메인 상수들
     pub mod entities;
+    pub mod error_catalog; // 스테이지 무관 에러 코드 카탈로그 + 조치 안내
     pub mod events;
     pub mod product_url;
     pub mod repositories;
@@ -76,6 +78,8 @@ pub mod domain {
     pub mod pagination;
     pub mod product;
     pub mod session_manager; // PHASE1: page_id/index_in_page 중앙 집중 모듈 (legacy -> canonical 전환용)
+    pub mod site_profile; // 크롤링 대상 사이트 프로필 (URL / 셀렉터 / 페이지당 제품 수)
+    pub mod warning_catalog; // SyncWarning 코드 카탈로그 (심각도 + 설명)
 
     // Re-export commonly used items
     pub use entities::*;
@@ -130,23 +134,56 @@ pub mod commands {
     pub mod actor_system_commands; // 🎭 NEW: Actor System commands
     pub mod actor_system_monitoring;
     pub mod advanced_engine_api; // 새로운 Advanced Engine API 추가
+    pub mod analytics; // 📊 Cached dashboard chart series
+    pub mod chunked_query; // 📑 start_query / fetch_query_chunk server-side cursors
     pub mod config_commands;
     pub mod crawling_test_commands; // 🧪 Phase C: 크롤링 테스트 도구
     pub mod dashboard_commands; // 🎨 Phase C: 실시간 대시보드
+    pub mod dashboard_overview; // 🧭 Main dashboard state in one call
+    pub mod data_export; // 📦 JSON/NDJSON export of products + product_details
     pub mod data_queries; // Backend-Only CRUD commands (Modern Rust 2024)
+    pub mod postgres_check; // 🐘 Postgres connectivity check (storage stays on SQLite)
+    pub mod database_import; // 📥 Merge another machine's DB snapshot by URL
+    pub mod db_backup; // 💾 Pre-destructive DB backups + staged restore
     pub mod db_cleanup;
     pub mod db_diagnostics; // 🧪 DB pagination mismatch scan
     pub mod db_repair; // 🔧 DB repair/sync between products and product_details
     pub mod debug_commands; // 🔎 UI debug logging helpers
+    pub mod dedup_conflicts; // 🧬 Dedup merge conflict queue
+    pub mod error_catalog; // 🏷️ describe_error / list_error_codes / describe_warning
+    pub mod extractor_rollout; // 🧪 get_extractor_rollout / promote / rollback extractor rules
+    pub mod failed_items; // 🪦 list_failed_items / requeue_failed_items (dead-letter queue)
+    pub mod field_refresh; // 🪄 refresh_fields (re-fetch details, rewrite chosen columns only)
+    pub mod incremental_crawl; // 🆕 New-products-only crawl since the DB head
+    pub mod item_priority; // ⏫ prioritize_items (boost pages/URLs in the active session)
+    pub mod light_sync_scheduler; // 🕒 Daily light-sync + anomaly gate
+    pub mod migration_rehearsal; // 🧪 Rehearse pending schema migrations on a DB copy
+    pub mod page_url_sets; // 🧾 Per-session list page URL sets (post-hoc analysis)
     pub mod performance_commands; // 🔧 Phase C: 성능 최적화 도구
+    pub mod plan_preview; // 🔍 Crawl dry-run / plan preview
+    pub mod product_history; // 🕰️ Product field change history (audit trail)
+    pub mod product_identity; // 🪪 Switch product identity strategy (URL / certificate_id)
+    pub mod query_control; // ⏱️ cancel_query / list_running_queries (query timeout middleware)
     pub mod real_actor_commands; // 🎭 진짜 Actor 시스템 명령어
     pub mod real_crawling_commands; // 🚀 Phase C: 실제 크롤링 기능
+    pub mod rolling_refresh; // ♻️ Certification-recency rolling refresh profile
+    pub mod schedule_commands; // 🗓️ Recurring crawl schedules
+    pub mod search_index; // 🔎 Search index / rollup drift check + repair
+    pub mod session_logs; // 🪵 get_session_logs (per-session captured tracing events)
+    pub mod session_notes; // 🗒️ Operator notes on sessions/batches (included in reports)
+    pub mod settings_bundle; // 📦 Settings bundle export/import + presets
+    pub mod simulated_crawl; // 🧪 Crawl against the built-in mock site (mock-site builds)
     pub mod simple_actor_test;
     pub mod smart_crawling;
+    pub mod startup_integrity; // 🩺 get_startup_integrity_report (launch-time DB self-check)
     pub mod sync_commands;
     pub mod system_analysis; // 시스템 분석 명령어
+    pub mod time_boxed_crawl; // ⏱️ Deadline-bound crawl, most stale pages first
     pub mod unified_crawling; // 🎯 NEW: 통합 크롤링 명령어 (Actor 시스템 진입점)
+    pub mod url_templates; // 🔗 Live check of configured listing/product URL templates
     pub mod validation_commands; // ✅ Validation pass commands (page/index integrity) // 🔄 Partial Sync (recrawl + DB upsert) // 🧹 DB URL duplicate cleanup
+    pub mod watchlist; // 👀 Watched products/manufacturers + change diffs
+    pub mod workspaces; // 🗂️ Named database workspaces (list / create / switch)
 
     // Re-export commonly used commands
     // simple_crawling removed
@@ -225,6 +262,24 @@ pub fn run() {
         );
     }
 
+    // Channel capacities / batch sizes / watermarks: defaults + system_config.toml + env
+    match ConfigManager::get_config_dir() {
+        Ok(dir) => match crawl_engine::system_config::SystemConfig::load_overrides(&dir) {
+            Ok(system_config) => {
+                let channels = &system_config.channels;
+                info!(
+                    "⚙️ SystemConfig -> session_events={}, actor_commands={}, bridge_queue={}",
+                    channels.stage_capacities.session_events,
+                    channels.stage_capacities.actor_commands,
+                    channels.bridge_queue_capacity
+                );
+                crawl_engine::system_config::SystemConfig::install(system_config);
+            }
+            Err(e) => error!("❌ Invalid system config, using defaults: {}", e),
+        },
+        Err(e) => warn!("⚠️ No config directory for system config overrides: {}", e),
+    }
+
     // Initialize runtime for async operations (already created above)
     info!("✅ Tokio runtime initialized successfully");
 
@@ -347,6 +402,7 @@ pub fn run() {
         .manage(commands::simple_actor_test::ActorSystemState::default())
         .manage(commands::performance_commands::PerformanceOptimizerState::default())
         .manage(commands::dashboard_commands::DashboardServiceState::default())
+        .manage(services::crawl_scheduler::CrawlSchedulerService::default())
         .setup(|app| {
             let app_handle = app.handle().clone();
 
@@ -363,8 +419,45 @@ pub fn run() {
                 }
                 info!("✅ Database connection pool initialized");
 
+                // Launch-time integrity self-check → banner (does not hold up the rest of startup)
+                if let Ok(pool) = state.get_database_pool().await {
+                    let integrity_app = app_handle.clone();
+                    tauri::async_runtime::spawn(async move {
+                        use crate::crawl_engine::actors::types::AppEvent;
+                        match services::startup_integrity::run_and_cache(&pool).await {
+                            Ok(report) => {
+                                info!(
+                                    "🩺 Startup integrity check ({} ms): {}",
+                                    report.elapsed_ms, report.summary
+                                );
+                                commands::validation_commands::emit_actor_event(
+                                    &integrity_app,
+                                    AppEvent::DataIntegritySnapshot {
+                                        report,
+                                        timestamp: chrono::Utc::now(),
+                                    },
+                                );
+                            }
+                            Err(e) => warn!("⚠️ Startup integrity check failed: {}", e),
+                        }
+                    });
+                }
+
+                // Postgres check: connect + migrate once so a bad URL shows up at startup
+                let postgres_config = state.get_config().await.advanced.database;
+                if postgres_config.postgres_url.is_some() {
+                    let status =
+                        infrastructure::postgres_check::check_postgres(&postgres_config).await;
+                    match status.error {
+                        None => info!("🐘 Postgres product store ready: {}", status.url),
+                        Some(e) => warn!("⚠️ Postgres product store unavailable: {}", e),
+                    }
+                }
+
                 // 2. Initialize event emitter
-                let emitter = application::EventEmitter::new(app_handle.clone());
+                let mirror_config = state.get_config().await.advanced.event_log_mirror;
+                let emitter = application::EventEmitter::new(app_handle.clone())
+                    .with_log_mirror(mirror_config);
                 if let Err(e) = state.initialize_event_emitter(emitter).await {
                     error!("❌ Failed to initialize event emitter: {}", e);
                     return;
@@ -377,6 +470,23 @@ pub fn run() {
                     return;
                 }
                 info!("✅ HTTP client initialized (shared)");
+                // Circuit breaker open/close events go to the window
+                crate::infrastructure::circuit_breaker::set_event_sink(std::sync::Arc::new(
+                    app_handle.clone(),
+                ));
+                // Cool-downs from a hostile period before the last shutdown still apply
+                crate::infrastructure::circuit_breaker::restore_persisted_state();
+                // Promoted extraction rules and a rollout still comparing new ones
+                crate::infrastructure::extractor_rollout::restore_persisted_state();
+                // Temp spill files of sessions that died with the previous process
+                match crate::infrastructure::session_spill::shared_spill_store().sweep_stale().await {
+                    Ok(sweep) if sweep.removed_sessions > 0 => info!(
+                        "🧹 Removed temp storage of {} stale sessions ({} bytes)",
+                        sweep.removed_sessions, sweep.freed_bytes
+                    ),
+                    Ok(_) => {}
+                    Err(e) => warn!("⚠️ Failed to sweep stale temp storage: {}", e),
+                }
 
                 // 4. Start system state broadcaster (10s intervals)
                 info!("� Starting system state broadcaster...");
@@ -384,6 +494,36 @@ pub fn run() {
                     app_handle.clone(),
                 );
 
+                // 5. Start daily light-sync scheduler (idle unless enabled in config)
+                commands::light_sync_scheduler::start_light_sync_scheduler(app_handle.clone());
+
+                // 6. Start recurring crawl schedules (cron / interval)
+                services::crawl_scheduler::start_crawl_scheduler(app_handle.clone());
+
+                // 7. Serve events over local SSE for headless monitoring (feature-gated, off by default)
+                #[cfg(feature = "event-stream")]
+                crate::infrastructure::event_stream::start_event_stream_server(
+                    &state.get_config().await.advanced.event_stream,
+                );
+
+                // 8. Accept JSON-RPC from companion tools over a local socket (feature-gated, off by default)
+                #[cfg(feature = "ipc-server")]
+                crate::infrastructure::ipc_server::start_ipc_server(
+                    app_handle.clone(),
+                    &state.get_config().await.advanced.ipc_server,
+                );
+
+                // 9. Serve Prometheus metrics on a local port (feature-gated, off by default)
+                #[cfg(feature = "metrics-exporter")]
+                crate::infrastructure::metrics_exporter::start_metrics_exporter(
+                    &state.get_config().await.advanced.metrics_exporter,
+                );
+
+                // 10. Warn about tracked background tasks outliving their expected lifetime
+                crawl_engine::runtime::task_registry::start_task_watchdog(
+                    std::time::Duration::from_secs(60),
+                );
+
                 info!("🎯 Unified backend services initialization complete");
             });
 
@@ -421,14 +561,31 @@ pub fn run() {
             commands::data_queries::get_latest_products,
             commands::data_queries::get_crawling_status_v2,
             commands::data_queries::get_system_status,
+            commands::data_queries::get_product_details_by_urls,
+            commands::data_queries::search_products,
+            commands::data_export::export_products,
+            commands::data_export::get_export_schema,
+            // 📑 Chunked retrieval of large query results
+            commands::chunked_query::start_query,
+            commands::chunked_query::fetch_query_chunk,
+            commands::chunked_query::close_query,
+            commands::chunked_query::list_open_queries,
             // Window Management commands (이미 config_commands에 구현됨)
             commands::config_commands::save_window_state,
             commands::config_commands::load_window_state,
+            commands::config_commands::list_ui_preferences,
+            commands::config_commands::get_ui_preference,
+            commands::config_commands::set_ui_preference,
+            commands::config_commands::get_grid_preferences,
+            commands::config_commands::save_grid_preferences,
+            commands::config_commands::reset_grid_preferences,
             commands::config_commands::set_window_position,
             commands::config_commands::set_window_size,
             commands::config_commands::maximize_window,
             commands::config_commands::show_window,
             commands::config_commands::write_frontend_log,
+            commands::config_commands::set_slim_ipc_mode,
+            commands::config_commands::get_slim_ipc_mode,
             // New Architecture Actor System commands (OneShot integration 완료)
             commands::simple_actor_test::test_new_arch_channels,
             commands::simple_actor_test::test_new_arch_performance,
@@ -436,11 +593,16 @@ pub fn run() {
             commands::actor_system_commands::start_actor_system_crawling,
             commands::actor_system_commands::pause_session,
             commands::actor_system_commands::resume_session,
+            commands::actor_system_commands::resume_crawling_session,
             commands::actor_system_commands::get_session_status,
             commands::actor_system_commands::request_graceful_shutdown,
             commands::actor_system_commands::test_session_actor_basic,
             commands::actor_system_commands::list_actor_sessions,
+            commands::actor_system_commands::get_event_bridge_stats,
+            commands::actor_system_commands::list_background_tasks,
             commands::actor_system_commands::check_page_index_consistency,
+            commands::plan_preview::preview_crawling_plan,
+            commands::plan_preview::export_execution_plan,
             // Real Crawling Integration commands (Option B implementation)
             // Note: These commands are temporarily disabled due to module restructuring
             // They will be re-enabled after Phase 2 completion
@@ -475,8 +637,34 @@ pub fn run() {
             // Settings store commands
             commands::config_commands::get_app_settings,
             commands::config_commands::save_app_settings,
+            commands::config_commands::probe_host_capabilities,
+            commands::config_commands::get_robots_status,
+            commands::config_commands::get_html_cache_stats,
+            commands::config_commands::clear_html_cache,
             crate::commands_integrated::reset_product_storage,
+            crate::commands_integrated::run_db_maintenance,
+            commands::db_backup::list_db_backups,
+            commands::db_backup::restore_db_backup,
+            commands::database_import::import_database,
+            commands::product_identity::switch_product_identity_strategy,
+            commands::simulated_crawl::run_simulated_crawl,
+            commands::migration_rehearsal::rehearse_migrations,
             commands::validation_commands::start_validation,
+            commands::validation_commands::run_db_cross_check,
+            commands::validation_commands::get_validation_findings,
+            commands::validation_commands::repair_validation_findings,
+            commands::validation_commands::apply_validation_repairs,
+            commands::session_notes::annotate_session,
+            commands::session_notes::get_session_notes,
+            commands::page_url_sets::get_page_url_set,
+            commands::page_url_sets::get_page_url_history,
+            commands::page_url_sets::prune_page_url_sets,
+            commands::field_refresh::refresh_fields,
+            commands::failed_items::list_failed_items,
+            commands::failed_items::requeue_failed_items,
+            commands::extractor_rollout::get_extractor_rollout,
+            commands::extractor_rollout::promote_extractor_rules,
+            commands::extractor_rollout::rollback_extractor_rules,
             commands::sync_commands::start_partial_sync, // TODO: Add other commands as they are implemented
             commands::sync_commands::start_batched_sync,
             commands::sync_commands::start_repair_sync,
@@ -484,10 +672,70 @@ pub fn run() {
             commands::sync_commands::start_basic_sync_pages,
             commands::sync_commands::retry_failed_details,
             commands::sync_commands::start_diagnostic_sync,
+            commands::sync_commands::get_sync_changeset,
+            commands::sync_commands::cancel_sync_session,
+            commands::sync_commands::list_running_syncs,
+            commands::sync_commands::pause_sync_session,
+            commands::sync_commands::resume_sync_session,
+            commands::sync_commands::list_paused_syncs,
+            commands::light_sync_scheduler::run_light_sync_now,
+            commands::rolling_refresh::preview_rolling_refresh,
+            commands::rolling_refresh::run_rolling_refresh,
+            commands::incremental_crawl::preview_incremental_crawl,
+            commands::incremental_crawl::run_incremental_crawl,
+            commands::time_boxed_crawl::preview_time_boxed_crawl,
+            commands::time_boxed_crawl::run_time_boxed_crawl,
+            commands::url_templates::test_url_templates,
+            commands::watchlist::add_watch_target,
+            commands::watchlist::remove_watch_target,
+            commands::watchlist::list_watch_targets,
+            commands::watchlist::check_watchlist_changes,
+            commands::watchlist::run_watchlist_refresh,
+            commands::product_history::get_product_history,
+            commands::product_history::get_recent_changes,
+            commands::product_history::explain_row,
+            commands::schedule_commands::create_crawl_schedule,
+            commands::schedule_commands::list_crawl_schedules,
+            commands::schedule_commands::delete_crawl_schedule,
+            commands::settings_bundle::export_settings,
+            commands::settings_bundle::import_settings,
+            commands::settings_bundle::list_settings_presets,
+            commands::settings_bundle::save_settings_preset,
+            commands::settings_bundle::delete_settings_preset,
             commands::actor_system_commands::start_manual_crawl_pages_actor,
             commands::db_diagnostics::scan_db_pagination_mismatches,
+            commands::db_diagnostics::get_coordinate_violations,
+            commands::db_diagnostics::get_db_runtime_settings,
+            commands::db_diagnostics::get_persistence_queue_metrics,
+            commands::db_diagnostics::flush_persistence_queue,
             commands::debug_commands::ui_debug_log,
             commands::db_repair::sync_product_details_coordinates,
+            commands::dedup_conflicts::scan_dedup_conflicts,
+            commands::dedup_conflicts::get_dedup_conflicts,
+            commands::dedup_conflicts::resolve_dedup_conflict,
+            commands::dedup_conflicts::apply_dedup_strategy,
+            commands::error_catalog::describe_error,
+            commands::error_catalog::list_error_codes,
+            commands::error_catalog::describe_warning,
+            commands::error_catalog::list_warning_codes,
+            commands::session_logs::get_session_logs,
+            commands::query_control::cancel_query,
+            commands::query_control::list_running_queries,
+            commands::startup_integrity::get_startup_integrity_report,
+            commands::workspaces::list_workspaces,
+            commands::workspaces::create_workspace,
+            commands::workspaces::switch_workspace,
+            commands::postgres_check::check_postgres_connection,
+            commands::dashboard_overview::get_dashboard_overview,
+            commands::item_priority::prioritize_items,
+            commands::performance_commands::get_concurrency_targets,
+            commands::performance_commands::get_batch_size_suggestion,
+            commands::performance_commands::get_memory_stats,
+            commands::search_index::check_search_index,
+            commands::analytics::get_chart_series,
+            commands::db_cleanup::analyze_identity_duplicates,
+            commands::db_cleanup::merge_identity_duplicates,
+            commands::db_cleanup::normalize_product_urls,
             commands::db_cleanup::cleanup_duplicate_urls // Most commands are temporarily disabled for compilation
         ]);
 
@@ -0,0 +1,395 @@
+//! 대시보드 차트 집계 캐시
+//!
+//! 차트(월별 제품 수, 디바이스 유형별 제품 수)는 `product_details` 전체 집계라 화면을 열 때마다
+//! 다시 돌리기엔 비싸다. `product_details` 트리거가 쓰기 시 영향을 받는 집계의 버전만 올리고
+//! (`analytics_versions`), 캐시된 시리즈는 계산할 때 읽은 버전이 현재 버전과 같으면 그대로 쓴다.
+//! INSERT/DELETE는 모든 집계를, UPDATE는 값이 바뀐 컬럼에 의존하는 집계만 무효화한다.
+//! 트리거 방식이라 repository upsert, write-behind 큐, sync SQL 등 어떤 쓰기 경로든 같은 규칙을 따른다.
+//! 오래된 시리즈는 `stale`로 표시해 즉시 돌려주고 재계산은 백그라운드에서 한 번만 돈다.
+
+use crate::crawl_engine::runtime::task_registry::spawn_tracked;
+use anyhow::Result;
+use chrono::Utc;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, warn};
+use ts_rs::TS;
+
+/// Device-type chart keeps the largest buckets and folds the rest into "Other"
+pub const DEFAULT_DEVICE_TYPE_LIMIT: u32 = 20;
+pub const MAX_DEVICE_TYPE_LIMIT: u32 = 200;
+
+/// Bucket label for NULL/blank device types
+const UNKNOWN_LABEL: &str = "Unknown";
+const OTHER_LABEL: &str = "Other";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum ChartId {
+    /// Products by certification month (`YYYY-MM`), oldest first
+    ProductsPerMonth,
+    /// Products by device type, largest first
+    ProductsPerDeviceType,
+}
+
+impl ChartId {
+    /// Row in `analytics_versions` that the chart's freshness follows
+    pub fn aggregate(self) -> &'static str {
+        match self {
+            ChartId::ProductsPerMonth => "products_per_month",
+            ChartId::ProductsPerDeviceType => "products_per_device_type",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim() {
+            "products_per_month" => Some(ChartId::ProductsPerMonth),
+            "products_per_device_type" => Some(ChartId::ProductsPerDeviceType),
+            _ => None,
+        }
+    }
+}
+
+/// Chart parameters; each distinct value set is cached separately
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ChartParams {
+    /// Inclusive `YYYY-MM` bounds (products_per_month)
+    #[serde(default)]
+    pub from_month: Option<String>,
+    #[serde(default)]
+    pub to_month: Option<String>,
+    /// Bucket count before "Other" (products_per_device_type)
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+impl ChartParams {
+    fn validate(&self) -> Result<()> {
+        for month in [&self.from_month, &self.to_month].into_iter().flatten() {
+            let valid = month.len() == 7
+                && month.as_bytes()[4] == b'-'
+                && month
+                    .bytes()
+                    .enumerate()
+                    .all(|(i, b)| i == 4 || b.is_ascii_digit());
+            if !valid {
+                anyhow::bail!("Invalid month '{month}' (expected YYYY-MM)");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, TS)]
+#[ts(export)]
+pub struct ChartPoint {
+    pub label: String,
+    pub value: i64,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ChartSeries {
+    pub chart_id: ChartId,
+    pub points: Vec<ChartPoint>,
+    /// RFC 3339, when the series was aggregated
+    pub computed_at: String,
+    /// Writes since `computed_at` changed the aggregate; a refresh is running
+    pub stale: bool,
+}
+
+#[derive(Debug, Clone)]
+struct CachedSeries {
+    version: i64,
+    points: Vec<ChartPoint>,
+    computed_at: String,
+}
+
+type CacheKey = (ChartId, ChartParams);
+
+/// In-memory chart cache; `shared()` is the instance used by the commands
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsCache {
+    entries: Arc<Mutex<HashMap<CacheKey, CachedSeries>>>,
+    refreshing: Arc<Mutex<HashSet<CacheKey>>>,
+}
+
+static SHARED: Lazy<Mutex<AnalyticsCache>> = Lazy::new(|| Mutex::new(AnalyticsCache::default()));
+
+async fn current_version(pool: &SqlitePool, chart: ChartId) -> Result<i64> {
+    let version: Option<i64> =
+        sqlx::query_scalar("SELECT version FROM analytics_versions WHERE aggregate = ?")
+            .bind(chart.aggregate())
+            .fetch_optional(pool)
+            .await?;
+    Ok(version.unwrap_or(0))
+}
+
+impl AnalyticsCache {
+    pub fn shared() -> Self {
+        SHARED.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Replace the shared cache with an empty one. Versions are per database, so entries of the
+    /// previous pool must not survive a workspace switch; refreshes still running fill the old
+    /// cache, not the new one.
+    pub fn reset_shared() {
+        *SHARED.lock().unwrap_or_else(|e| e.into_inner()) = Self::default();
+    }
+
+    /// Cached series when its aggregate has not been written since; otherwise the stale series
+    /// with a background refresh, or a synchronous computation when nothing is cached yet
+    pub async fn get_chart_series(
+        &self,
+        pool: &SqlitePool,
+        chart: ChartId,
+        params: ChartParams,
+    ) -> Result<ChartSeries> {
+        params.validate()?;
+        let version = current_version(pool, chart).await?;
+        let key = (chart, params);
+        let cached = self
+            .entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&key)
+            .cloned();
+        match cached {
+            Some(entry) if entry.version == version => Ok(series(chart, entry, false)),
+            Some(entry) => {
+                self.spawn_refresh(pool.clone(), key);
+                Ok(series(chart, entry, true))
+            }
+            None => {
+                let entry = self.refresh(pool, &key).await?;
+                Ok(series(chart, entry, false))
+            }
+        }
+    }
+
+    /// Recompute one series now and store it
+    async fn refresh(&self, pool: &SqlitePool, key: &CacheKey) -> Result<CachedSeries> {
+        // Version is read first: a write racing the aggregate leaves the entry stale
+        let version = current_version(pool, key.0).await?;
+        let points = compute_points(pool, key.0, &key.1).await?;
+        let entry = CachedSeries {
+            version,
+            points,
+            computed_at: Utc::now().to_rfc3339(),
+        };
+        debug!(
+            "📊 chart {:?} recomputed at version {} ({} points)",
+            key.0,
+            version,
+            entry.points.len()
+        );
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key.clone(), entry.clone());
+        Ok(entry)
+    }
+
+    fn spawn_refresh(&self, pool: SqlitePool, key: CacheKey) {
+        if !self
+            .refreshing
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key.clone())
+        {
+            return;
+        }
+        let cache = self.clone();
+        spawn_tracked(format!("chart-refresh:{:?}", key.0), None, async move {
+            if let Err(e) = cache.refresh(&pool, &key).await {
+                warn!("📊 chart {:?} refresh failed: {e:#}", key.0);
+            }
+            cache
+                .refreshing
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(&key);
+        });
+    }
+}
+
+fn series(chart_id: ChartId, entry: CachedSeries, stale: bool) -> ChartSeries {
+    ChartSeries {
+        chart_id,
+        points: entry.points,
+        computed_at: entry.computed_at,
+        stale,
+    }
+}
+
+async fn compute_points(
+    pool: &SqlitePool,
+    chart: ChartId,
+    params: &ChartParams,
+) -> Result<Vec<ChartPoint>> {
+    match chart {
+        ChartId::ProductsPerMonth => {
+            // Only ISO-dated rows (`YYYY-MM...`) have a month bucket
+            let rows = sqlx::query(
+                "SELECT substr(certification_date, 1, 7) AS month, COUNT(*) AS cnt \
+                 FROM product_details \
+                 WHERE certification_date GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]*' \
+                   AND (? IS NULL OR substr(certification_date, 1, 7) >= ?) \
+                   AND (? IS NULL OR substr(certification_date, 1, 7) <= ?) \
+                 GROUP BY month ORDER BY month",
+            )
+            .bind(&params.from_month)
+            .bind(&params.from_month)
+            .bind(&params.to_month)
+            .bind(&params.to_month)
+            .fetch_all(pool)
+            .await?;
+            rows.iter()
+                .map(|row| {
+                    Ok(ChartPoint {
+                        label: row.try_get("month")?,
+                        value: row.try_get("cnt")?,
+                    })
+                })
+                .collect()
+        }
+        ChartId::ProductsPerDeviceType => {
+            let limit = params
+                .limit
+                .unwrap_or(DEFAULT_DEVICE_TYPE_LIMIT)
+                .clamp(1, MAX_DEVICE_TYPE_LIMIT) as usize;
+            let rows = sqlx::query(
+                "SELECT NULLIF(TRIM(device_type), '') AS device_type, COUNT(*) AS cnt \
+                 FROM product_details GROUP BY 1 ORDER BY cnt DESC, 1",
+            )
+            .fetch_all(pool)
+            .await?;
+            let mut points = Vec::with_capacity(limit.min(rows.len()) + 1);
+            let mut other = 0i64;
+            for (i, row) in rows.iter().enumerate() {
+                let value: i64 = row.try_get("cnt")?;
+                if i < limit {
+                    let label: Option<String> = row.try_get("device_type")?;
+                    points.push(ChartPoint {
+                        label: label.unwrap_or_else(|| UNKNOWN_LABEL.to_string()),
+                        value,
+                    });
+                } else {
+                    other += value;
+                }
+            }
+            if other > 0 {
+                points.push(ChartPoint {
+                    label: OTHER_LABEL.to_string(),
+                    value: other,
+                });
+            }
+            Ok(points)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    fn labels(series: &ChartSeries) -> Vec<(&str, i64)> {
+        series
+            .points
+            .iter()
+            .map(|p| (p.label.as_str(), p.value))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn writes_invalidate_only_affected_charts() {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        let pool = db.pool().clone();
+        sqlx::query("INSERT INTO products (url) VALUES ('a'), ('b'), ('c'), ('d'), ('e')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO product_details (url, device_type, certification_date, model) VALUES \
+             ('a', 'Light', '2024-01-05', 'A'), ('b', 'Light', '2024-02-10', 'B'), \
+             ('c', 'Plug', '2024-02-11', 'C'), ('d', NULL, 'n/a', 'D')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let cache = AnalyticsCache::default();
+        let month = ChartParams::default();
+        let device = ChartParams {
+            limit: Some(1),
+            ..Default::default()
+        };
+        let months = cache
+            .get_chart_series(&pool, ChartId::ProductsPerMonth, month.clone())
+            .await
+            .unwrap();
+        assert!(!months.stale);
+        assert_eq!(labels(&months), vec![("2024-01", 1), ("2024-02", 2)]);
+        let types = cache
+            .get_chart_series(&pool, ChartId::ProductsPerDeviceType, device.clone())
+            .await
+            .unwrap();
+        assert_eq!(labels(&types), vec![("Light", 2), ("Other", 2)]);
+
+        // Unrelated column: both charts stay fresh
+        sqlx::query("UPDATE product_details SET model = 'A2' WHERE url = 'a'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        // Device type change: only the device chart goes stale
+        sqlx::query("UPDATE product_details SET device_type = 'Plug' WHERE url = 'a'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let months_again = cache
+            .get_chart_series(&pool, ChartId::ProductsPerMonth, month.clone())
+            .await
+            .unwrap();
+        assert!(!months_again.stale);
+        assert_eq!(months_again.computed_at, months.computed_at);
+        let stale_types = cache
+            .get_chart_series(&pool, ChartId::ProductsPerDeviceType, device.clone())
+            .await
+            .unwrap();
+        assert!(stale_types.stale);
+        assert_eq!(labels(&stale_types), vec![("Light", 2), ("Other", 2)]);
+
+        cache
+            .refresh(&pool, &(ChartId::ProductsPerDeviceType, device.clone()))
+            .await
+            .unwrap();
+        let fresh_types = cache
+            .get_chart_series(&pool, ChartId::ProductsPerDeviceType, device)
+            .await
+            .unwrap();
+        assert!(!fresh_types.stale);
+        assert_eq!(labels(&fresh_types), vec![("Plug", 2), ("Other", 2)]);
+
+        // Inserts invalidate every chart
+        sqlx::query(
+            "INSERT INTO product_details (url, device_type, certification_date, model) \
+             VALUES ('e', 'Plug', '2024-03-01', 'E')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert!(
+            cache
+                .get_chart_series(&pool, ChartId::ProductsPerMonth, month)
+                .await
+                .unwrap()
+                .stale
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! 공유용 내보내기 익명화 프로필
+//!
+//! 내보내기 파일이나 설정 번들을 외부에 넘길 때 호출마다 프로필을 지정해 필드를 빼거나(`exclude`)
+//! 해시로 바꾸고(`hash`), 이 머신의 앱 데이터/홈 디렉터리 경로를 `<app_data>` / `<home>`으로 치환한다.
+//! 필드 이름은 접두어 없는 키 이름으로 맞춘다: CSV/XLSX 열의 필드, JSON 객체의 키(중첩 포함).
+//! 해시는 같은 `salt`에서 같은 값이면 같은 결과라 익명화된 파일끼리도 조인할 수 있다.
+
+use crate::infrastructure::config::ConfigManager;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Hex digits kept from the BLAKE3 digest
+const DIGEST_HEX_LEN: usize = 16;
+
+/// Per-invocation anonymization settings for shared exports and bundles
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnonymizationProfile {
+    /// Fields dropped from the output (CSV/XLSX columns, JSON keys)
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Fields whose values are replaced by `h:<digest>`
+    #[serde(default)]
+    pub hash: Vec<String>,
+    /// Mixed into every digest; leave unset to keep digests stable across exports
+    #[serde(default)]
+    pub salt: Option<String>,
+    /// Replace app data / home directory prefixes in string values
+    #[serde(default = "default_redact_paths")]
+    pub redact_paths: bool,
+}
+
+fn default_redact_paths() -> bool {
+    true
+}
+
+impl Default for AnonymizationProfile {
+    fn default() -> Self {
+        Self {
+            exclude: Vec::new(),
+            hash: Vec::new(),
+            salt: None,
+            redact_paths: default_redact_paths(),
+        }
+    }
+}
+
+impl AnonymizationProfile {
+    /// Validate the profile and resolve this machine's directories to redact
+    pub fn anonymizer(&self) -> Result<Anonymizer, String> {
+        let mut roots = Vec::new();
+        if self.redact_paths {
+            if let Ok(dir) = ConfigManager::get_app_data_dir() {
+                roots.push((dir.display().to_string(), "<app_data>"));
+            }
+            if let Some(dir) = dirs::home_dir() {
+                roots.push((dir.display().to_string(), "<home>"));
+            }
+        }
+        Anonymizer::new(self, roots)
+    }
+}
+
+/// A validated profile, ready to apply
+#[derive(Debug, Clone)]
+pub struct Anonymizer {
+    exclude: HashSet<String>,
+    hash: HashSet<String>,
+    salt: String,
+    /// (prefix, label); longest prefix first so nested roots win
+    path_roots: Vec<(String, &'static str)>,
+}
+
+impl Anonymizer {
+    fn new(
+        profile: &AnonymizationProfile,
+        mut path_roots: Vec<(String, &'static str)>,
+    ) -> Result<Self, String> {
+        let normalize = |names: &[String]| -> Result<HashSet<String>, String> {
+            names
+                .iter()
+                .map(|n| match n.trim() {
+                    "" => Err("Anonymization field names must not be empty".to_string()),
+                    name => Ok(name.to_string()),
+                })
+                .collect()
+        };
+        let exclude = normalize(&profile.exclude)?;
+        let hash = normalize(&profile.hash)?;
+        let mut both: Vec<&String> = exclude.intersection(&hash).collect();
+        if !both.is_empty() {
+            both.sort();
+            return Err(format!(
+                "Fields cannot be both excluded and hashed: {}",
+                both.iter()
+                    .map(|s| s.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        // A bare "/" root would rewrite every absolute path
+        path_roots.retain(|(root, _)| root.trim_end_matches(['/', '\\']).len() > 1);
+        path_roots.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+        Ok(Self {
+            exclude,
+            hash,
+            salt: profile.salt.clone().unwrap_or_default(),
+            path_roots,
+        })
+    }
+
+    pub fn excludes(&self, field: &str) -> bool {
+        self.exclude.contains(field)
+    }
+
+    /// Anonymized text of `field`: a digest when hashed, otherwise paths redacted
+    pub fn apply_text(&self, field: &str, value: String) -> String {
+        if self.hash.contains(field) {
+            self.digest(&value)
+        } else {
+            self.redact_paths(value)
+        }
+    }
+
+    /// Apply the profile to every object key in `value`, recursively
+    pub fn apply_json(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                map.retain(|key, _| !self.exclude.contains(key));
+                for (key, inner) in map.iter_mut() {
+                    if self.hash.contains(key) {
+                        let text = match inner {
+                            Value::Null => continue,
+                            Value::String(s) => s.clone(),
+                            other => other.to_string(),
+                        };
+                        *inner = Value::String(self.digest(&text));
+                    } else {
+                        self.apply_json(inner);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.apply_json(item)),
+            Value::String(s) => *s = self.redact_paths(std::mem::take(s)),
+            _ => {}
+        }
+    }
+
+    fn digest(&self, value: &str) -> String {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(self.salt.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(value.as_bytes());
+        format!("h:{}", &hasher.finalize().to_hex()[..DIGEST_HEX_LEN])
+    }
+
+    fn redact_paths(&self, mut value: String) -> String {
+        for (root, label) in &self.path_roots {
+            if value.contains(root.as_str()) {
+                value = value.replace(root.as_str(), label);
+            }
+        }
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn anonymizer(profile: AnonymizationProfile) -> Anonymizer {
+        Anonymizer::new(
+            &profile,
+            vec![
+                ("/home/kim".into(), "<home>"),
+                (
+                    "/home/kim/.local/share/matter-certis-v2".into(),
+                    "<app_data>",
+                ),
+                ("/".into(), "<root>"),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn json_fields_are_dropped_hashed_and_paths_redacted() {
+        let anon = anonymizer(AnonymizationProfile {
+            exclude: vec!["note".into()],
+            hash: vec!["manufacturer".into(), "vid".into()],
+            salt: Some("s1".into()),
+            ..Default::default()
+        });
+        let mut value = json!({
+            "product": {"manufacturer": "Acme", "url": "https://x.test/a", "note": "internal"},
+            "details": {"manufacturer": "Acme", "vid": 4660, "pid": null},
+            "log": "/home/kim/.local/share/matter-certis-v2/logs/a.log and /home/kim/b",
+        });
+        anon.apply_json(&mut value);
+
+        let digest = value["product"]["manufacturer"].as_str().unwrap();
+        assert!(digest.starts_with("h:") && digest.len() == 2 + DIGEST_HEX_LEN);
+        assert_eq!(value["details"]["manufacturer"], digest);
+        assert!(value["product"].get("note").is_none());
+        assert_eq!(
+            value["details"]["vid"],
+            anon.apply_text("vid", "4660".into())
+        );
+        assert_eq!(value["product"]["url"], "https://x.test/a");
+        assert_eq!(value["log"], "<app_data>/logs/a.log and <home>/b");
+
+        // Salt changes digests, so shared files cannot be joined across salts
+        let other = anonymizer(AnonymizationProfile {
+            hash: vec!["manufacturer".into()],
+            ..Default::default()
+        });
+        assert_ne!(other.apply_text("manufacturer", "Acme".into()), digest);
+    }
+
+    #[test]
+    fn conflicting_or_blank_fields_are_rejected() {
+        let both = AnonymizationProfile {
+            exclude: vec!["model".into()],
+            hash: vec![" model ".into()],
+            ..Default::default()
+        };
+        assert!(Anonymizer::new(&both, Vec::new()).is_err());
+        let blank = AnonymizationProfile {
+            exclude: vec![" ".into()],
+            ..Default::default()
+        };
+        assert!(Anonymizer::new(&blank, Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,320 @@
+//! 배치 크기 자동 튜닝 (`advanced.batch_tuning`)
+//!
+//! `batch.batch_size` 는 사용자가 감으로 정해야 했다. 배치가 끝날 때마다 처리량(pages/min)과
+//! 메모리 압박을 `batch_metrics` 에 남기고, 다음 세션 계획 시 충분히 표본이 쌓인 크기 중
+//! 메모리 한도 안에서 처리량이 가장 높은 크기를 제안하거나(`suggest`) 바로 적용한다(`apply`).
+
+use crate::application::validated_crawling_config::ValidatedCrawlingConfig;
+use anyhow::Result;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use tracing::{info, warn};
+
+/// A different size must beat the current one by this share to be suggested
+const MIN_GAIN: f64 = 0.05;
+/// Shrink factor when the current size runs over the memory limit with no better-measured size
+const PRESSURE_SHRINK: f64 = 0.75;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchTuningMode {
+    /// Metrics are still recorded; nothing is suggested
+    #[default]
+    Off,
+    /// Suggestion is logged and exposed through `get_batch_size_suggestion`
+    Suggest,
+    /// Suggestion replaces `batch.batch_size` for the next session's plan
+    Apply,
+}
+
+/// `advanced.batch_tuning`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchTuningConfig {
+    #[serde(default)]
+    pub mode: BatchTuningMode,
+    /// Batches a size needs before its throughput is trusted
+    #[serde(default = "BatchTuningConfig::default_min_samples")]
+    pub min_samples: u32,
+    /// Mean memory pressure (0.0-1.0) above which a size is not suggested
+    #[serde(default = "BatchTuningConfig::default_max_memory_pressure")]
+    pub max_memory_pressure: f64,
+    /// Most recent batches considered
+    #[serde(default = "BatchTuningConfig::default_window")]
+    pub window: u32,
+}
+
+impl BatchTuningConfig {
+    fn default_min_samples() -> u32 {
+        3
+    }
+
+    fn default_max_memory_pressure() -> f64 {
+        0.85
+    }
+
+    fn default_window() -> u32 {
+        200
+    }
+}
+
+impl Default for BatchTuningConfig {
+    fn default() -> Self {
+        Self {
+            mode: BatchTuningMode::default(),
+            min_samples: Self::default_min_samples(),
+            max_memory_pressure: Self::default_max_memory_pressure(),
+            window: Self::default_window(),
+        }
+    }
+}
+
+/// One finished batch
+#[derive(Debug, Clone)]
+pub struct BatchSample {
+    pub session_id: String,
+    pub batch_id: String,
+    pub batch_size: u32,
+    pub pages: u32,
+    pub products: u32,
+    pub duration_ms: u64,
+    pub memory_pressure: Option<f64>,
+}
+
+/// Aggregate of recent batches run with one batch size
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BatchSizeStats {
+    pub batch_size: u32,
+    pub samples: u32,
+    pub pages_per_min: f64,
+    pub memory_pressure: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuggestionReason {
+    /// Another measured size had higher throughput
+    Throughput,
+    /// The current size ran over the memory limit
+    MemoryPressure,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BatchSizeSuggestion {
+    pub batch_size: u32,
+    pub current: u32,
+    pub reason: SuggestionReason,
+    /// Batches behind the suggested size (0 for a blind shrink)
+    pub samples: u32,
+    pub applied: bool,
+}
+
+pub async fn record_batch(pool: &SqlitePool, sample: &BatchSample) -> Result<()> {
+    if sample.pages == 0 || sample.duration_ms == 0 {
+        return Ok(());
+    }
+    let pages_per_min = f64::from(sample.pages) * 60_000.0 / sample.duration_ms as f64;
+    sqlx::query(
+        "INSERT INTO batch_metrics (session_id, batch_id, batch_size, pages, products, \
+         duration_ms, pages_per_min, memory_pressure, recorded_at) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&sample.session_id)
+    .bind(&sample.batch_id)
+    .bind(sample.batch_size as i64)
+    .bind(sample.pages as i64)
+    .bind(sample.products as i64)
+    .bind(sample.duration_ms as i64)
+    .bind(pages_per_min)
+    .bind(sample.memory_pressure)
+    .bind(Utc::now().to_rfc3339())
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Per-size aggregates over the last `window` batches
+pub async fn size_stats(pool: &SqlitePool, window: u32) -> Result<Vec<BatchSizeStats>> {
+    let rows = sqlx::query(
+        "SELECT batch_size, COUNT(*) AS samples, AVG(pages_per_min) AS ppm, \
+         AVG(memory_pressure) AS pressure \
+         FROM (SELECT * FROM batch_metrics ORDER BY id DESC LIMIT ?) \
+         GROUP BY batch_size ORDER BY batch_size",
+    )
+    .bind(window.max(1) as i64)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|r| BatchSizeStats {
+            batch_size: r.get::<i64, _>("batch_size") as u32,
+            samples: r.get::<i64, _>("samples") as u32,
+            pages_per_min: r.get("ppm"),
+            memory_pressure: r.get("pressure"),
+        })
+        .collect())
+}
+
+/// Pick the next session's batch size from measured sizes; None = keep `current`
+pub fn suggest_from(
+    stats: &[BatchSizeStats],
+    current: u32,
+    config: &BatchTuningConfig,
+) -> Option<BatchSizeSuggestion> {
+    let trusted = |s: &&BatchSizeStats| s.samples >= config.min_samples.max(1);
+    let within_memory = |s: &&BatchSizeStats| {
+        s.memory_pressure
+            .is_none_or(|p| p <= config.max_memory_pressure)
+    };
+    let current_stats = stats
+        .iter()
+        .filter(trusted)
+        .find(|s| s.batch_size == current);
+    let over_pressure = current_stats.is_some_and(|s| !within_memory(&s));
+    let best = stats
+        .iter()
+        .filter(trusted)
+        .filter(within_memory)
+        .filter(|s| !over_pressure || s.batch_size < current)
+        .max_by(|a, b| a.pages_per_min.total_cmp(&b.pages_per_min));
+
+    if over_pressure {
+        let (batch_size, samples) = match best {
+            Some(s) => (s.batch_size, s.samples),
+            None => (((f64::from(current) * PRESSURE_SHRINK) as u32).max(1), 0),
+        };
+        return (batch_size != current).then_some(BatchSizeSuggestion {
+            batch_size,
+            current,
+            reason: SuggestionReason::MemoryPressure,
+            samples,
+            applied: false,
+        });
+    }
+
+    let best = best.filter(|s| s.batch_size != current)?;
+    if let Some(cur) = current_stats {
+        if best.pages_per_min < cur.pages_per_min * (1.0 + MIN_GAIN) {
+            return None;
+        }
+    }
+    Some(BatchSizeSuggestion {
+        batch_size: best.batch_size,
+        current,
+        reason: SuggestionReason::Throughput,
+        samples: best.samples,
+        applied: false,
+    })
+}
+
+pub async fn suggest(
+    pool: &SqlitePool,
+    current: u32,
+    config: &BatchTuningConfig,
+) -> Result<Option<BatchSizeSuggestion>> {
+    let stats = size_stats(pool, config.window).await?;
+    Ok(suggest_from(&stats, current, config))
+}
+
+/// Measured sizes plus what the next plan will do with them
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchTuningStatus {
+    pub mode: BatchTuningMode,
+    pub current: u32,
+    pub sizes: Vec<BatchSizeStats>,
+    pub suggestion: Option<BatchSizeSuggestion>,
+}
+
+pub async fn status(
+    pool: &SqlitePool,
+    current: u32,
+    config: &BatchTuningConfig,
+) -> Result<BatchTuningStatus> {
+    let sizes = size_stats(pool, config.window).await?;
+    let suggestion = match config.mode {
+        BatchTuningMode::Off => None,
+        mode => suggest_from(&sizes, current, config).map(|s| BatchSizeSuggestion {
+            applied: mode == BatchTuningMode::Apply,
+            ..s
+        }),
+    };
+    Ok(BatchTuningStatus {
+        mode: config.mode,
+        current,
+        sizes,
+        suggestion,
+    })
+}
+
+/// Planning hook: in `apply` mode the suggestion replaces the validated batch size
+pub async fn tune(
+    pool: &SqlitePool,
+    validated: &mut ValidatedCrawlingConfig,
+    config: &BatchTuningConfig,
+) -> Option<BatchSizeSuggestion> {
+    if config.mode == BatchTuningMode::Off {
+        return None;
+    }
+    let mut suggestion = match suggest(pool, validated.batch_size(), config).await {
+        Ok(suggestion) => suggestion?,
+        Err(e) => {
+            warn!("Batch size tuning skipped: {}", e);
+            return None;
+        }
+    };
+    if config.mode == BatchTuningMode::Apply {
+        validated.apply_batch_size(suggestion.batch_size);
+        suggestion.applied = true;
+    }
+    info!(
+        "📐 Batch size suggestion: {} -> {} ({:?}, samples={}, applied={})",
+        suggestion.current,
+        suggestion.batch_size,
+        suggestion.reason,
+        suggestion.samples,
+        suggestion.applied
+    );
+    Some(suggestion)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(batch_size: u32, samples: u32, ppm: f64, pressure: f64) -> BatchSizeStats {
+        BatchSizeStats {
+            batch_size,
+            samples,
+            pages_per_min: ppm,
+            memory_pressure: Some(pressure),
+        }
+    }
+
+    #[test]
+    fn suggests_fastest_trusted_size_within_memory_limit() {
+        let config = BatchTuningConfig::default();
+        let history = vec![
+            stats(20, 5, 40.0, 0.5),
+            stats(50, 5, 45.0, 0.6),
+            // fastest, but too few batches to trust
+            stats(80, 2, 90.0, 0.6),
+            // fast, but over the memory limit
+            stats(100, 4, 70.0, 0.9),
+        ];
+
+        let s = suggest_from(&history, 20, &config).unwrap();
+        assert_eq!((s.batch_size, s.reason), (50, SuggestionReason::Throughput));
+        // less than MIN_GAIN better -> keep current
+        let close = vec![stats(20, 5, 44.0, 0.5), stats(50, 5, 45.0, 0.6)];
+        assert_eq!(suggest_from(&close, 20, &config), None);
+
+        // running over the limit falls back to a smaller measured size, else shrinks blind
+        let s = suggest_from(&history, 100, &config).unwrap();
+        assert_eq!(
+            (s.batch_size, s.reason),
+            (50, SuggestionReason::MemoryPressure)
+        );
+        let s = suggest_from(&[stats(100, 4, 70.0, 0.9)], 100, &config).unwrap();
+        assert_eq!((s.batch_size, s.samples), (75, 0));
+    }
+}
@@ -0,0 +1,241 @@
+//! 대용량 조회 결과의 청크 단위 전달 (server-side cursor)
+//!
+//! `start_query` 시점에 결과 행의 키(`products.rowid`)만 순서대로 스냅샷해 두고, 프런트엔드는
+//! `query_id` 와 청크 번호로 필요한 구간만 가져간다. 행 본문은 청크 요청 때마다 키로 다시 읽으므로
+//! IPC 한 번에 수만 행을 직렬화하지 않고, 청크 사이에 SQLite 읽기 트랜잭션을 붙잡아 두지도 않는다.
+//! 커서는 명시적으로 닫거나 유휴 TTL 이 지나면 정리된다.
+use chrono::{DateTime, Utc};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Rows per chunk when the caller does not choose
+pub const DEFAULT_CHUNK_SIZE: usize = 500;
+
+/// Upper bound on rows per chunk (keeps each IPC payload small)
+pub const MAX_CHUNK_SIZE: usize = 5_000;
+
+/// Open cursors kept at once; the least recently used one is dropped beyond this
+const MAX_OPEN_QUERIES: usize = 16;
+
+/// Cursors untouched for this long are dropped
+const QUERY_IDLE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Row source a chunked query reads from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChunkedQuerySource {
+    /// Export preview: products joined with product_details, filtered like `export_products`
+    Products {
+        #[serde(default)]
+        filter: crate::domain::product::ProductExportFilter,
+    },
+    /// Diagnostic scan: products that have no product_details row yet
+    ProductsMissingDetails,
+}
+
+/// Returned by `start_query`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkedQueryHandle {
+    pub query_id: String,
+    pub total_rows: usize,
+    pub chunk_size: usize,
+    pub total_chunks: usize,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One chunk of rows; `rows` may be shorter than the key range if rows were deleted meanwhile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryChunk<T> {
+    pub query_id: String,
+    pub chunk: usize,
+    pub total_chunks: usize,
+    pub total_rows: usize,
+    pub rows: Vec<T>,
+    pub has_more: bool,
+}
+
+#[derive(Debug)]
+struct QueryCursor {
+    keys: Arc<Vec<i64>>,
+    chunk_size: usize,
+    created_at: DateTime<Utc>,
+    last_access: Instant,
+}
+
+impl QueryCursor {
+    fn handle(&self, query_id: &str) -> ChunkedQueryHandle {
+        ChunkedQueryHandle {
+            query_id: query_id.to_string(),
+            total_rows: self.keys.len(),
+            chunk_size: self.chunk_size,
+            total_chunks: chunk_count(self.keys.len(), self.chunk_size),
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// Key slice for one chunk, resolved under the registry lock
+#[derive(Debug, Clone)]
+pub struct ChunkKeys {
+    pub keys: Vec<i64>,
+    pub chunk: usize,
+    pub total_chunks: usize,
+    pub total_rows: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct ChunkedQueryRegistry {
+    cursors: Mutex<HashMap<String, QueryCursor>>,
+}
+
+fn chunk_count(total_rows: usize, chunk_size: usize) -> usize {
+    total_rows.div_ceil(chunk_size.max(1))
+}
+
+impl ChunkedQueryRegistry {
+    /// Store a key snapshot and return its handle
+    pub fn open(&self, keys: Vec<i64>, chunk_size: Option<usize>) -> ChunkedQueryHandle {
+        let chunk_size = chunk_size
+            .unwrap_or(DEFAULT_CHUNK_SIZE)
+            .clamp(1, MAX_CHUNK_SIZE);
+        let query_id = uuid::Uuid::new_v4().to_string();
+        let cursor = QueryCursor {
+            keys: Arc::new(keys),
+            chunk_size,
+            created_at: Utc::now(),
+            last_access: Instant::now(),
+        };
+        let handle = cursor.handle(&query_id);
+        let Ok(mut cursors) = self.cursors.lock() else {
+            return handle;
+        };
+        Self::evict(&mut cursors);
+        while cursors.len() >= MAX_OPEN_QUERIES {
+            let Some(oldest) = cursors
+                .iter()
+                .min_by_key(|(_, c)| c.last_access)
+                .map(|(id, _)| id.clone())
+            else {
+                break;
+            };
+            cursors.remove(&oldest);
+        }
+        cursors.insert(query_id, cursor);
+        handle
+    }
+
+    /// Keys of chunk `chunk` (0-based); refreshes the cursor's idle timer
+    pub fn chunk_keys(&self, query_id: &str, chunk: usize) -> Result<ChunkKeys, String> {
+        let mut cursors = self
+            .cursors
+            .lock()
+            .map_err(|_| "Query registry poisoned".to_string())?;
+        Self::evict(&mut cursors);
+        let cursor = cursors
+            .get_mut(query_id)
+            .ok_or_else(|| format!("Unknown or expired query: {query_id}"))?;
+        cursor.last_access = Instant::now();
+        let total_rows = cursor.keys.len();
+        let total_chunks = chunk_count(total_rows, cursor.chunk_size);
+        if chunk >= total_chunks && !(chunk == 0 && total_rows == 0) {
+            return Err(format!(
+                "Chunk {chunk} out of range (query {query_id} has {total_chunks} chunks)"
+            ));
+        }
+        let start = (chunk * cursor.chunk_size).min(total_rows);
+        let end = (start + cursor.chunk_size).min(total_rows);
+        Ok(ChunkKeys {
+            keys: cursor.keys[start..end].to_vec(),
+            chunk,
+            total_chunks,
+            total_rows,
+        })
+    }
+
+    /// Drop a cursor; false when it was already gone
+    pub fn close(&self, query_id: &str) -> bool {
+        self.cursors
+            .lock()
+            .map(|mut c| c.remove(query_id).is_some())
+            .unwrap_or(false)
+    }
+
+    pub fn open_queries(&self) -> Vec<ChunkedQueryHandle> {
+        let Ok(mut cursors) = self.cursors.lock() else {
+            return Vec::new();
+        };
+        Self::evict(&mut cursors);
+        let mut handles: Vec<ChunkedQueryHandle> =
+            cursors.iter().map(|(id, c)| c.handle(id)).collect();
+        handles.sort_by_key(|h| h.created_at);
+        handles
+    }
+
+    fn evict(cursors: &mut HashMap<String, QueryCursor>) {
+        cursors.retain(|_, c| c.last_access.elapsed() < QUERY_IDLE_TTL);
+    }
+}
+
+static CHUNKED_QUERIES: OnceCell<Arc<ChunkedQueryRegistry>> = OnceCell::new();
+
+pub fn chunked_queries() -> Arc<ChunkedQueryRegistry> {
+    CHUNKED_QUERIES
+        .get_or_init(|| Arc::new(ChunkedQueryRegistry::default()))
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_cover_snapshot_in_order() {
+        let registry = ChunkedQueryRegistry::default();
+        let handle = registry.open((1..=7).collect(), Some(3));
+        assert_eq!(handle.total_rows, 7);
+        assert_eq!(handle.total_chunks, 3);
+
+        let second = registry.chunk_keys(&handle.query_id, 1).unwrap();
+        assert_eq!(second.keys, vec![4, 5, 6]);
+        let last = registry.chunk_keys(&handle.query_id, 2).unwrap();
+        assert_eq!(last.keys, vec![7]);
+        assert!(registry.chunk_keys(&handle.query_id, 3).is_err());
+        // earlier chunks can be re-read
+        assert_eq!(
+            registry.chunk_keys(&handle.query_id, 0).unwrap().keys,
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn empty_query_serves_empty_first_chunk_and_close_drops_it() {
+        let registry = ChunkedQueryRegistry::default();
+        let handle = registry.open(Vec::new(), None);
+        assert_eq!(handle.total_chunks, 0);
+        assert!(
+            registry
+                .chunk_keys(&handle.query_id, 0)
+                .unwrap()
+                .keys
+                .is_empty()
+        );
+        assert!(registry.close(&handle.query_id));
+        assert!(!registry.close(&handle.query_id));
+        assert!(registry.chunk_keys(&handle.query_id, 0).is_err());
+    }
+
+    #[test]
+    fn chunk_size_clamped_and_oldest_cursor_evicted() {
+        let registry = ChunkedQueryRegistry::default();
+        let first = registry.open(vec![1], Some(1_000_000));
+        assert_eq!(first.chunk_size, MAX_CHUNK_SIZE);
+        for _ in 0..MAX_OPEN_QUERIES {
+            registry.open(vec![1], Some(0));
+        }
+        assert_eq!(registry.open_queries().len(), MAX_OPEN_QUERIES);
+        assert!(registry.chunk_keys(&first.query_id, 0).is_err());
+    }
+}
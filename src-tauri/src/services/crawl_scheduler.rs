@@ -0,0 +1,542 @@
+//! 예약 크롤링 스케줄러 (cron / 고정 간격 반복 세션)
+//!
+//! 스케줄은 `crawl_schedules` 테이블에 저장되며, 백그라운드 루프가 `next_run_at`이 지난
+//! 스케줄을 찾아 `start_unified_crawling`, `start_partial_sync`, rolling-refresh 또는 incremental 프로필을 호출한다.
+//! cron 식은 로컬 시간 기준 5필드(`분 시 일 월 요일`)를 지원한다.
+
+use anyhow::{Context, Result, anyhow, bail};
+use chrono::{
+    DateTime, Datelike, Duration as ChronoDuration, Local, NaiveDateTime, TimeZone, Timelike, Utc,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tokio::sync::Notify;
+use tracing::{info, warn};
+
+use crate::application::AppState;
+use crate::commands::incremental_crawl::run_incremental_crawl;
+use crate::commands::rolling_refresh::run_rolling_refresh;
+use crate::commands::watchlist::run_watchlist_refresh;
+use crate::commands::sync_commands::start_partial_sync;
+use crate::commands::unified_crawling::{StartCrawlingRequest, start_unified_crawling};
+use crate::crawl_engine::runtime::crawl_limits::CrawlLimits;
+
+/// 다음 실행 시각이 없을 때 스케줄 테이블을 다시 확인하는 주기
+const IDLE_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// cron 다음 실행 시각 탐색 한도 (분 단위 스텝 기준 약 4년)
+const CRON_SEARCH_LIMIT_MINUTES: u32 = 4 * 366 * 24 * 60;
+
+/// When a schedule fires
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ScheduleSpec {
+    /// 5-field cron expression in local time, e.g. "30 2 * * *" (02:30 every day)
+    Cron { expression: String },
+    /// Fixed interval measured from the previous run (or creation)
+    Interval { minutes: u32 },
+}
+
+/// What a schedule runs when it fires
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ScheduleAction {
+    /// `start_unified_crawling` (mode: "advanced" | "live")
+    UnifiedCrawling {
+        #[serde(default)]
+        mode: Option<String>,
+    },
+    /// `start_partial_sync` with a physical page range expression (e.g. "5-1")
+    PartialSync { ranges: String },
+    /// `rolling-refresh` profile: next certification-recency slice
+    RollingRefresh,
+    /// `incremental` profile: only pages newer than the DB head
+    IncrementalCrawl,
+    /// Pages holding watched products, followed by a field-change check
+    WatchlistRefresh,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawlSchedule {
+    pub id: String,
+    pub name: String,
+    pub spec: ScheduleSpec,
+    pub action: ScheduleAction,
+    pub enabled: bool,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateScheduleRequest {
+    pub name: String,
+    pub spec: ScheduleSpec,
+    pub action: ScheduleAction,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl ScheduleSpec {
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            ScheduleSpec::Cron { expression } => CronExpression::parse(expression).map(|_| ()),
+            ScheduleSpec::Interval { minutes } if *minutes == 0 => {
+                bail!("interval must be at least 1 minute")
+            }
+            ScheduleSpec::Interval { .. } => Ok(()),
+        }
+    }
+
+    /// Next fire time strictly after `after`
+    pub fn next_after(&self, after: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+        match self {
+            ScheduleSpec::Cron { expression } => {
+                let cron = CronExpression::parse(expression)?;
+                let local_after = after.with_timezone(&Local).naive_local();
+                Ok(cron.next_after(local_after).and_then(|naive| {
+                    Local
+                        .from_local_datetime(&naive)
+                        .earliest()
+                        .map(|dt| dt.with_timezone(&Utc))
+                }))
+            }
+            ScheduleSpec::Interval { minutes } => {
+                Ok(Some(after + ChronoDuration::minutes(i64::from(*minutes))))
+            }
+        }
+    }
+}
+
+/// Parsed 5-field cron expression (minute hour day-of-month month day-of-week)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronExpression {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    dom_restricted: bool,
+    dow_restricted: bool,
+}
+
+impl CronExpression {
+    pub fn parse(expression: &str) -> Result<Self> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        if fields.len() != 5 {
+            bail!(
+                "cron expression must have 5 fields (minute hour day month weekday): {:?}",
+                expression
+            );
+        }
+        let mut days_of_week = parse_cron_field(fields[4], 0, 7)
+            .with_context(|| format!("invalid weekday field {:?}", fields[4]))?;
+        // 7 == Sunday (same as 0)
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+        Ok(Self {
+            minutes: parse_cron_field(fields[0], 0, 59)
+                .with_context(|| format!("invalid minute field {:?}", fields[0]))?,
+            hours: parse_cron_field(fields[1], 0, 23)
+                .with_context(|| format!("invalid hour field {:?}", fields[1]))?,
+            days_of_month: parse_cron_field(fields[2], 1, 31)
+                .with_context(|| format!("invalid day-of-month field {:?}", fields[2]))?,
+            months: parse_cron_field(fields[3], 1, 12)
+                .with_context(|| format!("invalid month field {:?}", fields[3]))?,
+            days_of_week,
+            dom_restricted: fields[2] != "*",
+            dow_restricted: fields[4] != "*",
+        })
+    }
+
+    fn day_matches(&self, t: &NaiveDateTime) -> bool {
+        let dom = self.days_of_month & (1 << t.day()) != 0;
+        let dow = self.days_of_week & (1 << t.weekday().num_days_from_sunday()) != 0;
+        // Vixie cron: when both day fields are restricted, either may match
+        match (self.dom_restricted, self.dow_restricted) {
+            (true, true) => dom || dow,
+            (true, false) => dom,
+            (false, true) => dow,
+            (false, false) => true,
+        }
+    }
+
+    /// First matching minute strictly after `after` (naive local time)
+    pub fn next_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let mut t = after.with_second(0)?.with_nanosecond(0)? + ChronoDuration::minutes(1);
+        for _ in 0..CRON_SEARCH_LIMIT_MINUTES {
+            if self.months & (1 << t.month()) == 0 {
+                let (y, m) = if t.month() == 12 {
+                    (t.year() + 1, 1)
+                } else {
+                    (t.year(), t.month() + 1)
+                };
+                t = chrono::NaiveDate::from_ymd_opt(y, m, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.day_matches(&t) {
+                t = t.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if self.hours & (1 << t.hour()) == 0 {
+                t = t.with_minute(0)? + ChronoDuration::hours(1);
+                continue;
+            }
+            if self.minutes & (1 << t.minute()) == 0 {
+                t += ChronoDuration::minutes(1);
+                continue;
+            }
+            return Some(t);
+        }
+        None
+    }
+}
+
+/// Parse one cron field (`*`, `*/n`, `a`, `a-b`, `a-b/n`, comma lists) into a bitset
+fn parse_cron_field(field: &str, min: u32, max: u32) -> Result<u64> {
+    let mut bits = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>()?),
+            None => (part, 1),
+        };
+        if step == 0 {
+            bail!("step must be positive");
+        }
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            (a.parse::<u32>()?, b.parse::<u32>()?)
+        } else {
+            let v = range.parse::<u32>()?;
+            // "a/n" means from a to max in steps of n
+            (v, if part.contains('/') { max } else { v })
+        };
+        if start < min || end > max || start > end {
+            bail!("value out of range {}-{}: {:?}", min, max, part);
+        }
+        for v in (start..=end).step_by(step as usize) {
+            bits |= 1 << v;
+        }
+    }
+    Ok(bits)
+}
+
+/// Background scheduler managed as Tauri state
+#[derive(Default)]
+pub struct CrawlSchedulerService {
+    /// Wakes the loop early when schedules change
+    wake: Notify,
+}
+
+impl CrawlSchedulerService {
+    pub async fn create_schedule(
+        &self,
+        pool: &SqlitePool,
+        request: CreateScheduleRequest,
+    ) -> Result<CrawlSchedule> {
+        if request.name.trim().is_empty() {
+            bail!("schedule name is required");
+        }
+        request.spec.validate()?;
+        if matches!(&request.action, ScheduleAction::PartialSync { ranges } if ranges.trim().is_empty())
+        {
+            bail!("partial sync schedule requires a range expression");
+        }
+        let now = Utc::now();
+        let schedule = CrawlSchedule {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: request.name.trim().to_string(),
+            next_run_at: request.spec.next_after(now)?,
+            spec: request.spec,
+            action: request.action,
+            enabled: request.enabled,
+            last_run_at: None,
+            last_error: None,
+            created_at: now,
+        };
+        insert_schedule(pool, &schedule).await?;
+        info!(
+            "🗓️ Crawl schedule created: {} ({}) next_run_at={:?}",
+            schedule.name, schedule.id, schedule.next_run_at
+        );
+        self.wake.notify_one();
+        Ok(schedule)
+    }
+
+    pub async fn list_schedules(&self, pool: &SqlitePool) -> Result<Vec<CrawlSchedule>> {
+        load_schedules(pool).await
+    }
+
+    pub async fn delete_schedule(&self, pool: &SqlitePool, id: &str) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM crawl_schedules WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        self.wake.notify_one();
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// Start the background loop that fires due schedules
+pub fn start_crawl_scheduler(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        info!("🗓️ Crawl scheduler started");
+        loop {
+            let wait = match run_due_schedules(&app).await {
+                Ok(Some(next)) => (next - Utc::now())
+                    .to_std()
+                    .unwrap_or(Duration::ZERO)
+                    .min(IDLE_POLL_INTERVAL),
+                Ok(None) => IDLE_POLL_INTERVAL,
+                Err(e) => {
+                    warn!("🗓️ Crawl scheduler tick failed: {}", e);
+                    IDLE_POLL_INTERVAL
+                }
+            };
+            let service = app.state::<CrawlSchedulerService>();
+            tokio::select! {
+                _ = tokio::time::sleep(wait) => {}
+                _ = service.wake.notified() => {}
+            }
+        }
+    });
+}
+
+/// Fire every enabled schedule whose `next_run_at` has passed; returns the earliest upcoming run
+async fn run_due_schedules(app: &AppHandle) -> Result<Option<DateTime<Utc>>> {
+    let state = app.state::<AppState>();
+    let Ok(pool) = state.get_database_pool().await else {
+        // DB 초기화 전
+        return Ok(None);
+    };
+    let now = Utc::now();
+    let mut upcoming: Option<DateTime<Utc>> = None;
+    for schedule in load_schedules(&pool).await? {
+        if !schedule.enabled {
+            continue;
+        }
+        let Some(next_run_at) = schedule.next_run_at else {
+            continue;
+        };
+        if next_run_at > now {
+            upcoming = Some(upcoming.map_or(next_run_at, |u| u.min(next_run_at)));
+            continue;
+        }
+
+        let error = if state.is_crawling_active().await {
+            info!(
+                "🗓️ Schedule {} skipped: crawling session is active",
+                schedule.name
+            );
+            Some("skipped: crawling session was active".to_string())
+        } else {
+            fire_schedule(app, &schedule).await.err()
+        };
+        let next = schedule.spec.next_after(now)?;
+        record_run(&pool, &schedule.id, now, next, error.as_deref()).await?;
+        if let Some(next) = next {
+            upcoming = Some(upcoming.map_or(next, |u| u.min(next)));
+        }
+    }
+    Ok(upcoming)
+}
+
+async fn fire_schedule(app: &AppHandle, schedule: &CrawlSchedule) -> Result<(), String> {
+    info!(
+        "🗓️ Firing schedule {} ({}): {:?}",
+        schedule.name, schedule.id, schedule.action
+    );
+    let result = match &schedule.action {
+        ScheduleAction::UnifiedCrawling { mode } => start_unified_crawling(
+            app.clone(),
+            StartCrawlingRequest {
+                mode: mode.clone(),
+                override_batch_size: None,
+                override_concurrency: None,
+                delay_ms: None,
+                export: None,
+                stage_flags: Vec::new(),
+                limits: CrawlLimits::default(),
+            },
+        )
+        .await
+        .map_err(String::from)
+        .and_then(|resp| {
+            if resp.success {
+                Ok(())
+            } else {
+                Err(resp.message)
+            }
+        }),
+        ScheduleAction::PartialSync { ranges } => start_partial_sync(
+            app.clone(),
+            app.state::<AppState>(),
+            ranges.clone(),
+            Some(false),
+            None,
+        )
+        .await
+        .map(|_| ())
+        .map_err(String::from),
+        ScheduleAction::RollingRefresh => run_rolling_refresh(app.clone(), app.state::<AppState>())
+            .await
+            .map(|_| ()),
+        ScheduleAction::IncrementalCrawl => {
+            run_incremental_crawl(app.clone(), app.state::<AppState>())
+                .await
+                .map(|_| ())
+        }
+        ScheduleAction::WatchlistRefresh => {
+            run_watchlist_refresh(app.clone(), app.state::<AppState>())
+                .await
+                .map(|_| ())
+        }
+    };
+    if let Err(e) = &result {
+        warn!("🗓️ Schedule {} failed: {}", schedule.name, e);
+    }
+    result
+}
+
+async fn insert_schedule(pool: &SqlitePool, schedule: &CrawlSchedule) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO crawl_schedules (id, name, spec, action, enabled, last_run_at, next_run_at, last_error, created_at) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&schedule.id)
+    .bind(&schedule.name)
+    .bind(serde_json::to_string(&schedule.spec)?)
+    .bind(serde_json::to_string(&schedule.action)?)
+    .bind(schedule.enabled)
+    .bind(schedule.last_run_at.map(|t| t.to_rfc3339()))
+    .bind(schedule.next_run_at.map(|t| t.to_rfc3339()))
+    .bind(&schedule.last_error)
+    .bind(schedule.created_at.to_rfc3339())
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn load_schedules(pool: &SqlitePool) -> Result<Vec<CrawlSchedule>> {
+    let rows = sqlx::query(
+        "SELECT id, name, spec, action, enabled, last_run_at, next_run_at, last_error, created_at \
+         FROM crawl_schedules ORDER BY created_at",
+    )
+    .fetch_all(pool)
+    .await?;
+    let parse_ts = |s: Option<String>| -> Option<DateTime<Utc>> {
+        s.and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|t| t.with_timezone(&Utc))
+    };
+    rows.into_iter()
+        .map(|r| -> Result<CrawlSchedule> {
+            let id: String = r.get("id");
+            Ok(CrawlSchedule {
+                spec: serde_json::from_str(r.get::<String, _>("spec").as_str())
+                    .map_err(|e| anyhow!("schedule {} has invalid spec: {}", id, e))?,
+                action: serde_json::from_str(r.get::<String, _>("action").as_str())
+                    .map_err(|e| anyhow!("schedule {} has invalid action: {}", id, e))?,
+                name: r.get("name"),
+                enabled: r.get::<i64, _>("enabled") != 0,
+                last_run_at: parse_ts(r.get("last_run_at")),
+                next_run_at: parse_ts(r.get("next_run_at")),
+                last_error: r.get("last_error"),
+                created_at: parse_ts(r.get("created_at")).unwrap_or_else(Utc::now),
+                id,
+            })
+        })
+        .collect()
+}
+
+async fn record_run(
+    pool: &SqlitePool,
+    id: &str,
+    ran_at: DateTime<Utc>,
+    next_run_at: Option<DateTime<Utc>>,
+    error: Option<&str>,
+) -> Result<()> {
+    sqlx::query(
+        "UPDATE crawl_schedules SET last_run_at = ?, next_run_at = ?, last_error = ? WHERE id = ?",
+    )
+    .bind(ran_at.to_rfc3339())
+    .bind(next_run_at.map(|t| t.to_rfc3339()))
+    .bind(error)
+    .bind(id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_opt(h, mi, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn nightly_cron_fires_next_day_after_passing_time() {
+        let cron = CronExpression::parse("30 2 * * *").unwrap();
+        assert_eq!(
+            cron.next_after(at(2026, 1, 10, 1, 0)),
+            Some(at(2026, 1, 10, 2, 30))
+        );
+        assert_eq!(
+            cron.next_after(at(2026, 1, 10, 2, 30)),
+            Some(at(2026, 1, 11, 2, 30))
+        );
+    }
+
+    #[test]
+    fn cron_steps_lists_and_weekdays() {
+        let cron = CronExpression::parse("*/15 9-17 * * 1-5").unwrap();
+        // 2026-01-10 is a Saturday → next Monday 09:00
+        assert_eq!(
+            cron.next_after(at(2026, 1, 10, 12, 0)),
+            Some(at(2026, 1, 12, 9, 0))
+        );
+        let cron = CronExpression::parse("0 0 1,15 * *").unwrap();
+        assert_eq!(
+            cron.next_after(at(2026, 2, 2, 0, 0)),
+            Some(at(2026, 2, 15, 0, 0))
+        );
+        // 7 is accepted as Sunday
+        let cron = CronExpression::parse("0 3 * * 7").unwrap();
+        assert_eq!(
+            cron.next_after(at(2026, 1, 10, 12, 0)),
+            Some(at(2026, 1, 11, 3, 0))
+        );
+    }
+
+    #[test]
+    fn invalid_cron_and_interval_are_rejected() {
+        assert!(CronExpression::parse("* * * *").is_err());
+        assert!(CronExpression::parse("60 * * * *").is_err());
+        assert!(CronExpression::parse("*/0 * * * *").is_err());
+        assert!(ScheduleSpec::Interval { minutes: 0 }.validate().is_err());
+    }
+
+    #[test]
+    fn interval_spec_adds_minutes() {
+        let now = Utc::now();
+        let next = ScheduleSpec::Interval { minutes: 90 }
+            .next_after(now)
+            .unwrap()
+            .unwrap();
+        assert_eq!(next - now, ChronoDuration::minutes(90));
+    }
+}
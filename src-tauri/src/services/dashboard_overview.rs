@@ -0,0 +1,381 @@
+//! 대시보드 개요: 메인 화면에 필요한 상태를 한 번의 IPC 로 모은다
+//!
+//! 진행 중 작업(크롤 세션 레지스트리 + 실행/일시정지 싱크), 최근 세션 리포트 5개, 최근 리포트로 만든
+//! KPI 추세, 미해결 이상 항목(검증 finding / 중복 충돌 / 재검증 대기), 예약 실행 예정, 상태 플래그를
+//! 합친다. 항목마다 따로 모으므로 하나가 실패해도 나머지는 채우고 이유는 `warnings` 에 남긴다.
+
+use crate::crawl_engine::runtime::session_registry::{SessionStatus, session_registry};
+use crate::crawl_engine::services::session_report::SessionReport;
+use crate::crawl_engine::validation::findings;
+use crate::services::crawl_scheduler::{CrawlSchedule, ScheduleAction};
+use crate::services::startup_integrity::DataIntegritySnapshot;
+use crate::services::sync_resume::PausedSync;
+use crate::services::{dedup_conflicts, page_revalidation};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Session summaries shown on the dashboard
+pub const RECENT_SESSIONS: usize = 5;
+/// Sessions (newest first) the KPI trends are computed over
+pub const TREND_WINDOW: usize = 10;
+/// Upcoming scheduled runs shown
+pub const NEXT_RUNS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    Crawl,
+    Sync,
+    PausedSync,
+}
+
+/// Crawl or sync that is running, pausing or waiting to be resumed
+#[derive(Debug, Clone, Serialize)]
+pub struct ActiveOperation {
+    pub id: String,
+    pub kind: OperationKind,
+    pub status: String,
+    pub started_at: Option<DateTime<Utc>>,
+    /// Pages processed / planned (paused syncs: 0 / remaining pages)
+    pub processed: u64,
+    pub total: u64,
+    /// None when the operation does not report progress
+    pub progress_pct: Option<f64>,
+    pub error_count: u32,
+}
+
+impl ActiveOperation {
+    pub fn running_sync(session_id: String) -> Self {
+        Self {
+            id: session_id,
+            kind: OperationKind::Sync,
+            status: "running".into(),
+            started_at: None,
+            processed: 0,
+            total: 0,
+            progress_pct: None,
+            error_count: 0,
+        }
+    }
+
+    pub fn paused_sync(paused: PausedSync) -> Self {
+        Self {
+            id: paused.session_id,
+            kind: OperationKind::PausedSync,
+            status: "paused".into(),
+            started_at: None,
+            processed: 0,
+            total: u64::from(paused.remaining_pages),
+            progress_pct: None,
+            error_count: 0,
+        }
+    }
+}
+
+/// Crawl sessions in the registry that have not finished, oldest first
+pub async fn crawl_operations() -> Vec<ActiveOperation> {
+    let registry = session_registry();
+    let sessions = registry.read().await;
+    let mut operations: Vec<ActiveOperation> = sessions
+        .iter()
+        .filter(|(_, entry)| {
+            matches!(
+                entry.status,
+                SessionStatus::Running | SessionStatus::Paused | SessionStatus::ShuttingDown
+            )
+        })
+        .map(|(id, entry)| ActiveOperation {
+            id: id.clone(),
+            kind: OperationKind::Crawl,
+            status: format!("{:?}", entry.status).to_lowercase(),
+            started_at: Some(entry.started_at),
+            processed: entry.processed_pages,
+            total: entry.total_pages_planned,
+            progress_pct: (entry.total_pages_planned > 0)
+                .then(|| entry.processed_pages as f64 / entry.total_pages_planned as f64 * 100.0),
+            error_count: entry.error_count,
+        })
+        .collect();
+    operations.sort_by_key(|op| op.started_at);
+    operations
+}
+
+/// One finished crawl session (from its session report)
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionDigest {
+    pub session_id: String,
+    pub final_state: String,
+    pub completed_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    pub pages_processed: u32,
+    pub products_inserted: u32,
+    pub products_updated: u32,
+    pub failed_items: u32,
+    pub item_retries: u32,
+    pub success_rate: f64,
+}
+
+impl From<&SessionReport> for SessionDigest {
+    fn from(report: &SessionReport) -> Self {
+        Self {
+            session_id: report.session_id.clone(),
+            final_state: report.final_state.clone(),
+            completed_at: report.completed_at,
+            duration_ms: report.duration_ms,
+            pages_processed: report.summary.total_pages_processed,
+            products_inserted: report.batches.products_inserted,
+            products_updated: report.batches.products_updated,
+            failed_items: report.failed_items.len() as u32 + report.failed_items_omitted,
+            item_retries: report.retries.item_retries,
+            success_rate: report.summary.success_rate,
+        }
+    }
+}
+
+/// Newest `limit` session reports in `dir` (by file modification time); unreadable files are skipped
+pub async fn load_recent_reports(dir: &Path, limit: usize) -> Result<Vec<SessionReport>> {
+    let mut entries = match tokio::fs::read_dir(dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let mut files = Vec::new();
+    while let Some(entry) = entries.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !(name.starts_with("session_") && name.ends_with(".json")) {
+            continue;
+        }
+        if let Ok(modified) = entry.metadata().await.and_then(|m| m.modified()) {
+            files.push((modified, entry.path()));
+        }
+    }
+    files.sort_by(|a, b| b.0.cmp(&a.0));
+
+    let mut reports = Vec::new();
+    for (_, path) in files {
+        if reports.len() >= limit {
+            break;
+        }
+        let parsed = tokio::fs::read(&path)
+            .await
+            .ok()
+            .and_then(|raw| serde_json::from_slice::<SessionReport>(&raw).ok());
+        if let Some(report) = parsed {
+            reports.push(report);
+        }
+    }
+    reports.sort_by(|a, b| b.completed_at.cmp(&a.completed_at));
+    Ok(reports)
+}
+
+/// One KPI over recent sessions
+#[derive(Debug, Clone, Serialize)]
+pub struct KpiTrend {
+    pub kpi: String,
+    /// Per session, oldest first
+    pub values: Vec<f64>,
+    pub latest: Option<f64>,
+    /// Latest value against the average of the earlier ones, in percent
+    pub change_pct: Option<f64>,
+}
+
+fn trend(kpi: &str, values: Vec<f64>) -> KpiTrend {
+    let latest = values.last().copied();
+    let earlier = &values[..values.len().saturating_sub(1)];
+    let change_pct = match (latest, earlier.is_empty()) {
+        (Some(latest), false) => {
+            let mean = earlier.iter().sum::<f64>() / earlier.len() as f64;
+            (mean != 0.0).then(|| (latest - mean) / mean * 100.0)
+        }
+        _ => None,
+    };
+    KpiTrend {
+        kpi: kpi.to_string(),
+        values,
+        latest,
+        change_pct,
+    }
+}
+
+/// Throughput, success rate, inserts, failures and retries over `reports` (newest first)
+pub fn kpi_trends(reports: &[SessionReport]) -> Vec<KpiTrend> {
+    let digests: Vec<SessionDigest> = reports.iter().rev().map(SessionDigest::from).collect();
+    let series = |f: fn(&SessionDigest) -> f64| digests.iter().map(f).collect::<Vec<f64>>();
+    vec![
+        trend(
+            "pages_per_minute",
+            series(|d| {
+                if d.duration_ms == 0 {
+                    0.0
+                } else {
+                    f64::from(d.pages_processed) / (d.duration_ms as f64 / 60_000.0)
+                }
+            }),
+        ),
+        trend("success_rate", series(|d| d.success_rate)),
+        trend(
+            "products_inserted",
+            series(|d| f64::from(d.products_inserted)),
+        ),
+        trend("failed_items", series(|d| f64::from(d.failed_items))),
+        trend("item_retries", series(|d| f64::from(d.item_retries))),
+    ]
+}
+
+/// Anomalies waiting on an operator or a repair pass
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PendingAnomalies {
+    pub open_findings: u32,
+    pub findings_by_kind: BTreeMap<String, u32>,
+    pub pending_dedup_conflicts: u32,
+    pub pages_awaiting_revalidation: u32,
+}
+
+pub async fn pending_anomalies(pool: &SqlitePool) -> Result<PendingAnomalies> {
+    let open = findings::list(pool, Some("open")).await?;
+    let mut findings_by_kind = BTreeMap::new();
+    for finding in &open {
+        *findings_by_kind.entry(finding.kind.clone()).or_insert(0) += 1;
+    }
+    Ok(PendingAnomalies {
+        open_findings: open.len() as u32,
+        findings_by_kind,
+        pending_dedup_conflicts: dedup_conflicts::pending_conflict_count(pool).await? as u32,
+        pages_awaiting_revalidation: page_revalidation::list_pending(pool).await?.len() as u32,
+    })
+}
+
+/// Next firing of an enabled schedule
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduledRun {
+    pub schedule_id: String,
+    pub name: String,
+    pub action: ScheduleAction,
+    pub next_run_at: DateTime<Utc>,
+}
+
+/// Soonest `limit` runs of enabled schedules
+pub fn next_runs(schedules: Vec<CrawlSchedule>, limit: usize) -> Vec<ScheduledRun> {
+    let mut runs: Vec<ScheduledRun> = schedules
+        .into_iter()
+        .filter(|s| s.enabled)
+        .filter_map(|s| {
+            s.next_run_at.map(|next_run_at| ScheduledRun {
+                schedule_id: s.id,
+                name: s.name,
+                action: s.action,
+                next_run_at,
+            })
+        })
+        .collect();
+    runs.sort_by_key(|r| r.next_run_at);
+    runs.truncate(limit);
+    runs
+}
+
+/// Process health at a glance; `healthy` is false when any flag needs attention
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct HealthFlags {
+    pub database: bool,
+    /// None until the startup integrity check has run
+    pub integrity_healthy: Option<bool>,
+    pub integrity_summary: Option<String>,
+    /// Hosts whose circuit breaker is open or half-open
+    pub open_circuits: Vec<String>,
+    pub persistence_queue_depth: usize,
+    pub persistence_failed_flushes: u64,
+    /// Background tasks running past their expected lifetime
+    pub overdue_tasks: u32,
+    pub healthy: bool,
+}
+
+impl HealthFlags {
+    pub fn new(
+        database: bool,
+        integrity: Option<&DataIntegritySnapshot>,
+        open_circuits: Vec<String>,
+        persistence_queue: (usize, u64),
+        overdue_tasks: u32,
+    ) -> Self {
+        let integrity_healthy = integrity.map(|r| r.healthy);
+        let (persistence_queue_depth, persistence_failed_flushes) = persistence_queue;
+        Self {
+            database,
+            integrity_healthy,
+            integrity_summary: integrity.map(|r| r.summary.clone()),
+            healthy: database
+                && integrity_healthy != Some(false)
+                && open_circuits.is_empty()
+                && persistence_failed_flushes == 0
+                && overdue_tasks == 0,
+            open_circuits,
+            persistence_queue_depth,
+            persistence_failed_flushes,
+            overdue_tasks,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DashboardOverview {
+    pub generated_at: DateTime<Utc>,
+    pub active_operations: Vec<ActiveOperation>,
+    /// Newest first
+    pub recent_sessions: Vec<SessionDigest>,
+    pub kpi_trends: Vec<KpiTrend>,
+    pub anomalies: PendingAnomalies,
+    pub next_runs: Vec<ScheduledRun>,
+    pub health: HealthFlags,
+    /// Sections that could not be collected
+    pub warnings: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::crawl_scheduler::ScheduleSpec;
+
+    fn schedule(id: &str, enabled: bool, next_in_mins: Option<i64>) -> CrawlSchedule {
+        CrawlSchedule {
+            id: id.into(),
+            name: id.into(),
+            spec: ScheduleSpec::Interval { minutes: 60 },
+            action: ScheduleAction::IncrementalCrawl,
+            enabled,
+            last_run_at: None,
+            next_run_at: next_in_mins.map(|m| Utc::now() + chrono::Duration::minutes(m)),
+            last_error: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn trends_compare_latest_with_earlier_mean_and_runs_are_soonest_first() {
+        let t = trend("pages_per_minute", vec![10.0, 30.0, 30.0]);
+        assert_eq!(t.latest, Some(30.0));
+        assert_eq!(t.change_pct, Some(50.0));
+        assert_eq!(trend("x", vec![5.0]).change_pct, None);
+        assert_eq!(trend("x", vec![0.0, 4.0]).change_pct, None);
+        assert!(kpi_trends(&[]).iter().all(|t| t.latest.is_none()));
+
+        let runs = next_runs(
+            vec![
+                schedule("later", true, Some(90)),
+                schedule("disabled", false, Some(1)),
+                schedule("soon", true, Some(5)),
+                schedule("never", true, None),
+            ],
+            NEXT_RUNS,
+        );
+        let ids: Vec<&str> = runs.iter().map(|r| r.schedule_id.as_str()).collect();
+        assert_eq!(ids, vec!["soon", "later"]);
+
+        let flags = HealthFlags::new(true, None, vec!["example.org".into()], (3, 0), 0);
+        assert!(!flags.healthy);
+        assert!(HealthFlags::new(true, None, Vec::new(), (3, 0), 0).healthy);
+    }
+}
@@ -0,0 +1,507 @@
+//! 외부 DB 스냅샷 병합 (다른 장비에서 수집한 데이터셋 가져오기)
+//!
+//! 두 번째 SQLite 파일을 `import_src`로 ATTACH한 뒤 `products` → `product_details` 순서로 URL 기준 병합한다.
+//! - 로컬에 없는 URL은 삽입한다. 페이지 좌표(page_id, index_in_page)가 로컬의 다른 URL과 겹치면
+//!   좌표 없이 삽입한다 (좌표는 다음 싱크가 다시 채운다).
+//! - 양쪽에 있는 URL은 필드별로 병합한다. 빈 값(NULL/공백)은 어느 규칙에서도 값을 지우지 않고
+//!   로컬 빈 값은 가져온 값으로 채운다. 둘 다 값이 있는데 다르면 충돌이며 `ImportConflictRule`로 정한다.
+//! - 좌표 컬럼은 로컬 싱크가 관리하므로 기존 행에서는 바꾸지 않는다.
+//!
+//! 전체가 한 트랜잭션이며 dry-run은 같은 작업을 수행한 뒤 롤백해 집계만 돌려준다.
+
+use crate::infrastructure::database_paths;
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, SqliteConnection, SqlitePool};
+use std::path::Path;
+use tracing::{info, warn};
+
+/// Schema name the source database is attached under
+const SOURCE_SCHEMA: &str = "import_src";
+
+/// Columns never merged field by field (key and audit timestamps)
+const KEY_COLUMNS: &[&str] = &["url", "created_at", "updated_at"];
+
+/// How a field holding different non-blank values on both sides is settled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportConflictRule {
+    /// The row with the later `updated_at` wins
+    #[default]
+    PreferNewer,
+    /// Local values are kept; the import only fills local blanks
+    PreferNonNull,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportTable {
+    Products,
+    ProductDetails,
+}
+
+impl ImportTable {
+    /// Products first: detail rows reference `products.url`
+    const ORDER: [ImportTable; 2] = [ImportTable::Products, ImportTable::ProductDetails];
+
+    fn name(self) -> &'static str {
+        match self {
+            ImportTable::Products => "products",
+            ImportTable::ProductDetails => "product_details",
+        }
+    }
+
+    /// Page coordinate columns (`products.id` is derived from them)
+    fn slot_columns(self) -> &'static [&'static str] {
+        match self {
+            ImportTable::Products => &["page_id", "index_in_page", "id"],
+            ImportTable::ProductDetails => &["page_id", "index_in_page"],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TableImportCounts {
+    pub source_rows: u64,
+    pub inserted: u64,
+    pub updated: u64,
+    /// URLs present on both sides with different non-blank values in at least one field
+    pub conflicted: u64,
+    /// Inserted without page coordinates because a local row already holds the slot
+    pub slot_cleared: u64,
+    /// Detail rows whose product exists in neither database
+    pub skipped_orphans: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DatabaseImportReport {
+    pub source_path: String,
+    pub rule: ImportConflictRule,
+    pub dry_run: bool,
+    pub products: TableImportCounts,
+    pub product_details: TableImportCounts,
+    /// Backup taken before a non-dry-run import
+    pub backup_file: Option<String>,
+}
+
+fn quote(column: &str) -> String {
+    format!("\"{}\"", column.replace('"', "\"\""))
+}
+
+fn blank(expr: &str) -> String {
+    format!("NULLIF(TRIM(CAST({expr} AS TEXT)), '') IS NULL")
+}
+
+fn differs(local: &str, incoming: &str) -> String {
+    format!("TRIM(CAST({local} AS TEXT)) IS NOT TRIM(CAST({incoming} AS TEXT))")
+}
+
+/// Both sides hold a value and the values differ
+fn conflict(column: &str) -> String {
+    let (m, s) = (format!("m.{column}"), format!("s.{column}"));
+    format!(
+        "(NOT {} AND NOT {} AND {})",
+        blank(&m),
+        blank(&s),
+        differs(&m, &s)
+    )
+}
+
+fn merged_value(column: &str, rule: ImportConflictRule) -> String {
+    let (m, s) = (format!("m.{column}"), format!("s.{column}"));
+    let incoming_wins = match rule {
+        ImportConflictRule::PreferNewer => {
+            "julianday(s.updated_at) > julianday(m.updated_at)".to_string()
+        }
+        ImportConflictRule::PreferNonNull => "0".to_string(),
+    };
+    format!(
+        "CASE WHEN {mb} THEN CASE WHEN {sb} THEN {m} ELSE {s} END \
+         WHEN {sb} THEN {m} \
+         WHEN {diff} AND {incoming_wins} THEN {s} \
+         ELSE {m} END",
+        mb = blank(&m),
+        sb = blank(&s),
+        diff = differs(&m, &s),
+    )
+}
+
+async fn columns(conn: &mut SqliteConnection, schema: &str, table: &str) -> Result<Vec<String>> {
+    Ok(
+        sqlx::query_scalar("SELECT name FROM pragma_table_info(?, ?)")
+            .bind(table)
+            .bind(schema)
+            .fetch_all(&mut *conn)
+            .await?,
+    )
+}
+
+async fn count(conn: &mut SqliteConnection, sql: &str) -> Result<u64> {
+    let n: i64 = sqlx::query_scalar(sql).fetch_one(&mut *conn).await?;
+    Ok(n as u64)
+}
+
+async fn merge_table(
+    conn: &mut SqliteConnection,
+    table: ImportTable,
+    rule: ImportConflictRule,
+) -> Result<TableImportCounts> {
+    let name = table.name();
+    let source_columns = columns(conn, SOURCE_SCHEMA, name).await?;
+    if source_columns.is_empty() {
+        if table == ImportTable::Products {
+            bail!("Source database has no `products` table");
+        }
+        warn!("📥 [DatabaseImport] source has no {name} table; skipped");
+        return Ok(TableImportCounts::default());
+    }
+    if !source_columns.iter().any(|c| c == "url") {
+        bail!("Source `{name}` table has no url column");
+    }
+    // Columns only one side knows are left alone (older snapshots lack later migrations)
+    let shared: Vec<String> = columns(conn, "main", name)
+        .await?
+        .into_iter()
+        .filter(|c| source_columns.contains(c))
+        .collect();
+    let has = |c: &str| shared.iter().any(|s| s == c);
+    let fields: Vec<&String> = shared
+        .iter()
+        .filter(|c| !KEY_COLUMNS.contains(&c.as_str()))
+        .filter(|c| !table.slot_columns().contains(&c.as_str()))
+        .collect();
+    let timestamps = has("updated_at");
+
+    let mut counts = TableImportCounts {
+        source_rows: count(
+            conn,
+            &format!("SELECT COUNT(*) FROM {SOURCE_SCHEMA}.{name} WHERE url IS NOT NULL"),
+        )
+        .await?,
+        ..Default::default()
+    };
+
+    // Existing URLs: count conflicts before merging erases them
+    if !fields.is_empty() {
+        let any_conflict = fields
+            .iter()
+            .map(|c| conflict(&quote(c)))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        counts.conflicted = count(
+            conn,
+            &format!(
+                "SELECT COUNT(*) FROM {SOURCE_SCHEMA}.{name} s JOIN main.{name} m ON m.url = s.url \
+                 WHERE {any_conflict}"
+            ),
+        )
+        .await?;
+
+        // Without timestamps on both sides there is no "newer" row; fall back to keeping local
+        let rule = if timestamps {
+            rule
+        } else {
+            ImportConflictRule::PreferNonNull
+        };
+        let mut assignments: Vec<String> = fields
+            .iter()
+            .map(|c| format!("{} = {}", quote(c), merged_value(&quote(c), rule)))
+            .collect();
+        if timestamps {
+            assignments.push(
+                "updated_at = CASE WHEN julianday(s.updated_at) > julianday(m.updated_at) \
+                 THEN s.updated_at ELSE m.updated_at END"
+                    .into(),
+            );
+        }
+        let any_change = fields
+            .iter()
+            .map(|c| {
+                let c = quote(c);
+                format!("({}) IS NOT m.{c}", merged_value(&c, rule))
+            })
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        counts.updated = sqlx::query(&format!(
+            "UPDATE main.{name} AS m SET {} FROM {SOURCE_SCHEMA}.{name} AS s \
+             WHERE m.url = s.url AND ({any_change})",
+            assignments.join(", ")
+        ))
+        .execute(&mut *conn)
+        .await
+        .with_context(|| format!("Failed to merge existing {name} rows"))?
+        .rows_affected();
+    }
+
+    // New URLs
+    let new_rows = format!(
+        "s.url IS NOT NULL AND NOT EXISTS (SELECT 1 FROM main.{name} m WHERE m.url = s.url)"
+    );
+    let new_rows = if table == ImportTable::ProductDetails {
+        counts.skipped_orphans = count(
+            conn,
+            &format!(
+                "SELECT COUNT(*) FROM {SOURCE_SCHEMA}.{name} s WHERE {new_rows} \
+                 AND NOT EXISTS (SELECT 1 FROM main.products p WHERE p.url = s.url)"
+            ),
+        )
+        .await?;
+        format!("{new_rows} AND EXISTS (SELECT 1 FROM main.products p WHERE p.url = s.url)")
+    } else {
+        new_rows
+    };
+    let slot_taken = if has("page_id") && has("index_in_page") {
+        let taken = format!(
+            "s.page_id IS NOT NULL AND s.index_in_page IS NOT NULL AND EXISTS (SELECT 1 FROM main.{name} o \
+             WHERE o.page_id = s.page_id AND o.index_in_page = s.index_in_page)"
+        );
+        counts.slot_cleared = count(
+            conn,
+            &format!("SELECT COUNT(*) FROM {SOURCE_SCHEMA}.{name} s WHERE {new_rows} AND {taken}"),
+        )
+        .await?;
+        Some(taken)
+    } else {
+        None
+    };
+    let mut targets = Vec::new();
+    let mut values = Vec::new();
+    for column in &shared {
+        let q = quote(column);
+        let value = match column.as_str() {
+            "created_at" | "updated_at" => format!("COALESCE(s.{q}, CURRENT_TIMESTAMP)"),
+            c if table.slot_columns().contains(&c) => match &slot_taken {
+                Some(taken) => format!("CASE WHEN {taken} THEN NULL ELSE s.{q} END"),
+                None => format!("s.{q}"),
+            },
+            _ => format!("s.{q}"),
+        };
+        targets.push(q);
+        values.push(value);
+    }
+    counts.inserted = sqlx::query(&format!(
+        "INSERT INTO main.{name} ({}) SELECT {} FROM {SOURCE_SCHEMA}.{name} s WHERE {new_rows}",
+        targets.join(", "),
+        values.join(", ")
+    ))
+    .execute(&mut *conn)
+    .await
+    .with_context(|| format!("Failed to insert new {name} rows"))?
+    .rows_affected();
+
+    Ok(counts)
+}
+
+async fn merge_attached(
+    conn: &mut SqliteConnection,
+    rule: ImportConflictRule,
+    dry_run: bool,
+) -> Result<(TableImportCounts, TableImportCounts)> {
+    let mut tx = conn.begin().await?;
+    let mut merged = Vec::with_capacity(ImportTable::ORDER.len());
+    for table in ImportTable::ORDER {
+        merged.push(merge_table(&mut tx, table, rule).await?);
+    }
+    if dry_run {
+        tx.rollback().await?;
+    } else {
+        tx.commit().await?;
+    }
+    let details = merged.pop().unwrap_or_default();
+    let products = merged.pop().unwrap_or_default();
+    Ok((products, details))
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Merge the `products`/`product_details` rows of the SQLite file at `source` into the pool's
+/// database. With `dry_run` nothing is written; the counts are what a real import would do.
+pub async fn import_database(
+    pool: &SqlitePool,
+    source: &Path,
+    rule: ImportConflictRule,
+    dry_run: bool,
+) -> Result<DatabaseImportReport> {
+    if !source.is_file() {
+        bail!("Import source {} is not a file", source.display());
+    }
+    if let Ok(main) = database_paths::database_file(pool).await {
+        if same_file(&main, source) {
+            bail!("Import source is the current database");
+        }
+    }
+
+    // ATTACH is per connection: attach, merge and detach on one pooled connection
+    let mut conn = pool.acquire().await?;
+    sqlx::query(&format!("ATTACH DATABASE ? AS {SOURCE_SCHEMA}"))
+        .bind(source.to_string_lossy().as_ref())
+        .execute(&mut *conn)
+        .await
+        .with_context(|| format!("Failed to attach {}", source.display()))?;
+    let merged = merge_attached(&mut conn, rule, dry_run).await;
+    if let Err(e) = sqlx::query(&format!("DETACH DATABASE {SOURCE_SCHEMA}"))
+        .execute(&mut *conn)
+        .await
+    {
+        // Never hand a connection with the source still attached back to the pool
+        warn!("📥 [DatabaseImport] detach failed ({e}); closing connection");
+        drop(conn.detach());
+    }
+    let (products, product_details) =
+        merged.with_context(|| format!("Import from {} failed", source.display()))?;
+
+    info!(
+        "📥 [DatabaseImport] {} ({rule:?}{}): products +{} ~{} !{}, details +{} ~{} !{}",
+        source.display(),
+        if dry_run { ", dry run" } else { "" },
+        products.inserted,
+        products.updated,
+        products.conflicted,
+        product_details.inserted,
+        product_details.updated,
+        product_details.conflicted
+    );
+    Ok(DatabaseImportReport {
+        source_path: source.display().to_string(),
+        rule,
+        dry_run,
+        products,
+        product_details,
+        backup_file: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const SCHEMA: &[&str] = &[
+        "CREATE TABLE products (url TEXT PRIMARY KEY, manufacturer TEXT, model TEXT, certificate_id TEXT, \
+         page_id INTEGER, index_in_page INTEGER, id TEXT, \
+         created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP, updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP)",
+        "CREATE UNIQUE INDEX ux_products_slot ON products(page_id, index_in_page) \
+         WHERE page_id IS NOT NULL AND index_in_page IS NOT NULL",
+        "CREATE TABLE product_details (url TEXT PRIMARY KEY, page_id INTEGER, index_in_page INTEGER, \
+         model TEXT, vid INTEGER, \
+         created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP, updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP, \
+         FOREIGN KEY (url) REFERENCES products (url) ON DELETE CASCADE)",
+    ];
+
+    async fn database(path: &Path, rows: &[&str]) -> SqlitePool {
+        let options = sqlx::sqlite::SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true)
+            .foreign_keys(true);
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .unwrap();
+        for sql in SCHEMA.iter().chain(rows) {
+            sqlx::query(sql).execute(&pool).await.unwrap();
+        }
+        pool
+    }
+
+    #[tokio::test]
+    async fn merges_by_url_with_conflict_rules() {
+        let dir = TempDir::new().unwrap();
+        let local = database(
+            &dir.path().join("local.db"),
+            &[
+                "INSERT INTO products (url, manufacturer, model, page_id, index_in_page, updated_at) VALUES \
+                 ('u1', 'Acme', 'X1', 1, 0, '2025-01-01 00:00:00'), \
+                 ('u2', NULL, 'Y', 1, 1, '2025-03-01 00:00:00')",
+                "INSERT INTO product_details (url, model, vid, updated_at) VALUES ('u1', 'X1', NULL, '2025-01-01 00:00:00')",
+            ],
+        )
+        .await;
+        let source_path = dir.path().join("other.db");
+        let source = database(
+            &source_path,
+            &[
+                // u1 newer with a different model; u2 older but fills the blank manufacturer;
+                // u3 is new and its slot is taken locally by u2
+                "INSERT INTO products (url, manufacturer, model, page_id, index_in_page, updated_at) VALUES \
+                 ('u1', 'Acme', 'X2', 1, 0, '2025-02-01 00:00:00'), \
+                 ('u2', 'Beta', '', 5, 5, '2025-01-01 00:00:00'), \
+                 ('u3', 'Gamma', 'Z', 1, 1, '2025-01-01 00:00:00')",
+                "INSERT INTO product_details (url, model, vid, updated_at) VALUES \
+                 ('u1', 'X2', 4660, '2025-02-01 00:00:00'), ('u3', 'Z', 1, '2025-01-01 00:00:00')",
+            ],
+        )
+        .await;
+        source.close().await;
+
+        let dry = import_database(&local, &source_path, ImportConflictRule::PreferNewer, true)
+            .await
+            .unwrap();
+        assert_eq!(
+            (
+                dry.products.inserted,
+                dry.products.updated,
+                dry.products.conflicted
+            ),
+            (1, 2, 1)
+        );
+        assert_eq!(dry.products.slot_cleared, 1);
+        assert_eq!(
+            (dry.product_details.inserted, dry.product_details.updated),
+            (1, 1)
+        );
+        let rows: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM products")
+            .fetch_one(&local)
+            .await
+            .unwrap();
+        assert_eq!(rows, 2, "dry run must not write");
+
+        let kept = import_database(
+            &local,
+            &source_path,
+            ImportConflictRule::PreferNonNull,
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!((kept.products.updated, kept.products.conflicted), (1, 1));
+        let model: String = sqlx::query_scalar("SELECT model FROM products WHERE url = 'u1'")
+            .fetch_one(&local)
+            .await
+            .unwrap();
+        assert_eq!(model, "X1");
+        let (manufacturer, model): (String, String) =
+            sqlx::query_as("SELECT manufacturer, model FROM products WHERE url = 'u2'")
+                .fetch_one(&local)
+                .await
+                .unwrap();
+        assert_eq!((manufacturer.as_str(), model.as_str()), ("Beta", "Y"));
+        let slot: (Option<i64>, Option<i64>) =
+            sqlx::query_as("SELECT page_id, index_in_page FROM products WHERE url = 'u3'")
+                .fetch_one(&local)
+                .await
+                .unwrap();
+        assert_eq!(slot, (None, None));
+
+        let newer = import_database(&local, &source_path, ImportConflictRule::PreferNewer, false)
+            .await
+            .unwrap();
+        assert_eq!((newer.products.inserted, newer.products.updated), (0, 1));
+        let model: String = sqlx::query_scalar("SELECT model FROM products WHERE url = 'u1'")
+            .fetch_one(&local)
+            .await
+            .unwrap();
+        assert_eq!(model, "X2");
+
+        let local_path = dir.path().join("local.db");
+        assert!(
+            import_database(&local, &local_path, ImportConflictRule::PreferNewer, true)
+                .await
+                .is_err()
+        );
+    }
+}
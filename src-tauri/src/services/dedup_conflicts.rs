@@ -0,0 +1,918 @@
+//! 중복 병합 충돌 큐 (near-duplicate merge conflicts)
+//!
+//! 정규화 URL(`dedup_key`)이 같은 행들을 하나의 중복 그룹으로 보고, 그룹 안에서 같은 필드에
+//! 서로 다른 값이 있으면 필드별 충돌을 `dedup_conflicts` 테이블에 pending으로 쌓는다.
+//! 충돌은 필드별로 값을 직접 고르거나 일괄 전략(most-recent-wins / most-complete-wins)으로
+//! 해소하며, 선택 결과는 `dedup_resolutions`에 저장되어 같은 충돌이 다시 나타나면 자동 적용된다.
+//! 해소되지 않은 충돌이 없는 그룹만 하나의 행으로 병합된다.
+
+use anyhow::{Result, anyhow, bail};
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use tracing::{debug, info, warn};
+
+/// Fields compared between duplicate `products` rows
+const PRODUCT_FIELDS: &[&str] = &["manufacturer", "model", "certificate_id"];
+
+/// Fields compared between duplicate `product_details` rows
+const DETAIL_FIELDS: &[&str] = &[
+    "id",
+    "manufacturer",
+    "model",
+    "device_type",
+    "certificate_id",
+    "certification_date",
+    "software_version",
+    "hardware_version",
+    "firmware_version",
+    "specification_version",
+    "vid",
+    "pid",
+    "family_sku",
+    "family_variant_sku",
+    "family_id",
+    "tis_trp_tested",
+    "transport_interface",
+    "primary_device_type_id",
+    "application_categories",
+    "description",
+    "compliance_document_url",
+    "program_type",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DedupTable {
+    Products,
+    ProductDetails,
+}
+
+impl DedupTable {
+    /// Merge order: details first so no detail row is left pointing at a deleted product URL
+    const MERGE_ORDER: [DedupTable; 2] = [DedupTable::ProductDetails, DedupTable::Products];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DedupTable::Products => "products",
+            DedupTable::ProductDetails => "product_details",
+        }
+    }
+
+    fn fields(self) -> &'static [&'static str] {
+        match self {
+            DedupTable::Products => PRODUCT_FIELDS,
+            DedupTable::ProductDetails => DETAIL_FIELDS,
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "products" => Some(DedupTable::Products),
+            "product_details" => Some(DedupTable::ProductDetails),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStatus {
+    Pending,
+    Resolved,
+}
+
+impl ConflictStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            ConflictStatus::Pending => "pending",
+            ConflictStatus::Resolved => "resolved",
+        }
+    }
+}
+
+/// How a conflict value was chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResolutionStrategy {
+    /// Picked per field by the user
+    Manual,
+    /// Value of the most recently updated row
+    MostRecentWins,
+    /// Value of the row with the most non-empty fields
+    MostCompleteWins,
+}
+
+impl ResolutionStrategy {
+    fn as_str(self) -> &'static str {
+        match self {
+            ResolutionStrategy::Manual => "manual",
+            ResolutionStrategy::MostRecentWins => "most_recent_wins",
+            ResolutionStrategy::MostCompleteWins => "most_complete_wins",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "manual" => Some(ResolutionStrategy::Manual),
+            "most_recent_wins" => Some(ResolutionStrategy::MostRecentWins),
+            "most_complete_wins" => Some(ResolutionStrategy::MostCompleteWins),
+            _ => None,
+        }
+    }
+
+    /// Candidate this bulk strategy picks (None for `Manual`)
+    pub fn pick<'a>(self, candidates: &'a [ConflictCandidate]) -> Option<&'a ConflictCandidate> {
+        match self {
+            ResolutionStrategy::Manual => None,
+            ResolutionStrategy::MostRecentWins => candidates
+                .iter()
+                .max_by(|a, b| (&a.updated_at, a.rowid).cmp(&(&b.updated_at, b.rowid))),
+            ResolutionStrategy::MostCompleteWins => candidates.iter().max_by(|a, b| {
+                (a.filled_fields, &a.updated_at, a.rowid).cmp(&(
+                    b.filled_fields,
+                    &b.updated_at,
+                    b.rowid,
+                ))
+            }),
+        }
+    }
+}
+
+/// One row's value for a conflicting field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConflictCandidate {
+    pub rowid: i64,
+    pub url: String,
+    pub value: String,
+    pub updated_at: Option<String>,
+    /// Non-empty compared fields on this row (used by most-complete-wins)
+    pub filled_fields: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DedupConflict {
+    pub id: String,
+    pub table: DedupTable,
+    pub dedup_key: String,
+    pub field: String,
+    pub candidates: Vec<ConflictCandidate>,
+    pub status: ConflictStatus,
+    pub resolved_value: Option<String>,
+    pub strategy: Option<ResolutionStrategy>,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DedupScanReport {
+    /// Keys with more than one row in either table
+    pub duplicate_groups: usize,
+    pub new_conflicts: usize,
+    /// Conflicts settled from a previously stored resolution
+    pub auto_resolved: usize,
+    pub pending_conflicts: usize,
+    pub merged_groups: usize,
+    pub rows_removed: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DedupResolveReport {
+    pub resolved: usize,
+    pub merged_groups: usize,
+    pub rows_removed: u64,
+    pub pending_conflicts: usize,
+}
+
+/// Row values for the compared fields (aligned with `DedupTable::fields`)
+#[derive(Debug, Clone)]
+struct RowSnapshot {
+    rowid: i64,
+    url: String,
+    updated_at: Option<String>,
+    values: Vec<Option<String>>,
+}
+
+impl RowSnapshot {
+    fn filled_fields(&self) -> usize {
+        self.values.iter().filter(|v| v.is_some()).count()
+    }
+}
+
+/// Rows per table sharing one dedup key
+type DedupGroup = BTreeMap<DedupTable, Vec<RowSnapshot>>;
+
+/// Resolved values keyed by (table, dedup_key) then field
+type ResolvedValues = HashMap<(DedupTable, String), HashMap<String, String>>;
+
+/// Identity used to detect near-duplicates: host without `www.`, path without trailing
+/// slash; scheme, query and fragment are ignored.
+pub fn dedup_key(url: &str) -> String {
+    let trimmed = url.trim();
+    match Url::parse(trimmed) {
+        Ok(parsed) => {
+            let host = parsed.host_str().unwrap_or("").to_ascii_lowercase();
+            let host = host.strip_prefix("www.").unwrap_or(&host);
+            format!("{}{}", host, parsed.path().trim_end_matches('/'))
+        }
+        Err(_) => trimmed.trim_end_matches('/').to_ascii_lowercase(),
+    }
+}
+
+/// Fields whose non-empty values differ across `rows`, with every row's candidate value
+fn detect_conflicts(
+    table: DedupTable,
+    rows: &[RowSnapshot],
+) -> Vec<(&'static str, Vec<ConflictCandidate>)> {
+    let mut conflicts = Vec::new();
+    for (i, field) in table.fields().iter().enumerate() {
+        let candidates: Vec<ConflictCandidate> = rows
+            .iter()
+            .filter_map(|row| {
+                row.values[i].as_ref().map(|value| ConflictCandidate {
+                    rowid: row.rowid,
+                    url: row.url.clone(),
+                    value: value.clone(),
+                    updated_at: row.updated_at.clone(),
+                    filled_fields: row.filled_fields(),
+                })
+            })
+            .collect();
+        let distinct: HashSet<&str> = candidates.iter().map(|c| c.value.as_str()).collect();
+        if distinct.len() > 1 {
+            conflicts.push((*field, candidates));
+        }
+    }
+    conflicts
+}
+
+/// Field values for the surviving row, or None while a conflicting field is unresolved
+fn merged_values(
+    table: DedupTable,
+    rows: &[RowSnapshot],
+    resolved: Option<&HashMap<String, String>>,
+) -> Option<Vec<Option<String>>> {
+    let conflicting: HashSet<&str> = detect_conflicts(table, rows)
+        .into_iter()
+        .map(|(field, _)| field)
+        .collect();
+    table
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            if conflicting.contains(field) {
+                resolved
+                    .and_then(|r| r.get(*field))
+                    .map(|value| Some(value.clone()))
+            } else {
+                Some(rows.iter().find_map(|row| row.values[i].clone()))
+            }
+        })
+        .collect()
+}
+
+/// URL every row of the group is merged into: the oldest product row, else the oldest detail row
+fn keeper_url(group: &DedupGroup) -> Option<&str> {
+    group
+        .get(&DedupTable::Products)
+        .and_then(|rows| rows.first())
+        .or_else(|| {
+            group
+                .get(&DedupTable::ProductDetails)
+                .and_then(|rows| rows.first())
+        })
+        .map(|row| row.url.as_str())
+}
+
+fn is_duplicate_group(group: &DedupGroup) -> bool {
+    group.values().any(|rows| rows.len() > 1)
+}
+
+/// Every row of both tables grouped by dedup key (singletons included so a lone
+/// product row can still anchor the keeper URL of its duplicated details)
+async fn load_groups(pool: &SqlitePool) -> Result<HashMap<String, DedupGroup>> {
+    let mut groups: HashMap<String, DedupGroup> = HashMap::new();
+    for table in DedupTable::MERGE_ORDER {
+        let columns: Vec<String> = table
+            .fields()
+            .iter()
+            .map(|f| format!("CAST({f} AS TEXT) AS {f}"))
+            .collect();
+        let sql = format!(
+            "SELECT rowid AS rid, url, CAST(updated_at AS TEXT) AS updated_at, {} \
+             FROM {} WHERE url IS NOT NULL ORDER BY rowid",
+            columns.join(", "),
+            table.name()
+        );
+        for row in sqlx::query(&sql).fetch_all(pool).await? {
+            let url: String = row.get("url");
+            let values = table
+                .fields()
+                .iter()
+                .map(|f| {
+                    row.get::<Option<String>, _>(*f)
+                        .map(|v| v.trim().to_string())
+                        .filter(|v| !v.is_empty())
+                })
+                .collect();
+            groups
+                .entry(dedup_key(&url))
+                .or_default()
+                .entry(table)
+                .or_default()
+                .push(RowSnapshot {
+                    rowid: row.get("rid"),
+                    url,
+                    updated_at: row.get("updated_at"),
+                    values,
+                });
+        }
+    }
+    groups.retain(|_, group| is_duplicate_group(group));
+    Ok(groups)
+}
+
+async fn load_resolutions(pool: &SqlitePool) -> Result<ResolvedValues> {
+    let rows = sqlx::query("SELECT table_name, dedup_key, field, value FROM dedup_resolutions")
+        .fetch_all(pool)
+        .await?;
+    let mut resolved: ResolvedValues = HashMap::new();
+    for row in rows {
+        let Some(table) = DedupTable::parse(row.get::<String, _>("table_name").as_str()) else {
+            continue;
+        };
+        resolved
+            .entry((table, row.get("dedup_key")))
+            .or_default()
+            .insert(row.get("field"), row.get("value"));
+    }
+    Ok(resolved)
+}
+
+async fn upsert_conflict(
+    pool: &SqlitePool,
+    table: DedupTable,
+    key: &str,
+    field: &str,
+    candidates: &[ConflictCandidate],
+    resolution: Option<&str>,
+) -> Result<()> {
+    let now = Utc::now().to_rfc3339();
+    let status = if resolution.is_some() {
+        ConflictStatus::Resolved
+    } else {
+        ConflictStatus::Pending
+    };
+    // A stored resolution keeps its original strategy; only status/value are refreshed here
+    sqlx::query(
+        "INSERT INTO dedup_conflicts (id, table_name, dedup_key, field, candidates, status, resolved_value, strategy, created_at, resolved_at) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, \
+                 (SELECT strategy FROM dedup_resolutions \
+                  WHERE ? = 'resolved' AND table_name = ? AND dedup_key = ? AND field = ?), ?, ?) \
+         ON CONFLICT (table_name, dedup_key, field) DO UPDATE SET \
+            candidates = excluded.candidates, status = excluded.status, \
+            resolved_value = excluded.resolved_value, strategy = excluded.strategy, \
+            resolved_at = CASE WHEN excluded.status = 'resolved' \
+                          THEN COALESCE(dedup_conflicts.resolved_at, excluded.resolved_at) END",
+    )
+    .bind(uuid::Uuid::new_v4().to_string())
+    .bind(table.name())
+    .bind(key)
+    .bind(field)
+    .bind(serde_json::to_string(candidates)?)
+    .bind(status.as_str())
+    .bind(resolution)
+    .bind(status.as_str())
+    .bind(table.name())
+    .bind(key)
+    .bind(field)
+    .bind(&now)
+    .bind(resolution.map(|_| now.clone()))
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Mark a conflict resolved and remember the choice for future scans
+async fn record_resolution(
+    pool: &SqlitePool,
+    conflict: &DedupConflict,
+    value: &str,
+    strategy: ResolutionStrategy,
+) -> Result<()> {
+    let now = Utc::now().to_rfc3339();
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "UPDATE dedup_conflicts SET status = 'resolved', resolved_value = ?, strategy = ?, resolved_at = ? WHERE id = ?",
+    )
+    .bind(value)
+    .bind(strategy.as_str())
+    .bind(&now)
+    .bind(&conflict.id)
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query(
+        "INSERT INTO dedup_resolutions (table_name, dedup_key, field, value, strategy, decided_at) \
+         VALUES (?, ?, ?, ?, ?, ?) \
+         ON CONFLICT (table_name, dedup_key, field) DO UPDATE SET \
+            value = excluded.value, strategy = excluded.strategy, decided_at = excluded.decided_at",
+    )
+    .bind(conflict.table.name())
+    .bind(&conflict.dedup_key)
+    .bind(&conflict.field)
+    .bind(value)
+    .bind(strategy.as_str())
+    .bind(&now)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Collapse one group into its keeper URL; returns removed row count
+async fn merge_group(
+    pool: &SqlitePool,
+    key: &str,
+    group: &DedupGroup,
+    resolved: &ResolvedValues,
+) -> Result<Option<u64>> {
+    let Some(keeper) = keeper_url(group) else {
+        return Ok(None);
+    };
+    let mut planned = Vec::new();
+    for table in DedupTable::MERGE_ORDER {
+        let Some(rows) = group.get(&table) else {
+            continue;
+        };
+        let Some(values) = merged_values(table, rows, resolved.get(&(table, key.to_string())))
+        else {
+            // Unresolved conflict left in this table
+            return Ok(None);
+        };
+        let keep = rows.iter().position(|r| r.url == keeper).unwrap_or(0);
+        planned.push((table, rows, keep, values));
+    }
+
+    let mut removed = 0u64;
+    let mut tx = pool.begin().await?;
+    for (table, rows, keep, values) in planned {
+        for (i, row) in rows.iter().enumerate() {
+            if i != keep {
+                removed += sqlx::query(&format!("DELETE FROM {} WHERE rowid = ?", table.name()))
+                    .bind(row.rowid)
+                    .execute(&mut *tx)
+                    .await?
+                    .rows_affected();
+            }
+        }
+        if rows.len() == 1 && rows[keep].url == keeper {
+            continue;
+        }
+        let assignments: Vec<String> = table.fields().iter().map(|f| format!("{f} = ?")).collect();
+        let sql = format!(
+            "UPDATE {} SET {}, url = ?, updated_at = CURRENT_TIMESTAMP WHERE rowid = ?",
+            table.name(),
+            assignments.join(", ")
+        );
+        let mut query = sqlx::query(&sql);
+        for value in values {
+            query = query.bind(value);
+        }
+        query
+            .bind(keeper)
+            .bind(rows[keep].rowid)
+            .execute(&mut *tx)
+            .await?;
+    }
+    tx.commit().await?;
+    debug!("🧬 merged duplicate group {} into {}", key, keeper);
+    if crate::infrastructure::features::feature_search_index_hooks() {
+        let urls: Vec<String> = group
+            .values()
+            .flatten()
+            .map(|row| row.url.clone())
+            .collect();
+        if let Err(e) = crate::services::search_index::apply_deltas(pool, &urls).await {
+            warn!("🧬 search index delta after merge failed: {e:#}");
+        }
+    }
+    Ok(Some(removed))
+}
+
+/// Merge every group whose conflicting fields all have a stored resolution
+async fn merge_ready_groups(
+    pool: &SqlitePool,
+    groups: &HashMap<String, DedupGroup>,
+) -> Result<(usize, u64)> {
+    let resolved = load_resolutions(pool).await?;
+    let mut merged = 0;
+    let mut removed = 0;
+    for (key, group) in groups {
+        if let Some(n) = merge_group(pool, key, group, &resolved).await? {
+            merged += 1;
+            removed += n;
+        }
+    }
+    Ok((merged, removed))
+}
+
+async fn count_pending(pool: &SqlitePool) -> Result<usize> {
+    let pending: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM dedup_conflicts WHERE status = 'pending'")
+            .fetch_one(pool)
+            .await?;
+    Ok(pending as usize)
+}
+
+/// Detect near-duplicate groups, queue their field conflicts, re-apply stored resolutions
+/// and merge every group that no longer needs a decision.
+pub async fn scan_conflicts(pool: &SqlitePool) -> Result<DedupScanReport> {
+    let groups = load_groups(pool).await?;
+    let stored = load_resolutions(pool).await?;
+    let previous: HashMap<(String, String, String), String> =
+        sqlx::query("SELECT table_name, dedup_key, field, status FROM dedup_conflicts")
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|r| {
+                (
+                    (
+                        r.get::<String, _>("table_name"),
+                        r.get::<String, _>("dedup_key"),
+                        r.get::<String, _>("field"),
+                    ),
+                    r.get::<String, _>("status"),
+                )
+            })
+            .collect();
+
+    let mut report = DedupScanReport {
+        duplicate_groups: groups.len(),
+        ..Default::default()
+    };
+    let mut seen: HashSet<(String, String, String)> = HashSet::new();
+    for (key, group) in &groups {
+        for (table, rows) in group {
+            let stored_for_key = stored.get(&(*table, key.clone()));
+            for (field, candidates) in detect_conflicts(*table, rows) {
+                let resolution = stored_for_key
+                    .and_then(|s| s.get(field))
+                    .filter(|value| candidates.iter().any(|c| &c.value == *value));
+                let id = (table.name().to_string(), key.clone(), field.to_string());
+                let was_pending = previous.get(&id).is_some_and(|s| s == "pending");
+                if resolution.is_some() {
+                    report.auto_resolved += 1;
+                } else if !was_pending {
+                    report.new_conflicts += 1;
+                }
+                upsert_conflict(
+                    pool,
+                    *table,
+                    key,
+                    field,
+                    &candidates,
+                    resolution.map(String::as_str),
+                )
+                .await?;
+                seen.insert(id);
+            }
+        }
+    }
+
+    // Pending conflicts whose rows no longer disagree (re-crawled or removed) are dropped
+    for (table, key, field) in previous
+        .iter()
+        .filter(|(id, status)| status.as_str() == "pending" && !seen.contains(*id))
+        .map(|(id, _)| id)
+    {
+        sqlx::query(
+            "DELETE FROM dedup_conflicts WHERE table_name = ? AND dedup_key = ? AND field = ?",
+        )
+        .bind(table)
+        .bind(key)
+        .bind(field)
+        .execute(pool)
+        .await?;
+    }
+
+    let (merged, removed) = merge_ready_groups(pool, &groups).await?;
+    report.merged_groups = merged;
+    report.rows_removed = removed;
+    report.pending_conflicts = count_pending(pool).await?;
+    info!(
+        "🧬 Dedup scan: groups={} new_conflicts={} auto_resolved={} merged={} removed={} pending={}",
+        report.duplicate_groups,
+        report.new_conflicts,
+        report.auto_resolved,
+        report.merged_groups,
+        report.rows_removed,
+        report.pending_conflicts
+    );
+    Ok(report)
+}
+
+fn conflict_from_row(row: &sqlx::sqlite::SqliteRow) -> Result<DedupConflict> {
+    let id: String = row.get("id");
+    let parse_ts = |s: Option<String>| -> Option<DateTime<Utc>> {
+        s.and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|t| t.with_timezone(&Utc))
+    };
+    Ok(DedupConflict {
+        table: DedupTable::parse(row.get::<String, _>("table_name").as_str())
+            .ok_or_else(|| anyhow!("conflict {} has unknown table", id))?,
+        dedup_key: row.get("dedup_key"),
+        field: row.get("field"),
+        candidates: serde_json::from_str(row.get::<String, _>("candidates").as_str())
+            .map_err(|e| anyhow!("conflict {} has invalid candidates: {}", id, e))?,
+        status: if row.get::<String, _>("status") == "resolved" {
+            ConflictStatus::Resolved
+        } else {
+            ConflictStatus::Pending
+        },
+        resolved_value: row.get("resolved_value"),
+        strategy: row
+            .get::<Option<String>, _>("strategy")
+            .and_then(|s| ResolutionStrategy::parse(&s)),
+        created_at: parse_ts(row.get("created_at")).unwrap_or_else(Utc::now),
+        resolved_at: parse_ts(row.get("resolved_at")),
+        id,
+    })
+}
+
+const CONFLICT_COLUMNS: &str = "id, table_name, dedup_key, field, candidates, status, resolved_value, strategy, created_at, resolved_at";
+
+/// Queued conflicts (oldest first), optionally filtered by status and dedup key
+pub async fn list_conflicts(
+    pool: &SqlitePool,
+    status: Option<ConflictStatus>,
+    dedup_key: Option<&str>,
+    limit: u32,
+) -> Result<Vec<DedupConflict>> {
+    let sql = format!(
+        "SELECT {CONFLICT_COLUMNS} FROM dedup_conflicts \
+         WHERE (?1 IS NULL OR status = ?1) AND (?2 IS NULL OR dedup_key = ?2) \
+         ORDER BY created_at, dedup_key, table_name, field LIMIT ?3"
+    );
+    sqlx::query(&sql)
+        .bind(status.map(ConflictStatus::as_str))
+        .bind(dedup_key)
+        .bind(i64::from(limit))
+        .fetch_all(pool)
+        .await?
+        .iter()
+        .map(conflict_from_row)
+        .collect()
+}
+
+/// Settle one field with a value chosen from its candidates, then merge the group if ready
+pub async fn resolve_conflict(
+    pool: &SqlitePool,
+    conflict_id: &str,
+    value: &str,
+) -> Result<DedupResolveReport> {
+    let row = sqlx::query(&format!(
+        "SELECT {CONFLICT_COLUMNS} FROM dedup_conflicts WHERE id = ?"
+    ))
+    .bind(conflict_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| anyhow!("no dedup conflict with id {}", conflict_id))?;
+    let conflict = conflict_from_row(&row)?;
+    if !conflict.candidates.iter().any(|c| c.value == value) {
+        bail!(
+            "{:?} is not a candidate value for {}.{} ({})",
+            value,
+            conflict.table.name(),
+            conflict.field,
+            conflict.dedup_key
+        );
+    }
+    record_resolution(pool, &conflict, value, ResolutionStrategy::Manual).await?;
+    finish_resolution(pool, 1).await
+}
+
+/// Resolve every pending conflict (or only those of `dedup_key`) with a bulk strategy
+pub async fn apply_strategy(
+    pool: &SqlitePool,
+    strategy: ResolutionStrategy,
+    dedup_key: Option<&str>,
+) -> Result<DedupResolveReport> {
+    if strategy == ResolutionStrategy::Manual {
+        bail!("manual resolution needs a chosen value; use resolve_dedup_conflict");
+    }
+    let pending = list_conflicts(pool, Some(ConflictStatus::Pending), dedup_key, u32::MAX).await?;
+    let mut resolved = 0;
+    for conflict in &pending {
+        if let Some(choice) = strategy.pick(&conflict.candidates) {
+            record_resolution(pool, conflict, &choice.value, strategy).await?;
+            resolved += 1;
+        }
+    }
+    info!(
+        "🧬 Applied {} to {} dedup conflicts",
+        strategy.as_str(),
+        resolved
+    );
+    finish_resolution(pool, resolved).await
+}
+
+async fn finish_resolution(pool: &SqlitePool, resolved: usize) -> Result<DedupResolveReport> {
+    let groups = load_groups(pool).await?;
+    let (merged_groups, rows_removed) = merge_ready_groups(pool, &groups).await?;
+    Ok(DedupResolveReport {
+        resolved,
+        merged_groups,
+        rows_removed,
+        pending_conflicts: count_pending(pool).await?,
+    })
+}
+
+/// Conflicts still waiting on a decision
+pub async fn pending_conflict_count(pool: &SqlitePool) -> Result<i64> {
+    Ok(
+        sqlx::query_scalar("SELECT COUNT(*) FROM dedup_conflicts WHERE status = 'pending'")
+            .fetch_one(pool)
+            .await?,
+    )
+}
+
+/// URLs of rows still waiting on a decision (destructive dedup passes must leave them alone)
+pub async fn pending_conflict_urls(pool: &SqlitePool) -> Result<Vec<String>> {
+    let mut urls: HashSet<String> = HashSet::new();
+    for candidates in sqlx::query_scalar::<_, String>(
+        "SELECT candidates FROM dedup_conflicts WHERE status = 'pending'",
+    )
+    .fetch_all(pool)
+    .await?
+    {
+        let candidates: Vec<ConflictCandidate> = serde_json::from_str(&candidates)?;
+        urls.extend(candidates.into_iter().map(|c| c.url));
+    }
+    Ok(urls.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    fn row(rowid: i64, url: &str, updated_at: &str, values: &[Option<&str>]) -> RowSnapshot {
+        RowSnapshot {
+            rowid,
+            url: url.to_string(),
+            updated_at: Some(updated_at.to_string()),
+            values: values.iter().map(|v| v.map(str::to_string)).collect(),
+        }
+    }
+
+    #[test]
+    fn dedup_key_ignores_scheme_www_trailing_slash_and_query() {
+        let key = dedup_key("https://csa-iot.org/csa_product/smart-plug-1/");
+        assert_eq!(key, "csa-iot.org/csa_product/smart-plug-1");
+        assert_eq!(
+            dedup_key("http://www.CSA-IOT.org/csa_product/smart-plug-1"),
+            key
+        );
+        assert_eq!(
+            dedup_key("https://csa-iot.org/csa_product/smart-plug-1/?utm=x#top"),
+            key
+        );
+        assert_ne!(
+            dedup_key("https://csa-iot.org/csa_product/smart-plug-2/"),
+            key
+        );
+    }
+
+    #[test]
+    fn only_differing_non_empty_values_conflict() {
+        let rows = vec![
+            row(
+                1,
+                "https://a.test/p/",
+                "2025-01-01",
+                &[Some("Acme"), Some("X1"), None],
+            ),
+            row(
+                2,
+                "https://a.test/p",
+                "2025-02-01",
+                &[Some("Acme"), Some("X2"), Some("C-9")],
+            ),
+        ];
+        let conflicts = detect_conflicts(DedupTable::Products, &rows);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].0, "model");
+        assert!(merged_values(DedupTable::Products, &rows, None).is_none());
+
+        let resolved = HashMap::from([("model".to_string(), "X2".to_string())]);
+        assert_eq!(
+            merged_values(DedupTable::Products, &rows, Some(&resolved)).unwrap(),
+            vec![
+                Some("Acme".to_string()),
+                Some("X2".to_string()),
+                Some("C-9".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn bulk_strategies_pick_recent_or_complete_row() {
+        let rows = vec![
+            row(
+                1,
+                "https://a.test/p",
+                "2025-03-01",
+                &[Some("Acme"), Some("X1"), None],
+            ),
+            row(
+                2,
+                "https://a.test/p/",
+                "2025-01-01",
+                &[Some("Acme"), Some("X2"), Some("C-9")],
+            ),
+        ];
+        let (_, candidates) = detect_conflicts(DedupTable::Products, &rows).remove(0);
+        let recent = ResolutionStrategy::MostRecentWins
+            .pick(&candidates)
+            .unwrap();
+        assert_eq!(recent.value, "X1");
+        let complete = ResolutionStrategy::MostCompleteWins
+            .pick(&candidates)
+            .unwrap();
+        assert_eq!(complete.value, "X2");
+        assert!(ResolutionStrategy::Manual.pick(&candidates).is_none());
+    }
+
+    async fn test_pool() -> SqlitePool {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        db.pool().clone()
+    }
+
+    async fn insert_product(pool: &SqlitePool, url: &str, model: &str, updated_at: &str) {
+        sqlx::query(
+            "INSERT INTO products (url, manufacturer, model, updated_at) VALUES (?, 'Acme', ?, ?)",
+        )
+        .bind(url)
+        .bind(model)
+        .bind(updated_at)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn conflicts_queue_then_merge_and_resolution_is_reused() {
+        let pool = test_pool().await;
+        insert_product(&pool, "https://a.test/p/1/", "X1", "2025-01-01 00:00:00").await;
+        insert_product(&pool, "https://a.test/p/1", "X2", "2025-02-01 00:00:00").await;
+        insert_product(&pool, "https://a.test/p/2/", "Y", "2025-01-01 00:00:00").await;
+        insert_product(&pool, "https://a.test/p/2", "Y", "2025-01-01 00:00:00").await;
+
+        let report = scan_conflicts(&pool).await.unwrap();
+        assert_eq!(report.duplicate_groups, 2);
+        assert_eq!(report.new_conflicts, 1);
+        // The conflict-free group merges immediately
+        assert_eq!((report.merged_groups, report.rows_removed), (1, 1));
+        assert_eq!(pending_conflict_urls(&pool).await.unwrap().len(), 2);
+
+        let pending = list_conflicts(&pool, Some(ConflictStatus::Pending), None, 10)
+            .await
+            .unwrap();
+        assert_eq!(pending[0].field, "model");
+        assert!(
+            resolve_conflict(&pool, &pending[0].id, "nope")
+                .await
+                .is_err()
+        );
+        let resolved = apply_strategy(&pool, ResolutionStrategy::MostRecentWins, None)
+            .await
+            .unwrap();
+        assert_eq!((resolved.resolved, resolved.merged_groups), (1, 1));
+        assert_eq!(resolved.pending_conflicts, 0);
+
+        let (url, model): (String, String) =
+            sqlx::query_as("SELECT url, model FROM products WHERE url LIKE '%/p/1%'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            (url.as_str(), model.as_str()),
+            ("https://a.test/p/1/", "X2")
+        );
+
+        // The same disagreement re-appearing is settled from the stored resolution
+        insert_product(&pool, "https://a.test/p/1", "X1", "2025-03-01 00:00:00").await;
+        let report = scan_conflicts(&pool).await.unwrap();
+        assert_eq!((report.auto_resolved, report.merged_groups), (1, 1));
+        let model: String =
+            sqlx::query_scalar("SELECT model FROM products WHERE url LIKE '%/p/1%'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(model, "X2");
+    }
+}
@@ -0,0 +1,353 @@
+//! 영구 실패 항목 dead-letter 큐 (`failed_items`)
+//!
+//! 재시도를 모두 소진한 목록 페이지 / 상세 URL 은 지금까지 로그에만 남고 사라졌다. 단계,
+//! 에러 분류(`ErrorCode`), 시도 이력, 재실행에 필요한 페이로드 스냅샷과 함께 (stage, item_key)
+//! 단위로 보관하고, 수정 후 개별 또는 일괄로 다시 돌릴 수 있게 한다. 같은 항목이 다시 실패하면
+//! 새 행을 만들지 않고 이력을 이어 붙인다.
+
+use crate::domain::error_catalog::ErrorCode;
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+/// Attempts kept per item; older ones are dropped first
+pub const MAX_ATTEMPT_HISTORY: usize = 50;
+pub const DEFAULT_LIST_LIMIT: u32 = 100;
+pub const MAX_LIST_LIMIT: u32 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailedItemStage {
+    /// Listing page; `item_key` is the canonical page_id
+    ListPage,
+    /// Product detail page; `item_key` is the URL
+    ProductDetail,
+}
+
+impl FailedItemStage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FailedItemStage::ListPage => "list_page",
+            FailedItemStage::ProductDetail => "product_detail",
+        }
+    }
+
+    fn parse(raw: &str) -> Result<Self> {
+        match raw {
+            "list_page" => Ok(FailedItemStage::ListPage),
+            "product_detail" => Ok(FailedItemStage::ProductDetail),
+            other => anyhow::bail!("unknown failed item stage {other}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailedItemStatus {
+    /// Waiting for a replay
+    Pending,
+    /// Handed to the next sync (list pages go through the revalidation queue)
+    Requeued,
+    /// A replay succeeded
+    Resolved,
+}
+
+impl FailedItemStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FailedItemStatus::Pending => "pending",
+            FailedItemStatus::Requeued => "requeued",
+            FailedItemStatus::Resolved => "resolved",
+        }
+    }
+
+    fn parse(raw: &str) -> Result<Self> {
+        match raw {
+            "pending" => Ok(FailedItemStatus::Pending),
+            "requeued" => Ok(FailedItemStatus::Requeued),
+            "resolved" => Ok(FailedItemStatus::Resolved),
+            other => anyhow::bail!("unknown failed item status {other}"),
+        }
+    }
+}
+
+/// One failed try of an item
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailedAttempt {
+    pub attempt: u32,
+    pub error: String,
+    /// RFC 3339
+    pub at: String,
+}
+
+impl FailedAttempt {
+    pub fn now(attempt: u32, error: impl Into<String>) -> Self {
+        Self {
+            attempt,
+            error: error.into(),
+            at: Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// An item that just exhausted its retries
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    pub stage: FailedItemStage,
+    pub item_key: String,
+    pub session_id: String,
+    pub error_class: ErrorCode,
+    pub attempts: Vec<FailedAttempt>,
+    /// What a replay needs (URL / page coordinates)
+    pub payload: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedItem {
+    pub id: i64,
+    pub stage: FailedItemStage,
+    pub item_key: String,
+    /// Session that dead-lettered it last
+    pub session_id: String,
+    pub error_class: ErrorCode,
+    pub last_error: String,
+    pub attempts: Vec<FailedAttempt>,
+    pub payload: serde_json::Value,
+    pub status: FailedItemStatus,
+    /// Times the item ran out of retries (1 + failed replays)
+    pub dead_letter_count: u32,
+    pub first_failed_at: String,
+    pub last_failed_at: String,
+    pub requeued_at: Option<String>,
+}
+
+/// `list_failed_items` filter; unset fields match everything
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FailedItemFilter {
+    #[serde(default)]
+    pub stage: Option<FailedItemStage>,
+    #[serde(default)]
+    pub status: Option<FailedItemStatus>,
+    #[serde(default)]
+    pub session_id: Option<String>,
+    #[serde(default)]
+    pub limit: Option<u32>,
+    #[serde(default)]
+    pub offset: Option<u32>,
+}
+
+/// Store an item that ran out of retries; an existing row goes back to pending with the new
+/// attempts appended to its history
+pub async fn record(pool: &SqlitePool, item: &DeadLetter) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    let previous: Option<String> = sqlx::query_scalar(
+        "SELECT attempts_json FROM failed_items WHERE stage = ? AND item_key = ?",
+    )
+    .bind(item.stage.as_str())
+    .bind(&item.item_key)
+    .fetch_optional(&mut *tx)
+    .await?;
+    let mut attempts: Vec<FailedAttempt> = previous
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    attempts.extend(item.attempts.iter().cloned());
+    let overflow = attempts.len().saturating_sub(MAX_ATTEMPT_HISTORY);
+    attempts.drain(..overflow);
+    let last_error = attempts.last().map(|a| a.error.clone()).unwrap_or_default();
+    let now = Utc::now().to_rfc3339();
+    sqlx::query(
+        "INSERT INTO failed_items (stage, item_key, session_id, error_class, last_error, \
+            attempts_json, payload_json, status, dead_letter_count, first_failed_at, last_failed_at) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, 'pending', 1, ?, ?) \
+         ON CONFLICT(stage, item_key) DO UPDATE SET \
+            session_id = excluded.session_id, error_class = excluded.error_class, \
+            last_error = excluded.last_error, attempts_json = excluded.attempts_json, \
+            payload_json = excluded.payload_json, status = 'pending', \
+            dead_letter_count = dead_letter_count + 1, last_failed_at = excluded.last_failed_at",
+    )
+    .bind(item.stage.as_str())
+    .bind(&item.item_key)
+    .bind(&item.session_id)
+    .bind(item.error_class.as_str())
+    .bind(last_error)
+    .bind(serde_json::to_string(&attempts)?)
+    .bind(item.payload.to_string())
+    .bind(&now)
+    .bind(&now)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+fn row_to_item(row: &sqlx::sqlite::SqliteRow) -> Result<FailedItem> {
+    let error_class: String = row.get("error_class");
+    Ok(FailedItem {
+        id: row.get("id"),
+        stage: FailedItemStage::parse(&row.get::<String, _>("stage"))?,
+        item_key: row.get("item_key"),
+        session_id: row.get("session_id"),
+        error_class: ErrorCode::from_code(&error_class).unwrap_or(ErrorCode::Internal),
+        last_error: row.get("last_error"),
+        attempts: serde_json::from_str(&row.get::<String, _>("attempts_json"))
+            .context("corrupt attempt history")?,
+        payload: serde_json::from_str(&row.get::<String, _>("payload_json"))
+            .context("corrupt payload snapshot")?,
+        status: FailedItemStatus::parse(&row.get::<String, _>("status"))?,
+        dead_letter_count: row.get::<i64, _>("dead_letter_count") as u32,
+        first_failed_at: row.get("first_failed_at"),
+        last_failed_at: row.get("last_failed_at"),
+        requeued_at: row.get("requeued_at"),
+    })
+}
+
+/// Newest failures first
+pub async fn list(pool: &SqlitePool, filter: &FailedItemFilter) -> Result<Vec<FailedItem>> {
+    let rows = sqlx::query(
+        "SELECT * FROM failed_items \
+         WHERE (? IS NULL OR stage = ?) AND (? IS NULL OR status = ?) \
+            AND (? IS NULL OR session_id = ?) \
+         ORDER BY last_failed_at DESC, id DESC LIMIT ? OFFSET ?",
+    )
+    .bind(filter.stage.map(FailedItemStage::as_str))
+    .bind(filter.stage.map(FailedItemStage::as_str))
+    .bind(filter.status.map(FailedItemStatus::as_str))
+    .bind(filter.status.map(FailedItemStatus::as_str))
+    .bind(filter.session_id.as_deref())
+    .bind(filter.session_id.as_deref())
+    .bind(
+        filter
+            .limit
+            .unwrap_or(DEFAULT_LIST_LIMIT)
+            .clamp(1, MAX_LIST_LIMIT) as i64,
+    )
+    .bind(filter.offset.unwrap_or(0) as i64)
+    .fetch_all(pool)
+    .await?;
+    rows.iter().map(row_to_item).collect()
+}
+
+/// Items by id (missing ids are skipped)
+pub async fn load(pool: &SqlitePool, ids: &[i64]) -> Result<Vec<FailedItem>> {
+    let mut items = Vec::with_capacity(ids.len());
+    for id in ids {
+        let row = sqlx::query("SELECT * FROM failed_items WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await?;
+        if let Some(row) = row {
+            items.push(row_to_item(&row)?);
+        }
+    }
+    Ok(items)
+}
+
+pub async fn set_status(pool: &SqlitePool, id: i64, status: FailedItemStatus) -> Result<()> {
+    sqlx::query(
+        "UPDATE failed_items SET status = ?, \
+            requeued_at = CASE WHEN ? = 'pending' THEN requeued_at ELSE ? END \
+         WHERE id = ?",
+    )
+    .bind(status.as_str())
+    .bind(status.as_str())
+    .bind(Utc::now().to_rfc3339())
+    .bind(id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Resolve list-page items whose canonical page re-synced cleanly; returns the rows updated
+pub async fn resolve_list_pages(pool: &SqlitePool, page_ids: &[i64]) -> Result<u64> {
+    let mut resolved = 0;
+    for page_id in page_ids {
+        resolved += sqlx::query(
+            "UPDATE failed_items SET status = 'resolved' \
+             WHERE stage = 'list_page' AND item_key = ? AND status != 'resolved'",
+        )
+        .bind(page_id.to_string())
+        .execute(pool)
+        .await?
+        .rows_affected();
+    }
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    #[tokio::test]
+    async fn repeated_failures_extend_history_and_requeue_updates_status() {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        let pool = db.pool().clone();
+        let letter = |session: &str, error: &str| DeadLetter {
+            stage: FailedItemStage::ProductDetail,
+            item_key: "https://x/p/1".into(),
+            session_id: session.into(),
+            error_class: ErrorCode::classify_message(error),
+            attempts: vec![FailedAttempt::now(1, error), FailedAttempt::now(2, error)],
+            payload: serde_json::json!({ "url": "https://x/p/1", "page_id": 3 }),
+        };
+        record(&pool, &letter("s1", "connection reset"))
+            .await
+            .unwrap();
+        record(
+            &pool,
+            &DeadLetter {
+                stage: FailedItemStage::ListPage,
+                item_key: "40".into(),
+                error_class: ErrorCode::EmptyListPage,
+                payload: serde_json::json!({ "page_id": 40, "physical_page": 2 }),
+                ..letter("s1", "nothing extracted")
+            },
+        )
+        .await
+        .unwrap();
+        record(&pool, &letter("s2", "request timed out"))
+            .await
+            .unwrap();
+
+        let details = list(
+            &pool,
+            &FailedItemFilter {
+                stage: Some(FailedItemStage::ProductDetail),
+                ..FailedItemFilter::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(details.len(), 1);
+        let item = &details[0];
+        assert_eq!(
+            (item.session_id.as_str(), item.dead_letter_count),
+            ("s2", 2)
+        );
+        assert_eq!(item.error_class, ErrorCode::NetworkTimeout);
+        assert_eq!(item.attempts.len(), 4);
+        assert_eq!(item.last_error, "request timed out");
+        assert_eq!(item.payload["page_id"], 3);
+
+        set_status(&pool, item.id, FailedItemStatus::Resolved)
+            .await
+            .unwrap();
+        let pending = list(
+            &pool,
+            &FailedItemFilter {
+                status: Some(FailedItemStatus::Pending),
+                ..FailedItemFilter::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].stage, FailedItemStage::ListPage);
+        let resolved = load(&pool, &[item.id, 999]).await.unwrap();
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].status, FailedItemStatus::Resolved);
+        assert!(resolved[0].requeued_at.is_some());
+    }
+}
@@ -0,0 +1,417 @@
+//! 선택 필드 재수집 (`refresh_fields`)
+//!
+//! compliance_document_url 이나 펌웨어 필드만 갱신이 필요할 때 전체 sync 는 과하다. 필터
+//! (URL 목록 / page_id 범위 / 요청 필드가 비어 있는 행)에 걸린 상세 페이지만 다시 받아 요청한
+//! 컬럼만 UPDATE 한다. 다른 컬럼은 건드리지 않고, 새 페이지에서 추출되지 않은 필드(None)는
+//! 기존 값을 유지한다. 변경 이력은 product_details 트리거가 평소처럼 남긴다.
+
+use crate::domain::product::ProductDetail;
+use crate::infrastructure::integrated_product_repository::IntegratedProductRepository;
+use anyhow::{Result, bail};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+
+pub const DEFAULT_REFRESH_LIMIT: u32 = 500;
+pub const MAX_REFRESH_LIMIT: u32 = 5000;
+
+/// product_details column `refresh_fields` may rewrite (serde name = column name)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefreshField {
+    Manufacturer,
+    Model,
+    DeviceType,
+    CertificateId,
+    CertificationDate,
+    SoftwareVersion,
+    HardwareVersion,
+    FirmwareVersion,
+    Vid,
+    Pid,
+    FamilySku,
+    FamilyVariantSku,
+    FamilyId,
+    TisTrpTested,
+    SpecificationVersion,
+    TransportInterface,
+    PrimaryDeviceTypeId,
+    ApplicationCategories,
+    Description,
+    ComplianceDocumentUrl,
+    ProgramType,
+}
+
+enum FieldValue<'a> {
+    Text(&'a str),
+    Int(i32),
+}
+
+impl RefreshField {
+    pub fn column(self) -> &'static str {
+        match self {
+            RefreshField::Manufacturer => "manufacturer",
+            RefreshField::Model => "model",
+            RefreshField::DeviceType => "device_type",
+            RefreshField::CertificateId => "certificate_id",
+            RefreshField::CertificationDate => "certification_date",
+            RefreshField::SoftwareVersion => "software_version",
+            RefreshField::HardwareVersion => "hardware_version",
+            RefreshField::FirmwareVersion => "firmware_version",
+            RefreshField::Vid => "vid",
+            RefreshField::Pid => "pid",
+            RefreshField::FamilySku => "family_sku",
+            RefreshField::FamilyVariantSku => "family_variant_sku",
+            RefreshField::FamilyId => "family_id",
+            RefreshField::TisTrpTested => "tis_trp_tested",
+            RefreshField::SpecificationVersion => "specification_version",
+            RefreshField::TransportInterface => "transport_interface",
+            RefreshField::PrimaryDeviceTypeId => "primary_device_type_id",
+            RefreshField::ApplicationCategories => "application_categories",
+            RefreshField::Description => "description",
+            RefreshField::ComplianceDocumentUrl => "compliance_document_url",
+            RefreshField::ProgramType => "program_type",
+        }
+    }
+
+    fn value(self, detail: &ProductDetail) -> Option<FieldValue<'_>> {
+        let text = match self {
+            RefreshField::Vid => return detail.vid.map(FieldValue::Int),
+            RefreshField::Pid => return detail.pid.map(FieldValue::Int),
+            RefreshField::Manufacturer => &detail.manufacturer,
+            RefreshField::Model => &detail.model,
+            RefreshField::DeviceType => &detail.device_type,
+            RefreshField::CertificateId => &detail.certificate_id,
+            RefreshField::CertificationDate => &detail.certification_date,
+            RefreshField::SoftwareVersion => &detail.software_version,
+            RefreshField::HardwareVersion => &detail.hardware_version,
+            RefreshField::FirmwareVersion => &detail.firmware_version,
+            RefreshField::FamilySku => &detail.family_sku,
+            RefreshField::FamilyVariantSku => &detail.family_variant_sku,
+            RefreshField::FamilyId => &detail.family_id,
+            RefreshField::TisTrpTested => &detail.tis_trp_tested,
+            RefreshField::SpecificationVersion => &detail.specification_version,
+            RefreshField::TransportInterface => &detail.transport_interface,
+            RefreshField::PrimaryDeviceTypeId => &detail.primary_device_type_id,
+            RefreshField::ApplicationCategories => &detail.application_categories,
+            RefreshField::Description => &detail.description,
+            RefreshField::ComplianceDocumentUrl => &detail.compliance_document_url,
+            RefreshField::ProgramType => &detail.program_type,
+        };
+        text.as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(FieldValue::Text)
+    }
+}
+
+/// Which product_details rows to re-fetch; filters combine with AND
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FieldRefreshFilter {
+    #[serde(default)]
+    pub urls: Vec<String>,
+    #[serde(default)]
+    pub page_id_min: Option<i32>,
+    #[serde(default)]
+    pub page_id_max: Option<i32>,
+    /// Only rows where at least one requested field is NULL
+    #[serde(default)]
+    pub only_missing: bool,
+    /// Max rows re-fetched (default 500, capped at 5000)
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+impl FieldRefreshFilter {
+    /// A filter is required so a typo cannot turn into a full-table re-crawl
+    pub fn validate(&self, fields: &[RefreshField]) -> Result<()> {
+        if fields.is_empty() {
+            bail!("at least one field is required");
+        }
+        if self.urls.is_empty()
+            && self.page_id_min.is_none()
+            && self.page_id_max.is_none()
+            && !self.only_missing
+        {
+            bail!("a URL list, page_id range or only_missing filter is required");
+        }
+        if let Some((min, max)) = self
+            .page_id_min
+            .zip(self.page_id_max)
+            .filter(|(min, max)| min > max)
+        {
+            bail!("page_id_min {min} is greater than page_id_max {max}");
+        }
+        Ok(())
+    }
+}
+
+/// Per-field outcome over all fetched rows
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldUpdateCount {
+    pub field: RefreshField,
+    pub updated: u32,
+    pub unchanged: u32,
+    /// Fresh page had no value; the stored one was kept
+    pub missing: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FieldRefreshReport {
+    pub dry_run: bool,
+    pub targeted: u32,
+    pub fetched: u32,
+    pub failed: u32,
+    pub fields: Vec<FieldUpdateCount>,
+}
+
+impl FieldRefreshReport {
+    pub fn new(fields: &[RefreshField], targeted: u32, dry_run: bool) -> Self {
+        Self {
+            dry_run,
+            targeted,
+            fields: fields
+                .iter()
+                .map(|&field| FieldUpdateCount {
+                    field,
+                    updated: 0,
+                    unchanged: 0,
+                    missing: 0,
+                })
+                .collect(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Sorted, de-duplicated field list
+pub fn normalize_fields(fields: &[RefreshField]) -> Vec<RefreshField> {
+    let mut fields = fields.to_vec();
+    fields.sort_unstable();
+    fields.dedup();
+    fields
+}
+
+/// URLs of product_details rows matching the filter, newest page first
+pub async fn select_targets(
+    pool: &SqlitePool,
+    fields: &[RefreshField],
+    filter: &FieldRefreshFilter,
+) -> Result<Vec<String>> {
+    let mut sql = String::from("SELECT url FROM product_details WHERE 1 = 1");
+    let urls: Vec<String> = filter
+        .urls
+        .iter()
+        .map(|u| IntegratedProductRepository::normalize_url(u.trim()))
+        .collect();
+    if !urls.is_empty() {
+        sql.push_str(&format!(
+            " AND url IN ({})",
+            vec!["?"; urls.len()].join(", ")
+        ));
+    }
+    if filter.page_id_min.is_some() {
+        sql.push_str(" AND page_id >= ?");
+    }
+    if filter.page_id_max.is_some() {
+        sql.push_str(" AND page_id <= ?");
+    }
+    if filter.only_missing && !fields.is_empty() {
+        let missing: Vec<String> = fields
+            .iter()
+            .map(|f| format!("{} IS NULL", f.column()))
+            .collect();
+        sql.push_str(&format!(" AND ({})", missing.join(" OR ")));
+    }
+    sql.push_str(" ORDER BY page_id DESC, index_in_page ASC LIMIT ?");
+
+    let mut query = sqlx::query_scalar::<_, String>(&sql);
+    for url in &urls {
+        query = query.bind(url);
+    }
+    if let Some(min) = filter.page_id_min {
+        query = query.bind(min);
+    }
+    if let Some(max) = filter.page_id_max {
+        query = query.bind(max);
+    }
+    let limit = filter
+        .limit
+        .unwrap_or(DEFAULT_REFRESH_LIMIT)
+        .clamp(1, MAX_REFRESH_LIMIT);
+    Ok(query.bind(limit as i64).fetch_all(pool).await?)
+}
+
+/// Write the requested fields of one freshly extracted detail; a dry run rolls back
+pub async fn apply_fields(
+    pool: &SqlitePool,
+    url: &str,
+    fresh: &ProductDetail,
+    report: &mut FieldRefreshReport,
+) -> Result<()> {
+    let now = Utc::now();
+    let mut tx = pool.begin().await?;
+    for count in report.fields.iter_mut() {
+        let Some(value) = count.field.value(fresh) else {
+            count.missing += 1;
+            continue;
+        };
+        let column = count.field.column();
+        let sql = format!(
+            "UPDATE product_details SET {column} = ?, updated_at = ? \
+             WHERE url = ? AND {column} IS NOT ?"
+        );
+        let query = match value {
+            FieldValue::Text(v) => sqlx::query(&sql).bind(v).bind(now).bind(url).bind(v),
+            FieldValue::Int(v) => sqlx::query(&sql).bind(v).bind(now).bind(url).bind(v),
+        };
+        if query.execute(&mut *tx).await?.rows_affected() > 0 {
+            count.updated += 1;
+        } else {
+            count.unchanged += 1;
+        }
+    }
+    if report.dry_run {
+        tx.rollback().await?;
+    } else {
+        tx.commit().await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    fn detail(url: &str) -> ProductDetail {
+        ProductDetail {
+            url: url.to_string(),
+            page_id: None,
+            index_in_page: None,
+            id: None,
+            manufacturer: None,
+            model: None,
+            device_type: None,
+            certificate_id: None,
+            certification_date: None,
+            software_version: None,
+            hardware_version: None,
+            vid: None,
+            pid: None,
+            family_sku: None,
+            family_variant_sku: None,
+            firmware_version: None,
+            family_id: None,
+            tis_trp_tested: None,
+            specification_version: None,
+            transport_interface: None,
+            primary_device_type_id: None,
+            application_categories: None,
+            description: None,
+            compliance_document_url: None,
+            program_type: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn updates_only_requested_columns_and_counts_per_field() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TABLE product_details (url TEXT PRIMARY KEY, page_id INTEGER, \
+             index_in_page INTEGER, model TEXT, firmware_version TEXT, vid INTEGER, \
+             compliance_document_url TEXT, updated_at DATETIME)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        for (url, page_id, doc) in [
+            ("https://x/p/1", 3, Some("https://x/doc/1")),
+            ("https://x/p/2", 3, None),
+            ("https://x/p/3", 9, None),
+        ] {
+            sqlx::query(
+                "INSERT INTO product_details (url, page_id, index_in_page, model, \
+                 firmware_version, compliance_document_url) VALUES (?, ?, 0, 'M', '1.0', ?)",
+            )
+            .bind(url)
+            .bind(page_id)
+            .bind(doc)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        let fields = normalize_fields(&[
+            RefreshField::FirmwareVersion,
+            RefreshField::ComplianceDocumentUrl,
+            RefreshField::FirmwareVersion,
+        ]);
+        assert!(FieldRefreshFilter::default().validate(&fields).is_err());
+        let filter = FieldRefreshFilter {
+            page_id_max: Some(5),
+            ..FieldRefreshFilter::default()
+        };
+        filter.validate(&fields).unwrap();
+        let targets = select_targets(&pool, &fields, &filter).await.unwrap();
+        assert_eq!(targets, vec!["https://x/p/1", "https://x/p/2"]);
+        let missing_only = FieldRefreshFilter {
+            only_missing: true,
+            ..FieldRefreshFilter::default()
+        };
+        assert_eq!(
+            select_targets(&pool, &fields, &missing_only).await.unwrap(),
+            vec!["https://x/p/3", "https://x/p/2"]
+        );
+
+        let mut report = FieldRefreshReport::new(&fields, targets.len() as u32, false);
+        let mut fresh = detail("https://x/p/1");
+        fresh.firmware_version = Some("2.0".into());
+        fresh.compliance_document_url = Some("https://x/doc/1".into());
+        fresh.model = Some("Other".into());
+        apply_fields(&pool, "https://x/p/1", &fresh, &mut report)
+            .await
+            .unwrap();
+        let mut fresh = detail("https://x/p/2");
+        fresh.firmware_version = Some("1.0".into());
+        apply_fields(&pool, "https://x/p/2", &fresh, &mut report)
+            .await
+            .unwrap();
+
+        let by_field = |f: RefreshField| {
+            let c = report.fields.iter().find(|c| c.field == f).unwrap();
+            (c.updated, c.unchanged, c.missing)
+        };
+        assert_eq!(by_field(RefreshField::FirmwareVersion), (1, 1, 0));
+        assert_eq!(by_field(RefreshField::ComplianceDocumentUrl), (0, 1, 1));
+        let (model, firmware): (String, String) = sqlx::query_as(
+            "SELECT model, firmware_version FROM product_details WHERE url = 'https://x/p/1'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        // model was not requested, so the fresh "Other" is not written
+        assert_eq!((model.as_str(), firmware.as_str()), ("M", "2.0"));
+
+        let mut dry = FieldRefreshReport::new(&fields, 1, true);
+        let mut fresh = detail("https://x/p/2");
+        fresh.firmware_version = Some("3.0".into());
+        apply_fields(&pool, "https://x/p/2", &fresh, &mut dry)
+            .await
+            .unwrap();
+        assert_eq!(dry.fields.iter().map(|c| c.updated).sum::<u32>(), 1);
+        let firmware: String = sqlx::query_scalar(
+            "SELECT firmware_version FROM product_details WHERE url = 'https://x/p/2'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(firmware, "1.0");
+    }
+}
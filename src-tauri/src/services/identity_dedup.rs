@@ -0,0 +1,267 @@
+//! 식별 키 기준 중복 제품 (URL 이 다르지만 같은 제품) 분석·병합
+//!
+//! `cleanup_duplicate_urls` 는 URL 이 정확히 같은 행만 다룬다. 같은 제품이 끝 슬래시나 쿼리 파라미터가
+//! 다른 URL 로 두 번 들어온 경우를 잡기 위해 products / product_details 의 certificate_id 와
+//! (vid, pid, model) 로 URL 을 묶는다 (`DeduplicationService::analyze_identity_duplicates`).
+//!
+//! 병합은 사용자가 보고서에서 고른 그룹(남길 URL 로 지정)만 수행하며, 다음 그룹은 건너뛴다:
+//! 보고서 이후 구성이 바뀌어 더는 그룹이 아닌 경우, certificate_id 가 서로 다른 그룹, 중복 충돌 큐에서
+//! 결정을 기다리는 URL 이 있는 그룹. 병합 전체가 한 트랜잭션이며 dry-run 은 롤백한다.
+
+use crate::domain::services::data_processing_services::{
+    DeduplicationService, IdentityDuplicateGroup, IdentityRecord,
+};
+use crate::infrastructure::DeduplicationServiceImpl;
+use crate::infrastructure::product_identity;
+use crate::services::dedup_conflicts;
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::{Row, SqlitePool};
+use std::collections::HashSet;
+use tracing::info;
+
+/// Groups listed in a report; the counts cover all of them
+const MAX_REPORTED_GROUPS: usize = 200;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IdentityDedupReport {
+    pub duplicate_groups: usize,
+    /// URLs a merge of every group would fold away
+    pub duplicate_urls: usize,
+    /// Groups joined by vid/pid/model whose certificate_ids differ (never merged)
+    pub conflicting_groups: usize,
+    /// First `MAX_REPORTED_GROUPS` groups
+    pub groups: Vec<IdentityDuplicateGroup>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IdentityMergeReport {
+    pub dry_run: bool,
+    pub merged_groups: usize,
+    /// URLs folded into their group's keeper
+    pub merged_urls: usize,
+    /// Requested keepers that no longer lead a duplicate group
+    pub not_found: Vec<String>,
+    /// Keepers skipped because their group has differing certificate_ids
+    pub conflicting: Vec<String>,
+    /// Keepers skipped because a URL of the group awaits a dedup conflict decision
+    pub held: Vec<String>,
+    /// Backup taken before a non-dry-run merge
+    pub backup_file: Option<String>,
+}
+
+/// Identity fields of every products / product_details row
+async fn load_records(pool: &SqlitePool) -> Result<Vec<IdentityRecord>> {
+    let rows = sqlx::query(
+        "SELECT url, certificate_id, NULL AS vid, NULL AS pid, model, updated_at FROM products \
+         UNION ALL \
+         SELECT url, certificate_id, vid, pid, model, updated_at FROM product_details",
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|r| IdentityRecord {
+            url: r.get("url"),
+            certificate_id: r.get("certificate_id"),
+            vid: r.get("vid"),
+            pid: r.get("pid"),
+            model: r.get("model"),
+            updated_at: r.try_get("updated_at").unwrap_or_default(),
+        })
+        .collect())
+}
+
+async fn identity_groups(pool: &SqlitePool) -> Result<Vec<IdentityDuplicateGroup>> {
+    let records = load_records(pool).await?;
+    DeduplicationServiceImpl::new(1.0)
+        .analyze_identity_duplicates(&records)
+        .await
+}
+
+/// Candidate duplicate groups by certificate_id or (vid, pid, model)
+pub async fn analyze(pool: &SqlitePool) -> Result<IdentityDedupReport> {
+    let groups = identity_groups(pool).await?;
+    Ok(IdentityDedupReport {
+        duplicate_groups: groups.len(),
+        duplicate_urls: groups.iter().map(|g| g.urls.len() - 1).sum(),
+        conflicting_groups: groups
+            .iter()
+            .filter(|g| g.has_conflicting_certificates())
+            .count(),
+        groups: groups.into_iter().take(MAX_REPORTED_GROUPS).collect(),
+    })
+}
+
+/// Fold every other URL of the groups led by `keepers` into the keeper
+pub async fn merge(
+    pool: &SqlitePool,
+    keepers: &[String],
+    dry_run: bool,
+) -> Result<IdentityMergeReport> {
+    let groups = identity_groups(pool).await?;
+    let held_urls: HashSet<String> = dedup_conflicts::pending_conflict_urls(pool)
+        .await?
+        .into_iter()
+        .collect();
+    let mut report = IdentityMergeReport {
+        dry_run,
+        ..Default::default()
+    };
+
+    let mut selected = Vec::new();
+    for keeper in keepers {
+        match groups.iter().find(|g| g.keeper() == keeper) {
+            None => report.not_found.push(keeper.clone()),
+            Some(group) if group.has_conflicting_certificates() => {
+                report.conflicting.push(keeper.clone())
+            }
+            Some(group) if group.urls.iter().any(|u| held_urls.contains(u)) => {
+                report.held.push(keeper.clone())
+            }
+            Some(group) => selected.push(group),
+        }
+    }
+    if selected.is_empty() {
+        return Ok(report);
+    }
+
+    let mut tx = pool.begin().await?;
+    product_identity::defer_foreign_keys(&mut tx).await?;
+    for group in &selected {
+        let (keeper, rest) = group.urls.split_first().expect("groups hold 2+ URLs");
+        for url in rest {
+            product_identity::fold(&mut tx, url, keeper).await?;
+        }
+        report.merged_groups += 1;
+        report.merged_urls += rest.len();
+    }
+    if dry_run {
+        tx.rollback().await?;
+    } else {
+        tx.commit().await?;
+        let urls: Vec<String> = selected.iter().flat_map(|g| g.urls.clone()).collect();
+        product_identity::refresh_search_index(pool, &urls).await;
+    }
+    info!(
+        "🧬 [IdentityDedup] {} groups / {} URLs merged{} ({} not found, {} conflicting, {} held)",
+        report.merged_groups,
+        report.merged_urls,
+        if dry_run { " (dry run)" } else { "" },
+        report.not_found.len(),
+        report.conflicting.len(),
+        report.held.len()
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    async fn seed(
+        pool: &SqlitePool,
+        url: &str,
+        cert: Option<&str>,
+        vid_pid_model: Option<(i32, i32, &str)>,
+        updated_at: &str,
+    ) {
+        sqlx::query(
+            "INSERT INTO products (url, model, certificate_id, updated_at) VALUES (?, ?, ?, ?)",
+        )
+        .bind(url)
+        .bind(vid_pid_model.map(|v| v.2))
+        .bind(cert)
+        .bind(updated_at)
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO product_details (url, model, certificate_id, vid, pid, updated_at) \
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(url)
+        .bind(vid_pid_model.map(|v| v.2))
+        .bind(cert)
+        .bind(vid_pid_model.map(|v| v.0))
+        .bind(vid_pid_model.map(|v| v.1))
+        .bind(updated_at)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn groups_by_certificate_or_vid_pid_model_and_merges_selected() {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        let pool = db.pool();
+        let rows: [(&str, Option<&str>, Option<(i32, i32, &str)>, &str); 7] = [
+            ("https://x/p/a", Some("CSA1"), None, "2024-01-01 00:00:00"),
+            (
+                "https://x/p/a/",
+                Some(" CSA1 "),
+                None,
+                "2024-02-01 00:00:00",
+            ),
+            (
+                "https://x/p/b",
+                None,
+                Some((1, 2, "Bulb")),
+                "2024-01-01 00:00:00",
+            ),
+            (
+                "https://x/p/b?x=1",
+                None,
+                Some((1, 2, " bulb")),
+                "2023-01-01 00:00:00",
+            ),
+            // Same vid/pid/model under different certificates: reported, never merged
+            (
+                "https://x/p/c",
+                Some("CSA3"),
+                Some((5, 6, "Plug")),
+                "2024-01-01 00:00:00",
+            ),
+            (
+                "https://x/p/d",
+                Some("CSA4"),
+                Some((5, 6, "plug")),
+                "2024-01-01 00:00:00",
+            ),
+            ("https://x/p/e", Some("CSA5"), None, "2024-01-01 00:00:00"),
+        ];
+        for (url, cert, vid_pid_model, updated_at) in rows {
+            seed(pool, url, cert, vid_pid_model, updated_at).await;
+        }
+
+        let report = analyze(pool).await.unwrap();
+        assert_eq!(report.duplicate_groups, 3);
+        assert_eq!(report.duplicate_urls, 3);
+        assert_eq!(report.conflicting_groups, 1);
+        let by_cert = &report.groups[0];
+        assert_eq!(by_cert.urls, vec!["https://x/p/a/", "https://x/p/a"]);
+
+        let keepers = vec![
+            "https://x/p/a/".to_string(),
+            "https://x/p/b".to_string(),
+            "https://x/p/c".to_string(),
+            "https://x/p/e".to_string(),
+        ];
+        let dry = merge(pool, &keepers, true).await.unwrap();
+        assert_eq!((dry.merged_groups, dry.merged_urls), (2, 2));
+        assert_eq!(dry.conflicting, vec!["https://x/p/c"]);
+        assert_eq!(dry.not_found, vec!["https://x/p/e"]);
+        assert_eq!(analyze(pool).await.unwrap().duplicate_groups, 3);
+
+        merge(pool, &keepers, false).await.unwrap();
+        let left = analyze(pool).await.unwrap();
+        assert_eq!(left.duplicate_groups, 1);
+        let products: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM products")
+            .fetch_one(pool)
+            .await
+            .unwrap();
+        assert_eq!(products, 5);
+    }
+}
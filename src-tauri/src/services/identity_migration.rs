@@ -0,0 +1,201 @@
+//! 제품 식별 전략 전환 (URL ↔ certificate_id) 시 기존 DB 정리
+//!
+//! certificate_id 전략은 certificate_id 하나에 URL 하나를 가정하지만, URL 전략으로 쌓인 DB 에는 같은
+//! certificate_id 를 가진 URL 이 여럿일 수 있다. 전환 전에 이런 그룹을 찾아 보고하고, 충돌 정책이
+//! `merge` 면 그룹마다 가장 최근에 갱신된 URL 로 나머지 행을 병합한다 (빈 필드만 채움, 부속 테이블 포함).
+//! 다른 정책이면 그룹은 그대로 두며 upsert 는 가장 최근 행을 그 제품으로 본다.
+//! URL 전략으로 돌아갈 때는 데이터를 바꾸지 않는다.
+//!
+//! 병합 전체가 한 트랜잭션이며 dry-run 은 같은 작업을 수행한 뒤 롤백해 집계만 돌려준다.
+
+use crate::infrastructure::config::{
+    IdentityCollisionPolicy, IdentityStrategy, ProductIdentityConfig,
+};
+use crate::infrastructure::product_identity;
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::{Row, SqlitePool};
+use tracing::info;
+
+/// Groups listed in a report; the count covers all of them
+const MAX_REPORTED_GROUPS: usize = 100;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CertificateGroup {
+    pub certificate_id: String,
+    /// Most recently updated first; a merge keeps the first URL
+    pub urls: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IdentityMigrationReport {
+    pub from: ProductIdentityConfig,
+    pub to: ProductIdentityConfig,
+    pub dry_run: bool,
+    /// certificate_id values held by more than one URL before the switch
+    pub duplicate_groups: usize,
+    /// First `MAX_REPORTED_GROUPS` groups
+    pub groups: Vec<CertificateGroup>,
+    pub merged_groups: usize,
+    /// URLs folded into their group's first URL
+    pub merged_urls: usize,
+    /// Backup taken before a merging, non-dry-run switch
+    pub backup_file: Option<String>,
+}
+
+/// Whether switching to `to` rewrites existing rows
+pub fn merges_duplicates(to: &ProductIdentityConfig) -> bool {
+    to.strategy == IdentityStrategy::CertificateId
+        && to.on_secondary_collision == IdentityCollisionPolicy::Merge
+}
+
+/// certificate_id values stored under more than one URL (products or product_details)
+pub async fn certificate_groups(pool: &SqlitePool) -> Result<Vec<CertificateGroup>> {
+    let rows = sqlx::query(
+        "SELECT certificate_id, url, MAX(updated_at) AS updated_at FROM ( \
+            SELECT TRIM(certificate_id) AS certificate_id, url, updated_at FROM products \
+            UNION ALL \
+            SELECT TRIM(certificate_id), url, updated_at FROM product_details \
+         ) WHERE certificate_id IS NOT NULL AND certificate_id <> '' \
+         GROUP BY certificate_id, url \
+         ORDER BY certificate_id, updated_at DESC, url",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut groups: Vec<CertificateGroup> = Vec::new();
+    for row in rows {
+        let certificate_id: String = row.try_get("certificate_id")?;
+        let url: String = row.try_get("url")?;
+        match groups.last_mut() {
+            Some(group) if group.certificate_id == certificate_id => group.urls.push(url),
+            _ => groups.push(CertificateGroup {
+                certificate_id,
+                urls: vec![url],
+            }),
+        }
+    }
+    groups.retain(|group| group.urls.len() > 1);
+    Ok(groups)
+}
+
+/// Prepare the database for the identity strategy `to` (currently `from`)
+pub async fn migrate(
+    pool: &SqlitePool,
+    from: ProductIdentityConfig,
+    to: ProductIdentityConfig,
+    dry_run: bool,
+) -> Result<IdentityMigrationReport> {
+    let groups = certificate_groups(pool).await?;
+    let mut report = IdentityMigrationReport {
+        from,
+        to,
+        dry_run,
+        duplicate_groups: groups.len(),
+        groups: groups.iter().take(MAX_REPORTED_GROUPS).cloned().collect(),
+        merged_groups: 0,
+        merged_urls: 0,
+        backup_file: None,
+    };
+    if !merges_duplicates(&to) || groups.is_empty() {
+        return Ok(report);
+    }
+
+    let mut tx = pool.begin().await?;
+    product_identity::defer_foreign_keys(&mut tx).await?;
+    for group in &groups {
+        let (keeper, rest) = group.urls.split_first().expect("groups hold 2+ URLs");
+        for url in rest {
+            product_identity::fold(&mut tx, url, keeper).await?;
+        }
+        report.merged_groups += 1;
+        report.merged_urls += rest.len();
+    }
+    if dry_run {
+        tx.rollback().await?;
+    } else {
+        tx.commit().await?;
+        let urls: Vec<String> = groups.into_iter().flat_map(|g| g.urls).collect();
+        product_identity::refresh_search_index(pool, &urls).await;
+    }
+    info!(
+        "🪪 [IdentityMigration] {:?} → {:?}{}: {} duplicate certificate groups, {} URLs merged",
+        from.strategy,
+        to.strategy,
+        if dry_run { " (dry run)" } else { "" },
+        report.duplicate_groups,
+        report.merged_urls
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        for sql in [
+            "CREATE TABLE products (url TEXT PRIMARY KEY, id TEXT, model TEXT, certificate_id TEXT, \
+             page_id INTEGER, index_in_page INTEGER, created_at TEXT, updated_at TEXT)",
+            "CREATE TABLE product_details (url TEXT PRIMARY KEY, model TEXT, certificate_id TEXT, \
+             page_id INTEGER, index_in_page INTEGER, updated_at TEXT)",
+            "INSERT INTO products VALUES ('https://x/a', NULL, 'A', 'C-1', 0, 0, '', '2025-01-01')",
+            "INSERT INTO products VALUES ('https://x/b', NULL, NULL, ' C-1 ', 0, 1, '', '2025-02-01')",
+            "INSERT INTO products VALUES ('https://x/c', NULL, 'C', 'C-2', 0, 2, '', '2025-01-01')",
+        ] {
+            sqlx::query(sql).execute(&pool).await.unwrap();
+        }
+        pool
+    }
+
+    fn config(
+        strategy: IdentityStrategy,
+        policy: IdentityCollisionPolicy,
+    ) -> ProductIdentityConfig {
+        ProductIdentityConfig {
+            strategy,
+            on_secondary_collision: policy,
+        }
+    }
+
+    #[tokio::test]
+    async fn merge_switch_folds_duplicate_certificates_into_latest_url() {
+        let pool = pool().await;
+        let groups = certificate_groups(&pool).await.unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].urls, vec!["https://x/b", "https://x/a"]);
+
+        let from = ProductIdentityConfig::default();
+        let to = config(
+            IdentityStrategy::CertificateId,
+            IdentityCollisionPolicy::Merge,
+        );
+        let dry = migrate(&pool, from, to, true).await.unwrap();
+        assert_eq!(dry.merged_urls, 1);
+        assert_eq!(certificate_groups(&pool).await.unwrap().len(), 1);
+
+        // Keeping both rows reports duplicates without touching them
+        let keep = config(
+            IdentityStrategy::CertificateId,
+            IdentityCollisionPolicy::KeepBoth,
+        );
+        let report = migrate(&pool, from, keep, false).await.unwrap();
+        assert_eq!((report.duplicate_groups, report.merged_groups), (1, 0));
+        assert_eq!(certificate_groups(&pool).await.unwrap().len(), 1);
+
+        migrate(&pool, from, to, false).await.unwrap();
+        assert!(certificate_groups(&pool).await.unwrap().is_empty());
+        let model: String =
+            sqlx::query_scalar("SELECT model FROM products WHERE url = 'https://x/b'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(model, "A");
+    }
+}
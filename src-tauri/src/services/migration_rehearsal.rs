@@ -0,0 +1,220 @@
+//! 스키마 업그레이드 리허설 (실제 DB 에 적용하기 전 사본에서 검증)
+//!
+//! 실제 DB 를 `VACUUM INTO` 로 임시 파일에 복사(WAL 에 있는 커밋까지 포함된 일관된 스냅샷)한 뒤, 앱 시작 시와
+//! 같은 `DatabaseConnection::migrate` 를 사본에 실행한다. 적용 전후의 대기 마이그레이션, 테이블별 행 수,
+//! `integrity_check` / `foreign_key_check` 결과를 보고하고 사본은 지운다. 실제 DB 는 읽기만 한다.
+//! 마이그레이션 실패도 보고서의 `error` 로 돌려준다.
+
+use crate::infrastructure::database_connection::{DatabaseConnection, pending_migrations};
+use crate::infrastructure::database_paths;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tracing::{info, warn};
+
+/// integrity_check messages kept in a report
+const MAX_INTEGRITY_ISSUES: usize = 20;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TableRowCount {
+    pub table: String,
+    /// None when the table did not exist before the migrations
+    pub before: Option<i64>,
+    /// None when the migrations dropped the table (or failed before counting)
+    pub after: Option<i64>,
+}
+
+impl TableRowCount {
+    fn changed(&self) -> bool {
+        self.before != self.after
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationRehearsalReport {
+    /// Migrations the real database would get at the next start
+    pub pending: Vec<String>,
+    /// Still pending on the copy afterwards (should be empty)
+    pub still_pending: Vec<String>,
+    pub copy_size_bytes: u64,
+    pub migration_ms: u64,
+    /// Migration error on the copy, if any
+    pub error: Option<String>,
+    pub integrity_ok: bool,
+    /// First `MAX_INTEGRITY_ISSUES` integrity_check messages
+    pub integrity_issues: Vec<String>,
+    pub foreign_key_violations: i64,
+    pub row_counts: Vec<TableRowCount>,
+    /// Tables whose row count differs after the migrations
+    pub changed_tables: Vec<String>,
+    /// Migrations succeeded, nothing left pending and the copy passed both checks
+    pub success: bool,
+}
+
+/// Rehearse pending migrations on a temporary copy of `pool`'s database
+pub async fn rehearse(pool: &SqlitePool) -> Result<MigrationRehearsalReport> {
+    // Fail early for in-memory databases
+    database_paths::database_file(pool).await?;
+    let pending = pending_migrations(pool).await?;
+
+    let copy = std::env::temp_dir().join(format!(
+        "rmattercertis-rehearsal-{}.db",
+        uuid::Uuid::new_v4()
+    ));
+    let result = rehearse_on_copy(pool, &copy, pending).await;
+    remove_copy(&copy).await;
+    result
+}
+
+async fn rehearse_on_copy(
+    pool: &SqlitePool,
+    copy: &Path,
+    pending: Vec<&'static str>,
+) -> Result<MigrationRehearsalReport> {
+    sqlx::query("VACUUM INTO ?")
+        .bind(copy.to_string_lossy().into_owned())
+        .execute(pool)
+        .await
+        .with_context(|| format!("Failed to copy database to {}", copy.display()))?;
+    let copy_size_bytes = tokio::fs::metadata(copy).await?.len();
+
+    let db = DatabaseConnection::new(&format!("sqlite:{}", copy.display())).await?;
+    let before = row_counts(db.pool()).await?;
+
+    let started = Instant::now();
+    let error = db.migrate().await.err().map(|e| format!("{e:#}"));
+    let migration_ms = started.elapsed().as_millis() as u64;
+
+    let after = row_counts(db.pool()).await?;
+    let still_pending = pending_migrations(db.pool()).await?;
+    let integrity: Vec<String> =
+        sqlx::query_scalar(&format!("PRAGMA integrity_check({MAX_INTEGRITY_ISSUES})"))
+            .fetch_all(db.pool())
+            .await?;
+    let integrity_ok = integrity.len() == 1 && integrity[0] == "ok";
+    let foreign_key_violations = sqlx::query("PRAGMA foreign_key_check")
+        .fetch_all(db.pool())
+        .await?
+        .len() as i64;
+    db.pool().close().await;
+
+    let row_counts = merge_counts(before, after);
+    let changed_tables = row_counts
+        .iter()
+        .filter(|c| c.changed())
+        .map(|c| c.table.clone())
+        .collect();
+    let success =
+        error.is_none() && still_pending.is_empty() && integrity_ok && foreign_key_violations == 0;
+    let report = MigrationRehearsalReport {
+        pending: pending.iter().map(|m| m.to_string()).collect(),
+        still_pending: still_pending.iter().map(|m| m.to_string()).collect(),
+        copy_size_bytes,
+        migration_ms,
+        error,
+        integrity_ok,
+        integrity_issues: if integrity_ok { Vec::new() } else { integrity },
+        foreign_key_violations,
+        row_counts,
+        changed_tables,
+        success,
+    };
+    info!(
+        "🧪 [MigrationRehearsal] pending={:?} success={} ({} ms, {} tables changed)",
+        report.pending,
+        report.success,
+        report.migration_ms,
+        report.changed_tables.len()
+    );
+    Ok(report)
+}
+
+/// Row count of every ordinary table, by name
+async fn row_counts(pool: &SqlitePool) -> Result<Vec<(String, i64)>> {
+    let tables: Vec<String> = sqlx::query_scalar(
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' \
+         AND sql NOT LIKE 'CREATE VIRTUAL%' ORDER BY name",
+    )
+    .fetch_all(pool)
+    .await?;
+    let mut counts = Vec::with_capacity(tables.len());
+    for table in tables {
+        let count: i64 = sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM \"{}\"",
+            table.replace('"', "\"\"")
+        ))
+        .fetch_one(pool)
+        .await?;
+        counts.push((table, count));
+    }
+    Ok(counts)
+}
+
+fn merge_counts(before: Vec<(String, i64)>, after: Vec<(String, i64)>) -> Vec<TableRowCount> {
+    let mut merged: Vec<TableRowCount> = before
+        .into_iter()
+        .map(|(table, count)| TableRowCount {
+            table,
+            before: Some(count),
+            after: None,
+        })
+        .collect();
+    for (table, count) in after {
+        match merged.iter_mut().find(|c| c.table == table) {
+            Some(entry) => entry.after = Some(count),
+            None => merged.push(TableRowCount {
+                table,
+                before: None,
+                after: Some(count),
+            }),
+        }
+    }
+    merged.sort_by(|a, b| a.table.cmp(&b.table));
+    merged
+}
+
+async fn remove_copy(copy: &Path) {
+    for suffix in ["", "-wal", "-shm", "-journal"] {
+        let path = PathBuf::from(format!("{}{suffix}", copy.display()));
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => warn!("Failed to remove rehearsal copy {}: {}", path.display(), e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn rehearses_pending_migration_on_a_copy_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!("sqlite:{}", dir.path().join("main.db").display());
+        let db = DatabaseConnection::new(&url).await.unwrap();
+        db.migrate().await.unwrap();
+        sqlx::query("DROP TRIGGER product_details_history")
+            .execute(db.pool())
+            .await
+            .unwrap();
+        assert_eq!(
+            pending_migrations(db.pool()).await.unwrap(),
+            vec!["007_product_history"]
+        );
+
+        let report = rehearse(db.pool()).await.unwrap();
+        assert!(report.success, "{report:?}");
+        assert_eq!(report.pending, vec!["007_product_history"]);
+        assert!(report.still_pending.is_empty());
+        assert!(report.changed_tables.is_empty());
+        assert!(report.row_counts.iter().any(|c| c.table == "products"));
+        // The real database is left as it was
+        assert_eq!(
+            pending_migrations(db.pool()).await.unwrap(),
+            vec!["007_product_history"]
+        );
+    }
+}
@@ -2,3 +2,31 @@
 
 // Archived UI no longer uses realtime dashboard; keep module available for future but avoid accidental imports
 pub mod dashboard_service; // 🎨 Phase C: 실시간 대시보드 서비스
+pub mod anonymization; // 🕶️ 공유용 내보내기/번들 익명화 프로필 (필드 제외 / 해시 / 경로 치환)
+pub mod analytics_cache; // 📊 대시보드 차트 집계 캐시 (트리거 버전 기반 무효화 / 백그라운드 재계산)
+pub mod batch_tuning; // 📐 배치별 처리량/메모리 압박 기록 → 다음 세션 batch_size 제안·적용
+pub mod chunked_query; // 📑 대용량 조회 결과 청크 커서 (rowid 스냅샷)
+pub mod crawl_scheduler; // 🗓️ 예약 크롤링 (cron / interval)
+pub mod dashboard_overview; // 🧭 대시보드 개요 (진행 작업 / 최근 세션 / KPI 추세 / 이상 / 예약 / 상태) 집계
+pub mod database_import; // 📥 외부 DB 스냅샷 URL 기준 병합 (충돌 규칙 / dry-run)
+pub mod dedup_conflicts; // 🧬 중복 병합 충돌 큐 (필드별 해소 / 일괄 전략)
+pub mod failed_items; // 🪦 재시도 소진 항목 dead-letter 보관 (단계/에러 분류/시도 이력/페이로드)
+pub mod field_refresh; // 🪄 선택 필드만 상세 페이지 재수집·UPDATE (필드별 갱신 수 보고)
+pub mod identity_dedup; // 🧬 certificate_id / (vid, pid, model) 기준 URL 중복 그룹 분석·선택 병합
+pub mod identity_migration; // 🪪 제품 식별 전략 전환 시 certificate_id 중복 그룹 보고·병합 (dry-run)
+pub mod migration_rehearsal; // 🧪 대기 중인 스키마 마이그레이션을 DB 사본에서 리허설 (무결성 / 행 수 비교)
+pub mod page_revalidation; // 🩹 커밋 실패 페이지 재검증 큐 (다음 싱크 세션에서 재수집·복구)
+pub mod page_url_sets; // 🧾 세션별 목록 페이지 URL 집합 (해시 + gzip 목록, 보존 기간) 사후 분석용
+pub mod product_history; // 🕰️ 제품 필드 변경 이력 (product_details UPDATE 트리거)
+pub mod row_explanation; // 🔍 제품 한 행의 변경 경위 (세션 / 필드 변경 / 좌표 / 경고)
+pub mod search_index; // 🔎 FTS 검색 인덱스 + 제조사 rollup 증분 유지 / 드리프트 복구
+pub mod session_export; // 📤 크롤/싱크 세션 저장 제품 CSV·NDJSON 스트리밍 내보내기
+pub mod session_notes; // 🗒️ 세션/배치 운영자 메모 (세션 리포트에 포함)
+pub mod settings_bundle; // 📦 설정 번들 (config / 프리셋 / 스케줄 / feature flag) 내보내기·가져오기
+pub mod slot_reservation; // 🎯 싱크 세션 (page_id, index_in_page) 슬롯 예약 (동시 워커 좌표 충돌 → SyncWarning)
+pub mod startup_integrity; // 🩺 시작 시 데이터 무결성 자가 점검 (행 수 / 12개 아닌 페이지 / 빈 좌표 / 고아 상세)
+pub mod sync_eta; // ⏱️ 싱크 예상 완료 시각 (과거 페이지 시간 EWMA + 동시성/rate limit, 진행하며 보정)
+pub mod sync_resume; // ⏸️ 일시정지된 싱크 세션의 남은 페이지 집합 (재개용)
+pub mod url_normalization; // 🔗 저장된 제품 URL 정규형 일괄 변환 + 충돌 병합 (일회성 마이그레이션)
+pub mod validation_repair; // 🩺 검증 finding → 물리 페이지/인덱스 집합 (진단 싱크 자동 복구 계획)
+pub mod watchlist; // 👀 관심 제품/제조사 + 필드 diff 알림
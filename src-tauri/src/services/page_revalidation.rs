@@ -0,0 +1,245 @@
+//! 커밋 실패 페이지 재검증 큐 (needs_revalidation)
+//!
+//! 싱크 중 페이지 트랜잭션 커밋이 실패(`tx_commit_failed`)하면 해당 캐논컬 페이지는 일부만
+//! 반영되었는지 알 수 없는 상태로 남는다. 이런 페이지를 `needs_revalidation` 테이블에 기록하고,
+//! 다음 싱크 세션이 시작될 때 범위에 함께 넣어 다시 수집·검증한다. 세션 종료 시(finalizer)
+//! 이번 패스에서 다시 실패하지 않은 페이지는 복구된 것으로 보고 큐에서 지운다. 그 페이지의
+//! dead-letter(`failed_items`) 항목도 함께 resolved 로 바꾼다.
+//!
+//! 페이지는 캐논컬 `page_id`로 저장한다. 사이트에 제품이 추가되면 물리 페이지 번호가 밀리므로
+//! 재검증 시점의 `total_pages`로 물리 페이지를 다시 계산한다.
+
+use crate::services::failed_items;
+use anyhow::Result;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use tracing::{info, warn};
+
+/// Passes after which a page stops riding along automatically (left for manual repair)
+pub const MAX_REVALIDATION_ATTEMPTS: i64 = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PendingRevalidation {
+    pub page_id: i64,
+    /// Physical page at the time of the failure
+    pub physical_page: u32,
+    /// Session whose commit last failed for this page
+    pub session_id: String,
+    pub error: Option<String>,
+    /// Revalidation passes already attempted
+    pub attempts: i64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Outcome of closing a revalidation pass
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RevalidationOutcome {
+    /// Pages that re-synced without another commit failure
+    pub repaired: u32,
+    /// Pages still queued (failed again, attempts left)
+    pub pending: u32,
+    /// Pages that ran out of automatic attempts
+    pub exhausted: u32,
+}
+
+/// Record a page whose transaction failed to commit
+pub async fn mark(
+    pool: &SqlitePool,
+    session_id: &str,
+    physical_page: u32,
+    page_id: i64,
+    error: &str,
+) -> Result<()> {
+    let now = Utc::now().to_rfc3339();
+    // A failure during a pass takes the page out of it, so the finalizer keeps it queued
+    sqlx::query(
+        "INSERT INTO needs_revalidation \
+            (page_id, physical_page, session_id, error, attempts, in_pass, created_at, updated_at) \
+         VALUES (?, ?, ?, ?, 0, 0, ?, ?) \
+         ON CONFLICT(page_id) DO UPDATE SET \
+            physical_page = excluded.physical_page, session_id = excluded.session_id, \
+            error = excluded.error, in_pass = 0, updated_at = excluded.updated_at",
+    )
+    .bind(page_id)
+    .bind(physical_page as i64)
+    .bind(session_id)
+    .bind(error)
+    .bind(&now)
+    .bind(&now)
+    .execute(pool)
+    .await?;
+    warn!(
+        "🩹 Page {} (pid {}) queued for revalidation after commit failure in {}",
+        physical_page, page_id, session_id
+    );
+    Ok(())
+}
+
+/// Every queued page, oldest first
+pub async fn list_pending(pool: &SqlitePool) -> Result<Vec<PendingRevalidation>> {
+    let rows = sqlx::query(
+        "SELECT page_id, physical_page, session_id, error, attempts, created_at, updated_at \
+         FROM needs_revalidation ORDER BY created_at, page_id",
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|r| PendingRevalidation {
+            page_id: r.get("page_id"),
+            physical_page: r.get::<i64, _>("physical_page") as u32,
+            session_id: r.get("session_id"),
+            error: r.get("error"),
+            attempts: r.get("attempts"),
+            created_at: r.get("created_at"),
+            updated_at: r.get("updated_at"),
+        })
+        .collect())
+}
+
+/// Current physical page of a canonical page id (`total_pages - page_id`, within site bounds)
+pub fn current_physical_page(page_id: i64, total_pages: u32) -> Option<u32> {
+    let physical = (total_pages as i64).checked_sub(page_id)?;
+    (physical >= 1 && physical <= total_pages as i64).then_some(physical as u32)
+}
+
+/// Start a pass: flags queued pages with attempts left and returns their current physical pages
+pub async fn begin_pass(pool: &SqlitePool, total_pages: u32) -> Result<Vec<u32>> {
+    // Flags left by a session that aborted before its finalizer must not count as repairs
+    sqlx::query("UPDATE needs_revalidation SET in_pass = 0 WHERE in_pass = 1")
+        .execute(pool)
+        .await?;
+    let mut pages = Vec::new();
+    for pending in list_pending(pool).await? {
+        if pending.attempts >= MAX_REVALIDATION_ATTEMPTS {
+            continue;
+        }
+        let Some(physical) = current_physical_page(pending.page_id, total_pages) else {
+            continue;
+        };
+        sqlx::query(
+            "UPDATE needs_revalidation SET in_pass = 1, attempts = attempts + 1 WHERE page_id = ?",
+        )
+        .bind(pending.page_id)
+        .execute(pool)
+        .await?;
+        pages.push(physical);
+    }
+    if !pages.is_empty() {
+        info!(
+            "🩹 Revalidating {} page(s) left by failed commits: {:?}",
+            pages.len(),
+            pages
+        );
+    }
+    Ok(pages)
+}
+
+/// Close the pass: pages not re-marked since `begin_pass` are repaired and leave the queue
+pub async fn finish_pass(pool: &SqlitePool) -> Result<RevalidationOutcome> {
+    let repaired: Vec<i64> =
+        sqlx::query_scalar("DELETE FROM needs_revalidation WHERE in_pass = 1 RETURNING page_id")
+            .fetch_all(pool)
+            .await?;
+    // Requeued dead letters for these pages are done with
+    if let Err(e) = failed_items::resolve_list_pages(pool, &repaired).await {
+        warn!(
+            "🩹 Failed to resolve dead-lettered pages {:?}: {}",
+            repaired, e
+        );
+    }
+    let (pending, exhausted): (i64, i64) = sqlx::query_as(
+        "SELECT COALESCE(SUM(attempts < ?), 0), COALESCE(SUM(attempts >= ?), 0) \
+         FROM needs_revalidation",
+    )
+    .bind(MAX_REVALIDATION_ATTEMPTS)
+    .bind(MAX_REVALIDATION_ATTEMPTS)
+    .fetch_one(pool)
+    .await?;
+    Ok(RevalidationOutcome {
+        repaired: repaired.len() as u32,
+        pending: pending as u32,
+        exhausted: exhausted as u32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    async fn test_pool() -> SqlitePool {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        db.pool().clone()
+    }
+
+    #[test]
+    fn physical_page_follows_site_growth() {
+        assert_eq!(current_physical_page(480, 500), Some(20));
+        assert_eq!(current_physical_page(480, 505), Some(25));
+        assert_eq!(current_physical_page(500, 500), None);
+        assert_eq!(current_physical_page(-1, 500), None);
+    }
+
+    #[tokio::test]
+    async fn pass_clears_repaired_pages_and_keeps_repeated_failures() {
+        let pool = test_pool().await;
+        mark(&pool, "sync-1", 20, 480, "database is locked")
+            .await
+            .unwrap();
+        mark(&pool, "sync-1", 21, 479, "database is locked")
+            .await
+            .unwrap();
+
+        // Site grew by two pages before the next session
+        let mut pages = begin_pass(&pool, 502).await.unwrap();
+        pages.sort_unstable();
+        assert_eq!(pages, vec![22, 23]);
+
+        // pid 480 was also dead-lettered by the failing session
+        let letter = failed_items::DeadLetter {
+            stage: failed_items::FailedItemStage::ListPage,
+            item_key: "480".into(),
+            session_id: "sync-1".into(),
+            error_class: crate::domain::error_catalog::ErrorCode::EmptyListPage,
+            attempts: Vec::new(),
+            payload: serde_json::json!({ "page_id": 480, "physical_page": 20 }),
+        };
+        failed_items::record(&pool, &letter).await.unwrap();
+
+        // Page 23 (pid 479) fails again during the pass
+        mark(&pool, "sync-2", 23, 479, "disk I/O error")
+            .await
+            .unwrap();
+        let outcome = finish_pass(&pool).await.unwrap();
+        assert_eq!(
+            outcome,
+            RevalidationOutcome {
+                repaired: 1,
+                pending: 1,
+                exhausted: 0
+            }
+        );
+        let dead = failed_items::list(&pool, &failed_items::FailedItemFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(dead[0].status, failed_items::FailedItemStatus::Resolved);
+        let left = list_pending(&pool).await.unwrap();
+        assert_eq!(left.len(), 1);
+        assert_eq!((left[0].page_id, left[0].attempts), (479, 1));
+        assert_eq!(left[0].session_id, "sync-2");
+
+        // Out of attempts: no longer picked up automatically
+        for _ in 1..MAX_REVALIDATION_ATTEMPTS {
+            assert_eq!(begin_pass(&pool, 502).await.unwrap(), vec![23]);
+            mark(&pool, "sync-n", 23, 479, "disk I/O error")
+                .await
+                .unwrap();
+        }
+        assert!(begin_pass(&pool, 502).await.unwrap().is_empty());
+        assert_eq!(finish_pass(&pool).await.unwrap().exhausted, 1);
+    }
+}
@@ -0,0 +1,273 @@
+//! 세션별 목록 페이지 URL 집합 보관 (`advanced.page_url_sets`)
+//!
+//! 싱크는 물리 페이지에서 추출한 URL 목록으로 좌표와 sweep 을 결정하지만 그 목록 자체는 남지
+//! 않아, "그 세션에서 7번 슬롯이 정말 비어 있었나?" 같은 질문에 사이트를 다시 받아야 했다.
+//! (session, physical_page) 마다 정렬 집합 해시 + 개수(1단계)와 추출 순서 그대로의 gzip 목록
+//! (2단계)을 남긴다. 목록은 최근 `full_list_sessions` 세션만 유지하고, 행 자체는
+//! `retention_days` 가 지나면 삭제한다.
+
+use anyhow::{Context, Result};
+use chrono::{Duration, Utc};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::io::{Read, Write};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PageUrlSetMode {
+    /// Nothing is recorded
+    Off,
+    /// Set hash and count only
+    HashOnly,
+    /// Hash, count and the gzip-compressed URL list
+    #[default]
+    Full,
+}
+
+/// `advanced.page_url_sets`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PageUrlSetConfig {
+    #[serde(default)]
+    pub mode: PageUrlSetMode,
+    /// Most recent sync sessions whose full URL lists are kept; older ones keep the hash only
+    #[serde(default = "PageUrlSetConfig::default_full_list_sessions")]
+    pub full_list_sessions: u32,
+    /// Rows older than this are deleted (0 = keep forever)
+    #[serde(default = "PageUrlSetConfig::default_retention_days")]
+    pub retention_days: u32,
+}
+
+impl PageUrlSetConfig {
+    fn default_full_list_sessions() -> u32 {
+        20
+    }
+
+    fn default_retention_days() -> u32 {
+        90
+    }
+}
+
+impl Default for PageUrlSetConfig {
+    fn default() -> Self {
+        Self {
+            mode: PageUrlSetMode::default(),
+            full_list_sessions: Self::default_full_list_sessions(),
+            retention_days: Self::default_retention_days(),
+        }
+    }
+}
+
+/// What one session saw on one physical page
+#[derive(Debug, Clone, Serialize)]
+pub struct PageUrlSet {
+    pub session_id: String,
+    pub physical_page: u32,
+    pub expected_count: u32,
+    pub url_count: u32,
+    /// blake3 of the sorted, de-duplicated URLs; equal hashes = same set
+    pub set_hash: String,
+    /// URLs in extraction order; None once pruned to the hash tier (or recorded hash-only)
+    pub urls: Option<Vec<String>>,
+    pub recorded_at: String,
+}
+
+impl PageUrlSet {
+    /// Extraction position of `url` on the page; None = absent (or list no longer kept)
+    pub fn position_of(&self, url: &str) -> Option<usize> {
+        self.urls.as_ref()?.iter().position(|u| u == url)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PruneReport {
+    pub lists_dropped: u64,
+    pub rows_deleted: u64,
+}
+
+pub fn set_hash(urls: &[String]) -> String {
+    let mut sorted: Vec<&str> = urls.iter().map(String::as_str).collect();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let mut hasher = blake3::Hasher::new();
+    for url in sorted {
+        hasher.update(url.as_bytes());
+        hasher.update(b"\n");
+    }
+    hasher.finalize().to_hex().to_string()
+}
+
+fn encode_urls(urls: &[String]) -> Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(urls.join("\n").as_bytes())?;
+    Ok(encoder.finish()?)
+}
+
+fn decode_urls(bytes: &[u8]) -> Result<Vec<String>> {
+    let mut text = String::new();
+    GzDecoder::new(bytes)
+        .read_to_string(&mut text)
+        .context("corrupt URL list")?;
+    Ok(text
+        .split('\n')
+        .filter(|u| !u.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Store the page's final URL list; a later pass over the same page replaces it
+pub async fn record_page(
+    pool: &SqlitePool,
+    config: &PageUrlSetConfig,
+    session_id: &str,
+    physical_page: u32,
+    expected_count: u32,
+    urls: &[String],
+) -> Result<()> {
+    if config.mode == PageUrlSetMode::Off {
+        return Ok(());
+    }
+    let urls_gz = match config.mode {
+        PageUrlSetMode::Full => Some(encode_urls(urls)?),
+        _ => None,
+    };
+    sqlx::query(
+        "INSERT OR REPLACE INTO page_url_sets (session_id, physical_page, expected_count, \
+         url_count, set_hash, urls_gz, recorded_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(session_id)
+    .bind(physical_page as i64)
+    .bind(expected_count as i64)
+    .bind(urls.len() as i64)
+    .bind(set_hash(urls))
+    .bind(urls_gz)
+    .bind(Utc::now().to_rfc3339())
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+fn row_to_set(row: &sqlx::sqlite::SqliteRow) -> Result<PageUrlSet> {
+    let urls = match row.get::<Option<Vec<u8>>, _>("urls_gz") {
+        Some(bytes) => Some(decode_urls(&bytes)?),
+        None => None,
+    };
+    Ok(PageUrlSet {
+        session_id: row.get("session_id"),
+        physical_page: row.get::<i64, _>("physical_page") as u32,
+        expected_count: row.get::<i64, _>("expected_count") as u32,
+        url_count: row.get::<i64, _>("url_count") as u32,
+        set_hash: row.get("set_hash"),
+        urls,
+        recorded_at: row.get("recorded_at"),
+    })
+}
+
+pub async fn load_page(
+    pool: &SqlitePool,
+    session_id: &str,
+    physical_page: u32,
+) -> Result<Option<PageUrlSet>> {
+    let row = sqlx::query("SELECT * FROM page_url_sets WHERE session_id = ? AND physical_page = ?")
+        .bind(session_id)
+        .bind(physical_page as i64)
+        .fetch_optional(pool)
+        .await?;
+    row.as_ref().map(row_to_set).transpose()
+}
+
+/// One physical page across sessions, newest first
+pub async fn page_history(
+    pool: &SqlitePool,
+    physical_page: u32,
+    limit: u32,
+) -> Result<Vec<PageUrlSet>> {
+    let rows = sqlx::query(
+        "SELECT * FROM page_url_sets WHERE physical_page = ? ORDER BY recorded_at DESC LIMIT ?",
+    )
+    .bind(physical_page as i64)
+    .bind(limit.max(1) as i64)
+    .fetch_all(pool)
+    .await?;
+    rows.iter().map(row_to_set).collect()
+}
+
+/// Drop URL lists beyond the newest `full_list_sessions` sessions, then rows past retention
+pub async fn prune(pool: &SqlitePool, config: &PageUrlSetConfig) -> Result<PruneReport> {
+    let lists_dropped = sqlx::query(
+        "UPDATE page_url_sets SET urls_gz = NULL WHERE urls_gz IS NOT NULL AND session_id NOT IN (\
+            SELECT session_id FROM page_url_sets GROUP BY session_id \
+            ORDER BY MAX(recorded_at) DESC LIMIT ?)",
+    )
+    .bind(config.full_list_sessions as i64)
+    .execute(pool)
+    .await?
+    .rows_affected();
+    let rows_deleted = if config.retention_days == 0 {
+        0
+    } else {
+        let cutoff = Utc::now() - Duration::days(i64::from(config.retention_days));
+        sqlx::query("DELETE FROM page_url_sets WHERE recorded_at < ?")
+            .bind(cutoff.to_rfc3339())
+            .execute(pool)
+            .await?
+            .rows_affected()
+    };
+    Ok(PruneReport {
+        lists_dropped,
+        rows_deleted,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    fn urls(ids: &[u32]) -> Vec<String> {
+        ids.iter()
+            .map(|i| format!("https://x.test/p/{i}/"))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn records_tiers_and_prunes_lists_to_hashes() {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        let pool = db.pool().clone();
+        let config = PageUrlSetConfig {
+            full_list_sessions: 1,
+            ..PageUrlSetConfig::default()
+        };
+
+        record_page(&pool, &config, "s1", 7, 12, &urls(&[3, 1, 2]))
+            .await
+            .unwrap();
+        let s1 = load_page(&pool, "s1", 7).await.unwrap().unwrap();
+        assert_eq!(s1.urls, Some(urls(&[3, 1, 2])));
+        assert_eq!(s1.position_of("https://x.test/p/1/"), Some(1));
+        assert_eq!(s1.position_of("https://x.test/p/9/"), None);
+        // same set in another order hashes equal
+        assert_eq!(s1.set_hash, set_hash(&urls(&[1, 2, 3])));
+
+        let hash_only = PageUrlSetConfig {
+            mode: PageUrlSetMode::HashOnly,
+            ..config.clone()
+        };
+        record_page(&pool, &hash_only, "s2", 7, 12, &urls(&[1, 2]))
+            .await
+            .unwrap();
+        let s2 = load_page(&pool, "s2", 7).await.unwrap().unwrap();
+        assert_eq!((s2.url_count, s2.urls.is_none()), (2, true));
+        assert_ne!(s2.set_hash, s1.set_hash);
+
+        // s2 is newest -> s1's list is dropped, its hash stays
+        let report = prune(&pool, &config).await.unwrap();
+        assert_eq!((report.lists_dropped, report.rows_deleted), (1, 0));
+        let history = page_history(&pool, 7, 10).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert!(history.iter().all(|h| h.urls.is_none()));
+    }
+}
@@ -0,0 +1,328 @@
+//! 제품 변경 이력 (audit trail)
+//!
+//! `product_details` UPDATE 마다 트리거(`migrations/007_product_history.sql`)가 추적 필드의
+//! 이전 값 → 새 값을 `product_history` 에 남긴다. 트리거 방식이라 repository upsert,
+//! write-behind 큐, sync SQL, 중복 병합 등 어떤 쓰기 경로든 같은 규칙으로 기록된다.
+//! 비어 있던 필드가 채워지는 경우는 잃은 값이 없으므로 기록하지 않는다.
+
+use crate::crawl_engine::actors::types::{SyncChangeset, SyncFieldChangeCount};
+use crate::infrastructure::database_connection::PRODUCT_HISTORY_MIGRATION;
+use crate::infrastructure::integrated_product_repository::IntegratedProductRepository;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+pub const DEFAULT_HISTORY_LIMIT: u32 = 100;
+pub const MAX_HISTORY_LIMIT: u32 = 1000;
+/// Sample URLs kept per field in a sync changeset
+pub const CHANGESET_SAMPLE_URLS: i64 = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductChange {
+    pub id: i64,
+    pub url: String,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    /// UTC, `YYYY-MM-DD HH:MM:SS`
+    pub changed_at: String,
+    /// Current values, for labelling rows in change feeds
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+}
+
+/// Create the history table and trigger when the startup migration has not run (tests, fresh pools)
+pub async fn ensure_history_tables(pool: &SqlitePool) -> Result<()> {
+    sqlx::query(PRODUCT_HISTORY_MIGRATION).execute(pool).await?;
+    Ok(())
+}
+
+const CHANGE_SELECT: &str = "SELECT h.id, h.url, h.field, h.old_value, h.new_value, \
+        CAST(h.changed_at AS TEXT) AS changed_at, pd.manufacturer, pd.model \
+     FROM product_history h LEFT JOIN product_details pd ON pd.url = h.url";
+
+fn clamp_limit(limit: Option<u32>) -> i64 {
+    limit
+        .unwrap_or(DEFAULT_HISTORY_LIMIT)
+        .clamp(1, MAX_HISTORY_LIMIT) as i64
+}
+
+/// Changes recorded for one product, newest first
+pub async fn history_for_url(
+    pool: &SqlitePool,
+    url: &str,
+    limit: Option<u32>,
+) -> Result<Vec<ProductChange>> {
+    ensure_history_tables(pool).await?;
+    let url = IntegratedProductRepository::normalize_url(url);
+    let rows = sqlx::query(&format!(
+        "{CHANGE_SELECT} WHERE h.url = ? ORDER BY h.changed_at DESC, h.id DESC LIMIT ?"
+    ))
+    .bind(&url)
+    .bind(clamp_limit(limit))
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.iter().map(change_from_row).collect())
+}
+
+/// Latest changes across all products, newest first
+pub async fn recent_changes(pool: &SqlitePool, limit: Option<u32>) -> Result<Vec<ProductChange>> {
+    ensure_history_tables(pool).await?;
+    let rows = sqlx::query(&format!(
+        "{CHANGE_SELECT} ORDER BY h.changed_at DESC, h.id DESC LIMIT ?"
+    ))
+    .bind(clamp_limit(limit))
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.iter().map(change_from_row).collect())
+}
+
+/// Highest history id so far; a session's changes are the rows recorded after it
+pub async fn history_watermark(pool: &SqlitePool) -> Result<i64> {
+    ensure_history_tables(pool).await?;
+    let id: Option<i64> = sqlx::query_scalar("SELECT MAX(id) FROM product_history")
+        .fetch_one(pool)
+        .await?;
+    Ok(id.unwrap_or(0))
+}
+
+/// Summarize history rows recorded after `since_id`. With `session_id`, only URLs the sync
+/// observed (`sync_observed`) count, so concurrent writers do not leak into the summary.
+pub async fn sync_changeset(
+    pool: &SqlitePool,
+    since_id: i64,
+    session_id: Option<&str>,
+) -> Result<SyncChangeset> {
+    ensure_history_tables(pool).await?;
+    let scope = if session_id.is_some() {
+        "h.id > ? AND h.url IN (SELECT url FROM sync_observed WHERE session_id = ?)"
+    } else {
+        "h.id > ? AND ? IS NULL"
+    };
+    let counts = sqlx::query(&format!(
+        "SELECT h.field AS field, COUNT(*) AS cnt FROM product_history h WHERE {scope} \
+         GROUP BY h.field ORDER BY cnt DESC, h.field"
+    ))
+    .bind(since_id)
+    .bind(session_id)
+    .fetch_all(pool)
+    .await?;
+    if counts.is_empty() {
+        return Ok(SyncChangeset::default());
+    }
+    let products_changed: i64 = sqlx::query_scalar(&format!(
+        "SELECT COUNT(DISTINCT h.url) FROM product_history h WHERE {scope}"
+    ))
+    .bind(since_id)
+    .bind(session_id)
+    .fetch_one(pool)
+    .await?;
+    let samples = sqlx::query(&format!(
+        "SELECT field, url FROM (\
+            SELECT h.field AS field, h.url AS url, \
+                ROW_NUMBER() OVER (PARTITION BY h.field ORDER BY MIN(h.id)) AS rn \
+            FROM product_history h WHERE {scope} GROUP BY h.field, h.url) \
+         WHERE rn <= ? ORDER BY field, rn"
+    ))
+    .bind(since_id)
+    .bind(session_id)
+    .bind(CHANGESET_SAMPLE_URLS)
+    .fetch_all(pool)
+    .await?;
+
+    let mut changeset = SyncChangeset {
+        total_changes: 0,
+        products_changed: products_changed as u32,
+        fields: Vec::with_capacity(counts.len()),
+    };
+    for row in &counts {
+        let field: String = row.get("field");
+        let count = row.get::<i64, _>("cnt") as u32;
+        changeset.total_changes += count;
+        let sample_urls = samples
+            .iter()
+            .filter(|s| s.get::<String, _>("field") == field)
+            .map(|s| s.get::<String, _>("url"))
+            .collect();
+        changeset.fields.push(SyncFieldChangeCount {
+            field,
+            count,
+            sample_urls,
+        });
+    }
+    Ok(changeset)
+}
+
+fn change_from_row(row: &sqlx::sqlite::SqliteRow) -> ProductChange {
+    ProductChange {
+        id: row.get("id"),
+        url: row.get("url"),
+        field: row.get("field"),
+        old_value: row.get("old_value"),
+        new_value: row.get("new_value"),
+        changed_at: row.get("changed_at"),
+        manufacturer: row.get("manufacturer"),
+        model: row.get("model"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn pool_with_details() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query(
+            "CREATE TABLE product_details (url TEXT PRIMARY KEY, manufacturer TEXT, model TEXT, \
+             device_type TEXT, certificate_id TEXT, certification_date TEXT, software_version TEXT, \
+             hardware_version TEXT, firmware_version TEXT, specification_version TEXT, vid INTEGER, \
+             pid INTEGER, family_sku TEXT, family_variant_sku TEXT, family_id TEXT, \
+             tis_trp_tested TEXT, transport_interface TEXT, primary_device_type_id TEXT, \
+             application_categories TEXT, description TEXT, compliance_document_url TEXT, \
+             program_type TEXT)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        ensure_history_tables(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn changeset_counts_fields_after_watermark_within_session() {
+        let pool = pool_with_details().await;
+        sqlx::query("CREATE TABLE sync_observed (session_id TEXT, url TEXT)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        for url in ["https://x/p/1", "https://x/p/2", "https://x/p/3"] {
+            sqlx::query(
+                "INSERT INTO product_details (url, firmware_version, model) VALUES (?, '1.0', 'M')",
+            )
+            .bind(url)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        // Before the watermark: not part of the session
+        sqlx::query("UPDATE product_details SET model = 'M0' WHERE url = 'https://x/p/1'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let watermark = history_watermark(&pool).await.unwrap();
+        assert_eq!(watermark, 1);
+
+        for url in ["https://x/p/1", "https://x/p/2"] {
+            sqlx::query("INSERT INTO sync_observed (session_id, url) VALUES ('s1', ?)")
+                .bind(url)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        sqlx::query("UPDATE product_details SET firmware_version = '2.0'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE product_details SET model = 'M2' WHERE url = 'https://x/p/2'")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let scoped = sync_changeset(&pool, watermark, Some("s1")).await.unwrap();
+        assert_eq!(scoped.total_changes, 3);
+        assert_eq!(scoped.products_changed, 2);
+        assert_eq!(
+            scoped.fields,
+            vec![
+                SyncFieldChangeCount {
+                    field: "firmware_version".into(),
+                    count: 2,
+                    sample_urls: vec!["https://x/p/1".into(), "https://x/p/2".into()],
+                },
+                SyncFieldChangeCount {
+                    field: "model".into(),
+                    count: 1,
+                    sample_urls: vec!["https://x/p/2".into()],
+                },
+            ]
+        );
+
+        let unscoped = sync_changeset(&pool, watermark, None).await.unwrap();
+        assert_eq!((unscoped.total_changes, unscoped.products_changed), (4, 3));
+        assert_eq!(
+            sync_changeset(&pool, history_watermark(&pool).await.unwrap(), None)
+                .await
+                .unwrap(),
+            SyncChangeset::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn upserts_record_changed_tracked_fields_only() {
+        let pool = pool_with_details().await;
+        let url = "https://csa-iot.org/csa_product/a/";
+        sqlx::query(
+            "INSERT INTO product_details (url, manufacturer, firmware_version, certification_date, vid) \
+             VALUES (?, 'Acme', '1.0', '2024-01-01', 4660)",
+        )
+        .bind(url)
+        .execute(&pool)
+        .await
+        .unwrap();
+        // Same values (modulo whitespace) and blank -> value fills are not history
+        sqlx::query(
+            "INSERT INTO product_details (url, firmware_version, model) VALUES (?, ' 1.0 ', 'M1') \
+             ON CONFLICT(url) DO UPDATE SET firmware_version = excluded.firmware_version, \
+             model = excluded.model",
+        )
+        .bind(url)
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert!(recent_changes(&pool, None).await.unwrap().is_empty());
+
+        sqlx::query(
+            "UPDATE product_details SET firmware_version = '1.1', vid = 4661, \
+             certification_date = '2024-02-01' WHERE url = ?",
+        )
+        .bind(url)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let history = history_for_url(&pool, url, None).await.unwrap();
+        let mut fields: Vec<(&str, Option<&str>, Option<&str>)> = history
+            .iter()
+            .map(|c| {
+                (
+                    c.field.as_str(),
+                    c.old_value.as_deref(),
+                    c.new_value.as_deref(),
+                )
+            })
+            .collect();
+        fields.sort();
+        assert_eq!(
+            fields,
+            vec![
+                ("certification_date", Some("2024-01-01"), Some("2024-02-01")),
+                ("firmware_version", Some("1.0"), Some("1.1")),
+                ("vid", Some("4660"), Some("4661")),
+            ]
+        );
+        assert!(
+            history
+                .iter()
+                .all(|c| c.manufacturer.as_deref() == Some("Acme"))
+        );
+
+        let recent = recent_changes(&pool, Some(2)).await.unwrap();
+        assert_eq!(recent.len(), 2);
+        assert!(recent[0].id > recent[1].id);
+    }
+}
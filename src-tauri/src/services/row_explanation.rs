@@ -0,0 +1,506 @@
+//! 제품 한 행의 "왜 바뀌었나" 설명
+//!
+//! 변경 이력(`product_history`), sync 관측 기록(`sync_observed` / `sync_sessions`), 현재 좌표,
+//! 중복 병합 충돌 큐, strict 좌표 위반 로그를 한 URL 기준으로 모은다. 필드 변경은 그 URL을
+//! 관측한 sync 세션 중 변경 시각을 실행 구간에 포함하는 세션(겹치면 가장 늦게 시작한 세션)에
+//! 귀속되고, 어느 세션에도 속하지 않는 변경(액터 크롤, 병합, 수동 복구 등)은 따로 남긴다.
+
+use crate::infrastructure::coordinate_guard::{self, CoordinateViolationKind};
+use crate::infrastructure::integrated_product_repository::IntegratedProductRepository;
+use crate::services::dedup_conflicts::{self, ConflictStatus};
+use crate::services::product_history::{self, MAX_HISTORY_LIMIT, ProductChange};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+/// Pending dedup conflicts listed per row
+const MAX_DEDUP_WARNINGS: u32 = 20;
+/// Other URLs reported for a shared slot
+const MAX_SLOT_COLLISIONS: i64 = 5;
+
+/// Current stored state of the row
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RowCurrentState {
+    pub in_products: bool,
+    pub in_product_details: bool,
+    pub page_id: Option<i64>,
+    pub index_in_page: Option<i64>,
+    /// Coordinates stored on the product_details row
+    pub detail_page_id: Option<i64>,
+    pub detail_index_in_page: Option<i64>,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+/// A sync session that observed the URL and what it changed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionTouch {
+    pub session_id: String,
+    pub status: Option<String>,
+    pub coverage: Option<String>,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+    /// Coordinates the session observed the URL at
+    pub page_id: Option<i64>,
+    pub index_in_page: Option<i64>,
+    /// Field changes recorded while the session ran, oldest first
+    pub changes: Vec<ProductChange>,
+}
+
+/// Observed coordinates, oldest session first
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoordinateStep {
+    pub session_id: String,
+    pub started_at: Option<String>,
+    pub page_id: Option<i64>,
+    pub index_in_page: Option<i64>,
+    /// Differs from the previous observation
+    pub moved: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RowWarningKind {
+    MissingProductRow,
+    MissingDetailRow,
+    /// products and product_details disagree on coordinates
+    CoordinateMismatch,
+    /// Another URL holds the same (page_id, index_in_page)
+    SlotCollision,
+    /// Stored coordinates differ from the latest sync observation
+    CoordinateDrift,
+    /// Strict coordinate mode rejected a write for this URL (this run only)
+    StrictCoordinateViolation,
+    PendingDedupConflict,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RowWarning {
+    pub kind: RowWarningKind,
+    pub message: String,
+}
+
+/// Complete change story for one product URL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RowExplanation {
+    /// Normalized URL the lookup used
+    pub url: String,
+    pub current: RowCurrentState,
+    /// Oldest first
+    pub sessions: Vec<SessionTouch>,
+    /// Changes outside every observing sync session, oldest first
+    pub other_changes: Vec<ProductChange>,
+    pub coordinate_history: Vec<CoordinateStep>,
+    pub warnings: Vec<RowWarning>,
+}
+
+pub async fn explain_row(pool: &SqlitePool, url: &str) -> Result<RowExplanation> {
+    let url = IntegratedProductRepository::normalize_url(url);
+    let current = load_current(pool, &url).await?;
+    let mut sessions = load_sessions(pool, &url).await?;
+
+    let mut history = product_history::history_for_url(pool, &url, Some(MAX_HISTORY_LIMIT)).await?;
+    history.reverse();
+    let other_changes = attribute_changes(&mut sessions, history);
+    let coordinate_history = coordinate_steps(&sessions);
+
+    let mut warnings = coordinate_warnings(&current, sessions.last());
+    if let (Some(page_id), Some(index)) = (current.page_id, current.index_in_page) {
+        let others: Vec<String> = sqlx::query_scalar(
+            "SELECT url FROM products WHERE page_id = ? AND index_in_page = ? AND url <> ? \
+             ORDER BY url LIMIT ?",
+        )
+        .bind(page_id)
+        .bind(index)
+        .bind(&url)
+        .bind(MAX_SLOT_COLLISIONS)
+        .fetch_all(pool)
+        .await?;
+        if !others.is_empty() {
+            warnings.push(RowWarning {
+                kind: RowWarningKind::SlotCollision,
+                message: format!(
+                    "slot ({page_id}, {index}) is also held by {}",
+                    others.join(", ")
+                ),
+            });
+        }
+    }
+    for violation in coordinate_guard::recent_violations()
+        .into_iter()
+        .filter(|v| v.url == url || v.conflicting_url.as_deref() == Some(url.as_str()))
+    {
+        let detail = match violation.kind {
+            CoordinateViolationKind::DuplicateSlot => format!(
+                "slot held by {}",
+                violation
+                    .conflicting_url
+                    .as_deref()
+                    .unwrap_or("another URL")
+            ),
+            kind => format!("{kind:?}"),
+        };
+        warnings.push(RowWarning {
+            kind: RowWarningKind::StrictCoordinateViolation,
+            message: format!(
+                "{} rejected at ({:?}, {:?}) on {}: {}",
+                violation.operation,
+                violation.page_id,
+                violation.index_in_page,
+                violation.detected_at.format("%Y-%m-%d %H:%M:%S"),
+                detail
+            ),
+        });
+    }
+    let key = dedup_conflicts::dedup_key(&url);
+    for conflict in dedup_conflicts::list_conflicts(
+        pool,
+        Some(ConflictStatus::Pending),
+        Some(&key),
+        MAX_DEDUP_WARNINGS,
+    )
+    .await?
+    {
+        let values: Vec<&str> = conflict
+            .candidates
+            .iter()
+            .map(|c| c.value.as_str())
+            .collect();
+        warnings.push(RowWarning {
+            kind: RowWarningKind::PendingDedupConflict,
+            message: format!(
+                "{}.{} has conflicting duplicate values [{}] (conflict {})",
+                conflict.table.name(),
+                conflict.field,
+                values.join(" | "),
+                conflict.id
+            ),
+        });
+    }
+
+    Ok(RowExplanation {
+        url,
+        current,
+        sessions,
+        other_changes,
+        coordinate_history,
+        warnings,
+    })
+}
+
+async fn load_current(pool: &SqlitePool, url: &str) -> Result<RowCurrentState> {
+    let mut current = RowCurrentState::default();
+    if let Some(row) = sqlx::query(
+        "SELECT page_id, index_in_page, manufacturer, model, \
+            CAST(created_at AS TEXT) AS created_at, CAST(updated_at AS TEXT) AS updated_at \
+         FROM products WHERE url = ?",
+    )
+    .bind(url)
+    .fetch_optional(pool)
+    .await?
+    {
+        current.in_products = true;
+        current.page_id = row.get("page_id");
+        current.index_in_page = row.get("index_in_page");
+        current.manufacturer = row.get("manufacturer");
+        current.model = row.get("model");
+        current.created_at = row.get("created_at");
+        current.updated_at = row.get("updated_at");
+    }
+    if let Some(row) = sqlx::query(
+        "SELECT page_id, index_in_page, manufacturer, model, \
+            CAST(updated_at AS TEXT) AS updated_at \
+         FROM product_details WHERE url = ?",
+    )
+    .bind(url)
+    .fetch_optional(pool)
+    .await?
+    {
+        current.in_product_details = true;
+        current.detail_page_id = row.get("page_id");
+        current.detail_index_in_page = row.get("index_in_page");
+        // Detail values are the richer source for labels
+        let manufacturer: Option<String> = row.get("manufacturer");
+        let model: Option<String> = row.get("model");
+        current.manufacturer = manufacturer.or(current.manufacturer);
+        current.model = model.or(current.model);
+        let updated_at: Option<String> = row.get("updated_at");
+        current.updated_at = current.updated_at.max(updated_at);
+    }
+    Ok(current)
+}
+
+async fn load_sessions(pool: &SqlitePool, url: &str) -> Result<Vec<SessionTouch>> {
+    let rows = sqlx::query(
+        "SELECT o.session_id, o.page_id, o.index_in_page, s.status, s.coverage_text, \
+            CAST(s.started_at AS TEXT) AS started_at, CAST(s.finished_at AS TEXT) AS finished_at \
+         FROM sync_observed o LEFT JOIN sync_sessions s ON s.session_id = o.session_id \
+         WHERE o.url = ? ORDER BY s.started_at, o.session_id",
+    )
+    .bind(url)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .iter()
+        .map(|row| SessionTouch {
+            session_id: row.get("session_id"),
+            status: row.get("status"),
+            coverage: row.get("coverage_text"),
+            started_at: row.get("started_at"),
+            finished_at: row.get("finished_at"),
+            page_id: row.get("page_id"),
+            index_in_page: row.get("index_in_page"),
+            changes: Vec::new(),
+        })
+        .collect())
+}
+
+/// Move each change (oldest first) into the latest-started session whose run contains it;
+/// returns the rest. Timestamps share SQLite's `YYYY-MM-DD HH:MM:SS` text form.
+fn attribute_changes(
+    sessions: &mut [SessionTouch],
+    history: Vec<ProductChange>,
+) -> Vec<ProductChange> {
+    let mut other = Vec::new();
+    for change in history {
+        let owner = sessions.iter_mut().rev().find(|s| {
+            s.started_at
+                .as_deref()
+                .is_some_and(|start| start <= change.changed_at.as_str())
+                && s.finished_at
+                    .as_deref()
+                    .is_none_or(|end| change.changed_at.as_str() <= end)
+        });
+        match owner {
+            Some(session) => session.changes.push(change),
+            None => other.push(change),
+        }
+    }
+    other
+}
+
+fn coordinate_steps(sessions: &[SessionTouch]) -> Vec<CoordinateStep> {
+    let mut previous: Option<(Option<i64>, Option<i64>)> = None;
+    sessions
+        .iter()
+        .map(|s| {
+            let coords = (s.page_id, s.index_in_page);
+            let moved = previous.is_some_and(|p| p != coords);
+            previous = Some(coords);
+            CoordinateStep {
+                session_id: s.session_id.clone(),
+                started_at: s.started_at.clone(),
+                page_id: s.page_id,
+                index_in_page: s.index_in_page,
+                moved,
+            }
+        })
+        .collect()
+}
+
+fn coordinate_warnings(
+    current: &RowCurrentState,
+    last_observed: Option<&SessionTouch>,
+) -> Vec<RowWarning> {
+    let mut warnings = Vec::new();
+    if !current.in_products {
+        warnings.push(RowWarning {
+            kind: RowWarningKind::MissingProductRow,
+            message: "no products row for this URL".into(),
+        });
+    }
+    if !current.in_product_details {
+        warnings.push(RowWarning {
+            kind: RowWarningKind::MissingDetailRow,
+            message: "no product_details row for this URL".into(),
+        });
+    }
+    let stored = (current.page_id, current.index_in_page);
+    if current.in_products
+        && current.in_product_details
+        && stored != (current.detail_page_id, current.detail_index_in_page)
+    {
+        warnings.push(RowWarning {
+            kind: RowWarningKind::CoordinateMismatch,
+            message: format!(
+                "products at {:?} but product_details at {:?}",
+                stored,
+                (current.detail_page_id, current.detail_index_in_page)
+            ),
+        });
+    }
+    if let Some(last) = last_observed {
+        let observed = (last.page_id, last.index_in_page);
+        if current.in_products && observed.0.is_some() && observed != stored {
+            warnings.push(RowWarning {
+                kind: RowWarningKind::CoordinateDrift,
+                message: format!(
+                    "stored at {:?} but last observed at {:?} by session {}",
+                    stored, observed, last.session_id
+                ),
+            });
+        }
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    const URL: &str = "https://csa-iot.org/csa_product/lamp/";
+
+    async fn pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        for sql in [
+            "CREATE TABLE products (url TEXT PRIMARY KEY, manufacturer TEXT, model TEXT, \
+             certificate_id TEXT, page_id INTEGER, index_in_page INTEGER, \
+             created_at DATETIME DEFAULT CURRENT_TIMESTAMP, updated_at DATETIME DEFAULT CURRENT_TIMESTAMP)",
+            "CREATE TABLE product_details (url TEXT PRIMARY KEY, page_id INTEGER, \
+             index_in_page INTEGER, manufacturer TEXT, model TEXT, device_type TEXT, \
+             certificate_id TEXT, certification_date TEXT, software_version TEXT, \
+             hardware_version TEXT, firmware_version TEXT, specification_version TEXT, \
+             vid INTEGER, pid INTEGER, family_sku TEXT, family_variant_sku TEXT, family_id TEXT, \
+             tis_trp_tested TEXT, transport_interface TEXT, primary_device_type_id TEXT, \
+             application_categories TEXT, description TEXT, compliance_document_url TEXT, \
+             program_type TEXT, updated_at DATETIME DEFAULT CURRENT_TIMESTAMP)",
+            "CREATE TABLE sync_sessions (session_id TEXT PRIMARY KEY, status TEXT NOT NULL, \
+             coverage_text TEXT, started_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP, \
+             finished_at DATETIME)",
+            "CREATE TABLE sync_observed (session_id TEXT NOT NULL, url TEXT NOT NULL, \
+             page_id INTEGER, index_in_page INTEGER, PRIMARY KEY (session_id, url))",
+        ] {
+            sqlx::query(sql).execute(&pool).await.unwrap();
+        }
+        product_history::ensure_history_tables(&pool).await.unwrap();
+        sqlx::query(include_str!("../../migrations/013_dedup_conflicts.sql"))
+            .execute(&pool)
+            .await
+            .unwrap();
+        pool
+    }
+
+    fn change(id: i64, field: &str, at: &str) -> ProductChange {
+        ProductChange {
+            id,
+            url: URL.into(),
+            field: field.into(),
+            old_value: Some("a".into()),
+            new_value: Some("b".into()),
+            changed_at: at.into(),
+            manufacturer: None,
+            model: None,
+        }
+    }
+
+    fn touch(id: &str, start: &str, end: Option<&str>, page: i64) -> SessionTouch {
+        SessionTouch {
+            session_id: id.into(),
+            status: Some("completed".into()),
+            coverage: None,
+            started_at: Some(start.into()),
+            finished_at: end.map(Into::into),
+            page_id: Some(page),
+            index_in_page: Some(3),
+            changes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn changes_go_to_the_session_running_at_the_time() {
+        let mut sessions = vec![
+            touch("s1", "2025-01-01 10:00:00", Some("2025-01-01 10:05:00"), 7),
+            touch("s2", "2025-01-02 09:00:00", None, 8),
+        ];
+        let other = attribute_changes(
+            &mut sessions,
+            vec![
+                change(1, "model", "2025-01-01 10:01:00"),
+                change(2, "vid", "2025-01-01 12:00:00"),
+                change(3, "pid", "2025-01-02 09:30:00"),
+            ],
+        );
+        assert_eq!(sessions[0].changes[0].id, 1);
+        assert_eq!(sessions[1].changes[0].id, 3);
+        assert_eq!(other.iter().map(|c| c.id).collect::<Vec<_>>(), vec![2]);
+
+        let steps = coordinate_steps(&sessions);
+        assert_eq!(
+            steps.iter().map(|s| s.moved).collect::<Vec<_>>(),
+            vec![false, true]
+        );
+    }
+
+    #[tokio::test]
+    async fn explains_sessions_changes_and_warnings() {
+        let pool = pool().await;
+        sqlx::query(
+            "INSERT INTO products (url, page_id, index_in_page) VALUES (?, 8, 3), \
+             ('https://csa-iot.org/csa_product/other/', 8, 3)",
+        )
+        .bind(URL)
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO product_details (url, page_id, index_in_page, model, firmware_version) \
+             VALUES (?, 7, 3, 'Lamp', '1.0')",
+        )
+        .bind(URL)
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO sync_sessions (session_id, status, started_at) \
+             VALUES ('old', 'completed', '2000-01-01 00:00:00')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO sync_sessions (session_id, status) VALUES ('now', 'running')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO sync_observed (session_id, url, page_id, index_in_page) \
+             VALUES ('old', ?1, 7, 3), ('now', ?1, 9, 3)",
+        )
+        .bind(URL)
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("UPDATE product_details SET firmware_version = '1.1' WHERE url = ?")
+            .bind(URL)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let explained = explain_row(&pool, URL).await.unwrap();
+        assert!(explained.current.in_products && explained.current.in_product_details);
+        assert_eq!(explained.current.model.as_deref(), Some("Lamp"));
+        let ids: Vec<&str> = explained
+            .sessions
+            .iter()
+            .map(|s| s.session_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["old", "now"]);
+        // The running session owns the firmware change
+        assert!(explained.sessions[0].changes.is_empty());
+        assert_eq!(explained.sessions[1].changes[0].field, "firmware_version");
+        assert!(explained.other_changes.is_empty());
+        assert!(explained.coordinate_history[1].moved);
+
+        let kinds: Vec<RowWarningKind> = explained.warnings.iter().map(|w| w.kind).collect();
+        assert!(kinds.contains(&RowWarningKind::CoordinateMismatch));
+        assert!(kinds.contains(&RowWarningKind::CoordinateDrift));
+        assert!(kinds.contains(&RowWarningKind::SlotCollision));
+        assert!(!kinds.contains(&RowWarningKind::MissingDetailRow));
+    }
+}
@@ -0,0 +1,534 @@
+//! 제품 검색 인덱스(FTS5) + 제조사 요약(rollup) 증분 유지
+//!
+//! `product_search`(FTS5)와 `manufacturer_rollups`는 `product_details`에서 파생된 테이블이다.
+//! 전체 재구축 대신 쓰기 경로가 건드린 URL만 넘겨 `apply_deltas`로 반영한다: URL마다 기존 인덱스
+//! 행을 지우고 현재 행이 있으면 다시 넣으며(insert/update/delete 모두 같은 경로), 이전·현재
+//! 제조사의 rollup만 다시 계산한다. `check_consistency`는 드리프트를 찾아 해당 행만 복구한다.
+//! `search`는 bm25 순위 + 접두어 검색 + 페이지네이션을 제공한다.
+
+use crate::infrastructure::query_control::QueryCancellation;
+use anyhow::Result;
+use chrono::Utc;
+use serde::Serialize;
+use sqlx::{Row, SqliteConnection, SqlitePool};
+use std::collections::{BTreeSet, HashMap};
+use tracing::{debug, info, warn};
+use ts_rs::TS;
+
+/// `product_details` columns mirrored into the FTS table (after `url`, as in migration 015)
+const SEARCH_FIELDS: [&str; 5] = [
+    "manufacturer",
+    "model",
+    "device_type",
+    "certificate_id",
+    "description",
+];
+
+/// URLs applied per transaction during repair
+const REPAIR_CHUNK: usize = 500;
+
+/// Drifted URLs/manufacturers echoed back in a report
+const MAX_DRIFT_SAMPLES: usize = 20;
+
+/// bm25 column weights: url (unindexed), manufacturer, model, device_type, certificate_id,
+/// description — name matches outrank description mentions
+const RANK_EXPR: &str = "bm25(product_search, 0.0, 5.0, 5.0, 2.0, 3.0, 1.0)";
+
+type SearchDoc = [Option<String>; 5];
+
+/// Result of one `apply_deltas` call
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IndexDeltaReport {
+    /// URLs (re)written to the FTS table
+    pub indexed: usize,
+    /// URLs dropped because their `product_details` row is gone
+    pub removed: usize,
+    pub rollups_refreshed: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SearchIndexDriftReport {
+    pub checked_rows: usize,
+    /// Detail rows with no FTS row
+    pub missing: usize,
+    /// FTS rows whose detail row no longer exists
+    pub orphaned: usize,
+    /// FTS rows whose text differs from the detail row (or appear more than once)
+    pub stale: usize,
+    /// Manufacturers whose rollup differs from a fresh aggregate
+    pub rollup_drift: usize,
+    pub sample_urls: Vec<String>,
+    pub sample_manufacturers: Vec<String>,
+    pub repaired: bool,
+}
+
+impl SearchIndexDriftReport {
+    pub fn is_consistent(&self) -> bool {
+        self.missing == 0 && self.orphaned == 0 && self.stale == 0 && self.rollup_drift == 0
+    }
+}
+
+/// One ranked search result
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ProductSearchHit {
+    pub url: String,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub device_type: Option<String>,
+    pub certificate_id: Option<String>,
+    /// Description excerpt with matches wrapped in `[` `]`
+    pub snippet: Option<String>,
+    /// bm25 score (lower is more relevant)
+    pub score: f64,
+}
+
+/// Bring the FTS rows and rollups for `urls` in line with `product_details`.
+/// Call after the detail rows were written or deleted; unknown URLs are no-ops.
+pub async fn apply_deltas(pool: &SqlitePool, urls: &[String]) -> Result<IndexDeltaReport> {
+    let mut report = IndexDeltaReport::default();
+    let urls: BTreeSet<&str> = urls.iter().map(String::as_str).collect();
+    if urls.is_empty() {
+        return Ok(report);
+    }
+    let select_detail = format!(
+        "SELECT {} FROM product_details WHERE url = ?",
+        SEARCH_FIELDS.join(", ")
+    );
+    let insert_doc = format!(
+        "INSERT INTO product_search (url, {}) VALUES (?, ?, ?, ?, ?, ?)",
+        SEARCH_FIELDS.join(", ")
+    );
+
+    let mut tx = pool.begin().await?;
+    let mut manufacturers = BTreeSet::new();
+    for url in urls {
+        // Previous manufacturer(s) come from the index itself
+        let previous: Vec<Option<String>> =
+            sqlx::query_scalar("SELECT manufacturer FROM product_search WHERE url = ?")
+                .bind(url)
+                .fetch_all(&mut *tx)
+                .await?;
+        manufacturers.extend(previous.into_iter().flatten());
+        let dropped = sqlx::query("DELETE FROM product_search WHERE url = ?")
+            .bind(url)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+        let current = sqlx::query(&select_detail)
+            .bind(url)
+            .fetch_optional(&mut *tx)
+            .await?;
+        match current {
+            Some(row) => {
+                let doc = search_doc(&row)?;
+                manufacturers.extend(doc[0].clone());
+                let mut query = sqlx::query(&insert_doc).bind(url);
+                for value in doc {
+                    query = query.bind(value);
+                }
+                query.execute(&mut *tx).await?;
+                report.indexed += 1;
+            }
+            None if dropped > 0 => report.removed += 1,
+            None => {}
+        }
+    }
+    report.rollups_refreshed = refresh_rollups(&mut *tx, &manufacturers).await?;
+    tx.commit().await?;
+    debug!(
+        "🔎 search index deltas: indexed={} removed={} rollups={}",
+        report.indexed, report.removed, report.rollups_refreshed
+    );
+    Ok(report)
+}
+
+/// Recompute the rollup row of each manufacturer (deleted when it has no products left)
+async fn refresh_rollups(
+    conn: &mut SqliteConnection,
+    manufacturers: &BTreeSet<String>,
+) -> Result<usize> {
+    let now = Utc::now().to_rfc3339();
+    for manufacturer in manufacturers {
+        sqlx::query("DELETE FROM manufacturer_rollups WHERE manufacturer = ?")
+            .bind(manufacturer)
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query(
+            "INSERT INTO manufacturer_rollups \
+                (manufacturer, product_count, latest_certification_date, refreshed_at) \
+             SELECT manufacturer, COUNT(*), MAX(certification_date), ? \
+             FROM product_details WHERE manufacturer = ? GROUP BY manufacturer",
+        )
+        .bind(&now)
+        .bind(manufacturer)
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(manufacturers.len())
+}
+
+/// Compare the FTS table and rollups against `product_details`; with `repair`,
+/// re-apply only the drifted URLs and manufacturers
+pub async fn check_consistency(pool: &SqlitePool, repair: bool) -> Result<SearchIndexDriftReport> {
+    check_consistency_cancellable(pool, repair, &QueryCancellation::none()).await
+}
+
+/// `check_consistency` that stops between repair chunks once `cancel` fires
+pub async fn check_consistency_cancellable(
+    pool: &SqlitePool,
+    repair: bool,
+    cancel: &QueryCancellation,
+) -> Result<SearchIndexDriftReport> {
+    let columns = SEARCH_FIELDS.join(", ");
+    let mut details: HashMap<String, SearchDoc> = HashMap::new();
+    for row in sqlx::query(&format!("SELECT url, {columns} FROM product_details"))
+        .fetch_all(pool)
+        .await?
+    {
+        details.insert(row.try_get("url")?, search_doc(&row)?);
+    }
+    let mut indexed: HashMap<String, Vec<SearchDoc>> = HashMap::new();
+    for row in sqlx::query(&format!("SELECT url, {columns} FROM product_search"))
+        .fetch_all(pool)
+        .await?
+    {
+        indexed
+            .entry(row.try_get("url")?)
+            .or_default()
+            .push(search_doc(&row)?);
+    }
+
+    let mut report = SearchIndexDriftReport {
+        checked_rows: details.len(),
+        ..Default::default()
+    };
+    let mut drifted: BTreeSet<String> = BTreeSet::new();
+    for (url, doc) in &details {
+        match indexed.get(url) {
+            None => report.missing += 1,
+            Some(docs) if docs.len() != 1 || docs[0] != *doc => report.stale += 1,
+            Some(_) => continue,
+        }
+        drifted.insert(url.clone());
+    }
+    for url in indexed.keys().filter(|url| !details.contains_key(*url)) {
+        report.orphaned += 1;
+        drifted.insert(url.clone());
+    }
+
+    let rollup_drift = rollup_drift(pool).await?;
+    report.rollup_drift = rollup_drift.len();
+    report.sample_urls = drifted.iter().take(MAX_DRIFT_SAMPLES).cloned().collect();
+    report.sample_manufacturers = rollup_drift
+        .iter()
+        .take(MAX_DRIFT_SAMPLES)
+        .cloned()
+        .collect();
+
+    if repair && !report.is_consistent() {
+        let drifted: Vec<String> = drifted.into_iter().collect();
+        for chunk in drifted.chunks(REPAIR_CHUNK) {
+            cancel.check()?;
+            apply_deltas(pool, chunk).await?;
+        }
+        // Rollups touched by the URL deltas are already fresh; this covers the rest
+        let mut conn = pool.acquire().await?;
+        refresh_rollups(&mut *conn, &rollup_drift).await?;
+        report.repaired = true;
+        info!(
+            "🔎 search index repaired: missing={} orphaned={} stale={} rollups={}",
+            report.missing, report.orphaned, report.stale, report.rollup_drift
+        );
+    }
+    Ok(report)
+}
+
+/// Manufacturers whose stored rollup is missing, extra or different from a fresh aggregate
+async fn rollup_drift(pool: &SqlitePool) -> Result<BTreeSet<String>> {
+    type Rollup = (i64, Option<String>);
+    let expected: HashMap<String, Rollup> = sqlx::query(
+        "SELECT manufacturer, COUNT(*) AS product_count, MAX(certification_date) AS latest \
+         FROM product_details WHERE manufacturer IS NOT NULL GROUP BY manufacturer",
+    )
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|row| {
+        Ok((
+            row.try_get("manufacturer")?,
+            (row.try_get("product_count")?, row.try_get("latest")?),
+        ))
+    })
+    .collect::<Result<_, sqlx::Error>>()?;
+    let stored: HashMap<String, Rollup> = sqlx::query(
+        "SELECT manufacturer, product_count, latest_certification_date AS latest \
+         FROM manufacturer_rollups",
+    )
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|row| {
+        Ok((
+            row.try_get("manufacturer")?,
+            (row.try_get("product_count")?, row.try_get("latest")?),
+        ))
+    })
+    .collect::<Result<_, sqlx::Error>>()?;
+
+    Ok(expected
+        .keys()
+        .chain(stored.keys())
+        .filter(|m| expected.get(*m) != stored.get(*m))
+        .cloned()
+        .collect())
+}
+
+/// Free text → FTS5 MATCH expression. Every term is quoted so punctuation (`-`, `:`, `.`) is
+/// literal, terms are ANDed, and with `prefix` each term also matches as a word prefix.
+/// None when the input has no terms.
+pub fn build_match_query(input: &str, prefix: bool) -> Option<String> {
+    let terms: Vec<String> = input
+        .split_whitespace()
+        .map(|term| term.replace('"', ""))
+        .filter(|term| !term.is_empty())
+        .map(|term| {
+            if prefix {
+                format!("\"{term}\"*")
+            } else {
+                format!("\"{term}\"")
+            }
+        })
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+/// Ranked page of matches plus the total match count
+pub async fn search(
+    pool: &SqlitePool,
+    match_query: &str,
+    limit: u32,
+    offset: u32,
+) -> Result<(Vec<ProductSearchHit>, u32)> {
+    let total: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM product_search WHERE product_search MATCH ?")
+            .bind(match_query)
+            .fetch_one(pool)
+            .await?;
+    let rows = sqlx::query(&format!(
+        "SELECT url, {}, snippet(product_search, 5, '[', ']', '…', 12) AS snippet, {RANK_EXPR} AS score \
+         FROM product_search WHERE product_search MATCH ? \
+         ORDER BY score LIMIT ? OFFSET ?",
+        SEARCH_FIELDS[..4].join(", ")
+    ))
+    .bind(match_query)
+    .bind(i64::from(limit))
+    .bind(i64::from(offset))
+    .fetch_all(pool)
+    .await?;
+    let hits = rows
+        .iter()
+        .map(|row| {
+            Ok(ProductSearchHit {
+                url: row.try_get("url")?,
+                manufacturer: row.try_get("manufacturer")?,
+                model: row.try_get("model")?,
+                device_type: row.try_get("device_type")?,
+                certificate_id: row.try_get("certificate_id")?,
+                snippet: row
+                    .try_get::<Option<String>, _>("snippet")?
+                    .filter(|s| !s.is_empty()),
+                score: row.try_get("score")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()?;
+    Ok((hits, total as u32))
+}
+
+/// Repair drift after writers that bypass the delta hooks (bulk sync SQL); best-effort
+pub async fn reconcile_after_bulk_write(pool: &SqlitePool) {
+    if !crate::infrastructure::features::feature_search_index_hooks() {
+        return;
+    }
+    match check_consistency(pool, true).await {
+        Ok(report) if report.repaired => debug!(
+            "🔎 search index reconciled: missing={} orphaned={} stale={} rollups={}",
+            report.missing, report.orphaned, report.stale, report.rollup_drift
+        ),
+        Ok(_) => {}
+        Err(e) => warn!("🔎 search index reconcile failed: {e:#}"),
+    }
+}
+
+fn search_doc(row: &sqlx::sqlite::SqliteRow) -> Result<SearchDoc> {
+    Ok([
+        row.try_get(SEARCH_FIELDS[0])?,
+        row.try_get(SEARCH_FIELDS[1])?,
+        row.try_get(SEARCH_FIELDS[2])?,
+        row.try_get(SEARCH_FIELDS[3])?,
+        row.try_get(SEARCH_FIELDS[4])?,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    async fn test_pool() -> SqlitePool {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        db.pool().clone()
+    }
+
+    async fn upsert_detail(pool: &SqlitePool, url: &str, manufacturer: &str, model: &str) {
+        sqlx::query("INSERT OR IGNORE INTO products (url) VALUES (?)")
+            .bind(url)
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO product_details (url, manufacturer, model, certification_date) \
+             VALUES (?, ?, ?, '2025-01-01') \
+             ON CONFLICT (url) DO UPDATE SET manufacturer = excluded.manufacturer, model = excluded.model",
+        )
+        .bind(url)
+        .bind(manufacturer)
+        .bind(model)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn rollup_count(pool: &SqlitePool, manufacturer: &str) -> Option<i64> {
+        sqlx::query_scalar("SELECT product_count FROM manufacturer_rollups WHERE manufacturer = ?")
+            .bind(manufacturer)
+            .fetch_optional(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn deltas_track_insert_update_and_delete() {
+        let pool = test_pool().await;
+        let a = "https://x.test/a".to_string();
+        let b = "https://x.test/b".to_string();
+        upsert_detail(&pool, &a, "Acme", "M1").await;
+        upsert_detail(&pool, &b, "Acme", "M2").await;
+        let report = apply_deltas(&pool, &[a.clone(), b.clone()]).await.unwrap();
+        assert_eq!(report.indexed, 2);
+        assert_eq!(rollup_count(&pool, "Acme").await, Some(2));
+
+        // Manufacturer change moves the product between rollups
+        upsert_detail(&pool, &b, "Globex", "M2").await;
+        apply_deltas(&pool, std::slice::from_ref(&b)).await.unwrap();
+        assert_eq!(rollup_count(&pool, "Acme").await, Some(1));
+        assert_eq!(rollup_count(&pool, "Globex").await, Some(1));
+        let hits: Vec<String> = sqlx::query_scalar(
+            "SELECT url FROM product_search WHERE product_search MATCH 'globex'",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(hits, vec![b.clone()]);
+
+        sqlx::query("DELETE FROM product_details WHERE url = ?")
+            .bind(&b)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let report = apply_deltas(&pool, std::slice::from_ref(&b)).await.unwrap();
+        assert_eq!(report.removed, 1);
+        assert_eq!(rollup_count(&pool, "Globex").await, None);
+        assert!(
+            check_consistency(&pool, false)
+                .await
+                .unwrap()
+                .is_consistent()
+        );
+    }
+
+    #[test]
+    fn match_query_quotes_terms_and_adds_prefix() {
+        assert_eq!(
+            build_match_query("  smart plug ", true).as_deref(),
+            Some("\"smart\"* \"plug\"*")
+        );
+        assert_eq!(
+            build_match_query("CSA-22\"1 OR", false).as_deref(),
+            Some("\"CSA-221\" \"OR\"")
+        );
+        assert_eq!(build_match_query(" \" ", true), None);
+    }
+
+    #[tokio::test]
+    async fn search_ranks_name_matches_and_paginates() {
+        let pool = test_pool().await;
+        let urls: Vec<String> = (0..5).map(|i| format!("https://x.test/{i}")).collect();
+        upsert_detail(&pool, &urls[0], "Acme", "Smart Plug").await;
+        upsert_detail(&pool, &urls[1], "Globex", "Bulb").await;
+        upsert_detail(&pool, &urls[2], "Initech", "Sensor").await;
+        upsert_detail(&pool, &urls[3], "Hooli", "Lock").await;
+        upsert_detail(&pool, &urls[4], "Umbrella", "Thermostat").await;
+        sqlx::query(
+            "UPDATE product_details SET description = 'Works with any smart plug' WHERE url = ?",
+        )
+        .bind(&urls[2])
+        .execute(&pool)
+        .await
+        .unwrap();
+        apply_deltas(&pool, &urls).await.unwrap();
+
+        let query = build_match_query("smar", true).unwrap();
+        let (hits, total) = search(&pool, &query, 10, 0).await.unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(
+            hits[0].url, urls[0],
+            "model match outranks description match"
+        );
+        assert_eq!(
+            hits[1].snippet.as_deref(),
+            Some("Works with any [smart] plug")
+        );
+
+        let (page2, total) = search(&pool, &query, 1, 1).await.unwrap();
+        assert_eq!((page2.len(), total), (1, 2));
+        assert_eq!(page2[0].url, urls[2]);
+        let exact = build_match_query("smar", false).unwrap();
+        assert_eq!(search(&pool, &exact, 10, 0).await.unwrap().1, 0);
+    }
+
+    #[tokio::test]
+    async fn consistency_check_repairs_only_drifted_rows() {
+        let pool = test_pool().await;
+        let urls: Vec<String> = (0..3).map(|i| format!("https://x.test/{i}")).collect();
+        for url in &urls {
+            upsert_detail(&pool, url, "Acme", "M").await;
+        }
+        apply_deltas(&pool, &urls).await.unwrap();
+
+        // Writes that bypassed the hooks: one update, one new row, one delete
+        upsert_detail(&pool, &urls[0], "Acme", "M-renamed").await;
+        upsert_detail(&pool, "https://x.test/new", "Initech", "N").await;
+        sqlx::query("DELETE FROM product_details WHERE url = ?")
+            .bind(&urls[2])
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let report = check_consistency(&pool, true).await.unwrap();
+        assert_eq!((report.missing, report.orphaned, report.stale), (1, 1, 1));
+        assert_eq!(report.rollup_drift, 2);
+        assert!(report.repaired);
+        assert!(!report.sample_urls.contains(&urls[1]));
+        assert!(
+            check_consistency(&pool, false)
+                .await
+                .unwrap()
+                .is_consistent()
+        );
+        assert_eq!(rollup_count(&pool, "Acme").await, Some(2));
+        assert_eq!(rollup_count(&pool, "Initech").await, Some(1));
+    }
+}
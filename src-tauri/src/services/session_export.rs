@@ -0,0 +1,373 @@
+//! 크롤/싱크 세션 단위 내보내기 싱크
+//!
+//! 크롤 또는 싱크 호출에 `export`를 지정하면 세션이 저장한 제품을 저장 직후 CSV/NDJSON 파일에
+//! 한 줄씩 이어 쓴다. 별도 내보내기 단계 없이 세션 하나로 결과 파일이 만들어진다.
+//! 한 세션에서 같은 URL이 여러 번 저장돼도 파일에는 처음 한 번만 기록한다.
+//! 행 형식과 CSV 열 선택은 `export_products`와 같다(`commands::data_export::ExportWriter`).
+
+use crate::commands::data_export::{ExportFormat, ExportWriter};
+use crate::crawl_engine::channels::types::AppEvent;
+use crate::crawl_engine::runtime::task_registry::{SESSION_TASK_LIFETIME, spawn_tracked};
+use crate::domain::product::{Product, ProductDetail, ProductWithDetails};
+use crate::infrastructure::config::ConfigManager;
+use crate::infrastructure::integrated_product_repository::IntegratedProductRepository;
+use crate::services::anonymization::AnonymizationProfile;
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+
+/// Export sink requested together with a crawl or sync invocation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionExportRequest {
+    /// `csv` or `ndjson` (JSON arrays cannot be appended while the session runs)
+    pub format: ExportFormat,
+    /// Target file; defaults to `<app data>/exports/session_<session_id>.<ext>`
+    #[serde(default)]
+    pub output_path: Option<String>,
+    /// CSV column selection, same names as `export_products`
+    #[serde(default)]
+    pub columns: Option<Vec<String>>,
+    /// Same anonymization profile as `export_products`
+    #[serde(default)]
+    pub anonymize: Option<AnonymizationProfile>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionExportSummary {
+    pub session_id: String,
+    pub output_path: String,
+    pub format: ExportFormat,
+    pub rows_written: u64,
+}
+
+struct SessionExportSink {
+    path: PathBuf,
+    format: ExportFormat,
+    writer: Mutex<ExportWriter>,
+    written_urls: Mutex<HashSet<String>>,
+}
+
+static SESSION_EXPORTS: OnceCell<Mutex<HashMap<String, Arc<SessionExportSink>>>> = OnceCell::new();
+
+fn sinks() -> &'static Mutex<HashMap<String, Arc<SessionExportSink>>> {
+    SESSION_EXPORTS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Create the session's export file; call before the session starts saving products
+pub fn open_session_export(
+    session_id: &str,
+    request: &SessionExportRequest,
+) -> Result<PathBuf, String> {
+    if matches!(request.format, ExportFormat::Json | ExportFormat::Xlsx) {
+        return Err(
+            "Session export supports csv or ndjson (json/xlsx files cannot be appended)".into(),
+        );
+    }
+    let path = match request
+        .output_path
+        .as_deref()
+        .filter(|p| !p.trim().is_empty())
+    {
+        Some(path) => PathBuf::from(path),
+        None => ConfigManager::get_app_data_dir()
+            .map_err(|e| e.to_string())?
+            .join("exports")
+            .join(format!(
+                "session_{session_id}.{}",
+                request.format.extension()
+            )),
+    };
+    let anonymizer = request
+        .anonymize
+        .as_ref()
+        .map(AnonymizationProfile::anonymizer)
+        .transpose()?;
+    let writer = ExportWriter::create(
+        &path,
+        request.format,
+        request.columns.as_deref(),
+        anonymizer,
+    )?;
+    let sink = Arc::new(SessionExportSink {
+        path: path.clone(),
+        format: request.format,
+        writer: Mutex::new(writer),
+        written_urls: Mutex::new(HashSet::new()),
+    });
+    sinks()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(session_id.to_string(), sink);
+    info!(target: "data_export", "session export opened: session={} format={:?} path={:?}", session_id, request.format, path);
+    Ok(path)
+}
+
+/// Closes the session's sink when dropped, so early returns cannot leave it open
+pub struct SessionExportGuard {
+    session_id: String,
+}
+
+impl Drop for SessionExportGuard {
+    fn drop(&mut self) {
+        close_session_export(&self.session_id);
+    }
+}
+
+/// `open_session_export` for callers that own the whole session (sync commands)
+pub fn open_scoped(
+    session_id: &str,
+    request: &SessionExportRequest,
+) -> Result<SessionExportGuard, String> {
+    open_session_export(session_id, request)?;
+    Ok(SessionExportGuard {
+        session_id: session_id.to_string(),
+    })
+}
+
+fn sink_for(session_id: &str) -> Option<Arc<SessionExportSink>> {
+    sinks()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(session_id)
+        .cloned()
+}
+
+/// Append saved products to the session's export file (no-op without a sink).
+/// Failures are logged and never fail the save itself.
+pub fn record_session_products(session_id: &str, details: &[ProductDetail]) {
+    let Some(sink) = sink_for(session_id) else {
+        return;
+    };
+    write_rows(&sink, session_id, details.iter().map(export_row));
+}
+
+/// Same as `record_session_products` for writers that only know the saved URLs
+/// (sync SQL paths): rows are read back after the write has committed.
+pub async fn record_session_urls(pool: &SqlitePool, session_id: &str, urls: &[String]) {
+    let Some(sink) = sink_for(session_id) else {
+        return;
+    };
+    let repo = IntegratedProductRepository::new(pool.clone());
+    let mut rows = Vec::with_capacity(urls.len());
+    for url in urls {
+        match repo.get_product_with_details(url).await {
+            Ok(Some(row)) => rows.push(row),
+            Ok(None) => {}
+            Err(e) => {
+                warn!(target: "data_export", "session export lookup failed: session={} url={} error={}", session_id, url, e)
+            }
+        }
+    }
+    write_rows(&sink, session_id, rows);
+}
+
+fn write_rows(
+    sink: &SessionExportSink,
+    session_id: &str,
+    rows: impl IntoIterator<Item = ProductWithDetails>,
+) {
+    let mut written = sink.written_urls.lock().unwrap_or_else(|e| e.into_inner());
+    let mut writer = sink.writer.lock().unwrap_or_else(|e| e.into_inner());
+    for row in rows {
+        if !written.insert(row.product.url.clone()) {
+            continue;
+        }
+        if let Err(e) = writer.write_row(&row) {
+            warn!(target: "data_export", "session export write failed: session={} url={} error={}", session_id, row.product.url, e);
+        }
+    }
+    // Keep the file readable while the session is still running
+    if let Err(e) = writer.flush() {
+        warn!(target: "data_export", "session export flush failed: session={} error={}", session_id, e);
+    }
+}
+
+/// Finish and unregister the session's sink; None when the session had none
+pub fn close_session_export(session_id: &str) -> Option<SessionExportSummary> {
+    let sink = sinks()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(session_id)?;
+    let rows_written = {
+        let mut writer = sink.writer.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err(e) = writer.flush() {
+            warn!(target: "data_export", "session export flush failed: session={} error={}", session_id, e);
+        }
+        writer.rows_written()
+    };
+    info!(target: "data_export", "session export closed: session={} rows={} path={:?}", session_id, rows_written, sink.path);
+    Some(SessionExportSummary {
+        session_id: session_id.to_string(),
+        output_path: sink.path.display().to_string(),
+        format: sink.format,
+        rows_written,
+    })
+}
+
+pub fn has_session_export(session_id: &str) -> bool {
+    sinks()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .contains_key(session_id)
+}
+
+/// Close the session's sink once the session ends (completed, failed, timed out or channel closed)
+pub fn spawn_session_export_closer(session_id: String, event_tx: broadcast::Sender<AppEvent>) {
+    let mut rx = event_tx.subscribe();
+    let task_name = format!("session-export:{}", session_id);
+    spawn_tracked(task_name, Some(SESSION_TASK_LIFETIME), async move {
+        loop {
+            match rx.recv().await {
+                Ok(AppEvent::SessionCompleted {
+                    session_id: sid, ..
+                })
+                | Ok(AppEvent::SessionTimeout {
+                    session_id: sid, ..
+                }) if sid == session_id => {
+                    break;
+                }
+                Ok(AppEvent::SessionFailed {
+                    session_id: sid,
+                    final_failure: true,
+                    ..
+                }) if sid == session_id => break,
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+        close_session_export(&session_id);
+    });
+}
+
+/// Same row shape as `export_products` (products columns are derived from the detail row)
+fn export_row(detail: &ProductDetail) -> ProductWithDetails {
+    ProductWithDetails {
+        product: Product {
+            id: detail.id.clone(),
+            url: detail.url.clone(),
+            manufacturer: detail.manufacturer.clone(),
+            model: detail.model.clone(),
+            certificate_id: detail.certificate_id.clone(),
+            page_id: detail.page_id,
+            index_in_page: detail.index_in_page,
+            created_at: detail.created_at,
+            updated_at: detail.updated_at,
+        },
+        details: Some(detail.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn detail(url: &str, model: &str) -> ProductDetail {
+        let now = Utc::now();
+        ProductDetail {
+            url: url.to_string(),
+            page_id: Some(1),
+            index_in_page: Some(0),
+            id: None,
+            manufacturer: Some("Acme, Inc.".into()),
+            model: Some(model.into()),
+            device_type: None,
+            certificate_id: None,
+            certification_date: None,
+            software_version: None,
+            hardware_version: None,
+            vid: None,
+            pid: None,
+            family_sku: None,
+            family_variant_sku: None,
+            firmware_version: None,
+            family_id: None,
+            tis_trp_tested: None,
+            specification_version: None,
+            transport_interface: None,
+            primary_device_type_id: None,
+            application_categories: None,
+            description: None,
+            compliance_document_url: None,
+            program_type: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn session_rows_stream_once_per_url() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.csv");
+        let request = SessionExportRequest {
+            format: ExportFormat::Csv,
+            output_path: Some(path.display().to_string()),
+            columns: Some(vec!["url".into(), "manufacturer".into(), "model".into()]),
+            anonymize: None,
+        };
+        open_session_export("export-test", &request).unwrap();
+        record_session_products(
+            "export-test",
+            &[
+                detail("https://x.test/a", "M1"),
+                detail("https://x.test/b", "M2"),
+            ],
+        );
+        record_session_products("export-test", &[detail("https://x.test/a", "M1b")]);
+        // Sessions without a sink are ignored
+        record_session_products("other-session", &[detail("https://x.test/c", "M3")]);
+
+        let summary = close_session_export("export-test").unwrap();
+        assert_eq!(summary.rows_written, 2);
+        assert!(close_session_export("export-test").is_none());
+        let content = std::fs::read_to_string(&path).unwrap();
+        let (schema_line, rows) = content.split_once("\r\n").unwrap();
+        assert!(schema_line.starts_with("# schema_id="));
+        assert_eq!(
+            rows,
+            "url,manufacturer,model\r\nhttps://x.test/a,\"Acme, Inc.\",M1\r\nhttps://x.test/b,\"Acme, Inc.\",M2\r\n"
+        );
+    }
+
+    #[test]
+    fn anonymized_sinks_drop_and_hash_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("shared.csv");
+        let request = SessionExportRequest {
+            format: ExportFormat::Csv,
+            output_path: Some(path.display().to_string()),
+            columns: Some(vec!["url".into(), "manufacturer".into(), "model".into()]),
+            anonymize: Some(AnonymizationProfile {
+                exclude: vec!["model".into()],
+                hash: vec!["manufacturer".into()],
+                ..Default::default()
+            }),
+        };
+        open_session_export("export-anon", &request).unwrap();
+        record_session_products("export-anon", &[detail("https://x.test/a", "M1")]);
+        close_session_export("export-anon").unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let mut lines = content.lines().skip(1);
+        assert_eq!(lines.next(), Some("url,manufacturer"));
+        let (url, manufacturer) = lines.next().unwrap().split_once(',').unwrap();
+        assert_eq!(url, "https://x.test/a");
+        assert!(manufacturer.starts_with("h:") && !content.contains("Acme"));
+    }
+
+    #[test]
+    fn json_sinks_are_rejected() {
+        let request = SessionExportRequest {
+            format: ExportFormat::Json,
+            output_path: None,
+            columns: None,
+            anonymize: None,
+        };
+        assert!(open_session_export("json-session", &request).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+//! 세션/배치 운영자 메모 (session_notes)
+//!
+//! 실행 중이거나 끝난 크롤/싱크 세션에 자유 텍스트 메모를 남긴다 (선택적으로 배치 단위). 메모는 DB 에
+//! 쌓이며 세션 리포트(`reports/session_<id>.json|md`)에 포함된다. 리포트가 이미 쓰인 세션은 메모를
+//! 추가할 때 리포트를 다시 쓴다.
+
+use anyhow::Result;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+/// Longest note accepted (characters)
+pub const MAX_NOTE_CHARS: usize = 4000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionNote {
+    pub id: i64,
+    pub session_id: String,
+    /// Batch the note is about (None = whole session)
+    pub batch_id: Option<String>,
+    pub note: String,
+    pub created_at: String,
+}
+
+/// Store a note; blank notes and notes over `MAX_NOTE_CHARS` are rejected
+pub async fn add_note(
+    pool: &SqlitePool,
+    session_id: &str,
+    batch_id: Option<&str>,
+    note: &str,
+) -> Result<SessionNote> {
+    let note = note.trim();
+    if note.is_empty() {
+        anyhow::bail!("note must not be empty");
+    }
+    if note.chars().count() > MAX_NOTE_CHARS {
+        anyhow::bail!("note is longer than {MAX_NOTE_CHARS} characters");
+    }
+    let batch_id = batch_id.map(str::trim).filter(|b| !b.is_empty());
+    let created_at = Utc::now().to_rfc3339();
+    let id: i64 = sqlx::query_scalar(
+        "INSERT INTO session_notes (session_id, batch_id, note, created_at) VALUES (?, ?, ?, ?) \
+         RETURNING id",
+    )
+    .bind(session_id)
+    .bind(batch_id)
+    .bind(note)
+    .bind(&created_at)
+    .fetch_one(pool)
+    .await?;
+    Ok(SessionNote {
+        id,
+        session_id: session_id.to_string(),
+        batch_id: batch_id.map(str::to_string),
+        note: note.to_string(),
+        created_at,
+    })
+}
+
+/// Notes of a session, oldest first
+pub async fn list_notes(pool: &SqlitePool, session_id: &str) -> Result<Vec<SessionNote>> {
+    let rows = sqlx::query(
+        "SELECT id, session_id, batch_id, note, created_at FROM session_notes \
+         WHERE session_id = ? ORDER BY id",
+    )
+    .bind(session_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|r| SessionNote {
+            id: r.get("id"),
+            session_id: r.get("session_id"),
+            batch_id: r.get("batch_id"),
+            note: r.get("note"),
+            created_at: r.get("created_at"),
+        })
+        .collect())
+}
+
+/// Whether `session_id` is a recorded sync session
+pub async fn is_sync_session(pool: &SqlitePool, session_id: &str) -> Result<bool> {
+    let found: Option<i64> =
+        sqlx::query_scalar("SELECT 1 FROM sync_sessions WHERE session_id = ? LIMIT 1")
+            .bind(session_id)
+            .fetch_optional(pool)
+            .await?;
+    Ok(found.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    #[tokio::test]
+    async fn stores_trimmed_notes_per_session_in_order() {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        let pool = db.pool().clone();
+        add_note(&pool, "s1", None, "  pages 120-130 slow overnight ")
+            .await
+            .unwrap();
+        let batch = add_note(&pool, "s1", Some("b2"), "proxy swapped")
+            .await
+            .unwrap();
+        add_note(&pool, "s2", Some(" "), "other").await.unwrap();
+        assert!(add_note(&pool, "s1", None, "   ").await.is_err());
+
+        let notes = list_notes(&pool, "s1").await.unwrap();
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0].note, "pages 120-130 slow overnight");
+        assert_eq!(notes[1], batch);
+        assert_eq!(list_notes(&pool, "s2").await.unwrap()[0].batch_id, None);
+    }
+}
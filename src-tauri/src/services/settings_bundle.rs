@@ -0,0 +1,426 @@
+//! 설정 번들 내보내기/가져오기
+//!
+//! 튜닝된 환경을 다른 머신에 그대로 옮기기 위해 사용자/고급 설정(추출 규칙 포함), 저장된 설정 프리셋,
+//! 예약 크롤링 스케줄, 런타임 feature flag 스냅샷을 하나의 버전 관리 JSON 파일로 묶는다.
+//! 가져오기는 전체를 먼저 검증하고 문제가 하나라도 있으면 아무것도 적용하지 않는다.
+//! 머신 고유 상태(`app_managed`: 마지막 페이지, 창 위치, 호스트 프로필 등)는 번들에 넣지 않는다.
+//! 진단용으로 공유할 번들은 익명화 프로필을 적용해 쓸 수 있으며(`anonymized: true`), 값이 바뀌었으므로
+//! 가져오기는 거부한다.
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+
+use crate::infrastructure::config::{
+    AdvancedConfig, ConfigManager, ListCollectionStrategy, UserConfig,
+};
+use crate::infrastructure::site_profiles::site_profile_by_id;
+use crate::services::anonymization::Anonymizer;
+use crate::services::crawl_scheduler::{CrawlSchedule, ScheduleAction, ScheduleSpec};
+
+/// `format` marker written to every bundle
+pub const SETTINGS_BUNDLE_FORMAT: &str = "rmattercertis-settings";
+
+/// Bundle schema version written by this build; older versions are migrated on import
+pub const SETTINGS_BUNDLE_VERSION: u32 = 1;
+
+/// Bundle sections the anonymization profile applies to (header fields stay intact)
+const ANONYMIZED_SECTIONS: [&str; 3] = ["config", "presets", "schedules"];
+
+const PRESETS_FILE_NAME: &str = "settings_presets.json";
+
+/// Portable part of `AppConfig` (extraction rules live in `advanced`:
+/// `site_profile`, `product_selectors`, `list_collection`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortableConfig {
+    pub user: UserConfig,
+    pub advanced: AdvancedConfig,
+}
+
+/// Named partial settings the UI can apply on top of the current config
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SettingsPreset {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Partial `AppConfig` JSON (same shape as `get_app_settings`)
+    pub config: serde_json::Value,
+}
+
+/// Schedule definition without run history or ids
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundledSchedule {
+    pub name: String,
+    pub spec: ScheduleSpec,
+    pub action: ScheduleAction,
+    pub enabled: bool,
+}
+
+impl From<&CrawlSchedule> for BundledSchedule {
+    fn from(schedule: &CrawlSchedule) -> Self {
+        Self {
+            name: schedule.name.clone(),
+            spec: schedule.spec.clone(),
+            action: schedule.action.clone(),
+            enabled: schedule.enabled,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsBundle {
+    pub format: String,
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub app_version: String,
+    pub config: PortableConfig,
+    #[serde(default)]
+    pub presets: Vec<SettingsPreset>,
+    #[serde(default)]
+    pub schedules: Vec<BundledSchedule>,
+    /// Runtime flags of the exporting machine (environment variables; informational on import)
+    #[serde(default)]
+    pub feature_flags: BTreeMap<String, bool>,
+}
+
+impl SettingsBundle {
+    pub fn new(
+        config: PortableConfig,
+        presets: Vec<SettingsPreset>,
+        schedules: Vec<BundledSchedule>,
+        feature_flags: BTreeMap<String, bool>,
+    ) -> Self {
+        Self {
+            format: SETTINGS_BUNDLE_FORMAT.to_string(),
+            version: SETTINGS_BUNDLE_VERSION,
+            exported_at: Utc::now(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            config,
+            presets,
+            schedules,
+            feature_flags,
+        }
+    }
+
+    /// Parse a bundle file body, checking the format marker and schema version first
+    pub fn parse(content: &str) -> Result<Self> {
+        let raw: serde_json::Value =
+            serde_json::from_str(content).context("Settings bundle is not valid JSON")?;
+        let format = raw.get("format").and_then(|v| v.as_str()).unwrap_or("");
+        if format != SETTINGS_BUNDLE_FORMAT {
+            bail!("Not a settings bundle (format '{format}', expected '{SETTINGS_BUNDLE_FORMAT}')");
+        }
+        if raw.get("anonymized").and_then(|v| v.as_bool()) == Some(true) {
+            bail!("Settings bundle was anonymized for sharing and cannot be imported");
+        }
+        let version = raw.get("version").and_then(|v| v.as_u64()).unwrap_or(0);
+        if version == 0 {
+            bail!("Settings bundle has no version");
+        }
+        if version > u64::from(SETTINGS_BUNDLE_VERSION) {
+            bail!(
+                "Settings bundle version {version} is newer than supported version {SETTINGS_BUNDLE_VERSION}; update the app first"
+            );
+        }
+        // v1 is the first schema; later versions add migrations here before deserializing
+        serde_json::from_value(raw).context("Settings bundle does not match the expected schema")
+    }
+
+    /// Every problem that would make applying this bundle unsafe; empty when valid
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let user = &self.config.user;
+        let advanced = &self.config.advanced;
+
+        if user.max_concurrent_requests == 0 {
+            errors.push("user.max_concurrent_requests must be at least 1".into());
+        }
+        if user.batch.batch_size == 0 {
+            errors.push("user.batch.batch_size must be at least 1".into());
+        }
+        if user.crawling.page_range_limit == 0 {
+            errors.push("user.crawling.page_range_limit must be at least 1".into());
+        }
+        if user.crawling.workers.list_page_max_concurrent == 0
+            || user.crawling.workers.product_detail_max_concurrent == 0
+        {
+            errors.push("user.crawling.workers concurrency must be at least 1".into());
+        }
+        if advanced.request_timeout_seconds == 0 {
+            errors.push("advanced.request_timeout_seconds must be at least 1".into());
+        }
+        if site_profile_by_id(&advanced.site_profile).is_none() {
+            errors.push(format!(
+                "advanced.site_profile '{}' is not a known site profile",
+                advanced.site_profile
+            ));
+        }
+        errors.extend(advanced.url_templates.validate());
+        if advanced
+            .product_selectors
+            .iter()
+            .any(|s| s.trim().is_empty() || scraper::Selector::parse(s).is_err())
+        {
+            errors.push("advanced.product_selectors contains an invalid CSS selector".into());
+        }
+        if advanced.list_collection.strategy == ListCollectionStrategy::Sitemap
+            && url::Url::parse(&advanced.list_collection.sitemap_url).is_err()
+        {
+            errors.push(format!(
+                "advanced.list_collection.sitemap_url '{}' is not a valid URL",
+                advanced.list_collection.sitemap_url
+            ));
+        }
+        if advanced.event_stream.enabled
+            && advanced
+                .event_stream
+                .bind_addr
+                .parse::<SocketAddr>()
+                .is_err()
+        {
+            errors.push(format!(
+                "advanced.event_stream.bind_addr '{}' is not a socket address",
+                advanced.event_stream.bind_addr
+            ));
+        }
+        if advanced.metrics_exporter.enabled
+            && advanced
+                .metrics_exporter
+                .bind_addr
+                .parse::<SocketAddr>()
+                .is_err()
+        {
+            errors.push(format!(
+                "advanced.metrics_exporter.bind_addr '{}' is not a socket address",
+                advanced.metrics_exporter.bind_addr
+            ));
+        }
+
+        let mut preset_names = std::collections::HashSet::new();
+        for preset in &self.presets {
+            if preset.name.trim().is_empty() {
+                errors.push("preset with an empty name".into());
+            } else if !preset_names.insert(preset.name.trim()) {
+                errors.push(format!("duplicate preset '{}'", preset.name));
+            }
+            if !preset.config.is_object() {
+                errors.push(format!(
+                    "preset '{}' config must be a JSON object",
+                    preset.name
+                ));
+            }
+        }
+
+        for schedule in &self.schedules {
+            if schedule.name.trim().is_empty() {
+                errors.push("schedule with an empty name".into());
+            }
+            if let Err(e) = schedule.spec.validate() {
+                errors.push(format!("schedule '{}': {e}", schedule.name));
+            }
+            if matches!(&schedule.action, ScheduleAction::PartialSync { ranges } if ranges.trim().is_empty())
+            {
+                errors.push(format!(
+                    "schedule '{}': partial sync requires a range expression",
+                    schedule.name
+                ));
+            }
+        }
+        errors
+    }
+
+    /// Flags whose value here differs from `current` (they are environment variables and
+    /// cannot be applied by import)
+    pub fn feature_flag_differences(&self, current: &BTreeMap<String, bool>) -> Vec<String> {
+        self.feature_flags
+            .iter()
+            .filter(|(name, value)| current.get(*name) != Some(value))
+            .map(|(name, value)| {
+                format!("feature flag {name}={value} in bundle differs from this machine; set the environment variable to match")
+            })
+            .collect()
+    }
+}
+
+/// Merge `incoming` presets into `existing` by name (incoming wins); returns the count merged
+pub fn merge_presets(existing: &mut Vec<SettingsPreset>, incoming: &[SettingsPreset]) -> usize {
+    for preset in incoming {
+        match existing.iter_mut().find(|p| p.name == preset.name) {
+            Some(slot) => *slot = preset.clone(),
+            None => existing.push(preset.clone()),
+        }
+    }
+    existing.sort_by(|a, b| a.name.cmp(&b.name));
+    incoming.len()
+}
+
+fn presets_path() -> Result<PathBuf> {
+    Ok(ConfigManager::get_config_dir()?.join(PRESETS_FILE_NAME))
+}
+
+/// Saved presets (empty when none were saved yet)
+pub async fn load_presets() -> Result<Vec<SettingsPreset>> {
+    let path = presets_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = tokio::fs::read_to_string(&path)
+        .await
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Invalid presets file {}", path.display()))
+}
+
+pub async fn save_presets(presets: &[SettingsPreset]) -> Result<()> {
+    let path = presets_path()?;
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let content = serde_json::to_string_pretty(presets)?;
+    tokio::fs::write(&path, content)
+        .await
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Write `bundle`; with an anonymizer the config/presets/schedules sections are anonymized and
+/// the file is marked `anonymized` so it cannot be imported
+pub async fn write_bundle(
+    path: &Path,
+    bundle: &SettingsBundle,
+    anonymizer: Option<&Anonymizer>,
+) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let content = serde_json::to_string_pretty(&bundle_json(bundle, anonymizer)?)?;
+    tokio::fs::write(path, content)
+        .await
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn bundle_json(
+    bundle: &SettingsBundle,
+    anonymizer: Option<&Anonymizer>,
+) -> Result<serde_json::Value> {
+    let mut value = serde_json::to_value(bundle)?;
+    if let (Some(anonymizer), Some(map)) = (anonymizer, value.as_object_mut()) {
+        for section in ANONYMIZED_SECTIONS {
+            if let Some(inner) = map.get_mut(section) {
+                anonymizer.apply_json(inner);
+            }
+        }
+        map.insert("anonymized".into(), serde_json::Value::Bool(true));
+    }
+    Ok(value)
+}
+
+pub async fn read_bundle(path: &Path) -> Result<SettingsBundle> {
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    SettingsBundle::parse(&content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::config::AppConfig;
+
+    fn bundle() -> SettingsBundle {
+        let config = AppConfig::default();
+        SettingsBundle::new(
+            PortableConfig {
+                user: config.user,
+                advanced: config.advanced,
+            },
+            vec![SettingsPreset {
+                name: "gentle".into(),
+                description: "slow and polite".into(),
+                config: serde_json::json!({ "user": { "request_delay_ms": 2000 } }),
+            }],
+            vec![BundledSchedule {
+                name: "nightly".into(),
+                spec: ScheduleSpec::Cron {
+                    expression: "30 2 * * *".into(),
+                },
+                action: ScheduleAction::IncrementalCrawl,
+                enabled: true,
+            }],
+            BTreeMap::from([("MC_FEATURE_HTTP_CLIENT_UNIFIED".to_string(), true)]),
+        )
+    }
+
+    #[test]
+    fn default_bundle_round_trips_and_validates() {
+        let original = bundle();
+        let json = serde_json::to_string_pretty(&original).unwrap();
+        let parsed = SettingsBundle::parse(&json).unwrap();
+        assert_eq!(parsed.version, SETTINGS_BUNDLE_VERSION);
+        assert_eq!(parsed.presets, original.presets);
+        assert_eq!(parsed.schedules, original.schedules);
+        assert!(parsed.validate().is_empty(), "{:?}", parsed.validate());
+    }
+
+    #[test]
+    fn parse_rejects_foreign_and_future_files() {
+        assert!(SettingsBundle::parse("{\"user\":{}}").is_err());
+        let mut raw = serde_json::to_value(bundle()).unwrap();
+        raw["version"] = serde_json::json!(SETTINGS_BUNDLE_VERSION + 1);
+        let err = SettingsBundle::parse(&raw.to_string()).unwrap_err();
+        assert!(err.to_string().contains("newer"));
+    }
+
+    #[test]
+    fn anonymized_bundles_keep_header_and_refuse_import() {
+        let anonymizer = crate::services::anonymization::AnonymizationProfile {
+            exclude: vec!["description".into()],
+            hash: vec!["name".into()],
+            ..Default::default()
+        }
+        .anonymizer()
+        .unwrap();
+        let value = bundle_json(&bundle(), Some(&anonymizer)).unwrap();
+        assert_eq!(value["format"], SETTINGS_BUNDLE_FORMAT);
+        assert!(value["presets"][0].get("description").is_none());
+        assert!(
+            value["schedules"][0]["name"]
+                .as_str()
+                .unwrap()
+                .starts_with("h:")
+        );
+        let err = SettingsBundle::parse(&value.to_string()).unwrap_err();
+        assert!(err.to_string().contains("anonymized"));
+    }
+
+    #[test]
+    fn validation_collects_every_problem() {
+        let mut b = bundle();
+        b.config.user.batch.batch_size = 0;
+        b.config.advanced.site_profile = "unknown-site".into();
+        b.config.advanced.product_selectors.push("div[".into());
+        b.config.advanced.url_templates.list_page_template = "https://csa-iot.org/p/".into();
+        b.schedules[0].spec = ScheduleSpec::Interval { minutes: 0 };
+        b.presets.push(b.presets[0].clone());
+        let errors = b.validate();
+        assert_eq!(errors.len(), 6, "{errors:?}");
+    }
+
+    #[test]
+    fn presets_merge_by_name_and_flags_report_differences() {
+        let mut existing = vec![SettingsPreset {
+            name: "gentle".into(),
+            description: "old".into(),
+            config: serde_json::json!({}),
+        }];
+        let b = bundle();
+        assert_eq!(merge_presets(&mut existing, &b.presets), 1);
+        assert_eq!(existing.len(), 1);
+        assert_eq!(existing[0].description, "slow and polite");
+
+        let current = BTreeMap::from([("MC_FEATURE_HTTP_CLIENT_UNIFIED".to_string(), false)]);
+        assert_eq!(b.feature_flag_differences(&current).len(), 1);
+    }
+}
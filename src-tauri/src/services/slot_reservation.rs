@@ -0,0 +1,230 @@
+//! 싱크 세션 페이지 슬롯 예약 — (page_id, index_in_page) 충돌 방지
+//!
+//! 크롤 중 사이트 목록이 밀리면 동시에 도는 페이지 워커가 같은 좌표를 계산할 수 있다. 워커는 좌표를 쓰기
+//! 전에 같은 페이지 트랜잭션 안에서 `slot_reservations` 에 (session, page_id, index_in_page) → url 을
+//! 예약한다. 유니크 인덱스가 한 세션 안의 중복 예약을 막고, 충돌은 행을 덮어쓰지 않고
+//! `SlotClaim::Collision` / `UrlElsewhere` 로 돌려줘 호출자가 SyncWarning 으로 알린다.
+//! 예약 없이 슬롯을 차지한 기존 행(이번 세션에서 아직 관측되지 않은 행)은 좌표를 비우고 `Displaced` 로
+//! 알린다. 페이지가 롤백되면 그 페이지의 예약도 함께 사라진다.
+
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::{SqliteConnection, SqlitePool};
+use tracing::debug;
+
+/// Reservation attempts when the conflicting reservation vanishes between statements
+const MAX_RESERVE_ATTEMPTS: u32 = 3;
+
+/// Outcome of `reserve`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SlotClaim {
+    /// Slot reserved (or already reserved by the same URL); write the coordinates
+    Reserved,
+    /// Slot reserved; `previous_url` held it from an earlier crawl and had its coordinates cleared
+    Displaced { previous_url: String },
+    /// Another URL of this session already holds the slot; leave the coordinates alone
+    Collision { holder_url: String },
+    /// The URL already holds a different slot in this session; leave the coordinates alone
+    UrlElsewhere { page_id: i32, index_in_page: i32 },
+}
+
+impl SlotClaim {
+    /// Whether the caller may write the slot coordinates
+    pub fn is_reserved(&self) -> bool {
+        matches!(self, SlotClaim::Reserved | SlotClaim::Displaced { .. })
+    }
+}
+
+/// Drop reservations of finished or day-old sessions
+pub async fn prune_stale(pool: &SqlitePool) -> Result<()> {
+    sqlx::query(
+        "DELETE FROM slot_reservations \
+         WHERE reserved_at < datetime('now', '-1 day') \
+            OR session_id IN (SELECT session_id FROM sync_sessions \
+                              WHERE status IN ('completed', 'aborted'))",
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Reserve (page_id, index_in_page) for `url` in `session_id` on the page transaction `conn`
+pub async fn reserve(
+    conn: &mut SqliteConnection,
+    session_id: &str,
+    url: &str,
+    page_id: i32,
+    index_in_page: i32,
+) -> Result<SlotClaim> {
+    for _ in 0..MAX_RESERVE_ATTEMPTS {
+        let inserted = sqlx::query(
+            "INSERT INTO slot_reservations (session_id, page_id, index_in_page, url) \
+             VALUES (?, ?, ?, ?) ON CONFLICT DO NOTHING",
+        )
+        .bind(session_id)
+        .bind(page_id)
+        .bind(index_in_page)
+        .bind(url)
+        .execute(&mut *conn)
+        .await?
+        .rows_affected();
+        if inserted == 1 {
+            return displace_stale_occupant(conn, url, page_id, index_in_page).await;
+        }
+
+        let holder: Option<String> = sqlx::query_scalar(
+            "SELECT url FROM slot_reservations \
+             WHERE session_id = ? AND page_id = ? AND index_in_page = ?",
+        )
+        .bind(session_id)
+        .bind(page_id)
+        .bind(index_in_page)
+        .fetch_optional(&mut *conn)
+        .await?;
+        match holder {
+            Some(holder) if holder == url => return Ok(SlotClaim::Reserved),
+            Some(holder_url) => return Ok(SlotClaim::Collision { holder_url }),
+            None => {}
+        }
+        let elsewhere: Option<(i32, i32)> = sqlx::query_as(
+            "SELECT page_id, index_in_page FROM slot_reservations \
+             WHERE session_id = ? AND url = ?",
+        )
+        .bind(session_id)
+        .bind(url)
+        .fetch_optional(&mut *conn)
+        .await?;
+        if let Some((page_id, index_in_page)) = elsewhere {
+            return Ok(SlotClaim::UrlElsewhere {
+                page_id,
+                index_in_page,
+            });
+        }
+        // The conflicting reservation was released in between; try again
+    }
+    anyhow::bail!(
+        "slot p{page_id:04}i{index_in_page:02} stayed contended after {MAX_RESERVE_ATTEMPTS} attempts"
+    )
+}
+
+/// Clear the coordinates of a row from an earlier crawl that still sits in the reserved slot
+async fn displace_stale_occupant(
+    conn: &mut SqliteConnection,
+    url: &str,
+    page_id: i32,
+    index_in_page: i32,
+) -> Result<SlotClaim> {
+    let occupant: Option<String> = sqlx::query_scalar(
+        "SELECT url FROM products WHERE page_id = ? AND index_in_page = ? AND url != ? \
+         UNION SELECT url FROM product_details WHERE page_id = ? AND index_in_page = ? AND url != ? \
+         LIMIT 1",
+    )
+    .bind(page_id)
+    .bind(index_in_page)
+    .bind(url)
+    .bind(page_id)
+    .bind(index_in_page)
+    .bind(url)
+    .fetch_optional(&mut *conn)
+    .await?;
+    let Some(previous_url) = occupant else {
+        return Ok(SlotClaim::Reserved);
+    };
+    for table in ["products", "product_details"] {
+        sqlx::query(&format!(
+            "UPDATE {table} SET page_id = NULL, index_in_page = NULL, id = NULL, \
+             updated_at = CURRENT_TIMESTAMP WHERE url = ?"
+        ))
+        .bind(&previous_url)
+        .execute(&mut *conn)
+        .await?;
+    }
+    debug!(
+        "[SlotReservation] p{:04}i{:02}: displaced {} for {}",
+        page_id, index_in_page, previous_url, url
+    );
+    Ok(SlotClaim::Displaced { previous_url })
+}
+
+/// Drop the reservations of a finished session
+pub async fn release_session(pool: &SqlitePool, session_id: &str) -> Result<u64> {
+    let removed = sqlx::query("DELETE FROM slot_reservations WHERE session_id = ?")
+        .bind(session_id)
+        .execute(pool)
+        .await?
+        .rows_affected();
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    #[tokio::test]
+    async fn reserves_once_per_session_and_reports_collisions() {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        let pool = db.pool();
+        sqlx::query(
+            "INSERT INTO products (url, page_id, index_in_page) VALUES ('https://x/old/', 3, 4)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+
+        let mut tx = pool.begin().await.unwrap();
+        let claim = reserve(&mut tx, "s1", "https://x/new/", 3, 4)
+            .await
+            .unwrap();
+        assert_eq!(
+            claim,
+            SlotClaim::Displaced {
+                previous_url: "https://x/old/".into()
+            }
+        );
+        assert_eq!(
+            reserve(&mut tx, "s1", "https://x/new/", 3, 4)
+                .await
+                .unwrap(),
+            SlotClaim::Reserved
+        );
+        let collision = reserve(&mut tx, "s1", "https://x/other/", 3, 4)
+            .await
+            .unwrap();
+        assert_eq!(
+            collision,
+            SlotClaim::Collision {
+                holder_url: "https://x/new/".into()
+            }
+        );
+        assert!(!collision.is_reserved());
+        assert_eq!(
+            reserve(&mut tx, "s1", "https://x/new/", 3, 5)
+                .await
+                .unwrap(),
+            SlotClaim::UrlElsewhere {
+                page_id: 3,
+                index_in_page: 4
+            }
+        );
+        // Other sessions reserve independently
+        assert!(
+            reserve(&mut tx, "s2", "https://x/other/", 3, 4)
+                .await
+                .unwrap()
+                .is_reserved()
+        );
+        tx.commit().await.unwrap();
+
+        let old_slot: (Option<i32>, Option<i32>) = sqlx::query_as(
+            "SELECT page_id, index_in_page FROM products WHERE url = 'https://x/old/'",
+        )
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        assert_eq!(old_slot, (None, None));
+        assert_eq!(release_session(pool, "s1").await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,212 @@
+//! 시작 시 데이터 무결성 자가 점검 (요약 배너용)
+//!
+//! 풀 초기화 직후 한 번 돌려 행 수, 12개가 아닌 페이지(최신 page_id 제외), 좌표가 빈 행,
+//! 짝 없는 상세/제품 행을 센다. 모두 집계 쿼리라 큰 DB에서도 빠르다. 결과는
+//! `DataIntegritySnapshot` 이벤트로 알리고 `get_startup_integrity_report` 가 돌려주도록 캐시한다.
+
+use crate::domain::constants::site::PRODUCTS_PER_PAGE;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::sync::RwLock;
+use std::time::Instant;
+
+/// Short pages listed in the report (the count covers all of them)
+const MAX_SAMPLE_PAGES: i64 = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageCountIssue {
+    pub page_id: i64,
+    pub count: i64,
+}
+
+/// Startup integrity summary
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataIntegritySnapshot {
+    pub checked_at: DateTime<Utc>,
+    pub elapsed_ms: u64,
+    pub products: i64,
+    pub product_details: i64,
+    pub pages: i64,
+    /// Pages other than the newest whose row count is not `PRODUCTS_PER_PAGE`
+    pub pages_with_wrong_count: i64,
+    pub wrong_count_samples: Vec<PageCountIssue>,
+    /// Products missing page_id or index_in_page
+    pub null_coordinate_products: i64,
+    pub null_coordinate_details: i64,
+    /// Details without a matching product row
+    pub orphan_details: i64,
+    /// Products not yet detailed (normal right after list-only crawls)
+    pub products_without_details: i64,
+    pub healthy: bool,
+    /// One-line banner text
+    pub summary: String,
+}
+
+static LAST_REPORT: RwLock<Option<DataIntegritySnapshot>> = RwLock::new(None);
+
+async fn count(pool: &SqlitePool, sql: &str) -> Result<i64> {
+    Ok(sqlx::query_scalar::<_, i64>(sql).fetch_one(pool).await?)
+}
+
+/// Run the check (aggregate queries only)
+pub async fn run_check(pool: &SqlitePool) -> Result<DataIntegritySnapshot> {
+    let started = Instant::now();
+    let products = count(pool, "SELECT COUNT(*) FROM products").await?;
+    let product_details = count(pool, "SELECT COUNT(*) FROM product_details").await?;
+    let pages = count(
+        pool,
+        "SELECT COUNT(DISTINCT page_id) FROM products WHERE page_id IS NOT NULL",
+    )
+    .await?;
+    // The newest page (highest page_id) is legitimately partial
+    const SHORT_PAGES: &str = "SELECT page_id, COUNT(*) AS n FROM products \
+         WHERE page_id IS NOT NULL AND page_id < (SELECT MAX(page_id) FROM products) \
+         GROUP BY page_id HAVING n != ?";
+    let pages_with_wrong_count: i64 =
+        sqlx::query_scalar(&format!("SELECT COUNT(*) FROM ({SHORT_PAGES})"))
+            .bind(PRODUCTS_PER_PAGE)
+            .fetch_one(pool)
+            .await?;
+    let wrong_count_samples: Vec<PageCountIssue> =
+        sqlx::query_as::<_, (i64, i64)>(&format!("{SHORT_PAGES} ORDER BY page_id LIMIT ?"))
+            .bind(PRODUCTS_PER_PAGE)
+            .bind(MAX_SAMPLE_PAGES)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|(page_id, count)| PageCountIssue { page_id, count })
+            .collect();
+    let null_coordinate_products = count(
+        pool,
+        "SELECT COUNT(*) FROM products WHERE page_id IS NULL OR index_in_page IS NULL",
+    )
+    .await?;
+    let null_coordinate_details = count(
+        pool,
+        "SELECT COUNT(*) FROM product_details WHERE page_id IS NULL OR index_in_page IS NULL",
+    )
+    .await?;
+    let orphan_details = count(
+        pool,
+        "SELECT COUNT(*) FROM product_details d LEFT JOIN products p ON p.url = d.url \
+         WHERE p.url IS NULL",
+    )
+    .await?;
+    let products_without_details = count(
+        pool,
+        "SELECT COUNT(*) FROM products p LEFT JOIN product_details d ON p.url = d.url \
+         WHERE d.url IS NULL",
+    )
+    .await?;
+
+    let mut issues = Vec::new();
+    if pages_with_wrong_count > 0 {
+        issues.push(format!(
+            "{pages_with_wrong_count} pages without {PRODUCTS_PER_PAGE} products"
+        ));
+    }
+    if null_coordinate_products + null_coordinate_details > 0 {
+        issues.push(format!(
+            "{} rows without coordinates",
+            null_coordinate_products + null_coordinate_details
+        ));
+    }
+    if orphan_details > 0 {
+        issues.push(format!("{orphan_details} orphan details"));
+    }
+    let healthy = issues.is_empty();
+    let summary = if healthy {
+        format!("Database OK: {products} products on {pages} pages")
+    } else {
+        format!("Database needs attention: {}", issues.join(", "))
+    };
+
+    Ok(DataIntegritySnapshot {
+        checked_at: Utc::now(),
+        elapsed_ms: started.elapsed().as_millis() as u64,
+        products,
+        product_details,
+        pages,
+        pages_with_wrong_count,
+        wrong_count_samples,
+        null_coordinate_products,
+        null_coordinate_details,
+        orphan_details,
+        products_without_details,
+        healthy,
+        summary,
+    })
+}
+
+/// Run the check and keep the result for `cached_report`
+pub async fn run_and_cache(pool: &SqlitePool) -> Result<DataIntegritySnapshot> {
+    let report = run_check(pool).await?;
+    *LAST_REPORT.write().unwrap_or_else(|e| e.into_inner()) = Some(report.clone());
+    Ok(report)
+}
+
+/// Most recent report (None until the startup check has finished)
+pub fn cached_report() -> Option<DataIntegritySnapshot> {
+    LAST_REPORT
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    #[tokio::test]
+    async fn flags_short_pages_null_coordinates_and_orphans() {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        let pool = db.pool();
+        // Page 0 short (11), page 1 newest and partial (3), one unplaced product
+        for (page_id, n) in [(0, 11), (1, 3)] {
+            for index in 0..n {
+                sqlx::query("INSERT INTO products (url, page_id, index_in_page) VALUES (?, ?, ?)")
+                    .bind(format!("https://x/p{page_id}-{index}/"))
+                    .bind(page_id)
+                    .bind(index)
+                    .execute(pool)
+                    .await
+                    .unwrap();
+            }
+        }
+        sqlx::query("INSERT INTO products (url) VALUES ('https://x/loose/')")
+            .execute(pool)
+            .await
+            .unwrap();
+        // Orphans only come from legacy / imported files; the FK would reject one here
+        let mut conn = pool.acquire().await.unwrap();
+        for sql in [
+            "PRAGMA foreign_keys = OFF",
+            "INSERT INTO product_details (url, page_id, index_in_page) \
+             VALUES ('https://x/gone/', 9, 0)",
+            "PRAGMA foreign_keys = ON",
+        ] {
+            sqlx::query(sql).execute(&mut *conn).await.unwrap();
+        }
+        drop(conn);
+
+        let report = run_and_cache(pool).await.unwrap();
+        assert_eq!((report.products, report.pages), (15, 2));
+        assert_eq!(
+            report.wrong_count_samples,
+            vec![PageCountIssue {
+                page_id: 0,
+                count: 11
+            }]
+        );
+        assert_eq!(report.null_coordinate_products, 1);
+        assert_eq!(report.orphan_details, 1);
+        assert_eq!(report.products_without_details, 15);
+        assert!(!report.healthy);
+        assert!(report.summary.contains("1 orphan details"));
+        assert_eq!(cached_report(), Some(report));
+    }
+}
@@ -0,0 +1,194 @@
+//! 싱크 예상 완료 시각 (`SyncStarted` / `SyncPageCompleted` 의 `estimated_finish_at`)
+//!
+//! SyncStarted 는 `rate_limit` 만 싣고 예상 소요 시간은 없었다. 시작 시에는 과거 싱크의 페이지당
+//! 소요 시간(`sync_page_timings` EWMA)을 동시성으로 나눈 값과 rate limit 하한 중 큰 쪽으로
+//! 추정하고, 페이지가 끝날 때마다 이번 세션의 페이지 시간과 실제 처리 속도 쪽으로 옮겨 간다.
+
+use crate::crawl_engine::runtime::latency::{self, LatencyOp};
+use crate::domain::constants::site::PRODUCTS_PER_PAGE;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Page time assumed before any sync has been measured
+const DEFAULT_PAGE_MS: f64 = 4000.0;
+/// One list request plus a detail fetch per product
+const REQUESTS_PER_PAGE: f64 = 1.0 + PRODUCTS_PER_PAGE as f64;
+/// Weight, in pages, of the historical page time against this session's pages
+const PRIOR_WEIGHT: f64 = 4.0;
+/// Share of a finished session's mean page time folded into the history
+const HISTORY_ALPHA: f64 = 0.3;
+
+/// Pages covered by `(start_oldest, end_newest)` ranges
+pub fn page_count(ranges: &[(u32, u32)]) -> u32 {
+    ranges.iter().map(|(a, b)| a.abs_diff(*b) + 1).sum()
+}
+
+/// Running finish-time estimate of one sync session
+#[derive(Debug, Clone)]
+pub struct SyncEta {
+    total_pages: u32,
+    concurrency: u32,
+    rate_limit: Option<u32>,
+    prior_page_ms: f64,
+    completed: u32,
+    page_ms_sum: u64,
+    started: Instant,
+}
+
+impl SyncEta {
+    pub fn new(
+        total_pages: u32,
+        concurrency: usize,
+        rate_limit: Option<u32>,
+        prior_page_ms: Option<f64>,
+    ) -> Self {
+        Self {
+            total_pages,
+            concurrency: (concurrency as u32).max(1),
+            rate_limit: rate_limit.filter(|rps| *rps > 0),
+            prior_page_ms: prior_page_ms.unwrap_or(DEFAULT_PAGE_MS),
+            completed: 0,
+            page_ms_sum: 0,
+            started: Instant::now(),
+        }
+    }
+
+    pub fn shared(self) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(self))
+    }
+
+    /// Page set changed after start (resume, clamping to the site's page count)
+    pub fn set_total_pages(&mut self, total_pages: u32) {
+        self.total_pages = total_pages;
+    }
+
+    /// Pages queued again (end-of-session retry pass)
+    pub fn add_pages(&mut self, pages: u32) {
+        self.total_pages += pages;
+    }
+
+    pub fn record_page(&mut self, ms: u64) {
+        self.completed += 1;
+        self.page_ms_sum += ms;
+    }
+
+    /// Expected time left once `elapsed` of wall clock has passed
+    pub fn remaining(&self, elapsed: Duration) -> Duration {
+        let remaining = f64::from(self.total_pages.saturating_sub(self.completed));
+        if remaining == 0.0 {
+            return Duration::ZERO;
+        }
+        let completed = f64::from(self.completed);
+        let concurrency = f64::from(self.concurrency);
+        let page_ms = (self.prior_page_ms * PRIOR_WEIGHT + self.page_ms_sum as f64)
+            / (PRIOR_WEIGHT + completed);
+        let mut ms = remaining * page_ms / concurrency;
+        if let Some(rps) = self.rate_limit {
+            ms = ms.max(remaining * REQUESTS_PER_PAGE * 1000.0 / f64::from(rps));
+        }
+        // Wall-clock throughput also captures rate-limit waits and retries; trust it more
+        // as pages finish
+        if self.completed >= self.concurrency {
+            let observed = elapsed.as_millis() as f64 / completed * remaining;
+            let weight = completed / (completed + concurrency);
+            ms = observed * weight + ms * (1.0 - weight);
+        }
+        Duration::from_millis(ms.round() as u64)
+    }
+
+    pub fn estimated_finish_at(&self) -> DateTime<Utc> {
+        let remaining = self.remaining(self.started.elapsed());
+        Utc::now() + chrono::Duration::from_std(remaining).unwrap_or(chrono::Duration::zero())
+    }
+}
+
+/// Count a finished page and return the refined finish time
+pub fn page_completed(eta: &Mutex<SyncEta>, ms: u64) -> Option<DateTime<Utc>> {
+    let mut eta = eta.lock().ok()?;
+    eta.record_page(ms);
+    Some(eta.estimated_finish_at())
+}
+
+/// Per-page time of past syncs (EWMA of session means); None before the first sync
+pub async fn historical_page_ms(pool: &SqlitePool) -> Result<Option<f64>> {
+    let ewma: Option<f64> =
+        sqlx::query_scalar("SELECT ewma_ms FROM sync_page_timings WHERE id = 1")
+            .fetch_optional(pool)
+            .await?;
+    Ok(ewma)
+}
+
+/// Fold the session's mean page time (from its latency stats) into the history
+pub async fn remember_session(pool: &SqlitePool, session_id: &str) -> Result<()> {
+    let Some(mean_ms) = latency::session_stats(session_id)
+        .into_iter()
+        .find(|s| s.op == LatencyOp::SyncPage && s.stage.is_none() && s.summary.count > 0)
+        .map(|s| s.summary.mean_ms)
+    else {
+        return Ok(());
+    };
+    sqlx::query(
+        "INSERT INTO sync_page_timings (id, ewma_ms, sessions, updated_at) VALUES (1, ?, 1, ?) \
+         ON CONFLICT(id) DO UPDATE SET \
+            ewma_ms = ewma_ms * (1 - ?) + excluded.ewma_ms * ?,\
+            sessions = sessions + 1,\
+            updated_at = excluded.updated_at",
+    )
+    .bind(mean_ms)
+    .bind(Utc::now().to_rfc3339())
+    .bind(HISTORY_ALPHA)
+    .bind(HISTORY_ALPHA)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// History lookup for a starting sync; failures only cost accuracy
+pub async fn prior_page_ms(pool: &SqlitePool) -> Option<f64> {
+    match historical_page_ms(pool).await {
+        Ok(prior) => prior,
+        Err(e) => {
+            warn!("Sync page timing history unavailable: {}", e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_respects_rate_limit_and_converges_to_observed_pace() {
+        assert_eq!(page_count(&[(498, 492), (489, 489)]), 8);
+
+        // 20 pages, 2s/page history, 4 workers -> 10s; a 1 rps limit needs 13 requests a page
+        let eta = SyncEta::new(20, 4, None, Some(2000.0));
+        assert_eq!(eta.remaining(Duration::ZERO), Duration::from_secs(10));
+        let limited = SyncEta::new(20, 4, Some(1), Some(2000.0));
+        assert_eq!(limited.remaining(Duration::ZERO), Duration::from_secs(260));
+
+        // Pages run 4s here and the session is already slower than modeled
+        let mut eta = SyncEta::new(20, 4, None, Some(2000.0));
+        for _ in 0..10 {
+            eta.record_page(4000);
+        }
+        let modeled_ms = 10.0 * ((2000.0 * 4.0 + 40_000.0) / 14.0) / 4.0;
+        let observed_ms = 30_000.0 / 10.0 * 10.0;
+        let expected = observed_ms * (10.0 / 14.0) + modeled_ms * (4.0 / 14.0);
+        let remaining = eta.remaining(Duration::from_secs(30)).as_millis() as f64;
+        assert!(
+            (remaining - expected).abs() < 1.0,
+            "{remaining} vs {expected}"
+        );
+
+        for _ in 0..10 {
+            eta.record_page(4000);
+        }
+        assert_eq!(eta.remaining(Duration::from_secs(60)), Duration::ZERO);
+    }
+}
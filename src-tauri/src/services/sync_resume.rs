@@ -0,0 +1,137 @@
+//! 일시정지된 싱크 세션의 남은 페이지 집합
+//!
+//! 일시정지를 지원하는 싱크는 시작할 때 실행할 페이지 전체를 `sync_remaining_pages`에 기록하고,
+//! 페이지가 끝날 때마다(`SyncPageCompleted`) 지운다. 일시정지 후 남은 행이 곧 재개할 페이지다.
+//! 페이지는 캐논컬 `page_id`로 저장해 그사이 사이트에 제품이 추가돼도 재개 시점의 `total_pages`로
+//! 물리 페이지를 다시 계산한다(`page_revalidation`과 같은 규칙).
+
+use crate::services::page_revalidation::current_physical_page;
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::SqlitePool;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PausedSync {
+    pub session_id: String,
+    pub remaining_pages: u32,
+    /// Original coverage of the session (`498-492,489`)
+    pub coverage_text: Option<String>,
+}
+
+fn page_id(physical_page: u32, total_pages: u32) -> i64 {
+    total_pages as i64 - physical_page as i64
+}
+
+/// Record the session's full page set (pages already recorded are kept)
+pub async fn record_plan(
+    pool: &SqlitePool,
+    session_id: &str,
+    total_pages: u32,
+    pages: &[u32],
+) -> Result<()> {
+    let mut tx = pool.begin().await?;
+    for &page in pages {
+        sqlx::query(
+            "INSERT OR IGNORE INTO sync_remaining_pages (session_id, page_id) VALUES (?, ?)",
+        )
+        .bind(session_id)
+        .bind(page_id(page, total_pages))
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+    Ok(())
+}
+
+pub async fn mark_done(
+    pool: &SqlitePool,
+    session_id: &str,
+    total_pages: u32,
+    physical_page: u32,
+) -> Result<()> {
+    sqlx::query("DELETE FROM sync_remaining_pages WHERE session_id = ? AND page_id = ?")
+        .bind(session_id)
+        .bind(page_id(physical_page, total_pages))
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Physical pages still to run, oldest first; pages that fell off the site are dropped
+pub async fn remaining(pool: &SqlitePool, session_id: &str, total_pages: u32) -> Result<Vec<u32>> {
+    let page_ids: Vec<i64> = sqlx::query_scalar(
+        "SELECT page_id FROM sync_remaining_pages WHERE session_id = ? ORDER BY page_id",
+    )
+    .bind(session_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(page_ids
+        .into_iter()
+        .filter_map(|id| current_physical_page(id, total_pages))
+        .collect())
+}
+
+pub async fn remaining_count(pool: &SqlitePool, session_id: &str) -> Result<u32> {
+    let count: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM sync_remaining_pages WHERE session_id = ?")
+            .bind(session_id)
+            .fetch_one(pool)
+            .await?;
+    Ok(count as u32)
+}
+
+/// Forget the session's page set (completed, aborted or never paused)
+pub async fn clear(pool: &SqlitePool, session_id: &str) -> Result<()> {
+    sqlx::query("DELETE FROM sync_remaining_pages WHERE session_id = ?")
+        .bind(session_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Sessions in `paused` state with their remaining page counts, newest first
+pub async fn paused_sessions(pool: &SqlitePool) -> Result<Vec<PausedSync>> {
+    let rows: Vec<(String, i64, Option<String>)> = sqlx::query_as(
+        "SELECT s.session_id, \
+                (SELECT COUNT(*) FROM sync_remaining_pages r WHERE r.session_id = s.session_id), \
+                s.coverage_text \
+         FROM sync_sessions s WHERE s.status = 'paused' ORDER BY s.started_at DESC",
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|(session_id, remaining, coverage_text)| PausedSync {
+            session_id,
+            remaining_pages: remaining as u32,
+            coverage_text,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    #[tokio::test]
+    async fn remaining_pages_follow_site_growth() {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        let pool = db.pool().clone();
+        record_plan(&pool, "sync-a", 500, &[21, 20, 19, 18])
+            .await
+            .unwrap();
+        mark_done(&pool, "sync-a", 500, 21).await.unwrap();
+        mark_done(&pool, "sync-a", 500, 20).await.unwrap();
+        // Re-recording keeps the finished pages finished
+        record_plan(&pool, "sync-a", 500, &[19]).await.unwrap();
+        assert_eq!(remaining(&pool, "sync-a", 500).await.unwrap(), vec![19, 18]);
+        // Three new pages on the site shift every physical page by three
+        assert_eq!(remaining(&pool, "sync-a", 503).await.unwrap(), vec![22, 21]);
+        assert_eq!(remaining_count(&pool, "sync-a").await.unwrap(), 2);
+
+        clear(&pool, "sync-a").await.unwrap();
+        assert!(remaining(&pool, "sync-a", 500).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,159 @@
+//! 저장된 제품 URL 을 정규형(`canonical_product_url`)으로 일괄 변환 (일회성 마이그레이션)
+//!
+//! 쓰기 경로는 모두 정규형으로 저장하지만, 그 전에 쌓인 행에는 호스트 대소문자 / 끝 슬래시 / 추적 파라미터가
+//! 다른 URL 이 남아 있다. products / product_details 의 URL 을 정규형으로 옮기고, 정규형 URL 에 이미 행이
+//! 있으면 그 행으로 병합한다 (빈 필드만 채움, 부속 테이블 포함 — `product_identity::fold`).
+//! 전체가 한 트랜잭션이며 dry-run 은 같은 작업을 수행한 뒤 롤백해 집계만 돌려준다.
+
+use crate::domain::product_url::canonical_product_url;
+use crate::infrastructure::product_identity;
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::collections::BTreeMap;
+use tracing::info;
+
+/// Rewrites listed in a report; the counts cover all of them
+const MAX_REPORTED_REWRITES: usize = 100;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UrlRewrite {
+    pub from: String,
+    pub to: String,
+    /// `to` already held a row (or another variant got there first)
+    pub merged: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UrlNormalizationReport {
+    pub dry_run: bool,
+    pub urls_scanned: usize,
+    /// URLs moved to their canonical form
+    pub rewritten: usize,
+    /// Of those, URLs merged into an existing canonical row
+    pub merged: usize,
+    /// First `MAX_REPORTED_REWRITES` rewrites
+    pub rewrites: Vec<UrlRewrite>,
+    /// Backup taken before a non-dry-run migration
+    pub backup_file: Option<String>,
+}
+
+/// Move every non-canonical URL to its canonical form, merging collisions
+pub async fn normalize_stored_urls(
+    pool: &SqlitePool,
+    dry_run: bool,
+) -> Result<UrlNormalizationReport> {
+    let urls: Vec<String> = sqlx::query_scalar(
+        "SELECT url FROM products UNION SELECT url FROM product_details ORDER BY url",
+    )
+    .fetch_all(pool)
+    .await?;
+    let mut report = UrlNormalizationReport {
+        dry_run,
+        urls_scanned: urls.len(),
+        ..Default::default()
+    };
+
+    // Canonical URL → stored variants; a variant equal to its canonical form stays put
+    let mut groups: BTreeMap<String, (bool, Vec<String>)> = BTreeMap::new();
+    for url in urls {
+        let canonical = canonical_product_url(&url);
+        let group = groups.entry(canonical.clone()).or_default();
+        if canonical == url {
+            group.0 = true;
+        } else {
+            group.1.push(url);
+        }
+    }
+    groups.retain(|_, (_, variants)| !variants.is_empty());
+    if groups.is_empty() {
+        return Ok(report);
+    }
+
+    let mut tx = pool.begin().await?;
+    product_identity::defer_foreign_keys(&mut tx).await?;
+    let mut touched = Vec::new();
+    for (canonical, (canonical_stored, variants)) in groups {
+        for (i, from) in variants.into_iter().enumerate() {
+            // The first variant of a group without a canonical row is moved, later ones merge
+            let merged = canonical_stored || i > 0;
+            product_identity::fold(&mut tx, &from, &canonical).await?;
+            report.rewritten += 1;
+            if merged {
+                report.merged += 1;
+            }
+            if report.rewrites.len() < MAX_REPORTED_REWRITES {
+                report.rewrites.push(UrlRewrite {
+                    from: from.clone(),
+                    to: canonical.clone(),
+                    merged,
+                });
+            }
+            touched.push(from);
+        }
+        touched.push(canonical);
+    }
+    if dry_run {
+        tx.rollback().await?;
+    } else {
+        tx.commit().await?;
+        product_identity::refresh_search_index(pool, &touched).await;
+    }
+    info!(
+        "🔗 [UrlNormalization] {} of {} URLs rewritten ({} merged){}",
+        report.rewritten,
+        report.urls_scanned,
+        report.merged,
+        if dry_run { " (dry run)" } else { "" }
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    #[tokio::test]
+    async fn moves_variants_to_canonical_urls_and_merges_collisions() {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        let pool = db.pool();
+        for (url, model) in [
+            ("https://x.test/p/a/", Some("A")),
+            ("https://X.test/p/a?utm_source=mail", None),
+            ("https://x.test/p/b", Some("B")),
+        ] {
+            sqlx::query("INSERT INTO products (url, model) VALUES (?, ?)")
+                .bind(url)
+                .bind(model)
+                .execute(pool)
+                .await
+                .unwrap();
+        }
+        sqlx::query("INSERT INTO product_details (url, vid) VALUES ('https://X.test/p/a?utm_source=mail', 7)")
+            .execute(pool)
+            .await
+            .unwrap();
+
+        let dry = normalize_stored_urls(pool, true).await.unwrap();
+        assert_eq!((dry.urls_scanned, dry.rewritten, dry.merged), (3, 2, 1));
+
+        normalize_stored_urls(pool, false).await.unwrap();
+        let urls: Vec<String> = sqlx::query_scalar("SELECT url FROM products ORDER BY url")
+            .fetch_all(pool)
+            .await
+            .unwrap();
+        assert_eq!(urls, vec!["https://x.test/p/a/", "https://x.test/p/b/"]);
+        let vid: Option<i32> =
+            sqlx::query_scalar("SELECT vid FROM product_details WHERE url = 'https://x.test/p/a/'")
+                .fetch_one(pool)
+                .await
+                .unwrap();
+        assert_eq!(vid, Some(7));
+        assert_eq!(
+            normalize_stored_urls(pool, true).await.unwrap().rewritten,
+            0
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! 검증 결과(validation_findings) 기반 자동 복구 계획
+//!
+//! 열린 finding 의 캐논컬 좌표 (page_id, index_in_page) 를 현재 사이트 상태(총 페이지 수 / 마지막 페이지
+//! 제품 수)로 물리 페이지와 페이지 내 위치로 바꾸고, 페이지별 인덱스 집합으로 묶어
+//! `start_diagnostic_sync` 입력을 만든다. 좌표가 없거나 사이트 범위를 벗어난 finding 은 따로 보고한다.
+//!
+//! 좌표 선택: products 쪽 좌표가 기준이며, products 행이 없는 경우(orphan detail, detail 중복 슬롯)만
+//! product_details 좌표를 쓴다.
+
+use crate::crawl_engine::validation::findings::StoredFinding;
+use crate::domain::pagination::CanonicalPageIdCalculator;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Slots to re-sync on one physical page
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct RepairPage {
+    pub physical_page: u32,
+    /// 0-based positions on the page (0 = newest)
+    pub indices: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationRepairPlan {
+    pub total_pages: u32,
+    pub items_on_last_page: u32,
+    /// Open findings the plan was built from
+    pub findings_considered: usize,
+    /// Newest page first
+    pub pages: Vec<RepairPage>,
+    pub slots: usize,
+    /// Finding ids without coordinates or outside the current site
+    pub unmapped: Vec<i64>,
+}
+
+/// Canonical slot a finding should be re-synced at
+fn finding_slot(finding: &StoredFinding) -> Option<(i64, i64)> {
+    match (finding.page_id, finding.index_in_page) {
+        (Some(page_id), Some(index)) => Some((page_id, index)),
+        _ => finding.detail_page_id.zip(finding.detail_index_in_page),
+    }
+}
+
+/// Group `findings` into minimal per-page index sets for the current site layout
+pub fn plan_repairs(
+    findings: &[StoredFinding],
+    total_pages: u32,
+    items_on_last_page: u32,
+) -> ValidationRepairPlan {
+    let calculator = CanonicalPageIdCalculator::new(total_pages, items_on_last_page as usize);
+    let mut by_page: BTreeMap<u32, BTreeSet<u32>> = BTreeMap::new();
+    let mut unmapped = Vec::new();
+    for finding in findings {
+        let position = finding_slot(finding).and_then(|(page_id, index)| {
+            calculator.physical_position(i32::try_from(page_id).ok()?, i32::try_from(index).ok()?)
+        });
+        match position {
+            Some((page, index)) => {
+                by_page.entry(page).or_default().insert(index as u32);
+            }
+            None => unmapped.push(finding.id),
+        }
+    }
+    let pages: Vec<RepairPage> = by_page
+        .into_iter()
+        .map(|(physical_page, indices)| RepairPage {
+            physical_page,
+            indices: indices.into_iter().collect(),
+        })
+        .collect();
+    ValidationRepairPlan {
+        total_pages,
+        items_on_last_page,
+        findings_considered: findings.len(),
+        slots: pages.iter().map(|p| p.indices.len()).sum(),
+        pages,
+        unmapped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(
+        id: i64,
+        products: Option<(i64, i64)>,
+        details: Option<(i64, i64)>,
+    ) -> StoredFinding {
+        StoredFinding {
+            id,
+            run_id: "xcheck-test".into(),
+            kind: "coord_mismatch".into(),
+            table_name: "product_details".into(),
+            url: format!("https://x/{id}"),
+            page_id: products.map(|p| p.0),
+            index_in_page: products.map(|p| p.1),
+            detail_page_id: details.map(|d| d.0),
+            detail_index_in_page: details.map(|d| d.1),
+            repair: "copy_coordinates".into(),
+            status: "open".into(),
+            created_at: String::new(),
+            resolved_at: None,
+        }
+    }
+
+    #[test]
+    fn groups_findings_into_page_index_sets() {
+        // 3 pages, 4 products on the last: page 3 holds page_id 0 slots 3..0
+        let findings = vec![
+            finding(1, Some((0, 0)), Some((0, 5))),
+            finding(2, None, Some((0, 3))),
+            finding(3, Some((0, 0)), None),
+            finding(4, Some((2, 3)), None),
+            finding(5, None, None),
+            // Past the newest product
+            finding(6, Some((2, 4)), None),
+        ];
+        let plan = plan_repairs(&findings, 3, 4);
+        assert_eq!(
+            plan.pages,
+            vec![
+                RepairPage {
+                    physical_page: 1,
+                    indices: vec![0]
+                },
+                RepairPage {
+                    physical_page: 3,
+                    indices: vec![0, 3]
+                },
+            ]
+        );
+        assert_eq!(plan.slots, 3);
+        assert_eq!(plan.unmapped, vec![5, 6]);
+    }
+}
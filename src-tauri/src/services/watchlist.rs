@@ -0,0 +1,372 @@
+//! 사용자 watchlist (관심 제품/제조사)
+//!
+//! 관심 대상은 `annotations` 테이블에 `kind = 'watch'` 로 저장한다 (target_type: product = URL,
+//! manufacturer = 제조사명, 대소문자/공백 무시). 관심 제품은 rolling-refresh 계획에서 매 실행
+//! 포함되고, 스냅샷(`watchlist_snapshots`)과 비교해 필드 단위 diff 를 만들어 알림으로 내보낸다.
+
+use anyhow::Result;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::collections::{BTreeMap, BTreeSet};
+
+const WATCH_KIND: &str = "watch";
+
+/// product_details columns compared between snapshots
+const TRACKED_FIELDS: &[&str] = &[
+    "manufacturer",
+    "model",
+    "device_type",
+    "certificate_id",
+    "certification_date",
+    "software_version",
+    "hardware_version",
+    "firmware_version",
+    "specification_version",
+    "vid",
+    "pid",
+    "family_sku",
+    "family_variant_sku",
+    "family_id",
+    "tis_trp_tested",
+    "transport_interface",
+    "primary_device_type_id",
+    "application_categories",
+    "description",
+    "compliance_document_url",
+    "program_type",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "target_type", content = "target", rename_all = "snake_case")]
+pub enum WatchTarget {
+    /// Product detail URL
+    Product(String),
+    /// Manufacturer name (matched case-insensitively)
+    Manufacturer(String),
+}
+
+impl WatchTarget {
+    fn parts(&self) -> (&'static str, String) {
+        match self {
+            WatchTarget::Product(url) => ("product", url.trim().to_string()),
+            WatchTarget::Manufacturer(name) => ("manufacturer", name.trim().to_string()),
+        }
+    }
+
+    fn from_parts(target_type: &str, target: String) -> Option<Self> {
+        match target_type {
+            "product" => Some(WatchTarget::Product(target)),
+            "manufacturer" => Some(WatchTarget::Manufacturer(target)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchEntry {
+    pub id: i64,
+    #[serde(flatten)]
+    pub target: WatchTarget,
+    pub note: Option<String>,
+    pub created_at: String,
+    /// Products currently covered by this entry
+    pub matched_products: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchedProductChange {
+    pub url: String,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WatchlistCheckReport {
+    pub watched_products: u32,
+    /// Watched products seen for the first time (snapshot taken, no diff)
+    pub baselined: u32,
+    pub changed: Vec<WatchedProductChange>,
+}
+
+/// Add (or re-note) a watch entry; returns its id
+pub async fn add_watch(
+    pool: &SqlitePool,
+    target: &WatchTarget,
+    note: Option<String>,
+) -> Result<i64> {
+    let (target_type, target) = target.parts();
+    if target.is_empty() {
+        anyhow::bail!("watch target must not be empty");
+    }
+    let id: i64 = sqlx::query_scalar(
+        "INSERT INTO annotations (target_type, target, kind, note, created_at) VALUES (?, ?, ?, ?, ?) \
+         ON CONFLICT(target_type, target, kind) DO UPDATE SET note = COALESCE(excluded.note, annotations.note) \
+         RETURNING id",
+    )
+    .bind(target_type)
+    .bind(&target)
+    .bind(WATCH_KIND)
+    .bind(note)
+    .bind(Utc::now().to_rfc3339())
+    .fetch_one(pool)
+    .await?;
+    Ok(id)
+}
+
+/// Remove a watch entry; returns whether it existed
+pub async fn remove_watch(pool: &SqlitePool, target: &WatchTarget) -> Result<bool> {
+    let (target_type, target) = target.parts();
+    let removed =
+        sqlx::query("DELETE FROM annotations WHERE target_type = ? AND target = ? AND kind = ?")
+            .bind(target_type)
+            .bind(&target)
+            .bind(WATCH_KIND)
+            .execute(pool)
+            .await?
+            .rows_affected();
+    Ok(removed > 0)
+}
+
+pub async fn list_watches(pool: &SqlitePool) -> Result<Vec<WatchEntry>> {
+    let rows = sqlx::query(
+        "SELECT a.id, a.target_type, a.target, a.note, a.created_at, \
+            CASE a.target_type \
+              WHEN 'product' THEN (SELECT COUNT(*) FROM products p WHERE p.url = a.target) \
+              ELSE (SELECT COUNT(*) FROM product_details pd \
+                    WHERE LOWER(TRIM(pd.manufacturer)) = LOWER(a.target)) \
+            END AS matched \
+         FROM annotations a WHERE a.kind = ? ORDER BY a.created_at DESC",
+    )
+    .bind(WATCH_KIND)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .filter_map(|r| {
+            let target_type: String = r.get("target_type");
+            Some(WatchEntry {
+                id: r.get("id"),
+                target: WatchTarget::from_parts(&target_type, r.get("target"))?,
+                note: r.get("note"),
+                created_at: r.get("created_at"),
+                matched_products: r.get("matched"),
+            })
+        })
+        .collect())
+}
+
+/// SQL yielding every watched product URL (explicit products + manufacturer matches)
+const WATCHED_URLS_SQL: &str = "SELECT target AS url FROM annotations \
+        WHERE kind = 'watch' AND target_type = 'product' \
+     UNION \
+     SELECT pd.url AS url FROM product_details pd \
+        WHERE LOWER(TRIM(pd.manufacturer)) IN (SELECT LOWER(target) FROM annotations \
+            WHERE kind = 'watch' AND target_type = 'manufacturer')";
+
+pub async fn watched_urls(pool: &SqlitePool) -> Result<Vec<String>> {
+    let urls: Vec<String> = sqlx::query_scalar(WATCHED_URLS_SQL).fetch_all(pool).await?;
+    Ok(urls)
+}
+
+/// Canonical page_ids holding watched products (planner input)
+pub async fn watched_page_ids(pool: &SqlitePool) -> Result<BTreeSet<i64>> {
+    let ids: Vec<i64> = sqlx::query_scalar(&format!(
+        "SELECT DISTINCT page_id FROM products \
+         WHERE page_id IS NOT NULL AND url IN ({WATCHED_URLS_SQL})"
+    ))
+    .fetch_all(pool)
+    .await?;
+    Ok(ids.into_iter().collect())
+}
+
+/// Compare watched products against their last snapshot and roll the snapshot forward
+pub async fn check_changes(pool: &SqlitePool) -> Result<WatchlistCheckReport> {
+    let columns = TRACKED_FIELDS
+        .iter()
+        .map(|f| format!("CAST(pd.{f} AS TEXT) AS {f}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let rows = sqlx::query(&format!(
+        "SELECT pd.url AS url, {columns}, s.fields AS snapshot \
+         FROM product_details pd LEFT JOIN watchlist_snapshots s ON s.url = pd.url \
+         WHERE pd.url IN ({WATCHED_URLS_SQL})"
+    ))
+    .fetch_all(pool)
+    .await?;
+
+    let mut report = WatchlistCheckReport {
+        watched_products: rows.len() as u32,
+        ..Default::default()
+    };
+    let now = Utc::now().to_rfc3339();
+    let mut tx = pool.begin().await?;
+    for row in rows {
+        let url: String = row.get("url");
+        let current: BTreeMap<String, Option<String>> = TRACKED_FIELDS
+            .iter()
+            .map(|f| (f.to_string(), row.get::<Option<String>, _>(*f)))
+            .collect();
+        let previous: Option<BTreeMap<String, Option<String>>> = row
+            .get::<Option<String>, _>("snapshot")
+            .and_then(|raw| serde_json::from_str(&raw).ok());
+        match previous {
+            None => report.baselined += 1,
+            Some(previous) => {
+                let changes = diff_fields(&previous, &current);
+                if changes.is_empty() {
+                    continue;
+                }
+                report.changed.push(WatchedProductChange {
+                    url: url.clone(),
+                    manufacturer: current.get("manufacturer").cloned().flatten(),
+                    model: current.get("model").cloned().flatten(),
+                    changes,
+                });
+            }
+        }
+        sqlx::query(
+            "INSERT INTO watchlist_snapshots (url, fields, captured_at) VALUES (?, ?, ?) \
+             ON CONFLICT(url) DO UPDATE SET fields = excluded.fields, captured_at = excluded.captured_at",
+        )
+        .bind(&url)
+        .bind(serde_json::to_string(&current)?)
+        .bind(&now)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+    Ok(report)
+}
+
+/// Field-level diff; fields missing from an older snapshot count as None
+fn diff_fields(
+    previous: &BTreeMap<String, Option<String>>,
+    current: &BTreeMap<String, Option<String>>,
+) -> Vec<FieldChange> {
+    current
+        .iter()
+        .filter_map(|(field, new)| {
+            let old = previous.get(field).cloned().flatten();
+            let normalize = |v: &Option<String>| {
+                v.as_deref()
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+            };
+            (normalize(&old) != normalize(new)).then(|| FieldChange {
+                field: field.clone(),
+                old,
+                new: new.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database_connection::DatabaseConnection;
+
+    fn fields(pairs: &[(&str, Option<&str>)]) -> BTreeMap<String, Option<String>> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.map(str::to_string)))
+            .collect()
+    }
+
+    #[test]
+    fn diff_ignores_whitespace_and_empty_values() {
+        let old = fields(&[
+            ("model", Some("A1")),
+            ("firmware_version", Some("1.0")),
+            ("description", None),
+        ]);
+        let new = fields(&[
+            ("model", Some("A1 ")),
+            ("firmware_version", Some("1.1")),
+            ("description", Some("")),
+            ("family_id", Some("F")),
+        ]);
+        let diff = diff_fields(&old, &new);
+        assert_eq!(
+            diff,
+            vec![
+                FieldChange {
+                    field: "family_id".into(),
+                    old: None,
+                    new: Some("F".into()),
+                },
+                FieldChange {
+                    field: "firmware_version".into(),
+                    old: Some("1.0".into()),
+                    new: Some("1.1".into()),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn manufacturer_watch_reports_changes_after_baseline() {
+        let db = DatabaseConnection::new("sqlite::memory:").await.unwrap();
+        db.migrate().await.unwrap();
+        let pool = db.pool().clone();
+        for (url, page_id, maker, fw) in [
+            ("https://x/p/1", 7, "Acme", "1.0"),
+            ("https://x/p/2", 3, "Other", "2.0"),
+        ] {
+            sqlx::query("INSERT INTO products (url, page_id) VALUES (?, ?)")
+                .bind(url)
+                .bind(page_id)
+                .execute(&pool)
+                .await
+                .unwrap();
+            sqlx::query(
+                "INSERT INTO product_details (url, manufacturer, firmware_version) VALUES (?, ?, ?)",
+            )
+            .bind(url)
+            .bind(maker)
+            .bind(fw)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        add_watch(&pool, &WatchTarget::Manufacturer(" acme ".into()), None)
+            .await
+            .unwrap();
+        assert_eq!(watched_urls(&pool).await.unwrap(), vec!["https://x/p/1"]);
+        assert_eq!(watched_page_ids(&pool).await.unwrap(), BTreeSet::from([7]));
+        let entries = list_watches(&pool).await.unwrap();
+        assert_eq!(entries[0].matched_products, 1);
+
+        let first = check_changes(&pool).await.unwrap();
+        assert_eq!((first.baselined, first.changed.len()), (1, 0));
+
+        sqlx::query(
+            "UPDATE product_details SET firmware_version = '1.1' WHERE url = 'https://x/p/1'",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let second = check_changes(&pool).await.unwrap();
+        assert_eq!(second.changed.len(), 1);
+        assert_eq!(second.changed[0].changes[0].field, "firmware_version");
+        assert!(check_changes(&pool).await.unwrap().changed.is_empty());
+
+        assert!(
+            remove_watch(&pool, &WatchTarget::Manufacturer("acme".into()))
+                .await
+                .unwrap()
+        );
+        assert!(watched_urls(&pool).await.unwrap().is_empty());
+    }
+}
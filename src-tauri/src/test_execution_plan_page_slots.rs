@@ -1,6 +1,7 @@
 use crate::crawl_engine::actors::types::{
     ExecutionPlan, ExecutionPlanKpi, PageRange, PageSlot, PlanInputSnapshot,
 };
+use crate::crawl_engine::runtime::crawl_limits::CrawlLimits;
 use chrono::Utc;
 
 // Focused unit tests for page_slots invariants.
@@ -80,6 +81,8 @@ mod tests {
             input_snapshot: snapshot,
             plan_hash: "hash".into(),
             skip_duplicate_urls: true,
+            stage_flags: Vec::new(),
+            limits: CrawlLimits::default(),
             kpi_meta: Some(ExecutionPlanKpi {
                 total_ranges: 1,
                 total_pages: total_site_pages,
@@ -9,6 +9,9 @@ use crate::infrastructure::{DatabaseConnection, IntegratedProductRepository};
 use anyhow::Result;
 use std::sync::Arc;
 
+pub mod mock_site; // Canned list/detail HTML fixtures (mock-site feature / simulated crawls)
+pub mod scripted_services; // Scriptable StatusChecker / list / detail collector doubles for actor tests
+
 /// Test database configuration
 pub struct TestDatabase {
     pub connection: DatabaseConnection,
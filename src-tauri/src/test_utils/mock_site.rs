@@ -0,0 +1,282 @@
+//! Canned listing/detail HTML for a fake certification site
+//!
+//! Pages follow the csa-iot markup the extractors expect (`div.post-feed article.type-product`,
+//! `div.entry-product-details li.item`). Content is a pure function of the product sequence
+//! number, so the same configuration always yields the same site. Listing pages live at the
+//! configured URL templates; product pages live under `MOCK_ORIGIN` so simulated rows are easy
+//! to tell apart from real ones.
+
+use crate::domain::constants::site::PRODUCTS_PER_PAGE;
+use crate::infrastructure::config::UrlTemplateConfig;
+
+/// Origin of every simulated product URL
+pub const MOCK_ORIGIN: &str = "https://mock-site.invalid";
+
+const MANUFACTURERS: &[&str] = &[
+    "Acme Home",
+    "Globex Devices",
+    "Initech Labs",
+    "Umbrella Lighting",
+    "Soylent Sensors",
+    "Hooli Connect",
+    "Stark Appliances",
+];
+
+const DEVICE_TYPES: &[&str] = &[
+    "Extended Color Light",
+    "On/Off Plug-in Unit",
+    "Contact Sensor",
+    "Thermostat",
+    "Door Lock",
+];
+
+const TRANSPORTS: &[&str] = &["Wi-Fi", "Thread", "Ethernet"];
+
+/// What a requested URL resolves to on the mock site
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockRoute {
+    /// Listing page (1 = newest); pages past the end have no products
+    ListPage(u32),
+    /// Product detail page by sequence (0 = oldest product)
+    Product(u32),
+    NotFound,
+}
+
+#[derive(Debug, Clone)]
+pub struct MockSite {
+    pub urls: UrlTemplateConfig,
+    pub total_pages: u32,
+    /// Products on the oldest (last) page, `1..=PRODUCTS_PER_PAGE`
+    pub products_on_last_page: u32,
+}
+
+impl MockSite {
+    pub fn new(urls: UrlTemplateConfig, total_pages: u32, products_on_last_page: u32) -> Self {
+        Self {
+            urls,
+            total_pages: total_pages.max(1),
+            products_on_last_page: products_on_last_page.clamp(1, PRODUCTS_PER_PAGE as u32),
+        }
+    }
+
+    pub fn total_products(&self) -> u32 {
+        (self.total_pages - 1) * PRODUCTS_PER_PAGE as u32 + self.products_on_last_page
+    }
+
+    pub fn product_url(&self, seq: u32) -> String {
+        format!(
+            "{MOCK_ORIGIN}/{}/mock-device-{seq:05}/",
+            self.urls.product_path.trim_matches('/')
+        )
+    }
+
+    pub fn certificate_id(seq: u32) -> String {
+        format!("CSA24{seq:05}-MOCK")
+    }
+
+    /// Sequences shown on listing `page`, in display order (newest first)
+    pub fn page_products(&self, page: u32) -> Vec<u32> {
+        if page == 0 || page > self.total_pages {
+            return Vec::new();
+        }
+        let total = self.total_products();
+        let first = (page - 1) * PRODUCTS_PER_PAGE as u32;
+        let last = (first + PRODUCTS_PER_PAGE as u32).min(total);
+        (first..last)
+            .map(|newest_index| total - 1 - newest_index)
+            .collect()
+    }
+
+    pub fn route(&self, url: &str) -> MockRoute {
+        if url == self.urls.first_page_url {
+            return MockRoute::ListPage(1);
+        }
+        if let Some((prefix, suffix)) = self
+            .urls
+            .list_page_template
+            .split_once(UrlTemplateConfig::PAGE_PLACEHOLDER)
+        {
+            let page = url
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(suffix))
+                .and_then(|n| n.parse::<u32>().ok());
+            if let Some(page) = page {
+                return MockRoute::ListPage(page);
+            }
+        }
+        if url.starts_with(&self.urls.listing_base_url) {
+            // Listing URLs built outside the templates (other query strings)
+            let page = url
+                .split("/page/")
+                .nth(1)
+                .and_then(|rest| rest.split('/').next())
+                .and_then(|n| n.parse::<u32>().ok())
+                .unwrap_or(1);
+            return MockRoute::ListPage(page);
+        }
+        let seq = url
+            .strip_prefix(MOCK_ORIGIN)
+            .and_then(|path| path.trim_end_matches('/').rsplit_once("/mock-device-"))
+            .and_then(|(_, n)| n.parse::<u32>().ok())
+            .filter(|seq| *seq < self.total_products());
+        match seq {
+            Some(seq) => MockRoute::Product(seq),
+            None => MockRoute::NotFound,
+        }
+    }
+
+    /// Listing page markup with up to five following page links plus the last page
+    pub fn list_page_html(&self, page: u32) -> String {
+        let articles: String = self
+            .page_products(page)
+            .into_iter()
+            .map(|seq| {
+                format!(
+                    r#"<article class="type-product product"><a href="{url}"><h3 class="entry-title">{model}</h3></a><p class="entry-company notranslate">{manufacturer}</p><p class="entry-certificate-id">Certificate ID: {cert}</p></article>"#,
+                    url = self.product_url(seq),
+                    model = model(seq),
+                    manufacturer = manufacturer(seq),
+                    cert = Self::certificate_id(seq),
+                )
+            })
+            .collect();
+        let mut links: Vec<u32> = (page + 1..=(page + 5).min(self.total_pages)).collect();
+        if page < self.total_pages && !links.contains(&self.total_pages) {
+            links.push(self.total_pages);
+        }
+        let pagination: String = links
+            .into_iter()
+            .map(|p| {
+                format!(
+                    r#"<a class="page-numbers" href="{}">{p}</a>"#,
+                    self.urls.list_page_url(p)
+                )
+            })
+            .collect();
+        format!(
+            r#"<!DOCTYPE html><html><head><title>Products - Page {page}</title></head><body><div class="post-feed">{articles}</div><nav class="pagination"><span class="page-numbers current">{page}</span>{pagination}</nav></body></html>"#
+        )
+    }
+
+    pub fn detail_page_html(&self, seq: u32) -> String {
+        let items = [
+            ("Manufacturer", manufacturer(seq).to_string()),
+            ("Vendor ID", format!("0x{:04X}", 0xFFF1 + seq % 4)),
+            ("Product ID", format!("0x{:04X}", 0x8000 + seq)),
+            ("Family SKU", format!("MOCK-FAM-{:03}", seq / 10)),
+            ("Family Variant SKU", format!("MOCK-VAR-{seq:05}")),
+            ("Firmware Version", format!("1.{}.0", seq % 10)),
+            ("Hardware Version", format!("{}", 1 + seq % 3)),
+            ("Certificate ID", Self::certificate_id(seq)),
+            ("Certification Date", certification_date(seq)),
+            ("Product Type", device_type(seq).to_string()),
+            (
+                "TIS/TRP Tested",
+                if seq % 2 == 0 { "Yes" } else { "No" }.to_string(),
+            ),
+            ("Specification Version", format!("1.{}", seq % 4)),
+            (
+                "Transport Interface",
+                TRANSPORTS[seq as usize % TRANSPORTS.len()].to_string(),
+            ),
+        ];
+        let items: String = items
+            .iter()
+            .map(|(label, value)| {
+                format!(
+                    r#"<li class="item"><span class="label">{label}</span><span class="value">{value}</span></li>"#
+                )
+            })
+            .collect();
+        format!(
+            r#"<!DOCTYPE html><html><head><title>{model}</title></head><body><h1 class="entry-title">{model}</h1><p class="entry-company">{manufacturer}</p><h6 class="entry-category">{device_type}</h6><div class="entry-product-details"><ul>{items}</ul></div></body></html>"#,
+            model = model(seq),
+            manufacturer = manufacturer(seq),
+            device_type = device_type(seq),
+        )
+    }
+}
+
+fn manufacturer(seq: u32) -> &'static str {
+    MANUFACTURERS[seq as usize % MANUFACTURERS.len()]
+}
+
+fn device_type(seq: u32) -> &'static str {
+    DEVICE_TYPES[seq as usize % DEVICE_TYPES.len()]
+}
+
+fn model(seq: u32) -> String {
+    format!("Mock {} {seq:05}", device_type(seq))
+}
+
+/// Oldest product certified 2023-01-01, one more every other day
+fn certification_date(seq: u32) -> String {
+    let base = chrono::NaiveDate::from_ymd_opt(2023, 1, 1).expect("valid date");
+    (base + chrono::Duration::days(i64::from(seq) * 2))
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::html_parser::MatterDataExtractor;
+    use scraper::Html;
+
+    fn site() -> MockSite {
+        MockSite::new(UrlTemplateConfig::default(), 3, 5)
+    }
+
+    #[test]
+    fn routes_listing_and_product_urls() {
+        let site = site();
+        assert_eq!(site.total_products(), 29);
+        assert_eq!(
+            site.route(&site.urls.list_page_url(1)),
+            MockRoute::ListPage(1)
+        );
+        assert_eq!(
+            site.route(&site.urls.list_page_url(7)),
+            MockRoute::ListPage(7)
+        );
+        assert_eq!(site.route(&site.product_url(28)), MockRoute::Product(28));
+        assert_eq!(site.route(&site.product_url(29)), MockRoute::NotFound);
+        // Newest first; the last page holds the oldest products
+        assert_eq!(site.page_products(1)[0], 28);
+        assert_eq!(site.page_products(3), vec![4, 3, 2, 1, 0]);
+        assert!(site.page_products(4).is_empty());
+    }
+
+    #[test]
+    fn fixtures_parse_with_the_site_extractors() {
+        let site = site();
+        let extractor = MatterDataExtractor::new().unwrap();
+        let urls = extractor
+            .extract_product_urls_from_content(&site.list_page_html(3))
+            .unwrap();
+        assert_eq!(
+            urls,
+            (0..5)
+                .rev()
+                .map(|s| site.product_url(s))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            extractor
+                .extract_total_pages(&site.list_page_html(1))
+                .unwrap(),
+            3
+        );
+
+        let detail = extractor
+            .extract_product_detail(
+                &Html::parse_document(&site.detail_page_html(7)),
+                site.product_url(7),
+            )
+            .unwrap();
+        assert_eq!(detail.certificate_id.as_deref(), Some("CSA2400007-MOCK"));
+        assert_eq!(detail.vid, Some(0xFFF4));
+        assert_eq!(detail.pid, Some(0x8007));
+        assert_eq!(detail.manufacturer.as_deref(), Some(manufacturer(7)));
+    }
+}
@@ -0,0 +1,570 @@
+//! Scriptable doubles for `StatusChecker`, `ProductListCollector` and `ProductDetailCollector`
+//!
+//! Tests declare the site per page / per URL: the response, a latency, and how many calls fail
+//! before it succeeds. Unlike the mock HTTP layer nothing is parsed, so actor tests can drive
+//! stage behavior (retries, partial batches, cancellation) directly. Batch and range calls skip
+//! failed entries the way the real collectors do; the single-item calls return the error.
+//! Every call is recorded for assertions.
+
+use crate::domain::constants::site::PRODUCTS_PER_PAGE;
+use crate::domain::pagination::CanonicalPageIdCalculator;
+use crate::domain::product::ProductDetail;
+use crate::domain::product_url::ProductUrl;
+use crate::domain::services::crawling_services::{
+    CrawlingRangeRecommendation, DatabaseAnalysis, SiteDataChangeStatus, SiteStatus,
+};
+use crate::domain::services::{ProductDetailCollector, ProductListCollector, StatusChecker};
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+/// Origin of generated product URLs
+pub const SCRIPTED_ORIGIN: &str = "https://scripted-site.invalid";
+
+/// Response of one page or URL
+#[derive(Debug, Clone)]
+struct Scripted<T> {
+    value: Option<T>,
+    /// Calls that fail before `value` is returned
+    failures_left: u32,
+    error: String,
+    latency: Option<Duration>,
+}
+
+impl<T> Default for Scripted<T> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            failures_left: 0,
+            error: String::new(),
+            latency: None,
+        }
+    }
+}
+
+impl<T: Clone> Scripted<T> {
+    /// Consume one call: the latency to wait and the outcome
+    fn next(&mut self, what: &str) -> (Option<Duration>, Result<T>) {
+        let outcome = if self.failures_left > 0 {
+            self.failures_left -= 1;
+            Err(anyhow!("{}", self.error))
+        } else {
+            self.value
+                .clone()
+                .ok_or_else(|| anyhow!("no scripted response for {what}"))
+        };
+        (self.latency, outcome)
+    }
+}
+
+/// Sleep for `latency`; false when `token` fires first
+async fn wait(latency: Duration, token: Option<&CancellationToken>) -> bool {
+    if latency.is_zero() {
+        return token.is_none_or(|t| !t.is_cancelled());
+    }
+    match token {
+        Some(token) => tokio::select! {
+            _ = tokio::time::sleep(latency) => !token.is_cancelled(),
+            _ = token.cancelled() => false,
+        },
+        None => {
+            tokio::time::sleep(latency).await;
+            true
+        }
+    }
+}
+
+/// Accessible, stable site with `total_pages` pages
+pub fn site_status(total_pages: u32, products_on_last_page: u32) -> SiteStatus {
+    let products = total_pages.saturating_sub(1) * PRODUCTS_PER_PAGE as u32 + products_on_last_page;
+    SiteStatus {
+        is_accessible: true,
+        response_time_ms: 0,
+        total_pages,
+        estimated_products: products,
+        products_on_last_page,
+        last_check_time: Utc::now(),
+        health_score: 1.0,
+        data_change_status: SiteDataChangeStatus::Stable { count: products },
+        decrease_recommendation: None,
+        crawling_range_recommendation: CrawlingRangeRecommendation::Full,
+    }
+}
+
+/// Product URLs of physical `page` (0 = newest on the page) with canonical coordinates
+pub fn page_urls(page: u32, total_pages: u32, products_on_last_page: u32) -> Vec<ProductUrl> {
+    let calculator = CanonicalPageIdCalculator::new(total_pages, products_on_last_page as usize);
+    let count = if page == total_pages {
+        products_on_last_page
+    } else {
+        PRODUCTS_PER_PAGE as u32
+    };
+    (0..count as usize)
+        .map(|index| {
+            let position = calculator.calculate(page, index);
+            ProductUrl::new(
+                format!("{SCRIPTED_ORIGIN}/product/{page}-{index}"),
+                position.page_id,
+                position.index_in_page,
+            )
+        })
+        .collect()
+}
+
+/// Detail generated from a product URL and its coordinates
+pub fn product_detail(product_url: &ProductUrl) -> ProductDetail {
+    let (page_id, index) = (product_url.page_id, product_url.index_in_page);
+    let now = Utc::now();
+    ProductDetail {
+        url: product_url.url.clone(),
+        page_id: Some(page_id),
+        index_in_page: Some(index),
+        id: Some(format!("p{page_id:04}i{index:02}")),
+        manufacturer: Some("Scripted Devices".into()),
+        model: Some(format!("Model {page_id}-{index}")),
+        device_type: Some("On/Off Light".into()),
+        certificate_id: Some(format!("CSA-SCRIPTED-{page_id:04}-{index:02}")),
+        certification_date: None,
+        software_version: None,
+        hardware_version: None,
+        vid: None,
+        pid: None,
+        family_sku: None,
+        family_variant_sku: None,
+        firmware_version: None,
+        family_id: None,
+        tis_trp_tested: None,
+        specification_version: None,
+        transport_interface: None,
+        primary_device_type_id: None,
+        application_categories: None,
+        description: None,
+        compliance_document_url: None,
+        program_type: None,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// `StatusChecker` returning a fixed site status after optional failures
+pub struct ScriptedStatusChecker {
+    status: SiteStatus,
+    recommendation: CrawlingRangeRecommendation,
+    script: Mutex<Scripted<SiteStatus>>,
+    calls: Mutex<u32>,
+}
+
+impl ScriptedStatusChecker {
+    pub fn new(total_pages: u32, products_on_last_page: u32) -> Self {
+        Self::with_status(site_status(total_pages, products_on_last_page))
+    }
+
+    pub fn with_status(status: SiteStatus) -> Self {
+        Self {
+            recommendation: status.crawling_range_recommendation.clone(),
+            script: Mutex::new(Scripted {
+                value: Some(status.clone()),
+                ..Default::default()
+            }),
+            status,
+            calls: Mutex::new(0),
+        }
+    }
+
+    /// Fail the next `times` status checks with `error`
+    pub fn fail_times(self, times: u32, error: impl Into<String>) -> Self {
+        {
+            let mut script = self.script.lock().unwrap();
+            script.failures_left = times;
+            script.error = error.into();
+        }
+        self
+    }
+
+    pub fn latency(self, latency: Duration) -> Self {
+        self.script.lock().unwrap().latency = Some(latency);
+        self
+    }
+
+    pub fn recommendation(mut self, recommendation: CrawlingRangeRecommendation) -> Self {
+        self.recommendation = recommendation;
+        self
+    }
+
+    /// `check_site_status` calls so far
+    pub fn calls(&self) -> u32 {
+        *self.calls.lock().unwrap()
+    }
+}
+
+#[async_trait]
+impl StatusChecker for ScriptedStatusChecker {
+    async fn check_site_status(&self) -> Result<SiteStatus> {
+        *self.calls.lock().unwrap() += 1;
+        let (latency, outcome) = self.script.lock().unwrap().next("site status");
+        wait(latency.unwrap_or_default(), None).await;
+        outcome.map(|status| SiteStatus {
+            last_check_time: Utc::now(),
+            ..status
+        })
+    }
+
+    async fn calculate_crawling_range_recommendation(
+        &self,
+        _site_status: &SiteStatus,
+        _db_analysis: &DatabaseAnalysis,
+    ) -> Result<CrawlingRangeRecommendation> {
+        Ok(self.recommendation.clone())
+    }
+
+    async fn estimate_crawling_time(&self, pages: u32) -> Duration {
+        let per_page = self.script.lock().unwrap().latency.unwrap_or_default();
+        per_page * pages
+    }
+
+    async fn verify_site_accessibility(&self) -> Result<bool> {
+        Ok(self.status.is_accessible)
+    }
+}
+
+/// `ProductListCollector` serving scripted pages
+#[derive(Default)]
+pub struct ScriptedListCollector {
+    pages: Mutex<HashMap<u32, Scripted<Vec<ProductUrl>>>>,
+    latency: Duration,
+    requested: Mutex<Vec<u32>>,
+}
+
+impl ScriptedListCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Script every page of a site with generated URLs (`page_urls`)
+    pub fn site(total_pages: u32, products_on_last_page: u32) -> Self {
+        let collector = Self::new();
+        for page in 1..=total_pages {
+            collector.set(page, |s| {
+                s.value = Some(page_urls(page, total_pages, products_on_last_page))
+            });
+        }
+        collector
+    }
+
+    fn set(&self, page: u32, f: impl FnOnce(&mut Scripted<Vec<ProductUrl>>)) {
+        f(self.pages.lock().unwrap().entry(page).or_default());
+    }
+
+    pub fn page(self, page: u32, urls: Vec<ProductUrl>) -> Self {
+        self.set(page, |s| s.value = Some(urls));
+        self
+    }
+
+    /// Fail the next `times` requests of `page` with `error`
+    pub fn fail_page(self, page: u32, times: u32, error: impl Into<String>) -> Self {
+        let error = error.into();
+        self.set(page, |s| {
+            s.failures_left = times;
+            s.error = error;
+        });
+        self
+    }
+
+    pub fn page_latency(self, page: u32, latency: Duration) -> Self {
+        self.set(page, |s| s.latency = Some(latency));
+        self
+    }
+
+    /// Latency of pages without their own
+    pub fn latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Pages requested so far, in call order
+    pub fn requested_pages(&self) -> Vec<u32> {
+        self.requested.lock().unwrap().clone()
+    }
+
+    async fn fetch(&self, page: u32, token: Option<&CancellationToken>) -> Result<Vec<ProductUrl>> {
+        self.requested.lock().unwrap().push(page);
+        let (latency, outcome) = self
+            .pages
+            .lock()
+            .unwrap()
+            .entry(page)
+            .or_default()
+            .next(&format!("page {page}"));
+        if !wait(latency.unwrap_or(self.latency), token).await {
+            return Err(anyhow!("page {page} cancelled"));
+        }
+        outcome
+    }
+
+    async fn fetch_pages(
+        &self,
+        pages: &[u32],
+        token: Option<&CancellationToken>,
+    ) -> Result<Vec<ProductUrl>> {
+        let mut urls = Vec::new();
+        for &page in pages {
+            if token.is_some_and(|t| t.is_cancelled()) {
+                break;
+            }
+            if let Ok(mut page_urls) = self.fetch(page, token).await {
+                urls.append(&mut page_urls);
+            }
+        }
+        Ok(urls)
+    }
+}
+
+fn range_pages(start_page: u32, end_page: u32) -> Vec<u32> {
+    if start_page > end_page {
+        (end_page..=start_page).rev().collect()
+    } else {
+        (start_page..=end_page).collect()
+    }
+}
+
+#[async_trait]
+impl ProductListCollector for ScriptedListCollector {
+    async fn collect_all_pages(
+        &self,
+        total_pages: u32,
+        _products_on_last_page: u32,
+    ) -> Result<Vec<ProductUrl>> {
+        self.fetch_pages(&range_pages(1, total_pages), None).await
+    }
+
+    async fn collect_page_range(
+        &self,
+        start_page: u32,
+        end_page: u32,
+        _total_pages: u32,
+        _products_on_last_page: u32,
+    ) -> Result<Vec<ProductUrl>> {
+        self.fetch_pages(&range_pages(start_page, end_page), None)
+            .await
+    }
+
+    async fn collect_page_range_with_cancellation(
+        &self,
+        start_page: u32,
+        end_page: u32,
+        _total_pages: u32,
+        _products_on_last_page: u32,
+        cancellation_token: CancellationToken,
+    ) -> Result<Vec<ProductUrl>> {
+        self.fetch_pages(
+            &range_pages(start_page, end_page),
+            Some(&cancellation_token),
+        )
+        .await
+    }
+
+    async fn collect_single_page(
+        &self,
+        page: u32,
+        _total_pages: u32,
+        _products_on_last_page: u32,
+    ) -> Result<Vec<ProductUrl>> {
+        self.fetch(page, None).await
+    }
+
+    async fn collect_page_batch(
+        &self,
+        pages: &[u32],
+        _total_pages: u32,
+        _products_on_last_page: u32,
+    ) -> Result<Vec<ProductUrl>> {
+        self.fetch_pages(pages, None).await
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// `ProductDetailCollector` serving scripted details
+#[derive(Default)]
+pub struct ScriptedDetailCollector {
+    details: Mutex<HashMap<String, Scripted<ProductDetail>>>,
+    /// Unscripted URLs get `product_detail` instead of an error
+    generate: bool,
+    latency: Duration,
+    requested: Mutex<Vec<String>>,
+}
+
+impl ScriptedDetailCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer every URL without its own script with a generated detail
+    pub fn generated() -> Self {
+        Self {
+            generate: true,
+            ..Self::default()
+        }
+    }
+
+    fn set(&self, url: &str, f: impl FnOnce(&mut Scripted<ProductDetail>)) {
+        f(self
+            .details
+            .lock()
+            .unwrap()
+            .entry(url.to_string())
+            .or_default());
+    }
+
+    pub fn detail(self, detail: ProductDetail) -> Self {
+        let url = detail.url.clone();
+        self.set(&url, |s| s.value = Some(detail));
+        self
+    }
+
+    /// Fail the next `times` requests of `url` with `error`
+    pub fn fail_url(self, url: &str, times: u32, error: impl Into<String>) -> Self {
+        let error = error.into();
+        self.set(url, |s| {
+            s.failures_left = times;
+            s.error = error;
+        });
+        self
+    }
+
+    pub fn url_latency(self, url: &str, latency: Duration) -> Self {
+        self.set(url, |s| s.latency = Some(latency));
+        self
+    }
+
+    /// Latency of URLs without their own
+    pub fn latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// URLs requested so far, in call order
+    pub fn requested_urls(&self) -> Vec<String> {
+        self.requested.lock().unwrap().clone()
+    }
+
+    async fn fetch(
+        &self,
+        product_url: &ProductUrl,
+        token: Option<&CancellationToken>,
+    ) -> Result<ProductDetail> {
+        let url = &product_url.url;
+        self.requested.lock().unwrap().push(url.clone());
+        let (latency, outcome) = {
+            let mut details = self.details.lock().unwrap();
+            let script = details.entry(url.clone()).or_default();
+            if self.generate && script.value.is_none() {
+                script.value = Some(product_detail(product_url));
+            }
+            script.next(url)
+        };
+        if !wait(latency.unwrap_or(self.latency), token).await {
+            return Err(anyhow!("{url} cancelled"));
+        }
+        outcome
+    }
+
+    async fn fetch_all(
+        &self,
+        product_urls: &[ProductUrl],
+        token: Option<&CancellationToken>,
+    ) -> Result<Vec<ProductDetail>> {
+        let mut details = Vec::with_capacity(product_urls.len());
+        for product_url in product_urls {
+            if token.is_some_and(|t| t.is_cancelled()) {
+                break;
+            }
+            if let Ok(detail) = self.fetch(product_url, token).await {
+                details.push(detail);
+            }
+        }
+        Ok(details)
+    }
+}
+
+#[async_trait]
+impl ProductDetailCollector for ScriptedDetailCollector {
+    async fn collect_details(&self, product_urls: &[ProductUrl]) -> Result<Vec<ProductDetail>> {
+        self.fetch_all(product_urls, None).await
+    }
+
+    async fn collect_details_with_cancellation(
+        &self,
+        product_urls: &[ProductUrl],
+        cancellation_token: CancellationToken,
+    ) -> Result<Vec<ProductDetail>> {
+        self.fetch_all(product_urls, Some(&cancellation_token))
+            .await
+    }
+
+    async fn collect_single_product(&self, product_url: &ProductUrl) -> Result<ProductDetail> {
+        self.fetch(product_url, None).await
+    }
+
+    async fn collect_product_batch(
+        &self,
+        product_urls: &[ProductUrl],
+    ) -> Result<Vec<ProductDetail>> {
+        self.fetch_all(product_urls, None).await
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn scripted_failures_latency_and_cancellation() {
+        let checker = ScriptedStatusChecker::new(3, 4).fail_times(1, "timeout");
+        assert!(checker.check_site_status().await.is_err());
+        let status = checker.check_site_status().await.unwrap();
+        assert_eq!((status.total_pages, status.estimated_products), (3, 28));
+        assert_eq!(checker.calls(), 2);
+
+        let lists = ScriptedListCollector::site(3, 4).fail_page(2, 1, "HTTP 503");
+        let urls = lists.collect_page_range(3, 1, 3, 4).await.unwrap();
+        assert_eq!(urls.len(), 4 + 12);
+        assert_eq!((urls[0].page_id, urls[0].index_in_page), (0, 3));
+        assert_eq!(lists.collect_single_page(2, 3, 4).await.unwrap().len(), 12);
+        assert_eq!(lists.requested_pages(), vec![3, 2, 1, 2]);
+
+        let failing = urls[1].url.clone();
+        let details: Arc<dyn ProductDetailCollector> = Arc::new(
+            ScriptedDetailCollector::generated()
+                .fail_url(&failing, 1, "parse error")
+                .latency(Duration::from_millis(20)),
+        );
+        let collected = details.collect_details(&urls[..3]).await.unwrap();
+        assert_eq!(collected.len(), 2);
+        assert_eq!(collected[0].page_id, Some(0));
+
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(30)).await;
+            canceller.cancel();
+        });
+        let partial = details
+            .collect_details_with_cancellation(&urls, token)
+            .await
+            .unwrap();
+        assert!(partial.len() < urls.len());
+    }
+}
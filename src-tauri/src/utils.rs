@@ -89,12 +89,46 @@ impl PageIdCalculator {
         calc.reverse(page_id, index_in_page, self.last_page_number)
             .map(|(phys, idx)| (phys, idx as usize))
     }
+
+    /// Exact inverse of `calculate`: (physical page, 0-based index on that page) currently
+    /// holding canonical slot (`page_id`, `index_in_page`), or None past the site's products
+    pub fn physical_position(&self, page_id: i32, index_in_page: i32) -> Option<(u32, usize)> {
+        const P: i64 = PRODUCTS_PER_PAGE as i64;
+        if self.last_page_number == 0 || page_id < 0 || !(0..P).contains(&(index_in_page as i64)) {
+            return None;
+        }
+        let total_products =
+            (i64::from(self.last_page_number) - 1) * P + self.products_in_last_page as i64;
+        let index_from_oldest = i64::from(page_id) * P + i64::from(index_in_page);
+        if index_from_oldest >= total_products {
+            return None;
+        }
+        let index_from_newest = total_products - 1 - index_from_oldest;
+        Some((
+            (index_from_newest / P + 1) as u32,
+            (index_from_newest % P) as usize,
+        ))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn physical_position_inverts_calculate() {
+        let calculator = PageIdCalculator::new(482, 4);
+        for (page, index) in [(482, 0), (482, 3), (481, 0), (481, 11), (1, 0), (1, 11)] {
+            let calc = calculator.calculate(page, index);
+            assert_eq!(
+                calculator.physical_position(calc.page_id, calc.index_in_page),
+                Some((page, index))
+            );
+        }
+        // Slot past the newest product
+        assert_eq!(calculator.physical_position(481, 4), None);
+    }
+
     #[test]
     fn test_page_id_calculation_example() {
         // 사용자 예시: 482페이지가 마지막 페이지이고 4개 제품이 있는 경우
@@ -3,6 +3,7 @@ import { invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
 // Types are relaxed locally to avoid tight coupling during integration
 import { tauriApi } from '../../services/tauri-api';
+import { formatCommandError } from '../../services/commandError';
 import EventConsole from '../dev/EventConsole';
 import { usePulse } from '../../hooks/usePulse';
 import CountUp from '../common/CountUp';
@@ -316,7 +317,7 @@ export default function CrawlingEngineTabSimple() {
       setStatusMessage('🎭 통합 파이프라인 실행 중 (라이트)');
     } catch (error) {
       console.error('통합 파이프라인(라이트) 시작 실패:', error);
-      addLog(`❌ 통합 파이프라인(라이트) 시작 실패: ${error}`);
+      addLog(`❌ 통합 파이프라인(라이트) 시작 실패: ${formatCommandError(error)}`);
       setStatusMessage('크롤링 실패');
       setIsRunning(false);
     }
@@ -341,7 +342,7 @@ export default function CrawlingEngineTabSimple() {
       setStatusMessage('🎭 통합 파이프라인 실행 중 (하이)');
     } catch (error) {
       console.error('통합 파이프라인(하이) 시작 실패:', error);
-      addLog(`❌ 통합 파이프라인(하이) 시작 실패: ${error}`);
+      addLog(`❌ 통합 파이프라인(하이) 시작 실패: ${formatCommandError(error)}`);
       setStatusMessage('크롤링 실패');
       setIsRunning(false);
     }
@@ -366,7 +367,7 @@ export default function CrawlingEngineTabSimple() {
       addLog(`✅ Validation 요청 완료: ${JSON.stringify(res)}`);
     } catch (e) {
       console.error(e);
-      addLog(`❌ Validation 실패: ${e}`);
+      addLog(`❌ Validation 실패: ${formatCommandError(e)}`);
     } finally {
       setIsValidating(false);
     }
@@ -384,7 +385,7 @@ export default function CrawlingEngineTabSimple() {
         : await tauriApi.startRepairSync();
       addLog(`✅ Sync 완료: ${JSON.stringify(res)}`);
     } catch (e) {
-      addLog(`❌ Sync 실패: ${e}`);
+      addLog(`❌ Sync 실패: ${formatCommandError(e)}`);
     } finally {
       setIsSyncing(false);
     }
@@ -0,0 +1,31 @@
+/**
+ * Structured command errors (sync / validation / crawling / long-running query commands).
+ *
+ * The backend rejects these commands with a `CommandError` (generated via ts-rs):
+ * `{ category, code, message, retryable, title, remediation }`. Other commands still
+ * reject with plain strings, so the helpers accept both.
+ */
+
+import type { CommandError } from '../types/generated/CommandError';
+
+export type { CommandError };
+
+export function isCommandError(err: unknown): err is CommandError {
+  return (
+    typeof err === 'object' &&
+    err !== null &&
+    typeof (err as any).category === 'string' &&
+    typeof (err as any).code === 'string' &&
+    typeof (err as any).message === 'string'
+  );
+}
+
+/** One-line message for logs/toasts: title, message and the first remediation hint */
+export function formatCommandError(err: unknown): string {
+  if (!isCommandError(err)) {
+    return err instanceof Error ? err.message : String(err);
+  }
+  const hint = err.remediation[0] ? ` → ${err.remediation[0]}` : '';
+  const retry = err.retryable ? ' (retryable)' : '';
+  return `${err.title}: ${err.message}${retry}${hint}`;
+}
@@ -77,3 +77,31 @@ export async function startDiagnosticSync(
 export async function retryFailedDetails(limit?: number): Promise<{ retried: number }>{
   return await invoke<{ retried: number }>("retry_failed_details", { limit });
 }
+
+// Cancel a running sync; resolves false when no sync with that id is running
+export async function cancelSyncSession(sessionId: string): Promise<boolean> {
+  return await invoke<boolean>("cancel_sync_session", { sessionId });
+}
+
+export async function listRunningSyncs(): Promise<string[]> {
+  return await invoke<string[]>("list_running_syncs");
+}
+
+// Pause a running sync; pages in flight finish, the rest wait for resumeSyncSession
+export async function pauseSyncSession(sessionId: string): Promise<boolean> {
+  return await invoke<boolean>("pause_sync_session", { sessionId });
+}
+
+export async function resumeSyncSession(sessionId: string): Promise<SyncSummary> {
+  return await invoke<SyncSummary>("resume_sync_session", { sessionId });
+}
+
+export interface PausedSync {
+  session_id: string;
+  remaining_pages: number;
+  coverage_text: string | null;
+}
+
+export async function listPausedSyncs(): Promise<PausedSync[]> {
+  return await invoke<PausedSync[]>("list_paused_syncs");
+}
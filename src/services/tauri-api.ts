@@ -9,6 +9,7 @@
 import { invoke } from '@tauri-apps/api/core';
 import { listen, type UnlistenFn } from '@tauri-apps/api/event';
 import { loggingService } from './loggingService';
+import { formatCommandError } from './commandError';
 import type {
   BackendCrawlerConfig,
   CrawlingProgress,
@@ -23,6 +24,47 @@ import type {
   LiveSystemState
 } from '../types/events';
 
+/**
+ * Per-call controls accepted by long-running query commands. On timeout the command
+ * rejects with an `E_TIMED_OUT` CommandError; `query_id` enables `cancelQuery`.
+ */
+export interface QueryOptions {
+  timeout_ms?: number;
+  query_id?: string;
+}
+
+/** URL list one sync session extracted from one physical page */
+export interface PageUrlSet {
+  session_id: string;
+  physical_page: number;
+  expected_count: number;
+  url_count: number;
+  set_hash: string;
+  urls: string[] | null;
+  recorded_at: string;
+}
+
+/** Shadow comparison of newly selected extraction rules against the promoted ones */
+export interface ExtractorRolloutComparison {
+  baseline: { site_profile: string; fingerprint: string };
+  candidate: { site_profile: string; fingerprint: string };
+  status: 'shadowing' | 'awaiting_decision' | 'promoted' | 'rolled_back';
+  shadow_pages: number;
+  pages_compared: number;
+  pages_diverged: number;
+  candidate_errors: number;
+  fields: Array<{
+    field: string;
+    matched: number;
+    diverged: number;
+    baseline_only: number;
+    candidate_only: number;
+  }>;
+  samples: Array<{ url: string; field: string; baseline: string | null; candidate: string | null }>;
+  started_at: string;
+  decided_at: string | null;
+}
+
 /**
  * Service class for communicating with the Rust backend
  */
@@ -42,12 +84,22 @@ export class TauriApiService {
     overrideBatchSize?: number;
     overrideConcurrency?: number;
     delayMs?: number;
+    /** Advanced: batch stages to skip */
+    stageFlags?: Array<'skip_validation' | 'skip_status_check' | 'list_only'>;
+    /** Stop early (completion_reason 'limit_reached') after this many new products */
+    maxNewProducts?: number;
+    maxPages?: number;
+    maxDurationSecs?: number;
   } = {}): Promise<{ success: boolean; message: string; session_id?: string }> {
     const req = {
       mode: options.mode,
       override_batch_size: options.overrideBatchSize,
       override_concurrency: options.overrideConcurrency,
       delay_ms: options.delayMs,
+      stage_flags: options.stageFlags ?? [],
+      max_new_products: options.maxNewProducts,
+      max_pages: options.maxPages,
+      max_duration_secs: options.maxDurationSecs,
     };
     const res = await invoke<any>('start_unified_crawling', { request: req });
     return res as { success: boolean; message: string; session_id?: string };
@@ -299,6 +351,31 @@ export class TauriApiService {
     }
   }
 
+  /**
+   * Products grid page. With `snapshot`, reads go through a consistent read snapshot that only
+   * advances at page-write boundaries, so a grid browsed during a crawl never shows half-written
+   * pages; `snapshot_at` tells when that snapshot was taken.
+   */
+  async getProductsPage(
+    page: number,
+    size: number,
+    snapshot = false,
+    options?: QueryOptions
+  ): Promise<{
+    products: any[];
+    total_count: number;
+    page: number;
+    size: number;
+    has_next: boolean;
+    snapshot_at?: string | null;
+  }> {
+    try {
+      return await invoke('get_products_page', { page, size, snapshot, options });
+    } catch (error) {
+      throw new Error(`Failed to get products page: ${formatCommandError(error)}`);
+    }
+  }
+
   /**
    * Get local database statistics
    */
@@ -693,6 +770,7 @@ export class TauriApiService {
       'actor-batch-report',
       'actor-session-report',
   'actor-next-plan-ready',
+      'actor-session-report-ready',
       // Phases / Shutdown
       'actor-phase-started',
       'actor-phase-completed',
@@ -728,6 +806,9 @@ export class TauriApiService {
       'actor-sync-page-completed',
       'actor-sync-warning',
       'actor-sync-completed',
+      'actor-sync-aborted',
+      'actor-sync-paused',
+      'actor-db-maintenance-progress',
     ];
 
     const unsubs: UnlistenFn[] = [];
@@ -873,11 +954,22 @@ export class TauriApiService {
   /**
    * Scan DB for pagination mismatches (page_id/index_in_page invariants)
    */
-  async scanDbPaginationMismatches(): Promise<any> {
+  async scanDbPaginationMismatches(options?: QueryOptions): Promise<any> {
+    try {
+      return await invoke<any>('scan_db_pagination_mismatches', { options });
+    } catch (error) {
+      throw new Error(`Failed to scan DB pagination mismatches: ${formatCommandError(error)}`);
+    }
+  }
+
+  /**
+   * Published JSON Schema of exported product records (defaults to the current version).
+   */
+  async getExportSchema(version?: number): Promise<Record<string, unknown>> {
     try {
-      return await invoke<any>('scan_db_pagination_mismatches');
+      return await invoke<any>('get_export_schema', { version: version ?? null });
     } catch (error) {
-      throw new Error(`Failed to scan DB pagination mismatches: ${error}`);
+      throw new Error(`Failed to get export schema: ${error}`);
     }
   }
 
@@ -892,6 +984,688 @@ export class TauriApiService {
     }
   }
 
+  /**
+   * Database backups (taken automatically before destructive commands), newest first.
+   */
+  async listDbBackups(): Promise<Array<{ file_name: string; reason: string; created_at: string; size_bytes: number }>> {
+    try {
+      return await invoke<any>('list_db_backups');
+    } catch (error) {
+      throw new Error(`Failed to list DB backups: ${error}`);
+    }
+  }
+
+  /**
+   * Stage a backup to replace the database on the next app start.
+   */
+  async restoreDbBackup(fileName: string): Promise<{ restored_from: string; safety_backup: { file_name: string }; restart_required: boolean }> {
+    try {
+      return await invoke<any>('restore_db_backup', { fileName });
+    } catch (error) {
+      throw new Error(`Failed to restore DB backup: ${error}`);
+    }
+  }
+
+  /**
+   * Merge products/product_details from another SQLite database file by URL.
+   */
+  async importDatabase(
+    sourcePath: string,
+    rule: 'prefer_newer' | 'prefer_non_null' = 'prefer_newer',
+    dryRun = false
+  ): Promise<any> {
+    try {
+      return await invoke<any>('import_database', { sourcePath, rule, dryRun });
+    } catch (error) {
+      throw new Error(`Failed to import database: ${error}`);
+    }
+  }
+
+  /**
+   * Switch the product identity strategy (URL or certificate_id). Reports certificate_id values
+   * held by several URLs and merges them first for certificate_id + merge.
+   */
+  async switchProductIdentityStrategy(
+    strategy: 'url' | 'certificate_id',
+    onSecondaryCollision?: 'keep_both' | 'merge' | 'skip',
+    dryRun = false
+  ): Promise<any> {
+    try {
+      return await invoke<any>('switch_product_identity_strategy', {
+        strategy,
+        onSecondaryCollision: onSecondaryCollision ?? null,
+        dryRun,
+      });
+    } catch (error) {
+      throw new Error(`Failed to switch product identity strategy: ${error}`);
+    }
+  }
+
+  /**
+   * Run the unified crawl against the built-in mock site (builds with the `mock-site` feature).
+   * Unset fields use the configured mock site shape.
+   */
+  async runSimulatedCrawl(request?: {
+    totalPages?: number;
+    productsOnLastPage?: number;
+    latencyMs?: number;
+  }): Promise<any> {
+    try {
+      return await invoke<any>('run_simulated_crawl', {
+        request: request
+          ? {
+              total_pages: request.totalPages ?? null,
+              products_on_last_page: request.productsOnLastPage ?? null,
+              latency_ms: request.latencyMs ?? null,
+            }
+          : null,
+      });
+    } catch (error) {
+      throw new Error(`Failed to run simulated crawl: ${error}`);
+    }
+  }
+
+  /**
+   * Apply pending schema migrations to a temporary copy of the database and report
+   * integrity checks and row-count changes. The real database is not modified.
+   */
+  async rehearseMigrations(): Promise<any> {
+    try {
+      return await invoke<any>('rehearse_migrations');
+    } catch (error) {
+      throw new Error(`Failed to rehearse migrations: ${error}`);
+    }
+  }
+
+  /**
+   * DB-only cross-check of products against product_details. Findings replace the open
+   * rows of the findings table; progress arrives as `validation-cross-check` events.
+   */
+  async runDbCrossCheck(scanDepth?: number, options?: QueryOptions): Promise<any> {
+    try {
+      return await invoke<any>('run_db_cross_check', { scanDepth: scanDepth ?? null, options });
+    } catch (error) {
+      throw new Error(`Failed to run DB cross-check: ${formatCommandError(error)}`);
+    }
+  }
+
+  async getValidationFindings(status?: 'open' | 'repaired'): Promise<any[]> {
+    try {
+      return await invoke<any[]>('get_validation_findings', { status: status ?? null });
+    } catch (error) {
+      throw new Error(`Failed to load validation findings: ${error}`);
+    }
+  }
+
+  /** Apply automatic repairs to open findings (all when `ids` is omitted). */
+  async repairValidationFindings(ids?: number[]): Promise<any> {
+    try {
+      return await invoke<any>('repair_validation_findings', { ids: ids ?? null });
+    } catch (error) {
+      throw new Error(`Failed to repair validation findings: ${error}`);
+    }
+  }
+
+  /**
+   * Re-sync the site slots behind open validation findings via diagnostic sync, then
+   * re-run the cross-check. `dryRun` only returns the page/index plan.
+   */
+  async applyValidationRepairs(dryRun = false): Promise<any> {
+    try {
+      return await invoke<any>('apply_validation_repairs', { dryRun });
+    } catch (error) {
+      throw new Error(`Failed to apply validation repairs: ${error}`);
+    }
+  }
+
+  /**
+   * Attach an operator note to a running or finished session (optionally one batch).
+   * Notes are included in the session report.
+   */
+  async annotateSession(sessionId: string, note: string, batchId?: string): Promise<any> {
+    try {
+      return await invoke<any>('annotate_session', { sessionId, note, batchId: batchId ?? null });
+    } catch (error) {
+      throw new Error(`Failed to annotate session: ${error}`);
+    }
+  }
+
+  async getSessionNotes(sessionId: string): Promise<any[]> {
+    try {
+      return await invoke<any[]>('get_session_notes', { sessionId });
+    } catch (error) {
+      throw new Error(`Failed to get session notes: ${error}`);
+    }
+  }
+
+  /**
+   * Duplicate products stored under different URLs, grouped by certificate_id or
+   * (vid, pid, model). Each group's first URL is the one a merge keeps.
+   */
+  async analyzeIdentityDuplicates(options?: QueryOptions): Promise<any> {
+    try {
+      return await invoke<any>('analyze_identity_duplicates', { options });
+    } catch (error) {
+      throw new Error(`Failed to analyze identity duplicates: ${formatCommandError(error)}`);
+    }
+  }
+
+  /** Merge the selected groups (by keeper URL) from `analyzeIdentityDuplicates`. */
+  async mergeIdentityDuplicates(keepers: string[], dryRun = false): Promise<any> {
+    try {
+      return await invoke<any>('merge_identity_duplicates', { keepers, dryRun });
+    } catch (error) {
+      throw new Error(`Failed to merge identity duplicates: ${error}`);
+    }
+  }
+
+  /**
+   * One-time migration: rewrite stored product URLs to their canonical form,
+   * merging variants into an already stored URL. `dryRun` reports only.
+   */
+  async normalizeProductUrls(dryRun = false): Promise<any> {
+    try {
+      return await invoke<any>('normalize_product_urls', { dryRun });
+    } catch (error) {
+      throw new Error(`Failed to normalize product URLs: ${error}`);
+    }
+  }
+
+  /** Cancel a query started with `options.query_id`; false when it already finished. */
+  async cancelQuery(queryId: string): Promise<boolean> {
+    try {
+      return await invoke<boolean>('cancel_query', { queryId });
+    } catch (error) {
+      throw new Error(`Failed to cancel query: ${error}`);
+    }
+  }
+
+  /** Queries started with a `query_id`, oldest first. */
+  async listRunningQueries(): Promise<
+    Array<{ query_id: string; command: string; started_at: string; timeout_ms?: number | null }>
+  > {
+    try {
+      return await invoke('list_running_queries');
+    } catch (error) {
+      throw new Error(`Failed to list running queries: ${error}`);
+    }
+  }
+
+  /**
+   * Mermaid / Graphviz diagram of a plan's batches, stages and estimated durations.
+   * Pass a session id, plan hash or plan id for a stored plan; omit it to plan afresh.
+   */
+  async exportExecutionPlan(
+    sessionOrPlanId?: string,
+    format: 'mermaid' | 'graphviz' = 'mermaid'
+  ): Promise<{ format: 'mermaid' | 'graphviz'; source: string; plan_hash: string; diagram: string }> {
+    try {
+      return await invoke('export_execution_plan', {
+        sessionOrPlanId: sessionOrPlanId ?? null,
+        format,
+      });
+    } catch (error) {
+      throw new Error(`Failed to export execution plan: ${error}`);
+    }
+  }
+
+  /**
+   * Launch-time DB integrity report (also pushed as `actor-data-integrity-snapshot`).
+   * Resolves to null until the startup check has finished; `refresh` re-runs it.
+   */
+  async getStartupIntegrityReport(refresh = false): Promise<{
+    checked_at: string;
+    elapsed_ms: number;
+    products: number;
+    product_details: number;
+    pages: number;
+    pages_with_wrong_count: number;
+    wrong_count_samples: Array<{ page_id: number; count: number }>;
+    null_coordinate_products: number;
+    null_coordinate_details: number;
+    orphan_details: number;
+    products_without_details: number;
+    healthy: boolean;
+    summary: string;
+  } | null> {
+    try {
+      return await invoke('get_startup_integrity_report', { refresh });
+    } catch (error) {
+      throw new Error(`Failed to get startup integrity report: ${error}`);
+    }
+  }
+
+  /**
+   * Named database workspaces (separate SQLite files), default first
+   */
+  async listWorkspaces(): Promise<Array<{
+    name: string;
+    database_path: string;
+    active: boolean;
+    size_bytes: number;
+  }>> {
+    try {
+      return await invoke('list_workspaces');
+    } catch (error) {
+      throw new Error(`Failed to list workspaces: ${error}`);
+    }
+  }
+
+  async createWorkspace(name: string): Promise<{
+    name: string;
+    database_path: string;
+    active: boolean;
+    size_bytes: number;
+  }> {
+    try {
+      return await invoke('create_workspace', { name });
+    } catch (error) {
+      throw new Error(`Failed to create workspace: ${error}`);
+    }
+  }
+
+  /**
+   * Switch the active workspace; refused while a crawl or sync is running
+   */
+  async switchWorkspace(name: string): Promise<{
+    name: string;
+    database_path: string;
+    active: boolean;
+    size_bytes: number;
+  }> {
+    try {
+      return await invoke('switch_workspace', { name });
+    } catch (error) {
+      throw new Error(`Failed to switch workspace: ${error}`);
+    }
+  }
+
+  /**
+   * Postgres connectivity check for `advanced.database.postgres_url`: configured, reachable,
+   * product count. The app itself stores into SQLite only.
+   */
+  async checkPostgresConnection(): Promise<{
+    configured: boolean;
+    url: string;
+    connected: boolean;
+    product_count: number | null;
+    error: string | null;
+  }> {
+    try {
+      return await invoke('check_postgres_connection');
+    } catch (error) {
+      throw new Error(`Failed to check Postgres connection: ${error}`);
+    }
+  }
+
+  /**
+   * Main dashboard state in one call: active operations, recent sessions,
+   * KPI trends, pending anomalies, next scheduled runs and health flags
+   */
+  async getDashboardOverview(): Promise<any> {
+    try {
+      return await invoke('get_dashboard_overview');
+    } catch (error) {
+      throw new Error(`Failed to get dashboard overview: ${error}`);
+    }
+  }
+
+  /**
+   * Per-table grid preferences (visible columns, sort, density) stored in the backend config,
+   * shared by every window and carried by settings export
+   */
+  async getGridPreferences(tableId: string): Promise<{
+    visible_columns: string[];
+    sort: { column: string; direction: 'asc' | 'desc' } | null;
+    density: 'compact' | 'standard' | 'comfortable';
+  } | null> {
+    try {
+      return await invoke('get_grid_preferences', { tableId });
+    } catch (error) {
+      throw new Error(`Failed to get grid preferences: ${error}`);
+    }
+  }
+
+  async saveGridPreferences(tableId: string, preferences: {
+    visible_columns: string[];
+    sort: { column: string; direction: 'asc' | 'desc' } | null;
+    density: 'compact' | 'standard' | 'comfortable';
+  }): Promise<void> {
+    try {
+      await invoke('save_grid_preferences', { tableId, preferences });
+    } catch (error) {
+      throw new Error(`Failed to save grid preferences: ${error}`);
+    }
+  }
+
+  async resetGridPreferences(tableId: string): Promise<void> {
+    try {
+      await invoke('reset_grid_preferences', { tableId });
+    } catch (error) {
+      throw new Error(`Failed to reset grid preferences: ${error}`);
+    }
+  }
+
+  /**
+   * Raw `ui_preferences` key-value store; a `null` value removes the key.
+   * Every window receives `ui-preferences-changed` ({ key, value }) after a write
+   */
+  async listUiPreferences(): Promise<Record<string, unknown>> {
+    try {
+      return await invoke('list_ui_preferences');
+    } catch (error) {
+      throw new Error(`Failed to list UI preferences: ${error}`);
+    }
+  }
+
+  async setUiPreference(key: string, value: unknown): Promise<void> {
+    try {
+      await invoke('set_ui_preference', { key, value });
+    } catch (error) {
+      throw new Error(`Failed to set UI preference: ${error}`);
+    }
+  }
+
+  /**
+   * Crawl specific list pages / product URLs ahead of the backlog of the active session
+   * (or `sessionId`); items already dispatched are unaffected
+   */
+  async prioritizeItems(request: {
+    sessionId?: string;
+    pages?: number[];
+    urls?: string[];
+    priority?: 'low' | 'normal' | 'high' | 'interactive';
+  }): Promise<{
+    session_id: string;
+    pages: number[];
+    urls: string[];
+    priority: 'low' | 'normal' | 'high' | 'interactive';
+  }> {
+    try {
+      return await invoke('prioritize_items', request);
+    } catch (error) {
+      throw new Error(`Failed to prioritize items: ${error}`);
+    }
+  }
+
+  /**
+   * Current adaptive (AIMD) concurrency target of every list/detail stage per session
+   */
+  async getConcurrencyTargets(): Promise<Array<{
+    session_id: string;
+    stage_type: string;
+    target: number;
+    min_permits: number;
+    max_permits: number;
+    active: boolean;
+    last_signals?: { samples: number; p95_ms: number; error_rate: number };
+    last_reason?: 'error_rate' | 'latency' | 'headroom';
+    updated_at: string;
+  }>> {
+    try {
+      return await invoke('get_concurrency_targets');
+    } catch (error) {
+      throw new Error(`Failed to get concurrency targets: ${error}`);
+    }
+  }
+
+  /**
+   * Per-batch-size throughput / memory pressure history and the batch size
+   * suggested (or applied) for the next session
+   */
+  async getBatchSizeSuggestion(): Promise<{
+    mode: 'off' | 'suggest' | 'apply';
+    current: number;
+    sizes: Array<{
+      batch_size: number;
+      samples: number;
+      pages_per_min: number;
+      memory_pressure?: number | null;
+    }>;
+    suggestion?: {
+      batch_size: number;
+      current: number;
+      reason: 'throughput' | 'memory_pressure';
+      samples: number;
+      applied: boolean;
+    } | null;
+  }> {
+    try {
+      return await invoke('get_batch_size_suggestion');
+    } catch (error) {
+      throw new Error(`Failed to get batch size suggestion: ${error}`);
+    }
+  }
+
+  /**
+   * Process / system memory plus the detail handoff and uncommitted-row sizes
+   * watched by the memory budget
+   */
+  async getMemoryStats(): Promise<{
+    process_rss_mb?: number | null;
+    system_memory_pressure?: number | null;
+    budget_bytes: number;
+    handoff_bytes: number;
+    peak_handoff_bytes: number;
+    queue_pending_bytes: number;
+    peak_queue_pending_bytes: number;
+    early_flushes: number;
+    backpressure_waits: number;
+  }> {
+    try {
+      return await invoke('get_memory_stats');
+    } catch (error) {
+      throw new Error(`Failed to get memory stats: ${error}`);
+    }
+  }
+
+  /**
+   * Severity and documentation of a sync warning code (`SyncWarning.code`).
+   */
+  async describeWarning(code: string): Promise<{
+    code: string;
+    severity: 'info' | 'warning' | 'error';
+    title: string;
+    documentation: string;
+  }> {
+    try {
+      return await invoke('describe_warning', { code });
+    } catch (error) {
+      throw new Error(`Failed to describe warning: ${error}`);
+    }
+  }
+
+  async listWarningCodes(): Promise<Array<{
+    code: string;
+    severity: 'info' | 'warning' | 'error';
+    title: string;
+    documentation: string;
+  }>> {
+    try {
+      return await invoke('list_warning_codes');
+    } catch (error) {
+      throw new Error(`Failed to list warning codes: ${error}`);
+    }
+  }
+
+  /**
+   * URL list a sync session extracted from one physical page.
+   * `urls` is null once retention pruned the page to its set hash.
+   */
+  async getPageUrlSet(sessionId: string, physicalPage: number): Promise<PageUrlSet | null> {
+    try {
+      return await invoke('get_page_url_set', { sessionId, physicalPage });
+    } catch (error) {
+      throw new Error(`Failed to get page URL set: ${error}`);
+    }
+  }
+
+  /** Recorded URL sets of one physical page across sessions, newest first */
+  async getPageUrlHistory(physicalPage: number, limit?: number): Promise<PageUrlSet[]> {
+    try {
+      return await invoke('get_page_url_history', { physicalPage, limit });
+    } catch (error) {
+      throw new Error(`Failed to get page URL history: ${error}`);
+    }
+  }
+
+  async prunePageUrlSets(): Promise<{ lists_dropped: number; rows_deleted: number }> {
+    try {
+      return await invoke('prune_page_url_sets');
+    } catch (error) {
+      throw new Error(`Failed to prune page URL sets: ${error}`);
+    }
+  }
+
+  /**
+   * Re-fetch detail pages matching `filter` and rewrite only `fields` (product_details column
+   * names). Missing fresh values keep the stored ones; `dryRun` counts without writing.
+   */
+  async refreshFields(
+    fields: string[],
+    filter: {
+      urls?: string[];
+      page_id_min?: number;
+      page_id_max?: number;
+      only_missing?: boolean;
+      limit?: number;
+    },
+    dryRun = false
+  ): Promise<{
+    dry_run: boolean;
+    targeted: number;
+    fetched: number;
+    failed: number;
+    fields: Array<{ field: string; updated: number; unchanged: number; missing: number }>;
+  }> {
+    try {
+      return await invoke('refresh_fields', { fields, filter, dryRun });
+    } catch (error) {
+      throw new Error(`Failed to refresh fields: ${error}`);
+    }
+  }
+
+  /**
+   * List/detail items that exhausted their retries, newest failures first.
+   */
+  async listFailedItems(
+    filter: {
+      stage?: 'list_page' | 'product_detail';
+      status?: 'pending' | 'requeued' | 'resolved';
+      session_id?: string;
+      limit?: number;
+      offset?: number;
+    } = {},
+    options?: QueryOptions
+  ): Promise<Array<{
+    id: number;
+    stage: 'list_page' | 'product_detail';
+    item_key: string;
+    session_id: string;
+    error_class: string;
+    last_error: string;
+    attempts: Array<{ attempt: number; error: string; at: string }>;
+    payload: Record<string, unknown>;
+    status: 'pending' | 'requeued' | 'resolved';
+    dead_letter_count: number;
+    first_failed_at: string;
+    last_failed_at: string;
+    requeued_at?: string | null;
+  }>> {
+    try {
+      return await invoke('list_failed_items', { filter, options });
+    } catch (error) {
+      throw new Error(`Failed to list failed items: ${formatCommandError(error)}`);
+    }
+  }
+
+  /**
+   * Replay dead-lettered items by id, or every pending item matching `filter`.
+   * List pages go to the next sync's revalidation pass; detail URLs are re-fetched now.
+   */
+  async requeueFailedItems(
+    ids?: number[],
+    filter?: {
+      stage?: 'list_page' | 'product_detail';
+      status?: 'pending' | 'requeued' | 'resolved';
+      session_id?: string;
+      limit?: number;
+    }
+  ): Promise<{
+    selected: number;
+    pages_queued: number;
+    resolved: number;
+    failed_again: number;
+    skipped: number;
+  }> {
+    try {
+      return await invoke('requeue_failed_items', { ids: ids ?? null, filter: filter ?? null });
+    } catch (error) {
+      throw new Error(`Failed to requeue failed items: ${error}`);
+    }
+  }
+
+  /**
+   * Promoted extraction rules and the latest shadow rollout with its field-level comparison.
+   */
+  async getExtractorRollout(): Promise<{
+    active?: { site_profile: string; fingerprint: string } | null;
+    rollout?: ExtractorRolloutComparison | null;
+  }> {
+    try {
+      return await invoke('get_extractor_rollout');
+    } catch (error) {
+      throw new Error(`Failed to get extractor rollout: ${error}`);
+    }
+  }
+
+  /**
+   * Promote the shadowing extraction rules; extractors built afterwards use them.
+   */
+  async promoteExtractorRules(): Promise<ExtractorRolloutComparison> {
+    try {
+      return await invoke('promote_extractor_rules');
+    } catch (error) {
+      throw new Error(`Failed to promote extractor rules: ${error}`);
+    }
+  }
+
+  /**
+   * Drop the shadowing extraction rules and point the settings back at the promoted profile.
+   */
+  async rollbackExtractorRules(): Promise<ExtractorRolloutComparison> {
+    try {
+      return await invoke('rollback_extractor_rules');
+    } catch (error) {
+      throw new Error(`Failed to roll back extractor rules: ${error}`);
+    }
+  }
+
+  /**
+   * Captured log entries of one session (oldest first) for the log viewer.
+   * `level` keeps entries at that level or more severe.
+   */
+  async getSessionLogs(
+    sessionId: string,
+    options: { level?: 'error' | 'warn' | 'info' | 'debug' | 'trace'; offset?: number; limit?: number } = {}
+  ): Promise<{
+    session_id: string;
+    total: number;
+    offset: number;
+    entries: Array<{ timestamp: string; level: string; target: string; message: string; fields?: Record<string, unknown> }>;
+  }> {
+    try {
+      return await invoke<any>('get_session_logs', {
+        sessionId,
+        level: options.level ?? null,
+        offset: options.offset ?? null,
+        limit: options.limit ?? null,
+      });
+    } catch (error) {
+      throw new Error(`Failed to get session logs: ${error}`);
+    }
+  }
+
   /**
    * Synchronize product_details coordinates and ids from products by URL.
    * Returns a concise report with counts.
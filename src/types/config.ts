@@ -12,6 +12,9 @@ export interface LoggingConfig {
   auto_cleanup_logs: boolean;
   keep_only_latest: boolean;
   module_filters: Record<string, string>;
+  /** Capture session-tagged events into logs/sessions/<session_id>.jsonl */
+  session_logs_enabled?: boolean;
+  session_logs_max_sessions?: number;
 }
 
 export interface BatchConfig {
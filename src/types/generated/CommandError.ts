@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CommandErrorDetail } from "./CommandErrorDetail";
+
+/**
+ * Error returned by sync, validation and crawling commands, tagged by `category`
+ */
+export type CommandError = { "category": "network" } & CommandErrorDetail | { "category": "site" } & CommandErrorDetail | { "category": "data" } & CommandErrorDetail | { "category": "storage" } & CommandErrorDetail | { "category": "configuration" } & CommandErrorDetail | { "category": "runtime" } & CommandErrorDetail | { "category": "request" } & CommandErrorDetail;
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ErrorCode } from "./ErrorCode";
+
+/**
+ * Payload shared by every `CommandError` category
+ */
+export type CommandErrorDetail = { code: ErrorCode, 
+/**
+ * Human-readable message without the `[E_...]` tag
+ */
+message: string, 
+/**
+ * Whether retrying the same command later can reasonably succeed
+ */
+retryable: boolean, title: string, remediation: Array<string>, };
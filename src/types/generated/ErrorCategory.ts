@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Broad grouping used for dashboards and retry decisions
+ */
+export type ErrorCategory = "network" | "site" | "data" | "storage" | "configuration" | "runtime" | "request";
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Machine-readable error code shared by every crawl layer
+ */
+export type ErrorCode = "NETWORK_TIMEOUT" | "NETWORK_FAILURE" | "HTTP_SERVER_ERROR" | "RATE_LIMITED" | "ACCESS_DENIED" | "PARSE_FAILED" | "EMPTY_LIST_PAGE" | "LIST_COLLECT_FAILED" | "SITE_STATUS_FAILED" | "DETAIL_COLLECT_FAILED" | "VALIDATION_FAILED" | "PERSISTENCE_FAILED" | "DATABASE_ERROR" | "CONFIGURATION_ERROR" | "UNSUPPORTED_STAGE" | "UNEXPECTED_STAGE_ITEM" | "SERIALIZATION_FAILED" | "CHANNEL_CLOSED" | "CANCELLED" | "TIMED_OUT" | "RESOURCE_EXHAUSTED" | "INVALID_REQUEST" | "INTERNAL";